@@ -2,23 +2,21 @@ use proc_macro::TokenStream;
 use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
-use syn::{Data, DeriveInput, Fields, parse_macro_input};
+use syn::{
+    Attribute, Data, DeriveInput, Fields, Generics, LitInt, LitStr, Token, Type, WherePredicate,
+    parse_macro_input, parse_quote, punctuated::Punctuated,
+};
 
-#[proc_macro_derive(FormModel)]
+#[proc_macro_derive(FormModel, attributes(form))]
 pub fn derive_form_model(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    if !input.generics.params.is_empty() {
-        return syn::Error::new_spanned(
-            input.ident,
-            "FormModel derive currently supports only non-generic structs",
-        )
-        .to_compile_error()
-        .into();
-    }
-
     let model_ident = input.ident;
     let fields_struct_ident = format_ident!("{model_ident}Fields");
+    let generics = input.generics;
+    let is_generic = !generics.params.is_empty();
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let model_ty = quote!(#model_ident #ty_generics);
 
     let named_fields = match input.data {
         Data::Struct(data) => match data.fields {
@@ -43,8 +41,26 @@ pub fn derive_form_model(input: TokenStream) -> TokenStream {
     };
 
     let calmui = calmui_path();
+    let nested_ident = format_ident!("{model_ident}NestedFields");
     let mut lens_defs = Vec::new();
     let mut fields_methods = Vec::new();
+    let mut nested_methods = Vec::new();
+    let mut rule_registrations = Vec::new();
+    let mut nested_rule_registrations = Vec::new();
+    let mut meta_arms = Vec::new();
+    let mut nested_meta_lookups = Vec::new();
+    let mut auto_fields = Vec::new();
+    let mut nested_auto_fields = Vec::new();
+    let mut model_generics = generics.clone();
+    let mut fields_generics = generics.clone();
+    if is_generic {
+        model_generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(
+                Self: ::core::clone::Clone + ::core::marker::Send + ::core::marker::Sync + 'static
+            ));
+    }
 
     for field in named_fields {
         let Some(field_ident) = field.ident else {
@@ -53,49 +69,407 @@ pub fn derive_form_model(input: TokenStream) -> TokenStream {
         let field_ty = field.ty;
         let field_name = field_ident.to_string();
         let lens_ident = format_ident!("{model_ident}{}Lens", to_pascal_case(&field_name));
+        let attrs = match parse_field_attrs(&field.attrs, &calmui) {
+            Ok(attrs) => attrs,
+            Err(error) => return error.to_compile_error().into(),
+        };
+        if let Some(descriptor) = attrs.meta.descriptor(&calmui) {
+            meta_arms.push(quote! {
+                #field_name => ::core::option::Option::Some(#descriptor),
+            });
+        }
+        let lens_ty = quote!(#lens_ident #ty_generics);
+        let lens_marker = marker_struct(&lens_ident, &generics, &model_ty);
+        let lens_value = marker_value(&lens_ident, is_generic);
+        let mut lens_generics = generics.clone();
+        if is_generic {
+            lens_generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#model_ty: 'static));
+        }
+
+        if attrs.nested {
+            if is_generic {
+                let predicate: WherePredicate =
+                    parse_quote!(#field_ty: #calmui::form::NestedFormModel);
+                model_generics
+                    .make_where_clause()
+                    .predicates
+                    .push(predicate.clone());
+                fields_generics
+                    .make_where_clause()
+                    .predicates
+                    .push(predicate);
+            }
+            auto_fields.push(quote! {
+                fields.extend(<#field_ty as #calmui::form::NestedFormModel>::nested_auto_fields(
+                    #lens_value,
+                ));
+            });
+            nested_auto_fields.push(quote! {
+                fields.extend(<#field_ty as #calmui::form::NestedFormModel>::nested_auto_fields(
+                    #calmui::form::ComposedLens::new(path, #lens_value),
+                ));
+            });
+            let prefix = format!("{field_name}.");
+            nested_meta_lookups.push(quote! {
+                if let ::core::option::Option::Some(rest) = key.as_str().strip_prefix(#prefix) {
+                    return <#field_ty as #calmui::form::FormModel>::field_meta(
+                        #calmui::form::FieldKey::new(rest),
+                    );
+                }
+            });
+            rule_registrations.push(quote! {
+                <#field_ty as #calmui::form::NestedFormModel>::register_nested_rules(
+                    controller,
+                    #lens_value,
+                )?;
+            });
+            nested_rule_registrations.push(quote! {
+                <#field_ty as #calmui::form::NestedFormModel>::register_nested_rules(
+                    controller,
+                    #calmui::form::ComposedLens::new(path, #lens_value),
+                )?;
+            });
+
+            let (lens_impl_generics, _, lens_where_clause) = lens_generics.split_for_impl();
+            lens_defs.push(quote! {
+                #lens_marker
+
+                impl #lens_impl_generics #calmui::form::FieldPath<#model_ty> for #lens_ty
+                #lens_where_clause
+                {
+                    type Target = #field_ty;
+
+                    fn path_key(self) -> #calmui::form::FieldKey {
+                        #calmui::form::FieldKey::new(#field_name)
+                    }
+
+                    fn target<'__model>(self, model: &'__model #model_ty) -> &'__model Self::Target {
+                        &model.#field_ident
+                    }
+
+                    fn target_mut<'__model>(
+                        self,
+                        model: &'__model mut #model_ty,
+                    ) -> &'__model mut Self::Target {
+                        &mut model.#field_ident
+                    }
+                }
+            });
+
+            fields_methods.push(quote! {
+                pub fn #field_ident(
+                    &self,
+                ) -> <#field_ty as #calmui::form::NestedFormModel>::NestedFields<#lens_ty> {
+                    <#field_ty as #calmui::form::NestedFormModel>::nested_fields(#lens_value)
+                }
+            });
+            nested_methods.push(quote! {
+                pub fn #field_ident(
+                    &self,
+                ) -> <#field_ty as #calmui::form::NestedFormModel>::NestedFields<
+                    #calmui::form::ComposedLens<__P, #lens_ty>,
+                > {
+                    <#field_ty as #calmui::form::NestedFormModel>::nested_fields(
+                        #calmui::form::ComposedLens::new(self.path, #lens_value),
+                    )
+                }
+            });
+            continue;
+        }
+
+        let auto_constructor = match (attrs.choice.or(auto_field_kind(&field_ty)), attrs.options) {
+            (Some(AutoFieldKind::Text), Some(options)) => Some(quote! {
+                #calmui::form::AutoField::select(__lens, [#(#options),*])
+            }),
+            (_, Some(options)) => {
+                return syn::Error::new_spanned(
+                    &options[0],
+                    "`options` requires a `SharedString` field",
+                )
+                .to_compile_error()
+                .into();
+            }
+            (Some(kind), None) => {
+                let constructor = kind.constructor();
+                Some(quote!(#calmui::form::AutoField::#constructor(__lens)))
+            }
+            (None, None) => None,
+        };
+        if let Some(constructor) = auto_constructor {
+            auto_fields.push(quote! {
+                let __lens = #lens_value;
+                fields.push(#constructor);
+            });
+            nested_auto_fields.push(quote! {
+                let __lens = #calmui::form::ComposedLens::new(path, #lens_value);
+                fields.push(#constructor);
+            });
+        }
+
+        let rules = attrs.rules;
+        let rules_method = if rules.is_empty() {
+            quote! {}
+        } else {
+            if is_generic {
+                let predicates = &mut model_generics.make_where_clause().predicates;
+                predicates.push(parse_quote!(#field_ty: #calmui::form::RuleValue));
+                predicates.push(lens_value_predicate(&field_ty));
+            }
+            rule_registrations.push(quote! {
+                controller.register_field_rules(
+                    #lens_value,
+                    #calmui::form::FieldLens::<Self>::rules(#lens_value),
+                )?;
+            });
+            nested_rule_registrations.push(quote! {
+                controller.register_field_rules(
+                    #calmui::form::ComposedLens::new(path, #lens_value),
+                    #calmui::form::FieldLens::<Self>::rules(#lens_value),
+                )?;
+            });
+            quote! {
+                fn rules(self) -> ::std::vec::Vec<#calmui::form::FieldRule> {
+                    ::std::vec![#(#rules),*]
+                }
+            }
+        };
+
+        if is_generic {
+            lens_generics
+                .make_where_clause()
+                .predicates
+                .push(lens_value_predicate(&field_ty));
+        }
+        let (lens_impl_generics, _, lens_where_clause) = lens_generics.split_for_impl();
 
         lens_defs.push(quote! {
-            #[derive(Clone, Copy, Debug, Default)]
-            pub struct #lens_ident;
+            #lens_marker
 
-            impl #calmui::form::FieldLens<#model_ident> for #lens_ident {
+            impl #lens_impl_generics #calmui::form::FieldLens<#model_ty> for #lens_ty
+            #lens_where_clause
+            {
                 type Value = #field_ty;
 
                 fn key(self) -> #calmui::form::FieldKey {
                     #calmui::form::FieldKey::new(#field_name)
                 }
 
-                fn get<'a>(self, model: &'a #model_ident) -> &'a Self::Value {
+                fn get<'__model>(self, model: &'__model #model_ty) -> &'__model Self::Value {
                     &model.#field_ident
                 }
 
-                fn set(self, model: &mut #model_ident, value: Self::Value) {
+                fn set(self, model: &mut #model_ty, value: Self::Value) {
                     model.#field_ident = value;
                 }
+
+                #rules_method
             }
         });
 
         fields_methods.push(quote! {
-            pub const fn #field_ident(&self) -> #lens_ident {
-                #lens_ident
+            pub const fn #field_ident(&self) -> #lens_ty {
+                #lens_value
+            }
+        });
+        nested_methods.push(quote! {
+            pub const fn #field_ident(&self) -> #calmui::form::ComposedLens<__P, #lens_ty> {
+                #calmui::form::ComposedLens::new(self.path, #lens_value)
             }
         });
     }
 
+    let register_rules = if rule_registrations.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn register_rules<E>(
+                controller: &#calmui::form::FormController<Self, E>,
+            ) -> #calmui::form::FormResult<()>
+            where
+                E: #calmui::form::ValidationError
+                    + ::core::convert::From<#calmui::form::FieldRuleViolation>,
+            {
+                #(#rule_registrations)*
+                Ok(())
+            }
+        }
+    };
+
+    let register_nested_rules = if nested_rule_registrations.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn register_nested_rules<__T, __E, __P>(
+                controller: &#calmui::form::FormController<__T, __E>,
+                path: __P,
+            ) -> #calmui::form::FormResult<()>
+            where
+                __T: ::core::clone::Clone + ::core::marker::Send + ::core::marker::Sync + 'static,
+                __E: #calmui::form::ValidationError
+                    + ::core::convert::From<#calmui::form::FieldRuleViolation>,
+                __P: #calmui::form::FieldPath<__T, Target = Self>,
+            {
+                #(#nested_rule_registrations)*
+                Ok(())
+            }
+        }
+    };
+
+    let field_meta = if meta_arms.is_empty() && nested_meta_lookups.is_empty() {
+        quote! {}
+    } else {
+        let own_meta = if meta_arms.is_empty() {
+            quote!(::core::option::Option::None)
+        } else {
+            quote! {
+                match key.as_str() {
+                    #(#meta_arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        };
+        quote! {
+            fn field_meta(
+                key: #calmui::form::FieldKey,
+            ) -> ::core::option::Option<#calmui::form::FieldDescriptor> {
+                #(#nested_meta_lookups)*
+                #own_meta
+            }
+        }
+    };
+
+    let auto_fields_method = if auto_fields.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn auto_fields<__E>() -> ::std::vec::Vec<#calmui::form::AutoField<Self, __E>>
+            where
+                __E: #calmui::form::ValidationError,
+            {
+                let mut fields = ::std::vec::Vec::new();
+                #(#auto_fields)*
+                fields
+            }
+        }
+    };
+
+    let nested_auto_fields_method = if nested_auto_fields.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn nested_auto_fields<__T, __E, __P>(
+                path: __P,
+            ) -> ::std::vec::Vec<#calmui::form::AutoField<__T, __E>>
+            where
+                __T: ::core::clone::Clone + ::core::marker::Send + ::core::marker::Sync + 'static,
+                __E: #calmui::form::ValidationError,
+                __P: #calmui::form::FieldPath<__T, Target = Self>,
+            {
+                let mut fields = ::std::vec::Vec::new();
+                #(#nested_auto_fields)*
+                fields
+            }
+        }
+    };
+
+    let mut nested_generics = generics.clone();
+    let path_position = nested_generics.lifetimes().count();
+    nested_generics
+        .params
+        .insert(path_position, parse_quote!(__P));
+    nested_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(__P: ::core::marker::Copy));
+    let mut nested_methods_generics = nested_generics.clone();
+    if let Some(extra) = fields_generics.where_clause.as_ref() {
+        nested_methods_generics
+            .make_where_clause()
+            .predicates
+            .extend(extra.predicates.iter().cloned());
+    }
+    let (nested_impl_generics, nested_ty_generics, nested_where_clause) =
+        nested_generics.split_for_impl();
+    let (nested_methods_impl_generics, _, nested_methods_where_clause) =
+        nested_methods_generics.split_for_impl();
+    let nested_name = nested_ident.to_string();
+
+    let (fields_impl_generics, _, fields_where_clause) = fields_generics.split_for_impl();
+    let (model_impl_generics, _, model_where_clause) = model_generics.split_for_impl();
+    let fields_marker = marker_struct(&fields_struct_ident, &generics, &model_ty);
+    let fields_value = marker_value(&fields_struct_ident, is_generic);
+
     quote! {
-        #[derive(Clone, Copy, Debug, Default)]
-        pub struct #fields_struct_ident;
+        #fields_marker
 
-        impl #fields_struct_ident {
+        impl #fields_impl_generics #fields_struct_ident #ty_generics #fields_where_clause {
             #(#fields_methods)*
         }
 
-        impl #calmui::form::FormModel for #model_ident {
-            type Fields = #fields_struct_ident;
+        #[allow(dead_code)]
+        pub struct #nested_ident #nested_impl_generics #nested_where_clause {
+            path: __P,
+            _model: ::core::marker::PhantomData<fn() -> #model_ty>,
+        }
+
+        impl #nested_impl_generics ::core::clone::Clone for #nested_ident #nested_ty_generics
+        #nested_where_clause
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #nested_impl_generics ::core::marker::Copy for #nested_ident #nested_ty_generics
+        #nested_where_clause
+        {
+        }
+
+        impl #nested_impl_generics ::core::fmt::Debug for #nested_ident #nested_ty_generics
+        #nested_where_clause
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(#nested_name)
+            }
+        }
+
+        #[allow(dead_code)]
+        impl #nested_methods_impl_generics #nested_ident #nested_ty_generics
+        #nested_methods_where_clause
+        {
+            #(#nested_methods)*
+        }
+
+        impl #model_impl_generics #calmui::form::FormModel for #model_ty #model_where_clause {
+            type Fields = #fields_struct_ident #ty_generics;
 
             fn fields() -> Self::Fields {
-                #fields_struct_ident
+                #fields_value
             }
+
+            #field_meta
+
+            #auto_fields_method
+
+            #register_rules
+        }
+
+        impl #model_impl_generics #calmui::form::NestedFormModel for #model_ty #model_where_clause {
+            type NestedFields<__P: ::core::marker::Copy> = #nested_ident #nested_ty_generics;
+
+            fn nested_fields<__P: ::core::marker::Copy>(path: __P) -> Self::NestedFields<__P> {
+                #nested_ident {
+                    path,
+                    _model: ::core::marker::PhantomData,
+                }
+            }
+
+            #register_nested_rules
+
+            #nested_auto_fields_method
         }
 
         #(#lens_defs)*
@@ -103,6 +477,345 @@ pub fn derive_form_model(input: TokenStream) -> TokenStream {
     .into()
 }
 
+#[proc_macro_derive(FieldOptions, attributes(option))]
+pub fn derive_field_options(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_field_options(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_field_options(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "FieldOptions derive is only supported on enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "FieldOptions derive does not support generic enums",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "FieldOptions derive needs at least one variant",
+        ));
+    }
+
+    let calmui = calmui_path();
+    let enum_ident = input.ident;
+    let mut variants = Vec::new();
+    let mut value_arms = Vec::new();
+    let mut label_arms = Vec::new();
+    for variant in data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant,
+                "FieldOptions variants cannot carry data",
+            ));
+        }
+        let mut value = None;
+        let mut label = None;
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("option"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("value") {
+                    set_meta_value(&mut value, &meta, "value")
+                } else if meta.path.is_ident("label") {
+                    set_meta_value(&mut label, &meta, "label")
+                } else {
+                    Err(meta.error("unsupported option attribute"))
+                }
+            })?;
+        }
+        let ident = variant.ident;
+        let name = ident.to_string();
+        let value = value.unwrap_or_else(|| LitStr::new(&to_snake_case(&name), ident.span()));
+        let label = label.unwrap_or_else(|| LitStr::new(&to_label(&name), ident.span()));
+        variants.push(quote!(#enum_ident::#ident));
+        value_arms.push(quote!(Self::#ident => #value));
+        label_arms.push(quote!(Self::#ident => #label));
+    }
+
+    Ok(quote! {
+        impl #calmui::form::FieldOptions for #enum_ident {
+            fn variants() -> &'static [Self] {
+                const VARIANTS: &[#enum_ident] = &[#(#variants),*];
+                VARIANTS
+            }
+
+            fn value(&self) -> &'static str {
+                match self {
+                    #(#value_arms,)*
+                }
+            }
+
+            fn label(&self) -> &'static str {
+                match self {
+                    #(#label_arms,)*
+                }
+            }
+        }
+    })
+}
+
+fn lens_value_predicate(field_ty: &Type) -> WherePredicate {
+    parse_quote!(
+        #field_ty: ::core::clone::Clone
+            + ::core::cmp::PartialEq
+            + ::core::marker::Send
+            + ::core::marker::Sync
+            + 'static
+    )
+}
+
+fn marker_struct(ident: &Ident, generics: &Generics, model_ty: &TokenStream2) -> TokenStream2 {
+    if generics.params.is_empty() {
+        return quote! {
+            #[derive(Clone, Copy, Debug, Default)]
+            pub struct #ident;
+        };
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = ident.to_string();
+    quote! {
+        pub struct #ident #impl_generics #where_clause {
+            _model: ::core::marker::PhantomData<fn() -> #model_ty>,
+        }
+
+        impl #impl_generics ::core::clone::Clone for #ident #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #impl_generics ::core::marker::Copy for #ident #ty_generics #where_clause {}
+
+        impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    _model: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(#name)
+            }
+        }
+    }
+}
+
+fn marker_value(ident: &Ident, is_generic: bool) -> TokenStream2 {
+    if is_generic {
+        quote!(#ident {
+            _model: ::core::marker::PhantomData,
+        })
+    } else {
+        quote!(#ident)
+    }
+}
+
+struct FieldAttrs {
+    rules: Vec<TokenStream2>,
+    nested: bool,
+    meta: FieldMetaAttrs,
+    options: Option<Vec<LitStr>>,
+    choice: Option<AutoFieldKind>,
+}
+
+#[derive(Clone, Copy)]
+enum AutoFieldKind {
+    Text,
+    Number,
+    Checkbox,
+    EnumSelect,
+    EnumRadio,
+}
+
+impl AutoFieldKind {
+    fn constructor(self) -> Ident {
+        let name = match self {
+            AutoFieldKind::Text => "text",
+            AutoFieldKind::Number => "number",
+            AutoFieldKind::Checkbox => "checkbox",
+            AutoFieldKind::EnumSelect => "enum_select",
+            AutoFieldKind::EnumRadio => "enum_radio",
+        };
+        Ident::new(name, Span::call_site())
+    }
+}
+
+/// Picks the `AutoForm` input from the field type's last path segment, which
+/// covers the value types the controller bindings accept.
+fn auto_field_kind(ty: &Type) -> Option<AutoFieldKind> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if !segment.arguments.is_empty() {
+        return None;
+    }
+    match segment.ident.to_string().as_str() {
+        "SharedString" => Some(AutoFieldKind::Text),
+        "Decimal" => Some(AutoFieldKind::Number),
+        "bool" => Some(AutoFieldKind::Checkbox),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct FieldMetaAttrs {
+    label: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    description: Option<LitStr>,
+}
+
+impl FieldMetaAttrs {
+    fn descriptor(&self, calmui: &TokenStream2) -> Option<TokenStream2> {
+        if self.label.is_none() && self.placeholder.is_none() && self.description.is_none() {
+            return None;
+        }
+        let label = option_tokens(self.label.as_ref());
+        let placeholder = option_tokens(self.placeholder.as_ref());
+        let description = option_tokens(self.description.as_ref());
+        Some(quote! {
+            #calmui::form::FieldDescriptor {
+                label: #label,
+                placeholder: #placeholder,
+                description: #description,
+            }
+        })
+    }
+}
+
+fn option_tokens(value: Option<&LitStr>) -> TokenStream2 {
+    match value {
+        Some(value) => quote!(::core::option::Option::Some(#value)),
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+fn set_meta_value(
+    slot: &mut Option<LitStr>,
+    meta: &syn::meta::ParseNestedMeta<'_>,
+    name: &str,
+) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error(format!("duplicate `{name}` attribute")));
+    }
+    *slot = Some(meta.value()?.parse()?);
+    Ok(())
+}
+
+fn parse_field_attrs(attrs: &[Attribute], calmui: &TokenStream2) -> syn::Result<FieldAttrs> {
+    let mut rules = Vec::new();
+    let mut nested = None;
+    let mut field_meta = FieldMetaAttrs::default();
+    let mut options = None;
+    let mut choice = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+        let mut attr_rules = Vec::new();
+        let mut message = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nested") {
+                nested = Some(attr);
+            } else if meta.path.is_ident("required") {
+                attr_rules.push(quote!(#calmui::form::FieldRule::required()));
+            } else if meta.path.is_ident("min_len") {
+                let value: LitInt = meta.value()?.parse()?;
+                attr_rules.push(quote!(#calmui::form::FieldRule::min_len(#value)));
+            } else if meta.path.is_ident("max_len") {
+                let value: LitInt = meta.value()?.parse()?;
+                attr_rules.push(quote!(#calmui::form::FieldRule::max_len(#value)));
+            } else if meta.path.is_ident("pattern") {
+                let value: LitStr = meta.value()?.parse()?;
+                attr_rules.push(quote!(#calmui::form::FieldRule::pattern(#value)));
+            } else if meta.path.is_ident("label") {
+                set_meta_value(&mut field_meta.label, &meta, "label")?;
+            } else if meta.path.is_ident("placeholder") {
+                set_meta_value(&mut field_meta.placeholder, &meta, "placeholder")?;
+            } else if meta.path.is_ident("description") {
+                set_meta_value(&mut field_meta.description, &meta, "description")?;
+            } else if meta.path.is_ident("select") || meta.path.is_ident("radio") {
+                if choice.is_some() || options.is_some() {
+                    return Err(meta.error("a field takes one of `select`, `radio` or `options`"));
+                }
+                choice = Some(if meta.path.is_ident("select") {
+                    AutoFieldKind::EnumSelect
+                } else {
+                    AutoFieldKind::EnumRadio
+                });
+            } else if meta.path.is_ident("options") {
+                if options.is_some() {
+                    return Err(meta.error("duplicate `options` form attribute"));
+                }
+                if choice.is_some() {
+                    return Err(meta.error("a field takes one of `select`, `radio` or `options`"));
+                }
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let values = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                if values.is_empty() {
+                    return Err(meta.error("`options` needs at least one value"));
+                }
+                options = Some(values.into_iter().collect::<Vec<_>>());
+            } else if meta.path.is_ident("message") {
+                message = Some(meta.value()?.parse::<LitStr>()?);
+            } else {
+                return Err(meta.error("unsupported form attribute"));
+            }
+            Ok(())
+        })?;
+
+        if let Some(message) = message {
+            if attr_rules.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`message` must accompany at least one validation rule",
+                ));
+            }
+            for rule in &mut attr_rules {
+                *rule = quote!(#rule.message(#message));
+            }
+        }
+        rules.extend(attr_rules);
+    }
+
+    if let Some(attr) = nested
+        && !rules.is_empty()
+    {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "`nested` fields take their rules from the nested model",
+        ));
+    }
+    if let Some(attr) = nested
+        && (options.is_some() || choice.is_some())
+    {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "`nested` fields take their inputs from the nested model",
+        ));
+    }
+    Ok(FieldAttrs {
+        rules,
+        nested: nested.is_some(),
+        meta: field_meta,
+        options,
+        choice,
+    })
+}
+
 fn calmui_path() -> TokenStream2 {
     match crate_name("calmui") {
         Ok(FoundCrate::Name(name)) => {
@@ -128,3 +841,26 @@ fn to_pascal_case(input: &str) -> String {
     }
     out
 }
+
+fn to_snake_case(input: &str) -> String {
+    let mut out = String::new();
+    let mut previous_lower = false;
+    for ch in input.chars() {
+        if ch.is_ascii_uppercase() && previous_lower {
+            out.push('_');
+        }
+        previous_lower = ch.is_ascii_lowercase() || ch.is_ascii_digit();
+        out.push(ch.to_ascii_lowercase());
+    }
+    out
+}
+
+/// `DarkMode` becomes "Dark mode".
+fn to_label(input: &str) -> String {
+    let words = to_snake_case(input).replace('_', " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => words,
+    }
+}
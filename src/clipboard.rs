@@ -0,0 +1,117 @@
+//! System clipboard writes with short-lived "copied" feedback.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use gpui::{ClipboardItem, SharedString, Window};
+
+/// How long [`Clipboard::copied`] stays `true` after a copy.
+pub const COPIED_FEEDBACK: Duration = Duration::from_millis(1500);
+
+static COPY_TICKETS: LazyLock<Mutex<HashMap<SharedString, usize>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static NEXT_TICKET: AtomicUsize = AtomicUsize::new(1);
+
+/// Copies text and remembers, per key, that it just did so a control can
+/// say "Copied!" for a moment. Handles with the same key share that state.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Clipboard {
+    key: SharedString,
+    feedback: Duration,
+}
+
+impl Clipboard {
+    pub fn new(key: impl Into<SharedString>) -> Self {
+        Self {
+            key: key.into(),
+            feedback: COPIED_FEEDBACK,
+        }
+    }
+
+    pub fn feedback(mut self, value: Duration) -> Self {
+        self.feedback = value;
+        self
+    }
+
+    pub fn key(&self) -> &SharedString {
+        &self.key
+    }
+
+    /// Writes `text` to the system clipboard, then marks this key copied
+    /// until the feedback time runs out or another copy restarts it.
+    pub fn copy(&self, text: impl Into<String>, window: &mut Window, cx: &mut gpui::App) {
+        cx.write_to_clipboard(ClipboardItem::new_string(text.into()));
+        let ticket = self.begin();
+        window.refresh();
+
+        let clipboard = self.clone();
+        let window_handle = window.window_handle();
+        cx.spawn(async move |cx| {
+            cx.background_executor().timer(clipboard.feedback).await;
+            let _ = window_handle.update(cx, |_, window, _| {
+                if clipboard.finish(ticket) {
+                    window.refresh();
+                }
+            });
+        })
+        .detach();
+    }
+
+    pub fn copied(&self) -> bool {
+        COPY_TICKETS
+            .lock()
+            .map(|tickets| tickets.contains_key(&self.key))
+            .unwrap_or(false)
+    }
+
+    /// Ends the feedback early.
+    pub fn reset(&self) {
+        if let Ok(mut tickets) = COPY_TICKETS.lock() {
+            tickets.remove(&self.key);
+        }
+    }
+
+    fn begin(&self) -> usize {
+        let ticket = NEXT_TICKET.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut tickets) = COPY_TICKETS.lock() {
+            tickets.insert(self.key.clone(), ticket);
+        }
+        ticket
+    }
+
+    /// Clears the feedback for `ticket`, unless a later copy has taken over.
+    fn finish(&self, ticket: usize) -> bool {
+        let Ok(mut tickets) = COPY_TICKETS.lock() else {
+            return false;
+        };
+        if tickets.get(&self.key) != Some(&ticket) {
+            return false;
+        }
+        tickets.remove(&self.key);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn later_copies_outlive_earlier_timers() {
+        let clipboard = Clipboard::new("clipboard-test");
+        assert!(!clipboard.copied());
+        let first = clipboard.begin();
+        let second = clipboard.begin();
+        assert!(clipboard.copied());
+        assert!(!clipboard.finish(first));
+        assert!(clipboard.copied());
+        assert!(clipboard.finish(second));
+        assert!(!clipboard.copied());
+
+        clipboard.begin();
+        clipboard.reset();
+        assert!(!clipboard.copied());
+    }
+}
@@ -16,7 +16,8 @@ use super::icon::Icon;
 use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
 use super::selection_state;
 use super::utils::{
-    InteractionStyles, apply_interaction_styles, apply_radius, interaction_style, resolve_hsla,
+    InteractionStyles, apply_disabled_state, apply_interaction_styles, apply_radius,
+    interaction_style, resolve_hsla,
 };
 
 type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
@@ -195,8 +196,12 @@ impl RenderOnce for Accordion {
         let is_controlled = self.value_controlled;
         let item_bg = self.variant_surface_color(resolve_hsla(&self.theme, tokens.item_bg));
         let item_border = self.variant_border_color(resolve_hsla(&self.theme, tokens.item_border));
-        let header_hover_bg = item_bg.blend(gpui::white().opacity(0.04));
-        let header_press_bg = header_hover_bg.blend(gpui::black().opacity(0.08));
+        let header_hover_bg = tokens
+            .states
+            .hover_bg_or(crate::theme::color_utils::lighten(item_bg, 0.04));
+        let header_press_bg = tokens
+            .states
+            .active_bg_or(crate::theme::color_utils::darken(header_hover_bg, 0.08));
 
         let item_views = self
             .items
@@ -261,7 +266,7 @@ impl RenderOnce for Accordion {
                 );
 
                 if item.meta.disabled {
-                    header = header.cursor_default().opacity(0.55);
+                    header = apply_disabled_state(header.cursor_default(), tokens.states, 0.55);
                 } else {
                     let activate_handler = if let Some(handler) = self.on_change.clone() {
                         let accordion_id = self.id.to_string();
@@ -330,6 +335,7 @@ impl RenderOnce for Accordion {
                         header = bind_press_adapter(
                             header,
                             PressAdapter::new(header_id.clone())
+                                .component("accordion")
                                 .on_activate(Some(activate_handler)),
                         );
                     } else {
@@ -15,9 +15,10 @@ use crate::style::{Radius, Size, Variant};
 use super::icon::Icon;
 use super::interaction_adapter::{PressAdapter, bind_press_adapter};
 use super::loader::{Loader, LoaderVariant};
+use super::press_feedback::PressFeedback;
 use super::utils::{
-    PressHandler, apply_interaction_styles, apply_radius, default_pressable_surface_styles,
-    resolve_hsla,
+    PressHandler, apply_disabled_state, apply_interaction_styles, apply_radius, hit_area,
+    hit_target_inset, pressable_surface_styles, resolve_hsla,
 };
 
 type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
@@ -136,6 +137,10 @@ impl RenderOnce for ActionIcon {
         let fg = resolve_hsla(&self.theme, fg_token);
         let size_preset = self.size_preset();
         let size_px = f32::from(size_preset.box_size);
+        let hit_inset = hit_target_inset(
+            px(size_px),
+            crate::provider::CalmProvider::min_hit_target(_cx),
+        );
 
         let fallback = self
             .id
@@ -166,8 +171,8 @@ impl RenderOnce for ActionIcon {
             .h(px(size_px))
             .bg(bg)
             .text_color(fg)
-            .border(super::utils::quantized_stroke_px(window, 1.0))
-            .child(content);
+            .border(super::utils::quantized_stroke_px(window, 1.0));
+        let mut children = vec![content];
 
         root = apply_radius(&self.theme, root, self.radius);
 
@@ -178,27 +183,39 @@ impl RenderOnce for ActionIcon {
         }
 
         if self.disabled || self.loading {
-            root = root.opacity(0.55).cursor_default();
+            root = apply_disabled_state(
+                root.cursor_default(),
+                self.theme.components.action_icon.states,
+                0.55,
+            );
         } else if self.on_click.is_some() {
             root = root.cursor_pointer();
+            let press_feedback = PressFeedback::resolve(&self.theme, _cx);
             root = apply_interaction_styles(
                 root,
-                default_pressable_surface_styles(
+                press_feedback.interaction_styles(pressable_surface_styles(
                     bg,
                     resolve_hsla(&self.theme, self.theme.semantic.focus_ring),
-                ),
-            );
-            root = bind_press_adapter(
-                root,
-                PressAdapter::new(self.id.clone())
-                    .on_click(self.on_click.clone())
-                    .focus_handle(self.focus_handle.clone()),
+                    self.theme.components.action_icon.states,
+                )),
             );
+            root = press_feedback.apply(&self.id, root);
+            let adapter = PressAdapter::new(self.id.clone())
+                .component("action_icon")
+                .on_click(self.on_click.clone())
+                .focus_handle(self.focus_handle.clone());
+            if let Some(hit_area) = hit_area(self.id.slot("hit-area"), hit_inset) {
+                children.push(bind_press_adapter(hit_area, adapter).into_any_element());
+                root = root.relative();
+            } else {
+                root = bind_press_adapter(root, adapter);
+            }
         } else {
             root = root.cursor_default();
         }
 
-        root.with_enter_transition(self.id.slot("enter"), self.motion)
+        root.children(children)
+            .with_enter_transition(self.id.slot("enter"), self.motion)
     }
 }
 
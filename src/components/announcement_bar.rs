@@ -0,0 +1,217 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, ClickEvent, Hsla, IntoElement, ParentElement, RenderOnce, SharedString, Styled,
+    Window, div,
+};
+
+use crate::id::ComponentId;
+
+use super::alert::AlertKind;
+use super::control;
+use super::icon::Icon;
+use super::utils::resolve_hsla;
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+type DismissHandler = Rc<dyn Fn(&ClickEvent, &mut Window, &mut gpui::App)>;
+
+/// Where [`AppShell`](super::AppShell) places its announcement bar relative
+/// to the title bar.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AnnouncementBarPosition {
+    AboveTitleBar,
+    #[default]
+    BelowTitleBar,
+}
+
+/// A full-width strip for app-wide messages such as maintenance notices or
+/// expiring licenses, colored by [`AlertKind`].
+///
+/// Dismissing hides the bar for the rest of the session. To keep it hidden
+/// across launches, persist the dismissal in [`AnnouncementBar::on_dismiss`]
+/// and pass `visible(false)` on the next run.
+#[derive(IntoElement)]
+pub struct AnnouncementBar {
+    pub(crate) id: ComponentId,
+    message: Option<SharedString>,
+    kind: AlertKind,
+    icon: Option<SharedString>,
+    show_icon: bool,
+    dismissible: bool,
+    visible: Option<bool>,
+    default_visible: bool,
+    action: Option<SlotRenderer>,
+    on_dismiss: Option<DismissHandler>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl AnnouncementBar {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            message: None,
+            kind: AlertKind::Info,
+            icon: None,
+            show_icon: true,
+            dismissible: true,
+            visible: None,
+            default_visible: true,
+            action: None,
+            on_dismiss: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    pub fn message(mut self, value: impl Into<SharedString>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    pub fn kind(mut self, value: AlertKind) -> Self {
+        self.kind = value;
+        self
+    }
+
+    /// Replaces the icon picked from the kind.
+    pub fn icon(mut self, value: impl Into<SharedString>) -> Self {
+        self.icon = Some(value.into());
+        self
+    }
+
+    pub fn show_icon(mut self, value: bool) -> Self {
+        self.show_icon = value;
+        self
+    }
+
+    pub fn dismissible(mut self, value: bool) -> Self {
+        self.dismissible = value;
+        self
+    }
+
+    pub fn default_visible(mut self, value: bool) -> Self {
+        self.default_visible = value;
+        self
+    }
+
+    /// Link or button placed after the message, such as "Learn more".
+    pub fn action(mut self, content: impl IntoElement + 'static) -> Self {
+        self.action = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    /// Called when the close button is pressed, after the bar hides itself.
+    pub fn on_dismiss(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_dismiss = Some(Rc::new(handler));
+        self
+    }
+
+    fn colors(&self) -> (Hsla, Hsla) {
+        let tokens = &self.theme.components.announcement_bar;
+        let (bg, fg) = match self.kind {
+            AlertKind::Info | AlertKind::Loading => (tokens.info_bg, tokens.info_fg),
+            AlertKind::Success => (tokens.success_bg, tokens.success_fg),
+            AlertKind::Warning => (tokens.warning_bg, tokens.warning_fg),
+            AlertKind::Error => (tokens.error_bg, tokens.error_fg),
+        };
+        (resolve_hsla(&self.theme, bg), resolve_hsla(&self.theme, fg))
+    }
+
+    fn default_icon(kind: AlertKind) -> &'static str {
+        match kind {
+            AlertKind::Info => "info-circle",
+            AlertKind::Success => "circle-check",
+            AlertKind::Warning => "alert-triangle",
+            AlertKind::Error => "alert-circle",
+            AlertKind::Loading => "loader-2",
+        }
+    }
+}
+
+impl RenderOnce for AnnouncementBar {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let visible = control::bool_state(&self.id, "visible", self.visible, self.default_visible);
+        if !visible {
+            return div().id(self.id.clone());
+        }
+
+        let (bg, fg) = self.colors();
+        let tokens = self.theme.components.announcement_bar.clone();
+        let icon = self.show_icon.then(|| {
+            let name = self
+                .icon
+                .clone()
+                .unwrap_or_else(|| Self::default_icon(self.kind).into());
+            self.id
+                .ctx()
+                .child("kind-icon", Icon::named(name.to_string()))
+                .size(f32::from(tokens.icon_size))
+                .color(fg)
+        });
+        let close = self.dismissible.then(|| {
+            let bar_id = self.id.clone();
+            let on_dismiss = self.on_dismiss.clone();
+            div()
+                .id(self.id.slot("close"))
+                .flex_none()
+                .flex()
+                .items_center()
+                .justify_center()
+                .size(tokens.close_button_size)
+                .rounded_full()
+                .cursor_pointer()
+                .hover(move |style| style.bg(fg.opacity(0.16)))
+                .active(move |style| style.bg(fg.opacity(0.24)))
+                .child(
+                    self.id
+                        .ctx()
+                        .child("close-icon", Icon::named("x"))
+                        .size(f32::from(tokens.close_icon_size))
+                        .color(fg),
+                )
+                .on_click(
+                    move |event: &ClickEvent, window: &mut Window, cx: &mut gpui::App| {
+                        control::set_bool_state(&bar_id, "visible", false);
+                        if let Some(handler) = on_dismiss.as_ref() {
+                            (handler)(event, window, cx);
+                        }
+                        window.refresh();
+                    },
+                )
+        });
+
+        div()
+            .id(self.id.clone())
+            .flex()
+            .items_center()
+            .gap(tokens.gap)
+            .w_full()
+            .min_h(tokens.min_height)
+            .px(tokens.padding_x)
+            .py(tokens.padding_y)
+            .bg(bg)
+            .text_color(fg)
+            .text_size(tokens.font_size)
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .gap(tokens.gap)
+                    .children(icon)
+                    .children(self.message.clone())
+                    .children(self.action.take().map(|action| action())),
+            )
+            .children(close)
+    }
+}
+
+crate::impl_visible!(AnnouncementBar, |this, value| this.visible = Some(value));
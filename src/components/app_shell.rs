@@ -1,14 +1,22 @@
 use std::any::{Any, TypeId};
 use std::rc::Rc;
+use std::time::Duration;
 
 use gpui::InteractiveElement;
 use gpui::{
-    AnyElement, ClickEvent, ElementId, Hsla, IntoElement, MouseButton, ParentElement, Refineable,
-    RenderOnce, Styled, Window, WindowControlArea, div, px,
+    Animation, AnimationExt, AnyElement, ClickEvent, ElementId, Hsla, IntoElement, MouseButton,
+    ParentElement, Refineable, RenderOnce, Styled, Window, WindowControlArea, div, px,
 };
 
+use crate::contracts::MotionAware;
 use crate::id::ComponentId;
+use crate::motion::{MotionConfig, MotionLevel};
 
+use super::announcement_bar::AnnouncementBarPosition;
+use super::app_shell_layout::{
+    self, APP_SHELL_KEY_CONTEXT, AnnouncementSlot, AppShellLayout, AppShellPanel,
+    ToggleBottomPanel, ToggleInspector, ToggleSidebar,
+};
 use super::control;
 use super::divider::Divider;
 use super::overlay::{Overlay, OverlayMaterialMode};
@@ -26,6 +34,8 @@ type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
 type TitleBarRenderer = Box<dyn FnOnce(bool) -> AnyElement>;
 /// AppShell overlay 区域开关变化回调。
 type OverlayOpenChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
+/// AppShell 停靠布局变化回调（拖拽结束、折叠/展开时触发）。
+type LayoutChangeHandler = Rc<dyn Fn(AppShellLayout, &mut Window, &mut gpui::App)>;
 
 /// 侧边栏容器组件。
 ///
@@ -284,6 +294,10 @@ pub struct AppShell {
     /// - 内置 `TitleBar`：由组件自身声明 Drag / Min / Max / Close 的互斥区域；
     /// - 自定义标题栏：保留 AppShell overlay Drag 作为兼容回退。
     title_bar_is_calmui: bool,
+    /// 全局公告栏内容。
+    announcement_bar: Option<SlotRenderer>,
+    /// 公告栏相对标题栏的位置。
+    announcement_bar_position: AnnouncementBarPosition,
     /// 左侧区域内容。
     sidebar: Option<SlotRenderer>,
     /// 中央主内容区域（必填）。
@@ -326,6 +340,16 @@ pub struct AppShell {
     inspector_chrome: PaneChrome,
     /// 底部面板外观。
     bottom_panel_chrome: PaneChrome,
+    /// 是否允许拖拽 inline 区域边缘调整尺寸。
+    resizable: bool,
+    /// 停靠布局（受控值）。
+    layout: Option<AppShellLayout>,
+    /// 停靠布局（非受控初始值）。
+    default_layout: AppShellLayout,
+    /// 停靠布局变化回调。
+    on_layout_change: Option<LayoutChangeHandler>,
+    /// 折叠/展开动画配置。
+    motion: MotionConfig,
     /// 局部主题（用于读取 token 以及组件级主题覆盖）。
     pub(crate) theme: crate::theme::LocalTheme,
     /// 通用样式精修。
@@ -341,6 +365,8 @@ impl AppShell {
             title_bar: None,
             title_bar_immersive: false,
             title_bar_is_calmui: false,
+            announcement_bar: None,
+            announcement_bar_position: AnnouncementBarPosition::default(),
             sidebar: None,
             content: Some(Box::new(|| content.into_any_element())),
             inspector: None,
@@ -362,6 +388,11 @@ impl AppShell {
             sidebar_chrome: PaneChrome::default(),
             inspector_chrome: PaneChrome::default(),
             bottom_panel_chrome: PaneChrome::default(),
+            resizable: false,
+            layout: None,
+            default_layout: AppShellLayout::default(),
+            on_layout_change: None,
+            motion: MotionConfig::default(),
             theme: crate::theme::LocalTheme::default(),
             style: gpui::StyleRefinement::default(),
         }
@@ -401,6 +432,21 @@ impl AppShell {
         self
     }
 
+    /// 设置全局公告栏（通常为 `AnnouncementBar`），横跨整个窗口宽度。
+    pub fn announcement_bar(mut self, value: impl IntoElement + 'static) -> Self {
+        self.announcement_bar = Some(Box::new(|| value.into_any_element()));
+        self
+    }
+
+    /// 设置公告栏位于标题栏上方还是下方。
+    ///
+    /// 沉浸模式下：上方时标题栏改为悬浮在公告栏下方的主体之上；
+    /// 下方时公告栏悬浮在标题栏下沿，不挤占主体高度。
+    pub fn announcement_bar_position(mut self, value: AnnouncementBarPosition) -> Self {
+        self.announcement_bar_position = value;
+        self
+    }
+
     /// 设置左侧区域内容。
     pub fn sidebar(mut self, value: impl IntoElement + 'static) -> Self {
         self.sidebar = Some(Box::new(|| value.into_any_element()));
@@ -527,6 +573,292 @@ impl AppShell {
         self
     }
 
+    /// 允许拖拽 sidebar / inspector / bottom panel 的内侧边缘调整尺寸。
+    pub fn resizable(mut self, value: bool) -> Self {
+        self.resizable = value;
+        self
+    }
+
+    /// 设置停靠布局（受控）。
+    pub fn layout(mut self, value: AppShellLayout) -> Self {
+        self.layout = Some(value);
+        self
+    }
+
+    /// 设置停靠布局默认值（非受控），通常用于恢复持久化的快照。
+    pub fn default_layout(mut self, value: AppShellLayout) -> Self {
+        self.default_layout = value;
+        self
+    }
+
+    /// 监听停靠布局变化，可在回调中持久化 `AppShellLayout`。
+    pub fn on_layout_change(
+        mut self,
+        handler: impl Fn(AppShellLayout, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_layout_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// 解析当前生效的停靠布局。
+    fn resolved_layout(&self) -> AppShellLayout {
+        app_shell_layout::layout(&self.id, self.layout, self.default_layout)
+    }
+
+    /// 区域最小尺寸（来自主题 token）。
+    fn panel_min_size(&self, panel: AppShellPanel) -> f32 {
+        let tokens = &self.theme.components.app_shell;
+        f32::from(match panel {
+            AppShellPanel::Sidebar => tokens.sidebar_min_width,
+            AppShellPanel::Inspector => tokens.inspector_min_width,
+            AppShellPanel::BottomPanel => tokens.bottom_panel_min_height,
+        })
+    }
+
+    /// 区域最终尺寸：拖拽预览 > 布局快照 > builder 值 > 主题 token。
+    fn panel_size(&self, panel: AppShellPanel, layout: &AppShellLayout) -> f32 {
+        let tokens = &self.theme.components.app_shell;
+        let (configured, token) = match panel {
+            AppShellPanel::Sidebar => (self.sidebar_width_px, tokens.sidebar_width),
+            AppShellPanel::Inspector => (self.inspector_width_px, tokens.inspector_width),
+            AppShellPanel::BottomPanel => (self.bottom_panel_height_px, tokens.bottom_panel_height),
+        };
+        app_shell_layout::resize_size(&self.id, panel)
+            .or(layout.size(panel))
+            .or(configured)
+            .unwrap_or_else(|| f32::from(token))
+            .max(self.panel_min_size(panel))
+    }
+
+    /// 写入新布局（非受控时）并通知调用方。
+    fn commit_layout(
+        id: &str,
+        controlled: bool,
+        next: AppShellLayout,
+        handler: Option<&LayoutChangeHandler>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        if !controlled {
+            app_shell_layout::set_layout(id, next);
+        }
+        if let Some(handler) = handler {
+            (handler)(next, window, cx);
+        }
+        window.refresh();
+    }
+
+    /// 为停靠区域套上可折叠的宿主容器，折叠状态变化时播放宽/高动画。
+    fn dock_host(
+        &self,
+        panel: AppShellPanel,
+        region: gpui::Stateful<gpui::Div>,
+        size: f32,
+        collapsed: bool,
+    ) -> AnyElement {
+        let vertical = panel.is_vertical_split();
+        let region = if vertical {
+            region.w(px(size)).h_full()
+        } else {
+            region.h(px(size)).w_full()
+        };
+        let host = div()
+            .id(self.id.slot(format!("{}-dock", panel.key())))
+            .flex_none()
+            .overflow_hidden()
+            .child(region);
+        let host = if vertical {
+            host.h_full()
+        } else {
+            host.w_full()
+        };
+        let resting = if collapsed { 0.0 } else { size };
+        let generation = app_shell_layout::collapse_generation(&self.id, panel, collapsed);
+        let animated = generation > 0 && self.motion.level == MotionLevel::Full;
+        if !animated {
+            return if vertical {
+                host.w(px(resting)).into_any_element()
+            } else {
+                host.h(px(resting)).into_any_element()
+            };
+        }
+
+        let profile = if collapsed {
+            self.motion.exit
+        } else {
+            self.motion.enter
+        };
+        let easing = profile.easing;
+        let animation = Animation::new(Duration::from_millis(u64::from(profile.duration_ms)))
+            .with_easing(move |delta| easing.apply(delta));
+        let from = size - resting;
+        let animation_id = self
+            .id
+            .slot(format!("{}-collapse-{generation}", panel.key()));
+        host.with_animation(animation_id, animation, move |this, delta| {
+            let current = px(from + (resting - from) * delta);
+            if vertical {
+                this.w(current)
+            } else {
+                this.h(current)
+            }
+        })
+        .into_any_element()
+    }
+
+    /// 渲染区域内侧边缘的拖拽手柄。
+    fn render_resize_handle(&self, panel: AppShellPanel, size: f32) -> AnyElement {
+        let tokens = &self.theme.components.app_shell;
+        let hover_bg = resolve_hsla(&self.theme, tokens.resize_handle_bg);
+        let handle_size = tokens.resize_handle_size;
+        let half = px(-f32::from(handle_size) / 2.0);
+        let vertical = panel.is_vertical_split();
+        let shell_id = self.id.to_string();
+        let handle = div()
+            .id(self.id.slot(format!("{}-resize-handle", panel.key())))
+            .absolute()
+            .hover(move |style| style.bg(hover_bg))
+            .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                let pointer = if vertical {
+                    event.position.x
+                } else {
+                    event.position.y
+                };
+                app_shell_layout::begin_resize(&shell_id, panel, f32::from(pointer), size);
+                cx.stop_propagation();
+                window.refresh();
+            });
+        let (host, handle) = if vertical {
+            let handle = handle.top_0().bottom_0().left(half).w(handle_size);
+            (div().w(px(0.0)).h_full(), handle.cursor_col_resize())
+        } else {
+            let handle = handle.left_0().right_0().top(half).h(handle_size);
+            (div().h(px(0.0)).w_full(), handle.cursor_row_resize())
+        };
+        host.relative().flex_none().child(handle).into_any_element()
+    }
+
+    /// 绑定 `ToggleSidebar` / `ToggleInspector` / `ToggleBottomPanel` 动作。
+    ///
+    /// inline 区域切换折叠状态；overlay 区域切换浮层开关。
+    fn bind_layout_actions(&self, root: gpui::Stateful<gpui::Div>) -> gpui::Stateful<gpui::Div> {
+        let toggle = {
+            let id = self.id.clone();
+            let controlled = self.layout;
+            let default_layout = self.default_layout;
+            let on_change = self.on_layout_change.clone();
+            Rc::new(
+                move |panel: AppShellPanel, window: &mut Window, cx: &mut gpui::App| {
+                    let current = app_shell_layout::layout(&id, controlled, default_layout);
+                    let next = current.toggled(panel);
+                    let handler = on_change.as_ref();
+                    Self::commit_layout(&id, controlled.is_some(), next, handler, window, cx);
+                },
+            )
+        };
+        let sidebar_overlay = self.overlay_toggle(
+            self.sidebar_mode,
+            SIDEBAR_OVERLAY_STATE_SLOT,
+            self.sidebar_overlay_opened,
+            self.sidebar_overlay_default_opened,
+            self.on_sidebar_overlay_open_change.clone(),
+        );
+        let inspector_overlay = self.overlay_toggle(
+            self.inspector_mode,
+            INSPECTOR_OVERLAY_STATE_SLOT,
+            self.inspector_overlay_opened,
+            self.inspector_overlay_default_opened,
+            self.on_inspector_overlay_open_change.clone(),
+        );
+
+        let sidebar_toggle = toggle.clone();
+        let inspector_toggle = toggle.clone();
+        root.on_action(
+            move |_: &ToggleSidebar, window, cx| match &sidebar_overlay {
+                Some(overlay) => overlay(window, cx),
+                None => sidebar_toggle(AppShellPanel::Sidebar, window, cx),
+            },
+        )
+        .on_action(
+            move |_: &ToggleInspector, window, cx| match &inspector_overlay {
+                Some(overlay) => overlay(window, cx),
+                None => inspector_toggle(AppShellPanel::Inspector, window, cx),
+            },
+        )
+        .on_action(move |_: &ToggleBottomPanel, window, cx| {
+            toggle(AppShellPanel::BottomPanel, window, cx);
+        })
+    }
+
+    /// overlay 模式下的开关切换器；inline 模式返回 `None`。
+    fn overlay_toggle(
+        &self,
+        mode: PanelMode,
+        slot: &'static str,
+        controlled: Option<bool>,
+        default_opened: bool,
+        on_change: Option<OverlayOpenChangeHandler>,
+    ) -> Option<Rc<dyn Fn(&mut Window, &mut gpui::App)>> {
+        if mode != PanelMode::Overlay {
+            return None;
+        }
+        let id = self.id.clone();
+        Some(Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
+            let next = !control::bool_state(&id, slot, controlled, default_opened);
+            if controlled.is_none() {
+                control::set_bool_state(&id, slot, next);
+            }
+            if let Some(handler) = on_change.as_ref() {
+                (handler)(next, window, cx);
+            }
+            window.refresh();
+        }))
+    }
+
+    /// 在根节点跟踪拖拽手柄的指针移动，实时预览尺寸并在松开时提交布局。
+    fn bind_resize_tracking(&self, root: gpui::Stateful<gpui::Div>) -> gpui::Stateful<gpui::Div> {
+        let min_sizes = AppShellPanel::ALL.map(|panel| self.panel_min_size(panel));
+        let min_size = move |panel: AppShellPanel| match panel {
+            AppShellPanel::Sidebar => min_sizes[0],
+            AppShellPanel::Inspector => min_sizes[1],
+            AppShellPanel::BottomPanel => min_sizes[2],
+        };
+        let move_id = self.id.to_string();
+        let release_id = self.id.clone();
+        let controlled = self.layout;
+        let default_layout = self.default_layout;
+        let on_change = self.on_layout_change.clone();
+
+        root.on_mouse_move(move |event, window, _| {
+            let Some(panel) = app_shell_layout::resizing_panel(&move_id) else {
+                return;
+            };
+            let viewport = window.viewport_size();
+            let (pointer, available) = if panel.is_vertical_split() {
+                (event.position.x, viewport.width)
+            } else {
+                (event.position.y, viewport.height)
+            };
+            let (min, max) = app_shell_layout::size_bounds(min_size(panel), f32::from(available));
+            let pointer = f32::from(pointer);
+            if app_shell_layout::resize_to(&move_id, panel, pointer, min, max).is_some() {
+                window.refresh();
+            }
+        })
+        .on_mouse_up(MouseButton::Left, move |_, window, cx| {
+            let Some(panel) = app_shell_layout::resizing_panel(&release_id) else {
+                return;
+            };
+            let Some(size) = app_shell_layout::end_resize(&release_id, panel) else {
+                return;
+            };
+            let current = app_shell_layout::layout(&release_id, controlled, default_layout);
+            let next = current.with_size(panel, size);
+            let handler = on_change.as_ref();
+            Self::commit_layout(&release_id, controlled.is_some(), next, handler, window, cx);
+        })
+    }
+
     /// 解析左侧 overlay 的最终可见状态。
     fn resolved_sidebar_overlay_opened(&self) -> bool {
         control::bool_state(
@@ -585,6 +917,13 @@ impl AppShell {
 
 impl AppShell {}
 
+impl MotionAware for AppShell {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
 impl RenderOnce for AppShell {
     fn render(mut self, window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
         self.theme.sync_from_provider(_cx);
@@ -602,18 +941,10 @@ impl RenderOnce for AppShell {
             .title_bar_height_px
             .unwrap_or_else(|| f32::from(app_tokens.title_bar_height))
             .max(0.0);
-        let sidebar_width_px = self
-            .sidebar_width_px
-            .unwrap_or_else(|| f32::from(app_tokens.sidebar_width))
-            .max(f32::from(app_tokens.sidebar_min_width));
-        let inspector_width_px = self
-            .inspector_width_px
-            .unwrap_or_else(|| f32::from(app_tokens.inspector_width))
-            .max(f32::from(app_tokens.inspector_min_width));
-        let bottom_panel_height_px = self
-            .bottom_panel_height_px
-            .unwrap_or_else(|| f32::from(app_tokens.bottom_panel_height))
-            .max(f32::from(app_tokens.bottom_panel_min_height));
+        let layout = self.resolved_layout();
+        let sidebar_width_px = self.panel_size(AppShellPanel::Sidebar, &layout);
+        let inspector_width_px = self.panel_size(AppShellPanel::Inspector, &layout);
+        let bottom_panel_height_px = self.panel_size(AppShellPanel::BottomPanel, &layout);
         let text_color = resolve_hsla(&self.theme, self.theme.semantic.text_primary);
 
         let has_sidebar = self.sidebar.is_some();
@@ -634,7 +965,30 @@ impl RenderOnce for AppShell {
             .flex_col()
             .relative()
             .bg(body_bg)
-            .text_color(text_color);
+            .text_color(text_color)
+            .key_context(APP_SHELL_KEY_CONTEXT);
+        root = self.bind_layout_actions(root);
+        if self.resizable {
+            root = self.bind_resize_tracking(root);
+        }
+
+        // 公告栏（可选）：上方时先于标题栏加入根容器，下方时在标题栏之后处理。
+        let announcement_slot = app_shell_layout::announcement_slot(
+            self.announcement_bar_position,
+            self.title_bar.is_some() && self.title_bar_immersive,
+        );
+        let mut announcement = self.announcement_bar.take().map(|announcement_bar| {
+            div()
+                .id(self.id.slot("announcement-bar"))
+                .w_full()
+                .flex_none()
+                .child(announcement_bar())
+        });
+        let has_announcement_above =
+            announcement_slot == AnnouncementSlot::Top && announcement.is_some();
+        if has_announcement_above && let Some(announcement) = announcement.take() {
+            root = root.child(announcement);
+        }
 
         // 顶部区域（可选）。
         // - 非沉浸：标题栏参与正常布局，占据固定高度。
@@ -738,6 +1092,23 @@ impl RenderOnce for AppShell {
             }
         }
 
+        // 标题栏下方的公告栏：沉浸模式下悬浮在标题栏下沿，否则参与正常布局。
+        let mut announcement_overlay: Option<AnyElement> = None;
+        if let Some(announcement) = announcement.take() {
+            if announcement_slot == AnnouncementSlot::OverlayBelowTitleBar {
+                announcement_overlay = Some(
+                    announcement
+                        .absolute()
+                        .top(px(title_bar_height_px))
+                        .left_0()
+                        .right_0()
+                        .into_any_element(),
+                );
+            } else {
+                root = root.child(announcement);
+            }
+        }
+
         // 主体容器：用于承载 inline 布局与 overlay 浮层。
         let mut body_host = div()
             .id(self.id.slot("body"))
@@ -757,29 +1128,30 @@ impl RenderOnce for AppShell {
         if self.sidebar_mode == PanelMode::Inline
             && let Some(sidebar) = self.sidebar.take()
         {
+            let collapsed = layout.sidebar_collapsed;
             let mut sidebar_chrome = self.sidebar_chrome.clone();
             if self.inline_dividers {
                 sidebar_chrome.bordered = false;
             }
-            let sidebar_region = self
-                .wrap_region(
-                    window,
-                    self.id.slot("sidebar-inline"),
-                    sidebar(),
-                    &sidebar_chrome,
-                    sidebar_bg,
-                )
-                .w(px(sidebar_width_px))
-                .h_full()
-                .flex_none();
-            row = row.child(sidebar_region);
-            if self.inline_dividers {
+            let sidebar_region = self.wrap_region(
+                window,
+                self.id.slot("sidebar-inline"),
+                sidebar(),
+                &sidebar_chrome,
+                sidebar_bg,
+            );
+            let panel = AppShellPanel::Sidebar;
+            row = row.child(self.dock_host(panel, sidebar_region, sidebar_width_px, collapsed));
+            if self.inline_dividers && !collapsed {
                 row = row.child(
                     self.id
                         .ctx()
                         .child("divider-sidebar-center", Divider::vertical()),
                 );
             }
+            if self.resizable && !collapsed {
+                row = row.child(self.render_resize_handle(panel, sidebar_width_px));
+            }
         }
 
         let mut center = div()
@@ -808,27 +1180,31 @@ impl RenderOnce for AppShell {
         );
 
         if let Some(bottom_panel) = self.bottom_panel.take() {
+            let collapsed = layout.bottom_panel_collapsed;
+            let panel = AppShellPanel::BottomPanel;
             let mut bottom_panel_chrome = self.bottom_panel_chrome.clone();
             if self.inline_dividers {
                 bottom_panel_chrome.bordered = false;
+            }
+            if self.inline_dividers && !collapsed {
                 center = center.child(
                     self.id
                         .ctx()
                         .child("divider-content-bottom", Divider::horizontal()),
                 );
             }
-            let bottom_region = self
-                .wrap_region(
-                    window,
-                    self.id.slot("bottom-panel"),
-                    bottom_panel(),
-                    &bottom_panel_chrome,
-                    bottom_panel_bg,
-                )
-                .h(px(bottom_panel_height_px))
-                .w_full()
-                .flex_none();
-            center = center.child(bottom_region);
+            if self.resizable && !collapsed {
+                center = center.child(self.render_resize_handle(panel, bottom_panel_height_px));
+            }
+            let bottom_region = self.wrap_region(
+                window,
+                self.id.slot("bottom-panel"),
+                bottom_panel(),
+                &bottom_panel_chrome,
+                bottom_panel_bg,
+            );
+            let height = bottom_panel_height_px;
+            center = center.child(self.dock_host(panel, bottom_region, height, collapsed));
         }
 
         row = row.child(center);
@@ -836,27 +1212,31 @@ impl RenderOnce for AppShell {
         if self.inspector_mode == PanelMode::Inline
             && let Some(inspector) = self.inspector.take()
         {
+            let collapsed = layout.inspector_collapsed;
+            let panel = AppShellPanel::Inspector;
             let mut inspector_chrome = self.inspector_chrome.clone();
             if self.inline_dividers {
                 inspector_chrome.bordered = false;
+            }
+            if self.resizable && !collapsed {
+                row = row.child(self.render_resize_handle(panel, inspector_width_px));
+            }
+            if self.inline_dividers && !collapsed {
                 row = row.child(
                     self.id
                         .ctx()
                         .child("divider-center-inspector", Divider::vertical()),
                 );
             }
-            let inspector_region = self
-                .wrap_region(
-                    window,
-                    self.id.slot("inspector-inline"),
-                    inspector(),
-                    &inspector_chrome,
-                    inspector_bg,
-                )
-                .w(px(inspector_width_px))
-                .h_full()
-                .flex_none();
-            row = row.child(inspector_region);
+            let inspector_region = self.wrap_region(
+                window,
+                self.id.slot("inspector-inline"),
+                inspector(),
+                &inspector_chrome,
+                inspector_bg,
+            );
+            let width = inspector_width_px;
+            row = row.child(self.dock_host(panel, inspector_region, width, collapsed));
         }
 
         body_host = body_host.child(row);
@@ -964,8 +1344,17 @@ impl RenderOnce for AppShell {
             body_host = body_host.child(inspector_region);
         }
 
+        if let Some(announcement_overlay) = announcement_overlay {
+            body_host = body_host.child(announcement_overlay);
+        }
+        // 公告栏在上方时，沉浸标题栏改为相对主体定位，避免盖住公告栏。
+        let mut root_title_bar_overlay = title_bar_overlay;
+        if has_announcement_above && let Some(title_bar_overlay) = root_title_bar_overlay.take() {
+            body_host = body_host.child(title_bar_overlay);
+        }
+
         root = root.child(body_host);
-        if let Some(title_bar_overlay) = title_bar_overlay {
+        if let Some(title_bar_overlay) = root_title_bar_overlay {
             root = root.child(title_bar_overlay);
         }
         root.style().refine(&self.style);
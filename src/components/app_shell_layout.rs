@@ -0,0 +1,241 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use gpui::actions;
+
+use super::announcement_bar::AnnouncementBarPosition;
+use super::control;
+
+pub const APP_SHELL_KEY_CONTEXT: &str = "calmui_app_shell";
+
+/// Upper bound for a dragged panel, as a share of the window dimension.
+const MAX_PANEL_FRACTION: f32 = 0.6;
+
+actions!(
+    calmui_app_shell,
+    [ToggleSidebar, ToggleInspector, ToggleBottomPanel]
+);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AppShellPanel {
+    Sidebar,
+    Inspector,
+    BottomPanel,
+}
+
+impl AppShellPanel {
+    pub const ALL: [AppShellPanel; 3] = [
+        AppShellPanel::Sidebar,
+        AppShellPanel::Inspector,
+        AppShellPanel::BottomPanel,
+    ];
+
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            AppShellPanel::Sidebar => "sidebar",
+            AppShellPanel::Inspector => "inspector",
+            AppShellPanel::BottomPanel => "bottom-panel",
+        }
+    }
+
+    pub(crate) fn is_vertical_split(self) -> bool {
+        !matches!(self, AppShellPanel::BottomPanel)
+    }
+
+    /// `1.0` when the panel grows as the pointer moves towards larger
+    /// coordinates, `-1.0` for panels docked on the right or bottom edge.
+    pub(crate) fn resize_direction(self) -> f32 {
+        match self {
+            AppShellPanel::Sidebar => 1.0,
+            AppShellPanel::Inspector | AppShellPanel::BottomPanel => -1.0,
+        }
+    }
+}
+
+/// Serializable snapshot of the dockable panels of an `AppShell`.
+///
+/// `None` sizes fall back to the shell's builder values and then to the
+/// theme tokens, so a snapshot saved before a theme change stays valid.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AppShellLayout {
+    pub sidebar_width: Option<f32>,
+    pub inspector_width: Option<f32>,
+    pub bottom_panel_height: Option<f32>,
+    pub sidebar_collapsed: bool,
+    pub inspector_collapsed: bool,
+    pub bottom_panel_collapsed: bool,
+}
+
+impl AppShellLayout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the layout an uncontrolled shell is currently showing.
+    pub fn current(shell_id: &str) -> Self {
+        layout(shell_id, None, Self::default())
+    }
+
+    /// Restores a saved layout into an uncontrolled shell.
+    pub fn restore(self, shell_id: &str) {
+        set_layout(shell_id, self);
+    }
+
+    pub fn size(&self, panel: AppShellPanel) -> Option<f32> {
+        match panel {
+            AppShellPanel::Sidebar => self.sidebar_width,
+            AppShellPanel::Inspector => self.inspector_width,
+            AppShellPanel::BottomPanel => self.bottom_panel_height,
+        }
+    }
+
+    pub fn with_size(mut self, panel: AppShellPanel, value: f32) -> Self {
+        let value = Some(value.max(0.0));
+        match panel {
+            AppShellPanel::Sidebar => self.sidebar_width = value,
+            AppShellPanel::Inspector => self.inspector_width = value,
+            AppShellPanel::BottomPanel => self.bottom_panel_height = value,
+        }
+        self
+    }
+
+    pub fn is_collapsed(&self, panel: AppShellPanel) -> bool {
+        match panel {
+            AppShellPanel::Sidebar => self.sidebar_collapsed,
+            AppShellPanel::Inspector => self.inspector_collapsed,
+            AppShellPanel::BottomPanel => self.bottom_panel_collapsed,
+        }
+    }
+
+    pub fn with_collapsed(mut self, panel: AppShellPanel, value: bool) -> Self {
+        match panel {
+            AppShellPanel::Sidebar => self.sidebar_collapsed = value,
+            AppShellPanel::Inspector => self.inspector_collapsed = value,
+            AppShellPanel::BottomPanel => self.bottom_panel_collapsed = value,
+        }
+        self
+    }
+
+    pub fn toggled(self, panel: AppShellPanel) -> Self {
+        let collapsed = self.is_collapsed(panel);
+        self.with_collapsed(panel, !collapsed)
+    }
+}
+
+fn panel_id(id: &str, panel: AppShellPanel) -> String {
+    format!("{id}::{}", panel.key())
+}
+
+pub fn layout(
+    id: &str,
+    controlled: Option<AppShellLayout>,
+    default: AppShellLayout,
+) -> AppShellLayout {
+    if let Some(layout) = controlled {
+        return layout;
+    }
+    AppShellPanel::ALL
+        .into_iter()
+        .fold(AppShellLayout::default(), |layout, panel| {
+            let state_id = panel_id(id, panel);
+            let size = control::optional_f32_state(&state_id, "size", None, default.size(panel));
+            let collapsed =
+                control::bool_state(&state_id, "collapsed", None, default.is_collapsed(panel));
+            let layout = layout.with_collapsed(panel, collapsed);
+            match size {
+                Some(size) => layout.with_size(panel, size),
+                None => layout,
+            }
+        })
+}
+
+pub fn set_layout(id: &str, layout: AppShellLayout) {
+    for panel in AppShellPanel::ALL {
+        let state_id = panel_id(id, panel);
+        control::set_optional_f32_state(&state_id, "size", layout.size(panel));
+        control::set_bool_state(&state_id, "collapsed", layout.is_collapsed(panel));
+    }
+}
+
+/// `(min, max)` for a dragged panel given the window dimension along its axis.
+pub fn size_bounds(min: f32, available: f32) -> (f32, f32) {
+    let max = (available.max(0.0) * MAX_PANEL_FRACTION).max(min);
+    (min, max)
+}
+
+/// Where the shell mounts its announcement bar.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnnouncementSlot {
+    /// First row of the shell; an immersive title bar moves into the body so
+    /// it does not cover the bar.
+    Top,
+    /// In the column right after the title bar.
+    BelowTitleBar,
+    /// Floated over the body at the lower edge of an immersive title bar.
+    OverlayBelowTitleBar,
+}
+
+pub fn announcement_slot(
+    position: AnnouncementBarPosition,
+    title_bar_overlaid: bool,
+) -> AnnouncementSlot {
+    match position {
+        AnnouncementBarPosition::AboveTitleBar => AnnouncementSlot::Top,
+        AnnouncementBarPosition::BelowTitleBar if title_bar_overlaid => {
+            AnnouncementSlot::OverlayBelowTitleBar
+        }
+        AnnouncementBarPosition::BelowTitleBar => AnnouncementSlot::BelowTitleBar,
+    }
+}
+
+/// Bumps a per-panel generation whenever the collapsed flag differs from the
+/// last rendered one, so the collapse animation replays once per change.
+pub fn collapse_generation(id: &str, panel: AppShellPanel, collapsed: bool) -> usize {
+    let state_id = panel_id(id, panel);
+    let generation = control::usize_state(&state_id, "collapse-generation", None, 0);
+    let last = control::bool_state(&state_id, "last-collapsed", None, collapsed);
+    control::set_bool_state(&state_id, "last-collapsed", collapsed);
+    if last == collapsed {
+        return generation;
+    }
+    control::set_usize_state(&state_id, "collapse-generation", generation + 1);
+    generation + 1
+}
+
+pub fn begin_resize(id: &str, panel: AppShellPanel, pointer: f32, size: f32) {
+    let state_id = panel_id(id, panel);
+    control::set_f32_state(&state_id, "resize-origin", pointer);
+    control::set_f32_state(&state_id, "resize-origin-size", size);
+    control::set_optional_f32_state(&state_id, "resize-size", Some(size));
+}
+
+pub fn resize_size(id: &str, panel: AppShellPanel) -> Option<f32> {
+    control::optional_f32_state(&panel_id(id, panel), "resize-size", None, None)
+}
+
+/// The panel currently being dragged, if any.
+pub fn resizing_panel(id: &str) -> Option<AppShellPanel> {
+    AppShellPanel::ALL
+        .into_iter()
+        .find(|panel| resize_size(id, *panel).is_some())
+}
+
+pub fn resize_to(id: &str, panel: AppShellPanel, pointer: f32, min: f32, max: f32) -> Option<f32> {
+    resize_size(id, panel)?;
+    let state_id = panel_id(id, panel);
+    let origin = control::f32_state(&state_id, "resize-origin", None, pointer);
+    let origin_size = control::f32_state(&state_id, "resize-origin-size", None, 0.0);
+    let delta = (pointer - origin) * panel.resize_direction();
+    let size = (origin_size + delta).clamp(min, max.max(min));
+    control::set_optional_f32_state(&state_id, "resize-size", Some(size));
+    Some(size)
+}
+
+pub fn end_resize(id: &str, panel: AppShellPanel) -> Option<f32> {
+    let size = resize_size(id, panel)?;
+    control::set_optional_f32_state(&panel_id(id, panel), "resize-size", None);
+    Some(size)
+}
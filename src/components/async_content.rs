@@ -0,0 +1,275 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    Animation, AnimationExt, AnyElement, InteractiveElement, IntoElement, ParentElement,
+    RenderOnce, SharedString, Styled, Window, div, relative,
+};
+
+use crate::icon::IconSource;
+use crate::id::ComponentId;
+use crate::motion::MotionLevel;
+use crate::provider::CalmProvider;
+use crate::resource::{AsyncState, Resource};
+
+use super::Stack;
+use super::alert::{Alert, AlertKind};
+use super::button::Button;
+use super::icon::Icon;
+
+type ContentRenderer<T> = Box<dyn FnOnce(T) -> AnyElement>;
+type EmptyPredicate<T> = Box<dyn Fn(&T) -> bool>;
+type RetryHandler = Rc<dyn Fn(&mut Window, &mut gpui::App)>;
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+
+#[derive(IntoElement)]
+pub struct AsyncContent<T: 'static> {
+    pub(crate) id: ComponentId,
+    state: AsyncState<T>,
+    skeleton_rows: usize,
+    error_title: SharedString,
+    retry_label: SharedString,
+    empty_title: SharedString,
+    empty_description: Option<SharedString>,
+    empty_icon: IconSource,
+    content: Option<ContentRenderer<T>>,
+    empty_when: Option<EmptyPredicate<T>>,
+    on_retry: Option<RetryHandler>,
+    loading_slot: Option<SlotRenderer>,
+    empty_slot: Option<SlotRenderer>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl<T: 'static> AsyncContent<T> {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            state: AsyncState::Loading,
+            skeleton_rows: 3,
+            error_title: "Something went wrong".into(),
+            retry_label: "Retry".into(),
+            empty_title: "Nothing here yet".into(),
+            empty_description: None,
+            empty_icon: IconSource::named("inbox"),
+            content: None,
+            empty_when: None,
+            on_retry: None,
+            loading_slot: None,
+            empty_slot: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    pub fn state(mut self, value: AsyncState<T>) -> Self {
+        self.state = value;
+        self
+    }
+
+    pub fn resource(mut self, resource: &Resource<T>) -> Self
+    where
+        T: Clone,
+    {
+        self.state = resource.state();
+        self
+    }
+
+    pub fn content(mut self, renderer: impl FnOnce(T) -> AnyElement + 'static) -> Self {
+        self.content = Some(Box::new(renderer));
+        self
+    }
+
+    pub fn empty_when(mut self, predicate: impl Fn(&T) -> bool + 'static) -> Self {
+        self.empty_when = Some(Box::new(predicate));
+        self
+    }
+
+    pub fn skeleton_rows(mut self, value: usize) -> Self {
+        self.skeleton_rows = value.max(1);
+        self
+    }
+
+    pub fn error_title(mut self, value: impl Into<SharedString>) -> Self {
+        self.error_title = value.into();
+        self
+    }
+
+    pub fn retry_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.retry_label = value.into();
+        self
+    }
+
+    pub fn on_retry(mut self, handler: impl Fn(&mut Window, &mut gpui::App) + 'static) -> Self {
+        self.on_retry = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn empty_title(mut self, value: impl Into<SharedString>) -> Self {
+        self.empty_title = value.into();
+        self
+    }
+
+    pub fn empty_description(mut self, value: impl Into<SharedString>) -> Self {
+        self.empty_description = Some(value.into());
+        self
+    }
+
+    pub fn empty_icon(mut self, value: impl Into<String>) -> Self {
+        self.empty_icon = IconSource::named(value);
+        self
+    }
+
+    pub fn empty_icon_source(mut self, source: IconSource) -> Self {
+        self.empty_icon = source;
+        self
+    }
+
+    pub fn loading_slot(mut self, content: impl IntoElement + 'static) -> Self {
+        self.loading_slot = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    pub fn empty_slot(mut self, content: impl IntoElement + 'static) -> Self {
+        self.empty_slot = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    fn render_skeleton(&self, animated: bool) -> AnyElement {
+        let tokens = &self.theme.components.async_content;
+        let rows = (0..self.skeleton_rows).map(|index| {
+            let width = if index + 1 == self.skeleton_rows && index > 0 {
+                relative(0.6)
+            } else {
+                relative(1.0)
+            };
+            div()
+                .id(self.id.slot_index("skeleton", index.to_string()))
+                .w(width)
+                .h(tokens.skeleton_line_height)
+                .rounded(tokens.skeleton_radius)
+                .bg(tokens.skeleton_bg)
+        });
+        let skeleton = Stack::vertical()
+            .id(self.id.slot("skeleton"))
+            .w_full()
+            .gap(tokens.skeleton_gap)
+            .children(rows);
+        if !animated {
+            return skeleton.into_any_element();
+        }
+        let pulse = Animation::new(Duration::from_millis(1200))
+            .repeat()
+            .with_easing(gpui::pulsating_between(0.45, 1.0));
+        skeleton
+            .with_animation(self.id.slot("pulse"), pulse, |this, delta| {
+                this.opacity(delta)
+            })
+            .into_any_element()
+    }
+
+    fn render_error(&self, message: SharedString) -> AnyElement {
+        let mut alert = self
+            .id
+            .ctx()
+            .child("error", Alert::new())
+            .kind(AlertKind::Error)
+            .title(self.error_title.clone())
+            .message(message)
+            .closable(false);
+        if let Some(handler) = self.on_retry.clone() {
+            let retry = self
+                .id
+                .ctx()
+                .child("retry", Button::new())
+                .label(self.retry_label.clone())
+                .on_click(move |_, window, cx| (handler)(window, cx));
+            alert = alert.right_slot(retry);
+        }
+        alert.into_any_element()
+    }
+
+    fn render_empty(&self) -> AnyElement {
+        let tokens = &self.theme.components.async_content;
+        let mut empty = Stack::vertical()
+            .id(self.id.slot("empty"))
+            .w_full()
+            .items_center()
+            .p(tokens.empty_padding)
+            .gap(tokens.empty_gap)
+            .child(
+                self.id
+                    .ctx()
+                    .child("empty-icon", Icon::new(self.empty_icon.clone()))
+                    .size(f32::from(tokens.empty_icon_size))
+                    .color(tokens.empty_icon),
+            )
+            .child(
+                div()
+                    .text_size(tokens.empty_title_size)
+                    .text_color(tokens.empty_title)
+                    .child(self.empty_title.clone()),
+            );
+        if let Some(description) = self.empty_description.clone() {
+            empty = empty.child(
+                div()
+                    .text_size(tokens.empty_description_size)
+                    .text_color(tokens.empty_description)
+                    .child(description),
+            );
+        }
+        empty.into_any_element()
+    }
+}
+
+impl<T: 'static> Default for AsyncContent<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> crate::contracts::WithId for AsyncContent<T> {
+    fn with_id(mut self, id: impl Into<ComponentId>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl<T: 'static> crate::contracts::ComponentThemeOverridable for AsyncContent<T> {
+    fn local_theme_mut(&mut self) -> &mut crate::theme::LocalTheme {
+        &mut self.theme
+    }
+}
+
+impl<T: 'static> crate::contracts::Themable for AsyncContent<T> {
+    type ThemeOverrides = crate::theme::AsyncContentOverrides;
+
+    fn component_overrides_mut(
+        overrides: &mut crate::theme::ComponentOverrides,
+    ) -> &mut Self::ThemeOverrides {
+        &mut overrides.async_content
+    }
+}
+
+impl<T: 'static> RenderOnce for AsyncContent<T> {
+    fn render(mut self, _window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let state = std::mem::take(&mut self.state);
+        let state = match (state, self.empty_when.as_ref()) {
+            (AsyncState::Ready(value), Some(is_empty)) if is_empty(&value) => AsyncState::Empty,
+            (state, _) => state,
+        };
+        let body = match state {
+            AsyncState::Loading => Some(match self.loading_slot.take() {
+                Some(slot) => slot(),
+                None => self.render_skeleton(CalmProvider::motion_level(_cx) == MotionLevel::Full),
+            }),
+            AsyncState::Error(message) => Some(self.render_error(message)),
+            AsyncState::Empty => Some(match self.empty_slot.take() {
+                Some(slot) => slot(),
+                None => self.render_empty(),
+            }),
+            AsyncState::Ready(value) => self.content.take().map(|content| content(value)),
+        };
+        div().id(self.id.clone()).w_full().children(body)
+    }
+}
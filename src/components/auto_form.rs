@@ -0,0 +1,213 @@
+use std::rc::Rc;
+
+use gpui::{AnyElement, IntoElement, ParentElement, RenderOnce, Window};
+
+use crate::form::{
+    AutoField, AutoFieldProps, FieldKey, FieldLens, FieldRuleViolation, FormController, FormModel,
+    ValidationError, humanized_label,
+};
+use crate::id::ComponentId;
+use crate::style::{FieldLayout, Size};
+
+use super::layout::{Grid, Stack};
+
+type FieldOverride<M, E> = Rc<dyn Fn(&FormController<M, E>, AutoFieldProps) -> AnyElement>;
+
+/// Renders every field `#[derive(FormModel)]` exposes for `M`, bound to a
+/// controller. Labels, placeholders and descriptions come from the derived
+/// metadata; fields without a label get one from their name.
+#[derive(IntoElement)]
+pub struct AutoForm<M, E = FieldRuleViolation>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    pub(crate) id: ComponentId,
+    controller: FormController<M, E>,
+    columns: usize,
+    field_layout: FieldLayout,
+    spacing: Size,
+    fields: Vec<AutoField<M, E>>,
+    hidden: Vec<FieldKey>,
+    overrides: Vec<(FieldKey, FieldOverride<M, E>)>,
+    footer: Option<AnyElement>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl<M, E> AutoForm<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    #[track_caller]
+    pub fn new(controller: FormController<M, E>) -> Self {
+        Self {
+            id: ComponentId::default(),
+            controller,
+            columns: 1,
+            field_layout: FieldLayout::Vertical,
+            spacing: Size::Md,
+            fields: M::auto_fields(),
+            hidden: Vec::new(),
+            overrides: Vec::new(),
+            footer: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    /// Lays fields out in a grid, filling rows left to right.
+    pub fn columns(mut self, value: usize) -> Self {
+        self.columns = value.max(1);
+        self
+    }
+
+    pub fn field_layout(mut self, value: FieldLayout) -> Self {
+        self.field_layout = value;
+        self
+    }
+
+    pub fn spacing(mut self, value: Size) -> Self {
+        self.spacing = value;
+        self
+    }
+
+    /// Appends a field the derive cannot infer, such as a lens over a
+    /// custom type. A field with the same key replaces the derived one.
+    pub fn field(mut self, field: AutoField<M, E>) -> Self {
+        match self
+            .fields
+            .iter_mut()
+            .find(|existing| existing.key() == field.key())
+        {
+            Some(existing) => *existing = field,
+            None => self.fields.push(field),
+        }
+        self
+    }
+
+    pub fn hide<L>(mut self, lens: L) -> Self
+    where
+        L: FieldLens<M>,
+    {
+        self.hidden.push(lens.key());
+        self
+    }
+
+    /// Renders `render` in place of the generated input for `lens`. The
+    /// props carry the resolved id, label and metadata.
+    pub fn override_field<L>(
+        mut self,
+        lens: L,
+        render: impl Fn(&FormController<M, E>, AutoFieldProps) -> AnyElement + 'static,
+    ) -> Self
+    where
+        L: FieldLens<M>,
+    {
+        let key = lens.key();
+        self.overrides.retain(|(existing, _)| *existing != key);
+        self.overrides.push((key, Rc::new(render)));
+        self
+    }
+
+    pub fn footer(mut self, content: impl IntoElement + 'static) -> Self {
+        self.footer = Some(content.into_any_element());
+        self
+    }
+
+    fn props(&self, key: FieldKey) -> AutoFieldProps {
+        let descriptor = M::field_meta(key).unwrap_or_default();
+        AutoFieldProps {
+            id: self.id.scoped_index("field", key.as_str()),
+            label: descriptor
+                .label
+                .map(Into::into)
+                .unwrap_or_else(|| humanized_label(key)),
+            placeholder: descriptor.placeholder.map(Into::into),
+            description: descriptor.description.map(Into::into),
+            layout: self.field_layout,
+        }
+    }
+
+    fn render_fields(&self) -> Vec<AnyElement> {
+        self.fields
+            .iter()
+            .filter(|field| !self.hidden.contains(&field.key()))
+            .filter_map(|field| {
+                let props = self.props(field.key());
+                match self.overrides.iter().find(|(key, _)| *key == field.key()) {
+                    Some((_, render)) => Some(render(&self.controller, props)),
+                    None => field.render(&self.controller, props).ok(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl<M, E> crate::contracts::WithId for AutoForm<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    fn with_id(mut self, id: impl Into<ComponentId>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl<M, E> crate::contracts::ComponentThemeOverridable for AutoForm<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    fn local_theme_mut(&mut self) -> &mut crate::theme::LocalTheme {
+        &mut self.theme
+    }
+}
+
+impl<M, E> crate::contracts::Themable for AutoForm<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    type ThemeOverrides = crate::theme::LayoutOverrides;
+
+    fn component_overrides_mut(
+        overrides: &mut crate::theme::ComponentOverrides,
+    ) -> &mut Self::ThemeOverrides {
+        &mut overrides.layout
+    }
+}
+
+impl<M, E> RenderOnce for AutoForm<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    fn render(mut self, _window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let tokens = &self.theme.components.layout;
+        let gap = tokens.gap.for_size(self.spacing);
+        let fields = self.render_fields();
+        let body = if self.columns > 1 {
+            self.id
+                .ctx()
+                .child("grid", Grid::new().columns(self.columns).gap(self.spacing))
+                .children(fields)
+                .into_any_element()
+        } else {
+            Stack::vertical()
+                .w_full()
+                .gap(gap)
+                .children(fields)
+                .into_any_element()
+        };
+
+        self.id.ctx().root(
+            Stack::vertical()
+                .w_full()
+                .gap(gap)
+                .child(body)
+                .children(self.footer.take()),
+        )
+    }
+}
@@ -0,0 +1,213 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{
+    AnyElement, Bounds, IntoElement, ParentElement, RenderOnce, SharedString, Styled, div, fill,
+    point, px, size,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+
+use super::chart::{self, ChartDatum, ValueFormatter};
+use super::chart_state;
+use super::utils::resolve_hsla;
+
+/// Vertical bars, one per [`ChartDatum`], over a value axis that always
+/// includes zero.
+#[derive(IntoElement)]
+pub struct BarChart {
+    pub(crate) id: ComponentId,
+    data: Vec<ChartDatum>,
+    height_px: Option<f32>,
+    show_grid: bool,
+    show_axis: bool,
+    show_tooltip: bool,
+    empty_label: SharedString,
+    value_format: Option<ValueFormatter>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl BarChart {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            data: Vec::new(),
+            height_px: None,
+            show_grid: true,
+            show_axis: true,
+            show_tooltip: true,
+            empty_label: "No data".into(),
+            value_format: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    pub fn data(mut self, data: impl IntoIterator<Item = ChartDatum>) -> Self {
+        self.data = data.into_iter().collect();
+        self
+    }
+
+    /// Height of the plot area, not counting the category labels.
+    pub fn height(mut self, value: f32) -> Self {
+        self.height_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn show_grid(mut self, value: bool) -> Self {
+        self.show_grid = value;
+        self
+    }
+
+    /// Shows the value ticks on the left and the category labels below.
+    pub fn show_axis(mut self, value: bool) -> Self {
+        self.show_axis = value;
+        self
+    }
+
+    pub fn show_tooltip(mut self, value: bool) -> Self {
+        self.show_tooltip = value;
+        self
+    }
+
+    pub fn empty_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.empty_label = value.into();
+        self
+    }
+
+    pub fn value_format(mut self, formatter: impl Fn(f32) -> SharedString + 'static) -> Self {
+        self.value_format = Some(Rc::new(formatter));
+        self
+    }
+
+    fn render_labels(&self) -> AnyElement {
+        let tokens = &self.theme.components.chart;
+        let labels = self.data.iter().map(|datum| {
+            div()
+                .flex_1()
+                .min_w_0()
+                .overflow_hidden()
+                .text_center()
+                .child(datum.label.clone())
+        });
+        div()
+            .flex()
+            .pl(tokens.axis_width + tokens.axis_gap)
+            .text_size(tokens.axis_size)
+            .text_color(resolve_hsla(&self.theme, tokens.axis_fg))
+            .children(labels)
+            .into_any_element()
+    }
+}
+
+impl MotionAware for BarChart {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for BarChart {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.chart.clone();
+        let height = self.height_px.unwrap_or(f32::from(tokens.height));
+        if self.data.is_empty() {
+            return div()
+                .id(self.id.clone())
+                .flex()
+                .items_center()
+                .justify_center()
+                .w_full()
+                .h(px(height))
+                .text_size(tokens.empty_size)
+                .text_color(resolve_hsla(&self.theme, tokens.empty_fg))
+                .child(self.empty_label);
+        }
+
+        let count = self.data.len();
+        let (min, max) = chart_state::value_bounds(self.data.iter().map(|d| d.value), true);
+        let ticks = chart_state::nice_ticks(min, max, 4);
+        let range = (ticks[0], ticks[ticks.len() - 1]);
+        let colors = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, datum)| {
+                datum
+                    .color
+                    .unwrap_or_else(|| chart::series_color(&self.theme, index))
+            })
+            .collect::<Vec<_>>();
+        let hovered = chart_state::hovered(&self.id).filter(|index| *index < count);
+        let grid = resolve_hsla(&self.theme, tokens.grid);
+        let (gap, radius, show_grid) =
+            (f32::from(tokens.bar_gap), tokens.bar_radius, self.show_grid);
+        let paint_ticks = ticks.clone();
+        let paint_colors = colors.clone();
+
+        let plot = chart::plot_layer(
+            &self.id,
+            self.data.iter().map(|datum| datum.value).collect(),
+            chart::data_motion(self.motion, cx),
+            move |bounds, values, window| {
+                if show_grid {
+                    chart::paint_grid(window, bounds, &paint_ticks, grid);
+                }
+                let (width, height) = (f32::from(bounds.size.width), f32::from(bounds.size.height));
+                for (index, value) in values.iter().enumerate() {
+                    let (left, bar_width) = chart_state::bar_band(index, count, width, gap);
+                    let top = chart_state::scale_y(value.max(0.0), range, height);
+                    let bottom = chart_state::scale_y(value.min(0.0), range, height);
+                    let color = match hovered {
+                        Some(hovered) if hovered != index => paint_colors[index].opacity(0.55),
+                        _ => paint_colors[index],
+                    };
+                    let bar = Bounds::new(
+                        point(bounds.origin.x + px(left), bounds.origin.y + px(top)),
+                        size(px(bar_width), px((bottom - top).max(1.0))),
+                    );
+                    window.paint_quad(fill(bar, color).corner_radii(radius));
+                }
+            },
+        );
+
+        let tooltip = hovered.filter(|_| self.show_tooltip).map(|index| {
+            let (_, (width, height)) = chart_state::plot(&self.id);
+            let datum = &self.data[index];
+            let (left, bar_width) = chart_state::bar_band(index, count, width, gap);
+            let top = chart_state::scale_y(datum.value.max(0.0), range, height);
+            let value = chart::format_with(self.value_format.as_ref(), datum.value);
+            let rows = vec![(colors[index], datum.label.clone(), value)];
+            let bubble = chart::tooltip_bubble(&self.theme, window, None, rows);
+            chart::tooltip_at(&self.id, &self.theme, (left + bar_width / 2.0, top), bubble)
+        });
+        let plot = div()
+            .id(self.id.slot("plot"))
+            .relative()
+            .flex_1()
+            .h(px(height))
+            .child(plot)
+            .children(tooltip);
+        let plot = chart::track_hover(plot, &self.id, move |(x, _), (width, _)| {
+            chart_state::bar_at(x, count, width)
+        });
+
+        let axis = self
+            .show_axis
+            .then(|| chart::value_axis(&self.theme, &ticks, height, self.value_format.as_ref()));
+        let labels = self.show_axis.then(|| self.render_labels());
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .gap(tokens.axis_gap)
+            .w_full()
+            .child(div().flex().gap(tokens.axis_gap).children(axis).child(plot))
+            .children(labels)
+    }
+}
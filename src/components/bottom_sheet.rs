@@ -0,0 +1,326 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{
+    AnyElement, ClickEvent, IntoElement, MouseButton, ParentElement, RenderOnce, SharedString,
+    Styled, Window, div, px,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
+use crate::motion::MotionConfig;
+
+use super::bottom_sheet_state;
+use super::control;
+use super::overlay::{Overlay, OverlayCoverage, OverlayMaterialMode};
+use super::popup_state::{self, PopupStateInput, PopupStateValue};
+use super::utils::resolve_hsla;
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+type CloseHandler = Rc<dyn Fn(&mut Window, &mut gpui::App)>;
+type SnapHandler = Rc<dyn Fn(usize, &mut Window, &mut gpui::App)>;
+
+#[derive(IntoElement)]
+pub struct BottomSheet {
+    pub(crate) id: ComponentId,
+    opened: Option<bool>,
+    default_opened: bool,
+    title: Option<SharedString>,
+    snap_points: Vec<f32>,
+    default_snap: usize,
+    keyboard_inset: f32,
+    drag_to_dismiss: bool,
+    close_on_click_outside: bool,
+    close_on_escape: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    content: Option<SlotRenderer>,
+    on_close: Option<CloseHandler>,
+    on_snap_change: Option<SnapHandler>,
+}
+
+impl BottomSheet {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            opened: None,
+            default_opened: false,
+            title: None,
+            snap_points: vec![0.5, 0.9],
+            default_snap: 0,
+            keyboard_inset: 0.0,
+            drag_to_dismiss: true,
+            close_on_click_outside: true,
+            close_on_escape: true,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            content: None,
+            on_close: None,
+            on_snap_change: None,
+        }
+    }
+
+    pub fn titled(title: impl Into<SharedString>) -> Self {
+        Self::new().title(title)
+    }
+
+    pub fn title(mut self, value: impl Into<SharedString>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    pub fn default_opened(mut self, value: bool) -> Self {
+        self.default_opened = value;
+        self
+    }
+
+    pub fn snap_points(mut self, value: impl IntoIterator<Item = f32>) -> Self {
+        let points: Vec<f32> = value.into_iter().collect();
+        if !points.is_empty() {
+            self.snap_points = points;
+        }
+        self
+    }
+
+    pub fn default_snap(mut self, index: usize) -> Self {
+        self.default_snap = index;
+        self
+    }
+
+    pub fn keyboard_inset(mut self, value: f32) -> Self {
+        self.keyboard_inset = if value.is_finite() {
+            value.max(0.0)
+        } else {
+            0.0
+        };
+        self
+    }
+
+    pub fn drag_to_dismiss(mut self, value: bool) -> Self {
+        self.drag_to_dismiss = value;
+        self
+    }
+
+    pub fn close_on_click_outside(mut self, value: bool) -> Self {
+        self.close_on_click_outside = value;
+        self
+    }
+
+    pub fn close_on_escape(mut self, value: bool) -> Self {
+        self.close_on_escape = value;
+        self
+    }
+
+    pub fn content(mut self, content: impl IntoElement + 'static) -> Self {
+        self.content = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut gpui::App) + 'static) -> Self {
+        self.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_snap_change(
+        mut self,
+        handler: impl Fn(usize, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_snap_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_opened(&self) -> bool {
+        PopupStateValue::resolve(PopupStateInput {
+            id: &self.id,
+            opened: self.opened,
+            default_opened: self.default_opened,
+            disabled: false,
+        })
+        .opened
+    }
+
+    fn dismiss(id: &ComponentId, window: &mut Window, cx: &mut gpui::App) {
+        if crate::provider::CalmProvider::focus(cx).release(id, window, cx) {
+            let event = InteractionEvent::new("bottom_sheet", id, InteractionAction::Close);
+            interaction::emit(event, cx);
+        }
+    }
+
+    fn request_close(
+        id: &ComponentId,
+        controlled: bool,
+        on_close: Option<&CloseHandler>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        if popup_state::on_close_request(id, controlled) {
+            window.refresh();
+        }
+        Self::dismiss(id, window, cx);
+        if let Some(handler) = on_close {
+            (handler)(window, cx);
+        }
+    }
+
+    fn render_header(&self, height: f32) -> AnyElement {
+        let tokens = &self.theme.components.drawer;
+        let handle_bg = resolve_hsla(&self.theme, self.theme.semantic.border_subtle);
+        let handle_id = self.id.to_string();
+        let grip = div().w(px(36.0)).h(px(4.0));
+        let grip = grip.rounded_full().bg(handle_bg);
+        let handle = div()
+            .id(self.id.slot("handle"))
+            .w_full()
+            .flex()
+            .justify_center()
+            .py(px(6.0))
+            .cursor_grab()
+            .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                bottom_sheet_state::begin_drag(&handle_id, f32::from(event.position.y), height);
+                cx.stop_propagation();
+                window.refresh();
+            })
+            .child(grip);
+        let title = self.title.clone().map(|title| {
+            div()
+                .mb(tokens.header_margin_bottom)
+                .text_size(tokens.title_size)
+                .font_weight(tokens.title_weight)
+                .text_color(resolve_hsla(&self.theme, tokens.title))
+                .child(title)
+        });
+        div().child(handle).children(title).into_any_element()
+    }
+}
+
+impl MotionAware for BottomSheet {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for BottomSheet {
+    fn render(mut self, window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let opened = self.resolved_opened();
+        let focus = crate::provider::CalmProvider::focus(_cx);
+        if !opened {
+            bottom_sheet_state::reset(&self.id);
+            Self::dismiss(&self.id, window, _cx);
+            return div().id(self.id);
+        }
+        if !focus.is_trapped(&self.id) {
+            let event = InteractionEvent::new("bottom_sheet", &self.id, InteractionAction::Open);
+            interaction::emit(event, _cx);
+        }
+        let focus_root = focus.trap(&self.id, window, _cx);
+
+        let is_controlled = self.opened.is_some();
+        let available = (f32::from(window.viewport_size().height) - self.keyboard_inset).max(0.0);
+        let heights = bottom_sheet_state::snap_heights(&self.snap_points, available);
+        let max_snap = heights.len().saturating_sub(1);
+        let snap = bottom_sheet_state::snap_index(&self.id, self.default_snap).min(max_snap);
+        let resting = heights.get(snap).copied().unwrap_or(available);
+        let height = bottom_sheet_state::drag_height(&self.id).unwrap_or(resting);
+        let tokens = &self.theme.components.drawer;
+
+        let close_on_click_outside = self.close_on_click_outside;
+        let outside_id = self.id.clone();
+        let outside_on_close = self.on_close.clone();
+        let overlay = self
+            .id
+            .ctx()
+            .child("overlay", Overlay::new())
+            .coverage(OverlayCoverage::Window)
+            .material_mode(OverlayMaterialMode::TintOnly)
+            .frosted(false)
+            .color(tokens.overlay_bg)
+            .opacity(1.0)
+            .readability_boost(0.84)
+            .on_click(
+                move |_: &ClickEvent, window: &mut Window, cx: &mut gpui::App| {
+                    if close_on_click_outside {
+                        let handler = outside_on_close.as_ref();
+                        Self::request_close(&outside_id, is_controlled, handler, window, cx);
+                    }
+                },
+            );
+
+        let body = self
+            .content
+            .take()
+            .map(|content| div().flex_1().min_h_0().overflow_hidden().child(content()));
+        let panel = div()
+            .id(self.id.slot("panel"))
+            .flex()
+            .flex_col()
+            .w_full()
+            .h(px(height))
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(resolve_hsla(&self.theme, tokens.panel_border))
+            .bg(resolve_hsla(&self.theme, tokens.panel_bg))
+            .rounded_t(tokens.panel_radius)
+            .px(tokens.panel_padding)
+            .pb(tokens.panel_padding)
+            .child(self.render_header(height))
+            .children(body)
+            .with_enter_transition(self.id.slot("panel-enter"), self.motion);
+        let host = div()
+            .absolute()
+            .left_0()
+            .bottom(px(self.keyboard_inset))
+            .w_full()
+            .child(panel);
+
+        let move_id = self.id.to_string();
+        let release_id = self.id.clone();
+        let drag_to_dismiss = self.drag_to_dismiss;
+        let release_on_close = self.on_close.clone();
+        let on_snap_change = self.on_snap_change.clone();
+        let close_on_escape = self.close_on_escape;
+        let escape_id = self.id.clone();
+        let escape_on_close = self.on_close.clone();
+        let root = div().id(self.id).absolute().top_0().left_0();
+        let root = root.size_full();
+        focus
+            .scope(&focus_root, root)
+            .on_mouse_move(move |event, window, _| {
+                let pointer_y = f32::from(event.position.y);
+                if bottom_sheet_state::drag_to(&move_id, pointer_y, available).is_some() {
+                    window.refresh();
+                }
+            })
+            .on_mouse_up(MouseButton::Left, move |_, window, cx| {
+                let Some(height) = bottom_sheet_state::end_drag(&release_id) else {
+                    return;
+                };
+                window.refresh();
+                let target = bottom_sheet_state::release_target(&heights, height, drag_to_dismiss);
+                let Some(index) = target else {
+                    let handler = release_on_close.as_ref();
+                    Self::request_close(&release_id, is_controlled, handler, window, cx);
+                    return;
+                };
+                bottom_sheet_state::set_snap_index(&release_id, index);
+                if index != snap
+                    && let Some(handler) = on_snap_change.as_ref()
+                {
+                    (handler)(index, window, cx);
+                }
+            })
+            .on_key_down(move |event, window, cx| {
+                if close_on_escape && control::is_escape_keystroke(event) {
+                    let handler = escape_on_close.as_ref();
+                    Self::request_close(&escape_id, is_controlled, handler, window, cx);
+                }
+            })
+            .child(overlay)
+            .child(host)
+    }
+}
+
+crate::impl_openable!(BottomSheet, |this, value| this.opened = Some(value));
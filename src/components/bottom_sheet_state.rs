@@ -0,0 +1,63 @@
+use super::control;
+
+pub fn snap_heights(points: &[f32], available: f32) -> Vec<f32> {
+    let available = available.max(0.0);
+    let mut heights: Vec<f32> = points
+        .iter()
+        .filter(|point| point.is_finite())
+        .map(|point| point.clamp(0.05, 1.0) * available)
+        .collect();
+    heights.sort_by(f32::total_cmp);
+    heights.dedup_by(|a, b| (*a - *b).abs() < 0.5);
+    heights
+}
+
+pub fn release_target(heights: &[f32], height: f32, dismissible: bool) -> Option<usize> {
+    let lowest = *heights.first()?;
+    if dismissible && height < lowest * 0.5 {
+        return None;
+    }
+    heights
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - height).abs().total_cmp(&(*b - height).abs()))
+        .map(|(index, _)| index)
+}
+
+pub fn snap_index(id: &str, default: usize) -> usize {
+    control::optional_usize_state(id, "snap-index", None, None).unwrap_or(default)
+}
+
+pub fn set_snap_index(id: &str, index: usize) {
+    control::set_optional_usize_state(id, "snap-index", Some(index));
+}
+
+pub fn reset(id: &str) {
+    control::set_optional_usize_state(id, "snap-index", None);
+    control::set_optional_f32_state(id, "drag-height", None);
+}
+
+pub fn begin_drag(id: &str, pointer_y: f32, height: f32) {
+    control::set_f32_state(id, "drag-origin-y", pointer_y);
+    control::set_f32_state(id, "drag-origin-height", height);
+    control::set_optional_f32_state(id, "drag-height", Some(height));
+}
+
+pub fn drag_height(id: &str) -> Option<f32> {
+    control::optional_f32_state(id, "drag-height", None, None)
+}
+
+pub fn drag_to(id: &str, pointer_y: f32, max_height: f32) -> Option<f32> {
+    drag_height(id)?;
+    let origin_y = control::f32_state(id, "drag-origin-y", None, pointer_y);
+    let origin_height = control::f32_state(id, "drag-origin-height", None, 0.0);
+    let height = (origin_height + origin_y - pointer_y).clamp(0.0, max_height.max(0.0));
+    control::set_optional_f32_state(id, "drag-height", Some(height));
+    Some(height)
+}
+
+pub fn end_drag(id: &str) -> Option<f32> {
+    let height = drag_height(id)?;
+    control::set_optional_f32_state(id, "drag-height", None);
+    Some(height)
+}
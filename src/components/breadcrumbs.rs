@@ -1,22 +1,45 @@
+#[cfg(feature = "overlays")]
+use std::future::Future;
+#[cfg(feature = "overlays")]
+use std::pin::Pin;
 use std::rc::Rc;
 
 use gpui::InteractiveElement;
-use gpui::{IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window, div};
+use gpui::{
+    AnyElement, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window, canvas, div,
+};
 
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
+#[cfg(feature = "overlays")]
+use crate::resource::AsyncState;
 use crate::style::Size;
 
 use super::Stack;
+use super::breadcrumbs_state;
+use super::icon::Icon;
 use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
-use super::utils::{InteractionStyles, apply_interaction_styles, interaction_style, resolve_hsla};
+#[cfg(feature = "overlays")]
+use super::menu::{Menu, MenuItem};
+use super::utils::{
+    InteractionStyles, apply_disabled_state, apply_interaction_styles, interaction_style,
+    resolve_hsla,
+};
 
 type ItemClickHandler = Rc<dyn Fn(usize, SharedString, &mut Window, &mut gpui::App)>;
+#[cfg(feature = "overlays")]
+type SiblingsLoaderFuture = Pin<Box<dyn Future<Output = Vec<BreadcrumbItem>>>>;
+#[cfg(feature = "overlays")]
+type SiblingsLoader = Rc<dyn Fn(usize, SharedString) -> SiblingsLoaderFuture>;
+
+/// Width assumed for the ellipsis crumb until it has been measured.
+const ELLIPSIS_FALLBACK_WIDTH: f32 = 24.0;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BreadcrumbItem {
     pub label: Option<SharedString>,
+    pub icon: Option<SharedString>,
     pub disabled: bool,
 }
 
@@ -30,6 +53,7 @@ impl BreadcrumbItem {
     pub fn new() -> Self {
         Self {
             label: None,
+            icon: None,
             disabled: false,
         }
     }
@@ -42,13 +66,24 @@ impl BreadcrumbItem {
         self.label = Some(value.into());
         self
     }
+
+    pub fn icon(mut self, value: impl Into<SharedString>) -> Self {
+        self.icon = Some(value.into());
+        self
+    }
+
+    fn display_label(&self) -> SharedString {
+        self.label.clone().unwrap_or_default()
+    }
 }
 
 enum CrumbNode {
     Item(usize, BreadcrumbItem),
-    Ellipsis,
+    Ellipsis(Vec<usize>),
 }
 
+/// A trail of crumbs that collapses its middle items behind an ellipsis
+/// when the available width or `max_items` cannot fit them all.
 #[derive(IntoElement)]
 pub struct Breadcrumbs {
     pub(crate) id: ComponentId,
@@ -59,6 +94,10 @@ pub struct Breadcrumbs {
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
     on_item_click: Option<ItemClickHandler>,
+    #[cfg(feature = "overlays")]
+    siblings_loader: Option<SiblingsLoader>,
+    #[cfg(feature = "overlays")]
+    on_sibling_click: Option<ItemClickHandler>,
 }
 
 impl Breadcrumbs {
@@ -73,6 +112,10 @@ impl Breadcrumbs {
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::default(),
             on_item_click: None,
+            #[cfg(feature = "overlays")]
+            siblings_loader: None,
+            #[cfg(feature = "overlays")]
+            on_sibling_click: None,
         }
     }
 
@@ -103,6 +146,28 @@ impl Breadcrumbs {
         self
     }
 
+    /// Adds a dropdown next to every crumb listing its siblings. `loader`
+    /// runs with the crumb index and label the first time the dropdown opens.
+    #[cfg(feature = "overlays")]
+    pub fn load_siblings<F, Fut>(mut self, loader: F) -> Self
+    where
+        F: Fn(usize, SharedString) -> Fut + 'static,
+        Fut: Future<Output = Vec<BreadcrumbItem>> + 'static,
+    {
+        self.siblings_loader = Some(Rc::new(move |index, label| Box::pin(loader(index, label))));
+        self
+    }
+
+    /// Called with the crumb index and the picked sibling's label.
+    #[cfg(feature = "overlays")]
+    pub fn on_sibling_click(
+        mut self,
+        handler: impl Fn(usize, SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_sibling_click = Some(Rc::new(handler));
+        self
+    }
+
     fn apply_item_size<T: Styled>(
         &self,
         node: T,
@@ -111,42 +176,294 @@ impl Breadcrumbs {
         node.text_size(preset.font_size)
     }
 
+    fn measure(id: &ComponentId, store: impl Fn(&str, f32) -> bool + 'static) -> gpui::Canvas<()> {
+        let id = id.to_string();
+        canvas(
+            move |bounds, window, _| {
+                if store(&id, f32::from(bounds.size.width)) {
+                    window.refresh();
+                }
+            },
+            |_, _, _, _| {},
+        )
+    }
+
     fn nodes(&self) -> Vec<CrumbNode> {
         let total = self.items.len();
-        if total == 0 {
-            return Vec::new();
+        let candidates: Vec<usize> = match self.max_items {
+            Some(max_items) if total > max_items => {
+                let tail_start = total.saturating_sub(max_items.saturating_sub(1));
+                std::iter::once(0).chain(tail_start..total).collect()
+            }
+            _ => (0..total).collect(),
+        };
+
+        let separator = breadcrumbs_state::separator_width(&self.id).unwrap_or(0.0);
+        let tokens = &self.theme.components.breadcrumbs;
+        let spacing = separator + f32::from(tokens.root_gap) * 2.0;
+        let widths = breadcrumbs_state::item_widths(&self.id, total)
+            .into_iter()
+            .map(|width| width + spacing)
+            .collect::<Vec<_>>();
+        let ellipsis =
+            breadcrumbs_state::ellipsis_width(&self.id).unwrap_or(ELLIPSIS_FALLBACK_WIDTH);
+        let kept = breadcrumbs_state::fit(
+            &candidates,
+            &widths,
+            breadcrumbs_state::available_width(&self.id),
+            ellipsis + spacing,
+        );
+
+        let mut nodes = Vec::with_capacity(kept.len() + 1);
+        for (position, index) in kept.into_iter().enumerate() {
+            if position == 1 && index > 1 {
+                nodes.push(CrumbNode::Ellipsis((1..index).collect()));
+            }
+            nodes.push(CrumbNode::Item(index, self.items[index].clone()));
         }
+        nodes
+    }
 
-        let Some(max_items) = self.max_items else {
-            return self
-                .items
-                .iter()
-                .cloned()
-                .enumerate()
-                .map(|(index, item)| CrumbNode::Item(index, item))
-                .collect();
+    fn render_crumb(
+        &self,
+        index: usize,
+        item: BreadcrumbItem,
+        is_current: bool,
+        tokens: &crate::theme::BreadcrumbsTokens,
+    ) -> gpui::Stateful<gpui::Div> {
+        let size_preset = tokens.sizes.for_size(self.size);
+        let fg = if is_current {
+            resolve_hsla(&self.theme, tokens.item_current_fg)
+        } else {
+            resolve_hsla(&self.theme, tokens.item_fg)
         };
+        let mut crumb = div()
+            .id(self.id.slot_index("item", index.to_string()))
+            .relative()
+            .flex()
+            .items_center()
+            .gap(tokens.root_gap)
+            .text_color(fg)
+            .child(
+                Self::measure(&self.id, move |id, width| {
+                    breadcrumbs_state::store_item_width(id, index, width)
+                })
+                .absolute()
+                .size_full(),
+            );
+        if let Some(icon) = item.icon.clone() {
+            let icon = self
+                .id
+                .ctx()
+                .child_index("icon", index.to_string(), Icon::named(icon.to_string()))
+                .size(f32::from(size_preset.font_size))
+                .color(fg);
+            crumb = crumb.child(icon);
+        }
+        if let Some(label) = item.label.clone() {
+            crumb = crumb.child(label);
+        }
+        crumb = self.apply_item_size(crumb, size_preset);
+
+        if !is_current && !item.disabled {
+            if let Some(handler) = self.on_item_click.clone() {
+                let label = item.display_label();
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(resolve_hsla(&self.theme, tokens.item_hover_bg));
+                let press_bg = tokens
+                    .states
+                    .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
+                let activate_handler: ActivateHandler = Rc::new(move |window, cx| {
+                    (handler)(index, label.clone(), window, cx);
+                });
+                crumb = crumb
+                    .px(size_preset.item_padding_x)
+                    .py(size_preset.item_padding_y)
+                    .rounded(size_preset.item_radius)
+                    .cursor_pointer();
+                crumb = apply_interaction_styles(
+                    crumb,
+                    InteractionStyles::new()
+                        .hover(interaction_style(move |style| style.bg(hover_bg)))
+                        .active(interaction_style(move |style| style.bg(press_bg)))
+                        .focus(interaction_style(move |style| style.bg(hover_bg))),
+                );
+                crumb = bind_press_adapter(
+                    crumb,
+                    PressAdapter::new(self.id.slot_index("item", index.to_string()))
+                        .component("breadcrumbs")
+                        .on_activate(Some(activate_handler)),
+                );
+            }
+        } else if item.disabled {
+            crumb = apply_disabled_state(crumb.cursor_default(), tokens.states, 0.5);
+        }
+        crumb
+    }
 
-        if total <= max_items {
-            return self
-                .items
-                .iter()
-                .cloned()
-                .enumerate()
-                .map(|(index, item)| CrumbNode::Item(index, item))
-                .collect();
+    fn render_ellipsis(
+        &self,
+        position: usize,
+        hidden: Vec<usize>,
+        tokens: &crate::theme::BreadcrumbsTokens,
+    ) -> AnyElement {
+        let size_preset = tokens.sizes.for_size(self.size);
+        let mut ellipsis = div()
+            .id(self.id.slot_index("ellipsis", position.to_string()))
+            .relative()
+            .text_color(resolve_hsla(&self.theme, tokens.separator))
+            .child(
+                Self::measure(&self.id, breadcrumbs_state::store_ellipsis_width)
+                    .absolute()
+                    .size_full(),
+            )
+            .child("...");
+        ellipsis = self.apply_item_size(ellipsis, size_preset);
+        self.collapsed_menu(ellipsis, hidden, tokens)
+    }
+
+    #[cfg(not(feature = "overlays"))]
+    fn collapsed_menu(
+        &self,
+        ellipsis: gpui::Stateful<gpui::Div>,
+        _hidden: Vec<usize>,
+        _tokens: &crate::theme::BreadcrumbsTokens,
+    ) -> AnyElement {
+        ellipsis.into_any_element()
+    }
+
+    /// Lists the collapsed crumbs in a menu opened from the ellipsis.
+    #[cfg(feature = "overlays")]
+    fn collapsed_menu(
+        &self,
+        ellipsis: gpui::Stateful<gpui::Div>,
+        hidden: Vec<usize>,
+        tokens: &crate::theme::BreadcrumbsTokens,
+    ) -> AnyElement {
+        let size_preset = tokens.sizes.for_size(self.size);
+        let hover_bg = resolve_hsla(&self.theme, tokens.item_hover_bg);
+        let trigger = ellipsis
+            .px(size_preset.item_padding_x)
+            .py(size_preset.item_padding_y)
+            .rounded(size_preset.item_radius)
+            .cursor_pointer()
+            .hover(move |style| style.bg(hover_bg));
+        let entries = hidden.iter().map(|index| {
+            let item = &self.items[*index];
+            Self::menu_item(index.to_string(), item)
+        });
+        let labels = hidden
+            .iter()
+            .map(|index| self.items[*index].display_label())
+            .collect::<Vec<_>>();
+        let mut menu = self
+            .id
+            .ctx()
+            .child("collapsed", Menu::new())
+            .trigger(trigger)
+            .items(entries);
+        if let Some(handler) = self.on_item_click.clone() {
+            menu = menu.on_item_click(move |value, window, cx| {
+                let Ok(index) = value.parse::<usize>() else {
+                    return;
+                };
+                let label = hidden
+                    .iter()
+                    .position(|hidden| *hidden == index)
+                    .and_then(|position| labels.get(position).cloned())
+                    .unwrap_or_default();
+                (handler)(index, label, window, cx);
+            });
         }
+        menu.into_any_element()
+    }
 
-        let tail_count = max_items.saturating_sub(1);
-        let tail_start = total.saturating_sub(tail_count);
+    #[cfg(feature = "overlays")]
+    fn menu_item(value: String, item: &BreadcrumbItem) -> MenuItem {
+        let label = item.display_label();
+        let entry = MenuItem::labeled(value, label).disabled(item.disabled);
+        match item.icon.clone() {
+            Some(icon) => entry.left_icon(icon),
+            None => entry,
+        }
+    }
 
-        let mut nodes = Vec::with_capacity(max_items + 1);
-        nodes.push(CrumbNode::Item(0, self.items[0].clone()));
-        nodes.push(CrumbNode::Ellipsis);
-        for index in tail_start..total {
-            nodes.push(CrumbNode::Item(index, self.items[index].clone()));
+    #[cfg(not(feature = "overlays"))]
+    fn render_siblings(
+        &self,
+        _index: usize,
+        _item: &BreadcrumbItem,
+        _tokens: &crate::theme::BreadcrumbsTokens,
+    ) -> Option<AnyElement> {
+        None
+    }
+
+    /// Dropdown next to a crumb whose entries come from the siblings loader.
+    #[cfg(feature = "overlays")]
+    fn render_siblings(
+        &self,
+        index: usize,
+        item: &BreadcrumbItem,
+        tokens: &crate::theme::BreadcrumbsTokens,
+    ) -> Option<AnyElement> {
+        let loader = self.siblings_loader.clone()?;
+        let size_preset = tokens.sizes.for_size(self.size);
+        let fg = resolve_hsla(&self.theme, tokens.separator);
+        let hover_bg = resolve_hsla(&self.theme, tokens.item_hover_bg);
+        let resource = breadcrumbs_state::siblings_cache(&self.id).get(&index);
+        let entries = match resource.state() {
+            AsyncState::Ready(siblings) if !siblings.is_empty() => siblings
+                .iter()
+                .map(|sibling| {
+                    let label = sibling.display_label();
+                    Self::menu_item(label.to_string(), sibling)
+                })
+                .collect(),
+            AsyncState::Ready(_) | AsyncState::Empty => {
+                vec![MenuItem::labeled("", "Nothing here").disabled(true)]
+            }
+            AsyncState::Error(error) => vec![MenuItem::labeled("", error).disabled(true)],
+            AsyncState::Loading => vec![MenuItem::labeled("", "Loading…").disabled(true)],
+        };
+
+        let icon = self
+            .id
+            .ctx()
+            .child_index(
+                "siblings-icon",
+                index.to_string(),
+                Icon::named("chevron-down"),
+            )
+            .size(f32::from(size_preset.font_size) * 0.75)
+            .color(fg);
+        let trigger = div()
+            .flex()
+            .items_center()
+            .rounded(size_preset.item_radius)
+            .cursor_pointer()
+            .hover(move |style| style.bg(hover_bg))
+            .child(icon);
+        let label = item.display_label();
+        let mut menu = self
+            .id
+            .ctx()
+            .child_index("siblings", index.to_string(), Menu::new())
+            .trigger(trigger)
+            .items(entries)
+            .on_open_change(move |opened, _, cx| {
+                if opened {
+                    resource.revalidate(|| loader(index, label.clone()), cx);
+                }
+            });
+        if let Some(handler) = self.on_sibling_click.clone() {
+            menu = menu.on_item_click(move |value, window, cx| {
+                if !value.is_empty() {
+                    (handler)(index, value, window, cx);
+                }
+            });
         }
-        nodes
+        Some(menu.into_any_element())
     }
 }
 
@@ -167,79 +484,53 @@ impl RenderOnce for Breadcrumbs {
         let nodes = self.nodes();
         let total_nodes = nodes.len();
 
-        let mut children = Vec::with_capacity(total_nodes.saturating_mul(2).max(1));
+        let mut children = Vec::with_capacity(total_nodes.saturating_mul(3).max(1));
         for (position, node) in nodes.into_iter().enumerate() {
             match node {
                 CrumbNode::Item(index, item) => {
                     let is_current = position == total_nodes.saturating_sub(1);
-                    let mut crumb = div()
-                        .id(self.id.slot_index("item", index.to_string()))
-                        .text_color(if is_current {
-                            resolve_hsla(&self.theme, tokens.item_current_fg)
-                        } else {
-                            resolve_hsla(&self.theme, tokens.item_fg)
-                        });
-                    if let Some(label) = item.label.clone() {
-                        crumb = crumb.child(label);
-                    }
-                    crumb = self.apply_item_size(crumb, size_preset);
-
-                    if !is_current && !item.disabled {
-                        if let Some(handler) = self.on_item_click.clone() {
-                            let label = item.label.clone().unwrap_or_default();
-                            let hover_bg = resolve_hsla(&self.theme, tokens.item_hover_bg);
-                            let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
-                            let activate_handler: ActivateHandler = Rc::new(move |window, cx| {
-                                (handler)(index, label.clone(), window, cx);
-                            });
-                            crumb = crumb
-                                .px(size_preset.item_padding_x)
-                                .py(size_preset.item_padding_y)
-                                .rounded(size_preset.item_radius)
-                                .cursor_pointer();
-                            crumb = apply_interaction_styles(
-                                crumb,
-                                InteractionStyles::new()
-                                    .hover(interaction_style(move |style| style.bg(hover_bg)))
-                                    .active(interaction_style(move |style| style.bg(press_bg)))
-                                    .focus(interaction_style(move |style| style.bg(hover_bg))),
-                            );
-                            crumb = bind_press_adapter(
-                                crumb,
-                                PressAdapter::new(self.id.slot_index("item", index.to_string()))
-                                    .on_activate(Some(activate_handler)),
-                            );
-                        }
-                    } else if item.disabled {
-                        crumb = crumb.opacity(0.5).cursor_default();
-                    }
-
-                    children.push(crumb);
+                    let siblings = self.render_siblings(index, &item, &tokens);
+                    children.push(
+                        self.render_crumb(index, item, is_current, &tokens)
+                            .into_any_element(),
+                    );
+                    children.extend(siblings);
                 }
-                CrumbNode::Ellipsis => {
-                    let mut ellipsis = div()
-                        .id(self.id.slot_index("ellipsis", position.to_string()))
-                        .text_color(resolve_hsla(&self.theme, tokens.separator))
-                        .child("...");
-                    ellipsis = self.apply_item_size(ellipsis, size_preset);
-                    children.push(ellipsis);
+                CrumbNode::Ellipsis(hidden) => {
+                    children.push(self.render_ellipsis(position, hidden, &tokens));
                 }
             }
 
             if position < total_nodes.saturating_sub(1) {
                 let mut separator = div()
                     .id(self.id.slot_index("sep", position.to_string()))
+                    .relative()
                     .text_color(resolve_hsla(&self.theme, tokens.separator))
                     .child(self.separator.clone());
+                if position == 0 {
+                    separator = separator.child(
+                        Self::measure(&self.id, breadcrumbs_state::store_separator_width)
+                            .absolute()
+                            .size_full(),
+                    );
+                }
                 separator = self.apply_item_size(separator, size_preset);
-                children.push(separator);
+                children.push(separator.into_any_element());
             }
         }
 
+        let available = Self::measure(&self.id, breadcrumbs_state::store_available_width)
+            .absolute()
+            .size_full();
         Stack::horizontal()
             .id(self.id.clone())
+            .relative()
+            .w_full()
+            .min_w_0()
+            .overflow_hidden()
             .items_center()
             .gap(tokens.root_gap)
+            .child(available)
             .children(children)
             .with_enter_transition(self.id.slot("enter"), self.motion)
     }
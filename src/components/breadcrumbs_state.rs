@@ -0,0 +1,107 @@
+#[cfg(feature = "overlays")]
+use std::collections::HashMap;
+#[cfg(feature = "overlays")]
+use std::sync::{LazyLock, Mutex};
+
+#[cfg(feature = "overlays")]
+use crate::resource::ResourceCache;
+
+#[cfg(feature = "overlays")]
+use super::breadcrumbs::BreadcrumbItem;
+use super::control;
+
+#[cfg(feature = "overlays")]
+type SiblingsCache = ResourceCache<usize, Vec<BreadcrumbItem>>;
+
+#[cfg(feature = "overlays")]
+static LOADED_SIBLINGS: LazyLock<Mutex<HashMap<String, SiblingsCache>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Picks the crumbs that stay in the trail when `available` px is not enough
+/// for all of them. The first and last crumbs are always kept; the rest are
+/// filled in from the end while they fit next to the ellipsis.
+pub fn fit(
+    candidates: &[usize],
+    widths: &[f32],
+    available: Option<f32>,
+    ellipsis: f32,
+) -> Vec<usize> {
+    let width = |index: &usize| widths.get(*index).copied().unwrap_or(0.0);
+    let Some(available) = available.filter(|available| *available > 0.0) else {
+        return candidates.to_vec();
+    };
+    if candidates.len() <= 2 || candidates.iter().map(width).sum::<f32>() <= available {
+        return candidates.to_vec();
+    }
+
+    let (first, rest) = candidates.split_first().unwrap_or((&0, &[]));
+    let (last, middle) = rest.split_last().unwrap_or((first, &[]));
+    let mut used = width(first) + width(last) + ellipsis.max(0.0);
+    let mut tail = vec![*last];
+    for index in middle.iter().rev() {
+        if used + width(index) > available {
+            break;
+        }
+        used += width(index);
+        tail.push(*index);
+    }
+    tail.reverse();
+    let mut kept = vec![*first];
+    kept.extend(tail);
+    kept
+}
+
+pub fn store_item_width(id: &str, index: usize, width: f32) -> bool {
+    store_width(id, &format!("item-width:{index}"), width)
+}
+
+pub fn item_widths(id: &str, count: usize) -> Vec<f32> {
+    (0..count)
+        .map(|index| stored_width(id, &format!("item-width:{index}")).unwrap_or(0.0))
+        .collect()
+}
+
+pub fn store_ellipsis_width(id: &str, width: f32) -> bool {
+    store_width(id, "ellipsis-width", width)
+}
+
+pub fn ellipsis_width(id: &str) -> Option<f32> {
+    stored_width(id, "ellipsis-width")
+}
+
+pub fn store_separator_width(id: &str, width: f32) -> bool {
+    store_width(id, "separator-width", width)
+}
+
+pub fn separator_width(id: &str) -> Option<f32> {
+    stored_width(id, "separator-width")
+}
+
+pub fn store_available_width(id: &str, width: f32) -> bool {
+    store_width(id, "available-width", width)
+}
+
+pub fn available_width(id: &str) -> Option<f32> {
+    stored_width(id, "available-width")
+}
+
+fn store_width(id: &str, slot: &str, width: f32) -> bool {
+    let previous = stored_width(id, slot);
+    if previous.is_some_and(|previous| (previous - width).abs() < 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, slot, Some(width));
+    true
+}
+
+fn stored_width(id: &str, slot: &str) -> Option<f32> {
+    control::optional_f32_state(id, slot, None, None)
+}
+
+#[cfg(feature = "overlays")]
+pub fn siblings_cache(id: &str) -> SiblingsCache {
+    LOADED_SIBLINGS
+        .lock()
+        .map(|mut loaded| loaded.entry(id.to_string()).or_default().clone())
+        .unwrap_or_default()
+}
@@ -12,14 +12,17 @@ use crate::contracts::{MotionAware, Radiused, Sized, Varianted};
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
 use crate::style::{GroupOrientation, Radius, Size, Variant};
+use crate::theme::SemanticRadiusToken;
 
 use super::Stack;
+use super::control;
 use super::interaction_adapter::{PressAdapter, bind_press_adapter};
 use super::loader::{Loader, LoaderElement, LoaderVariant};
+use super::press_feedback::PressFeedback;
 use super::selection_state;
 use super::utils::{
-    PressHandler, apply_interaction_styles, apply_radius, default_pressable_surface_styles,
-    resolve_hsla, variant_text_weight,
+    PressHandler, apply_disabled_state, apply_focus_ring, apply_interaction_styles, apply_radius,
+    pressable_surface_styles, resolve_hsla, resolve_radius, variant_text_weight,
 };
 
 type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
@@ -274,22 +277,41 @@ impl RenderOnce for Button {
         }
 
         if self.disabled || self.loading {
-            root = root.cursor_default().opacity(0.55);
+            root = apply_disabled_state(
+                root.cursor_default(),
+                self.theme.components.button.states,
+                0.55,
+            );
         } else if self.on_click.is_some() {
             root = root.cursor_pointer();
+            let press_feedback = PressFeedback::resolve(&self.theme, _cx);
             root = apply_interaction_styles(
                 root,
-                default_pressable_surface_styles(
+                press_feedback.interaction_styles(pressable_surface_styles(
                     bg,
                     resolve_hsla(&self.theme, self.theme.semantic.focus_ring),
-                ),
+                    self.theme.components.button.states,
+                )),
             );
+            root = press_feedback.apply(&self.id, root);
             root = bind_press_adapter(
                 root,
                 PressAdapter::new(self.id.clone())
+                    .component("button")
                     .on_click(self.on_click.clone())
                     .focus_handle(self.focus_handle.clone()),
             );
+            let focused = control::focused_state(&self.id, None, false)
+                || self
+                    .focus_handle
+                    .as_ref()
+                    .is_some_and(|handle| handle.is_focused(window));
+            root = apply_focus_ring(
+                &self.theme,
+                root,
+                focused,
+                resolve_radius(&self.theme, SemanticRadiusToken::from(self.radius)),
+            );
         } else {
             root = root.cursor_default();
         }
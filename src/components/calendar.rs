@@ -0,0 +1,716 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, FocusHandle, FontWeight, Hsla, InteractiveElement, IntoElement, ParentElement,
+    RenderOnce, SharedString, StatefulInteractiveElement, Styled, Window, div,
+};
+
+use crate::contracts::{Sized, Varianted};
+use crate::id::ComponentId;
+use crate::style::{Radius, Size, Variant};
+
+pub use super::calendar_state::{CalendarView, DateRange};
+
+use super::Stack;
+use super::action_icon::ActionIcon;
+use super::button::{Button, ButtonGroup, ButtonGroupItem};
+use super::calendar_state::{self, RangeClick};
+use super::control;
+use super::date_picker_state::{self, CalendarDate, MONTH_LABELS, WEEKDAY_LABELS};
+use super::icon::Icon;
+use super::interaction_adapter::{PressAdapter, bind_press_adapter};
+use super::selection_state;
+use super::utils::{
+    InteractionStyles, apply_interaction_styles, apply_radius, interaction_style, resolve_hsla,
+};
+
+type RangeChangeHandler = Rc<dyn Fn(DateRange, &mut Window, &mut gpui::App)>;
+type ViewChangeHandler = Rc<dyn Fn(CalendarView, &mut Window, &mut gpui::App)>;
+type EventClickHandler = Rc<dyn Fn(&CalendarEvent, &mut Window, &mut gpui::App)>;
+
+/// An entry shown as a chip on every day it spans.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalendarEvent {
+    id: SharedString,
+    title: SharedString,
+    range: DateRange,
+    color: Option<Hsla>,
+}
+
+impl CalendarEvent {
+    pub fn new(
+        id: impl Into<SharedString>,
+        title: impl Into<SharedString>,
+        date: CalendarDate,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            range: DateRange::single(date),
+            color: None,
+        }
+    }
+
+    /// Extends the event through `end`, inclusive.
+    pub fn until(mut self, end: CalendarDate) -> Self {
+        self.range = DateRange::new(self.range.start(), end);
+        self
+    }
+
+    pub fn color(mut self, value: Hsla) -> Self {
+        self.color = Some(value);
+        self
+    }
+
+    pub fn id(&self) -> &SharedString {
+        &self.id
+    }
+
+    pub fn title(&self) -> &SharedString {
+        &self.title
+    }
+
+    pub fn range(&self) -> DateRange {
+        self.range
+    }
+}
+
+/// Supplies the events for the days a [`Calendar`] is showing. Called on
+/// every render with the visible range, so keep it cheap or cache upstream.
+pub trait CalendarEventSource {
+    fn events(&self, visible: DateRange) -> Vec<CalendarEvent>;
+}
+
+impl CalendarEventSource for Vec<CalendarEvent> {
+    fn events(&self, visible: DateRange) -> Vec<CalendarEvent> {
+        self.iter()
+            .filter(|event| event.range.overlaps(visible))
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+struct CalendarCommit {
+    id: ComponentId,
+    value_controlled: bool,
+    view_controlled: bool,
+    range_selection: bool,
+    on_change: Option<RangeChangeHandler>,
+    on_view_change: Option<ViewChangeHandler>,
+}
+
+impl CalendarCommit {
+    fn pick(&self, date: CalendarDate, window: &mut Window, cx: &mut gpui::App) {
+        date_picker_state::set_focused_date(&self.id, Some(date));
+        let range = if self.range_selection {
+            match calendar_state::click_range(calendar_state::range_anchor(&self.id), date) {
+                RangeClick::Anchored(anchor) => {
+                    calendar_state::set_range_anchor(&self.id, Some(anchor));
+                    window.refresh();
+                    return;
+                }
+                RangeClick::Completed(range) => range,
+            }
+        } else {
+            DateRange::single(date)
+        };
+        calendar_state::set_range_anchor(&self.id, None);
+        calendar_state::set_hovered_date(&self.id, None);
+        selection_state::apply_optional_text(
+            &self.id,
+            "value",
+            self.value_controlled,
+            Some(range.to_string()),
+        );
+        window.refresh();
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(range, window, cx);
+        }
+    }
+
+    fn focus(&self, date: CalendarDate, window: &mut Window) {
+        date_picker_state::set_focused_date(&self.id, Some(date));
+        window.refresh();
+    }
+
+    fn set_view(&self, view: CalendarView, window: &mut Window, cx: &mut gpui::App) {
+        if !self.view_controlled {
+            control::set_text_state(&self.id, "view", view_value(view).to_string());
+        }
+        window.refresh();
+        if let Some(handler) = self.on_view_change.as_ref() {
+            (handler)(view, window, cx);
+        }
+    }
+}
+
+fn view_value(view: CalendarView) -> &'static str {
+    match view {
+        CalendarView::Month => "month",
+        CalendarView::Week => "week",
+    }
+}
+
+fn view_from_value(value: &str) -> CalendarView {
+    match value {
+        "week" => CalendarView::Week,
+        _ => CalendarView::Month,
+    }
+}
+
+fn month_name(date: CalendarDate) -> &'static str {
+    MONTH_LABELS[usize::from(date.month() - 1)]
+}
+
+/// A month or week grid of days with event chips, independent of any input.
+/// Selects a single day by default or a span with `range_selection(true)`.
+#[derive(IntoElement)]
+pub struct Calendar {
+    pub(crate) id: ComponentId,
+    view: Option<CalendarView>,
+    default_view: CalendarView,
+    value: Option<DateRange>,
+    value_controlled: bool,
+    default_value: Option<DateRange>,
+    range_selection: bool,
+    first_weekday: Option<u8>,
+    locale: Option<SharedString>,
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+    today: Option<CalendarDate>,
+    max_events_per_day: usize,
+    events: Option<Rc<dyn CalendarEventSource>>,
+    radius: Radius,
+    focus_handle: Option<FocusHandle>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    on_change: Option<RangeChangeHandler>,
+    on_view_change: Option<ViewChangeHandler>,
+    on_event_click: Option<EventClickHandler>,
+}
+
+impl Calendar {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            view: None,
+            default_view: CalendarView::Month,
+            value: None,
+            value_controlled: false,
+            default_value: None,
+            range_selection: false,
+            first_weekday: None,
+            locale: None,
+            min: None,
+            max: None,
+            today: None,
+            max_events_per_day: 3,
+            events: None,
+            radius: Radius::Sm,
+            focus_handle: None,
+            theme: crate::theme::LocalTheme::default(),
+            on_change: None,
+            on_view_change: None,
+            on_event_click: None,
+        }
+    }
+
+    pub fn view(mut self, value: CalendarView) -> Self {
+        self.view = Some(value);
+        self
+    }
+
+    pub fn default_view(mut self, value: CalendarView) -> Self {
+        self.default_view = value;
+        self
+    }
+
+    pub fn value(mut self, value: DateRange) -> Self {
+        self.value = Some(value);
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn clear_value(mut self) -> Self {
+        self.value = None;
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn default_value(mut self, value: DateRange) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+
+    /// Picks a span with two clicks instead of a single day.
+    pub fn range_selection(mut self, value: bool) -> Self {
+        self.range_selection = value;
+        self
+    }
+
+    /// `0` is Sunday. Takes precedence over [`Calendar::locale`].
+    pub fn first_weekday(mut self, value: u8) -> Self {
+        self.first_weekday = Some(value % 7);
+        self
+    }
+
+    /// Derives the first day of the week from a locale tag such as `de-DE`.
+    /// Without it the provider's i18n locale is used when available.
+    pub fn locale(mut self, value: impl Into<SharedString>) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    pub fn min_date(mut self, value: CalendarDate) -> Self {
+        self.min = Some(value);
+        self
+    }
+
+    pub fn max_date(mut self, value: CalendarDate) -> Self {
+        self.max = Some(value);
+        self
+    }
+
+    /// Overrides the highlighted current day, which defaults to the system date.
+    pub fn today(mut self, value: CalendarDate) -> Self {
+        self.today = Some(value);
+        self
+    }
+
+    /// Chips shown per day before collapsing the rest into "+N more".
+    pub fn max_events_per_day(mut self, value: usize) -> Self {
+        self.max_events_per_day = value.max(1);
+        self
+    }
+
+    pub fn events(mut self, source: impl CalendarEventSource + 'static) -> Self {
+        self.events = Some(Rc::new(source));
+        self
+    }
+
+    pub fn focus_handle(mut self, value: FocusHandle) -> Self {
+        self.focus_handle = Some(value);
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(DateRange, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_view_change(
+        mut self,
+        handler: impl Fn(CalendarView, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_view_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_event_click(
+        mut self,
+        handler: impl Fn(&CalendarEvent, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_event_click = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_view(&self) -> CalendarView {
+        view_from_value(&control::text_state(
+            &self.id,
+            "view",
+            self.view.map(|view| view_value(view).to_string()),
+            view_value(self.default_view).to_string(),
+        ))
+    }
+
+    fn resolved_value(&self) -> Option<DateRange> {
+        selection_state::resolve_optional_text(
+            &self.id,
+            "value",
+            self.value_controlled,
+            self.value.map(|value| value.to_string()),
+            self.default_value.map(|value| value.to_string()),
+        )
+        .and_then(|value| value.parse().ok())
+    }
+
+    fn resolved_first_weekday(&self, _cx: &gpui::App) -> u8 {
+        if let Some(first_weekday) = self.first_weekday {
+            return first_weekday;
+        }
+        if let Some(locale) = self.locale.as_ref() {
+            return calendar_state::first_weekday_for_locale(locale);
+        }
+        #[cfg(feature = "i18n")]
+        if let Some(tag) = crate::provider::CalmProvider::i18n(_cx).locale_tag() {
+            return calendar_state::first_weekday_for_locale(&tag);
+        }
+        0
+    }
+
+    fn commit(&self) -> CalendarCommit {
+        CalendarCommit {
+            id: self.id.clone(),
+            value_controlled: self.value_controlled,
+            view_controlled: self.view.is_some(),
+            range_selection: self.range_selection,
+            on_change: self.on_change.clone(),
+            on_view_change: self.on_view_change.clone(),
+        }
+    }
+
+    fn title(view: CalendarView, days: &[CalendarDate], focused: CalendarDate) -> String {
+        let (Some(first), Some(last)) = (days.first(), days.last()) else {
+            return String::new();
+        };
+        match view {
+            CalendarView::Month => format!("{} {}", month_name(focused), focused.year()),
+            CalendarView::Week if first.month() == last.month() => format!(
+                "{} {} – {}, {}",
+                month_name(*first),
+                first.day(),
+                last.day(),
+                last.year()
+            ),
+            CalendarView::Week => format!(
+                "{} {} – {} {}, {}",
+                &month_name(*first)[..3],
+                first.day(),
+                &month_name(*last)[..3],
+                last.day(),
+                last.year()
+            ),
+        }
+    }
+
+    fn nav_button(&self, name: &str, icon: &str, target: CalendarDate) -> ActionIcon {
+        let tokens = &self.theme.components.calendar;
+        let commit = self.commit();
+        let (min, max) = (self.min, self.max);
+        self.id
+            .ctx()
+            .child(name, ActionIcon::new())
+            .with_variant(Variant::Subtle)
+            .with_size(Size::Sm)
+            .child(
+                Icon::named(icon)
+                    .size(f32::from(tokens.icon_size))
+                    .color(resolve_hsla(&self.theme, tokens.icon)),
+            )
+            .on_click(move |_, window, _cx| {
+                commit.focus(date_picker_state::clamp_date(target, min, max), window);
+            })
+    }
+
+    fn render_header(
+        &self,
+        view: CalendarView,
+        days: &[CalendarDate],
+        focused: CalendarDate,
+        today: CalendarDate,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.calendar;
+        let commit = self.commit();
+        let (min, max) = (self.min, self.max);
+        let today_button = self
+            .id
+            .ctx()
+            .child("today", Button::labeled("Today"))
+            .with_variant(Variant::Default)
+            .with_size(Size::Xs)
+            .on_click(move |_, window, _cx| {
+                commit.focus(date_picker_state::clamp_date(today, min, max), window);
+            });
+        let commit = self.commit();
+        let views = self
+            .id
+            .ctx()
+            .child("views", ButtonGroup::new())
+            .items([
+                ButtonGroupItem::labeled("month", "Month"),
+                ButtonGroupItem::labeled("week", "Week"),
+            ])
+            .value(view_value(view))
+            .inactive_variant(Variant::Subtle)
+            .with_size(Size::Xs)
+            .on_change(move |value, window, cx| {
+                commit.set_view(view_from_value(&value), window, cx);
+            });
+
+        Stack::horizontal()
+            .w_full()
+            .gap(tokens.header_gap)
+            .child(self.nav_button(
+                "prev",
+                "chevron-left",
+                calendar_state::step(view, focused, -1),
+            ))
+            .child(self.nav_button(
+                "next",
+                "chevron-right",
+                calendar_state::step(view, focused, 1),
+            ))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .truncate()
+                    .text_size(tokens.header_size)
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(resolve_hsla(&self.theme, tokens.header_fg))
+                    .child(Self::title(view, days, focused)),
+            )
+            .child(today_button)
+            .child(views)
+            .into_any_element()
+    }
+
+    fn render_weekdays(&self, first_weekday: u8) -> AnyElement {
+        let tokens = &self.theme.components.calendar;
+        let color = resolve_hsla(&self.theme, tokens.weekday_fg);
+        Stack::horizontal()
+            .w_full()
+            .children((0..7).map(|offset| {
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .px(tokens.cell_padding)
+                    .text_size(tokens.weekday_size)
+                    .text_color(color)
+                    .child(WEEKDAY_LABELS[usize::from((first_weekday + offset) % 7)])
+            }))
+            .into_any_element()
+    }
+
+    fn render_chip(&self, event: &CalendarEvent) -> AnyElement {
+        let tokens = &self.theme.components.calendar;
+        let bg = event
+            .color
+            .unwrap_or_else(|| resolve_hsla(&self.theme, tokens.chip_bg));
+        let fg = match event.color {
+            Some(_) => gpui::white(),
+            None => resolve_hsla(&self.theme, tokens.chip_fg),
+        };
+        let mut chip = div()
+            .id(self.id.slot_index("event", event.id.to_string()))
+            .w_full()
+            .min_w_0()
+            .truncate()
+            .px(tokens.chip_padding_x)
+            .rounded_sm()
+            .bg(bg)
+            .text_color(fg)
+            .text_size(tokens.chip_size)
+            .child(event.title.clone());
+        if let Some(handler) = self.on_event_click.clone() {
+            let event = event.clone();
+            chip = chip.cursor_pointer().on_click(move |_, window, cx| {
+                cx.stop_propagation();
+                handler(&event, window, cx);
+            });
+        }
+        chip.into_any_element()
+    }
+
+    fn render_day(
+        &self,
+        date: CalendarDate,
+        view: CalendarView,
+        focused: CalendarDate,
+        today: CalendarDate,
+        highlight: Option<DateRange>,
+        events: &[CalendarEvent],
+    ) -> AnyElement {
+        let tokens = &self.theme.components.calendar;
+        let day_id = self.id.slot_index("day", date.to_string());
+        let in_month = view == CalendarView::Week
+            || (date.month() == focused.month() && date.year() == focused.year());
+        let enabled = date_picker_state::is_within(date, self.min, self.max);
+        let hover_bg = resolve_hsla(&self.theme, tokens.day_hover_bg);
+        let (chips, hidden) = calendar_state::chips_for_day(events, date, self.max_events_per_day);
+
+        let mut number = div()
+            .flex()
+            .items_center()
+            .justify_center()
+            .size(tokens.day_size * 1.8)
+            .rounded_full()
+            .text_size(tokens.day_size)
+            .child(date.day().to_string());
+        number = if date == today {
+            number
+                .bg(resolve_hsla(&self.theme, tokens.today_bg))
+                .text_color(resolve_hsla(&self.theme, tokens.today_fg))
+                .font_weight(FontWeight::SEMIBOLD)
+        } else if in_month {
+            number.text_color(resolve_hsla(&self.theme, tokens.day_fg))
+        } else {
+            number.text_color(resolve_hsla(&self.theme, tokens.day_outside_fg))
+        };
+
+        let mut cell = div()
+            .id(day_id.clone())
+            .flex()
+            .flex_col()
+            .gap(tokens.chip_gap)
+            .flex_1()
+            .min_w_0()
+            .h(match view {
+                CalendarView::Month => tokens.month_cell_height,
+                CalendarView::Week => tokens.week_cell_height,
+            })
+            .p(tokens.cell_padding)
+            .overflow_hidden()
+            .border_color(resolve_hsla(&self.theme, tokens.cell_border))
+            .border_t_1()
+            .border_l_1()
+            .child(number)
+            .children(chips.into_iter().map(|event| self.render_chip(event)));
+        if hidden > 0 {
+            cell = cell.child(
+                div()
+                    .text_size(tokens.chip_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.more_fg))
+                    .child(format!("+{hidden} more")),
+            );
+        }
+        cell = match highlight {
+            Some(range) if range.start() == date || range.end() == date => {
+                cell.bg(resolve_hsla(&self.theme, tokens.range_edge_bg))
+            }
+            Some(range) if range.contains(date) => {
+                cell.bg(resolve_hsla(&self.theme, tokens.range_bg))
+            }
+            _ if date == focused => cell.bg(hover_bg),
+            _ => cell,
+        };
+        if !enabled {
+            return cell.opacity(0.45).cursor_default().into_any_element();
+        }
+
+        let commit = self.commit();
+        let id = self.id.clone();
+        let previewing = self.range_selection && calendar_state::range_anchor(&self.id).is_some();
+        bind_press_adapter(
+            apply_interaction_styles(
+                cell.cursor_pointer(),
+                InteractionStyles::new().hover(interaction_style(move |style| style.bg(hover_bg))),
+            )
+            .on_hover(move |hovered, window, _cx| {
+                if previewing && *hovered && calendar_state::set_hovered_date(&id, Some(date)) {
+                    window.refresh();
+                }
+            }),
+            PressAdapter::new(day_id).on_activate(Some(Rc::new(
+                move |window: &mut Window, cx: &mut gpui::App| commit.pick(date, window, cx),
+            ))),
+        )
+        .into_any_element()
+    }
+}
+
+crate::impl_radiused_via_method!(Calendar, radius);
+
+impl RenderOnce for Calendar {
+    fn render(mut self, window: &mut Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let view = self.resolved_view();
+        let value = self.resolved_value();
+        let today = self.today.unwrap_or_else(CalendarDate::today);
+        let first_weekday = self.resolved_first_weekday(cx);
+        let focused = date_picker_state::focused_date(
+            &self.id,
+            date_picker_state::clamp_date(
+                value.map_or(today, DateRange::start),
+                self.min,
+                self.max,
+            ),
+        );
+        let days = calendar_state::visible_days(view, focused, first_weekday);
+        let visible = DateRange::new(
+            days.first().copied().unwrap_or(focused),
+            days.last().copied().unwrap_or(focused),
+        );
+        let events = self
+            .events
+            .as_ref()
+            .map(|source| source.events(visible))
+            .unwrap_or_default();
+        let highlight = match calendar_state::range_anchor(&self.id) {
+            Some(anchor) if self.range_selection => Some(DateRange::new(
+                anchor,
+                calendar_state::hovered_date(&self.id).unwrap_or(anchor),
+            )),
+            _ => value,
+        };
+
+        let tokens = &self.theme.components.calendar;
+        let weeks = days.chunks(7).map(|week| {
+            Stack::horizontal().w_full().children(
+                week.iter()
+                    .map(|date| self.render_day(*date, view, focused, today, highlight, &events)),
+            )
+        });
+        let grid = Stack::vertical()
+            .w_full()
+            .child(self.render_weekdays(first_weekday))
+            .children(weeks);
+
+        let commit = self.commit();
+        let (min, max) = (self.min, self.max);
+        let mut root = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .w_full()
+            .gap(tokens.header_gap)
+            .p(tokens.cell_padding)
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(resolve_hsla(&self.theme, tokens.border))
+            .child(self.render_header(view, &days, focused, today))
+            .child(grid);
+        if let Some(handle) = self.focus_handle.as_ref() {
+            root = root.track_focus(handle);
+        }
+        root = root.on_key_down(move |event, window, cx| {
+            if control::is_escape_keystroke(event)
+                && calendar_state::range_anchor(&commit.id).is_some()
+            {
+                calendar_state::set_range_anchor(&commit.id, None);
+                calendar_state::set_hovered_date(&commit.id, None);
+                window.refresh();
+                cx.stop_propagation();
+                return;
+            }
+            if !control::is_plain_keystroke(event) {
+                return;
+            }
+            let key = event.keystroke.key.as_str();
+            if control::is_activation_key(key) {
+                if date_picker_state::is_within(focused, min, max) {
+                    commit.pick(focused, window, cx);
+                }
+                cx.stop_propagation();
+                window.prevent_default();
+                return;
+            }
+            let Some(next) = date_picker_state::keyboard_date(
+                focused,
+                key,
+                event.keystroke.modifiers.shift,
+                first_weekday,
+            ) else {
+                return;
+            };
+            commit.focus(date_picker_state::clamp_date(next, min, max), window);
+            cx.stop_propagation();
+            window.prevent_default();
+        });
+        apply_radius(&self.theme, root, self.radius)
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use super::calendar::CalendarEvent;
+use super::control;
+use super::date_picker_state::{self, CalendarDate};
+
+const SUNDAY_REGIONS: [&str; 46] = [
+    "AG", "AS", "BR", "BS", "BT", "BW", "BZ", "CA", "CO", "DM", "DO", "ET", "GT", "GU", "HK", "HN",
+    "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX", "MZ", "NI",
+    "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TW", "US",
+];
+const SATURDAY_REGIONS: [&str; 15] = [
+    "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY",
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CalendarView {
+    #[default]
+    Month,
+    Week,
+}
+
+/// An inclusive span of days. A single-day selection has `start == end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: CalendarDate,
+    end: CalendarDate,
+}
+
+impl DateRange {
+    /// Builds a range from two days in either order.
+    pub fn new(a: CalendarDate, b: CalendarDate) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    pub fn single(date: CalendarDate) -> Self {
+        Self::new(date, date)
+    }
+
+    pub const fn start(self) -> CalendarDate {
+        self.start
+    }
+
+    pub const fn end(self) -> CalendarDate {
+        self.end
+    }
+
+    pub fn contains(self, date: CalendarDate) -> bool {
+        date >= self.start && date <= self.end
+    }
+
+    pub fn overlaps(self, other: DateRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Number of days covered, counting both ends.
+    pub fn days(self) -> i64 {
+        self.end.to_days() - self.start.to_days() + 1
+    }
+}
+
+impl Display for DateRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl FromStr for DateRange {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (start, end) = value.split_once("..").ok_or(())?;
+        Ok(Self::new(start.parse()?, end.parse()?))
+    }
+}
+
+/// What a click does while picking a range: the first click anchors it, the
+/// second one completes it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeClick {
+    Anchored(CalendarDate),
+    Completed(DateRange),
+}
+
+pub fn click_range(anchor: Option<CalendarDate>, date: CalendarDate) -> RangeClick {
+    match anchor {
+        Some(anchor) => RangeClick::Completed(DateRange::new(anchor, date)),
+        None => RangeClick::Anchored(date),
+    }
+}
+
+pub fn week_days(date: CalendarDate, first_weekday: u8) -> Vec<CalendarDate> {
+    let offset = (i64::from(date.weekday()) - i64::from(first_weekday % 7)).rem_euclid(7);
+    let start = date.add_days(-offset);
+    (0..7).map(|day| start.add_days(day)).collect()
+}
+
+pub fn visible_days(
+    view: CalendarView,
+    focused: CalendarDate,
+    first_weekday: u8,
+) -> Vec<CalendarDate> {
+    match view {
+        CalendarView::Month => date_picker_state::month_grid(focused, first_weekday),
+        CalendarView::Week => week_days(focused, first_weekday),
+    }
+}
+
+/// Moves the focused day by whole pages of `view`.
+pub fn step(view: CalendarView, focused: CalendarDate, pages: i32) -> CalendarDate {
+    match view {
+        CalendarView::Month => focused.add_months(pages),
+        CalendarView::Week => focused.add_days(i64::from(pages) * 7),
+    }
+}
+
+/// Splits the events touching `date` into the chips that fit and the number
+/// left over for a "+N more" label. Longer events come first.
+pub fn chips_for_day(
+    events: &[CalendarEvent],
+    date: CalendarDate,
+    max: usize,
+) -> (Vec<&CalendarEvent>, usize) {
+    let mut touching = events
+        .iter()
+        .filter(|event| event.range().contains(date))
+        .collect::<Vec<_>>();
+    touching.sort_by_key(|event| (event.range().start(), -event.range().days()));
+    let hidden = touching.len().saturating_sub(max);
+    touching.truncate(max);
+    (touching, hidden)
+}
+
+/// First day of the week for a BCP 47 or POSIX locale tag, `0` being
+/// Sunday. Regions default to Monday; a bare language uses its most common
+/// region.
+pub fn first_weekday_for_locale(tag: &str) -> u8 {
+    let tag = tag.split(['.', '@']).next().unwrap_or_default();
+    let mut parts = tag.split(['-', '_']);
+    let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+    let region = parts
+        .find(|part| {
+            part.len() == 2 || (part.len() == 3 && part.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(str::to_ascii_uppercase);
+    match region.as_deref() {
+        Some(region) if SUNDAY_REGIONS.contains(&region) => 0,
+        Some(region) if SATURDAY_REGIONS.contains(&region) => 6,
+        Some(_) => 1,
+        None => match language.as_str() {
+            "en" | "ja" | "ko" | "he" | "hi" | "th" | "pt" => 0,
+            "ar" | "fa" => 6,
+            _ => 1,
+        },
+    }
+}
+
+pub fn range_anchor(id: &str) -> Option<CalendarDate> {
+    control::optional_text_state(id, "range-anchor", None, None)
+        .and_then(|value| value.parse().ok())
+}
+
+pub fn set_range_anchor(id: &str, date: Option<CalendarDate>) {
+    control::set_optional_text_state(id, "range-anchor", date.map(|date| date.to_string()));
+}
+
+pub fn hovered_date(id: &str) -> Option<CalendarDate> {
+    control::optional_text_state(id, "hovered-date", None, None)
+        .and_then(|value| value.parse().ok())
+}
+
+/// Remembers the day under the pointer for the range preview. Returns
+/// whether it changed.
+pub fn set_hovered_date(id: &str, date: Option<CalendarDate>) -> bool {
+    if hovered_date(id) == date {
+        return false;
+    }
+    control::set_optional_text_state(id, "hovered-date", date.map(|date| date.to_string()));
+    true
+}
@@ -0,0 +1,376 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    Animation, AnimationExt, AnyElement, Bounds, Div, Hsla, IntoElement, ParentElement,
+    PathBuilder, Pixels, Point, Rgba, SharedString, Stateful, Styled, Window, canvas, div, point,
+    px,
+};
+use gpui::{InteractiveElement, StatefulInteractiveElement};
+
+use crate::id::ComponentId;
+use crate::motion::{MotionConfig, MotionLevel, MotionTransition};
+use crate::provider::CalmProvider;
+use crate::theme::{ColorScheme, Theme};
+use crate::tokens::{PaletteCatalog, PaletteKey};
+
+use super::chart_state;
+use super::popup::{PopupPlacement, anchored_host};
+use super::utils::{quantized_stroke_px, resolve_hsla};
+
+/// Palette order used for series without an explicit color, after the
+/// theme's primary color.
+const SERIES_PALETTE: [PaletteKey; 9] = [
+    PaletteKey::Teal,
+    PaletteKey::Orange,
+    PaletteKey::Grape,
+    PaletteKey::Cyan,
+    PaletteKey::Pink,
+    PaletteKey::Lime,
+    PaletteKey::Indigo,
+    PaletteKey::Yellow,
+    PaletteKey::Red,
+];
+
+pub(crate) type ValueFormatter = Rc<dyn Fn(f32) -> SharedString>;
+type PlotPainter = Rc<dyn Fn(Bounds<Pixels>, &[f32], &mut Window)>;
+
+/// One labelled value of a bar or donut chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartDatum {
+    pub(crate) label: SharedString,
+    pub(crate) value: f32,
+    pub(crate) color: Option<Hsla>,
+}
+
+impl ChartDatum {
+    pub fn new(label: impl Into<SharedString>, value: f32) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            color: None,
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// A named run of values drawn as one line of a line chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartSeries {
+    pub(crate) name: SharedString,
+    pub(crate) values: Vec<f32>,
+    pub(crate) color: Option<Hsla>,
+}
+
+impl ChartSeries {
+    pub fn new(name: impl Into<SharedString>, values: impl IntoIterator<Item = f32>) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into_iter().collect(),
+            color: None,
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// Default color of the `index`-th series: the primary color first, then a
+/// fixed walk through the palette at the theme's primary shade.
+pub(crate) fn series_color(theme: &Theme, index: usize) -> Hsla {
+    let shade = match theme.color_scheme {
+        ColorScheme::Light => theme.primary_shade_light,
+        ColorScheme::Dark => theme.primary_shade_dark,
+    };
+    let keys = std::iter::once(theme.primary_color)
+        .chain(
+            SERIES_PALETTE
+                .into_iter()
+                .filter(|key| *key != theme.primary_color),
+        )
+        .collect::<Vec<_>>();
+    let key = keys[index % keys.len()];
+    let scale = theme
+        .palette
+        .get(&key)
+        .copied()
+        .unwrap_or_else(|| PaletteCatalog::resolve(key));
+    Rgba::try_from(scale[usize::from(shade.min(9))])
+        .map(Into::into)
+        .unwrap_or_else(|_| gpui::black())
+}
+
+pub(crate) fn format_with(formatter: Option<&ValueFormatter>, value: f32) -> SharedString {
+    match formatter {
+        Some(formatter) => formatter(value),
+        None => chart_state::format_value(value).into(),
+    }
+}
+
+/// The transition data changes animate with, or `None` when motion is
+/// reduced for the component or the whole app.
+pub(crate) fn data_motion(motion: MotionConfig, cx: &gpui::App) -> Option<MotionTransition> {
+    let full = motion.level == MotionLevel::Full;
+    (full && CalmProvider::motion_level(cx) == MotionLevel::Full).then_some(motion.enter)
+}
+
+/// The painted part of a chart. The canvas records its bounds for hover
+/// hit-testing, and when the data changed since the last frame it blends
+/// from the previous values using `motion`.
+pub(crate) fn plot_layer(
+    id: &ComponentId,
+    values: Vec<f32>,
+    motion: Option<MotionTransition>,
+    paint: impl Fn(Bounds<Pixels>, &[f32], &mut Window) + 'static,
+) -> AnyElement {
+    let (previous, generation) = chart_state::data_generation(id, &values);
+    let paint: PlotPainter = Rc::new(paint);
+    let plot_id = id.to_string();
+    let layer = move |values: Vec<f32>| {
+        let paint = paint.clone();
+        let plot_id = plot_id.clone();
+        canvas(
+            move |bounds, _, _| {
+                let origin = (f32::from(bounds.origin.x), f32::from(bounds.origin.y));
+                let size = (f32::from(bounds.size.width), f32::from(bounds.size.height));
+                chart_state::store_plot(&plot_id, origin, size);
+            },
+            move |bounds, _, window, _| paint(bounds, &values, window),
+        )
+        .absolute()
+        .size_full()
+    };
+    let host = div().absolute().top_0().left_0().size_full();
+    match motion {
+        Some(profile) if generation > 0 => {
+            let easing = profile.easing;
+            let animation = Animation::new(Duration::from_millis(u64::from(profile.duration_ms)))
+                .with_easing(move |delta| easing.apply(delta));
+            host.with_animation(
+                id.slot(format!("data-{generation}")),
+                animation,
+                move |this, delta| {
+                    this.child(layer(chart_state::interpolate(&previous, &values, delta)))
+                },
+            )
+            .into_any_element()
+        }
+        _ => host.child(layer(values)).into_any_element(),
+    }
+}
+
+/// Tracks which datum is under the pointer. `hit` maps a pointer position
+/// relative to the plot, and the plot size, to a datum index.
+pub(crate) fn track_hover(
+    element: Stateful<Div>,
+    id: &ComponentId,
+    hit: impl Fn((f32, f32), (f32, f32)) -> Option<usize> + 'static,
+) -> Stateful<Div> {
+    let move_id = id.to_string();
+    let leave_id = id.to_string();
+    element
+        .on_mouse_move(move |event, window, _| {
+            let ((x, y), size) = chart_state::plot(&move_id);
+            let local = (
+                f32::from(event.position.x) - x,
+                f32::from(event.position.y) - y,
+            );
+            if chart_state::set_hovered(&move_id, hit(local, size)) {
+                window.refresh();
+            }
+        })
+        .on_hover(move |hovered, window, _| {
+            if !*hovered && chart_state::set_hovered(&leave_id, None) {
+                window.refresh();
+            }
+        })
+}
+
+/// A tooltip bubble listing `rows` of swatch, name and value under an
+/// optional title, styled like a tooltip.
+pub(crate) fn tooltip_bubble(
+    theme: &Theme,
+    window: &Window,
+    title: Option<SharedString>,
+    rows: Vec<(Hsla, SharedString, SharedString)>,
+) -> AnyElement {
+    let tokens = &theme.components.tooltip;
+    let swatch = theme.components.chart.swatch_size;
+    let rows = rows.into_iter().map(|(color, name, value)| {
+        div()
+            .flex()
+            .items_center()
+            .gap(tokens.padding_x)
+            .child(div().size(swatch).rounded_full().bg(color))
+            .child(div().flex_1().opacity(0.8).child(name))
+            .child(value)
+    });
+    div()
+        .flex()
+        .flex_col()
+        .gap(tokens.padding_y)
+        .text_size(tokens.text_size)
+        .px(tokens.padding_x)
+        .py(tokens.padding_y)
+        .max_w(tokens.max_width)
+        .rounded(tokens.radius)
+        .border(quantized_stroke_px(window, 1.0))
+        .border_color(resolve_hsla(theme, tokens.border))
+        .bg(resolve_hsla(theme, tokens.bg))
+        .text_color(resolve_hsla(theme, tokens.fg))
+        .children(title)
+        .children(rows)
+        .into_any_element()
+}
+
+/// Floats `bubble` above the point `at`, given relative to the plot.
+pub(crate) fn tooltip_at(
+    id: &ComponentId,
+    theme: &Theme,
+    at: (f32, f32),
+    bubble: AnyElement,
+) -> AnyElement {
+    let margin = theme.components.layout.popup_snap_margin;
+    let options = PopupPlacement::Top.options(6.0, margin);
+    div()
+        .absolute()
+        .left(px(at.0))
+        .top(px(at.1))
+        .size(px(0.0))
+        .child(anchored_host(id, "tooltip", options, bubble, 24, false))
+        .into_any_element()
+}
+
+/// Tick labels to the left of a plot `height` px tall, with `ticks` spanning
+/// the axis from bottom to top.
+pub(crate) fn value_axis(
+    theme: &Theme,
+    ticks: &[f32],
+    height: f32,
+    formatter: Option<&ValueFormatter>,
+) -> AnyElement {
+    let tokens = &theme.components.chart;
+    let bounds = (
+        ticks.first().copied().unwrap_or(0.0),
+        ticks.last().copied().unwrap_or(1.0),
+    );
+    let half_line = f32::from(tokens.axis_size) * 0.75;
+    let labels = ticks.iter().map(|tick| {
+        let top = chart_state::scale_y(*tick, bounds, height) - half_line;
+        div()
+            .absolute()
+            .right_0()
+            .top(px(top))
+            .child(format_with(formatter, *tick))
+    });
+    div()
+        .relative()
+        .flex_none()
+        .w(tokens.axis_width)
+        .h(px(height))
+        .text_size(tokens.axis_size)
+        .text_color(resolve_hsla(theme, tokens.axis_fg))
+        .children(labels)
+        .into_any_element()
+}
+
+/// Swatches with labels, wrapping onto more lines when needed.
+pub(crate) fn legend(theme: &Theme, items: Vec<(Hsla, SharedString)>) -> AnyElement {
+    let tokens = &theme.components.chart;
+    let items = items.into_iter().map(|(color, label)| {
+        div()
+            .flex()
+            .items_center()
+            .gap(tokens.axis_gap)
+            .child(div().size(tokens.swatch_size).rounded_full().bg(color))
+            .child(label)
+    });
+    div()
+        .flex()
+        .flex_wrap()
+        .justify_center()
+        .gap(tokens.legend_gap)
+        .text_size(tokens.legend_size)
+        .text_color(resolve_hsla(theme, tokens.legend_fg))
+        .children(items)
+        .into_any_element()
+}
+
+/// Horizontal grid lines at each tick.
+pub(crate) fn paint_grid(window: &mut Window, bounds: Bounds<Pixels>, ticks: &[f32], color: Hsla) {
+    let (Some(min), Some(max)) = (ticks.first(), ticks.last()) else {
+        return;
+    };
+    let height = f32::from(bounds.size.height);
+    for tick in ticks {
+        let y = bounds.origin.y + px(chart_state::scale_y(*tick, (*min, *max), height));
+        let mut builder = PathBuilder::stroke(px(1.0));
+        builder.move_to(point(bounds.origin.x, y));
+        builder.line_to(point(bounds.origin.x + bounds.size.width, y));
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, color);
+        }
+    }
+}
+
+/// Strokes `points` as one polyline and, when `area` is set, fills the
+/// region between it and the bottom of `bounds`.
+pub(crate) fn paint_line(
+    window: &mut Window,
+    bounds: Bounds<Pixels>,
+    points: &[Point<Pixels>],
+    width: Pixels,
+    color: Hsla,
+    area: bool,
+) {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return;
+    };
+    if area && points.len() > 1 {
+        let bottom = bounds.origin.y + bounds.size.height;
+        let mut builder = PathBuilder::fill();
+        builder.move_to(point(first.x, bottom));
+        for at in points {
+            builder.line_to(*at);
+        }
+        builder.line_to(point(last.x, bottom));
+        builder.close();
+        if let Ok(path) = builder.build() {
+            window.paint_path(path, color.alpha(0.16));
+        }
+    }
+    let mut builder = PathBuilder::stroke(width);
+    builder.move_to(*first);
+    for at in &points[1..] {
+        builder.line_to(*at);
+    }
+    if points.len() == 1 {
+        builder.line_to(point(first.x + px(0.5), first.y));
+    }
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// A ringed dot marking the hovered point.
+pub(crate) fn paint_marker(
+    window: &mut Window,
+    at: Point<Pixels>,
+    diameter: Pixels,
+    color: Hsla,
+    center: Hsla,
+) {
+    let dot = |diameter: Pixels| {
+        let origin = point(at.x - diameter / 2.0, at.y - diameter / 2.0);
+        Bounds::new(origin, size(diameter, diameter))
+    };
+    window.paint_quad(fill(dot(diameter), color).corner_radii(diameter / 2.0));
+    let inner = (diameter - px(4.0)).max(px(0.0));
+    window.paint_quad(fill(dot(inner), center).corner_radii(inner / 2.0));
+}
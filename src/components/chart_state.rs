@@ -0,0 +1,209 @@
+use std::f32::consts::TAU;
+
+use super::control;
+
+/// Lower and upper bound of a value axis. Bar charts pass `include_zero` so
+/// bars always grow from the baseline; a flat series gets a unit of padding
+/// so it still has a height to scale against.
+pub fn value_bounds(values: impl IntoIterator<Item = f32>, include_zero: bool) -> (f32, f32) {
+    let (mut min, mut max) = values
+        .into_iter()
+        .filter(|value| value.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    if min > max {
+        return (0.0, 1.0);
+    }
+    if include_zero {
+        min = min.min(0.0);
+        max = max.max(0.0);
+    }
+    if (max - min).abs() < f32::EPSILON {
+        return (min - 1.0, max + 1.0);
+    }
+    (min, max)
+}
+
+/// Evenly spaced, rounded tick values covering `min..=max`, aiming for about
+/// `target` ticks. The first and last ticks are the widened axis bounds.
+pub fn nice_ticks(min: f32, max: f32, target: usize) -> Vec<f32> {
+    let span = (max - min).abs().max(f32::EPSILON);
+    let raw = span / target.max(1) as f32;
+    let magnitude = 10_f32.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= raw)
+        .unwrap_or(10.0 * magnitude);
+    let first = (min / step).floor() * step;
+    let last = (max / step).ceil() * step;
+    let count = ((last - first) / step).round() as usize;
+    (0..=count)
+        .map(|index| first + step * index as f32)
+        .collect()
+}
+
+/// Distance of `value` from the top of a plot `extent` px tall.
+pub fn scale_y(value: f32, (min, max): (f32, f32), extent: f32) -> f32 {
+    let span = (max - min).max(f32::EPSILON);
+    extent - (value - min) / span * extent
+}
+
+/// Horizontal position of point `index` when `count` points span `width`.
+/// A single point sits in the middle.
+pub fn point_x(index: usize, count: usize, width: f32) -> f32 {
+    if count <= 1 {
+        return width / 2.0;
+    }
+    index as f32 / (count - 1) as f32 * width
+}
+
+/// The point closest to `x`, for hover hit-testing on line charts.
+pub fn nearest_point(x: f32, count: usize, width: f32) -> Option<usize> {
+    if count == 0 || width <= 0.0 {
+        return None;
+    }
+    if count == 1 {
+        return Some(0);
+    }
+    let step = width / (count - 1) as f32;
+    Some(((x.clamp(0.0, width) / step).round() as usize).min(count - 1))
+}
+
+/// Left edge and width of bar `index` out of `count`, separated by `gap`.
+pub fn bar_band(index: usize, count: usize, width: f32, gap: f32) -> (f32, f32) {
+    let band = width / count.max(1) as f32;
+    let gap = gap.min(band * 0.5);
+    (band * index as f32 + gap / 2.0, (band - gap).max(1.0))
+}
+
+/// The bar whose band contains `x`.
+pub fn bar_at(x: f32, count: usize, width: f32) -> Option<usize> {
+    if count == 0 || width <= 0.0 || !(0.0..width).contains(&x) {
+        return None;
+    }
+    Some(((x / width * count as f32) as usize).min(count - 1))
+}
+
+/// Start and end angle, in radians clockwise from twelve o'clock, of each
+/// donut segment. Negative values count as empty.
+pub fn donut_segments(values: &[f32]) -> Vec<(f32, f32)> {
+    let total = values.iter().map(|value| value.max(0.0)).sum::<f32>();
+    let mut start = 0.0;
+    values
+        .iter()
+        .map(|value| {
+            let sweep = if total > 0.0 {
+                value.max(0.0) / total * TAU
+            } else {
+                0.0
+            };
+            let segment = (start, start + sweep);
+            start += sweep;
+            segment
+        })
+        .collect()
+}
+
+/// The segment under a pointer `(dx, dy)` px from the donut center, if the
+/// pointer is on the ring.
+pub fn donut_segment_at(
+    (dx, dy): (f32, f32),
+    outer: f32,
+    inner: f32,
+    segments: &[(f32, f32)],
+) -> Option<usize> {
+    let distance = (dx * dx + dy * dy).sqrt();
+    if distance < inner || distance > outer {
+        return None;
+    }
+    let angle = dx.atan2(-dy).rem_euclid(TAU);
+    segments
+        .iter()
+        .position(|(start, end)| end > start && (*start..*end).contains(&angle))
+}
+
+/// Blends from the previous data to the next one. Values that did not exist
+/// before grow from zero.
+pub fn interpolate(from: &[f32], to: &[f32], delta: f32) -> Vec<f32> {
+    to.iter()
+        .enumerate()
+        .map(|(index, target)| {
+            let start = from.get(index).copied().unwrap_or(0.0);
+            start + (target - start) * delta
+        })
+        .collect()
+}
+
+/// Short label for a value: whole numbers without decimals, anything else
+/// with at most two.
+pub fn format_value(value: f32) -> String {
+    if value.fract().abs() < 0.005 {
+        return format!("{value:.0}");
+    }
+    let text = format!("{value:.2}");
+    text.trim_end_matches('0').to_owned()
+}
+
+/// Records the data a chart rendered. Returns the data from before the last
+/// change and a generation that bumps every time the data changes, so a new
+/// animation can be keyed off it.
+pub fn data_generation(id: &str, values: &[f32]) -> (Vec<f32>, usize) {
+    let next = values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>();
+    let generation = control::usize_state(id, "data-generation", None, 0);
+    let current = control::list_state(id, "data", None, next.clone());
+    if current == next {
+        let previous = control::list_state(id, "previous-data", None, Vec::new());
+        return (parse_values(&previous), generation);
+    }
+    control::set_list_state(id, "previous-data", current.clone());
+    control::set_list_state(id, "data", next);
+    control::set_usize_state(id, "data-generation", generation + 1);
+    (parse_values(&current), generation + 1)
+}
+
+fn parse_values(values: &[String]) -> Vec<f32> {
+    values
+        .iter()
+        .map(|value| value.parse().unwrap_or(0.0))
+        .collect()
+}
+
+pub fn store_plot(id: &str, origin: (f32, f32), size: (f32, f32)) {
+    control::set_f32_state(id, "plot-x", origin.0);
+    control::set_f32_state(id, "plot-y", origin.1);
+    control::set_f32_state(id, "plot-width", size.0);
+    control::set_f32_state(id, "plot-height", size.1);
+}
+
+/// Window-space origin and size of the plot from the last paint.
+pub fn plot(id: &str) -> ((f32, f32), (f32, f32)) {
+    let scope = control::scope(id);
+    (
+        (
+            scope.f32("plot-x", None, 0.0),
+            scope.f32("plot-y", None, 0.0),
+        ),
+        (
+            scope.f32("plot-width", None, 0.0),
+            scope.f32("plot-height", None, 0.0),
+        ),
+    )
+}
+
+pub fn hovered(id: &str) -> Option<usize> {
+    control::optional_usize_state(id, "hovered", None, None)
+}
+
+/// Moves the hover highlight. Returns whether it changed.
+pub fn set_hovered(id: &str, index: Option<usize>) -> bool {
+    if hovered(id) == index {
+        return false;
+    }
+    control::set_optional_usize_state(id, "hovered", index);
+    true
+}
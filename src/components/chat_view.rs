@@ -0,0 +1,506 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, FontWeight, InteractiveElement, IntoElement, ParentElement, RenderOnce,
+    ScrollHandle, SharedString, StatefulInteractiveElement, Styled, Window, canvas, div, point, px,
+};
+
+use crate::contracts::Sized;
+use crate::id::ComponentId;
+use crate::style::Size;
+
+use super::chat_view_state;
+use super::icon::Icon;
+use super::loader::Loader;
+use super::markdown::{Markdown, MarkdownLinkClick};
+use super::utils::resolve_hsla;
+
+type MessageSlot = Rc<dyn Fn(&ChatMessage) -> AnyElement>;
+type LinkHandler = Rc<dyn Fn(&MarkdownLinkClick, &mut Window, &mut gpui::App) -> bool>;
+
+/// Within this many px of the bottom the view sticks to new messages.
+const STICK_DISTANCE: f32 = 2.0;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChatMessage {
+    /// Stable key, used for measured heights and element ids.
+    pub id: SharedString,
+    pub author: SharedString,
+    /// Sent by the current user: drawn on the right without an avatar.
+    pub own: bool,
+    /// Markdown source of the message.
+    pub body: SharedString,
+    /// Shown next to the author, such as "14:02".
+    pub time: Option<SharedString>,
+    /// Label of the day the message belongs to, such as "Today". A separator
+    /// goes wherever it changes.
+    pub day: Option<SharedString>,
+    /// The body is still arriving; see [`Markdown::streaming`].
+    pub streaming: bool,
+}
+
+impl ChatMessage {
+    pub fn new(
+        id: impl Into<SharedString>,
+        author: impl Into<SharedString>,
+        body: impl Into<SharedString>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            author: author.into(),
+            own: false,
+            body: body.into(),
+            time: None,
+            day: None,
+            streaming: false,
+        }
+    }
+
+    pub fn own(mut self, value: bool) -> Self {
+        self.own = value;
+        self
+    }
+
+    pub fn time(mut self, value: impl Into<SharedString>) -> Self {
+        self.time = Some(value.into());
+        self
+    }
+
+    pub fn day(mut self, value: impl Into<SharedString>) -> Self {
+        self.day = Some(value.into());
+        self
+    }
+
+    pub fn streaming(mut self, value: bool) -> Self {
+        self.streaming = value;
+        self
+    }
+}
+
+enum ChatRow {
+    Day(SharedString),
+    /// Index of the message, and whether it continues a run from the same
+    /// author so the avatar and name can be left out.
+    Message(usize, bool),
+}
+
+/// Conversation view that starts at the newest message and stays there as
+/// more arrive. Only the messages in view are rendered; their heights are
+/// measured as they show up. Scrolling up holds the view in place and a
+/// pill offers the way back down with a count of what came in meanwhile.
+#[derive(IntoElement)]
+pub struct ChatView {
+    pub(crate) id: ComponentId,
+    messages: Vec<ChatMessage>,
+    typing: Option<SharedString>,
+    show_avatars: bool,
+    overscan: usize,
+    estimated_height: Option<f32>,
+    avatar: Option<MessageSlot>,
+    timestamp: Option<MessageSlot>,
+    link_handler: Option<LinkHandler>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl ChatView {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            messages: Vec::new(),
+            typing: None,
+            show_avatars: true,
+            overscan: 4,
+            estimated_height: None,
+            avatar: None,
+            timestamp: None,
+            link_handler: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    /// Messages oldest first.
+    pub fn messages(mut self, messages: impl IntoIterator<Item = ChatMessage>) -> Self {
+        self.messages = messages.into_iter().collect();
+        self
+    }
+
+    /// Shows a typing indicator under the list, such as "Sam is typing".
+    pub fn typing(mut self, label: impl Into<SharedString>) -> Self {
+        self.typing = Some(label.into());
+        self
+    }
+
+    pub fn show_avatars(mut self, value: bool) -> Self {
+        self.show_avatars = value;
+        self
+    }
+
+    /// Messages rendered above and below the visible ones.
+    pub fn overscan(mut self, value: usize) -> Self {
+        self.overscan = value;
+        self
+    }
+
+    /// Height assumed for a message until it has been laid out once.
+    pub fn estimated_message_height(mut self, value: f32) -> Self {
+        self.estimated_height = Some(value.max(1.0));
+        self
+    }
+
+    /// Replaces the initial shown for other people's messages.
+    pub fn avatar(mut self, render: impl Fn(&ChatMessage) -> AnyElement + 'static) -> Self {
+        self.avatar = Some(Rc::new(render));
+        self
+    }
+
+    /// Replaces the time text next to the author.
+    pub fn timestamp(mut self, render: impl Fn(&ChatMessage) -> AnyElement + 'static) -> Self {
+        self.timestamp = Some(Rc::new(render));
+        self
+    }
+
+    /// Passed to each message body; see [`Markdown::link_handler`].
+    pub fn link_handler(
+        mut self,
+        handler: impl Fn(&MarkdownLinkClick, &mut Window, &mut gpui::App) -> bool + 'static,
+    ) -> Self {
+        self.link_handler = Some(Rc::new(handler));
+        self
+    }
+
+    fn rows(&self) -> Vec<(String, ChatRow)> {
+        let mut rows = Vec::with_capacity(self.messages.len());
+        let mut previous: Option<&ChatMessage> = None;
+        for (index, message) in self.messages.iter().enumerate() {
+            let new_day = message.day.is_some()
+                && previous.is_none_or(|previous| previous.day != message.day);
+            if new_day && let Some(day) = message.day.clone() {
+                rows.push((format!("day:{}", message.id), ChatRow::Day(day)));
+            }
+            let continued = !new_day
+                && previous.is_some_and(|previous| {
+                    previous.own == message.own && previous.author == message.author
+                });
+            rows.push((
+                format!("message:{}", message.id),
+                ChatRow::Message(index, continued),
+            ));
+            previous = Some(message);
+        }
+        rows
+    }
+
+    fn render_day(&self, label: SharedString) -> AnyElement {
+        let tokens = &self.theme.components.chat_view;
+        let line = resolve_hsla(&self.theme, tokens.separator_line);
+        div()
+            .flex()
+            .items_center()
+            .gap(tokens.avatar_gap)
+            .py(tokens.padding)
+            .text_size(tokens.separator_size)
+            .text_color(resolve_hsla(&self.theme, tokens.separator_fg))
+            .child(div().flex_1().h(px(1.0)).bg(line))
+            .child(label)
+            .child(div().flex_1().h(px(1.0)).bg(line))
+            .into_any_element()
+    }
+
+    fn render_avatar(&self, message: &ChatMessage, continued: bool) -> AnyElement {
+        let tokens = &self.theme.components.chat_view;
+        let slot = div().flex_none().w(tokens.avatar_size);
+        if continued {
+            return slot.into_any_element();
+        }
+        if let Some(render) = self.avatar.as_ref() {
+            return slot.child(render(message)).into_any_element();
+        }
+        let initial = message
+            .author
+            .chars()
+            .next()
+            .map(|first| first.to_uppercase().to_string())
+            .unwrap_or_default();
+        slot.h(tokens.avatar_size)
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded_full()
+            .bg(resolve_hsla(&self.theme, tokens.avatar_bg))
+            .text_size(tokens.meta_size)
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(resolve_hsla(&self.theme, tokens.avatar_fg))
+            .child(initial)
+            .into_any_element()
+    }
+
+    fn render_message(&self, message: &ChatMessage, continued: bool) -> AnyElement {
+        let tokens = &self.theme.components.chat_view;
+        let mut body = self
+            .id
+            .ctx()
+            .child_index(
+                "body",
+                message.id.to_string(),
+                Markdown::new(message.body.clone()),
+            )
+            .compact(true)
+            .streaming(message.streaming);
+        if let Some(handler) = self.link_handler.clone() {
+            body = body.link_handler(move |link, window, cx| handler(link, window, cx));
+        }
+        let bubble = div()
+            .max_w(tokens.bubble_max_width)
+            .px(tokens.bubble_padding_x)
+            .py(tokens.bubble_padding_y)
+            .rounded(tokens.bubble_radius)
+            .bg(resolve_hsla(
+                &self.theme,
+                if message.own {
+                    tokens.own_bg
+                } else {
+                    tokens.other_bg
+                },
+            ))
+            .child(body);
+
+        let time = match (self.timestamp.as_ref(), message.time.clone()) {
+            (Some(render), _) => Some(render(message)),
+            (None, Some(time)) => Some(time.into_any_element()),
+            (None, None) => None,
+        };
+        let meta = (!continued).then(|| {
+            let author = (!message.own).then(|| {
+                div()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(resolve_hsla(&self.theme, tokens.author_fg))
+                    .child(message.author.clone())
+            });
+            div()
+                .flex()
+                .gap(tokens.avatar_gap)
+                .text_size(tokens.meta_size)
+                .text_color(resolve_hsla(&self.theme, tokens.time_fg))
+                .children(author)
+                .children(time)
+        });
+
+        let mut column = div()
+            .flex()
+            .flex_col()
+            .min_w_0()
+            .gap(tokens.message_gap)
+            .children(meta)
+            .child(bubble);
+        column = if message.own {
+            column.items_end()
+        } else {
+            column.items_start()
+        };
+
+        let mut row = div().flex().w_full().gap(tokens.avatar_gap);
+        if message.own {
+            row = row.justify_end();
+        } else if self.show_avatars {
+            row = row.child(self.render_avatar(message, continued));
+        }
+        row.child(column).into_any_element()
+    }
+
+    /// Wraps a row so its laid-out height is recorded for the next frame.
+    fn measured(&self, key: &str, content: AnyElement) -> AnyElement {
+        let tokens = &self.theme.components.chat_view;
+        let (id, row) = (self.id.to_string(), key.to_string());
+        div()
+            .id(self.id.slot_index("row", key))
+            .relative()
+            .flex_none()
+            .w_full()
+            .px(tokens.padding)
+            .pt(tokens.message_gap)
+            .child(content)
+            .child(
+                canvas(
+                    move |bounds, window, _cx| {
+                        if chat_view_state::store_row_height(
+                            &id,
+                            &row,
+                            f32::from(bounds.size.height),
+                        ) {
+                            window.refresh();
+                        }
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .into_any_element()
+    }
+
+    fn render_pill(&self, unread: usize) -> AnyElement {
+        let tokens = &self.theme.components.chat_view;
+        let id = self.id.to_string();
+        let fg = resolve_hsla(&self.theme, tokens.pill_fg);
+        let label = match unread {
+            0 => None,
+            1 => Some(SharedString::from("1 new message")),
+            count => Some(SharedString::from(format!("{count} new messages"))),
+        };
+        div()
+            .absolute()
+            .left_0()
+            .right_0()
+            .bottom(tokens.pill_offset)
+            .flex()
+            .justify_center()
+            .child(
+                div()
+                    .id(self.id.slot("pill"))
+                    .flex()
+                    .items_center()
+                    .gap(tokens.message_gap)
+                    .px(tokens.bubble_padding_x)
+                    .py(tokens.message_gap)
+                    .rounded_full()
+                    .bg(resolve_hsla(&self.theme, tokens.pill_bg))
+                    .text_size(tokens.meta_size)
+                    .text_color(fg)
+                    .cursor_pointer()
+                    .child(Icon::named("chevron-down").size(14.0).color(fg))
+                    .children(label)
+                    .on_click(move |_, window, _cx| {
+                        chat_view_state::set_anchor(&id, None);
+                        window.refresh();
+                    }),
+            )
+            .into_any_element()
+    }
+
+    fn render_typing(&self, label: SharedString) -> AnyElement {
+        let tokens = &self.theme.components.chat_view;
+        let color = resolve_hsla(&self.theme, tokens.typing_fg);
+        div()
+            .flex()
+            .items_center()
+            .flex_none()
+            .px(tokens.padding)
+            .py(tokens.message_gap)
+            .child(
+                self.id
+                    .ctx()
+                    .child("typing", Loader::new())
+                    .with_size(Size::Xs)
+                    .color(color)
+                    .label(label),
+            )
+            .into_any_element()
+    }
+}
+
+impl RenderOnce for ChatView {
+    fn render(mut self, _window: &mut Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.chat_view.clone();
+        let chat_id = self.id.to_string();
+        let estimate = self
+            .estimated_height
+            .unwrap_or(f32::from(tokens.estimated_row_height));
+
+        let rows = self.rows();
+        let keys = rows.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>();
+        let heights = keys
+            .iter()
+            .map(|key| chat_view_state::row_height(&chat_id, key, estimate))
+            .collect::<Vec<_>>();
+        let total = heights.iter().sum::<f32>();
+        let viewport = chat_view_state::viewport(&chat_id, estimate * 8.0);
+        let bottom = (total - viewport).max(0.0);
+        let anchor = chat_view_state::anchor(&chat_id).and_then(|(row, offset)| {
+            let index = keys.iter().position(|key| *key == row)?;
+            Some(chat_view_state::row_top(&heights, index) + offset)
+        });
+        let scroll_top = anchor.map_or(bottom, |top| top.min(bottom));
+        let window_range =
+            chat_view_state::visible_rows(&heights, viewport, scroll_top, self.overscan);
+        let unread = chat_view_state::unread_count(
+            self.messages
+                .iter()
+                .map(|message| (message.id.as_ref(), message.own)),
+            chat_view_state::seen(&chat_id).as_deref(),
+        );
+
+        let scroll_handle = ScrollHandle::new();
+        scroll_handle.set_offset(point(px(0.0), px(-scroll_top)));
+        let monitor = {
+            let handle = scroll_handle.clone();
+            let newest = self.messages.last().map(|message| message.id.to_string());
+            let (chat_id, keys, heights) = (chat_id.clone(), keys.clone(), heights.clone());
+            canvas(
+                move |_bounds, window, _cx| {
+                    let top = -f32::from(handle.offset().y);
+                    let max = f32::from(handle.max_offset().height);
+                    let mut refresh = chat_view_state::store_viewport(
+                        &chat_id,
+                        f32::from(handle.bounds().size.height),
+                    );
+                    let anchor = if max - top <= STICK_DISTANCE {
+                        refresh |= chat_view_state::set_seen(&chat_id, newest.clone());
+                        None
+                    } else {
+                        chat_view_state::anchor_at(&heights, top)
+                            .map(|(index, offset)| (keys[index].clone(), offset))
+                    };
+                    refresh |= chat_view_state::set_anchor(&chat_id, anchor);
+                    if refresh {
+                        window.refresh();
+                    }
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full()
+        };
+
+        let before = chat_view_state::row_top(&heights, window_range.start);
+        let after = total - chat_view_state::row_top(&heights, window_range.end);
+        let mut list = div()
+            .id(self.id.slot("list"))
+            .relative()
+            .flex()
+            .flex_col()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&scroll_handle)
+            .child(div().flex_none().h(px(before)));
+        for index in window_range {
+            let (key, row) = &rows[index];
+            let content = match row {
+                ChatRow::Day(label) => self.render_day(label.clone()),
+                ChatRow::Message(message, continued) => {
+                    self.render_message(&self.messages[*message], *continued)
+                }
+            };
+            list = list.child(self.measured(key, content));
+        }
+        let list = list.child(div().flex_none().h(px(after))).child(monitor);
+
+        let pill = anchor.is_some().then(|| self.render_pill(unread));
+        let typing = self.typing.clone().map(|label| self.render_typing(label));
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .size_full()
+            .child(
+                div()
+                    .relative()
+                    .flex_1()
+                    .min_h_0()
+                    .child(list)
+                    .children(pill),
+            )
+            .children(typing)
+    }
+}
@@ -0,0 +1,126 @@
+use std::ops::Range;
+
+use super::control;
+
+/// Offset of row `index`'s top edge.
+pub fn row_top(heights: &[f32], index: usize) -> f32 {
+    heights[..index.min(heights.len())].iter().sum()
+}
+
+/// Rows to render for a list scrolled `scroll_top` px down a `viewport` px
+/// tall window, padded by `overscan` rows on each side.
+pub fn visible_rows(
+    heights: &[f32],
+    viewport: f32,
+    scroll_top: f32,
+    overscan: usize,
+) -> Range<usize> {
+    let bottom = scroll_top + viewport.max(0.0);
+    let mut first = heights.len();
+    let mut end = heights.len();
+    let mut top = 0.0;
+    for (index, height) in heights.iter().enumerate() {
+        if first == heights.len() && top + height > scroll_top {
+            first = index;
+        }
+        if top >= bottom {
+            end = index;
+            break;
+        }
+        top += height;
+    }
+    let first = first.min(end);
+    first.saturating_sub(overscan)..(end + overscan).min(heights.len())
+}
+
+/// Row under `scroll_top` and how far down into it the top edge sits.
+pub fn anchor_at(heights: &[f32], scroll_top: f32) -> Option<(usize, f32)> {
+    let mut top = 0.0;
+    for (index, height) in heights.iter().enumerate() {
+        if top + height > scroll_top {
+            return Some((index, (scroll_top - top).max(0.0)));
+        }
+        top += height;
+    }
+    None
+}
+
+/// Messages from others that came in after `seen`. Nothing counts as
+/// unread until something has been seen.
+pub fn unread_count<'a>(
+    messages: impl DoubleEndedIterator<Item = (&'a str, bool)>,
+    seen: Option<&str>,
+) -> usize {
+    let Some(seen) = seen else {
+        return 0;
+    };
+    let mut count = 0;
+    for (id, own) in messages.rev() {
+        if id == seen {
+            return count;
+        }
+        if !own {
+            count += 1;
+        }
+    }
+    0
+}
+
+pub fn row_height(id: &str, row: &str, estimate: f32) -> f32 {
+    control::optional_f32_state(id, &format!("height:{row}"), None, None).unwrap_or(estimate)
+}
+
+/// Records a row's laid-out height. Returns whether it changed.
+pub fn store_row_height(id: &str, row: &str, height: f32) -> bool {
+    let slot = format!("height:{row}");
+    let previous = control::optional_f32_state(id, &slot, None, None);
+    if previous.is_some_and(|previous| (previous - height).abs() <= 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, &slot, Some(height));
+    true
+}
+
+pub fn viewport(id: &str, fallback: f32) -> f32 {
+    control::optional_f32_state(id, "viewport", None, None).unwrap_or(fallback)
+}
+
+pub fn store_viewport(id: &str, height: f32) -> bool {
+    let previous = control::optional_f32_state(id, "viewport", None, None);
+    if previous.is_some_and(|previous| (previous - height).abs() <= 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, "viewport", Some(height));
+    true
+}
+
+/// Row the view is held at and the offset into it, or `None` while it
+/// follows the newest message.
+pub fn anchor(id: &str) -> Option<(String, f32)> {
+    let row = control::optional_text_state(id, "anchor", None, None)?;
+    Some((row, control::f32_state(id, "anchor-offset", None, 0.0)))
+}
+
+/// Holds the view at a row, or lets it follow the bottom again. Returns
+/// whether the row changed; moving within a row does not need a new frame.
+pub fn set_anchor(id: &str, value: Option<(String, f32)>) -> bool {
+    let previous = control::optional_text_state(id, "anchor", None, None);
+    let changed = previous.as_deref() != value.as_ref().map(|(row, _)| row.as_str());
+    if let Some((_, offset)) = value.as_ref() {
+        control::set_f32_state(id, "anchor-offset", *offset);
+    }
+    control::set_optional_text_state(id, "anchor", value.map(|(row, _)| row));
+    changed
+}
+
+pub fn seen(id: &str) -> Option<String> {
+    control::optional_text_state(id, "seen", None, None)
+}
+
+pub fn set_seen(id: &str, message: Option<String>) -> bool {
+    if seen(id) == message {
+        return false;
+    }
+    control::set_optional_text_state(id, "seen", message);
+    true
+}
@@ -11,13 +11,16 @@ use crate::contracts::{FieldLike, MotionAware, Radiused, Sized, Varianted};
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
 use crate::style::{FieldLayout, GroupOrientation, Radius, Size, Variant};
+use crate::theme::SemanticRadiusToken;
 
 use super::Stack;
 use super::control;
 use super::selection_state;
 use super::toggle::{ToggleConfig, wire_toggle_handlers};
 use super::transition::TransitionExt;
-use super::utils::{apply_radius, resolve_hsla};
+use super::utils::{
+    apply_focus_ring, apply_hit_target, apply_radius, resolve_hsla, resolve_radius,
+};
 
 type CheckboxChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
 type CheckboxGroupChangeHandler = Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut gpui::App)>;
@@ -210,6 +213,12 @@ impl RenderOnce for Checkbox {
             .items_center()
             .justify_center();
         control = apply_radius(&self.theme, control, self.radius);
+        control = apply_focus_ring(
+            &self.theme,
+            control,
+            is_focused && !self.disabled,
+            resolve_radius(&self.theme, SemanticRadiusToken::from(self.radius)),
+        );
         if !self.disabled {
             let hover_border = resolve_hsla(&self.theme, tokens.border_hover);
             control = control.hover(move |style| style.border_color(hover_border));
@@ -315,10 +324,16 @@ impl RenderOnce for Checkbox {
         if self.disabled {
             row = row.cursor_default().opacity(0.55);
         } else {
+            row = apply_hit_target(
+                row,
+                px(size),
+                crate::provider::CalmProvider::min_hit_target(_cx),
+            );
             row = wire_toggle_handlers(
                 row,
                 ToggleConfig {
                     id: self.id.clone(),
+                    component: "checkbox",
                     checked,
                     controlled: is_controlled,
                     allow_uncheck: true,
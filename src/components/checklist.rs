@@ -0,0 +1,313 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, FontWeight, InteractiveElement, IntoElement, ParentElement, RenderOnce,
+    SharedString, Styled, Window, div,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
+use crate::motion::MotionConfig;
+
+use super::Stack;
+use super::checklist_state::{self, ChecklistSummary};
+use super::icon::Icon;
+use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
+use super::progress::{Progress, ProgressSection};
+use super::utils::resolve_hsla;
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+type ToggleHandler = Rc<dyn Fn(SharedString, bool, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ChecklistStatus {
+    #[default]
+    Pending,
+    Active,
+    Done,
+    Error,
+}
+
+impl ChecklistStatus {
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Pending => "circle",
+            Self::Active => "circle-dot",
+            Self::Done => "circle-check",
+            Self::Error => "circle-x",
+        }
+    }
+}
+
+pub struct ChecklistStep {
+    pub value: SharedString,
+    pub label: Option<SharedString>,
+    pub description: Option<SharedString>,
+    pub status: ChecklistStatus,
+    details: Option<SlotRenderer>,
+}
+
+impl ChecklistStep {
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: None,
+            description: None,
+            status: ChecklistStatus::Pending,
+            details: None,
+        }
+    }
+
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    pub fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    pub fn status(mut self, value: ChecklistStatus) -> Self {
+        self.status = value;
+        self
+    }
+
+    pub fn details(mut self, content: impl IntoElement + 'static) -> Self {
+        self.details = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+}
+
+#[derive(IntoElement)]
+pub struct Checklist {
+    pub(crate) id: ComponentId,
+    title: Option<SharedString>,
+    steps: Vec<ChecklistStep>,
+    show_summary: bool,
+    collapsible: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_toggle: Option<ToggleHandler>,
+}
+
+impl Checklist {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            title: None,
+            steps: Vec::new(),
+            show_summary: true,
+            collapsible: true,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_toggle: None,
+        }
+    }
+
+    pub fn title(mut self, value: impl Into<SharedString>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    pub fn step(mut self, step: ChecklistStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn steps(mut self, steps: impl IntoIterator<Item = ChecklistStep>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    pub fn show_summary(mut self, value: bool) -> Self {
+        self.show_summary = value;
+        self
+    }
+
+    pub fn collapsible(mut self, value: bool) -> Self {
+        self.collapsible = value;
+        self
+    }
+
+    pub fn on_toggle(
+        mut self,
+        handler: impl Fn(SharedString, bool, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn summary(&self) -> ChecklistSummary {
+        ChecklistSummary::from_statuses(self.steps.iter().map(|step| step.status))
+    }
+
+    fn render_summary(&self, summary: ChecklistSummary) -> Option<AnyElement> {
+        if self.title.is_none() && !self.show_summary {
+            return None;
+        }
+        let tokens = &self.theme.components.checklist;
+        let title = self.title.clone().map(|title| {
+            div()
+                .flex_1()
+                .min_w_0()
+                .truncate()
+                .text_size(tokens.label_size)
+                .font_weight(FontWeight::SEMIBOLD)
+                .text_color(resolve_hsla(&self.theme, tokens.label))
+                .child(title)
+        });
+        let text = format!(
+            "{}/{} · {:.0}%",
+            summary.done,
+            summary.total,
+            summary.percent()
+        );
+        let counts = self.show_summary.then(|| {
+            div()
+                .flex_none()
+                .text_size(tokens.summary_size)
+                .text_color(resolve_hsla(&self.theme, tokens.summary))
+                .child(text)
+        });
+        let total = summary.total.max(1) as f32;
+        let sections = [
+            ProgressSection::new(summary.done as f32 / total * 100.0)
+                .color(resolve_hsla(&self.theme, tokens.done)),
+            ProgressSection::new(summary.failed as f32 / total * 100.0)
+                .color(resolve_hsla(&self.theme, tokens.error)),
+        ];
+        let bar = self.show_summary.then(|| {
+            self.id
+                .ctx()
+                .child("progress", Progress::new())
+                .sections(sections)
+        });
+        let heading = Stack::horizontal()
+            .w_full()
+            .items_center()
+            .gap(tokens.item_gap)
+            .children(title)
+            .children(counts);
+        let header = Stack::vertical().w_full().gap(tokens.item_gap);
+        Some(header.child(heading).children(bar).into_any_element())
+    }
+
+    fn render_step(&self, step: ChecklistStep) -> AnyElement {
+        let tokens = &self.theme.components.checklist;
+        let status_color = match step.status {
+            ChecklistStatus::Pending => tokens.pending,
+            ChecklistStatus::Active => tokens.active,
+            ChecklistStatus::Done => tokens.done,
+            ChecklistStatus::Error => tokens.error,
+        };
+        let value = step.value.clone();
+        let has_details = step.details.is_some();
+        let attention = matches!(
+            step.status,
+            ChecklistStatus::Active | ChecklistStatus::Error
+        );
+        let expanded = !self.collapsible || checklist_state::expanded(&self.id, &value, attention);
+
+        let icon = self
+            .id
+            .ctx()
+            .child_index("status", value.to_string(), Icon::named(step.status.icon()))
+            .size(f32::from(tokens.icon_size))
+            .color(resolve_hsla(&self.theme, status_color));
+        let weight = if step.status == ChecklistStatus::Active {
+            FontWeight::SEMIBOLD
+        } else {
+            FontWeight::NORMAL
+        };
+        let label = div()
+            .text_size(tokens.label_size)
+            .font_weight(weight)
+            .text_color(resolve_hsla(&self.theme, tokens.label))
+            .child(step.label.clone().unwrap_or_else(|| value.clone()));
+        let description = step.description.clone().map(|description| {
+            div()
+                .text_size(tokens.description_size)
+                .text_color(resolve_hsla(&self.theme, tokens.description))
+                .child(description)
+        });
+        let text = Stack::vertical().flex_1().min_w_0().child(label);
+        let chevron = (has_details && self.collapsible).then(|| {
+            let name = if expanded {
+                "chevron-down"
+            } else {
+                "chevron-right"
+            };
+            self.id
+                .ctx()
+                .child_index("chevron", value.to_string(), Icon::named(name))
+                .size(f32::from(tokens.icon_size))
+                .color(resolve_hsla(&self.theme, tokens.description))
+        });
+
+        let row_id = self.id.slot_index("step", value.to_string());
+        let mut row = div()
+            .id(row_id.clone())
+            .flex()
+            .items_start()
+            .gap(tokens.item_gap)
+            .child(icon);
+        row = row.child(text.children(description)).children(chevron);
+        if has_details && self.collapsible {
+            let checklist_id = self.id.clone();
+            let on_toggle = self.on_toggle.clone();
+            let activate: ActivateHandler =
+                Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
+                    let next = checklist_state::toggle(&checklist_id, &value, attention);
+                    let event = InteractionEvent::new(
+                        "checklist",
+                        &checklist_id,
+                        InteractionAction::Toggle,
+                    );
+                    interaction::emit(event.value(value.clone()), cx);
+                    if let Some(handler) = on_toggle.as_ref() {
+                        (handler)(value.clone(), next, window, cx);
+                    }
+                    window.refresh();
+                });
+            let adapter = PressAdapter::new(row_id).on_activate(Some(activate));
+            row = bind_press_adapter(row.cursor_pointer(), adapter);
+        }
+
+        let details = step
+            .details
+            .filter(|_| expanded)
+            .map(|details| div().pl(tokens.detail_indent).child(details()));
+        let item = Stack::vertical().w_full().gap(tokens.item_gap);
+        item.child(row).children(details).into_any_element()
+    }
+}
+
+impl MotionAware for Checklist {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for Checklist {
+    fn render(mut self, _window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let summary = self.summary();
+        let header = self.render_summary(summary);
+        let steps = std::mem::take(&mut self.steps);
+        let rows: Vec<_> = steps
+            .into_iter()
+            .map(|step| self.render_step(step))
+            .collect();
+        Stack::vertical()
+            .id(self.id.clone())
+            .w_full()
+            .gap(self.theme.components.checklist.gap)
+            .children(header)
+            .children(rows)
+            .with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
@@ -0,0 +1,49 @@
+use super::checklist::ChecklistStatus;
+use super::control;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ChecklistSummary {
+    pub total: usize,
+    pub done: usize,
+    pub failed: usize,
+    pub active: Option<usize>,
+}
+
+impl ChecklistSummary {
+    pub fn from_statuses(statuses: impl IntoIterator<Item = ChecklistStatus>) -> Self {
+        let mut summary = Self::default();
+        for (index, status) in statuses.into_iter().enumerate() {
+            summary.total += 1;
+            match status {
+                ChecklistStatus::Done => summary.done += 1,
+                ChecklistStatus::Error => summary.failed += 1,
+                ChecklistStatus::Active if summary.active.is_none() => {
+                    summary.active = Some(index);
+                }
+                _ => {}
+            }
+        }
+        summary
+    }
+
+    pub fn percent(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.done as f32 / self.total as f32 * 100.0
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.total > 0 && self.done == self.total
+    }
+}
+
+pub fn expanded(id: &str, value: &str, default: bool) -> bool {
+    control::bool_state(id, &format!("expanded-{value}"), None, default)
+}
+
+pub fn toggle(id: &str, value: &str, default: bool) -> bool {
+    let next = !expanded(id, value, default);
+    control::set_bool_state(id, &format!("expanded-{value}"), next);
+    next
+}
@@ -15,10 +15,13 @@ use crate::style::{FieldLayout, GroupOrientation, Radius, Size, Variant};
 use super::Stack;
 use super::control;
 use super::icon::Icon;
+use super::press_feedback::PressFeedback;
 use super::selection_state;
 use super::toggle::{ToggleConfig, wire_toggle_handlers};
 use super::transition::TransitionExt;
-use super::utils::{apply_radius, resolve_hsla, variant_text_weight};
+use super::utils::{
+    InteractionStyles, apply_interaction_styles, apply_radius, resolve_hsla, variant_text_weight,
+};
 
 type ChipChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
 type ChipGroupChangeHandler = Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut gpui::App)>;
@@ -202,10 +205,17 @@ impl RenderOnce for Chip {
         } else {
             let hover_border = resolve_hsla(&self.theme, tokens.border_hover);
             chip = chip.hover(move |style: gpui::StyleRefinement| style.border_color(hover_border));
+            let press_feedback = PressFeedback::resolve(&self.theme, _cx);
+            chip = apply_interaction_styles(
+                chip,
+                press_feedback.interaction_styles(InteractionStyles::new()),
+            );
+            chip = press_feedback.apply(&self.id, chip);
             chip = wire_toggle_handlers(
                 chip,
                 ToggleConfig {
                     id: self.id.clone(),
+                    component: "chip",
                     checked,
                     controlled: is_controlled,
                     allow_uncheck: true,
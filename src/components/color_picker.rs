@@ -0,0 +1,456 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, AppContext, EmptyView, Hsla, IntoElement, MouseButton, ParentElement, RenderOnce,
+    SharedString, Styled, Window, canvas, div, linear_color_stop, linear_gradient,
+};
+
+use crate::contracts::{Disableable, MotionAware};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+
+use super::Stack;
+use super::color_picker_state::{self, ColorSurface, Hsva};
+use super::input::TextInput;
+use super::interaction_adapter::{PressAdapter, bind_press_adapter};
+use super::utils::{quantized_stroke_px, resolve_hsla};
+
+type ColorChangeHandler = Rc<dyn Fn(Hsla, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone)]
+struct ColorDragState {
+    picker_id: String,
+    surface: ColorSurface,
+}
+
+#[derive(Clone)]
+struct ColorCommit {
+    id: ComponentId,
+    on_change: Option<ColorChangeHandler>,
+}
+
+impl ColorCommit {
+    fn apply(&self, hsva: Hsva, window: &mut Window, cx: &mut gpui::App) {
+        color_picker_state::store_hsva(&self.id, hsva);
+        color_picker_state::set_hex_draft(&self.id, None);
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(hsva.to_hsla(), window, cx);
+        }
+        window.refresh();
+    }
+}
+
+#[derive(IntoElement)]
+pub struct ColorPicker {
+    pub(crate) id: ComponentId,
+    value: Option<Hsla>,
+    default_value: Hsla,
+    with_alpha: bool,
+    show_hex: bool,
+    swatches: Option<Vec<Hsla>>,
+    swatch_shade: usize,
+    disabled: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<ColorChangeHandler>,
+}
+
+impl ColorPicker {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: None,
+            default_value: gpui::hsla(0.6, 0.8, 0.5, 1.0),
+            with_alpha: true,
+            show_hex: true,
+            swatches: None,
+            swatch_shade: 6,
+            disabled: false,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<Hsla>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn default_value(mut self, value: impl Into<Hsla>) -> Self {
+        self.default_value = value.into();
+        self
+    }
+
+    pub fn with_alpha(mut self, value: bool) -> Self {
+        self.with_alpha = value;
+        self
+    }
+
+    pub fn show_hex(mut self, value: bool) -> Self {
+        self.show_hex = value;
+        self
+    }
+
+    pub fn swatches(mut self, swatches: impl IntoIterator<Item = impl Into<Hsla>>) -> Self {
+        self.swatches = Some(swatches.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn swatch_shade(mut self, shade: usize) -> Self {
+        self.swatch_shade = shade.min(9);
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(Hsla, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn commit(&self) -> ColorCommit {
+        ColorCommit {
+            id: self.id.clone(),
+            on_change: self.on_change.clone(),
+        }
+    }
+
+    fn render_thumb(&self, color: Hsla, size: gpui::Pixels, window: &Window) -> gpui::Div {
+        div()
+            .absolute()
+            .size(size)
+            .ml(-size * 0.5)
+            .rounded_full()
+            .border(quantized_stroke_px(window, 2.0))
+            .border_color(resolve_hsla(
+                &self.theme,
+                self.theme.components.color_picker.thumb_border,
+            ))
+            .shadow_sm()
+            .bg(color)
+    }
+
+    fn render_surface(
+        &self,
+        surface: ColorSurface,
+        hsva: Hsva,
+        height: gpui::Pixels,
+        layers: Vec<AnyElement>,
+    ) -> AnyElement {
+        let picker_id = self.id.to_string();
+        let measure = canvas(
+            {
+                let picker_id = picker_id.clone();
+                move |bounds, _, _| {
+                    color_picker_state::store_frame(
+                        &picker_id,
+                        surface,
+                        (f32::from(bounds.origin.x), f32::from(bounds.origin.y)),
+                        (f32::from(bounds.size.width), f32::from(bounds.size.height)),
+                    );
+                }
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let frame = div()
+            .id(self.id.slot(surface.slot()))
+            .relative()
+            .w_full()
+            .h(height)
+            .rounded_sm()
+            .child(measure)
+            .children(layers);
+        if self.disabled {
+            return frame.into_any_element();
+        }
+
+        let commit = self.commit();
+        frame
+            .cursor_pointer()
+            .on_mouse_down(MouseButton::Left, {
+                let picker_id = picker_id.clone();
+                let commit = commit.clone();
+                move |event, window, cx| {
+                    let (x, y) = color_picker_state::frame_point(
+                        &picker_id,
+                        surface,
+                        f32::from(event.position.x),
+                        f32::from(event.position.y),
+                    );
+                    commit.apply(hsva.apply_pointer(surface, x, y), window, cx);
+                }
+            })
+            .on_drag(
+                ColorDragState {
+                    picker_id: picker_id.clone(),
+                    surface,
+                },
+                |_drag, _, _, cx| cx.new(|_| EmptyView),
+            )
+            .on_drag_move::<ColorDragState>(move |event, window, cx| {
+                let drag = event.drag(cx);
+                if drag.picker_id != picker_id || drag.surface != surface {
+                    return;
+                }
+                let bounds = event.bounds;
+                let x = (f32::from(event.event.position.x) - f32::from(bounds.origin.x))
+                    / f32::from(bounds.size.width).max(1.0);
+                let y = (f32::from(event.event.position.y) - f32::from(bounds.origin.y))
+                    / f32::from(bounds.size.height).max(1.0);
+                let current = color_picker_state::resolve_hsva(&picker_id, None, hsva.to_hsla());
+                commit.apply(current.apply_pointer(surface, x, y), window, cx);
+            })
+            .into_any_element()
+    }
+
+    fn render_area(&self, hsva: Hsva, window: &Window) -> AnyElement {
+        let tokens = &self.theme.components.color_picker;
+        let white = gpui::white();
+        let black = gpui::black();
+        let layers = vec![
+            div()
+                .absolute()
+                .size_full()
+                .rounded_sm()
+                .bg(linear_gradient(
+                    90.0,
+                    linear_color_stop(white, 0.0),
+                    linear_color_stop(white.opacity(0.0), 1.0),
+                ))
+                .into_any_element(),
+            div()
+                .absolute()
+                .size_full()
+                .rounded_sm()
+                .bg(linear_gradient(
+                    180.0,
+                    linear_color_stop(black.opacity(0.0), 0.0),
+                    linear_color_stop(black, 1.0),
+                ))
+                .into_any_element(),
+            self.render_thumb(
+                Hsva { alpha: 1.0, ..hsva }.to_hsla(),
+                tokens.thumb_size,
+                window,
+            )
+            .left(gpui::relative(hsva.saturation))
+            .top(gpui::relative(1.0 - hsva.value))
+            .mt(-tokens.thumb_size * 0.5)
+            .into_any_element(),
+        ];
+        div()
+            .w_full()
+            .rounded_sm()
+            .bg(hsva.pure_hue())
+            .child(self.render_surface(ColorSurface::Area, hsva, tokens.area_height, layers))
+            .into_any_element()
+    }
+
+    fn render_hue(&self, hsva: Hsva, window: &Window) -> AnyElement {
+        let tokens = &self.theme.components.color_picker;
+        let segments = Stack::horizontal()
+            .absolute()
+            .size_full()
+            .children((0..6).map(|index| {
+                let from = gpui::hsla(index as f32 / 6.0, 1.0, 0.5, 1.0);
+                let to = gpui::hsla((index + 1) as f32 / 6.0 % 1.0, 1.0, 0.5, 1.0);
+                div().flex_1().h_full().bg(linear_gradient(
+                    90.0,
+                    linear_color_stop(from, 0.0),
+                    linear_color_stop(to, 1.0),
+                ))
+            }));
+        let layers = vec![
+            segments.into_any_element(),
+            self.render_thumb(hsva.pure_hue(), tokens.slider_height, window)
+                .top_0()
+                .left(gpui::relative(hsva.hue))
+                .into_any_element(),
+        ];
+        self.render_surface(ColorSurface::Hue, hsva, tokens.slider_height, layers)
+    }
+
+    fn render_alpha(&self, hsva: Hsva, window: &Window) -> AnyElement {
+        let tokens = &self.theme.components.color_picker;
+        let opaque = Hsva { alpha: 1.0, ..hsva }.to_hsla();
+        let layers = vec![
+            div()
+                .absolute()
+                .size_full()
+                .rounded_sm()
+                .bg(resolve_hsla(&self.theme, tokens.checker))
+                .into_any_element(),
+            div()
+                .absolute()
+                .size_full()
+                .rounded_sm()
+                .bg(linear_gradient(
+                    90.0,
+                    linear_color_stop(opaque.opacity(0.0), 0.0),
+                    linear_color_stop(opaque, 1.0),
+                ))
+                .into_any_element(),
+            self.render_thumb(hsva.to_hsla(), tokens.slider_height, window)
+                .top_0()
+                .left(gpui::relative(hsva.alpha))
+                .into_any_element(),
+        ];
+        self.render_surface(ColorSurface::Alpha, hsva, tokens.slider_height, layers)
+    }
+
+    fn render_hex(&self, hsva: Hsva, window: &Window) -> AnyElement {
+        let tokens = &self.theme.components.color_picker;
+        let color = hsva.to_hsla();
+        let text = color_picker_state::hex_draft(&self.id)
+            .unwrap_or_else(|| color_picker_state::format_hex(color, self.with_alpha));
+        let id = self.id.clone();
+        let commit = self.commit();
+        let with_alpha = self.with_alpha;
+        let input = self
+            .id
+            .ctx()
+            .child("hex", TextInput::new())
+            .value(SharedString::from(text))
+            .max_length(9)
+            .disabled(self.disabled)
+            .on_change(move |next: SharedString, window, cx| {
+                match color_picker_state::parse_hex(&next) {
+                    Some(parsed) => {
+                        let mut next_hsva = Hsva::from_hsla(parsed);
+                        if !with_alpha {
+                            next_hsva.alpha = 1.0;
+                        }
+                        commit.apply(next_hsva, window, cx);
+                        color_picker_state::set_hex_draft(&id, Some(next.to_string()));
+                    }
+                    None => {
+                        color_picker_state::set_hex_draft(&id, Some(next.to_string()));
+                        window.refresh();
+                    }
+                }
+            });
+        Stack::horizontal()
+            .gap(tokens.swatch_gap)
+            .items_center()
+            .child(
+                div()
+                    .flex_none()
+                    .size(tokens.preview_size)
+                    .rounded_sm()
+                    .border(quantized_stroke_px(window, 1.0))
+                    .border_color(resolve_hsla(&self.theme, tokens.swatch_border))
+                    .bg(color),
+            )
+            .child(div().flex_1().min_w_0().child(input))
+            .into_any_element()
+    }
+
+    fn render_swatches(&self, hsva: Hsva, window: &Window) -> AnyElement {
+        let tokens = &self.theme.components.color_picker;
+        let swatches = self
+            .swatches
+            .clone()
+            .unwrap_or_else(|| color_picker_state::palette_swatches(self.swatch_shade));
+        let current = hsva.to_hsla();
+        let with_alpha = self.with_alpha;
+        div()
+            .flex()
+            .flex_wrap()
+            .gap(tokens.swatch_gap)
+            .children(swatches.into_iter().enumerate().map(|(index, color)| {
+                let selected = color_picker_state::same_color(
+                    color,
+                    Hsla {
+                        a: if with_alpha { current.a } else { 1.0 },
+                        ..current
+                    },
+                );
+                let swatch_id = self.id.slot_index("swatch", index.to_string());
+                let swatch = div()
+                    .id(swatch_id.clone())
+                    .size(tokens.swatch_size)
+                    .rounded_sm()
+                    .border(quantized_stroke_px(
+                        window,
+                        if selected { 2.0 } else { 1.0 },
+                    ))
+                    .border_color(resolve_hsla(
+                        &self.theme,
+                        if selected {
+                            tokens.swatch_selected_border
+                        } else {
+                            tokens.swatch_border
+                        },
+                    ))
+                    .bg(color);
+                if self.disabled {
+                    return swatch.into_any_element();
+                }
+                let commit = self.commit();
+                bind_press_adapter(
+                    swatch.cursor_pointer(),
+                    PressAdapter::new(swatch_id).on_activate(Some(Rc::new(
+                        move |window: &mut Window, cx: &mut gpui::App| {
+                            commit.apply(Hsva::from_hsla(color), window, cx);
+                        },
+                    ))),
+                )
+                .into_any_element()
+            }))
+            .into_any_element()
+    }
+}
+
+crate::impl_disableable!(ColorPicker, |this, value| this.disabled = value);
+
+impl MotionAware for ColorPicker {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for ColorPicker {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.color_picker.clone();
+        let mut hsva = color_picker_state::resolve_hsva(&self.id, self.value, self.default_value);
+        if !self.with_alpha {
+            hsva.alpha = 1.0;
+        }
+
+        let mut root = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .w_full()
+            .gap(tokens.gap)
+            .p(tokens.padding)
+            .rounded_md()
+            .border(quantized_stroke_px(window, 1.0))
+            .border_color(resolve_hsla(&self.theme, tokens.border))
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .child(self.render_area(hsva, window))
+            .child(self.render_hue(hsva, window));
+        if self.with_alpha {
+            root = root.child(self.render_alpha(hsva, window));
+        }
+        if self.show_hex {
+            root = root.child(self.render_hex(hsva, window));
+        }
+        root = root.child(self.render_swatches(hsva, window));
+        if self.disabled {
+            root = root.opacity(0.55);
+        }
+        root.with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
@@ -0,0 +1,176 @@
+use gpui::{Hsla, Rgba, hsla};
+
+use super::control;
+use crate::tokens::PaletteCatalog;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ColorSurface {
+    Area,
+    Hue,
+    Alpha,
+}
+
+impl ColorSurface {
+    pub const fn slot(self) -> &'static str {
+        match self {
+            Self::Area => "area",
+            Self::Hue => "hue",
+            Self::Alpha => "alpha",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsva {
+    pub hue: f32,
+    pub saturation: f32,
+    pub value: f32,
+    pub alpha: f32,
+}
+
+impl Hsva {
+    pub fn from_hsla(color: Hsla) -> Self {
+        let value = color.l + color.s * color.l.min(1.0 - color.l);
+        let saturation = if value <= f32::EPSILON {
+            0.0
+        } else {
+            2.0 * (1.0 - color.l / value)
+        };
+        Self {
+            hue: color.h.rem_euclid(1.0),
+            saturation: clamp_unit(saturation),
+            value: clamp_unit(value),
+            alpha: clamp_unit(color.a),
+        }
+    }
+
+    pub fn to_hsla(self) -> Hsla {
+        let lightness = self.value * (1.0 - self.saturation / 2.0);
+        let saturation = if lightness <= f32::EPSILON || lightness >= 1.0 - f32::EPSILON {
+            0.0
+        } else {
+            (self.value - lightness) / lightness.min(1.0 - lightness)
+        };
+        hsla(self.hue, clamp_unit(saturation), lightness, self.alpha)
+    }
+
+    pub fn pure_hue(self) -> Hsla {
+        hsla(self.hue, 1.0, 0.5, 1.0)
+    }
+
+    pub fn apply_pointer(self, surface: ColorSurface, x: f32, y: f32) -> Self {
+        match surface {
+            ColorSurface::Area => Self {
+                saturation: clamp_unit(x),
+                value: clamp_unit(1.0 - y),
+                ..self
+            },
+            ColorSurface::Hue => Self {
+                hue: clamp_unit(x).min(0.9999),
+                ..self
+            },
+            ColorSurface::Alpha => Self {
+                alpha: clamp_unit(x),
+                ..self
+            },
+        }
+    }
+}
+
+pub fn clamp_unit(value: f32) -> f32 {
+    if value.is_finite() {
+        value.clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+pub fn parse_hex(text: &str) -> Option<Hsla> {
+    let digits = text.trim().trim_start_matches('#');
+    if !matches!(digits.len(), 3 | 4 | 6 | 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    Rgba::try_from(format!("#{digits}").as_str())
+        .ok()
+        .map(Hsla::from)
+}
+
+pub fn format_hex(color: Hsla, with_alpha: bool) -> String {
+    let rgba = color.to_rgb();
+    let channel = |value: f32| (clamp_unit(value) * 255.0).round() as u8;
+    let mut hex = format!(
+        "#{:02X}{:02X}{:02X}",
+        channel(rgba.r),
+        channel(rgba.g),
+        channel(rgba.b)
+    );
+    if with_alpha {
+        hex.push_str(&format!("{:02X}", channel(rgba.a)));
+    }
+    hex
+}
+
+pub fn same_color(a: Hsla, b: Hsla) -> bool {
+    format_hex(a, true) == format_hex(b, true)
+}
+
+pub fn palette_swatches(shade: usize) -> Vec<Hsla> {
+    PaletteCatalog::store()
+        .values()
+        .filter_map(|scale| Rgba::try_from(scale[shade.min(9)]).ok())
+        .map(Hsla::from)
+        .collect()
+}
+
+pub fn resolve_hsva(id: &str, controlled: Option<Hsla>, default: Hsla) -> Hsva {
+    let stored = stored_hsva(id);
+    match (controlled, stored) {
+        (Some(color), Some(stored)) if same_color(stored.to_hsla(), color) => stored,
+        (Some(color), _) => Hsva::from_hsla(color),
+        (None, Some(stored)) => stored,
+        (None, None) => Hsva::from_hsla(default),
+    }
+}
+
+fn stored_hsva(id: &str) -> Option<Hsva> {
+    Some(Hsva {
+        hue: control::optional_f32_state(id, "hue", None, None)?,
+        saturation: control::optional_f32_state(id, "saturation", None, None)?,
+        value: control::optional_f32_state(id, "brightness", None, None)?,
+        alpha: control::optional_f32_state(id, "alpha", None, None)?,
+    })
+}
+
+pub fn store_hsva(id: &str, hsva: Hsva) {
+    control::set_optional_f32_state(id, "hue", Some(hsva.hue));
+    control::set_optional_f32_state(id, "saturation", Some(hsva.saturation));
+    control::set_optional_f32_state(id, "brightness", Some(hsva.value));
+    control::set_optional_f32_state(id, "alpha", Some(hsva.alpha));
+}
+
+pub fn hex_draft(id: &str) -> Option<String> {
+    control::optional_text_state(id, "hex-draft", None, None)
+}
+
+pub fn set_hex_draft(id: &str, value: Option<String>) {
+    control::set_optional_text_state(id, "hex-draft", value);
+}
+
+pub fn store_frame(id: &str, surface: ColorSurface, origin: (f32, f32), size: (f32, f32)) {
+    let slot = surface.slot();
+    control::set_f32_state(id, &format!("{slot}-x"), origin.0);
+    control::set_f32_state(id, &format!("{slot}-y"), origin.1);
+    control::set_f32_state(id, &format!("{slot}-w"), size.0);
+    control::set_f32_state(id, &format!("{slot}-h"), size.1);
+}
+
+pub fn frame_point(id: &str, surface: ColorSurface, x: f32, y: f32) -> (f32, f32) {
+    let slot = surface.slot();
+    let read = |suffix: &str, default: f32| {
+        control::f32_state(id, &format!("{slot}-{suffix}"), None, default)
+    };
+    (
+        clamp_unit((x - read("x", 0.0)) / read("w", 1.0).max(1.0)),
+        clamp_unit((y - read("y", 0.0)) / read("h", 1.0).max(1.0)),
+    )
+}
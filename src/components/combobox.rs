@@ -0,0 +1,684 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::prelude::FluentBuilder;
+use gpui::{
+    AnyElement, FocusHandle, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window,
+    canvas, div, px,
+};
+
+use crate::contracts::{
+    Disableable as _, FieldLike, MotionAware, Radiused as _, Sized as _, Themable, Varianted as _,
+};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::resource::Resource;
+use crate::style::{FieldLayout, Radius, Size, Variant};
+
+use super::TextInput;
+use super::combobox_state::{self, ComboboxRow};
+use super::composition;
+use super::control;
+use super::interaction_adapter::{PressAdapter, bind_press_adapter};
+use super::popup::{PopupPlacement, anchored_host};
+use super::popup_state::{self, PopupStateInput, PopupStateValue};
+use super::select::SelectOption;
+use super::select_state;
+use super::utils::{InteractionStyles, apply_interaction_styles, interaction_style, resolve_hsla};
+
+type TextHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+type OptionRenderer = Rc<dyn Fn(&SelectOption, bool) -> AnyElement>;
+
+#[derive(Clone)]
+enum ComboboxChoice {
+    Option {
+        value: SharedString,
+        text: SharedString,
+    },
+    Create(SharedString),
+}
+
+#[derive(Clone)]
+struct ComboboxCommit {
+    id: ComponentId,
+    value_controlled: bool,
+    debounce_ms: u64,
+    on_change: Option<TextHandler>,
+    on_option_select: Option<TextHandler>,
+    on_create: Option<TextHandler>,
+    on_search: Option<TextHandler>,
+}
+
+impl ComboboxCommit {
+    fn set_opened(&self, opened: bool, window: &mut Window) {
+        if !opened {
+            combobox_state::set_highlighted(&self.id, None);
+        }
+        if popup_state::apply_opened(&self.id, false, opened) {
+            window.refresh();
+        }
+    }
+
+    fn choose(&self, choice: ComboboxChoice, window: &mut Window, cx: &mut gpui::App) {
+        let text = match &choice {
+            ComboboxChoice::Option { text, .. } => text.clone(),
+            ComboboxChoice::Create(query) => query.clone(),
+        };
+        if !self.value_controlled {
+            combobox_state::set_text(&self.id, text.to_string());
+        }
+        combobox_state::set_filter_query(&self.id, text.to_string());
+        self.set_opened(false, window);
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(text, window, cx);
+        }
+        let (handler, payload) = match choice {
+            ComboboxChoice::Option { value, .. } => (self.on_option_select.as_ref(), value),
+            ComboboxChoice::Create(query) => (self.on_create.as_ref(), query),
+        };
+        if let Some(handler) = handler {
+            (handler)(payload, window, cx);
+        }
+        window.refresh();
+    }
+
+    fn edit(&self, text: SharedString, window: &mut Window, cx: &mut gpui::App) {
+        if !self.value_controlled {
+            combobox_state::set_text(&self.id, text.to_string());
+        }
+        combobox_state::set_highlighted(&self.id, None);
+        popup_state::apply_opened(&self.id, false, true);
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(text.clone(), window, cx);
+        }
+
+        let revision = combobox_state::bump_revision(&self.id);
+        if self.debounce_ms == 0 {
+            self.search(text, window, cx);
+            window.refresh();
+            return;
+        }
+        window.refresh();
+        let window_handle = window.window_handle();
+        let commit = self.clone();
+        let delay = Duration::from_millis(self.debounce_ms);
+        cx.spawn(async move |cx| {
+            cx.background_executor().timer(delay).await;
+            let _ = window_handle.update(cx, |_, window, cx| {
+                if combobox_state::revision(&commit.id) == revision {
+                    commit.search(text, window, cx);
+                    window.refresh();
+                }
+            });
+        })
+        .detach();
+    }
+
+    fn search(&self, text: SharedString, window: &mut Window, cx: &mut gpui::App) {
+        combobox_state::set_filter_query(&self.id, text.to_string());
+        if let Some(handler) = self.on_search.as_ref() {
+            (handler)(text, window, cx);
+        }
+    }
+}
+
+#[derive(IntoElement)]
+pub struct Combobox {
+    pub(crate) id: ComponentId,
+    value: Option<SharedString>,
+    default_value: SharedString,
+    options: Vec<SelectOption>,
+    placeholder: Option<SharedString>,
+    label: Option<SharedString>,
+    description: Option<SharedString>,
+    error: Option<SharedString>,
+    required: bool,
+    layout: FieldLayout,
+    filter: bool,
+    limit: Option<usize>,
+    debounce_ms: u64,
+    loading: bool,
+    creatable: bool,
+    nothing_found: Option<SharedString>,
+    disabled: bool,
+    size: Size,
+    radius: Radius,
+    variant: Variant,
+    focus_handle: Option<FocusHandle>,
+    option_renderer: Option<OptionRenderer>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<TextHandler>,
+    on_option_select: Option<TextHandler>,
+    on_create: Option<TextHandler>,
+    on_search: Option<TextHandler>,
+    on_submit: Option<TextHandler>,
+}
+
+impl Combobox {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: None,
+            default_value: SharedString::default(),
+            options: Vec::new(),
+            placeholder: None,
+            label: None,
+            description: None,
+            error: None,
+            required: false,
+            layout: FieldLayout::Vertical,
+            filter: true,
+            limit: None,
+            debounce_ms: 150,
+            loading: false,
+            creatable: false,
+            nothing_found: None,
+            disabled: false,
+            size: Size::Md,
+            radius: Radius::Sm,
+            variant: Variant::Default,
+            focus_handle: None,
+            option_renderer: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+            on_option_select: None,
+            on_create: None,
+            on_search: None,
+            on_submit: None,
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_value = value.into();
+        self
+    }
+
+    pub fn option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn options(mut self, options: impl IntoIterator<Item = SelectOption>) -> Self {
+        self.options.extend(options);
+        self
+    }
+
+    pub fn options_resource(mut self, resource: &Resource<Vec<SelectOption>>) -> Self {
+        self.options.extend(resource.value().unwrap_or_default());
+        self.loading = resource.is_loading();
+        if let Some(error) = resource.error() {
+            self.error = Some(error);
+        }
+        self
+    }
+
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(value.into());
+        self
+    }
+
+    pub fn filter(mut self, value: bool) -> Self {
+        self.filter = value;
+        self
+    }
+
+    pub fn limit(mut self, value: usize) -> Self {
+        self.limit = Some(value.max(1));
+        self
+    }
+
+    pub fn debounce_ms(mut self, value: u64) -> Self {
+        self.debounce_ms = value;
+        self
+    }
+
+    pub fn loading(mut self, value: bool) -> Self {
+        self.loading = value;
+        self
+    }
+
+    pub fn creatable(mut self, value: bool) -> Self {
+        self.creatable = value;
+        self
+    }
+
+    pub fn nothing_found(mut self, value: impl Into<SharedString>) -> Self {
+        self.nothing_found = Some(value.into());
+        self
+    }
+
+    pub fn focus_handle(mut self, value: FocusHandle) -> Self {
+        self.focus_handle = Some(value);
+        self
+    }
+
+    pub fn render_option(
+        mut self,
+        renderer: impl Fn(&SelectOption, bool) -> AnyElement + 'static,
+    ) -> Self {
+        self.option_renderer = Some(Rc::new(renderer));
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_option_select(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_option_select = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_create(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_create = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_search(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_search = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_submit(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_submit = Some(Rc::new(handler));
+        self
+    }
+
+    fn commit(&self) -> ComboboxCommit {
+        ComboboxCommit {
+            id: self.id.clone(),
+            value_controlled: self.value.is_some(),
+            debounce_ms: self.debounce_ms,
+            on_change: self.on_change.clone(),
+            on_option_select: self.on_option_select.clone(),
+            on_create: self.on_create.clone(),
+            on_search: self.on_search.clone(),
+        }
+    }
+
+    fn option_text(option: &SelectOption) -> SharedString {
+        option.label.clone().unwrap_or_else(|| option.value.clone())
+    }
+
+    fn resolve_rows(&self, text: &str) -> Vec<ComboboxRow> {
+        let labels = self
+            .options
+            .iter()
+            .map(|option| Self::option_text(option).to_string())
+            .collect::<Vec<_>>();
+        let query = if self.debounce_ms == 0 {
+            text.to_string()
+        } else {
+            combobox_state::filter_query(&self.id, text)
+        };
+        let filtered = if self.filter {
+            combobox_state::filter_options(&labels, &query, self.limit)
+        } else {
+            (0..labels.len().min(self.limit.unwrap_or(usize::MAX))).collect()
+        };
+        let create = self.creatable && combobox_state::create_candidate(&labels, text).is_some();
+        combobox_state::rows(&filtered, create)
+    }
+
+    fn choice_for(&self, row: ComboboxRow, text: &str) -> ComboboxChoice {
+        match row {
+            ComboboxRow::Option(index) => {
+                let option = &self.options[index];
+                ComboboxChoice::Option {
+                    value: option.value.clone(),
+                    text: Self::option_text(option),
+                }
+            }
+            ComboboxRow::Create => {
+                ComboboxChoice::Create(SharedString::from(text.trim().to_string()))
+            }
+        }
+    }
+
+    fn render_row(
+        &self,
+        row: ComboboxRow,
+        index: usize,
+        highlighted: bool,
+        text: &str,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.select;
+        let row_id = self.id.slot_index("row", index.to_string());
+        let hover_bg = resolve_hsla(&self.theme, tokens.option_hover_bg);
+        let (content, disabled) = match row {
+            ComboboxRow::Option(option_index) => {
+                let option = &self.options[option_index];
+                let content = match self.option_renderer.as_ref() {
+                    Some(renderer) => renderer(option, highlighted),
+                    None => div()
+                        .min_w_0()
+                        .truncate()
+                        .child(Self::option_text(option))
+                        .into_any_element(),
+                };
+                (content, option.disabled)
+            }
+            ComboboxRow::Create => (
+                div()
+                    .min_w_0()
+                    .truncate()
+                    .child(format!("+ Create \"{}\"", text.trim()))
+                    .into_any_element(),
+                false,
+            ),
+        };
+
+        let mut node = div()
+            .id(row_id.clone())
+            .px(tokens.option_padding_x)
+            .py(tokens.option_padding_y)
+            .rounded_sm()
+            .text_size(tokens.option_size)
+            .text_color(resolve_hsla(&self.theme, tokens.option_fg))
+            .when(highlighted, |node| node.bg(hover_bg))
+            .child(content);
+        if disabled {
+            return node.opacity(0.45).cursor_default().into_any_element();
+        }
+
+        let commit = self.commit();
+        let choice = self.choice_for(row, text);
+        node = apply_interaction_styles(
+            node.cursor_pointer(),
+            InteractionStyles::new().hover(interaction_style(move |style| style.bg(hover_bg))),
+        );
+        bind_press_adapter(
+            node,
+            PressAdapter::new(row_id).on_activate(Some(Rc::new(
+                move |window: &mut Window, cx: &mut gpui::App| {
+                    commit.choose(choice.clone(), window, cx);
+                },
+            ))),
+        )
+        .into_any_element()
+    }
+
+    fn render_dropdown(
+        &self,
+        window: &Window,
+        rows: &[ComboboxRow],
+        highlighted: Option<usize>,
+        text: &str,
+    ) -> Option<AnyElement> {
+        let tokens = &self.theme.components.select;
+        let status = if self.loading {
+            Some(SharedString::from("Loading…"))
+        } else if rows.is_empty() {
+            self.nothing_found.clone()
+        } else {
+            None
+        };
+        if rows.is_empty() && status.is_none() {
+            return None;
+        }
+
+        let mut list =
+            div()
+                .flex()
+                .flex_col()
+                .gap(tokens.dropdown_gap)
+                .children(rows.iter().enumerate().map(|(index, row)| {
+                    self.render_row(*row, index, highlighted == Some(index), text)
+                }));
+        if let Some(status) = status {
+            list = list.child(
+                div()
+                    .px(tokens.option_padding_x)
+                    .py(tokens.option_padding_y)
+                    .text_size(tokens.option_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.placeholder))
+                    .child(status),
+            );
+        }
+
+        let commit = self.commit();
+        Some(
+            div()
+                .id(self.id.slot("dropdown"))
+                .w(px(select_state::dropdown_width_px(
+                    &self.id,
+                    f32::from(tokens.dropdown_width_fallback),
+                )))
+                .rounded_md()
+                .border(super::utils::quantized_stroke_px(window, 1.0))
+                .border_color(resolve_hsla(&self.theme, tokens.dropdown_border))
+                .bg(resolve_hsla(&self.theme, tokens.dropdown_bg))
+                .shadow_sm()
+                .max_h(tokens.dropdown_max_height)
+                .overflow_y_scroll()
+                .p(tokens.dropdown_padding)
+                .child(list)
+                .on_mouse_down_out(move |_, window: &mut Window, _cx: &mut gpui::App| {
+                    commit.set_opened(false, window);
+                })
+                .with_enter_transition(self.id.slot("dropdown-enter"), self.motion)
+                .into_any_element(),
+        )
+    }
+}
+
+impl FieldLike for Combobox {
+    fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    fn error(mut self, value: impl Into<SharedString>) -> Self {
+        self.error = Some(value.into());
+        self
+    }
+
+    fn required(mut self, value: bool) -> Self {
+        self.required = value;
+        self
+    }
+
+    fn layout(mut self, value: FieldLayout) -> Self {
+        self.layout = value;
+        self
+    }
+}
+
+crate::impl_variant_size_radius_via_methods!(Combobox, variant, size, radius);
+crate::impl_disableable!(Combobox, |this, value| this.disabled = value);
+
+impl MotionAware for Combobox {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for Combobox {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.select.clone();
+        let text = combobox_state::text(
+            &self.id,
+            self.value.as_ref().map(ToString::to_string),
+            self.default_value.to_string(),
+        );
+        let opened = !self.disabled
+            && PopupStateValue::resolve(PopupStateInput {
+                id: &self.id,
+                opened: None,
+                default_opened: false,
+                disabled: self.disabled,
+            })
+            .opened;
+        let rows = self.resolve_rows(&text);
+        let enabled = rows
+            .iter()
+            .map(|row| match row {
+                ComboboxRow::Option(index) => !self.options[*index].disabled,
+                ComboboxRow::Create => true,
+            })
+            .collect::<Vec<_>>();
+        let highlighted = combobox_state::highlighted(&self.id, rows.len());
+        let commit = self.commit();
+
+        let mut input = self
+            .id
+            .ctx()
+            .root(TextInput::new())
+            .value(text.clone())
+            .themed(|overrides| {
+                overrides
+                    .bg(tokens.bg)
+                    .fg(tokens.fg)
+                    .placeholder(tokens.placeholder)
+                    .border(tokens.border)
+                    .border_focus(tokens.border_focus)
+                    .border_error(tokens.border_error)
+                    .label(tokens.label)
+                    .label_size(tokens.label_size)
+                    .label_weight(tokens.label_weight)
+                    .description(tokens.description)
+                    .description_size(tokens.description_size)
+                    .error(tokens.error)
+                    .error_size(tokens.error_size)
+                    .sizes(tokens.sizes)
+            })
+            .required(self.required)
+            .layout(self.layout)
+            .disabled(self.disabled)
+            .with_variant(self.variant)
+            .with_size(self.size)
+            .with_radius(self.radius);
+        input = MotionAware::motion(input, self.motion).on_change({
+            let commit = commit.clone();
+            move |next: SharedString, window, cx| commit.edit(next, window, cx)
+        });
+        if let Some(placeholder) = self.placeholder.clone() {
+            input = input.placeholder(placeholder);
+        }
+        if let Some(label) = self.label.clone() {
+            input = input.label(label);
+        }
+        if let Some(description) = self.description.clone() {
+            input = input.description(description);
+        }
+        if let Some(error) = self.error.clone() {
+            input = input.error(error);
+        }
+        if let Some(focus_handle) = self.focus_handle.clone() {
+            input = input.focus_handle(focus_handle);
+        }
+        if let Some(handler) = self.on_submit.clone() {
+            input = input.on_submit(move |value, window, cx| (handler)(value, window, cx));
+        }
+
+        let id_for_width = self.id.clone();
+        let mut field = div()
+            .id(self.id.slot("field"))
+            .relative()
+            .w_full()
+            .child(input.render(window, cx).into_any_element())
+            .child(
+                canvas(
+                    move |bounds, _, _| {
+                        select_state::set_dropdown_width(
+                            &id_for_width,
+                            f32::from(bounds.size.width),
+                        );
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            );
+        if opened && let Some(dropdown) = self.render_dropdown(window, &rows, highlighted, &text) {
+            field = field.child(anchored_host(
+                &self.id,
+                "anchor-host",
+                PopupPlacement::Bottom.options(
+                    f32::from(tokens.dropdown_anchor_offset),
+                    self.theme.components.layout.popup_snap_margin,
+                ),
+                dropdown,
+                24,
+                true,
+            ));
+        }
+        if self.disabled {
+            return field.into_any_element();
+        }
+
+        let choices = rows
+            .iter()
+            .map(|row| self.choice_for(*row, &text))
+            .collect::<Vec<_>>();
+        let id = self.id.clone();
+        div()
+            .id(self.id.slot("keyboard-proxy"))
+            .w_full()
+            .on_key_down(move |event, window, cx| {
+                if !control::focused_state(&id, None, false)
+                    || !control::is_plain_keystroke(event)
+                    || composition::is_composing(&id)
+                {
+                    return;
+                }
+                match event.keystroke.key.as_str() {
+                    "down" | "up" => {
+                        let delta = if event.keystroke.key == "down" { 1 } else { -1 };
+                        combobox_state::set_highlighted(
+                            &id,
+                            combobox_state::move_highlight(highlighted, delta, &enabled),
+                        );
+                        commit.set_opened(true, window);
+                        window.refresh();
+                    }
+                    "enter" if opened => {
+                        let Some(choice) = highlighted.and_then(|index| choices.get(index)) else {
+                            return;
+                        };
+                        commit.choose(choice.clone(), window, cx);
+                    }
+                    "escape" if opened => commit.set_opened(false, window),
+                    _ => return,
+                }
+                cx.stop_propagation();
+                window.prevent_default();
+            })
+            .child(field)
+            .into_any_element()
+    }
+}
@@ -0,0 +1,102 @@
+use super::control;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ComboboxRow {
+    Option(usize),
+    Create,
+}
+
+pub fn filter_options(labels: &[String], query: &str, limit: Option<usize>) -> Vec<usize> {
+    let query = query.trim().to_lowercase();
+    let mut prefix = Vec::new();
+    let mut contains = Vec::new();
+    for (index, label) in labels.iter().enumerate() {
+        let label = label.to_lowercase();
+        if query.is_empty() || label.starts_with(&query) {
+            prefix.push(index);
+        } else if label.contains(&query) {
+            contains.push(index);
+        }
+    }
+    prefix.extend(contains);
+    if let Some(limit) = limit {
+        prefix.truncate(limit);
+    }
+    prefix
+}
+
+pub fn create_candidate(labels: &[String], query: &str) -> Option<String> {
+    let query = query.trim();
+    if query.is_empty()
+        || labels
+            .iter()
+            .any(|label| label.trim().eq_ignore_ascii_case(query))
+    {
+        return None;
+    }
+    Some(query.to_string())
+}
+
+pub fn rows(filtered: &[usize], create: bool) -> Vec<ComboboxRow> {
+    let mut rows = filtered
+        .iter()
+        .map(|index| ComboboxRow::Option(*index))
+        .collect::<Vec<_>>();
+    if create {
+        rows.push(ComboboxRow::Create);
+    }
+    rows
+}
+
+pub fn move_highlight(current: Option<usize>, delta: i32, enabled: &[bool]) -> Option<usize> {
+    let count = enabled.len();
+    if count == 0 || !enabled.iter().any(|enabled| *enabled) {
+        return None;
+    }
+    let mut index = match current {
+        Some(index) if index < count => index as i32,
+        _ if delta >= 0 => -1,
+        _ => count as i32,
+    };
+    loop {
+        index = (index + delta.signum()).rem_euclid(count as i32);
+        if enabled[index as usize] {
+            return Some(index as usize);
+        }
+    }
+}
+
+pub fn highlighted(id: &str, count: usize) -> Option<usize> {
+    control::optional_usize_state(id, "highlighted", None, None).filter(|index| *index < count)
+}
+
+pub fn set_highlighted(id: &str, value: Option<usize>) {
+    control::set_optional_usize_state(id, "highlighted", value);
+}
+
+pub fn text(id: &str, controlled: Option<String>, default: String) -> String {
+    control::text_state(id, "value", controlled, default)
+}
+
+pub fn set_text(id: &str, value: String) {
+    control::set_text_state(id, "value", value);
+}
+
+pub fn filter_query(id: &str, fallback: &str) -> String {
+    control::optional_text_state(id, "filter-query", None, None)
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+pub fn set_filter_query(id: &str, value: String) {
+    control::set_optional_text_state(id, "filter-query", Some(value));
+}
+
+pub fn bump_revision(id: &str) -> usize {
+    let next = revision(id).wrapping_add(1);
+    control::set_usize_state(id, "revision", next);
+    next
+}
+
+pub fn revision(id: &str) -> usize {
+    control::usize_state(id, "revision", None, 0)
+}
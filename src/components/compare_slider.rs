@@ -0,0 +1,345 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, AppContext, EmptyView, FocusHandle, IntoElement, MouseButton, ParentElement,
+    RenderOnce, Styled, Window, canvas, div, px,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::{Radius, Size};
+
+use super::compare_slider_state;
+use super::control;
+use super::utils::{apply_radius, quantized_stroke_px, resolve_hsla};
+
+type ChangeHandler = Rc<dyn Fn(f32, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone)]
+struct CompareSliderDragState {
+    slider_id: String,
+    controlled: bool,
+}
+
+#[derive(IntoElement)]
+pub struct CompareSlider {
+    pub(crate) id: ComponentId,
+    before: Option<AnyElement>,
+    after: Option<AnyElement>,
+    value: f32,
+    value_controlled: bool,
+    default_value: f32,
+    step: f32,
+    height_px: Option<f32>,
+    disabled: bool,
+    size: Size,
+    radius: Radius,
+    focus_handle: Option<FocusHandle>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<ChangeHandler>,
+}
+
+impl CompareSlider {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            before: None,
+            after: None,
+            value: 50.0,
+            value_controlled: false,
+            default_value: 50.0,
+            step: 1.0,
+            height_px: None,
+            disabled: false,
+            size: Size::Md,
+            radius: Radius::Sm,
+            focus_handle: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+        }
+    }
+
+    pub fn before(mut self, content: impl IntoElement + 'static) -> Self {
+        self.before = Some(content.into_any_element());
+        self
+    }
+
+    pub fn after(mut self, content: impl IntoElement + 'static) -> Self {
+        self.after = Some(content.into_any_element());
+        self
+    }
+
+    pub fn value(mut self, percent: f32) -> Self {
+        self.value = compare_slider_state::clamp_percent(percent);
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn default_value(mut self, percent: f32) -> Self {
+        self.default_value = compare_slider_state::clamp_percent(percent);
+        self
+    }
+
+    pub fn step(mut self, percent: f32) -> Self {
+        self.step = percent.max(0.1);
+        self
+    }
+
+    pub fn height(mut self, value: f32) -> Self {
+        self.height_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(f32, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_value(&self) -> f32 {
+        let controlled = self.value_controlled.then_some(self.value);
+        compare_slider_state::clamp_percent(control::f32_state(
+            &self.id,
+            "value",
+            controlled,
+            self.default_value,
+        ))
+    }
+
+    fn commit(
+        id: &str,
+        controlled: bool,
+        next: f32,
+        on_change: Option<&ChangeHandler>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        let previous = control::f32_state(id, "value", None, next);
+        if !controlled {
+            control::set_f32_state(id, "value", next);
+            window.refresh();
+        }
+        if (previous - next).abs() > f32::EPSILON
+            && let Some(handler) = on_change
+        {
+            (handler)(next, window, cx);
+        }
+    }
+}
+
+crate::impl_disableable!(CompareSlider, |this, value| this.disabled = value);
+crate::impl_sized_via_method!(CompareSlider, size);
+crate::impl_radiused_via_method!(CompareSlider, radius);
+
+impl MotionAware for CompareSlider {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for CompareSlider {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = &self.theme.components.slider;
+        let knob_size = tokens.sizes.for_size(self.size).thumb_size;
+        let percent = self.resolved_value();
+        let ratio = percent / compare_slider_state::MAX_PERCENT;
+        let is_controlled = self.value_controlled;
+        if is_controlled {
+            control::set_f32_state(&self.id, "value", percent);
+        }
+        let divider_color = resolve_hsla(&self.theme, tokens.thumb_border);
+        let stroke = quantized_stroke_px(window, 2.0);
+
+        let measure = canvas(
+            {
+                let id = self.id.clone();
+                move |bounds, window, _| {
+                    if compare_slider_state::store_frame(
+                        &id,
+                        f32::from(bounds.origin.x),
+                        f32::from(bounds.size.width),
+                    ) {
+                        window.refresh();
+                    }
+                }
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let mut after_layer = div().absolute().top_0().left_0().size_full();
+        if let Some(after) = self.after.take() {
+            after_layer = after_layer.child(after);
+        }
+
+        let mut before_content = div().absolute().top_0().left_0().h_full();
+        before_content = match compare_slider_state::stored_width(&self.id) {
+            Some(width) => before_content.w(px(width)),
+            None => before_content.w_full(),
+        };
+        if let Some(before) = self.before.take() {
+            before_content = before_content.child(before);
+        }
+        let before_layer = div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .h_full()
+            .w(gpui::relative(ratio))
+            .overflow_hidden()
+            .child(before_content);
+
+        let mut knob = div()
+            .w(knob_size)
+            .h(knob_size)
+            .border(quantized_stroke_px(window, 1.0))
+            .border_color(divider_color)
+            .bg(resolve_hsla(&self.theme, tokens.thumb_bg));
+        knob = apply_radius(&self.theme, knob, Radius::Pill);
+        let handle = div()
+            .absolute()
+            .top_0()
+            .h_full()
+            .left(gpui::relative(ratio))
+            .ml(-knob_size * 0.5)
+            .w(knob_size)
+            .flex()
+            .justify_center()
+            .items_center()
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .h_full()
+                    .w(stroke)
+                    .left(knob_size * 0.5 - stroke * 0.5)
+                    .bg(divider_color),
+            )
+            .child(knob);
+
+        let mut frame = div()
+            .id(self.id.clone())
+            .relative()
+            .w_full()
+            .overflow_hidden()
+            .child(measure)
+            .child(after_layer)
+            .child(before_layer)
+            .child(handle);
+        frame = match self.height_px {
+            Some(height) => frame.h(px(height)),
+            None => frame.h_full(),
+        };
+        frame = apply_radius(&self.theme, frame, self.radius);
+
+        if self.disabled {
+            return frame
+                .opacity(0.65)
+                .with_enter_transition(self.id.slot("enter"), self.motion);
+        }
+
+        let slider_id = self.id.to_string();
+        let on_change = self.on_change.clone();
+        let step = self.step;
+        frame = frame.cursor_ew_resize().focusable();
+        if let Some(focus_handle) = self.focus_handle.as_ref() {
+            frame = frame.track_focus(focus_handle);
+        }
+
+        frame
+            .on_mouse_down(MouseButton::Left, {
+                let slider_id = slider_id.clone();
+                let on_change = on_change.clone();
+                let focus_handle = self.focus_handle.clone();
+                move |event, window, cx| {
+                    control::set_focused_state(&slider_id, true);
+                    if let Some(focus_handle) = focus_handle.as_ref() {
+                        window.focus(focus_handle, cx);
+                    }
+                    let next = compare_slider_state::frame_percent(
+                        &slider_id,
+                        f32::from(event.position.x),
+                    );
+                    Self::commit(
+                        &slider_id,
+                        is_controlled,
+                        next,
+                        on_change.as_ref(),
+                        window,
+                        cx,
+                    );
+                }
+            })
+            .on_key_down({
+                let slider_id = slider_id.clone();
+                let on_change = on_change.clone();
+                move |event, window, cx| {
+                    if !control::is_plain_keystroke(event) {
+                        return;
+                    }
+                    let current = control::f32_state(&slider_id, "value", None, percent);
+                    let Some(next) = compare_slider_state::keyboard_percent(
+                        current,
+                        event.keystroke.key.as_str(),
+                        step,
+                    ) else {
+                        return;
+                    };
+                    Self::commit(
+                        &slider_id,
+                        is_controlled,
+                        next,
+                        on_change.as_ref(),
+                        window,
+                        cx,
+                    );
+                    cx.stop_propagation();
+                    window.prevent_default();
+                }
+            })
+            .on_mouse_down_out({
+                let slider_id = slider_id.clone();
+                move |_, window, _| {
+                    control::set_focused_state(&slider_id, false);
+                    window.refresh();
+                }
+            })
+            .on_drag(
+                CompareSliderDragState {
+                    slider_id: slider_id.clone(),
+                    controlled: is_controlled,
+                },
+                |_drag, _, _, cx| cx.new(|_| EmptyView),
+            )
+            .on_drag_move::<CompareSliderDragState>(move |event, window, cx| {
+                let drag = event.drag(cx);
+                if drag.slider_id != slider_id {
+                    return;
+                }
+                let controlled = drag.controlled;
+                let next = compare_slider_state::percent_from_local(
+                    f32::from(event.event.position.x) - f32::from(event.bounds.origin.x),
+                    f32::from(event.bounds.size.width),
+                );
+                Self::commit(&slider_id, controlled, next, on_change.as_ref(), window, cx);
+            })
+            .with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
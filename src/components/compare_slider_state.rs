@@ -0,0 +1,49 @@
+use super::control;
+
+pub const MIN_PERCENT: f32 = 0.0;
+pub const MAX_PERCENT: f32 = 100.0;
+
+pub fn clamp_percent(value: f32) -> f32 {
+    if value.is_finite() {
+        value.clamp(MIN_PERCENT, MAX_PERCENT)
+    } else {
+        MIN_PERCENT
+    }
+}
+
+pub fn percent_from_local(local_x: f32, width: f32) -> f32 {
+    clamp_percent(local_x / width.max(1.0) * MAX_PERCENT)
+}
+
+pub fn keyboard_percent(current: f32, key: &str, step: f32) -> Option<f32> {
+    let step = step.max(0.1);
+    let next = match key {
+        "left" | "down" => current - step,
+        "right" | "up" => current + step,
+        "pagedown" => current - step * 10.0,
+        "pageup" => current + step * 10.0,
+        "home" => MIN_PERCENT,
+        "end" => MAX_PERCENT,
+        _ => return None,
+    };
+    Some(clamp_percent(next))
+}
+
+pub fn store_frame(id: &str, origin_x: f32, width: f32) -> bool {
+    control::set_f32_state(id, "frame-origin-x", origin_x);
+    let previous = control::optional_f32_state(id, "frame-width", None, None);
+    if previous.is_some_and(|value| (value - width).abs() < 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, "frame-width", Some(width));
+    true
+}
+
+pub fn stored_width(id: &str) -> Option<f32> {
+    control::optional_f32_state(id, "frame-width", None, None)
+}
+
+pub fn frame_percent(id: &str, pointer_x: f32) -> f32 {
+    let origin_x = control::f32_state(id, "frame-origin-x", None, 0.0);
+    percent_from_local(pointer_x - origin_x, stored_width(id).unwrap_or(1.0))
+}
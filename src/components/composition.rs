@@ -0,0 +1,82 @@
+use gpui::{Bounds, Hsla, IntoElement, Styled, canvas, fill, point, px, size};
+
+use super::control;
+
+const UNDERLINE_THICKNESS: f32 = 1.5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct CompositionUnderline {
+    pub start_x: f32,
+    pub end_x: f32,
+    pub baseline_y: f32,
+}
+
+pub(super) fn marked_range(id: &str, len: usize) -> Option<(usize, usize)> {
+    let start = control::optional_usize_state(id, "marked-start", None, None)?;
+    let end = control::optional_usize_state(id, "marked-end", None, None)?;
+    let start = start.min(len);
+    let end = end.min(len);
+    (start < end).then_some((start, end))
+}
+
+pub(super) fn set_marked_range(id: &str, marked: Option<(usize, usize)>) {
+    control::set_optional_usize_state(id, "marked-start", marked.map(|(start, _)| start));
+    control::set_optional_usize_state(id, "marked-end", marked.map(|(_, end)| end));
+}
+
+pub(super) fn is_composing(id: &str) -> bool {
+    marked_range(id, usize::MAX).is_some()
+}
+
+/// Keeps the preedit text in place and ends the composition, which is how
+/// platform IMEs commit when the field loses focus.
+pub(super) fn commit(id: &str) {
+    set_marked_range(id, None);
+}
+
+/// Maps full-width digits produced by CJK IMEs in full-width mode to ASCII.
+pub(super) fn ascii_digit(ch: char) -> Option<char> {
+    match ch {
+        '0'..='9' => Some(ch),
+        '\u{FF10}'..='\u{FF19}' => char::from_u32(ch as u32 - 0xFF10 + '0' as u32),
+        _ => None,
+    }
+}
+
+pub(super) fn line_segment(
+    marked: (usize, usize),
+    line_start: usize,
+    line_end: usize,
+) -> Option<(usize, usize)> {
+    let start = marked.0.clamp(line_start, line_end);
+    let end = marked.1.clamp(line_start, line_end);
+    (start < end).then_some((start - line_start, end - line_start))
+}
+
+pub(super) fn composition_underlines(
+    underlines: Vec<CompositionUnderline>,
+    color: Hsla,
+) -> impl IntoElement {
+    canvas(
+        |_, _, _| (),
+        move |bounds, _, window, _| {
+            for underline in &underlines {
+                let width = (underline.end_x - underline.start_x).max(1.0);
+                window.paint_quad(fill(
+                    Bounds::new(
+                        point(
+                            bounds.origin.x + px(underline.start_x),
+                            bounds.origin.y + px(underline.baseline_y),
+                        ),
+                        size(px(width), px(UNDERLINE_THICKNESS)),
+                    ),
+                    color,
+                ));
+            }
+        },
+    )
+    .absolute()
+    .top_0()
+    .left_0()
+    .size_full()
+}
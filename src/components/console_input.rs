@@ -0,0 +1,289 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{
+    FocusHandle, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window, div,
+};
+
+use crate::contracts::{Disableable, MotionAware, Radiused, Sized, Varianted};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::{Radius, Size, Variant};
+
+use super::composition;
+use super::console_state;
+use super::control;
+use super::text_input_actions::{
+    CONSOLE_KEY_CONTEXT, CompleteInput, HistoryNext, HistoryPrevious, MoveDown, MoveUp, Submit,
+};
+use super::textarea::Textarea;
+use super::utils::resolve_hsla;
+
+type SubmitHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+type CompleteHandler =
+    Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App) -> Option<SharedString>>;
+
+#[derive(IntoElement)]
+pub struct ConsoleInput {
+    pub(crate) id: ComponentId,
+    pub(crate) inner: Textarea,
+    prompt: Option<SharedString>,
+    history: Option<Vec<SharedString>>,
+    history_limit: usize,
+    size: Size,
+    on_submit: Option<SubmitHandler>,
+    on_complete: Option<CompleteHandler>,
+}
+
+impl ConsoleInput {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            inner: Textarea::new().min_rows(1).max_rows(6).with_size(Size::Sm),
+            prompt: Some(">".into()),
+            history: None,
+            history_limit: 200,
+            size: Size::Sm,
+            on_submit: None,
+            on_complete: None,
+        }
+    }
+
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.inner = self.inner.default_value(value);
+        self
+    }
+
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.inner = self.inner.placeholder(value);
+        self
+    }
+
+    pub fn prompt(mut self, value: impl Into<SharedString>) -> Self {
+        self.prompt = Some(value.into());
+        self
+    }
+
+    pub fn clear_prompt(mut self) -> Self {
+        self.prompt = None;
+        self
+    }
+
+    pub fn history(mut self, entries: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.history = Some(entries.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn history_limit(mut self, value: usize) -> Self {
+        self.history_limit = value.max(1);
+        self
+    }
+
+    pub fn max_rows(mut self, rows: usize) -> Self {
+        self.inner = self.inner.max_rows(rows);
+        self
+    }
+
+    pub fn read_only(mut self, value: bool) -> Self {
+        self.inner = self.inner.read_only(value);
+        self
+    }
+
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.inner = self.inner.focus_handle(focus_handle);
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.inner = self.inner.on_change(handler);
+        self
+    }
+
+    pub fn on_submit(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_submit = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_complete(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) -> Option<SharedString> + 'static,
+    ) -> Self {
+        self.on_complete = Some(Rc::new(handler));
+        self
+    }
+
+    fn editor_value(editor_id: &str) -> String {
+        control::text_state(editor_id, "value", None, String::new())
+    }
+
+    fn editor_caret(editor_id: &str, value: &str) -> usize {
+        let len = value.chars().count();
+        control::usize_state(editor_id, "caret-index", None, len).min(len)
+    }
+}
+
+impl Disableable for ConsoleInput {
+    fn disabled(mut self, value: bool) -> Self {
+        self.inner = self.inner.disabled(value);
+        self
+    }
+}
+
+impl Varianted for ConsoleInput {
+    fn with_variant(mut self, value: Variant) -> Self {
+        self.inner = self.inner.with_variant(value);
+        self
+    }
+}
+
+impl Sized for ConsoleInput {
+    fn with_size(mut self, value: Size) -> Self {
+        self.size = value;
+        self.inner = self.inner.with_size(value);
+        self
+    }
+}
+
+impl Radiused for ConsoleInput {
+    fn with_radius(mut self, value: Radius) -> Self {
+        self.inner = self.inner.with_radius(value);
+        self
+    }
+}
+
+impl MotionAware for ConsoleInput {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.inner = self.inner.motion(value);
+        self
+    }
+}
+
+impl RenderOnce for ConsoleInput {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.inner.theme.sync_from_provider(cx);
+        let tokens = &self.inner.theme.components.textarea;
+        let size_preset = tokens.sizes.for_size(self.size);
+        let prompt_color = resolve_hsla(&self.inner.theme, tokens.placeholder);
+        let mono = self.inner.theme.fonts.monospace();
+        let history_controlled = self.history.is_some();
+        let history = console_state::resolve_history(
+            &self.id,
+            self.history
+                .map(|entries| entries.iter().map(ToString::to_string).collect()),
+        );
+        let history_limit = self.history_limit;
+
+        let editor = self
+            .id
+            .ctx()
+            .child("editor", self.inner)
+            .key_context(CONSOLE_KEY_CONTEXT);
+        let editor_id = editor.id.clone();
+
+        let mut root = div()
+            .id(self.id.clone())
+            .w_full()
+            .flex()
+            .flex_row()
+            .items_start()
+            .gap(size_preset.padding_x)
+            .font(mono);
+
+        if let Some(prompt) = self.prompt {
+            root = root.child(
+                div()
+                    .flex_none()
+                    .pt(size_preset.padding_y)
+                    .text_size(size_preset.font_size)
+                    .line_height(size_preset.line_height)
+                    .text_color(prompt_color)
+                    .child(prompt),
+            );
+        }
+
+        root.child(div().flex_1().min_w_0().child(editor))
+            .on_action({
+                let id = self.id.clone();
+                let editor_id = editor_id.clone();
+                let history = history.clone();
+                let on_submit = self.on_submit.clone();
+                move |_: &Submit, window, cx| {
+                    if composition::is_composing(&editor_id) {
+                        return;
+                    }
+                    let value = Self::editor_value(&editor_id);
+                    console_state::record_entry(
+                        &id,
+                        history_controlled,
+                        history.clone(),
+                        &value,
+                        history_limit,
+                    );
+                    Textarea::replace_uncontrolled_value(&editor_id, "");
+                    window.refresh();
+                    if let Some(handler) = on_submit.as_ref() {
+                        (handler)(value.into(), window, cx);
+                    }
+                }
+            })
+            .on_action({
+                let id = self.id.clone();
+                let editor_id = editor_id.clone();
+                let history = history.clone();
+                move |_: &HistoryPrevious, window, cx| {
+                    if composition::is_composing(&editor_id) {
+                        return;
+                    }
+                    let value = Self::editor_value(&editor_id);
+                    let caret = Self::editor_caret(&editor_id, &value);
+                    if !console_state::caret_on_first_line(&value, caret) {
+                        window.dispatch_action(Box::new(MoveUp), cx);
+                        return;
+                    }
+                    if let Some(recalled) = console_state::step_back(&id, &history, &value) {
+                        Textarea::replace_uncontrolled_value(&editor_id, &recalled);
+                        window.refresh();
+                    }
+                }
+            })
+            .on_action({
+                let id = self.id.clone();
+                let editor_id = editor_id.clone();
+                move |_: &HistoryNext, window, cx| {
+                    if composition::is_composing(&editor_id) {
+                        return;
+                    }
+                    let value = Self::editor_value(&editor_id);
+                    let caret = Self::editor_caret(&editor_id, &value);
+                    if !console_state::caret_on_last_line(&value, caret) {
+                        window.dispatch_action(Box::new(MoveDown), cx);
+                        return;
+                    }
+                    if let Some(recalled) = console_state::step_forward(&id, &history) {
+                        Textarea::replace_uncontrolled_value(&editor_id, &recalled);
+                        window.refresh();
+                    }
+                }
+            })
+            .on_action({
+                let on_complete = self.on_complete.clone();
+                move |_: &CompleteInput, window, cx| {
+                    let Some(handler) = on_complete.as_ref() else {
+                        return;
+                    };
+                    let value = Self::editor_value(&editor_id);
+                    if let Some(completed) = (handler)(value.into(), window, cx) {
+                        Textarea::replace_uncontrolled_value(&editor_id, &completed);
+                        window.refresh();
+                    }
+                }
+            })
+    }
+}
@@ -0,0 +1,70 @@
+use super::control;
+
+pub fn resolve_history(id: &str, controlled: Option<Vec<String>>) -> Vec<String> {
+    control::list_state(id, "history", controlled, Vec::new())
+}
+
+pub fn record_entry(
+    id: &str,
+    history_controlled: bool,
+    mut history: Vec<String>,
+    entry: &str,
+    limit: usize,
+) -> Vec<String> {
+    reset_navigation(id);
+    if entry.trim().is_empty() || history.last().is_some_and(|last| last == entry) {
+        return history;
+    }
+
+    history.push(entry.to_string());
+    let overflow = history.len().saturating_sub(limit.max(1));
+    history.drain(..overflow);
+    if !history_controlled {
+        control::set_list_state(id, "history", history.clone());
+    }
+    history
+}
+
+pub fn step_back(id: &str, history: &[String], current: &str) -> Option<String> {
+    if history.is_empty() {
+        return None;
+    }
+
+    let cursor = control::optional_usize_state(id, "history-cursor", None, None);
+    let next = match cursor {
+        None => {
+            control::set_text_state(id, "history-draft", current.to_string());
+            history.len() - 1
+        }
+        Some(0) => return None,
+        Some(index) => index.min(history.len()) - 1,
+    };
+    control::set_optional_usize_state(id, "history-cursor", Some(next));
+    history.get(next).cloned()
+}
+
+pub fn step_forward(id: &str, history: &[String]) -> Option<String> {
+    let cursor = control::optional_usize_state(id, "history-cursor", None, None)?;
+    let next = cursor + 1;
+    if next < history.len() {
+        control::set_optional_usize_state(id, "history-cursor", Some(next));
+        return history.get(next).cloned();
+    }
+
+    let draft = control::text_state(id, "history-draft", None, String::new());
+    reset_navigation(id);
+    Some(draft)
+}
+
+pub fn reset_navigation(id: &str) {
+    control::set_optional_usize_state(id, "history-cursor", None);
+    control::set_text_state(id, "history-draft", String::new());
+}
+
+pub fn caret_on_first_line(value: &str, caret: usize) -> bool {
+    !value.chars().take(caret).any(|ch| ch == '\n')
+}
+
+pub fn caret_on_last_line(value: &str, caret: usize) -> bool {
+    !value.chars().skip(caret).any(|ch| ch == '\n')
+}
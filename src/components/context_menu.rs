@@ -0,0 +1,467 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, InteractiveElement, IntoElement, MouseButton, ParentElement, RenderOnce,
+    SharedString, StatefulInteractiveElement, Styled, Window, anchored, deferred, div, point, px,
+    relative,
+};
+
+use crate::contracts::MotionAware;
+use crate::focus::{FocusCoordinator, FocusOrientation};
+use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
+use crate::motion::MotionConfig;
+use crate::provider::CalmProvider;
+
+use super::Stack;
+use super::context_menu_state;
+use super::control;
+use super::icon::Icon;
+use super::interaction_adapter::{
+    ActivateHandler, PressAdapter, RovingItem, bind_press_adapter, bind_roving_focus,
+};
+use super::utils::{apply_focus_ring, resolve_hsla};
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+type ItemClickHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+type OpenChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContextMenuItem {
+    pub value: SharedString,
+    pub label: Option<SharedString>,
+    pub disabled: bool,
+    pub icon: Option<SharedString>,
+    pub shortcut: Option<SharedString>,
+    pub separator: bool,
+    pub children: Vec<ContextMenuItem>,
+}
+
+impl ContextMenuItem {
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn labeled(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self::new(value).label(label)
+    }
+
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    pub fn icon(mut self, value: impl Into<SharedString>) -> Self {
+        self.icon = Some(value.into());
+        self
+    }
+
+    pub fn shortcut(mut self, action: impl Into<SharedString>) -> Self {
+        self.shortcut = Some(action.into());
+        self
+    }
+
+    pub fn submenu(mut self, items: impl IntoIterator<Item = ContextMenuItem>) -> Self {
+        self.children.extend(items);
+        self
+    }
+}
+
+#[derive(IntoElement)]
+pub struct ContextMenu {
+    pub(crate) id: ComponentId,
+    disabled: bool,
+    target: Option<SlotRenderer>,
+    items: Vec<ContextMenuItem>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_item_click: Option<ItemClickHandler>,
+    on_open_change: Option<OpenChangeHandler>,
+}
+
+impl ContextMenu {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            disabled: false,
+            target: None,
+            items: Vec::new(),
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_item_click: None,
+            on_open_change: None,
+        }
+    }
+
+    pub fn target(mut self, value: impl IntoElement + 'static) -> Self {
+        self.target = Some(Box::new(|| value.into_any_element()));
+        self
+    }
+
+    pub fn item(mut self, value: ContextMenuItem) -> Self {
+        self.items.push(value);
+        self
+    }
+
+    pub fn items(mut self, values: impl IntoIterator<Item = ContextMenuItem>) -> Self {
+        self.items.extend(values);
+        self
+    }
+
+    pub fn on_item_click(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_item_click = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_open_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_open_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn open_at(
+        id: &ComponentId,
+        position: gpui::Point<gpui::Pixels>,
+        on_open_change: Option<&OpenChangeHandler>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        let was_open = context_menu_state::opened(id);
+        context_menu_state::open_at(id, f32::from(position.x), f32::from(position.y));
+        if !was_open {
+            let event = InteractionEvent::new("context_menu", id, InteractionAction::Open);
+            interaction::emit(event, cx);
+            if let Some(handler) = on_open_change {
+                (handler)(true, window, cx);
+            }
+        }
+        window.refresh();
+    }
+
+    fn dismiss(
+        id: &ComponentId,
+        on_open_change: Option<&OpenChangeHandler>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        if !context_menu_state::close(id) {
+            return;
+        }
+        CalmProvider::focus(cx).release(id, window, cx);
+        let event = InteractionEvent::new("context_menu", id, InteractionAction::Close);
+        interaction::emit(event, cx);
+        if let Some(handler) = on_open_change {
+            (handler)(false, window, cx);
+        }
+        window.refresh();
+    }
+
+    fn item_handle(
+        &self,
+        focus: &FocusCoordinator,
+        value: &str,
+        cx: &gpui::App,
+    ) -> gpui::FocusHandle {
+        focus.handle(&self.id, &format!("item-{value}"), cx)
+    }
+
+    fn render_row(
+        &self,
+        item: &ContextMenuItem,
+        depth: usize,
+        path: &[String],
+        window: &Window,
+        cx: &gpui::App,
+    ) -> (AnyElement, RovingItem) {
+        let tokens = &self.theme.components.menu;
+        let focus = CalmProvider::focus(cx);
+        let icon_fg = resolve_hsla(&self.theme, tokens.icon);
+        let row_id = self.id.slot_index("item", item.value.to_string());
+        let focus_handle = self.item_handle(&focus, &item.value, cx);
+        let expanded = path
+            .get(depth)
+            .is_some_and(|open| open.as_str() == item.value.as_ref());
+        let has_children = !item.children.is_empty();
+
+        let icon = item.icon.clone().map(|icon| {
+            let icon = Icon::named(icon.to_string());
+            self.id
+                .ctx()
+                .child_index("item-icon", item.value.to_string(), icon)
+                .size(f32::from(tokens.item_icon_size))
+                .color(icon_fg)
+        });
+        let hint = item
+            .shortcut
+            .as_ref()
+            .and_then(|action| CalmProvider::shortcuts(cx).hint(action))
+            .map(|hint| div().flex_none().text_color(icon_fg).child(hint));
+        let chevron = has_children.then(|| {
+            let chevron = Icon::named("chevron-right");
+            self.id
+                .ctx()
+                .child_index("item-chevron", item.value.to_string(), chevron)
+                .size(f32::from(tokens.item_icon_size))
+                .color(icon_fg)
+        });
+        let label = item.label.clone().unwrap_or_else(|| item.value.clone());
+        let label_node = div().flex_1().min_w_0().truncate().child(label);
+
+        let mut row = div()
+            .id(row_id.clone())
+            .relative()
+            .flex()
+            .items_center()
+            .gap(tokens.item_gap)
+            .px(tokens.item_padding_x)
+            .py(tokens.item_padding_y)
+            .rounded(tokens.item_radius)
+            .text_size(tokens.item_size)
+            .text_color(resolve_hsla(&self.theme, tokens.item_fg));
+        row = row.children(icon).child(label_node);
+        row = row.children(hint).children(chevron);
+
+        if item.disabled {
+            row = row
+                .cursor_default()
+                .text_color(resolve_hsla(&self.theme, tokens.item_disabled_fg));
+        } else {
+            let hover_bg = tokens
+                .states
+                .hover_bg_or(resolve_hsla(&self.theme, tokens.item_hover_bg));
+            if expanded {
+                row = row.bg(hover_bg);
+            }
+            let menu_id = self.id.clone();
+            let value = item.value.clone();
+            row = row.cursor_pointer().hover(move |style| style.bg(hover_bg));
+            row = row.on_hover(move |hovered, window, _cx| {
+                let changed = match (*hovered, has_children) {
+                    (false, _) => false,
+                    (true, true) => context_menu_state::open_submenu(&menu_id, depth, &value),
+                    (true, false) => context_menu_state::close_submenu(&menu_id, depth),
+                };
+                if changed {
+                    window.refresh();
+                }
+            });
+            let activate = self.activate_handler(item, depth, focus.clone(), cx);
+            row = bind_press_adapter(
+                row,
+                PressAdapter::new(row_id.clone())
+                    .on_activate(Some(activate.clone()))
+                    .focus_handle(Some(focus_handle.clone())),
+            );
+            if has_children {
+                row = row.on_key_down(move |event, window, cx| {
+                    if event.keystroke.key == "right" && !event.keystroke.modifiers.modified() {
+                        (activate)(window, cx);
+                        cx.stop_propagation();
+                    }
+                });
+            }
+            row = apply_focus_ring(
+                &self.theme,
+                row,
+                control::focused_state(&row_id.to_string(), None, false),
+                tokens.item_radius,
+            );
+        }
+
+        if expanded && has_children {
+            let submenu = self.render_panel(&item.children, depth + 1, path, window, cx);
+            row = row.child(
+                div()
+                    .absolute()
+                    .top(-tokens.dropdown_padding)
+                    .left(relative(1.0))
+                    .pl(tokens.dropdown_gap)
+                    .child(submenu),
+            );
+        }
+
+        let roving = RovingItem {
+            id: row_id.into(),
+            focus_handle,
+            disabled: item.disabled,
+            on_activate: None,
+        };
+        (row.into_any_element(), roving)
+    }
+
+    fn activate_handler(
+        &self,
+        item: &ContextMenuItem,
+        depth: usize,
+        focus: FocusCoordinator,
+        cx: &gpui::App,
+    ) -> ActivateHandler {
+        let menu_id = self.id.clone();
+        let value = item.value.clone();
+        if let Some(first) = item
+            .children
+            .iter()
+            .find(|child| !child.disabled && !child.separator)
+        {
+            let first = self.item_handle(&focus, &first.value, cx);
+            return Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
+                context_menu_state::open_submenu(&menu_id, depth, &value);
+                window.focus(&first, cx);
+                window.refresh();
+            });
+        }
+        let on_item_click = self.on_item_click.clone();
+        let on_open_change = self.on_open_change.clone();
+        Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
+            let event = InteractionEvent::new("context_menu", &menu_id, InteractionAction::Select);
+            interaction::emit(event.value(value.clone()), cx);
+            if let Some(handler) = on_item_click.as_ref() {
+                (handler)(value.clone(), window, cx);
+            }
+            Self::dismiss(&menu_id, on_open_change.as_ref(), window, cx);
+        })
+    }
+
+    fn render_panel(
+        &self,
+        items: &[ContextMenuItem],
+        depth: usize,
+        path: &[String],
+        window: &Window,
+        cx: &gpui::App,
+    ) -> Stack {
+        let tokens = &self.theme.components.menu;
+        let border = resolve_hsla(&self.theme, tokens.dropdown_border);
+        let mut rows = Vec::with_capacity(items.len());
+        let mut roving = Vec::with_capacity(items.len());
+        for item in items {
+            if item.separator {
+                let separator = div().h(super::utils::quantized_stroke_px(window, 1.0));
+                rows.push(
+                    separator
+                        .mx(tokens.item_padding_x)
+                        .bg(border)
+                        .into_any_element(),
+                );
+                continue;
+            }
+            let (row, roving_item) = self.render_row(item, depth, path, window, cx);
+            rows.push(row);
+            roving.push(roving_item);
+        }
+
+        let panel = Stack::vertical()
+            .id(self.id.slot_index("panel", depth.to_string()))
+            .min_w(tokens.dropdown_min_width)
+            .p(tokens.dropdown_padding)
+            .gap(tokens.dropdown_gap)
+            .rounded(tokens.dropdown_radius)
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(border)
+            .bg(resolve_hsla(&self.theme, tokens.dropdown_bg))
+            .shadow_sm()
+            .children(rows)
+            .on_mouse_down(MouseButton::Right, |_, _, cx| cx.stop_propagation());
+        let panel = bind_roving_focus(panel, roving, FocusOrientation::Vertical, false);
+        let Some(parent) = depth.checked_sub(1).and_then(|depth| path.get(depth)) else {
+            return panel;
+        };
+        let menu_id = self.id.clone();
+        let parent = self.item_handle(&CalmProvider::focus(cx), parent, cx);
+        panel.on_key_down(move |event, window, cx| {
+            if event.keystroke.key == "left" && !event.keystroke.modifiers.modified() {
+                context_menu_state::close_submenu(&menu_id, depth - 1);
+                window.focus(&parent, cx);
+                window.refresh();
+                cx.stop_propagation();
+            }
+        })
+    }
+
+    fn render_backdrop(&self, window: &Window) -> impl IntoElement {
+        let viewport = window.viewport_size();
+        let close_id = self.id.clone();
+        let close_handler = self.on_open_change.clone();
+        let move_id = self.id.clone();
+        let backdrop = div()
+            .id(self.id.slot("backdrop"))
+            .w(viewport.width)
+            .h(viewport.height)
+            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                Self::dismiss(&close_id, close_handler.as_ref(), window, cx);
+                cx.stop_propagation();
+            })
+            .on_mouse_down(MouseButton::Right, move |event, window, cx| {
+                Self::open_at(&move_id, event.position, None, window, cx);
+                cx.stop_propagation();
+            });
+        anchored().position(point(px(0.0), px(0.0))).child(backdrop)
+    }
+}
+
+impl MotionAware for ContextMenu {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for ContextMenu {
+    fn render(mut self, window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let opened = !self.disabled && context_menu_state::opened(&self.id);
+        let root = div().id(self.id.clone()).relative();
+        let mut root = root.children(self.target.take().map(|target| target()));
+        if !self.disabled {
+            let id = self.id.clone();
+            let on_open_change = self.on_open_change.clone();
+            root = root.on_mouse_down(MouseButton::Right, move |event, window, cx| {
+                Self::open_at(&id, event.position, on_open_change.as_ref(), window, cx);
+                cx.stop_propagation();
+            });
+        }
+        if !opened {
+            return root;
+        }
+
+        let focus = CalmProvider::focus(_cx);
+        let focus_root = focus.trap(&self.id, window, _cx);
+        let path = context_menu_state::submenu_path(&self.id);
+        let panel = self.render_panel(&self.items, 0, &path, window, _cx);
+        let panel = focus.scope(&focus_root, panel);
+        let id = self.id.clone();
+        let on_open_change = self.on_open_change.clone();
+        let panel = panel.on_key_down(move |event, window, cx| {
+            if control::is_escape_keystroke(event) {
+                Self::dismiss(&id, on_open_change.as_ref(), window, cx);
+                cx.stop_propagation();
+            }
+        });
+        let panel = panel.with_enter_transition(self.id.slot("panel-enter"), self.motion);
+
+        let (x, y) = context_menu_state::position(&self.id);
+        let margin = self.theme.components.layout.popup_snap_margin;
+        let layer = anchored()
+            .position(point(px(x), px(y)))
+            .snap_to_window_with_margin(margin)
+            .child(panel);
+        root.child(deferred(self.render_backdrop(window)).priority(30))
+            .child(deferred(layer).priority(31))
+    }
+}
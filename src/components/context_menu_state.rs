@@ -0,0 +1,32 @@
+use super::control;
+
+pub use super::menu_state::{close_submenu, open_submenu, submenu_path};
+
+pub fn opened(id: &str) -> bool {
+    control::bool_state(id, "opened", None, false)
+}
+
+pub fn position(id: &str) -> (f32, f32) {
+    (
+        control::f32_state(id, "x", None, 0.0),
+        control::f32_state(id, "y", None, 0.0),
+    )
+}
+
+pub fn open_at(id: &str, x: f32, y: f32) -> bool {
+    let changed = !opened(id) || position(id) != (x, y);
+    control::set_f32_state(id, "x", x);
+    control::set_f32_state(id, "y", y);
+    control::set_bool_state(id, "opened", true);
+    close_submenu(id, 0);
+    changed
+}
+
+pub fn close(id: &str) -> bool {
+    if !opened(id) {
+        return false;
+    }
+    control::set_bool_state(id, "opened", false);
+    close_submenu(id, 0);
+    true
+}
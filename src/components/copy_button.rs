@@ -0,0 +1,180 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{AnyElement, IntoElement, RenderOnce, SharedString, Window};
+
+use crate::clipboard::{COPIED_FEEDBACK, Clipboard};
+use crate::contracts::{Disableable, Radiused, Sized, Varianted};
+use crate::id::ComponentId;
+use crate::style::{Radius, Size, Variant};
+
+use super::action_icon::ActionIcon;
+use super::icon::Icon;
+use super::popup_state;
+#[cfg(feature = "overlays")]
+use super::tooltip::{Tooltip, TooltipPlacement};
+use super::utils::resolve_hsla;
+
+type CopyHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+
+/// Icon button that copies a fixed value to the clipboard. The icon turns
+/// into a check for a moment afterwards, and with the `overlays` feature a
+/// tooltip reads "Copied!".
+#[derive(IntoElement)]
+pub struct CopyButton {
+    pub(crate) id: ComponentId,
+    value: SharedString,
+    #[cfg(feature = "overlays")]
+    label: SharedString,
+    #[cfg(feature = "overlays")]
+    copied_label: SharedString,
+    #[cfg(feature = "overlays")]
+    tooltip: bool,
+    #[cfg(feature = "overlays")]
+    placement: TooltipPlacement,
+    feedback: Duration,
+    variant: Variant,
+    size: Size,
+    radius: Radius,
+    disabled: bool,
+    on_copy: Option<CopyHandler>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl CopyButton {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: SharedString::default(),
+            #[cfg(feature = "overlays")]
+            label: SharedString::from("Copy"),
+            #[cfg(feature = "overlays")]
+            copied_label: SharedString::from("Copied!"),
+            #[cfg(feature = "overlays")]
+            tooltip: true,
+            #[cfg(feature = "overlays")]
+            placement: TooltipPlacement::Top,
+            feedback: COPIED_FEEDBACK,
+            variant: Variant::Subtle,
+            size: Size::Sm,
+            radius: Radius::Sm,
+            disabled: false,
+            on_copy: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    /// Text written to the clipboard on click.
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    #[cfg(feature = "overlays")]
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = value.into();
+        self
+    }
+
+    #[cfg(feature = "overlays")]
+    pub fn copied_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.copied_label = value.into();
+        self
+    }
+
+    /// Shows the labels in a tooltip. Without it only the icon changes.
+    #[cfg(feature = "overlays")]
+    pub fn tooltip(mut self, value: bool) -> Self {
+        self.tooltip = value;
+        self
+    }
+
+    #[cfg(feature = "overlays")]
+    pub fn tooltip_placement(mut self, value: TooltipPlacement) -> Self {
+        self.placement = value;
+        self
+    }
+
+    /// How long the copied state lasts.
+    pub fn feedback(mut self, value: Duration) -> Self {
+        self.feedback = value;
+        self
+    }
+
+    pub fn on_copy(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_copy = Some(Rc::new(handler));
+        self
+    }
+}
+
+crate::impl_variant_size_radius_via_methods!(CopyButton, variant, size, radius);
+crate::impl_disableable!(CopyButton, |this, value| this.disabled = value);
+
+impl RenderOnce for CopyButton {
+    fn render(mut self, _window: &mut Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = &self.theme.components.copy_button;
+        let clipboard = Clipboard::new(self.id.to_string()).feedback(self.feedback);
+        let copied = !self.disabled && clipboard.copied();
+        // Same id the tooltip gets from `ctx().child("tooltip", ..)` in
+        // `with_tooltip`.
+        let tooltip_id = self.id.scoped("tooltip");
+
+        let mut icon =
+            Icon::named(if copied { "check" } else { "copy" }).size(f32::from(tokens.icon_size));
+        if copied {
+            icon = icon.color(resolve_hsla(&self.theme, tokens.copied_fg));
+        }
+        let value = self.value.clone();
+        let on_copy = self.on_copy.clone();
+        let button = self
+            .id
+            .ctx()
+            .child("button", ActionIcon::new())
+            .with_variant(self.variant)
+            .with_size(self.size)
+            .with_radius(self.radius)
+            .disabled(self.disabled)
+            .child(icon)
+            .on_click(move |_, window, cx| {
+                clipboard.copy(value.to_string(), window, cx);
+                // Keep the bubble up to confirm the copy, even on touch.
+                popup_state::apply_opened(&tooltip_id, false, true);
+                if let Some(handler) = on_copy.as_ref() {
+                    (handler)(value.clone(), window, cx);
+                }
+            });
+
+        self.with_tooltip(button, copied)
+    }
+}
+
+impl CopyButton {
+    #[cfg(feature = "overlays")]
+    fn with_tooltip(&self, button: ActionIcon, copied: bool) -> AnyElement {
+        if !self.tooltip {
+            return button.into_any_element();
+        }
+        self.id
+            .ctx()
+            .child("tooltip", Tooltip::new())
+            .label(if copied {
+                self.copied_label.clone()
+            } else {
+                self.label.clone()
+            })
+            .placement(self.placement)
+            .disabled(self.disabled)
+            .trigger(button)
+            .into_any_element()
+    }
+
+    #[cfg(not(feature = "overlays"))]
+    fn with_tooltip(&self, button: ActionIcon, _copied: bool) -> AnyElement {
+        button.into_any_element()
+    }
+}
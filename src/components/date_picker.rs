@@ -0,0 +1,732 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, FocusHandle, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window,
+    div,
+};
+
+use crate::contracts::{FieldLike, MotionAware};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::{FieldLayout, Radius, Size, Variant};
+use crate::theme::Theme;
+
+pub use super::date_picker_state::CalendarDate;
+
+use super::Stack;
+use super::control;
+use super::date_picker_state;
+use super::field_variant::FieldVariantRuntime;
+use super::icon::Icon;
+use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
+use super::popup::{PopupPlacement, anchored_host};
+use super::popup_state::{self, PopupStateInput, PopupStateValue};
+use super::selection_state;
+use super::utils::{
+    InteractionStyles, apply_disabled_state, apply_field_size, apply_interaction_styles,
+    apply_radius, interaction_style, resolve_hsla,
+};
+
+type DateChangeHandler = Rc<dyn Fn(CalendarDate, &mut Window, &mut gpui::App)>;
+type OpenChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
+type DateFocusHandler = Rc<dyn Fn(CalendarDate, &mut Window)>;
+type DateFormatter = Rc<dyn Fn(CalendarDate) -> SharedString>;
+
+#[derive(Clone)]
+struct DateCommit {
+    id: ComponentId,
+    value_controlled: bool,
+    opened_controlled: bool,
+    on_change: Option<DateChangeHandler>,
+    on_open_change: Option<OpenChangeHandler>,
+}
+
+impl DateCommit {
+    fn select(&self, date: CalendarDate, window: &mut Window, cx: &mut gpui::App) {
+        selection_state::apply_optional_text(
+            &self.id,
+            "value",
+            self.value_controlled,
+            Some(date.to_string()),
+        );
+        date_picker_state::set_focused_date(&self.id, None);
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(date, window, cx);
+        }
+        self.set_opened(false, window, cx);
+    }
+
+    fn set_opened(&self, next: bool, window: &mut Window, cx: &mut gpui::App) {
+        popup_state::apply_opened(&self.id, self.opened_controlled, next);
+        if !next {
+            date_picker_state::set_focused_date(&self.id, None);
+        }
+        window.refresh();
+        if let Some(handler) = self.on_open_change.as_ref() {
+            (handler)(next, window, cx);
+        }
+    }
+
+    fn focus(&self, date: CalendarDate, window: &mut Window) {
+        date_picker_state::set_focused_date(&self.id, Some(date));
+        window.refresh();
+    }
+}
+
+#[derive(IntoElement)]
+pub struct DatePicker {
+    pub(crate) id: ComponentId,
+    value: Option<CalendarDate>,
+    value_controlled: bool,
+    default_value: Option<CalendarDate>,
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+    first_weekday: u8,
+    placeholder: Option<SharedString>,
+    label: Option<SharedString>,
+    description: Option<SharedString>,
+    error: Option<SharedString>,
+    required: bool,
+    layout: FieldLayout,
+    opened: Option<bool>,
+    default_opened: bool,
+    disabled: bool,
+    size: Size,
+    radius: Radius,
+    variant: Variant,
+    focus_handle: Option<FocusHandle>,
+    formatter: Option<DateFormatter>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<DateChangeHandler>,
+    on_open_change: Option<OpenChangeHandler>,
+}
+
+impl DatePicker {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: None,
+            value_controlled: false,
+            default_value: None,
+            min: None,
+            max: None,
+            first_weekday: 0,
+            placeholder: None,
+            label: None,
+            description: None,
+            error: None,
+            required: false,
+            layout: FieldLayout::Vertical,
+            opened: None,
+            default_opened: false,
+            disabled: false,
+            size: Size::Md,
+            radius: Radius::Sm,
+            variant: Variant::Default,
+            focus_handle: None,
+            formatter: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+            on_open_change: None,
+        }
+    }
+
+    pub fn value(mut self, value: CalendarDate) -> Self {
+        self.value = Some(value);
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn clear_value(mut self) -> Self {
+        self.value = None;
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn default_value(mut self, value: CalendarDate) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+
+    pub fn min_date(mut self, value: CalendarDate) -> Self {
+        self.min = Some(value);
+        self
+    }
+
+    pub fn max_date(mut self, value: CalendarDate) -> Self {
+        self.max = Some(value);
+        self
+    }
+
+    pub fn first_weekday(mut self, value: u8) -> Self {
+        self.first_weekday = value % 7;
+        self
+    }
+
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(value.into());
+        self
+    }
+
+    pub fn opened(mut self, value: bool) -> Self {
+        self.opened = Some(value);
+        self
+    }
+
+    pub fn default_opened(mut self, value: bool) -> Self {
+        self.default_opened = value;
+        self
+    }
+
+    pub fn focus_handle(mut self, value: FocusHandle) -> Self {
+        self.focus_handle = Some(value);
+        self
+    }
+
+    pub fn format(mut self, formatter: impl Fn(CalendarDate) -> SharedString + 'static) -> Self {
+        self.formatter = Some(Rc::new(formatter));
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(CalendarDate, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_open_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_open_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_value(&self) -> Option<CalendarDate> {
+        selection_state::resolve_optional_text(
+            &self.id,
+            "value",
+            self.value_controlled,
+            self.value.map(|value| value.to_string()),
+            self.default_value.map(|value| value.to_string()),
+        )
+        .and_then(|value| value.parse().ok())
+    }
+
+    fn commit(&self) -> DateCommit {
+        DateCommit {
+            id: self.id.clone(),
+            value_controlled: self.value_controlled,
+            opened_controlled: self.opened.is_some(),
+            on_change: self.on_change.clone(),
+            on_open_change: self.on_open_change.clone(),
+        }
+    }
+
+    fn render_label_block(&self, width: Option<gpui::Pixels>) -> Option<AnyElement> {
+        if self.label.is_none() && self.description.is_none() && self.error.is_none() {
+            return None;
+        }
+        let tokens = &self.theme.components.date_picker;
+        let mut block = Stack::vertical().gap(tokens.label_block_gap);
+        if let Some(label) = self.label.clone() {
+            let mut row = Stack::horizontal().gap(tokens.label_row_gap).child(
+                div()
+                    .text_size(tokens.label_size)
+                    .font_weight(tokens.label_weight)
+                    .text_color(resolve_hsla(&self.theme, tokens.label))
+                    .child(label),
+            );
+            if self.required {
+                row = row.child(
+                    div()
+                        .text_color(resolve_hsla(&self.theme, self.theme.semantic.status_error))
+                        .child("*"),
+                );
+            }
+            block = block.child(row);
+        }
+        if let Some(description) = self.description.clone() {
+            block = block.child(
+                div()
+                    .text_size(tokens.description_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.description))
+                    .child(description),
+            );
+        }
+        if let Some(error) = self.error.clone() {
+            block = block.child(
+                div()
+                    .text_size(tokens.error_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.error))
+                    .child(error),
+            );
+        }
+        if let Some(width) = width {
+            block = block.w(width);
+        }
+        Some(block.into_any_element())
+    }
+
+    fn render_control(
+        &self,
+        window: &gpui::Window,
+        opened: bool,
+        value: Option<CalendarDate>,
+        focused: CalendarDate,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.date_picker;
+        let control_bg =
+            FieldVariantRuntime::control_bg(resolve_hsla(&self.theme, tokens.bg), self.variant);
+        let border = FieldVariantRuntime::control_border(
+            if self.error.is_some() {
+                resolve_hsla(&self.theme, tokens.border_error)
+            } else if opened {
+                resolve_hsla(&self.theme, tokens.border_focus)
+            } else {
+                resolve_hsla(&self.theme, tokens.border)
+            },
+            self.variant,
+            opened,
+            self.error.is_some(),
+        );
+
+        let mut control = div()
+            .id(self.id.slot("control"))
+            .relative()
+            .w_full()
+            .flex()
+            .items_center()
+            .gap(tokens.slot_gap)
+            .bg(control_bg)
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(border);
+        control = apply_field_size(control, tokens.sizes.for_size(self.size));
+        control = apply_radius(&self.theme, control, self.radius);
+
+        let (text, color) = match value {
+            Some(date) => (
+                self.formatter
+                    .as_ref()
+                    .map(|format| format(date))
+                    .unwrap_or_else(|| date.to_string().into()),
+                resolve_hsla(&self.theme, tokens.fg),
+            ),
+            None => (
+                self.placeholder
+                    .clone()
+                    .unwrap_or_else(|| SharedString::from("Pick a date")),
+                resolve_hsla(&self.theme, tokens.placeholder),
+            ),
+        };
+        control = control
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .truncate()
+                    .text_color(color)
+                    .child(text),
+            )
+            .child(
+                self.id
+                    .ctx()
+                    .child("calendar-icon", Icon::named("calendar"))
+                    .size(f32::from(tokens.icon_size))
+                    .color(resolve_hsla(&self.theme, tokens.icon)),
+            );
+
+        if self.disabled {
+            return apply_disabled_state(control.cursor_default(), tokens.states, 0.55)
+                .into_any_element();
+        }
+
+        let hover_bg = tokens
+            .states
+            .hover_bg_or(crate::theme::color_utils::lighten(control_bg, 0.04));
+        let focus_border = tokens
+            .states
+            .focus_border_or(resolve_hsla(&self.theme, tokens.border_focus));
+        control = apply_interaction_styles(
+            control.cursor_pointer(),
+            InteractionStyles::new()
+                .hover(interaction_style(move |style| style.bg(hover_bg)))
+                .focus(interaction_style(move |style| {
+                    style.border_color(focus_border)
+                })),
+        );
+
+        let commit = self.commit();
+        let toggle: ActivateHandler = Rc::new({
+            let commit = commit.clone();
+            move |window: &mut Window, cx: &mut gpui::App| {
+                commit.set_opened(!opened, window, cx);
+            }
+        });
+        control = bind_press_adapter(
+            control,
+            PressAdapter::new(self.id.slot("control"))
+                .on_activate(Some(toggle))
+                .focus_handle(self.focus_handle.clone()),
+        );
+
+        let (min, max) = (self.min, self.max);
+        let first_weekday = self.first_weekday;
+        control
+            .on_key_down(move |event, window, cx| {
+                if control::is_escape_keystroke(event) && opened {
+                    commit.set_opened(false, window, cx);
+                    cx.stop_propagation();
+                    return;
+                }
+                if !control::is_plain_keystroke(event) {
+                    return;
+                }
+                let key = event.keystroke.key.as_str();
+                if !opened {
+                    if key == "down" {
+                        commit.set_opened(true, window, cx);
+                        cx.stop_propagation();
+                    }
+                    return;
+                }
+                if control::is_activation_key(key) {
+                    if date_picker_state::is_within(focused, min, max) {
+                        commit.select(focused, window, cx);
+                    }
+                    cx.stop_propagation();
+                    window.prevent_default();
+                    return;
+                }
+                let Some(next) = date_picker_state::keyboard_date(
+                    focused,
+                    key,
+                    event.keystroke.modifiers.shift,
+                    first_weekday,
+                ) else {
+                    return;
+                };
+                commit.focus(date_picker_state::clamp_date(next, min, max), window);
+                cx.stop_propagation();
+                window.prevent_default();
+            })
+            .into_any_element()
+    }
+
+    fn render_calendar(
+        &self,
+        window: &gpui::Window,
+        focused: CalendarDate,
+        value: Option<CalendarDate>,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.date_picker;
+        let commit = self.commit();
+        let grid = CalendarGrid {
+            id: &self.id,
+            theme: &self.theme,
+            focused,
+            value,
+            min: self.min,
+            max: self.max,
+            first_weekday: self.first_weekday,
+            on_select: Rc::new({
+                let commit = commit.clone();
+                move |date: CalendarDate, window: &mut Window, cx: &mut gpui::App| {
+                    commit.select(date, window, cx)
+                }
+            }),
+            on_focus: Rc::new({
+                let commit = commit.clone();
+                move |date: CalendarDate, window: &mut Window| commit.focus(date, window)
+            }),
+        };
+
+        div()
+            .id(self.id.slot("calendar"))
+            .rounded_md()
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(resolve_hsla(&self.theme, tokens.dropdown_border))
+            .bg(resolve_hsla(&self.theme, tokens.dropdown_bg))
+            .shadow_sm()
+            .p(tokens.dropdown_padding)
+            .child(grid.render())
+            .on_mouse_down_out(move |_, window, cx| commit.set_opened(false, window, cx))
+            .with_enter_transition(self.id.slot("calendar-enter"), self.motion)
+            .into_any_element()
+    }
+}
+
+pub(super) struct CalendarGrid<'a> {
+    pub(super) id: &'a ComponentId,
+    pub(super) theme: &'a Theme,
+    pub(super) focused: CalendarDate,
+    pub(super) value: Option<CalendarDate>,
+    pub(super) min: Option<CalendarDate>,
+    pub(super) max: Option<CalendarDate>,
+    pub(super) first_weekday: u8,
+    pub(super) on_select: DateChangeHandler,
+    pub(super) on_focus: DateFocusHandler,
+}
+
+impl CalendarGrid<'_> {
+    fn render_nav_button(&self, slot: &str, icon: &str, target: CalendarDate) -> AnyElement {
+        let tokens = &self.theme.components.date_picker;
+        let hover_bg = resolve_hsla(self.theme, tokens.nav_hover_bg);
+        let on_focus = self.on_focus.clone();
+        let button = div()
+            .id(self.id.slot(slot.to_owned()))
+            .flex()
+            .items_center()
+            .justify_center()
+            .size(tokens.day_cell_size)
+            .rounded_sm()
+            .cursor_pointer()
+            .child(
+                self.id
+                    .ctx()
+                    .child(format!("{slot}-icon"), Icon::named(icon))
+                    .size(f32::from(tokens.icon_size))
+                    .color(resolve_hsla(self.theme, tokens.icon)),
+            );
+        bind_press_adapter(
+            apply_interaction_styles(
+                button,
+                InteractionStyles::new().hover(interaction_style(move |style| style.bg(hover_bg))),
+            ),
+            PressAdapter::new(self.id.slot(slot.to_owned())).on_activate(Some(Rc::new(
+                move |window: &mut Window, _cx: &mut gpui::App| on_focus(target, window),
+            ))),
+        )
+        .into_any_element()
+    }
+
+    fn render_day(&self, date: CalendarDate, today: CalendarDate) -> AnyElement {
+        let tokens = &self.theme.components.date_picker;
+        let day_id = self.id.slot_index("day", date.to_string());
+        let selected = self.value == Some(date);
+        let in_month = date.year() == self.focused.year() && date.month() == self.focused.month();
+        let enabled = date_picker_state::is_within(date, self.min, self.max);
+        let hover_bg = resolve_hsla(self.theme, tokens.day_hover_bg);
+
+        let mut cell = div()
+            .id(day_id.clone())
+            .flex()
+            .items_center()
+            .justify_center()
+            .size(tokens.day_cell_size)
+            .rounded_sm()
+            .border_1()
+            .border_color(if date == today {
+                resolve_hsla(self.theme, tokens.day_today_border)
+            } else {
+                gpui::transparent_black()
+            })
+            .text_size(tokens.day_size)
+            .child(date.day().to_string());
+        cell = if selected {
+            cell.bg(resolve_hsla(self.theme, tokens.day_selected_bg))
+                .text_color(resolve_hsla(self.theme, tokens.day_selected_fg))
+        } else if in_month {
+            cell.text_color(resolve_hsla(self.theme, tokens.day_fg))
+        } else {
+            cell.text_color(resolve_hsla(self.theme, tokens.day_outside_fg))
+        };
+        if date == self.focused && !selected {
+            cell = cell.bg(hover_bg);
+        }
+        if !enabled {
+            return cell.opacity(0.35).cursor_default().into_any_element();
+        }
+
+        let on_select = self.on_select.clone();
+        bind_press_adapter(
+            apply_interaction_styles(
+                cell.cursor_pointer(),
+                InteractionStyles::new().hover(interaction_style(move |style| style.bg(hover_bg))),
+            ),
+            PressAdapter::new(day_id).on_activate(Some(Rc::new(
+                move |window: &mut Window, cx: &mut gpui::App| on_select(date, window, cx),
+            ))),
+        )
+        .into_any_element()
+    }
+
+    pub(super) fn render(&self) -> AnyElement {
+        let tokens = &self.theme.components.date_picker;
+        let today = CalendarDate::today();
+        let title = format!(
+            "{} {}",
+            date_picker_state::MONTH_LABELS[usize::from(self.focused.month() - 1)],
+            self.focused.year()
+        );
+
+        let header = Stack::horizontal()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(self.render_nav_button(
+                "prev-month",
+                "chevron-left",
+                self.focused.add_months(-1),
+            ))
+            .child(
+                div()
+                    .text_size(tokens.header_size)
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .text_color(resolve_hsla(self.theme, tokens.header_fg))
+                    .child(title),
+            )
+            .child(self.render_nav_button(
+                "next-month",
+                "chevron-right",
+                self.focused.add_months(1),
+            ));
+
+        let weekdays = Stack::horizontal()
+            .gap(tokens.grid_gap)
+            .children((0..7).map(|offset| {
+                div()
+                    .flex()
+                    .justify_center()
+                    .w(tokens.day_cell_size)
+                    .text_size(tokens.weekday_size)
+                    .text_color(resolve_hsla(self.theme, tokens.weekday_fg))
+                    .child(
+                        date_picker_state::WEEKDAY_LABELS
+                            [usize::from((self.first_weekday + offset) % 7)],
+                    )
+            }));
+
+        let days = date_picker_state::month_grid(self.focused, self.first_weekday);
+        let weeks = days.chunks(7).map(|week| {
+            Stack::horizontal()
+                .gap(tokens.grid_gap)
+                .children(week.iter().map(|date| self.render_day(*date, today)))
+        });
+
+        Stack::vertical()
+            .gap(tokens.grid_gap)
+            .child(header)
+            .child(weekdays)
+            .children(weeks)
+            .into_any_element()
+    }
+}
+
+impl FieldLike for DatePicker {
+    fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    fn error(mut self, value: impl Into<SharedString>) -> Self {
+        self.error = Some(value.into());
+        self
+    }
+
+    fn required(mut self, value: bool) -> Self {
+        self.required = value;
+        self
+    }
+
+    fn layout(mut self, value: FieldLayout) -> Self {
+        self.layout = value;
+        self
+    }
+}
+
+crate::impl_variant_size_radius_via_methods!(DatePicker, variant, size, radius);
+crate::impl_disableable!(DatePicker, |this, value| this.disabled = value);
+
+impl MotionAware for DatePicker {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for DatePicker {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.date_picker.clone();
+        let opened = PopupStateValue::resolve(PopupStateInput {
+            id: &self.id,
+            opened: self.opened,
+            default_opened: self.default_opened,
+            disabled: self.disabled,
+        })
+        .opened;
+        let value = self.resolved_value();
+        let focused = date_picker_state::focused_date(
+            &self.id,
+            date_picker_state::clamp_date(
+                value.unwrap_or_else(CalendarDate::today),
+                self.min,
+                self.max,
+            ),
+        );
+
+        let mut trigger = div()
+            .id(self.id.slot("trigger"))
+            .relative()
+            .w_full()
+            .child(self.render_control(window, opened, value, focused));
+        if opened {
+            trigger = trigger.child(anchored_host(
+                &self.id,
+                "anchor-host",
+                PopupPlacement::Bottom.options(
+                    f32::from(tokens.dropdown_anchor_offset),
+                    self.theme.components.layout.popup_snap_margin,
+                ),
+                self.render_calendar(window, focused, value),
+                24,
+                false,
+            ));
+        }
+
+        let field = Stack::vertical()
+            .gap(tokens.label_block_gap)
+            .w_full()
+            .min_w_0()
+            .child(trigger);
+        let root = match self.layout {
+            FieldLayout::Vertical => {
+                let mut container = Stack::vertical()
+                    .id(self.id.clone())
+                    .gap(tokens.layout_gap_vertical)
+                    .w_full();
+                if let Some(label_block) = self.render_label_block(None) {
+                    container = container.child(label_block);
+                }
+                container.child(field)
+            }
+            FieldLayout::Horizontal => {
+                let mut row = Stack::horizontal()
+                    .id(self.id.clone())
+                    .items_start()
+                    .gap(tokens.layout_gap_horizontal);
+                if let Some(label_block) =
+                    self.render_label_block(Some(tokens.horizontal_label_width))
+                {
+                    row = row.child(label_block);
+                }
+                row.child(field)
+            }
+        };
+        root.with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use super::control;
+
+pub const MONTH_LABELS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+pub const WEEKDAY_LABELS: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CalendarDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl CalendarDate {
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    pub fn today() -> Self {
+        let days = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| (elapsed.as_secs() / 86_400) as i64)
+            .unwrap_or(0);
+        Self::from_days(days)
+    }
+
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    pub fn weekday(self) -> u8 {
+        (self.to_days() + 4).rem_euclid(7) as u8
+    }
+
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + i32::from(self.month) - 1 + months;
+        let year = index.div_euclid(12);
+        let month = (index.rem_euclid(12) + 1) as u8;
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    pub fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    pub fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u8;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month,
+            day,
+        }
+    }
+}
+
+impl Display for CalendarDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for CalendarDate {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.trim().splitn(3, '-');
+        let year = parts.next().and_then(|part| part.parse().ok()).ok_or(())?;
+        let month = parts.next().and_then(|part| part.parse().ok()).ok_or(())?;
+        let day = parts.next().and_then(|part| part.parse().ok()).ok_or(())?;
+        Self::new(year, month, day).ok_or(())
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub fn clamp_date(
+    date: CalendarDate,
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+) -> CalendarDate {
+    let date = min.map_or(date, |min| date.max(min));
+    max.map_or(date, |max| date.min(max))
+}
+
+pub fn is_within(date: CalendarDate, min: Option<CalendarDate>, max: Option<CalendarDate>) -> bool {
+    min.is_none_or(|min| date >= min) && max.is_none_or(|max| date <= max)
+}
+
+pub fn month_grid(month: CalendarDate, first_weekday: u8) -> Vec<CalendarDate> {
+    let first = month.first_of_month();
+    let lead = (i64::from(first.weekday()) - i64::from(first_weekday % 7)).rem_euclid(7);
+    let start = first.add_days(-lead);
+    (0..42).map(|offset| start.add_days(offset)).collect()
+}
+
+pub fn keyboard_date(
+    focused: CalendarDate,
+    key: &str,
+    shift: bool,
+    first_weekday: u8,
+) -> Option<CalendarDate> {
+    let week_offset = (i64::from(focused.weekday()) - i64::from(first_weekday % 7)).rem_euclid(7);
+    match key {
+        "left" => Some(focused.add_days(-1)),
+        "right" => Some(focused.add_days(1)),
+        "up" => Some(focused.add_days(-7)),
+        "down" => Some(focused.add_days(7)),
+        "pageup" => Some(focused.add_months(if shift { -12 } else { -1 })),
+        "pagedown" => Some(focused.add_months(if shift { 12 } else { 1 })),
+        "home" => Some(focused.add_days(-week_offset)),
+        "end" => Some(focused.add_days(6 - week_offset)),
+        _ => None,
+    }
+}
+
+pub fn focused_date(id: &str, fallback: CalendarDate) -> CalendarDate {
+    control::optional_text_state(id, "focused-date", None, None)
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(fallback)
+}
+
+pub fn set_focused_date(id: &str, date: Option<CalendarDate>) {
+    control::set_optional_text_state(id, "focused-date", date.map(|date| date.to_string()));
+}
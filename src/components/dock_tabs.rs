@@ -0,0 +1,715 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, AppContext, EmptyView, IntoElement, MouseButton, ParentElement, Pixels, Point,
+    RenderOnce, SharedString, Styled, Window, canvas, div, px,
+};
+
+use crate::contracts::{Disableable, MotionAware};
+use crate::focus::FocusOrientation;
+use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
+use crate::motion::MotionConfig;
+
+use super::Icon;
+use super::dock_tabs_state;
+use super::interaction_adapter::{
+    ActivateHandler, PressAdapter, RovingItem, bind_press_adapter, bind_roving_focus,
+};
+use super::menu::{Menu, MenuItem};
+use super::selection_state;
+use super::utils::resolve_hsla;
+
+type ValueHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+type ReorderHandler = Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut gpui::App)>;
+type DetachHandler = Rc<dyn Fn(SharedString, Point<Pixels>, &mut Window, &mut gpui::App)>;
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+
+/// Distance past the strip edge at which a dragged tab is torn off.
+const DETACH_THRESHOLD_PX: f32 = 24.0;
+
+#[derive(Clone)]
+struct DockTabDrag {
+    strip_id: String,
+    value: SharedString,
+    index: usize,
+}
+
+pub struct DockTab {
+    pub value: SharedString,
+    pub label: Option<SharedString>,
+    pub closable: bool,
+    pub dirty: bool,
+    pub disabled: bool,
+    panel: Option<SlotRenderer>,
+}
+
+impl DockTab {
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: None,
+            closable: true,
+            dirty: false,
+            disabled: false,
+            panel: None,
+        }
+    }
+
+    pub fn labeled(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self::new(value).label(label)
+    }
+
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    pub fn closable(mut self, value: bool) -> Self {
+        self.closable = value;
+        self
+    }
+
+    /// Marks unsaved changes with a dot that turns into the close button
+    /// on hover.
+    pub fn dirty(mut self, value: bool) -> Self {
+        self.dirty = value;
+        self
+    }
+
+    pub fn panel(mut self, content: impl IntoElement + 'static) -> Self {
+        self.panel = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    fn display_label(&self) -> SharedString {
+        self.label.clone().unwrap_or_else(|| self.value.clone())
+    }
+}
+
+/// Editor-style tab strip. Tabs can be closed, reordered and dragged out of
+/// the strip; tabs that do not fit move into an overflow menu.
+///
+/// Closing and detaching only report through callbacks, so the owner decides
+/// whether a dirty document may go away.
+#[derive(IntoElement)]
+pub struct DockTabs {
+    pub(crate) id: ComponentId,
+    items: Vec<DockTab>,
+    value: Option<SharedString>,
+    value_controlled: bool,
+    default_value: Option<SharedString>,
+    order: Option<Vec<SharedString>>,
+    reorderable: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<ValueHandler>,
+    on_close: Option<ValueHandler>,
+    on_reorder: Option<ReorderHandler>,
+    on_detach: Option<DetachHandler>,
+}
+
+struct TabContext {
+    strip_id: String,
+    order: Vec<String>,
+    order_controlled: bool,
+    select: ValueHandler,
+    on_close: Option<ValueHandler>,
+    on_reorder: Option<ReorderHandler>,
+}
+
+impl DockTabs {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            items: Vec::new(),
+            value: None,
+            value_controlled: false,
+            default_value: None,
+            order: None,
+            reorderable: true,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+            on_close: None,
+            on_reorder: None,
+            on_detach: None,
+        }
+    }
+
+    pub fn item(mut self, item: DockTab) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = DockTab>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn clear_value(mut self) -> Self {
+        self.value = None;
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_value = Some(value.into());
+        self
+    }
+
+    /// Controlled tab order by value. Values missing from `order` follow in
+    /// item order.
+    pub fn order(mut self, values: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.order = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn reorderable(mut self, value: bool) -> Self {
+        self.reorderable = value;
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called from the close button or a middle click.
+    pub fn on_close(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_reorder(
+        mut self,
+        handler: impl Fn(Vec<SharedString>, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called when a tab is dropped away from the strip, with the window
+    /// position of the pointer.
+    pub fn on_detach(
+        mut self,
+        handler: impl Fn(SharedString, Point<Pixels>, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_detach = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_order(&self) -> Vec<String> {
+        let values = self
+            .items
+            .iter()
+            .map(|item| item.value.to_string())
+            .collect::<Vec<_>>();
+        match self.order.as_ref() {
+            Some(order) => {
+                let preferred = order.iter().map(ToString::to_string).collect();
+                dock_tabs_state::merge_order(preferred, &values)
+            }
+            None => dock_tabs_state::resolve_order(&self.id, &values),
+        }
+    }
+
+    fn resolved_value(&self, order: &[String]) -> Option<SharedString> {
+        let default = self
+            .default_value
+            .as_ref()
+            .map(|value| value.to_string())
+            .or_else(|| order.first().cloned());
+
+        selection_state::resolve_optional_text(
+            &self.id,
+            "value",
+            self.value_controlled,
+            self.value.as_ref().map(|value| value.to_string()),
+            default,
+        )
+        .map(SharedString::from)
+    }
+
+    fn select_handler(&self) -> ValueHandler {
+        let id = self.id.clone();
+        let controlled = self.value_controlled;
+        let on_change = self.on_change.clone();
+        Rc::new(move |value: SharedString, window, cx| {
+            if selection_state::apply_optional_text(
+                &id,
+                "value",
+                controlled,
+                Some(value.to_string()),
+            ) {
+                window.refresh();
+            }
+            let event = InteractionEvent::new("dock-tabs", &id, InteractionAction::Select);
+            interaction::emit(event.value(value.clone()), cx);
+            if let Some(handler) = on_change.as_ref() {
+                (handler)(value, window, cx);
+            }
+        })
+    }
+
+    fn commit_order(
+        context: &TabContext,
+        from: usize,
+        to: usize,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        let next = dock_tabs_state::move_tab(&context.order, from, to);
+        if next == context.order {
+            return;
+        }
+        if !context.order_controlled {
+            dock_tabs_state::set_order(&context.strip_id, next.clone());
+            window.refresh();
+        }
+        if let Some(handler) = context.on_reorder.as_ref() {
+            (handler)(
+                next.into_iter().map(SharedString::from).collect(),
+                window,
+                cx,
+            );
+        }
+    }
+
+    fn close(context: &TabContext, value: SharedString, window: &mut Window, cx: &mut gpui::App) {
+        let event = InteractionEvent::new(
+            "dock-tabs",
+            context.strip_id.clone(),
+            InteractionAction::Close,
+        );
+        interaction::emit(event.value(value.clone()), cx);
+        if let Some(handler) = context.on_close.as_ref() {
+            (handler)(value, window, cx);
+        }
+    }
+
+    fn render_trailing(
+        &self,
+        item: &DockTab,
+        is_active: bool,
+        group: &SharedString,
+        context: &Rc<TabContext>,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.dock_tabs;
+        let mut slot = div().relative().flex_none().size(tokens.close_size);
+        if item.dirty {
+            let mut dot = div()
+                .absolute()
+                .inset_0()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(
+                    div()
+                        .size(tokens.dirty_dot_size)
+                        .rounded_full()
+                        .bg(resolve_hsla(&self.theme, tokens.dirty_dot)),
+                );
+            if item.closable && !item.disabled {
+                dot = dot.group_hover(group.clone(), |style| style.opacity(0.0));
+            }
+            slot = slot.child(dot);
+        }
+        if !item.closable || item.disabled {
+            return slot.into_any_element();
+        }
+
+        let fg = resolve_hsla(&self.theme, tokens.tab_fg);
+        let hover_bg = resolve_hsla(&self.theme, tokens.tab_hover_bg);
+        let icon = self
+            .id
+            .ctx()
+            .child_index("close-icon", item.value.to_string(), Icon::named("x"))
+            .size(f32::from(tokens.close_size) * 0.75)
+            .color(fg);
+        let close = div()
+            .id(self.id.slot_index("close", item.value.to_string()))
+            .absolute()
+            .inset_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded_sm()
+            .cursor_pointer()
+            .opacity(if is_active && !item.dirty { 1.0 } else { 0.0 })
+            .group_hover(group.clone(), |style| style.opacity(1.0))
+            .hover(move |style| style.bg(hover_bg))
+            .child(icon)
+            .on_click({
+                let context = context.clone();
+                let value = item.value.clone();
+                move |_, window, cx| {
+                    Self::close(&context, value.clone(), window, cx);
+                    cx.stop_propagation();
+                }
+            });
+        slot.child(close).into_any_element()
+    }
+
+    fn render_tab(
+        &self,
+        index: usize,
+        item: &DockTab,
+        is_active: bool,
+        context: &Rc<TabContext>,
+        roving: &mut Vec<RovingItem>,
+        cx: &gpui::App,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.dock_tabs;
+        let value = item.value.clone();
+        let tab_id = self.id.slot_index("tab", value.to_string());
+        let group = SharedString::from(format!("{}-tab-{value}", self.id));
+        let focus_handle =
+            crate::provider::CalmProvider::focus(cx).handle(&self.id, &format!("tab-{value}"), cx);
+        let strip_border = resolve_hsla(&self.theme, tokens.strip_border);
+        let fg = if item.disabled {
+            tokens.tab_disabled_fg
+        } else if is_active {
+            tokens.tab_active_fg
+        } else {
+            tokens.tab_fg
+        };
+
+        let measure = canvas(
+            {
+                let strip_id = context.strip_id.clone();
+                let value = value.to_string();
+                move |bounds, window, _| {
+                    let width = f32::from(bounds.size.width);
+                    if dock_tabs_state::store_tab_width(&strip_id, &value, width) {
+                        window.refresh();
+                    }
+                }
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let label = div().min_w_0().truncate().child(item.display_label());
+        let mut tab = div()
+            .id(tab_id.clone())
+            .group(group.clone())
+            .relative()
+            .flex_none()
+            .flex()
+            .items_center()
+            .gap(tokens.tab_gap)
+            .h_full()
+            .max_w(tokens.tab_max_width)
+            .px(tokens.tab_padding_x)
+            .border_r_1()
+            .border_color(strip_border)
+            .text_color(resolve_hsla(&self.theme, fg))
+            .child(measure)
+            .child(label)
+            .child(self.render_trailing(item, is_active, &group, context));
+        if is_active {
+            let indicator = div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .w_full()
+                .h(px(2.0))
+                .bg(resolve_hsla(&self.theme, tokens.tab_active_indicator));
+            tab = tab
+                .bg(resolve_hsla(&self.theme, tokens.tab_active_bg))
+                .child(indicator);
+        }
+
+        if item.disabled {
+            roving.push(RovingItem {
+                id: tab_id.into(),
+                focus_handle,
+                disabled: true,
+                on_activate: None,
+            });
+            return tab.cursor_default().into_any_element();
+        }
+
+        let hover_bg = resolve_hsla(&self.theme, tokens.tab_hover_bg);
+        if !is_active {
+            tab = tab.hover(move |style| style.bg(hover_bg));
+        }
+        let activate: ActivateHandler = {
+            let select = context.select.clone();
+            let value = value.clone();
+            Rc::new(move |window, cx| (select)(value.clone(), window, cx))
+        };
+        tab = bind_press_adapter(
+            tab.cursor_pointer(),
+            PressAdapter::new(tab_id.clone())
+                .on_activate(Some(activate.clone()))
+                .focus_handle(Some(focus_handle.clone())),
+        );
+        roving.push(RovingItem {
+            id: tab_id.into(),
+            focus_handle,
+            disabled: false,
+            on_activate: Some(activate),
+        });
+
+        if item.closable {
+            tab = tab.on_mouse_down(MouseButton::Middle, {
+                let context = context.clone();
+                let value = value.clone();
+                move |_, window, cx| {
+                    Self::close(&context, value.clone(), window, cx);
+                    cx.stop_propagation();
+                }
+            });
+        }
+        if self.reorderable || self.on_detach.is_some() {
+            tab = self.bind_tab_drag(tab, index, value, context);
+        }
+        tab.into_any_element()
+    }
+
+    fn bind_tab_drag(
+        &self,
+        tab: gpui::Stateful<gpui::Div>,
+        index: usize,
+        value: SharedString,
+        context: &Rc<TabContext>,
+    ) -> gpui::Stateful<gpui::Div> {
+        let drag = DockTabDrag {
+            strip_id: context.strip_id.clone(),
+            value,
+            index,
+        };
+        let tab = tab.on_drag(drag, |_drag, _, _, cx| cx.new(|_| EmptyView));
+        if !self.reorderable {
+            return tab;
+        }
+        let drop_indicator =
+            resolve_hsla(&self.theme, self.theme.components.dock_tabs.drop_indicator);
+        let context = context.clone();
+        tab.drag_over::<DockTabDrag>(move |style, _, _, _| {
+            style.border_l_2().border_color(drop_indicator)
+        })
+        .on_drop::<DockTabDrag>(move |drag, window, cx| {
+            if drag.strip_id != context.strip_id {
+                return;
+            }
+            dock_tabs_state::set_detach_pending(&context.strip_id, None);
+            Self::commit_order(&context, drag.index, index, window, cx);
+        })
+    }
+
+    fn render_overflow(
+        &self,
+        items: &[DockTab],
+        hidden: &[usize],
+        context: &Rc<TabContext>,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.dock_tabs;
+        let fg = resolve_hsla(&self.theme, tokens.tab_fg);
+        let hover_bg = resolve_hsla(&self.theme, tokens.tab_hover_bg);
+        let icon = self
+            .id
+            .ctx()
+            .child("overflow-icon", Icon::named("chevron-down"))
+            .size(14.0)
+            .color(fg);
+        let trigger = div()
+            .flex()
+            .items_center()
+            .justify_center()
+            .w(tokens.overflow_button_width)
+            .h(tokens.tab_height)
+            .hover(move |style| style.bg(hover_bg))
+            .child(icon);
+        let entries = hidden.iter().map(|index| {
+            let item = &items[*index];
+            MenuItem::labeled(item.value.clone(), item.display_label()).disabled(item.disabled)
+        });
+        let select = context.select.clone();
+        let menu = self
+            .id
+            .ctx()
+            .child("overflow", Menu::new())
+            .trigger(trigger)
+            .items(entries)
+            .on_item_click(move |value, window, cx| (select)(value, window, cx));
+        div().flex_none().child(menu).into_any_element()
+    }
+
+    fn bind_detach(
+        strip: gpui::Stateful<gpui::Div>,
+        strip_id: String,
+        on_detach: DetachHandler,
+    ) -> gpui::Stateful<gpui::Div> {
+        strip
+            .on_drag_move::<DockTabDrag>({
+                let strip_id = strip_id.clone();
+                move |event, _, cx| {
+                    let drag = event.drag(cx);
+                    if drag.strip_id != strip_id {
+                        return;
+                    }
+                    let bounds = event.bounds;
+                    let position = event.event.position;
+                    let above = f32::from(bounds.origin.y - position.y);
+                    let below = f32::from(position.y - bounds.bottom());
+                    let outside = above.max(below);
+                    let pending = dock_tabs_state::is_detach_distance(outside, DETACH_THRESHOLD_PX)
+                        .then(|| drag.value.to_string());
+                    dock_tabs_state::set_detach_pending(&strip_id, pending);
+                }
+            })
+            .on_mouse_up_out(MouseButton::Left, move |event, window, cx| {
+                if let Some(value) = dock_tabs_state::take_detach_pending(&strip_id) {
+                    (on_detach)(SharedString::from(value), event.position, window, cx);
+                    window.refresh();
+                }
+            })
+    }
+}
+
+impl MotionAware for DockTabs {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for DockTabs {
+    fn render(mut self, _window: &mut gpui::Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let tokens = self.theme.components.dock_tabs.clone();
+        let order = self.resolved_order();
+        let selected = self.resolved_value(&order);
+        let strip_id = self.id.to_string();
+        let context = Rc::new(TabContext {
+            strip_id: strip_id.clone(),
+            order: order.clone(),
+            order_controlled: self.order.is_some(),
+            select: self.select_handler(),
+            on_close: self.on_close.clone(),
+            on_reorder: self.on_reorder.clone(),
+        });
+
+        let mut items = std::mem::take(&mut self.items);
+        items.sort_by_key(|item| {
+            order
+                .iter()
+                .position(|value| value.as_str() == item.value.as_ref())
+        });
+        let active = items.iter().position(|item| {
+            selected
+                .as_ref()
+                .is_some_and(|value| value.as_ref() == item.value.as_ref())
+        });
+        let fallback_width = f32::from(tokens.tab_max_width);
+        let widths = items
+            .iter()
+            .map(|item| {
+                dock_tabs_state::tab_width(&strip_id, &item.value).unwrap_or(fallback_width)
+            })
+            .collect::<Vec<_>>();
+        let (visible, hidden) = dock_tabs_state::partition(
+            &widths,
+            dock_tabs_state::strip_width(&strip_id),
+            f32::from(tokens.overflow_button_width),
+            active,
+        );
+
+        let mut roving = Vec::new();
+        let tabs = visible
+            .iter()
+            .map(|index| {
+                let item = &items[*index];
+                let is_active = Some(*index) == active;
+                self.render_tab(*index, item, is_active, &context, &mut roving, _cx)
+            })
+            .collect::<Vec<_>>();
+        let panel = active.and_then(|index| items[index].panel.take());
+
+        let measure = canvas(
+            {
+                let strip_id = strip_id.clone();
+                move |bounds, window, _| {
+                    let width = f32::from(bounds.size.width);
+                    if dock_tabs_state::store_strip_width(&strip_id, width) {
+                        window.refresh();
+                    }
+                }
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let mut strip = div()
+            .id(self.id.slot("strip"))
+            .relative()
+            .flex()
+            .flex_row()
+            .items_center()
+            .w_full()
+            .h(tokens.tab_height)
+            .overflow_hidden()
+            .bg(resolve_hsla(&self.theme, tokens.strip_bg))
+            .border_b_1()
+            .border_color(resolve_hsla(&self.theme, tokens.strip_border))
+            .child(measure)
+            .children(tabs);
+        if self.reorderable {
+            let last = order.len().saturating_sub(1);
+            let context = context.clone();
+            let drop_target = div()
+                .id(self.id.slot("strip-end"))
+                .flex_1()
+                .h_full()
+                .on_drop::<DockTabDrag>(move |drag, window, cx| {
+                    if drag.strip_id != context.strip_id {
+                        return;
+                    }
+                    dock_tabs_state::set_detach_pending(&context.strip_id, None);
+                    Self::commit_order(&context, drag.index, last, window, cx);
+                });
+            strip = strip.child(drop_target);
+        }
+        if !hidden.is_empty() {
+            strip = strip.child(self.render_overflow(&items, &hidden, &context));
+        }
+        strip = bind_roving_focus(strip, roving, FocusOrientation::Horizontal, true);
+        if let Some(on_detach) = self.on_detach.clone() {
+            strip = Self::bind_detach(strip, strip_id.clone(), on_detach);
+        }
+
+        let mut root = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .w_full()
+            .child(strip);
+        if let Some(panel) = panel {
+            root = root.child(div().w_full().flex_1().min_h_0().child(panel()));
+        }
+        root.with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
+
+crate::impl_disableable!(DockTab, |this, value| this.disabled = value);
@@ -0,0 +1,125 @@
+use super::control;
+
+/// Reconciles a preferred tab order with the values passed this frame:
+/// known values keep their position, new ones are appended in the order
+/// given and removed ones are dropped.
+pub fn merge_order(preferred: Vec<String>, values: &[String]) -> Vec<String> {
+    let mut order = preferred
+        .into_iter()
+        .filter(|value| values.contains(value))
+        .collect::<Vec<_>>();
+    for value in values {
+        if !order.contains(value) {
+            order.push(value.clone());
+        }
+    }
+    order
+}
+
+pub fn resolve_order(id: &str, values: &[String]) -> Vec<String> {
+    merge_order(control::list_state(id, "order", None, Vec::new()), values)
+}
+
+pub fn set_order(id: &str, order: Vec<String>) {
+    control::set_list_state(id, "order", order);
+}
+
+/// Moves `from` onto the slot of `to`, shifting the tabs in between.
+pub fn move_tab(order: &[String], from: usize, to: usize) -> Vec<String> {
+    let mut order = order.to_vec();
+    if from >= order.len() || from == to {
+        return order;
+    }
+    let moved = order.remove(from);
+    order.insert(to.min(order.len()), moved);
+    order
+}
+
+/// Splits tab indices into the ones shown in the strip and the ones listed
+/// in the overflow menu. The active tab always stays in the strip, taking
+/// the place of the last tab that would otherwise fit.
+pub fn partition(
+    widths: &[f32],
+    available: Option<f32>,
+    overflow_width: f32,
+    active: Option<usize>,
+) -> (Vec<usize>, Vec<usize>) {
+    let all = (0..widths.len()).collect::<Vec<_>>();
+    let Some(available) = available.filter(|available| *available > 0.0) else {
+        return (all, Vec::new());
+    };
+    if widths.iter().sum::<f32>() <= available {
+        return (all, Vec::new());
+    }
+
+    let budget = available - overflow_width.max(0.0);
+    let mut used = 0.0;
+    let mut visible = Vec::new();
+    for (index, width) in widths.iter().enumerate() {
+        if used + width > budget {
+            break;
+        }
+        used += width;
+        visible.push(index);
+    }
+    if let Some(active) = active.filter(|active| *active < widths.len())
+        && !visible.contains(&active)
+    {
+        while !visible.is_empty() && used + widths[active] > budget {
+            if let Some(last) = visible.pop() {
+                used -= widths[last];
+            }
+        }
+        visible.push(active);
+    }
+    let hidden = all
+        .into_iter()
+        .filter(|index| !visible.contains(index))
+        .collect();
+    (visible, hidden)
+}
+
+pub fn store_tab_width(id: &str, value: &str, width: f32) -> bool {
+    let slot = format!("tab-width:{value}");
+    let previous = control::optional_f32_state(id, &slot, None, None);
+    if previous.is_some_and(|previous| (previous - width).abs() < 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, &slot, Some(width));
+    true
+}
+
+pub fn tab_width(id: &str, value: &str) -> Option<f32> {
+    control::optional_f32_state(id, &format!("tab-width:{value}"), None, None)
+}
+
+pub fn store_strip_width(id: &str, width: f32) -> bool {
+    let previous = strip_width(id);
+    if previous.is_some_and(|previous| (previous - width).abs() < 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, "strip-width", Some(width));
+    true
+}
+
+pub fn strip_width(id: &str) -> Option<f32> {
+    control::optional_f32_state(id, "strip-width", None, None)
+}
+
+/// Whether a pointer at `distance` px beyond the strip edge tears the tab
+/// off instead of reordering it.
+pub fn is_detach_distance(distance: f32, threshold: f32) -> bool {
+    distance > threshold.max(0.0)
+}
+
+pub fn set_detach_pending(id: &str, value: Option<String>) {
+    control::set_optional_text_state(id, "detach", value);
+}
+
+pub fn take_detach_pending(id: &str) -> Option<String> {
+    let value = control::optional_text_state(id, "detach", None, None);
+    if value.is_some() {
+        set_detach_pending(id, None);
+    }
+    value
+}
@@ -0,0 +1,245 @@
+use std::f32::consts::TAU;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{
+    Bounds, Hsla, IntoElement, ParentElement, PathBuilder, Pixels, RenderOnce, SharedString,
+    Styled, Window, div, point, px,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+
+use super::chart::{self, ChartDatum, ValueFormatter};
+use super::chart_state;
+use super::utils::resolve_hsla;
+
+/// Angle left empty between neighbouring segments, in radians.
+const SEGMENT_GAP: f32 = 0.02;
+/// How far the hovered segment grows past the ring, in px.
+const HOVER_GROW: f32 = 3.0;
+
+/// A ring split into one segment per [`ChartDatum`], with the total or a
+/// custom label in the middle.
+#[derive(IntoElement)]
+pub struct DonutChart {
+    pub(crate) id: ComponentId,
+    data: Vec<ChartDatum>,
+    size_px: Option<f32>,
+    thickness_px: Option<f32>,
+    label: Option<SharedString>,
+    show_legend: bool,
+    show_tooltip: bool,
+    value_format: Option<ValueFormatter>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl DonutChart {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            data: Vec::new(),
+            size_px: None,
+            thickness_px: None,
+            label: None,
+            show_legend: true,
+            show_tooltip: true,
+            value_format: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    pub fn data(mut self, data: impl IntoIterator<Item = ChartDatum>) -> Self {
+        self.data = data.into_iter().collect();
+        self
+    }
+
+    /// Outer diameter of the ring.
+    pub fn size(mut self, value: f32) -> Self {
+        self.size_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn thickness(mut self, value: f32) -> Self {
+        self.thickness_px = Some(value.max(1.0));
+        self
+    }
+
+    /// Text in the middle of the ring. Defaults to the formatted total.
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    pub fn show_legend(mut self, value: bool) -> Self {
+        self.show_legend = value;
+        self
+    }
+
+    pub fn show_tooltip(mut self, value: bool) -> Self {
+        self.show_tooltip = value;
+        self
+    }
+
+    pub fn value_format(mut self, formatter: impl Fn(f32) -> SharedString + 'static) -> Self {
+        self.value_format = Some(Rc::new(formatter));
+        self
+    }
+}
+
+impl MotionAware for DonutChart {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+/// Fills the part of a ring between `outer` and `inner` radius that spans
+/// `start..end`, measured clockwise from twelve o'clock.
+fn paint_segment(
+    window: &mut Window,
+    bounds: Bounds<Pixels>,
+    (start, end): (f32, f32),
+    (outer, inner): (f32, f32),
+    color: Hsla,
+) {
+    let center = bounds.center();
+    let at = |radius: f32, angle: f32| {
+        point(
+            center.x + px(radius * angle.sin()),
+            center.y - px(radius * angle.cos()),
+        )
+    };
+    let steps = ((end - start) / TAU * 96.0).ceil().max(2.0) as usize;
+    let angle = |step: usize| start + (end - start) * step as f32 / steps as f32;
+    let mut builder = PathBuilder::fill();
+    builder.move_to(at(outer, start));
+    for step in 1..=steps {
+        builder.line_to(at(outer, angle(step)));
+    }
+    for step in (0..=steps).rev() {
+        builder.line_to(at(inner, angle(step)));
+    }
+    builder.close();
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+impl RenderOnce for DonutChart {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.chart.clone();
+        let diameter = self.size_px.unwrap_or(f32::from(tokens.donut_size));
+        let thickness = self
+            .thickness_px
+            .unwrap_or(f32::from(tokens.donut_thickness))
+            .min(diameter / 2.0);
+        let outer = diameter / 2.0 - HOVER_GROW;
+        let inner = (outer - thickness).max(0.0);
+        let count = self.data.len();
+        let colors = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, datum)| {
+                datum
+                    .color
+                    .unwrap_or_else(|| chart::series_color(&self.theme, index))
+            })
+            .collect::<Vec<_>>();
+        let values = self
+            .data
+            .iter()
+            .map(|datum| datum.value)
+            .collect::<Vec<_>>();
+        let segments = chart_state::donut_segments(&values);
+        let hovered = chart_state::hovered(&self.id).filter(|index| *index < count);
+        let track = resolve_hsla(&self.theme, tokens.donut_track);
+        let paint_colors = colors.clone();
+
+        let plot = chart::plot_layer(
+            &self.id,
+            values.clone(),
+            chart::data_motion(self.motion, cx),
+            move |bounds, values, window| {
+                let segments = chart_state::donut_segments(values);
+                let drawn = segments.iter().filter(|(start, end)| end > start).count();
+                if drawn == 0 {
+                    paint_segment(window, bounds, (0.0, TAU), (outer, inner), track);
+                    return;
+                }
+                let gap = if drawn > 1 { SEGMENT_GAP } else { 0.0 };
+                for (index, (start, end)) in segments.into_iter().enumerate() {
+                    if end - start <= gap {
+                        continue;
+                    }
+                    let (color, reach) = match hovered {
+                        Some(hovered) if hovered == index => (paint_colors[index], HOVER_GROW),
+                        Some(_) => (paint_colors[index].opacity(0.55), 0.0),
+                        None => (paint_colors[index], 0.0),
+                    };
+                    let span = (start + gap / 2.0, end - gap / 2.0);
+                    paint_segment(window, bounds, span, (outer + reach, inner), color);
+                }
+            },
+        );
+
+        let center_label = self.label.clone().unwrap_or_else(|| {
+            let total = values.iter().map(|value| value.max(0.0)).sum::<f32>();
+            chart::format_with(self.value_format.as_ref(), total)
+        });
+        let tooltip = hovered.filter(|_| self.show_tooltip).map(|index| {
+            let datum = &self.data[index];
+            let (start, end) = segments[index];
+            let middle = (start + end) / 2.0;
+            let at = (
+                diameter / 2.0 + outer * middle.sin(),
+                diameter / 2.0 - outer * middle.cos(),
+            );
+            let value = chart::format_with(self.value_format.as_ref(), datum.value);
+            let rows = vec![(colors[index], datum.label.clone(), value)];
+            let bubble = chart::tooltip_bubble(&self.theme, window, None, rows);
+            chart::tooltip_at(&self.id, &self.theme, at, bubble)
+        });
+        let ring = div()
+            .id(self.id.slot("plot"))
+            .relative()
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .size(px(diameter))
+            .text_size(tokens.center_size)
+            .text_color(resolve_hsla(&self.theme, tokens.center_fg))
+            .child(plot)
+            .child(center_label)
+            .children(tooltip);
+        let ring = chart::track_hover(ring, &self.id, move |(x, y), (width, height)| {
+            let offset = (x - width / 2.0, y - height / 2.0);
+            chart_state::donut_segment_at(offset, outer + HOVER_GROW, inner, &segments)
+        });
+
+        let legend = (self.show_legend && count > 0).then(|| {
+            let items = self
+                .data
+                .iter()
+                .zip(&colors)
+                .map(|(datum, color)| (*color, datum.label.clone()))
+                .collect();
+            chart::legend(&self.theme, items)
+        });
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .items_center()
+            .gap(tokens.legend_gap)
+            .child(ring)
+            .children(legend)
+    }
+}
@@ -4,21 +4,27 @@ use std::rc::Rc;
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
 use gpui::{
-    AnyElement, ClickEvent, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window,
-    div, px,
+    AnyElement, ClickEvent, IntoElement, MouseButton, ParentElement, RenderOnce, SharedString,
+    Styled, Window, div, px,
 };
 
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
 use crate::motion::MotionConfig;
 
+use super::control;
+use super::drawer_state;
 use super::icon::Icon;
 use super::overlay::{Overlay, OverlayCoverage, OverlayMaterialMode};
 use super::popup_state::{self, PopupStateInput, PopupStateValue};
-use super::utils::resolve_hsla;
+use super::utils::{hit_area, hit_target_inset, resolve_hsla};
 
 type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
 type CloseHandler = Rc<dyn Fn(&mut Window, &mut gpui::App)>;
+type ResizeHandler = Rc<dyn Fn(f32, &mut Window, &mut gpui::App)>;
+
+const RESIZE_HANDLE_PX: f32 = 6.0;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum DrawerPlacement {
@@ -37,12 +43,18 @@ pub struct Drawer {
     body: Option<SharedString>,
     placement: DrawerPlacement,
     size_px: f32,
+    resizable: bool,
+    min_size_px: f32,
+    max_size_px: Option<f32>,
+    snap_points: Vec<f32>,
     close_button: bool,
     close_on_click_outside: bool,
+    close_on_escape: bool,
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
     content: Option<SlotRenderer>,
     on_close: Option<CloseHandler>,
+    on_resize: Option<ResizeHandler>,
 }
 
 impl Drawer {
@@ -56,12 +68,18 @@ impl Drawer {
             body: None,
             placement: DrawerPlacement::Right,
             size_px: 360.0,
+            resizable: false,
+            min_size_px: 160.0,
+            max_size_px: None,
+            snap_points: Vec::new(),
             close_button: true,
             close_on_click_outside: true,
+            close_on_escape: true,
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::default(),
             content: None,
             on_close: None,
+            on_resize: None,
         }
     }
 
@@ -93,6 +111,41 @@ impl Drawer {
         self
     }
 
+    /// Lets the user drag the panel's inner edge to resize it. `size` becomes
+    /// the initial size; the dragged size is kept until the drawer is rebuilt
+    /// with a different id.
+    pub fn resizable(mut self, value: bool) -> Self {
+        self.resizable = value;
+        self
+    }
+
+    pub fn min_size(mut self, value: f32) -> Self {
+        self.min_size_px = value.max(0.0);
+        self
+    }
+
+    pub fn max_size(mut self, value: f32) -> Self {
+        self.max_size_px = Some(value.max(0.0));
+        self
+    }
+
+    /// Fractions of the window width (left/right) or height (top/bottom) the
+    /// panel settles on when a resize drag ends, such as `[0.3, 0.5, 1.0]`.
+    pub fn snap_points(mut self, value: impl IntoIterator<Item = f32>) -> Self {
+        self.snap_points = value.into_iter().collect();
+        self
+    }
+
+    /// Called with the settled size after each resize, e.g. to persist it and
+    /// pass it back through `size` next time.
+    pub fn on_resize(
+        mut self,
+        handler: impl Fn(f32, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Rc::new(handler));
+        self
+    }
+
     pub fn close_button(mut self, value: bool) -> Self {
         self.close_button = value;
         self
@@ -103,6 +156,11 @@ impl Drawer {
         self
     }
 
+    pub fn close_on_escape(mut self, value: bool) -> Self {
+        self.close_on_escape = value;
+        self
+    }
+
     pub fn content(mut self, content: impl IntoElement + 'static) -> Self {
         self.content = Some(Box::new(|| content.into_any_element()));
         self
@@ -122,6 +180,60 @@ impl Drawer {
         })
         .opened
     }
+
+    fn is_horizontal(&self) -> bool {
+        matches!(
+            self.placement,
+            DrawerPlacement::Left | DrawerPlacement::Right
+        )
+    }
+
+    /// Pointer direction that grows the panel along its resize axis.
+    fn resize_direction(&self) -> f32 {
+        match self.placement {
+            DrawerPlacement::Left | DrawerPlacement::Top => 1.0,
+            DrawerPlacement::Right | DrawerPlacement::Bottom => -1.0,
+        }
+    }
+
+    fn render_resize_handle(&self, size: f32, hover_bg: gpui::Hsla) -> AnyElement {
+        let handle_id = self.id.to_string();
+        let horizontal = self.is_horizontal();
+        let handle = div()
+            .id(self.id.slot("resize-handle"))
+            .absolute()
+            .hover(move |style| style.bg(hover_bg));
+        let handle = match self.placement {
+            DrawerPlacement::Left => handle.top_0().right_0().h_full(),
+            DrawerPlacement::Right => handle.top_0().left_0().h_full(),
+            DrawerPlacement::Top => handle.bottom_0().left_0().w_full(),
+            DrawerPlacement::Bottom => handle.top_0().left_0().w_full(),
+        };
+        let handle = if horizontal {
+            handle.w(px(RESIZE_HANDLE_PX)).cursor_col_resize()
+        } else {
+            handle.h(px(RESIZE_HANDLE_PX)).cursor_row_resize()
+        };
+        handle
+            .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                let pointer = if horizontal {
+                    event.position.x
+                } else {
+                    event.position.y
+                };
+                drawer_state::begin_resize(&handle_id, f32::from(pointer), size);
+                cx.stop_propagation();
+                window.refresh();
+            })
+            .into_any_element()
+    }
+
+    fn dismiss(id: &ComponentId, window: &mut Window, cx: &mut gpui::App) {
+        if crate::provider::CalmProvider::focus(cx).release(id, window, cx) {
+            let event = InteractionEvent::new("drawer", id, InteractionAction::Close);
+            interaction::emit(event, cx);
+        }
+    }
 }
 
 impl Drawer {}
@@ -137,9 +249,16 @@ impl RenderOnce for Drawer {
     fn render(mut self, window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
         self.theme.sync_from_provider(_cx);
         let opened = self.resolved_opened();
+        let focus = crate::provider::CalmProvider::focus(_cx);
         if !opened {
+            Self::dismiss(&self.id, window, _cx);
             return div().id(self.id);
         }
+        if !focus.is_trapped(&self.id) {
+            let event = InteractionEvent::new("drawer", &self.id, InteractionAction::Open);
+            interaction::emit(event, _cx);
+        }
+        let focus_root = focus.trap(&self.id, window, _cx);
 
         let is_controlled = self.opened.is_some();
         let tokens = &self.theme.components.drawer;
@@ -163,6 +282,7 @@ impl RenderOnce for Drawer {
                         if popup_state::on_close_request(&drawer_id_for_overlay, is_controlled) {
                             window.refresh();
                         }
+                        Self::dismiss(&drawer_id_for_overlay, window, cx);
                         if let Some(handler) = outside_on_close.as_ref() {
                             (handler)(window, cx);
                         }
@@ -175,6 +295,30 @@ impl RenderOnce for Drawer {
             let on_close = self.on_close.clone();
             let close_id = self.id.clone();
             let close_fg = resolve_hsla(&self.theme, tokens.title);
+            let close_click = move |_: &ClickEvent, window: &mut Window, cx: &mut gpui::App| {
+                if popup_state::on_close_request(&close_id, is_controlled) {
+                    window.refresh();
+                }
+                Self::dismiss(&close_id, window, cx);
+                if let Some(handler) = on_close.as_ref() {
+                    (handler)(window, cx);
+                }
+            };
+            let close_icon = self
+                .id
+                .ctx()
+                .child("close-icon", Icon::named("x"))
+                .size(f32::from(tokens.close_icon_size))
+                .color(close_fg);
+            let close_hit_area = hit_area(
+                self.id.slot("close-hit-area"),
+                hit_target_inset(
+                    tokens.close_size,
+                    crate::provider::CalmProvider::min_hit_target(_cx),
+                ),
+            )
+            .map(|area| area.on_click(close_click.clone()).into_any_element());
+            let expanded_hit_area = close_hit_area.is_some();
             close_action = div()
                 .id(self.id.slot("close"))
                 .w(tokens.close_size)
@@ -188,21 +332,25 @@ impl RenderOnce for Drawer {
                 .cursor_pointer()
                 .text_color(close_fg)
                 .hover(|style| style.opacity(0.82))
-                .child(
-                    self.id
-                        .ctx()
-                        .child("close-icon", Icon::named("x"))
-                        .size(f32::from(tokens.close_icon_size))
-                        .color(close_fg),
-                )
-                .on_click(move |_, window, cx| {
-                    if popup_state::on_close_request(&close_id, is_controlled) {
-                        window.refresh();
-                    }
-                    if let Some(handler) = on_close.as_ref() {
-                        (handler)(window, cx);
+                .children(
+                    Some(close_icon.into_any_element())
+                        .into_iter()
+                        .chain(close_hit_area),
+                );
+            let close_key = close_click.clone();
+            close_action = close_action
+                .track_focus(&focus.handle(&self.id, "close", _cx))
+                .on_key_down(move |event, window, cx| {
+                    if control::is_activation_keystroke(event) {
+                        (close_key)(&ClickEvent::default(), window, cx);
+                        cx.stop_propagation();
                     }
                 });
+            close_action = if expanded_hit_area {
+                close_action.relative()
+            } else {
+                close_action.on_click(close_click)
+            };
         }
 
         let mut body = div();
@@ -249,9 +397,30 @@ impl RenderOnce for Drawer {
             panel = panel.child(content());
         }
 
+        let viewport = window.viewport_size();
+        let available = f32::from(if self.is_horizontal() {
+            viewport.width
+        } else {
+            viewport.height
+        });
+        let (min_size, max_size) =
+            drawer_state::size_bounds(self.min_size_px, self.max_size_px, available);
+        let size = if self.resizable {
+            let resting = drawer_state::size(&self.id, self.size_px).clamp(min_size, max_size);
+            drawer_state::resize_size(&self.id).unwrap_or(resting)
+        } else {
+            self.size_px
+        };
+        if self.resizable {
+            let hover_bg = resolve_hsla(&self.theme, self.theme.semantic.border_subtle);
+            panel = panel
+                .relative()
+                .child(self.render_resize_handle(size, hover_bg));
+        }
+
         panel = match self.placement {
-            DrawerPlacement::Left | DrawerPlacement::Right => panel.w(px(self.size_px)).h_full(),
-            DrawerPlacement::Top | DrawerPlacement::Bottom => panel.h(px(self.size_px)).w_full(),
+            DrawerPlacement::Left | DrawerPlacement::Right => panel.w(px(size)).h_full(),
+            DrawerPlacement::Top | DrawerPlacement::Bottom => panel.h(px(size)).w_full(),
         };
 
         let panel = panel.with_enter_transition(self.id.slot("panel-enter"), self.motion);
@@ -263,12 +432,54 @@ impl RenderOnce for Drawer {
             DrawerPlacement::Bottom => div().absolute().bottom_0().left_0().w_full().child(panel),
         };
 
-        div()
-            .id(self.id)
-            .absolute()
-            .top_0()
-            .left_0()
-            .size_full()
+        let horizontal = self.is_horizontal();
+        let direction = self.resize_direction();
+        let snaps = drawer_state::snap_sizes(&self.snap_points, available, min_size, max_size);
+        let move_id = self.id.to_string();
+        let release_id = self.id.to_string();
+        let on_resize = self.on_resize.clone();
+        let close_on_escape = self.close_on_escape;
+        let escape_id = self.id.clone();
+        let escape_on_close = self.on_close.clone();
+        let root = div().id(self.id).absolute().top_0().left_0();
+        let root = root.size_full();
+        focus
+            .scope(&focus_root, root)
+            .on_mouse_move(move |event, window, _| {
+                let pointer = if horizontal {
+                    event.position.x
+                } else {
+                    event.position.y
+                };
+                let pointer = f32::from(pointer);
+                if drawer_state::resize_to(&move_id, pointer, direction, min_size, max_size)
+                    .is_some()
+                {
+                    window.refresh();
+                }
+            })
+            .on_mouse_up(MouseButton::Left, move |_, window, cx| {
+                let Some(size) = drawer_state::end_resize(&release_id) else {
+                    return;
+                };
+                let size = drawer_state::nearest_snap(&snaps, size);
+                drawer_state::set_size(&release_id, size);
+                if let Some(handler) = on_resize.as_ref() {
+                    (handler)(size, window, cx);
+                }
+                window.refresh();
+            })
+            .on_key_down(move |event, window, cx| {
+                if close_on_escape && control::is_escape_keystroke(event) {
+                    if popup_state::on_close_request(&escape_id, is_controlled) {
+                        window.refresh();
+                    }
+                    Self::dismiss(&escape_id, window, cx);
+                    if let Some(handler) = escape_on_close.as_ref() {
+                        (handler)(window, cx);
+                    }
+                }
+            })
             .child(overlay)
             .child(host)
     }
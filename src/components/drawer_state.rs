@@ -0,0 +1,64 @@
+use super::control;
+
+/// Effective `(min, max)` panel size; the max never exceeds the space
+/// available and the min never exceeds the max.
+pub fn size_bounds(min: f32, max: Option<f32>, available: f32) -> (f32, f32) {
+    let available = available.max(0.0);
+    let max = max.map_or(available, |max| max.min(available));
+    (min.min(max), max)
+}
+
+pub fn snap_sizes(points: &[f32], available: f32, min: f32, max: f32) -> Vec<f32> {
+    let available = available.max(0.0);
+    let mut sizes: Vec<f32> = points
+        .iter()
+        .filter(|point| point.is_finite())
+        .map(|point| (point.clamp(0.0, 1.0) * available).clamp(min, max))
+        .collect();
+    sizes.sort_by(f32::total_cmp);
+    sizes.dedup_by(|a, b| (*a - *b).abs() < 0.5);
+    sizes
+}
+
+pub fn nearest_snap(sizes: &[f32], size: f32) -> f32 {
+    sizes
+        .iter()
+        .copied()
+        .min_by(|a, b| (*a - size).abs().total_cmp(&(*b - size).abs()))
+        .unwrap_or(size)
+}
+
+pub fn size(id: &str, default: f32) -> f32 {
+    control::optional_f32_state(id, "size", None, None).unwrap_or(default)
+}
+
+pub fn set_size(id: &str, value: f32) {
+    control::set_optional_f32_state(id, "size", Some(value));
+}
+
+pub fn begin_resize(id: &str, pointer: f32, size: f32) {
+    control::set_f32_state(id, "resize-origin", pointer);
+    control::set_f32_state(id, "resize-origin-size", size);
+    control::set_optional_f32_state(id, "resize-size", Some(size));
+}
+
+pub fn resize_size(id: &str) -> Option<f32> {
+    control::optional_f32_state(id, "resize-size", None, None)
+}
+
+/// `direction` is `1.0` when the panel grows as the pointer moves towards
+/// larger coordinates (left/top drawers) and `-1.0` otherwise.
+pub fn resize_to(id: &str, pointer: f32, direction: f32, min: f32, max: f32) -> Option<f32> {
+    resize_size(id)?;
+    let origin = control::f32_state(id, "resize-origin", None, pointer);
+    let origin_size = control::f32_state(id, "resize-origin-size", None, 0.0);
+    let size = (origin_size + (pointer - origin) * direction).clamp(min, max.max(min));
+    control::set_optional_f32_state(id, "resize-size", Some(size));
+    Some(size)
+}
+
+pub fn end_resize(id: &str) -> Option<f32> {
+    let size = resize_size(id)?;
+    control::set_optional_f32_state(id, "resize-size", None);
+    Some(size)
+}
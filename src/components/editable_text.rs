@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{LazyLock, Mutex};
+
+use gpui::{
+    FocusHandle, InteractiveElement, IntoElement, MouseButton, ParentElement, RenderOnce,
+    SharedString, Styled, Window, div,
+};
+
+use crate::contracts::Sized;
+use crate::id::ComponentId;
+use crate::style::Size;
+
+use super::control;
+use super::editable_text_state;
+use super::input::TextInput;
+use super::utils::resolve_hsla;
+
+type ChangeHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+type CancelHandler = Rc<dyn Fn(&mut Window, &mut gpui::App)>;
+type Validator = Rc<dyn Fn(&str) -> Result<(), SharedString>>;
+type SaveFuture = Pin<Box<dyn Future<Output = Result<(), SharedString>>>>;
+type SaveHandler = Rc<dyn Fn(SharedString) -> SaveFuture>;
+
+static EDITABLE_TEXT_FOCUS_HANDLES: LazyLock<Mutex<HashMap<String, FocusHandle>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn input_focus_handle(id: &str, cx: &gpui::App) -> FocusHandle {
+    if let Ok(mut handles) = EDITABLE_TEXT_FOCUS_HANDLES.lock() {
+        return handles
+            .entry(id.to_string())
+            .or_insert_with(|| cx.focus_handle())
+            .clone();
+    }
+    cx.focus_handle()
+}
+
+/// What turns an [`EditableText`] into an input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EditTrigger {
+    Click,
+    DoubleClick,
+}
+
+#[derive(Clone)]
+struct EditCommit {
+    id: ComponentId,
+    controlled: bool,
+    current: String,
+    validator: Option<Validator>,
+    on_change: Option<ChangeHandler>,
+    on_cancel: Option<CancelHandler>,
+    save: Option<SaveHandler>,
+}
+
+impl EditCommit {
+    fn confirm(&self, window: &mut Window, cx: &mut gpui::App) {
+        let draft = editable_text_state::draft(&self.id);
+        if let Some(check) = self.validator.as_ref()
+            && let Err(message) = check(&draft)
+        {
+            editable_text_state::set_error(&self.id, Some(message.to_string()));
+            window.refresh();
+            return;
+        }
+        editable_text_state::stop(&self.id);
+        window.refresh();
+        if draft == self.current {
+            return;
+        }
+        self.apply(draft.clone(), window, cx);
+
+        let Some(save) = self.save.as_ref() else {
+            return;
+        };
+        let ticket = editable_text_state::begin_save(&self.id, &draft);
+        let pending = save(draft.into());
+        let window_handle = window.window_handle();
+        let commit = self.clone();
+        cx.spawn(async move |cx| {
+            let result = pending.await;
+            let _ = window_handle.update(cx, |_, window, cx| {
+                if !editable_text_state::finish_save(&commit.id, ticket) {
+                    return;
+                }
+                if let Err(message) = result {
+                    // Roll the optimistic update back.
+                    commit.apply(commit.current.clone(), window, cx);
+                    editable_text_state::set_error(&commit.id, Some(message.to_string()));
+                }
+                window.refresh();
+            });
+        })
+        .detach();
+    }
+
+    fn apply(&self, value: String, window: &mut Window, cx: &mut gpui::App) {
+        if !self.controlled {
+            control::set_text_state(&self.id, "value", value.clone());
+        }
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(value.into(), window, cx);
+        }
+    }
+
+    fn cancel(&self, window: &mut Window, cx: &mut gpui::App) {
+        editable_text_state::stop(&self.id);
+        editable_text_state::set_error(&self.id, None);
+        if let Some(handler) = self.on_cancel.as_ref() {
+            (handler)(window, cx);
+        }
+        window.refresh();
+    }
+}
+
+/// Text that turns into an input when clicked, for renaming in place in
+/// trees, tables and headers. Enter or clicking elsewhere confirms, Escape
+/// cancels.
+#[derive(IntoElement)]
+pub struct EditableText {
+    pub(crate) id: ComponentId,
+    value: Option<SharedString>,
+    default_value: SharedString,
+    placeholder: Option<SharedString>,
+    trigger: EditTrigger,
+    disabled: bool,
+    size: Size,
+    validator: Option<Validator>,
+    on_change: Option<ChangeHandler>,
+    on_cancel: Option<CancelHandler>,
+    save: Option<SaveHandler>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl EditableText {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: None,
+            default_value: SharedString::default(),
+            placeholder: None,
+            trigger: EditTrigger::Click,
+            disabled: false,
+            size: Size::Sm,
+            validator: None,
+            on_change: None,
+            on_cancel: None,
+            save: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_value = value.into();
+        self
+    }
+
+    /// Shown dimmed while the value is empty.
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(value.into());
+        self
+    }
+
+    pub fn trigger(mut self, value: EditTrigger) -> Self {
+        self.trigger = value;
+        self
+    }
+
+    /// Runs on confirm. An `Err` keeps the input open and shows the message
+    /// under it.
+    pub fn validate(mut self, check: impl Fn(&str) -> Result<(), SharedString> + 'static) -> Self {
+        self.validator = Some(Rc::new(check));
+        self
+    }
+
+    /// Called with the new value as soon as an edit is confirmed, and with
+    /// the old one again if [`EditableText::save`] then fails.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_cancel(mut self, handler: impl Fn(&mut Window, &mut gpui::App) + 'static) -> Self {
+        self.on_cancel = Some(Rc::new(handler));
+        self
+    }
+
+    /// Persists a confirmed edit, such as a rename on the server. The new
+    /// value shows dimmed while it runs; an `Err` rolls it back and shows
+    /// the message.
+    pub fn save<F, Fut>(mut self, save: F) -> Self
+    where
+        F: Fn(SharedString) -> Fut + 'static,
+        Fut: Future<Output = Result<(), SharedString>> + 'static,
+    {
+        self.save = Some(Rc::new(move |value| Box::pin(save(value))));
+        self
+    }
+
+    fn resolved_value(&self) -> String {
+        control::text_state(
+            &self.id,
+            "value",
+            self.value.as_ref().map(ToString::to_string),
+            self.default_value.to_string(),
+        )
+    }
+}
+
+crate::impl_disableable!(EditableText, |this, value| this.disabled = value);
+crate::impl_sized_via_method!(EditableText, size);
+
+impl RenderOnce for EditableText {
+    fn render(mut self, _window: &mut Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.editable_text.clone();
+        let value = self.resolved_value();
+        let editing = !self.disabled && editable_text_state::editing(&self.id);
+        let error = editable_text_state::error(&self.id);
+        let focus_handle = input_focus_handle(&self.id, cx);
+        let commit = EditCommit {
+            id: self.id.clone(),
+            controlled: self.value.is_some(),
+            current: value.clone(),
+            validator: self.validator.clone(),
+            on_change: self.on_change.clone(),
+            on_cancel: self.on_cancel.clone(),
+            save: self.save.clone(),
+        };
+
+        let root = div().id(self.id.clone()).flex().flex_col().gap(tokens.gap);
+
+        if editing {
+            let draft_id = self.id.clone();
+            let submit = commit.clone();
+            let mut input = self
+                .id
+                .ctx()
+                .child("input", TextInput::new())
+                .focus_handle(focus_handle)
+                .value(editable_text_state::draft(&self.id))
+                .with_size(self.size)
+                .on_change(move |value: SharedString, window, _| {
+                    editable_text_state::set_draft(&draft_id, value.to_string());
+                    window.refresh();
+                })
+                .on_submit(move |_, window, cx| submit.confirm(window, cx));
+            if let Some(placeholder) = self.placeholder.clone() {
+                input = input.placeholder(placeholder);
+            }
+            if let Some(error) = error {
+                input = input.error(error);
+            }
+            let cancel = commit.clone();
+            return root
+                .on_key_down(move |event, window, cx| {
+                    if control::is_escape_keystroke(event) {
+                        cancel.cancel(window, cx);
+                        cx.stop_propagation();
+                    }
+                })
+                .on_mouse_down_out(move |_, window, cx| commit.confirm(window, cx))
+                .child(input);
+        }
+
+        let pending = editable_text_state::pending(&self.id);
+        let shown = pending.clone().unwrap_or_else(|| value.clone());
+        let (text, color) = match (shown.is_empty(), self.placeholder.clone()) {
+            (true, Some(placeholder)) => (placeholder, tokens.placeholder),
+            _ => (SharedString::from(shown), tokens.fg),
+        };
+        let mut display = div()
+            .id(self.id.slot("display"))
+            .px(tokens.padding_x)
+            .py(tokens.padding_y)
+            .rounded(tokens.radius)
+            .text_size(tokens.text_size)
+            .text_color(resolve_hsla(&self.theme, color))
+            .child(text);
+        if pending.is_some() {
+            display = display.opacity(0.6);
+        }
+        if !self.disabled {
+            let id = self.id.clone();
+            let clicks = match self.trigger {
+                EditTrigger::Click => 1,
+                EditTrigger::DoubleClick => 2,
+            };
+            let hover_bg = resolve_hsla(&self.theme, tokens.hover_bg);
+            display = display
+                .cursor_text()
+                .hover(move |style| style.bg(hover_bg))
+                .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                    if event.click_count < clicks {
+                        return;
+                    }
+                    editable_text_state::start(&id, &value);
+                    window.focus(&focus_handle, cx);
+                    window.refresh();
+                });
+        }
+
+        let error = error.map(|message| {
+            div()
+                .text_size(tokens.error_size)
+                .text_color(resolve_hsla(&self.theme, tokens.error_fg))
+                .child(message)
+        });
+        root.child(display).children(error)
+    }
+}
@@ -0,0 +1,57 @@
+use super::control;
+
+pub fn editing(id: &str) -> bool {
+    control::bool_state(id, "editing", None, false)
+}
+
+/// Swaps to the input with `current` as the draft and any earlier error
+/// cleared.
+pub fn start(id: &str, current: &str) {
+    control::set_text_state(id, "draft", current.to_string());
+    control::set_optional_text_state(id, "error", None);
+    control::set_bool_state(id, "editing", true);
+}
+
+pub fn stop(id: &str) {
+    control::set_bool_state(id, "editing", false);
+}
+
+pub fn draft(id: &str) -> String {
+    control::text_state(id, "draft", None, String::new())
+}
+
+pub fn set_draft(id: &str, value: String) {
+    control::set_text_state(id, "draft", value);
+}
+
+pub fn error(id: &str) -> Option<String> {
+    control::optional_text_state(id, "error", None, None)
+}
+
+pub fn set_error(id: &str, value: Option<String>) {
+    control::set_optional_text_state(id, "error", value);
+}
+
+/// Value shown while a save is in flight, ahead of the owner confirming it.
+pub fn pending(id: &str) -> Option<String> {
+    control::optional_text_state(id, "pending", None, None)
+}
+
+/// Shows `value` optimistically and returns the save's ticket. Starting
+/// another save makes older tickets stale.
+pub fn begin_save(id: &str, value: &str) -> usize {
+    let ticket = control::usize_state(id, "save", None, 0) + 1;
+    control::set_usize_state(id, "save", ticket);
+    control::set_optional_text_state(id, "pending", Some(value.to_string()));
+    ticket
+}
+
+/// Ends the save for `ticket`. Returns `false` when it went stale, in which
+/// case its result should be dropped.
+pub fn finish_save(id: &str, ticket: usize) -> bool {
+    if control::usize_state(id, "save", None, 0) != ticket {
+        return false;
+    }
+    control::set_optional_text_state(id, "pending", None);
+    true
+}
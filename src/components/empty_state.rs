@@ -0,0 +1,202 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{
+    AnyElement, ClickEvent, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window,
+    div,
+};
+
+use crate::contracts::{MotionAware, Sized, Varianted};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::{Size, Variant};
+
+use super::button::Button;
+use super::icon::Icon;
+use super::utils::{PressHandler, resolve_hsla};
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+
+struct EmptyStateAction {
+    label: SharedString,
+    on_click: PressHandler,
+}
+
+/// Placeholder for a view with nothing to show yet: an icon or
+/// illustration, a title, a description and up to two actions.
+#[derive(IntoElement)]
+pub struct EmptyState {
+    pub(crate) id: ComponentId,
+    icon: Option<SharedString>,
+    illustration: Option<SlotRenderer>,
+    title: Option<SharedString>,
+    description: Option<SharedString>,
+    primary_action: Option<EmptyStateAction>,
+    secondary_action: Option<EmptyStateAction>,
+    size: Size,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl EmptyState {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            icon: None,
+            illustration: None,
+            title: None,
+            description: None,
+            primary_action: None,
+            secondary_action: None,
+            size: Size::Md,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    /// Icon name drawn inside a tinted circle.
+    pub fn icon(mut self, name: impl Into<SharedString>) -> Self {
+        self.icon = Some(name.into());
+        self
+    }
+
+    /// Custom artwork. Replaces the icon.
+    pub fn illustration(mut self, content: impl IntoElement + 'static) -> Self {
+        self.illustration = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    pub fn title(mut self, value: impl Into<SharedString>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    pub fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Filled button for the main way out, such as "Create project".
+    pub fn primary_action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.primary_action = Some(EmptyStateAction {
+            label: label.into(),
+            on_click: Rc::new(handler),
+        });
+        self
+    }
+
+    /// Quieter button shown after the primary one, such as "Clear filters".
+    pub fn secondary_action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.secondary_action = Some(EmptyStateAction {
+            label: label.into(),
+            on_click: Rc::new(handler),
+        });
+        self
+    }
+
+    fn render_action(&self, slot: &str, action: EmptyStateAction, variant: Variant) -> AnyElement {
+        let on_click = action.on_click;
+        self.id
+            .ctx()
+            .child(slot, Button::new())
+            .label(action.label)
+            .with_variant(variant)
+            .with_size(self.size)
+            .motion(self.motion)
+            .on_click(move |event, window, cx| (on_click)(event, window, cx))
+            .into_any_element()
+    }
+}
+
+impl MotionAware for EmptyState {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+crate::impl_sized_via_method!(EmptyState, size);
+
+impl RenderOnce for EmptyState {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.empty_state.clone();
+        let preset = tokens.sizes.for_size(self.size);
+
+        let artwork = match self.illustration.take() {
+            Some(render) => Some(render()),
+            None => self.icon.clone().map(|name| {
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .size(preset.icon_box_size)
+                    .rounded_full()
+                    .bg(resolve_hsla(&self.theme, tokens.icon_bg))
+                    .child(
+                        self.id
+                            .ctx()
+                            .child("icon", Icon::named(name.to_string()))
+                            .size(f32::from(preset.icon_size))
+                            .color(resolve_hsla(&self.theme, tokens.icon_fg)),
+                    )
+                    .into_any_element()
+            }),
+        };
+        let title = self.title.clone().map(|title| {
+            div()
+                .text_size(preset.title_size)
+                .text_color(resolve_hsla(&self.theme, tokens.title_fg))
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .child(title)
+        });
+        let description = self.description.clone().map(|description| {
+            div()
+                .max_w(preset.max_width)
+                .text_center()
+                .text_size(preset.description_size)
+                .text_color(resolve_hsla(&self.theme, tokens.description_fg))
+                .child(description)
+        });
+
+        let mut actions = Vec::new();
+        if let Some(action) = self.primary_action.take() {
+            actions.push(self.render_action("primary-action", action, Variant::Filled));
+        }
+        if let Some(action) = self.secondary_action.take() {
+            actions.push(self.render_action("secondary-action", action, Variant::Default));
+        }
+        let actions = (!actions.is_empty()).then(|| {
+            div()
+                .flex()
+                .flex_wrap()
+                .justify_center()
+                .gap(tokens.actions_gap)
+                .mt(preset.gap)
+                .children(actions)
+        });
+
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap(preset.gap)
+            .w_full()
+            .p(preset.padding)
+            .children(artwork)
+            .children(title)
+            .children(description)
+            .children(actions)
+    }
+}
@@ -0,0 +1,498 @@
+use super::transition::TransitionExt;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, ExternalPaths, IntoElement, ParentElement, PathPromptOptions, RenderOnce,
+    SharedString, Styled, Window, div,
+};
+
+use crate::contracts::{FieldLike, MotionAware};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::FieldLayout;
+
+use super::Stack;
+use super::file_input_state;
+use super::icon::Icon;
+use super::interaction_adapter::{PressAdapter, bind_press_adapter};
+use super::utils::{InteractionStyles, apply_interaction_styles, interaction_style, resolve_hsla};
+
+type FilesChangeHandler = Rc<dyn Fn(Vec<PathBuf>, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone)]
+struct FileCommit {
+    id: ComponentId,
+    value_controlled: bool,
+    current: Vec<PathBuf>,
+    accept: Vec<String>,
+    multiple: bool,
+    max_files: Option<usize>,
+    on_change: Option<FilesChangeHandler>,
+    on_reject: Option<FilesChangeHandler>,
+}
+
+impl FileCommit {
+    fn apply(&self, files: Vec<PathBuf>, window: &mut Window, cx: &mut gpui::App) {
+        if !self.value_controlled {
+            file_input_state::store_files(&self.id, &files);
+        }
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(files, window, cx);
+        }
+        window.refresh();
+    }
+
+    fn add(&self, paths: Vec<PathBuf>, window: &mut Window, cx: &mut gpui::App) {
+        let merge = file_input_state::merge_files(
+            &self.current,
+            paths,
+            &self.accept,
+            self.multiple,
+            self.max_files,
+        );
+        if !merge.rejected.is_empty()
+            && let Some(handler) = self.on_reject.as_ref()
+        {
+            (handler)(merge.rejected, window, cx);
+        }
+        if merge.files != self.current {
+            self.apply(merge.files, window, cx);
+        }
+    }
+
+    fn remove(&self, index: usize, window: &mut Window, cx: &mut gpui::App) {
+        self.apply(
+            file_input_state::remove_file(&self.current, index),
+            window,
+            cx,
+        );
+    }
+
+    fn browse(&self, window: &mut Window, cx: &mut gpui::App) {
+        let receiver = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: self.multiple,
+            prompt: None,
+        });
+        let window_handle = window.window_handle();
+        let commit = self.clone();
+        cx.spawn(async move |cx| {
+            let Ok(Ok(Some(paths))) = receiver.await else {
+                return;
+            };
+            let _ = window_handle.update(cx, |_, window, cx| commit.add(paths, window, cx));
+        })
+        .detach();
+    }
+}
+
+#[derive(IntoElement)]
+pub struct FileInput {
+    pub(crate) id: ComponentId,
+    value: Option<Vec<PathBuf>>,
+    default_value: Vec<PathBuf>,
+    accept: Vec<String>,
+    multiple: bool,
+    max_files: Option<usize>,
+    show_size: bool,
+    placeholder: Option<SharedString>,
+    label: Option<SharedString>,
+    description: Option<SharedString>,
+    error: Option<SharedString>,
+    required: bool,
+    layout: FieldLayout,
+    disabled: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<FilesChangeHandler>,
+    on_reject: Option<FilesChangeHandler>,
+}
+
+impl FileInput {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: None,
+            default_value: Vec::new(),
+            accept: Vec::new(),
+            multiple: false,
+            max_files: None,
+            show_size: true,
+            placeholder: None,
+            label: None,
+            description: None,
+            error: None,
+            required: false,
+            layout: FieldLayout::Vertical,
+            disabled: false,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+            on_reject: None,
+        }
+    }
+
+    pub fn value(mut self, value: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.value = Some(value.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn default_value(mut self, value: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.default_value = value.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn accept(mut self, extensions: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.accept = extensions
+            .into_iter()
+            .map(|extension| file_input_state::normalize_extension(extension.as_ref()))
+            .collect();
+        self
+    }
+
+    pub fn multiple(mut self, value: bool) -> Self {
+        self.multiple = value;
+        self
+    }
+
+    pub fn max_files(mut self, value: usize) -> Self {
+        self.max_files = Some(value.max(1));
+        self
+    }
+
+    pub fn show_size(mut self, value: bool) -> Self {
+        self.show_size = value;
+        self
+    }
+
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(value.into());
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(Vec<PathBuf>, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_reject(
+        mut self,
+        handler: impl Fn(Vec<PathBuf>, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_reject = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_files(&self) -> Vec<PathBuf> {
+        file_input_state::resolve_files(&self.id, self.value.as_deref(), &self.default_value)
+    }
+
+    fn commit(&self, current: Vec<PathBuf>) -> FileCommit {
+        FileCommit {
+            id: self.id.clone(),
+            value_controlled: self.value.is_some(),
+            current,
+            accept: self.accept.clone(),
+            multiple: self.multiple,
+            max_files: self.max_files,
+            on_change: self.on_change.clone(),
+            on_reject: self.on_reject.clone(),
+        }
+    }
+
+    fn render_label_block(&self, width: Option<gpui::Pixels>) -> Option<AnyElement> {
+        if self.label.is_none() && self.description.is_none() && self.error.is_none() {
+            return None;
+        }
+        let tokens = &self.theme.components.file_input;
+        let mut block = Stack::vertical().gap(tokens.label_block_gap);
+        if let Some(label) = self.label.clone() {
+            let mut row = Stack::horizontal().gap(tokens.label_row_gap).child(
+                div()
+                    .text_size(tokens.label_size)
+                    .font_weight(tokens.label_weight)
+                    .text_color(resolve_hsla(&self.theme, tokens.label))
+                    .child(label),
+            );
+            if self.required {
+                row = row.child(
+                    div()
+                        .text_color(resolve_hsla(&self.theme, self.theme.semantic.status_error))
+                        .child("*"),
+                );
+            }
+            block = block.child(row);
+        }
+        if let Some(description) = self.description.clone() {
+            block = block.child(
+                div()
+                    .text_size(tokens.description_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.description))
+                    .child(description),
+            );
+        }
+        if let Some(error) = self.error.clone() {
+            block = block.child(
+                div()
+                    .text_size(tokens.error_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.error))
+                    .child(error),
+            );
+        }
+        if let Some(width) = width {
+            block = block.w(width);
+        }
+        Some(block.into_any_element())
+    }
+
+    fn render_dropzone(&self, window: &Window, commit: &FileCommit) -> AnyElement {
+        let tokens = &self.theme.components.file_input;
+        let border = if self.error.is_some() {
+            resolve_hsla(&self.theme, tokens.border_error)
+        } else {
+            resolve_hsla(&self.theme, tokens.border)
+        };
+        let placeholder = self.placeholder.clone().unwrap_or_else(|| {
+            SharedString::from(if self.multiple {
+                "Drop files here or click to browse"
+            } else {
+                "Drop a file here or click to browse"
+            })
+        });
+
+        let mut zone = div()
+            .id(self.id.slot("dropzone"))
+            .w_full()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap(tokens.gap)
+            .p(tokens.padding)
+            .rounded(tokens.radius)
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(border)
+            .child(
+                self.id
+                    .ctx()
+                    .child("upload-icon", Icon::named("upload"))
+                    .size(f32::from(tokens.icon_size))
+                    .color(resolve_hsla(&self.theme, tokens.icon)),
+            )
+            .child(
+                div()
+                    .text_size(tokens.text_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.fg))
+                    .child(placeholder),
+            );
+        if let Some(hint) = file_input_state::accept_hint(&self.accept) {
+            zone = zone.child(
+                div()
+                    .text_size(tokens.hint_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.hint))
+                    .child(hint),
+            );
+        }
+        if self.disabled {
+            return zone.cursor_default().opacity(0.55).into_any_element();
+        }
+
+        let active_bg = resolve_hsla(&self.theme, tokens.bg_active);
+        let active_border = resolve_hsla(&self.theme, tokens.border_active);
+        zone = apply_interaction_styles(
+            zone.cursor_pointer(),
+            InteractionStyles::new()
+                .hover(interaction_style(move |style| {
+                    style.border_color(active_border)
+                }))
+                .focus(interaction_style(move |style| {
+                    style.border_color(active_border)
+                })),
+        )
+        .drag_over::<ExternalPaths>(move |style, _, _, _| {
+            style.bg(active_bg).border_color(active_border)
+        })
+        .on_drop::<ExternalPaths>({
+            let commit = commit.clone();
+            move |paths, window, cx| commit.add(paths.paths().to_vec(), window, cx)
+        });
+        let commit = commit.clone();
+        bind_press_adapter(
+            zone,
+            PressAdapter::new(self.id.slot("dropzone")).on_activate(Some(Rc::new(
+                move |window: &mut Window, cx: &mut gpui::App| commit.browse(window, cx),
+            ))),
+        )
+        .into_any_element()
+    }
+
+    fn render_chip(
+        &self,
+        window: &Window,
+        commit: &FileCommit,
+        index: usize,
+        path: &Path,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.file_input;
+        let mut chip = Stack::horizontal()
+            .items_center()
+            .gap(tokens.chip_gap)
+            .max_w(tokens.chip_max_width)
+            .px(tokens.chip_padding_x)
+            .py(tokens.chip_padding_y)
+            .rounded(tokens.radius)
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(resolve_hsla(&self.theme, tokens.chip_border))
+            .bg(resolve_hsla(&self.theme, tokens.chip_bg))
+            .text_size(tokens.chip_size)
+            .child(
+                self.id
+                    .ctx()
+                    .child_index("file-icon", index.to_string(), Icon::named("file"))
+                    .size(f32::from(tokens.chip_size))
+                    .color(resolve_hsla(&self.theme, tokens.chip_meta)),
+            )
+            .child(
+                div()
+                    .min_w_0()
+                    .truncate()
+                    .text_color(resolve_hsla(&self.theme, tokens.chip_fg))
+                    .child(file_input_state::file_name(path)),
+            );
+        if self.show_size
+            && let Some(size) = file_input_state::file_size(path)
+        {
+            chip = chip.child(
+                div()
+                    .flex_none()
+                    .text_color(resolve_hsla(&self.theme, tokens.chip_meta))
+                    .child(file_input_state::format_file_size(size)),
+            );
+        }
+        if self.disabled {
+            return chip.into_any_element();
+        }
+
+        let remove_id = self.id.slot_index("remove", index.to_string());
+        let hover_bg = resolve_hsla(&self.theme, tokens.chip_remove_hover_bg);
+        let remove = apply_interaction_styles(
+            div()
+                .id(remove_id.clone())
+                .flex_none()
+                .rounded_sm()
+                .cursor_pointer()
+                .child(
+                    self.id
+                        .ctx()
+                        .child_index("remove-icon", index.to_string(), Icon::named("x"))
+                        .size(f32::from(tokens.chip_size))
+                        .color(resolve_hsla(&self.theme, tokens.chip_meta)),
+                ),
+            InteractionStyles::new().hover(interaction_style(move |style| style.bg(hover_bg))),
+        );
+        let commit = commit.clone();
+        chip.child(bind_press_adapter(
+            remove,
+            PressAdapter::new(remove_id).on_activate(Some(Rc::new(
+                move |window: &mut Window, cx: &mut gpui::App| commit.remove(index, window, cx),
+            ))),
+        ))
+        .into_any_element()
+    }
+}
+
+impl FieldLike for FileInput {
+    fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    fn error(mut self, value: impl Into<SharedString>) -> Self {
+        self.error = Some(value.into());
+        self
+    }
+
+    fn required(mut self, value: bool) -> Self {
+        self.required = value;
+        self
+    }
+
+    fn layout(mut self, value: FieldLayout) -> Self {
+        self.layout = value;
+        self
+    }
+}
+
+crate::impl_disableable!(FileInput, |this, value| this.disabled = value);
+
+impl MotionAware for FileInput {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for FileInput {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.file_input.clone();
+        let files = self.resolved_files();
+        let commit = self.commit(files.clone());
+
+        let mut field = Stack::vertical()
+            .gap(tokens.gap)
+            .w_full()
+            .min_w_0()
+            .child(self.render_dropzone(window, &commit));
+        if !files.is_empty() {
+            field = field.child(
+                div().flex().flex_wrap().gap(tokens.chip_gap).children(
+                    files
+                        .iter()
+                        .enumerate()
+                        .map(|(index, path)| self.render_chip(window, &commit, index, path)),
+                ),
+            );
+        }
+
+        let root = match self.layout {
+            FieldLayout::Vertical => {
+                let mut container = Stack::vertical()
+                    .id(self.id.clone())
+                    .gap(tokens.layout_gap_vertical)
+                    .w_full();
+                if let Some(label_block) = self.render_label_block(None) {
+                    container = container.child(label_block);
+                }
+                container.child(field)
+            }
+            FieldLayout::Horizontal => {
+                let mut row = Stack::horizontal()
+                    .id(self.id.clone())
+                    .items_start()
+                    .gap(tokens.layout_gap_horizontal);
+                if let Some(label_block) =
+                    self.render_label_block(Some(tokens.horizontal_label_width))
+                {
+                    row = row.child(label_block);
+                }
+                row.child(field)
+            }
+        };
+        root.with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
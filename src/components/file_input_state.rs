@@ -0,0 +1,146 @@
+use std::path::{Path, PathBuf};
+
+use super::control;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FileMerge {
+    pub files: Vec<PathBuf>,
+    pub rejected: Vec<PathBuf>,
+}
+
+pub fn normalize_extension(value: &str) -> String {
+    value
+        .trim()
+        .trim_start_matches('*')
+        .trim_start_matches('.')
+        .to_ascii_lowercase()
+}
+
+pub fn accepts(path: &Path, accept: &[String]) -> bool {
+    if accept.is_empty() || accept.iter().any(|extension| extension.is_empty()) {
+        return true;
+    }
+    let Some(extension) = path.extension().and_then(|extension| extension.to_str()) else {
+        return false;
+    };
+    let extension = extension.to_ascii_lowercase();
+    accept.iter().any(|candidate| *candidate == extension)
+}
+
+pub fn merge_files(
+    current: &[PathBuf],
+    incoming: impl IntoIterator<Item = PathBuf>,
+    accept: &[String],
+    multiple: bool,
+    max_files: Option<usize>,
+) -> FileMerge {
+    let mut merge = FileMerge {
+        files: if multiple {
+            current.to_vec()
+        } else {
+            Vec::new()
+        },
+        rejected: Vec::new(),
+    };
+    let limit = if multiple {
+        max_files.unwrap_or(usize::MAX)
+    } else {
+        1
+    };
+    for path in incoming {
+        if merge.files.contains(&path) {
+            continue;
+        }
+        if !accepts(&path, accept) {
+            merge.rejected.push(path);
+        } else if merge.files.len() < limit {
+            merge.files.push(path);
+        } else if !multiple {
+            merge.files[0] = path;
+        } else {
+            merge.rejected.push(path);
+        }
+    }
+    merge
+}
+
+pub fn remove_file(current: &[PathBuf], index: usize) -> Vec<PathBuf> {
+    current
+        .iter()
+        .enumerate()
+        .filter(|(position, _)| *position != index)
+        .map(|(_, path)| path.clone())
+        .collect()
+}
+
+pub fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string_lossy().into_owned())
+}
+
+pub fn file_size(path: &Path) -> Option<u64> {
+    std::fs::metadata(path)
+        .ok()
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+}
+
+pub fn format_file_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if value >= 100.0 {
+        format!("{value:.0} {}", UNITS[unit])
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+pub fn accept_hint(accept: &[String]) -> Option<String> {
+    if accept.is_empty() || accept.iter().any(|extension| extension.is_empty()) {
+        return None;
+    }
+    Some(
+        accept
+            .iter()
+            .map(|extension| format!(".{extension}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+pub fn resolve_files(
+    id: &str,
+    controlled: Option<&[PathBuf]>,
+    default: &[PathBuf],
+) -> Vec<PathBuf> {
+    let encode = |paths: &[PathBuf]| {
+        paths
+            .iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+    };
+    control::list_state(id, "files", controlled.map(encode), encode(default))
+        .into_iter()
+        .map(PathBuf::from)
+        .collect()
+}
+
+pub fn store_files(id: &str, files: &[PathBuf]) {
+    control::set_list_state(
+        id,
+        "files",
+        files
+            .iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect(),
+    );
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{LazyLock, Mutex};
+
+use gpui::{
+    AnyElement, FocusHandle, InteractiveElement, IntoElement, KeyDownEvent, ParentElement,
+    RenderOnce, SharedString, Styled, Window, div,
+};
+
+use crate::contracts::{Sized, Varianted};
+use crate::id::ComponentId;
+use crate::style::{Size, Variant};
+
+use super::action_icon::ActionIcon;
+use super::control;
+use super::icon::Icon;
+use super::input::TextInput;
+use super::text_selection::{FindMatch, FindScope};
+use super::utils::resolve_hsla;
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+type NavigateHandler = Rc<dyn Fn(FindMatch, &mut Window, &mut gpui::App)>;
+
+static FIND_QUERY_FOCUS_HANDLES: LazyLock<Mutex<HashMap<String, FocusHandle>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn query_focus_handle(id: &str, cx: &gpui::App) -> FocusHandle {
+    if let Ok(mut handles) = FIND_QUERY_FOCUS_HANDLES.lock() {
+        return handles
+            .entry(id.to_string())
+            .or_insert_with(|| cx.focus_handle())
+            .clone();
+    }
+    cx.focus_handle()
+}
+
+fn is_find_keystroke(event: &KeyDownEvent) -> bool {
+    let modifiers = &event.keystroke.modifiers;
+    (modifiers.control || modifiers.platform)
+        && !modifiers.alt
+        && !modifiers.shift
+        && event.keystroke.key.eq_ignore_ascii_case("f")
+}
+
+fn navigate(
+    scope: &FindScope,
+    forward: bool,
+    on_navigate: Option<&NavigateHandler>,
+    window: &mut Window,
+    cx: &mut gpui::App,
+) {
+    let found = if forward {
+        scope.select_next()
+    } else {
+        scope.select_previous()
+    };
+    if let (Some(found), Some(handler)) = (found, on_navigate) {
+        (handler)(found, window, cx);
+    }
+    window.refresh();
+}
+
+/// Wraps content with a find-in-page bar that opens on Ctrl/Cmd+F while focus
+/// is inside the content and searches the selectable text registered with
+/// its [`FindScope`].
+#[derive(IntoElement)]
+pub struct FindBar {
+    pub(crate) id: ComponentId,
+    scope: FindScope,
+    placeholder: SharedString,
+    no_results_label: SharedString,
+    pub(crate) theme: crate::theme::LocalTheme,
+    content: Option<SlotRenderer>,
+    on_navigate: Option<NavigateHandler>,
+}
+
+impl FindBar {
+    #[track_caller]
+    pub fn new(scope: FindScope) -> Self {
+        Self {
+            id: ComponentId::default(),
+            scope,
+            placeholder: SharedString::from("Find"),
+            no_results_label: SharedString::from("No results"),
+            theme: crate::theme::LocalTheme::default(),
+            content: None,
+            on_navigate: None,
+        }
+    }
+
+    pub fn content(mut self, content: impl IntoElement + 'static) -> Self {
+        self.content = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.placeholder = value.into();
+        self
+    }
+
+    pub fn no_results_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.no_results_label = value.into();
+        self
+    }
+
+    /// Called with the newly active match, e.g. to scroll it into view.
+    pub fn on_navigate(
+        mut self,
+        handler: impl Fn(FindMatch, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_navigate = Some(Rc::new(handler));
+        self
+    }
+
+    fn count_label(&self) -> Option<SharedString> {
+        if self.scope.query().is_empty() {
+            return None;
+        }
+        let count = self.scope.match_count();
+        Some(match self.scope.active_index() {
+            Some(index) => SharedString::from(format!("{}/{count}", index + 1)),
+            None => self.no_results_label.clone(),
+        })
+    }
+
+    fn nav_button(&self, name: &str, icon: &str, forward: bool) -> ActionIcon {
+        let tokens = &self.theme.components.find_bar;
+        let scope = self.scope.clone();
+        let on_navigate = self.on_navigate.clone();
+        self.id
+            .ctx()
+            .child(name, ActionIcon::new())
+            .with_variant(Variant::Subtle)
+            .with_size(Size::Xs)
+            .child(Icon::named(icon).size(f32::from(tokens.count_size)))
+            .on_click(move |_, window, cx| {
+                navigate(&scope, forward, on_navigate.as_ref(), window, cx);
+            })
+    }
+
+    fn render_bar(&self, focus_handle: FocusHandle) -> AnyElement {
+        let tokens = &self.theme.components.find_bar;
+        let input = {
+            let change_scope = self.scope.clone();
+            let submit_scope = self.scope.clone();
+            let change_navigate = self.on_navigate.clone();
+            let submit_navigate = self.on_navigate.clone();
+            self.id
+                .ctx()
+                .child("query", TextInput::new())
+                .focus_handle(focus_handle)
+                .placeholder(self.placeholder.clone())
+                .value(self.scope.query())
+                .with_size(Size::Xs)
+                .on_change(move |value: SharedString, window, cx| {
+                    change_scope.set_query(value.to_string());
+                    if let (Some(found), Some(handler)) =
+                        (change_scope.active_match(), change_navigate.as_ref())
+                    {
+                        (handler)(found, window, cx);
+                    }
+                    window.refresh();
+                })
+                .on_submit(move |_, window, cx| {
+                    navigate(&submit_scope, true, submit_navigate.as_ref(), window, cx);
+                })
+        };
+        let count = self.count_label().map(|label| {
+            div()
+                .flex_none()
+                .text_size(tokens.count_size)
+                .text_color(resolve_hsla(&self.theme, tokens.count))
+                .child(label)
+        });
+        let close = {
+            let scope = self.scope.clone();
+            self.id
+                .ctx()
+                .child("close", ActionIcon::new())
+                .with_variant(Variant::Subtle)
+                .with_size(Size::Xs)
+                .child(Icon::named("x").size(f32::from(tokens.count_size)))
+                .on_click(move |_, window, _| {
+                    scope.close();
+                    window.refresh();
+                })
+        };
+
+        div()
+            .id(self.id.slot("bar"))
+            .absolute()
+            .top(tokens.offset)
+            .right(tokens.offset)
+            .w(tokens.width)
+            .flex()
+            .items_center()
+            .gap(tokens.gap)
+            .p(tokens.padding)
+            .rounded(tokens.radius)
+            .border_1()
+            .border_color(resolve_hsla(&self.theme, tokens.border))
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .shadow_md()
+            .child(div().flex_1().min_w_0().child(input))
+            .children(count)
+            .child(self.nav_button("previous", "chevron-up", false))
+            .child(self.nav_button("next", "chevron-down", true))
+            .child(close)
+            .into_any_element()
+    }
+}
+
+impl RenderOnce for FindBar {
+    fn render(mut self, _window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let focus_handle = query_focus_handle(&self.id, _cx);
+        let mut root = div().id(self.id.clone()).relative().w_full();
+        root = root.on_key_down({
+            let scope = self.scope.clone();
+            let focus_handle = focus_handle.clone();
+            move |event, window, cx| {
+                if is_find_keystroke(event) {
+                    scope.open();
+                    window.focus(&focus_handle, cx);
+                    window.refresh();
+                } else if control::is_escape_keystroke(event) && scope.is_open() {
+                    scope.close();
+                    window.refresh();
+                }
+            }
+        });
+
+        if let Some(content) = self.content.take() {
+            root = root.child(content());
+        }
+        if !self.scope.is_open() {
+            return root;
+        }
+        root.child(self.render_bar(focus_handle))
+    }
+}
@@ -0,0 +1,527 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, AppContext, Bounds, EmptyView, Hsla, IntoElement, MouseButton, ParentElement,
+    PathBuilder, RenderOnce, Styled, Window, canvas, div, fill, point, px, size,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+
+pub use super::graph_model::{
+    GraphConnectError, GraphEdge, GraphModel, GraphNode, GraphPort, GraphPortRef,
+};
+
+use super::control;
+use super::graph_canvas_state::{self, PORT_ROW_HEIGHT};
+use super::utils::resolve_hsla;
+use super::zoom_pane::{ZoomPane, ZoomPaneHandle, ZoomView};
+use super::zoom_pane_state;
+
+type NodeMoveHandler = Rc<dyn Fn(&str, f32, f32, &mut Window, &mut gpui::App)>;
+type SelectionHandler = Rc<dyn Fn(&[String], &mut Window, &mut gpui::App)>;
+type ConnectHandler = Rc<dyn Fn(GraphPortRef, GraphPortRef, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone)]
+struct GraphNodeDrag {
+    canvas_id: String,
+    node_id: String,
+}
+
+#[derive(Clone)]
+struct GraphPortDrag {
+    canvas_id: String,
+    from: GraphPortRef,
+}
+
+#[derive(Clone, Copy)]
+struct GraphPalette {
+    node_bg: Hsla,
+    node_border: Hsla,
+    node_selected_border: Hsla,
+    node_header_bg: Hsla,
+    node_fg: Hsla,
+    port_bg: Hsla,
+    port_border: Hsla,
+    edge: Hsla,
+    edge_selected: Hsla,
+    node_radius: f32,
+    edge_width: f32,
+    port_size: f32,
+    label_size: f32,
+}
+
+#[derive(Clone)]
+struct GraphHandlers {
+    canvas_id: String,
+    controlled_selection: bool,
+    on_node_move: Option<NodeMoveHandler>,
+    on_selection_change: Option<SelectionHandler>,
+    on_connect: Option<ConnectHandler>,
+}
+
+#[derive(IntoElement)]
+pub struct GraphCanvas {
+    pub(crate) id: ComponentId,
+    model: GraphModel,
+    selected: Option<Vec<String>>,
+    zoom_handle: Option<ZoomPaneHandle>,
+    height_px: Option<f32>,
+    show_grid: bool,
+    show_minimap: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_node_move: Option<NodeMoveHandler>,
+    on_selection_change: Option<SelectionHandler>,
+    on_connect: Option<ConnectHandler>,
+}
+
+impl GraphCanvas {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            model: GraphModel::default(),
+            selected: None,
+            zoom_handle: None,
+            height_px: None,
+            show_grid: true,
+            show_minimap: true,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_node_move: None,
+            on_selection_change: None,
+            on_connect: None,
+        }
+    }
+
+    pub fn model(mut self, model: GraphModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    pub fn selected<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.selected = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn zoom_handle(mut self, handle: ZoomPaneHandle) -> Self {
+        self.zoom_handle = Some(handle);
+        self
+    }
+
+    pub fn height(mut self, value: f32) -> Self {
+        self.height_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn grid(mut self, value: bool) -> Self {
+        self.show_grid = value;
+        self
+    }
+
+    pub fn minimap(mut self, value: bool) -> Self {
+        self.show_minimap = value;
+        self
+    }
+
+    pub fn on_node_move(
+        mut self,
+        handler: impl Fn(&str, f32, f32, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_node_move = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_selection_change(
+        mut self,
+        handler: impl Fn(&[String], &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_selection_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_connect(
+        mut self,
+        handler: impl Fn(GraphPortRef, GraphPortRef, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_connect = Some(Rc::new(handler));
+        self
+    }
+
+    fn render_node(
+        id: &ComponentId,
+        node: &GraphNode,
+        view: ZoomView,
+        is_selected: bool,
+        selection: &[String],
+        palette: GraphPalette,
+        handlers: &GraphHandlers,
+    ) -> AnyElement {
+        let zoom = view.zoom;
+        let width = node.width * zoom;
+        let port_size = palette.port_size * zoom;
+        let label_size = palette.label_size * zoom;
+
+        let mut element = div()
+            .id(id.slot_index("node", node.id.clone()))
+            .absolute()
+            .left(px(node.x * zoom))
+            .top(px(node.y * zoom))
+            .w(px(width))
+            .h(px(graph_canvas_state::node_height(node) * zoom))
+            .rounded(px(palette.node_radius * zoom))
+            .border(px(if is_selected { 2.0 } else { 1.0 }))
+            .border_color(if is_selected {
+                palette.node_selected_border
+            } else {
+                palette.node_border
+            })
+            .bg(palette.node_bg)
+            .text_size(px(label_size))
+            .text_color(palette.node_fg)
+            .child(
+                div()
+                    .h(px(graph_canvas_state::NODE_HEADER_HEIGHT * zoom))
+                    .px(px(8.0 * zoom))
+                    .flex()
+                    .items_center()
+                    .rounded_t(px(palette.node_radius * zoom))
+                    .bg(palette.node_header_bg)
+                    .truncate()
+                    .child(node.label.clone()),
+            );
+
+        for (index, port) in node.inputs.iter().enumerate() {
+            let center_y = graph_canvas_state::port_offset_y(index) * zoom;
+            let target = GraphPortRef::new(node.id.clone(), port.id.clone());
+            let handlers = handlers.clone();
+            element = element
+                .child(
+                    div()
+                        .id(id.slot_index("input", format!("{}:{}", node.id, port.id)))
+                        .absolute()
+                        .left(px(-port_size * 0.5))
+                        .top(px(center_y - port_size * 0.5))
+                        .size(px(port_size))
+                        .rounded_full()
+                        .border_1()
+                        .border_color(palette.port_border)
+                        .bg(palette.port_bg)
+                        .on_drop::<GraphPortDrag>(move |drag, window, cx| {
+                            if drag.canvas_id != handlers.canvas_id {
+                                return;
+                            }
+                            if let Some(handler) = handlers.on_connect.as_ref() {
+                                (handler)(drag.from.clone(), target.clone(), window, cx);
+                            }
+                        }),
+                )
+                .child(
+                    div()
+                        .absolute()
+                        .left(px(port_size))
+                        .top(px(center_y - PORT_ROW_HEIGHT * zoom * 0.5))
+                        .h(px(PORT_ROW_HEIGHT * zoom))
+                        .flex()
+                        .items_center()
+                        .child(port.label.clone()),
+                );
+        }
+
+        for (index, port) in node.outputs.iter().enumerate() {
+            let center_y = graph_canvas_state::port_offset_y(index) * zoom;
+            element = element
+                .child(
+                    div()
+                        .absolute()
+                        .right(px(port_size))
+                        .top(px(center_y - PORT_ROW_HEIGHT * zoom * 0.5))
+                        .h(px(PORT_ROW_HEIGHT * zoom))
+                        .flex()
+                        .items_center()
+                        .child(port.label.clone()),
+                )
+                .child(
+                    div()
+                        .id(id.slot_index("output", format!("{}:{}", node.id, port.id)))
+                        .absolute()
+                        .left(px(width - port_size * 0.5))
+                        .top(px(center_y - port_size * 0.5))
+                        .size(px(port_size))
+                        .rounded_full()
+                        .border_1()
+                        .border_color(palette.port_border)
+                        .bg(palette.port_bg)
+                        .cursor_crosshair()
+                        .on_drag(
+                            GraphPortDrag {
+                                canvas_id: handlers.canvas_id.clone(),
+                                from: GraphPortRef::new(node.id.clone(), port.id.clone()),
+                            },
+                            |_drag, _, _, cx| cx.new(|_| EmptyView),
+                        ),
+                );
+        }
+
+        let drag = GraphNodeDrag {
+            canvas_id: handlers.canvas_id.clone(),
+            node_id: node.id.clone(),
+        };
+        let node_id = node.id.clone();
+        let selection = selection.to_vec();
+        let handlers = handlers.clone();
+        element
+            .cursor_move()
+            .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                zoom_pane_state::store_drag_point(
+                    &handlers.canvas_id,
+                    f32::from(event.position.x),
+                    f32::from(event.position.y),
+                );
+                let additive = event.modifiers.shift || event.modifiers.platform;
+                let next = graph_canvas_state::toggle_selection(&selection, &node_id, additive);
+                if !handlers.controlled_selection {
+                    control::set_list_state(&handlers.canvas_id, "selection", next.clone());
+                }
+                if let Some(handler) = handlers.on_selection_change.as_ref() {
+                    (handler)(&next, window, cx);
+                }
+                window.refresh();
+            })
+            .on_drag(drag, |_drag, _, _, cx| cx.new(|_| EmptyView))
+            .into_any_element()
+    }
+}
+
+impl MotionAware for GraphCanvas {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+fn paint_edge(
+    window: &mut Window,
+    origin: gpui::Point<gpui::Pixels>,
+    from: (f32, f32),
+    to: (f32, f32),
+    zoom: f32,
+    width: f32,
+    color: Hsla,
+) {
+    let (control_a, control_b) = graph_canvas_state::bezier_controls(from, to);
+    let at = |(x, y): (f32, f32)| point(origin.x + px(x * zoom), origin.y + px(y * zoom));
+    let mut builder = PathBuilder::stroke(px((width * zoom).max(1.0)));
+    builder.move_to(at(from));
+    builder.cubic_bezier_to(at(to), at(control_a), at(control_b));
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+impl RenderOnce for GraphCanvas {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.graph_canvas.clone();
+        let palette = GraphPalette {
+            node_bg: resolve_hsla(&self.theme, tokens.node_bg),
+            node_border: resolve_hsla(&self.theme, tokens.node_border),
+            node_selected_border: resolve_hsla(&self.theme, tokens.node_selected_border),
+            node_header_bg: resolve_hsla(&self.theme, tokens.node_header_bg),
+            node_fg: resolve_hsla(&self.theme, tokens.node_fg),
+            port_bg: resolve_hsla(&self.theme, tokens.port_bg),
+            port_border: resolve_hsla(&self.theme, tokens.port_border),
+            edge: resolve_hsla(&self.theme, tokens.edge),
+            edge_selected: resolve_hsla(&self.theme, tokens.edge_selected),
+            node_radius: f32::from(tokens.node_radius),
+            edge_width: f32::from(tokens.edge_width),
+            port_size: f32::from(tokens.port_size),
+            label_size: f32::from(tokens.label_size),
+        };
+
+        let canvas_id = self.id.to_string();
+        let pane_id = self.id.scoped("pane").to_string();
+        let model = graph_canvas_state::resolved_model(&canvas_id, &self.model);
+        let selection = graph_canvas_state::selected_nodes(&canvas_id, self.selected.clone());
+        let extent = graph_canvas_state::graph_extent(&model);
+        let view = match self.zoom_handle.as_ref() {
+            Some(handle) => handle.view(),
+            None => zoom_pane_state::load_view(&pane_id, ZoomView::default()),
+        };
+        let handlers = GraphHandlers {
+            canvas_id: canvas_id.clone(),
+            controlled_selection: self.selected.is_some(),
+            on_node_move: self.on_node_move.clone(),
+            on_selection_change: self.on_selection_change.clone(),
+            on_connect: self.on_connect.clone(),
+        };
+
+        let mut pane = ZoomPane::new()
+            .content_size(extent.0, extent.1)
+            .grid(self.show_grid)
+            .content({
+                let id = self.id.clone();
+                let model = model.clone();
+                let selection = selection.clone();
+                move |view, _, _| {
+                    let edges = canvas(|_, _, _| (), {
+                        let model = model.clone();
+                        let selection = selection.clone();
+                        move |bounds, _, window, _| {
+                            for edge in &model.edges {
+                                let Some((from, to)) =
+                                    graph_canvas_state::edge_anchors(&model, edge)
+                                else {
+                                    continue;
+                                };
+                                let highlighted = selection.contains(&edge.from.node)
+                                    || selection.contains(&edge.to.node);
+                                paint_edge(
+                                    window,
+                                    bounds.origin,
+                                    from,
+                                    to,
+                                    view.zoom,
+                                    palette.edge_width,
+                                    if highlighted {
+                                        palette.edge_selected
+                                    } else {
+                                        palette.edge
+                                    },
+                                );
+                            }
+                        }
+                    })
+                    .absolute()
+                    .size_full();
+
+                    let handlers = handlers.clone();
+                    div()
+                        .id(id.slot("stage"))
+                        .relative()
+                        .size_full()
+                        .child(edges)
+                        .children(model.nodes.iter().map(|node| {
+                            Self::render_node(
+                                &id,
+                                node,
+                                view,
+                                selection.contains(&node.id),
+                                &selection,
+                                palette,
+                                &handlers,
+                            )
+                        }))
+                        .on_drag_move::<GraphNodeDrag>(move |event, window, cx| {
+                            let drag = event.drag(cx).clone();
+                            if drag.canvas_id != handlers.canvas_id {
+                                return;
+                            }
+                            let Some(node) = model.find_node(&drag.node_id) else {
+                                return;
+                            };
+                            let (dx, dy) = zoom_pane_state::drag_delta(
+                                &handlers.canvas_id,
+                                f32::from(event.event.position.x),
+                                f32::from(event.event.position.y),
+                            );
+                            let (x, y) =
+                                graph_canvas_state::node_position(&handlers.canvas_id, node);
+                            let (x, y) = (x + dx / view.zoom, y + dy / view.zoom);
+                            graph_canvas_state::store_node_position(
+                                &handlers.canvas_id,
+                                &drag.node_id,
+                                x,
+                                y,
+                            );
+                            if let Some(handler) = handlers.on_node_move.as_ref() {
+                                (handler)(&drag.node_id, x, y, window, cx);
+                            }
+                            window.refresh();
+                        })
+                }
+            });
+        if let Some(handle) = self.zoom_handle.clone() {
+            pane = pane.handle(handle);
+        }
+        if let Some(height) = self.height_px {
+            pane = pane.height(height);
+        }
+
+        let mut root = div()
+            .id(self.id.clone())
+            .relative()
+            .w_full()
+            .child(self.id.ctx().child("pane", pane));
+        root = match self.height_px {
+            Some(height) => root.h(px(height)),
+            None => root.h_full(),
+        };
+
+        if self.show_minimap {
+            let minimap_bg = resolve_hsla(&self.theme, tokens.minimap_bg);
+            let minimap_node = resolve_hsla(&self.theme, tokens.minimap_node);
+            let minimap_viewport = resolve_hsla(&self.theme, tokens.minimap_viewport);
+            let viewport = zoom_pane_state::viewport_size(&pane_id);
+            root = root.child(
+                canvas(
+                    |_, _, _| (),
+                    move |bounds, _, window, _| {
+                        let minimap = (f32::from(bounds.size.width), f32::from(bounds.size.height));
+                        let scale = graph_canvas_state::minimap_scale(extent, minimap);
+                        let rect = |x: f32, y: f32, w: f32, h: f32| {
+                            Bounds::new(
+                                point(
+                                    bounds.origin.x + px(x * scale),
+                                    bounds.origin.y + px(y * scale),
+                                ),
+                                size(px((w * scale).max(1.0)), px((h * scale).max(1.0))),
+                            )
+                        };
+                        window.paint_quad(fill(bounds, minimap_bg));
+                        for node in &model.nodes {
+                            window.paint_quad(fill(
+                                rect(
+                                    node.x,
+                                    node.y,
+                                    node.width,
+                                    graph_canvas_state::node_height(node),
+                                ),
+                                minimap_node,
+                            ));
+                        }
+                        let (left, top) = view.to_content(0.0, 0.0);
+                        let (right, bottom) = view.to_content(viewport.0, viewport.1);
+                        window.paint_quad(fill(
+                            rect(
+                                left.max(0.0),
+                                top.max(0.0),
+                                right - left.max(0.0),
+                                bottom - top.max(0.0),
+                            ),
+                            minimap_viewport,
+                        ));
+                    },
+                )
+                .absolute()
+                .right(px(12.0))
+                .bottom(px(12.0))
+                .w(tokens.minimap_width)
+                .h(tokens.minimap_height)
+                .rounded(px(4.0))
+                .overflow_hidden(),
+            );
+        }
+
+        root.with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
@@ -0,0 +1,88 @@
+use super::control;
+use super::graph_model::{GraphEdge, GraphModel, GraphNode};
+
+pub const NODE_HEADER_HEIGHT: f32 = 28.0;
+pub const PORT_ROW_HEIGHT: f32 = 22.0;
+pub const NODE_PADDING_Y: f32 = 6.0;
+const EXTENT_MARGIN: f32 = 40.0;
+const MIN_CONTROL_DISTANCE: f32 = 40.0;
+
+pub fn node_height(node: &GraphNode) -> f32 {
+    let rows = node.inputs.len().max(node.outputs.len()) as f32;
+    NODE_HEADER_HEIGHT + rows * PORT_ROW_HEIGHT + NODE_PADDING_Y * 2.0
+}
+
+pub fn port_offset_y(index: usize) -> f32 {
+    NODE_HEADER_HEIGHT + NODE_PADDING_Y + index as f32 * PORT_ROW_HEIGHT + PORT_ROW_HEIGHT * 0.5
+}
+
+pub fn node_position(id: &str, node: &GraphNode) -> (f32, f32) {
+    (
+        control::f32_state(id, &format!("node-x:{}", node.id), None, node.x),
+        control::f32_state(id, &format!("node-y:{}", node.id), None, node.y),
+    )
+}
+
+pub fn store_node_position(id: &str, node_id: &str, x: f32, y: f32) {
+    control::set_f32_state(id, &format!("node-x:{node_id}"), x);
+    control::set_f32_state(id, &format!("node-y:{node_id}"), y);
+}
+
+pub fn resolved_model(id: &str, model: &GraphModel) -> GraphModel {
+    let mut resolved = model.clone();
+    for node in &mut resolved.nodes {
+        (node.x, node.y) = node_position(id, node);
+    }
+    resolved
+}
+
+pub fn port_anchor(node: &GraphNode, port: &str, output: bool) -> Option<(f32, f32)> {
+    let ports = if output { &node.outputs } else { &node.inputs };
+    let index = ports.iter().position(|candidate| candidate.id == port)?;
+    let x = if output { node.x + node.width } else { node.x };
+    Some((x, node.y + port_offset_y(index)))
+}
+
+pub fn edge_anchors(model: &GraphModel, edge: &GraphEdge) -> Option<((f32, f32), (f32, f32))> {
+    let from = port_anchor(model.find_node(&edge.from.node)?, &edge.from.port, true)?;
+    let to = port_anchor(model.find_node(&edge.to.node)?, &edge.to.port, false)?;
+    Some((from, to))
+}
+
+pub fn bezier_controls(from: (f32, f32), to: (f32, f32)) -> ((f32, f32), (f32, f32)) {
+    let distance = ((to.0 - from.0).abs() * 0.5).max(MIN_CONTROL_DISTANCE);
+    ((from.0 + distance, from.1), (to.0 - distance, to.1))
+}
+
+pub fn graph_extent(model: &GraphModel) -> (f32, f32) {
+    model
+        .nodes
+        .iter()
+        .fold((EXTENT_MARGIN, EXTENT_MARGIN), |(width, height), node| {
+            (
+                width.max(node.x + node.width + EXTENT_MARGIN),
+                height.max(node.y + node_height(node) + EXTENT_MARGIN),
+            )
+        })
+}
+
+pub fn minimap_scale(extent: (f32, f32), minimap: (f32, f32)) -> f32 {
+    (minimap.0 / extent.0.max(1.0)).min(minimap.1 / extent.1.max(1.0))
+}
+
+pub fn selected_nodes(id: &str, controlled: Option<Vec<String>>) -> Vec<String> {
+    control::list_state(id, "selection", controlled, Vec::new())
+}
+
+pub fn toggle_selection(current: &[String], node: &str, additive: bool) -> Vec<String> {
+    if !additive {
+        return vec![node.to_string()];
+    }
+    let mut next = current.to_vec();
+    if let Some(index) = next.iter().position(|selected| selected == node) {
+        next.remove(index);
+    } else {
+        next.push(node.to_string());
+    }
+    next
+}
@@ -0,0 +1,199 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GraphPort {
+    pub id: String,
+    pub label: String,
+}
+
+impl GraphPort {
+    pub fn new(id: impl Into<String>) -> Self {
+        let id = id.into();
+        Self {
+            label: id.clone(),
+            id,
+        }
+    }
+
+    pub fn label(mut self, value: impl Into<String>) -> Self {
+        self.label = value.into();
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GraphNode {
+    pub id: String,
+    pub label: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub inputs: Vec<GraphPort>,
+    pub outputs: Vec<GraphPort>,
+}
+
+impl GraphNode {
+    pub fn new(id: impl Into<String>) -> Self {
+        let id = id.into();
+        Self {
+            label: id.clone(),
+            id,
+            x: 0.0,
+            y: 0.0,
+            width: 160.0,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn label(mut self, value: impl Into<String>) -> Self {
+        self.label = value.into();
+        self
+    }
+
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    pub fn width(mut self, value: f32) -> Self {
+        self.width = value.max(48.0);
+        self
+    }
+
+    pub fn input(mut self, port: GraphPort) -> Self {
+        self.inputs.push(port);
+        self
+    }
+
+    pub fn output(mut self, port: GraphPort) -> Self {
+        self.outputs.push(port);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GraphPortRef {
+    pub node: String,
+    pub port: String,
+}
+
+impl GraphPortRef {
+    pub fn new(node: impl Into<String>, port: impl Into<String>) -> Self {
+        Self {
+            node: node.into(),
+            port: port.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GraphEdge {
+    pub id: String,
+    pub from: GraphPortRef,
+    pub to: GraphPortRef,
+}
+
+impl GraphEdge {
+    pub fn new(id: impl Into<String>, from: GraphPortRef, to: GraphPortRef) -> Self {
+        Self {
+            id: id.into(),
+            from,
+            to,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GraphConnectError {
+    UnknownPort,
+    SelfLoop,
+    Duplicate,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GraphModel {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl GraphModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn node(mut self, node: GraphNode) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    pub fn edge(mut self, edge: GraphEdge) -> Self {
+        self.edges.push(edge);
+        self
+    }
+
+    pub fn find_node(&self, id: &str) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    pub fn move_node(&mut self, id: &str, x: f32, y: f32) -> bool {
+        let Some(node) = self.nodes.iter_mut().find(|node| node.id == id) else {
+            return false;
+        };
+        node.x = x;
+        node.y = y;
+        true
+    }
+
+    pub fn remove_node(&mut self, id: &str) -> Option<GraphNode> {
+        let index = self.nodes.iter().position(|node| node.id == id)?;
+        self.edges
+            .retain(|edge| edge.from.node != id && edge.to.node != id);
+        Some(self.nodes.remove(index))
+    }
+
+    pub fn has_output(&self, port: &GraphPortRef) -> bool {
+        self.find_node(&port.node)
+            .is_some_and(|node| node.outputs.iter().any(|output| output.id == port.port))
+    }
+
+    pub fn has_input(&self, port: &GraphPortRef) -> bool {
+        self.find_node(&port.node)
+            .is_some_and(|node| node.inputs.iter().any(|input| input.id == port.port))
+    }
+
+    pub fn connect(
+        &mut self,
+        from: GraphPortRef,
+        to: GraphPortRef,
+    ) -> Result<&GraphEdge, GraphConnectError> {
+        if !self.has_output(&from) || !self.has_input(&to) {
+            return Err(GraphConnectError::UnknownPort);
+        }
+        if from.node == to.node {
+            return Err(GraphConnectError::SelfLoop);
+        }
+        if self
+            .edges
+            .iter()
+            .any(|edge| edge.from == from && edge.to == to)
+        {
+            return Err(GraphConnectError::Duplicate);
+        }
+        let id = format!("{}.{}->{}.{}", from.node, from.port, to.node, to.port);
+        self.edges.push(GraphEdge::new(id, from, to));
+        Ok(self.edges.last().expect("edge was just pushed"))
+    }
+
+    pub fn disconnect(&mut self, edge_id: &str) -> Option<GraphEdge> {
+        let index = self.edges.iter().position(|edge| edge.id == edge_id)?;
+        Some(self.edges.remove(index))
+    }
+}
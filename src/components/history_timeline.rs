@@ -0,0 +1,193 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window, div};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::Size;
+
+use super::Stack;
+use super::history_timeline_state::{self, StepRole};
+use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
+use super::utils::{
+    InteractionStyles, apply_disabled_state, apply_interaction_styles, interaction_style,
+    resolve_hsla,
+};
+
+type JumpHandler = Rc<dyn Fn(usize, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryStep {
+    pub label: SharedString,
+    pub branches: usize,
+}
+
+impl HistoryStep {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            branches: 0,
+        }
+    }
+
+    pub fn branches(mut self, value: usize) -> Self {
+        self.branches = value;
+        self
+    }
+}
+
+#[derive(IntoElement)]
+pub struct HistoryTimeline {
+    pub(crate) id: ComponentId,
+    steps: Vec<HistoryStep>,
+    current: Option<usize>,
+    separator: SharedString,
+    size: Size,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_jump: Option<JumpHandler>,
+}
+
+impl HistoryTimeline {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            steps: Vec::new(),
+            current: None,
+            separator: "›".into(),
+            size: Size::Sm,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_jump: None,
+        }
+    }
+
+    pub fn step(mut self, step: HistoryStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn steps(mut self, steps: impl IntoIterator<Item = HistoryStep>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    pub fn current(mut self, value: usize) -> Self {
+        self.current = Some(value);
+        self
+    }
+
+    pub fn separator(mut self, value: impl Into<SharedString>) -> Self {
+        self.separator = value.into();
+        self
+    }
+
+    pub fn on_jump(
+        mut self,
+        handler: impl Fn(usize, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_jump = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl MotionAware for HistoryTimeline {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for HistoryTimeline {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.breadcrumbs.clone();
+        let size_preset = tokens.sizes.for_size(self.size);
+        let current = history_timeline_state::resolve_current(self.steps.len(), self.current)
+            .unwrap_or_default();
+        let total = self.steps.len();
+        let hover_bg = tokens
+            .states
+            .hover_bg_or(resolve_hsla(&self.theme, tokens.item_hover_bg));
+        let press_bg = tokens
+            .states
+            .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
+        let separator_fg = resolve_hsla(&self.theme, tokens.separator);
+
+        let mut children = Vec::with_capacity(total.saturating_mul(2));
+        for (index, step) in self.steps.iter().enumerate() {
+            let role = history_timeline_state::step_role(index, current);
+            let is_current = role == StepRole::Current;
+            let mut chip = div()
+                .id(self.id.slot_index("step", index.to_string()))
+                .flex()
+                .items_center()
+                .gap_1()
+                .px(size_preset.item_padding_x)
+                .py(size_preset.item_padding_y)
+                .rounded(size_preset.item_radius)
+                .text_size(size_preset.font_size)
+                .text_color(if is_current {
+                    resolve_hsla(&self.theme, tokens.item_current_fg)
+                } else {
+                    resolve_hsla(&self.theme, tokens.item_fg)
+                })
+                .child(step.label.clone());
+
+            if is_current {
+                chip = chip.bg(hover_bg);
+            }
+            if role == StepRole::Redo {
+                chip = apply_disabled_state(chip, tokens.states, 0.55);
+            }
+            if step.branches > 0 {
+                chip = chip.child(
+                    div()
+                        .text_color(separator_fg)
+                        .child(format!("⑂{}", step.branches)),
+                );
+            }
+
+            if !is_current && let Some(handler) = self.on_jump.clone() {
+                let activate_handler: ActivateHandler = Rc::new(move |window, cx| {
+                    (handler)(index, window, cx);
+                });
+                chip = apply_interaction_styles(
+                    chip.cursor_pointer(),
+                    InteractionStyles::new()
+                        .hover(interaction_style(move |style| style.bg(hover_bg)))
+                        .active(interaction_style(move |style| style.bg(press_bg)))
+                        .focus(interaction_style(move |style| style.bg(hover_bg))),
+                );
+                chip = bind_press_adapter(
+                    chip,
+                    PressAdapter::new(self.id.slot_index("step", index.to_string()))
+                        .on_activate(Some(activate_handler)),
+                );
+            }
+            children.push(chip);
+
+            if index + 1 < total {
+                children.push(
+                    div()
+                        .id(self.id.slot_index("sep", index.to_string()))
+                        .text_size(size_preset.font_size)
+                        .text_color(separator_fg)
+                        .child(self.separator.clone()),
+                );
+            }
+        }
+
+        Stack::horizontal()
+            .id(self.id.clone())
+            .items_center()
+            .gap(tokens.root_gap)
+            .children(children)
+            .with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
+
+crate::impl_sized_via_method!(HistoryTimeline, size);
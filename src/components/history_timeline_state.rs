@@ -0,0 +1,23 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StepRole {
+    /// Already applied; jumping here undoes the steps after it.
+    Undo,
+    Current,
+    /// Undone; jumping here redoes the steps up to it.
+    Redo,
+}
+
+/// Index of the current step, defaulting to the newest one. `None` when
+/// there are no steps.
+pub fn resolve_current(len: usize, current: Option<usize>) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    Some(current.unwrap_or(last).min(last))
+}
+
+pub fn step_role(index: usize, current: usize) -> StepRole {
+    match index.cmp(&current) {
+        std::cmp::Ordering::Less => StepRole::Undo,
+        std::cmp::Ordering::Equal => StepRole::Current,
+        std::cmp::Ordering::Greater => StepRole::Redo,
+    }
+}
@@ -10,6 +10,7 @@ use gpui::{
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
+use crate::overlay::positioning::Align;
 
 use super::Stack;
 use super::control;
@@ -41,6 +42,7 @@ pub struct HoverCard {
     disabled: bool,
     placement: HoverCardPlacement,
     offset_px: f32,
+    align: Align,
     match_trigger_width: bool,
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
@@ -61,6 +63,7 @@ impl HoverCard {
             disabled: false,
             placement: HoverCardPlacement::Bottom,
             offset_px: 2.0,
+            align: Align::Start,
             match_trigger_width: true,
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::default(),
@@ -97,6 +100,11 @@ impl HoverCard {
         self
     }
 
+    pub fn align(mut self, value: Align) -> Self {
+        self.align = value;
+        self
+    }
+
     pub fn match_trigger_width(mut self, value: bool) -> Self {
         self.match_trigger_width = value;
         self
@@ -337,17 +345,9 @@ impl RenderOnce for HoverCard {
                 HoverCardPlacement::Top => PopupPlacement::Top,
                 HoverCardPlacement::Bottom => PopupPlacement::Bottom,
             };
-            let anchor_host = anchored_host(
-                &self.id,
-                "anchor-host",
-                placement,
-                self.offset_px,
-                self.theme.components.layout.popup_snap_margin,
-                card,
-                26,
-                matches!(self.placement, HoverCardPlacement::Bottom),
-                false,
-            );
+            let margin = self.theme.components.layout.popup_snap_margin;
+            let options = placement.options(self.offset_px, margin).align(self.align);
+            let anchor_host = anchored_host(&self.id, "anchor-host", options, card, 26, false);
 
             trigger = trigger.child(anchor_host);
         }
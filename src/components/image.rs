@@ -0,0 +1,265 @@
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use gpui::{
+    Animation, AnimationExt, AnyElement, ImageSource, InteractiveElement, IntoElement, ObjectFit,
+    ParentElement, RenderOnce, SharedString, Styled, StyledImage, Window, div, img, px,
+};
+
+use crate::id::ComponentId;
+use crate::motion::MotionLevel;
+use crate::provider::CalmProvider;
+use crate::style::Radius;
+
+use super::icon::Icon;
+use super::image_state;
+use super::utils::{apply_radius, resolve_hsla};
+
+type SlotRenderer = Rc<dyn Fn() -> AnyElement>;
+
+/// Where an [`Image`] reads its pixels from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImageSrc {
+    Path(Arc<Path>),
+    /// Encoded bytes; the format is detected from the content.
+    Bytes(Arc<[u8]>),
+    /// Fetched through the app's HTTP client.
+    #[cfg(feature = "image-network")]
+    Url(SharedString),
+}
+
+impl From<&Path> for ImageSrc {
+    fn from(value: &Path) -> Self {
+        Self::Path(value.into())
+    }
+}
+
+impl From<std::path::PathBuf> for ImageSrc {
+    fn from(value: std::path::PathBuf) -> Self {
+        Self::Path(value.into())
+    }
+}
+
+impl From<Vec<u8>> for ImageSrc {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value.into())
+    }
+}
+
+impl From<Arc<[u8]>> for ImageSrc {
+    fn from(value: Arc<[u8]>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ImageFit {
+    /// Scales to fit inside the box, letterboxing the rest.
+    Contain,
+    /// Fills the box, cropping whatever overflows.
+    #[default]
+    Cover,
+    /// Stretches to the box, ignoring the aspect ratio.
+    Fill,
+}
+
+impl ImageFit {
+    fn object_fit(self) -> ObjectFit {
+        match self {
+            Self::Contain => ObjectFit::Contain,
+            Self::Cover => ObjectFit::Cover,
+            Self::Fill => ObjectFit::Fill,
+        }
+    }
+}
+
+/// Displays an image from a file, encoded bytes or (with `image-network`) a
+/// URL. A skeleton shows while it loads and a fallback replaces it when it
+/// cannot be decoded.
+#[derive(IntoElement)]
+pub struct Image {
+    pub(crate) id: ComponentId,
+    source: Option<ImageSrc>,
+    fit: ImageFit,
+    radius: Radius,
+    width: Option<f32>,
+    height: Option<f32>,
+    alt: Option<SharedString>,
+    placeholder: Option<SlotRenderer>,
+    fallback: Option<SlotRenderer>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl Image {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            source: None,
+            fit: ImageFit::default(),
+            radius: Radius::Sm,
+            width: None,
+            height: None,
+            alt: None,
+            placeholder: None,
+            fallback: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    pub fn path(self, value: impl AsRef<Path>) -> Self {
+        self.source(value.as_ref())
+    }
+
+    pub fn bytes(self, value: impl Into<Arc<[u8]>>) -> Self {
+        self.source(ImageSrc::Bytes(value.into()))
+    }
+
+    #[cfg(feature = "image-network")]
+    pub fn url(self, value: impl Into<SharedString>) -> Self {
+        self.source(ImageSrc::Url(value.into()))
+    }
+
+    pub fn source(mut self, value: impl Into<ImageSrc>) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    pub fn fit(mut self, value: ImageFit) -> Self {
+        self.fit = value;
+        self
+    }
+
+    pub fn width(mut self, value: f32) -> Self {
+        self.width = Some(value.max(0.0));
+        self
+    }
+
+    pub fn height(mut self, value: f32) -> Self {
+        self.height = Some(value.max(0.0));
+        self
+    }
+
+    /// Describes the image; shown in the default fallback.
+    pub fn alt(mut self, value: impl Into<SharedString>) -> Self {
+        self.alt = Some(value.into());
+        self
+    }
+
+    /// Replaces the loading skeleton.
+    pub fn placeholder(mut self, content: impl Fn() -> AnyElement + 'static) -> Self {
+        self.placeholder = Some(Rc::new(content));
+        self
+    }
+
+    /// Replaces the content shown when the image fails to load.
+    pub fn fallback(mut self, content: impl Fn() -> AnyElement + 'static) -> Self {
+        self.fallback = Some(Rc::new(content));
+        self
+    }
+
+    fn image_source(&self) -> Option<ImageSource> {
+        match self.source.as_ref()? {
+            ImageSrc::Path(path) => Some(ImageSource::from(path.clone())),
+            ImageSrc::Bytes(bytes) => image_state::decoded(bytes).map(ImageSource::from),
+            #[cfg(feature = "image-network")]
+            ImageSrc::Url(url) => Some(ImageSource::from(url.clone())),
+        }
+    }
+
+    fn skeleton(&self, animated: bool) -> SlotRenderer {
+        if let Some(placeholder) = self.placeholder.clone() {
+            return placeholder;
+        }
+        let bg = resolve_hsla(&self.theme, self.theme.components.image.placeholder_bg);
+        let id = self.id.slot("pulse");
+        Rc::new(move || {
+            let skeleton = div().size_full().bg(bg);
+            if !animated {
+                return skeleton.into_any_element();
+            }
+            let pulse = Animation::new(Duration::from_millis(1200))
+                .repeat()
+                .with_easing(gpui::pulsating_between(0.45, 1.0));
+            skeleton
+                .with_animation(id.clone(), pulse, |this, delta| this.opacity(delta))
+                .into_any_element()
+        })
+    }
+
+    fn fallback_content(&self) -> SlotRenderer {
+        if let Some(fallback) = self.fallback.clone() {
+            return fallback;
+        }
+        let tokens = &self.theme.components.image;
+        let bg = resolve_hsla(&self.theme, tokens.fallback_bg);
+        let fg = resolve_hsla(&self.theme, tokens.fallback_fg);
+        let (icon_size, gap, font_size) = (
+            tokens.fallback_icon_size,
+            tokens.fallback_gap,
+            tokens.fallback_font_size,
+        );
+        let id = self.id.clone();
+        let alt = self.alt.clone();
+        Rc::new(move || {
+            div()
+                .flex()
+                .flex_col()
+                .size_full()
+                .items_center()
+                .justify_center()
+                .gap(gap)
+                .bg(bg)
+                .text_color(fg)
+                .text_size(font_size)
+                .child(
+                    id.ctx()
+                        .child("fallback-icon", Icon::named("photo-off"))
+                        .size(f32::from(icon_size))
+                        .color(fg),
+                )
+                .children(alt.clone())
+                .into_any_element()
+        })
+    }
+}
+
+crate::impl_radiused_via_method!(Image, radius);
+
+impl RenderOnce for Image {
+    fn render(mut self, _window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let fallback = self.fallback_content();
+        let content = match self.image_source() {
+            Some(source) => {
+                let loading = self.skeleton(CalmProvider::motion_level(_cx) == MotionLevel::Full);
+                let failed = fallback.clone();
+                img(source)
+                    .id(self.id.slot("image"))
+                    .size_full()
+                    .object_fit(self.fit.object_fit())
+                    .with_loading(move || loading())
+                    .with_fallback(move || failed())
+                    .into_any_element()
+            }
+            None => fallback(),
+        };
+
+        let mut root = div()
+            .id(self.id.clone())
+            .relative()
+            .overflow_hidden()
+            .flex_none();
+        root = match self.width {
+            Some(width) => root.w(px(width)),
+            None => root.w_full(),
+        };
+        root = match self.height {
+            Some(height) => root.h(px(height)),
+            None => root.h_full(),
+        };
+        apply_radius(&self.theme, root, self.radius).child(content)
+    }
+}
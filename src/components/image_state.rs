@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use gpui::ImageFormat;
+
+/// Byte-backed images kept between frames so gpui's asset cache keeps
+/// hitting the same decoded image instead of decoding a fresh copy.
+const DECODED_CAPACITY: usize = 64;
+
+static DECODED: LazyLock<Mutex<HashMap<u64, Arc<gpui::Image>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Identifies the encoding from the leading magic bytes.
+pub fn sniff_format(bytes: &[u8]) -> Option<ImageFormat> {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(256)]);
+    let text = head.trim_start();
+    match bytes {
+        [0x89, b'P', b'N', b'G', ..] => Some(ImageFormat::Png),
+        [0xFF, 0xD8, 0xFF, ..] => Some(ImageFormat::Jpeg),
+        [b'G', b'I', b'F', b'8', ..] => Some(ImageFormat::Gif),
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'E',
+            b'B',
+            b'P',
+            ..,
+        ] => Some(ImageFormat::Webp),
+        [b'B', b'M', ..] => Some(ImageFormat::Bmp),
+        [b'I', b'I', 0x2A, 0x00, ..] | [b'M', b'M', 0x00, 0x2A, ..] => Some(ImageFormat::Tiff),
+        _ if text.starts_with("<svg") || text.starts_with("<?xml") => Some(ImageFormat::Svg),
+        _ => None,
+    }
+}
+
+fn content_key(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the cached image for `bytes`, creating it on first use. `None`
+/// when the encoding is not recognized.
+pub fn decoded(bytes: &[u8]) -> Option<Arc<gpui::Image>> {
+    let key = content_key(bytes);
+    let mut cache = DECODED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(image) = cache.get(&key) {
+        return Some(image.clone());
+    }
+    let format = sniff_format(bytes)?;
+    if cache.len() >= DECODED_CAPACITY {
+        cache.clear();
+    }
+    let image = Arc::new(gpui::Image::from_bytes(format, bytes.to_vec()));
+    cache.insert(key, image.clone());
+    Some(image)
+}
@@ -1,7 +1,9 @@
-use super::transition::TransitionExt;
+use super::transition::{TransitionExt, TransitionStage};
 use std::{
     collections::HashMap,
+    future::Future,
     ops::Range,
+    pin::Pin,
     rc::Rc,
     sync::{Arc, LazyLock, Mutex},
     time::{Duration, Instant},
@@ -17,23 +19,37 @@ use gpui::{
 
 use crate::contracts::{FieldLike, MotionAware};
 use crate::id::ComponentId;
-use crate::motion::MotionConfig;
+use crate::motion::{Easing, MotionConfig, MotionLevel, MotionTransition, TransitionPreset};
 use crate::style::{FieldLayout, Radius, Size, Variant};
+use crate::theme::SemanticRadiusToken;
 
 use super::Stack;
+use super::composition::{self, CompositionUnderline, composition_underlines};
 use super::control;
 use super::field_variant::FieldVariantRuntime;
+use super::icon::Icon;
+use super::input_mask::InputMask;
+use super::password_strength::PasswordStrength;
+use super::paste_attachment::{PasteAttachment, PasteAttachmentHandler, take_pasted_text};
+use super::pin_input_state;
 use super::text_input_actions::{
     CopySelection, CutSelection, DeleteBackward, DeleteForward, INPUT_KEY_CONTEXT, MoveEnd,
     MoveHome, MoveLeft, MoveRight, PasteClipboard, SelectAll, SelectEnd, SelectHome, SelectLeft,
     SelectRight, Submit, ensure_text_keybindings,
 };
 use super::text_input_state::InputState;
-use super::utils::{apply_field_size, apply_radius, quantized_stroke_px, resolve_hsla};
+use super::utils::{
+    apply_field_size, apply_focus_ring, apply_radius, quantized_stroke_px, resolve_hsla,
+    resolve_radius,
+};
 
 type ChangeHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
 type SubmitHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
 type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+type RevealChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
+type StrengthEstimator = Rc<dyn Fn(&str) -> PasswordStrength>;
+type PinVerifier =
+    Rc<dyn Fn(SharedString) -> Pin<Box<dyn Future<Output = Result<(), SharedString>>>>>;
 type SelectionRange = Option<(usize, usize)>;
 type NormalizedEdit = (String, usize, SelectionRange, SelectionRange);
 const CARET_BLINK_TOGGLE_MS: u64 = 680;
@@ -61,6 +77,7 @@ struct TextInputImeHandler {
     read_only: bool,
     masked: bool,
     mask_reveal_ms: u64,
+    input_mask: Option<InputMask>,
     font_size: f32,
     on_change: Option<ChangeHandler>,
 }
@@ -109,26 +126,11 @@ impl TextInputImeHandler {
     }
 
     fn marked_range_chars(&self, len: usize) -> Option<(usize, usize)> {
-        let start = control::optional_usize_state(&self.id, "marked-start", None, None)
-            .unwrap_or(usize::MAX);
-        let end =
-            control::optional_usize_state(&self.id, "marked-end", None, None).unwrap_or(usize::MAX);
-        if start == usize::MAX || end == usize::MAX {
-            return None;
-        }
-        let start = start.min(len);
-        let end = end.min(len);
-        (start < end).then_some((start, end))
+        composition::marked_range(&self.id, len)
     }
 
     fn set_marked_range_chars(&self, marked: Option<(usize, usize)>) {
-        if let Some((start, end)) = marked {
-            control::set_optional_usize_state(&self.id, "marked-start", Some(start));
-            control::set_optional_usize_state(&self.id, "marked-end", Some(end));
-        } else {
-            control::set_optional_usize_state(&self.id, "marked-start", None);
-            control::set_optional_usize_state(&self.id, "marked-end", None);
-        }
+        composition::set_marked_range(&self.id, marked);
     }
 
     fn resolve_replacement_range(
@@ -189,6 +191,16 @@ impl TextInputImeHandler {
         window: &mut Window,
         cx: &mut gpui::App,
     ) {
+        let (next, caret, selection) = match self.input_mask.as_ref() {
+            Some(mask) if marked.is_none() && next != previous => {
+                let len = previous.chars().count();
+                let previous_caret =
+                    control::usize_state(&self.id, "caret-index", None, len).min(len);
+                let (next, caret) = mask.apply_edit(previous, previous_caret, &next, caret);
+                (next, caret, None)
+            }
+            _ => (next, caret, selection),
+        };
         let changed = next != previous;
         if changed && self.masked {
             let previous_len = previous.chars().count();
@@ -323,13 +335,21 @@ impl InputHandler for TextInputImeHandler {
         });
 
         let caret = selection.map(|(_, end)| end).unwrap_or(fallback_caret);
-        let (next, caret, marked, selection) =
-            self.apply_max_length(next, caret, marked, selection);
+        // Preedit text is not clamped to `max_length`: a CJK composition is often longer
+        // than the text it commits, so the limit is applied once the IME commits.
         self.apply_edit_result(&value, next, caret, selection, marked, window, cx);
     }
 
-    fn unmark_text(&mut self, _window: &mut Window, _cx: &mut gpui::App) {
-        self.set_marked_range_chars(None);
+    fn unmark_text(&mut self, window: &mut Window, cx: &mut gpui::App) {
+        let value = self.current_value();
+        let len = value.chars().count();
+        if self.marked_range_chars(len).is_none() {
+            return;
+        }
+        let caret = control::usize_state(&self.id, "caret-index", None, len).min(len);
+        let (next, caret, _marked, _selection) =
+            self.apply_max_length(value.clone(), caret, None, None);
+        self.apply_edit_result(&value, next, caret, None, None, window, cx);
     }
 
     fn bounds_for_range(
@@ -409,6 +429,7 @@ pub struct TextInput {
     read_only: bool,
     masked: bool,
     mask_reveal_ms: u64,
+    input_mask: Option<InputMask>,
     max_length: Option<usize>,
     variant: Variant,
     size: Size,
@@ -419,6 +440,7 @@ pub struct TextInput {
     focus_handle: Option<FocusHandle>,
     on_change: Option<ChangeHandler>,
     on_submit: Option<SubmitHandler>,
+    on_paste_attachment: Option<PasteAttachmentHandler>,
 }
 
 impl TextInput {
@@ -454,6 +476,7 @@ impl TextInput {
             read_only: false,
             masked: false,
             mask_reveal_ms: 0,
+            input_mask: None,
             max_length: None,
             variant: Variant::Default,
             size: Size::Md,
@@ -464,6 +487,7 @@ impl TextInput {
             focus_handle: None,
             on_change: None,
             on_submit: None,
+            on_paste_attachment: None,
         }
     }
 
@@ -531,6 +555,14 @@ impl TextInput {
         self.mask_reveal_ms = duration_ms;
         self
     }
+
+    /// Formats the text as it is typed or pasted, such as a phone number or
+    /// an amount. `value`, `default_value`, `on_change` and `on_submit` keep
+    /// using the unformatted value.
+    pub fn mask(mut self, mask: InputMask) -> Self {
+        self.input_mask = Some(mask);
+        self
+    }
     pub fn max_length(mut self, max_length: usize) -> Self {
         self.max_length = Some(max_length.max(1));
         self
@@ -556,6 +588,14 @@ impl TextInput {
         self
     }
 
+    pub fn on_paste_attachment(
+        mut self,
+        handler: impl Fn(Vec<PasteAttachment>, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_paste_attachment = Some(Rc::new(handler));
+        self
+    }
+
     pub(crate) fn refine_style(mut self, style: &gpui::StyleRefinement) -> Self {
         gpui::Refineable::refine(&mut self.style, style);
         self
@@ -588,6 +628,27 @@ impl TextInput {
         }
     }
 
+    /// Switches the value and callbacks over to formatted text, so editing
+    /// only ever sees what is on screen.
+    fn apply_input_mask(&mut self) {
+        let Some(mask) = self.input_mask.clone() else {
+            return;
+        };
+        self.value = self.value.take().map(|value| mask.format(&value).into());
+        self.default_value = mask.format(&self.default_value).into();
+        if let Some(handler) = self.on_change.take() {
+            let mask = mask.clone();
+            self.on_change = Some(Rc::new(move |value, window, cx| {
+                (handler)(mask.unmask(&value).into(), window, cx)
+            }));
+        }
+        if let Some(handler) = self.on_submit.take() {
+            self.on_submit = Some(Rc::new(move |value, window, cx| {
+                (handler)(mask.unmask(&value).into(), window, cx)
+            }));
+        }
+    }
+
     fn caret_height_px(&self) -> f32 {
         let size = self.theme.components.input.sizes.for_size(self.size);
         f32::from(size.caret_height)
@@ -744,10 +805,23 @@ impl TextInput {
         value_controlled: bool,
         masked: bool,
         mask_reveal_ms: u64,
+        input_mask: Option<&InputMask>,
         on_change: Option<&ChangeHandler>,
         window: &mut Window,
         cx: &mut gpui::App,
     ) {
+        let formatted;
+        let state = match input_mask {
+            Some(mask) if state.value != previous_value => {
+                let len = previous_value.chars().count();
+                let previous_caret = control::usize_state(id, "caret-index", None, len).min(len);
+                let (value, caret) =
+                    mask.apply_edit(previous_value, previous_caret, &state.value, state.caret);
+                formatted = InputState::new(value, caret, caret, None);
+                &formatted
+            }
+            _ => state,
+        };
         let next_value = state.value.clone();
         let value_changed = next_value != previous_value;
 
@@ -868,6 +942,12 @@ impl TextInput {
             self.error.is_some(),
         );
         input = input.border_color(border);
+        input = apply_focus_ring(
+            &self.theme,
+            input,
+            is_focused && !self.disabled,
+            resolve_radius(&self.theme, SemanticRadiusToken::from(self.radius)),
+        );
 
         if self.disabled {
             input = input.cursor_default().opacity(0.55);
@@ -879,6 +959,7 @@ impl TextInput {
         input = input.on_mouse_down_out(move |_, window, _cx| {
             control::set_focused_state(&id_for_blur, false);
             control::set_bool_state(&id_for_blur, "mouse-selecting", false);
+            composition::commit(&id_for_blur);
             window.refresh();
         });
 
@@ -976,6 +1057,7 @@ impl TextInput {
             let rendered_value = current_value.clone();
             let value_controlled = self.value_controlled;
             let on_change = self.on_change.clone();
+            let input_mask = self.input_mask.clone();
             let masked = self.masked;
             let mask_reveal_ms = self.mask_reveal_ms;
             input = input
@@ -995,6 +1077,7 @@ impl TextInput {
                         value_controlled,
                         masked,
                         mask_reveal_ms,
+                        input_mask.as_ref(),
                         on_change.as_ref(),
                         window,
                         cx,
@@ -1004,6 +1087,7 @@ impl TextInput {
                     let input_id = self.id.clone();
                     let rendered_value = current_value.clone();
                     let on_change = self.on_change.clone();
+                    let input_mask = self.input_mask.clone();
                     move |_: &MoveRight, window, cx| {
                         let current_value = control::text_state(
                             &input_id,
@@ -1020,6 +1104,7 @@ impl TextInput {
                             value_controlled,
                             masked,
                             mask_reveal_ms,
+                            input_mask.as_ref(),
                             on_change.as_ref(),
                             window,
                             cx,
@@ -1030,6 +1115,7 @@ impl TextInput {
                     let input_id = self.id.clone();
                     let rendered_value = current_value.clone();
                     let on_change = self.on_change.clone();
+                    let input_mask = self.input_mask.clone();
                     move |_: &MoveHome, window, cx| {
                         let current_value = control::text_state(
                             &input_id,
@@ -1046,6 +1132,7 @@ impl TextInput {
                             value_controlled,
                             masked,
                             mask_reveal_ms,
+                            input_mask.as_ref(),
                             on_change.as_ref(),
                             window,
                             cx,
@@ -1056,6 +1143,7 @@ impl TextInput {
                     let input_id = self.id.clone();
                     let rendered_value = current_value.clone();
                     let on_change = self.on_change.clone();
+                    let input_mask = self.input_mask.clone();
                     move |_: &MoveEnd, window, cx| {
                         let current_value = control::text_state(
                             &input_id,
@@ -1072,6 +1160,7 @@ impl TextInput {
                             value_controlled,
                             masked,
                             mask_reveal_ms,
+                            input_mask.as_ref(),
                             on_change.as_ref(),
                             window,
                             cx,
@@ -1082,6 +1171,7 @@ impl TextInput {
                     let input_id = self.id.clone();
                     let rendered_value = current_value.clone();
                     let on_change = self.on_change.clone();
+                    let input_mask = self.input_mask.clone();
                     move |_: &SelectLeft, window, cx| {
                         let current_value = control::text_state(
                             &input_id,
@@ -1098,6 +1188,7 @@ impl TextInput {
                             value_controlled,
                             masked,
                             mask_reveal_ms,
+                            input_mask.as_ref(),
                             on_change.as_ref(),
                             window,
                             cx,
@@ -1108,6 +1199,7 @@ impl TextInput {
                     let input_id = self.id.clone();
                     let rendered_value = current_value.clone();
                     let on_change = self.on_change.clone();
+                    let input_mask = self.input_mask.clone();
                     move |_: &SelectRight, window, cx| {
                         let current_value = control::text_state(
                             &input_id,
@@ -1124,6 +1216,7 @@ impl TextInput {
                             value_controlled,
                             masked,
                             mask_reveal_ms,
+                            input_mask.as_ref(),
                             on_change.as_ref(),
                             window,
                             cx,
@@ -1134,6 +1227,7 @@ impl TextInput {
                     let input_id = self.id.clone();
                     let rendered_value = current_value.clone();
                     let on_change = self.on_change.clone();
+                    let input_mask = self.input_mask.clone();
                     move |_: &SelectHome, window, cx| {
                         let current_value = control::text_state(
                             &input_id,
@@ -1150,6 +1244,7 @@ impl TextInput {
                             value_controlled,
                             masked,
                             mask_reveal_ms,
+                            input_mask.as_ref(),
                             on_change.as_ref(),
                             window,
                             cx,
@@ -1160,6 +1255,7 @@ impl TextInput {
                     let input_id = self.id.clone();
                     let rendered_value = current_value.clone();
                     let on_change = self.on_change.clone();
+                    let input_mask = self.input_mask.clone();
                     move |_: &SelectEnd, window, cx| {
                         let current_value = control::text_state(
                             &input_id,
@@ -1177,6 +1273,7 @@ impl TextInput {
                             value_controlled,
                             masked,
                             mask_reveal_ms,
+                            input_mask.as_ref(),
                             on_change.as_ref(),
                             window,
                             cx,
@@ -1187,6 +1284,7 @@ impl TextInput {
                     let input_id = self.id.clone();
                     let rendered_value = current_value.clone();
                     let on_change = self.on_change.clone();
+                    let input_mask = self.input_mask.clone();
                     move |_: &SelectAll, window, cx| {
                         let current_value = control::text_state(
                             &input_id,
@@ -1204,6 +1302,7 @@ impl TextInput {
                             value_controlled,
                             masked,
                             mask_reveal_ms,
+                            input_mask.as_ref(),
                             on_change.as_ref(),
                             window,
                             cx,
@@ -1234,6 +1333,7 @@ impl TextInput {
                         let input_id = self.id.clone();
                         let rendered_value = current_value.clone();
                         let on_change = self.on_change.clone();
+                        let input_mask = self.input_mask.clone();
                         move |_: &DeleteBackward, window, cx| {
                             let current_value = control::text_state(
                                 &input_id,
@@ -1252,6 +1352,7 @@ impl TextInput {
                                 value_controlled,
                                 masked,
                                 mask_reveal_ms,
+                                input_mask.as_ref(),
                                 on_change.as_ref(),
                                 window,
                                 cx,
@@ -1262,6 +1363,7 @@ impl TextInput {
                         let input_id = self.id.clone();
                         let rendered_value = current_value.clone();
                         let on_change = self.on_change.clone();
+                        let input_mask = self.input_mask.clone();
                         move |_: &DeleteForward, window, cx| {
                             let current_value = control::text_state(
                                 &input_id,
@@ -1280,6 +1382,7 @@ impl TextInput {
                                 value_controlled,
                                 masked,
                                 mask_reveal_ms,
+                                input_mask.as_ref(),
                                 on_change.as_ref(),
                                 window,
                                 cx,
@@ -1290,6 +1393,7 @@ impl TextInput {
                         let input_id = self.id.clone();
                         let rendered_value = current_value.clone();
                         let on_change = self.on_change.clone();
+                        let input_mask = self.input_mask.clone();
                         move |_: &CutSelection, window, cx| {
                             let current_value = control::text_state(
                                 &input_id,
@@ -1313,6 +1417,7 @@ impl TextInput {
                                 value_controlled,
                                 masked,
                                 mask_reveal_ms,
+                                input_mask.as_ref(),
                                 on_change.as_ref(),
                                 window,
                                 cx,
@@ -1323,11 +1428,14 @@ impl TextInput {
                         let input_id = self.id.clone();
                         let rendered_value = current_value.clone();
                         let on_change = self.on_change.clone();
+                        let input_mask = self.input_mask.clone();
+                        let on_paste_attachment = self.on_paste_attachment.clone();
                         move |_: &PasteClipboard, window, cx| {
                             let Some(item) = cx.read_from_clipboard() else {
                                 return;
                             };
-                            let Some(text) = item.text() else {
+                            let handler = on_paste_attachment.as_ref();
+                            let Some(text) = take_pasted_text(&item, handler, window, cx) else {
                                 return;
                             };
                             let sanitized = text.replace(['\r', '\n'], " ");
@@ -1351,6 +1459,7 @@ impl TextInput {
                                 value_controlled,
                                 masked,
                                 mask_reveal_ms,
+                                input_mask.as_ref(),
                                 on_change.as_ref(),
                                 window,
                                 cx,
@@ -1362,6 +1471,9 @@ impl TextInput {
                         let rendered_value = current_value.clone();
                         let on_submit = self.on_submit.clone();
                         move |_: &Submit, window, cx| {
+                            if composition::is_composing(&input_id) {
+                                return;
+                            }
                             let current_value = control::text_state(
                                 &input_id,
                                 "value",
@@ -1385,6 +1497,7 @@ impl TextInput {
         let ime_read_only = self.read_only;
         let ime_masked = self.masked;
         let ime_mask_reveal_ms = self.mask_reveal_ms;
+        let ime_input_mask = self.input_mask.clone();
         let ime_font_size = font_size;
         let ime_on_change = self.on_change.clone();
 
@@ -1466,6 +1579,7 @@ impl TextInput {
                             read_only: ime_read_only,
                             masked: ime_masked,
                             mask_reveal_ms: ime_mask_reveal_ms,
+                            input_mask: ime_input_mask.clone(),
                             font_size: ime_font_size,
                             on_change: ime_on_change.clone(),
                         },
@@ -1524,6 +1638,21 @@ impl TextInput {
             }
             value_container = value_container.child(content_row);
 
+            if let Some((marked_start, marked_end)) =
+                composition::marked_range(&self.id, current_len)
+            {
+                let (_, _, _, content_height) = Self::content_geometry(&self.id);
+                let underline = CompositionUnderline {
+                    start_x: Self::x_for_char(window, font_size, &value, marked_start) - scroll_x,
+                    end_x: Self::x_for_char(window, font_size, &value, marked_end) - scroll_x,
+                    baseline_y: (content_height + self.caret_height_px()) * 0.5,
+                };
+                value_container = value_container.child(composition_underlines(
+                    vec![underline],
+                    resolve_hsla(&self.theme, tokens.fg),
+                ));
+            }
+
             if !self.disabled && !self.read_only && show_caret && selection.is_none() {
                 let caret_left = (Self::x_for_char(window, font_size, &value, current_caret)
                     - scroll_x)
@@ -1664,6 +1793,7 @@ impl MotionAware for TextInput {
 impl RenderOnce for TextInput {
     fn render(mut self, window: &mut gpui::Window, _cx: &mut gpui::App) -> impl IntoElement {
         self.theme.sync_from_provider(_cx);
+        self.apply_input_mask();
         match self.layout {
             FieldLayout::Vertical => {
                 let mut container = Stack::vertical()
@@ -1692,11 +1822,24 @@ impl RenderOnce for TextInput {
     }
 }
 
+/// Text field with masked input, an eye button to show the password, a
+/// Caps Lock warning and an optional strength meter.
 #[derive(IntoElement)]
 pub struct PasswordInput {
     pub(crate) id: ComponentId,
     pub(crate) inner: TextInput,
     style: gpui::StyleRefinement,
+    value: Option<SharedString>,
+    default_value: SharedString,
+    disabled: bool,
+    revealed: Option<bool>,
+    reveal_toggle: bool,
+    on_reveal_change: Option<RevealChangeHandler>,
+    right_slot: Option<SlotRenderer>,
+    caps_lock_warning: bool,
+    caps_lock_label: SharedString,
+    strength_estimator: Option<StrengthEstimator>,
+    strength_label: bool,
 }
 
 impl PasswordInput {
@@ -1706,15 +1849,30 @@ impl PasswordInput {
             id: ComponentId::default(),
             inner: TextInput::new().masked(true).mask_reveal_ms(700),
             style: gpui::StyleRefinement::default(),
+            value: None,
+            default_value: SharedString::default(),
+            disabled: false,
+            revealed: None,
+            reveal_toggle: true,
+            on_reveal_change: None,
+            right_slot: None,
+            caps_lock_warning: true,
+            caps_lock_label: "Caps Lock is on".into(),
+            strength_estimator: None,
+            strength_label: true,
         }
     }
 
     pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        let value = value.into();
+        self.value = Some(value.clone());
         self.inner = self.inner.value(value);
         self
     }
 
     pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        let value = value.into();
+        self.default_value = value.clone();
         self.inner = self.inner.default_value(value);
         self
     }
@@ -1754,13 +1912,65 @@ impl PasswordInput {
         self
     }
 
+    /// Placed before the reveal button when that is shown.
     pub fn right_slot(mut self, content: impl IntoElement + 'static) -> Self {
-        self.inner = self.inner.right_slot(content);
+        self.right_slot = Some(Box::new(|| content.into_any_element()));
         self
     }
 
+    /// Shows the password in plain text. Setting this makes the revealed
+    /// state controlled; pair it with [`PasswordInput::on_reveal_change`].
     pub fn reveal(mut self, reveal: bool) -> Self {
-        self.inner = self.inner.masked(!reveal);
+        self.revealed = Some(reveal);
+        self
+    }
+
+    /// Eye button at the end of the field that shows or hides the password.
+    pub fn reveal_toggle(mut self, value: bool) -> Self {
+        self.reveal_toggle = value;
+        self
+    }
+
+    pub fn on_reveal_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_reveal_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Warns under the field while it has focus and Caps Lock is on.
+    pub fn caps_lock_warning(mut self, value: bool) -> Self {
+        self.caps_lock_warning = value;
+        self
+    }
+
+    pub fn caps_lock_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.caps_lock_label = value.into();
+        self
+    }
+
+    /// Shows a four-segment strength meter under the field, scored with
+    /// [`PasswordStrength::estimate`].
+    pub fn strength_meter(mut self, value: bool) -> Self {
+        self.strength_estimator =
+            value.then(|| Rc::new(PasswordStrength::estimate) as StrengthEstimator);
+        self
+    }
+
+    /// Shows the strength meter, scored by `estimator` instead of the
+    /// built-in heuristic.
+    pub fn strength_estimator(
+        mut self,
+        estimator: impl Fn(&str) -> PasswordStrength + 'static,
+    ) -> Self {
+        self.strength_estimator = Some(Rc::new(estimator));
+        self
+    }
+
+    /// Names the strength next to the meter, such as "Weak" or "Strong".
+    pub fn strength_label(mut self, value: bool) -> Self {
+        self.strength_label = value;
         self
     }
 
@@ -1789,9 +1999,87 @@ impl PasswordInput {
         self.inner = self.inner.on_submit(handler);
         self
     }
-}
 
-impl PasswordInput {}
+    fn strength_color(&self, strength: PasswordStrength) -> gpui::Hsla {
+        let tokens = &self.inner.theme.components.input;
+        let color = match strength {
+            PasswordStrength::Weak => tokens.password_strength_weak,
+            PasswordStrength::Fair => tokens.password_strength_fair,
+            PasswordStrength::Good => tokens.password_strength_good,
+            PasswordStrength::Strong => tokens.password_strength_strong,
+        };
+        resolve_hsla(&self.inner.theme, color)
+    }
+
+    fn render_reveal_toggle(&self, revealed: bool) -> AnyElement {
+        let tokens = &self.inner.theme.components.input;
+        let icon = self
+            .id
+            .ctx()
+            .child(
+                "reveal-icon",
+                Icon::named(if revealed { "eye-off" } else { "eye" }),
+            )
+            .size(f32::from(tokens.password_toggle_size))
+            .color(resolve_hsla(&self.inner.theme, tokens.slot_fg));
+        let mut toggle = div()
+            .id(self.id.slot("reveal-toggle"))
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(icon);
+        if !self.disabled {
+            let id = self.id.clone();
+            let controlled = self.revealed.is_some();
+            let on_reveal_change = self.on_reveal_change.clone();
+            toggle = toggle
+                .cursor_pointer()
+                .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                .on_click(move |_, window, cx| {
+                    let next = !revealed;
+                    if !controlled {
+                        control::set_bool_state(&id, "revealed", next);
+                    }
+                    if let Some(handler) = on_reveal_change.as_ref() {
+                        (handler)(next, window, cx);
+                    }
+                    window.refresh();
+                });
+        }
+        toggle.into_any_element()
+    }
+
+    fn render_strength_meter(&self, strength: PasswordStrength) -> AnyElement {
+        let tokens = &self.inner.theme.components.input;
+        let filled = self.strength_color(strength);
+        let track = resolve_hsla(&self.inner.theme, tokens.password_meter_track);
+        let segments = (0..4).map(|index| {
+            div()
+                .flex_1()
+                .h(tokens.password_meter_height)
+                .rounded_full()
+                .bg(if index < strength.segments() {
+                    filled
+                } else {
+                    track
+                })
+        });
+        let label = self.strength_label.then(|| {
+            div()
+                .flex_none()
+                .text_size(tokens.password_strength_label_size)
+                .text_color(filled)
+                .child(strength.label())
+        });
+        div()
+            .flex()
+            .items_center()
+            .gap(tokens.password_meter_segment_gap)
+            .children(segments)
+            .children(label)
+            .into_any_element()
+    }
+}
 
 impl FieldLike for PasswordInput {
     fn label(mut self, value: impl Into<SharedString>) -> Self {
@@ -1828,9 +2116,132 @@ impl MotionAware for PasswordInput {
 }
 
 impl RenderOnce for PasswordInput {
-    fn render(self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
-        let inner = self.id.ctx().root(self.inner).refine_style(&self.style);
-        inner.render(window, cx)
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.inner.theme.sync_from_provider(cx);
+        let tokens = self.inner.theme.components.input.clone();
+        let revealed = control::bool_state(&self.id, "revealed", self.revealed, false);
+        let value = control::text_state(
+            &self.id,
+            "value",
+            self.value.as_ref().map(ToString::to_string),
+            self.default_value.to_string(),
+        );
+        let strength = self
+            .strength_estimator
+            .as_ref()
+            .filter(|_| !value.is_empty())
+            .map(|estimator| estimator(&value));
+        let caps_lock = self.caps_lock_warning
+            && !self.disabled
+            && control::focused_state(&self.id, None, false)
+            && window.capslock().on;
+
+        let toggle = self
+            .reveal_toggle
+            .then(|| self.render_reveal_toggle(revealed));
+        let right_slot = self.right_slot.take().map(|render| render());
+        if toggle.is_some() || right_slot.is_some() {
+            self.inner = self.inner.right_slot(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(tokens.slot_gap)
+                    .children(right_slot)
+                    .children(toggle),
+            );
+        }
+        let caps_warning = caps_lock.then(|| {
+            let fg = resolve_hsla(&self.inner.theme, tokens.password_caps_lock_fg);
+            div()
+                .flex()
+                .items_center()
+                .gap(px(4.0))
+                .text_size(tokens.password_caps_lock_size)
+                .text_color(fg)
+                .child(
+                    self.id
+                        .ctx()
+                        .child("caps-lock-icon", Icon::named("arrow-big-up-line"))
+                        .size(f32::from(tokens.password_caps_lock_size))
+                        .color(fg),
+                )
+                .child(self.caps_lock_label.clone())
+        });
+        let meter = strength.map(|strength| self.render_strength_meter(strength));
+
+        let inner = self
+            .id
+            .ctx()
+            .root(self.inner)
+            .masked(!revealed)
+            .refine_style(&self.style);
+        div()
+            .id(self.id.slot("password"))
+            .flex()
+            .flex_col()
+            .w_full()
+            .gap(tokens.password_meter_gap)
+            .on_modifiers_changed(|_, window, _| window.refresh())
+            .child(inner)
+            .children(caps_warning)
+            .children(meter)
+    }
+}
+
+#[derive(Clone)]
+struct PinHandlers {
+    value_controlled: bool,
+    on_change: Option<ChangeHandler>,
+    on_complete: Option<SubmitHandler>,
+    verify: Option<PinVerifier>,
+}
+
+impl PinHandlers {
+    fn set_value(&self, id: &str, value: &str, window: &mut Window, cx: &mut gpui::App) {
+        if !self.value_controlled {
+            control::set_text_state(id, "value", value.to_string());
+        }
+        control::set_usize_state(id, "caret-index", value.chars().count());
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(value.to_string().into(), window, cx);
+        }
+    }
+
+    fn complete(&self, id: &str, code: SharedString, window: &mut Window, cx: &mut gpui::App) {
+        let Some(verify) = self.verify.as_ref() else {
+            if let Some(handler) = self.on_complete.as_ref() {
+                (handler)(code, window, cx);
+            }
+            return;
+        };
+
+        let ticket = pin_input_state::begin_verification(id);
+        let pending = verify(code.clone());
+        let window_handle = window.window_handle();
+        let handlers = self.clone();
+        let id = id.to_string();
+        window.refresh();
+        cx.spawn(async move |cx| {
+            let result = pending.await;
+            let _ = window_handle.update(cx, |_, window, cx| {
+                if !pin_input_state::finish_verification(&id, ticket) {
+                    return;
+                }
+                match result {
+                    Ok(()) => {
+                        if let Some(handler) = handlers.on_complete.as_ref() {
+                            (handler)(code, window, cx);
+                        }
+                    }
+                    Err(message) => {
+                        pin_input_state::set_verify_error(&id, Some(message.to_string()));
+                        handlers.set_value(&id, "", window, cx);
+                    }
+                }
+                window.refresh();
+            });
+        })
+        .detach();
     }
 }
 
@@ -1843,6 +2254,8 @@ pub struct PinInput {
     error: Option<SharedString>,
     disabled: bool,
     read_only: bool,
+    masked: bool,
+    verifying: bool,
     length: usize,
     variant: Variant,
     size: Size,
@@ -1851,6 +2264,8 @@ pub struct PinInput {
     motion: MotionConfig,
     focus_handle: Option<FocusHandle>,
     on_change: Option<ChangeHandler>,
+    on_complete: Option<SubmitHandler>,
+    verify: Option<PinVerifier>,
 }
 
 impl PinInput {
@@ -1864,6 +2279,8 @@ impl PinInput {
             error: None,
             disabled: false,
             read_only: false,
+            masked: false,
+            verifying: false,
             length: length.max(1),
             variant: Variant::Default,
             size: Size::Md,
@@ -1872,6 +2289,8 @@ impl PinInput {
             motion: MotionConfig::default(),
             focus_handle: None,
             on_change: None,
+            on_complete: None,
+            verify: None,
         }
     }
 
@@ -1890,6 +2309,19 @@ impl PinInput {
         self
     }
 
+    /// Shows a dot in filled cells instead of the digit.
+    pub fn masked(mut self, value: bool) -> Self {
+        self.masked = value;
+        self
+    }
+
+    /// Locks the cells while a check started outside the input runs. Checks
+    /// passed to [`PinInput::verify`] set this on their own.
+    pub fn verifying(mut self, value: bool) -> Self {
+        self.verifying = value;
+        self
+    }
+
     pub fn error(mut self, value: impl Into<SharedString>) -> Self {
         self.error = Some(value.into());
         self
@@ -1903,6 +2335,29 @@ impl PinInput {
         self
     }
 
+    /// Called with the code once every cell is filled, so the form can
+    /// submit without an extra button press. With [`PinInput::verify`] set,
+    /// it runs only after the check passes.
+    pub fn on_complete(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_complete = Some(Rc::new(handler));
+        self
+    }
+
+    /// Checks a completed code, for example against a server. The cells are
+    /// locked until it resolves; an `Err` clears them, shows the message and
+    /// shakes the field.
+    pub fn verify<F, Fut>(mut self, check: F) -> Self
+    where
+        F: Fn(SharedString) -> Fut + 'static,
+        Fut: Future<Output = Result<(), SharedString>> + 'static,
+    {
+        self.verify = Some(Rc::new(move |code| Box::pin(check(code))));
+        self
+    }
+
     pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
         self.focus_handle = Some(focus_handle);
         self
@@ -1960,9 +2415,8 @@ impl PinInput {
         id: &str,
         previous_value: &str,
         state: &InputState,
-        value_controlled: bool,
         length: usize,
-        on_change: Option<&ChangeHandler>,
+        handlers: &PinHandlers,
         window: &mut Window,
         cx: &mut gpui::App,
     ) {
@@ -1974,15 +2428,24 @@ impl PinInput {
         next_state.set_caret(next_len, false);
 
         let changed = next_state.value != previous_value;
-        if changed && !value_controlled {
+        if changed && !handlers.value_controlled {
             control::set_text_state(id, "value", next_state.value.clone());
         }
         control::set_usize_state(id, "caret-index", next_state.caret);
+        if changed {
+            pin_input_state::set_verify_error(id, None);
+        }
         window.refresh();
 
-        if changed && let Some(handler) = on_change {
+        if !changed {
+            return;
+        }
+        if let Some(handler) = handlers.on_change.as_ref() {
             (handler)(next_state.value.clone().into(), window, cx);
         }
+        if next_state.value.chars().count() == length {
+            handlers.complete(id, next_state.value.into(), window, cx);
+        }
     }
 
     fn digit_from_key(event: &gpui::KeyDownEvent) -> Option<char> {
@@ -1991,13 +2454,13 @@ impl PinInput {
             .key_char
             .as_ref()
             .and_then(|value| value.chars().next())
-            .filter(|ch| ch.is_ascii_digit())
+            .and_then(composition::ascii_digit)
             .or_else(|| {
                 let key = event.keystroke.key.as_str();
-                if key.len() == 1 {
-                    key.chars().next().filter(|ch| ch.is_ascii_digit())
-                } else {
-                    None
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => composition::ascii_digit(ch),
+                    _ => None,
                 }
             })
     }
@@ -2058,8 +2521,15 @@ impl RenderOnce for PinInput {
             + (f32::from(size_preset.padding_x) * 0.9))
             .max(24.0);
         let caret_color = resolve_hsla(&self.theme, self.theme.components.input.caret);
-        let has_error = self.error.is_some();
-        let interactive = !self.disabled && !self.read_only;
+        let verifying = self.verifying || pin_input_state::verifying(&self.id);
+        let error = self
+            .error
+            .clone()
+            .or_else(|| pin_input_state::verify_error(&self.id).map(SharedString::from));
+        let shake = pin_input_state::note_error(&self.id, error.as_deref());
+        let has_error = error.is_some();
+        let locked = self.disabled || verifying;
+        let interactive = !locked && !self.read_only;
 
         let mut root = Stack::horizontal()
             .id(self.id.clone())
@@ -2071,39 +2541,46 @@ impl RenderOnce for PinInput {
             let input_id = self.id.clone();
             let rendered_value_for_edit = normalized_value.clone();
             let value_controlled = self.value_controlled;
-            let on_change = self.on_change.clone();
+            let handlers = PinHandlers {
+                value_controlled,
+                on_change: self.on_change.clone(),
+                on_complete: self.on_complete.clone(),
+                verify: self.verify.clone(),
+            };
             let length = self.length;
             root = root
-                .on_action(move |_: &DeleteBackward, window, cx| {
-                    let current_value = Self::current_value_for(
-                        &input_id,
-                        &rendered_value_for_edit,
-                        value_controlled,
-                    );
-                    let mut state = Self::editor_state_for(
-                        &input_id,
-                        &rendered_value_for_edit,
-                        value_controlled,
-                        length,
-                    );
-                    if !state.delete_backward() {
-                        return;
+                .on_action({
+                    let handlers = handlers.clone();
+                    move |_: &DeleteBackward, window, cx| {
+                        let current_value = Self::current_value_for(
+                            &input_id,
+                            &rendered_value_for_edit,
+                            value_controlled,
+                        );
+                        let mut state = Self::editor_state_for(
+                            &input_id,
+                            &rendered_value_for_edit,
+                            value_controlled,
+                            length,
+                        );
+                        if !state.delete_backward() {
+                            return;
+                        }
+                        Self::apply_editor_state(
+                            &input_id,
+                            &current_value,
+                            &state,
+                            length,
+                            &handlers,
+                            window,
+                            cx,
+                        );
                     }
-                    Self::apply_editor_state(
-                        &input_id,
-                        &current_value,
-                        &state,
-                        value_controlled,
-                        length,
-                        on_change.as_ref(),
-                        window,
-                        cx,
-                    );
                 })
                 .on_action({
                     let input_id = self.id.clone();
                     let rendered_value_for_edit = normalized_value.clone();
-                    let on_change = self.on_change.clone();
+                    let handlers = handlers.clone();
                     move |_: &DeleteForward, window, cx| {
                         let current_value = Self::current_value_for(
                             &input_id,
@@ -2123,9 +2600,8 @@ impl RenderOnce for PinInput {
                             &input_id,
                             &current_value,
                             &state,
-                            value_controlled,
                             length,
-                            on_change.as_ref(),
+                            &handlers,
                             window,
                             cx,
                         );
@@ -2134,7 +2610,7 @@ impl RenderOnce for PinInput {
                 .on_action({
                     let input_id = self.id.clone();
                     let rendered_value_for_edit = normalized_value.clone();
-                    let on_change = self.on_change.clone();
+                    let handlers = handlers.clone();
                     move |_: &PasteClipboard, window, cx| {
                         let Some(item) = cx.read_from_clipboard() else {
                             return;
@@ -2142,10 +2618,7 @@ impl RenderOnce for PinInput {
                         let Some(pasted) = item.text() else {
                             return;
                         };
-                        let digits = pasted
-                            .chars()
-                            .filter(|ch| ch.is_ascii_digit())
-                            .collect::<String>();
+                        let (digits, replaces) = pin_input_state::pasted_code(&pasted, length);
                         if digits.is_empty() {
                             return;
                         }
@@ -2161,15 +2634,17 @@ impl RenderOnce for PinInput {
                             value_controlled,
                             length,
                         );
+                        if replaces {
+                            state = InputState::new(String::new(), 0, 0, None);
+                        }
                         state.insert_text(&digits);
                         state.clamp_to_max_length(Some(length));
                         Self::apply_editor_state(
                             &input_id,
                             &current_value,
                             &state,
-                            value_controlled,
                             length,
-                            on_change.as_ref(),
+                            &handlers,
                             window,
                             cx,
                         );
@@ -2178,7 +2653,7 @@ impl RenderOnce for PinInput {
                 .on_key_down({
                     let input_id = self.id.clone();
                     let rendered_value_for_edit = normalized_value.clone();
-                    let on_change = self.on_change.clone();
+                    let handlers = handlers.clone();
                     move |event, window, cx| {
                         if event.keystroke.modifiers.control
                             || event.keystroke.modifiers.platform
@@ -2209,9 +2684,8 @@ impl RenderOnce for PinInput {
                             &input_id,
                             &current_value,
                             &state,
-                            value_controlled,
                             length,
-                            on_change.as_ref(),
+                            &handlers,
                             window,
                             cx,
                         );
@@ -2221,7 +2695,7 @@ impl RenderOnce for PinInput {
         }
 
         if let Some(focus_handle) = &self.focus_handle
-            && !self.disabled
+            && !locked
         {
             let handle_for_click = focus_handle.clone();
             let focus_state_id = self.id.clone();
@@ -2243,7 +2717,7 @@ impl RenderOnce for PinInput {
                     window.focus(&handle_for_click, cx);
                     window.refresh();
                 });
-        } else if !self.disabled {
+        } else if !locked {
             let focus_state_id = self.id.clone();
             let input_id = self.id.clone();
             let rendered_value_for_focus = normalized_value.clone();
@@ -2259,7 +2733,7 @@ impl RenderOnce for PinInput {
             });
         }
 
-        if self.disabled {
+        if locked {
             root = root.opacity(0.55);
         }
 
@@ -2270,7 +2744,13 @@ impl RenderOnce for PinInput {
         });
 
         for index in 0..self.length {
-            let content = value_chars.get(index).map(|ch| ch.to_string());
+            let content = value_chars.get(index).map(|ch| {
+                if self.masked {
+                    "\u{2022}".to_string()
+                } else {
+                    ch.to_string()
+                }
+            });
             let border = if self.disabled {
                 resolve_hsla(&self.theme, self.theme.semantic.border_subtle)
             } else if has_error {
@@ -2350,7 +2830,7 @@ impl RenderOnce for PinInput {
             root = root.child(cell);
         }
 
-        let field = if self.disabled || self.read_only {
+        let field = if locked || self.read_only {
             div()
                 .cursor_default()
                 .child(root.with_enter_transition(self.id.slot("enter"), self.motion))
@@ -2359,8 +2839,25 @@ impl RenderOnce for PinInput {
                 .cursor_text()
                 .child(root.with_enter_transition(self.id.slot("enter"), self.motion))
         };
+        let field = if shake > 0 && self.motion.level != MotionLevel::None {
+            let profile = MotionTransition::new()
+                .preset(TransitionPreset::Shake)
+                .duration_ms(360)
+                .offset_px(30)
+                .start_opacity_pct(100)
+                .easing(Easing::Linear);
+            field
+                .with_transition_profile(
+                    self.id.slot_index("shake", shake.to_string()),
+                    profile,
+                    TransitionStage::Enter,
+                )
+                .into_any_element()
+        } else {
+            field.into_any_element()
+        };
 
-        if let Some(error) = self.error {
+        if let Some(error) = error {
             Stack::vertical()
                 .id(self.id.slot("field"))
                 .gap(self.theme.components.input.pin_error_gap)
@@ -2373,7 +2870,7 @@ impl RenderOnce for PinInput {
                 )
                 .into_any_element()
         } else {
-            field.into_any_element()
+            field
         }
     }
 }
@@ -2385,6 +2882,7 @@ crate::impl_variant_size_radius_via_methods!(PinInput, variant, size, radius);
 
 impl crate::contracts::Disableable for PasswordInput {
     fn disabled(mut self, value: bool) -> Self {
+        self.disabled = value;
         self.inner = crate::contracts::Disableable::disabled(self.inner, value);
         self
     }
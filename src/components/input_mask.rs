@@ -0,0 +1,319 @@
+/// Formatting rule for [`TextInput::mask`](super::TextInput::mask).
+///
+/// The field shows the formatted text while `value`, `default_value`,
+/// `on_change` and `on_submit` deal in the bare characters, so a form model
+/// stores `5551234567` rather than `(555) 123-4567`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMask {
+    kind: MaskKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum MaskKind {
+    Pattern(Vec<MaskSlot>),
+    Number(NumberFormat),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MaskSlot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl MaskSlot {
+    fn accepts(self, ch: char) -> bool {
+        match self {
+            Self::Digit => ch.is_ascii_digit(),
+            Self::Letter => ch.is_alphabetic(),
+            Self::Alphanumeric => ch.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct NumberFormat {
+    prefix: String,
+    suffix: String,
+    decimals: usize,
+    thousands_separator: Option<char>,
+    decimal_separator: char,
+    signed: bool,
+}
+
+impl InputMask {
+    /// Fixed-shape mask. `#` takes a digit, `A` a letter and `*` either;
+    /// `\` escapes the next character, and anything else is shown as is,
+    /// e.g. `(###) ###-####` for phone numbers or `##/##/####` for dates.
+    pub fn pattern(spec: &str) -> Self {
+        let mut slots = Vec::new();
+        let mut chars = spec.chars();
+        while let Some(ch) = chars.next() {
+            slots.push(match ch {
+                '#' => MaskSlot::Digit,
+                'A' => MaskSlot::Letter,
+                '*' => MaskSlot::Alphanumeric,
+                '\\' => match chars.next() {
+                    Some(escaped) => MaskSlot::Literal(escaped),
+                    None => break,
+                },
+                other => MaskSlot::Literal(other),
+            });
+        }
+        Self {
+            kind: MaskKind::Pattern(slots),
+        }
+    }
+
+    /// Whole number grouped by thousands, e.g. `1,234,567`.
+    pub fn number() -> Self {
+        Self {
+            kind: MaskKind::Number(NumberFormat {
+                prefix: String::new(),
+                suffix: String::new(),
+                decimals: 0,
+                thousands_separator: Some(','),
+                decimal_separator: '.',
+                signed: false,
+            }),
+        }
+    }
+
+    /// Amount with a currency symbol and two decimals, e.g. `$1,234.50`.
+    /// The bare value uses `.` as the decimal point.
+    pub fn currency(symbol: impl Into<String>) -> Self {
+        Self::number().prefix(symbol).decimals(2)
+    }
+
+    /// Digits allowed after the decimal separator. Number masks only.
+    pub fn decimals(mut self, value: usize) -> Self {
+        if let MaskKind::Number(format) = &mut self.kind {
+            format.decimals = value;
+        }
+        self
+    }
+
+    /// Number masks only.
+    pub fn thousands_separator(mut self, value: Option<char>) -> Self {
+        if let MaskKind::Number(format) = &mut self.kind {
+            format.thousands_separator = value;
+        }
+        self
+    }
+
+    /// Number masks only.
+    pub fn decimal_separator(mut self, value: char) -> Self {
+        if let MaskKind::Number(format) = &mut self.kind {
+            format.decimal_separator = value;
+        }
+        self
+    }
+
+    /// Lets the value start with `-`, shown ahead of the prefix as in
+    /// `-$12.50`. Number masks only.
+    pub fn signed(mut self, value: bool) -> Self {
+        if let MaskKind::Number(format) = &mut self.kind {
+            format.signed = value;
+        }
+        self
+    }
+
+    /// Text shown before the number. Number masks only.
+    pub fn prefix(mut self, value: impl Into<String>) -> Self {
+        if let MaskKind::Number(format) = &mut self.kind {
+            format.prefix = value.into();
+        }
+        self
+    }
+
+    /// Text shown after the number, such as ` kg`. Number masks only.
+    pub fn suffix(mut self, value: impl Into<String>) -> Self {
+        if let MaskKind::Number(format) = &mut self.kind {
+            format.suffix = value.into();
+        }
+        self
+    }
+
+    /// Formats a bare value for display. Characters the mask can't place are
+    /// dropped.
+    pub fn format(&self, raw: &str) -> String {
+        match &self.kind {
+            MaskKind::Pattern(slots) => format_pattern(slots, raw),
+            MaskKind::Number(format) => format_number(format, raw),
+        }
+    }
+
+    /// Recovers the bare value from displayed text.
+    pub fn unmask(&self, display: &str) -> String {
+        match &self.kind {
+            MaskKind::Pattern(slots) => unmask_pattern(slots, display),
+            MaskKind::Number(format) => unmask_number(format, display),
+        }
+    }
+
+    /// Reformats the field after an edit turned `previous` into `next`, and
+    /// moves the caret so it stays after the same typed character.
+    pub(crate) fn apply_edit(
+        &self,
+        previous: &str,
+        previous_caret: usize,
+        next: &str,
+        caret: usize,
+    ) -> (String, usize) {
+        let mut raw = self.unmask(next).chars().collect::<Vec<_>>();
+        let mut raw_caret = self.raw_len_before(next, caret);
+        let removed_only_literals = next.chars().count() < previous.chars().count()
+            && raw.iter().collect::<String>() == self.unmask(previous);
+        if removed_only_literals {
+            // Deleting a separator alone would be undone by reformatting, so
+            // take the character on the other side of it as well.
+            if caret < previous_caret {
+                if raw_caret > 0 {
+                    raw_caret -= 1;
+                    raw.remove(raw_caret);
+                }
+            } else if raw_caret < raw.len() {
+                raw.remove(raw_caret);
+            }
+        }
+        let formatted = self.format(&raw.into_iter().collect::<String>());
+        let len = formatted.chars().count();
+        let caret = (0..=len)
+            .find(|index| self.raw_len_before(&formatted, *index) >= raw_caret)
+            .unwrap_or(len);
+        (formatted, caret)
+    }
+
+    fn raw_len_before(&self, display: &str, caret: usize) -> usize {
+        let head = display.chars().take(caret).collect::<String>();
+        self.unmask(&head).chars().count()
+    }
+}
+
+fn format_pattern(slots: &[MaskSlot], raw: &str) -> String {
+    let mut chars = raw.chars();
+    let mut out = String::new();
+    let mut pending = String::new();
+    for slot in slots {
+        if let MaskSlot::Literal(literal) = slot {
+            pending.push(*literal);
+            continue;
+        }
+        let Some(ch) = chars.by_ref().find(|ch| slot.accepts(*ch)) else {
+            break;
+        };
+        out.push_str(&pending);
+        pending.clear();
+        out.push(ch);
+    }
+    out
+}
+
+fn unmask_pattern(slots: &[MaskSlot], display: &str) -> String {
+    let mut out = String::new();
+    let mut slot = 0;
+    for ch in display.chars() {
+        if slots.get(slot) == Some(&MaskSlot::Literal(ch)) {
+            slot += 1;
+            continue;
+        }
+        let Some(input) =
+            (slot..slots.len()).find(|index| !matches!(slots[*index], MaskSlot::Literal(_)))
+        else {
+            break;
+        };
+        if slots[input].accepts(ch) {
+            out.push(ch);
+            slot = input + 1;
+        }
+    }
+    out
+}
+
+fn format_number(format: &NumberFormat, raw: &str) -> String {
+    let raw = number_chars(raw, '.', format.decimals, format.signed);
+    let (sign, raw) = match raw.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", raw.as_str()),
+    };
+    if raw.is_empty() {
+        // A lone minus sign is kept so the rest of the number can follow.
+        return if sign.is_empty() {
+            String::new()
+        } else {
+            format!("{sign}{}", format.prefix)
+        };
+    }
+    let (integer, fraction) = match raw.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (raw, None),
+    };
+    let integer = match integer.trim_start_matches('0') {
+        "" => "0",
+        trimmed => trimmed,
+    };
+
+    let mut out = format!("{sign}{}", format.prefix);
+    let digits = integer.chars().collect::<Vec<_>>();
+    for (index, digit) in digits.iter().enumerate() {
+        let remaining = digits.len() - index;
+        if index > 0
+            && remaining % 3 == 0
+            && let Some(separator) = format.thousands_separator
+        {
+            out.push(separator);
+        }
+        out.push(*digit);
+    }
+    if let Some(fraction) = fraction {
+        out.push(format.decimal_separator);
+        out.push_str(fraction);
+    }
+    out.push_str(&format.suffix);
+    out
+}
+
+fn unmask_number(format: &NumberFormat, display: &str) -> String {
+    let (negative, body) = match display.strip_prefix('-') {
+        Some(rest) if format.signed => (true, rest),
+        _ => (false, display),
+    };
+    let body = body.strip_prefix(&format.prefix).unwrap_or(body);
+    let body = body.strip_suffix(&format.suffix).unwrap_or(body);
+    let digits = number_chars(
+        body,
+        format.decimal_separator,
+        format.decimals,
+        format.signed,
+    );
+    if negative && !digits.starts_with('-') {
+        format!("-{digits}")
+    } else {
+        digits
+    }
+}
+
+/// Digits plus at most one decimal point (written as `.`) followed by at
+/// most `decimals` digits, with a leading `-` when `signed`.
+fn number_chars(text: &str, point: char, decimals: usize, signed: bool) -> String {
+    let mut out = String::new();
+    let mut fraction_len = None;
+    for ch in text.chars() {
+        if ch == '-' && signed && out.is_empty() {
+            out.push(ch);
+        } else if ch.is_ascii_digit() {
+            match fraction_len.as_mut() {
+                Some(len) if *len >= decimals => continue,
+                Some(len) => *len += 1,
+                None => {}
+            }
+            out.push(ch);
+        } else if ch == point && decimals > 0 && fraction_len.is_none() {
+            fraction_len = Some(0);
+            out.push('.');
+        }
+    }
+    out
+}
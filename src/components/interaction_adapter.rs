@@ -3,7 +3,9 @@ use std::rc::Rc;
 use gpui::StatefulInteractiveElement;
 use gpui::{ClickEvent, FocusHandle, InteractiveElement, Window};
 
+use crate::focus::{self, FocusOrientation};
 use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
 
 use super::control;
 
@@ -14,6 +16,7 @@ pub type ClickActivateHandler = Rc<dyn Fn(&ClickEvent, &mut Window, &mut gpui::A
 pub struct PressAdapter {
     pub id: ComponentId,
     pub focus_handle: Option<FocusHandle>,
+    pub component: Option<&'static str>,
     pub on_activate: Option<ActivateHandler>,
     pub on_click: Option<ClickActivateHandler>,
 }
@@ -23,6 +26,7 @@ impl PressAdapter {
         Self {
             id: id.into(),
             focus_handle: None,
+            component: None,
             on_activate: None,
             on_click: None,
         }
@@ -42,6 +46,11 @@ impl PressAdapter {
         self.focus_handle = value;
         self
     }
+
+    pub fn component(mut self, value: &'static str) -> Self {
+        self.component = Some(value);
+        self
+    }
 }
 
 pub fn bind_press_adapter<T>(mut node: T, adapter: PressAdapter) -> T
@@ -61,11 +70,13 @@ where
     let activate_handler = adapter.on_activate.clone();
     let id_for_click = adapter.id.clone();
     let focus_for_click = adapter.focus_handle.clone();
+    let component = adapter.component;
     node = node.on_click(move |event, window, cx| {
         control::set_focused_state(&id_for_click, true);
         if let Some(focus_handle) = focus_for_click.as_ref() {
             window.focus(focus_handle, cx);
         }
+        emit_press(component, &id_for_click, cx);
         if let Some(handler) = click_handler.as_ref() {
             (handler)(event, window, cx);
         } else if let Some(handler) = activate_handler.as_ref() {
@@ -86,6 +97,7 @@ where
         if let Some(focus_handle) = focus_for_key.as_ref() {
             window.focus(focus_handle, cx);
         }
+        emit_press(component, &id_for_key, cx);
         if let Some(handler) = activate_handler.as_ref() {
             (handler)(window, cx);
         } else if let Some(handler) = click_handler.as_ref() {
@@ -104,6 +116,56 @@ where
     node
 }
 
+fn emit_press(component: Option<&'static str>, id: &ComponentId, cx: &gpui::App) {
+    if let Some(component) = component {
+        interaction::emit(
+            InteractionEvent::new(component, id, InteractionAction::Press),
+            cx,
+        );
+    }
+}
+
+pub struct RovingItem {
+    pub id: ComponentId,
+    pub focus_handle: FocusHandle,
+    pub disabled: bool,
+    pub on_activate: Option<ActivateHandler>,
+}
+
+pub fn bind_roving_focus<T>(
+    node: T,
+    items: Vec<RovingItem>,
+    orientation: FocusOrientation,
+    activate: bool,
+) -> T
+where
+    T: InteractiveElement,
+{
+    if items.is_empty() {
+        return node;
+    }
+    let disabled = items.iter().map(|item| item.disabled).collect::<Vec<_>>();
+    node.on_key_down(move |event, window, cx| {
+        let current = items
+            .iter()
+            .position(|item| item.focus_handle.is_focused(window));
+        let Some(target) = focus::roving_target(event, current, &disabled, orientation) else {
+            return;
+        };
+        if let Some(current) = current {
+            control::set_focused_state(&items[current].id, false);
+        }
+        let item = &items[target];
+        control::set_focused_state(&item.id, true);
+        window.focus(&item.focus_handle, cx);
+        if let Some(handler) = item.on_activate.as_ref().filter(|_| activate) {
+            (handler)(window, cx);
+        }
+        window.refresh();
+        cx.stop_propagation();
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::PressAdapter;
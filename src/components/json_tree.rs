@@ -0,0 +1,477 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use gpui::{
+    AnyElement, Hsla, InteractiveElement, IntoElement, ParentElement, RenderOnce, ScrollHandle,
+    SharedString, StatefulInteractiveElement, Styled, StyledText, TextRun, Window, canvas, div,
+    point, px,
+};
+use serde_json::Value;
+
+use crate::clipboard::Clipboard;
+use crate::contracts::{Sized, Varianted};
+use crate::id::ComponentId;
+use crate::style::{Size, Variant};
+
+use super::action_icon::ActionIcon;
+use super::icon::Icon;
+use super::json_tree_state::{self, JsonKey, JsonRow, JsonRowKind, JsonSearch};
+use super::kanban_board_state::visible_window;
+use super::text_selection::{find_ranges, highlight_runs};
+use super::utils::resolve_hsla;
+
+type CopyHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+type ToggleHandler = Rc<dyn Fn(SharedString, bool, &mut Window, &mut gpui::App)>;
+
+/// Characters of a string value shown in its row.
+const PREVIEW_CHARS: usize = 500;
+
+struct JsonSearchCache {
+    root: Arc<Value>,
+    query: String,
+    result: Arc<JsonSearch>,
+}
+
+static JSON_SEARCHES: LazyLock<Mutex<HashMap<String, JsonSearchCache>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Searches `root` once per document and query rather than every frame.
+/// Documents are told apart by their `Arc`, so pass the same one each time.
+fn cached_search(key: &str, root: &Arc<Value>, query: &str) -> Arc<JsonSearch> {
+    let Ok(mut searches) = JSON_SEARCHES.lock() else {
+        return Arc::new(json_tree_state::search(root, query));
+    };
+    if let Some(cache) = searches
+        .get(key)
+        .filter(|cache| Arc::ptr_eq(&cache.root, root) && cache.query == query)
+    {
+        return cache.result.clone();
+    }
+    if searches.len() > 64 && !searches.contains_key(key) {
+        searches.clear();
+    }
+    let result = Arc::new(json_tree_state::search(root, query));
+    searches.insert(
+        key.to_owned(),
+        JsonSearchCache {
+            root: root.clone(),
+            query: query.to_owned(),
+            result: result.clone(),
+        },
+    );
+    result
+}
+
+/// Shows a JSON document as a tree of expandable objects and arrays, with
+/// values colored by type. Rows are virtualized and containers list their
+/// children a page at a time, so large documents stay responsive.
+///
+/// Needs the `json` feature.
+#[derive(IntoElement)]
+pub struct JsonTree {
+    pub(crate) id: ComponentId,
+    value: Arc<Value>,
+    search: SharedString,
+    expand_depth: usize,
+    page_size: usize,
+    copy_actions: bool,
+    overscan: usize,
+    on_copy: Option<CopyHandler>,
+    on_toggle: Option<ToggleHandler>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl JsonTree {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: Arc::new(Value::Null),
+            search: SharedString::default(),
+            expand_depth: 1,
+            page_size: 100,
+            copy_actions: true,
+            overscan: 10,
+            on_copy: None,
+            on_toggle: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<Arc<Value>>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Highlights keys and values containing this text, ignoring ASCII case,
+    /// and opens the containers around them.
+    pub fn search(mut self, value: impl Into<SharedString>) -> Self {
+        self.search = value.into();
+        self
+    }
+
+    /// Levels open at first; `1` shows the root's children.
+    pub fn expand_depth(mut self, value: usize) -> Self {
+        self.expand_depth = value;
+        self
+    }
+
+    /// Children a container lists before a "Show more" row.
+    pub fn page_size(mut self, value: usize) -> Self {
+        self.page_size = value.max(1);
+        self
+    }
+
+    /// Shows copy path and copy value buttons on hovered rows.
+    pub fn copy_actions(mut self, value: bool) -> Self {
+        self.copy_actions = value;
+        self
+    }
+
+    /// Rows rendered above and below the visible ones.
+    pub fn overscan(mut self, value: usize) -> Self {
+        self.overscan = value;
+        self
+    }
+
+    /// Called with the text written to the clipboard.
+    pub fn on_copy(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_copy = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called with the JSON Pointer of a container and whether it opened.
+    pub fn on_toggle(
+        mut self,
+        handler: impl Fn(SharedString, bool, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    fn text(&self, text: String, color: Hsla) -> StyledText {
+        let tokens = &self.theme.components.json_tree;
+        let run = TextRun {
+            len: text.len(),
+            font: self.theme.fonts.monospace(),
+            color,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let match_bg = resolve_hsla(&self.theme, tokens.match_bg);
+        let runs = find_ranges(&text, &self.search, false)
+            .into_iter()
+            .fold(vec![run], |runs, range| {
+                highlight_runs(runs, range, match_bg)
+            });
+        StyledText::new(text).with_runs(runs)
+    }
+
+    fn render_value(&self, value: &Value, expanded: bool) -> AnyElement {
+        let tokens = &self.theme.components.json_tree;
+        let color = |token| resolve_hsla(&self.theme, token);
+        match value {
+            Value::String(text) => {
+                let preview: String = text.chars().take(PREVIEW_CHARS).collect();
+                let mut shown = serde_json::to_string(&preview).unwrap_or_default();
+                if preview.len() < text.len() {
+                    shown.insert(shown.len() - 1, '…');
+                }
+                self.text(shown, color(tokens.string_fg)).into_any_element()
+            }
+            Value::Number(number) => self
+                .text(number.to_string(), color(tokens.number_fg))
+                .into_any_element(),
+            Value::Bool(flag) => self
+                .text(flag.to_string(), color(tokens.boolean_fg))
+                .into_any_element(),
+            Value::Null => self
+                .text("null".to_owned(), color(tokens.null_fg))
+                .into_any_element(),
+            Value::Array(_) | Value::Object(_) => {
+                let empty = match value {
+                    Value::Array(items) => items.is_empty(),
+                    Value::Object(fields) => fields.is_empty(),
+                    _ => false,
+                };
+                let brackets = match (value, empty, expanded) {
+                    (Value::Array(_), true, _) => "[]",
+                    (Value::Array(_), false, true) => "[",
+                    (Value::Array(_), false, false) => "[…]",
+                    (_, true, _) => "{}",
+                    (_, false, true) => "{",
+                    (_, false, false) => "{…}",
+                };
+                div()
+                    .flex()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_color(color(tokens.punctuation_fg))
+                            .child(brackets),
+                    )
+                    .children((!empty).then(|| {
+                        div()
+                            .text_color(color(tokens.summary_fg))
+                            .child(json_tree_state::summary(value).unwrap_or_default())
+                    }))
+                    .into_any_element()
+            }
+        }
+    }
+
+    /// Copy button for the value at `pointer`. The text is only built on
+    /// click, since a container's can be the whole document.
+    fn copy_action(
+        &self,
+        slot: &str,
+        pointer: &str,
+        icon: &str,
+        text: fn(&Value, &str) -> Option<String>,
+    ) -> ActionIcon {
+        let tokens = &self.theme.components.json_tree;
+        let clipboard = Clipboard::new(format!("{}:{slot}:{pointer}", self.id));
+        let copied = clipboard.copied();
+        let root = self.value.clone();
+        let target = pointer.to_owned();
+        let on_copy = self.on_copy.clone();
+        self.id
+            .ctx()
+            .child_index(slot, pointer.to_owned(), ActionIcon::new())
+            .with_variant(Variant::Subtle)
+            .with_size(Size::Xs)
+            .child(
+                Icon::named(if copied { "check" } else { icon })
+                    .size(f32::from(tokens.toggle_size)),
+            )
+            .on_click(move |_, window, cx| {
+                let Some(text) = text(&root, &target) else {
+                    return;
+                };
+                clipboard.copy(text.clone(), window, cx);
+                if let Some(handler) = on_copy.as_ref() {
+                    (handler)(text.into(), window, cx);
+                }
+            })
+    }
+
+    fn render_row(&self, row: &JsonRow<'_>) -> AnyElement {
+        let tokens = &self.theme.components.json_tree;
+        let id = self.id.to_string();
+        let color = |token| resolve_hsla(&self.theme, token);
+        let indent = tokens.padding + tokens.indent * row.depth as f32;
+        let pointer = row.pointer.clone();
+
+        let value = match row.kind {
+            JsonRowKind::Value(value) => value,
+            JsonRowKind::More { remaining } => {
+                let page_size = self.page_size;
+                return div()
+                    .id(self.id.slot_index("more", pointer.clone()))
+                    .h(tokens.row_height)
+                    .flex()
+                    .items_center()
+                    .pl(indent + tokens.toggle_size)
+                    .text_color(color(tokens.more_fg))
+                    .cursor_pointer()
+                    .child(format!(
+                        "Show {} more ({remaining} hidden)",
+                        remaining.min(page_size)
+                    ))
+                    .on_click(move |_, window, _cx| {
+                        json_tree_state::show_more(&id, &pointer, page_size);
+                        window.refresh();
+                    })
+                    .into_any_element();
+            }
+        };
+
+        let toggles = matches!(value, Value::Array(items) if !items.is_empty())
+            || matches!(value, Value::Object(fields) if !fields.is_empty());
+        let toggle = if toggles {
+            Icon::named(if row.expanded {
+                "chevron-down"
+            } else {
+                "chevron-right"
+            })
+            .size(f32::from(tokens.toggle_size))
+            .color(color(tokens.toggle_fg))
+            .into_any_element()
+        } else {
+            div().size(tokens.toggle_size).into_any_element()
+        };
+        let key = row.key.as_ref().map(|key| {
+            let (text, fg) = match key {
+                JsonKey::Field(name) if name.is_empty() => ("\"\"".to_owned(), tokens.key_fg),
+                JsonKey::Field(name) => (name.clone(), tokens.key_fg),
+                JsonKey::Index(index) => (index.to_string(), tokens.index_fg),
+            };
+            div()
+                .flex_none()
+                .flex()
+                .child(self.text(text, color(fg)))
+                .child(div().text_color(color(tokens.punctuation_fg)).child(":"))
+        });
+
+        let mut main = div()
+            .id(self.id.slot_index("row", pointer.clone()))
+            .flex_1()
+            .min_w_0()
+            .h_full()
+            .flex()
+            .items_center()
+            .gap_1()
+            .pl(indent)
+            .overflow_hidden()
+            .child(toggle)
+            .children(key)
+            .child(self.render_value(value, row.expanded));
+        if toggles {
+            let expanded = row.expanded;
+            let on_toggle = self.on_toggle.clone();
+            main = main.cursor_pointer().on_click(move |_, window, cx| {
+                json_tree_state::set_toggled(&id, &pointer, !expanded);
+                if let Some(handler) = on_toggle.as_ref() {
+                    (handler)(pointer.clone().into(), !expanded, window, cx);
+                }
+                window.refresh();
+            });
+        }
+
+        let group = SharedString::from(format!("{}:row:{}", self.id, row.pointer));
+        let hover_bg = color(tokens.row_hover_bg);
+        let actions = self.copy_actions.then(|| {
+            div()
+                .flex_none()
+                .flex()
+                .items_center()
+                .pr(tokens.padding)
+                .opacity(0.0)
+                .group_hover(group.clone(), |style| style.opacity(1.0))
+                .child(
+                    self.copy_action("copy-path", &row.pointer, "route", |root, pointer| {
+                        Some(json_tree_state::display_path(root, pointer))
+                    }),
+                )
+                .child(
+                    self.copy_action("copy-value", &row.pointer, "copy", |root, pointer| {
+                        root.pointer(pointer).map(json_tree_state::copy_text)
+                    }),
+                )
+        });
+
+        div()
+            .group(group)
+            .h(tokens.row_height)
+            .flex()
+            .items_center()
+            .hover(move |style| style.bg(hover_bg))
+            .child(main)
+            .children(actions)
+            .into_any_element()
+    }
+}
+
+impl RenderOnce for JsonTree {
+    fn render(mut self, _window: &mut Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.json_tree.clone();
+        let id = self.id.to_string();
+        let root = self.value.clone();
+        let search = cached_search(&id, &root, &self.search);
+        let (opened, closed) = json_tree_state::toggles(&id);
+        let expand_depth = self.expand_depth;
+        let page_size = self.page_size;
+        let rows = json_tree_state::visible_rows(
+            &root,
+            |pointer, depth| {
+                if opened.contains(pointer) {
+                    true
+                } else if closed.contains(pointer) {
+                    false
+                } else {
+                    depth < expand_depth || search.reveal.contains_key(pointer)
+                }
+            },
+            |pointer| {
+                let needed = search.reveal.get(pointer).copied().unwrap_or(0);
+                json_tree_state::shown(&id, pointer, page_size).max(needed)
+            },
+        );
+
+        let row_height = f32::from(tokens.row_height);
+        let viewport = json_tree_state::viewport(&id, row_height * 20.0);
+        if json_tree_state::query_changed(&id, &self.search) {
+            let first = search.matches.first().and_then(|pointer| {
+                rows.iter().position(|row| {
+                    matches!(row.kind, JsonRowKind::Value(_)) && &row.pointer == pointer
+                })
+            });
+            if let Some(index) = first {
+                json_tree_state::set_scroll_y(&id, index as f32 * row_height);
+            }
+        }
+        let max_scroll = (rows.len() as f32 * row_height - viewport).max(0.0);
+        let scroll_y = json_tree_state::scroll_y(&id).min(max_scroll);
+        let window_range =
+            visible_window(rows.len(), row_height, viewport, scroll_y, self.overscan);
+
+        let scroll_handle = ScrollHandle::new();
+        scroll_handle.set_offset(point(px(0.0), px(-scroll_y)));
+        let monitor = {
+            let handle = scroll_handle.clone();
+            let id = id.clone();
+            canvas(
+                move |_bounds, window, _cx| {
+                    let top = -f32::from(handle.offset().y);
+                    let mut refresh = json_tree_state::store_viewport(
+                        &id,
+                        f32::from(handle.bounds().size.height),
+                    );
+                    refresh |= json_tree_state::store_scroll_y(&id, top, row_height);
+                    if refresh {
+                        window.refresh();
+                    }
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full()
+        };
+
+        let list = div()
+            .id(self.id.slot("list"))
+            .relative()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&scroll_handle)
+            .child(div().h(px(window_range.start as f32 * row_height)))
+            .children(
+                rows[window_range.clone()]
+                    .iter()
+                    .map(|row| self.render_row(row)),
+            )
+            .child(div().h(px((rows.len() - window_range.end) as f32 * row_height)))
+            .child(monitor);
+
+        div()
+            .id(self.id.clone())
+            .size_full()
+            .overflow_hidden()
+            .rounded(tokens.radius)
+            .border_1()
+            .border_color(resolve_hsla(&self.theme, tokens.border))
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .py(tokens.padding)
+            .font(self.theme.fonts.monospace())
+            .text_size(tokens.text_size)
+            .whitespace_nowrap()
+            .text_color(resolve_hsla(&self.theme, tokens.punctuation_fg))
+            .child(list)
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::{HashMap, HashSet};
+
+use serde_json::Value;
+
+use super::control;
+use super::text_selection::find_ranges;
+
+/// Search stops collecting after this many matches, so a one-letter query
+/// on a huge document does not expand all of it.
+pub const MAX_MATCHES: usize = 1000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonKey {
+    Field(String),
+    Index(usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonRowKind<'a> {
+    Value(&'a Value),
+    /// Stands in for the children of `pointer` past the ones shown.
+    More {
+        remaining: usize,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonRow<'a> {
+    pub depth: usize,
+    /// JSON Pointer (RFC 6901) of the value, or of the parent for `More`.
+    pub pointer: String,
+    pub key: Option<JsonKey>,
+    pub kind: JsonRowKind<'a>,
+    pub expanded: bool,
+}
+
+/// Pointer of `key` under the value at `parent`.
+pub fn child_pointer(parent: &str, key: &JsonKey) -> String {
+    match key {
+        JsonKey::Field(name) => format!("{parent}/{}", name.replace('~', "~0").replace('/', "~1")),
+        JsonKey::Index(index) => format!("{parent}/{index}"),
+    }
+}
+
+/// Path to the value at `pointer` the way code would spell it, such as
+/// `$.users[0]["first name"]`.
+pub fn display_path(root: &Value, pointer: &str) -> String {
+    let mut path = String::from("$");
+    let mut current = Some(root);
+    for token in pointer.split('/').skip(1) {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        match current {
+            Some(Value::Array(items)) => {
+                path.push_str(&format!("[{token}]"));
+                current = token
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| items.get(index));
+            }
+            _ => {
+                let plain = token.chars().next().is_some_and(|first| {
+                    first.is_ascii_alphabetic() || first == '_' || first == '$'
+                }) && token
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$');
+                if plain {
+                    path.push('.');
+                    path.push_str(&token);
+                } else {
+                    let quoted = serde_json::to_string(&token).unwrap_or_default();
+                    path.push_str(&format!("[{quoted}]"));
+                }
+                current = current.and_then(|value| value.get(&token));
+            }
+        }
+    }
+    path
+}
+
+/// Text a scalar is searched by: strings without quotes, the rest as JSON.
+pub fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        Value::Null => Some("null".to_owned()),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+/// Collapsed stand-in for a container, such as `3 keys`.
+pub fn summary(value: &Value) -> Option<String> {
+    let (count, one, many) = match value {
+        Value::Array(items) => (items.len(), "item", "items"),
+        Value::Object(fields) => (fields.len(), "key", "keys"),
+        _ => return None,
+    };
+    Some(format!("{count} {}", if count == 1 { one } else { many }))
+}
+
+/// Text "copy value" writes: strings as they are, anything else as
+/// pretty-printed JSON.
+pub fn copy_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        _ => serde_json::to_string_pretty(value).unwrap_or_default(),
+    }
+}
+
+fn children(value: &Value) -> Box<dyn Iterator<Item = (JsonKey, &Value)> + '_> {
+    match value {
+        Value::Array(items) => Box::new(
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| (JsonKey::Index(index), item)),
+        ),
+        Value::Object(fields) => Box::new(
+            fields
+                .iter()
+                .map(|(name, field)| (JsonKey::Field(name.clone()), field)),
+        ),
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+fn child_count(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => items.len(),
+        Value::Object(fields) => fields.len(),
+        _ => 0,
+    }
+}
+
+/// Rows for the expanded part of `root`. Only expanded containers are
+/// walked, and of those only the first `shown(pointer)` children.
+pub fn visible_rows<'a>(
+    root: &'a Value,
+    expanded: impl Fn(&str, usize) -> bool,
+    shown: impl Fn(&str) -> usize,
+) -> Vec<JsonRow<'a>> {
+    let mut rows = Vec::new();
+    push_rows(&mut rows, root, String::new(), None, 0, &expanded, &shown);
+    rows
+}
+
+fn push_rows<'a>(
+    rows: &mut Vec<JsonRow<'a>>,
+    value: &'a Value,
+    pointer: String,
+    key: Option<JsonKey>,
+    depth: usize,
+    expanded: &impl Fn(&str, usize) -> bool,
+    shown: &impl Fn(&str) -> usize,
+) {
+    let count = child_count(value);
+    let open = count > 0 && expanded(&pointer, depth);
+    rows.push(JsonRow {
+        depth,
+        pointer: pointer.clone(),
+        key,
+        kind: JsonRowKind::Value(value),
+        expanded: open,
+    });
+    if !open {
+        return;
+    }
+    let limit = shown(&pointer).min(count);
+    for (key, child) in children(value).take(limit) {
+        let child_pointer = child_pointer(&pointer, &key);
+        push_rows(
+            rows,
+            child,
+            child_pointer,
+            Some(key),
+            depth + 1,
+            expanded,
+            shown,
+        );
+    }
+    if limit < count {
+        rows.push(JsonRow {
+            depth: depth + 1,
+            pointer,
+            key: None,
+            kind: JsonRowKind::More {
+                remaining: count - limit,
+            },
+            expanded: false,
+        });
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JsonSearch {
+    /// Pointers of matching values, in document order.
+    pub matches: Vec<String>,
+    /// Containers to open so every match shows, with how many of their
+    /// children that takes.
+    pub reveal: HashMap<String, usize>,
+}
+
+/// Values whose key or scalar text contains `query`, ignoring ASCII case.
+pub fn search(root: &Value, query: &str) -> JsonSearch {
+    let mut result = JsonSearch::default();
+    if !query.is_empty() {
+        let mut ancestors = Vec::new();
+        search_value(
+            &mut result,
+            root,
+            String::new(),
+            None,
+            query,
+            &mut ancestors,
+        );
+    }
+    result
+}
+
+fn search_value(
+    result: &mut JsonSearch,
+    value: &Value,
+    pointer: String,
+    key: Option<&JsonKey>,
+    query: &str,
+    ancestors: &mut Vec<(String, usize)>,
+) {
+    if result.matches.len() >= MAX_MATCHES {
+        return;
+    }
+    let key_matches =
+        matches!(key, Some(JsonKey::Field(name)) if !find_ranges(name, query, false).is_empty());
+    let value_matches =
+        scalar_text(value).is_some_and(|text| !find_ranges(&text, query, false).is_empty());
+    if key_matches || value_matches {
+        for (container, index) in ancestors.iter() {
+            let needed = result.reveal.entry(container.clone()).or_default();
+            *needed = (*needed).max(index + 1);
+        }
+        result.matches.push(pointer.clone());
+    }
+    for (index, (key, child)) in children(value).enumerate() {
+        ancestors.push((pointer.clone(), index));
+        let child_pointer = child_pointer(&pointer, &key);
+        search_value(result, child, child_pointer, Some(&key), query, ancestors);
+        ancestors.pop();
+    }
+}
+
+/// Containers the user opened and closed, overriding the default depth
+/// and search.
+pub fn toggles(id: &str) -> (HashSet<String>, HashSet<String>) {
+    let opened = control::list_state(id, "opened", None, Vec::new());
+    let closed = control::list_state(id, "closed", None, Vec::new());
+    (opened.into_iter().collect(), closed.into_iter().collect())
+}
+
+pub fn set_toggled(id: &str, pointer: &str, open: bool) {
+    let (add, remove) = if open {
+        ("opened", "closed")
+    } else {
+        ("closed", "opened")
+    };
+    let mut removed = control::list_state(id, remove, None, Vec::new());
+    removed.retain(|entry| entry != pointer);
+    control::set_list_state(id, remove, removed);
+    let mut added = control::list_state(id, add, None, Vec::new());
+    if !added.iter().any(|entry| entry == pointer) {
+        added.push(pointer.to_owned());
+    }
+    control::set_list_state(id, add, added);
+}
+
+/// Children of the container at `pointer` shown so far.
+pub fn shown(id: &str, pointer: &str, page_size: usize) -> usize {
+    control::usize_state(id, &format!("shown:{pointer}"), None, page_size)
+}
+
+pub fn show_more(id: &str, pointer: &str, page_size: usize) {
+    let next = shown(id, pointer, page_size) + page_size;
+    control::set_usize_state(id, &format!("shown:{pointer}"), next);
+}
+
+pub fn scroll_y(id: &str) -> f32 {
+    control::f32_state(id, "scroll-y", None, 0.0)
+}
+
+pub fn set_scroll_y(id: &str, value: f32) {
+    control::set_f32_state(id, "scroll-y", value);
+}
+
+/// Records the scroll offset. Returns whether the first visible row moved.
+pub fn store_scroll_y(id: &str, next_y: f32, row_height: f32) -> bool {
+    let previous = scroll_y(id);
+    if (next_y - previous).abs() <= 0.5 {
+        return false;
+    }
+    set_scroll_y(id, next_y);
+    let row_height = row_height.max(1.0);
+    (previous / row_height).floor() != (next_y / row_height).floor()
+}
+
+pub fn viewport(id: &str, fallback: f32) -> f32 {
+    control::optional_f32_state(id, "viewport", None, None).unwrap_or(fallback)
+}
+
+pub fn store_viewport(id: &str, height: f32) -> bool {
+    let previous = control::optional_f32_state(id, "viewport", None, None);
+    if previous.is_some_and(|previous| (previous - height).abs() <= 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, "viewport", Some(height));
+    true
+}
+
+/// Whether `query` differs from the one searched last, remembering it.
+pub fn query_changed(id: &str, query: &str) -> bool {
+    if control::text_state(id, "query", None, String::new()) == query {
+        return false;
+    }
+    control::set_text_state(id, "query", query.to_owned());
+    true
+}
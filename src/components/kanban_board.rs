@@ -0,0 +1,489 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, AppContext, EmptyView, FontWeight, InteractiveElement, IntoElement, MouseButton,
+    ParentElement, RenderOnce, ScrollHandle, SharedString, StatefulInteractiveElement, Styled,
+    Window, canvas, div, point, px,
+};
+
+use crate::id::ComponentId;
+
+pub use super::kanban_board_state::KanbanMove;
+
+use super::Stack;
+use super::kanban_board_state;
+use super::utils::resolve_hsla;
+
+type MoveHandler = Rc<dyn Fn(&KanbanMove, &mut Window, &mut gpui::App)>;
+type CardClickHandler = Rc<dyn Fn(SharedString, SharedString, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KanbanColumn {
+    pub id: SharedString,
+    pub title: SharedString,
+    /// Work-in-progress limit; the count badge turns red past it.
+    pub limit: Option<usize>,
+}
+
+impl KanbanColumn {
+    pub fn new(id: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            limit: None,
+        }
+    }
+
+    pub fn limit(mut self, value: usize) -> Self {
+        self.limit = Some(value);
+        self
+    }
+}
+
+/// Supplies the columns and cards of a [`KanbanBoard`]. Cards are asked for
+/// by index so only the ones scrolled into view are rendered.
+///
+/// The board never reorders the data itself: apply the moves reported by
+/// `on_card_move`/`on_card_reorder` to the source and render again.
+pub trait KanbanSource {
+    fn columns(&self) -> Vec<KanbanColumn>;
+    fn card_count(&self, column: &SharedString) -> usize;
+    /// Stable key of a card, used for drag state and element ids.
+    fn card_id(&self, column: &SharedString, index: usize) -> SharedString;
+    fn render_card(&self, column: &SharedString, index: usize) -> AnyElement;
+}
+
+#[derive(Clone)]
+struct KanbanCardDrag {
+    board_id: String,
+    card: SharedString,
+    column: SharedString,
+    index: usize,
+}
+
+#[derive(Clone)]
+struct DropContext {
+    board_id: String,
+    on_card_move: Option<MoveHandler>,
+    on_card_reorder: Option<MoveHandler>,
+}
+
+impl DropContext {
+    fn drop(
+        &self,
+        drag: &KanbanCardDrag,
+        column: &SharedString,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        let target = kanban_board_state::drop_target(&self.board_id);
+        kanban_board_state::finish_drag(&self.board_id);
+        window.refresh();
+        let Some((_, slot)) = target.filter(|(target, _)| target == column.as_ref()) else {
+            return;
+        };
+        let Some(change) = kanban_board_state::resolve_move(
+            drag.card.clone(),
+            drag.column.clone(),
+            drag.index,
+            column.clone(),
+            slot,
+        ) else {
+            return;
+        };
+        let handler = if change.is_reorder() {
+            self.on_card_reorder.as_ref()
+        } else {
+            self.on_card_move.as_ref()
+        };
+        if let Some(handler) = handler {
+            (handler)(&change, window, cx);
+        }
+    }
+}
+
+/// Columns of cards that can be dragged within and between columns. Each
+/// column scrolls on its own and only renders the cards in view, so cards
+/// share a fixed height.
+#[derive(IntoElement)]
+pub struct KanbanBoard {
+    pub(crate) id: ComponentId,
+    source: Option<Rc<dyn KanbanSource>>,
+    card_height: Option<f32>,
+    list_height: Option<f32>,
+    overscan: usize,
+    draggable: bool,
+    empty_label: SharedString,
+    pub(crate) theme: crate::theme::LocalTheme,
+    on_card_click: Option<CardClickHandler>,
+    on_card_move: Option<MoveHandler>,
+    on_card_reorder: Option<MoveHandler>,
+}
+
+impl KanbanBoard {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            source: None,
+            card_height: None,
+            list_height: None,
+            overscan: 4,
+            draggable: true,
+            empty_label: SharedString::from("No cards"),
+            theme: crate::theme::LocalTheme::default(),
+            on_card_click: None,
+            on_card_move: None,
+            on_card_reorder: None,
+        }
+    }
+
+    pub fn source(mut self, value: impl KanbanSource + 'static) -> Self {
+        self.source = Some(Rc::new(value));
+        self
+    }
+
+    /// Height every card is laid out at, gap excluded.
+    pub fn card_height(mut self, value: f32) -> Self {
+        self.card_height = Some(value.max(1.0));
+        self
+    }
+
+    /// Height of each column's scrolling card list.
+    pub fn list_height(mut self, value: f32) -> Self {
+        self.list_height = Some(value.max(1.0));
+        self
+    }
+
+    /// Cards rendered above and below the visible ones.
+    pub fn overscan(mut self, value: usize) -> Self {
+        self.overscan = value;
+        self
+    }
+
+    pub fn draggable(mut self, value: bool) -> Self {
+        self.draggable = value;
+        self
+    }
+
+    pub fn empty_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.empty_label = value.into();
+        self
+    }
+
+    /// Called with the card and column ids.
+    pub fn on_card_click(
+        mut self,
+        handler: impl Fn(SharedString, SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_card_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called when a card is dropped into another column.
+    pub fn on_card_move(
+        mut self,
+        handler: impl Fn(&KanbanMove, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_card_move = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called when a card is dropped at a new position in its own column.
+    pub fn on_card_reorder(
+        mut self,
+        handler: impl Fn(&KanbanMove, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_card_reorder = Some(Rc::new(handler));
+        self
+    }
+
+    fn extents(&self) -> (f32, f32) {
+        let tokens = &self.theme.components.kanban_board;
+        let card = self.card_height.unwrap_or(f32::from(tokens.card_height));
+        (card, card + f32::from(tokens.card_gap))
+    }
+
+    fn drop_context(&self) -> DropContext {
+        DropContext {
+            board_id: self.id.to_string(),
+            on_card_move: self.on_card_move.clone(),
+            on_card_reorder: self.on_card_reorder.clone(),
+        }
+    }
+
+    fn render_header(&self, column: &KanbanColumn, count: usize) -> AnyElement {
+        let tokens = &self.theme.components.kanban_board;
+        let over_limit = column.limit.is_some_and(|limit| count > limit);
+        let (count_bg, count_fg) = if over_limit {
+            (tokens.count_over_limit_bg, tokens.count_over_limit_fg)
+        } else {
+            (tokens.count_bg, tokens.count_fg)
+        };
+        let label = match column.limit {
+            Some(limit) => format!("{count}/{limit}"),
+            None => count.to_string(),
+        };
+        Stack::horizontal()
+            .w_full()
+            .gap(tokens.header_gap)
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .truncate()
+                    .text_size(tokens.header_size)
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(resolve_hsla(&self.theme, tokens.header_fg))
+                    .child(column.title.clone()),
+            )
+            .child(
+                div()
+                    .px(px(6.0))
+                    .rounded_full()
+                    .bg(resolve_hsla(&self.theme, count_bg))
+                    .text_color(resolve_hsla(&self.theme, count_fg))
+                    .text_size(tokens.count_size)
+                    .child(label),
+            )
+            .into_any_element()
+    }
+
+    fn render_card(
+        &self,
+        source: &Rc<dyn KanbanSource>,
+        column: &SharedString,
+        index: usize,
+        dragging: Option<&str>,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.kanban_board;
+        let (card_height, extent) = self.extents();
+        let card = source.card_id(column, index);
+        let mut wrapper = div()
+            .id(self.id.slot_index("card", card.to_string()))
+            .flex_none()
+            .w_full()
+            .h(px(extent))
+            .pb(tokens.card_gap)
+            .child(
+                div()
+                    .w_full()
+                    .h(px(card_height))
+                    .overflow_hidden()
+                    .p(tokens.card_padding)
+                    .rounded(tokens.column_radius)
+                    .border_1()
+                    .border_color(resolve_hsla(&self.theme, tokens.card_border))
+                    .bg(resolve_hsla(&self.theme, tokens.card_bg))
+                    .child(source.render_card(column, index)),
+            );
+        if dragging == Some(card.as_ref()) {
+            wrapper = wrapper.opacity(0.4);
+        }
+        if let Some(handler) = self.on_card_click.clone() {
+            let (card, column) = (card.clone(), column.clone());
+            wrapper = wrapper.cursor_pointer().on_click(move |_, window, cx| {
+                handler(card.clone(), column.clone(), window, cx);
+            });
+        }
+        if !self.draggable {
+            return wrapper.into_any_element();
+        }
+        let drag = KanbanCardDrag {
+            board_id: self.id.to_string(),
+            card,
+            column: column.clone(),
+            index,
+        };
+        wrapper
+            .on_drag(drag, |drag, _, _, cx| {
+                kanban_board_state::set_dragging(&drag.board_id, Some(drag.card.to_string()));
+                cx.new(|_| EmptyView)
+            })
+            .into_any_element()
+    }
+
+    fn render_placeholder(&self) -> AnyElement {
+        let tokens = &self.theme.components.kanban_board;
+        let (card_height, extent) = self.extents();
+        div()
+            .flex_none()
+            .w_full()
+            .h(px(extent))
+            .pb(tokens.card_gap)
+            .child(
+                div()
+                    .w_full()
+                    .h(px(card_height))
+                    .rounded(tokens.column_radius)
+                    .border_2()
+                    .border_dashed()
+                    .border_color(resolve_hsla(&self.theme, tokens.placeholder_border))
+                    .bg(resolve_hsla(&self.theme, tokens.placeholder_bg)),
+            )
+            .into_any_element()
+    }
+
+    fn render_cards(
+        &self,
+        source: &Rc<dyn KanbanSource>,
+        column: &SharedString,
+        window_range: Range<usize>,
+        placeholder: Option<usize>,
+    ) -> Vec<AnyElement> {
+        let (_, extent) = self.extents();
+        let count = source.card_count(column);
+        let dragging = kanban_board_state::dragging(&self.id);
+        let before = placeholder.is_some_and(|slot| slot < window_range.start);
+        let after = placeholder.is_some_and(|slot| slot > window_range.end);
+        let top = window_range.start as f32 * extent + if before { extent } else { 0.0 };
+        let bottom = (count - window_range.end) as f32 * extent + if after { extent } else { 0.0 };
+
+        let mut cards = vec![div().flex_none().h(px(top)).into_any_element()];
+        for index in window_range.clone() {
+            if placeholder == Some(index) {
+                cards.push(self.render_placeholder());
+            }
+            cards.push(self.render_card(source, column, index, dragging.as_deref()));
+        }
+        if placeholder == Some(window_range.end) {
+            cards.push(self.render_placeholder());
+        }
+        cards.push(div().flex_none().h(px(bottom)).into_any_element());
+        cards
+    }
+
+    fn render_column(&self, source: &Rc<dyn KanbanSource>, column: &KanbanColumn) -> AnyElement {
+        let tokens = &self.theme.components.kanban_board;
+        let (_, extent) = self.extents();
+        let list_height = self.list_height.unwrap_or(f32::from(tokens.list_height));
+        let count = source.card_count(&column.id);
+        let board_id = self.id.to_string();
+        let scroll_y = kanban_board_state::scroll_y(&board_id, &column.id);
+        let placeholder = kanban_board_state::drop_target(&board_id)
+            .filter(|(target, _)| *target == column.id.as_ref())
+            .map(|(_, slot)| slot);
+        let window_range =
+            kanban_board_state::visible_window(count, extent, list_height, scroll_y, self.overscan);
+
+        let scroll_handle = ScrollHandle::new();
+        scroll_handle.set_offset(point(px(0.0), px(-scroll_y)));
+        let monitor = {
+            let handle = scroll_handle.clone();
+            let (board_id, column_id) = (board_id.clone(), column.id.clone());
+            canvas(
+                move |_bounds, window, _cx| {
+                    let next_y = -f32::from(handle.offset().y);
+                    if kanban_board_state::store_scroll_y(&board_id, &column_id, next_y, extent) {
+                        window.refresh();
+                    }
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full()
+        };
+
+        let mut list = div()
+            .id(self.id.slot_index("list", column.id.to_string()))
+            .relative()
+            .flex()
+            .flex_col()
+            .w_full()
+            .h(px(list_height))
+            .overflow_y_scroll()
+            .track_scroll(&scroll_handle)
+            .children(self.render_cards(source, &column.id, window_range, placeholder))
+            .child(monitor);
+        if count == 0 && placeholder.is_none() {
+            list = list.child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .w_full()
+                    .text_size(tokens.empty_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.empty_fg))
+                    .child(self.empty_label.clone()),
+            );
+        }
+        if self.draggable {
+            let (move_board, move_column) = (board_id.clone(), column.id.clone());
+            let drop_context = self.drop_context();
+            let drop_column = column.id.clone();
+            list = list
+                .on_drag_move::<KanbanCardDrag>(move |event, window, cx| {
+                    let position = event.event.position;
+                    if event.drag(cx).board_id != move_board || !event.bounds.contains(&position) {
+                        return;
+                    }
+                    let offset = f32::from(position.y - event.bounds.top())
+                        + kanban_board_state::scroll_y(&move_board, &move_column);
+                    let current = kanban_board_state::drop_target(&move_board)
+                        .filter(|(target, _)| *target == move_column.as_ref())
+                        .map(|(_, slot)| slot);
+                    let slot = kanban_board_state::drop_slot(offset, extent, count, current);
+                    if kanban_board_state::set_drop_target(
+                        &move_board,
+                        Some((move_column.as_ref(), slot)),
+                    ) {
+                        window.refresh();
+                    }
+                })
+                .on_drop::<KanbanCardDrag>(move |drag, window, cx| {
+                    if drag.board_id == drop_context.board_id {
+                        drop_context.drop(drag, &drop_column, window, cx);
+                    }
+                });
+        }
+
+        div()
+            .id(self.id.slot_index("column", column.id.to_string()))
+            .flex()
+            .flex_col()
+            .flex_none()
+            .w(tokens.column_width)
+            .gap(tokens.header_gap)
+            .p(tokens.column_padding)
+            .rounded(tokens.column_radius)
+            .border_1()
+            .border_color(resolve_hsla(&self.theme, tokens.column_border))
+            .bg(resolve_hsla(&self.theme, tokens.column_bg))
+            .child(self.render_header(column, count))
+            .child(list)
+            .into_any_element()
+    }
+}
+
+impl RenderOnce for KanbanBoard {
+    fn render(mut self, _window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let tokens = &self.theme.components.kanban_board;
+        let columns = self.source.as_ref().map(|source| {
+            source
+                .columns()
+                .iter()
+                .map(|column| self.render_column(source, column))
+                .collect::<Vec<_>>()
+        });
+
+        let board_id = self.id.to_string();
+        let end_drag = move |_: &gpui::MouseUpEvent, window: &mut Window, _: &mut gpui::App| {
+            if kanban_board_state::dragging(&board_id).is_some() {
+                kanban_board_state::finish_drag(&board_id);
+                window.refresh();
+            }
+        };
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_start()
+            .w_full()
+            .gap(tokens.column_gap)
+            .overflow_x_scroll()
+            .children(columns.unwrap_or_default())
+            .on_mouse_up(MouseButton::Left, end_drag.clone())
+            .on_mouse_up_out(MouseButton::Left, end_drag)
+    }
+}
@@ -0,0 +1,138 @@
+use std::ops::Range;
+
+use gpui::SharedString;
+
+use super::control;
+
+/// A card leaving `from_column` at `from_index` and landing in `to_column`
+/// at `to_index`. Indices are positions in each column's list; `to_index`
+/// already accounts for the card having been removed from its old place.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KanbanMove {
+    pub card: SharedString,
+    pub from_column: SharedString,
+    pub from_index: usize,
+    pub to_column: SharedString,
+    pub to_index: usize,
+}
+
+impl KanbanMove {
+    /// Whether the card stays in its column and only changes position.
+    pub fn is_reorder(&self) -> bool {
+        self.from_column == self.to_column
+    }
+}
+
+/// Card indices to render for a column scrolled to `scroll_y`, padded by
+/// `overscan` cards on each side.
+pub fn visible_window(
+    count: usize,
+    extent: f32,
+    viewport: f32,
+    scroll_y: f32,
+    overscan: usize,
+) -> Range<usize> {
+    let extent = extent.max(1.0);
+    let first = (scroll_y.max(0.0) / extent).floor() as usize;
+    let shown = (viewport.max(0.0) / extent).ceil() as usize + 1;
+    let start = first.saturating_sub(overscan).min(count);
+    let end = (first + shown + overscan).min(count);
+    start..end
+}
+
+/// Slot a card dropped `offset_y` px below the top of a column's content
+/// lands in, between `0` and `count`. `current` is the slot the placeholder
+/// already occupies; the pointer is mapped back past it so the placeholder
+/// does not flicker between two slots.
+pub fn drop_slot(offset_y: f32, extent: f32, count: usize, current: Option<usize>) -> usize {
+    let extent = extent.max(1.0);
+    let mut offset = offset_y.max(0.0);
+    if let Some(current) = current {
+        let top = current as f32 * extent;
+        if (top..top + extent).contains(&offset) {
+            return current.min(count);
+        }
+        if offset >= top + extent {
+            offset -= extent;
+        }
+    }
+    ((offset / extent).round() as usize).min(count)
+}
+
+/// Turns a drop on `slot` into a move, or `None` when the card would end up
+/// where it started.
+pub fn resolve_move(
+    card: SharedString,
+    from_column: SharedString,
+    from_index: usize,
+    to_column: SharedString,
+    slot: usize,
+) -> Option<KanbanMove> {
+    let same_column = from_column == to_column;
+    let to_index = if same_column && slot > from_index {
+        slot - 1
+    } else {
+        slot
+    };
+    if same_column && to_index == from_index {
+        return None;
+    }
+    Some(KanbanMove {
+        card,
+        from_column,
+        from_index,
+        to_column,
+        to_index,
+    })
+}
+
+pub fn scroll_y(id: &str, column: &str) -> f32 {
+    control::f32_state(id, &format!("scroll-y:{column}"), None, 0.0)
+}
+
+/// Records a column's scroll offset. Returns whether the rendered window
+/// moved and the board needs another frame.
+pub fn store_scroll_y(id: &str, column: &str, next_y: f32, extent: f32) -> bool {
+    let previous = scroll_y(id, column);
+    if (next_y - previous).abs() <= 0.5 {
+        return false;
+    }
+    control::set_f32_state(id, &format!("scroll-y:{column}"), next_y);
+    let extent = extent.max(1.0);
+    (previous / extent).floor() != (next_y / extent).floor()
+}
+
+pub fn drop_target(id: &str) -> Option<(String, usize)> {
+    let column = control::optional_text_state(id, "drop-column", None, None)?;
+    let slot = control::optional_usize_state(id, "drop-slot", None, None)?;
+    Some((column, slot))
+}
+
+/// Moves the placeholder. Returns whether it changed.
+pub fn set_drop_target(id: &str, target: Option<(&str, usize)>) -> bool {
+    let current = drop_target(id);
+    if current
+        .as_ref()
+        .map(|(column, slot)| (column.as_str(), *slot))
+        == target
+    {
+        return false;
+    }
+    control::set_optional_text_state(id, "drop-column", target.map(|(column, _)| column.into()));
+    control::set_optional_usize_state(id, "drop-slot", target.map(|(_, slot)| slot));
+    true
+}
+
+pub fn dragging(id: &str) -> Option<String> {
+    control::optional_text_state(id, "dragging", None, None)
+}
+
+pub fn set_dragging(id: &str, card: Option<String>) {
+    control::set_optional_text_state(id, "dragging", card);
+}
+
+/// Clears the drag bookkeeping once a drag ends, dropped or not.
+pub fn finish_drag(id: &str) {
+    set_dragging(id, None);
+    set_drop_target(id, None);
+}
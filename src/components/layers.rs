@@ -2,16 +2,18 @@ use super::transition::TransitionExt;
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
 use gpui::{
-    AnyElement, ClickEvent, IntoElement, ParentElement, RenderOnce, Styled, Window, div, px,
+    Animation, AnimationExt, AnyElement, ClickEvent, IntoElement, ParentElement, RenderOnce,
+    Styled, Window, div, px, relative,
 };
 use std::time::Duration;
 
-use crate::contracts::Varianted;
-use crate::feedback::{ToastEntry, ToastKind, ToastManager, ToastPosition};
+use crate::contracts::{Sized, Varianted};
+use crate::feedback::{ToastEntry, ToastId, ToastKind, ToastManager, ToastPosition};
 use crate::icon::{IconRegistry, IconSource};
 use crate::id::ComponentId;
 use crate::motion::{MotionConfig, MotionTransition, TransitionPreset};
-use crate::overlay::{ManagedModal, ModalCloseReason, ModalKind, ModalManager};
+use crate::overlay::{ManagedModal, ModalCloseReason, ModalId, ModalKind, ModalManager};
+use crate::style::{Size, Variant};
 
 use super::Stack;
 use super::button::Button;
@@ -24,6 +26,7 @@ use super::utils::{deepened_surface_border, resolve_hsla};
 pub struct ToastLayer {
     pub(crate) id: ComponentId,
     manager: ToastManager,
+    regions: Vec<ToastPosition>,
     icons: IconRegistry,
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
@@ -35,6 +38,7 @@ impl ToastLayer {
         Self {
             id: ComponentId::default(),
             manager,
+            regions: ToastPosition::ALL.to_vec(),
             icons: IconRegistry::new(),
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::new().enter(
@@ -56,6 +60,11 @@ impl ToastLayer {
         self
     }
 
+    pub fn regions(mut self, regions: impl IntoIterator<Item = ToastPosition>) -> Self {
+        self.regions = regions.into_iter().collect();
+        self
+    }
+
     fn toast_colors(&self, entry: &ToastEntry) -> (gpui::Hsla, gpui::Hsla) {
         let tokens = &self.theme.components.toast;
         match entry.kind {
@@ -92,12 +101,77 @@ impl ToastLayer {
         }
     }
 
+    fn render_toast_actions(&self, id: ToastId, entry: &ToastEntry) -> Option<AnyElement> {
+        if entry.actions.is_empty() {
+            return None;
+        }
+        let tokens = &self.theme.components.toast;
+        let buttons: Vec<_> = entry
+            .actions
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, action)| {
+                let manager = self.manager.clone();
+                let key = format!("{}-{index}", id.0);
+                self.id
+                    .ctx()
+                    .child_index("toast-action", key, Button::new())
+                    .label(action.label.clone())
+                    .with_variant(Variant::Subtle)
+                    .with_size(Size::Xs)
+                    .on_click(move |_, window, cx| {
+                        action.invoke(id, window, cx);
+                        if action.dismiss {
+                            manager.dismiss(id);
+                        }
+                        window.refresh();
+                    })
+            })
+            .collect();
+        let row = Stack::horizontal().gap(tokens.action_gap);
+        Some(row.children(buttons).into_any_element())
+    }
+
+    fn render_countdown(&self, id: ToastId, fg: gpui::Hsla) -> Option<AnyElement> {
+        let remaining = self.manager.remaining_ms(id)?;
+        let fraction = self.manager.countdown(id)?;
+        let version = self.manager.version_of(id).unwrap_or_default();
+        let bar = div().h_full().w(relative(fraction)).bg(fg.opacity(0.48));
+        let bar = if self.manager.is_paused(id) || remaining == 0 {
+            bar.into_any_element()
+        } else {
+            let animation = Animation::new(Duration::from_millis(u64::from(remaining)))
+                .with_easing(gpui::linear);
+            let key = format!("{}-{version}", id.0);
+            bar.with_animation(
+                self.id.slot_index("toast-countdown", key),
+                animation,
+                move |this, delta| this.w(relative(fraction * (1.0 - delta))),
+            )
+            .into_any_element()
+        };
+        let track = div()
+            .w_full()
+            .h(self.theme.components.toast.progress_height)
+            .mt(self.theme.components.toast.content_gap)
+            .rounded_full()
+            .overflow_hidden()
+            .bg(fg.opacity(0.12));
+        Some(track.child(bar).into_any_element())
+    }
+
     fn render_toast_card(&self, entry: ToastEntry, window: &gpui::Window) -> AnyElement {
         let (bg, fg) = self.toast_colors(&entry);
         let tokens = &self.theme.components.toast;
         let manager = self.manager.clone();
         let toast_id = entry.id;
         let toast_key = entry.id.map(|value| value.0).unwrap_or_default();
+        let actions = toast_id.and_then(|id| self.render_toast_actions(id, &entry));
+        let countdown = toast_id
+            .filter(|_| entry.show_countdown)
+            .and_then(|id| self.render_countdown(id, fg));
+        let pausable = entry.pause_on_hover && entry.auto_close_ms.is_some();
         let title = entry.title;
         let message = entry.message;
         let icon = entry.icon.unwrap_or_else(|| Self::default_icon(entry.kind));
@@ -132,14 +206,15 @@ impl ToastLayer {
                     .color(fg)
                     .registry(icons.clone()),
             )
-            .on_click(
+            .on_click({
+                let manager = manager.clone();
                 move |_: &ClickEvent, window: &mut Window, _cx: &mut gpui::App| {
                     if let Some(id) = toast_id {
                         manager.dismiss(id);
                         window.refresh();
                     }
-                },
-            );
+                }
+            });
 
         let icon_badge = div()
             .id(self.id.slot_index("toast-icon", (toast_key).to_string()))
@@ -159,7 +234,28 @@ impl ToastLayer {
                     .registry(icons),
             );
 
-        div()
+        let title = div()
+            .w_full()
+            .text_size(tokens.title_size)
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .truncate()
+            .child(title);
+        let message = div()
+            .w_full()
+            .text_size(tokens.body_size)
+            .whitespace_normal()
+            .line_clamp(3)
+            .child(message);
+        let content = Stack::vertical()
+            .flex_1()
+            .overflow_hidden()
+            .gap(tokens.content_gap);
+        let content = content.child(title).child(message).children(actions);
+        let close = closable.then_some(close_button);
+        let row = div().flex().flex_row().items_start().gap(tokens.row_gap);
+        let row = row.child(icon_badge).child(content).children(close);
+
+        let mut card = div()
             .id(self.id.slot_index("toast", (toast_key).to_string()))
             .w(tokens.card_width)
             .max_w_full()
@@ -168,38 +264,21 @@ impl ToastLayer {
             .border(super::utils::quantized_stroke_px(window, 1.0))
             .border_color(deepened_surface_border(bg))
             .bg(bg)
-            .text_color(fg)
-            .child(
-                div()
-                    .flex()
-                    .flex_row()
-                    .items_start()
-                    .gap(tokens.row_gap)
-                    .child(icon_badge)
-                    .child(
-                        Stack::vertical()
-                            .flex_1()
-                            .overflow_hidden()
-                            .gap(tokens.content_gap)
-                            .child(
-                                div()
-                                    .w_full()
-                                    .text_size(tokens.title_size)
-                                    .font_weight(gpui::FontWeight::SEMIBOLD)
-                                    .truncate()
-                                    .child(title),
-                            )
-                            .child(
-                                div()
-                                    .w_full()
-                                    .text_size(tokens.body_size)
-                                    .whitespace_normal()
-                                    .line_clamp(3)
-                                    .child(message),
-                            ),
-                    )
-                    .children(closable.then_some(close_button)),
-            )
+            .text_color(fg);
+        if let Some(id) = toast_id.filter(|_| pausable) {
+            card = card.on_hover(move |hovered: &bool, window, _| {
+                let changed = if *hovered {
+                    manager.pause(id)
+                } else {
+                    manager.resume(id)
+                };
+                if changed {
+                    window.refresh();
+                }
+            });
+        }
+        card.child(row)
+            .children(countdown)
             .with_enter_transition(
                 self.id.slot_index("toast-enter", toast_key.to_string()),
                 self.motion,
@@ -267,15 +346,18 @@ impl ToastLayer {
         let Some(id) = entry.id else {
             return;
         };
-        let Some(delay_ms) = entry.auto_close_ms else {
+        if entry.auto_close_ms.is_none() {
             return;
-        };
+        }
         let Some(version) = self.manager.version_of(id) else {
             return;
         };
         if !self.manager.mark_auto_close_scheduled(id, version) {
             return;
         }
+        let Some(delay_ms) = self.manager.remaining_ms(id) else {
+            return;
+        };
 
         let manager = self.manager.clone();
         let window_handle = window.window_handle();
@@ -301,15 +383,6 @@ impl RenderOnce for ToastLayer {
         let toast_tokens = &self.theme.components.toast;
         let top_offset = f32::from(self.theme.components.title_bar.height)
             + f32::from(toast_tokens.top_offset_extra);
-        let positions = [
-            ToastPosition::TopLeft,
-            ToastPosition::TopCenter,
-            ToastPosition::TopRight,
-            ToastPosition::BottomLeft,
-            ToastPosition::BottomCenter,
-            ToastPosition::BottomRight,
-        ];
-
         let mut root = div()
             .id(self.id.clone())
             .size_full()
@@ -317,7 +390,7 @@ impl RenderOnce for ToastLayer {
             .top_0()
             .left_0();
 
-        for position in positions {
+        for position in self.regions.clone() {
             let toasts = self.manager.list(position);
             if toasts.is_empty() {
                 continue;
@@ -349,6 +422,7 @@ pub struct ModalLayer {
     pub(crate) id: ComponentId,
     manager: ModalManager,
     icons: IconRegistry,
+    nested_overlay_opacity: f32,
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
 }
@@ -360,6 +434,7 @@ impl ModalLayer {
             id: ComponentId::default(),
             manager,
             icons: IconRegistry::new(),
+            nested_overlay_opacity: 0.5,
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::new().enter(
                 MotionTransition::new()
@@ -380,6 +455,13 @@ impl ModalLayer {
         self
     }
 
+    /// Overlay opacity for modals stacked above the first one, so nested
+    /// dialogs dim the modal underneath without darkening the app twice.
+    pub fn nested_overlay_opacity(mut self, value: f32) -> Self {
+        self.nested_overlay_opacity = value.clamp(0.0, 1.0);
+        self
+    }
+
     fn modal_kind_icon(&self, kind: ModalKind) -> Option<IconSource> {
         match kind {
             ModalKind::Custom => None,
@@ -403,8 +485,22 @@ impl ModalLayer {
         }
     }
 
-    fn render_modal(&self, managed: ManagedModal, window: &gpui::Window) -> AnyElement {
+    fn focus_scope(&self, id: ModalId) -> String {
+        format!("{}:{}", self.id, id.0)
+    }
+
+    fn render_modal(
+        &self,
+        managed: ManagedModal,
+        depth: usize,
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) -> AnyElement {
         let id = managed.id();
+        let key = id.0.to_string();
+        let focus = crate::provider::CalmProvider::focus(cx);
+        let focus_scope = self.focus_scope(id);
+        let focus_root = focus.trap(focus_scope.clone(), window, cx);
         let modal = managed.modal_arc();
         let entry = modal.as_ref();
         let modal_tokens = &self.theme.components.modal;
@@ -422,15 +518,20 @@ impl ModalLayer {
         let icons = self.icons.clone();
 
         let close_on_click_outside = entry.close_on_click_outside_enabled();
+        let overlay_opacity = if depth == 0 {
+            1.0
+        } else {
+            self.nested_overlay_opacity
+        };
         let overlay = self
             .id
             .ctx()
-            .child("overlay", Overlay::new())
+            .child_index("overlay", key.clone(), Overlay::new())
             .coverage(OverlayCoverage::Window)
             .material_mode(OverlayMaterialMode::TintOnly)
             .frosted(false)
             .color(self.theme.components.modal.overlay_bg)
-            .opacity(1.0)
+            .opacity(overlay_opacity)
             .readability_boost(0.84)
             .on_click(
                 move |_: &ClickEvent, window: &mut Window, _cx: &mut gpui::App| {
@@ -465,7 +566,7 @@ impl ModalLayer {
         let close_action = if entry.close_button_enabled() {
             Some(
                 div()
-                    .id(self.id.slot_index("modal-close", (id.0).to_string()))
+                    .id(self.id.slot_index("modal-close", key.clone()))
                     .w(modal_tokens.close_size)
                     .h(modal_tokens.close_size)
                     .flex()
@@ -475,7 +576,7 @@ impl ModalLayer {
                     .child(
                         self.id
                             .ctx()
-                            .child_index("modal-close-icon", (id.0).to_string(), Icon::named("x"))
+                            .child_index("modal-close-icon", key.clone(), Icon::named("x"))
                             .size(f32::from(modal_tokens.close_icon_size))
                             .color(title_color)
                             .registry(icons.clone()),
@@ -493,7 +594,7 @@ impl ModalLayer {
         };
 
         let mut panel = div()
-            .id(self.id.slot("modal-panel"))
+            .id(self.id.slot_index("modal-panel", key.clone()))
             .w(px(entry.resolved_width_px(modal_tokens)))
             .max_w_full()
             .bg(panel_bg)
@@ -533,18 +634,24 @@ impl ModalLayer {
                     .justify_end()
                     .gap(modal_tokens.actions_gap)
                     .child(
-                        Button::new()
+                        self.id
+                            .ctx()
+                            .child_index("modal-cancel", key.clone(), Button::new())
                             .label(entry.cancel_label_ref().clone())
                             .with_variant(crate::style::Variant::Default)
+                            .focus_handle(focus.handle(&focus_scope, "cancel", cx))
                             .on_click(move |_, window, _| {
                                 manager_for_cancel.cancel(id);
                                 window.refresh();
                             }),
                     )
                     .child(
-                        Button::new()
+                        self.id
+                            .ctx()
+                            .child_index("modal-confirm", key.clone(), Button::new())
                             .label(entry.confirm_label_ref().clone())
                             .with_variant(crate::style::Variant::Filled)
+                            .focus_handle(focus.handle(&focus_scope, "confirm", cx))
                             .on_click(move |_, window, _| {
                                 manager_for_confirm.confirm(id);
                                 window.refresh();
@@ -558,9 +665,12 @@ impl ModalLayer {
                     .flex()
                     .justify_end()
                     .child(
-                        Button::new()
+                        self.id
+                            .ctx()
+                            .child_index("modal-complete", key.clone(), Button::new())
                             .label(entry.complete_label_ref().clone())
                             .with_variant(crate::style::Variant::Filled)
+                            .focus_handle(focus.handle(&focus_scope, "complete", cx))
                             .on_click(move |_, window, _| {
                                 manager_for_complete.complete(id);
                                 window.refresh();
@@ -570,23 +680,28 @@ impl ModalLayer {
         }
 
         let panel = panel.with_enter_transition(
-            self.id.slot_index("modal-enter", id.0.to_string()),
+            self.id.slot_index("modal-enter", key.clone()),
             entry.motion_ref(),
         );
 
         let close_on_escape = entry.close_on_escape_enabled();
 
-        div()
-            .id(self.id.slot_index("modal-root", (id.0).to_string()))
+        let root = div()
+            .id(self.id.slot_index("modal-root", key))
             .size_full()
             .absolute()
             .top_0()
             .left_0()
             .flex()
             .items_center()
-            .justify_center()
+            .justify_center();
+        focus
+            .scope(&focus_root, root)
             .on_key_down(move |event, window, _cx| {
-                if close_on_escape && control::is_escape_keystroke(event) {
+                if close_on_escape
+                    && control::is_escape_keystroke(event)
+                    && manager_for_escape.is_top(id)
+                {
                     manager_for_escape.close_with_reason(id, ModalCloseReason::EscapeKey);
                     window.refresh();
                 }
@@ -602,9 +717,33 @@ impl ModalLayer {}
 impl RenderOnce for ModalLayer {
     fn render(mut self, window: &mut gpui::Window, _cx: &mut gpui::App) -> impl IntoElement {
         self.theme.sync_from_provider(_cx);
-        let Some(entry) = self.manager.top() else {
+        let prefix = format!("{}:", self.id);
+        let live = self
+            .manager
+            .list()
+            .into_iter()
+            .map(|managed| self.focus_scope(managed.id()))
+            .collect::<Vec<_>>();
+        crate::provider::CalmProvider::focus(_cx).release_where(
+            |scope| scope.starts_with(&prefix) && !live.iter().any(|live| live == scope),
+            window,
+            _cx,
+        );
+        let stack = self.manager.list();
+        if stack.is_empty() {
             return div().into_any_element();
-        };
-        self.render_modal(entry, window)
+        }
+        let layers = stack
+            .into_iter()
+            .enumerate()
+            .map(|(depth, managed)| self.render_modal(managed, depth, window, _cx))
+            .collect::<Vec<_>>();
+        div()
+            .size_full()
+            .absolute()
+            .top_0()
+            .left_0()
+            .children(layers)
+            .into_any_element()
     }
 }
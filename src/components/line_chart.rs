@@ -0,0 +1,326 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{
+    AnyElement, IntoElement, ParentElement, PathBuilder, RenderOnce, SharedString, Styled, div,
+    point, px, relative,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+
+use super::chart::{self, ChartSeries, ValueFormatter};
+use super::chart_state;
+use super::utils::resolve_hsla;
+
+/// Most category labels shown under the plot before every other one is
+/// skipped.
+const MAX_X_LABELS: usize = 8;
+
+/// One line per [`ChartSeries`], sharing a category axis given by
+/// [`LineChart::labels`].
+#[derive(IntoElement)]
+pub struct LineChart {
+    pub(crate) id: ComponentId,
+    series: Vec<ChartSeries>,
+    labels: Vec<SharedString>,
+    height_px: Option<f32>,
+    show_grid: bool,
+    show_axis: bool,
+    show_points: bool,
+    show_legend: Option<bool>,
+    show_tooltip: bool,
+    area: bool,
+    empty_label: SharedString,
+    value_format: Option<ValueFormatter>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl LineChart {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            series: Vec::new(),
+            labels: Vec::new(),
+            height_px: None,
+            show_grid: true,
+            show_axis: true,
+            show_points: false,
+            show_legend: None,
+            show_tooltip: true,
+            area: false,
+            empty_label: "No data".into(),
+            value_format: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    pub fn series(mut self, series: impl IntoIterator<Item = ChartSeries>) -> Self {
+        self.series = series.into_iter().collect();
+        self
+    }
+
+    /// Category labels for each x position, drawn under the plot and used
+    /// as the tooltip title.
+    pub fn labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<SharedString>,
+    {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Height of the plot area, not counting labels and legend.
+    pub fn height(mut self, value: f32) -> Self {
+        self.height_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn show_grid(mut self, value: bool) -> Self {
+        self.show_grid = value;
+        self
+    }
+
+    /// Shows the value ticks on the left and the category labels below.
+    pub fn show_axis(mut self, value: bool) -> Self {
+        self.show_axis = value;
+        self
+    }
+
+    pub fn show_points(mut self, value: bool) -> Self {
+        self.show_points = value;
+        self
+    }
+
+    /// Defaults to showing the legend only when there is more than one
+    /// series.
+    pub fn show_legend(mut self, value: bool) -> Self {
+        self.show_legend = Some(value);
+        self
+    }
+
+    pub fn show_tooltip(mut self, value: bool) -> Self {
+        self.show_tooltip = value;
+        self
+    }
+
+    /// Shades the region under each line.
+    pub fn area(mut self, value: bool) -> Self {
+        self.area = value;
+        self
+    }
+
+    pub fn empty_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.empty_label = value.into();
+        self
+    }
+
+    pub fn value_format(mut self, formatter: impl Fn(f32) -> SharedString + 'static) -> Self {
+        self.value_format = Some(Rc::new(formatter));
+        self
+    }
+
+    fn point_count(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn render_labels(&self, count: usize) -> AnyElement {
+        let tokens = &self.theme.components.chart;
+        let label_width = tokens.axis_width * 2.0;
+        let step = count.div_ceil(MAX_X_LABELS).max(1);
+        let labels = self
+            .labels
+            .iter()
+            .take(count)
+            .enumerate()
+            .filter(|(index, _)| index % step == 0)
+            .map(|(index, label)| {
+                let at = chart_state::point_x(index, count, 1.0);
+                div()
+                    .absolute()
+                    .top_0()
+                    .left(relative(at))
+                    .ml(-label_width / 2.0)
+                    .w(label_width)
+                    .overflow_hidden()
+                    .text_center()
+                    .child(label.clone())
+            });
+        div()
+            .relative()
+            .ml(tokens.axis_width + tokens.axis_gap)
+            .h(tokens.axis_size * 1.5)
+            .text_size(tokens.axis_size)
+            .text_color(resolve_hsla(&self.theme, tokens.axis_fg))
+            .children(labels)
+            .into_any_element()
+    }
+}
+
+impl MotionAware for LineChart {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for LineChart {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.chart.clone();
+        let height = self.height_px.unwrap_or(f32::from(tokens.height));
+        let count = self.point_count();
+        if count == 0 {
+            return div()
+                .id(self.id.clone())
+                .flex()
+                .items_center()
+                .justify_center()
+                .w_full()
+                .h(px(height))
+                .text_size(tokens.empty_size)
+                .text_color(resolve_hsla(&self.theme, tokens.empty_fg))
+                .child(self.empty_label);
+        }
+
+        let values = self
+            .series
+            .iter()
+            .flat_map(|series| series.values.iter().copied())
+            .collect::<Vec<_>>();
+        let (min, max) = chart_state::value_bounds(values.iter().copied(), false);
+        let ticks = chart_state::nice_ticks(min, max, 4);
+        let range = (ticks[0], ticks[ticks.len() - 1]);
+        let colors = self
+            .series
+            .iter()
+            .enumerate()
+            .map(|(index, series)| {
+                series
+                    .color
+                    .unwrap_or_else(|| chart::series_color(&self.theme, index))
+            })
+            .collect::<Vec<_>>();
+        let lengths = self
+            .series
+            .iter()
+            .map(|series| series.values.len())
+            .collect::<Vec<_>>();
+        let hovered = chart_state::hovered(&self.id).filter(|index| *index < count);
+        let grid = resolve_hsla(&self.theme, tokens.grid);
+        let point_bg = resolve_hsla(&self.theme, tokens.point_bg);
+        let (line_width, point_size) = (tokens.line_width, tokens.point_size);
+        let (show_grid, show_points, area) = (self.show_grid, self.show_points, self.area);
+        let paint_ticks = ticks.clone();
+        let paint_colors = colors.clone();
+
+        let plot = chart::plot_layer(
+            &self.id,
+            values,
+            chart::data_motion(self.motion, cx),
+            move |bounds, values, window| {
+                if show_grid {
+                    chart::paint_grid(window, bounds, &paint_ticks, grid);
+                }
+                let (width, height) = (f32::from(bounds.size.width), f32::from(bounds.size.height));
+                if let Some(index) = hovered {
+                    let x = bounds.origin.x + px(chart_state::point_x(index, count, width));
+                    let mut builder = PathBuilder::stroke(px(1.0));
+                    builder.move_to(point(x, bounds.origin.y));
+                    builder.line_to(point(x, bounds.origin.y + bounds.size.height));
+                    if let Ok(path) = builder.build() {
+                        window.paint_path(path, grid);
+                    }
+                }
+                let mut start = 0;
+                for (series, len) in lengths.iter().enumerate() {
+                    let color = paint_colors[series];
+                    let points = values[start..start + len]
+                        .iter()
+                        .enumerate()
+                        .map(|(index, value)| {
+                            let x = chart_state::point_x(index, count, width);
+                            let y = chart_state::scale_y(*value, range, height);
+                            point(bounds.origin.x + px(x), bounds.origin.y + px(y))
+                        })
+                        .collect::<Vec<_>>();
+                    start += len;
+                    chart::paint_line(window, bounds, &points, line_width, color, area);
+                    for (index, at) in points.iter().enumerate() {
+                        if show_points || hovered == Some(index) {
+                            chart::paint_marker(window, *at, point_size, color, point_bg);
+                        }
+                    }
+                }
+            },
+        );
+
+        let tooltip = hovered.filter(|_| self.show_tooltip).map(|index| {
+            let (_, (width, height)) = chart_state::plot(&self.id);
+            let rows = self
+                .series
+                .iter()
+                .zip(&colors)
+                .filter_map(|(series, color)| {
+                    let value = *series.values.get(index)?;
+                    let text = chart::format_with(self.value_format.as_ref(), value);
+                    Some((*color, series.name.clone(), text))
+                })
+                .collect::<Vec<_>>();
+            let top = self
+                .series
+                .iter()
+                .filter_map(|series| series.values.get(index).copied())
+                .fold(f32::NEG_INFINITY, f32::max);
+            let at = (
+                chart_state::point_x(index, count, width),
+                chart_state::scale_y(top, range, height),
+            );
+            let title = self.labels.get(index).cloned();
+            let bubble = chart::tooltip_bubble(&self.theme, window, title, rows);
+            chart::tooltip_at(&self.id, &self.theme, at, bubble)
+        });
+        let plot = div()
+            .id(self.id.slot("plot"))
+            .relative()
+            .flex_1()
+            .h(px(height))
+            .child(plot)
+            .children(tooltip);
+        let plot = chart::track_hover(plot, &self.id, move |(x, _), (width, _)| {
+            chart_state::nearest_point(x, count, width)
+        });
+
+        let axis = self
+            .show_axis
+            .then(|| chart::value_axis(&self.theme, &ticks, height, self.value_format.as_ref()));
+        let labels = (self.show_axis && !self.labels.is_empty()).then(|| self.render_labels(count));
+        let legend = self.show_legend.unwrap_or(self.series.len() > 1).then(|| {
+            let items = self
+                .series
+                .iter()
+                .zip(&colors)
+                .map(|(series, color)| (*color, series.name.clone()))
+                .collect();
+            chart::legend(&self.theme, items)
+        });
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .gap(tokens.axis_gap)
+            .w_full()
+            .child(div().flex().gap(tokens.axis_gap).children(axis).child(plot))
+            .children(labels)
+            .children(legend)
+    }
+}
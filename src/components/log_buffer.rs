@@ -0,0 +1,350 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
+
+use gpui::SharedString;
+
+/// Lines a [`LogBuffer`] keeps unless told otherwise.
+pub const DEFAULT_MAX_LINES: usize = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Level named by one of the first few words of `line`, such as `WARN`
+    /// in `12:00:01 WARN disk almost full` or `[error]`.
+    pub fn detect(line: &str) -> Option<Self> {
+        line.split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|word| !word.is_empty())
+            .take(6)
+            .find_map(|word| match word.to_ascii_uppercase().as_str() {
+                "TRACE" => Some(Self::Trace),
+                "DEBUG" => Some(Self::Debug),
+                "INFO" => Some(Self::Info),
+                "WARN" | "WARNING" => Some(Self::Warn),
+                "ERROR" | "ERR" | "FATAL" => Some(Self::Error),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnsiColor {
+    /// One of the 256 terminal colors; 0-15 follow the theme.
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnsiStyle {
+    pub fg: Option<AnsiColor>,
+    pub bg: Option<AnsiColor>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl AnsiStyle {
+    /// Applies the parameters of one SGR (`ESC [ … m`) sequence.
+    fn apply(mut self, params: &str) -> Self {
+        let codes: Vec<u16> = params
+            .split(';')
+            .map(|code| code.parse().unwrap_or(0))
+            .collect();
+        let mut index = 0;
+        while index < codes.len() {
+            match codes[index] {
+                0 => self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => (self.bold, self.dim) = (false, false),
+                23 => self.italic = false,
+                24 => self.underline = false,
+                code @ 30..=37 => self.fg = Some(AnsiColor::Indexed((code - 30) as u8)),
+                code @ 90..=97 => self.fg = Some(AnsiColor::Indexed((code - 90 + 8) as u8)),
+                39 => self.fg = None,
+                code @ 40..=47 => self.bg = Some(AnsiColor::Indexed((code - 40) as u8)),
+                code @ 100..=107 => self.bg = Some(AnsiColor::Indexed((code - 100 + 8) as u8)),
+                49 => self.bg = None,
+                code @ (38 | 48) => {
+                    let (color, used) = extended_color(&codes[index + 1..]);
+                    index += used;
+                    if code == 38 {
+                        self.fg = color;
+                    } else {
+                        self.bg = color;
+                    }
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+        self
+    }
+}
+
+/// Reads the `5;n` or `2;r;g;b` tail of a 38/48 code. Returns the color and
+/// how many codes it took.
+fn extended_color(codes: &[u16]) -> (Option<AnsiColor>, usize) {
+    let byte = |index: usize| codes.get(index).map(|code| (*code).min(255) as u8);
+    match codes.first() {
+        Some(5) => (byte(1).map(AnsiColor::Indexed), 2),
+        Some(2) => match (byte(1), byte(2), byte(3)) {
+            (Some(r), Some(g), Some(b)) => (Some(AnsiColor::Rgb(r, g, b)), 4),
+            _ => (None, codes.len()),
+        },
+        _ => (None, 0),
+    }
+}
+
+/// Strips escape sequences from `line`. Returns the plain text and the byte
+/// ranges of it that carry a style.
+pub fn parse_ansi(line: &str) -> (String, Vec<(Range<usize>, AnsiStyle)>) {
+    let mut text = String::with_capacity(line.len());
+    let mut spans = Vec::new();
+    let mut style = AnsiStyle::default();
+    let mut span_start = 0;
+    let mut chars = line.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\x1b' {
+            if ch != '\r' {
+                text.push(ch);
+            }
+            continue;
+        }
+        match chars.next() {
+            Some('[') => {
+                let mut params = String::new();
+                let mut command = None;
+                for ch in chars.by_ref() {
+                    if ('@'..='~').contains(&ch) {
+                        command = Some(ch);
+                        break;
+                    }
+                    params.push(ch);
+                }
+                let next = match command {
+                    Some('m') => style.apply(&params),
+                    _ => style,
+                };
+                if next != style {
+                    if span_start < text.len() && style != AnsiStyle::default() {
+                        spans.push((span_start..text.len(), style));
+                    }
+                    span_start = text.len();
+                    style = next;
+                }
+            }
+            // Window titles and hyperlinks run to BEL or ESC \.
+            Some(']') => {
+                let mut escaped = false;
+                for ch in chars.by_ref() {
+                    if ch == '\x07' || escaped {
+                        break;
+                    }
+                    escaped = ch == '\x1b';
+                }
+            }
+            _ => {}
+        }
+    }
+    if span_start < text.len() && style != AnsiStyle::default() {
+        spans.push((span_start..text.len(), style));
+    }
+    (text, spans)
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct LogLine {
+    pub(super) text: SharedString,
+    pub(super) spans: Vec<(Range<usize>, AnsiStyle)>,
+    pub(super) level: Option<LogLevel>,
+}
+
+/// Matches for the filter last asked for, extended as lines arrive instead
+/// of rescanning the whole buffer.
+struct FilterCache {
+    query: String,
+    min_level: Option<LogLevel>,
+    scanned: usize,
+    matches: VecDeque<usize>,
+}
+
+struct LogBufferState {
+    lines: VecDeque<LogLine>,
+    max_lines: usize,
+    dropped: usize,
+    filter: Option<FilterCache>,
+}
+
+impl LogBufferState {
+    /// `query` is expected in lowercase.
+    fn matches(line: &LogLine, query: &str, min_level: Option<LogLevel>) -> bool {
+        let level_ok = match (min_level, line.level) {
+            (Some(min), Some(level)) => level >= min,
+            _ => true,
+        };
+        level_ok && (query.is_empty() || line.text.to_ascii_lowercase().contains(query))
+    }
+
+    /// Absolute numbers of the lines passing the filter, oldest first.
+    fn filtered(&mut self, query: &str, min_level: Option<LogLevel>) -> &VecDeque<usize> {
+        let query = query.to_ascii_lowercase();
+        let (dropped, total) = (self.dropped, self.dropped + self.lines.len());
+        if self
+            .filter
+            .as_ref()
+            .is_some_and(|cache| cache.query != query || cache.min_level != min_level)
+        {
+            self.filter = None;
+        }
+        let cache = self.filter.get_or_insert_with(|| FilterCache {
+            query: query.clone(),
+            min_level,
+            scanned: dropped,
+            matches: VecDeque::new(),
+        });
+        while cache.matches.front().is_some_and(|line| *line < dropped) {
+            cache.matches.pop_front();
+        }
+        for number in cache.scanned.max(dropped)..total {
+            if Self::matches(&self.lines[number - dropped], &query, min_level) {
+                cache.matches.push_back(number);
+            }
+        }
+        cache.scanned = total;
+        &cache.matches
+    }
+}
+
+/// Append-only store of log lines for a `LogView`, shared between whatever
+/// produces the lines and the view. Clones point at the same buffer, so it
+/// can be filled from another thread; the view shows new lines the next
+/// time it renders. Past `max_lines` the oldest lines are dropped.
+#[derive(Clone)]
+pub struct LogBuffer {
+    state: Arc<RwLock<LogBufferState>>,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogBuffer {
+    pub fn new() -> Self {
+        Self::with_max_lines(DEFAULT_MAX_LINES)
+    }
+
+    pub fn with_max_lines(max_lines: usize) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(LogBufferState {
+                lines: VecDeque::new(),
+                max_lines: max_lines.max(1),
+                dropped: 0,
+                filter: None,
+            })),
+        }
+    }
+
+    /// Appends text, one line per `\n`. ANSI color codes are parsed and the
+    /// level is guessed from the start of each line.
+    pub fn push(&self, text: impl AsRef<str>) {
+        self.append(text.as_ref(), None);
+    }
+
+    pub fn push_with_level(&self, level: LogLevel, text: impl AsRef<str>) {
+        self.append(text.as_ref(), Some(level));
+    }
+
+    fn append(&self, text: &str, level: Option<LogLevel>) {
+        let mut state = self.state.write().expect("log buffer poisoned");
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        for raw in text.split('\n') {
+            let (plain, spans) = parse_ansi(raw);
+            let level = level.or_else(|| LogLevel::detect(&plain));
+            state.lines.push_back(LogLine {
+                text: plain.into(),
+                spans,
+                level,
+            });
+        }
+        let overflow = state.lines.len().saturating_sub(state.max_lines);
+        state.lines.drain(..overflow);
+        state.dropped += overflow;
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.write().expect("log buffer poisoned");
+        state.dropped += state.lines.len();
+        state.lines.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.read().expect("log buffer poisoned").lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn max_lines(&self) -> usize {
+        self.state.read().expect("log buffer poisoned").max_lines
+    }
+
+    /// Lines dropped so far by the cap or `clear`. Line numbers keep
+    /// counting past them.
+    pub fn dropped(&self) -> usize {
+        self.state.read().expect("log buffer poisoned").dropped
+    }
+
+    /// The kept lines as plain text, escape codes removed.
+    pub fn text(&self) -> String {
+        let state = self.state.read().expect("log buffer poisoned");
+        let lines: Vec<&str> = state.lines.iter().map(|line| line.text.as_ref()).collect();
+        lines.join("\n")
+    }
+
+    /// How many lines pass the filter, and those at `range` among them with
+    /// their absolute line numbers.
+    pub(super) fn window(
+        &self,
+        query: &str,
+        min_level: Option<LogLevel>,
+        range: Range<usize>,
+    ) -> (usize, Vec<(usize, LogLine)>) {
+        let mut state = self.state.write().expect("log buffer poisoned");
+        if query.is_empty() && min_level.is_none() {
+            let count = state.lines.len();
+            let lines = state
+                .lines
+                .range(range.start.min(count)..range.end.min(count))
+                .enumerate()
+                .map(|(offset, line)| (state.dropped + range.start + offset, line.clone()))
+                .collect();
+            return (count, lines);
+        }
+        let matches = state.filtered(query, min_level);
+        let count = matches.len();
+        let numbers: Vec<usize> = matches
+            .range(range.start.min(count)..range.end.min(count))
+            .copied()
+            .collect();
+        let lines = numbers
+            .into_iter()
+            .map(|number| (number, state.lines[number - state.dropped].clone()))
+            .collect();
+        (count, lines)
+    }
+}
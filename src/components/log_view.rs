@@ -0,0 +1,392 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, Font, FontStyle, FontWeight, Hsla, InteractiveElement, IntoElement, MouseButton,
+    ParentElement, RenderOnce, Rgba, ScrollHandle, SharedString, StatefulInteractiveElement,
+    Styled, StyledText, TextRun, UnderlineStyle, Window, canvas, div, point, px,
+};
+
+use crate::contracts::{Sized, Varianted};
+use crate::id::ComponentId;
+use crate::style::{Size, Variant};
+
+use super::action_icon::ActionIcon;
+use super::icon::Icon;
+use super::kanban_board_state::visible_window;
+use super::log_buffer::{AnsiColor, AnsiStyle, LogBuffer, LogLevel, LogLine};
+use super::log_view_state;
+use super::text_selection::SelectableText;
+use super::utils::resolve_hsla;
+
+type FollowHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
+
+/// Colors and font the visible lines are drawn with.
+struct LogPalette {
+    font: Font,
+    fg: Hsla,
+    muted: Hsla,
+    warn: Hsla,
+    error: Hsla,
+    ansi: [Hsla; 8],
+}
+
+impl LogPalette {
+    fn level(&self, level: Option<LogLevel>) -> Hsla {
+        match level {
+            Some(LogLevel::Error) => self.error,
+            Some(LogLevel::Warn) => self.warn,
+            Some(LogLevel::Debug | LogLevel::Trace) => self.muted,
+            Some(LogLevel::Info) | None => self.fg,
+        }
+    }
+
+    fn ansi(&self, color: AnsiColor) -> Hsla {
+        let (r, g, b) = match color {
+            AnsiColor::Indexed(index @ 0..=15) => return self.ansi[usize::from(index % 8)],
+            AnsiColor::Indexed(index @ 16..=231) => {
+                let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+                let index = index - 16;
+                (level(index / 36), level(index / 6 % 6), level(index % 6))
+            }
+            AnsiColor::Indexed(index) => {
+                let gray = 8 + (index - 232) * 10;
+                (gray, gray, gray)
+            }
+            AnsiColor::Rgb(r, g, b) => (r, g, b),
+        };
+        Rgba {
+            r: f32::from(r) / 255.0,
+            g: f32::from(g) / 255.0,
+            b: f32::from(b) / 255.0,
+            a: 1.0,
+        }
+        .into()
+    }
+
+    fn run(&self, len: usize, base: Hsla, style: AnsiStyle) -> TextRun {
+        let mut run = TextRun {
+            len,
+            font: self.font.clone(),
+            color: style.fg.map_or(base, |color| self.ansi(color)),
+            background_color: style.bg.map(|color| self.ansi(color)),
+            underline: None,
+            strikethrough: None,
+        };
+        if style.bold {
+            run.font.weight = FontWeight::BOLD;
+        }
+        if style.italic {
+            run.font.style = FontStyle::Italic;
+        }
+        if style.dim {
+            run.color = run.color.opacity(0.6);
+        }
+        if style.underline {
+            run.underline = Some(UnderlineStyle {
+                thickness: px(1.0),
+                color: None,
+                wavy: false,
+            });
+        }
+        run
+    }
+
+    /// Joins `lines` with newlines and styles them by level and ANSI codes.
+    fn layout(&self, lines: &[(usize, LogLine)]) -> (String, Vec<TextRun>) {
+        let mut text = String::new();
+        let mut runs = Vec::new();
+        for (index, (_, line)) in lines.iter().enumerate() {
+            let base = self.level(line.level);
+            if index > 0 {
+                text.push('\n');
+                runs.push(self.run(1, base, AnsiStyle::default()));
+            }
+            let start = text.len();
+            text.push_str(&line.text);
+            let mut offset = 0;
+            for (range, style) in &line.spans {
+                if range.start > offset {
+                    runs.push(self.run(range.start - offset, base, AnsiStyle::default()));
+                }
+                runs.push(self.run(range.len(), base, *style));
+                offset = range.end;
+            }
+            if text.len() - start > offset {
+                runs.push(self.run(text.len() - start - offset, base, AnsiStyle::default()));
+            }
+        }
+        (text, runs)
+    }
+}
+
+/// Read-only view of a [`LogBuffer`] for build output, server logs and the
+/// like. Only the lines in view are laid out, so every line has the same
+/// height and long lines are clipped rather than wrapped.
+///
+/// The view follows the tail while new lines arrive. Scrolling up, or
+/// starting a selection, pauses that until the view is back at the end or
+/// the follow button is pressed.
+#[derive(IntoElement)]
+pub struct LogView {
+    pub(crate) id: ComponentId,
+    buffer: LogBuffer,
+    filter: SharedString,
+    min_level: Option<LogLevel>,
+    follow: Option<bool>,
+    line_numbers: bool,
+    selectable: bool,
+    overscan: usize,
+    on_follow_change: Option<FollowHandler>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl LogView {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            buffer: LogBuffer::new(),
+            filter: SharedString::default(),
+            min_level: None,
+            follow: None,
+            line_numbers: true,
+            selectable: true,
+            overscan: 10,
+            on_follow_change: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    pub fn buffer(mut self, value: LogBuffer) -> Self {
+        self.buffer = value;
+        self
+    }
+
+    /// Shows only lines containing this text, ignoring ASCII case.
+    pub fn filter(mut self, value: impl Into<SharedString>) -> Self {
+        self.filter = value.into();
+        self
+    }
+
+    /// Hides lines below this level. Lines without a level always show.
+    pub fn min_level(mut self, value: LogLevel) -> Self {
+        self.min_level = Some(value);
+        self
+    }
+
+    pub fn follow(mut self, value: bool) -> Self {
+        self.follow = Some(value);
+        self
+    }
+
+    pub fn line_numbers(mut self, value: bool) -> Self {
+        self.line_numbers = value;
+        self
+    }
+
+    /// Lets the visible lines be selected and copied.
+    pub fn selectable(mut self, value: bool) -> Self {
+        self.selectable = value;
+        self
+    }
+
+    /// Lines rendered above and below the visible ones.
+    pub fn overscan(mut self, value: usize) -> Self {
+        self.overscan = value;
+        self
+    }
+
+    pub fn on_follow_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_follow_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn palette(&self) -> LogPalette {
+        let tokens = &self.theme.components.log_view;
+        let color = |token| resolve_hsla(&self.theme, token);
+        LogPalette {
+            font: self.theme.fonts.monospace(),
+            fg: color(tokens.fg),
+            muted: color(tokens.muted_fg),
+            warn: color(tokens.warn_fg),
+            error: color(tokens.error_fg),
+            ansi: [
+                color(tokens.ansi_black),
+                color(tokens.ansi_red),
+                color(tokens.ansi_green),
+                color(tokens.ansi_yellow),
+                color(tokens.ansi_blue),
+                color(tokens.ansi_magenta),
+                color(tokens.ansi_cyan),
+                color(tokens.ansi_white),
+            ],
+        }
+    }
+
+    fn follow_setter(&self) -> impl Fn(bool, &mut Window, &mut gpui::App) + Clone + 'static {
+        let id = self.id.to_string();
+        let controlled = self.follow.is_some();
+        let handler = self.on_follow_change.clone();
+        move |value, window, cx| {
+            if !controlled {
+                log_view_state::set_following(&id, value);
+            }
+            if let Some(handler) = handler.as_ref() {
+                (handler)(value, window, cx);
+            }
+            window.refresh();
+        }
+    }
+
+    fn render_lines(&self, lines: &[(usize, LogLine)], first: usize) -> AnyElement {
+        let tokens = &self.theme.components.log_view;
+        let (text, runs) = self.palette().layout(lines);
+        if text.is_empty() {
+            return div().into_any_element();
+        }
+        if !self.selectable {
+            return StyledText::new(text).with_runs(runs).into_any_element();
+        }
+        // Keyed by the first line so a selection never lands on other text
+        // once the window moves.
+        SelectableText::new(
+            self.id.scoped_index("lines", first.to_string()),
+            text,
+            resolve_hsla(&self.theme, tokens.selection_bg),
+        )
+        .runs(runs)
+        .into_any_element()
+    }
+}
+
+impl RenderOnce for LogView {
+    fn render(mut self, _window: &mut Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.log_view.clone();
+        let id = self.id.to_string();
+        let line_height = f32::from(tokens.line_height);
+        let viewport = log_view_state::viewport(&id, line_height * 20.0);
+        let following = log_view_state::following(&id, self.follow);
+
+        let (count, _) = self.buffer.window(&self.filter, self.min_level, 0..0);
+        let max_scroll = (count as f32 * line_height - viewport).max(0.0);
+        let scroll_y = if following {
+            max_scroll
+        } else {
+            log_view_state::scroll_y(&id).min(max_scroll)
+        };
+        let window_range = visible_window(count, line_height, viewport, scroll_y, self.overscan);
+        let (_, lines) = self
+            .buffer
+            .window(&self.filter, self.min_level, window_range.clone());
+        let set_follow = self.follow_setter();
+
+        let scroll_handle = ScrollHandle::new();
+        scroll_handle.set_offset(point(px(0.0), px(-scroll_y)));
+        let monitor = {
+            let handle = scroll_handle.clone();
+            let (id, set_follow) = (id.clone(), set_follow.clone());
+            canvas(
+                move |_bounds, window, cx| {
+                    let top = -f32::from(handle.offset().y);
+                    let max = f32::from(handle.max_offset().height);
+                    let mut refresh =
+                        log_view_state::store_viewport(&id, f32::from(handle.bounds().size.height));
+                    refresh |= log_view_state::store_scroll_y(&id, top, line_height);
+                    if let Some(value) =
+                        log_view_state::follow_after_scroll(following, scroll_y, top, max)
+                    {
+                        set_follow(value, window, cx);
+                    } else if refresh {
+                        window.refresh();
+                    }
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full()
+        };
+
+        let gutter =
+            self.line_numbers.then(|| {
+                div()
+                    .flex_none()
+                    .flex()
+                    .flex_col()
+                    .items_end()
+                    .text_color(resolve_hsla(&self.theme, tokens.gutter_fg))
+                    .children(lines.iter().map(|(number, _)| {
+                        div().h(tokens.line_height).child((number + 1).to_string())
+                    }))
+            });
+        let mut content = div()
+            .flex_1()
+            .min_w_0()
+            .whitespace_nowrap()
+            .child(self.render_lines(&lines, window_range.start));
+        if self.selectable {
+            let pause = set_follow.clone();
+            content = content.on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                if following {
+                    pause(false, window, cx);
+                }
+            });
+        }
+
+        let list = div()
+            .id(self.id.slot("list"))
+            .relative()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&scroll_handle)
+            .px(tokens.padding)
+            .child(div().h(px(window_range.start as f32 * line_height)))
+            .child(
+                div()
+                    .flex()
+                    .gap(tokens.gutter_gap)
+                    .children(gutter)
+                    .child(content),
+            )
+            .child(div().h(px((count - window_range.end) as f32 * line_height)))
+            .child(monitor);
+
+        let follow_button = self
+            .id
+            .ctx()
+            .child("follow", ActionIcon::new())
+            .with_variant(if following {
+                Variant::Filled
+            } else {
+                Variant::Default
+            })
+            .with_size(Size::Sm)
+            .child(Icon::named("arrow-bar-to-down").size(14.0))
+            .on_click(move |_, window, cx| set_follow(!following, window, cx));
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .rounded(tokens.radius)
+            .border_1()
+            .border_color(resolve_hsla(&self.theme, tokens.border))
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .font(self.theme.fonts.monospace())
+            .text_size(tokens.text_size)
+            .line_height(tokens.line_height)
+            .text_color(resolve_hsla(&self.theme, tokens.fg))
+            .child(list)
+            .child(
+                div()
+                    .absolute()
+                    .right(tokens.padding)
+                    .bottom(tokens.padding)
+                    .child(follow_button),
+            )
+    }
+}
@@ -0,0 +1,53 @@
+use super::control;
+
+/// Within this many px of the end the view counts as showing the tail.
+const TAIL_DISTANCE: f32 = 2.0;
+
+pub fn following(id: &str, controlled: Option<bool>) -> bool {
+    control::bool_state(id, "follow", controlled, true)
+}
+
+pub fn set_following(id: &str, value: bool) {
+    control::set_bool_state(id, "follow", value);
+}
+
+pub fn scroll_y(id: &str) -> f32 {
+    control::f32_state(id, "scroll-y", None, 0.0)
+}
+
+/// Records the scroll offset. Returns whether the first visible line moved
+/// and the view needs another frame.
+pub fn store_scroll_y(id: &str, next_y: f32, line_height: f32) -> bool {
+    let previous = scroll_y(id);
+    if (next_y - previous).abs() <= 0.5 {
+        return false;
+    }
+    control::set_f32_state(id, "scroll-y", next_y);
+    let line_height = line_height.max(1.0);
+    (previous / line_height).floor() != (next_y / line_height).floor()
+}
+
+pub fn viewport(id: &str, fallback: f32) -> f32 {
+    control::optional_f32_state(id, "viewport", None, None).unwrap_or(fallback)
+}
+
+pub fn store_viewport(id: &str, height: f32) -> bool {
+    let previous = control::optional_f32_state(id, "viewport", None, None);
+    if previous.is_some_and(|previous| (previous - height).abs() <= 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, "viewport", Some(height));
+    true
+}
+
+/// Follow state after the user scrolled from `expected` to `top`, or `None`
+/// when it should stay as it is. Moving away from the tail stops following
+/// and coming back to it starts again; offsets the view set itself, or that
+/// layout clamped to the end, do not count.
+pub fn follow_after_scroll(following: bool, expected: f32, top: f32, max: f32) -> Option<bool> {
+    if (top - expected).abs() <= 0.5 {
+        return None;
+    }
+    let at_tail = max - top <= TAIL_DISTANCE;
+    (at_tail != following).then_some(at_tail)
+}
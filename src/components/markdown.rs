@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 use std::rc::Rc;
 use std::sync::{Arc, LazyLock, Mutex};
 
@@ -10,11 +11,17 @@ use gpui::{
     RenderOnce, SharedString, StrikethroughStyle, Styled, StyledText, TextRun, UnderlineStyle,
     Window, div, font, img, px,
 };
+use gpui::{ImageSource, StyledImage};
 use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 
 use super::Stack;
+use super::copy_button::CopyButton;
+use super::markdown_stream;
+use super::text_selection::{FindHighlight, FindScope, SelectableText};
 use super::utils::resolve_hsla;
+use crate::contracts::Sized;
 use crate::id::ComponentId;
+use crate::style::Size;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MarkdownLinkClick {
@@ -24,7 +31,23 @@ pub struct MarkdownLinkClick {
     pub is_external: bool,
 }
 
-type LinkClickHandler = Rc<dyn Fn(&MarkdownLinkClick, &mut Window, &mut gpui::App)>;
+/// A task list checkbox the user clicked in a [`Markdown`] document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarkdownTaskToggle {
+    /// Position among the document's task items, counting from zero.
+    pub index: usize,
+    /// State the item should change to.
+    pub checked: bool,
+    /// Byte range of the `[ ]` or `[x]` marker in the source, for writing the
+    /// new state back.
+    pub range: Range<usize>,
+}
+
+/// Returns `true` when it took care of the link, so the system opener is
+/// skipped.
+type LinkHandler = Rc<dyn Fn(&MarkdownLinkClick, &mut Window, &mut gpui::App) -> bool>;
+type ImageResolver = Rc<dyn Fn(&str) -> Option<ImageSource>>;
+type TaskToggleHandler = Rc<dyn Fn(&MarkdownTaskToggle, &mut Window, &mut gpui::App)>;
 
 #[derive(Clone, Debug)]
 enum MarkdownBlock {
@@ -54,11 +77,25 @@ enum MarkdownBlock {
         alt: SharedString,
     },
     Rule,
+    Footnotes(Vec<Footnote>),
 }
 
 #[derive(Clone, Debug, Default)]
 struct ListItem {
-    checked: Option<bool>,
+    task: Option<TaskMarker>,
+    blocks: Vec<MarkdownBlock>,
+}
+
+#[derive(Clone, Debug)]
+struct TaskMarker {
+    index: usize,
+    checked: bool,
+    range: Range<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct Footnote {
+    number: usize,
     blocks: Vec<MarkdownBlock>,
 }
 
@@ -92,6 +129,7 @@ enum InlineNode {
         title: Option<SharedString>,
         alt: Vec<InlineNode>,
     },
+    FootnoteRef(usize),
 }
 
 #[derive(Clone)]
@@ -103,6 +141,17 @@ struct CachedMarkdown {
 static MARKDOWN_CACHE: LazyLock<Mutex<HashMap<u64, CachedMarkdown>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
 
+/// Parse state for a [`Markdown::streaming`] document: the blocks of its
+/// settled prefix, kept while text is appended after it.
+struct StreamedMarkdown {
+    source: String,
+    settled: usize,
+    blocks: Vec<MarkdownBlock>,
+}
+
+static MARKDOWN_STREAMS: LazyLock<Mutex<HashMap<String, StreamedMarkdown>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 fn hash_markdown(source: &str) -> u64 {
     let mut hasher = std::collections::hash_map::DefaultHasher::new();
     source.hash(&mut hasher);
@@ -190,7 +239,11 @@ enum ParseContainer {
         items: Vec<ListItem>,
     },
     Item {
-        checked: Option<bool>,
+        task: Option<TaskMarker>,
+        blocks: Vec<MarkdownBlock>,
+    },
+    Footnote {
+        label: String,
         blocks: Vec<MarkdownBlock>,
     },
     Table(TableAcc),
@@ -290,7 +343,8 @@ fn push_block(stack: &mut [ParseContainer], block: MarkdownBlock) {
         match container {
             ParseContainer::Root { blocks }
             | ParseContainer::Quote { blocks }
-            | ParseContainer::Item { blocks, .. } => {
+            | ParseContainer::Item { blocks, .. }
+            | ParseContainer::Footnote { blocks, .. } => {
                 blocks.push(block);
                 return;
             }
@@ -329,6 +383,7 @@ fn flatten_inline_text(inlines: &[InlineNode]) -> String {
                     title: _,
                 } => walk(buffer, children),
                 InlineNode::Image { alt, .. } => walk(buffer, alt),
+                InlineNode::FootnoteRef(number) => buffer.push_str(&format!("[{number}]")),
             }
         }
     }
@@ -358,15 +413,30 @@ fn finish_paragraph(inlines: Vec<InlineNode>) -> Option<MarkdownBlock> {
     Some(MarkdownBlock::Paragraph(trimmed))
 }
 
+/// Number shown for the footnote `label`, assigned in order of first use.
+fn footnote_number(labels: &mut Vec<String>, label: &str) -> usize {
+    match labels.iter().position(|known| known == label) {
+        Some(index) => index + 1,
+        None => {
+            labels.push(label.to_string());
+            labels.len()
+        }
+    }
+}
+
 fn parse_blocks(source: &str) -> Vec<MarkdownBlock> {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_TABLES);
     options.insert(Options::ENABLE_TASKLISTS);
     options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_FOOTNOTES);
 
     let mut stack = vec![ParseContainer::Root { blocks: Vec::new() }];
+    let mut task_count = 0usize;
+    let mut footnote_labels = Vec::new();
+    let mut footnotes = Vec::new();
 
-    for event in Parser::new_ext(source, options) {
+    for (event, range) in Parser::new_ext(source, options).into_offset_iter() {
         match event {
             Event::Start(tag) => match tag {
                 Tag::Heading { level, .. } => stack.push(ParseContainer::Heading {
@@ -400,7 +470,11 @@ fn parse_blocks(source: &str) -> Vec<MarkdownBlock> {
                     items: Vec::new(),
                 }),
                 Tag::Item => stack.push(ParseContainer::Item {
-                    checked: None,
+                    task: None,
+                    blocks: Vec::new(),
+                }),
+                Tag::FootnoteDefinition(label) => stack.push(ParseContainer::Footnote {
+                    label: label.to_string(),
                     blocks: Vec::new(),
                 }),
                 Tag::Table(alignments) => stack.push(ParseContainer::Table(TableAcc {
@@ -477,15 +551,23 @@ fn parse_blocks(source: &str) -> Vec<MarkdownBlock> {
                     }
                 }
                 TagEnd::Item => {
-                    if let Some(ParseContainer::Item { checked, blocks }) = stack.pop() {
+                    if let Some(ParseContainer::Item { task, blocks }) = stack.pop() {
                         for container in stack.iter_mut().rev() {
                             if let ParseContainer::List { items, .. } = container {
-                                items.push(ListItem { checked, blocks });
+                                items.push(ListItem { task, blocks });
                                 break;
                             }
                         }
                     }
                 }
+                TagEnd::FootnoteDefinition => {
+                    if let Some(ParseContainer::Footnote { label, blocks }) = stack.pop() {
+                        footnotes.push(Footnote {
+                            number: footnote_number(&mut footnote_labels, &label),
+                            blocks,
+                        });
+                    }
+                }
                 TagEnd::List(_) => {
                     if let Some(ParseContainer::List {
                         ordered,
@@ -668,8 +750,13 @@ fn parse_blocks(source: &str) -> Vec<MarkdownBlock> {
             Event::Rule => push_block(&mut stack, MarkdownBlock::Rule),
             Event::TaskListMarker(checked) => {
                 for container in stack.iter_mut().rev() {
-                    if let ParseContainer::Item { checked: state, .. } = container {
-                        *state = Some(checked);
+                    if let ParseContainer::Item { task, .. } = container {
+                        *task = Some(TaskMarker {
+                            index: task_count,
+                            checked,
+                            range,
+                        });
+                        task_count += 1;
                         break;
                     }
                 }
@@ -720,8 +807,12 @@ fn parse_blocks(source: &str) -> Vec<MarkdownBlock> {
                 push_text(&mut stack, value.as_ref());
             }
             Event::FootnoteReference(value) => {
-                let text = format!("[^{value}]");
-                push_text(&mut stack, &text);
+                let inline = InlineNode::FootnoteRef(footnote_number(&mut footnote_labels, &value));
+                for container in stack.iter_mut().rev() {
+                    if push_inline(container, inline.clone()) {
+                        break;
+                    }
+                }
             }
         }
     }
@@ -773,14 +864,18 @@ fn parse_blocks(source: &str) -> Vec<MarkdownBlock> {
                     );
                 }
             }
-            Some(ParseContainer::Item { checked, blocks }) => {
+            Some(ParseContainer::Item { task, blocks }) => {
                 for container in stack.iter_mut().rev() {
                     if let ParseContainer::List { items, .. } = container {
-                        items.push(ListItem { checked, blocks });
+                        items.push(ListItem { task, blocks });
                         break;
                     }
                 }
             }
+            Some(ParseContainer::Footnote { label, blocks }) => footnotes.push(Footnote {
+                number: footnote_number(&mut footnote_labels, &label),
+                blocks,
+            }),
             Some(ParseContainer::Mark { inlines }) => {
                 let inline = InlineNode::Mark(inlines);
                 for container in stack.iter_mut().rev() {
@@ -826,6 +921,12 @@ fn parse_blocks(source: &str) -> Vec<MarkdownBlock> {
         }
     }
 
+    if !footnotes.is_empty() {
+        // Definitions may come in any order; list them as they were numbered.
+        footnotes.sort_by_key(|footnote| footnote.number);
+        push_block(&mut stack, MarkdownBlock::Footnotes(footnotes));
+    }
+
     match stack.pop() {
         Some(ParseContainer::Root { blocks }) => blocks,
         _ => Vec::new(),
@@ -857,6 +958,41 @@ fn cached_blocks(source: &str) -> Arc<Vec<MarkdownBlock>> {
     parsed
 }
 
+/// Parses only what follows the settled prefix of the last render. Anything
+/// other than an append, such as an edit or a reset, starts over.
+fn streamed_blocks(key: &str, source: &str) -> Arc<Vec<MarkdownBlock>> {
+    let Ok(mut streams) = MARKDOWN_STREAMS.lock() else {
+        return Arc::new(parse_blocks(source));
+    };
+    if streams.len() > 64 && !streams.contains_key(key) {
+        streams.clear();
+    }
+    let stream = streams
+        .entry(key.to_string())
+        .or_insert_with(|| StreamedMarkdown {
+            source: String::new(),
+            settled: 0,
+            blocks: Vec::new(),
+        });
+    if !source.starts_with(&stream.source[..stream.settled]) {
+        stream.settled = 0;
+        stream.blocks.clear();
+    }
+
+    let boundary = markdown_stream::settled_boundary(source, stream.settled);
+    if boundary > stream.settled {
+        stream
+            .blocks
+            .extend(parse_blocks(&source[stream.settled..boundary]));
+        stream.settled = boundary;
+    }
+    stream.source = source.to_string();
+
+    let mut blocks = stream.blocks.clone();
+    blocks.extend(parse_blocks(&source[stream.settled..]));
+    Arc::new(blocks)
+}
+
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 struct InlineStyle {
     strong: bool,
@@ -866,6 +1002,7 @@ struct InlineStyle {
     mark: bool,
     kbd: bool,
     math: bool,
+    footnote: bool,
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -881,7 +1018,7 @@ struct InlineSegment {
     link: Option<LinkMeta>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct InlinePalette {
     paragraph: gpui::Hsla,
     link: gpui::Hsla,
@@ -896,6 +1033,203 @@ struct InlinePalette {
     kbd_bg: gpui::Hsla,
     inline_code_border: gpui::Hsla,
     kbd_border: gpui::Hsla,
+    selection: Option<gpui::Hsla>,
+    find: Option<FindHighlight>,
+    copy_code: bool,
+    image_placeholder: gpui::Hsla,
+    resolve_image: Option<ImageResolver>,
+}
+
+enum InlinePiece {
+    Text(Vec<InlineNode>),
+    Image {
+        src: SharedString,
+        alt: SharedString,
+        link: Option<LinkMeta>,
+    },
+}
+
+/// Pulls images, and images wrapped in links, out of a run of inlines so
+/// they can sit between the text pieces instead of flattening to alt text.
+fn split_inline_images(inlines: &[InlineNode]) -> Vec<InlinePiece> {
+    let mut pieces = Vec::new();
+    let mut text = Vec::new();
+    for inline in inlines {
+        let (link, children) = match inline {
+            InlineNode::Image { .. } => (None, std::slice::from_ref(inline)),
+            InlineNode::Link {
+                href,
+                title,
+                children,
+            } if children
+                .iter()
+                .any(|child| matches!(child, InlineNode::Image { .. })) =>
+            {
+                let link = LinkMeta {
+                    href: href.clone(),
+                    title: title.clone(),
+                };
+                (Some(link), children.as_slice())
+            }
+            _ => {
+                text.push(inline.clone());
+                continue;
+            }
+        };
+        for child in children {
+            match child {
+                InlineNode::Image { src, alt, .. } => {
+                    if !text.is_empty() {
+                        pieces.push(InlinePiece::Text(std::mem::take(&mut text)));
+                    }
+                    pieces.push(InlinePiece::Image {
+                        src: src.clone(),
+                        alt: flatten_inline_text(alt).into(),
+                        link: link.clone(),
+                    });
+                }
+                other => text.push(match link.as_ref() {
+                    Some(link) => InlineNode::Link {
+                        href: link.href.clone(),
+                        title: link.title.clone(),
+                        children: vec![other.clone()],
+                    },
+                    None => other.clone(),
+                }),
+            }
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(InlinePiece::Text(text));
+    }
+    pieces
+}
+
+/// An image that shows a tinted box while it loads and its alt text if it
+/// fails.
+fn markdown_image(
+    id: ElementId,
+    src: &SharedString,
+    alt: SharedString,
+    loading_size: gpui::Pixels,
+    palette: &InlinePalette,
+) -> gpui::Stateful<gpui::Img> {
+    let source = palette
+        .resolve_image
+        .as_ref()
+        .and_then(|resolve| resolve(src.as_ref()))
+        .unwrap_or_else(|| ImageSource::from(src.clone()));
+    let placeholder = palette.image_placeholder;
+    let alt_fg = palette.paragraph;
+    img(source)
+        .id(id)
+        .with_loading(move || {
+            div()
+                .size_full()
+                .min_w(loading_size)
+                .min_h(loading_size)
+                .bg(placeholder)
+                .into_any_element()
+        })
+        .with_fallback(move || {
+            div()
+                .text_color(alt_fg)
+                .opacity(0.7)
+                .child(alt.clone())
+                .into_any_element()
+        })
+}
+
+fn activate_link(
+    payload: &MarkdownLinkClick,
+    handler: Option<&LinkHandler>,
+    open_links_with_system: bool,
+    window: &mut Window,
+    cx: &mut gpui::App,
+) {
+    let handled = handler.is_some_and(|handler| handler(payload, window, cx));
+    if !handled && open_links_with_system && is_system_openable_url(payload.href.as_ref()) {
+        cx.open_url(payload.href.as_ref());
+    }
+}
+
+/// Paragraph content: plain interactive text, or a wrapping row when the
+/// paragraph has inline images.
+fn paragraph_inlines(
+    markdown_id: &ComponentId,
+    key: &str,
+    inlines: &[InlineNode],
+    palette: InlinePalette,
+    line_height: gpui::Pixels,
+    on_link_click: Option<LinkHandler>,
+    open_links_with_system: bool,
+) -> AnyElement {
+    let pieces = split_inline_images(inlines);
+    if !pieces
+        .iter()
+        .any(|piece| matches!(piece, InlinePiece::Image { .. }))
+    {
+        return interactive_text_from_inlines(
+            markdown_id.slot_index("paragraph-inline", key),
+            inlines,
+            palette,
+            on_link_click,
+            open_links_with_system,
+        );
+    }
+
+    div()
+        .flex()
+        .flex_row()
+        .flex_wrap()
+        .items_center()
+        .children(pieces.into_iter().enumerate().map(|(index, piece)| {
+            let key = format!("{key}-{index}");
+            match piece {
+                InlinePiece::Text(nodes) => interactive_text_from_inlines(
+                    markdown_id.slot_index("paragraph-inline", key),
+                    &nodes,
+                    palette.clone(),
+                    on_link_click.clone(),
+                    open_links_with_system,
+                ),
+                InlinePiece::Image { src, alt, link } => {
+                    let image = markdown_image(
+                        markdown_id.slot_index("inline-image", key.clone()),
+                        &src,
+                        alt.clone(),
+                        line_height,
+                        &palette,
+                    )
+                    .h(line_height);
+                    let Some(link) = link else {
+                        return image.into_any_element();
+                    };
+                    let payload = MarkdownLinkClick {
+                        is_external: is_external_url(link.href.as_ref()),
+                        href: link.href,
+                        title: link.title,
+                        text: alt,
+                    };
+                    let handler = on_link_click.clone();
+                    div()
+                        .id(markdown_id.slot_index("inline-image-link", key))
+                        .cursor_pointer()
+                        .on_click(move |_, window, cx| {
+                            activate_link(
+                                &payload,
+                                handler.as_ref(),
+                                open_links_with_system,
+                                window,
+                                cx,
+                            );
+                        })
+                        .child(image)
+                        .into_any_element()
+                }
+            }
+        }))
+        .into_any_element()
 }
 
 fn flatten_inlines(
@@ -1012,6 +1346,16 @@ fn flatten_inlines(
                     flatten_inlines(alt, style, link.clone(), output);
                 }
             }
+            InlineNode::FootnoteRef(number) => {
+                let mut next = style.clone();
+                next.footnote = true;
+                flatten_inlines(
+                    &[InlineNode::Text(format!("[{number}]").into())],
+                    &next,
+                    link.clone(),
+                    output,
+                );
+            }
         }
     }
 }
@@ -1033,7 +1377,7 @@ fn interactive_text_from_inlines(
     id: ElementId,
     inlines: &[InlineNode],
     palette: InlinePalette,
-    on_link_click: Option<LinkClickHandler>,
+    on_link_click: Option<LinkHandler>,
     open_links_with_system: bool,
 ) -> AnyElement {
     let mut segments = Vec::new();
@@ -1096,6 +1440,10 @@ fn interactive_text_from_inlines(
             run.color = palette.mark_fg;
             run.background_color = Some(palette.mark_bg);
         }
+        if segment.style.footnote {
+            run.color = palette.link;
+            run.font.weight = FontWeight::MEDIUM;
+        }
         if segment.style.kbd {
             run.font = font("SFMono-Regular");
             run.font.weight = FontWeight::MEDIUM;
@@ -1134,23 +1482,36 @@ fn interactive_text_from_inlines(
         return div().into_any_element();
     }
 
-    let styled = StyledText::new(text).with_runs(runs);
-    if clickable_ranges.is_empty() {
-        return styled.into_any_element();
-    }
+    let link_id = id.clone();
+    let with_links = move |styled: StyledText| {
+        if clickable_ranges.is_empty() {
+            return styled.into_any_element();
+        }
 
-    let click_handler = on_link_click.clone();
-    gpui::InteractiveText::new(id, styled)
-        .on_click(clickable_ranges, move |index, window, cx| {
-            if let Some(payload) = link_payloads.get(index).cloned() {
-                if let Some(handler) = click_handler.as_ref() {
-                    handler(&payload, window, cx);
-                } else if open_links_with_system && is_system_openable_url(payload.href.as_ref()) {
-                    cx.open_url(payload.href.as_ref());
+        let click_handler = on_link_click.clone();
+        gpui::InteractiveText::new(link_id, styled)
+            .on_click(clickable_ranges, move |index, window, cx| {
+                if let Some(payload) = link_payloads.get(index) {
+                    activate_link(
+                        payload,
+                        click_handler.as_ref(),
+                        open_links_with_system,
+                        window,
+                        cx,
+                    );
                 }
-            }
-        })
-        .into_any_element()
+            })
+            .into_any_element()
+    };
+
+    match palette.selection {
+        Some(selection_bg) => SelectableText::new(id, text, selection_bg)
+            .runs(runs)
+            .find(palette.find)
+            .wrap(with_links)
+            .into_any_element(),
+        None => with_links(StyledText::new(text).with_runs(runs)),
+    }
 }
 
 #[derive(IntoElement)]
@@ -1158,8 +1519,14 @@ pub struct Markdown {
     pub(crate) id: ComponentId,
     source: SharedString,
     compact: bool,
-    on_link_click: Option<LinkClickHandler>,
+    on_link_click: Option<LinkHandler>,
     open_links_with_system: bool,
+    selectable: bool,
+    find_scope: Option<FindScope>,
+    copy_code: bool,
+    on_task_toggle: Option<TaskToggleHandler>,
+    resolve_image: Option<ImageResolver>,
+    streaming: bool,
     pub(crate) theme: crate::theme::LocalTheme,
     style: gpui::StyleRefinement,
 }
@@ -1173,6 +1540,12 @@ impl Markdown {
             compact: false,
             on_link_click: None,
             open_links_with_system: true,
+            selectable: false,
+            find_scope: None,
+            copy_code: false,
+            on_task_toggle: None,
+            resolve_image: None,
+            streaming: false,
             theme: crate::theme::LocalTheme::default(),
             style: gpui::StyleRefinement::default(),
         }
@@ -1183,9 +1556,23 @@ impl Markdown {
         self
     }
 
+    /// Takes over every link click; the system opener never runs.
     pub fn on_link_click(
         mut self,
         handler: impl Fn(&MarkdownLinkClick, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_link_click = Some(Rc::new(move |link, window, cx| {
+            handler(link, window, cx);
+            true
+        }));
+        self
+    }
+
+    /// Sees each link click first, for in-app navigation. Returning `false`
+    /// passes the link on to the system opener.
+    pub fn link_handler(
+        mut self,
+        handler: impl Fn(&MarkdownLinkClick, &mut Window, &mut gpui::App) -> bool + 'static,
     ) -> Self {
         self.on_link_click = Some(Rc::new(handler));
         self
@@ -1195,6 +1582,57 @@ impl Markdown {
         self.open_links_with_system = value;
         self
     }
+
+    pub fn selectable(mut self, value: bool) -> Self {
+        self.selectable = value;
+        self
+    }
+
+    /// Makes paragraphs, headings, table cells and code blocks searchable by
+    /// the `FindBar` that owns `scope`.
+    pub fn find_scope(mut self, scope: FindScope) -> Self {
+        self.find_scope = Some(scope);
+        self
+    }
+
+    /// Adds a copy button to the corner of each code block.
+    pub fn copy_code(mut self, value: bool) -> Self {
+        self.copy_code = value;
+        self
+    }
+
+    /// Makes task list checkboxes clickable. The document does not change on
+    /// its own: write the new state into the source at the given range.
+    pub fn on_task_toggle(
+        mut self,
+        handler: impl Fn(&MarkdownTaskToggle, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_task_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    /// Maps an image `src` to what gets loaded, such as a path relative to
+    /// the document or bytes the app already holds. `None` loads `src` as a
+    /// path or URL.
+    pub fn resolve_image(
+        mut self,
+        resolver: impl Fn(&str) -> Option<ImageSource> + 'static,
+    ) -> Self {
+        self.resolve_image = Some(Rc::new(resolver));
+        self
+    }
+
+    /// For text that grows while it is shown, such as a chat reply arriving
+    /// in chunks. Pass the whole text so far on each render; blocks that a
+    /// later blank line has closed are parsed once and kept, and only the
+    /// trailing ones are parsed again. Their element ids stay put, so a
+    /// scroll position above the tail does not move. Needs a stable id.
+    /// Footnotes and reference links only resolve within the stretch of
+    /// text that settled together.
+    pub fn streaming(mut self, value: bool) -> Self {
+        self.streaming = value;
+        self
+    }
 }
 
 impl Markdown {}
@@ -1203,7 +1641,11 @@ impl RenderOnce for Markdown {
     fn render(mut self, window: &mut gpui::Window, _cx: &mut gpui::App) -> impl IntoElement {
         self.theme.sync_from_provider(_cx);
         let tokens = &self.theme.components.markdown;
-        let blocks = cached_blocks(self.source.as_ref());
+        let blocks = if self.streaming {
+            streamed_blocks(&self.id, self.source.as_ref())
+        } else {
+            cached_blocks(self.source.as_ref())
+        };
 
         let paragraph_color = resolve_hsla(&self.theme, tokens.paragraph);
         let paragraph_muted = resolve_hsla(&self.theme, tokens.paragraph_muted);
@@ -1247,6 +1689,8 @@ impl RenderOnce for Markdown {
         let image_border = resolve_hsla(&self.theme, tokens.image_border);
         let image_bg = resolve_hsla(&self.theme, tokens.image_bg);
         let image_caption_fg = resolve_hsla(&self.theme, tokens.image_caption_fg);
+        let footnote_fg = resolve_hsla(&self.theme, tokens.footnote_fg);
+        let selection_bg = resolve_hsla(&self.theme, tokens.selection_bg);
 
         let mut root = Stack::vertical().id(self.id.clone()).w_full();
         root = if self.compact {
@@ -1269,6 +1713,14 @@ impl RenderOnce for Markdown {
             kbd_bg,
             inline_code_border,
             kbd_border,
+            selection: (self.selectable || self.find_scope.is_some()).then_some(selection_bg),
+            find: self
+                .find_scope
+                .take()
+                .map(|scope| FindHighlight::new(scope, &self.theme)),
+            copy_code: self.copy_code,
+            image_placeholder: resolve_hsla(&self.theme, tokens.image_placeholder_bg),
+            resolve_image: self.resolve_image.clone(),
         };
 
         fn node() -> gpui::Div {
@@ -1333,8 +1785,10 @@ impl RenderOnce for Markdown {
             image_border: gpui::Hsla,
             image_bg: gpui::Hsla,
             image_caption_fg: gpui::Hsla,
-            on_link_click: Option<LinkClickHandler>,
+            footnote_fg: gpui::Hsla,
+            on_link_click: Option<LinkHandler>,
             open_links_with_system: bool,
+            on_task_toggle: Option<TaskToggleHandler>,
             depth: usize,
             list_depth: usize,
             inline_counter: &mut usize,
@@ -1372,7 +1826,7 @@ impl RenderOnce for Markdown {
                                 inlines,
                                 InlinePalette {
                                     paragraph: heading_color,
-                                    ..palette
+                                    ..palette.clone()
                                 },
                                 on_link_click.clone(),
                                 open_links_with_system,
@@ -1385,10 +1839,12 @@ impl RenderOnce for Markdown {
                         .text_size(tokens.paragraph_size)
                         .line_height(tokens.paragraph_line_height)
                         .text_color(palette.paragraph)
-                        .child(interactive_text_from_inlines(
-                            markdown_id.slot_index("paragraph-inline", id_key.clone()),
+                        .child(paragraph_inlines(
+                            markdown_id,
+                            &id_key,
                             inlines,
-                            palette,
+                            palette.clone(),
+                            tokens.paragraph_line_height,
                             on_link_click.clone(),
                             open_links_with_system,
                         ))
@@ -1412,7 +1868,7 @@ impl RenderOnce for Markdown {
                                 tokens,
                                 InlinePalette {
                                     paragraph: quote_fg,
-                                    ..palette
+                                    ..palette.clone()
                                 },
                                 heading_color,
                                 paragraph_muted,
@@ -1438,8 +1894,10 @@ impl RenderOnce for Markdown {
                                 image_border,
                                 image_bg,
                                 image_caption_fg,
+                                footnote_fg,
                                 on_link_click.clone(),
                                 open_links_with_system,
+                                on_task_toggle.clone(),
                                 depth + 1,
                                 list_depth,
                                 inline_counter,
@@ -1472,15 +1930,40 @@ impl RenderOnce for Markdown {
                                 .truncate()
                                 .child(lang.clone())]);
                         }
+                        let code_text = match palette.selection {
+                            Some(selection_bg) => SelectableText::new(
+                                markdown_id.scoped_index("code-text", id_key.clone()),
+                                code.clone(),
+                                selection_bg,
+                            )
+                            .find(palette.find.clone())
+                            .into_any_element(),
+                            None => code.clone().into_any_element(),
+                        };
                         content = content.children([node()
                             .text_size(tokens.code_size)
                             .line_height(tokens.code_line_height)
                             .text_color(code_fg)
-                            .child(code.clone())]);
+                            .child(code_text)]);
+
+                        let copy = palette.copy_code.then(|| {
+                            node()
+                                .absolute()
+                                .top(tokens.code_padding)
+                                .right(tokens.code_padding)
+                                .child(
+                                    markdown_id
+                                        .ctx()
+                                        .child_index("code-copy", id_key.clone(), CopyButton::new())
+                                        .value(code.clone())
+                                        .with_size(Size::Xs),
+                                )
+                        });
 
                         content
                             .into_div()
                             .id(markdown_id.slot_index("code", id_key.clone()))
+                            .relative()
                             .w_full()
                             .px(tokens.code_padding)
                             .py(tokens.code_padding)
@@ -1488,6 +1971,7 @@ impl RenderOnce for Markdown {
                             .bg(code_bg)
                             .border(super::utils::quantized_stroke_px(window, 1.0))
                             .border_color(code_border.opacity(0.85))
+                            .children(copy)
                             .into_any_element()
                     }
                     MarkdownBlock::List {
@@ -1503,9 +1987,11 @@ impl RenderOnce for Markdown {
                                 list_depth,
                             );
 
-                            let marker = if let Some(checked) = item.checked {
+                            let marker = if let Some(task) = item.task.as_ref() {
+                                let checked = task.checked;
                                 let check_text = if checked { "✓" } else { " " };
-                                node()
+                                let mut checkbox = node()
+                                    .id(markdown_id.slot_index("task", task.index.to_string()))
                                     .w(tokens.list_indent)
                                     .min_w(tokens.list_indent)
                                     .h(tokens.list_indent)
@@ -1521,8 +2007,19 @@ impl RenderOnce for Markdown {
                                     .text_size(tokens.list_size)
                                     .line_height(tokens.list_indent)
                                     .text_center()
-                                    .child(check_text)
-                                    .into_any_element()
+                                    .child(check_text);
+                                if let Some(handler) = on_task_toggle.clone() {
+                                    let toggle = MarkdownTaskToggle {
+                                        index: task.index,
+                                        checked: !checked,
+                                        range: task.range.clone(),
+                                    };
+                                    checkbox =
+                                        checkbox.cursor_pointer().on_click(move |_, window, cx| {
+                                            handler(&toggle, window, cx)
+                                        });
+                                }
+                                checkbox.into_any_element()
                             } else {
                                 node()
                                     .min_w(tokens.list_indent)
@@ -1537,7 +2034,7 @@ impl RenderOnce for Markdown {
                                 &item.blocks,
                                 window,
                                 tokens,
-                                palette,
+                                palette.clone(),
                                 heading_color,
                                 paragraph_muted,
                                 _heading2_border,
@@ -1562,8 +2059,10 @@ impl RenderOnce for Markdown {
                                 image_border,
                                 image_bg,
                                 image_caption_fg,
+                                footnote_fg,
                                 on_link_click.clone(),
                                 open_links_with_system,
+                                on_task_toggle.clone(),
                                 depth + 1,
                                 list_depth + 1,
                                 inline_counter,
@@ -1625,7 +2124,7 @@ impl RenderOnce for Markdown {
                                         &cell.inlines,
                                         InlinePalette {
                                             paragraph: table_header_fg,
-                                            ..palette
+                                            ..palette.clone()
                                         },
                                         on_link_click.clone(),
                                         open_links_with_system,
@@ -1678,7 +2177,7 @@ impl RenderOnce for Markdown {
                                         &cell.inlines,
                                         InlinePalette {
                                             paragraph: table_cell_fg,
-                                            ..palette
+                                            ..palette.clone()
                                         },
                                         on_link_click.clone(),
                                         open_links_with_system,
@@ -1713,7 +2212,16 @@ impl RenderOnce for Markdown {
                                 .bg(image_bg)
                                 .border(super::utils::quantized_stroke_px(window, 1.0))
                                 .border_color(image_border)
-                                .child(img(src.clone()).w_full())]);
+                                .child(
+                                    markdown_image(
+                                        markdown_id.slot_index("image", id_key.clone()),
+                                        src,
+                                        caption.clone(),
+                                        tokens.image_placeholder_height,
+                                        &palette,
+                                    )
+                                    .w_full(),
+                                )]);
 
                         if !caption.trim().is_empty() {
                             image_block = image_block.children([node()
@@ -1725,6 +2233,93 @@ impl RenderOnce for Markdown {
 
                         image_block.into_any_element()
                     }
+                    MarkdownBlock::Footnotes(footnotes) => {
+                        let footnote_palette = InlinePalette {
+                            paragraph: footnote_fg,
+                            ..palette.clone()
+                        };
+                        let mut section = Stack::vertical().gap(tokens.list_gap).children([node()
+                            .w_full()
+                            .h(super::utils::hairline_px(window))
+                            .bg(rule_color)]);
+                        for footnote in footnotes {
+                            // A plain note keeps the smaller footnote size; richer
+                            // notes fall back to the regular block layout.
+                            let body = match footnote.blocks.as_slice() {
+                                [MarkdownBlock::Paragraph(inlines)] => {
+                                    node().child(interactive_text_from_inlines(
+                                        markdown_id.slot_index(
+                                            "footnote-inline",
+                                            format!("{id_key}-{}", footnote.number),
+                                        ),
+                                        inlines,
+                                        footnote_palette.clone(),
+                                        on_link_click.clone(),
+                                        open_links_with_system,
+                                    ))
+                                }
+                                blocks => render_blocks(
+                                    markdown_id,
+                                    blocks,
+                                    window,
+                                    tokens,
+                                    footnote_palette.clone(),
+                                    heading_color,
+                                    paragraph_muted,
+                                    _heading2_border,
+                                    quote_bg,
+                                    quote_border,
+                                    quote_fg,
+                                    code_bg,
+                                    code_border,
+                                    code_fg,
+                                    code_lang_fg,
+                                    list_marker,
+                                    rule_color,
+                                    table_border,
+                                    table_header_bg,
+                                    table_header_fg,
+                                    table_row_alt_bg,
+                                    table_cell_fg,
+                                    task_border,
+                                    task_bg,
+                                    task_checked_bg,
+                                    task_checked_fg,
+                                    image_border,
+                                    image_bg,
+                                    image_caption_fg,
+                                    footnote_fg,
+                                    on_link_click.clone(),
+                                    open_links_with_system,
+                                    on_task_toggle.clone(),
+                                    depth + 1,
+                                    list_depth,
+                                    inline_counter,
+                                )
+                                .gap(tokens.list_gap),
+                            };
+                            section = section.children([node()
+                                .flex()
+                                .flex_row()
+                                .gap(tokens.list_item_gap)
+                                .items_start()
+                                .w_full()
+                                .text_size(tokens.footnote_size)
+                                .text_color(footnote_fg)
+                                .children([
+                                    node()
+                                        .min_w(tokens.list_indent)
+                                        .child(format!("{}.", footnote.number))
+                                        .into_any_element(),
+                                    body.flex_1().min_w_0().into_any_element(),
+                                ])]);
+                        }
+                        section
+                            .into_div()
+                            .id(markdown_id.slot_index("footnotes", id_key.clone()))
+                            .w_full()
+                            .into_any_element()
+                    }
                     MarkdownBlock::Rule => node()
                         .id(markdown_id.slot_index("rule", id_key.clone()))
                         .w_full()
@@ -1770,8 +2365,10 @@ impl RenderOnce for Markdown {
             image_border,
             image_bg,
             image_caption_fg,
+            footnote_fg,
             self.on_link_click.clone(),
             self.open_links_with_system,
+            self.on_task_toggle.clone(),
             0,
             0,
             &mut inline_counter,
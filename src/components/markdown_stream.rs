@@ -0,0 +1,49 @@
+/// Byte offset past which a growing document may still change how earlier
+/// text parses: the start of the last block that follows a blank line,
+/// outside any code fence and not indented under the block above it.
+/// `from` must itself be such a boundary (or zero). Text before the result
+/// can be parsed once and kept while more is appended.
+pub fn settled_boundary(source: &str, from: usize) -> usize {
+    let mut boundary = from;
+    let mut fence: Option<(char, usize)> = None;
+    let mut after_blank = false;
+    let mut offset = from;
+
+    for line in source[from..].split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim_start_matches(' ');
+        let indent = line.len() - trimmed.len();
+
+        if let Some((marker, len)) = fence {
+            if indent < 4
+                && fence_run(trimmed, marker) >= len
+                && trimmed.trim_end().chars().all(|c| c == marker)
+            {
+                fence = None;
+            }
+            after_blank = false;
+            continue;
+        }
+
+        let blank = line.trim().is_empty();
+        if after_blank && !blank && !line.starts_with([' ', '\t']) {
+            boundary = start;
+        }
+        if indent < 4 {
+            for marker in ['`', '~'] {
+                let len = fence_run(trimmed, marker);
+                if len >= 3 {
+                    fence = Some((marker, len));
+                }
+            }
+        }
+        after_blank = blank;
+    }
+
+    boundary
+}
+
+fn fence_run(line: &str, marker: char) -> usize {
+    line.chars().take_while(|c| *c == marker).count()
+}
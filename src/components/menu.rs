@@ -8,18 +8,17 @@ use gpui::{
 
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
 use crate::motion::MotionConfig;
 
-use super::Stack;
-use super::icon::Icon;
 use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
+use super::menu_panel::{ItemClickHandler, MenuPanel, OpenChangeHandler};
 use super::menu_state::{self, MenuState, MenuStateInput};
 use super::popup::{PopupPlacement, anchored_host};
-use super::utils::{InteractionStyles, apply_interaction_styles, interaction_style, resolve_hsla};
+use super::press_feedback::PressFeedback;
+use super::utils::apply_disabled_state;
 
 type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
-type ItemClickHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
-type OpenChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MenuItem {
@@ -27,6 +26,8 @@ pub struct MenuItem {
     pub label: Option<SharedString>,
     pub disabled: bool,
     pub left_icon: Option<SharedString>,
+    pub shortcut: Option<SharedString>,
+    pub children: Vec<MenuItem>,
 }
 
 impl MenuItem {
@@ -36,6 +37,8 @@ impl MenuItem {
             label: None,
             disabled: false,
             left_icon: None,
+            shortcut: None,
+            children: Vec::new(),
         }
     }
 
@@ -51,6 +54,16 @@ impl MenuItem {
         self.left_icon = Some(value.into());
         self
     }
+
+    pub fn shortcut(mut self, action: impl Into<SharedString>) -> Self {
+        self.shortcut = Some(action.into());
+        self
+    }
+
+    pub fn submenu(mut self, items: impl IntoIterator<Item = MenuItem>) -> Self {
+        self.children.extend(items);
+        self
+    }
 }
 
 #[derive(IntoElement)]
@@ -139,119 +152,50 @@ impl Menu {
         self
     }
 
+    fn report_open(id: &ComponentId, opened: bool, cx: &gpui::App) {
+        let action = if opened {
+            InteractionAction::Open
+        } else {
+            InteractionAction::Close
+        };
+        interaction::emit(InteractionEvent::new("menu", id, action), cx);
+    }
+
+    fn inside_submenu(id: &ComponentId, event: &gpui::MouseDownEvent) -> bool {
+        let (x, y) = (f32::from(event.position.x), f32::from(event.position.y));
+        menu_state::submenu_contains(id, x, y)
+    }
+
     fn render_dropdown(
         &self,
         is_controlled: bool,
         dropdown_width_px: f32,
+        press_feedback: PressFeedback,
         window: &gpui::Window,
+        cx: &gpui::App,
     ) -> AnyElement {
-        let tokens = &self.theme.components.menu;
-        let on_item_click = self.on_item_click.clone();
-        let on_open_change = self.on_open_change.clone();
-        let close_on_item_click = self.close_on_item_click;
-        let menu_id = self.id.clone();
-
-        let rows = self
-            .items
-            .clone()
-            .into_iter()
-            .map(|item| {
-                let row_id = self.id.slot_index("item", item.value.to_string());
-                let mut row = div()
-                    .id(row_id.clone())
-                    .flex()
-                    .items_center()
-                    .gap(tokens.item_gap)
-                    .px(tokens.item_padding_x)
-                    .py(tokens.item_padding_y)
-                    .rounded(tokens.item_radius)
-                    .text_size(tokens.item_size)
-                    .text_color(resolve_hsla(&self.theme, tokens.item_fg));
-
-                if let Some(icon) = item.left_icon.clone() {
-                    row = row.child(
-                        self.id
-                            .ctx()
-                            .child_index(
-                                "item-icon",
-                                (item.value).to_string(),
-                                Icon::named(icon.to_string()),
-                            )
-                            .size(f32::from(tokens.item_icon_size))
-                            .color(resolve_hsla(&self.theme, tokens.icon)),
-                    );
-                }
-                let mut label_node = div().flex_1().min_w_0().truncate();
-                if let Some(label) = item.label.clone() {
-                    label_node = label_node.child(label);
-                }
-                row = row.child(label_node);
-
-                if item.disabled {
-                    row = row
-                        .cursor_default()
-                        .text_color(resolve_hsla(&self.theme, tokens.item_disabled_fg))
-                        .hover(|style| style);
-                } else {
-                    let value = item.value.clone();
-                    let on_item_click = on_item_click.clone();
-                    let on_open_change = on_open_change.clone();
-                    let menu_id = menu_id.clone();
-                    let hover_bg = resolve_hsla(&self.theme, tokens.item_hover_bg);
-                    let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
-                    let activate_handler: ActivateHandler =
-                        Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
-                            if let Some(handler) = on_item_click.as_ref() {
-                                (handler)(value.clone(), window, cx);
-                            }
-
-                            if close_on_item_click {
-                                if menu_state::on_item_click(
-                                    &menu_id,
-                                    is_controlled,
-                                    close_on_item_click,
-                                ) {
-                                    window.refresh();
-                                }
-                                if let Some(handler) = on_open_change.as_ref() {
-                                    (handler)(false, window, cx);
-                                }
-                            }
-                        });
-                    row = apply_interaction_styles(
-                        row.cursor_pointer(),
-                        InteractionStyles::new()
-                            .hover(interaction_style(move |style| style.bg(hover_bg)))
-                            .active(interaction_style(move |style| style.bg(press_bg)))
-                            .focus(interaction_style(move |style| style.bg(hover_bg))),
-                    );
-                    row = bind_press_adapter(
-                        row,
-                        PressAdapter::new(row_id.clone()).on_activate(Some(activate_handler)),
-                    );
-                }
-
-                row
-            })
-            .collect::<Vec<_>>();
-
-        let mut dropdown = Stack::vertical()
-            .id(self.id.slot("dropdown"))
+        let panel = MenuPanel {
+            id: &self.id,
+            theme: &self.theme,
+            controlled: is_controlled,
+            close_on_item_click: self.close_on_item_click,
+            press_feedback,
+            path: menu_state::submenu_path(&self.id),
+            on_item_click: self.on_item_click.clone(),
+            on_open_change: self.on_open_change.clone(),
+        };
+        let mut dropdown = panel
+            .render(&self.items, 0, window, cx)
             .w(px(dropdown_width_px))
-            .max_w_full()
-            .p(tokens.dropdown_padding)
-            .gap(tokens.dropdown_gap)
-            .rounded(tokens.dropdown_radius)
-            .border(super::utils::quantized_stroke_px(window, 1.0))
-            .border_color(resolve_hsla(&self.theme, tokens.dropdown_border))
-            .bg(resolve_hsla(&self.theme, tokens.dropdown_bg))
-            .shadow_sm()
-            .children(rows);
+            .max_w_full();
 
         if self.close_on_click_outside {
             if let Some(handler) = self.on_open_change.clone() {
                 let menu_id = self.id.clone();
-                dropdown = dropdown.on_mouse_down_out(move |_, window, cx| {
+                dropdown = dropdown.on_mouse_down_out(move |event, window, cx| {
+                    if Self::inside_submenu(&menu_id, event) {
+                        return;
+                    }
                     if menu_state::on_close_request(&menu_id, is_controlled) {
                         window.refresh();
                     }
@@ -259,7 +203,10 @@ impl Menu {
                 });
             } else if !is_controlled {
                 let menu_id = self.id.clone();
-                dropdown = dropdown.on_mouse_down_out(move |_, window, _cx| {
+                dropdown = dropdown.on_mouse_down_out(move |event, window, _cx| {
+                    if Self::inside_submenu(&menu_id, event) {
+                        return;
+                    }
                     if menu_state::on_close_request(&menu_id, false) {
                         window.refresh();
                     }
@@ -295,6 +242,9 @@ impl RenderOnce for Menu {
             dropdown_min_width: f32::from(tokens.dropdown_min_width),
         });
         let opened = state.opened;
+        if !opened {
+            menu_state::close_submenu(&self.id, 0);
+        }
         let is_controlled = state.controlled;
         let dropdown_width_px = state.dropdown_width_px;
 
@@ -320,7 +270,7 @@ impl RenderOnce for Menu {
         });
 
         if self.disabled {
-            trigger = trigger.opacity(0.55).cursor_default();
+            trigger = apply_disabled_state(trigger.cursor_default(), tokens.states, 0.55);
         } else {
             let activate_handler = if let Some(handler) = self.on_open_change.clone() {
                 let id = self.id.clone();
@@ -329,15 +279,17 @@ impl RenderOnce for Menu {
                     if menu_state::on_trigger_toggle(&id, is_controlled, next) {
                         window.refresh();
                     }
+                    Self::report_open(&id, next, cx);
                     (handler)(next, window, cx);
                 }) as ActivateHandler)
             } else if !is_controlled {
                 let id = self.id.clone();
                 let next = !opened;
-                Some(Rc::new(move |window: &mut Window, _cx: &mut gpui::App| {
+                Some(Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
                     if menu_state::on_trigger_toggle(&id, false, next) {
                         window.refresh();
                     }
+                    Self::report_open(&id, next, cx);
                 }) as ActivateHandler)
             } else {
                 None
@@ -355,16 +307,23 @@ impl RenderOnce for Menu {
         }
 
         if opened {
-            let dropdown = self.render_dropdown(is_controlled, dropdown_width_px, window);
+            let press_feedback = PressFeedback::resolve(&self.theme, _cx);
+            let dropdown = self.render_dropdown(
+                is_controlled,
+                dropdown_width_px,
+                press_feedback,
+                window,
+                _cx,
+            );
             let anchor_host = anchored_host(
                 &self.id,
                 "anchor-host",
-                PopupPlacement::Bottom,
-                self.offset_px,
-                self.theme.components.layout.popup_snap_margin,
+                PopupPlacement::Bottom.options(
+                    self.offset_px,
+                    self.theme.components.layout.popup_snap_margin,
+                ),
                 dropdown,
                 22,
-                true,
                 false,
             );
             trigger = trigger.child(anchor_host);
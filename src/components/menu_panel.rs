@@ -0,0 +1,337 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, Bounds, Corner, InteractiveElement, IntoElement, ParentElement, Pixels,
+    SharedString, Styled, Window, anchored, canvas, deferred, div, point, px, relative,
+};
+
+use crate::focus::{FocusCoordinator, FocusOrientation};
+use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
+use crate::provider::CalmProvider;
+
+use super::Stack;
+use super::control;
+use super::icon::Icon;
+use super::interaction_adapter::{
+    ActivateHandler, PressAdapter, RovingItem, bind_press_adapter, bind_roving_focus,
+};
+use super::menu::MenuItem;
+use super::menu_state::{self, MenuFrame, SubmenuSide};
+use super::press_feedback::PressFeedback;
+use super::utils::{
+    InteractionStyles, apply_focus_ring, apply_interaction_styles, interaction_style, resolve_hsla,
+};
+
+pub(super) type ItemClickHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+pub(super) type OpenChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
+
+const DROPDOWN_PRIORITY: usize = 22;
+
+pub(super) struct MenuPanel<'a> {
+    pub id: &'a ComponentId,
+    pub theme: &'a crate::theme::LocalTheme,
+    pub controlled: bool,
+    pub close_on_item_click: bool,
+    pub press_feedback: PressFeedback,
+    pub path: Vec<String>,
+    pub on_item_click: Option<ItemClickHandler>,
+    pub on_open_change: Option<OpenChangeHandler>,
+}
+
+fn frame_of(bounds: Bounds<Pixels>) -> MenuFrame {
+    MenuFrame {
+        x: f32::from(bounds.origin.x),
+        y: f32::from(bounds.origin.y),
+        width: f32::from(bounds.size.width),
+        height: f32::from(bounds.size.height),
+    }
+}
+
+fn frame_probe(store: impl Fn(MenuFrame) + 'static) -> impl IntoElement {
+    canvas(move |bounds, _, _| store(frame_of(bounds)), |_, _, _, _| {})
+        .absolute()
+        .size_full()
+}
+
+fn is_plain_key(event: &gpui::KeyDownEvent, key: &str) -> bool {
+    event.keystroke.key == key && !event.keystroke.modifiers.modified()
+}
+
+impl MenuPanel<'_> {
+    pub fn render(
+        &self,
+        items: &[MenuItem],
+        depth: usize,
+        window: &Window,
+        cx: &gpui::App,
+    ) -> Stack {
+        let tokens = &self.theme.components.menu;
+        let (rows, roving): (Vec<_>, Vec<_>) = items
+            .iter()
+            .map(|item| self.render_row(item, depth, window, cx))
+            .unzip();
+        let panel = Stack::vertical()
+            .id(self.id.slot_index("panel", depth.to_string()))
+            .p(tokens.dropdown_padding)
+            .gap(tokens.dropdown_gap)
+            .rounded(tokens.dropdown_radius)
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(resolve_hsla(self.theme, tokens.dropdown_border))
+            .bg(resolve_hsla(self.theme, tokens.dropdown_bg))
+            .shadow_sm()
+            .children(rows);
+        let panel = bind_roving_focus(panel, roving, FocusOrientation::Vertical, false);
+        let Some(parent) = depth.checked_sub(1).and_then(|depth| self.path.get(depth)) else {
+            return panel;
+        };
+
+        let menu_id = self.id.clone();
+        let probe_id = self.id.to_string();
+        let parent = self.item_handle(&CalmProvider::focus(cx), parent, cx);
+        let probe = frame_probe(move |frame| {
+            menu_state::set_submenu_frame(&probe_id, depth, frame);
+        });
+        panel
+            .relative()
+            .min_w(tokens.dropdown_min_width)
+            .child(probe)
+            .on_key_down(move |event, window, cx| {
+                if is_plain_key(event, "left") {
+                    menu_state::close_submenu(&menu_id, depth - 1);
+                    window.focus(&parent, cx);
+                    window.refresh();
+                    cx.stop_propagation();
+                }
+            })
+    }
+
+    fn item_handle(
+        &self,
+        focus: &FocusCoordinator,
+        value: &str,
+        cx: &gpui::App,
+    ) -> gpui::FocusHandle {
+        focus.handle(self.id, &format!("item-{value}"), cx)
+    }
+
+    fn render_row(
+        &self,
+        item: &MenuItem,
+        depth: usize,
+        window: &Window,
+        cx: &gpui::App,
+    ) -> (AnyElement, RovingItem) {
+        let tokens = &self.theme.components.menu;
+        let focus = CalmProvider::focus(cx);
+        let icon_fg = resolve_hsla(self.theme, tokens.icon);
+        let row_id = self.id.slot_index("item", item.value.to_string());
+        let focus_handle = self.item_handle(&focus, &item.value, cx);
+        let has_children = !item.children.is_empty();
+        let expanded = has_children
+            && self
+                .path
+                .get(depth)
+                .is_some_and(|open| open.as_str() == item.value.as_ref());
+
+        let icon = item.left_icon.clone().map(|icon| {
+            let icon = Icon::named(icon.to_string());
+            self.id
+                .ctx()
+                .child_index("item-icon", item.value.to_string(), icon)
+                .size(f32::from(tokens.item_icon_size))
+                .color(icon_fg)
+        });
+        let hint = item
+            .shortcut
+            .as_ref()
+            .and_then(|action| CalmProvider::shortcuts(cx).hint(action))
+            .map(|hint| div().flex_none().text_color(icon_fg).child(hint));
+        let chevron = has_children.then(|| {
+            let chevron = Icon::named("chevron-right");
+            self.id
+                .ctx()
+                .child_index("item-chevron", item.value.to_string(), chevron)
+                .size(f32::from(tokens.item_icon_size))
+                .color(icon_fg)
+        });
+        let label_node = div().flex_1().min_w_0().truncate();
+        let label_node = label_node.children(item.label.clone());
+
+        let mut row = div()
+            .id(row_id.clone())
+            .relative()
+            .flex()
+            .items_center()
+            .gap(tokens.item_gap)
+            .px(tokens.item_padding_x)
+            .py(tokens.item_padding_y)
+            .rounded(tokens.item_radius)
+            .text_size(tokens.item_size)
+            .text_color(resolve_hsla(self.theme, tokens.item_fg));
+        row = row.children(icon).child(label_node);
+        row = row.children(hint).children(chevron);
+
+        if item.disabled {
+            row = row
+                .cursor_default()
+                .text_color(resolve_hsla(self.theme, tokens.item_disabled_fg))
+                .hover(|style| style);
+        } else {
+            let hover_bg = tokens
+                .states
+                .hover_bg_or(resolve_hsla(self.theme, tokens.item_hover_bg));
+            let press_bg = tokens
+                .states
+                .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
+            if expanded {
+                row = row.bg(hover_bg);
+            }
+            let menu_id = self.id.clone();
+            let value = item.value.clone();
+            row = row.on_hover(move |hovered, window, _cx| {
+                let changed = match (*hovered, has_children) {
+                    (false, _) => false,
+                    (true, true) => menu_state::open_submenu(&menu_id, depth, &value),
+                    (true, false) => menu_state::close_submenu(&menu_id, depth),
+                };
+                if changed {
+                    window.refresh();
+                }
+            });
+            row = apply_interaction_styles(
+                row.cursor_pointer(),
+                self.press_feedback.interaction_styles(
+                    InteractionStyles::new()
+                        .hover(interaction_style(move |style| style.bg(hover_bg)))
+                        .active(interaction_style(move |style| style.bg(press_bg)))
+                        .focus(interaction_style(move |style| style.bg(hover_bg))),
+                ),
+            );
+            row = self.press_feedback.apply(&ComponentId::from(&row_id), row);
+            let activate = self.activate_handler(item, depth, &focus, cx);
+            row = bind_press_adapter(
+                row,
+                PressAdapter::new(row_id.clone())
+                    .on_activate(Some(activate.clone()))
+                    .focus_handle(Some(focus_handle.clone())),
+            );
+            if has_children {
+                row = row.on_key_down(move |event, window, cx| {
+                    if is_plain_key(event, "right") {
+                        (activate)(window, cx);
+                        cx.stop_propagation();
+                    }
+                });
+            }
+            row = apply_focus_ring(
+                self.theme,
+                row,
+                control::focused_state(&row_id.to_string(), None, false),
+                tokens.item_radius,
+            );
+        }
+
+        if has_children {
+            let probe_id = self.id.to_string();
+            let value = item.value.to_string();
+            row = row.child(frame_probe(move |frame| {
+                menu_state::set_row_frame(&probe_id, &value, frame);
+            }));
+        }
+        if expanded && !item.disabled {
+            row = row.child(self.render_submenu(item, depth, window, cx));
+        }
+
+        let roving = RovingItem {
+            id: row_id.into(),
+            focus_handle,
+            disabled: item.disabled,
+            on_activate: None,
+        };
+        (row.into_any_element(), roving)
+    }
+
+    fn activate_handler(
+        &self,
+        item: &MenuItem,
+        depth: usize,
+        focus: &FocusCoordinator,
+        cx: &gpui::App,
+    ) -> ActivateHandler {
+        let menu_id = self.id.clone();
+        let value = item.value.clone();
+        if !item.children.is_empty() {
+            let first = item
+                .children
+                .iter()
+                .find(|child| !child.disabled)
+                .map(|child| self.item_handle(focus, &child.value, cx));
+            return Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
+                menu_state::open_submenu(&menu_id, depth, &value);
+                if let Some(first) = first.as_ref() {
+                    window.focus(first, cx);
+                }
+                window.refresh();
+            });
+        }
+
+        let on_item_click = self.on_item_click.clone();
+        let on_open_change = self.on_open_change.clone();
+        let controlled = self.controlled;
+        let close_on_item_click = self.close_on_item_click;
+        Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
+            let event = InteractionEvent::new("menu", &menu_id, InteractionAction::Select);
+            interaction::emit(event.value(value.clone()), cx);
+            if let Some(handler) = on_item_click.as_ref() {
+                (handler)(value.clone(), window, cx);
+            }
+
+            if close_on_item_click {
+                if menu_state::on_item_click(&menu_id, controlled, close_on_item_click) {
+                    window.refresh();
+                }
+                if let Some(handler) = on_open_change.as_ref() {
+                    (handler)(false, window, cx);
+                }
+            }
+        })
+    }
+
+    fn render_submenu(
+        &self,
+        item: &MenuItem,
+        depth: usize,
+        window: &Window,
+        cx: &gpui::App,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.menu;
+        let panel = self.render(&item.children, depth + 1, window, cx);
+        let margin = self.theme.components.layout.popup_snap_margin;
+        let width = menu_state::submenu_frame(self.id, depth + 1)
+            .map_or(f32::from(tokens.dropdown_min_width), |frame| frame.width);
+        let viewport_width = f32::from(window.viewport_size().width);
+        let side = menu_state::row_frame(self.id, &item.value).map_or(SubmenuSide::Right, |row| {
+            menu_state::submenu_side(row, width, viewport_width, f32::from(margin))
+        });
+
+        let gap = f32::from(tokens.dropdown_gap);
+        let lift = px(-f32::from(tokens.dropdown_padding));
+        let (host, corner, offset) = match side {
+            SubmenuSide::Right => (div().left(relative(1.0)), Corner::TopLeft, px(gap)),
+            SubmenuSide::Left => (div().right(relative(1.0)), Corner::TopRight, px(-gap)),
+        };
+        let layer = anchored()
+            .anchor(corner)
+            .offset(point(offset, lift))
+            .snap_to_window_with_margin(margin)
+            .child(panel);
+        let layer = deferred(layer).priority(DROPDOWN_PRIORITY + depth + 1);
+        host.absolute()
+            .top_0()
+            .w(px(0.0))
+            .h(px(0.0))
+            .child(layer)
+            .into_any_element()
+    }
+}
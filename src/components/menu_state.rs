@@ -1,3 +1,5 @@
+use crate::overlay::positioning::{self, PositionOptions, Rect, Side};
+
 use super::control;
 use super::popup_state::{self, PopupStateInput, PopupStateValue};
 
@@ -61,3 +63,89 @@ pub fn on_item_click(id: &str, controlled: bool, close_on_item_click: bool) -> b
 pub fn on_close_request(id: &str, controlled: bool) -> bool {
     apply_opened(id, controlled, false)
 }
+
+pub type MenuFrame = Rect;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SubmenuSide {
+    Right,
+    Left,
+}
+
+pub fn submenu_side(
+    anchor: MenuFrame,
+    width: f32,
+    viewport_width: f32,
+    margin: f32,
+) -> SubmenuSide {
+    let viewport = Rect::new(0.0, 0.0, viewport_width, anchor.bottom());
+    let options = PositionOptions::new(Side::Right).margin(margin);
+    match positioning::resolve_side(anchor, width, 0.0, viewport, options) {
+        Side::Left => SubmenuSide::Left,
+        _ => SubmenuSide::Right,
+    }
+}
+
+fn set_frame(id: &str, key: &str, frame: MenuFrame) {
+    control::set_f32_state(id, &format!("{key}-x"), frame.x);
+    control::set_f32_state(id, &format!("{key}-y"), frame.y);
+    control::set_f32_state(id, &format!("{key}-width"), frame.width);
+    control::set_optional_f32_state(id, &format!("{key}-height"), Some(frame.height));
+}
+
+fn frame(id: &str, key: &str) -> Option<MenuFrame> {
+    let height = control::optional_f32_state(id, &format!("{key}-height"), None, None)?;
+    Some(MenuFrame {
+        x: control::f32_state(id, &format!("{key}-x"), None, 0.0),
+        y: control::f32_state(id, &format!("{key}-y"), None, 0.0),
+        width: control::f32_state(id, &format!("{key}-width"), None, 0.0),
+        height,
+    })
+}
+
+pub fn set_row_frame(id: &str, value: &str, frame: MenuFrame) {
+    set_frame(id, &format!("row-{value}"), frame);
+}
+
+pub fn row_frame(id: &str, value: &str) -> Option<MenuFrame> {
+    frame(id, &format!("row-{value}"))
+}
+
+pub fn set_submenu_frame(id: &str, depth: usize, frame: MenuFrame) {
+    set_frame(id, &format!("submenu-{depth}"), frame);
+}
+
+pub fn submenu_frame(id: &str, depth: usize) -> Option<MenuFrame> {
+    frame(id, &format!("submenu-{depth}"))
+}
+
+pub fn submenu_contains(id: &str, x: f32, y: f32) -> bool {
+    (1..=submenu_path(id).len())
+        .filter_map(|depth| submenu_frame(id, depth))
+        .any(|frame| frame.contains(x, y))
+}
+
+pub fn submenu_path(id: &str) -> Vec<String> {
+    control::list_state(id, "submenu-path", None, Vec::new())
+}
+
+pub fn open_submenu(id: &str, depth: usize, value: &str) -> bool {
+    let mut path = submenu_path(id);
+    if path.get(depth).map(String::as_str) == Some(value) && path.len() == depth + 1 {
+        return false;
+    }
+    path.truncate(depth);
+    path.push(value.to_string());
+    control::set_list_state(id, "submenu-path", path);
+    true
+}
+
+pub fn close_submenu(id: &str, depth: usize) -> bool {
+    let mut path = submenu_path(id);
+    if path.len() <= depth {
+        return false;
+    }
+    path.truncate(depth);
+    control::set_list_state(id, "submenu-path", path);
+    true
+}
@@ -0,0 +1,281 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AppContext, Bounds, EmptyView, Hsla, IntoElement, MouseButton, ParentElement, RenderOnce,
+    ScrollHandle, Styled, Window, canvas, div, fill, point, px, size,
+};
+
+use crate::id::ComponentId;
+
+use super::minimap_state;
+use super::utils::{quantized_stroke_px, resolve_hsla};
+
+type JumpHandler = Rc<dyn Fn(f32, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MiniMapMarkerKind {
+    Info,
+    Search,
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MiniMapMarker {
+    pub position: f32,
+    pub span: f32,
+    pub kind: MiniMapMarkerKind,
+}
+
+impl MiniMapMarker {
+    pub fn new(position: f32) -> Self {
+        Self {
+            position: position.clamp(0.0, 1.0),
+            span: 0.0,
+            kind: MiniMapMarkerKind::Info,
+        }
+    }
+
+    pub fn at_row(row: usize, total_rows: usize) -> Self {
+        Self::new(row as f32 / total_rows.max(1) as f32)
+    }
+
+    pub fn span(mut self, value: f32) -> Self {
+        self.span = value.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn kind(mut self, value: MiniMapMarkerKind) -> Self {
+        self.kind = value;
+        self
+    }
+}
+
+#[derive(Clone)]
+struct MiniMapDragState {
+    minimap_id: String,
+}
+
+#[derive(IntoElement)]
+pub struct MiniMap {
+    pub(crate) id: ComponentId,
+    scroll_handle: Option<ScrollHandle>,
+    height_px: Option<f32>,
+    rows: Vec<f32>,
+    markers: Vec<MiniMapMarker>,
+    on_jump: Option<JumpHandler>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl MiniMap {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            scroll_handle: None,
+            height_px: None,
+            rows: Vec::new(),
+            markers: Vec::new(),
+            on_jump: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    pub fn scroll_handle(mut self, handle: ScrollHandle) -> Self {
+        self.scroll_handle = Some(handle);
+        self
+    }
+
+    pub fn height(mut self, value: f32) -> Self {
+        self.height_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn rows(mut self, rows: impl IntoIterator<Item = f32>) -> Self {
+        self.rows = rows
+            .into_iter()
+            .map(|value| value.clamp(0.0, 1.0))
+            .collect();
+        self
+    }
+
+    pub fn marker(mut self, marker: MiniMapMarker) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
+    pub fn markers(mut self, markers: impl IntoIterator<Item = MiniMapMarker>) -> Self {
+        self.markers.extend(markers);
+        self
+    }
+
+    pub fn on_jump(mut self, handler: impl Fn(f32, &mut Window, &mut gpui::App) + 'static) -> Self {
+        self.on_jump = Some(Rc::new(handler));
+        self
+    }
+
+    fn jump(
+        handle: Option<&ScrollHandle>,
+        on_jump: Option<&JumpHandler>,
+        ratio: f32,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        if let Some(handle) = handle {
+            let viewport_len = f32::from(handle.bounds().size.height);
+            let max_scroll = f32::from(handle.max_offset().height);
+            let next = minimap_state::scroll_for_ratio(ratio, viewport_len, max_scroll);
+            handle.set_offset(point(handle.offset().x, px(-next)));
+            window.refresh();
+        }
+        if let Some(handler) = on_jump {
+            (handler)(ratio, window, cx);
+        }
+    }
+}
+
+impl RenderOnce for MiniMap {
+    fn render(mut self, window: &mut Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = &self.theme.components.scroll_area;
+        let row_color = resolve_hsla(&self.theme, tokens.minimap_row);
+        let marker_colors: [Hsla; 4] = [
+            resolve_hsla(&self.theme, tokens.minimap_marker_info),
+            resolve_hsla(&self.theme, tokens.minimap_marker_search),
+            resolve_hsla(&self.theme, tokens.minimap_marker_warning),
+            resolve_hsla(&self.theme, tokens.minimap_marker_error),
+        ];
+
+        let window_geometry = self.scroll_handle.as_ref().map(|handle| {
+            minimap_state::viewport_window(
+                -f32::from(handle.offset().y),
+                f32::from(handle.bounds().size.height),
+                f32::from(handle.max_offset().height),
+            )
+        });
+
+        let rows = self.rows;
+        let markers = self.markers;
+        let layer = canvas(
+            {
+                let id = self.id.clone();
+                let handle = self.scroll_handle.clone();
+                move |bounds, window, _| {
+                    minimap_state::store_strip(
+                        &id,
+                        f32::from(bounds.origin.y),
+                        f32::from(bounds.size.height),
+                    );
+                    if let Some(handle) = handle.as_ref()
+                        && minimap_state::sync_scroll(&id, -f32::from(handle.offset().y))
+                    {
+                        window.refresh();
+                    }
+                }
+            },
+            move |bounds, _, window, _| {
+                let width = f32::from(bounds.size.width);
+                let height = f32::from(bounds.size.height);
+                if !rows.is_empty() {
+                    let row_len = height / rows.len() as f32;
+                    let bar_len = (row_len * 0.6).max(1.0);
+                    for (index, value) in rows.iter().enumerate() {
+                        if *value <= 0.0 {
+                            continue;
+                        }
+                        let top = index as f32 * row_len;
+                        window.paint_quad(fill(
+                            Bounds::new(
+                                point(bounds.origin.x, bounds.origin.y + px(top)),
+                                size(px(width * value), px(bar_len)),
+                            ),
+                            row_color,
+                        ));
+                    }
+                }
+
+                for marker in &markers {
+                    let top = marker.position * height;
+                    let len = (marker.span * height).max(2.0);
+                    let color = match marker.kind {
+                        MiniMapMarkerKind::Info => marker_colors[0],
+                        MiniMapMarkerKind::Search => marker_colors[1],
+                        MiniMapMarkerKind::Warning => marker_colors[2],
+                        MiniMapMarkerKind::Error => marker_colors[3],
+                    };
+                    window.paint_quad(fill(
+                        Bounds::new(
+                            point(bounds.origin.x, bounds.origin.y + px(top.min(height - len))),
+                            size(px(width), px(len)),
+                        ),
+                        color,
+                    ));
+                }
+            },
+        )
+        .absolute()
+        .size_full();
+
+        let mut strip = div()
+            .id(self.id.clone())
+            .relative()
+            .flex_none()
+            .w(tokens.minimap_width)
+            .overflow_hidden()
+            .cursor_pointer()
+            .bg(resolve_hsla(&self.theme, tokens.minimap_bg))
+            .child(layer);
+
+        strip = match self.height_px {
+            Some(height) => strip.h(px(height)),
+            None => strip.h_full(),
+        };
+
+        if let Some(geometry) = window_geometry {
+            strip = strip.child(
+                div()
+                    .absolute()
+                    .left_0()
+                    .w_full()
+                    .top(gpui::relative(geometry.top))
+                    .h(gpui::relative(geometry.height))
+                    .bg(resolve_hsla(&self.theme, tokens.minimap_viewport_bg))
+                    .border_t(quantized_stroke_px(window, 1.0))
+                    .border_b(quantized_stroke_px(window, 1.0))
+                    .border_color(resolve_hsla(&self.theme, tokens.minimap_viewport_border)),
+            );
+        }
+
+        let minimap_id = self.id.to_string();
+        let handle = self.scroll_handle;
+        let on_jump = self.on_jump;
+        strip
+            .on_mouse_down(MouseButton::Left, {
+                let minimap_id = minimap_id.clone();
+                let handle = handle.clone();
+                let on_jump = on_jump.clone();
+                move |event, window, cx| {
+                    let ratio =
+                        minimap_state::strip_ratio(&minimap_id, f32::from(event.position.y));
+                    Self::jump(handle.as_ref(), on_jump.as_ref(), ratio, window, cx);
+                }
+            })
+            .on_drag(
+                MiniMapDragState {
+                    minimap_id: minimap_id.clone(),
+                },
+                |_drag, _, _, cx| cx.new(|_| EmptyView),
+            )
+            .on_drag_move::<MiniMapDragState>(move |event, window, cx| {
+                if event.drag(cx).minimap_id != minimap_id {
+                    return;
+                }
+                let local_y = f32::from(event.event.position.y) - f32::from(event.bounds.origin.y);
+                let ratio =
+                    minimap_state::ratio_from_local(local_y, f32::from(event.bounds.size.height));
+                Self::jump(handle.as_ref(), on_jump.as_ref(), ratio, window, cx);
+            })
+    }
+}
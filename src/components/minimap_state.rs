@@ -0,0 +1,47 @@
+use super::control;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewportWindow {
+    pub top: f32,
+    pub height: f32,
+}
+
+pub fn viewport_window(scroll_y: f32, viewport_len: f32, max_scroll: f32) -> ViewportWindow {
+    let viewport_len = viewport_len.max(1.0);
+    let max_scroll = max_scroll.max(0.0);
+    let content_len = viewport_len + max_scroll;
+    let height = (viewport_len / content_len).clamp(0.0, 1.0);
+    let top = (scroll_y.clamp(0.0, max_scroll) / content_len).clamp(0.0, 1.0 - height);
+    ViewportWindow { top, height }
+}
+
+pub fn scroll_for_ratio(ratio: f32, viewport_len: f32, max_scroll: f32) -> f32 {
+    let viewport_len = viewport_len.max(1.0);
+    let max_scroll = max_scroll.max(0.0);
+    let content_len = viewport_len + max_scroll;
+    (ratio.clamp(0.0, 1.0) * content_len - viewport_len * 0.5).clamp(0.0, max_scroll)
+}
+
+pub fn ratio_from_local(local: f32, strip_len: f32) -> f32 {
+    (local / strip_len.max(1.0)).clamp(0.0, 1.0)
+}
+
+pub fn store_strip(id: &str, origin_y: f32, height: f32) {
+    control::set_f32_state(id, "strip-origin-y", origin_y);
+    control::set_f32_state(id, "strip-height", height.max(1.0));
+}
+
+pub fn strip_ratio(id: &str, pointer_y: f32) -> f32 {
+    let origin_y = control::f32_state(id, "strip-origin-y", None, 0.0);
+    let height = control::f32_state(id, "strip-height", None, 1.0);
+    ratio_from_local(pointer_y - origin_y, height)
+}
+
+pub fn sync_scroll(id: &str, scroll_y: f32) -> bool {
+    let previous = control::optional_f32_state(id, "scroll-y", None, None);
+    if previous.is_some_and(|value| (value - scroll_y).abs() < 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, "scroll-y", Some(scroll_y));
+    previous.is_some()
+}
@@ -1,283 +1,776 @@
 mod accordion;
 mod action_icon;
 mod alert;
+mod announcement_bar;
 mod app_shell;
+mod app_shell_layout;
+mod async_content;
+#[cfg(feature = "forms")]
+mod auto_form;
 mod badge;
+#[cfg(feature = "charts")]
+mod bar_chart;
+#[cfg(feature = "overlays")]
+mod bottom_sheet;
+#[cfg(feature = "overlays")]
+mod bottom_sheet_state;
 mod breadcrumbs;
+mod breadcrumbs_state;
 mod button;
+mod calendar;
+mod calendar_state;
+#[cfg(feature = "charts")]
+mod chart;
+#[cfg(feature = "charts")]
+mod chart_state;
+#[cfg(feature = "markdown")]
+mod chat_view;
+#[cfg(feature = "markdown")]
+mod chat_view_state;
+#[cfg(feature = "forms")]
 mod checkbox;
+mod checklist;
+mod checklist_state;
+#[cfg(feature = "forms")]
 mod chip;
+#[cfg(feature = "forms")]
+mod color_picker;
+#[cfg(feature = "forms")]
+mod color_picker_state;
+#[cfg(feature = "forms")]
+mod combobox;
+#[cfg(feature = "forms")]
+mod combobox_state;
+mod compare_slider;
+mod compare_slider_state;
+#[cfg(feature = "forms")]
+mod composition;
+#[cfg(feature = "forms")]
+mod console_input;
+#[cfg(feature = "forms")]
+mod console_state;
+#[cfg(feature = "overlays")]
+mod context_menu;
+#[cfg(feature = "overlays")]
+mod context_menu_state;
 mod control;
+mod copy_button;
+#[cfg(feature = "forms")]
+mod date_picker;
+mod date_picker_state;
 mod divider;
+#[cfg(feature = "overlays")]
+mod dock_tabs;
+#[cfg(feature = "overlays")]
+mod dock_tabs_state;
+#[cfg(feature = "charts")]
+mod donut_chart;
+#[cfg(feature = "overlays")]
 mod drawer;
+#[cfg(feature = "overlays")]
+mod drawer_state;
+#[cfg(feature = "forms")]
+mod editable_text;
+#[cfg(feature = "forms")]
+mod editable_text_state;
+mod empty_state;
+#[cfg(feature = "forms")]
 mod field_variant;
+#[cfg(feature = "forms")]
+mod file_input;
+#[cfg(feature = "forms")]
+mod file_input_state;
+#[cfg(feature = "forms")]
+mod find_bar;
+#[cfg(feature = "charts")]
+mod graph_canvas;
+#[cfg(feature = "charts")]
+mod graph_canvas_state;
+#[cfg(feature = "charts")]
+mod graph_model;
+mod history_timeline;
+mod history_timeline_state;
+#[cfg(feature = "overlays")]
 mod hovercard;
 mod icon;
+mod image;
+mod image_state;
 mod indicator;
+#[cfg(feature = "forms")]
 mod input;
+#[cfg(feature = "forms")]
+mod input_mask;
 mod interaction_adapter;
+#[cfg(feature = "json")]
+mod json_tree;
+#[cfg(feature = "json")]
+mod json_tree_state;
+mod kanban_board;
+mod kanban_board_state;
 mod layers;
 mod layout;
+#[cfg(feature = "charts")]
+mod line_chart;
 mod loader;
+#[cfg(feature = "overlays")]
 mod loading_overlay;
+mod log_buffer;
+mod log_view;
+mod log_view_state;
+#[cfg(feature = "markdown")]
 mod markdown;
+#[cfg(feature = "markdown")]
+mod markdown_stream;
+#[cfg(feature = "overlays")]
 mod menu;
+#[cfg(feature = "overlays")]
+mod menu_panel;
+#[cfg(feature = "overlays")]
 mod menu_state;
+mod minimap;
+mod minimap_state;
 mod modal;
+mod notification_center;
+#[cfg(feature = "forms")]
 mod number_input;
+#[cfg(feature = "forms")]
+mod number_input_state;
 mod overlay;
 mod pagination;
 mod paper;
+#[cfg(feature = "forms")]
+mod password_strength;
+mod paste_attachment;
+#[cfg(feature = "forms")]
+mod pin_input_state;
+#[cfg(feature = "overlays")]
 mod popover;
 mod popup;
 mod popup_state;
+mod press_feedback;
 mod progress;
+#[cfg(feature = "forms")]
 mod radio;
+#[cfg(feature = "forms")]
 mod range_slider;
+#[cfg(feature = "forms")]
 mod rating;
+#[cfg(feature = "forms")]
+mod rating_state;
+mod ring_progress;
+mod ring_progress_state;
+mod rulers;
+mod rulers_state;
 mod scroll_area;
+#[cfg(feature = "forms")]
 mod segmented_control;
+#[cfg(feature = "forms")]
 mod select;
+#[cfg(feature = "forms")]
 mod select_state;
 mod selection_state;
+#[cfg(feature = "forms")]
+mod settings_view;
+#[cfg(feature = "forms")]
+mod settings_view_state;
+#[cfg(feature = "forms")]
 mod slider;
+#[cfg(feature = "forms")]
 mod slider_axis;
+#[cfg(feature = "charts")]
+mod sparkline;
+#[cfg(feature = "spellcheck")]
+mod spell_underline;
+mod split_pane;
+mod split_pane_state;
+mod stat_card;
 mod stepper;
+#[cfg(feature = "forms")]
 mod switch;
+#[cfg(feature = "tables")]
 mod table;
+#[cfg(feature = "tables")]
 mod table_state;
 mod tabs;
+#[cfg(feature = "forms")]
+mod tags_input;
+#[cfg(feature = "forms")]
+mod tags_input_state;
+#[cfg(feature = "overlays")]
+mod task_status;
 mod text;
+#[cfg(feature = "forms")]
 mod text_input_actions;
+#[cfg(feature = "forms")]
 mod text_input_state;
+mod text_selection;
+#[cfg(feature = "forms")]
 mod textarea;
+#[cfg(feature = "forms")]
+mod time_picker;
+#[cfg(feature = "forms")]
+mod time_picker_state;
 mod timeline;
 mod title;
 mod title_bar;
+#[cfg(feature = "forms")]
 mod toggle;
+#[cfg(feature = "overlays")]
 mod tooltip;
 mod transition;
 mod tree;
 mod tree_state;
 mod utils;
+mod wizard;
+mod wizard_state;
+mod zoom_pane;
+mod zoom_pane_state;
 
-#[cfg(test)]
+#[cfg(all(
+    test,
+    feature = "forms",
+    feature = "tables",
+    feature = "charts",
+    feature = "overlays",
+    feature = "markdown"
+))]
 #[path = "test_state_logic.rs"]
 mod test_state_logic;
 
 pub use accordion::{Accordion, AccordionItem, AccordionItemMeta};
 pub use action_icon::ActionIcon;
 pub use alert::{Alert, AlertKind};
+pub use announcement_bar::{AnnouncementBar, AnnouncementBarPosition};
 pub use app_shell::{AppShell, PaneChrome, PanelMode, Sidebar, SidebarMode};
+pub use app_shell_layout::{
+    APP_SHELL_KEY_CONTEXT, AppShellLayout, AppShellPanel, ToggleBottomPanel, ToggleInspector,
+    ToggleSidebar,
+};
+pub use async_content::AsyncContent;
+#[cfg(feature = "forms")]
+pub use auto_form::AutoForm;
 pub use badge::Badge;
+#[cfg(feature = "charts")]
+pub use bar_chart::BarChart;
+#[cfg(feature = "overlays")]
+pub use bottom_sheet::BottomSheet;
 pub use breadcrumbs::{BreadcrumbItem, Breadcrumbs};
 pub use button::{Button, ButtonGroup, ButtonGroupItem};
+pub use calendar::{Calendar, CalendarEvent, CalendarEventSource, CalendarView, DateRange};
+#[cfg(feature = "charts")]
+pub use chart::{ChartDatum, ChartSeries};
+#[cfg(feature = "markdown")]
+pub use chat_view::{ChatMessage, ChatView};
+#[cfg(feature = "forms")]
 pub use checkbox::{Checkbox, CheckboxGroup, CheckboxOption};
+pub use checklist::{Checklist, ChecklistStatus, ChecklistStep};
+#[cfg(feature = "forms")]
 pub use chip::{Chip, ChipGroup, ChipOption, ChipSelectionMode};
+#[cfg(feature = "forms")]
+pub use color_picker::ColorPicker;
+#[cfg(feature = "forms")]
+pub use combobox::Combobox;
+pub use compare_slider::CompareSlider;
+#[cfg(feature = "forms")]
+pub use console_input::ConsoleInput;
+#[cfg(feature = "overlays")]
+pub use context_menu::{ContextMenu, ContextMenuItem};
+pub use copy_button::CopyButton;
+#[cfg(feature = "forms")]
+pub use date_picker::DatePicker;
+pub use date_picker_state::CalendarDate;
 pub use divider::{Divider, DividerLabelPosition};
+#[cfg(feature = "overlays")]
+pub use dock_tabs::{DockTab, DockTabs};
+#[cfg(feature = "charts")]
+pub use donut_chart::DonutChart;
+#[cfg(feature = "overlays")]
 pub use drawer::{Drawer, DrawerPlacement};
+#[cfg(feature = "forms")]
+pub use editable_text::{EditTrigger, EditableText};
+pub use empty_state::EmptyState;
+#[cfg(feature = "forms")]
+pub use file_input::FileInput;
+#[cfg(feature = "forms")]
+pub use find_bar::FindBar;
+#[cfg(feature = "charts")]
+pub use graph_canvas::{
+    GraphCanvas, GraphConnectError, GraphEdge, GraphModel, GraphNode, GraphPort, GraphPortRef,
+};
+pub use history_timeline::{HistoryStep, HistoryTimeline};
+#[cfg(feature = "overlays")]
 pub use hovercard::{HoverCard, HoverCardPlacement};
 pub use icon::Icon;
+pub use image::{Image, ImageFit, ImageSrc};
 pub use indicator::{Indicator, IndicatorPosition};
+#[cfg(feature = "forms")]
 pub use input::{PasswordInput, PinInput, TextInput};
+#[cfg(feature = "forms")]
+pub use input_mask::InputMask;
+#[cfg(feature = "json")]
+pub use json_tree::JsonTree;
+pub use kanban_board::{KanbanBoard, KanbanColumn, KanbanMove, KanbanSource};
 pub use layers::{ModalLayer, ToastLayer};
 pub use layout::{Grid, SimpleGrid, Space, Stack};
+#[cfg(feature = "charts")]
+pub use line_chart::LineChart;
 pub use loader::{Loader, LoaderElement, LoaderVariant};
+#[cfg(feature = "overlays")]
 pub use loading_overlay::LoadingOverlay;
-pub use markdown::{Markdown, MarkdownLinkClick};
+pub use log_buffer::{DEFAULT_MAX_LINES, LogBuffer, LogLevel};
+pub use log_view::LogView;
+#[cfg(feature = "markdown")]
+pub use markdown::{Markdown, MarkdownLinkClick, MarkdownTaskToggle};
+#[cfg(feature = "overlays")]
 pub use menu::{Menu, MenuItem};
+pub use minimap::{MiniMap, MiniMapMarker, MiniMapMarkerKind};
 pub use modal::Modal;
+pub use notification_center::NotificationCenter;
+#[cfg(feature = "forms")]
 pub use number_input::NumberInput;
 pub use overlay::{Overlay, OverlayCoverage, OverlayMaterialMode};
 pub use pagination::Pagination;
 pub use paper::Paper;
+#[cfg(feature = "forms")]
+pub use password_strength::PasswordStrength;
+pub use paste_attachment::PasteAttachment;
+#[cfg(feature = "overlays")]
 pub use popover::{Popover, PopoverPlacement};
 pub use progress::{Progress, ProgressSection};
+#[cfg(feature = "forms")]
 pub use radio::{Radio, RadioGroup, RadioOption};
+#[cfg(feature = "forms")]
 pub use range_slider::RangeSlider;
-pub use rating::Rating;
+#[cfg(feature = "forms")]
+pub use rating::{Rating, RatingSymbol};
+pub use ring_progress::RingProgress;
+pub use rulers::{GuideAxis, GuideSnap, RulerGuide, Rulers};
 pub use scroll_area::{ScrollArea, ScrollDirection};
+#[cfg(feature = "forms")]
 pub use segmented_control::{SegmentedControl, SegmentedControlItem};
+#[cfg(feature = "forms")]
 pub use select::{MultiSelect, Select, SelectOption};
-pub use slider::Slider;
+#[cfg(feature = "forms")]
+pub use settings_view::{SettingsItem, SettingsSchema, SettingsSection, SettingsView};
+#[cfg(feature = "forms")]
+pub use slider::{Slider, SliderMark};
+#[cfg(feature = "charts")]
+pub use sparkline::Sparkline;
+pub use split_pane::SplitPane;
+pub use stat_card::StatCard;
 pub use stepper::{Stepper, StepperContentPosition, StepperStep};
+#[cfg(feature = "forms")]
 pub use switch::{Switch, SwitchLabelPosition};
+#[cfg(feature = "tables")]
 pub use table::{
-    Table, TableAlign, TableCell, TablePaginationPosition, TableRow, TableSort, TableSortDirection,
+    Table, TableAlign, TableCell, TableLayoutState, TablePaginationPosition, TableRow,
+    TableSelectionChanged, TableSelectionMode, TableSort, TableSortDirection,
 };
 pub use tabs::{TabItem, Tabs};
+#[cfg(feature = "forms")]
+pub use tags_input::TagsInput;
+#[cfg(feature = "overlays")]
+pub use task_status::TaskStatusItem;
 pub use text::{Text, TextTone};
+pub use text_selection::{FindMatch, FindScope};
+#[cfg(feature = "forms")]
 pub use textarea::Textarea;
+#[cfg(feature = "forms")]
+pub use time_picker::{CalendarDateTime, ClockTime, DateTimePicker, TimeColumn, TimePicker};
 pub use timeline::{Timeline, TimelineItem};
 pub use title::Title;
 pub use title_bar::TitleBar;
+#[cfg(feature = "overlays")]
 pub use tooltip::{Tooltip, TooltipPlacement};
 pub use transition::{TransitionExt, TransitionStage};
-pub use tree::{Tree, TreeNode, TreeTogglePosition};
+pub use tree::{Tree, TreeDropEvent, TreeDropPosition, TreeNode, TreeTogglePosition};
+pub use wizard::{Wizard, WizardStep};
+pub use zoom_pane::{ZoomPane, ZoomPaneHandle, ZoomView};
 
 crate::impl_with_id_for_field!(Accordion, id);
 crate::impl_with_id_for_field!(ActionIcon, id);
 crate::impl_with_id_for_field!(Alert, id);
+crate::impl_with_id_for_field!(AnnouncementBar, id);
 crate::impl_with_id_for_field!(AppShell, id);
 crate::impl_with_id_for_field!(Badge, id);
+#[cfg(feature = "charts")]
+crate::impl_with_id_for_field!(BarChart, id);
+#[cfg(feature = "overlays")]
+crate::impl_with_id_for_field!(BottomSheet, id);
 crate::impl_with_id_for_field!(Breadcrumbs, id);
 crate::impl_with_id_for_field!(Button, id);
+crate::impl_with_id_for_field!(Calendar, id);
 crate::impl_with_id_for_field!(ButtonGroup, id);
+#[cfg(feature = "markdown")]
+crate::impl_with_id_for_field!(ChatView, id);
+crate::impl_with_id_for_field!(Checklist, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(Checkbox, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(CheckboxGroup, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(Chip, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(ChipGroup, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(ColorPicker, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(Combobox, id);
+crate::impl_with_id_for_field!(CompareSlider, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(ConsoleInput, id);
+crate::impl_with_id_for_field!(CopyButton, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(DatePicker, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(DateTimePicker, id);
 crate::impl_with_id_for_field!(Divider, id);
+#[cfg(feature = "charts")]
+crate::impl_with_id_for_field!(DonutChart, id);
+#[cfg(feature = "overlays")]
+crate::impl_with_id_for_field!(DockTabs, id);
+#[cfg(feature = "overlays")]
 crate::impl_with_id_for_field!(Drawer, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(EditableText, id);
+crate::impl_with_id_for_field!(EmptyState, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(FileInput, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(FindBar, id);
+#[cfg(feature = "charts")]
+crate::impl_with_id_for_field!(GraphCanvas, id);
 crate::impl_with_id_for_field!(Grid, id);
+crate::impl_with_id_for_field!(HistoryTimeline, id);
+#[cfg(feature = "overlays")]
 crate::impl_with_id_for_field!(HoverCard, id);
+#[cfg(feature = "overlays")]
+crate::impl_with_id_for_field!(ContextMenu, id);
 crate::impl_with_id_for_field!(Icon, id);
+crate::impl_with_id_for_field!(Image, id);
 crate::impl_with_id_for_field!(Indicator, id);
+#[cfg(feature = "json")]
+crate::impl_with_id_for_field!(JsonTree, id);
+crate::impl_with_id_for_field!(KanbanBoard, id);
+#[cfg(feature = "overlays")]
 crate::impl_with_id_for_field!(LoadingOverlay, id);
+#[cfg(feature = "charts")]
+crate::impl_with_id_for_field!(LineChart, id);
 crate::impl_with_id_for_field!(Loader, id);
+crate::impl_with_id_for_field!(LogView, id);
+#[cfg(feature = "markdown")]
 crate::impl_with_id_for_field!(Markdown, id);
+#[cfg(feature = "overlays")]
 crate::impl_with_id_for_field!(Menu, id);
+crate::impl_with_id_for_field!(MiniMap, id);
 crate::impl_with_id_for_field!(Modal, id);
 crate::impl_with_id_for_field!(ModalLayer, id);
+crate::impl_with_id_for_field!(NotificationCenter, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(MultiSelect, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(NumberInput, id);
 crate::impl_with_id_for_field!(Overlay, id);
 crate::impl_with_id_for_field!(Pagination, id);
 crate::impl_with_id_for_field!(Paper, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(PasswordInput, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(PinInput, id);
+#[cfg(feature = "overlays")]
 crate::impl_with_id_for_field!(Popover, id);
 crate::impl_with_id_for_field!(Progress, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(Radio, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(RadioGroup, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(RangeSlider, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(Rating, id);
+crate::impl_with_id_for_field!(RingProgress, id);
+crate::impl_with_id_for_field!(Rulers, id);
 crate::impl_with_id_for_field!(ScrollArea, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(SegmentedControl, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(Select, id);
 crate::impl_with_id_for_field!(Sidebar, id);
 crate::impl_with_id_for_field!(SimpleGrid, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(Slider, id);
 crate::impl_with_id_for_field!(Space, id);
+#[cfg(feature = "charts")]
+crate::impl_with_id_for_field!(Sparkline, id);
+crate::impl_with_id_for_field!(SplitPane, id);
+crate::impl_with_id_for_field!(StatCard, id);
 crate::impl_with_id_for_field!(Stepper, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(Switch, id);
+#[cfg(feature = "tables")]
 crate::impl_with_id_for_field!(Table, id);
 crate::impl_with_id_for_field!(Tabs, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(TagsInput, id);
+#[cfg(feature = "overlays")]
+crate::impl_with_id_for_field!(TaskStatusItem, id);
 crate::impl_with_id_for_field!(Text, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(TextInput, id);
+#[cfg(feature = "forms")]
 crate::impl_with_id_for_field!(Textarea, id);
+#[cfg(feature = "forms")]
+crate::impl_with_id_for_field!(TimePicker, id);
 crate::impl_with_id_for_field!(Timeline, id);
 crate::impl_with_id_for_field!(Title, id);
 crate::impl_with_id_for_field!(TitleBar, id);
 crate::impl_with_id_for_field!(ToastLayer, id);
+#[cfg(feature = "overlays")]
 crate::impl_with_id_for_field!(Tooltip, id);
 crate::impl_with_id_for_field!(Tree, id);
+crate::impl_with_id_for_field!(Wizard, id);
+crate::impl_with_id_for_field!(ZoomPane, id);
 
 crate::impl_default_via_new!(
     Accordion,
     ActionIcon,
     Alert,
+    AnnouncementBar,
     Badge,
     Breadcrumbs,
     Button,
+    Calendar,
     ButtonGroup,
-    Checkbox,
-    CheckboxGroup,
-    Chip,
-    ChipGroup,
-    Drawer,
+    Checklist,
+    CompareSlider,
+    CopyButton,
+    EmptyState,
     Grid,
-    HoverCard,
+    HistoryTimeline,
+    Image,
     Indicator,
+    KanbanBoard,
     Loader,
-    LoadingOverlay,
-    Menu,
+    LogView,
+    MiniMap,
     Modal,
-    MultiSelect,
-    NumberInput,
+    NotificationCenter,
     Overlay,
     Pagination,
     Paper,
-    PasswordInput,
-    Popover,
     Progress,
+    RingProgress,
+    Rulers,
+    ScrollArea,
+    Sidebar,
+    SimpleGrid,
+    Space,
+    StatCard,
+    Stepper,
+    Tabs,
+    Timeline,
+    TitleBar,
+    Tree,
+    Wizard,
+    ZoomPane,
+);
+
+#[cfg(feature = "forms")]
+crate::impl_default_via_new!(
+    Checkbox,
+    CheckboxGroup,
+    Chip,
+    ChipGroup,
+    ColorPicker,
+    Combobox,
+    ConsoleInput,
+    DatePicker,
+    DateTimePicker,
+    EditableText,
+    FileInput,
+    MultiSelect,
+    NumberInput,
+    PasswordInput,
     Radio,
     RadioGroup,
     RangeSlider,
     Rating,
-    ScrollArea,
     SegmentedControl,
     Select,
-    Sidebar,
-    SimpleGrid,
     Slider,
-    Space,
-    Stepper,
     Switch,
-    Table,
-    Tabs,
+    TagsInput,
     TextInput,
     Textarea,
-    Timeline,
-    TitleBar,
-    Tooltip,
-    Tree
+    TimePicker
+);
+
+#[cfg(feature = "tables")]
+crate::impl_default_via_new!(Table);
+
+#[cfg(feature = "charts")]
+crate::impl_default_via_new!(BarChart, DonutChart, GraphCanvas, LineChart, Sparkline);
+
+#[cfg(feature = "markdown")]
+crate::impl_default_via_new!(ChatView);
+
+#[cfg(feature = "json")]
+crate::impl_default_via_new!(JsonTree);
+
+#[cfg(feature = "overlays")]
+crate::impl_default_via_new!(
+    BottomSheet,
+    ContextMenu,
+    DockTabs,
+    Drawer,
+    HoverCard,
+    LoadingOverlay,
+    Menu,
+    Popover,
+    TaskStatusItem,
+    Tooltip
 );
 
 crate::impl_component_theme_overridable!(Accordion, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(ActionIcon, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Alert, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(AnnouncementBar, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(AppShell, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Badge, |this| &mut this.theme);
+#[cfg(feature = "charts")]
+crate::impl_component_theme_overridable!(BarChart, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
+crate::impl_component_theme_overridable!(BottomSheet, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Breadcrumbs, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Button, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(Calendar, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(ButtonGroup, |this| &mut this.theme);
+#[cfg(feature = "markdown")]
+crate::impl_component_theme_overridable!(ChatView, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(Checklist, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(Checkbox, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(CheckboxGroup, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(Chip, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(ChipGroup, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(ColorPicker, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(Combobox, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(CompareSlider, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(ConsoleInput, |this| &mut this.inner.theme);
+crate::impl_component_theme_overridable!(CopyButton, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(DatePicker, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(DateTimePicker, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Divider, |this| &mut this.theme);
+#[cfg(feature = "charts")]
+crate::impl_component_theme_overridable!(DonutChart, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
+crate::impl_component_theme_overridable!(DockTabs, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
 crate::impl_component_theme_overridable!(Drawer, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(EditableText, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(EmptyState, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(FileInput, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(FindBar, |this| &mut this.theme);
+#[cfg(feature = "charts")]
+crate::impl_component_theme_overridable!(GraphCanvas, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Grid, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(HistoryTimeline, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
 crate::impl_component_theme_overridable!(HoverCard, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Icon, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(Image, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Indicator, |this| &mut this.theme);
+#[cfg(feature = "json")]
+crate::impl_component_theme_overridable!(JsonTree, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(KanbanBoard, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
 crate::impl_component_theme_overridable!(LoadingOverlay, |this| &mut this.theme);
+#[cfg(feature = "charts")]
+crate::impl_component_theme_overridable!(LineChart, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Loader, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(LogView, |this| &mut this.theme);
+#[cfg(feature = "markdown")]
 crate::impl_component_theme_overridable!(Markdown, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
 crate::impl_component_theme_overridable!(Menu, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
+crate::impl_component_theme_overridable!(ContextMenu, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(MiniMap, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Modal, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(ModalLayer, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(NotificationCenter, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(MultiSelect, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(NumberInput, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Overlay, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Pagination, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Paper, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(PasswordInput, |this| &mut this.inner.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(PinInput, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
 crate::impl_component_theme_overridable!(Popover, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Progress, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(Radio, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(RadioGroup, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(RangeSlider, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(Rating, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(RingProgress, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(Rulers, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(ScrollArea, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(SegmentedControl, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(Select, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Sidebar, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(SimpleGrid, |this| this.inner.local_theme_mut());
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(Slider, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Space, |this| &mut this.theme);
+#[cfg(feature = "charts")]
+crate::impl_component_theme_overridable!(Sparkline, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(SplitPane, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(StatCard, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Stepper, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(Switch, |this| &mut this.theme);
+#[cfg(feature = "tables")]
 crate::impl_component_theme_overridable!(Table, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Tabs, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(TagsInput, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
+crate::impl_component_theme_overridable!(TaskStatusItem, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Text, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(TextInput, |this| &mut this.theme);
+#[cfg(feature = "forms")]
 crate::impl_component_theme_overridable!(Textarea, |this| &mut this.theme);
+#[cfg(feature = "forms")]
+crate::impl_component_theme_overridable!(TimePicker, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Timeline, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Title, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(TitleBar, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(ToastLayer, |this| &mut this.theme);
+#[cfg(feature = "overlays")]
 crate::impl_component_theme_overridable!(Tooltip, |this| &mut this.theme);
 crate::impl_component_theme_overridable!(Tree, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(Wizard, |this| &mut this.theme);
+crate::impl_component_theme_overridable!(ZoomPane, |this| &mut this.theme);
@@ -10,6 +10,7 @@ use gpui::{
 
 use crate::contracts::{MotionAware, Varianted};
 use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
 use crate::motion::MotionConfig;
 use crate::overlay::{ModalCloseReason, ModalKind, ModalStateChange};
 use crate::style::Variant;
@@ -19,7 +20,7 @@ use super::control;
 use super::icon::Icon;
 use super::overlay::{Overlay, OverlayCoverage, OverlayMaterialMode};
 use super::popup_state::{self, PopupStateInput, PopupStateValue};
-use super::utils::resolve_hsla;
+use super::utils::{hit_area, hit_target_inset, resolve_hsla};
 
 type SlotRenderer = Arc<dyn Fn() -> AnyElement>;
 type OpenHandler = Arc<dyn Fn()>;
@@ -115,7 +116,7 @@ impl Modal {
         self
     }
 
-    fn with_kind(mut self, value: ModalKind) -> Self {
+    pub(crate) fn with_kind(mut self, value: ModalKind) -> Self {
         self.kind = value;
         self
     }
@@ -316,6 +317,13 @@ impl Modal {
         }
     }
 
+    fn dismiss(id: &ComponentId, window: &mut Window, cx: &mut gpui::App) {
+        if crate::provider::CalmProvider::focus(cx).release(id, window, cx) {
+            let event = InteractionEvent::new("modal", id, InteractionAction::Close);
+            interaction::emit(event, cx);
+        }
+    }
+
     fn close_from_callbacks(
         close: &Option<CloseHandler>,
         state_change: &Option<StateChangeHandler>,
@@ -368,9 +376,16 @@ impl Modal {
     ) -> AnyElement {
         self.theme.sync_from_provider(_cx);
         let opened = self.resolved_opened();
+        let focus = crate::provider::CalmProvider::focus(_cx);
         if !opened {
+            Self::dismiss(&self.id, window, _cx);
             return div().into_any_element();
         }
+        if !focus.is_trapped(&self.id) {
+            let event = InteractionEvent::new("modal", &self.id, InteractionAction::Open);
+            interaction::emit(event, _cx);
+        }
+        let focus_root = focus.trap(&self.id, window, _cx);
 
         let is_controlled = self.opened.is_some();
         let tokens = &self.theme.components.modal;
@@ -391,11 +406,12 @@ impl Modal {
             .opacity(1.0)
             .readability_boost(0.86)
             .on_click(
-                move |_: &ClickEvent, window: &mut Window, _cx: &mut gpui::App| {
+                move |_: &ClickEvent, window: &mut Window, cx: &mut gpui::App| {
                     if close_on_click_outside {
                         if popup_state::on_close_request(&id_for_overlay, is_controlled) {
                             window.refresh();
                         }
+                        Self::dismiss(&id_for_overlay, window, cx);
                         Self::close_from_callbacks(
                             &close_callbacks_for_overlay,
                             &state_change_for_overlay,
@@ -409,41 +425,55 @@ impl Modal {
             let id_for_close = self.id.clone();
             let close_callbacks_for_close = self.on_close.clone();
             let state_change_for_close = self.on_state_change.clone();
-            Some(
-                div()
-                    .id(self.id.slot("close"))
-                    .w(tokens.close_size)
-                    .h(tokens.close_size)
-                    .rounded_full()
-                    .border(super::utils::quantized_stroke_px(window, 1.0))
-                    .border_color(resolve_hsla(&self.theme, self.theme.semantic.border_subtle))
-                    .flex()
-                    .items_center()
-                    .justify_center()
-                    .cursor_pointer()
-                    .text_color(resolve_hsla(&self.theme, tokens.title))
-                    .hover(|style| style.opacity(0.8))
-                    .child(
-                        self.id
-                            .ctx()
-                            .child("close-icon", Icon::named("x"))
-                            .size(f32::from(tokens.close_icon_size))
-                            .color(resolve_hsla(&self.theme, tokens.title)),
-                    )
-                    .on_click(
-                        move |_: &ClickEvent, window: &mut Window, _cx: &mut gpui::App| {
-                            if popup_state::on_close_request(&id_for_close, is_controlled) {
-                                window.refresh();
-                            }
-                            Self::close_from_callbacks(
-                                &close_callbacks_for_close,
-                                &state_change_for_close,
-                                ModalCloseReason::CloseButton,
-                            );
-                        },
-                    )
-                    .into_any_element(),
+            let close_click = move |_: &ClickEvent, window: &mut Window, cx: &mut gpui::App| {
+                if popup_state::on_close_request(&id_for_close, is_controlled) {
+                    window.refresh();
+                }
+                Self::dismiss(&id_for_close, window, cx);
+                Self::close_from_callbacks(
+                    &close_callbacks_for_close,
+                    &state_change_for_close,
+                    ModalCloseReason::CloseButton,
+                );
+            };
+            let close_icon = self
+                .id
+                .ctx()
+                .child("close-icon", Icon::named("x"))
+                .size(f32::from(tokens.close_icon_size))
+                .color(resolve_hsla(&self.theme, tokens.title));
+            let close_hit_area = hit_area(
+                self.id.slot("close-hit-area"),
+                hit_target_inset(
+                    tokens.close_size,
+                    crate::provider::CalmProvider::min_hit_target(_cx),
+                ),
             )
+            .map(|area| area.on_click(close_click.clone()).into_any_element());
+            let expanded_hit_area = close_hit_area.is_some();
+            let close = div()
+                .id(self.id.slot("close"))
+                .w(tokens.close_size)
+                .h(tokens.close_size)
+                .rounded_full()
+                .border(super::utils::quantized_stroke_px(window, 1.0))
+                .border_color(resolve_hsla(&self.theme, self.theme.semantic.border_subtle))
+                .flex()
+                .items_center()
+                .justify_center()
+                .cursor_pointer()
+                .text_color(resolve_hsla(&self.theme, tokens.title))
+                .hover(|style| style.opacity(0.8))
+                .children(
+                    Some(close_icon.into_any_element())
+                        .into_iter()
+                        .chain(close_hit_area),
+                );
+            Some(if expanded_hit_area {
+                close.relative().into_any_element()
+            } else {
+                close.on_click(close_click).into_any_element()
+            })
         } else {
             None
         };
@@ -508,10 +538,12 @@ impl Modal {
                         Button::new()
                             .label(self.cancel_label.clone())
                             .with_variant(Variant::Default)
-                            .on_click(move |_, window, _| {
+                            .focus_handle(focus.handle(&self.id, "cancel", _cx))
+                            .on_click(move |_, window, cx| {
                                 if popup_state::on_close_request(&id_for_cancel, is_controlled) {
                                     window.refresh();
                                 }
+                                Self::dismiss(&id_for_cancel, window, cx);
                                 Self::action_from_callbacks(
                                     &cancel_cb,
                                     &state_change_for_cancel,
@@ -528,10 +560,12 @@ impl Modal {
                         Button::new()
                             .label(self.confirm_label.clone())
                             .with_variant(Variant::Filled)
-                            .on_click(move |_, window, _| {
+                            .focus_handle(focus.handle(&self.id, "confirm", _cx))
+                            .on_click(move |_, window, cx| {
                                 if popup_state::on_close_request(&id_for_confirm, is_controlled) {
                                     window.refresh();
                                 }
+                                Self::dismiss(&id_for_confirm, window, cx);
                                 Self::action_from_callbacks(
                                     &confirm_cb,
                                     &state_change_for_confirm,
@@ -559,10 +593,12 @@ impl Modal {
                         Button::new()
                             .label(self.complete_label.clone())
                             .with_variant(Variant::Filled)
-                            .on_click(move |_, window, _| {
+                            .focus_handle(focus.handle(&self.id, "complete", _cx))
+                            .on_click(move |_, window, cx| {
                                 if popup_state::on_close_request(&id_for_complete, is_controlled) {
                                     window.refresh();
                                 }
+                                Self::dismiss(&id_for_complete, window, cx);
                                 Self::action_from_callbacks(
                                     &complete_cb,
                                     &state_change_for_complete,
@@ -585,16 +621,19 @@ impl Modal {
         let close_callbacks_for_escape = self.on_close.clone();
         let state_change_for_escape = self.on_state_change.clone();
 
-        div()
+        let root = div()
             .id(self.id.clone())
             .absolute()
             .top_0()
             .left_0()
-            .size_full()
-            .on_key_down(move |event, window, _cx| {
+            .size_full();
+        focus
+            .scope(&focus_root, root)
+            .on_key_down(move |event, window, cx| {
                 if close_on_escape && control::is_escape_keystroke(event) {
                     let should_refresh =
                         popup_state::on_close_request(&id_for_escape, is_controlled);
+                    Self::dismiss(&id_for_escape, window, cx);
                     Self::close_from_callbacks(
                         &close_callbacks_for_escape,
                         &state_change_for_escape,
@@ -0,0 +1,418 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, FontWeight, InteractiveElement, IntoElement, ParentElement, RenderOnce,
+    SharedString, Styled, Window, div,
+};
+
+use crate::contracts::{MotionAware, Sized, Varianted};
+use crate::feedback::{
+    NotificationEntry, NotificationFilter, NotificationId, NotificationInbox, ToastKind,
+};
+use crate::icon::IconSource;
+use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
+use crate::motion::MotionConfig;
+use crate::style::{Size, Variant};
+
+use super::Stack;
+use super::action_icon::ActionIcon;
+use super::badge::Badge;
+use super::button::{Button, ButtonGroup, ButtonGroupItem};
+use super::control;
+use super::icon::Icon;
+use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
+use super::utils::resolve_hsla;
+
+type OpenHandler = Rc<dyn Fn(NotificationEntry, &mut Window, &mut gpui::App)>;
+type FilterChangeHandler = Rc<dyn Fn(NotificationFilter, &mut Window, &mut gpui::App)>;
+
+const DEFAULT_FILTERS: [NotificationFilter; 6] = [
+    NotificationFilter::All,
+    NotificationFilter::Unread,
+    NotificationFilter::Kind(ToastKind::Info),
+    NotificationFilter::Kind(ToastKind::Success),
+    NotificationFilter::Kind(ToastKind::Warning),
+    NotificationFilter::Kind(ToastKind::Error),
+];
+
+fn filter_value(filter: NotificationFilter) -> &'static str {
+    match filter {
+        NotificationFilter::All => "all",
+        NotificationFilter::Unread => "unread",
+        NotificationFilter::Kind(ToastKind::Info) => "info",
+        NotificationFilter::Kind(ToastKind::Success) => "success",
+        NotificationFilter::Kind(ToastKind::Warning) => "warning",
+        NotificationFilter::Kind(ToastKind::Error) => "error",
+        NotificationFilter::Kind(ToastKind::Loading) => "loading",
+    }
+}
+
+fn filter_from_value(value: &str) -> NotificationFilter {
+    match value {
+        "unread" => NotificationFilter::Unread,
+        "info" => NotificationFilter::Kind(ToastKind::Info),
+        "success" => NotificationFilter::Kind(ToastKind::Success),
+        "warning" => NotificationFilter::Kind(ToastKind::Warning),
+        "error" => NotificationFilter::Kind(ToastKind::Error),
+        "loading" => NotificationFilter::Kind(ToastKind::Loading),
+        _ => NotificationFilter::All,
+    }
+}
+
+fn filter_label(filter: NotificationFilter) -> &'static str {
+    match filter {
+        NotificationFilter::All => "All",
+        NotificationFilter::Unread => "Unread",
+        NotificationFilter::Kind(ToastKind::Info) => "Info",
+        NotificationFilter::Kind(ToastKind::Success) => "Success",
+        NotificationFilter::Kind(ToastKind::Warning) => "Warning",
+        NotificationFilter::Kind(ToastKind::Error) => "Error",
+        NotificationFilter::Kind(ToastKind::Loading) => "In progress",
+    }
+}
+
+#[derive(IntoElement)]
+pub struct NotificationCenter {
+    pub(crate) id: ComponentId,
+    inbox: Option<NotificationInbox>,
+    title: SharedString,
+    empty_label: SharedString,
+    mark_all_label: SharedString,
+    filters: Vec<NotificationFilter>,
+    filter: Option<NotificationFilter>,
+    default_filter: NotificationFilter,
+    dismissible: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_open: Option<OpenHandler>,
+    on_filter_change: Option<FilterChangeHandler>,
+}
+
+impl NotificationCenter {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            inbox: None,
+            title: SharedString::from("Notifications"),
+            empty_label: SharedString::from("No notifications"),
+            mark_all_label: SharedString::from("Mark all read"),
+            filters: DEFAULT_FILTERS.to_vec(),
+            filter: None,
+            default_filter: NotificationFilter::All,
+            dismissible: true,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_open: None,
+            on_filter_change: None,
+        }
+    }
+
+    pub fn inbox(mut self, value: NotificationInbox) -> Self {
+        self.inbox = Some(value);
+        self
+    }
+
+    pub fn title(mut self, value: impl Into<SharedString>) -> Self {
+        self.title = value.into();
+        self
+    }
+
+    pub fn empty_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.empty_label = value.into();
+        self
+    }
+
+    pub fn mark_all_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.mark_all_label = value.into();
+        self
+    }
+
+    pub fn filters(mut self, value: impl IntoIterator<Item = NotificationFilter>) -> Self {
+        self.filters = value.into_iter().collect();
+        self
+    }
+
+    pub fn filter(mut self, value: NotificationFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+
+    pub fn default_filter(mut self, value: NotificationFilter) -> Self {
+        self.default_filter = value;
+        self
+    }
+
+    pub fn dismissible(mut self, value: bool) -> Self {
+        self.dismissible = value;
+        self
+    }
+
+    pub fn on_open(
+        mut self,
+        handler: impl Fn(NotificationEntry, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_open = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_filter_change(
+        mut self,
+        handler: impl Fn(NotificationFilter, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_filter_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_filter(&self) -> NotificationFilter {
+        let value = control::text_state(
+            &self.id,
+            "filter",
+            self.filter.map(|filter| filter_value(filter).to_string()),
+            filter_value(self.default_filter).to_string(),
+        );
+        filter_from_value(&value)
+    }
+
+    fn kind_color(&self, kind: ToastKind) -> gpui::Hsla {
+        let tokens = &self.theme.components.notification_center;
+        let color = match kind {
+            ToastKind::Info | ToastKind::Loading => tokens.info,
+            ToastKind::Success => tokens.success,
+            ToastKind::Warning => tokens.warning,
+            ToastKind::Error => tokens.error,
+        };
+        resolve_hsla(&self.theme, color)
+    }
+
+    fn kind_icon(kind: ToastKind) -> IconSource {
+        match kind {
+            ToastKind::Info => IconSource::named("info-circle"),
+            ToastKind::Success => IconSource::named("circle-check"),
+            ToastKind::Warning => IconSource::named("alert-triangle"),
+            ToastKind::Error => IconSource::named("alert-circle"),
+            ToastKind::Loading => IconSource::named("loader-2"),
+        }
+    }
+
+    fn render_header(&self, inbox: &NotificationInbox, unread: usize) -> AnyElement {
+        let tokens = &self.theme.components.notification_center;
+        let title = div()
+            .text_size(tokens.header_size)
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(resolve_hsla(&self.theme, tokens.header))
+            .child(self.title.clone());
+        let badge = (unread > 0).then(|| {
+            self.id
+                .ctx()
+                .child("unread-badge", Badge::labeled(unread.to_string()))
+                .with_size(Size::Xs)
+        });
+        let mark_all = (unread > 0).then(|| {
+            let inbox = inbox.clone();
+            let button = self
+                .id
+                .ctx()
+                .child("mark-all", Button::new())
+                .label(self.mark_all_label.clone())
+                .with_variant(Variant::Subtle)
+                .with_size(Size::Xs)
+                .on_click(move |_, window, _| {
+                    if inbox.mark_all_read() > 0 {
+                        window.refresh();
+                    }
+                });
+            div().ml_auto().child(button)
+        });
+        Stack::horizontal()
+            .w_full()
+            .items_center()
+            .gap(tokens.item_gap)
+            .child(title)
+            .children(badge)
+            .children(mark_all)
+            .into_any_element()
+    }
+
+    fn render_filters(&self, active: NotificationFilter) -> Option<AnyElement> {
+        if self.filters.len() < 2 {
+            return None;
+        }
+        let items = self
+            .filters
+            .iter()
+            .map(|filter| ButtonGroupItem::labeled(filter_value(*filter), filter_label(*filter)));
+        let id = self.id.clone();
+        let controlled = self.filter.is_some();
+        let on_filter_change = self.on_filter_change.clone();
+        let group = self
+            .id
+            .ctx()
+            .child("filters", ButtonGroup::new())
+            .items(items)
+            .value(filter_value(active))
+            .inactive_variant(Variant::Subtle)
+            .with_size(Size::Xs)
+            .on_change(move |value, window, cx| {
+                if !controlled {
+                    control::set_text_state(&id, "filter", value.to_string());
+                }
+                if let Some(handler) = on_filter_change.as_ref() {
+                    (handler)(filter_from_value(&value), window, cx);
+                }
+                window.refresh();
+            });
+        Some(group.into_any_element())
+    }
+
+    fn render_entry(
+        &self,
+        notification_id: NotificationId,
+        entry: NotificationEntry,
+        inbox: &NotificationInbox,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.notification_center;
+        let key = notification_id.0.to_string();
+        let icon = self
+            .id
+            .ctx()
+            .child_index(
+                "kind-icon",
+                key.clone(),
+                Icon::new(
+                    entry
+                        .icon
+                        .clone()
+                        .unwrap_or_else(|| Self::kind_icon(entry.kind)),
+                ),
+            )
+            .size(f32::from(tokens.icon_size))
+            .color(self.kind_color(entry.kind));
+        let weight = if entry.read {
+            FontWeight::NORMAL
+        } else {
+            FontWeight::SEMIBOLD
+        };
+        let title = div()
+            .truncate()
+            .text_size(tokens.title_size)
+            .font_weight(weight)
+            .text_color(resolve_hsla(&self.theme, tokens.title))
+            .child(entry.title.clone());
+        let message = (!entry.message.is_empty()).then(|| {
+            div()
+                .text_size(tokens.message_size)
+                .text_color(resolve_hsla(&self.theme, tokens.message))
+                .child(entry.message.clone())
+        });
+        let text = Stack::vertical()
+            .flex_1()
+            .min_w_0()
+            .gap(tokens.item_gap)
+            .child(title)
+            .children(message);
+        let dot = (!entry.read).then(|| {
+            div()
+                .flex_none()
+                .mt(tokens.item_gap)
+                .size(tokens.dot_size)
+                .rounded_full()
+                .bg(resolve_hsla(&self.theme, tokens.unread_dot))
+        });
+        let dismiss = self.dismissible.then(|| {
+            let inbox = inbox.clone();
+            self.id
+                .ctx()
+                .child_index("dismiss", key.clone(), ActionIcon::new())
+                .with_variant(Variant::Subtle)
+                .with_size(Size::Xs)
+                .child(Icon::named("x").size(f32::from(tokens.icon_size)))
+                .on_click(move |_, window, _| {
+                    if inbox.remove(notification_id) {
+                        window.refresh();
+                    }
+                })
+        });
+
+        let row_id = self.id.slot_index("entry", key);
+        let hover_bg = resolve_hsla(&self.theme, tokens.row_hover_bg);
+        let mut row = div()
+            .id(row_id.clone())
+            .flex()
+            .items_start()
+            .w_full()
+            .gap(tokens.row_gap)
+            .p(tokens.row_padding)
+            .rounded_sm()
+            .cursor_pointer()
+            .hover(move |style| style.bg(hover_bg));
+        if !entry.read {
+            row = row.bg(resolve_hsla(&self.theme, tokens.unread_bg));
+        }
+        row = row.child(icon).child(text).children(dot).children(dismiss);
+
+        let center_id = self.id.clone();
+        let inbox = inbox.clone();
+        let on_open = self.on_open.clone();
+        let activate: ActivateHandler = Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
+            inbox.mark_read(notification_id);
+            let event =
+                InteractionEvent::new("notification_center", &center_id, InteractionAction::Open);
+            interaction::emit(event.value(notification_id.0.to_string()), cx);
+            if let Some(handler) = on_open.as_ref() {
+                (handler)(entry.clone(), window, cx);
+            }
+            window.refresh();
+        });
+        let adapter = PressAdapter::new(row_id).on_activate(Some(activate));
+        bind_press_adapter(row, adapter).into_any_element()
+    }
+}
+
+impl MotionAware for NotificationCenter {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for NotificationCenter {
+    fn render(mut self, _window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let inbox = self
+            .inbox
+            .take()
+            .unwrap_or_else(|| crate::provider::CalmProvider::notifications(_cx));
+        let tokens = &self.theme.components.notification_center;
+        let filter = self.resolved_filter();
+        let header = self.render_header(&inbox, inbox.unread_count());
+        let filters = self.render_filters(filter);
+        let entries = inbox.list(filter);
+        let empty = entries.is_empty().then(|| {
+            div()
+                .w_full()
+                .py(tokens.row_gap)
+                .text_size(tokens.message_size)
+                .text_color(resolve_hsla(&self.theme, tokens.empty))
+                .child(self.empty_label.clone())
+        });
+        let rows: Vec<_> = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let id = entry.id?;
+                Some(self.render_entry(id, entry, &inbox))
+            })
+            .collect();
+        Stack::vertical()
+            .id(self.id.clone())
+            .w(tokens.panel_width)
+            .gap(tokens.row_gap)
+            .child(header)
+            .children(filters)
+            .children(rows)
+            .children(empty)
+            .with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
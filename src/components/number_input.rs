@@ -1,10 +1,10 @@
-use std::{rc::Rc, str::FromStr};
+use std::{rc::Rc, str::FromStr, time::Duration};
 
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
 use gpui::{
-    AnyElement, ClickEvent, FocusHandle, IntoElement, ParentElement, RenderOnce, SharedString,
-    Styled, Window, div,
+    AnyElement, FocusHandle, IntoElement, MouseButton, MouseDownEvent, ParentElement, RenderOnce,
+    SharedString, Styled, Window, div,
 };
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
@@ -14,17 +14,139 @@ use crate::contracts::{
     FieldLike, MotionAware, Radiused as _, Sized as _, Themable, Varianted as _,
 };
 use crate::id::ComponentId;
-use crate::motion::MotionConfig;
+use crate::motion::{Easing, MotionConfig, MotionLevel, MotionTransition, TransitionPreset};
 use crate::style::{FieldLayout, Radius, Size, Variant};
 
 use super::TextInput;
 use super::control;
 use super::icon::Icon;
+use super::input_mask::InputMask;
+use super::number_input_state;
+use super::transition::{TransitionExt, TransitionStage};
 use super::utils::{apply_radius, quantized_stroke_px, resolve_hsla};
 
 type ChangeHandler = Rc<dyn Fn(f64, &mut Window, &mut gpui::App)>;
 type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
 
+#[derive(Clone)]
+struct NumberStepper {
+    id: ComponentId,
+    fallback: String,
+    value_controlled: bool,
+    on_change: Option<ChangeHandler>,
+    step: f64,
+    big_step: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+    precision: Option<usize>,
+    fixed_decimals: bool,
+    default_value: f64,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl NumberStepper {
+    /// Text and value one step away from `current_text`, and whether a bound
+    /// cut the step short.
+    fn stepped(&self, current_text: &str, direction: f64, big: bool) -> (String, f64, bool) {
+        let min_decimal = self.min.map(NumberInput::decimal_from_f64);
+        let max_decimal = self.max.map(NumberInput::decimal_from_f64);
+        let clamp = |mut value: Decimal| {
+            if let Some(min) = min_decimal {
+                value = value.max(min);
+            }
+            if let Some(max) = max_decimal {
+                value = value.min(max);
+            }
+            value
+        };
+
+        let current = NumberInput::parse_number(current_text)
+            .map(clamp)
+            .unwrap_or_else(|| clamp(NumberInput::decimal_from_f64(self.default_value)));
+
+        let step = NumberInput::decimal_from_f64(self.step.abs().max(0.000_001));
+        let amount = if big {
+            NumberInput::decimal_from_f64(self.big_step)
+        } else {
+            step
+        };
+        let base = min_decimal.unwrap_or(Decimal::ZERO);
+        let delta = if direction < 0.0 { -amount } else { amount };
+        let raw_next = current + delta;
+        let stepped = (((raw_next - base) / amount).round() * amount) + base;
+        let clamped = clamp(stepped);
+        let formatted =
+            NumberInput::format_decimal_value(clamped, self.precision, step, self.fixed_decimals);
+        let as_f64 = clamped.to_f64().unwrap_or(self.default_value);
+        (formatted, as_f64, clamped != stepped)
+    }
+
+    /// Takes one step and returns the new text and whether it hit a bound.
+    fn apply(
+        &self,
+        direction: f64,
+        big: bool,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) -> (String, bool) {
+        let current =
+            NumberInput::current_text_for(&self.id, &self.fallback, self.value_controlled);
+        let (next_text, next_value, clamped) = self.stepped(&current, direction, big);
+        if !self.value_controlled {
+            control::set_text_state(&self.id, "value-text", next_text.clone());
+        }
+        if clamped {
+            number_input_state::nudge(&self.id);
+        }
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(next_value, window, cx);
+        }
+        window.refresh();
+        (next_text, clamped)
+    }
+
+    /// Steps once, then keeps stepping while the control stays pressed,
+    /// until it is released or a bound is reached.
+    fn hold(&self, direction: f64, big: bool, window: &mut Window, cx: &mut gpui::App) {
+        if let Some(handle) = self.focus_handle.as_ref() {
+            window.focus(handle, cx);
+        }
+        let (next_text, clamped) = self.apply(direction, big, window, cx);
+        if clamped {
+            return;
+        }
+
+        let ticket = number_input_state::begin_repeat(&self.id);
+        let mut stepper = self.clone();
+        stepper.fallback = next_text;
+        let window_handle = window.window_handle();
+        cx.spawn(async move |cx| {
+            let mut delay = number_input_state::REPEAT_DELAY_MS;
+            loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(delay))
+                    .await;
+                delay = number_input_state::REPEAT_INTERVAL_MS;
+                let keep_going = window_handle
+                    .update(cx, |_, window, cx| {
+                        if !number_input_state::repeating(&stepper.id, ticket) {
+                            return false;
+                        }
+                        let (next_text, clamped) = stepper.apply(direction, big, window, cx);
+                        // Controlled values only come back on the next render.
+                        stepper.fallback = next_text;
+                        !clamped
+                    })
+                    .unwrap_or(false);
+                if !keep_going {
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+}
+
 #[derive(IntoElement)]
 pub struct NumberInput {
     pub(crate) id: ComponentId,
@@ -34,7 +156,14 @@ pub struct NumberInput {
     min: Option<f64>,
     max: Option<f64>,
     step: f64,
+    big_step: Option<f64>,
     precision: Option<usize>,
+    fixed_decimals: bool,
+    group_thousands: bool,
+    locale: Option<SharedString>,
+    separators: Option<(char, char)>,
+    prefix: Option<SharedString>,
+    suffix: Option<SharedString>,
     placeholder: Option<SharedString>,
     label: Option<SharedString>,
     description: Option<SharedString>,
@@ -68,7 +197,14 @@ impl NumberInput {
             min: None,
             max: None,
             step: 1.0,
+            big_step: None,
             precision: None,
+            fixed_decimals: false,
+            group_thousands: false,
+            locale: None,
+            separators: None,
+            prefix: None,
+            suffix: None,
             placeholder: None,
             label: None,
             description: None,
@@ -139,11 +275,58 @@ impl NumberInput {
         self
     }
 
+    /// Step taken when Shift is held with the arrow keys or the controls.
+    /// Defaults to ten steps.
+    pub fn big_step(mut self, value: f64) -> Self {
+        self.big_step = Some(value.abs().max(0.000_001));
+        self
+    }
+
     pub fn precision(mut self, value: usize) -> Self {
         self.precision = Some(value.min(8));
         self
     }
 
+    /// Keeps trailing zeros up to [`NumberInput::precision`], so `1.5` shows
+    /// as `1.50`.
+    pub fn fixed_decimals(mut self, value: bool) -> Self {
+        self.fixed_decimals = value;
+        self
+    }
+
+    /// Groups the integer digits by thousands, e.g. `1,234,567`.
+    pub fn group_thousands(mut self, value: bool) -> Self {
+        self.group_thousands = value;
+        self
+    }
+
+    /// Picks the thousands and decimal separators from a locale tag such as
+    /// `de-DE`. Without it the provider's i18n locale is used when
+    /// available.
+    pub fn locale(mut self, value: impl Into<SharedString>) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Thousands and decimal separators. Takes precedence over
+    /// [`NumberInput::locale`].
+    pub fn separators(mut self, thousands: char, decimal: char) -> Self {
+        self.separators = Some((thousands, decimal));
+        self
+    }
+
+    /// Unit shown before the number, such as `$`.
+    pub fn prefix(mut self, value: impl Into<SharedString>) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+
+    /// Unit shown after the number, such as `px`.
+    pub fn suffix(mut self, value: impl Into<SharedString>) -> Self {
+        self.suffix = Some(value.into());
+        self
+    }
+
     pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
         self.placeholder = Some(value.into());
         self
@@ -233,11 +416,23 @@ impl NumberInput {
         text.split('.').nth(1).map(|part| part.len()).unwrap_or(0)
     }
 
-    fn format_decimal_value(value: Decimal, precision: Option<usize>, step: Decimal) -> String {
-        let precision = precision.unwrap_or_else(|| Self::decimals_from_step(step));
-        let rounded = value.round_dp(precision as u32).normalize();
+    fn format_decimal_value(
+        value: Decimal,
+        precision: Option<usize>,
+        step: Decimal,
+        fixed_decimals: bool,
+    ) -> String {
+        let decimals = precision.unwrap_or_else(|| Self::decimals_from_step(step));
+        let mut rounded = value.round_dp(decimals as u32).normalize();
+        if fixed_decimals && precision.is_some() {
+            rounded.rescale(decimals as u32);
+        }
         let text = rounded.to_string();
-        if text == "-0" { "0".to_string() } else { text }
+        if rounded.is_zero() && text.starts_with('-') {
+            text[1..].to_string()
+        } else {
+            text
+        }
     }
 
     fn is_incomplete_number(text: &str) -> bool {
@@ -294,17 +489,99 @@ impl NumberInput {
             .then_some(self.value.unwrap_or(self.default_value))
             .map(Self::decimal_from_f64)
             .map(|value| self.clamp_decimal(value))
-            .map(|value| Self::format_decimal_value(value, self.precision, step));
+            .map(|value| {
+                Self::format_decimal_value(value, self.precision, step, self.fixed_decimals)
+            });
 
         let default = Self::format_decimal_value(
             self.clamp_decimal(Self::decimal_from_f64(self.default_value)),
             self.precision,
             step,
+            self.fixed_decimals,
         );
 
         control::text_state(&self.id, "value-text", controlled, default)
     }
 
+    fn out_of_range(&self, text: &str) -> bool {
+        Self::parse_number(text).is_some_and(|value| self.clamp_decimal(value) != value)
+    }
+
+    /// Rewrites typed text once the field loses focus: out-of-range values
+    /// are pulled back to the nearest bound with a nudge, and the rest are
+    /// rounded to the field's precision.
+    fn commit_typed_text(&self, window: &mut Window, cx: &mut gpui::App) {
+        if self.value_controlled {
+            return;
+        }
+        let Some(typed) = Self::parse_number(&self.resolved_text()) else {
+            return;
+        };
+        let clamped = self.clamp_decimal(typed);
+        if clamped != typed {
+            number_input_state::nudge(&self.id);
+        }
+        let step = Self::decimal_from_f64(self.step);
+        let text = Self::format_decimal_value(clamped, self.precision, step, self.fixed_decimals);
+        let rounded = Self::parse_number(&text).unwrap_or(clamped);
+        control::set_text_state(&self.id, "value-text", text);
+        // Typing already reported the clamped value; only rounding is news.
+        if rounded != clamped
+            && let Some(handler) = self.on_change.as_ref()
+        {
+            (handler)(rounded.to_f64().unwrap_or(0.0), window, cx);
+        }
+    }
+
+    fn input_mask(&self, _cx: &gpui::App) -> Option<InputMask> {
+        let prefix = self.prefix.as_deref().unwrap_or_default();
+        let suffix = self.suffix.as_deref().unwrap_or_default();
+        let localized = self.separators.is_some() || self.locale.is_some();
+        if !self.group_thousands && !localized && prefix.is_empty() && suffix.is_empty() {
+            return None;
+        }
+
+        let (thousands, decimal) = self.separators.unwrap_or_else(|| {
+            if let Some(locale) = self.locale.as_ref() {
+                return number_input_state::separators_for_locale(locale);
+            }
+            #[cfg(feature = "i18n")]
+            if let Some(tag) = crate::provider::CalmProvider::i18n(_cx).locale_tag() {
+                return number_input_state::separators_for_locale(&tag);
+            }
+            (',', '.')
+        });
+        let decimals = self
+            .precision
+            .unwrap_or_else(|| Self::decimals_from_step(Self::decimal_from_f64(self.step)));
+        Some(
+            InputMask::number()
+                .decimals(decimals)
+                .thousands_separator(self.group_thousands.then_some(thousands))
+                .decimal_separator(decimal)
+                .prefix(prefix)
+                .suffix(suffix)
+                .signed(self.min.is_none_or(|min| min < 0.0)),
+        )
+    }
+
+    fn stepper(&self, fallback: String) -> NumberStepper {
+        NumberStepper {
+            id: self.id.clone(),
+            fallback,
+            value_controlled: self.value_controlled,
+            on_change: self.on_change.clone(),
+            step: self.step,
+            big_step: self.big_step.unwrap_or(self.step * 10.0),
+            min: self.min,
+            max: self.max,
+            precision: self.precision,
+            fixed_decimals: self.fixed_decimals,
+            default_value: self.default_value,
+            focus_handle: self.focus_handle.clone(),
+        }
+    }
+
     fn current_text_for(id: &str, fallback: &str, value_controlled: bool) -> String {
         control::text_state(
             id,
@@ -314,46 +591,6 @@ impl NumberInput {
         )
     }
 
-    fn stepped_value_text_for(
-        current_text: &str,
-        direction: f64,
-        step: f64,
-        min: Option<f64>,
-        max: Option<f64>,
-        precision: Option<usize>,
-        default_value: f64,
-    ) -> (String, f64) {
-        let min_decimal = min.map(Self::decimal_from_f64);
-        let max_decimal = max.map(Self::decimal_from_f64);
-        let clamp = |mut value: Decimal| {
-            if let Some(min) = min_decimal {
-                value = value.max(min);
-            }
-            if let Some(max) = max_decimal {
-                value = value.min(max);
-            }
-            value
-        };
-
-        let current = Self::parse_number(current_text)
-            .map(clamp)
-            .unwrap_or_else(|| clamp(Self::decimal_from_f64(default_value)));
-
-        let step_decimal = Self::decimal_from_f64(step.abs().max(0.000_001));
-        let base = min_decimal.unwrap_or(Decimal::ZERO);
-        let delta = if direction < 0.0 {
-            -step_decimal
-        } else {
-            step_decimal
-        };
-        let raw_next = current + delta;
-        let stepped = (((raw_next - base) / step_decimal).round() * step_decimal) + base;
-        let clamped = clamp(stepped);
-        let formatted = Self::format_decimal_value(clamped, precision, step_decimal);
-        let as_f64 = clamped.to_f64().unwrap_or(default_value);
-        (formatted, as_f64)
-    }
-
     fn compose_right_slot(
         user_right_slot: Option<AnyElement>,
         controls_slot: Option<AnyElement>,
@@ -375,125 +612,90 @@ impl NumberInput {
         }
     }
 
-    fn render_controls_slot(&self, fallback_text: String, window: &gpui::Window) -> AnyElement {
+    fn render_control(
+        &self,
+        slot: &'static str,
+        icon: &'static str,
+        direction: f64,
+        at_bound: bool,
+        fallback_text: &str,
+        window: &gpui::Window,
+    ) -> gpui::Stateful<gpui::Div> {
         let tokens = &self.theme.components.number_input;
-        let controls_bg = resolve_hsla(&self.theme, tokens.controls_bg);
         let controls_fg = resolve_hsla(&self.theme, tokens.controls_fg);
-        let controls_border = resolve_hsla(&self.theme, tokens.controls_border);
-
-        let mut up = div()
-            .id(self.id.slot("control-up"))
+        let mut control = div()
+            .id(self.id.slot(slot))
             .w(tokens.controls_width)
             .h(tokens.controls_height)
             .flex()
             .items_center()
             .justify_center()
-            .bg(controls_bg)
+            .bg(resolve_hsla(&self.theme, tokens.controls_bg))
             .text_color(controls_fg)
             .border(quantized_stroke_px(window, 1.0))
-            .border_color(controls_border)
+            .border_color(resolve_hsla(&self.theme, tokens.controls_border))
             .child(
                 self.id
                     .ctx()
-                    .child("chevron-up", Icon::named("chevron-up"))
+                    .child(icon, Icon::named(icon))
                     .size(f32::from(tokens.controls_icon_size))
                     .color(controls_fg),
             );
 
-        let mut down = div()
-            .id(self.id.slot("control-down"))
-            .w(tokens.controls_width)
-            .h(tokens.controls_height)
-            .flex()
-            .items_center()
-            .justify_center()
-            .bg(controls_bg)
-            .text_color(controls_fg)
-            .border(quantized_stroke_px(window, 1.0))
-            .border_color(controls_border)
-            .child(
-                self.id
-                    .ctx()
-                    .child("chevron-down", Icon::named("chevron-down"))
-                    .size(f32::from(tokens.controls_icon_size))
-                    .color(controls_fg),
-            );
-
-        if !self.disabled && !self.read_only {
-            let id = self.id.clone();
-            let fallback = fallback_text.clone();
-            let value_controlled = self.value_controlled;
-            let on_change = self.on_change.clone();
-            let step = self.step;
-            let min = self.min;
-            let max = self.max;
-            let precision = self.precision;
-            let default_value = self.default_value;
-            let focus_handle = self.focus_handle.clone();
-            up = up
-                .cursor_pointer()
-                .on_click(move |_: &ClickEvent, window, cx| {
-                    let current = Self::current_text_for(&id, &fallback, value_controlled);
-                    let (next_text, next_value) = Self::stepped_value_text_for(
-                        &current,
-                        1.0,
-                        step,
-                        min,
-                        max,
-                        precision,
-                        default_value,
-                    );
-                    if !value_controlled {
-                        control::set_text_state(&id, "value-text", next_text);
-                    }
-                    if let Some(handler) = on_change.as_ref() {
-                        (handler)(next_value, window, cx);
-                    }
-                    if let Some(handle) = focus_handle.as_ref() {
-                        window.focus(handle, cx);
-                    }
-                    window.refresh();
-                });
-
-            let id = self.id.clone();
-            let fallback = fallback_text;
-            let value_controlled = self.value_controlled;
-            let on_change = self.on_change.clone();
-            let step = self.step;
-            let min = self.min;
-            let max = self.max;
-            let precision = self.precision;
-            let default_value = self.default_value;
-            let focus_handle = self.focus_handle.clone();
-            down = down
-                .cursor_pointer()
-                .on_click(move |_: &ClickEvent, window, cx| {
-                    let current = Self::current_text_for(&id, &fallback, value_controlled);
-                    let (next_text, next_value) = Self::stepped_value_text_for(
-                        &current,
-                        -1.0,
-                        step,
-                        min,
-                        max,
-                        precision,
-                        default_value,
-                    );
-                    if !value_controlled {
-                        control::set_text_state(&id, "value-text", next_text);
-                    }
-                    if let Some(handler) = on_change.as_ref() {
-                        (handler)(next_value, window, cx);
-                    }
-                    if let Some(handle) = focus_handle.as_ref() {
-                        window.focus(handle, cx);
-                    }
-                    window.refresh();
-                });
-        } else {
-            up = up.opacity(0.55);
-            down = down.opacity(0.55);
+        if self.disabled || self.read_only {
+            return control.opacity(0.55);
+        }
+        if at_bound {
+            // Still pressable, so pushing past the bound plays the nudge.
+            control = control.opacity(0.55);
         }
 
+        let stepper = self.stepper(fallback_text.to_string());
+        let id_for_up = self.id.clone();
+        let id_for_up_out = self.id.clone();
+        control
+            .cursor_pointer()
+            .on_mouse_down(
+                MouseButton::Left,
+                move |event: &MouseDownEvent, window, cx| {
+                    stepper.hold(direction, event.modifiers.shift, window, cx);
+                },
+            )
+            .on_mouse_up(MouseButton::Left, move |_, _, _| {
+                number_input_state::stop_repeat(&id_for_up);
+            })
+            .on_mouse_up_out(MouseButton::Left, move |_, _, _| {
+                number_input_state::stop_repeat(&id_for_up_out);
+            })
+    }
+
+    fn render_controls_slot(&self, fallback_text: String, window: &gpui::Window) -> AnyElement {
+        let current = Self::parse_number(&fallback_text);
+        let at_min = self
+            .min
+            .zip(current)
+            .is_some_and(|(min, value)| value <= Self::decimal_from_f64(min));
+        let at_max = self
+            .max
+            .zip(current)
+            .is_some_and(|(max, value)| value >= Self::decimal_from_f64(max));
+        let up = self.render_control(
+            "control-up",
+            "chevron-up",
+            1.0,
+            at_max,
+            &fallback_text,
+            window,
+        );
+        let down = self.render_control(
+            "control-down",
+            "chevron-down",
+            -1.0,
+            at_min,
+            &fallback_text,
+            window,
+        );
+
         let controls = super::Stack::vertical().child(up).child(down);
         apply_radius(&self.theme, controls, self.radius).into_any_element()
     }
@@ -539,7 +741,12 @@ impl RenderOnce for NumberInput {
     fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
         self.theme.sync_from_provider(cx);
 
+        let focused = control::focused_state(&self.id, None, false);
+        if number_input_state::note_focus(&self.id, focused) {
+            self.commit_typed_text(window, cx);
+        }
         let current_text = self.resolved_text();
+        let out_of_range = self.out_of_range(&current_text);
         let id = self.id.clone();
         let value_controlled = self.value_controlled;
         let on_change = self.on_change.clone();
@@ -554,13 +761,18 @@ impl RenderOnce for NumberInput {
             .value(current_text.clone());
 
         let field_tokens = self.theme.components.number_input.clone();
+        let (border, border_focus) = if out_of_range {
+            (field_tokens.border_error, field_tokens.border_error)
+        } else {
+            (field_tokens.border, field_tokens.border_focus)
+        };
         input = input.themed(|overrides| {
             overrides
                 .bg(field_tokens.bg)
                 .fg(field_tokens.fg)
                 .placeholder(field_tokens.placeholder)
-                .border(field_tokens.border)
-                .border_focus(field_tokens.border_focus)
+                .border(border)
+                .border_focus(border_focus)
                 .border_error(field_tokens.border_error)
                 .label(field_tokens.label)
                 .label_size(field_tokens.label_size)
@@ -618,6 +830,9 @@ impl RenderOnce for NumberInput {
             },
         );
 
+        if let Some(mask) = self.input_mask(cx) {
+            input = input.mask(mask);
+        }
         if let Some(max_length) = self.max_length {
             input = input.max_length(max_length);
         }
@@ -649,15 +864,28 @@ impl RenderOnce for NumberInput {
             return field;
         }
 
+        let nudge = number_input_state::nudge_count(&self.id);
+        let field = if nudge > 0 && self.motion.level != MotionLevel::None {
+            let profile = MotionTransition::new()
+                .preset(TransitionPreset::Shake)
+                .duration_ms(240)
+                .offset_px(12)
+                .start_opacity_pct(100)
+                .easing(Easing::Linear);
+            div()
+                .child(field)
+                .with_transition_profile(
+                    self.id.slot_index("nudge", nudge.to_string()),
+                    profile,
+                    TransitionStage::Enter,
+                )
+                .into_any_element()
+        } else {
+            field
+        };
+
         let id_for_step = self.id.clone();
-        let fallback_for_step = current_text;
-        let value_controlled_for_step = self.value_controlled;
-        let on_change_for_step = self.on_change.clone();
-        let step = self.step;
-        let min = self.min;
-        let max = self.max;
-        let precision = self.precision;
-        let default_value = self.default_value;
+        let stepper = self.stepper(current_text);
 
         div()
             .id(self.id.slot("keyboard-proxy"))
@@ -670,31 +898,9 @@ impl RenderOnce for NumberInput {
                     return;
                 };
 
-                let current = Self::current_text_for(
-                    &id_for_step,
-                    &fallback_for_step,
-                    value_controlled_for_step,
-                );
-                let (next_text, next_value) = Self::stepped_value_text_for(
-                    &current,
-                    direction,
-                    step,
-                    min,
-                    max,
-                    precision,
-                    default_value,
-                );
-
-                if !value_controlled_for_step {
-                    control::set_text_state(&id_for_step, "value-text", next_text);
-                }
-                if let Some(handler) = on_change_for_step.as_ref() {
-                    (handler)(next_value, window, cx);
-                }
-
+                stepper.apply(direction, event.keystroke.modifiers.shift, window, cx);
                 cx.stop_propagation();
                 window.prevent_default();
-                window.refresh();
             })
             .child(field)
             .into_any_element()
@@ -0,0 +1,68 @@
+use super::control;
+
+/// Delay before a held increment control starts repeating.
+pub const REPEAT_DELAY_MS: u64 = 400;
+/// Time between steps while an increment control is held.
+pub const REPEAT_INTERVAL_MS: u64 = 60;
+
+/// Thousands and decimal separators for a BCP 47 or POSIX locale tag.
+/// Unknown languages use `,` and `.`.
+pub fn separators_for_locale(tag: &str) -> (char, char) {
+    let tag = tag.split(['.', '@']).next().unwrap_or_default();
+    let mut parts = tag.split(['-', '_']);
+    let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+    let region = parts
+        .find(|part| part.len() == 2)
+        .map(str::to_ascii_uppercase);
+    match (language.as_str(), region.as_deref()) {
+        ("de" | "it", Some("CH")) => ('\u{2019}', '.'),
+        ("pt", Some("PT")) => ('\u{a0}', ','),
+        ("es", Some("MX" | "US")) => (',', '.'),
+        ("de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl", _) => {
+            ('.', ',')
+        }
+        (
+            "fr" | "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "hu" | "bg"
+            | "lt" | "lv" | "et",
+            _,
+        ) => ('\u{a0}', ','),
+        _ => (',', '.'),
+    }
+}
+
+/// Starts a hold-to-repeat run and returns its ticket. Starting another one
+/// or calling [`stop_repeat`] makes older tickets stale.
+pub fn begin_repeat(id: &str) -> usize {
+    let ticket = control::usize_state(id, "repeat", None, 0) + 1;
+    control::set_usize_state(id, "repeat", ticket);
+    ticket
+}
+
+pub fn repeating(id: &str, ticket: usize) -> bool {
+    control::usize_state(id, "repeat", None, 0) == ticket
+}
+
+pub fn stop_repeat(id: &str) {
+    let ticket = control::usize_state(id, "repeat", None, 0);
+    control::set_usize_state(id, "repeat", ticket + 1);
+}
+
+pub fn nudge_count(id: &str) -> usize {
+    control::usize_state(id, "nudge", None, 0)
+}
+
+/// Plays the clamp nudge once more, after a value was pulled back into
+/// range.
+pub fn nudge(id: &str) {
+    control::set_usize_state(id, "nudge", nudge_count(id) + 1);
+}
+
+/// Records whether the field has focus and returns `true` when it has just
+/// lost it.
+pub fn note_focus(id: &str, focused: bool) -> bool {
+    let was_focused = control::bool_state(id, "was-focused", None, false);
+    if was_focused != focused {
+        control::set_bool_state(id, "was-focused", focused);
+    }
+    was_focused && !focused
+}
@@ -13,7 +13,8 @@ use super::Stack;
 use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
 use super::selection_state;
 use super::utils::{
-    InteractionStyles, apply_interaction_styles, apply_radius, interaction_style, resolve_hsla,
+    InteractionStyles, apply_disabled_state, apply_interaction_styles, apply_radius,
+    interaction_style, resolve_hsla,
 };
 
 type ChangeHandler = Rc<dyn Fn(usize, &mut Window, &mut gpui::App)>;
@@ -206,13 +207,19 @@ impl RenderOnce for Pagination {
             item = apply_radius(&self.theme, item, self.radius).text_center();
 
             if disabled || self.disabled {
-                item = item.cursor_default().opacity(0.6);
+                item = apply_disabled_state(item.cursor_default(), tokens.states, 0.6);
             } else {
                 let id = pagination_id.clone();
                 let on_change = on_change.clone();
-                let hover_bg = resolve_hsla(&theme, tokens.item_hover_bg);
-                let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
-                let focus_ring = resolve_hsla(&theme, theme.semantic.focus_ring);
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(resolve_hsla(&theme, tokens.item_hover_bg));
+                let press_bg = tokens
+                    .states
+                    .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
+                let focus_ring = tokens
+                    .states
+                    .focus_border_or(resolve_hsla(&theme, theme.semantic.focus_ring));
                 let activate_handler: ActivateHandler = Rc::new(move |window, cx| {
                     if selection_state::apply_usize(&id, "page", controlled, target) {
                         window.refresh();
@@ -232,7 +239,9 @@ impl RenderOnce for Pagination {
                 );
                 item = bind_press_adapter(
                     item,
-                    PressAdapter::new(adapter_id.clone()).on_activate(Some(activate_handler)),
+                    PressAdapter::new(adapter_id.clone())
+                        .component("pagination")
+                        .on_activate(Some(activate_handler)),
                 );
             }
 
@@ -277,15 +286,22 @@ impl RenderOnce for Pagination {
                     page_item = apply_radius(&self.theme, page_item, self.radius).text_center();
 
                     if self.disabled {
-                        page_item = page_item.cursor_default().opacity(0.6);
+                        page_item =
+                            apply_disabled_state(page_item.cursor_default(), tokens.states, 0.6);
                     } else if is_active {
                         page_item = page_item.cursor_default();
                     } else {
                         let id = self.id.clone();
                         let on_change = on_change.clone();
-                        let hover_bg = resolve_hsla(&theme, tokens.item_hover_bg);
-                        let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
-                        let focus_ring = resolve_hsla(&theme, theme.semantic.focus_ring);
+                        let hover_bg = tokens
+                            .states
+                            .hover_bg_or(resolve_hsla(&theme, tokens.item_hover_bg));
+                        let press_bg = tokens
+                            .states
+                            .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
+                        let focus_ring = tokens
+                            .states
+                            .focus_border_or(resolve_hsla(&theme, theme.semantic.focus_ring));
                         let activate_handler: ActivateHandler = Rc::new(move |window, cx| {
                             if selection_state::apply_usize(&id, "page", controlled, page) {
                                 window.refresh();
@@ -305,7 +321,9 @@ impl RenderOnce for Pagination {
                         );
                         page_item = bind_press_adapter(
                             page_item,
-                            PressAdapter::new(page_id.clone()).on_activate(Some(activate_handler)),
+                            PressAdapter::new(page_id.clone())
+                                .component("pagination")
+                                .on_activate(Some(activate_handler)),
                         );
                     }
 
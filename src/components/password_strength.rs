@@ -0,0 +1,58 @@
+/// How hard a password looks to guess, as shown by the
+/// [`PasswordInput`](super::PasswordInput) strength meter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PasswordStrength {
+    Weak,
+    Fair,
+    Good,
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Built-in estimate from length and the mix of lowercase, uppercase,
+    /// digits and symbols. Swap in a real estimator such as zxcvbn through
+    /// [`PasswordInput::strength_estimator`](super::PasswordInput::strength_estimator)
+    /// where it matters.
+    pub fn estimate(password: &str) -> Self {
+        let length = password.chars().count();
+        let classes = [
+            password.chars().any(|ch| ch.is_lowercase()),
+            password.chars().any(|ch| ch.is_uppercase()),
+            password.chars().any(|ch| ch.is_ascii_digit()),
+            password.chars().any(|ch| !ch.is_alphanumeric()),
+        ]
+        .into_iter()
+        .filter(|present| *present)
+        .count();
+        let length_score = match length {
+            0..=7 => 0,
+            8..=11 => 1,
+            _ => 2,
+        };
+        match length_score + classes.saturating_sub(1).min(2) {
+            0 | 1 => Self::Weak,
+            2 => Self::Fair,
+            3 => Self::Good,
+            _ => Self::Strong,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Weak => "Weak",
+            Self::Fair => "Fair",
+            Self::Good => "Good",
+            Self::Strong => "Strong",
+        }
+    }
+
+    /// Meter segments filled out of four.
+    pub(crate) fn segments(self) -> usize {
+        match self {
+            Self::Weak => 1,
+            Self::Fair => 2,
+            Self::Good => 3,
+            Self::Strong => 4,
+        }
+    }
+}
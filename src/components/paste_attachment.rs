@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use gpui::{ClipboardEntry, ClipboardItem, ImageFormat, Window};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PasteAttachment {
+    Image { format: ImageFormat, bytes: Vec<u8> },
+    File(PathBuf),
+}
+
+pub(super) type PasteAttachmentHandler =
+    Rc<dyn Fn(Vec<PasteAttachment>, &mut Window, &mut gpui::App)>;
+
+fn decode_path(value: &str) -> PathBuf {
+    let value = value.strip_prefix("localhost").unwrap_or(value);
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| value.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    PathBuf::from(String::from_utf8_lossy(&decoded).into_owned())
+}
+
+pub fn file_paths(text: &str) -> Option<Vec<PathBuf>> {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+    lines
+        .into_iter()
+        .map(|line| line.strip_prefix("file://").map(decode_path))
+        .collect()
+}
+
+pub fn split_clipboard(item: &ClipboardItem) -> (Option<String>, Vec<PasteAttachment>) {
+    let mut text: Option<String> = None;
+    let mut attachments = Vec::new();
+    for entry in item.entries() {
+        match entry {
+            ClipboardEntry::Image(image) => attachments.push(PasteAttachment::Image {
+                format: image.format,
+                bytes: image.bytes.clone(),
+            }),
+            ClipboardEntry::String(value) => match file_paths(value.text()) {
+                Some(paths) => attachments.extend(paths.into_iter().map(PasteAttachment::File)),
+                None => text.get_or_insert_default().push_str(value.text()),
+            },
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+    (text, attachments)
+}
+
+pub(super) fn take_pasted_text(
+    item: &ClipboardItem,
+    handler: Option<&PasteAttachmentHandler>,
+    window: &mut Window,
+    cx: &mut gpui::App,
+) -> Option<String> {
+    let Some(handler) = handler else {
+        return item.text();
+    };
+    let (text, attachments) = split_clipboard(item);
+    if !attachments.is_empty() {
+        (handler)(attachments, window, cx);
+    }
+    text
+}
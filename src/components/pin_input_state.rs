@@ -0,0 +1,65 @@
+use super::composition;
+use super::control;
+
+/// Digits to keep from a paste. A paste holding at least `length` digits is
+/// taken as a whole code and replaces the current value.
+pub fn pasted_code(pasted: &str, length: usize) -> (String, bool) {
+    let digits = pasted
+        .chars()
+        .filter_map(composition::ascii_digit)
+        .collect::<String>();
+    let replaces = digits.chars().count() >= length;
+    (digits, replaces)
+}
+
+pub fn verifying(id: &str) -> bool {
+    control::bool_state(id, "verifying", None, false)
+}
+
+/// Marks a verification as running and returns its ticket. Starting another
+/// one makes older tickets stale.
+pub fn begin_verification(id: &str) -> usize {
+    let ticket = control::usize_state(id, "verification", None, 0) + 1;
+    control::set_usize_state(id, "verification", ticket);
+    control::set_bool_state(id, "verifying", true);
+    ticket
+}
+
+/// Ends the verification for `ticket`. Returns `false` when it went stale,
+/// in which case its result should be dropped.
+pub fn finish_verification(id: &str, ticket: usize) -> bool {
+    if control::usize_state(id, "verification", None, 0) != ticket {
+        return false;
+    }
+    control::set_bool_state(id, "verifying", false);
+    true
+}
+
+pub fn verify_error(id: &str) -> Option<String> {
+    control::optional_text_state(id, "verify-error", None, None)
+}
+
+pub fn set_verify_error(id: &str, value: Option<String>) {
+    control::set_optional_text_state(id, "verify-error", value);
+}
+
+pub fn shake_count(id: &str) -> usize {
+    control::usize_state(id, "shake", None, 0)
+}
+
+pub fn trigger_shake(id: &str) {
+    control::set_usize_state(id, "shake", shake_count(id) + 1);
+}
+
+/// Shakes once for every new error message shown, and returns the count to
+/// key the animation with.
+pub fn note_error(id: &str, error: Option<&str>) -> usize {
+    let previous = control::optional_text_state(id, "shown-error", None, None);
+    if previous.as_deref() != error {
+        control::set_optional_text_state(id, "shown-error", error.map(str::to_string));
+        if error.is_some() {
+            trigger_shake(id);
+        }
+    }
+    shake_count(id)
+}
@@ -7,6 +7,7 @@ use gpui::{AnyElement, ClickEvent, IntoElement, ParentElement, RenderOnce, Style
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
+use crate::overlay::positioning::Align;
 
 use super::Stack;
 use super::popup::{PopupPlacement, anchored_host};
@@ -30,6 +31,7 @@ pub struct Popover {
     disabled: bool,
     placement: PopoverPlacement,
     offset_px: f32,
+    align: Align,
     close_on_click_outside: bool,
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
@@ -48,6 +50,7 @@ impl Popover {
             disabled: false,
             placement: PopoverPlacement::Bottom,
             offset_px: 3.0,
+            align: Align::Start,
             close_on_click_outside: true,
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::default(),
@@ -70,6 +73,11 @@ impl Popover {
         self
     }
 
+    pub fn align(mut self, value: Align) -> Self {
+        self.align = value;
+        self
+    }
+
     pub fn close_on_click_outside(mut self, value: bool) -> Self {
         self.close_on_click_outside = value;
         self
@@ -197,17 +205,9 @@ impl RenderOnce for Popover {
                 PopoverPlacement::Top => PopupPlacement::Top,
                 PopoverPlacement::Bottom => PopupPlacement::Bottom,
             };
-            let anchor_host = anchored_host(
-                &self.id,
-                "anchor-host",
-                placement,
-                self.offset_px,
-                self.theme.components.layout.popup_snap_margin,
-                panel,
-                20,
-                matches!(self.placement, PopoverPlacement::Bottom),
-                false,
-            );
+            let margin = self.theme.components.layout.popup_snap_margin;
+            let options = placement.options(self.offset_px, margin).align(self.align);
+            let anchor_host = anchored_host(&self.id, "anchor-host", options, panel, 20, false);
 
             trigger = trigger.child(anchor_host);
         }
@@ -1,11 +1,14 @@
 use gpui::InteractiveElement;
 use gpui::{
-    AnyElement, Corner, IntoElement, ParentElement, Styled, anchored, deferred, div, point, px,
+    Anchored, AnyElement, Bounds, Corner, IntoElement, ParentElement, Pixels, Styled, Window,
+    anchored, canvas, deferred, div, point, px,
 };
 
 use crate::id::ComponentId;
+use crate::overlay::positioning::{PositionOptions, Rect, Side};
 
 use super::control;
+use super::popup_state;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PopupPlacement {
@@ -13,6 +16,17 @@ pub enum PopupPlacement {
     Bottom,
 }
 
+impl PopupPlacement {
+    pub fn options(self, offset_px: f32, snap_margin: Pixels) -> PositionOptions {
+        let side = match self {
+            Self::Top => Side::Top,
+            Self::Bottom => Side::Bottom,
+        };
+        let options = PositionOptions::new(side).offset(offset_px.max(0.0));
+        options.margin(f32::from(snap_margin))
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct PopupState {
     pub opened: bool,
@@ -28,48 +42,75 @@ impl PopupState {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
+fn frame_of(bounds: Bounds<Pixels>) -> Rect {
+    let (origin, size) = (bounds.origin, bounds.size);
+    let (x, y) = (f32::from(origin.x), f32::from(origin.y));
+    Rect::new(x, y, f32::from(size.width), f32::from(size.height))
+}
+
+fn probe(store: impl Fn(Rect, &mut Window) -> bool + 'static) -> impl IntoElement {
+    let paint = |_, _, _: &mut Window, _: &mut gpui::App| {};
+    let measure = move |bounds, window: &mut Window, _: &mut gpui::App| {
+        if store(frame_of(bounds), window) {
+            window.refresh();
+        }
+    };
+    canvas(measure, paint).absolute().size_full()
+}
+
+fn fallback_layer(options: PositionOptions) -> Anchored {
+    let offset = options.offset;
+    let (corner, offset) = match options.side {
+        Side::Top => (Corner::BottomLeft, point(px(0.0), px(-offset))),
+        Side::Bottom => (Corner::TopLeft, point(px(0.0), px(offset))),
+        Side::Left => (Corner::TopRight, point(px(-offset), px(0.0))),
+        Side::Right => (Corner::TopLeft, point(px(offset), px(0.0))),
+    };
+    let layer = anchored().anchor(corner).offset(offset);
+    if options.flip || options.shift {
+        layer.snap_to_window_with_margin(px(options.margin))
+    } else {
+        layer
+    }
+}
+
 pub fn anchored_host(
     id: &ComponentId,
     slot: &str,
-    placement: PopupPlacement,
-    offset_px: f32,
-    snap_margin: gpui::Pixels,
+    options: PositionOptions,
     floating: AnyElement,
     priority: usize,
-    snap_to_window: bool,
     full_width_host: bool,
 ) -> AnyElement {
-    let anchor_corner = match placement {
-        PopupPlacement::Top => Corner::BottomLeft,
-        PopupPlacement::Bottom => Corner::TopLeft,
-    };
-    let offset = match placement {
-        PopupPlacement::Top => point(px(0.0), px(-offset_px.max(0.0))),
-        PopupPlacement::Bottom => point(px(0.0), px(offset_px.max(0.0))),
-    };
-    let host_slot = id.slot(slot.to_owned());
-    let anchored_panel = if snap_to_window {
-        anchored()
-            .anchor(anchor_corner)
-            .offset(offset)
-            .snap_to_window_with_margin(snap_margin)
-            .child(floating)
-    } else {
-        anchored()
-            .anchor(anchor_corner)
-            .offset(offset)
-            .child(floating)
+    let key = id.scoped(slot.to_owned());
+    let anchor_key = key.clone();
+    let anchor_probe = probe(move |anchor, window| {
+        let size = window.viewport_size();
+        let viewport = Rect::new(0.0, 0.0, f32::from(size.width), f32::from(size.height));
+        popup_state::set_anchor_frame(&anchor_key, anchor, viewport)
+    });
+    let floating_key = key.clone();
+    let floating_probe = probe(move |frame, _| {
+        popup_state::set_floating_size(&floating_key, frame.width, frame.height)
+    });
+    let floating = div().relative().child(floating).child(floating_probe);
+
+    let layer = match popup_state::resolve_placement(&key, options) {
+        Some(placement) => anchored().position(point(px(placement.x), px(placement.y))),
+        None => fallback_layer(options),
     };
-    let mut host = match placement {
-        PopupPlacement::Top => div().id(host_slot).absolute().top_0().left_0(),
-        PopupPlacement::Bottom => div().id(host_slot).absolute().bottom_0().left_0(),
+    let host = div().id(id.slot(slot.to_owned())).absolute();
+    let mut host = match options.side {
+        Side::Top | Side::Left => host.top_0().left_0(),
+        Side::Bottom => host.bottom_0().left_0(),
+        Side::Right => host.top_0().right_0(),
     };
     if full_width_host {
         host = host.w_full().h(px(0.0));
     } else {
         host = host.w(px(0.0)).h(px(0.0));
     }
-    host.child(deferred(anchored_panel).priority(priority))
-        .into_any_element()
+    let host = host.child(deferred(layer.child(floating)).priority(priority));
+    let wrapper = div().absolute().top_0().left_0().size_full();
+    wrapper.child(anchor_probe).child(host).into_any_element()
 }
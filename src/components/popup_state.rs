@@ -1,3 +1,5 @@
+use crate::overlay::positioning::{self, Placement, PositionOptions, Rect};
+
 use super::control;
 use super::popup::PopupState;
 
@@ -43,3 +45,40 @@ pub fn on_close_request(id: &str, controlled: bool) -> bool {
 pub fn on_open_request(id: &str, controlled: bool) -> bool {
     apply_opened(id, controlled, true)
 }
+
+fn set_rect(id: &str, key: &str, rect: Rect) -> bool {
+    if frame(id, key) == Some(rect) {
+        return false;
+    }
+    control::set_f32_state(id, &format!("{key}-x"), rect.x);
+    control::set_f32_state(id, &format!("{key}-y"), rect.y);
+    control::set_f32_state(id, &format!("{key}-width"), rect.width);
+    control::set_optional_f32_state(id, &format!("{key}-height"), Some(rect.height));
+    true
+}
+
+fn frame(id: &str, key: &str) -> Option<Rect> {
+    let height = control::optional_f32_state(id, &format!("{key}-height"), None, None)?;
+    let x = control::f32_state(id, &format!("{key}-x"), None, 0.0);
+    let y = control::f32_state(id, &format!("{key}-y"), None, 0.0);
+    let width = control::f32_state(id, &format!("{key}-width"), None, 0.0);
+    Some(Rect::new(x, y, width, height))
+}
+
+pub fn set_anchor_frame(id: &str, anchor: Rect, viewport: Rect) -> bool {
+    let anchor_changed = set_rect(id, "anchor", anchor);
+    set_rect(id, "viewport", viewport) || anchor_changed
+}
+
+pub fn set_floating_size(id: &str, width: f32, height: f32) -> bool {
+    set_rect(id, "floating", Rect::new(0.0, 0.0, width, height))
+}
+
+pub fn resolve_placement(id: &str, options: PositionOptions) -> Option<Placement> {
+    let anchor = frame(id, "anchor")?;
+    let viewport = frame(id, "viewport")?;
+    let floating = frame(id, "floating")?;
+    let placement =
+        positioning::compute(anchor, floating.width, floating.height, viewport, options);
+    Some(placement)
+}
@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use gpui::{
+    Animation, AnimationExt, Div, Hsla, InteractiveElement, MouseButton, ParentElement, Pixels,
+    Stateful, Styled, canvas, div, px,
+};
+
+use crate::id::ComponentId;
+use crate::theme::PressFeedbackStyle;
+
+use super::control;
+use super::utils::{InteractionStyles, resolve_hsla};
+
+const RIPPLE_DURATION_MS: u64 = 420;
+
+pub fn ripple_radius(x: f32, y: f32, width: f32, height: f32) -> f32 {
+    let dx = x.max(width - x);
+    let dy = y.max(height - y);
+    dx.hypot(dy)
+}
+
+#[derive(Clone, Copy)]
+pub struct PressFeedback {
+    style: PressFeedbackStyle,
+    scale_inset: Pixels,
+    ripple_color: Hsla,
+}
+
+impl PressFeedback {
+    pub fn resolve(theme: &crate::theme::LocalTheme, cx: &gpui::App) -> Self {
+        let tokens = &theme.components.press_feedback;
+        let style = if crate::provider::CalmProvider::press_feedback(cx) {
+            tokens.style
+        } else {
+            PressFeedbackStyle::None
+        };
+        Self {
+            style,
+            scale_inset: tokens.scale_inset,
+            ripple_color: resolve_hsla(theme, tokens.ripple_color),
+        }
+    }
+
+    // gpui has no element transforms, so the scale-down is approximated by
+    // sinking the pressed surface by `scale_inset` and dimming it slightly.
+    pub fn interaction_styles(&self, mut styles: InteractionStyles) -> InteractionStyles {
+        if self.style != PressFeedbackStyle::Scale {
+            return styles;
+        }
+        let inset = self.scale_inset;
+        let active = styles.active.take().unwrap_or_default();
+        styles.active(active.mt(inset).mb(-inset).opacity(0.92))
+    }
+
+    pub fn apply(&self, id: &ComponentId, node: Stateful<Div>) -> Stateful<Div> {
+        if self.style != PressFeedbackStyle::Ripple {
+            return node;
+        }
+
+        let measure_id = id.to_string();
+        let measure = canvas(
+            move |bounds, _window, _cx| {
+                control::set_f32_state(&measure_id, "ripple-left", f32::from(bounds.left()));
+                control::set_f32_state(&measure_id, "ripple-top", f32::from(bounds.top()));
+                control::set_f32_state(&measure_id, "ripple-width", f32::from(bounds.size.width));
+                control::set_f32_state(&measure_id, "ripple-height", f32::from(bounds.size.height));
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let presses = control::usize_state(id, "ripple-count", None, 0);
+        let ripple = (presses > 0).then(|| {
+            let x = control::f32_state(id, "ripple-x", None, 0.0);
+            let y = control::f32_state(id, "ripple-y", None, 0.0);
+            let width = control::f32_state(id, "ripple-width", None, 0.0);
+            let height = control::f32_state(id, "ripple-height", None, 0.0);
+            let radius = ripple_radius(x, y, width, height);
+            let animation = Animation::new(Duration::from_millis(RIPPLE_DURATION_MS))
+                .with_easing(gpui::ease_in_out);
+            div()
+                .id(id.slot_index("ripple", presses.to_string()))
+                .absolute()
+                .rounded_full()
+                .bg(self.ripple_color)
+                .with_animation(
+                    id.slot_index("ripple-anim", presses.to_string()),
+                    animation,
+                    move |ripple, delta| {
+                        let reach = radius * delta;
+                        let ripple = ripple.left(px(x - reach)).top(px(y - reach));
+                        ripple.size(px(reach * 2.0)).opacity(1.0 - delta)
+                    },
+                )
+        });
+
+        let layer = div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .overflow_hidden()
+            .child(measure)
+            .children(ripple);
+
+        let press_id = id.to_string();
+        node.relative()
+            .child(layer)
+            .on_mouse_down(MouseButton::Left, move |event, window, _cx| {
+                let left = control::f32_state(&press_id, "ripple-left", None, 0.0);
+                let top = control::f32_state(&press_id, "ripple-top", None, 0.0);
+                let presses = control::usize_state(&press_id, "ripple-count", None, 0);
+                control::set_f32_state(&press_id, "ripple-x", f32::from(event.position.x) - left);
+                control::set_f32_state(&press_id, "ripple-y", f32::from(event.position.y) - top);
+                control::set_usize_state(&press_id, "ripple-count", presses + 1);
+                window.refresh();
+            })
+    }
+}
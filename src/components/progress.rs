@@ -9,15 +9,15 @@ use gpui::{
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
-use crate::style::{Radius, Size, Variant};
+use crate::style::{GroupOrientation, Radius, Size, Variant};
 
 use super::Stack;
 use super::utils::{apply_radius, resolve_hsla, snap_px};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ProgressSection {
-    value: f32,
-    color: Option<Hsla>,
+    pub(crate) value: f32,
+    pub(crate) color: Option<Hsla>,
 }
 
 impl ProgressSection {
@@ -31,6 +31,29 @@ impl ProgressSection {
     }
 }
 
+/// Clamps `sections` so they add up to at most 100, or turns a bare `value`
+/// into a single section when there are none.
+pub(crate) fn resolve_sections(value: f32, sections: &[ProgressSection]) -> Vec<ProgressSection> {
+    if sections.is_empty() {
+        return vec![ProgressSection::new(value.clamp(0.0, 100.0))];
+    }
+
+    let mut remaining = 100.0_f32;
+    let mut normalized = Vec::with_capacity(sections.len());
+    for section in sections {
+        if remaining <= 0.0 {
+            break;
+        }
+        let value = section.value.clamp(0.0, 100.0).min(remaining);
+        remaining -= value;
+        normalized.push(ProgressSection {
+            value,
+            color: section.color,
+        });
+    }
+    normalized
+}
+
 #[derive(IntoElement)]
 pub struct Progress {
     pub(crate) id: ComponentId,
@@ -41,6 +64,7 @@ pub struct Progress {
     striped: bool,
     animated: bool,
     width_px: Option<f32>,
+    orientation: GroupOrientation,
     variant: Variant,
     size: Size,
     radius: Radius,
@@ -60,6 +84,7 @@ impl Progress {
             striped: false,
             animated: false,
             width_px: None,
+            orientation: GroupOrientation::Horizontal,
             variant: Variant::Filled,
             size: Size::Md,
             radius: Radius::Pill,
@@ -108,13 +133,17 @@ impl Progress {
         self
     }
 
+    /// Length of the bar, measured along its orientation.
     pub fn width(mut self, width_px: f32) -> Self {
         self.width_px = Some(width_px.max(0.0));
         self
     }
 
-    fn normalized_value(value: f32) -> f32 {
-        value.clamp(0.0, 100.0)
+    /// Vertical bars fill from the bottom up and keep the size preset's
+    /// `bar_height` as their thickness.
+    pub fn orientation(mut self, value: GroupOrientation) -> Self {
+        self.orientation = value;
+        self
     }
 
     fn variant_fill_color(&self) -> gpui::Hsla {
@@ -129,42 +158,26 @@ impl Progress {
     }
 
     fn resolved_sections(&self) -> Vec<ProgressSection> {
-        if self.sections.is_empty() {
-            return vec![ProgressSection::new(Self::normalized_value(self.value))];
-        }
-
-        let mut remaining = 100.0_f32;
-        let mut normalized = Vec::with_capacity(self.sections.len());
-        for section in &self.sections {
-            if remaining <= 0.0 {
-                break;
-            }
-            let value = Self::normalized_value(section.value).min(remaining);
-            remaining -= value;
-            normalized.push(ProgressSection {
-                value,
-                color: section.color,
-            });
-        }
-        normalized
+        resolve_sections(self.value, &self.sections)
     }
 
     fn striped_overlay(
         color: gpui::Hsla,
         key: ComponentId,
         filled_ranges: Vec<(f32, f32)>,
-        width_px: f32,
+        length_px: f32,
         bar_height: f32,
+        vertical: bool,
         animated: bool,
     ) -> AnyElement {
-        if filled_ranges.is_empty() || width_px <= 0.0 {
+        if filled_ranges.is_empty() || length_px <= 0.0 {
             return div().id(key).absolute().size_full().into_any_element();
         }
 
         let stripe_width = (bar_height * 1.55).clamp(6.0, 14.0);
         let stripe_step = stripe_width * 1.7;
-        let overlay_width = if animated { width_px * 2.0 } else { width_px };
-        let overlay_offset = if animated { width_px } else { 0.0 };
+        let overlay_length = if animated { length_px * 2.0 } else { length_px };
+        let overlay_offset = if animated { length_px } else { 0.0 };
         let stripe_ranges = filled_ranges;
 
         let stripe_canvas = canvas(
@@ -174,13 +187,13 @@ impl Progress {
                     return;
                 }
 
-                for (range_left, range_width) in &stripe_ranges {
-                    if *range_width <= 0.0 {
+                for (range_start, range_len) in &stripe_ranges {
+                    if *range_len <= 0.0 {
                         continue;
                     }
 
-                    let range_start = overlay_offset + *range_left;
-                    let range_end = range_start + *range_width;
+                    let range_start = overlay_offset + *range_start;
+                    let range_end = range_start + *range_len;
                     let mut x = range_start - stripe_step;
                     let mut stripe_index = 0usize;
 
@@ -196,13 +209,18 @@ impl Progress {
                                 1 => 0.1,
                                 _ => 0.05,
                             };
-                            window.paint_quad(fill(
+                            let stripe_bounds = if vertical {
+                                Bounds::new(
+                                    point(bounds.origin.x, bounds.origin.y + px(snapped_start)),
+                                    size(bounds.size.width, px(snapped_width)),
+                                )
+                            } else {
                                 Bounds::new(
                                     point(bounds.origin.x + px(snapped_start), bounds.origin.y),
                                     size(px(snapped_width), bounds.size.height),
-                                ),
-                                color.alpha(alpha),
-                            ));
+                                )
+                            };
+                            window.paint_quad(fill(stripe_bounds, color.alpha(alpha)));
                         }
                         stripe_index += 1;
                         x += stripe_step;
@@ -214,15 +232,26 @@ impl Progress {
         .size_full();
 
         if animated {
-            let animation_ms = (2800.0 + width_px * 4.0).clamp(3200.0, 5200.0) as u64;
+            let animation_ms = (2800.0 + length_px * 4.0).clamp(3200.0, 5200.0) as u64;
             let move_key = key.slot("move");
-            div()
-                .id(key)
-                .absolute()
-                .top_0()
-                .bottom_0()
-                .left(px(-width_px))
-                .w(px(overlay_width))
+            let overlay = if vertical {
+                div()
+                    .id(key)
+                    .absolute()
+                    .left_0()
+                    .right_0()
+                    .top(px(-length_px))
+                    .h(px(overlay_length))
+            } else {
+                div()
+                    .id(key)
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .left(px(-length_px))
+                    .w(px(overlay_length))
+            };
+            overlay
                 .overflow_hidden()
                 .child(stripe_canvas)
                 .with_animation(
@@ -231,8 +260,12 @@ impl Progress {
                         .repeat()
                         .with_easing(gpui::ease_in_out),
                     move |this, delta| {
-                        let eased = gpui::ease_in_out(delta);
-                        this.left(px(-width_px + width_px * eased))
+                        let offset = px(-length_px + length_px * gpui::ease_in_out(delta));
+                        if vertical {
+                            this.top(offset)
+                        } else {
+                            this.left(offset)
+                        }
                     },
                 )
                 .into_any_element()
@@ -279,22 +312,27 @@ impl RenderOnce for Progress {
             .iter()
             .fold(0.0_f32, |acc, section| acc + section.value);
 
+        let vertical = self.orientation == GroupOrientation::Vertical;
+
         let mut track = div()
             .id(self.id.slot("track"))
             .relative()
-            .w(px(track_width))
-            .h(px(bar_height))
             .overflow_hidden()
             .bg(track_bg);
+        track = if vertical {
+            track.w(px(bar_height)).h(px(track_width))
+        } else {
+            track.w(px(track_width)).h(px(bar_height))
+        };
         track = apply_radius(&self.theme, track, self.radius);
 
-        let mut left = 0.0_f32;
+        let mut start = 0.0_f32;
         let mut filled_ranges = Vec::new();
         for (index, section) in sections.into_iter().enumerate() {
             if section.value <= 0.0 {
                 continue;
             }
-            let width = track_width * (section.value / 100.0);
+            let length = track_width * (section.value / 100.0);
             let fill_color = section
                 .color
                 .as_ref()
@@ -304,14 +342,20 @@ impl RenderOnce for Progress {
             let fill = div()
                 .id(self.id.slot_index("fill", index.to_string()))
                 .absolute()
-                .left(px(left))
-                .top_0()
-                .w(px(width))
-                .h(px(bar_height))
                 .bg(fill_color);
-            filled_ranges.push((left, width));
-
-            left += width;
+            let fill = if vertical {
+                // Sections stack upwards; stripes are laid out from the top.
+                filled_ranges.push((track_width - start - length, length));
+                fill.left_0()
+                    .bottom(px(start))
+                    .w(px(bar_height))
+                    .h(px(length))
+            } else {
+                filled_ranges.push((start, length));
+                fill.left(px(start)).top_0().w(px(length)).h(px(bar_height))
+            };
+
+            start += length;
             track = track.child(fill);
         }
         if self.striped && !filled_ranges.is_empty() {
@@ -322,17 +366,25 @@ impl RenderOnce for Progress {
                 filled_ranges,
                 track_width,
                 bar_height,
+                vertical,
                 self.animated,
             ));
         }
 
         let mut root = Stack::vertical().id(self.id.clone()).gap(tokens.root_gap);
+        if vertical {
+            root = root.items_center();
+        }
 
         if self.label.is_some() || self.show_value {
-            let mut header = Stack::horizontal()
-                .justify_between()
-                .items_center()
-                .w(px(track_width));
+            let mut header = if vertical {
+                Stack::vertical().items_center()
+            } else {
+                Stack::horizontal()
+                    .justify_between()
+                    .items_center()
+                    .w(px(track_width))
+            };
 
             if let Some(label) = self.label {
                 header = header.child(label);
@@ -2,18 +2,21 @@ use std::rc::Rc;
 
 use gpui::InteractiveElement;
 use gpui::{
-    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
-    Window, div, px,
+    FocusHandle, IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement,
+    Styled, Window, div, px,
 };
 
 use crate::contracts::Disableable as _;
 use crate::contracts::{FieldLike, MotionAware, Radiused, Sized, Varianted};
+use crate::focus::FocusOrientation;
 use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
 use crate::motion::MotionConfig;
 use crate::style::{FieldLayout, GroupOrientation, Radius, Size, Variant};
 
 use super::Stack;
 use super::control;
+use super::interaction_adapter::{ActivateHandler, RovingItem, bind_roving_focus};
 use super::selection_state;
 use super::toggle::{ToggleConfig, wire_toggle_handlers};
 use super::utils::resolve_hsla;
@@ -38,6 +41,7 @@ pub struct Radio {
     radius: Radius,
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
+    focus_handle: Option<FocusHandle>,
     on_change: Option<RadioChangeHandler>,
 }
 
@@ -60,6 +64,7 @@ impl Radio {
             radius: Radius::Pill,
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::default(),
+            focus_handle: None,
             on_change: None,
         }
     }
@@ -114,6 +119,12 @@ impl Radio {
         self.default_checked = checked;
         self
     }
+
+    pub fn focus_handle(mut self, value: FocusHandle) -> Self {
+        self.focus_handle = Some(value);
+        self
+    }
+
     pub fn on_change(
         mut self,
         handler: impl Fn(bool, &mut Window, &mut gpui::App) + 'static,
@@ -298,6 +309,9 @@ impl RenderOnce for Radio {
             .id(self.id.clone())
             .focusable()
             .child(content);
+        if let Some(focus_handle) = self.focus_handle.as_ref() {
+            row = row.track_focus(focus_handle);
+        }
 
         if self.disabled {
             row = row.opacity(0.55);
@@ -306,6 +320,7 @@ impl RenderOnce for Radio {
                 row,
                 ToggleConfig {
                     id: self.id.clone(),
+                    component: "radio",
                     checked,
                     controlled: is_controlled,
                     allow_uncheck: false,
@@ -493,7 +508,8 @@ impl RenderOnce for RadioGroup {
         let error_color = resolve_hsla(&self.theme, self.theme.semantic.status_error);
         let selected_value = self.resolved_value();
         let is_controlled = self.value_controlled;
-        let radios = self
+        let focus = crate::provider::CalmProvider::focus(_cx);
+        let (radios, roving): (Vec<_>, Vec<_>) = self
             .options
             .into_iter()
             .enumerate()
@@ -523,44 +539,69 @@ impl RenderOnce for RadioGroup {
                 let value = option.value;
                 let on_change = self.on_change.clone();
                 let id = self.id.clone();
+                let select: ActivateHandler = Rc::new(move |window, cx| {
+                    if selection_state::apply_optional_text(
+                        &id,
+                        "value",
+                        is_controlled,
+                        Some(value.to_string()),
+                    ) {
+                        window.refresh();
+                    }
+                    let event =
+                        InteractionEvent::new("radio_group", &id, InteractionAction::Select);
+                    interaction::emit(event.value(value.clone()), cx);
+                    if let Some(handler) = on_change.as_ref() {
+                        (handler)(value.clone(), window, cx);
+                    }
+                });
+                let select_on_change = select.clone();
                 radio = radio.on_change(move |next, window, cx| {
                     if next {
-                        if selection_state::apply_optional_text(
-                            &id,
-                            "value",
-                            is_controlled,
-                            Some(value.to_string()),
-                        ) {
-                            window.refresh();
-                        }
-                        if let Some(handler) = on_change.as_ref() {
-                            (handler)(value.clone(), window, cx);
-                        }
+                        (select_on_change)(window, cx);
                     }
                 });
-                div().group(self.id.clone()).child(radio)
+                let focus_handle = focus.handle(&self.id, &format!("option-{index}"), _cx);
+                radio = radio.focus_handle(focus_handle.clone());
+                let roving = RovingItem {
+                    id: radio.id.clone(),
+                    focus_handle,
+                    disabled: option.disabled,
+                    on_activate: Some(select),
+                };
+                (div().group(self.id.clone()).child(radio), roving)
             })
-            .collect::<Vec<_>>();
+            .unzip();
 
         let group = match self.orientation {
-            GroupOrientation::Horizontal => div()
-                .id(self.id.clone())
-                .group(self.id.clone())
-                .tab_group()
-                .flex()
-                .flex_row()
-                .items_start()
-                .gap(tokens.group_gap_horizontal)
-                .flex_wrap()
-                .children(radios)
-                .into_any_element(),
-            GroupOrientation::Vertical => Stack::vertical()
-                .id(self.id.clone())
-                .group(self.id.clone())
-                .tab_group()
-                .gap(tokens.group_gap_vertical)
-                .children(radios)
-                .into_any_element(),
+            GroupOrientation::Horizontal => bind_roving_focus(
+                div()
+                    .id(self.id.clone())
+                    .group(self.id.clone())
+                    .tab_group()
+                    .flex()
+                    .flex_row()
+                    .items_start()
+                    .gap(tokens.group_gap_horizontal)
+                    .flex_wrap()
+                    .children(radios),
+                roving,
+                FocusOrientation::Both,
+                true,
+            )
+            .into_any_element(),
+            GroupOrientation::Vertical => bind_roving_focus(
+                Stack::vertical()
+                    .id(self.id.clone())
+                    .group(self.id.clone())
+                    .tab_group()
+                    .gap(tokens.group_gap_vertical)
+                    .children(radios),
+                roving,
+                FocusOrientation::Both,
+                true,
+            )
+            .into_any_element(),
         };
 
         let label = self.label.clone().map(|value| {
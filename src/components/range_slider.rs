@@ -3,20 +3,26 @@ use std::rc::Rc;
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
 use gpui::{
-    AppContext, Bounds, ClickEvent, Corners, EmptyView, IntoElement, ParentElement, RenderOnce,
-    SharedString, Styled, Window, canvas, div, fill, point, px, size,
+    AnyElement, AppContext, Bounds, ClickEvent, Corners, Div, EmptyView, FocusHandle, Hsla,
+    IntoElement, MouseButton, ParentElement, RenderOnce, SharedString, Stateful, Styled, Window,
+    canvas, div, fill, point, px, size,
 };
 
 use crate::contracts::{FieldLike, MotionAware};
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
-use crate::style::{FieldLayout, Radius, Size, Variant};
+use crate::style::{FieldLayout, GroupOrientation, Radius, Size, Variant};
 use crate::theme::SemanticRadiusToken;
 
 use super::Stack;
 use super::control;
+use super::slider::{
+    SliderMark, end_drag, mark_labels, paint_mark_dots, tick_offsets, value_tooltip,
+};
 use super::slider_axis::{self, RailGeometry, SliderAxis};
-use super::utils::{apply_radius, quantized_stroke_px, resolve_hsla, resolve_radius, snap_px};
+use super::utils::{
+    apply_focus_ring, apply_radius, quantized_stroke_px, resolve_hsla, resolve_radius, snap_px,
+};
 
 type ChangeHandler = Rc<dyn Fn((f32, f32), &mut Window, &mut gpui::App)>;
 
@@ -26,12 +32,6 @@ enum RangeThumb {
     Right,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum RangeSliderOrientation {
-    Horizontal,
-    Vertical,
-}
-
 #[derive(Clone)]
 struct RangeSliderDragState {
     slider_id: String,
@@ -39,6 +39,7 @@ struct RangeSliderDragState {
     min: f32,
     max: f32,
     step: f32,
+    snap_marks: Vec<f32>,
     controlled: bool,
     fallback_left: f32,
     fallback_right: f32,
@@ -59,9 +60,15 @@ pub struct RangeSlider {
     required: bool,
     layout: FieldLayout,
     show_value: bool,
+    marks: Vec<SliderMark>,
+    tick_interval: Option<f32>,
+    show_ticks: bool,
+    snap_to_marks: bool,
+    value_tooltip: bool,
     disabled: bool,
     width_px: Option<f32>,
-    orientation: RangeSliderOrientation,
+    orientation: GroupOrientation,
+    focus_handle: Option<FocusHandle>,
     variant: Variant,
     size: Size,
     radius: Radius,
@@ -87,9 +94,15 @@ impl RangeSlider {
             required: false,
             layout: FieldLayout::Vertical,
             show_value: true,
+            marks: Vec::new(),
+            tick_interval: None,
+            show_ticks: true,
+            snap_to_marks: false,
+            value_tooltip: false,
             disabled: false,
             width_px: None,
-            orientation: RangeSliderOrientation::Horizontal,
+            orientation: GroupOrientation::Horizontal,
+            focus_handle: None,
             variant: Variant::Filled,
             size: Size::Md,
             radius: Radius::Pill,
@@ -100,11 +113,11 @@ impl RangeSlider {
     }
 
     pub fn horizontal() -> Self {
-        Self::new().with_orientation(RangeSliderOrientation::Horizontal)
+        Self::new().orientation(GroupOrientation::Horizontal)
     }
 
     pub fn vertical() -> Self {
-        Self::new().with_orientation(RangeSliderOrientation::Vertical)
+        Self::new().orientation(GroupOrientation::Vertical)
     }
 
     pub fn values(mut self, start: f32, end: f32) -> Self {
@@ -165,16 +178,59 @@ impl RangeSlider {
         self.show_value = show_value;
         self
     }
+
+    pub fn mark(mut self, mark: SliderMark) -> Self {
+        self.marks.push(mark);
+        self
+    }
+
+    pub fn marks(mut self, marks: impl IntoIterator<Item = SliderMark>) -> Self {
+        self.marks.extend(marks);
+        self
+    }
+
+    /// Draws tick lines every `interval` instead of at every step.
+    pub fn tick_interval(mut self, interval: f32) -> Self {
+        self.tick_interval = Some(interval.max(0.001));
+        self
+    }
+
+    pub fn show_ticks(mut self, value: bool) -> Self {
+        self.show_ticks = value;
+        self
+    }
+
+    /// Restricts both values to the marks, ignoring the step.
+    pub fn snap_to_marks(mut self, value: bool) -> Self {
+        self.snap_to_marks = value;
+        self
+    }
+
+    /// Shows the value in a bubble over the thumb being dragged.
+    pub fn value_tooltip(mut self, value: bool) -> Self {
+        self.value_tooltip = value;
+        self
+    }
+
+    /// Length of the rail, measured along its orientation.
     pub fn width(mut self, width_px: f32) -> Self {
         self.width_px = Some(width_px.max(0.0));
         self
     }
 
-    fn with_orientation(mut self, value: RangeSliderOrientation) -> Self {
+    /// Vertical sliders put the start value at the bottom.
+    pub fn orientation(mut self, value: GroupOrientation) -> Self {
         self.orientation = value;
         self
     }
 
+    /// Lets the arrow keys move the thumb grabbed last once the rail has
+    /// been clicked.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
     pub fn on_change(
         mut self,
         handler: impl Fn((f32, f32), &mut Window, &mut gpui::App) + 'static,
@@ -183,8 +239,79 @@ impl RangeSlider {
         self
     }
 
-    fn normalize_with(min: f32, max: f32, step: f32, raw: f32) -> f32 {
-        slider_axis::normalize(min, max, step, raw)
+    fn start_drag(drag: &RangeSliderDragState, cx: &mut gpui::App) -> gpui::Entity<EmptyView> {
+        control::set_bool_state(&drag.slider_id, "dragging", true);
+        control::set_bool_state(
+            &drag.slider_id,
+            "dragging-right",
+            drag.thumb == RangeThumb::Right,
+        );
+        cx.new(|_| EmptyView)
+    }
+
+    /// Arrow keys, Page Up/Down and Home/End move the thumb grabbed last,
+    /// which can't be pushed past the other one.
+    fn keyboard_rail(&self, rail: Stateful<Div>, values: (f32, f32)) -> Stateful<Div> {
+        let mut rail = rail.focusable();
+        if let Some(focus_handle) = self.focus_handle.as_ref() {
+            rail = rail.track_focus(focus_handle);
+        }
+        let id = self.id.clone();
+        let id_for_blur = self.id.clone();
+        let focus_handle = self.focus_handle.clone();
+        let min = self.min;
+        let max = self.max;
+        let step = self.step;
+        let marks = self.snap_marks();
+        let controlled = self.values_controlled;
+        let on_change = self.on_change.clone();
+        let id_for_down = self.id.clone();
+        rail.on_mouse_down(MouseButton::Left, move |_, window, cx| {
+            control::set_focused_state(&id_for_down, true);
+            if let Some(focus_handle) = focus_handle.as_ref() {
+                window.focus(focus_handle, cx);
+            }
+            window.refresh();
+        })
+        .on_mouse_down_out(move |_, window, _| {
+            control::set_focused_state(&id_for_blur, false);
+            window.refresh();
+        })
+        .on_key_down(move |event, window, cx| {
+            if !control::is_plain_keystroke(event) {
+                return;
+            }
+            let (left, right) = Self::state_values(&id, values, min, max, step);
+            let moving_right = control::bool_state(&id, "dragging-right", None, false);
+            let Some(target) = slider_axis::keyboard_value(
+                event.keystroke.key.as_str(),
+                event.keystroke.modifiers.shift,
+                if moving_right { right } else { left },
+                min,
+                max,
+                step,
+                &marks,
+            ) else {
+                return;
+            };
+            cx.stop_propagation();
+            window.prevent_default();
+            let next = if moving_right {
+                (left, target.max(left))
+            } else {
+                (target.min(right), right)
+            };
+            if next == (left, right) {
+                return;
+            }
+            if !controlled {
+                Self::set_values_state(&id, next);
+                window.refresh();
+            }
+            if let Some(handler) = on_change.as_ref() {
+                (handler)(next, window, cx);
+            }
+        })
     }
 
     fn normalize_pair_with(min: f32, max: f32, step: f32, left: f32, right: f32) -> (f32, f32) {
@@ -259,9 +386,81 @@ impl RangeSlider {
         }
     }
 
-    fn tick_count(&self) -> usize {
-        let span = (self.max - self.min).max(self.step.max(0.001));
-        ((span / self.step.max(0.001)).round() as usize).clamp(1, 80)
+    fn tick_offsets(&self, axis: SliderAxis, track_len: f32, thumb_size: f32) -> Vec<f32> {
+        if !self.show_ticks {
+            return Vec::new();
+        }
+        tick_offsets(
+            axis,
+            self.min,
+            self.max,
+            self.step,
+            self.tick_interval,
+            track_len,
+            thumb_size,
+        )
+    }
+
+    fn snap_marks(&self) -> Vec<f32> {
+        if !self.snap_to_marks {
+            return Vec::new();
+        }
+        self.marks.iter().map(|mark| mark.value).collect()
+    }
+
+    fn mark_dots(
+        &self,
+        axis: SliderAxis,
+        track_len: f32,
+        thumb_size: f32,
+        values: (f32, f32),
+    ) -> Vec<(f32, Hsla)> {
+        let tokens = &self.theme.components.range_slider;
+        let mark_color = resolve_hsla(&self.theme, tokens.mark_bg);
+        let filled_color = resolve_hsla(&self.theme, tokens.thumb_bg);
+        self.marks
+            .iter()
+            .filter(|mark| (self.min..=self.max).contains(&mark.value))
+            .map(|mark| {
+                let offset =
+                    slider_axis::value_offset(axis, track_len, thumb_size, self.ratio(mark.value));
+                let color = if (values.0..=values.1).contains(&mark.value) {
+                    filled_color
+                } else {
+                    mark_color
+                };
+                (offset, color)
+            })
+            .collect()
+    }
+
+    fn mark_label_row(
+        &self,
+        axis: SliderAxis,
+        track_len: f32,
+        thumb_size: f32,
+    ) -> Option<AnyElement> {
+        let labels = self
+            .marks
+            .iter()
+            .filter(|mark| (self.min..=self.max).contains(&mark.value))
+            .filter_map(|mark| {
+                let offset =
+                    slider_axis::value_offset(axis, track_len, thumb_size, self.ratio(mark.value));
+                mark.label.clone().map(|label| (offset, label))
+            })
+            .collect::<Vec<_>>();
+        if labels.is_empty() {
+            return None;
+        }
+        let tokens = &self.theme.components.range_slider;
+        Some(mark_labels(
+            axis,
+            track_len,
+            labels,
+            resolve_hsla(&self.theme, tokens.mark_label),
+            tokens.mark_label_size,
+        ))
     }
 }
 
@@ -304,7 +503,11 @@ impl RenderOnce for RangeSlider {
         let track_top = ((thumb_size - track_height) * 0.5).max(0.0);
         let left_thumb_x = ((track_len - thumb_size) * left_ratio).max(0.0);
         let right_thumb_x = ((track_len - thumb_size) * right_ratio).max(0.0);
-        let tick_count = self.tick_count();
+        let mark_size = f32::from(tokens.mark_size);
+        let snap_marks = self.snap_marks();
+        let dragging = self.value_tooltip && control::bool_state(&self.id, "dragging", None, false);
+        let dragging_right = control::bool_state(&self.id, "dragging-right", None, false);
+        let thumb_focused = !self.disabled && control::focused_state(&self.id, None, false);
         let tick_color = resolve_hsla(&self.theme, tokens.thumb_border).alpha(0.32);
         let tick_thickness = f32::from(quantized_stroke_px(window, 1.0));
         let track_corner = Corners::all(resolve_radius(
@@ -326,13 +529,15 @@ impl RenderOnce for RangeSlider {
         let has_meta =
             label_text.is_some() || self.show_value || description.is_some() || error.is_some();
 
-        if orientation == RangeSliderOrientation::Vertical {
+        if orientation == GroupOrientation::Vertical {
             let track_left = ((thumb_size - track_height) * 0.5).max(0.0);
             let left_thumb_y = ((track_len - thumb_size) * (1.0 - left_ratio)).max(0.0);
             let right_thumb_y = ((track_len - thumb_size) * (1.0 - right_ratio)).max(0.0);
             let fill_top = right_thumb_y + (thumb_size * 0.5);
             let fill_bottom = left_thumb_y + (thumb_size * 0.5);
             let fill_height = (fill_bottom - fill_top).max(0.0);
+            let ticks = self.tick_offsets(SliderAxis::Vertical, track_len, thumb_size);
+            let dots = self.mark_dots(SliderAxis::Vertical, track_len, thumb_size, values);
             let track_layer = canvas(
                 |_, _, _| (),
                 move |bounds, _, window, _| {
@@ -354,9 +559,8 @@ impl RenderOnce for RangeSlider {
                             .paint_quad(fill(fill_bounds, range_color).corner_radii(track_corner));
                     }
 
-                    if tick_count > 1 && tick_thickness > 0.0 {
-                        for index in 1..tick_count {
-                            let y = track_len * (index as f32 / tick_count as f32);
+                    if tick_thickness > 0.0 {
+                        for y in &ticks {
                             let tick_top = f32::from(snap_px(window, y - (tick_thickness * 0.5)));
                             let tick_bounds = Bounds::new(
                                 point(
@@ -368,6 +572,14 @@ impl RenderOnce for RangeSlider {
                             window.paint_quad(fill(tick_bounds, tick_color));
                         }
                     }
+                    paint_mark_dots(
+                        window,
+                        bounds,
+                        SliderAxis::Vertical,
+                        thumb_size * 0.5,
+                        mark_size,
+                        &dots,
+                    );
                 },
             )
             .absolute()
@@ -399,6 +611,34 @@ impl RenderOnce for RangeSlider {
                 .bg(thumb_bg);
             right_thumb = apply_radius(&self.theme, right_thumb, Radius::Pill);
 
+            if thumb_focused {
+                let thumb_radius =
+                    resolve_radius(&self.theme, SemanticRadiusToken::from(Radius::Pill));
+                if dragging_right {
+                    right_thumb = apply_focus_ring(&self.theme, right_thumb, true, thumb_radius);
+                } else {
+                    left_thumb = apply_focus_ring(&self.theme, left_thumb, true, thumb_radius);
+                }
+            }
+
+            if dragging {
+                let dragged = if dragging_right { values.1 } else { values.0 };
+                let tooltip = value_tooltip(
+                    &self.id,
+                    "value-tooltip",
+                    &self.theme,
+                    SliderAxis::Vertical,
+                    tokens.tooltip_offset,
+                    format!("{dragged:.display_precision$}"),
+                    window,
+                );
+                if dragging_right {
+                    right_thumb = right_thumb.child(tooltip);
+                } else {
+                    left_thumb = left_thumb.child(tooltip);
+                }
+            }
+
             if !self.disabled {
                 let drag_common = |thumb: RangeThumb| RangeSliderDragState {
                     slider_id: self.id.to_string(),
@@ -406,6 +646,7 @@ impl RenderOnce for RangeSlider {
                     min: self.min,
                     max: self.max,
                     step: self.step,
+                    snap_marks: snap_marks.clone(),
                     controlled: is_controlled,
                     fallback_left: values.0,
                     fallback_right: values.1,
@@ -417,8 +658,8 @@ impl RenderOnce for RangeSlider {
                 let slider_id_for_drag_right = self.id.to_string();
 
                 left_thumb = left_thumb
-                    .on_drag(drag_common(RangeThumb::Left), |_drag, _, _, cx| {
-                        cx.new(|_| EmptyView)
+                    .on_drag(drag_common(RangeThumb::Left), |drag, _, _, cx| {
+                        Self::start_drag(drag, cx)
                     })
                     .on_drag_move::<RangeSliderDragState>(move |event, window, cx| {
                         let drag = event.drag(cx);
@@ -443,7 +684,8 @@ impl RenderOnce for RangeSlider {
                             drag.min,
                             drag.max,
                         );
-                        let target = Self::normalize_with(drag.min, drag.max, drag.step, raw);
+                        let target =
+                            slider_axis::snap(drag.min, drag.max, drag.step, &drag.snap_marks, raw);
                         let fallback = (drag.fallback_left, drag.fallback_right);
                         let (_left, right) = Self::state_values(
                             &drag.slider_id,
@@ -464,8 +706,8 @@ impl RenderOnce for RangeSlider {
                     });
 
                 right_thumb = right_thumb
-                    .on_drag(drag_common(RangeThumb::Right), |_drag, _, _, cx| {
-                        cx.new(|_| EmptyView)
+                    .on_drag(drag_common(RangeThumb::Right), |drag, _, _, cx| {
+                        Self::start_drag(drag, cx)
                     })
                     .on_drag_move::<RangeSliderDragState>(move |event, window, cx| {
                         let drag = event.drag(cx);
@@ -490,7 +732,8 @@ impl RenderOnce for RangeSlider {
                             drag.min,
                             drag.max,
                         );
-                        let target = Self::normalize_with(drag.min, drag.max, drag.step, raw);
+                        let target =
+                            slider_axis::snap(drag.min, drag.max, drag.step, &drag.snap_marks, raw);
                         let fallback = (drag.fallback_left, drag.fallback_right);
                         let (left, _right) = Self::state_values(
                             &drag.slider_id,
@@ -547,9 +790,18 @@ impl RenderOnce for RangeSlider {
                 let max = self.max;
                 let step = self.step;
                 let fallback = values;
+                let id_for_up = self.id.clone();
+                let id_for_up_out = self.id.clone();
                 let on_change = self.on_change.clone();
-                rail = rail
+                rail = self
+                    .keyboard_rail(rail, values)
                     .cursor_pointer()
+                    .on_mouse_up(MouseButton::Left, move |_, window, _| {
+                        end_drag(&id_for_up, window)
+                    })
+                    .on_mouse_up_out(MouseButton::Left, move |_, window, _| {
+                        end_drag(&id_for_up_out, window)
+                    })
                     .on_click(move |event: &ClickEvent, window, cx| {
                         let geometry = Self::rail_geometry(&id, thumb_size, track_len);
                         let axis = SliderAxis::Vertical;
@@ -568,10 +820,12 @@ impl RenderOnce for RangeSlider {
                             min,
                             max,
                         );
-                        let target = Self::normalize_with(min, max, step, raw);
+                        let target = slider_axis::snap(min, max, step, &snap_marks, raw);
 
                         let (left, right) = Self::state_values(&id, fallback, min, max, step);
-                        let next = if (target - left).abs() <= (target - right).abs() {
+                        let grab_left = (target - left).abs() <= (target - right).abs();
+                        control::set_bool_state(&id, "dragging-right", !grab_left);
+                        let next = if grab_left {
                             (target.min(right), right)
                         } else {
                             (left, target.max(left))
@@ -632,6 +886,15 @@ impl RenderOnce for RangeSlider {
                 );
             }
 
+            let rail = match self.mark_label_row(SliderAxis::Vertical, track_len, thumb_size) {
+                Some(labels) => Stack::horizontal()
+                    .gap(tokens.mark_label_gap)
+                    .child(rail)
+                    .child(labels)
+                    .into_any_element(),
+                None => rail.into_any_element(),
+            };
+
             return match self.layout {
                 FieldLayout::Vertical => {
                     let mut container = Stack::vertical()
@@ -665,6 +928,8 @@ impl RenderOnce for RangeSlider {
         let fill_left = left_thumb_x + (thumb_size * 0.5);
         let fill_right = right_thumb_x + (thumb_size * 0.5);
         let fill_width = (fill_right - fill_left).max(0.0);
+        let ticks = self.tick_offsets(SliderAxis::Horizontal, track_len, thumb_size);
+        let dots = self.mark_dots(SliderAxis::Horizontal, track_len, thumb_size, values);
         let track_layer = canvas(
             |_, _, _| (),
             move |bounds, _, window, _| {
@@ -685,9 +950,8 @@ impl RenderOnce for RangeSlider {
                     window.paint_quad(fill(fill_bounds, range_color).corner_radii(track_corner));
                 }
 
-                if tick_count > 1 && tick_thickness > 0.0 {
-                    for index in 1..tick_count {
-                        let x = track_len * (index as f32 / tick_count as f32);
+                if tick_thickness > 0.0 {
+                    for x in &ticks {
                         let tick_left = f32::from(snap_px(window, x - (tick_thickness * 0.5)));
                         let tick_bounds = Bounds::new(
                             point(
@@ -699,6 +963,14 @@ impl RenderOnce for RangeSlider {
                         window.paint_quad(fill(tick_bounds, tick_color));
                     }
                 }
+                paint_mark_dots(
+                    window,
+                    bounds,
+                    SliderAxis::Horizontal,
+                    thumb_size * 0.5,
+                    mark_size,
+                    &dots,
+                );
             },
         )
         .absolute()
@@ -730,6 +1002,33 @@ impl RenderOnce for RangeSlider {
             .bg(thumb_bg);
         right_thumb = apply_radius(&self.theme, right_thumb, Radius::Pill);
 
+        if thumb_focused {
+            let thumb_radius = resolve_radius(&self.theme, SemanticRadiusToken::from(Radius::Pill));
+            if dragging_right {
+                right_thumb = apply_focus_ring(&self.theme, right_thumb, true, thumb_radius);
+            } else {
+                left_thumb = apply_focus_ring(&self.theme, left_thumb, true, thumb_radius);
+            }
+        }
+
+        if dragging {
+            let dragged = if dragging_right { values.1 } else { values.0 };
+            let tooltip = value_tooltip(
+                &self.id,
+                "value-tooltip",
+                &self.theme,
+                SliderAxis::Horizontal,
+                tokens.tooltip_offset,
+                format!("{dragged:.display_precision$}"),
+                window,
+            );
+            if dragging_right {
+                right_thumb = right_thumb.child(tooltip);
+            } else {
+                left_thumb = left_thumb.child(tooltip);
+            }
+        }
+
         if !self.disabled {
             let drag_common = |thumb: RangeThumb| RangeSliderDragState {
                 slider_id: self.id.to_string(),
@@ -737,6 +1036,7 @@ impl RenderOnce for RangeSlider {
                 min: self.min,
                 max: self.max,
                 step: self.step,
+                snap_marks: snap_marks.clone(),
                 controlled: is_controlled,
                 fallback_left: values.0,
                 fallback_right: values.1,
@@ -748,8 +1048,8 @@ impl RenderOnce for RangeSlider {
             let slider_id_for_drag_right = self.id.to_string();
 
             left_thumb = left_thumb
-                .on_drag(drag_common(RangeThumb::Left), |_drag, _, _, cx| {
-                    cx.new(|_| EmptyView)
+                .on_drag(drag_common(RangeThumb::Left), |drag, _, _, cx| {
+                    Self::start_drag(drag, cx)
                 })
                 .on_drag_move::<RangeSliderDragState>(move |event, window, cx| {
                     let drag = event.drag(cx);
@@ -774,7 +1074,8 @@ impl RenderOnce for RangeSlider {
                         drag.min,
                         drag.max,
                     );
-                    let target = Self::normalize_with(drag.min, drag.max, drag.step, raw);
+                    let target =
+                        slider_axis::snap(drag.min, drag.max, drag.step, &drag.snap_marks, raw);
 
                     let fallback = (drag.fallback_left, drag.fallback_right);
                     let (left, right) = Self::state_values(
@@ -800,8 +1101,8 @@ impl RenderOnce for RangeSlider {
                 });
 
             right_thumb = right_thumb
-                .on_drag(drag_common(RangeThumb::Right), |_drag, _, _, cx| {
-                    cx.new(|_| EmptyView)
+                .on_drag(drag_common(RangeThumb::Right), |drag, _, _, cx| {
+                    Self::start_drag(drag, cx)
                 })
                 .on_drag_move::<RangeSliderDragState>(move |event, window, cx| {
                     let drag = event.drag(cx);
@@ -826,7 +1127,8 @@ impl RenderOnce for RangeSlider {
                         drag.min,
                         drag.max,
                     );
-                    let target = Self::normalize_with(drag.min, drag.max, drag.step, raw);
+                    let target =
+                        slider_axis::snap(drag.min, drag.max, drag.step, &drag.snap_marks, raw);
 
                     let fallback = (drag.fallback_left, drag.fallback_right);
                     let (left, right) = Self::state_values(
@@ -888,10 +1190,19 @@ impl RenderOnce for RangeSlider {
             let max = self.max;
             let step = self.step;
             let fallback = values;
+            let id_for_up = self.id.clone();
+            let id_for_up_out = self.id.clone();
             let on_change = self.on_change.clone();
 
-            rail = rail
+            rail = self
+                .keyboard_rail(rail, values)
                 .cursor_pointer()
+                .on_mouse_up(MouseButton::Left, move |_, window, _| {
+                    end_drag(&id_for_up, window)
+                })
+                .on_mouse_up_out(MouseButton::Left, move |_, window, _| {
+                    end_drag(&id_for_up_out, window)
+                })
                 .on_click(move |event: &ClickEvent, window, cx| {
                     let geometry = Self::rail_geometry(&id, track_len, thumb_size);
                     let axis = SliderAxis::Horizontal;
@@ -910,10 +1221,12 @@ impl RenderOnce for RangeSlider {
                         min,
                         max,
                     );
-                    let target = Self::normalize_with(min, max, step, raw);
+                    let target = slider_axis::snap(min, max, step, &snap_marks, raw);
 
                     let (left, right) = Self::state_values(&id, fallback, min, max, step);
-                    let next = if (target - left).abs() <= (target - right).abs() {
+                    let grab_left = (target - left).abs() <= (target - right).abs();
+                    control::set_bool_state(&id, "dragging-right", !grab_left);
+                    let next = if grab_left {
                         (target.min(right), right)
                     } else {
                         (left, target.max(left))
@@ -975,6 +1288,15 @@ impl RenderOnce for RangeSlider {
             );
         }
 
+        let rail = match self.mark_label_row(SliderAxis::Horizontal, track_len, thumb_size) {
+            Some(labels) => Stack::vertical()
+                .gap(tokens.mark_label_gap)
+                .child(rail)
+                .child(labels)
+                .into_any_element(),
+            None => rail.into_any_element(),
+        };
+
         match self.layout {
             FieldLayout::Vertical => {
                 let mut container = Stack::vertical()
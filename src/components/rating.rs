@@ -2,9 +2,13 @@ use std::rc::Rc;
 
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
-use gpui::{ClickEvent, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window, div};
+use gpui::{
+    AnyElement, ClickEvent, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window,
+    div, px,
+};
 
 use crate::contracts::{FieldLike, MotionAware};
+use crate::icon::{IconRegistry, IconSource};
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
 use crate::style::{FieldLayout, Radius, Size, Variant};
@@ -12,10 +16,44 @@ use crate::style::{FieldLayout, Radius, Size, Variant};
 use super::Stack;
 use super::control;
 use super::icon::Icon;
+use super::rating_state;
 use super::utils::resolve_hsla;
 
 type ChangeHandler = Rc<dyn Fn(f32, &mut Window, &mut gpui::App)>;
 
+/// Pair of icons a [`Rating`] is drawn with: `empty` for unset positions
+/// and `full` over it, cut to width for fractional values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RatingSymbol {
+    pub(crate) empty: IconSource,
+    pub(crate) full: IconSource,
+}
+
+impl RatingSymbol {
+    pub fn new(empty: IconSource, full: IconSource) -> Self {
+        Self { empty, full }
+    }
+
+    /// Same icon for both states, told apart by the active and inactive
+    /// colors. Handy for a custom SVG from a registered icon pack, such as
+    /// `"brand:flame"`.
+    pub fn single(name: impl Into<String>) -> Self {
+        let source = IconSource::named(name);
+        Self::new(source.clone(), source)
+    }
+
+    pub fn star() -> Self {
+        Self::new(IconSource::named("star"), IconSource::named("star-filled"))
+    }
+
+    pub fn heart() -> Self {
+        Self::new(
+            IconSource::named("heart"),
+            IconSource::named("heart-filled"),
+        )
+    }
+}
+
 #[derive(IntoElement)]
 pub struct Rating {
     pub(crate) id: ComponentId,
@@ -23,8 +61,11 @@ pub struct Rating {
     value_controlled: bool,
     default_value: f32,
     max: usize,
-    allow_half: bool,
+    fractions: usize,
     clearable: bool,
+    symbol: RatingSymbol,
+    icons: IconRegistry,
+    show_value: bool,
     label: Option<SharedString>,
     description: Option<SharedString>,
     error: Option<SharedString>,
@@ -49,8 +90,11 @@ impl Rating {
             value_controlled: false,
             default_value: 0.0,
             max: 5,
-            allow_half: true,
+            fractions: 2,
             clearable: false,
+            symbol: RatingSymbol::star(),
+            icons: IconRegistry::new(),
+            show_value: false,
             label: None,
             description: None,
             error: None,
@@ -83,8 +127,34 @@ impl Rating {
         self
     }
 
+    /// Shorthand for [`Rating::fractions`] of 2, or 1 when `false`.
     pub fn allow_half(mut self, value: bool) -> Self {
-        self.allow_half = value;
+        self.fractions = if value { 2 } else { 1 };
+        self
+    }
+
+    /// Slices each icon can be picked and shown in: 1 for whole icons, 2
+    /// for halves, 4 for quarters. Clicks pick the slice under the pointer.
+    pub fn fractions(mut self, value: usize) -> Self {
+        self.fractions = value.max(1);
+        self
+    }
+
+    pub fn symbol(mut self, value: RatingSymbol) -> Self {
+        self.symbol = value;
+        self
+    }
+
+    /// Registry the symbol's icons are looked up in, for custom packs.
+    pub fn icons(mut self, icons: IconRegistry) -> Self {
+        self.icons = icons;
+        self
+    }
+
+    /// Prints the value after the icons, as in `4.5`, which suits a
+    /// [`read_only`](Rating::read_only) summary.
+    pub fn show_value(mut self, value: bool) -> Self {
+        self.show_value = value;
         self
     }
 
@@ -117,6 +187,7 @@ impl Rating {
         self.layout = value;
         self
     }
+
     pub fn read_only(mut self, value: bool) -> Self {
         self.read_only = value;
         self
@@ -139,6 +210,50 @@ impl Rating {
         control::f32_state(&self.id, "value", controlled, default).clamp(0.0, max)
     }
 
+    fn icon(&self, slot: &str, index: usize, source: IconSource, size: f32) -> Icon {
+        self.id
+            .ctx()
+            .child_index(slot, index.to_string(), Icon::new(source))
+            .registry(self.icons.clone())
+            .size(size)
+    }
+
+    /// Empty icon with the full one laid over it, clipped to `fill` of its
+    /// width.
+    fn render_symbol(
+        &self,
+        index: usize,
+        fill: f32,
+        icon_size: f32,
+        colors: (gpui::Hsla, gpui::Hsla),
+    ) -> AnyElement {
+        let (active, inactive) = colors;
+        let empty = self
+            .icon("empty", index, self.symbol.empty.clone(), icon_size)
+            .color(inactive);
+        let full = self
+            .icon("full", index, self.symbol.full.clone(), icon_size)
+            .color(active);
+        let mut symbol = div()
+            .relative()
+            .w(px(icon_size))
+            .h(px(icon_size))
+            .child(empty);
+        if fill > 0.0 {
+            symbol = symbol.child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .h(px(icon_size))
+                    .w(px(icon_size * fill))
+                    .overflow_hidden()
+                    .child(full),
+            );
+        }
+        symbol.into_any_element()
+    }
+
     fn active_color(&self) -> gpui::Hsla {
         let base = resolve_hsla(&self.theme, self.theme.components.rating.active);
         match self.variant {
@@ -183,139 +298,82 @@ impl RenderOnce for Rating {
         let active = self.active_color();
         let inactive = self.inactive_color();
 
+        let interactive = !self.disabled && !self.read_only;
+        let shown = match rating_state::hover(&self.id) {
+            Some(hovered) if interactive => hovered,
+            _ => value,
+        };
+        let shown = rating_state::quantize(shown, self.fractions);
+
         let stars = (1..=self.max)
             .map(|index| {
-                let index_value = index as f32;
-                let is_full = value >= index_value;
-                let is_half =
-                    self.allow_half && value >= (index_value - 0.5) && value < index_value;
-
-                let icon = self
-                    .id
-                    .ctx()
-                    .child_index(
-                        "star",
-                        index.to_string(),
-                        if is_full {
-                            Icon::named("star-filled")
-                        } else if is_half {
-                            Icon::named("star-half")
-                        } else {
-                            Icon::named("star")
-                        },
-                    )
-                    .size(icon_size)
-                    .color(if is_full || is_half { active } else { inactive });
-
+                let fill = rating_state::fill_ratio(shown, index);
                 let mut cell = div()
                     .id(self.id.slot_index("cell", index.to_string()))
                     .relative()
-                    .child(icon)
-                    .text_color(if is_full || is_half { active } else { inactive });
+                    .child(self.render_symbol(index, fill, icon_size, (active, inactive)));
+
+                if !interactive {
+                    return cell.opacity(0.6).cursor_default();
+                }
 
-                if self.disabled || self.read_only {
-                    cell = cell.opacity(0.6).cursor_default();
-                } else {
+                let slice = icon_size / self.fractions as f32;
+                for part in 0..self.fractions {
+                    let target =
+                        rating_state::target(index, part, self.fractions, value, self.clearable);
                     let id = self.id.clone();
-                    let clearable = self.clearable;
-                    let current = value;
+                    let id_for_hover = self.id.clone();
                     let value_controlled = self.value_controlled;
                     let on_change = self.on_change.clone();
-                    if self.allow_half {
-                        let id_for_left = id.clone();
-                        let id_for_right = id.clone();
-                        let on_change_left = on_change.clone();
-                        let on_change_right = on_change.clone();
-                        let left_target = (index_value - 0.5).max(0.0);
-                        let right_target = index_value;
-                        let left_value = if clearable && (current - left_target).abs() < 0.001 {
-                            0.0
-                        } else {
-                            left_target
-                        };
-                        let right_value = if clearable && (current - right_target).abs() < 0.001 {
-                            0.0
-                        } else {
-                            right_target
-                        };
-
-                        cell = cell
-                            .cursor_pointer()
-                            .child(
-                                div()
-                                    .id(self.id.slot_index("cell-left", index.to_string()))
-                                    .absolute()
-                                    .top_0()
-                                    .left_0()
-                                    .w(gpui::px(icon_size * 0.5))
-                                    .h(gpui::px(icon_size))
-                                    .cursor_pointer()
-                                    .on_click(move |_: &ClickEvent, window, cx| {
-                                        if !value_controlled {
-                                            control::set_text_state(
-                                                &id_for_left,
-                                                "value",
-                                                left_value.to_string(),
-                                            );
-                                            window.refresh();
-                                        }
-                                        if let Some(handler) = on_change_left.as_ref() {
-                                            (handler)(left_value, window, cx);
-                                        }
-                                    }),
-                            )
-                            .child(
-                                div()
-                                    .id(self.id.slot_index("cell-right", index.to_string()))
-                                    .absolute()
-                                    .top_0()
-                                    .right_0()
-                                    .w(gpui::px(icon_size * 0.5))
-                                    .h(gpui::px(icon_size))
-                                    .cursor_pointer()
-                                    .on_click(move |_: &ClickEvent, window, cx| {
-                                        if !value_controlled {
-                                            control::set_text_state(
-                                                &id_for_right,
-                                                "value",
-                                                right_value.to_string(),
-                                            );
-                                            window.refresh();
-                                        }
-                                        if let Some(handler) = on_change_right.as_ref() {
-                                            (handler)(right_value, window, cx);
-                                        }
-                                    }),
-                            );
-                    } else {
-                        let next_value = if clearable && (current - index_value).abs() < 0.001 {
-                            0.0
-                        } else {
-                            index_value
-                        };
-                        cell = cell
+                    cell = cell.child(
+                        div()
+                            .id(self.id.slot_index("cell-part", format!("{index}-{part}")))
+                            .absolute()
+                            .top_0()
+                            .left(px(slice * part as f32))
+                            .w(px(slice))
+                            .h(px(icon_size))
                             .cursor_pointer()
+                            .on_hover(move |hovered, window, _| {
+                                if *hovered {
+                                    rating_state::set_hover(&id_for_hover, target);
+                                } else {
+                                    rating_state::clear_hover(&id_for_hover, target);
+                                }
+                                window.refresh();
+                            })
                             .on_click(move |_: &ClickEvent, window, cx| {
                                 if !value_controlled {
-                                    control::set_text_state(&id, "value", next_value.to_string());
+                                    control::set_f32_state(&id, "value", target);
                                     window.refresh();
                                 }
                                 if let Some(handler) = on_change.as_ref() {
-                                    (handler)(next_value, window, cx);
+                                    (handler)(target, window, cx);
                                 }
-                            });
-                    }
+                            }),
+                    );
                 }
-
-                cell
+                cell.cursor_pointer()
             })
             .collect::<Vec<_>>();
 
+        let value_label = self.show_value.then(|| {
+            div()
+                .ml(tokens.value_gap)
+                .text_size(tokens.value_size)
+                .text_color(resolve_hsla(&self.theme, tokens.value))
+                .child(rating_state::format_value(rating_state::quantize(
+                    value,
+                    self.fractions,
+                )))
+        });
+
         let stars_row = Stack::horizontal()
             .id(self.id.clone())
             .items_center()
             .gap(size_preset.gap)
-            .children(stars);
+            .children(stars)
+            .children(value_label);
 
         let label_text = self.label.map(|label| {
             if self.required {
@@ -0,0 +1,49 @@
+use super::control;
+
+/// Rounds `value` to the nearest `1 / fractions` of an icon.
+pub fn quantize(value: f32, fractions: usize) -> f32 {
+    let fractions = fractions.max(1) as f32;
+    (value * fractions).round() / fractions
+}
+
+/// How much of the icon at 1-based `index` is filled for `value`, from 0 to 1.
+pub fn fill_ratio(value: f32, index: usize) -> f32 {
+    (value - (index as f32 - 1.0)).clamp(0.0, 1.0)
+}
+
+/// Value picked by clicking the `part`th of `fractions` slices of the icon
+/// at 1-based `index`. Clicking the current value again clears it when
+/// `clearable`.
+pub fn target(index: usize, part: usize, fractions: usize, current: f32, clearable: bool) -> f32 {
+    let fractions = fractions.max(1);
+    let value = index as f32 - 1.0 + (part.min(fractions - 1) + 1) as f32 / fractions as f32;
+    if clearable && (current - value).abs() < 0.001 {
+        0.0
+    } else {
+        value
+    }
+}
+
+/// Numeric label for a value, without trailing zeros, e.g. `3.75`, `3.5`
+/// or `4`.
+pub fn format_value(value: f32) -> String {
+    let text = format!("{value:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Value under the pointer, previewed in place of the current one.
+pub fn hover(id: &str) -> Option<f32> {
+    control::optional_f32_state(id, "hover", None, None)
+}
+
+pub fn set_hover(id: &str, target: f32) {
+    control::set_optional_f32_state(id, "hover", Some(target));
+}
+
+/// Ends the preview of `target`, unless the pointer has already moved on to
+/// another slice.
+pub fn clear_hover(id: &str, target: f32) {
+    if hover(id).is_some_and(|value| (value - target).abs() < 0.001) {
+        control::set_optional_f32_state(id, "hover", None);
+    }
+}
@@ -0,0 +1,276 @@
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use gpui::InteractiveElement;
+use gpui::{
+    Animation, AnimationExt, AnyElement, Bounds, Hsla, IntoElement, ParentElement, PathBuilder,
+    Pixels, RenderOnce, Styled, Window, canvas, div, point, px,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::{MotionConfig, MotionLevel};
+use crate::provider::CalmProvider;
+
+use super::progress::{ProgressSection, resolve_sections};
+use super::ring_progress_state;
+use super::utils::resolve_hsla;
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+
+const SPIN_DURATION_MS: u64 = 1000;
+
+/// Circular progress. Defaults to a full ring starting at twelve o'clock;
+/// [`RingProgress::gauge`] gives an open 270° dial.
+#[derive(IntoElement)]
+pub struct RingProgress {
+    pub(crate) id: ComponentId,
+    value: f32,
+    sections: Vec<ProgressSection>,
+    color: Option<Hsla>,
+    size_px: Option<f32>,
+    thickness_px: Option<f32>,
+    start_angle: f32,
+    sweep: f32,
+    show_value: bool,
+    indeterminate: bool,
+    label: Option<SlotRenderer>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl RingProgress {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: 0.0,
+            sections: Vec::new(),
+            color: None,
+            size_px: None,
+            thickness_px: None,
+            start_angle: 0.0,
+            sweep: 360.0,
+            show_value: false,
+            indeterminate: false,
+            label: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    /// A dial open at the bottom, sweeping 270° from the lower left.
+    #[track_caller]
+    pub fn gauge() -> Self {
+        Self::new().start_angle(-135.0).sweep(270.0)
+    }
+
+    /// Filled share of the ring, from 0 to 100.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn section(mut self, section: ProgressSection) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    pub fn sections(mut self, sections: impl IntoIterator<Item = ProgressSection>) -> Self {
+        self.sections.extend(sections);
+        self
+    }
+
+    pub fn clear_sections(mut self) -> Self {
+        self.sections.clear();
+        self
+    }
+
+    /// Fill color for the value and for sections without their own.
+    pub fn color(mut self, value: impl Into<Hsla>) -> Self {
+        self.color = Some(value.into());
+        self
+    }
+
+    /// Outer diameter.
+    pub fn size(mut self, value: f32) -> Self {
+        self.size_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn thickness(mut self, value: f32) -> Self {
+        self.thickness_px = Some(value.max(1.0));
+        self
+    }
+
+    /// Where the ring starts, in degrees clockwise from twelve o'clock.
+    pub fn start_angle(mut self, degrees: f32) -> Self {
+        self.start_angle = degrees;
+        self
+    }
+
+    /// How much of the circle the track covers, in degrees.
+    pub fn sweep(mut self, degrees: f32) -> Self {
+        self.sweep = degrees.clamp(1.0, 360.0);
+        self
+    }
+
+    /// Shows the total percentage in the middle when no label is set.
+    pub fn show_value(mut self, value: bool) -> Self {
+        self.show_value = value;
+        self
+    }
+
+    /// Spins an arc instead of showing a value, for work of unknown length.
+    pub fn indeterminate(mut self, value: bool) -> Self {
+        self.indeterminate = value;
+        self
+    }
+
+    /// Content centered inside the ring.
+    pub fn label(mut self, content: impl IntoElement + 'static) -> Self {
+        self.label = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+}
+
+impl MotionAware for RingProgress {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+/// Strokes an arc along the middle of the ring, starting `start` radians
+/// clockwise from twelve o'clock and covering `span` turns.
+fn paint_arc(
+    window: &mut Window,
+    bounds: Bounds<Pixels>,
+    radius: f32,
+    thickness: f32,
+    (start, span): (f32, f32),
+    color: Hsla,
+) {
+    if span <= 0.0 {
+        return;
+    }
+    let center = bounds.center();
+    let at = |angle: f32| {
+        point(
+            center.x + px(radius * angle.sin()),
+            center.y - px(radius * angle.cos()),
+        )
+    };
+    let steps = (span * 96.0).ceil().max(2.0) as usize;
+    let mut builder = PathBuilder::stroke(px(thickness));
+    builder.move_to(at(start));
+    for step in 1..=steps {
+        builder.line_to(at(start + span * TAU * step as f32 / steps as f32));
+    }
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+impl RenderOnce for RingProgress {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.ring_progress.clone();
+        let colors = &self.theme.components.progress;
+        let track = resolve_hsla(&self.theme, colors.track_bg);
+        let fill = self
+            .color
+            .unwrap_or_else(|| resolve_hsla(&self.theme, colors.fill_bg));
+        let label_fg = resolve_hsla(&self.theme, colors.label);
+        let diameter = self.size_px.unwrap_or(f32::from(tokens.size));
+        let thickness = self
+            .thickness_px
+            .unwrap_or(f32::from(tokens.thickness))
+            .min(diameter / 2.0);
+        let radius = (diameter - thickness) / 2.0;
+        let start = self.start_angle.to_radians();
+        let sweep = self.sweep / 360.0;
+
+        let ring = div().absolute().top_0().left_0().size_full();
+        let ring = if self.indeterminate {
+            let spinner = move |offset: f32| {
+                canvas(
+                    |_, _, _| {},
+                    move |bounds, _, window, _| {
+                        paint_arc(window, bounds, radius, thickness, (start, sweep), track);
+                        let (from, span) = ring_progress_state::spinner_arc(sweep, offset);
+                        paint_arc(
+                            window,
+                            bounds,
+                            radius,
+                            thickness,
+                            (start + from * TAU, span),
+                            fill,
+                        );
+                    },
+                )
+                .absolute()
+                .size_full()
+            };
+            let spins = self.motion.level == MotionLevel::Full
+                && CalmProvider::motion_level(cx) == MotionLevel::Full;
+            if spins {
+                let animation = Animation::new(Duration::from_millis(SPIN_DURATION_MS)).repeat();
+                ring.with_animation(self.id.slot("spin"), animation, move |this, delta| {
+                    this.child(spinner(delta))
+                })
+                .into_any_element()
+            } else {
+                ring.child(spinner(0.0)).into_any_element()
+            }
+        } else {
+            let sections = resolve_sections(self.value, &self.sections)
+                .into_iter()
+                .filter(|section| section.value > 0.0)
+                .map(|section| (section.value / 100.0 * sweep, section.color.unwrap_or(fill)))
+                .collect::<Vec<_>>();
+            let gap = if sections.len() > 1 {
+                f32::from(tokens.section_gap) / (radius * TAU).max(1.0)
+            } else {
+                0.0
+            };
+            ring.child(
+                canvas(
+                    |_, _, _| {},
+                    move |bounds, _, window, _| {
+                        paint_arc(window, bounds, radius, thickness, (start, sweep), track);
+                        let mut from = start;
+                        for (span, color) in &sections {
+                            let drawn = (span - gap).max(0.0);
+                            paint_arc(window, bounds, radius, thickness, (from, drawn), *color);
+                            from += span * TAU;
+                        }
+                    },
+                )
+                .absolute()
+                .size_full(),
+            )
+            .into_any_element()
+        };
+
+        let label = match self.label.take() {
+            Some(render) => Some(render()),
+            None if self.show_value && !self.indeterminate => Some(
+                ring_progress_state::value_label(self.value, &self.sections).into_any_element(),
+            ),
+            None => None,
+        };
+        div()
+            .id(self.id.clone())
+            .relative()
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .size(px(diameter))
+            .text_size(tokens.label_size)
+            .text_color(label_fg)
+            .child(ring)
+            .children(label)
+    }
+}
@@ -0,0 +1,27 @@
+use super::progress::{ProgressSection, resolve_sections};
+
+/// Share of the track the indeterminate arc covers.
+pub const SPINNER_SPAN: f32 = 0.25;
+
+/// Indeterminate arc at animation `offset` (0..1), as `(from, span)` in
+/// turns from the start angle. A full ring wraps around; a partial `sweep`
+/// slides the arc in at the start and out at the end so it stays on the track.
+pub fn spinner_arc(sweep: f32, offset: f32) -> (f32, f32) {
+    let span = SPINNER_SPAN * sweep;
+    if sweep >= 1.0 {
+        return (offset, span);
+    }
+    let head = offset * (sweep + span) - span;
+    let from = head.max(0.0);
+    let to = (head + span).min(sweep);
+    (from, (to - from).max(0.0))
+}
+
+/// Total of the clamped sections, formatted for the center label.
+pub fn value_label(value: f32, sections: &[ProgressSection]) -> String {
+    let total = resolve_sections(value, sections)
+        .iter()
+        .map(|section| section.value)
+        .sum::<f32>();
+    format!("{total:.0}%")
+}
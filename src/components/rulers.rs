@@ -0,0 +1,528 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, AppContext, EmptyView, IntoElement, MouseButton, ParentElement, Pixels, Point,
+    RenderOnce, Styled, Window, canvas, div, px,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::theme::RulersTokens;
+
+pub use super::rulers_state::{GuideAxis, GuideSnap, RulerGuide};
+
+use super::rulers_state;
+use super::utils::resolve_hsla;
+use super::zoom_pane::{ZoomPaneHandle, paint_line};
+use super::zoom_pane_state::ZoomView;
+
+type GuidesChangeHandler = Rc<dyn Fn(&[RulerGuide], &mut Window, &mut gpui::App)>;
+type GuideSnapHandler = Rc<dyn Fn(GuideSnap, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone)]
+struct RulerGuideDrag {
+    rulers_id: String,
+    axis: GuideAxis,
+}
+
+#[derive(Clone)]
+struct GuideDragContext {
+    id: String,
+    handle: Option<ZoomPaneHandle>,
+    controlled: Option<Vec<RulerGuide>>,
+    defaults: Vec<RulerGuide>,
+    snap_step: Option<f32>,
+    snap_targets: Vec<f32>,
+    snap_threshold: f32,
+    ruler_size: f32,
+    on_guides_change: Option<GuidesChangeHandler>,
+    on_snap: Option<GuideSnapHandler>,
+}
+
+impl GuideDragContext {
+    fn view(&self) -> ZoomView {
+        self.handle
+            .as_ref()
+            .map(ZoomPaneHandle::view)
+            .unwrap_or_default()
+    }
+
+    fn guides(&self) -> Vec<RulerGuide> {
+        rulers_state::load_guides(&self.id, self.controlled.as_deref(), &self.defaults)
+    }
+
+    fn commit(&self, guides: &[RulerGuide], window: &mut Window, cx: &mut gpui::App) {
+        if self.controlled.is_none() {
+            rulers_state::store_guides(&self.id, guides);
+        }
+        window.refresh();
+        if let Some(handler) = self.on_guides_change.as_ref() {
+            (handler)(guides, window, cx);
+        }
+    }
+
+    fn drag_to(
+        &self,
+        axis: GuideAxis,
+        position: Point<Pixels>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        let local =
+            rulers_state::store_pointer(&self.id, f32::from(position.x), f32::from(position.y));
+        let active = rulers_state::active_guide(&self.id);
+        if active.is_none() && rulers_state::in_ruler(axis, local, self.ruler_size) {
+            return;
+        }
+
+        let view = self.view();
+        let guides = self.guides();
+        let mut targets = self.snap_targets.clone();
+        targets.extend(
+            guides
+                .iter()
+                .enumerate()
+                .filter(|(index, guide)| guide.axis == axis && Some(*index) != active)
+                .map(|(_, guide)| guide.position),
+        );
+        let (position, snapped) = rulers_state::snap_position(
+            rulers_state::pointer_position(axis, view, local),
+            self.snap_step,
+            &targets,
+            self.snap_threshold / view.zoom.max(0.01),
+        );
+        let (next, index) =
+            rulers_state::upsert_guide(&guides, active, RulerGuide { axis, position });
+        if active != Some(index) {
+            rulers_state::set_active_guide(&self.id, Some(index));
+        }
+        let snapped_now = rulers_state::update_snapped(&self.id, snapped);
+        self.commit(&next, window, cx);
+        if snapped_now && let Some(handler) = self.on_snap.as_ref() {
+            (handler)(
+                GuideSnap {
+                    index,
+                    axis,
+                    position,
+                },
+                window,
+                cx,
+            );
+        }
+    }
+
+    fn drop(&self, axis: GuideAxis, window: &mut Window, cx: &mut gpui::App) {
+        let Some(index) = rulers_state::active_guide(&self.id) else {
+            return;
+        };
+        rulers_state::set_active_guide(&self.id, None);
+        let released = rulers_state::last_pointer(&self.id);
+        if !rulers_state::in_ruler(axis, released, self.ruler_size) {
+            window.refresh();
+            return;
+        }
+        let mut guides = self.guides();
+        if index < guides.len() {
+            guides.remove(index);
+        }
+        self.commit(&guides, window, cx);
+    }
+}
+
+#[derive(IntoElement)]
+pub struct Rulers {
+    pub(crate) id: ComponentId,
+    handle: Option<ZoomPaneHandle>,
+    content: Option<AnyElement>,
+    guides: Option<Vec<RulerGuide>>,
+    default_guides: Vec<RulerGuide>,
+    show_guides: bool,
+    snap_step: Option<f32>,
+    snap_targets: Vec<f32>,
+    snap_threshold: f32,
+    height_px: Option<f32>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_guides_change: Option<GuidesChangeHandler>,
+    on_snap: Option<GuideSnapHandler>,
+}
+
+impl Rulers {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            handle: None,
+            content: None,
+            guides: None,
+            default_guides: Vec::new(),
+            show_guides: true,
+            snap_step: None,
+            snap_targets: Vec::new(),
+            snap_threshold: 6.0,
+            height_px: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_guides_change: None,
+            on_snap: None,
+        }
+    }
+
+    pub fn handle(mut self, handle: ZoomPaneHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    pub fn content(mut self, content: impl IntoElement) -> Self {
+        self.content = Some(content.into_any_element());
+        self
+    }
+
+    pub fn guides(mut self, guides: impl IntoIterator<Item = RulerGuide>) -> Self {
+        self.guides = Some(guides.into_iter().collect());
+        self
+    }
+
+    pub fn default_guides(mut self, guides: impl IntoIterator<Item = RulerGuide>) -> Self {
+        self.default_guides = guides.into_iter().collect();
+        self
+    }
+
+    pub fn show_guides(mut self, value: bool) -> Self {
+        self.show_guides = value;
+        self
+    }
+
+    pub fn snap_step(mut self, value: f32) -> Self {
+        self.snap_step = (value > 0.0).then_some(value);
+        self
+    }
+
+    pub fn snap_to(mut self, targets: impl IntoIterator<Item = f32>) -> Self {
+        self.snap_targets = targets.into_iter().collect();
+        self
+    }
+
+    pub fn snap_threshold(mut self, value: f32) -> Self {
+        self.snap_threshold = value.max(0.0);
+        self
+    }
+
+    pub fn height(mut self, value: f32) -> Self {
+        self.height_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn on_guides_change(
+        mut self,
+        handler: impl Fn(&[RulerGuide], &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_guides_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_snap(
+        mut self,
+        handler: impl Fn(GuideSnap, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_snap = Some(Rc::new(handler));
+        self
+    }
+
+    fn drag_context(&self, ruler_size: f32) -> GuideDragContext {
+        GuideDragContext {
+            id: self.id.to_string(),
+            handle: self.handle.clone(),
+            controlled: self.guides.clone(),
+            defaults: self.default_guides.clone(),
+            snap_step: self.snap_step,
+            snap_targets: self.snap_targets.clone(),
+            snap_threshold: self.snap_threshold,
+            ruler_size,
+            on_guides_change: self.on_guides_change.clone(),
+            on_snap: self.on_snap.clone(),
+        }
+    }
+
+    fn render_strip(&self, axis: GuideAxis, view: ZoomView, tokens: &RulersTokens) -> AnyElement {
+        let ruler_size = f32::from(tokens.size);
+        let (extent_w, extent_h) = rulers_state::extent(&self.id);
+        let marks = match axis {
+            GuideAxis::Horizontal => rulers_state::ruler_marks(
+                view.offset_x,
+                view.zoom,
+                f32::from(tokens.spacing),
+                extent_w,
+            ),
+            GuideAxis::Vertical => rulers_state::ruler_marks(
+                view.offset_y,
+                view.zoom,
+                f32::from(tokens.spacing),
+                extent_h,
+            ),
+        };
+        let tick = resolve_hsla(&self.theme, tokens.tick);
+        let tick_major = resolve_hsla(&self.theme, tokens.tick_major);
+        let border = resolve_hsla(&self.theme, tokens.border);
+        let label_color = resolve_hsla(&self.theme, tokens.label);
+
+        let ticks = canvas(|_, _, _| (), {
+            let marks = marks.clone();
+            move |bounds, _, window, _| {
+                let width = f32::from(bounds.size.width);
+                let height = f32::from(bounds.size.height);
+                let origin = bounds.origin;
+                for mark in &marks {
+                    let (len, color) = if mark.major {
+                        (ruler_size * 0.6, tick_major)
+                    } else {
+                        (ruler_size * 0.3, tick)
+                    };
+                    match axis {
+                        GuideAxis::Horizontal => paint_line(
+                            window,
+                            (origin.x + px(mark.offset), origin.y + px(height - len)),
+                            (1.0, len),
+                            color,
+                        ),
+                        GuideAxis::Vertical => paint_line(
+                            window,
+                            (origin.x + px(width - len), origin.y + px(mark.offset)),
+                            (len, 1.0),
+                            color,
+                        ),
+                    }
+                }
+                match axis {
+                    GuideAxis::Horizontal => paint_line(
+                        window,
+                        (origin.x, origin.y + px(height - 1.0)),
+                        (width, 1.0),
+                        border,
+                    ),
+                    GuideAxis::Vertical => paint_line(
+                        window,
+                        (origin.x + px(width - 1.0), origin.y),
+                        (1.0, height),
+                        border,
+                    ),
+                }
+            }
+        })
+        .absolute()
+        .size_full();
+
+        let labels = marks.into_iter().filter(|mark| mark.major).map(|mark| {
+            let label = div()
+                .absolute()
+                .text_size(tokens.label_size)
+                .text_color(label_color)
+                .child(rulers_state::mark_label(mark.value));
+            match axis {
+                GuideAxis::Horizontal => label.top_0().left(px(mark.offset + 2.0)),
+                GuideAxis::Vertical => label.left(px(1.0)).top(px(mark.offset + 1.0)),
+            }
+        });
+
+        let slot = match axis {
+            GuideAxis::Horizontal => "ruler-top",
+            GuideAxis::Vertical => "ruler-left",
+        };
+        let mut strip = div()
+            .id(self.id.slot(slot))
+            .relative()
+            .overflow_hidden()
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .child(ticks)
+            .children(labels);
+        strip = match axis {
+            GuideAxis::Horizontal => strip.flex_1().h_full().cursor_ns_resize(),
+            GuideAxis::Vertical => strip.w(tokens.size).h_full().cursor_ew_resize(),
+        };
+        let rulers_id = self.id.to_string();
+        strip
+            .on_mouse_down(MouseButton::Left, {
+                let rulers_id = rulers_id.clone();
+                move |_, _, _| rulers_state::set_active_guide(&rulers_id, None)
+            })
+            .on_drag(RulerGuideDrag { rulers_id, axis }, |_, _, _, cx| {
+                cx.new(|_| EmptyView)
+            })
+            .into_any_element()
+    }
+
+    fn render_guide(
+        &self,
+        index: usize,
+        guide: RulerGuide,
+        view: ZoomView,
+        tokens: &RulersTokens,
+    ) -> AnyElement {
+        let hit = f32::from(tokens.guide_hit_width);
+        let offset = rulers_state::guide_offset(guide, view) - hit * 0.5;
+        let snapped = rulers_state::active_guide(&self.id) == Some(index)
+            && rulers_state::is_snapped(&self.id);
+        let color = resolve_hsla(
+            &self.theme,
+            if snapped {
+                tokens.guide_snapped
+            } else {
+                tokens.guide
+            },
+        );
+
+        let line = div().bg(color);
+        let mut handle = div()
+            .id(self.id.slot_index("guide", index.to_string()))
+            .absolute()
+            .flex()
+            .items_center()
+            .justify_center();
+        handle = match guide.axis {
+            GuideAxis::Horizontal => handle
+                .left_0()
+                .w_full()
+                .top(px(offset))
+                .h(px(hit))
+                .cursor_ns_resize()
+                .child(line.w_full().h(px(1.0))),
+            GuideAxis::Vertical => handle
+                .top_0()
+                .h_full()
+                .left(px(offset))
+                .w(px(hit))
+                .cursor_ew_resize()
+                .child(line.h_full().w(px(1.0))),
+        };
+        let rulers_id = self.id.to_string();
+        handle
+            .on_mouse_down(MouseButton::Left, {
+                let rulers_id = rulers_id.clone();
+                move |_, _, cx| {
+                    rulers_state::set_active_guide(&rulers_id, Some(index));
+                    cx.stop_propagation();
+                }
+            })
+            .on_drag(
+                RulerGuideDrag {
+                    rulers_id,
+                    axis: guide.axis,
+                },
+                |_, _, _, cx| cx.new(|_| EmptyView),
+            )
+            .into_any_element()
+    }
+}
+
+impl MotionAware for Rulers {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for Rulers {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.rulers.clone();
+        let ruler_size = f32::from(tokens.size);
+        let context = self.drag_context(ruler_size);
+        let view = context.view();
+
+        let bounds_probe = canvas(
+            {
+                let id = self.id.to_string();
+                move |bounds, _, _| {
+                    rulers_state::store_bounds(
+                        &id,
+                        (f32::from(bounds.origin.x), f32::from(bounds.origin.y)),
+                        (f32::from(bounds.size.width), f32::from(bounds.size.height)),
+                    );
+                }
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let guides = if self.show_guides {
+            context
+                .guides()
+                .into_iter()
+                .enumerate()
+                .map(|(index, guide)| self.render_guide(index, guide, view, &tokens))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut stage = div()
+            .relative()
+            .flex_1()
+            .h_full()
+            .overflow_hidden()
+            .child(bounds_probe);
+        if let Some(content) = self.content.take() {
+            stage = stage.child(div().absolute().size_full().child(content));
+        }
+
+        let corner = div()
+            .flex_none()
+            .w(tokens.size)
+            .h_full()
+            .bg(resolve_hsla(&self.theme, tokens.bg));
+        let top = div()
+            .flex()
+            .flex_none()
+            .w_full()
+            .h(tokens.size)
+            .child(corner)
+            .child(self.render_strip(GuideAxis::Horizontal, view, &tokens));
+        let body = div()
+            .flex()
+            .flex_1()
+            .min_h_0()
+            .w_full()
+            .child(self.render_strip(GuideAxis::Vertical, view, &tokens))
+            .child(stage.children(guides));
+
+        let mut root = div()
+            .id(self.id.clone())
+            .relative()
+            .flex()
+            .flex_col()
+            .w_full()
+            .overflow_hidden()
+            .child(top)
+            .child(body);
+        root = match self.height_px {
+            Some(height) => root.h(px(height)),
+            None => root.h_full(),
+        };
+
+        let rulers_id = self.id.to_string();
+        root.on_drag_move::<RulerGuideDrag>({
+            let context = context.clone();
+            let rulers_id = rulers_id.clone();
+            move |event, window, cx| {
+                let drag = event.drag(cx);
+                if drag.rulers_id != rulers_id {
+                    return;
+                }
+                let axis = drag.axis;
+                context.drag_to(axis, event.event.position, window, cx);
+            }
+        })
+        .on_drop::<RulerGuideDrag>(move |drag, window, cx| {
+            if drag.rulers_id != rulers_id {
+                return;
+            }
+            context.drop(drag.axis, window, cx);
+        })
+        .with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
@@ -0,0 +1,222 @@
+use super::control;
+use super::zoom_pane_state::{self, ZoomView};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum GuideAxis {
+    Horizontal,
+    Vertical,
+}
+
+impl GuideAxis {
+    fn key(self) -> &'static str {
+        match self {
+            Self::Horizontal => "h",
+            Self::Vertical => "v",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RulerGuide {
+    pub axis: GuideAxis,
+    pub position: f32,
+}
+
+impl RulerGuide {
+    pub fn horizontal(y: f32) -> Self {
+        Self {
+            axis: GuideAxis::Horizontal,
+            position: y,
+        }
+    }
+
+    pub fn vertical(x: f32) -> Self {
+        Self {
+            axis: GuideAxis::Vertical,
+            position: x,
+        }
+    }
+
+    fn encode(self) -> String {
+        format!("{}:{}", self.axis.key(), self.position)
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        let (axis, position) = value.split_once(':')?;
+        let position = position.parse().ok()?;
+        match axis {
+            "h" => Some(Self::horizontal(position)),
+            "v" => Some(Self::vertical(position)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GuideSnap {
+    pub index: usize,
+    pub axis: GuideAxis,
+    pub position: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RulerMark {
+    pub offset: f32,
+    pub major: bool,
+    pub value: f32,
+}
+
+pub fn ruler_marks(offset: f32, zoom: f32, spacing: f32, extent: f32) -> Vec<RulerMark> {
+    let zoom = zoom.max(0.01);
+    zoom_pane_state::grid_lines(offset, zoom, spacing, extent)
+        .into_iter()
+        .map(|(mark, major)| RulerMark {
+            offset: mark,
+            major,
+            value: (mark - offset) / zoom,
+        })
+        .collect()
+}
+
+pub fn mark_label(value: f32) -> String {
+    let rounded = value.round();
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        format!("{rounded}")
+    }
+}
+
+pub fn guide_offset(guide: RulerGuide, view: ZoomView) -> f32 {
+    match guide.axis {
+        GuideAxis::Horizontal => guide.position * view.zoom + view.offset_y,
+        GuideAxis::Vertical => guide.position * view.zoom + view.offset_x,
+    }
+}
+
+pub fn pointer_position(axis: GuideAxis, view: ZoomView, local: (f32, f32)) -> f32 {
+    let (x, y) = view.to_content(local.0, local.1);
+    match axis {
+        GuideAxis::Horizontal => y,
+        GuideAxis::Vertical => x,
+    }
+}
+
+pub fn snap_position(
+    position: f32,
+    step: Option<f32>,
+    targets: &[f32],
+    threshold: f32,
+) -> (f32, bool) {
+    let nearest_target = targets
+        .iter()
+        .copied()
+        .filter(|target| (target - position).abs() <= threshold)
+        .min_by(|a, b| (a - position).abs().total_cmp(&(b - position).abs()));
+    if let Some(target) = nearest_target {
+        return (target, true);
+    }
+    if let Some(step) = step.filter(|step| *step > 0.0) {
+        let snapped = (position / step).round() * step;
+        if (snapped - position).abs() <= threshold {
+            return (snapped, true);
+        }
+    }
+    (position, false)
+}
+
+pub fn upsert_guide(
+    guides: &[RulerGuide],
+    index: Option<usize>,
+    guide: RulerGuide,
+) -> (Vec<RulerGuide>, usize) {
+    let mut next = guides.to_vec();
+    match index.filter(|index| *index < next.len()) {
+        Some(index) => {
+            next[index] = guide;
+            (next, index)
+        }
+        None => {
+            next.push(guide);
+            let index = next.len() - 1;
+            (next, index)
+        }
+    }
+}
+
+pub fn in_ruler(axis: GuideAxis, local: (f32, f32), ruler_size: f32) -> bool {
+    match axis {
+        GuideAxis::Horizontal => local.1 < 0.0 && local.1 >= -ruler_size,
+        GuideAxis::Vertical => local.0 < 0.0 && local.0 >= -ruler_size,
+    }
+}
+
+pub fn load_guides(
+    id: &str,
+    controlled: Option<&[RulerGuide]>,
+    default: &[RulerGuide],
+) -> Vec<RulerGuide> {
+    let encode = |guides: &[RulerGuide]| guides.iter().map(|guide| guide.encode()).collect();
+    control::list_state(id, "guides", controlled.map(encode), encode(default))
+        .iter()
+        .filter_map(|value| RulerGuide::decode(value))
+        .collect()
+}
+
+pub fn store_guides(id: &str, guides: &[RulerGuide]) {
+    control::set_list_state(
+        id,
+        "guides",
+        guides.iter().map(|guide| guide.encode()).collect(),
+    );
+}
+
+pub fn active_guide(id: &str) -> Option<usize> {
+    control::optional_usize_state(id, "active-guide", None, None)
+}
+
+pub fn set_active_guide(id: &str, index: Option<usize>) {
+    control::set_optional_usize_state(id, "active-guide", index);
+    control::set_bool_state(id, "snapped", false);
+}
+
+pub fn is_snapped(id: &str) -> bool {
+    control::bool_state(id, "snapped", None, false)
+}
+
+pub fn update_snapped(id: &str, snapped: bool) -> bool {
+    let previous = is_snapped(id);
+    control::set_bool_state(id, "snapped", snapped);
+    snapped && !previous
+}
+
+pub fn store_bounds(id: &str, origin: (f32, f32), size: (f32, f32)) {
+    control::set_f32_state(id, "origin-x", origin.0);
+    control::set_f32_state(id, "origin-y", origin.1);
+    control::set_f32_state(id, "extent-w", size.0);
+    control::set_f32_state(id, "extent-h", size.1);
+}
+
+pub fn extent(id: &str) -> (f32, f32) {
+    (
+        control::f32_state(id, "extent-w", None, 0.0),
+        control::f32_state(id, "extent-h", None, 0.0),
+    )
+}
+
+pub fn store_pointer(id: &str, x: f32, y: f32) -> (f32, f32) {
+    let local = (
+        x - control::f32_state(id, "origin-x", None, 0.0),
+        y - control::f32_state(id, "origin-y", None, 0.0),
+    );
+    control::set_f32_state(id, "pointer-x", local.0);
+    control::set_f32_state(id, "pointer-y", local.1);
+    local
+}
+
+pub fn last_pointer(id: &str) -> (f32, f32) {
+    (
+        control::f32_state(id, "pointer-x", None, 0.0),
+        control::f32_state(id, "pointer-y", None, 0.0),
+    )
+}
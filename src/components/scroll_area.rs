@@ -1,6 +1,8 @@
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
-use gpui::{AnyElement, IntoElement, ParentElement, RenderOnce, Styled, Window, div, px};
+use gpui::{
+    AnyElement, IntoElement, ParentElement, RenderOnce, ScrollHandle, Styled, Window, div, px,
+};
 
 use crate::id::ComponentId;
 use crate::style::Size;
@@ -23,6 +25,7 @@ pub struct ScrollArea {
     direction: ScrollDirection,
     show_scrollbars: bool,
     bordered: bool,
+    scroll_handle: Option<ScrollHandle>,
     pub(crate) theme: crate::theme::LocalTheme,
     children: Vec<AnyElement>,
 }
@@ -38,6 +41,7 @@ impl ScrollArea {
             direction: ScrollDirection::Vertical,
             show_scrollbars: true,
             bordered: true,
+            scroll_handle: None,
             theme: crate::theme::LocalTheme::default(),
             children: Vec::new(),
         }
@@ -73,6 +77,11 @@ impl ScrollArea {
         self
     }
 
+    pub fn scroll_handle(mut self, handle: ScrollHandle) -> Self {
+        self.scroll_handle = Some(handle);
+        self
+    }
+
     pub fn child(mut self, content: impl IntoElement + 'static) -> Self {
         self.children.push(content.into_any_element());
         self
@@ -118,6 +127,10 @@ impl RenderOnce for ScrollArea {
             viewport = viewport.w(px(width));
         }
 
+        if let Some(handle) = self.scroll_handle.as_ref() {
+            viewport = viewport.track_scroll(handle);
+        }
+
         if !self.show_scrollbars {
             viewport = viewport.scrollbar_width(px(0.0));
         }
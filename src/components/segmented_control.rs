@@ -4,16 +4,21 @@ use gpui::InteractiveElement;
 use gpui::{IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window, div, px};
 
 use crate::contracts::{FieldLike, MotionAware};
+use crate::focus::FocusOrientation;
 use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
 use crate::motion::{MotionConfig, MotionLevel, TransitionPreset};
 use crate::style::{FieldLayout, Radius, Size, Variant};
 
 use super::Stack;
-use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
+use super::interaction_adapter::{
+    ActivateHandler, PressAdapter, RovingItem, bind_press_adapter, bind_roving_focus,
+};
 use super::selection_state;
 use super::transition::{TransitionExt, TransitionStage};
 use super::utils::{
-    InteractionStyles, apply_interaction_styles, apply_radius, interaction_style, resolve_hsla,
+    InteractionStyles, apply_disabled_state, apply_interaction_styles, apply_radius,
+    interaction_style, resolve_hsla,
 };
 
 type ChangeHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
@@ -229,11 +234,14 @@ impl RenderOnce for SegmentedControl {
             selection_state::resolve_optional_usize(&self.id, "prev-index", None, None);
         let divider_height = size_preset.divider_height;
 
-        let items = self
+        let focus = crate::provider::CalmProvider::focus(_cx);
+        let (items, roving): (Vec<_>, Vec<_>) = self
             .items
             .into_iter()
             .enumerate()
             .map(|(index, item)| {
+                let focus_handle = focus.handle(&self.id, &format!("item-{index}"), _cx);
+                let mut roving_activate = None;
                 let is_active = selected
                     .as_ref()
                     .is_some_and(|value| value.as_ref() == item.value.as_ref());
@@ -333,10 +341,14 @@ impl RenderOnce for SegmentedControl {
                     let value = item.value.clone();
                     let id = control_id.clone();
                     let previous = selected_index;
-                    let hover_bg = resolve_hsla(&theme, tokens.item_hover_bg);
-                    let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
+                    let hover_bg = tokens
+                        .states
+                        .hover_bg_or(resolve_hsla(&theme, tokens.item_hover_bg));
+                    let press_bg = tokens
+                        .states
+                        .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
                     let focus_bg = if is_active {
-                        active_bg.blend(gpui::white().opacity(0.04))
+                        crate::theme::color_utils::lighten(active_bg, 0.04)
                     } else {
                         hover_bg
                     };
@@ -355,6 +367,12 @@ impl RenderOnce for SegmentedControl {
                         ) {
                             window.refresh();
                         }
+                        let event = InteractionEvent::new(
+                            "segmented_control",
+                            &id,
+                            InteractionAction::Select,
+                        );
+                        interaction::emit(event.value(value.clone()), cx);
                         if let Some(handler) = on_change.as_ref() {
                             (handler)(value.clone(), window, cx);
                         }
@@ -369,18 +387,26 @@ impl RenderOnce for SegmentedControl {
                     }
                     segment =
                         apply_interaction_styles(segment.cursor_pointer(), interaction_styles);
+                    roving_activate = Some(activate_handler.clone());
                     segment = bind_press_adapter(
                         segment,
                         PressAdapter::new(self.id.slot_index("item", index.to_string()))
-                            .on_activate(Some(activate_handler)),
+                            .on_activate(Some(activate_handler))
+                            .focus_handle(Some(focus_handle.clone())),
                     );
                 } else {
-                    segment = segment.opacity(0.5).cursor_default();
+                    segment = apply_disabled_state(segment.cursor_default(), tokens.states, 0.5);
                 }
 
-                segment
+                let roving = RovingItem {
+                    id: self.id.slot_index("item", index.to_string()).into(),
+                    focus_handle,
+                    disabled: item.disabled,
+                    on_activate: roving_activate,
+                };
+                (segment, roving)
             })
-            .collect::<Vec<_>>();
+            .unzip();
 
         let mut track = div()
             .id(root_id.slot("track"))
@@ -395,6 +421,7 @@ impl RenderOnce for SegmentedControl {
         }
 
         track = apply_radius(&self.theme, track, self.radius);
+        track = bind_roving_focus(track, roving, FocusOrientation::Horizontal, true);
 
         let track = track.into_any_element();
         let label = self.label.map(|value| {
@@ -11,6 +11,7 @@ use gpui::{
 use crate::contracts::{FieldLike, MotionAware};
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
+use crate::resource::Resource;
 use crate::style::{FieldLayout, Radius, Size, Variant};
 use crate::theme::{SelectTokens, Theme};
 
@@ -21,8 +22,8 @@ use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapt
 use super::popup::{PopupPlacement, anchored_host};
 use super::select_state::{self, SelectState, SelectStateInput};
 use super::utils::{
-    InteractionStyles, apply_field_size, apply_interaction_styles, apply_radius, interaction_style,
-    resolve_hsla,
+    InteractionStyles, apply_disabled_state, apply_field_size, apply_interaction_styles,
+    apply_radius, interaction_style, resolve_hsla,
 };
 
 type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
@@ -234,6 +235,11 @@ impl Select {
         self
     }
 
+    pub fn options_resource(mut self, resource: &Resource<Vec<SelectOption>>) -> Self {
+        self.options.extend(resource.value().unwrap_or_default());
+        self
+    }
+
     pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
         self.placeholder = Some(placeholder.into());
         self
@@ -384,7 +390,7 @@ impl Select {
         }
 
         if self.disabled {
-            control = control.cursor_default().opacity(0.55);
+            control = apply_disabled_state(control.cursor_default(), tokens.states, 0.55);
         } else {
             let activate_handler = if let Some(handler) = self.on_open_change.clone() {
                 let next = !opened;
@@ -421,12 +427,18 @@ impl Select {
             };
 
             if let Some(activate_handler) = activate_handler {
-                let hover_bg = control_bg.blend(gpui::white().opacity(0.04));
-                let press_bg = control_bg.blend(gpui::black().opacity(0.08));
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(crate::theme::color_utils::lighten(control_bg, 0.04));
+                let press_bg = tokens
+                    .states
+                    .active_bg_or(crate::theme::color_utils::darken(control_bg, 0.08));
                 let focus_border = if self.error.is_some() {
                     resolve_hsla(&self.theme, tokens.border_error)
                 } else {
-                    resolve_hsla(&self.theme, tokens.border_focus)
+                    tokens
+                        .states
+                        .focus_border_or(resolve_hsla(&self.theme, tokens.border_focus))
                 };
                 control = apply_interaction_styles(
                     control.cursor_pointer(),
@@ -538,7 +550,9 @@ impl Select {
                     } else {
                         resolve_hsla(&self.theme, gpui::transparent_black())
                     };
-                    let hover_bg = resolve_hsla(&self.theme, tokens.option_hover_bg);
+                    let hover_bg = tokens
+                        .states
+                        .hover_bg_or(resolve_hsla(&self.theme, tokens.option_hover_bg));
 
                     let mut row = div()
                         .id(row_id.clone())
@@ -591,7 +605,9 @@ impl Select {
                         let id = self.id.clone();
                         let value_controlled = self.value_controlled;
                         let opened_controlled = self.opened_controlled;
-                        let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
+                        let press_bg = tokens
+                            .states
+                            .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
                         let activate_handler: ActivateHandler =
                             Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
                                 if select_state::apply_single_option_commit(
@@ -752,25 +768,21 @@ impl RenderOnce for Select {
                 anchored_host(
                     &self.id,
                     "anchor-host",
-                    PopupPlacement::Top,
-                    f32::from(dropdown_anchor_offset),
-                    popup_snap_margin,
+                    PopupPlacement::Top
+                        .options(f32::from(dropdown_anchor_offset), popup_snap_margin),
                     floating,
                     24,
                     true,
-                    true,
                 )
             } else {
                 anchored_host(
                     &self.id,
                     "anchor-host",
-                    PopupPlacement::Bottom,
-                    f32::from(dropdown_anchor_offset),
-                    popup_snap_margin,
+                    PopupPlacement::Bottom
+                        .options(f32::from(dropdown_anchor_offset), popup_snap_margin),
                     floating,
                     24,
                     true,
-                    true,
                 )
             };
             trigger = trigger.child(anchor_host);
@@ -1040,7 +1052,7 @@ impl MultiSelect {
         }
 
         if self.disabled {
-            control = control.cursor_default().opacity(0.55);
+            control = apply_disabled_state(control.cursor_default(), tokens.states, 0.55);
         } else {
             let activate_handler = if let Some(handler) = self.on_open_change.clone() {
                 let next = !opened;
@@ -1077,12 +1089,18 @@ impl MultiSelect {
             };
 
             if let Some(activate_handler) = activate_handler {
-                let hover_bg = control_bg.blend(gpui::white().opacity(0.04));
-                let press_bg = control_bg.blend(gpui::black().opacity(0.08));
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(crate::theme::color_utils::lighten(control_bg, 0.04));
+                let press_bg = tokens
+                    .states
+                    .active_bg_or(crate::theme::color_utils::darken(control_bg, 0.08));
                 let focus_border = if self.error.is_some() {
                     resolve_hsla(&self.theme, tokens.border_error)
                 } else {
-                    resolve_hsla(&self.theme, tokens.border_focus)
+                    tokens
+                        .states
+                        .focus_border_or(resolve_hsla(&self.theme, tokens.border_focus))
                 };
                 control = apply_interaction_styles(
                     control.cursor_pointer(),
@@ -1214,7 +1232,9 @@ impl MultiSelect {
                 } else {
                     resolve_hsla(&self.theme, gpui::transparent_black())
                 };
-                let hover_bg = resolve_hsla(&self.theme, tokens.option_hover_bg);
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(resolve_hsla(&self.theme, tokens.option_hover_bg));
 
                 let mut row = div()
                     .id(row_id.clone())
@@ -1267,7 +1287,9 @@ impl MultiSelect {
                     let selected_values = current_values.clone();
                     let id = self.id.clone();
                     let values_controlled = self.values_controlled;
-                    let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
+                    let press_bg = tokens
+                        .states
+                        .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
                     let activate_handler: ActivateHandler =
                         Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
                             let selected = selected_values
@@ -1432,25 +1454,21 @@ impl RenderOnce for MultiSelect {
                 anchored_host(
                     &self.id,
                     "anchor-host",
-                    PopupPlacement::Top,
-                    f32::from(dropdown_anchor_offset),
-                    popup_snap_margin,
+                    PopupPlacement::Top
+                        .options(f32::from(dropdown_anchor_offset), popup_snap_margin),
                     floating,
                     24,
                     true,
-                    true,
                 )
             } else {
                 anchored_host(
                     &self.id,
                     "anchor-host",
-                    PopupPlacement::Bottom,
-                    f32::from(dropdown_anchor_offset),
-                    popup_snap_margin,
+                    PopupPlacement::Bottom
+                        .options(f32::from(dropdown_anchor_offset), popup_snap_margin),
                     floating,
                     24,
                     true,
-                    true,
                 )
             };
             trigger = trigger.child(anchor_host);
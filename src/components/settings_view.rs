@@ -0,0 +1,685 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::{
+    AnyElement, FontWeight, HighlightStyle, Hsla, InteractiveElement, IntoElement, ParentElement,
+    RenderOnce, SharedString, StatefulInteractiveElement, Styled, StyledText, Window, div,
+};
+
+use crate::contracts::Sized;
+use crate::form::{
+    AutoField, AutoFieldProps, FieldKey, FieldLens, FieldRuleViolation, FormController, FormModel,
+    ValidationError, humanized_label,
+};
+use crate::id::ComponentId;
+use crate::style::{FieldLayout, Size};
+
+use super::icon::Icon;
+use super::input::TextInput;
+use super::settings_view_state;
+use super::utils::resolve_hsla;
+
+type ItemRender<M, E> = Rc<dyn Fn(&FormController<M, E>, AutoFieldProps) -> Option<AnyElement>>;
+type SectionChangeHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+type SearchChangeHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+type ChangeListener<M> = Arc<dyn Fn(FieldKey, &M) + Send + Sync>;
+
+/// One row of a settings page: a label and description on the left and
+/// the control editing the field on the right.
+pub struct SettingsItem<M, E = FieldRuleViolation>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    key: FieldKey,
+    render: ItemRender<M, E>,
+    label: Option<SharedString>,
+    description: Option<SharedString>,
+    keywords: Vec<SharedString>,
+}
+
+impl<M, E> SettingsItem<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    /// A setting edited with the input `field` builds, such as one from
+    /// `M::auto_fields()`.
+    pub fn new(field: AutoField<M, E>) -> Self {
+        Self {
+            key: field.key(),
+            render: Rc::new(move |controller, props| field.render(controller, props).ok()),
+            label: None,
+            description: None,
+            keywords: Vec::new(),
+        }
+    }
+
+    /// A setting with a hand-built control. The props carry the id and
+    /// metadata resolved for `lens`.
+    pub fn custom<L>(
+        lens: L,
+        render: impl Fn(&FormController<M, E>, AutoFieldProps) -> AnyElement + 'static,
+    ) -> Self
+    where
+        L: FieldLens<M>,
+    {
+        Self {
+            key: lens.key(),
+            render: Rc::new(move |controller, props| Some(render(controller, props))),
+            label: None,
+            description: None,
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Overrides the label from the derived metadata.
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    pub fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Extra words search matches this item by, without showing them.
+    pub fn keywords(mut self, values: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.keywords.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn key(&self) -> FieldKey {
+        self.key
+    }
+
+    fn resolved_label(&self) -> SharedString {
+        self.label.clone().unwrap_or_else(|| {
+            M::field_meta(self.key)
+                .and_then(|descriptor| descriptor.label)
+                .map(Into::into)
+                .unwrap_or_else(|| humanized_label(self.key))
+        })
+    }
+
+    fn resolved_description(&self) -> Option<SharedString> {
+        self.description.clone().or_else(|| {
+            M::field_meta(self.key)
+                .and_then(|descriptor| descriptor.description)
+                .map(Into::into)
+        })
+    }
+}
+
+/// A category in the settings navigation.
+pub struct SettingsSection<M, E = FieldRuleViolation>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    value: SharedString,
+    title: SharedString,
+    description: Option<SharedString>,
+    icon: Option<SharedString>,
+    items: Vec<SettingsItem<M, E>>,
+}
+
+impl<M, E> SettingsSection<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    pub fn new(value: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            title: title.into(),
+            description: None,
+            icon: None,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Icon shown next to the title in the navigation.
+    pub fn icon(mut self, name: impl Into<SharedString>) -> Self {
+        self.icon = Some(name.into());
+        self
+    }
+
+    pub fn item(mut self, item: SettingsItem<M, E>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = SettingsItem<M, E>>) -> Self {
+        self.items.extend(items);
+        self
+    }
+}
+
+/// Sections and items a [`SettingsView`] shows, in order.
+pub struct SettingsSchema<M, E = FieldRuleViolation>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    sections: Vec<SettingsSection<M, E>>,
+}
+
+impl<M, E> SettingsSchema<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    pub fn new() -> Self {
+        Self {
+            sections: Vec::new(),
+        }
+    }
+
+    pub fn section(mut self, section: SettingsSection<M, E>) -> Self {
+        self.sections.push(section);
+        self
+    }
+}
+
+impl<M, E> Default for SettingsSchema<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Preferences page with a category list on the left and the active
+/// category's settings on the right, each bound to a field of the form
+/// model.
+///
+/// Typing in the search box lists matching items from every section, with
+/// the matched words highlighted. An item matches when each word of the
+/// query appears in its label, description, keywords or section title.
+#[derive(IntoElement)]
+pub struct SettingsView<M, E = FieldRuleViolation>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    pub(crate) id: ComponentId,
+    controller: FormController<M, E>,
+    schema: SettingsSchema<M, E>,
+    section: Option<SharedString>,
+    section_controlled: bool,
+    default_section: Option<SharedString>,
+    search: Option<SharedString>,
+    search_placeholder: SharedString,
+    empty_label: SharedString,
+    on_section_change: Option<SectionChangeHandler>,
+    on_search_change: Option<SearchChangeHandler>,
+    on_change: Option<ChangeListener<M>>,
+    pub(crate) theme: crate::theme::LocalTheme,
+}
+
+impl<M, E> SettingsView<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    #[track_caller]
+    pub fn new(controller: FormController<M, E>, schema: SettingsSchema<M, E>) -> Self {
+        Self {
+            id: ComponentId::default(),
+            controller,
+            schema,
+            section: None,
+            section_controlled: false,
+            default_section: None,
+            search: None,
+            search_placeholder: "Search settings".into(),
+            empty_label: "No matching settings".into(),
+            on_section_change: None,
+            on_search_change: None,
+            on_change: None,
+            theme: crate::theme::LocalTheme::default(),
+        }
+    }
+
+    pub fn section(mut self, value: impl Into<SharedString>) -> Self {
+        self.section = Some(value.into());
+        self.section_controlled = true;
+        self
+    }
+
+    pub fn default_section(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_section = Some(value.into());
+        self
+    }
+
+    pub fn on_section_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_section_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn search(mut self, value: impl Into<SharedString>) -> Self {
+        self.search = Some(value.into());
+        self
+    }
+
+    pub fn search_placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.search_placeholder = value.into();
+        self
+    }
+
+    pub fn on_search_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_search_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Shown in place of items when nothing matches the search.
+    pub fn empty_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.empty_label = value.into();
+        self
+    }
+
+    /// Called with the key and updated model whenever a setting changes,
+    /// whether through this view or any other caller of the controller.
+    pub fn on_change(mut self, handler: impl Fn(FieldKey, &M) + Send + Sync + 'static) -> Self {
+        self.on_change = Some(Arc::new(handler));
+        self
+    }
+
+    fn highlighted(&self, text: SharedString, query: &str) -> StyledText {
+        let tokens = &self.theme.components.settings_view;
+        let style = HighlightStyle {
+            background_color: Some(resolve_hsla(&self.theme, tokens.match_bg)),
+            ..HighlightStyle::default()
+        };
+        let ranges = settings_view_state::highlight_ranges(&text, query);
+        StyledText::new(text).with_highlights(ranges.into_iter().map(|range| (range, style)))
+    }
+
+    fn render_item(&self, item: &SettingsItem<M, E>, query: &str) -> Option<AnyElement> {
+        let tokens = &self.theme.components.settings_view;
+        let descriptor = M::field_meta(item.key).unwrap_or_default();
+        let props = AutoFieldProps {
+            id: self.id.scoped_index("field", item.key.as_str()),
+            label: SharedString::default(),
+            placeholder: descriptor.placeholder.map(Into::into),
+            description: None,
+            layout: FieldLayout::Vertical,
+        };
+        let control = (item.render)(&self.controller, props)?;
+        let description = item.resolved_description().map(|description| {
+            div()
+                .text_size(tokens.description_size)
+                .text_color(resolve_hsla(&self.theme, tokens.description_fg))
+                .child(self.highlighted(description, query))
+        });
+
+        Some(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap(tokens.item_gap)
+                .py(tokens.item_padding_y)
+                .border_b_1()
+                .border_color(resolve_hsla(&self.theme, tokens.separator))
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(
+                            div()
+                                .text_size(tokens.label_size)
+                                .text_color(resolve_hsla(&self.theme, tokens.label_fg))
+                                .child(self.highlighted(item.resolved_label(), query)),
+                        )
+                        .children(description),
+                )
+                .child(
+                    div()
+                        .flex_none()
+                        .w(tokens.control_width)
+                        .flex()
+                        .justify_end()
+                        .child(control),
+                )
+                .into_any_element(),
+        )
+    }
+
+    fn render_nav_item(
+        &self,
+        section: &SettingsSection<M, E>,
+        active: bool,
+        count: Option<usize>,
+        select: SectionChangeHandler,
+    ) -> AnyElement {
+        let tokens = &self.theme.components.settings_view;
+        let (bg, fg) = if active {
+            (
+                resolve_hsla(&self.theme, tokens.nav_active_bg),
+                resolve_hsla(&self.theme, tokens.nav_active_fg),
+            )
+        } else {
+            (
+                resolve_hsla(&self.theme, tokens.nav_bg),
+                resolve_hsla(&self.theme, tokens.nav_fg),
+            )
+        };
+        let hover_bg = resolve_hsla(&self.theme, tokens.nav_hover_bg);
+        let value = section.value.clone();
+
+        let mut item = div()
+            .id(self.id.slot_index("section", section.value.to_string()))
+            .flex()
+            .items_center()
+            .gap_2()
+            .px(tokens.nav_item_padding_x)
+            .py(tokens.nav_item_padding_y)
+            .rounded(tokens.nav_item_radius)
+            .text_size(tokens.nav_item_size)
+            .text_color(fg)
+            .bg(bg)
+            .cursor_pointer()
+            .when_some(section.icon.clone(), |this, icon| {
+                this.child(Icon::named(icon.to_string()).size(16.0).color(fg))
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .truncate()
+                    .child(section.title.clone()),
+            )
+            .when_some(count, |this, count| {
+                this.child(
+                    div()
+                        .flex_none()
+                        .text_color(resolve_hsla(&self.theme, tokens.count_fg))
+                        .child(count.to_string()),
+                )
+            })
+            .on_click(move |_, window, cx| select(value.clone(), window, cx));
+        if active {
+            item = item.font_weight(FontWeight::MEDIUM);
+        } else {
+            item = item.hover(move |style| style.bg(hover_bg));
+        }
+        item.into_any_element()
+    }
+
+    fn render_heading(&self, title: SharedString, size: gpui::Pixels, color: Hsla) -> AnyElement {
+        div()
+            .text_size(size)
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(color)
+            .child(title)
+            .into_any_element()
+    }
+}
+
+impl<M, E> crate::contracts::WithId for SettingsView<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    fn with_id(mut self, id: impl Into<ComponentId>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl<M, E> crate::contracts::ComponentThemeOverridable for SettingsView<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    fn local_theme_mut(&mut self) -> &mut crate::theme::LocalTheme {
+        &mut self.theme
+    }
+}
+
+impl<M, E> crate::contracts::Themable for SettingsView<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    type ThemeOverrides = crate::theme::SettingsViewOverrides;
+
+    fn component_overrides_mut(
+        overrides: &mut crate::theme::ComponentOverrides,
+    ) -> &mut Self::ThemeOverrides {
+        &mut overrides.settings_view
+    }
+}
+
+impl<M, E> RenderOnce for SettingsView<M, E>
+where
+    M: FormModel,
+    E: ValidationError,
+{
+    fn render(mut self, _window: &mut Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.settings_view.clone();
+        let id = self.id.to_string();
+
+        // Keyed by the view id, so the listener is replaced on every render
+        // rather than piling up.
+        let _ = match self.on_change.clone() {
+            Some(listener) => self
+                .controller
+                .register_change_listener(id.clone(), move |key, model| listener(key, model)),
+            None => self.controller.unregister_change_listener(&id),
+        };
+
+        let search_controlled = self.search.is_some();
+        let query =
+            settings_view_state::query(&id, self.search.as_ref().map(|value| value.to_string()));
+        let searching = !query.trim().is_empty();
+        let values = self
+            .schema
+            .sections
+            .iter()
+            .map(|section| section.value.as_ref())
+            .collect::<Vec<_>>();
+        let active = settings_view_state::active_section(
+            &id,
+            self.section_controlled,
+            self.section.as_ref().map(|value| value.to_string()),
+            self.default_section.as_ref().map(|value| value.to_string()),
+            &values,
+        );
+
+        // Items of each section that match the query, by index.
+        let matches = self
+            .schema
+            .sections
+            .iter()
+            .map(|section| {
+                section
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| {
+                        let label = item.resolved_label();
+                        let description = item.resolved_description().unwrap_or_default();
+                        let mut texts =
+                            vec![label.as_ref(), description.as_ref(), section.title.as_ref()];
+                        texts.extend(item.keywords.iter().map(|keyword| keyword.as_ref()));
+                        settings_view_state::matches(&query, &texts)
+                    })
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let set_search = {
+            let id = id.clone();
+            let handler = self.on_search_change.clone();
+            Rc::new(
+                move |value: SharedString, window: &mut Window, cx: &mut gpui::App| {
+                    settings_view_state::set_query(&id, search_controlled, value.to_string());
+                    if let Some(handler) = handler.as_ref() {
+                        (handler)(value, window, cx);
+                    }
+                    window.refresh();
+                },
+            )
+        };
+        let select: SectionChangeHandler = {
+            let id = id.clone();
+            let controlled = self.section_controlled;
+            let handler = self.on_section_change.clone();
+            let set_search = set_search.clone();
+            Rc::new(move |value: SharedString, window, cx| {
+                settings_view_state::set_active_section(&id, controlled, value.to_string());
+                if let Some(handler) = handler.as_ref() {
+                    (handler)(value, window, cx);
+                }
+                // Picking a category leaves the search results.
+                if searching {
+                    set_search(SharedString::default(), window, cx);
+                }
+                window.refresh();
+            })
+        };
+
+        let search_input = {
+            let set_search = set_search.clone();
+            self.id
+                .ctx()
+                .child("search", TextInput::new())
+                .placeholder(self.search_placeholder.clone())
+                .value(query.clone())
+                .left_slot(Icon::named("search").size(14.0))
+                .with_size(Size::Sm)
+                .on_change(move |value: SharedString, window, cx| set_search(value, window, cx))
+        };
+        let nav_items = self
+            .schema
+            .sections
+            .iter()
+            .zip(&matches)
+            .map(|(section, matched)| {
+                let is_active = !searching && active.as_deref() == Some(section.value.as_ref());
+                let count = searching.then_some(matched.len());
+                self.render_nav_item(section, is_active, count, select.clone())
+            })
+            .collect::<Vec<_>>();
+        let nav = div()
+            .flex_none()
+            .w(tokens.nav_width)
+            .h_full()
+            .flex()
+            .flex_col()
+            .gap(tokens.nav_gap)
+            .p(tokens.nav_padding)
+            .border_r_1()
+            .border_color(resolve_hsla(&self.theme, tokens.border))
+            .bg(resolve_hsla(&self.theme, tokens.nav_bg))
+            .child(div().pb(tokens.nav_padding).child(search_input))
+            .children(nav_items);
+
+        let title_fg = resolve_hsla(&self.theme, tokens.title_fg);
+        let mut body = div()
+            .w_full()
+            .max_w(tokens.content_max_width)
+            .flex()
+            .flex_col()
+            .gap(tokens.section_gap);
+        if searching {
+            let groups =
+                self.schema
+                    .sections
+                    .iter()
+                    .zip(&matches)
+                    .filter(|(_, matched)| !matched.is_empty())
+                    .map(|(section, matched)| {
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(self.render_heading(
+                                section.title.clone(),
+                                tokens.heading_size,
+                                title_fg,
+                            ))
+                            .children(matched.iter().filter_map(|index| {
+                                self.render_item(&section.items[*index], &query)
+                            }))
+                    })
+                    .collect::<Vec<_>>();
+            if groups.is_empty() {
+                body = body.child(
+                    div()
+                        .text_size(tokens.description_size)
+                        .text_color(resolve_hsla(&self.theme, tokens.empty_fg))
+                        .child(self.empty_label.clone()),
+                );
+            }
+            body = body.children(groups);
+        } else if let Some(section) = self
+            .schema
+            .sections
+            .iter()
+            .find(|section| active.as_deref() == Some(section.value.as_ref()))
+        {
+            let header = div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .child(self.render_heading(section.title.clone(), tokens.title_size, title_fg))
+                .when_some(section.description.clone(), |this, description| {
+                    this.child(
+                        div()
+                            .text_size(tokens.description_size)
+                            .text_color(resolve_hsla(&self.theme, tokens.description_fg))
+                            .child(description),
+                    )
+                });
+            body = body.child(
+                div().flex().flex_col().child(header).children(
+                    section
+                        .items
+                        .iter()
+                        .filter_map(|item| self.render_item(item, "")),
+                ),
+            );
+        }
+
+        let content = div()
+            .id(self.id.slot("content"))
+            .flex_1()
+            .min_w_0()
+            .h_full()
+            .overflow_y_scroll()
+            .p(tokens.content_padding)
+            .child(body);
+
+        div()
+            .id(self.id.clone())
+            .flex()
+            .size_full()
+            .overflow_hidden()
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .child(nav)
+            .child(content)
+    }
+}
@@ -0,0 +1,64 @@
+use std::ops::Range;
+
+use super::control;
+use super::selection_state;
+use super::text_selection::find_ranges;
+
+/// Whether every word of `query` appears in one of `texts`, ignoring ASCII
+/// case. An empty query matches everything.
+pub fn matches(query: &str, texts: &[&str]) -> bool {
+    query.split_whitespace().all(|word| {
+        texts
+            .iter()
+            .any(|text| !find_ranges(text, word, false).is_empty())
+    })
+}
+
+/// Sorted, non-overlapping ranges of `text` that match a word of `query`.
+pub fn highlight_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    let mut ranges = query
+        .split_whitespace()
+        .flat_map(|word| find_ranges(text, word, false))
+        .collect::<Vec<_>>();
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Active section, falling back to the first one when the stored value
+/// names no section.
+pub fn active_section(
+    id: &str,
+    controlled: bool,
+    value: Option<String>,
+    default: Option<String>,
+    sections: &[&str],
+) -> Option<String> {
+    let resolved =
+        selection_state::resolve_optional_text(id, "section", controlled, value, default);
+    resolved
+        .filter(|value| sections.contains(&value.as_str()))
+        .or_else(|| sections.first().map(|value| (*value).to_owned()))
+}
+
+pub fn set_active_section(id: &str, controlled: bool, value: String) -> bool {
+    selection_state::apply_optional_text(id, "section", controlled, Some(value))
+}
+
+pub fn query(id: &str, controlled: Option<String>) -> String {
+    control::text_state(id, "query", controlled, String::new())
+}
+
+pub fn set_query(id: &str, controlled: bool, value: String) -> bool {
+    if controlled {
+        return false;
+    }
+    control::set_text_state(id, "query", value);
+    true
+}
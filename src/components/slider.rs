@@ -3,38 +3,189 @@ use std::rc::Rc;
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
 use gpui::{
-    AppContext, Bounds, ClickEvent, Corners, EmptyView, IntoElement, ParentElement, RenderOnce,
-    SharedString, Styled, Window, canvas, div, fill, point, px, size,
+    AnyElement, AppContext, Bounds, ClickEvent, Corners, Div, EmptyView, FocusHandle, Hsla,
+    IntoElement, MouseButton, ParentElement, Pixels, RenderOnce, SharedString, Stateful, Styled,
+    Window, canvas, div, fill, point, px, size,
 };
 
 use crate::contracts::{FieldLike, MotionAware};
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
-use crate::style::{FieldLayout, Radius, Size, Variant};
-use crate::theme::SemanticRadiusToken;
+use crate::overlay::positioning::{Align, PositionOptions, Side};
+use crate::style::{FieldLayout, GroupOrientation, Radius, Size, Variant};
+use crate::theme::{SemanticRadiusToken, Theme};
 
 use super::Stack;
 use super::control;
+use super::popup::anchored_host;
 use super::slider_axis::{self, SliderAxis};
-use super::utils::{apply_radius, quantized_stroke_px, resolve_hsla, resolve_radius, snap_px};
+use super::utils::{
+    apply_focus_ring, apply_radius, quantized_stroke_px, resolve_hsla, resolve_radius, snap_px,
+};
 
 type ChangeHandler = Rc<dyn Fn(f32, &mut Window, &mut gpui::App)>;
 
+/// Width given to each mark label, centred under its mark.
+const MARK_LABEL_WIDTH: f32 = 64.0;
+
+/// A point of interest on a [`Slider`] or [`RangeSlider`](super::RangeSlider)
+/// rail, drawn as a dot with an optional label beside the rail.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderMark {
+    pub value: f32,
+    pub label: Option<SharedString>,
+}
+
+impl SliderMark {
+    pub fn new(value: f32) -> Self {
+        Self { value, label: None }
+    }
+
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+}
+
+/// Offsets along the rail for the tick lines: one per step by default, or
+/// every `interval` when set.
+pub(crate) fn tick_offsets(
+    axis: SliderAxis,
+    min: f32,
+    max: f32,
+    step: f32,
+    interval: Option<f32>,
+    track_len: f32,
+    thumb_size: f32,
+) -> Vec<f32> {
+    match interval {
+        Some(interval) => slider_axis::tick_ratios(min, max, interval)
+            .into_iter()
+            .map(|ratio| slider_axis::value_offset(axis, track_len, thumb_size, ratio))
+            .collect(),
+        None => {
+            let span = (max - min).max(step.max(0.001));
+            let count = ((span / step.max(0.001)).round() as usize).clamp(1, 80);
+            (1..count)
+                .map(|index| track_len * (index as f32 / count as f32))
+                .collect()
+        }
+    }
+}
+
+/// Paints a round dot centred on the track for each `(offset, color)`.
+pub(crate) fn paint_mark_dots(
+    window: &mut Window,
+    bounds: Bounds<Pixels>,
+    axis: SliderAxis,
+    cross_center: f32,
+    dot_size: f32,
+    dots: &[(f32, Hsla)],
+) {
+    let radius = Corners::all(px(dot_size * 0.5));
+    for (offset, color) in dots {
+        let (x, y) = match axis {
+            SliderAxis::Horizontal => (*offset, cross_center),
+            SliderAxis::Vertical => (cross_center, *offset),
+        };
+        let dot = Bounds::new(
+            point(
+                bounds.origin.x + px(x - dot_size * 0.5),
+                bounds.origin.y + px(y - dot_size * 0.5),
+            ),
+            size(px(dot_size), px(dot_size)),
+        );
+        window.paint_quad(fill(dot, *color).corner_radii(radius));
+    }
+}
+
+/// Mark labels laid out along the rail: underneath a horizontal one, to the
+/// right of a vertical one.
+pub(crate) fn mark_labels(
+    axis: SliderAxis,
+    track_len: f32,
+    labels: Vec<(f32, SharedString)>,
+    color: Hsla,
+    text_size: Pixels,
+) -> AnyElement {
+    let line = f32::from(text_size) * 1.4;
+    let labels = labels.into_iter().map(|(offset, label)| {
+        let item = div()
+            .absolute()
+            .flex()
+            .text_size(text_size)
+            .line_height(px(line))
+            .text_color(color)
+            .child(label);
+        match axis {
+            SliderAxis::Horizontal => item
+                .top_0()
+                .left(px(offset - MARK_LABEL_WIDTH * 0.5))
+                .w(px(MARK_LABEL_WIDTH))
+                .justify_center(),
+            SliderAxis::Vertical => item.left_0().top(px(offset - line * 0.5)),
+        }
+    });
+    let row = div().relative().children(labels);
+    let row = match axis {
+        SliderAxis::Horizontal => row.w(px(track_len)).h(px(line)),
+        SliderAxis::Vertical => row.h(px(track_len)).w(px(MARK_LABEL_WIDTH)),
+    };
+    row.into_any_element()
+}
+
+/// Bubble showing the value next to the thumb being dragged, placed by the
+/// overlay positioning engine so it stays inside the window.
+pub(crate) fn value_tooltip(
+    id: &ComponentId,
+    slot: &str,
+    theme: &Theme,
+    axis: SliderAxis,
+    offset: Pixels,
+    text: String,
+    window: &Window,
+) -> AnyElement {
+    let tokens = &theme.components.tooltip;
+    let bubble = div()
+        .text_size(tokens.text_size)
+        .px(tokens.padding_x)
+        .py(tokens.padding_y)
+        .rounded(tokens.radius)
+        .border(quantized_stroke_px(window, 1.0))
+        .border_color(resolve_hsla(theme, tokens.border))
+        .bg(resolve_hsla(theme, tokens.bg))
+        .text_color(resolve_hsla(theme, tokens.fg))
+        .child(text)
+        .into_any_element();
+    let side = match axis {
+        SliderAxis::Horizontal => Side::Top,
+        SliderAxis::Vertical => Side::Right,
+    };
+    let options = PositionOptions::new(side)
+        .align(Align::Center)
+        .offset(f32::from(offset))
+        .margin(f32::from(theme.components.layout.popup_snap_margin));
+    anchored_host(id, slot, options, bubble, 24, false)
+}
+
+/// Hides the value tooltip once the pointer is released.
+pub(crate) fn end_drag(id: &str, window: &mut Window) {
+    if control::bool_state(id, "dragging", None, false) {
+        control::set_bool_state(id, "dragging", false);
+        window.refresh();
+    }
+}
+
 #[derive(Clone)]
 struct SliderDragState {
     slider_id: String,
     min: f32,
     max: f32,
     step: f32,
+    snap_marks: Vec<f32>,
     controlled: bool,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum SliderOrientation {
-    Horizontal,
-    Vertical,
-}
-
 #[derive(IntoElement)]
 pub struct Slider {
     pub(crate) id: ComponentId,
@@ -50,9 +201,15 @@ pub struct Slider {
     required: bool,
     layout: FieldLayout,
     show_value: bool,
+    marks: Vec<SliderMark>,
+    tick_interval: Option<f32>,
+    show_ticks: bool,
+    snap_to_marks: bool,
+    value_tooltip: bool,
     disabled: bool,
     width_px: Option<f32>,
-    orientation: SliderOrientation,
+    orientation: GroupOrientation,
+    focus_handle: Option<FocusHandle>,
     variant: Variant,
     size: Size,
     radius: Radius,
@@ -78,9 +235,15 @@ impl Slider {
             required: false,
             layout: FieldLayout::Vertical,
             show_value: true,
+            marks: Vec::new(),
+            tick_interval: None,
+            show_ticks: true,
+            snap_to_marks: false,
+            value_tooltip: false,
             disabled: false,
             width_px: None,
-            orientation: SliderOrientation::Horizontal,
+            orientation: GroupOrientation::Horizontal,
+            focus_handle: None,
             variant: Variant::Filled,
             size: Size::Md,
             radius: Radius::Pill,
@@ -91,11 +254,11 @@ impl Slider {
     }
 
     pub fn horizontal() -> Self {
-        Self::new().with_orientation(SliderOrientation::Horizontal)
+        Self::new().orientation(GroupOrientation::Horizontal)
     }
 
     pub fn vertical() -> Self {
-        Self::new().with_orientation(SliderOrientation::Vertical)
+        Self::new().orientation(GroupOrientation::Vertical)
     }
 
     pub fn value(mut self, value: f32) -> Self {
@@ -166,16 +329,58 @@ impl Slider {
         self.show_value = show_value;
         self
     }
+
+    pub fn mark(mut self, mark: SliderMark) -> Self {
+        self.marks.push(mark);
+        self
+    }
+
+    pub fn marks(mut self, marks: impl IntoIterator<Item = SliderMark>) -> Self {
+        self.marks.extend(marks);
+        self
+    }
+
+    /// Draws tick lines every `interval` instead of at every step.
+    pub fn tick_interval(mut self, interval: f32) -> Self {
+        self.tick_interval = Some(interval.max(0.001));
+        self
+    }
+
+    pub fn show_ticks(mut self, value: bool) -> Self {
+        self.show_ticks = value;
+        self
+    }
+
+    /// Restricts the value to the marks, ignoring the step.
+    pub fn snap_to_marks(mut self, value: bool) -> Self {
+        self.snap_to_marks = value;
+        self
+    }
+
+    /// Shows the value in a bubble over the thumb while it is dragged.
+    pub fn value_tooltip(mut self, value: bool) -> Self {
+        self.value_tooltip = value;
+        self
+    }
+
+    /// Length of the rail, measured along its orientation.
     pub fn width(mut self, width_px: f32) -> Self {
         self.width_px = Some(width_px.max(0.0));
         self
     }
 
-    fn with_orientation(mut self, value: SliderOrientation) -> Self {
+    /// Vertical sliders fill from the bottom, as in a mixer fader.
+    pub fn orientation(mut self, value: GroupOrientation) -> Self {
         self.orientation = value;
         self
     }
 
+    /// Lets the arrow keys move the thumb once the rail has been clicked.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
     pub fn on_change(
         mut self,
         handler: impl Fn(f32, &mut Window, &mut gpui::App) + 'static,
@@ -185,7 +390,69 @@ impl Slider {
     }
 
     fn normalize(&self, raw: f32) -> f32 {
-        slider_axis::normalize(self.min, self.max, self.step, raw)
+        slider_axis::snap(self.min, self.max, self.step, &self.snap_marks(), raw)
+    }
+
+    fn snap_marks(&self) -> Vec<f32> {
+        if !self.snap_to_marks {
+            return Vec::new();
+        }
+        self.marks.iter().map(|mark| mark.value).collect()
+    }
+
+    fn mark_dots(
+        &self,
+        axis: SliderAxis,
+        track_len: f32,
+        thumb_size: f32,
+        value: f32,
+    ) -> Vec<(f32, Hsla)> {
+        let tokens = &self.theme.components.slider;
+        let mark_color = resolve_hsla(&self.theme, tokens.mark_bg);
+        let filled_color = resolve_hsla(&self.theme, tokens.thumb_bg);
+        self.marks
+            .iter()
+            .filter(|mark| (self.min..=self.max).contains(&mark.value))
+            .map(|mark| {
+                let offset =
+                    slider_axis::value_offset(axis, track_len, thumb_size, self.ratio(mark.value));
+                let color = if mark.value <= value {
+                    filled_color
+                } else {
+                    mark_color
+                };
+                (offset, color)
+            })
+            .collect()
+    }
+
+    fn mark_label_row(
+        &self,
+        axis: SliderAxis,
+        track_len: f32,
+        thumb_size: f32,
+    ) -> Option<AnyElement> {
+        let labels = self
+            .marks
+            .iter()
+            .filter(|mark| (self.min..=self.max).contains(&mark.value))
+            .filter_map(|mark| {
+                let offset =
+                    slider_axis::value_offset(axis, track_len, thumb_size, self.ratio(mark.value));
+                mark.label.clone().map(|label| (offset, label))
+            })
+            .collect::<Vec<_>>();
+        if labels.is_empty() {
+            return None;
+        }
+        let tokens = &self.theme.components.slider;
+        Some(mark_labels(
+            axis,
+            track_len,
+            labels,
+            resolve_hsla(&self.theme, tokens.mark_label),
+            tokens.mark_label_size,
+        ))
     }
 
     fn resolved_value(&self) -> f32 {
@@ -198,9 +465,19 @@ impl Slider {
         slider_axis::ratio(self.min, self.max, value)
     }
 
-    fn segments(&self) -> usize {
-        let span = (self.max - self.min).max(self.step.max(0.001));
-        ((span / self.step.max(0.001)).round() as usize).clamp(1, 80)
+    fn tick_offsets(&self, axis: SliderAxis, track_len: f32, thumb_size: f32) -> Vec<f32> {
+        if !self.show_ticks {
+            return Vec::new();
+        }
+        tick_offsets(
+            axis,
+            self.min,
+            self.max,
+            self.step,
+            self.tick_interval,
+            track_len,
+            thumb_size,
+        )
     }
 
     fn filled_color(&self) -> gpui::Hsla {
@@ -213,6 +490,77 @@ impl Slider {
             Variant::Ghost => base.alpha(0.55),
         }
     }
+
+    fn track_focus<T: InteractiveElement>(id: &ComponentId, node: T) -> T {
+        let id_for_down = id.clone();
+        let id_for_blur = id.clone();
+        let id_for_up = id.clone();
+        let id_for_up_out = id.clone();
+        node.on_mouse_down(MouseButton::Left, move |_, window, _| {
+            control::set_focused_state(&id_for_down, true);
+            window.refresh();
+        })
+        .on_mouse_down_out(move |_, window, _| {
+            control::set_focused_state(&id_for_blur, false);
+            window.refresh();
+        })
+        .on_mouse_up(MouseButton::Left, move |_, window, _| {
+            end_drag(&id_for_up, window);
+        })
+        .on_mouse_up_out(MouseButton::Left, move |_, window, _| {
+            end_drag(&id_for_up_out, window);
+        })
+    }
+
+    /// Arrow keys, Page Up/Down and Home/End while the rail has focus.
+    fn keyboard_rail(&self, rail: Stateful<Div>, value: f32) -> Stateful<Div> {
+        let mut rail = rail.focusable();
+        if let Some(focus_handle) = self.focus_handle.as_ref() {
+            rail = rail.track_focus(focus_handle);
+        }
+        let id = self.id.clone();
+        let focus_handle = self.focus_handle.clone();
+        let min = self.min;
+        let max = self.max;
+        let step = self.step;
+        let marks = self.snap_marks();
+        let controlled = self.value_controlled;
+        let on_change = self.on_change.clone();
+        rail.on_mouse_down(MouseButton::Left, move |_, window, cx| {
+            if let Some(focus_handle) = focus_handle.as_ref() {
+                window.focus(focus_handle, cx);
+            }
+        })
+        .on_key_down(move |event, window, cx| {
+            if !control::is_plain_keystroke(event) {
+                return;
+            }
+            let current = control::f32_state(&id, "value", None, value);
+            let Some(next) = slider_axis::keyboard_value(
+                event.keystroke.key.as_str(),
+                event.keystroke.modifiers.shift,
+                current,
+                min,
+                max,
+                step,
+                &marks,
+            ) else {
+                return;
+            };
+            cx.stop_propagation();
+            window.prevent_default();
+            if (next - current).abs() <= f32::EPSILON {
+                return;
+            }
+            if !controlled {
+                control::set_f32_state(&id, "value", next);
+                window.refresh();
+            }
+            if let Some(handler) = on_change.as_ref() {
+                (handler)(next, window, cx);
+            }
+        })
+    }
 }
 
 impl Slider {}
@@ -242,7 +590,9 @@ impl RenderOnce for Slider {
         let track_top = ((thumb_size - track_height) * 0.5).max(0.0);
         let thumb_left =
             slider_axis::thumb_offset(SliderAxis::Horizontal, track_len, thumb_size, ratio);
-        let segment_count = self.segments();
+        let mark_size = f32::from(tokens.mark_size);
+        let dragging = self.value_tooltip && control::bool_state(&self.id, "dragging", None, false);
+        let snap_marks = self.snap_marks();
         let track_color = resolve_hsla(&self.theme, tokens.track_bg);
         let fill_color = self.filled_color();
         let tick_color = resolve_hsla(&self.theme, tokens.thumb_border).alpha(0.35);
@@ -251,6 +601,8 @@ impl RenderOnce for Slider {
             &self.theme,
             SemanticRadiusToken::from(self.radius),
         ));
+        let thumb_focused = !self.disabled && control::focused_state(&self.id, None, false);
+        let thumb_radius = resolve_radius(&self.theme, SemanticRadiusToken::from(Radius::Pill));
         let display_precision = if self.step < 1.0 { 2 } else { 0 };
         let is_controlled = self.value_controlled;
         let orientation = self.orientation;
@@ -267,12 +619,14 @@ impl RenderOnce for Slider {
         let has_meta =
             label_text.is_some() || self.show_value || description.is_some() || error.is_some();
 
-        if orientation == SliderOrientation::Vertical {
+        if orientation == GroupOrientation::Vertical {
             let track_left = ((thumb_size - track_height) * 0.5).max(0.0);
             let thumb_top =
                 slider_axis::thumb_offset(SliderAxis::Vertical, track_len, thumb_size, ratio);
             let fill_top = (thumb_top + (thumb_size * 0.5)).clamp(0.0, track_len);
             let fill_height = (track_len - fill_top).max(0.0);
+            let ticks = self.tick_offsets(SliderAxis::Vertical, track_len, thumb_size);
+            let dots = self.mark_dots(SliderAxis::Vertical, track_len, thumb_size, value);
             let track_layer = canvas(
                 |_, _, _| (),
                 move |bounds, _, window, _| {
@@ -293,9 +647,8 @@ impl RenderOnce for Slider {
                         window.paint_quad(fill(fill_bounds, fill_color).corner_radii(track_corner));
                     }
 
-                    if segment_count > 1 && tick_thickness > 0.0 {
-                        for index in 1..segment_count {
-                            let y = track_len * (index as f32 / segment_count as f32);
+                    if tick_thickness > 0.0 {
+                        for y in &ticks {
                             let tick_top = f32::from(snap_px(window, y - (tick_thickness * 0.5)));
                             let tick_bounds = Bounds::new(
                                 point(
@@ -307,6 +660,14 @@ impl RenderOnce for Slider {
                             window.paint_quad(fill(tick_bounds, tick_color));
                         }
                     }
+                    paint_mark_dots(
+                        window,
+                        bounds,
+                        SliderAxis::Vertical,
+                        thumb_size * 0.5,
+                        mark_size,
+                        &dots,
+                    );
                 },
             )
             .absolute()
@@ -324,9 +685,21 @@ impl RenderOnce for Slider {
                 .border_color(resolve_hsla(&self.theme, tokens.thumb_border))
                 .bg(resolve_hsla(&self.theme, tokens.thumb_bg));
             thumb = apply_radius(&self.theme, thumb, Radius::Pill);
+            thumb = apply_focus_ring(&self.theme, thumb, thumb_focused, thumb_radius);
             if self.disabled {
                 thumb = thumb.opacity(0.65);
             }
+            if dragging {
+                thumb = thumb.child(value_tooltip(
+                    &self.id,
+                    "value-tooltip",
+                    &self.theme,
+                    SliderAxis::Vertical,
+                    tokens.tooltip_offset,
+                    format!("{value:.display_precision$}"),
+                    window,
+                ));
+            }
 
             let mut rail = div()
                 .id(self.id.slot("rail"))
@@ -347,12 +720,13 @@ impl RenderOnce for Slider {
                     min: self.min,
                     max: self.max,
                     step: self.step,
+                    snap_marks: snap_marks.clone(),
                     controlled: is_controlled,
                 };
                 let slider_id = self.id.to_string();
                 let on_change_for_drag = on_change.clone();
 
-                rail = rail
+                rail = Self::track_focus(&self.id, self.keyboard_rail(rail, value))
                     .cursor_pointer()
                     .on_click(move |event: &ClickEvent, window, cx| {
                         let local_y = f32::from(event.position().y).clamp(0.0, track_len);
@@ -363,7 +737,7 @@ impl RenderOnce for Slider {
                             min,
                             max,
                         );
-                        let next = slider_axis::normalize(min, max, step, raw);
+                        let next = slider_axis::snap(min, max, step, &snap_marks, raw);
                         if !is_controlled {
                             control::set_f32_state(&id, "value", next);
                             window.refresh();
@@ -372,7 +746,10 @@ impl RenderOnce for Slider {
                             (handler)(next, window, cx);
                         }
                     })
-                    .on_drag(drag_state, |_drag, _, _, cx| cx.new(|_| EmptyView))
+                    .on_drag(drag_state, |drag, _, _, cx| {
+                        control::set_bool_state(&drag.slider_id, "dragging", true);
+                        cx.new(|_| EmptyView)
+                    })
                     .on_drag_move::<SliderDragState>(move |event, window, cx| {
                         let drag = event.drag(cx);
                         if drag.slider_id != slider_id {
@@ -390,7 +767,8 @@ impl RenderOnce for Slider {
                             drag.min,
                             drag.max,
                         );
-                        let next = slider_axis::normalize(drag.min, drag.max, drag.step, raw);
+                        let next =
+                            slider_axis::snap(drag.min, drag.max, drag.step, &drag.snap_marks, raw);
 
                         if !drag.controlled {
                             control::set_f32_state(&slider_id, "value", next);
@@ -444,6 +822,15 @@ impl RenderOnce for Slider {
                 );
             }
 
+            let rail = match self.mark_label_row(SliderAxis::Vertical, track_len, thumb_size) {
+                Some(labels) => Stack::horizontal()
+                    .gap(tokens.mark_label_gap)
+                    .child(rail)
+                    .child(labels)
+                    .into_any_element(),
+                None => rail.into_any_element(),
+            };
+
             return match self.layout {
                 FieldLayout::Vertical => {
                     let mut container = Stack::vertical()
@@ -475,6 +862,8 @@ impl RenderOnce for Slider {
         }
 
         let fill_width = (track_len * ratio).clamp(0.0, track_len);
+        let ticks = self.tick_offsets(SliderAxis::Horizontal, track_len, thumb_size);
+        let dots = self.mark_dots(SliderAxis::Horizontal, track_len, thumb_size, value);
         let track_layer = canvas(
             |_, _, _| (),
             move |bounds, _, window, _| {
@@ -492,9 +881,8 @@ impl RenderOnce for Slider {
                     window.paint_quad(fill(fill_bounds, fill_color).corner_radii(track_corner));
                 }
 
-                if segment_count > 1 && tick_thickness > 0.0 {
-                    for index in 1..segment_count {
-                        let x = track_len * (index as f32 / segment_count as f32);
+                if tick_thickness > 0.0 {
+                    for x in &ticks {
                         let tick_left = f32::from(snap_px(window, x - (tick_thickness * 0.5)));
                         let tick_bounds = Bounds::new(
                             point(
@@ -506,6 +894,14 @@ impl RenderOnce for Slider {
                         window.paint_quad(fill(tick_bounds, tick_color));
                     }
                 }
+                paint_mark_dots(
+                    window,
+                    bounds,
+                    SliderAxis::Horizontal,
+                    thumb_size * 0.5,
+                    mark_size,
+                    &dots,
+                );
             },
         )
         .absolute()
@@ -523,9 +919,21 @@ impl RenderOnce for Slider {
             .border_color(resolve_hsla(&self.theme, tokens.thumb_border))
             .bg(resolve_hsla(&self.theme, tokens.thumb_bg));
         thumb = apply_radius(&self.theme, thumb, Radius::Pill);
+        thumb = apply_focus_ring(&self.theme, thumb, thumb_focused, thumb_radius);
         if self.disabled {
             thumb = thumb.opacity(0.65);
         }
+        if dragging {
+            thumb = thumb.child(value_tooltip(
+                &self.id,
+                "value-tooltip",
+                &self.theme,
+                SliderAxis::Horizontal,
+                tokens.tooltip_offset,
+                format!("{value:.display_precision$}"),
+                window,
+            ));
+        }
 
         let mut rail = div()
             .id(self.id.slot("rail"))
@@ -541,6 +949,7 @@ impl RenderOnce for Slider {
                 min: self.min,
                 max: self.max,
                 step: self.step,
+                snap_marks: snap_marks.clone(),
                 controlled: is_controlled,
             };
             let slider_id = self.id.to_string();
@@ -551,7 +960,7 @@ impl RenderOnce for Slider {
             let step = self.step;
             let on_change_for_click = on_change.clone();
 
-            rail = rail
+            rail = Self::track_focus(&self.id, self.keyboard_rail(rail, value))
                 .cursor_pointer()
                 .on_click(move |event: &ClickEvent, window, cx| {
                     let local_x = f32::from(event.position().x).clamp(0.0, track_len);
@@ -562,7 +971,7 @@ impl RenderOnce for Slider {
                         min,
                         max,
                     );
-                    let next = slider_axis::normalize(min, max, step, raw);
+                    let next = slider_axis::snap(min, max, step, &snap_marks, raw);
                     if !is_controlled {
                         control::set_f32_state(&id, "value", next);
                         window.refresh();
@@ -571,7 +980,10 @@ impl RenderOnce for Slider {
                         (handler)(next, window, cx);
                     }
                 })
-                .on_drag(drag_state, |_drag, _, _, cx| cx.new(|_| EmptyView))
+                .on_drag(drag_state, |drag, _, _, cx| {
+                    control::set_bool_state(&drag.slider_id, "dragging", true);
+                    cx.new(|_| EmptyView)
+                })
                 .on_drag_move::<SliderDragState>(move |event, window, cx| {
                     let drag = event.drag(cx);
                     if drag.slider_id != slider_id {
@@ -589,7 +1001,8 @@ impl RenderOnce for Slider {
                         drag.min,
                         drag.max,
                     );
-                    let next = slider_axis::normalize(drag.min, drag.max, drag.step, raw);
+                    let next =
+                        slider_axis::snap(drag.min, drag.max, drag.step, &drag.snap_marks, raw);
 
                     if !drag.controlled {
                         control::set_f32_state(&slider_id, "value", next);
@@ -643,6 +1056,15 @@ impl RenderOnce for Slider {
             );
         }
 
+        let rail = match self.mark_label_row(SliderAxis::Horizontal, track_len, thumb_size) {
+            Some(labels) => Stack::vertical()
+                .gap(tokens.mark_label_gap)
+                .child(rail)
+                .child(labels)
+                .into_any_element(),
+            None => rail.into_any_element(),
+        };
+
         match self.layout {
             FieldLayout::Vertical => {
                 let mut container = Stack::vertical()
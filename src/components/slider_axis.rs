@@ -90,3 +90,86 @@ pub fn thumb_offset(axis: SliderAxis, track_len: f32, thumb_size: f32, value_rat
         SliderAxis::Vertical => span * (1.0 - value_ratio),
     }
 }
+
+/// Offset of the thumb's centre along the rail, where a mark for the value
+/// at `value_ratio` lines up.
+pub fn value_offset(axis: SliderAxis, track_len: f32, thumb_size: f32, value_ratio: f32) -> f32 {
+    thumb_offset(axis, track_len, thumb_size, value_ratio) + (thumb_size * 0.5)
+}
+
+/// Ratios of the ticks every `interval` from `min`, leaving out both ends.
+/// Returns nothing when they would be packed tighter than one per 1% of the
+/// rail.
+pub fn tick_ratios(min: f32, max: f32, interval: f32) -> Vec<f32> {
+    let span = max - min;
+    if interval <= 0.0 || span <= 0.0 || span / interval > 100.0 {
+        return Vec::new();
+    }
+    let count = (span / interval).ceil() as usize;
+    (1..count)
+        .map(|index| index as f32 * interval / span)
+        .filter(|ratio| *ratio < 1.0 - 0.0001)
+        .collect()
+}
+
+/// Snaps `raw` to the nearest of `marks` inside the range, falling back to
+/// the step grid when there are none.
+pub fn snap(min: f32, max: f32, step: f32, marks: &[f32], raw: f32) -> f32 {
+    let (low, high) = if min <= max { (min, max) } else { (max, min) };
+    marks
+        .iter()
+        .copied()
+        .filter(|mark| (low..=high).contains(mark))
+        .min_by(|a, b| (a - raw).abs().total_cmp(&(b - raw).abs()))
+        .unwrap_or_else(|| normalize(min, max, step, raw))
+}
+
+/// Value after pressing `key` on a focused slider. Up and Right raise it,
+/// Down and Left lower it, whatever the orientation; Shift or Page Up/Down
+/// move ten steps and Home/End jump to the ends. With `marks`, arrows move
+/// to the neighbouring mark instead.
+pub fn keyboard_value(
+    key: &str,
+    shift: bool,
+    current: f32,
+    min: f32,
+    max: f32,
+    step: f32,
+    marks: &[f32],
+) -> Option<f32> {
+    let (low, high) = if min <= max { (min, max) } else { (max, min) };
+    let direction = match key {
+        "right" | "up" | "pageup" => 1.0,
+        "left" | "down" | "pagedown" => -1.0,
+        "home" => return Some(snap(min, max, step, marks, low)),
+        "end" => return Some(snap(min, max, step, marks, high)),
+        _ => return None,
+    };
+    let in_range = marks
+        .iter()
+        .copied()
+        .filter(|mark| (low..=high).contains(mark));
+    let neighbour = if direction > 0.0 {
+        in_range
+            .filter(|mark| *mark > current + 0.0001)
+            .min_by(f32::total_cmp)
+    } else {
+        in_range
+            .filter(|mark| *mark < current - 0.0001)
+            .max_by(f32::total_cmp)
+    };
+    if !marks.is_empty() {
+        return Some(neighbour.unwrap_or(current));
+    }
+    let steps = if shift || key.starts_with("page") {
+        10.0
+    } else {
+        1.0
+    };
+    Some(normalize(
+        min,
+        max,
+        step,
+        current + direction * steps * step.max(0.001),
+    ))
+}
@@ -0,0 +1,152 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{Hsla, IntoElement, ParentElement, RenderOnce, SharedString, Styled, div, point, px};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+
+use super::chart::{self, ValueFormatter};
+use super::chart_state;
+use super::utils::resolve_hsla;
+
+/// A small axis-less line for showing a trend inline with text or in a
+/// table cell.
+#[derive(IntoElement)]
+pub struct Sparkline {
+    pub(crate) id: ComponentId,
+    values: Vec<f32>,
+    color: Option<Hsla>,
+    width_px: Option<f32>,
+    height_px: Option<f32>,
+    area: bool,
+    show_tooltip: bool,
+    value_format: Option<ValueFormatter>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl Sparkline {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            values: Vec::new(),
+            color: None,
+            width_px: None,
+            height_px: None,
+            area: false,
+            show_tooltip: true,
+            value_format: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    pub fn values(mut self, values: impl IntoIterator<Item = f32>) -> Self {
+        self.values = values.into_iter().collect();
+        self
+    }
+
+    /// Line color. Defaults to the theme's primary color.
+    pub fn color(mut self, value: impl Into<Hsla>) -> Self {
+        self.color = Some(value.into());
+        self
+    }
+
+    pub fn width(mut self, value: f32) -> Self {
+        self.width_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn height(mut self, value: f32) -> Self {
+        self.height_px = Some(value.max(1.0));
+        self
+    }
+
+    /// Shades the region under the line.
+    pub fn area(mut self, value: bool) -> Self {
+        self.area = value;
+        self
+    }
+
+    pub fn show_tooltip(mut self, value: bool) -> Self {
+        self.show_tooltip = value;
+        self
+    }
+
+    pub fn value_format(mut self, formatter: impl Fn(f32) -> SharedString + 'static) -> Self {
+        self.value_format = Some(Rc::new(formatter));
+        self
+    }
+}
+
+impl MotionAware for Sparkline {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for Sparkline {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.chart.clone();
+        let color = self
+            .color
+            .unwrap_or_else(|| chart::series_color(&self.theme, 0));
+        let point_bg = resolve_hsla(&self.theme, tokens.point_bg);
+        let range = chart_state::value_bounds(self.values.iter().copied(), false);
+        let count = self.values.len();
+        let hovered = chart_state::hovered(&self.id).filter(|index| *index < count);
+        let (line_width, point_size, area) =
+            (tokens.sparkline_line_width, tokens.point_size, self.area);
+
+        let plot = chart::plot_layer(
+            &self.id,
+            self.values.clone(),
+            chart::data_motion(self.motion, cx),
+            move |bounds, values, window| {
+                let (width, height) = (f32::from(bounds.size.width), f32::from(bounds.size.height));
+                let points = values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let x = chart_state::point_x(index, count, width);
+                        let y = chart_state::scale_y(*value, range, height);
+                        point(bounds.origin.x + px(x), bounds.origin.y + px(y))
+                    })
+                    .collect::<Vec<_>>();
+                chart::paint_line(window, bounds, &points, line_width, color, area);
+                if let Some(at) = hovered.and_then(|index| points.get(index)) {
+                    chart::paint_marker(window, *at, point_size, color, point_bg);
+                }
+            },
+        );
+
+        let root = div()
+            .id(self.id.clone())
+            .relative()
+            .flex_none()
+            .w(self.width_px.map(px).unwrap_or(tokens.sparkline_width))
+            .h(self.height_px.map(px).unwrap_or(tokens.sparkline_height))
+            .child(plot);
+        let root = chart::track_hover(root, &self.id, move |(x, _), (width, _)| {
+            chart_state::nearest_point(x, count, width)
+        });
+
+        let tooltip = hovered.filter(|_| self.show_tooltip).map(|index| {
+            let (_, (width, height)) = chart_state::plot(&self.id);
+            let value = self.values[index];
+            let at = (
+                chart_state::point_x(index, count, width),
+                chart_state::scale_y(value, range, height),
+            );
+            let label = chart::format_with(self.value_format.as_ref(), value);
+            let bubble = chart::tooltip_bubble(&self.theme, window, Some(label), Vec::new());
+            chart::tooltip_at(&self.id, &self.theme, at, bubble)
+        });
+        root.children(tooltip)
+    }
+}
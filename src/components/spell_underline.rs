@@ -0,0 +1,49 @@
+use gpui::{Hsla, IntoElement, PathBuilder, Styled, canvas, point, px};
+
+const WAVE_STEP: f32 = 2.0;
+const WAVE_HEIGHT: f32 = 1.5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct SpellUnderline {
+    pub start_x: f32,
+    pub end_x: f32,
+    pub baseline_y: f32,
+}
+
+pub(super) fn wave_points(underline: SpellUnderline) -> Vec<(f32, f32)> {
+    let width = (underline.end_x - underline.start_x).max(0.0);
+    let steps = (width / WAVE_STEP).ceil().max(1.0) as usize;
+    (0..=steps)
+        .map(|step| {
+            let x = (underline.start_x + step as f32 * WAVE_STEP).min(underline.end_x);
+            let offset = if step % 2 == 0 { 0.0 } else { WAVE_HEIGHT };
+            (x, underline.baseline_y + offset)
+        })
+        .collect()
+}
+
+pub(super) fn spell_underlines(underlines: Vec<SpellUnderline>, color: Hsla) -> impl IntoElement {
+    canvas(
+        |_, _, _| (),
+        move |bounds, _, window, _| {
+            for underline in &underlines {
+                let mut builder = PathBuilder::stroke(px(1.0));
+                for (index, (x, y)) in wave_points(*underline).into_iter().enumerate() {
+                    let at = point(bounds.origin.x + px(x), bounds.origin.y + px(y));
+                    if index == 0 {
+                        builder.move_to(at);
+                    } else {
+                        builder.line_to(at);
+                    }
+                }
+                if let Ok(path) = builder.build() {
+                    window.paint_path(path, color);
+                }
+            }
+        },
+    )
+    .absolute()
+    .top_0()
+    .left_0()
+    .size_full()
+}
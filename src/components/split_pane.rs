@@ -0,0 +1,381 @@
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, AppContext, EmptyView, FocusHandle, IntoElement, MouseButton, ParentElement,
+    RenderOnce, Styled, Window, canvas, div, relative,
+};
+
+use crate::id::ComponentId;
+use crate::style::GroupOrientation;
+
+use super::control;
+use super::split_pane_state;
+use super::utils::resolve_hsla;
+
+type ResizeHandler = Rc<dyn Fn(f32, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone)]
+struct SplitPaneDragState {
+    split_id: String,
+}
+
+/// Two panes separated by a draggable divider. Panes can hold another
+/// `SplitPane` to build nested layouts.
+#[derive(IntoElement)]
+pub struct SplitPane {
+    pub(crate) id: ComponentId,
+    orientation: GroupOrientation,
+    first: Option<AnyElement>,
+    second: Option<AnyElement>,
+    ratio: Option<f32>,
+    default_ratio: f32,
+    min_first_px: Option<f32>,
+    min_second_px: Option<f32>,
+    step_px: f32,
+    focus_handle: Option<FocusHandle>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    on_resize: Option<ResizeHandler>,
+}
+
+impl SplitPane {
+    #[track_caller]
+    pub fn new(orientation: GroupOrientation) -> Self {
+        Self {
+            id: ComponentId::default(),
+            orientation,
+            first: None,
+            second: None,
+            ratio: None,
+            default_ratio: 0.5,
+            min_first_px: None,
+            min_second_px: None,
+            step_px: 16.0,
+            focus_handle: None,
+            theme: crate::theme::LocalTheme::default(),
+            on_resize: None,
+        }
+    }
+
+    /// Panes side by side with a vertical divider.
+    #[track_caller]
+    pub fn horizontal() -> Self {
+        Self::new(GroupOrientation::Horizontal)
+    }
+
+    /// Panes stacked with a horizontal divider.
+    #[track_caller]
+    pub fn vertical() -> Self {
+        Self::new(GroupOrientation::Vertical)
+    }
+
+    pub fn first(mut self, content: impl IntoElement + 'static) -> Self {
+        self.first = Some(content.into_any_element());
+        self
+    }
+
+    pub fn second(mut self, content: impl IntoElement + 'static) -> Self {
+        self.second = Some(content.into_any_element());
+        self
+    }
+
+    /// Share of the space given to the first pane, from `0.0` to `1.0`.
+    pub fn ratio(mut self, value: f32) -> Self {
+        self.ratio = Some(split_pane_state::clamp_unit(value));
+        self
+    }
+
+    /// Initial ratio, also restored by double-clicking the divider.
+    pub fn default_ratio(mut self, value: f32) -> Self {
+        self.default_ratio = split_pane_state::clamp_unit(value);
+        self
+    }
+
+    pub fn min_first(mut self, value: f32) -> Self {
+        self.min_first_px = Some(value.max(0.0));
+        self
+    }
+
+    pub fn min_second(mut self, value: f32) -> Self {
+        self.min_second_px = Some(value.max(0.0));
+        self
+    }
+
+    /// Distance the divider moves per arrow key press.
+    pub fn step(mut self, value: f32) -> Self {
+        self.step_px = value.max(1.0);
+        self
+    }
+
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
+    pub fn on_resize(
+        mut self,
+        handler: impl Fn(f32, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Rc::new(handler));
+        self
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.orientation == GroupOrientation::Vertical
+    }
+
+    fn min_sizes(&self) -> (f32, f32) {
+        let fallback = f32::from(self.theme.components.split_pane.min_pane_size);
+        (
+            self.min_first_px.unwrap_or(fallback),
+            self.min_second_px.unwrap_or(fallback),
+        )
+    }
+
+    fn resolved_ratio(&self) -> f32 {
+        let (min_first, min_second) = self.min_sizes();
+        let ratio = control::f32_state(&self.id, "ratio", self.ratio, self.default_ratio);
+        let extent = split_pane_state::stored_extent(&self.id);
+        split_pane_state::clamp_ratio(ratio, extent, min_first, min_second)
+    }
+
+    fn commit(
+        id: &str,
+        controlled: bool,
+        next: f32,
+        on_resize: Option<&ResizeHandler>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        let previous = control::f32_state(id, "ratio", None, next);
+        if !controlled {
+            control::set_f32_state(id, "ratio", next);
+            window.refresh();
+        }
+        if (previous - next).abs() > f32::EPSILON
+            && let Some(handler) = on_resize
+        {
+            (handler)(next, window, cx);
+        }
+    }
+
+    fn render_handle(&self, ratio: f32, active: gpui::Hsla) -> AnyElement {
+        let tokens = &self.theme.components.split_pane;
+        let handle_size = tokens.handle_size;
+        let vertical = self.is_vertical();
+        let split_id = self.id.to_string();
+        let controlled = self.ratio.is_some();
+        let default_ratio = self.default_ratio;
+        let (min_first, min_second) = self.min_sizes();
+        let step = self.step_px;
+        let on_resize = self.on_resize.clone();
+        let focused = control::focused_state(&self.id, None, false);
+
+        let indicator = focused.then(|| {
+            let indicator = div().bg(active);
+            if vertical {
+                indicator.w_full().h(tokens.divider_size)
+            } else {
+                indicator.h_full().w(tokens.divider_size)
+            }
+        });
+
+        let mut handle = div()
+            .id(self.id.slot("handle"))
+            .absolute()
+            .flex()
+            .items_center()
+            .justify_center()
+            .focusable()
+            .hover(move |style| style.bg(active.opacity(0.2)))
+            .children(indicator);
+        handle = if vertical {
+            handle
+                .left_0()
+                .w_full()
+                .top(relative(ratio))
+                .mt(-handle_size * 0.5)
+                .h(handle_size)
+                .cursor_row_resize()
+        } else {
+            handle
+                .top_0()
+                .h_full()
+                .left(relative(ratio))
+                .ml(-handle_size * 0.5)
+                .w(handle_size)
+                .cursor_col_resize()
+        };
+        if let Some(focus_handle) = self.focus_handle.as_ref() {
+            handle = handle.track_focus(focus_handle);
+        }
+
+        handle
+            .on_mouse_down(MouseButton::Left, {
+                let split_id = split_id.clone();
+                let on_resize = on_resize.clone();
+                let focus_handle = self.focus_handle.clone();
+                move |event, window, cx| {
+                    control::set_focused_state(&split_id, true);
+                    if let Some(focus_handle) = focus_handle.as_ref() {
+                        window.focus(focus_handle, cx);
+                    }
+                    if event.click_count == 2 {
+                        let extent = split_pane_state::stored_extent(&split_id);
+                        let next = split_pane_state::clamp_ratio(
+                            default_ratio,
+                            extent,
+                            min_first,
+                            min_second,
+                        );
+                        Self::commit(&split_id, controlled, next, on_resize.as_ref(), window, cx);
+                    }
+                    cx.stop_propagation();
+                    window.refresh();
+                }
+            })
+            .on_key_down({
+                let split_id = split_id.clone();
+                let on_resize = on_resize.clone();
+                move |event, window, cx| {
+                    if !control::is_plain_keystroke(event) {
+                        return;
+                    }
+                    let extent = split_pane_state::stored_extent(&split_id);
+                    let current = control::f32_state(&split_id, "ratio", None, ratio);
+                    let Some(next) = split_pane_state::keyboard_ratio(
+                        current,
+                        event.keystroke.key.as_str(),
+                        step,
+                        extent.unwrap_or(1.0),
+                        vertical,
+                    ) else {
+                        return;
+                    };
+                    let next = split_pane_state::clamp_ratio(next, extent, min_first, min_second);
+                    Self::commit(&split_id, controlled, next, on_resize.as_ref(), window, cx);
+                    cx.stop_propagation();
+                    window.prevent_default();
+                }
+            })
+            .on_mouse_down_out(move |_, window, _| {
+                if control::focused_state(&split_id, None, false) {
+                    control::set_focused_state(&split_id, false);
+                    window.refresh();
+                }
+            })
+            .on_drag(
+                SplitPaneDragState {
+                    split_id: self.id.to_string(),
+                },
+                |_drag, _, _, cx| cx.new(|_| EmptyView),
+            )
+            .into_any_element()
+    }
+}
+
+impl RenderOnce for SplitPane {
+    fn render(mut self, _window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let tokens = &self.theme.components.split_pane;
+        let line = resolve_hsla(&self.theme, tokens.divider);
+        let active = resolve_hsla(&self.theme, tokens.divider_active);
+        let divider_size = tokens.divider_size;
+        let vertical = self.is_vertical();
+        let ratio = self.resolved_ratio();
+        let controlled = self.ratio.is_some();
+        if controlled {
+            control::set_f32_state(&self.id, "ratio", ratio);
+        }
+
+        let measure = canvas(
+            {
+                let id = self.id.clone();
+                move |bounds, window, _| {
+                    let extent = if vertical {
+                        bounds.size.height
+                    } else {
+                        bounds.size.width
+                    };
+                    if split_pane_state::store_extent(&id, f32::from(extent)) {
+                        window.refresh();
+                    }
+                }
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+
+        let mut first = div()
+            .id(self.id.slot("first"))
+            .relative()
+            .flex_none()
+            .overflow_hidden();
+        let mut second = div()
+            .id(self.id.slot("second"))
+            .relative()
+            .flex_1()
+            .min_w_0()
+            .min_h_0()
+            .overflow_hidden();
+        let divider = div().flex_none().bg(line);
+        let (first_pane, second_pane, divider) = if vertical {
+            first = first.w_full().h(relative(ratio));
+            second = second.w_full();
+            (first, second, divider.w_full().h(divider_size))
+        } else {
+            first = first.h_full().w(relative(ratio));
+            second = second.h_full();
+            (first, second, divider.h_full().w(divider_size))
+        };
+        let first_pane = match self.first.take() {
+            Some(content) => first_pane.child(content),
+            None => first_pane,
+        };
+        let second_pane = match self.second.take() {
+            Some(content) => second_pane.child(content),
+            None => second_pane,
+        };
+
+        let mut frame = div()
+            .id(self.id.clone())
+            .relative()
+            .size_full()
+            .flex()
+            .overflow_hidden();
+        frame = if vertical {
+            frame.flex_col()
+        } else {
+            frame.flex_row()
+        };
+
+        let (min_first, min_second) = self.min_sizes();
+        let split_id = self.id.to_string();
+        let on_resize = self.on_resize.clone();
+        frame
+            .child(measure)
+            .child(first_pane)
+            .child(divider)
+            .child(second_pane)
+            .child(self.render_handle(ratio, active))
+            .on_drag_move::<SplitPaneDragState>(move |event, window, cx| {
+                let drag = event.drag(cx);
+                if drag.split_id != split_id {
+                    return;
+                }
+                let bounds = event.bounds;
+                let position = event.event.position;
+                let (local, extent) = if vertical {
+                    (position.y - bounds.origin.y, bounds.size.height)
+                } else {
+                    (position.x - bounds.origin.x, bounds.size.width)
+                };
+                let extent = f32::from(extent);
+                let next = split_pane_state::ratio_from_local(f32::from(local), extent);
+                let next = split_pane_state::clamp_ratio(next, Some(extent), min_first, min_second);
+                Self::commit(&split_id, controlled, next, on_resize.as_ref(), window, cx);
+            })
+    }
+}
@@ -0,0 +1,63 @@
+use super::control;
+
+pub fn clamp_unit(value: f32) -> f32 {
+    if value.is_finite() {
+        value.clamp(0.0, 1.0)
+    } else {
+        0.5
+    }
+}
+
+/// Clamps `ratio` so both panes keep their minimum size within `extent`.
+/// When the minimums do not fit, they share the space proportionally.
+pub fn clamp_ratio(ratio: f32, extent: Option<f32>, min_first: f32, min_second: f32) -> f32 {
+    let ratio = clamp_unit(ratio);
+    let Some(extent) = extent.filter(|extent| *extent > 0.0) else {
+        return ratio;
+    };
+    let min_first = min_first.max(0.0);
+    let min_second = min_second.max(0.0);
+    if min_first + min_second >= extent {
+        return clamp_unit(min_first / (min_first + min_second).max(1.0));
+    }
+    ratio.clamp(min_first / extent, 1.0 - min_second / extent)
+}
+
+pub fn ratio_from_local(local: f32, extent: f32) -> f32 {
+    clamp_unit(local / extent.max(1.0))
+}
+
+/// Arrow keys along the split axis move the divider by `step_px`; Home and
+/// End jump to the extremes, which `clamp_ratio` then bounds.
+pub fn keyboard_ratio(
+    current: f32,
+    key: &str,
+    step_px: f32,
+    extent: f32,
+    vertical: bool,
+) -> Option<f32> {
+    let step = step_px.max(1.0) / extent.max(1.0);
+    let next = match (key, vertical) {
+        ("left", false) | ("up", true) => current - step,
+        ("right", false) | ("down", true) => current + step,
+        ("pageup", _) => current - step * 10.0,
+        ("pagedown", _) => current + step * 10.0,
+        ("home", _) => 0.0,
+        ("end", _) => 1.0,
+        _ => return None,
+    };
+    Some(clamp_unit(next))
+}
+
+pub fn store_extent(id: &str, extent: f32) -> bool {
+    let previous = stored_extent(id);
+    if previous.is_some_and(|value| (value - extent).abs() < 0.5) {
+        return false;
+    }
+    control::set_optional_f32_state(id, "extent", Some(extent));
+    true
+}
+
+pub fn stored_extent(id: &str) -> Option<f32> {
+    control::optional_f32_state(id, "extent", None, None)
+}
@@ -0,0 +1,314 @@
+use std::time::Duration;
+
+use gpui::InteractiveElement;
+use gpui::{
+    Animation, AnimationExt, AnyElement, Hsla, IntoElement, ParentElement, Pixels, RenderOnce,
+    SharedString, Styled, div, px,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::{MotionConfig, MotionLevel};
+use crate::provider::CalmProvider;
+
+use super::icon::Icon;
+use super::utils::resolve_hsla;
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+
+/// Direction of a [`StatCard`] delta.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DeltaTrend {
+    Up,
+    Down,
+    Flat,
+}
+
+impl DeltaTrend {
+    pub(crate) fn of(delta: f32) -> Self {
+        if delta > 0.0 {
+            Self::Up
+        } else if delta < 0.0 {
+            Self::Down
+        } else {
+            Self::Flat
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Up => "trending-up",
+            Self::Down => "trending-down",
+            Self::Flat => "minus",
+        }
+    }
+}
+
+/// Signed percentage with at most one decimal, e.g. `+12.5%` or `-3%`.
+pub(crate) fn format_delta(delta: f32) -> SharedString {
+    let rounded = (delta * 10.0).round() / 10.0;
+    let sign = if rounded > 0.0 { "+" } else { "" };
+    if rounded == 0.0 {
+        "0%".into()
+    } else if rounded.fract() == 0.0 {
+        format!("{sign}{rounded:.0}%").into()
+    } else {
+        format!("{sign}{rounded:.1}%").into()
+    }
+}
+
+/// A dashboard figure: a label, a large value, and optionally how it
+/// changed, a short description and a trend chart underneath.
+#[derive(IntoElement)]
+pub struct StatCard {
+    pub(crate) id: ComponentId,
+    label: Option<SharedString>,
+    value: Option<SharedString>,
+    icon: Option<SharedString>,
+    delta: Option<f32>,
+    delta_label: Option<SharedString>,
+    invert_delta: bool,
+    description: Option<SharedString>,
+    sparkline: Option<SlotRenderer>,
+    loading: bool,
+    bordered: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl StatCard {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            label: None,
+            value: None,
+            icon: None,
+            delta: None,
+            delta_label: None,
+            invert_delta: false,
+            description: None,
+            sparkline: None,
+            loading: false,
+            bordered: true,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Icon name shown in the top right corner.
+    pub fn icon(mut self, name: impl Into<SharedString>) -> Self {
+        self.icon = Some(name.into());
+        self
+    }
+
+    /// Change in percent. Its sign picks the arrow and color.
+    pub fn delta(mut self, value: f32) -> Self {
+        self.delta = Some(value);
+        self
+    }
+
+    /// Replaces the formatted percentage next to the arrow.
+    pub fn delta_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.delta_label = Some(value.into());
+        self
+    }
+
+    /// Colors decreases as good and increases as bad, for figures such as
+    /// cost or latency.
+    pub fn invert_delta(mut self, value: bool) -> Self {
+        self.invert_delta = value;
+        self
+    }
+
+    /// Muted text after the delta, such as "vs last month".
+    pub fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Content under the figures, usually a
+    /// [`Sparkline`](crate::widgets::charts::Sparkline).
+    pub fn sparkline(mut self, content: impl IntoElement + 'static) -> Self {
+        self.sparkline = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    /// Shows pulsing placeholders instead of the value and delta.
+    pub fn loading(mut self, value: bool) -> Self {
+        self.loading = value;
+        self
+    }
+
+    pub fn bordered(mut self, value: bool) -> Self {
+        self.bordered = value;
+        self
+    }
+
+    fn delta_color(&self, trend: DeltaTrend) -> Hsla {
+        let tokens = &self.theme.components.stat_card;
+        let color = match (trend, self.invert_delta) {
+            (DeltaTrend::Flat, _) => tokens.delta_flat,
+            (DeltaTrend::Up, false) | (DeltaTrend::Down, true) => tokens.delta_up,
+            (DeltaTrend::Down, false) | (DeltaTrend::Up, true) => tokens.delta_down,
+        };
+        resolve_hsla(&self.theme, color)
+    }
+
+    fn render_delta(&self) -> Option<AnyElement> {
+        let tokens = &self.theme.components.stat_card;
+        let description = self.description.clone().map(|text| {
+            div()
+                .text_size(tokens.description_size)
+                .text_color(resolve_hsla(&self.theme, tokens.description_fg))
+                .child(text)
+        });
+        let delta = self.delta.map(|delta| {
+            let trend = DeltaTrend::of(delta);
+            let color = self.delta_color(trend);
+            div()
+                .flex()
+                .items_center()
+                .gap(px(2.0))
+                .text_size(tokens.delta_size)
+                .text_color(color)
+                .font_weight(gpui::FontWeight::MEDIUM)
+                .child(
+                    self.id
+                        .ctx()
+                        .child("delta-icon", Icon::named(trend.icon()))
+                        .size(f32::from(tokens.delta_icon_size))
+                        .color(color),
+                )
+                .child(
+                    self.delta_label
+                        .clone()
+                        .unwrap_or_else(|| format_delta(delta)),
+                )
+        });
+        if delta.is_none() && description.is_none() {
+            return None;
+        }
+        Some(
+            div()
+                .flex()
+                .items_center()
+                .gap(tokens.gap)
+                .children(delta)
+                .children(description)
+                .into_any_element(),
+        )
+    }
+
+    fn skeleton(&self, width: Pixels, height: Pixels, animated: bool, slot: &str) -> AnyElement {
+        let tokens = &self.theme.components.stat_card;
+        let bar = div()
+            .w(width)
+            .h(height)
+            .rounded(px(4.0))
+            .bg(resolve_hsla(&self.theme, tokens.skeleton_bg));
+        if !animated {
+            return bar.into_any_element();
+        }
+        let pulse = Animation::new(Duration::from_millis(1200))
+            .repeat()
+            .with_easing(gpui::pulsating_between(0.45, 1.0));
+        bar.with_animation(self.id.slot(slot), pulse, |this, delta| this.opacity(delta))
+            .into_any_element()
+    }
+}
+
+impl MotionAware for StatCard {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for StatCard {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.stat_card.clone();
+        let animated = self.motion.level == MotionLevel::Full
+            && CalmProvider::motion_level(cx) == MotionLevel::Full;
+
+        let icon = self.icon.clone().map(|name| {
+            self.id
+                .ctx()
+                .child("icon", Icon::named(name.to_string()))
+                .size(f32::from(tokens.icon_size))
+                .color(resolve_hsla(&self.theme, tokens.icon_fg))
+        });
+        let header = div()
+            .flex()
+            .items_start()
+            .justify_between()
+            .gap(tokens.gap)
+            .child(
+                div()
+                    .min_w_0()
+                    .text_size(tokens.label_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.label_fg))
+                    .children(self.label.clone()),
+            )
+            .children(icon);
+
+        let (value, delta) = if self.loading {
+            let value = self.skeleton(
+                tokens.value_size * 4.0,
+                tokens.value_size,
+                animated,
+                "value",
+            );
+            let delta = self.skeleton(
+                tokens.delta_size * 6.0,
+                tokens.delta_size,
+                animated,
+                "delta",
+            );
+            (value, Some(delta))
+        } else {
+            let value = div()
+                .text_size(tokens.value_size)
+                .text_color(resolve_hsla(&self.theme, tokens.value_fg))
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .children(self.value.clone())
+                .into_any_element();
+            (value, self.render_delta())
+        };
+        let sparkline = self
+            .sparkline
+            .take()
+            .filter(|_| !self.loading)
+            .map(|render| render());
+
+        let mut root = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .gap(tokens.gap)
+            .p(tokens.padding)
+            .rounded(tokens.radius)
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .child(header)
+            .child(value)
+            .children(delta)
+            .children(sparkline);
+        if self.bordered {
+            root = root
+                .border_1()
+                .border_color(resolve_hsla(&self.theme, tokens.border));
+        }
+        root
+    }
+}
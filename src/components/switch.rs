@@ -10,12 +10,13 @@ use crate::contracts::{FieldLike, MotionAware};
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
 use crate::style::{FieldLayout, Radius, Size, Variant};
+use crate::theme::SemanticRadiusToken;
 
 use super::Stack;
 use super::control;
 use super::toggle::{ToggleConfig, wire_toggle_handlers};
 use super::transition::TransitionExt;
-use super::utils::{apply_radius, resolve_hsla, snap_px};
+use super::utils::{apply_focus_ring, apply_radius, resolve_hsla, resolve_radius, snap_px};
 
 type SwitchChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
 
@@ -201,6 +202,12 @@ impl RenderOnce for Switch {
             track.justify_start()
         };
         track = apply_radius(&self.theme, track, self.radius);
+        track = apply_focus_ring(
+            &self.theme,
+            track,
+            is_focused && !self.disabled,
+            resolve_radius(&self.theme, SemanticRadiusToken::from(self.radius)),
+        );
         if !self.disabled {
             let hover_border =
                 self.variant_track_color(resolve_hsla(&self.theme, tokens.track_hover_border));
@@ -304,6 +311,7 @@ impl RenderOnce for Switch {
                 row,
                 ToggleConfig {
                     id: self.id.clone(),
+                    component: "switch",
                     checked,
                     controlled: is_controlled,
                     allow_uncheck: true,
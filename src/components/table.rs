@@ -1,22 +1,30 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 use std::rc::Rc;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
 use gpui::{
-    AnyElement, IntoElement, ParentElement, RenderOnce, ScrollHandle, SharedString, Styled, canvas,
-    div, point, px,
+    AnyElement, AppContext, Div, ElementId, EmptyView, IntoElement, MouseButton, ParentElement,
+    RenderOnce, ScrollHandle, SharedString, Stateful, Styled, canvas, div, point, px,
 };
 
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
+use crate::resource::Resource;
 use crate::style::{Radius, Size};
 
 use super::Stack;
+use super::icon::Icon;
 use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
 use super::pagination::Pagination;
 use super::scroll_area::{ScrollArea, ScrollDirection};
-use super::table_state::{self, TableState, TableStateInput};
+use super::table_state::{self, SelectionModifiers, TableState, TableStateInput};
+use super::text_selection::{FindHighlight, FindScope, SelectableText};
 use super::utils::{
     InteractionStyles, apply_interaction_styles, apply_radius, hairline_px, interaction_style,
     resolve_hsla,
@@ -27,6 +35,13 @@ type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
 type PageChangeHandler = Rc<dyn Fn(usize, &mut gpui::Window, &mut gpui::App)>;
 type PageSizeChangeHandler = Rc<dyn Fn(usize, &mut gpui::Window, &mut gpui::App)>;
 type RowClickHandler = Rc<dyn Fn(usize, &mut gpui::Window, &mut gpui::App)>;
+type SortChangeHandler = Rc<dyn Fn(&[TableSort], &mut gpui::Window, &mut gpui::App)>;
+type SortComparator = Rc<dyn Fn(&str, &str) -> Ordering>;
+type LayoutChangeHandler = Rc<dyn Fn(&TableLayoutState, &mut gpui::Window, &mut gpui::App)>;
+type SelectionChangeHandler = Rc<dyn Fn(&TableSelectionChanged, &mut gpui::Window, &mut gpui::App)>;
+type ExpandedChangeHandler = Rc<dyn Fn(&[SharedString], &mut gpui::Window, &mut gpui::App)>;
+type GroupAggregate = Rc<dyn Fn(&[SharedString]) -> SharedString>;
+type ControlCellHandler = Rc<dyn Fn(bool, &mut gpui::Window, &mut gpui::App)>;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TableSortDirection {
@@ -40,6 +55,199 @@ pub struct TableSort {
     pub direction: TableSortDirection,
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TableSelectionMode {
+    #[default]
+    None,
+    Single,
+    Multi,
+    Checkbox,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TableSelectionChanged {
+    pub selected_keys: Vec<SharedString>,
+    pub key: Option<SharedString>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableLayoutState {
+    pub order: Vec<usize>,
+    pub widths: BTreeMap<usize, f32>,
+}
+
+impl TableLayoutState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn order(mut self, order: impl IntoIterator<Item = usize>) -> Self {
+        self.order = order.into_iter().collect();
+        self
+    }
+
+    pub fn width(mut self, column: usize, width: f32) -> Self {
+        self.set_column_width(column, width);
+        self
+    }
+
+    pub fn column_order(&self, column_count: usize) -> Vec<usize> {
+        let mut order = self
+            .order
+            .iter()
+            .copied()
+            .filter(|column| *column < column_count)
+            .fold(Vec::with_capacity(column_count), |mut order, column| {
+                if !order.contains(&column) {
+                    order.push(column);
+                }
+                order
+            });
+        let missing = (0..column_count)
+            .filter(|column| !order.contains(column))
+            .collect::<Vec<_>>();
+        order.extend(missing);
+        order
+    }
+
+    pub fn column_width(&self, column: usize) -> Option<f32> {
+        self.widths.get(&column).copied()
+    }
+
+    pub fn set_column_width(&mut self, column: usize, width: f32) {
+        if width.is_finite() && width > 0.0 {
+            self.widths.insert(column, width);
+        }
+    }
+
+    pub fn clear_column_width(&mut self, column: usize) {
+        self.widths.remove(&column);
+    }
+
+    pub fn move_column(&mut self, column: usize, target: usize, column_count: usize) {
+        let mut order = self.column_order(column_count);
+        let (Some(from), Some(to)) = (
+            order.iter().position(|value| *value == column),
+            order.iter().position(|value| *value == target),
+        ) else {
+            return;
+        };
+        let moved = order.remove(from);
+        order.insert(to, moved);
+        self.order = order;
+    }
+}
+
+#[derive(Clone)]
+struct TableColumnDrag {
+    table_id: ComponentId,
+    column: usize,
+}
+
+#[derive(Clone)]
+struct TableColumnResizeDrag {
+    table_id: ComponentId,
+    column: usize,
+}
+
+#[derive(Clone)]
+struct SelectionContext {
+    table_id: ComponentId,
+    mode: TableSelectionMode,
+    controlled: Option<Vec<String>>,
+    default: Vec<String>,
+    visible: Vec<String>,
+    on_change: Option<SelectionChangeHandler>,
+}
+
+impl SelectionContext {
+    fn current(&self) -> Vec<String> {
+        table_state::resolve_selection(
+            &self.table_id,
+            self.controlled.clone(),
+            self.default.clone(),
+        )
+    }
+
+    fn select(
+        &self,
+        key: &str,
+        modifiers: SelectionModifiers,
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) {
+        let anchor = table_state::selection_anchor(&self.table_id);
+        let next = table_state::select_row(
+            &self.current(),
+            &self.visible,
+            key,
+            anchor.as_deref(),
+            self.mode,
+            modifiers,
+        );
+        if !(modifiers.range && anchor.is_some()) {
+            table_state::set_selection_anchor(&self.table_id, Some(key.to_string()));
+        }
+        self.commit(next, Some(key), window, cx);
+    }
+
+    fn toggle_all(&self, window: &mut gpui::Window, cx: &mut gpui::App) {
+        let next = table_state::toggle_all(&self.current(), &self.visible);
+        self.commit(next, None, window, cx);
+    }
+
+    fn commit(
+        &self,
+        next: Vec<String>,
+        key: Option<&str>,
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) {
+        if self.controlled.is_none() {
+            table_state::set_selection(&self.table_id, next.clone());
+        }
+        if let Some(handler) = self.on_change.as_ref() {
+            let event = TableSelectionChanged {
+                selected_keys: next.into_iter().map(SharedString::from).collect(),
+                key: key.map(|key| SharedString::from(key.to_string())),
+            };
+            (handler)(&event, window, cx);
+        }
+        window.refresh();
+    }
+}
+
+#[derive(Clone)]
+struct ExpansionContext {
+    table_id: ComponentId,
+    controlled: Option<Vec<String>>,
+    default: Vec<String>,
+    on_change: Option<ExpandedChangeHandler>,
+}
+
+impl ExpansionContext {
+    fn current(&self) -> Vec<String> {
+        table_state::resolve_expanded_rows(
+            &self.table_id,
+            self.controlled.clone(),
+            self.default.clone(),
+        )
+    }
+
+    fn toggle(&self, key: &str, window: &mut gpui::Window, cx: &mut gpui::App) {
+        let next = table_state::toggle_expanded_row(&self.current(), key);
+        if self.controlled.is_none() {
+            table_state::set_expanded_rows(&self.table_id, next.clone());
+        }
+        if let Some(handler) = self.on_change.as_ref() {
+            let keys = next.into_iter().map(SharedString::from).collect::<Vec<_>>();
+            (handler)(&keys, window, cx);
+        }
+        window.refresh();
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TableAlign {
     Left,
@@ -56,6 +264,7 @@ pub enum TablePaginationPosition {
 
 pub struct TableCell {
     content: CellRenderer,
+    text: Option<SharedString>,
     align: TableAlign,
     sort_value: Option<SharedString>,
     filter_value: Option<SharedString>,
@@ -65,12 +274,23 @@ impl TableCell {
     pub fn new(content: impl IntoElement + 'static) -> Self {
         Self {
             content: Box::new(|| content.into_any_element()),
+            text: None,
             align: TableAlign::Left,
             sort_value: None,
             filter_value: None,
         }
     }
 
+    /// Plain-text cell; its content can be selected and copied when the table
+    /// enables `selectable_cells`.
+    pub fn text(value: impl Into<SharedString>) -> Self {
+        let value = value.into();
+        Self {
+            text: Some(value.clone()),
+            ..Self::new(value)
+        }
+    }
+
     pub fn align(mut self, value: TableAlign) -> Self {
         self.align = value;
         self
@@ -88,7 +308,9 @@ impl TableCell {
 }
 
 pub struct TableRow {
+    key: Option<SharedString>,
     cells: Vec<TableCell>,
+    detail: Option<SlotRenderer>,
 }
 
 impl Default for TableRow {
@@ -99,7 +321,16 @@ impl Default for TableRow {
 
 impl TableRow {
     pub fn new() -> Self {
-        Self { cells: Vec::new() }
+        Self {
+            key: None,
+            cells: Vec::new(),
+            detail: None,
+        }
+    }
+
+    pub fn key(mut self, key: impl Into<SharedString>) -> Self {
+        self.key = Some(key.into());
+        self
     }
 
     pub fn cell(mut self, cell: TableCell) -> Self {
@@ -111,6 +342,15 @@ impl TableRow {
         self.cells.extend(cells);
         self
     }
+
+    pub fn detail(mut self, content: impl IntoElement + 'static) -> Self {
+        self.detail = Some(Box::new(|| content.into_any_element()));
+        self
+    }
+
+    fn has_detail(&self) -> bool {
+        self.detail.is_some()
+    }
 }
 
 #[derive(IntoElement)]
@@ -125,7 +365,25 @@ pub struct Table {
     highlight_on_hover: bool,
     max_height_px: Option<f32>,
     sticky_header: bool,
-    sort: Option<TableSort>,
+    sorts: Option<Vec<TableSort>>,
+    default_sorts: Vec<TableSort>,
+    sortable: bool,
+    sortable_columns: Vec<usize>,
+    manual_sorting: bool,
+    sort_comparators: BTreeMap<usize, SortComparator>,
+    resizable_columns: bool,
+    reorderable_columns: bool,
+    layout: Option<TableLayoutState>,
+    default_layout: TableLayoutState,
+    min_column_width: f32,
+    selection_mode: TableSelectionMode,
+    selected_keys: Option<Vec<SharedString>>,
+    default_selected_keys: Vec<SharedString>,
+    expanded_keys: Option<Vec<SharedString>>,
+    default_expanded_keys: Vec<SharedString>,
+    group_by: Option<usize>,
+    group_aggregates: BTreeMap<usize, GroupAggregate>,
+    default_collapsed_groups: Vec<SharedString>,
     filter_query: Option<SharedString>,
     filter_column: Option<usize>,
     virtual_window: Option<(usize, usize)>,
@@ -146,6 +404,8 @@ pub struct Table {
     page_size_options: Vec<usize>,
     with_outer_border: bool,
     with_column_borders: bool,
+    selectable_cells: bool,
+    find_scope: Option<FindScope>,
     size: Size,
     radius: Radius,
     pub(crate) theme: crate::theme::LocalTheme,
@@ -153,6 +413,10 @@ pub struct Table {
     on_page_change: Option<PageChangeHandler>,
     on_page_size_change: Option<PageSizeChangeHandler>,
     on_row_click: Option<RowClickHandler>,
+    on_sort_change: Option<SortChangeHandler>,
+    on_layout_change: Option<LayoutChangeHandler>,
+    on_selection_change: Option<SelectionChangeHandler>,
+    on_expanded_change: Option<ExpandedChangeHandler>,
 }
 
 impl Table {
@@ -169,7 +433,25 @@ impl Table {
             highlight_on_hover: true,
             max_height_px: None,
             sticky_header: false,
-            sort: None,
+            sorts: None,
+            default_sorts: Vec::new(),
+            sortable: false,
+            sortable_columns: Vec::new(),
+            manual_sorting: false,
+            sort_comparators: BTreeMap::new(),
+            resizable_columns: false,
+            reorderable_columns: false,
+            layout: None,
+            default_layout: TableLayoutState::default(),
+            min_column_width: 48.0,
+            selection_mode: TableSelectionMode::None,
+            selected_keys: None,
+            default_selected_keys: Vec::new(),
+            expanded_keys: None,
+            default_expanded_keys: Vec::new(),
+            group_by: None,
+            group_aggregates: BTreeMap::new(),
+            default_collapsed_groups: Vec::new(),
             filter_query: None,
             filter_column: None,
             virtual_window: None,
@@ -190,6 +472,8 @@ impl Table {
             page_size_options: vec![10, 20, 50, 100],
             with_outer_border: true,
             with_column_borders: false,
+            selectable_cells: false,
+            find_scope: None,
             size: Size::Md,
             radius: Radius::Sm,
             theme: crate::theme::LocalTheme::default(),
@@ -197,6 +481,10 @@ impl Table {
             on_page_change: None,
             on_page_size_change: None,
             on_row_click: None,
+            on_sort_change: None,
+            on_layout_change: None,
+            on_selection_change: None,
+            on_expanded_change: None,
         }
     }
 
@@ -220,6 +508,16 @@ impl Table {
         self
     }
 
+    pub fn rows_resource<T: Clone>(
+        mut self,
+        resource: &Resource<Vec<T>>,
+        row: impl Fn(&T) -> TableRow,
+    ) -> Self {
+        let records = resource.value().unwrap_or_default();
+        self.rows.extend(records.iter().map(row));
+        self
+    }
+
     pub fn caption(mut self, text: impl Into<SharedString>) -> Self {
         self.caption = Some(text.into());
         self
@@ -256,12 +554,142 @@ impl Table {
     }
 
     pub fn sort(mut self, column: usize, direction: TableSortDirection) -> Self {
-        self.sort = Some(TableSort { column, direction });
+        self.sorts = Some(vec![TableSort { column, direction }]);
+        self
+    }
+
+    pub fn sorts(mut self, sorts: impl IntoIterator<Item = TableSort>) -> Self {
+        self.sorts = Some(sorts.into_iter().collect());
+        self
+    }
+
+    pub fn default_sort(mut self, column: usize, direction: TableSortDirection) -> Self {
+        self.default_sorts = vec![TableSort { column, direction }];
+        self
+    }
+
+    pub fn default_sorts(mut self, sorts: impl IntoIterator<Item = TableSort>) -> Self {
+        self.default_sorts = sorts.into_iter().collect();
         self
     }
 
     pub fn clear_sort(mut self) -> Self {
-        self.sort = None;
+        self.sorts = Some(Vec::new());
+        self
+    }
+
+    pub fn sortable(mut self, value: bool) -> Self {
+        self.sortable = value;
+        self
+    }
+
+    pub fn sortable_column(mut self, column: usize) -> Self {
+        if !self.sortable_columns.contains(&column) {
+            self.sortable_columns.push(column);
+        }
+        self
+    }
+
+    pub fn manual_sorting(mut self, value: bool) -> Self {
+        self.manual_sorting = value;
+        self
+    }
+
+    pub fn sort_comparator(
+        mut self,
+        column: usize,
+        comparator: impl Fn(&str, &str) -> Ordering + 'static,
+    ) -> Self {
+        self.sort_comparators.insert(column, Rc::new(comparator));
+        self
+    }
+
+    pub fn resizable_columns(mut self, value: bool) -> Self {
+        self.resizable_columns = value;
+        self
+    }
+
+    pub fn reorderable_columns(mut self, value: bool) -> Self {
+        self.reorderable_columns = value;
+        self
+    }
+
+    pub fn layout(mut self, value: TableLayoutState) -> Self {
+        self.layout = Some(value);
+        self
+    }
+
+    pub fn default_layout(mut self, value: TableLayoutState) -> Self {
+        self.default_layout = value;
+        self
+    }
+
+    pub fn min_column_width(mut self, value: f32) -> Self {
+        self.min_column_width = value.max(1.0);
+        self
+    }
+
+    pub fn selection_mode(mut self, value: TableSelectionMode) -> Self {
+        self.selection_mode = value;
+        self
+    }
+
+    pub fn selected_keys(
+        mut self,
+        keys: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.selected_keys = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn default_selected_keys(
+        mut self,
+        keys: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.default_selected_keys = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn clear_selection(mut self) -> Self {
+        self.selected_keys = Some(Vec::new());
+        self
+    }
+
+    pub fn expanded_keys(
+        mut self,
+        keys: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.expanded_keys = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn default_expanded_keys(
+        mut self,
+        keys: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.default_expanded_keys = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn group_by(mut self, column: usize) -> Self {
+        self.group_by = Some(column);
+        self
+    }
+
+    pub fn group_aggregate(
+        mut self,
+        column: usize,
+        aggregate: impl Fn(&[SharedString]) -> SharedString + 'static,
+    ) -> Self {
+        self.group_aggregates.insert(column, Rc::new(aggregate));
+        self
+    }
+
+    pub fn default_collapsed_groups(
+        mut self,
+        groups: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.default_collapsed_groups = groups.into_iter().map(Into::into).collect();
         self
     }
 
@@ -388,6 +816,38 @@ impl Table {
         self
     }
 
+    pub fn on_sort_change(
+        mut self,
+        handler: impl Fn(&[TableSort], &mut gpui::Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_sort_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_layout_change(
+        mut self,
+        handler: impl Fn(&TableLayoutState, &mut gpui::Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_layout_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_selection_change(
+        mut self,
+        handler: impl Fn(&TableSelectionChanged, &mut gpui::Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_selection_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_expanded_change(
+        mut self,
+        handler: impl Fn(&[SharedString], &mut gpui::Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_expanded_change = Some(Rc::new(handler));
+        self
+    }
+
     pub fn with_outer_border(mut self, value: bool) -> Self {
         self.with_outer_border = value;
         self
@@ -397,12 +857,97 @@ impl Table {
         self.with_column_borders = value;
         self
     }
+
+    pub fn selectable_cells(mut self, value: bool) -> Self {
+        self.selectable_cells = value;
+        self
+    }
+
+    /// Makes text cells searchable by the `FindBar` that owns `scope`.
+    pub fn find_scope(mut self, scope: FindScope) -> Self {
+        self.find_scope = Some(scope);
+        self
+    }
     fn apply_cell_size<T: Styled>(preset: crate::theme::TableSizePreset, node: T) -> T {
         node.text_size(preset.font_size)
             .px(preset.padding_x)
             .py(preset.padding_y)
     }
 
+    fn apply_column_width<T: Styled>(node: T, width: Option<f32>) -> T {
+        match width {
+            Some(width) => node.flex_none().w(px(width)),
+            None => node.flex_1(),
+        }
+    }
+
+    fn commit_layout(
+        table_id: &str,
+        controlled: bool,
+        layout: &TableLayoutState,
+        on_layout_change: Option<&LayoutChangeHandler>,
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) {
+        if !controlled {
+            table_state::set_layout(table_id, layout);
+        }
+        if let Some(handler) = on_layout_change {
+            (handler)(layout, window, cx);
+        }
+        window.refresh();
+    }
+
+    fn row_key(source_index: usize, row: &TableRow) -> String {
+        row.key
+            .as_ref()
+            .map_or_else(|| source_index.to_string(), ToString::to_string)
+    }
+
+    fn control_cell(
+        preset: crate::theme::TableSizePreset,
+        table_id: &ComponentId,
+        slot: &'static str,
+        key: &str,
+        content: Option<AnyElement>,
+        on_press: Option<ControlCellHandler>,
+    ) -> AnyElement {
+        let cell =
+            Self::apply_cell_size(preset, div().id(table_id.slot_index(slot, key.to_string())))
+                .flex_none()
+                .w(preset.font_size + preset.padding_x * 2.0)
+                .flex()
+                .items_center()
+                .justify_center()
+                .children(content);
+        let Some(on_press) = on_press else {
+            return cell.into_any_element();
+        };
+        let pending_id = table_id.clone();
+        let press_id = table_id.clone();
+        cell.cursor_pointer()
+            .on_mouse_down(MouseButton::Left, move |event, _, cx| {
+                table_state::set_pending_range(&pending_id, event.modifiers.shift);
+                cx.stop_propagation();
+            })
+            .on_click(move |_, window, cx| {
+                on_press(table_state::take_pending_range(&press_id), window, cx)
+            })
+            .into_any_element()
+    }
+
+    fn check_glyph(checked: bool) -> AnyElement {
+        SharedString::from(if checked { "☑" } else { "☐" }).into_any_element()
+    }
+
+    fn data_cell(
+        preset: crate::theme::TableSizePreset,
+        id: impl Into<ElementId>,
+        width: Option<f32>,
+    ) -> Stateful<Div> {
+        Self::apply_column_width(Self::apply_cell_size(preset, div().id(id).min_w_0()), width)
+    }
+
     fn column_count(&self) -> usize {
         let row_max = self
             .rows
@@ -441,6 +986,12 @@ impl RenderOnce for Table {
         let striped = self.striped;
         let highlight_on_hover = self.highlight_on_hover;
         let with_column_borders = self.with_column_borders;
+        let selection_bg = (self.selectable_cells || self.find_scope.is_some())
+            .then(|| resolve_hsla(&self.theme, tokens.selection_bg));
+        let find = self
+            .find_scope
+            .take()
+            .map(|scope| FindHighlight::new(scope, &self.theme));
         let motion = self.motion;
         let max_height_px = self
             .max_height_px
@@ -452,7 +1003,26 @@ impl RenderOnce for Table {
             .map(|value| value.to_string().to_ascii_lowercase())
             .filter(|value| !value.trim().is_empty());
         let filter_column = self.filter_column;
-        let sort = self.sort;
+        let controlled_sorts = self.sorts.clone();
+        let default_sorts = self.default_sorts.clone();
+        let sorts =
+            table_state::resolve_sorts(&table_id, controlled_sorts.clone(), default_sorts.clone());
+        let sortable_columns = (0..column_count)
+            .map(|column| self.sortable || self.sortable_columns.contains(&column))
+            .collect::<Vec<_>>();
+        let on_sort_change = self.on_sort_change.clone();
+        let controlled_layout = self.layout.clone();
+        let default_layout = self.default_layout.clone();
+        let layout = table_state::resolve_layout(
+            &table_id,
+            controlled_layout.clone(),
+            default_layout.clone(),
+        );
+        let column_order = layout.column_order(column_count);
+        let resizable_columns = self.resizable_columns;
+        let reorderable_columns = self.reorderable_columns;
+        let min_column_width = self.min_column_width;
+        let on_layout_change = self.on_layout_change.clone();
         let virtual_window = self.virtual_window;
         let pagination_enabled = self.pagination_enabled;
         let page_size = self.page_size.max(1);
@@ -462,12 +1032,37 @@ impl RenderOnce for Table {
         let on_row_click = self.on_row_click.clone();
         let pagination_position = self.pagination_position;
         let show_page_size_selector = self.show_page_size_selector;
-        let separator = || {
-            div()
-                .w_full()
-                .h(line_thickness)
-                .bg(resolve_hsla(&self.theme, tokens.row_border))
+        let group_by = self.group_by;
+        let default_collapsed_groups = self
+            .default_collapsed_groups
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let expander_column = group_by.is_some() || self.rows.iter().any(TableRow::has_detail);
+        let expansion = ExpansionContext {
+            table_id: table_id.clone(),
+            controlled: self
+                .expanded_keys
+                .as_ref()
+                .map(|keys| keys.iter().map(ToString::to_string).collect()),
+            default: self
+                .default_expanded_keys
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            on_change: self.on_expanded_change.clone(),
+        };
+        let expanded_rows = expansion.current();
+        let rule = |horizontal: bool| {
+            let line = div().bg(resolve_hsla(&self.theme, tokens.row_border));
+            if horizontal {
+                line.w_full().h(line_thickness)
+            } else {
+                line.w(line_thickness).h_full()
+            }
         };
+        let separator = || rule(true);
+        let column_border = || rule(false);
 
         let mut rows_with_meta = self
             .rows
@@ -504,24 +1099,111 @@ impl RenderOnce for Table {
             });
         }
 
-        if let Some(sort) = sort {
-            let column = sort.column;
-            rows_with_meta.sort_by(|(_, left_meta, _), (_, right_meta, _)| {
-                let left = left_meta
-                    .get(column)
-                    .map(String::as_str)
-                    .unwrap_or_default();
-                let right = right_meta
+        if !self.manual_sorting && !sorts.is_empty() {
+            let comparators = &self.sort_comparators;
+            let sort_key = |row: &TableRow, column: usize| {
+                row.cells
                     .get(column)
-                    .map(String::as_str)
-                    .unwrap_or_default();
-                match sort.direction {
-                    TableSortDirection::Asc => left.cmp(right),
-                    TableSortDirection::Desc => right.cmp(left),
-                }
+                    .and_then(|cell| {
+                        cell.sort_value
+                            .clone()
+                            .or_else(|| cell.filter_value.clone())
+                    })
+                    .unwrap_or_default()
+            };
+            rows_with_meta.sort_by(|(_, _, left_row), (_, _, right_row)| {
+                sorts
+                    .iter()
+                    .map(|sort| {
+                        let left = sort_key(left_row, sort.column);
+                        let right = sort_key(right_row, sort.column);
+                        let ordering = match comparators.get(&sort.column) {
+                            Some(comparator) => comparator(&left, &right),
+                            None => table_state::compare_sort_values(&left, &right),
+                        };
+                        match sort.direction {
+                            TableSortDirection::Asc => ordering,
+                            TableSortDirection::Desc => ordering.reverse(),
+                        }
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+
+        let mut group_summaries = BTreeMap::new();
+        let collapsed_groups =
+            table_state::collapsed_groups(&table_id, default_collapsed_groups.clone());
+        if let Some(group_column) = group_by {
+            let group_key =
+                |meta: &Vec<String>| meta.get(group_column).cloned().unwrap_or_default();
+            let keys = rows_with_meta
+                .iter()
+                .map(|(_, meta, _)| group_key(meta))
+                .collect::<Vec<_>>();
+            let mut slots = rows_with_meta.into_iter().map(Some).collect::<Vec<_>>();
+            rows_with_meta = table_state::group_order(&keys)
+                .into_iter()
+                .filter_map(|index| slots[index].take())
+                .collect();
+            let mut members: BTreeMap<String, Vec<&Vec<String>>> = BTreeMap::new();
+            for (_, meta, _) in &rows_with_meta {
+                members.entry(group_key(meta)).or_default().push(meta);
+            }
+            for (group, metas) in members {
+                let aggregates = self
+                    .group_aggregates
+                    .iter()
+                    .map(|(column, aggregate)| {
+                        let values = metas
+                            .iter()
+                            .map(|meta| {
+                                SharedString::from(meta.get(*column).cloned().unwrap_or_default())
+                            })
+                            .collect::<Vec<_>>();
+                        (*column, aggregate(&values))
+                    })
+                    .collect::<BTreeMap<_, _>>();
+                group_summaries.insert(group, (metas.len(), aggregates));
+            }
+            let mut seen = BTreeSet::new();
+            rows_with_meta.retain(|(_, meta, _)| {
+                let group = group_key(meta);
+                seen.insert(group.clone()) || !collapsed_groups.contains(&group)
             });
         }
 
+        let fit_chars = (0..column_count)
+            .map(|column| {
+                let header_chars = headers.get(column).map_or(0, |text| text.chars().count());
+                rows_with_meta
+                    .iter()
+                    .map(|(_, meta, _)| meta[column].chars().count())
+                    .fold(header_chars, usize::max)
+            })
+            .collect::<Vec<_>>();
+
+        let selection = SelectionContext {
+            table_id: table_id.clone(),
+            mode: self.selection_mode,
+            controlled: self
+                .selected_keys
+                .as_ref()
+                .map(|keys| keys.iter().map(ToString::to_string).collect()),
+            default: self
+                .default_selected_keys
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            visible: rows_with_meta
+                .iter()
+                .map(|(source_index, _, row)| Self::row_key(*source_index, row))
+                .collect(),
+            on_change: self.on_selection_change.clone(),
+        };
+        let selected_keys = selection.current();
+        let checkbox_selection = selection.mode == TableSelectionMode::Checkbox;
+
         let total_rows = rows_with_meta.len();
         let state = TableState::resolve(TableStateInput {
             id: &table_id,
@@ -560,7 +1242,10 @@ impl RenderOnce for Table {
             .into_iter()
             .skip(state.window_start.min(total_rows))
             .take(state.window_count.max(1))
-            .map(|(source_index, _, row)| (source_index, row))
+            .map(|(source_index, meta, row)| {
+                let group = group_by.map(|column| meta.get(column).cloned().unwrap_or_default());
+                (source_index, group, row)
+            })
             .collect::<Vec<_>>();
 
         let mut root = Stack::vertical()
@@ -586,13 +1271,12 @@ impl RenderOnce for Table {
                     .font_weight(gpui::FontWeight::MEDIUM)
                     .child(caption),
             );
-            root = root.child(caption);
-            root = root.child(separator());
+            root = root.child(caption).child(separator());
         }
 
         let mut header_row_any = None;
         if !headers.is_empty() {
-            let mut header_row = div()
+            let header_row = div()
                 .id(table_id.slot("header"))
                 .w_full()
                 .flex()
@@ -600,14 +1284,37 @@ impl RenderOnce for Table {
                 .bg(resolve_hsla(&self.theme, tokens.header_bg))
                 .text_color(resolve_hsla(&self.theme, tokens.header_fg));
 
-            for index in 0..column_count {
-                if index > 0 && with_column_borders {
-                    header_row = header_row.child(
-                        div()
-                            .w(line_thickness)
-                            .h_full()
-                            .bg(resolve_hsla(&self.theme, tokens.row_border)),
-                    );
+            let mut header_cells = Vec::with_capacity(column_count * 2 + 1);
+            if checkbox_selection {
+                let selection = selection.clone();
+                let all_selected = table_state::all_selected(&selected_keys, &selection.visible);
+                let toggle_all: ControlCellHandler = Rc::new(
+                    move |_: bool, window: &mut gpui::Window, cx: &mut gpui::App| {
+                        selection.toggle_all(window, cx)
+                    },
+                );
+                header_cells.push(Self::control_cell(
+                    table_size_preset,
+                    &table_id,
+                    "select",
+                    "all",
+                    Some(Self::check_glyph(all_selected)),
+                    Some(toggle_all),
+                ));
+            }
+            if expander_column {
+                header_cells.push(Self::control_cell(
+                    table_size_preset,
+                    &table_id,
+                    "expand",
+                    "header",
+                    None,
+                    None,
+                ));
+            }
+            for (position, index) in column_order.iter().copied().enumerate() {
+                if position > 0 && with_column_borders {
+                    header_cells.push(column_border().into_any_element());
                 }
 
                 let text = headers.get(index).cloned().unwrap_or_else(|| {
@@ -617,20 +1324,208 @@ impl RenderOnce for Table {
                         SharedString::default()
                     }
                 });
-                let cell = Self::apply_cell_size(
-                    table_size_preset,
-                    div()
-                        .id(table_id.slot_index("header-cell", index.to_string()))
-                        .flex_1()
-                        .min_w_0()
-                        .font_weight(gpui::FontWeight::SEMIBOLD)
-                        .truncate()
-                        .child(text),
+                let sortable = sortable_columns.get(index).copied().unwrap_or(false);
+                let sort_position = sortable
+                    .then(|| sorts.iter().position(|sort| sort.column == index))
+                    .flatten();
+                let mut content = vec![text.into_any_element()];
+                if let Some(position) = sort_position {
+                    let ascending = sorts[position].direction == TableSortDirection::Asc;
+                    content.push(
+                        table_id
+                            .ctx()
+                            .child_index(
+                                "sort-indicator",
+                                index.to_string(),
+                                Icon::named(if ascending {
+                                    "chevron-up"
+                                } else {
+                                    "chevron-down"
+                                }),
+                            )
+                            .size(f32::from(table_size_preset.font_size))
+                            .color(resolve_hsla(&self.theme, tokens.header_fg))
+                            .into_any_element(),
+                    );
+                    if sorts.len() > 1 {
+                        content.push(
+                            SharedString::from((position + 1).to_string()).into_any_element(),
+                        );
+                    }
+                }
+                if resizable_columns {
+                    let fit_width = table_state::auto_fit_width(
+                        fit_chars[index],
+                        f32::from(table_size_preset.font_size),
+                        f32::from(table_size_preset.padding_x),
+                        min_column_width,
+                    );
+                    let table_id = table_id.clone();
+                    let controlled_layout = controlled_layout.clone();
+                    let default_layout = default_layout.clone();
+                    let on_layout_change = on_layout_change.clone();
+                    let resize_drag = TableColumnResizeDrag {
+                        table_id: table_id.clone(),
+                        column: index,
+                    };
+                    content.push(
+                        div()
+                            .id(table_id.slot_index("resize-handle", index.to_string()))
+                            .absolute()
+                            .top_0()
+                            .bottom_0()
+                            .right_0()
+                            .w(px(6.0))
+                            .cursor_ew_resize()
+                            .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                                cx.stop_propagation();
+                                table_state::set_header_press(&table_id, None);
+                                if event.click_count < 2 {
+                                    return;
+                                }
+                                let mut next = table_state::resolve_layout(
+                                    &table_id,
+                                    controlled_layout.clone(),
+                                    default_layout.clone(),
+                                );
+                                next.set_column_width(index, fit_width);
+                                Self::commit_layout(
+                                    &table_id,
+                                    controlled_layout.is_some(),
+                                    &next,
+                                    on_layout_change.as_ref(),
+                                    window,
+                                    cx,
+                                );
+                            })
+                            .on_drag(resize_drag, |_, _, _, cx| cx.new(|_| EmptyView))
+                            .into_any_element(),
+                    );
+                }
+                let mut cell = Self::apply_column_width(
+                    Self::apply_cell_size(
+                        table_size_preset,
+                        div()
+                            .id(table_id.slot_index("header-cell", index.to_string()))
+                            .relative()
+                            .min_w_0()
+                            .font_weight(gpui::FontWeight::SEMIBOLD)
+                            .truncate()
+                            .children(content),
+                    ),
+                    layout.column_width(index),
                 );
-                header_row = header_row.child(cell);
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(resolve_hsla(&self.theme, tokens.row_hover_bg));
+                if sortable || reorderable_columns {
+                    let table_id = table_id.clone();
+                    cell = cell.on_mouse_down(MouseButton::Left, move |_, _, _| {
+                        table_state::set_header_press(&table_id, Some(index));
+                    });
+                }
+                if resizable_columns {
+                    let table_id = table_id.clone();
+                    let controlled_layout = controlled_layout.clone();
+                    let default_layout = default_layout.clone();
+                    let on_layout_change = on_layout_change.clone();
+                    cell = cell.on_drag_move::<TableColumnResizeDrag>(move |event, window, cx| {
+                        let drag = event.drag(cx);
+                        if drag.table_id != table_id || drag.column != index {
+                            return;
+                        }
+                        let width = table_state::resize_width(
+                            f32::from(event.event.position.x),
+                            f32::from(event.bounds.left()),
+                            min_column_width,
+                        );
+                        let mut next = table_state::resolve_layout(
+                            &table_id,
+                            controlled_layout.clone(),
+                            default_layout.clone(),
+                        );
+                        if next.column_width(index) == Some(width) {
+                            return;
+                        }
+                        next.set_column_width(index, width);
+                        Self::commit_layout(
+                            &table_id,
+                            controlled_layout.is_some(),
+                            &next,
+                            on_layout_change.as_ref(),
+                            window,
+                            cx,
+                        );
+                    });
+                }
+                if reorderable_columns {
+                    let column_drag = TableColumnDrag {
+                        table_id: table_id.clone(),
+                        column: index,
+                    };
+                    let table_id = table_id.clone();
+                    let controlled_layout = controlled_layout.clone();
+                    let default_layout = default_layout.clone();
+                    let on_layout_change = on_layout_change.clone();
+                    cell = cell
+                        .on_drag(column_drag, |drag, _, _, cx| {
+                            table_state::set_header_press(&drag.table_id, None);
+                            cx.new(|_| EmptyView)
+                        })
+                        .drag_over::<TableColumnDrag>(move |style, _, _, _| style.bg(hover_bg))
+                        .on_drop::<TableColumnDrag>(move |drag, window, cx| {
+                            if drag.table_id != table_id || drag.column == index {
+                                return;
+                            }
+                            let mut next = table_state::resolve_layout(
+                                &table_id,
+                                controlled_layout.clone(),
+                                default_layout.clone(),
+                            );
+                            next.move_column(drag.column, index, column_count);
+                            Self::commit_layout(
+                                &table_id,
+                                controlled_layout.is_some(),
+                                &next,
+                                on_layout_change.as_ref(),
+                                window,
+                                cx,
+                            );
+                        });
+                }
+                if sortable {
+                    cell = cell.flex().items_center().gap_1().cursor_pointer();
+                    let table_id = table_id.clone();
+                    let controlled_sorts = controlled_sorts.clone();
+                    let default_sorts = default_sorts.clone();
+                    let on_sort_change = on_sort_change.clone();
+                    cell = cell.hover(move |style| style.bg(hover_bg)).on_mouse_up(
+                        MouseButton::Left,
+                        move |event, window, cx| {
+                            if table_state::take_header_press(&table_id) != Some(index) {
+                                return;
+                            }
+                            let multi = event.modifiers.shift || event.modifiers.platform;
+                            let current = table_state::resolve_sorts(
+                                &table_id,
+                                controlled_sorts.clone(),
+                                default_sorts.clone(),
+                            );
+                            let next = table_state::toggle_sort(&current, index, multi);
+                            if controlled_sorts.is_none() {
+                                table_state::set_sorts(&table_id, &next);
+                            }
+                            if let Some(handler) = on_sort_change.as_ref() {
+                                (handler)(&next, window, cx);
+                            }
+                            window.refresh();
+                        },
+                    );
+                }
+                header_cells.push(cell.into_any_element());
             }
 
-            header_row_any = Some(header_row);
+            header_row_any = Some(header_row.children(header_cells));
         }
 
         let visible_row_count = rows.len();
@@ -641,15 +1536,89 @@ impl RenderOnce for Table {
             .id(table_id.slot("rows"))
             .w_full()
             .gap(tokens.row_gap);
+        let spacer =
+            |slot: &'static str, height: f32| div().id(table_id.slot(slot)).w_full().h(px(height));
+        let mut body = Vec::with_capacity(visible_row_count + 2);
         if top_spacer_height > 0.0 {
-            rows_root = rows_root.child(
-                div()
-                    .id(table_id.slot("virtual-top-spacer"))
-                    .w_full()
-                    .h(px(top_spacer_height)),
-            );
+            body.push(spacer("virtual-top-spacer", top_spacer_height).into_any_element());
         }
-        for (row_index, (source_index, row)) in rows.into_iter().enumerate() {
+        let mut previous_group = None;
+        for (row_index, (source_index, group, row)) in rows.into_iter().enumerate() {
+            if let Some(group) = group.filter(|group| previous_group.as_ref() != Some(group)) {
+                let collapsed = collapsed_groups.contains(&group);
+                let (count, aggregates) = group_summaries.get(&group).cloned().unwrap_or_default();
+                let mut group_cells = Vec::with_capacity(column_count * 2 + 2);
+                if checkbox_selection {
+                    group_cells.push(Self::control_cell(
+                        table_size_preset,
+                        &table_id,
+                        "group-select",
+                        &group,
+                        None,
+                        None,
+                    ));
+                }
+                let toggle_id = table_id.clone();
+                let toggle_default = default_collapsed_groups.clone();
+                let toggle_key = group.clone();
+                let toggle_group: ControlCellHandler = Rc::new(
+                    move |_: bool, window: &mut gpui::Window, _cx: &mut gpui::App| {
+                        table_state::toggle_group(&toggle_id, toggle_default.clone(), &toggle_key);
+                        window.refresh();
+                    },
+                );
+                let group_icon = table_id
+                    .ctx()
+                    .child_index(
+                        "group-icon",
+                        group.clone(),
+                        Icon::named(if collapsed {
+                            "chevron-right"
+                        } else {
+                            "chevron-down"
+                        }),
+                    )
+                    .size(f32::from(table_size_preset.font_size));
+                group_cells.push(Self::control_cell(
+                    table_size_preset,
+                    &table_id,
+                    "group-toggle",
+                    &group,
+                    Some(group_icon.into_any_element()),
+                    Some(toggle_group),
+                ));
+                for (position, column) in column_order.iter().copied().enumerate() {
+                    if position > 0 && with_column_borders {
+                        group_cells.push(column_border().into_any_element());
+                    }
+                    let text = match (position, aggregates.get(&column)) {
+                        (0, Some(aggregate)) => format!("{group} ({count}) · {aggregate}"),
+                        (0, None) => format!("{group} ({count})"),
+                        (_, aggregate) => aggregate.map(ToString::to_string).unwrap_or_default(),
+                    };
+                    let cell = Self::data_cell(
+                        table_size_preset,
+                        table_id.slot_index("group-cell", format!("{group}-{column}")),
+                        layout.column_width(column),
+                    );
+                    group_cells.push(cell.child(text).into_any_element());
+                }
+                let group_row = div()
+                    .id(table_id.slot_index("group", group.clone()))
+                    .w_full()
+                    .flex()
+                    .items_center()
+                    .bg(resolve_hsla(&self.theme, tokens.header_bg))
+                    .text_color(resolve_hsla(&self.theme, tokens.header_fg))
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .children(group_cells);
+                body.push(group_row.into_any_element());
+                previous_group = Some(group);
+                if collapsed {
+                    continue;
+                }
+            }
+
             let striped_index = window_start + row_index;
             let row_bg = if striped && striped_index % 2 == 1 {
                 resolve_hsla(&self.theme, tokens.row_alt_bg)
@@ -657,29 +1626,32 @@ impl RenderOnce for Table {
                 resolve_hsla(&self.theme, tokens.row_bg)
             };
 
+            let row_key = Self::row_key(source_index, &row);
+            let is_selected = selected_keys.contains(&row_key);
             let mut row_node = div()
                 .id(table_id.slot_index("row", row_index.to_string()))
                 .w_full()
                 .flex()
                 .items_center()
-                .bg(row_bg)
+                .bg(if is_selected {
+                    resolve_hsla(&self.theme, tokens.row_selected_bg)
+                } else {
+                    row_bg
+                })
                 .text_color(resolve_hsla(&self.theme, tokens.cell_fg));
             if row_index > 0 {
-                row_node = row_node.relative().child(
-                    div()
-                        .absolute()
-                        .top_0()
-                        .left_0()
-                        .right_0()
-                        .h(line_thickness)
-                        .bg(resolve_hsla(&self.theme, tokens.row_border)),
-                );
+                let divider = rule(true).absolute().top_0().left_0();
+                row_node = row_node.relative().child(divider);
             }
 
             if let Some(handler) = on_row_click.as_ref() {
                 let on_row_click = handler.clone();
-                let hover_bg = resolve_hsla(&self.theme, tokens.row_hover_bg);
-                let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(resolve_hsla(&self.theme, tokens.row_hover_bg));
+                let press_bg = tokens
+                    .states
+                    .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
                 let mut interaction_styles = InteractionStyles::new()
                     .active(interaction_style(move |style| style.bg(press_bg)))
                     .focus(interaction_style(move |style| style.bg(hover_bg)));
@@ -698,7 +1670,9 @@ impl RenderOnce for Table {
                         .on_activate(Some(activate_handler)),
                 );
             } else if highlight_on_hover {
-                let hover_bg = resolve_hsla(&self.theme, tokens.row_hover_bg);
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(resolve_hsla(&self.theme, tokens.row_hover_bg));
                 row_node = row_node.hover(move |style| style.bg(hover_bg));
             }
             if auto_virtualization_enabled && row_index == 0 {
@@ -718,48 +1692,135 @@ impl RenderOnce for Table {
                 );
             }
 
-            let mut cells = row.cells.into_iter();
-            for column in 0..column_count {
-                if column > 0 && with_column_borders {
-                    row_node = row_node.child(
-                        div()
-                            .w(line_thickness)
-                            .h_full()
-                            .bg(resolve_hsla(&self.theme, tokens.row_border)),
-                    );
+            if matches!(
+                selection.mode,
+                TableSelectionMode::Single | TableSelectionMode::Multi
+            ) {
+                let selection = selection.clone();
+                let row_key = row_key.clone();
+                row_node = row_node.cursor_pointer().on_mouse_down(
+                    MouseButton::Left,
+                    move |event, window, cx| {
+                        let modifiers = SelectionModifiers {
+                            range: event.modifiers.shift,
+                            toggle: event.modifiers.control || event.modifiers.platform,
+                        };
+                        selection.select(&row_key, modifiers, window, cx);
+                    },
+                );
+            }
+
+            let mut row_cells = Vec::with_capacity(column_count * 2 + 1);
+            if checkbox_selection {
+                let selection = selection.clone();
+                let key = row_key.clone();
+                let toggle_row: ControlCellHandler = Rc::new(
+                    move |range: bool, window: &mut gpui::Window, cx: &mut gpui::App| {
+                        let modifiers = SelectionModifiers {
+                            range,
+                            toggle: true,
+                        };
+                        selection.select(&key, modifiers, window, cx);
+                    },
+                );
+                row_cells.push(Self::control_cell(
+                    table_size_preset,
+                    &table_id,
+                    "select",
+                    &row_key,
+                    Some(Self::check_glyph(is_selected)),
+                    Some(toggle_row),
+                ));
+            }
+            let is_expanded = expanded_rows.contains(&row_key);
+            if expander_column {
+                let toggle = row.has_detail().then(|| {
+                    let expansion = expansion.clone();
+                    let key = row_key.clone();
+                    Rc::new(
+                        move |_: bool, window: &mut gpui::Window, cx: &mut gpui::App| {
+                            expansion.toggle(&key, window, cx)
+                        },
+                    ) as ControlCellHandler
+                });
+                let expand_icon = toggle.is_some().then(|| {
+                    table_id
+                        .ctx()
+                        .child_index(
+                            "expand-icon",
+                            row_key.clone(),
+                            Icon::named(if is_expanded {
+                                "chevron-down"
+                            } else {
+                                "chevron-right"
+                            }),
+                        )
+                        .size(f32::from(table_size_preset.font_size))
+                        .into_any_element()
+                });
+                row_cells.push(Self::control_cell(
+                    table_size_preset,
+                    &table_id,
+                    "expand",
+                    &row_key,
+                    expand_icon,
+                    toggle,
+                ));
+            }
+            let detail = row.detail.filter(|_| is_expanded);
+            let mut cells = row.cells.into_iter().map(Some).collect::<Vec<_>>();
+            for (position, column) in column_order.iter().copied().enumerate() {
+                if position > 0 && with_column_borders {
+                    row_cells.push(column_border().into_any_element());
                 }
 
-                let next_cell = cells.next();
-                let mut cell = Self::apply_cell_size(
+                let next_cell = cells.get_mut(column).and_then(Option::take);
+                let cell_key = format!("{row_index}-{column}");
+                let mut cell = Self::data_cell(
                     table_size_preset,
-                    div()
-                        .id(table_id.slot_index("row-cell", format!("{row_index}-{column}")))
-                        .flex_1()
-                        .min_w_0(),
+                    table_id.slot_index("row-cell", cell_key.clone()),
+                    layout.column_width(column),
                 );
 
                 if let Some(cell_data) = next_cell {
+                    let content = match (selection_bg, cell_data.text) {
+                        (Some(selection_bg), Some(text)) => SelectableText::new(
+                            table_id.scoped_index("cell-text", cell_key),
+                            text,
+                            selection_bg,
+                        )
+                        .find(find.clone())
+                        .into_any_element(),
+                        _ => (cell_data.content)(),
+                    };
                     cell = match cell_data.align {
                         TableAlign::Left => cell.items_start().justify_start(),
                         TableAlign::Center => cell.items_center().justify_center(),
                         TableAlign::Right => cell.items_end().justify_end(),
                     }
-                    .child((cell_data.content)());
+                    .child(content);
                 }
 
-                row_node = row_node.child(cell);
+                row_cells.push(cell.into_any_element());
             }
 
-            rows_root = rows_root.child(row_node);
+            let detail_panel = detail.map(|detail| {
+                Self::apply_cell_size(
+                    table_size_preset,
+                    div().id(table_id.slot_index("row-detail", row_key.clone())),
+                )
+                .w_full()
+                .bg(resolve_hsla(&self.theme, tokens.row_alt_bg))
+                .child(detail())
+                .into_any_element()
+            });
+            body.push(row_node.children(row_cells).into_any_element());
+            body.extend(detail_panel);
         }
         if bottom_spacer_height > 0.0 {
-            rows_root = rows_root.child(
-                div()
-                    .id(table_id.slot("virtual-bottom-spacer"))
-                    .w_full()
-                    .h(px(bottom_spacer_height)),
-            );
+            body.push(spacer("virtual-bottom-spacer", bottom_spacer_height).into_any_element());
         }
+        rows_root = rows_root.children(body);
 
         if !has_rows {
             let mut empty_cell = div()
@@ -817,9 +1878,16 @@ impl RenderOnce for Table {
                     if !is_active {
                         let on_page_size_change = on_page_size_change.clone();
                         let table_id_for_page_size = table_id_for_page_size.clone();
-                        let hover_bg = resolve_hsla(&self.theme, tokens.row_hover_bg);
-                        let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
-                        let focus_ring = resolve_hsla(&self.theme, self.theme.semantic.focus_ring);
+                        let hover_bg = tokens
+                            .states
+                            .hover_bg_or(resolve_hsla(&self.theme, tokens.row_hover_bg));
+                        let press_bg = tokens
+                            .states
+                            .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
+                        let focus_ring = tokens.states.focus_border_or(resolve_hsla(
+                            &self.theme,
+                            self.theme.semantic.focus_ring,
+                        ));
                         let activate_handler: ActivateHandler =
                             Rc::new(move |window: &mut gpui::Window, cx| {
                                 table_state::on_page_size_change(&table_id_for_page_size, option);
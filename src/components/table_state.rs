@@ -1,4 +1,13 @@
+use std::cmp::Ordering;
+
 use super::control;
+use super::table::{TableLayoutState, TableSelectionMode, TableSort, TableSortDirection};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SelectionModifiers {
+    pub range: bool,
+    pub toggle: bool,
+}
 
 pub struct TableStateInput<'a> {
     pub id: &'a str,
@@ -231,3 +240,303 @@ pub fn on_virtual_scroll(id: &str, next_y: f32, row_extent: f32, overscan_rows:
 
     should_refresh
 }
+
+pub fn resolve_sorts(
+    id: &str,
+    controlled: Option<Vec<TableSort>>,
+    default: Vec<TableSort>,
+) -> Vec<TableSort> {
+    control::list_state(
+        id,
+        "sort",
+        controlled.map(|sorts| encode_sorts(&sorts)),
+        encode_sorts(&default),
+    )
+    .iter()
+    .filter_map(|entry| decode_sort(entry))
+    .collect()
+}
+
+pub fn set_sorts(id: &str, sorts: &[TableSort]) {
+    control::set_list_state(id, "sort", encode_sorts(sorts));
+}
+
+pub fn toggle_sort(current: &[TableSort], column: usize, multi: bool) -> Vec<TableSort> {
+    let existing = current.iter().find(|sort| sort.column == column).copied();
+    let next = match existing.map(|sort| sort.direction) {
+        None => Some(TableSortDirection::Asc),
+        Some(TableSortDirection::Asc) => Some(TableSortDirection::Desc),
+        Some(TableSortDirection::Desc) => None,
+    };
+    let mut sorts = if multi {
+        current.to_vec()
+    } else {
+        current
+            .iter()
+            .filter(|sort| sort.column == column)
+            .copied()
+            .collect()
+    };
+    match next {
+        Some(direction) => match sorts.iter_mut().find(|sort| sort.column == column) {
+            Some(sort) => sort.direction = direction,
+            None => sorts.push(TableSort { column, direction }),
+        },
+        None => sorts.retain(|sort| sort.column != column),
+    }
+    sorts
+}
+
+pub fn compare_sort_values(left: &str, right: &str) -> Ordering {
+    match (left.trim().parse::<f64>(), right.trim().parse::<f64>()) {
+        (Ok(left), Ok(right)) => left.total_cmp(&right),
+        _ => left.cmp(right),
+    }
+}
+
+pub fn resolve_layout(
+    id: &str,
+    controlled: Option<TableLayoutState>,
+    default: TableLayoutState,
+) -> TableLayoutState {
+    let order = control::list_state(
+        id,
+        "layout-order",
+        controlled
+            .as_ref()
+            .map(|layout| encode_order(&layout.order)),
+        encode_order(&default.order),
+    );
+    let widths = control::list_state(
+        id,
+        "layout-widths",
+        controlled.as_ref().map(encode_widths),
+        encode_widths(&default),
+    );
+    TableLayoutState {
+        order: order
+            .iter()
+            .filter_map(|entry| entry.parse().ok())
+            .collect(),
+        widths: widths
+            .iter()
+            .filter_map(|entry| {
+                let (column, width) = entry.split_once(':')?;
+                Some((column.parse().ok()?, width.parse().ok()?))
+            })
+            .collect(),
+    }
+}
+
+pub fn set_layout(id: &str, layout: &TableLayoutState) {
+    control::set_list_state(id, "layout-order", encode_order(&layout.order));
+    control::set_list_state(id, "layout-widths", encode_widths(layout));
+}
+
+pub fn resize_width(cursor_x: f32, cell_left: f32, min_width: f32) -> f32 {
+    (cursor_x - cell_left).max(min_width).round()
+}
+
+pub fn auto_fit_width(max_chars: usize, font_size: f32, padding_x: f32, min_width: f32) -> f32 {
+    (max_chars as f32 * font_size * 0.6 + padding_x * 2.0)
+        .max(min_width)
+        .round()
+}
+
+pub fn set_header_press(id: &str, column: Option<usize>) {
+    control::set_optional_usize_state(id, "header-press", column);
+}
+
+pub fn take_header_press(id: &str) -> Option<usize> {
+    let pressed = control::optional_usize_state(id, "header-press", None, None);
+    control::set_optional_usize_state(id, "header-press", None);
+    pressed
+}
+
+pub fn resolve_selection(
+    id: &str,
+    controlled: Option<Vec<String>>,
+    default: Vec<String>,
+) -> Vec<String> {
+    control::list_state(id, "selected", controlled, default)
+}
+
+pub fn set_selection(id: &str, keys: Vec<String>) {
+    control::set_list_state(id, "selected", keys);
+}
+
+pub fn selection_anchor(id: &str) -> Option<String> {
+    control::optional_text_state(id, "selection-anchor", None, None)
+}
+
+pub fn set_selection_anchor(id: &str, key: Option<String>) {
+    control::set_optional_text_state(id, "selection-anchor", key);
+}
+
+pub fn set_pending_range(id: &str, range: bool) {
+    control::set_bool_state(id, "selection-range", range);
+}
+
+pub fn take_pending_range(id: &str) -> bool {
+    let range = control::bool_state(id, "selection-range", None, false);
+    control::set_bool_state(id, "selection-range", false);
+    range
+}
+
+pub fn select_row(
+    current: &[String],
+    visible: &[String],
+    key: &str,
+    anchor: Option<&str>,
+    mode: TableSelectionMode,
+    modifiers: SelectionModifiers,
+) -> Vec<String> {
+    let additive = modifiers.toggle || mode == TableSelectionMode::Checkbox;
+    match mode {
+        TableSelectionMode::None => current.to_vec(),
+        TableSelectionMode::Single => {
+            if modifiers.toggle && current.iter().any(|selected| selected == key) {
+                Vec::new()
+            } else {
+                vec![key.to_string()]
+            }
+        }
+        TableSelectionMode::Multi | TableSelectionMode::Checkbox => {
+            let range = anchor.filter(|_| modifiers.range).and_then(|anchor| {
+                let start = visible.iter().position(|visible| visible == anchor)?;
+                let end = visible.iter().position(|visible| visible == key)?;
+                Some(&visible[start.min(end)..=start.max(end)])
+            });
+            if let Some(range) = range {
+                let mut next = if additive {
+                    current.to_vec()
+                } else {
+                    Vec::new()
+                };
+                for key in range {
+                    if !next.contains(key) {
+                        next.push(key.clone());
+                    }
+                }
+                next
+            } else if additive {
+                toggle_key(current, key)
+            } else {
+                vec![key.to_string()]
+            }
+        }
+    }
+}
+
+pub fn toggle_all(current: &[String], visible: &[String]) -> Vec<String> {
+    if all_selected(current, visible) {
+        current
+            .iter()
+            .filter(|key| !visible.contains(key))
+            .cloned()
+            .collect()
+    } else {
+        let mut next = current.to_vec();
+        for key in visible {
+            if !next.contains(key) {
+                next.push(key.clone());
+            }
+        }
+        next
+    }
+}
+
+pub fn all_selected(current: &[String], visible: &[String]) -> bool {
+    !visible.is_empty() && visible.iter().all(|key| current.contains(key))
+}
+
+pub fn resolve_expanded_rows(
+    id: &str,
+    controlled: Option<Vec<String>>,
+    default: Vec<String>,
+) -> Vec<String> {
+    control::list_state(id, "expanded-rows", controlled, default)
+}
+
+pub fn set_expanded_rows(id: &str, keys: Vec<String>) {
+    control::set_list_state(id, "expanded-rows", keys);
+}
+
+pub fn toggle_expanded_row(current: &[String], key: &str) -> Vec<String> {
+    toggle_key(current, key)
+}
+
+pub fn collapsed_groups(id: &str, default: Vec<String>) -> Vec<String> {
+    control::list_state(id, "collapsed-groups", None, default)
+}
+
+pub fn toggle_group(id: &str, default: Vec<String>, group: &str) {
+    let next = toggle_key(&collapsed_groups(id, default), group);
+    control::set_list_state(id, "collapsed-groups", next);
+}
+
+pub fn group_order(keys: &[String]) -> Vec<usize> {
+    let mut groups: Vec<(&str, Vec<usize>)> = Vec::new();
+    for (index, key) in keys.iter().enumerate() {
+        match groups.iter_mut().find(|(group, _)| *group == key.as_str()) {
+            Some((_, members)) => members.push(index),
+            None => groups.push((key.as_str(), vec![index])),
+        }
+    }
+    groups
+        .into_iter()
+        .flat_map(|(_, members)| members)
+        .collect()
+}
+
+fn toggle_key(current: &[String], key: &str) -> Vec<String> {
+    if current.iter().any(|selected| selected == key) {
+        current
+            .iter()
+            .filter(|selected| selected.as_str() != key)
+            .cloned()
+            .collect()
+    } else {
+        let mut next = current.to_vec();
+        next.push(key.to_string());
+        next
+    }
+}
+
+fn encode_order(order: &[usize]) -> Vec<String> {
+    order.iter().map(usize::to_string).collect()
+}
+
+fn encode_widths(layout: &TableLayoutState) -> Vec<String> {
+    layout
+        .widths
+        .iter()
+        .map(|(column, width)| format!("{column}:{width}"))
+        .collect()
+}
+
+fn encode_sorts(sorts: &[TableSort]) -> Vec<String> {
+    sorts
+        .iter()
+        .map(|sort| {
+            let direction = match sort.direction {
+                TableSortDirection::Asc => "asc",
+                TableSortDirection::Desc => "desc",
+            };
+            format!("{}:{direction}", sort.column)
+        })
+        .collect()
+}
+
+fn decode_sort(entry: &str) -> Option<TableSort> {
+    let (column, direction) = entry.split_once(':')?;
+    let direction = match direction {
+        "asc" => TableSortDirection::Asc,
+        "desc" => TableSortDirection::Desc,
+        _ => return None,
+    };
+    Some(TableSort {
+        column: column.parse().ok()?,
+        direction,
+    })
+}
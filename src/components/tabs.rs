@@ -4,15 +4,22 @@ use gpui::InteractiveElement;
 use gpui::{AnyElement, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window, div};
 
 use crate::contracts::MotionAware;
+use crate::focus::FocusOrientation;
 use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
 use crate::motion::MotionConfig;
 use crate::style::{Radius, Size, Variant};
+use crate::theme::SemanticRadiusToken;
 
 use super::Stack;
-use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
+use super::control;
+use super::interaction_adapter::{
+    ActivateHandler, PressAdapter, RovingItem, bind_press_adapter, bind_roving_focus,
+};
 use super::selection_state;
 use super::utils::{
-    InteractionStyles, apply_interaction_styles, apply_radius, interaction_style, resolve_hsla,
+    InteractionStyles, apply_disabled_state, apply_focus_ring, apply_interaction_styles,
+    apply_radius, interaction_style, resolve_hsla, resolve_radius,
 };
 
 type ChangeHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
@@ -178,13 +185,18 @@ impl RenderOnce for Tabs {
         let motion = self.motion;
         let panel_fallback_fg = resolve_hsla(&self.theme, self.theme.semantic.text_muted);
         let transparent = resolve_hsla(&theme, gpui::transparent_black());
+        let tab_radius = resolve_radius(&theme, SemanticRadiusToken::from(self.radius));
 
         let mut selected_panel: Option<AnyElement> = None;
         let mut first_panel: Option<AnyElement> = None;
         let mut triggers: Vec<AnyElement> = Vec::new();
+        let mut roving: Vec<RovingItem> = Vec::new();
+        let focus = crate::provider::CalmProvider::focus(_cx);
 
         for (index, mut item) in self.items.into_iter().enumerate() {
             let tab_id = self.id.slot_index("tab", index.to_string());
+            let focus_handle = focus.handle(&self.id, &format!("tab-{index}"), _cx);
+            let mut roving_activate = None;
             let is_active = selected
                 .as_ref()
                 .is_some_and(|value| value.as_ref() == item.value.as_ref());
@@ -233,14 +245,20 @@ impl RenderOnce for Tabs {
                 let on_change = on_change.clone();
                 let value = item.value.clone();
                 let id = control_id.clone();
-                let hover_bg = resolve_hsla(&theme, tokens.tab_hover_bg);
-                let press_bg = hover_bg.blend(gpui::black().opacity(0.08));
+                let hover_bg = tokens
+                    .states
+                    .hover_bg_or(resolve_hsla(&theme, tokens.tab_hover_bg));
+                let press_bg = tokens
+                    .states
+                    .active_bg_or(crate::theme::color_utils::darken(hover_bg, 0.08));
                 let focus_bg = if is_active {
-                    active_bg.blend(gpui::white().opacity(0.04))
+                    crate::theme::color_utils::lighten(active_bg, 0.04)
                 } else {
                     hover_bg
                 };
-                let focus_ring = resolve_hsla(&theme, theme.semantic.focus_ring);
+                let focus_ring = tokens
+                    .states
+                    .focus_border_or(resolve_hsla(&theme, theme.semantic.focus_ring));
                 let activate_handler: ActivateHandler = Rc::new(move |window, cx| {
                     if selection_state::apply_optional_text(
                         &id,
@@ -250,6 +268,8 @@ impl RenderOnce for Tabs {
                     ) {
                         window.refresh();
                     }
+                    let event = InteractionEvent::new("tabs", &id, InteractionAction::Select);
+                    interaction::emit(event.value(value.clone()), cx);
                     if let Some(handler) = on_change.as_ref() {
                         (handler)(value.clone(), window, cx);
                     }
@@ -266,15 +286,26 @@ impl RenderOnce for Tabs {
                 }
 
                 trigger = apply_interaction_styles(trigger.cursor_pointer(), interaction_styles);
+                roving_activate = Some(activate_handler.clone());
                 trigger = bind_press_adapter(
                     trigger,
-                    PressAdapter::new(tab_id.clone()).on_activate(Some(activate_handler)),
+                    PressAdapter::new(tab_id.clone())
+                        .on_activate(Some(activate_handler))
+                        .focus_handle(Some(focus_handle.clone())),
                 );
+                let focused = control::focused_state(&tab_id.to_string(), None, false);
+                trigger = apply_focus_ring(&theme, trigger, focused, tab_radius);
             } else {
-                trigger = trigger.opacity(0.55).cursor_default();
+                trigger = apply_disabled_state(trigger.cursor_default(), tokens.states, 0.55);
             }
 
             triggers.push(trigger.into_any_element());
+            roving.push(RovingItem {
+                id: tab_id.into(),
+                focus_handle,
+                disabled: item.disabled,
+                on_activate: roving_activate,
+            });
         }
 
         let panel_content = selected_panel.or(first_panel);
@@ -289,6 +320,7 @@ impl RenderOnce for Tabs {
             .border_color(resolve_hsla(&theme, tokens.list_border))
             .children(triggers);
         list = apply_radius(&self.theme, list, self.radius);
+        list = bind_roving_focus(list, roving, FocusOrientation::Horizontal, true);
 
         let mut panel = div()
             .id(self.id.slot("panel"))
@@ -0,0 +1,749 @@
+use super::transition::TransitionExt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::prelude::FluentBuilder;
+use gpui::{
+    AnyElement, FocusHandle, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window,
+    canvas, div, px,
+};
+
+use crate::contracts::{
+    Disableable as _, FieldLike, MotionAware, Radiused as _, Sized as _, Themable, Varianted as _,
+};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::{FieldLayout, Radius, Size, Variant};
+
+use super::Stack;
+use super::TextInput;
+use super::combobox_state;
+use super::control;
+use super::icon::Icon;
+use super::interaction_adapter::{PressAdapter, bind_press_adapter};
+use super::popup::{PopupPlacement, anchored_host};
+use super::popup_state::{self, PopupStateInput, PopupStateValue};
+use super::select::SelectOption;
+use super::select_state;
+use super::tags_input_state;
+use super::utils::{InteractionStyles, apply_interaction_styles, interaction_style, resolve_hsla};
+
+type TagsLoaderFuture = Pin<Box<dyn Future<Output = Vec<SelectOption>>>>;
+
+type TagsLoader = Rc<dyn Fn(SharedString) -> TagsLoaderFuture>;
+type TagsChangeHandler = Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut gpui::App)>;
+type SearchHandler = Rc<dyn Fn(SharedString, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone)]
+struct TagsCommit {
+    id: ComponentId,
+    values_controlled: bool,
+    current: Vec<String>,
+    max_tags: Option<usize>,
+    allow_duplicates: bool,
+    separators: Vec<char>,
+    debounce_ms: u64,
+    loader: Option<TagsLoader>,
+    on_change: Option<TagsChangeHandler>,
+    on_search: Option<SearchHandler>,
+}
+
+impl TagsCommit {
+    fn apply(&self, next: Vec<String>, window: &mut Window, cx: &mut gpui::App) {
+        if next == self.current {
+            return;
+        }
+        if !self.values_controlled {
+            tags_input_state::set_values(&self.id, next.clone());
+        }
+        if let Some(handler) = self.on_change.as_ref() {
+            (handler)(
+                next.into_iter().map(SharedString::from).collect(),
+                window,
+                cx,
+            );
+        }
+        window.refresh();
+    }
+
+    fn add(&self, incoming: Vec<String>, window: &mut Window, cx: &mut gpui::App) {
+        let next = tags_input_state::add_tags(
+            &self.current,
+            incoming,
+            self.max_tags,
+            self.allow_duplicates,
+        );
+        combobox_state::set_highlighted(&self.id, None);
+        self.apply(next, window, cx);
+    }
+
+    fn remove(&self, index: usize, window: &mut Window, cx: &mut gpui::App) {
+        self.apply(
+            tags_input_state::remove_tag(&self.current, index),
+            window,
+            cx,
+        );
+    }
+
+    fn set_opened(&self, opened: bool, window: &mut Window) {
+        if !opened {
+            combobox_state::set_highlighted(&self.id, None);
+        }
+        if popup_state::apply_opened(&self.id, false, opened) {
+            window.refresh();
+        }
+    }
+
+    fn edit(&self, text: SharedString, window: &mut Window, cx: &mut gpui::App) {
+        let (complete, remainder) = tags_input_state::split_input(&text, &self.separators);
+        tags_input_state::set_query(&self.id, remainder.clone());
+        combobox_state::set_highlighted(&self.id, None);
+        popup_state::apply_opened(&self.id, false, true);
+        if !complete.is_empty() {
+            self.add(complete, window, cx);
+        }
+        self.search(remainder, window, cx);
+        window.refresh();
+    }
+
+    fn submit(&self, tag: String, window: &mut Window, cx: &mut gpui::App) {
+        self.add(vec![tag], window, cx);
+        tags_input_state::set_query(&self.id, String::new());
+        self.search(String::new(), window, cx);
+        window.refresh();
+    }
+
+    fn search(&self, query: String, window: &mut Window, cx: &mut gpui::App) {
+        if self.on_search.is_none() && self.loader.is_none() {
+            return;
+        }
+        let revision = tags_input_state::bump_revision(&self.id);
+        let remote = tags_input_state::remote(&self.id);
+        let ticket = self.loader.is_some().then(|| remote.begin());
+        let window_handle = window.window_handle();
+        let commit = self.clone();
+        let delay = Duration::from_millis(self.debounce_ms);
+        cx.spawn(async move |cx| {
+            cx.background_executor().timer(delay).await;
+            if tags_input_state::revision(&commit.id) != revision {
+                return;
+            }
+            let query = SharedString::from(query);
+            let _ = window_handle.update(cx, |_, window, cx| {
+                if let Some(handler) = commit.on_search.as_ref() {
+                    (handler)(query.clone(), window, cx);
+                }
+            });
+            let (Some(loader), Some(ticket)) = (commit.loader.clone(), ticket) else {
+                return;
+            };
+            let options = loader(query).await;
+            let options = options
+                .into_iter()
+                .map(|option| {
+                    let label = option.label.unwrap_or_else(|| option.value.clone());
+                    (option.value.to_string(), label.to_string())
+                })
+                .collect();
+            if remote.finish(ticket, Ok(options)) {
+                let _ = window_handle.update(cx, |_, window, _| window.refresh());
+            }
+        })
+        .detach();
+    }
+}
+
+#[derive(IntoElement)]
+pub struct TagsInput {
+    pub(crate) id: ComponentId,
+    values: Option<Vec<SharedString>>,
+    default_values: Vec<SharedString>,
+    options: Vec<SelectOption>,
+    placeholder: Option<SharedString>,
+    label: Option<SharedString>,
+    description: Option<SharedString>,
+    error: Option<SharedString>,
+    required: bool,
+    layout: FieldLayout,
+    separators: Vec<char>,
+    max_tags: Option<usize>,
+    max_visible_tags: Option<usize>,
+    allow_duplicates: bool,
+    creatable: bool,
+    limit: Option<usize>,
+    debounce_ms: u64,
+    disabled: bool,
+    size: Size,
+    radius: Radius,
+    variant: Variant,
+    focus_handle: Option<FocusHandle>,
+    loader: Option<TagsLoader>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<TagsChangeHandler>,
+    on_search: Option<SearchHandler>,
+}
+
+impl TagsInput {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            values: None,
+            default_values: Vec::new(),
+            options: Vec::new(),
+            placeholder: None,
+            label: None,
+            description: None,
+            error: None,
+            required: false,
+            layout: FieldLayout::Vertical,
+            separators: vec![',', '\n'],
+            max_tags: None,
+            max_visible_tags: None,
+            allow_duplicates: false,
+            creatable: true,
+            limit: None,
+            debounce_ms: 200,
+            disabled: false,
+            size: Size::Md,
+            radius: Radius::Sm,
+            variant: Variant::Default,
+            focus_handle: None,
+            loader: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+            on_search: None,
+        }
+    }
+
+    pub fn values(mut self, values: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.values = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn default_values(
+        mut self,
+        values: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.default_values = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn options(mut self, options: impl IntoIterator<Item = SelectOption>) -> Self {
+        self.options.extend(options);
+        self
+    }
+
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(value.into());
+        self
+    }
+
+    pub fn separators(mut self, value: impl IntoIterator<Item = char>) -> Self {
+        self.separators = value.into_iter().collect();
+        self
+    }
+
+    pub fn max_tags(mut self, value: usize) -> Self {
+        self.max_tags = Some(value.max(1));
+        self
+    }
+
+    pub fn max_visible_tags(mut self, value: usize) -> Self {
+        self.max_visible_tags = Some(value);
+        self
+    }
+
+    pub fn allow_duplicates(mut self, value: bool) -> Self {
+        self.allow_duplicates = value;
+        self
+    }
+
+    pub fn creatable(mut self, value: bool) -> Self {
+        self.creatable = value;
+        self
+    }
+
+    pub fn limit(mut self, value: usize) -> Self {
+        self.limit = Some(value.max(1));
+        self
+    }
+
+    pub fn debounce_ms(mut self, value: u64) -> Self {
+        self.debounce_ms = value;
+        self
+    }
+
+    pub fn focus_handle(mut self, value: FocusHandle) -> Self {
+        self.focus_handle = Some(value);
+        self
+    }
+
+    pub fn load_options<F, Fut>(mut self, loader: F) -> Self
+    where
+        F: Fn(SharedString) -> Fut + 'static,
+        Fut: Future<Output = Vec<SelectOption>> + 'static,
+    {
+        self.loader = Some(Rc::new(move |query| Box::pin(loader(query))));
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(Vec<SharedString>, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_search(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_search = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_values(&self) -> Vec<String> {
+        tags_input_state::resolve_values(
+            &self.id,
+            self.values
+                .as_ref()
+                .map(|values| values.iter().map(ToString::to_string).collect()),
+            self.default_values
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        )
+    }
+
+    fn commit(&self, current: Vec<String>) -> TagsCommit {
+        TagsCommit {
+            id: self.id.clone(),
+            values_controlled: self.values.is_some(),
+            current,
+            max_tags: self.max_tags,
+            allow_duplicates: self.allow_duplicates,
+            separators: self.separators.clone(),
+            debounce_ms: self.debounce_ms,
+            loader: self.loader.clone(),
+            on_change: self.on_change.clone(),
+            on_search: self.on_search.clone(),
+        }
+    }
+
+    fn tag_label(&self, value: &str) -> SharedString {
+        self.options
+            .iter()
+            .find(|option| option.value.as_ref() == value)
+            .and_then(|option| option.label.clone())
+            .unwrap_or_else(|| SharedString::from(value.to_string()))
+    }
+
+    fn suggestions(&self, values: &[String], query: &str) -> Vec<SelectOption> {
+        let available = |option: &SelectOption| {
+            self.allow_duplicates || !values.iter().any(|value| value == option.value.as_ref())
+        };
+        let local = self
+            .options
+            .iter()
+            .filter(|option| available(option))
+            .cloned()
+            .collect::<Vec<_>>();
+        let labels = local
+            .iter()
+            .map(|option| {
+                option
+                    .label
+                    .clone()
+                    .unwrap_or_else(|| option.value.clone())
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        let mut suggestions = combobox_state::filter_options(&labels, query, None)
+            .into_iter()
+            .map(|index| local[index].clone())
+            .collect::<Vec<_>>();
+        if self.loader.is_some() {
+            for (value, label) in tags_input_state::remote_options(&self.id) {
+                let option = SelectOption::labeled(value, label);
+                if available(&option)
+                    && !suggestions
+                        .iter()
+                        .any(|existing| existing.value == option.value)
+                {
+                    suggestions.push(option);
+                }
+            }
+        }
+        if let Some(limit) = self.limit {
+            suggestions.truncate(limit);
+        }
+        suggestions
+    }
+
+    fn render_tags(&self, window: &Window, commit: &TagsCommit, values: &[String]) -> AnyElement {
+        let tokens = &self.theme.components.select;
+        let (visible, hidden) = tags_input_state::visible_tags(values.len(), self.max_visible_tags);
+        let tag = |content: AnyElement| {
+            Stack::horizontal()
+                .items_center()
+                .gap(tokens.tag_gap)
+                .flex_none()
+                .px(tokens.tag_padding_x)
+                .py(tokens.tag_padding_y)
+                .text_size(tokens.tag_size)
+                .rounded_full()
+                .border(super::utils::quantized_stroke_px(window, 1.0))
+                .border_color(resolve_hsla(&self.theme, tokens.tag_border))
+                .bg(resolve_hsla(&self.theme, tokens.tag_bg))
+                .text_color(resolve_hsla(&self.theme, tokens.tag_fg))
+                .max_w(tokens.tag_max_width)
+                .child(content)
+        };
+
+        let mut row = Stack::horizontal()
+            .items_center()
+            .gap(tokens.tag_gap)
+            .min_w_0()
+            .overflow_hidden();
+        for (index, value) in values.iter().take(visible).enumerate() {
+            let label = div()
+                .min_w_0()
+                .truncate()
+                .child(self.tag_label(value))
+                .into_any_element();
+            let mut chip = tag(label);
+            if !self.disabled {
+                let remove_id = self.id.slot_index("remove", index.to_string());
+                let commit = commit.clone();
+                chip = chip.child(bind_press_adapter(
+                    div()
+                        .id(remove_id.clone())
+                        .flex_none()
+                        .cursor_pointer()
+                        .child(
+                            self.id
+                                .ctx()
+                                .child_index("remove-icon", index.to_string(), Icon::named("x"))
+                                .size(f32::from(tokens.tag_size))
+                                .color(resolve_hsla(&self.theme, tokens.icon)),
+                        ),
+                    PressAdapter::new(remove_id).on_activate(Some(Rc::new(
+                        move |window: &mut Window, cx: &mut gpui::App| {
+                            commit.remove(index, window, cx)
+                        },
+                    ))),
+                ));
+            }
+            row = row.child(chip);
+        }
+        if hidden > 0 {
+            row = row.child(tag(format!("+{hidden}").into_any_element()));
+        }
+        row.into_any_element()
+    }
+
+    fn render_dropdown(
+        &self,
+        window: &Window,
+        commit: &TagsCommit,
+        suggestions: &[SelectOption],
+        highlighted: Option<usize>,
+        loading: bool,
+    ) -> Option<AnyElement> {
+        if suggestions.is_empty() && !loading {
+            return None;
+        }
+        let tokens = &self.theme.components.select;
+        let hover_bg = resolve_hsla(&self.theme, tokens.option_hover_bg);
+        let rows = suggestions.iter().enumerate().map(|(index, option)| {
+            let row_id = self.id.slot_index("suggestion", index.to_string());
+            let row = div()
+                .id(row_id.clone())
+                .px(tokens.option_padding_x)
+                .py(tokens.option_padding_y)
+                .rounded_sm()
+                .text_size(tokens.option_size)
+                .text_color(resolve_hsla(&self.theme, tokens.option_fg))
+                .when(highlighted == Some(index), |row| row.bg(hover_bg))
+                .child(
+                    div()
+                        .min_w_0()
+                        .truncate()
+                        .child(option.label.clone().unwrap_or_else(|| option.value.clone())),
+                );
+            if option.disabled {
+                return row.opacity(0.45).cursor_default().into_any_element();
+            }
+            let commit = commit.clone();
+            let value = option.value.to_string();
+            bind_press_adapter(
+                apply_interaction_styles(
+                    row.cursor_pointer(),
+                    InteractionStyles::new()
+                        .hover(interaction_style(move |style| style.bg(hover_bg))),
+                ),
+                PressAdapter::new(row_id).on_activate(Some(Rc::new(
+                    move |window: &mut Window, cx: &mut gpui::App| {
+                        commit.submit(value.clone(), window, cx)
+                    },
+                ))),
+            )
+            .into_any_element()
+        });
+
+        let mut list = div()
+            .flex()
+            .flex_col()
+            .gap(tokens.dropdown_gap)
+            .children(rows);
+        if loading {
+            list = list.child(
+                div()
+                    .px(tokens.option_padding_x)
+                    .py(tokens.option_padding_y)
+                    .text_size(tokens.option_size)
+                    .text_color(resolve_hsla(&self.theme, tokens.placeholder))
+                    .child("Loading…"),
+            );
+        }
+
+        let commit = commit.clone();
+        Some(
+            div()
+                .id(self.id.slot("dropdown"))
+                .w(px(select_state::dropdown_width_px(
+                    &self.id,
+                    f32::from(tokens.dropdown_width_fallback),
+                )))
+                .rounded_md()
+                .border(super::utils::quantized_stroke_px(window, 1.0))
+                .border_color(resolve_hsla(&self.theme, tokens.dropdown_border))
+                .bg(resolve_hsla(&self.theme, tokens.dropdown_bg))
+                .shadow_sm()
+                .max_h(tokens.dropdown_max_height)
+                .overflow_y_scroll()
+                .p(tokens.dropdown_padding)
+                .child(list)
+                .on_mouse_down_out(move |_, window: &mut Window, _cx: &mut gpui::App| {
+                    commit.set_opened(false, window);
+                })
+                .with_enter_transition(self.id.slot("dropdown-enter"), self.motion)
+                .into_any_element(),
+        )
+    }
+}
+
+impl FieldLike for TagsInput {
+    fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    fn error(mut self, value: impl Into<SharedString>) -> Self {
+        self.error = Some(value.into());
+        self
+    }
+
+    fn required(mut self, value: bool) -> Self {
+        self.required = value;
+        self
+    }
+
+    fn layout(mut self, value: FieldLayout) -> Self {
+        self.layout = value;
+        self
+    }
+}
+
+crate::impl_variant_size_radius_via_methods!(TagsInput, variant, size, radius);
+crate::impl_disableable!(TagsInput, |this, value| this.disabled = value);
+
+impl MotionAware for TagsInput {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for TagsInput {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.select.clone();
+        let values = self.resolved_values();
+        let query = tags_input_state::query(&self.id);
+        let loading = self.loader.is_some() && tags_input_state::loading(&self.id);
+        let suggestions = self.suggestions(&values, &query);
+        let highlighted = combobox_state::highlighted(&self.id, suggestions.len());
+        let full = self
+            .max_tags
+            .is_some_and(|max_tags| values.len() >= max_tags);
+        let opened = !self.disabled
+            && !full
+            && PopupStateValue::resolve(PopupStateInput {
+                id: &self.id,
+                opened: None,
+                default_opened: false,
+                disabled: self.disabled,
+            })
+            .opened;
+        let commit = self.commit(values.clone());
+
+        let mut input = self
+            .id
+            .ctx()
+            .root(TextInput::new())
+            .value(query.clone())
+            .themed(|overrides| {
+                overrides
+                    .bg(tokens.bg)
+                    .fg(tokens.fg)
+                    .placeholder(tokens.placeholder)
+                    .border(tokens.border)
+                    .border_focus(tokens.border_focus)
+                    .border_error(tokens.border_error)
+                    .label(tokens.label)
+                    .label_size(tokens.label_size)
+                    .label_weight(tokens.label_weight)
+                    .description(tokens.description)
+                    .description_size(tokens.description_size)
+                    .error(tokens.error)
+                    .error_size(tokens.error_size)
+                    .sizes(tokens.sizes)
+            })
+            .required(self.required)
+            .layout(self.layout)
+            .disabled(self.disabled)
+            .read_only(full)
+            .with_variant(self.variant)
+            .with_size(self.size)
+            .with_radius(self.radius);
+        input = MotionAware::motion(input, self.motion).on_change({
+            let commit = commit.clone();
+            move |next: SharedString, window, cx| commit.edit(next, window, cx)
+        });
+        input = input.on_submit({
+            let commit = commit.clone();
+            let creatable = self.creatable;
+            let highlighted_value = highlighted
+                .filter(|_| opened)
+                .map(|index| suggestions[index].value.to_string());
+            move |text: SharedString, window, cx| match highlighted_value.clone() {
+                Some(value) => commit.submit(value, window, cx),
+                None if creatable => commit.submit(text.to_string(), window, cx),
+                None => {}
+            }
+        });
+        if values.is_empty()
+            && let Some(placeholder) = self.placeholder.clone()
+        {
+            input = input.placeholder(placeholder);
+        }
+        if let Some(label) = self.label.clone() {
+            input = input.label(label);
+        }
+        if let Some(description) = self.description.clone() {
+            input = input.description(description);
+        }
+        if let Some(error) = self.error.clone() {
+            input = input.error(error);
+        }
+        if let Some(focus_handle) = self.focus_handle.clone() {
+            input = input.focus_handle(focus_handle);
+        }
+        if !values.is_empty() {
+            input = input.left_slot(self.render_tags(window, &commit, &values));
+        }
+
+        let id_for_width = self.id.clone();
+        let mut field = div()
+            .id(self.id.slot("field"))
+            .relative()
+            .w_full()
+            .child(input.render(window, cx).into_any_element())
+            .child(
+                canvas(
+                    move |bounds, _, _| {
+                        select_state::set_dropdown_width(
+                            &id_for_width,
+                            f32::from(bounds.size.width),
+                        );
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            );
+        if opened
+            && let Some(dropdown) =
+                self.render_dropdown(window, &commit, &suggestions, highlighted, loading)
+        {
+            field = field.child(anchored_host(
+                &self.id,
+                "anchor-host",
+                PopupPlacement::Bottom.options(
+                    f32::from(tokens.dropdown_anchor_offset),
+                    self.theme.components.layout.popup_snap_margin,
+                ),
+                dropdown,
+                24,
+                true,
+            ));
+        }
+        if self.disabled {
+            return field.into_any_element();
+        }
+
+        let enabled = suggestions
+            .iter()
+            .map(|option| !option.disabled)
+            .collect::<Vec<_>>();
+        let id = self.id.clone();
+        div()
+            .id(self.id.slot("keyboard-proxy"))
+            .w_full()
+            .on_key_down(move |event, window, cx| {
+                if !control::focused_state(&id, None, false) || !control::is_plain_keystroke(event)
+                {
+                    return;
+                }
+                match event.keystroke.key.as_str() {
+                    "down" | "up" => {
+                        let delta = if event.keystroke.key == "down" { 1 } else { -1 };
+                        combobox_state::set_highlighted(
+                            &id,
+                            combobox_state::move_highlight(highlighted, delta, &enabled),
+                        );
+                        commit.set_opened(true, window);
+                        window.refresh();
+                    }
+                    "backspace" if query.is_empty() && !commit.current.is_empty() => {
+                        commit.remove(commit.current.len() - 1, window, cx);
+                    }
+                    "escape" if opened => commit.set_opened(false, window),
+                    _ => return,
+                }
+                cx.stop_propagation();
+                window.prevent_default();
+            })
+            .child(field)
+            .into_any_element()
+    }
+}
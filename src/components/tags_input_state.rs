@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::resource::Resource;
+
+use super::control;
+
+type RemoteOptions = Resource<Vec<(String, String)>>;
+
+static REMOTE_OPTIONS: LazyLock<Mutex<HashMap<String, RemoteOptions>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+pub fn split_input(text: &str, separators: &[char]) -> (Vec<String>, String) {
+    let mut segments = text
+        .split(|ch: char| separators.contains(&ch))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    let remainder = segments.pop().unwrap_or_default();
+    let complete = segments
+        .into_iter()
+        .map(|segment| segment.trim().to_string())
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    (complete, remainder)
+}
+
+pub fn add_tags(
+    current: &[String],
+    incoming: impl IntoIterator<Item = String>,
+    max_tags: Option<usize>,
+    allow_duplicates: bool,
+) -> Vec<String> {
+    let mut tags = current.to_vec();
+    let limit = max_tags.unwrap_or(usize::MAX);
+    for tag in incoming {
+        let tag = tag.trim().to_string();
+        if tag.is_empty() || (!allow_duplicates && tags.contains(&tag)) {
+            continue;
+        }
+        if tags.len() >= limit {
+            break;
+        }
+        tags.push(tag);
+    }
+    tags
+}
+
+pub fn remove_tag(current: &[String], index: usize) -> Vec<String> {
+    current
+        .iter()
+        .enumerate()
+        .filter(|(position, _)| *position != index)
+        .map(|(_, tag)| tag.clone())
+        .collect()
+}
+
+pub fn visible_tags(count: usize, max_visible: Option<usize>) -> (usize, usize) {
+    match max_visible {
+        Some(max_visible) if count > max_visible => (max_visible, count - max_visible),
+        _ => (count, 0),
+    }
+}
+
+pub fn resolve_values(
+    id: &str,
+    controlled: Option<Vec<String>>,
+    default: Vec<String>,
+) -> Vec<String> {
+    control::list_state(id, "values", controlled, default)
+}
+
+pub fn set_values(id: &str, values: Vec<String>) {
+    control::set_list_state(id, "values", values);
+}
+
+pub fn query(id: &str) -> String {
+    control::text_state(id, "query", None, String::new())
+}
+
+pub fn set_query(id: &str, value: String) {
+    control::set_text_state(id, "query", value);
+}
+
+pub fn remote(id: &str) -> RemoteOptions {
+    REMOTE_OPTIONS
+        .lock()
+        .map(|mut remote| remote.entry(id.to_string()).or_default().clone())
+        .unwrap_or_default()
+}
+
+pub fn remote_options(id: &str) -> Vec<(String, String)> {
+    remote(id).value().unwrap_or_default()
+}
+
+pub fn set_remote_options(id: &str, options: Vec<(String, String)>) {
+    remote(id).set(options);
+}
+
+pub fn loading(id: &str) -> bool {
+    remote(id).is_loading()
+}
+
+pub fn set_loading(id: &str, value: bool) {
+    let remote = remote(id);
+    if value {
+        remote.begin();
+    } else {
+        remote.cancel();
+    }
+}
+
+pub fn bump_revision(id: &str) -> usize {
+    let next = revision(id).wrapping_add(1);
+    control::set_usize_state(id, "revision", next);
+    next
+}
+
+pub fn revision(id: &str) -> usize {
+    control::usize_state(id, "revision", None, 0)
+}
@@ -0,0 +1,271 @@
+use std::time::Duration;
+
+use gpui::{
+    AnyElement, FontWeight, InteractiveElement, IntoElement, ParentElement, RenderOnce,
+    SharedString, Styled, Window, div,
+};
+
+use crate::contracts::{MotionAware, Sized, Varianted};
+use crate::feedback::{TaskEntry, TaskManager, TaskStatus, TaskSummary};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::{Size, Variant};
+
+use super::Stack;
+use super::action_icon::ActionIcon;
+use super::button::Button;
+use super::icon::Icon;
+use super::popover::{Popover, PopoverPlacement};
+use super::progress::{Progress, ProgressSection};
+use super::utils::resolve_hsla;
+
+const REFRESH_INTERVAL_MS: u64 = 250;
+
+#[derive(IntoElement)]
+pub struct TaskStatusItem {
+    pub(crate) id: ComponentId,
+    manager: Option<TaskManager>,
+    label: Option<SharedString>,
+    clear_label: SharedString,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl TaskStatusItem {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            manager: None,
+            label: None,
+            clear_label: SharedString::from("Clear finished"),
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    pub fn manager(mut self, value: TaskManager) -> Self {
+        self.manager = Some(value);
+        self
+    }
+
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    pub fn clear_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.clear_label = value.into();
+        self
+    }
+
+    fn status_color(&self, status: TaskStatus) -> gpui::Hsla {
+        let tokens = &self.theme.components.task_status;
+        let color = match status {
+            TaskStatus::Running => tokens.running,
+            TaskStatus::Completed => tokens.done,
+            TaskStatus::Failed => tokens.error,
+            TaskStatus::Cancelled => tokens.cancelled,
+        };
+        resolve_hsla(&self.theme, color)
+    }
+
+    fn progress_bar(&self, key: &str, progress: Option<f32>, status: TaskStatus) -> Progress {
+        let color = self.status_color(status);
+        let section = match progress {
+            Some(value) => ProgressSection::new(value * 100.0).color(color),
+            None => ProgressSection::new(100.0).color(color.opacity(0.35)),
+        };
+        let indeterminate = progress.is_none() && status == TaskStatus::Running;
+        self.id
+            .ctx()
+            .child_index("bar", key.to_string(), Progress::new())
+            .sections([section])
+            .striped(indeterminate)
+            .animated(indeterminate)
+    }
+
+    fn trigger_text(&self, summary: TaskSummary) -> String {
+        if let Some(label) = self.label.as_ref() {
+            return label.to_string();
+        }
+        let noun = if summary.running == 1 {
+            "task"
+        } else {
+            "tasks"
+        };
+        match (summary.running, summary.progress) {
+            (0, _) if summary.failed > 0 => format!("{} failed", summary.failed),
+            (0, _) => "Tasks finished".to_string(),
+            (running, Some(progress)) => {
+                format!("{running} {noun} · {:.0}%", progress * 100.0)
+            }
+            (running, None) => format!("{running} {noun}"),
+        }
+    }
+
+    fn render_trigger(&self, summary: TaskSummary) -> AnyElement {
+        let tokens = &self.theme.components.task_status;
+        let (icon, status) = match summary.running {
+            0 if summary.failed > 0 => ("alert-circle", TaskStatus::Failed),
+            0 => ("circle-check", TaskStatus::Completed),
+            _ => ("loader-2", TaskStatus::Running),
+        };
+        let icon = self
+            .id
+            .ctx()
+            .child("status-icon", Icon::named(icon))
+            .size(f32::from(tokens.icon_size))
+            .color(self.status_color(status));
+        let bar = (summary.running > 0).then(|| {
+            let bar = self.progress_bar("summary", summary.progress, TaskStatus::Running);
+            div().w(tokens.trigger_bar_width).child(bar)
+        });
+        let hover_bg = resolve_hsla(&self.theme, tokens.trigger_hover_bg);
+        div()
+            .id(self.id.slot("trigger"))
+            .flex()
+            .items_center()
+            .gap(tokens.row_gap)
+            .px(tokens.row_gap)
+            .rounded_sm()
+            .text_size(tokens.trigger_size)
+            .text_color(resolve_hsla(&self.theme, tokens.trigger_fg))
+            .hover(move |style| style.bg(hover_bg))
+            .child(icon)
+            .child(self.trigger_text(summary))
+            .children(bar)
+            .into_any_element()
+    }
+
+    fn render_task(&self, entry: TaskEntry, manager: &TaskManager) -> AnyElement {
+        let tokens = &self.theme.components.task_status;
+        let key = entry.id.map(|id| id.0).unwrap_or_default().to_string();
+        let title = div()
+            .flex_1()
+            .min_w_0()
+            .truncate()
+            .text_size(tokens.title_size)
+            .font_weight(FontWeight::MEDIUM)
+            .text_color(resolve_hsla(&self.theme, tokens.title))
+            .child(entry.title.clone());
+        let percent = entry
+            .progress
+            .map(|value| format!("{:.0}%", value * 100.0))
+            .map(|text| div().flex_none().child(text));
+        let cancel = (entry.status == TaskStatus::Running && entry.cancellable)
+            .then_some(entry.id)
+            .flatten()
+            .map(|task_id| {
+                let manager = manager.clone();
+                self.id
+                    .ctx()
+                    .child_index("cancel", key.clone(), ActionIcon::new())
+                    .with_variant(Variant::Subtle)
+                    .with_size(Size::Xs)
+                    .child(Icon::named("x").size(f32::from(tokens.icon_size)))
+                    .on_click(move |_, window, _| {
+                        if manager.cancel(task_id) {
+                            window.refresh();
+                        }
+                    })
+            });
+        let header = Stack::horizontal()
+            .w_full()
+            .items_center()
+            .gap(tokens.row_gap)
+            .child(title)
+            .children(percent)
+            .children(cancel);
+
+        let message = entry.message.clone().map(|message| {
+            div()
+                .truncate()
+                .text_size(tokens.message_size)
+                .text_color(resolve_hsla(&self.theme, tokens.message))
+                .child(message)
+        });
+        let bar = self.progress_bar(&key, entry.progress, entry.status);
+        let item = Stack::vertical().w_full().gap(tokens.row_gap);
+        item.child(header)
+            .child(bar)
+            .children(message)
+            .into_any_element()
+    }
+
+    fn render_panel(&self, manager: &TaskManager, summary: TaskSummary) -> AnyElement {
+        let tokens = &self.theme.components.task_status;
+        let rows: Vec<_> = manager
+            .list()
+            .into_iter()
+            .map(|entry| self.render_task(entry, manager))
+            .collect();
+        let clear = (summary.finished > 0).then(|| {
+            let manager = manager.clone();
+            self.id
+                .ctx()
+                .child("clear", Button::new())
+                .label(self.clear_label.clone())
+                .with_variant(Variant::Subtle)
+                .with_size(Size::Xs)
+                .on_click(move |_, window, _| {
+                    manager.clear_finished();
+                    window.refresh();
+                })
+        });
+        Stack::vertical()
+            .w(tokens.panel_width)
+            .gap(tokens.item_gap)
+            .children(rows)
+            .children(clear)
+            .into_any_element()
+    }
+
+    fn schedule_refresh(manager: &TaskManager, window: &Window, cx: &mut gpui::App) {
+        if !manager.mark_refresh_scheduled() {
+            return;
+        }
+        let manager = manager.clone();
+        let window_handle = window.window_handle();
+        cx.spawn(async move |cx| {
+            cx.background_executor()
+                .timer(Duration::from_millis(REFRESH_INTERVAL_MS))
+                .await;
+            manager.finish_refresh();
+            let _ = window_handle.update(cx, |_, window, _| window.refresh());
+        })
+        .detach();
+    }
+}
+
+impl MotionAware for TaskStatusItem {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for TaskStatusItem {
+    fn render(mut self, window: &mut Window, _cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(_cx);
+        let manager = self
+            .manager
+            .take()
+            .unwrap_or_else(|| crate::provider::CalmProvider::tasks(_cx));
+        Self::schedule_refresh(&manager, window, _cx);
+        let summary = manager.summary();
+        if summary.running + summary.finished == 0 {
+            return div().id(self.id.clone());
+        }
+
+        let popover = self
+            .id
+            .ctx()
+            .child("popover", Popover::new())
+            .placement(PopoverPlacement::Top)
+            .motion(self.motion)
+            .trigger(self.render_trigger(summary))
+            .content(self.render_panel(&manager, summary));
+        div().id(self.id.clone()).child(popover)
+    }
+}
@@ -1,8 +1,21 @@
+use std::path::PathBuf;
 use std::sync::MutexGuard;
 
+use crate::overlay::positioning::{Rect, Side};
+
 use super::{
-    control, menu_state, popup, popup_state, select_state, selection_state, slider_axis,
-    table_state, text_input_state, tree_state,
+    AnnouncementBarPosition, AppShellLayout, AppShellPanel, CalendarDate, CalendarEvent,
+    CalendarView, ChecklistStatus, DateRange, ProgressSection, TreeDropPosition, TreeNode,
+    app_shell_layout, bottom_sheet_state, breadcrumbs_state, calendar_state, chart_state,
+    chat_view_state, checklist_state, color_picker_state, combobox_state, compare_slider_state,
+    composition, console_state, context_menu_state, control, date_picker_state, dock_tabs_state,
+    drawer_state, editable_text_state, file_input_state, graph_canvas_state, graph_model,
+    history_timeline_state, image_state, input_mask, kanban_board_state, log_buffer,
+    log_view_state, markdown_stream, menu_state, minimap_state, number_input_state,
+    password_strength, paste_attachment, pin_input_state, popup, popup_state, press_feedback,
+    progress, rating_state, ring_progress_state, rulers_state, select_state, selection_state,
+    settings_view_state, slider_axis, split_pane_state, stat_card, table_state, tags_input_state,
+    text_input_state, text_selection, time_picker_state, tree_state, wizard_state, zoom_pane_state,
 };
 
 struct StateTestGuard {
@@ -56,6 +69,7 @@ fn demo_visible_nodes() -> Vec<tree_state::TreeVisibleNode> {
             path: "0".into(),
             disabled: false,
             has_children: true,
+            child_count: 2,
             first_child: Some("child-a".into()),
         },
         tree_state::TreeVisibleNode {
@@ -66,6 +80,7 @@ fn demo_visible_nodes() -> Vec<tree_state::TreeVisibleNode> {
             path: "0-0".into(),
             disabled: false,
             has_children: false,
+            child_count: 0,
             first_child: None,
         },
         tree_state::TreeVisibleNode {
@@ -76,6 +91,7 @@ fn demo_visible_nodes() -> Vec<tree_state::TreeVisibleNode> {
             path: "0-1".into(),
             disabled: true,
             has_children: false,
+            child_count: 0,
             first_child: None,
         },
     ]
@@ -109,6 +125,33 @@ fn popup_state_value_disables_opened_flag_when_disabled() {
     assert!(resolved.controlled);
 }
 
+#[test]
+fn popup_state_resolves_measured_placement_with_flipping() {
+    let _guard = guard();
+
+    let options = popup::PopupPlacement::Bottom.options(4.0, gpui::px(8.0));
+    assert_eq!(popup_state::resolve_placement("popup-place", options), None);
+
+    let anchor = Rect::new(40.0, 560.0, 120.0, 28.0);
+    let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+    assert!(popup_state::set_anchor_frame(
+        "popup-place",
+        anchor,
+        viewport
+    ));
+    assert!(!popup_state::set_anchor_frame(
+        "popup-place",
+        anchor,
+        viewport
+    ));
+    assert!(popup_state::set_floating_size("popup-place", 200.0, 180.0));
+    assert!(!popup_state::set_floating_size("popup-place", 200.0, 180.0));
+
+    let placement = popup_state::resolve_placement("popup-place", options).expect("measured");
+    assert_eq!(placement.side, Side::Top);
+    assert_eq!((placement.x, placement.y), (40.0, 376.0));
+}
+
 #[test]
 fn popup_apply_opened_respects_controlled_mode() {
     let _guard = guard();
@@ -144,6 +187,297 @@ fn menu_state_resolves_dropdown_width_with_fallback_and_minimum() {
     assert_eq!(resolved.dropdown_width_px, 176.0);
 }
 
+#[test]
+fn bottom_sheet_state_snaps_drags_and_dismisses() {
+    let _guard = guard();
+
+    let heights = bottom_sheet_state::snap_heights(&[0.9, 0.25, 0.5, 0.5], 800.0);
+    assert_eq!(heights, vec![200.0, 400.0, 720.0]);
+    assert_eq!(
+        bottom_sheet_state::release_target(&heights, 330.0, true),
+        Some(1)
+    );
+    assert_eq!(
+        bottom_sheet_state::release_target(&heights, 900.0, true),
+        Some(2)
+    );
+    assert_eq!(
+        bottom_sheet_state::release_target(&heights, 60.0, true),
+        None
+    );
+    assert_eq!(
+        bottom_sheet_state::release_target(&heights, 60.0, false),
+        Some(0)
+    );
+    assert_eq!(bottom_sheet_state::release_target(&[], 60.0, true), None);
+
+    assert_eq!(bottom_sheet_state::snap_index("sheet", 1), 1);
+    assert_eq!(bottom_sheet_state::drag_to("sheet", 100.0, 800.0), None);
+    bottom_sheet_state::begin_drag("sheet", 500.0, 400.0);
+    assert_eq!(
+        bottom_sheet_state::drag_to("sheet", 300.0, 800.0),
+        Some(600.0)
+    );
+    assert_eq!(
+        bottom_sheet_state::drag_to("sheet", -200.0, 800.0),
+        Some(800.0)
+    );
+    assert_eq!(bottom_sheet_state::end_drag("sheet"), Some(800.0));
+    assert_eq!(bottom_sheet_state::drag_height("sheet"), None);
+
+    bottom_sheet_state::set_snap_index("sheet", 2);
+    assert_eq!(bottom_sheet_state::snap_index("sheet", 0), 2);
+    bottom_sheet_state::reset("sheet");
+    assert_eq!(bottom_sheet_state::snap_index("sheet", 0), 0);
+}
+
+#[test]
+fn app_shell_layout_places_announcement_bar_around_title_bar() {
+    use app_shell_layout::{AnnouncementSlot, announcement_slot};
+
+    for overlaid in [false, true] {
+        assert_eq!(
+            announcement_slot(AnnouncementBarPosition::AboveTitleBar, overlaid),
+            AnnouncementSlot::Top
+        );
+    }
+    assert_eq!(
+        announcement_slot(AnnouncementBarPosition::BelowTitleBar, false),
+        AnnouncementSlot::BelowTitleBar
+    );
+    assert_eq!(
+        announcement_slot(AnnouncementBarPosition::BelowTitleBar, true),
+        AnnouncementSlot::OverlayBelowTitleBar
+    );
+    assert_eq!(
+        announcement_slot(AnnouncementBarPosition::default(), false),
+        AnnouncementSlot::BelowTitleBar
+    );
+}
+
+#[test]
+fn app_shell_layout_persists_resizes_and_tracks_collapse_changes() {
+    let _guard = guard();
+
+    let saved = AppShellLayout::new()
+        .with_size(AppShellPanel::Sidebar, 280.0)
+        .with_collapsed(AppShellPanel::Inspector, true);
+    assert_eq!(app_shell_layout::layout("shell", None, saved), saved);
+    let controlled = saved.toggled(AppShellPanel::BottomPanel);
+    assert_eq!(
+        app_shell_layout::layout("shell", Some(controlled), saved),
+        controlled
+    );
+
+    let restored = saved.with_size(AppShellPanel::BottomPanel, 220.0);
+    restored.restore("shell");
+    assert_eq!(AppShellLayout::current("shell"), restored);
+    assert_eq!(
+        app_shell_layout::layout("shell", None, AppShellLayout::default()),
+        restored
+    );
+
+    assert_eq!(app_shell_layout::size_bounds(120.0, 1000.0), (120.0, 600.0));
+    assert_eq!(app_shell_layout::size_bounds(120.0, 100.0), (120.0, 120.0));
+
+    assert_eq!(app_shell_layout::resizing_panel("shell"), None);
+    app_shell_layout::begin_resize("shell", AppShellPanel::Inspector, 900.0, 320.0);
+    assert_eq!(
+        app_shell_layout::resizing_panel("shell"),
+        Some(AppShellPanel::Inspector)
+    );
+    assert_eq!(
+        app_shell_layout::resize_to("shell", AppShellPanel::Inspector, 850.0, 120.0, 600.0),
+        Some(370.0)
+    );
+    assert_eq!(
+        app_shell_layout::resize_to("shell", AppShellPanel::Sidebar, 850.0, 120.0, 600.0),
+        None
+    );
+    assert_eq!(
+        app_shell_layout::resize_to("shell", AppShellPanel::Inspector, 100.0, 120.0, 600.0),
+        Some(600.0)
+    );
+    assert_eq!(
+        app_shell_layout::end_resize("shell", AppShellPanel::Inspector),
+        Some(600.0)
+    );
+    assert_eq!(app_shell_layout::resizing_panel("shell"), None);
+
+    let panel = AppShellPanel::Sidebar;
+    assert_eq!(
+        app_shell_layout::collapse_generation("shell", panel, false),
+        0
+    );
+    assert_eq!(
+        app_shell_layout::collapse_generation("shell", panel, false),
+        0
+    );
+    assert_eq!(
+        app_shell_layout::collapse_generation("shell", panel, true),
+        1
+    );
+    assert_eq!(
+        app_shell_layout::collapse_generation("shell", panel, true),
+        1
+    );
+    assert_eq!(
+        app_shell_layout::collapse_generation("shell", panel, false),
+        2
+    );
+}
+
+#[test]
+fn drawer_state_bounds_resizes_and_snaps() {
+    let _guard = guard();
+
+    assert_eq!(
+        drawer_state::size_bounds(160.0, Some(600.0), 1000.0),
+        (160.0, 600.0)
+    );
+    assert_eq!(
+        drawer_state::size_bounds(160.0, None, 400.0),
+        (160.0, 400.0)
+    );
+    assert_eq!(
+        drawer_state::size_bounds(300.0, Some(200.0), 1000.0),
+        (200.0, 200.0)
+    );
+
+    let snaps = drawer_state::snap_sizes(&[1.0, 0.3, 0.5, f32::NAN], 1000.0, 200.0, 800.0);
+    assert_eq!(snaps, vec![300.0, 500.0, 800.0]);
+    assert_eq!(drawer_state::nearest_snap(&snaps, 380.0), 300.0);
+    assert_eq!(drawer_state::nearest_snap(&snaps, 720.0), 800.0);
+    assert_eq!(drawer_state::nearest_snap(&[], 420.0), 420.0);
+
+    assert_eq!(drawer_state::size("drawer", 360.0), 360.0);
+    assert_eq!(
+        drawer_state::resize_to("drawer", 10.0, -1.0, 160.0, 800.0),
+        None
+    );
+    drawer_state::begin_resize("drawer", 600.0, 360.0);
+    assert_eq!(
+        drawer_state::resize_to("drawer", 500.0, -1.0, 160.0, 800.0),
+        Some(460.0)
+    );
+    assert_eq!(
+        drawer_state::resize_to("drawer", 900.0, -1.0, 160.0, 800.0),
+        Some(160.0)
+    );
+    assert_eq!(
+        drawer_state::resize_to("drawer", 0.0, -1.0, 160.0, 800.0),
+        Some(800.0)
+    );
+    assert_eq!(drawer_state::end_resize("drawer"), Some(800.0));
+    assert_eq!(drawer_state::resize_size("drawer"), None);
+
+    drawer_state::begin_resize("drawer", 100.0, 300.0);
+    assert_eq!(
+        drawer_state::resize_to("drawer", 150.0, 1.0, 160.0, 800.0),
+        Some(350.0)
+    );
+    drawer_state::set_size("drawer", 420.0);
+    assert_eq!(drawer_state::size("drawer", 360.0), 420.0);
+}
+
+#[test]
+fn checklist_state_summarizes_and_toggles_details() {
+    let _guard = guard();
+
+    let summary = checklist_state::ChecklistSummary::from_statuses([
+        ChecklistStatus::Done,
+        ChecklistStatus::Done,
+        ChecklistStatus::Active,
+        ChecklistStatus::Error,
+        ChecklistStatus::Active,
+    ]);
+    assert_eq!(summary.total, 5);
+    assert_eq!(summary.done, 2);
+    assert_eq!(summary.failed, 1);
+    assert_eq!(summary.active, Some(2));
+    assert_eq!(summary.percent(), 40.0);
+    assert!(!summary.is_complete());
+    assert_eq!(
+        checklist_state::ChecklistSummary::from_statuses([]).percent(),
+        0.0
+    );
+
+    assert!(checklist_state::expanded("checklist", "build", true));
+    assert!(!checklist_state::toggle("checklist", "build", true));
+    assert!(!checklist_state::expanded("checklist", "build", true));
+    assert!(checklist_state::toggle("checklist", "fetch", false));
+}
+
+#[test]
+fn context_menu_state_tracks_pointer_and_submenu_path() {
+    let _guard = guard();
+
+    assert!(!context_menu_state::opened("ctx"));
+    assert!(context_menu_state::open_at("ctx", 40.0, 80.0));
+    assert!(!context_menu_state::open_at("ctx", 40.0, 80.0));
+    assert_eq!(context_menu_state::position("ctx"), (40.0, 80.0));
+
+    assert!(context_menu_state::open_submenu("ctx", 0, "share"));
+    assert!(!context_menu_state::open_submenu("ctx", 0, "share"));
+    assert!(context_menu_state::open_submenu("ctx", 1, "email"));
+    assert_eq!(
+        context_menu_state::submenu_path("ctx"),
+        vec!["share", "email"]
+    );
+    assert!(context_menu_state::open_submenu("ctx", 0, "export"));
+    assert_eq!(context_menu_state::submenu_path("ctx"), vec!["export"]);
+    assert!(context_menu_state::close_submenu("ctx", 0));
+    assert!(!context_menu_state::close_submenu("ctx", 0));
+
+    context_menu_state::open_submenu("ctx", 0, "share");
+    assert!(context_menu_state::open_at("ctx", 12.0, 16.0));
+    assert!(context_menu_state::submenu_path("ctx").is_empty());
+    assert!(context_menu_state::close("ctx"));
+    assert!(!context_menu_state::close("ctx"));
+}
+
+#[test]
+fn menu_state_tracks_submenus_and_flips_near_edges() {
+    let _guard = guard();
+
+    assert!(menu_state::open_submenu("menu-nested", 0, "share"));
+    assert!(menu_state::open_submenu("menu-nested", 1, "email"));
+    assert_eq!(
+        menu_state::submenu_path("menu-nested"),
+        vec!["share", "email"]
+    );
+
+    let frame = menu_state::MenuFrame {
+        x: 200.0,
+        y: 40.0,
+        width: 160.0,
+        height: 120.0,
+    };
+    menu_state::set_submenu_frame("menu-nested", 1, frame);
+    assert!(menu_state::submenu_contains("menu-nested", 240.0, 100.0));
+    assert!(!menu_state::submenu_contains("menu-nested", 100.0, 100.0));
+    assert!(menu_state::close_submenu("menu-nested", 0));
+    assert!(!menu_state::submenu_contains("menu-nested", 240.0, 100.0));
+
+    let row = menu_state::MenuFrame {
+        x: 600.0,
+        y: 0.0,
+        width: 180.0,
+        height: 28.0,
+    };
+    assert_eq!(
+        menu_state::submenu_side(row, 160.0, 1200.0, 8.0),
+        menu_state::SubmenuSide::Right
+    );
+    assert_eq!(
+        menu_state::submenu_side(row, 160.0, 900.0, 8.0),
+        menu_state::SubmenuSide::Left
+    );
+    menu_state::set_row_frame("menu-nested", "share", row);
+    assert_eq!(menu_state::row_frame("menu-nested", "share"), Some(row));
+    assert_eq!(menu_state::row_frame("menu-nested", "export"), None);
+}
+
 #[test]
 fn menu_state_item_click_respects_close_flag() {
     let _guard = guard();
@@ -223,6 +557,53 @@ fn selection_state_optional_text_and_list_respect_controlled_mode() {
     );
 }
 
+#[test]
+fn settings_view_search_matches_all_words_and_merges_highlights() {
+    let _guard = guard();
+
+    let texts = ["Font size", "Size of editor text", "Appearance"];
+    assert!(settings_view_state::matches("", &texts));
+    assert!(settings_view_state::matches("FONT  appear", &texts));
+    assert!(!settings_view_state::matches("font color", &texts));
+    assert_eq!(
+        settings_view_state::highlight_ranges("Font size", "size ont fon"),
+        vec![0..4, 5..9]
+    );
+
+    let sections = ["general", "editor"];
+    assert_eq!(
+        settings_view_state::active_section(
+            "settings",
+            false,
+            None,
+            Some("editor".into()),
+            &sections
+        ),
+        Some("editor".into())
+    );
+    assert!(settings_view_state::set_active_section(
+        "settings",
+        false,
+        "missing".into()
+    ));
+    assert_eq!(
+        settings_view_state::active_section("settings", false, None, None, &sections),
+        Some("general".into())
+    );
+    assert!(settings_view_state::set_query(
+        "settings",
+        false,
+        "font".into()
+    ));
+    assert_eq!(settings_view_state::query("settings", None), "font");
+    assert!(!settings_view_state::set_query(
+        "settings",
+        true,
+        "size".into()
+    ));
+    assert_eq!(settings_view_state::query("settings", None), "font");
+}
+
 #[test]
 fn selection_state_usize_family_behaves_consistently() {
     let _guard = guard();
@@ -351,6 +732,222 @@ fn table_state_page_and_size_callbacks_update_state_store() {
     assert_eq!(control::usize_state("table-cb", "page", None, 99), 1);
 }
 
+#[test]
+fn table_state_sort_toggles_cycle_and_support_multi_column() {
+    use super::table::{TableSort, TableSortDirection};
+    let _guard = guard();
+
+    let asc = |column| TableSort {
+        column,
+        direction: TableSortDirection::Asc,
+    };
+    let desc = |column| TableSort {
+        column,
+        direction: TableSortDirection::Desc,
+    };
+
+    let first = table_state::toggle_sort(&[], 1, false);
+    assert_eq!(first, vec![asc(1)]);
+    assert_eq!(table_state::toggle_sort(&first, 1, false), vec![desc(1)]);
+    assert!(table_state::toggle_sort(&[desc(1)], 1, false).is_empty());
+    assert_eq!(table_state::toggle_sort(&first, 0, false), vec![asc(0)]);
+
+    let multi = table_state::toggle_sort(&first, 0, true);
+    assert_eq!(multi, vec![asc(1), asc(0)]);
+    assert_eq!(
+        table_state::toggle_sort(&multi, 1, true),
+        vec![desc(1), asc(0)]
+    );
+
+    assert_eq!(
+        table_state::resolve_sorts("table-sort", None, vec![desc(2)]),
+        vec![desc(2)]
+    );
+    table_state::set_sorts("table-sort", &multi);
+    assert_eq!(
+        table_state::resolve_sorts("table-sort", None, Vec::new()),
+        multi
+    );
+    assert_eq!(
+        table_state::resolve_sorts("table-sort", Some(vec![asc(3)]), Vec::new()),
+        vec![asc(3)]
+    );
+
+    assert!(table_state::compare_sort_values("9", "10").is_lt());
+    assert!(table_state::compare_sort_values("b", "a").is_gt());
+}
+
+#[test]
+fn table_state_layout_normalizes_order_and_persists_widths() {
+    use super::table::TableLayoutState;
+    let _guard = guard();
+
+    let layout = TableLayoutState::new().order([2, 2, 9, 0]).width(1, 120.0);
+    assert_eq!(layout.column_order(3), vec![2, 0, 1]);
+    assert_eq!(layout.column_width(1), Some(120.0));
+    assert_eq!(layout.column_width(0), None);
+
+    let mut moved = layout.clone();
+    moved.move_column(1, 2, 3);
+    assert_eq!(moved.order, vec![1, 2, 0]);
+    moved.set_column_width(0, -4.0);
+    assert_eq!(moved.column_width(0), None);
+
+    assert_eq!(
+        table_state::resolve_layout("table-layout", None, layout.clone()),
+        layout
+    );
+    table_state::set_layout("table-layout", &moved);
+    assert_eq!(
+        table_state::resolve_layout("table-layout", None, TableLayoutState::default()),
+        moved
+    );
+
+    assert_eq!(table_state::resize_width(150.0, 100.0, 48.0), 50.0);
+    assert_eq!(table_state::resize_width(110.0, 100.0, 48.0), 48.0);
+    assert_eq!(table_state::auto_fit_width(10, 14.0, 12.0, 48.0), 108.0);
+    assert_eq!(table_state::auto_fit_width(0, 14.0, 12.0, 48.0), 48.0);
+
+    table_state::set_header_press("table-layout", Some(1));
+    assert_eq!(table_state::take_header_press("table-layout"), Some(1));
+    assert_eq!(table_state::take_header_press("table-layout"), None);
+}
+
+#[test]
+fn table_state_selection_supports_toggle_range_and_select_all() {
+    use super::table::TableSelectionMode;
+    use super::table_state::SelectionModifiers;
+    let _guard = guard();
+
+    let visible = ["a", "b", "c", "d"].map(String::from).to_vec();
+    let plain = SelectionModifiers::default();
+    let toggle = SelectionModifiers {
+        range: false,
+        toggle: true,
+    };
+    let range = SelectionModifiers {
+        range: true,
+        toggle: false,
+    };
+
+    let single =
+        table_state::select_row(&[], &visible, "b", None, TableSelectionMode::Single, plain);
+    assert_eq!(single, vec!["b"]);
+    assert!(
+        table_state::select_row(
+            &single,
+            &visible,
+            "b",
+            None,
+            TableSelectionMode::Single,
+            toggle
+        )
+        .is_empty()
+    );
+
+    let multi = table_state::select_row(
+        &single,
+        &visible,
+        "c",
+        None,
+        TableSelectionMode::Multi,
+        plain,
+    );
+    assert_eq!(multi, vec!["c"]);
+    let multi = table_state::select_row(
+        &multi,
+        &visible,
+        "a",
+        None,
+        TableSelectionMode::Multi,
+        toggle,
+    );
+    assert_eq!(multi, vec!["c", "a"]);
+    let ranged = table_state::select_row(
+        &multi,
+        &visible,
+        "d",
+        Some("b"),
+        TableSelectionMode::Multi,
+        range,
+    );
+    assert_eq!(ranged, vec!["b", "c", "d"]);
+
+    let checked = table_state::select_row(
+        &[],
+        &visible,
+        "a",
+        None,
+        TableSelectionMode::Checkbox,
+        plain,
+    );
+    assert_eq!(checked, vec!["a"]);
+    let checked = table_state::select_row(
+        &checked,
+        &visible,
+        "c",
+        Some("a"),
+        TableSelectionMode::Checkbox,
+        range,
+    );
+    assert_eq!(checked, vec!["a", "b", "c"]);
+
+    assert!(!table_state::all_selected(&checked, &visible));
+    let all = table_state::toggle_all(&checked, &visible);
+    assert!(table_state::all_selected(&all, &visible));
+    assert!(table_state::toggle_all(&all, &visible).is_empty());
+
+    assert_eq!(
+        table_state::resolve_selection("table-select", None, vec!["a".into()]),
+        vec!["a"]
+    );
+    table_state::set_selection("table-select", vec!["b".into(), "c".into()]);
+    assert_eq!(
+        table_state::resolve_selection("table-select", None, Vec::new()),
+        vec!["b", "c"]
+    );
+    table_state::set_pending_range("table-select", true);
+    assert!(table_state::take_pending_range("table-select"));
+    assert!(!table_state::take_pending_range("table-select"));
+}
+
+#[test]
+fn table_state_groups_rows_and_tracks_expansion() {
+    let _guard = guard();
+
+    let keys = ["b", "a", "b", "c", "a"].map(String::from);
+    assert_eq!(table_state::group_order(&keys), vec![0, 2, 1, 4, 3]);
+    assert!(table_state::group_order(&[]).is_empty());
+
+    assert_eq!(
+        table_state::collapsed_groups("table-group", vec!["a".into()]),
+        vec!["a"]
+    );
+    table_state::toggle_group("table-group", vec!["a".into()], "b");
+    assert_eq!(
+        table_state::collapsed_groups("table-group", Vec::new()),
+        vec!["a", "b"]
+    );
+    table_state::toggle_group("table-group", Vec::new(), "a");
+    assert_eq!(
+        table_state::collapsed_groups("table-group", Vec::new()),
+        vec!["b"]
+    );
+
+    let expanded = table_state::toggle_expanded_row(&[], "row-1");
+    assert_eq!(expanded, vec!["row-1"]);
+    assert!(table_state::toggle_expanded_row(&expanded, "row-1").is_empty());
+    assert_eq!(
+        table_state::resolve_expanded_rows("table-group", None, vec!["row-2".into()]),
+        vec!["row-2"]
+    );
+    table_state::set_expanded_rows("table-group", expanded);
+    assert_eq!(
+        table_state::resolve_expanded_rows("table-group", None, Vec::new()),
+        vec!["row-1"]
+    );
+}
+
 #[test]
 fn table_state_row_measurement_and_virtual_scroll_have_thresholds() {
     let _guard = guard();
@@ -379,6 +976,14 @@ fn table_state_row_measurement_and_virtual_scroll_have_thresholds() {
     ));
 }
 
+#[test]
+fn press_feedback_ripple_reaches_farthest_corner() {
+    let _guard = guard();
+    assert_eq!(press_feedback::ripple_radius(0.0, 0.0, 30.0, 40.0), 50.0);
+    assert_eq!(press_feedback::ripple_radius(30.0, 40.0, 30.0, 40.0), 50.0);
+    assert_eq!(press_feedback::ripple_radius(15.0, 20.0, 30.0, 40.0), 25.0);
+}
+
 #[test]
 fn tree_state_toggle_and_key_navigation_follow_expected_rules() {
     let _guard = guard();
@@ -410,42 +1015,309 @@ fn tree_state_toggle_and_key_navigation_follow_expected_rules() {
 }
 
 #[test]
-fn text_input_state_handles_selection_and_unicode_correctly() {
+fn tree_state_drop_targets_resolve_positions_and_reject_cycles() {
     let _guard = guard();
+    let nodes = demo_visible_nodes();
 
-    let mut state = text_input_state::InputState::new("ab中d", 10, 0, Some((3, 1)));
-    assert_eq!(state.len(), 4);
-    assert_eq!(state.selection, Some((1, 3)));
-    assert_eq!(state.selected_text(), "b中");
-
-    state.move_left(false);
-    assert_eq!(state.caret, 1);
-    assert!(state.selection.is_none());
-
-    state.set_selection_from_anchor(1, 3);
-    assert!(state.delete_backward());
-    assert_eq!(state.value, "ad");
-    assert_eq!(state.caret, 1);
+    assert_eq!(
+        tree_state::drop_position(2.0, 20.0),
+        TreeDropPosition::Before
+    );
+    assert_eq!(
+        tree_state::drop_position(10.0, 20.0),
+        TreeDropPosition::Inside
+    );
+    assert_eq!(
+        tree_state::drop_position(18.0, 20.0),
+        TreeDropPosition::After
+    );
 
-    assert!(state.insert_text("XYZ"));
-    assert_eq!(state.value, "aXYZd");
+    assert!(tree_state::is_descendant(&nodes, "root", "child-b"));
+    assert!(!tree_state::is_descendant(&nodes, "child-a", "root"));
     assert_eq!(
-        text_input_state::InputState::byte_index_at_char("a中b", 2),
-        "a中".len()
+        tree_state::drop_destination(&nodes, "root", "child-a", TreeDropPosition::Inside),
+        None
+    );
+    assert_eq!(
+        tree_state::drop_destination(&nodes, "child-a", "child-b", TreeDropPosition::After),
+        Some((Some("root".into()), 1))
+    );
+    assert_eq!(
+        tree_state::drop_destination(&nodes, "child-b", "child-a", TreeDropPosition::Before),
+        Some((Some("root".into()), 0))
+    );
+    assert_eq!(
+        tree_state::drop_destination(&nodes, "child-a", "root", TreeDropPosition::Inside),
+        Some((Some("root".into()), 1))
     );
 
-    assert!(state.clamp_to_max_length(Some(3)));
-    assert_eq!(state.value, "aXY");
+    assert!(tree_state::set_drop_hover(
+        "tree-drop",
+        Some(("root".into(), TreeDropPosition::After))
+    ));
+    assert!(!tree_state::set_drop_hover(
+        "tree-drop",
+        Some(("root".into(), TreeDropPosition::After))
+    ));
+    assert_eq!(
+        tree_state::drop_hover("tree-drop"),
+        Some(("root".into(), TreeDropPosition::After))
+    );
+    assert!(tree_state::set_drop_hover("tree-drop", None));
+    assert_eq!(tree_state::drop_hover("tree-drop"), None);
 }
 
 #[test]
-fn slider_axis_math_functions_are_stable() {
+fn tree_state_checks_cascade_and_report_indeterminate_parents() {
     let _guard = guard();
+    let children = [
+        ("root", vec!["a", "b"]),
+        ("a", vec!["a1", "a2"]),
+        ("b", vec![]),
+    ]
+    .into_iter()
+    .map(|(value, nested)| {
+        (
+            value.to_string(),
+            nested.into_iter().map(str::to_string).collect::<Vec<_>>(),
+        )
+    })
+    .collect::<std::collections::BTreeMap<_, _>>();
 
-    let normalized = slider_axis::normalize(0.0, 10.0, 2.0, 5.1);
-    assert_eq!(normalized, 6.0);
+    assert_eq!(
+        tree_state::leaf_descendants(&children, "root"),
+        vec!["a1", "a2", "b"]
+    );
 
-    let pair = slider_axis::normalize_pair(0.0, 10.0, 1.0, 9.2, 2.2);
+    let checked = tree_state::toggle_checked(&children, &[], "a");
+    assert_eq!(checked, vec!["a1", "a2"]);
+    let set = checked.iter().cloned().collect();
+    assert_eq!(
+        tree_state::check_state(&children, &set, "a"),
+        tree_state::TreeCheckState::Checked
+    );
+    assert_eq!(
+        tree_state::check_state(&children, &set, "root"),
+        tree_state::TreeCheckState::Indeterminate
+    );
+    assert_eq!(
+        tree_state::check_state(&children, &set, "b"),
+        tree_state::TreeCheckState::Unchecked
+    );
+
+    let all = tree_state::toggle_checked(&children, &checked, "root");
+    assert_eq!(all, vec!["a1", "a2", "b"]);
+    assert!(tree_state::toggle_checked(&children, &all, "root").is_empty());
+
+    assert!(tree_state::apply_checked(
+        "tree-check",
+        false,
+        vec!["b".into()]
+    ));
+    assert!(!tree_state::apply_checked("tree-check", true, Vec::new()));
+    assert_eq!(
+        tree_state::resolve_checked("tree-check", false, Vec::new(), Vec::new()),
+        vec!["b"]
+    );
+}
+
+#[test]
+fn tree_state_lazy_children_load_once_per_expanded_node() {
+    let _guard = guard();
+    let mut nodes = demo_visible_nodes();
+    nodes[1].has_children = true;
+    let expanded = ["root".to_string(), "child-a".to_string()]
+        .into_iter()
+        .collect::<std::collections::BTreeSet<_>>();
+
+    let loaded = tree_state::loaded_children("tree-lazy");
+    assert_eq!(
+        tree_state::pending_loads(&nodes, &expanded, &[], &loaded),
+        vec!["child-a".to_string()]
+    );
+
+    tree_state::set_node_loading("tree-lazy", "child-a", true);
+    let loading = tree_state::loading_values("tree-lazy");
+    assert!(tree_state::pending_loads(&nodes, &expanded, &loading, &loaded).is_empty());
+
+    tree_state::store_loaded_children("tree-lazy", "child-a", vec![TreeNode::new("grandchild")]);
+    tree_state::set_node_loading("tree-lazy", "child-a", false);
+    let loaded = tree_state::loaded_children("tree-lazy");
+    assert_eq!(loaded["child-a"], vec![TreeNode::new("grandchild")]);
+    assert!(tree_state::loading_values("tree-lazy").is_empty());
+    assert!(tree_state::pending_loads(&nodes, &expanded, &[], &loaded).is_empty());
+}
+
+#[test]
+fn text_input_state_handles_selection_and_unicode_correctly() {
+    let _guard = guard();
+
+    let mut state = text_input_state::InputState::new("ab中d", 10, 0, Some((3, 1)));
+    assert_eq!(state.len(), 4);
+    assert_eq!(state.selection, Some((1, 3)));
+    assert_eq!(state.selected_text(), "b中");
+
+    state.move_left(false);
+    assert_eq!(state.caret, 1);
+    assert!(state.selection.is_none());
+
+    state.set_selection_from_anchor(1, 3);
+    assert!(state.delete_backward());
+    assert_eq!(state.value, "ad");
+    assert_eq!(state.caret, 1);
+
+    assert!(state.insert_text("XYZ"));
+    assert_eq!(state.value, "aXYZd");
+    assert_eq!(
+        text_input_state::InputState::byte_index_at_char("a中b", 2),
+        "a中".len()
+    );
+
+    assert!(state.clamp_to_max_length(Some(3)));
+    assert_eq!(state.value, "aXY");
+}
+
+#[test]
+fn composition_tracks_marked_range_and_normalizes_ime_digits() {
+    let _guard = guard();
+
+    assert!(!composition::is_composing("ime"));
+    composition::set_marked_range("ime", Some((2, 5)));
+    assert!(composition::is_composing("ime"));
+    assert_eq!(composition::marked_range("ime", 4), Some((2, 4)));
+    assert_eq!(composition::marked_range("ime", 2), None);
+
+    assert_eq!(composition::line_segment((2, 5), 0, 3), Some((2, 3)));
+    assert_eq!(composition::line_segment((2, 5), 3, 8), Some((0, 2)));
+    assert_eq!(composition::line_segment((2, 5), 5, 8), None);
+
+    composition::commit("ime");
+    assert!(!composition::is_composing("ime"));
+
+    assert_eq!(composition::ascii_digit('7'), Some('7'));
+    assert_eq!(composition::ascii_digit('\u{FF13}'), Some('3'));
+    assert_eq!(composition::ascii_digit('中'), None);
+}
+
+#[test]
+fn text_selection_orders_ranges_selects_words_and_splits_runs() {
+    let _guard = guard();
+
+    assert_eq!(text_selection::selection_range("copy", 10), None);
+    text_selection::set_selection("copy", 7, 2);
+    assert_eq!(text_selection::selection_range("copy", 10), Some(2..7));
+    assert_eq!(text_selection::selection_range("copy", 5), Some(2..5));
+    text_selection::set_selection("copy", 4, 4);
+    assert_eq!(text_selection::selection_range("copy", 10), None);
+    text_selection::set_selection("copy", 0, 3);
+    text_selection::clear_selection("copy");
+    assert_eq!(text_selection::selection_range("copy", 10), None);
+
+    let text = "hello, wide_world  ok";
+    assert_eq!(text_selection::word_range(text, 2), 0..5);
+    assert_eq!(text_selection::word_range(text, 5), 5..6);
+    assert_eq!(text_selection::word_range(text, 9), 7..17);
+    assert_eq!(text_selection::word_range(text, 18), 17..19);
+    assert_eq!(text_selection::word_range(text, text.len()), 19..21);
+    assert_eq!(text_selection::word_range("", 0), 0..0);
+    assert_eq!(text_selection::word_range("日本 語", 1), 0..6);
+
+    let run = |len| gpui::TextRun {
+        len,
+        font: gpui::font("Inter"),
+        color: gpui::black(),
+        background_color: None,
+        underline: None,
+        strikethrough: None,
+    };
+    let selection = gpui::white();
+    let runs = text_selection::highlight_runs(vec![run(4), run(6)], 2..7, selection);
+    let lens = runs.iter().map(|run| run.len).collect::<Vec<_>>();
+    assert_eq!(lens, vec![2, 2, 3, 3]);
+    let selected = runs
+        .iter()
+        .map(|run| run.background_color == Some(selection))
+        .collect::<Vec<_>>();
+    assert_eq!(selected, vec![false, true, true, false]);
+}
+
+#[test]
+fn find_scope_counts_matches_wraps_navigation_and_layers_highlights() {
+    let _guard = guard();
+
+    assert_eq!(
+        text_selection::find_ranges("Find, find, FIND", "find", false),
+        vec![0..4, 6..10, 12..16]
+    );
+    assert_eq!(
+        text_selection::find_ranges("Find, find", "find", true),
+        vec![6..10]
+    );
+    assert_eq!(
+        text_selection::find_ranges("aaaa", "aa", false),
+        vec![0..2, 2..4]
+    );
+    assert_eq!(
+        text_selection::find_ranges("日本 find", "FIND", false),
+        vec![7..11]
+    );
+    assert!(text_selection::find_ranges("text", "", false).is_empty());
+
+    let scope = text_selection::FindScope::new();
+    scope.register("intro", "alpha beta alpha");
+    scope.register("body", "gamma alpha");
+    scope.set_query("alpha");
+    assert_eq!(scope.match_count(), 3);
+    assert_eq!(scope.active_index(), Some(0));
+    assert_eq!(scope.select_next().map(|found| found.range), Some(11..16));
+    assert_eq!(
+        scope.select_next().map(|found| found.source),
+        Some("body".into())
+    );
+    assert_eq!(scope.select_next().map(|found| found.range), Some(0..5));
+    assert_eq!(
+        scope.select_previous().map(|found| found.range),
+        Some(6..11)
+    );
+
+    assert!(scope.highlights_for("body").is_empty());
+    scope.open();
+    assert_eq!(scope.highlights_for("body"), vec![(6..11, true)]);
+    assert_eq!(
+        scope.highlights_for("intro"),
+        vec![(0..5, false), (11..16, false)]
+    );
+
+    scope.register("body", "no match");
+    assert_eq!(scope.match_count(), 2);
+    assert_eq!(scope.active_index(), Some(1));
+    scope.set_query("omega");
+    assert_eq!(scope.active_index(), None);
+    assert_eq!(scope.select_next(), None);
+    assert!(scope.unregister("intro"));
+    assert!(!scope.unregister("intro"));
+    scope.close();
+    assert!(!scope.is_open());
+    assert_eq!(scope.query(), "omega");
+
+    let matched = gpui::white();
+    let selected = gpui::black();
+    let layers =
+        text_selection::layer_backgrounds(&[(0..4, matched), (6..10, matched), (2..8, selected)]);
+    assert_eq!(
+        layers,
+        vec![(0..2, matched), (2..8, selected), (8..10, matched)]
+    );
+}
+
+#[test]
+fn slider_axis_math_functions_are_stable() {
+    let _guard = guard();
+
+    let normalized = slider_axis::normalize(0.0, 10.0, 2.0, 5.1);
+    assert_eq!(normalized, 6.0);
+
+    let pair = slider_axis::normalize_pair(0.0, 10.0, 1.0, 9.2, 2.2);
     assert_eq!(pair, (2.0, 9.0));
 
     let ratio = slider_axis::ratio(0.0, 100.0, 25.0);
@@ -479,3 +1351,1698 @@ fn slider_axis_geometry_store_and_restore_round_trips() {
     assert_eq!(geom.width, 1.0);
     assert_eq!(geom.height, 1.0);
 }
+
+#[test]
+fn console_state_records_history_and_skips_duplicates() {
+    let _guard = guard();
+
+    let history = console_state::resolve_history("console", None);
+    let history = console_state::record_entry("console", false, history, "ls", 2);
+    let history = console_state::record_entry("console", false, history, "ls", 2);
+    let history = console_state::record_entry("console", false, history, "  ", 2);
+    assert_eq!(history, vec!["ls"]);
+
+    let history = console_state::record_entry("console", false, history, "pwd", 2);
+    let history = console_state::record_entry("console", false, history, "whoami", 2);
+    assert_eq!(history, vec!["pwd", "whoami"]);
+    assert_eq!(
+        console_state::resolve_history("console", None),
+        vec!["pwd", "whoami"]
+    );
+}
+
+#[test]
+fn console_state_history_navigation_restores_draft() {
+    let _guard = guard();
+
+    let history = vec!["first".to_string(), "second".to_string()];
+    assert_eq!(
+        console_state::step_back("console-nav", &history, "draft"),
+        Some("second".into())
+    );
+    assert_eq!(
+        console_state::step_back("console-nav", &history, "second"),
+        Some("first".into())
+    );
+    assert_eq!(
+        console_state::step_back("console-nav", &history, "first"),
+        None
+    );
+    assert_eq!(
+        console_state::step_forward("console-nav", &history),
+        Some("second".into())
+    );
+    assert_eq!(
+        console_state::step_forward("console-nav", &history),
+        Some("draft".into())
+    );
+    assert_eq!(console_state::step_forward("console-nav", &history), None);
+}
+
+#[test]
+fn console_state_caret_line_checks() {
+    assert!(console_state::caret_on_first_line("one\ntwo", 2));
+    assert!(!console_state::caret_on_first_line("one\ntwo", 5));
+    assert!(console_state::caret_on_last_line("one\ntwo", 5));
+    assert!(!console_state::caret_on_last_line("one\ntwo", 2));
+}
+
+#[test]
+fn minimap_state_maps_scroll_to_viewport_window() {
+    let window = minimap_state::viewport_window(300.0, 100.0, 300.0);
+    assert!((window.height - 0.25).abs() < 0.0001);
+    assert!((window.top - 0.75).abs() < 0.0001);
+
+    let unscrollable = minimap_state::viewport_window(0.0, 100.0, 0.0);
+    assert_eq!(unscrollable.height, 1.0);
+    assert_eq!(unscrollable.top, 0.0);
+
+    assert_eq!(minimap_state::scroll_for_ratio(0.5, 100.0, 300.0), 150.0);
+    assert_eq!(minimap_state::scroll_for_ratio(0.0, 100.0, 300.0), 0.0);
+    assert_eq!(minimap_state::scroll_for_ratio(1.0, 100.0, 300.0), 300.0);
+}
+
+#[test]
+fn minimap_state_strip_geometry_and_scroll_sync() {
+    let _guard = guard();
+
+    minimap_state::store_strip("minimap", 20.0, 200.0);
+    assert!((minimap_state::strip_ratio("minimap", 70.0) - 0.25).abs() < 0.0001);
+    assert_eq!(minimap_state::strip_ratio("minimap", 0.0), 0.0);
+
+    assert!(!minimap_state::sync_scroll("minimap", 0.0));
+    assert!(!minimap_state::sync_scroll("minimap", 0.2));
+    assert!(minimap_state::sync_scroll("minimap", 40.0));
+}
+
+#[test]
+fn split_pane_state_clamps_to_min_sizes_and_steps_by_keyboard() {
+    let _guard = guard();
+
+    assert_eq!(split_pane_state::clamp_unit(f32::NAN), 0.5);
+    assert_eq!(split_pane_state::clamp_ratio(0.7, None, 100.0, 100.0), 0.7);
+    assert_eq!(
+        split_pane_state::clamp_ratio(0.05, Some(1000.0), 100.0, 200.0),
+        0.1
+    );
+    assert_eq!(
+        split_pane_state::clamp_ratio(0.95, Some(1000.0), 100.0, 200.0),
+        0.8
+    );
+    assert_eq!(
+        split_pane_state::clamp_ratio(0.5, Some(200.0), 300.0, 100.0),
+        0.75
+    );
+    assert_eq!(split_pane_state::ratio_from_local(250.0, 1000.0), 0.25);
+    assert_eq!(split_pane_state::ratio_from_local(-10.0, 1000.0), 0.0);
+
+    assert_eq!(
+        split_pane_state::keyboard_ratio(0.5, "right", 100.0, 1000.0, false),
+        Some(0.6)
+    );
+    assert_eq!(
+        split_pane_state::keyboard_ratio(0.5, "down", 100.0, 1000.0, false),
+        None
+    );
+    assert_eq!(
+        split_pane_state::keyboard_ratio(0.5, "up", 100.0, 1000.0, true),
+        Some(0.4)
+    );
+    assert_eq!(
+        split_pane_state::keyboard_ratio(0.5, "end", 100.0, 1000.0, true),
+        Some(1.0)
+    );
+
+    assert!(split_pane_state::store_extent("split", 800.0));
+    assert!(!split_pane_state::store_extent("split", 800.3));
+    assert_eq!(split_pane_state::stored_extent("split"), Some(800.0));
+}
+
+#[test]
+fn history_timeline_state_maps_cursor_to_undo_and_redo_steps() {
+    use history_timeline_state::StepRole;
+
+    assert_eq!(history_timeline_state::resolve_current(0, Some(2)), None);
+    assert_eq!(history_timeline_state::resolve_current(4, None), Some(3));
+    assert_eq!(history_timeline_state::resolve_current(4, Some(1)), Some(1));
+    assert_eq!(history_timeline_state::resolve_current(4, Some(9)), Some(3));
+
+    let roles = (0..4)
+        .map(|index| history_timeline_state::step_role(index, 1))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        roles,
+        vec![
+            StepRole::Undo,
+            StepRole::Current,
+            StepRole::Redo,
+            StepRole::Redo
+        ]
+    );
+}
+
+#[test]
+fn compare_slider_state_maps_pointer_and_keys_to_percent() {
+    let _guard = guard();
+
+    assert_eq!(compare_slider_state::percent_from_local(50.0, 200.0), 25.0);
+    assert_eq!(compare_slider_state::percent_from_local(-10.0, 200.0), 0.0);
+    assert_eq!(
+        compare_slider_state::percent_from_local(400.0, 200.0),
+        100.0
+    );
+    assert_eq!(compare_slider_state::clamp_percent(f32::NAN), 0.0);
+
+    assert_eq!(
+        compare_slider_state::keyboard_percent(50.0, "left", 1.0),
+        Some(49.0)
+    );
+    assert_eq!(
+        compare_slider_state::keyboard_percent(50.0, "up", 2.0),
+        Some(52.0)
+    );
+    assert_eq!(
+        compare_slider_state::keyboard_percent(95.0, "pageup", 1.0),
+        Some(100.0)
+    );
+    assert_eq!(
+        compare_slider_state::keyboard_percent(50.0, "home", 1.0),
+        Some(0.0)
+    );
+    assert_eq!(
+        compare_slider_state::keyboard_percent(50.0, "end", 1.0),
+        Some(100.0)
+    );
+    assert_eq!(compare_slider_state::keyboard_percent(50.0, "a", 1.0), None);
+
+    assert!(compare_slider_state::store_frame("compare", 20.0, 400.0));
+    assert!(!compare_slider_state::store_frame("compare", 20.0, 400.2));
+    assert_eq!(compare_slider_state::stored_width("compare"), Some(400.0));
+    assert_eq!(compare_slider_state::frame_percent("compare", 120.0), 25.0);
+}
+
+#[test]
+fn zoom_pane_state_zooms_around_anchor_and_fits_content() {
+    let _guard = guard();
+
+    let view = zoom_pane_state::ZoomView::default().zoom_at(2.0, 100.0, 50.0);
+    assert_eq!(view.offset_x, -100.0);
+    assert_eq!(view.offset_y, -50.0);
+    assert_eq!(view.to_content(100.0, 50.0), (100.0, 50.0));
+    assert_eq!(view.to_viewport(100.0, 50.0), (100.0, 50.0));
+
+    let fitted = zoom_pane_state::ZoomView::fit((400.0, 200.0), (220.0, 220.0), 10.0, 0.1, 8.0);
+    assert_eq!(fitted.zoom, 0.5);
+    assert_eq!((fitted.offset_x, fitted.offset_y), (10.0, 60.0));
+
+    assert_eq!(zoom_pane_state::clamp_zoom(20.0, 0.1, 8.0), 8.0);
+    assert_eq!(zoom_pane_state::clamp_zoom(f32::NAN, 0.1, 8.0), 1.0);
+    assert!(zoom_pane_state::wheel_zoom_factor(-40.0) < 1.0);
+
+    let lines = zoom_pane_state::grid_lines(0.0, 1.0, 10.0, 50.0);
+    assert_eq!(lines.len(), 6);
+    assert!(lines[0].1 && !lines[1].1 && lines[5].1);
+
+    zoom_pane_state::store_view("zoom", fitted);
+    assert_eq!(
+        zoom_pane_state::load_view("zoom", zoom_pane_state::ZoomView::default()),
+        fitted
+    );
+    zoom_pane_state::store_viewport("zoom", (20.0, 30.0), (200.0, 100.0));
+    assert_eq!(
+        zoom_pane_state::viewport_local("zoom", 25.0, 40.0),
+        (5.0, 10.0)
+    );
+    zoom_pane_state::store_drag_point("zoom", 10.0, 10.0);
+    assert_eq!(zoom_pane_state::drag_delta("zoom", 14.0, 7.0), (4.0, -3.0));
+    assert_eq!(zoom_pane_state::drag_delta("zoom", 14.0, 7.0), (0.0, 0.0));
+}
+
+#[test]
+fn graph_model_connects_ports_and_tracks_node_geometry() {
+    let _guard = guard();
+
+    let mut model = graph_model::GraphModel::new()
+        .node(
+            graph_model::GraphNode::new("source")
+                .position(10.0, 20.0)
+                .width(120.0)
+                .output(graph_model::GraphPort::new("out")),
+        )
+        .node(
+            graph_model::GraphNode::new("sink")
+                .position(300.0, 40.0)
+                .input(graph_model::GraphPort::new("a"))
+                .input(graph_model::GraphPort::new("b").label("B")),
+        );
+    let from = graph_model::GraphPortRef::new("source", "out");
+    let to = graph_model::GraphPortRef::new("sink", "b");
+
+    assert!(model.connect(from.clone(), to.clone()).is_ok());
+    assert_eq!(
+        model.connect(from.clone(), to.clone()),
+        Err(graph_model::GraphConnectError::Duplicate)
+    );
+    assert_eq!(
+        model.connect(to.clone(), from.clone()),
+        Err(graph_model::GraphConnectError::UnknownPort)
+    );
+    assert_eq!(model.edges.len(), 1);
+
+    let sink = model.find_node("sink").expect("sink node");
+    assert_eq!(graph_canvas_state::node_height(sink), 28.0 + 44.0 + 12.0);
+    let (start, end) =
+        graph_canvas_state::edge_anchors(&model, &model.edges[0]).expect("edge anchors");
+    assert_eq!(start, (130.0, 20.0 + 28.0 + 6.0 + 11.0));
+    assert_eq!(end, (300.0, 40.0 + 28.0 + 6.0 + 22.0 + 11.0));
+    let (control_a, control_b) = graph_canvas_state::bezier_controls(start, end);
+    assert_eq!(control_a.0, 215.0);
+    assert_eq!(control_b.0, 215.0);
+    assert_eq!(graph_canvas_state::graph_extent(&model), (500.0, 164.0));
+
+    graph_canvas_state::store_node_position("graph", "sink", 320.0, 60.0);
+    let resolved = graph_canvas_state::resolved_model("graph", &model);
+    assert_eq!(
+        resolved.find_node("sink").map(|node| (node.x, node.y)),
+        Some((320.0, 60.0))
+    );
+
+    let selection = graph_canvas_state::toggle_selection(&[], "source", false);
+    let selection = graph_canvas_state::toggle_selection(&selection, "sink", true);
+    assert_eq!(selection, vec!["source".to_string(), "sink".to_string()]);
+    let selection = graph_canvas_state::toggle_selection(&selection, "source", true);
+    assert_eq!(selection, vec!["sink".to_string()]);
+
+    assert!(model.remove_node("source").is_some());
+    assert!(model.edges.is_empty());
+}
+
+#[test]
+fn color_picker_state_converts_hex_and_hsv() {
+    use crate::tokens::{PALETTE_KEYS, PaletteCatalog, PaletteKey};
+    use color_picker_state::{ColorSurface, Hsva};
+    use gpui::{Hsla, Rgba};
+
+    let _guard = guard();
+
+    let blue = color_picker_state::parse_hex("#228be6").expect("valid hex");
+    assert_eq!(color_picker_state::format_hex(blue, false), "#228BE6");
+    assert_eq!(color_picker_state::format_hex(blue, true), "#228BE6FF");
+    assert!(color_picker_state::parse_hex("abc").is_some());
+    assert_eq!(
+        color_picker_state::parse_hex("#11223380")
+            .map(|color| color_picker_state::format_hex(color, true)),
+        Some("#11223380".to_string())
+    );
+    assert!(color_picker_state::parse_hex("#12345").is_none());
+    assert!(color_picker_state::parse_hex("#zzzzzz").is_none());
+
+    let hsva = Hsva::from_hsla(blue);
+    assert!(color_picker_state::same_color(hsva.to_hsla(), blue));
+    let red = Hsva::from_hsla(gpui::hsla(0.0, 1.0, 0.5, 1.0));
+    assert!((red.saturation - 1.0).abs() < 1e-4);
+    assert!((red.value - 1.0).abs() < 1e-4);
+
+    let moved = red.apply_pointer(ColorSurface::Area, 0.5, 0.25);
+    assert!((moved.saturation - 0.5).abs() < 1e-4);
+    assert!((moved.value - 0.75).abs() < 1e-4);
+    assert!((red.apply_pointer(ColorSurface::Alpha, 1.4, 0.0).alpha - 1.0).abs() < 1e-4);
+    assert!(red.apply_pointer(ColorSurface::Hue, 1.0, 0.0).hue < 1.0);
+
+    let swatches = color_picker_state::palette_swatches(6);
+    assert!(swatches.len() >= PALETTE_KEYS.len());
+    assert_eq!(
+        swatches[2],
+        Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6])
+            .map(Hsla::from)
+            .expect("valid hex")
+    );
+
+    let gray = Hsva {
+        hue: 0.3,
+        saturation: 0.0,
+        value: 0.5,
+        alpha: 1.0,
+    };
+    color_picker_state::store_hsva("cp", gray);
+    assert_eq!(
+        color_picker_state::resolve_hsva("cp", Some(gray.to_hsla()), blue).hue,
+        0.3
+    );
+    assert!(color_picker_state::same_color(
+        color_picker_state::resolve_hsva("cp", Some(blue), blue).to_hsla(),
+        blue
+    ));
+
+    color_picker_state::store_frame("cp", ColorSurface::Area, (10.0, 20.0), (100.0, 50.0));
+    assert_eq!(
+        color_picker_state::frame_point("cp", ColorSurface::Area, 60.0, 45.0),
+        (0.5, 0.5)
+    );
+    assert_eq!(
+        color_picker_state::frame_point("cp", ColorSurface::Area, -10.0, 90.0),
+        (0.0, 1.0)
+    );
+}
+
+#[test]
+fn file_input_state_filters_and_merges_files() {
+    use std::path::PathBuf;
+
+    let _guard = guard();
+
+    let accept = vec![
+        file_input_state::normalize_extension(".PNG"),
+        file_input_state::normalize_extension("*.jpg"),
+    ];
+    assert_eq!(accept, vec!["png".to_string(), "jpg".to_string()]);
+    assert!(file_input_state::accepts(
+        &PathBuf::from("a/photo.Png"),
+        &accept
+    ));
+    assert!(!file_input_state::accepts(
+        &PathBuf::from("notes.txt"),
+        &accept
+    ));
+    assert!(file_input_state::accepts(&PathBuf::from("notes.txt"), &[]));
+    assert_eq!(
+        file_input_state::accept_hint(&accept).as_deref(),
+        Some(".png, .jpg")
+    );
+
+    let current = vec![PathBuf::from("a.png")];
+    let merged = file_input_state::merge_files(
+        &current,
+        vec![
+            PathBuf::from("a.png"),
+            PathBuf::from("b.jpg"),
+            PathBuf::from("c.txt"),
+            PathBuf::from("d.png"),
+        ],
+        &accept,
+        true,
+        Some(2),
+    );
+    assert_eq!(
+        merged.files,
+        vec![PathBuf::from("a.png"), PathBuf::from("b.jpg")]
+    );
+    assert_eq!(
+        merged.rejected,
+        vec![PathBuf::from("c.txt"), PathBuf::from("d.png")]
+    );
+
+    let single = file_input_state::merge_files(
+        &current,
+        vec![PathBuf::from("b.jpg"), PathBuf::from("d.png")],
+        &accept,
+        false,
+        None,
+    );
+    assert_eq!(single.files, vec![PathBuf::from("d.png")]);
+    assert_eq!(
+        file_input_state::remove_file(&merged.files, 0),
+        vec![PathBuf::from("b.jpg")]
+    );
+
+    assert_eq!(file_input_state::format_file_size(512), "512 B");
+    assert_eq!(file_input_state::format_file_size(1536), "1.5 KB");
+    assert_eq!(
+        file_input_state::format_file_size(5 * 1024 * 1024),
+        "5.0 MB"
+    );
+    assert_eq!(
+        file_input_state::file_name(&PathBuf::from("dir/report.pdf")),
+        "report.pdf"
+    );
+
+    file_input_state::store_files("fi", &merged.files);
+    assert_eq!(
+        file_input_state::resolve_files("fi", None, &[]),
+        merged.files
+    );
+    assert_eq!(
+        file_input_state::resolve_files("fi", Some(&current), &[]),
+        current
+    );
+}
+
+#[test]
+fn date_picker_state_calendar_math_and_keyboard_moves() {
+    let _guard = guard();
+    let date = |year, month, day| {
+        date_picker_state::CalendarDate::new(year, month, day).expect("valid date")
+    };
+
+    assert_eq!(
+        date_picker_state::CalendarDate::from_days(0),
+        date(1970, 1, 1)
+    );
+    assert_eq!(date(1970, 1, 1).weekday(), 4);
+    assert_eq!(date(2024, 3, 1).to_days() - date(2024, 2, 1).to_days(), 29);
+    assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+    assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+    assert!(date_picker_state::CalendarDate::new(2023, 2, 29).is_none());
+    assert_eq!(date(2024, 7, 4).to_string(), "2024-07-04");
+    assert_eq!("2024-07-04".parse(), Ok(date(2024, 7, 4)));
+
+    let grid = date_picker_state::month_grid(date(2024, 1, 15), 0);
+    assert_eq!(grid.len(), 42);
+    assert_eq!(grid[0], date(2023, 12, 31));
+    let grid = date_picker_state::month_grid(date(2024, 1, 15), 1);
+    assert_eq!(grid[0], date(2024, 1, 1));
+
+    let focused = date(2024, 1, 31);
+    assert_eq!(
+        date_picker_state::keyboard_date(focused, "right", false, 0),
+        Some(date(2024, 2, 1))
+    );
+    assert_eq!(
+        date_picker_state::keyboard_date(focused, "up", false, 0),
+        Some(date(2024, 1, 24))
+    );
+    assert_eq!(
+        date_picker_state::keyboard_date(focused, "pagedown", false, 0),
+        Some(date(2024, 2, 29))
+    );
+    assert_eq!(
+        date_picker_state::keyboard_date(focused, "pageup", true, 0),
+        Some(date(2023, 1, 31))
+    );
+    assert_eq!(
+        date_picker_state::keyboard_date(focused, "home", false, 1),
+        Some(date(2024, 1, 29))
+    );
+    assert_eq!(
+        date_picker_state::keyboard_date(focused, "end", false, 1),
+        Some(date(2024, 2, 4))
+    );
+    assert_eq!(
+        date_picker_state::keyboard_date(focused, "x", false, 0),
+        None
+    );
+
+    let (min, max) = (Some(date(2024, 1, 10)), Some(date(2024, 1, 20)));
+    assert_eq!(
+        date_picker_state::clamp_date(focused, min, max),
+        date(2024, 1, 20)
+    );
+    assert!(!date_picker_state::is_within(date(2024, 1, 9), min, max));
+    assert!(date_picker_state::is_within(date(2024, 1, 10), min, None));
+
+    assert_eq!(date_picker_state::focused_date("dp", focused), focused);
+    date_picker_state::set_focused_date("dp", Some(date(2024, 5, 6)));
+    assert_eq!(
+        date_picker_state::focused_date("dp", focused),
+        date(2024, 5, 6)
+    );
+}
+
+#[test]
+fn time_picker_state_parses_steps_and_types_columns() {
+    use time_picker_state::{ClockTime, TimeColumn, TimeKeyOutcome};
+
+    let _guard = guard();
+    let time = |hour, minute, second| ClockTime::new(hour, minute, second).expect("valid time");
+
+    assert!(ClockTime::new(24, 0, 0).is_none());
+    assert_eq!(time(9, 5, 7).to_string(), "09:05:07");
+    assert_eq!("09:05".parse(), Ok(time(9, 5, 0)));
+    assert_eq!("23:59:59".parse(), Ok(time(23, 59, 59)));
+    assert!("12:60".parse::<ClockTime>().is_err());
+    assert_eq!(ClockTime::from_seconds(86_400 + 61), time(0, 1, 1));
+    assert_eq!(time(1, 1, 1).to_seconds(), 3_661);
+    assert_eq!(
+        time_picker_state::format_clock(time(7, 30, 15), false),
+        "07:30"
+    );
+
+    let stamp: time_picker_state::CalendarDateTime =
+        "2024-02-29T08:15:00".parse().expect("valid timestamp");
+    assert_eq!(stamp.to_string(), "2024-02-29 08:15:00");
+
+    assert_eq!(time_picker_state::step_column(0, -1, 24, 1), 23);
+    assert_eq!(time_picker_state::step_column(58, 1, 60, 1), 59);
+    assert_eq!(time_picker_state::step_column(7, 1, 60, 5), 10);
+    assert_eq!(time_picker_state::step_column(55, 1, 60, 5), 0);
+    assert_eq!(
+        time_picker_state::wheel_values(0, 24, 1, 2),
+        vec![22, 23, 0, 1, 2]
+    );
+
+    assert_eq!(time_picker_state::type_digit("", '2', 24), Some((2, false)));
+    assert_eq!(
+        time_picker_state::type_digit("2", '3', 24),
+        Some((23, true))
+    );
+    assert_eq!(time_picker_state::type_digit("2", '5', 24), Some((5, true)));
+    assert_eq!(time_picker_state::type_digit("", '7', 60), Some((7, true)));
+    assert_eq!(time_picker_state::type_digit("", 'x', 60), None);
+
+    let columns = time_picker_state::time_columns(true);
+    let value = time(10, 20, 30);
+    assert_eq!(
+        time_picker_state::time_key("tp", columns, [1, 5, 1], value, "down"),
+        TimeKeyOutcome::Changed(time(11, 20, 30))
+    );
+    assert_eq!(
+        time_picker_state::time_key("tp", columns, [1, 5, 1], value, "right"),
+        TimeKeyOutcome::Moved
+    );
+    assert_eq!(
+        time_picker_state::active_column("tp", columns),
+        TimeColumn::Minute
+    );
+    assert_eq!(
+        time_picker_state::time_key("tp", columns, [1, 5, 1], value, "up"),
+        TimeKeyOutcome::Changed(time(10, 15, 30))
+    );
+    assert_eq!(
+        time_picker_state::time_key("tp", columns, [1, 5, 1], value, "4"),
+        TimeKeyOutcome::Changed(time(10, 4, 30))
+    );
+    assert_eq!(
+        time_picker_state::time_key("tp", columns, [1, 5, 1], time(10, 4, 30), "5"),
+        TimeKeyOutcome::Changed(time(10, 45, 30))
+    );
+    assert_eq!(
+        time_picker_state::active_column("tp", columns),
+        TimeColumn::Second
+    );
+    assert_eq!(
+        time_picker_state::time_key("tp", columns, [1, 5, 1], value, "tab"),
+        TimeKeyOutcome::Ignored
+    );
+
+    assert_eq!(
+        time_picker_state::consume_wheel("tp", TimeColumn::Hour, -20.0, 28.0),
+        0
+    );
+    assert_eq!(
+        time_picker_state::consume_wheel("tp", TimeColumn::Hour, -20.0, 28.0),
+        1
+    );
+}
+
+#[test]
+fn ring_progress_state_clamps_sections_and_keeps_spinner_on_the_track() {
+    let clamped = progress::resolve_sections(
+        0.0,
+        &[
+            ProgressSection::new(60.0),
+            ProgressSection::new(30.0),
+            ProgressSection::new(40.0),
+            ProgressSection::new(10.0),
+        ],
+    );
+    assert_eq!(
+        clamped
+            .iter()
+            .map(|section| section.value)
+            .collect::<Vec<_>>(),
+        vec![60.0, 30.0, 10.0]
+    );
+    assert_eq!(
+        progress::resolve_sections(140.0, &[])
+            .iter()
+            .map(|section| section.value)
+            .collect::<Vec<_>>(),
+        vec![100.0]
+    );
+    assert_eq!(
+        progress::resolve_sections(0.0, &[ProgressSection::new(-5.0)])[0].value,
+        0.0
+    );
+
+    assert_eq!(ring_progress_state::value_label(42.4, &[]), "42%");
+    assert_eq!(ring_progress_state::value_label(-3.0, &[]), "0%");
+    assert_eq!(
+        ring_progress_state::value_label(
+            0.0,
+            &[ProgressSection::new(70.0), ProgressSection::new(45.0)]
+        ),
+        "100%"
+    );
+
+    let full = ring_progress_state::spinner_arc(1.0, 0.9);
+    assert_eq!(full, (0.9, ring_progress_state::SPINNER_SPAN));
+
+    let sweep = 0.75;
+    for step in 0..=20 {
+        let (from, span) = ring_progress_state::spinner_arc(sweep, step as f32 / 20.0);
+        assert!(from >= 0.0);
+        assert!(from + span <= sweep + f32::EPSILON);
+    }
+    assert_eq!(ring_progress_state::spinner_arc(sweep, 0.0).1, 0.0);
+    assert_eq!(ring_progress_state::spinner_arc(sweep, 1.0).1, 0.0);
+    let (_, middle) = ring_progress_state::spinner_arc(sweep, 0.5);
+    assert!((middle - ring_progress_state::SPINNER_SPAN * sweep).abs() < 1e-6);
+}
+
+#[test]
+fn rulers_state_snaps_guides_and_maps_marks() {
+    let _guard = guard();
+
+    assert_eq!(
+        rulers_state::snap_position(47.0, Some(10.0), &[], 4.0),
+        (50.0, true)
+    );
+    assert_eq!(
+        rulers_state::snap_position(47.0, Some(10.0), &[46.0], 4.0),
+        (46.0, true)
+    );
+    assert_eq!(
+        rulers_state::snap_position(44.0, Some(10.0), &[], 2.0),
+        (44.0, false)
+    );
+
+    let view = zoom_pane_state::ZoomView {
+        zoom: 2.0,
+        offset_x: 20.0,
+        offset_y: -10.0,
+    };
+    let marks = rulers_state::ruler_marks(view.offset_x, view.zoom, 10.0, 100.0);
+    assert_eq!(marks[0].offset, 0.0);
+    assert_eq!(marks[0].value, -10.0);
+    assert!(marks.iter().any(|mark| mark.major && mark.value == 0.0));
+    assert_eq!(rulers_state::mark_label(-0.2), "0");
+    assert_eq!(rulers_state::mark_label(150.0), "150");
+
+    let guide = rulers_state::RulerGuide::horizontal(30.0);
+    assert_eq!(rulers_state::guide_offset(guide, view), 50.0);
+    assert_eq!(
+        rulers_state::pointer_position(rulers_state::GuideAxis::Horizontal, view, (0.0, 50.0)),
+        30.0
+    );
+    assert!(rulers_state::in_ruler(
+        rulers_state::GuideAxis::Vertical,
+        (-5.0, 40.0),
+        20.0
+    ));
+    assert!(!rulers_state::in_ruler(
+        rulers_state::GuideAxis::Vertical,
+        (5.0, 40.0),
+        20.0
+    ));
+
+    let (guides, index) = rulers_state::upsert_guide(&[], None, guide);
+    assert_eq!(index, 0);
+    let (guides, index) =
+        rulers_state::upsert_guide(&guides, Some(0), rulers_state::RulerGuide::horizontal(60.0));
+    assert_eq!((guides.len(), index), (1, 0));
+
+    rulers_state::store_guides("rulers", &guides);
+    assert_eq!(rulers_state::load_guides("rulers", None, &[]), guides);
+    assert!(rulers_state::update_snapped("rulers", true));
+    assert!(!rulers_state::update_snapped("rulers", true));
+    rulers_state::set_active_guide("rulers", Some(0));
+    assert!(!rulers_state::is_snapped("rulers"));
+
+    rulers_state::store_bounds("rulers", (100.0, 50.0), (400.0, 300.0));
+    assert_eq!(
+        rulers_state::store_pointer("rulers", 110.0, 45.0),
+        (10.0, -5.0)
+    );
+    assert_eq!(rulers_state::last_pointer("rulers"), (10.0, -5.0));
+    assert_eq!(rulers_state::extent("rulers"), (400.0, 300.0));
+}
+
+#[test]
+fn combobox_state_filters_highlights_and_debounces() {
+    let _guard = guard();
+
+    let labels = vec![
+        "Banana".to_string(),
+        "Apple".to_string(),
+        "Pineapple".to_string(),
+        "Apricot".to_string(),
+    ];
+    assert_eq!(
+        combobox_state::filter_options(&labels, "ap", None),
+        vec![1, 3, 2]
+    );
+    assert_eq!(
+        combobox_state::filter_options(&labels, "ap", Some(2)),
+        vec![1, 3]
+    );
+    assert_eq!(
+        combobox_state::filter_options(&labels, "  ", None),
+        vec![0, 1, 2, 3]
+    );
+    assert!(combobox_state::filter_options(&labels, "kiwi", None).is_empty());
+
+    assert_eq!(combobox_state::create_candidate(&labels, " apple "), None);
+    assert_eq!(
+        combobox_state::create_candidate(&labels, " Kiwi "),
+        Some("Kiwi".to_string())
+    );
+    assert_eq!(combobox_state::create_candidate(&labels, ""), None);
+    assert_eq!(
+        combobox_state::rows(&[2], true),
+        vec![
+            combobox_state::ComboboxRow::Option(2),
+            combobox_state::ComboboxRow::Create
+        ]
+    );
+
+    let enabled = [true, false, true];
+    assert_eq!(combobox_state::move_highlight(None, 1, &enabled), Some(0));
+    assert_eq!(
+        combobox_state::move_highlight(Some(0), 1, &enabled),
+        Some(2)
+    );
+    assert_eq!(
+        combobox_state::move_highlight(Some(2), 1, &enabled),
+        Some(0)
+    );
+    assert_eq!(combobox_state::move_highlight(None, -1, &enabled), Some(2));
+    assert_eq!(combobox_state::move_highlight(None, 1, &[false]), None);
+
+    combobox_state::set_highlighted("combo", Some(2));
+    assert_eq!(combobox_state::highlighted("combo", 3), Some(2));
+    assert_eq!(combobox_state::highlighted("combo", 2), None);
+
+    assert_eq!(
+        combobox_state::text("combo", None, "seed".to_string()),
+        "seed"
+    );
+    combobox_state::set_text("combo", "app".to_string());
+    assert_eq!(
+        combobox_state::text("combo", None, "seed".to_string()),
+        "app"
+    );
+    assert_eq!(combobox_state::filter_query("combo", "app"), "app");
+    combobox_state::set_filter_query("combo", "ap".to_string());
+    assert_eq!(combobox_state::filter_query("combo", "app"), "ap");
+
+    let first = combobox_state::bump_revision("combo");
+    let second = combobox_state::bump_revision("combo");
+    assert_ne!(first, second);
+    assert_eq!(combobox_state::revision("combo"), second);
+}
+
+#[test]
+fn tags_input_state_splits_limits_and_collapses_tags() {
+    let _guard = guard();
+
+    let (complete, remainder) = tags_input_state::split_input("rust, gpui,, ui", &[',']);
+    assert_eq!(complete, vec!["rust".to_string(), "gpui".to_string()]);
+    assert_eq!(remainder, " ui");
+    let (complete, remainder) = tags_input_state::split_input("draft", &[',']);
+    assert!(complete.is_empty());
+    assert_eq!(remainder, "draft");
+
+    let current = vec!["rust".to_string()];
+    let next = tags_input_state::add_tags(
+        &current,
+        ["gpui", "rust", " ", "ui", "extra"].map(String::from),
+        Some(3),
+        false,
+    );
+    assert_eq!(next, vec!["rust", "gpui", "ui"]);
+    assert_eq!(
+        tags_input_state::add_tags(&current, ["rust".to_string()], None, true),
+        vec!["rust", "rust"]
+    );
+    assert_eq!(tags_input_state::remove_tag(&next, 1), vec!["rust", "ui"]);
+
+    assert_eq!(tags_input_state::visible_tags(5, Some(2)), (2, 3));
+    assert_eq!(tags_input_state::visible_tags(2, Some(2)), (2, 0));
+    assert_eq!(tags_input_state::visible_tags(4, None), (4, 0));
+
+    assert_eq!(
+        tags_input_state::resolve_values("tags", None, vec!["seed".to_string()]),
+        vec!["seed"]
+    );
+    tags_input_state::set_values("tags", vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(
+        tags_input_state::resolve_values("tags", None, Vec::new()),
+        vec!["a", "b"]
+    );
+
+    tags_input_state::set_remote_options(
+        "tags",
+        vec![("us".to_string(), "United States".to_string())],
+    );
+    assert_eq!(
+        tags_input_state::remote_options("tags"),
+        vec![("us".to_string(), "United States".to_string())]
+    );
+    tags_input_state::set_loading("tags", true);
+    assert!(tags_input_state::loading("tags"));
+    let revision = tags_input_state::bump_revision("tags");
+    assert_eq!(tags_input_state::revision("tags"), revision);
+}
+
+#[test]
+fn paste_attachment_detects_file_uri_lists() {
+    assert_eq!(
+        paste_attachment::file_paths("file:///tmp/shot%20one.png\nfile://localhost/tmp/b.txt"),
+        Some(vec![
+            PathBuf::from("/tmp/shot one.png"),
+            PathBuf::from("/tmp/b.txt"),
+        ])
+    );
+    assert_eq!(
+        paste_attachment::file_paths("# copied\n\nfile:///tmp/a.png\n"),
+        Some(vec![PathBuf::from("/tmp/a.png")])
+    );
+    assert_eq!(paste_attachment::file_paths("see file:///tmp/a.png"), None);
+    assert_eq!(
+        paste_attachment::file_paths("file:///tmp/a.png\nplain text"),
+        None
+    );
+    assert_eq!(paste_attachment::file_paths(""), None);
+}
+
+#[test]
+fn dock_tabs_state_reorders_and_partitions_overflow() {
+    let _guard = guard();
+    let values = ["a", "b", "c"].map(String::from).to_vec();
+    assert_eq!(dock_tabs_state::resolve_order("dock", &values), values);
+
+    let moved = dock_tabs_state::move_tab(&values, 0, 2);
+    assert_eq!(moved, ["b", "c", "a"].map(String::from).to_vec());
+    assert_eq!(dock_tabs_state::move_tab(&values, 5, 0), values);
+    dock_tabs_state::set_order("dock", moved);
+    let reconciled = ["a", "c", "d"].map(String::from).to_vec();
+    assert_eq!(
+        dock_tabs_state::resolve_order("dock", &reconciled),
+        ["c", "a", "d"].map(String::from).to_vec()
+    );
+
+    let widths = [100.0, 100.0, 100.0, 100.0];
+    let (visible, hidden) = dock_tabs_state::partition(&widths, None, 30.0, None);
+    assert_eq!((visible.len(), hidden.len()), (4, 0));
+    let (visible, hidden) = dock_tabs_state::partition(&widths, Some(400.0), 30.0, Some(3));
+    assert_eq!((visible.len(), hidden.len()), (4, 0));
+    let (visible, hidden) = dock_tabs_state::partition(&widths, Some(330.0), 30.0, Some(0));
+    assert_eq!(visible, vec![0, 1, 2]);
+    assert_eq!(hidden, vec![3]);
+    let (visible, hidden) = dock_tabs_state::partition(&widths, Some(330.0), 30.0, Some(3));
+    assert_eq!(visible, vec![0, 1, 3]);
+    assert_eq!(hidden, vec![2]);
+
+    assert!(dock_tabs_state::store_tab_width("dock", "a", 96.0));
+    assert!(!dock_tabs_state::store_tab_width("dock", "a", 96.2));
+    assert_eq!(dock_tabs_state::tab_width("dock", "a"), Some(96.0));
+    assert!(dock_tabs_state::store_strip_width("dock", 640.0));
+    assert_eq!(dock_tabs_state::strip_width("dock"), Some(640.0));
+
+    assert!(!dock_tabs_state::is_detach_distance(-10.0, 24.0));
+    assert!(dock_tabs_state::is_detach_distance(30.0, 24.0));
+    dock_tabs_state::set_detach_pending("dock", Some("a".into()));
+    assert_eq!(
+        dock_tabs_state::take_detach_pending("dock"),
+        Some("a".to_string())
+    );
+    assert_eq!(dock_tabs_state::take_detach_pending("dock"), None);
+}
+
+#[test]
+fn breadcrumbs_state_collapses_middle_items_to_fit() {
+    let _guard = guard();
+    let all = (0..5).collect::<Vec<_>>();
+    let widths = [40.0, 60.0, 60.0, 50.0, 70.0];
+    assert_eq!(breadcrumbs_state::fit(&all, &widths, None, 20.0), all);
+    assert_eq!(
+        breadcrumbs_state::fit(&all, &widths, Some(400.0), 20.0),
+        all
+    );
+    assert_eq!(
+        breadcrumbs_state::fit(&all, &widths, Some(200.0), 20.0),
+        vec![0, 3, 4]
+    );
+    assert_eq!(
+        breadcrumbs_state::fit(&all, &widths, Some(100.0), 20.0),
+        vec![0, 4],
+        "first and last crumbs stay even when they overflow"
+    );
+    assert_eq!(
+        breadcrumbs_state::fit(&[0, 3, 4], &widths, Some(500.0), 20.0),
+        vec![0, 3, 4]
+    );
+
+    assert!(breadcrumbs_state::store_item_width("crumbs", 1, 60.0));
+    assert!(!breadcrumbs_state::store_item_width("crumbs", 1, 60.3));
+    assert_eq!(
+        breadcrumbs_state::item_widths("crumbs", 3),
+        vec![0.0, 60.0, 0.0]
+    );
+    assert!(breadcrumbs_state::store_available_width("crumbs", 320.0));
+    assert_eq!(breadcrumbs_state::available_width("crumbs"), Some(320.0));
+    assert_eq!(breadcrumbs_state::ellipsis_width("crumbs"), None);
+}
+
+#[test]
+fn image_state_sniffs_formats_and_reuses_decoded_images() {
+    let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    assert_eq!(
+        image_state::sniff_format(&png),
+        Some(gpui::ImageFormat::Png)
+    );
+    assert_eq!(
+        image_state::sniff_format(&[0xFF, 0xD8, 0xFF, 0xE0]),
+        Some(gpui::ImageFormat::Jpeg)
+    );
+    assert_eq!(
+        image_state::sniff_format(b"RIFF\0\0\0\0WEBPVP8 "),
+        Some(gpui::ImageFormat::Webp)
+    );
+    assert_eq!(
+        image_state::sniff_format(b"  <svg xmlns=\"http://www.w3.org/2000/svg\"/>"),
+        Some(gpui::ImageFormat::Svg)
+    );
+    assert_eq!(image_state::sniff_format(b"plain text"), None);
+
+    let first = image_state::decoded(&png).expect("png is recognized");
+    let second = image_state::decoded(&png).expect("png is recognized");
+    assert!(std::sync::Arc::ptr_eq(&first, &second));
+    assert!(image_state::decoded(b"plain text").is_none());
+}
+
+#[test]
+fn calendar_state_builds_weeks_ranges_and_day_chips() {
+    let _guard = guard();
+    let date = |day| CalendarDate::new(2026, 10, day).expect("valid date");
+
+    let week = calendar_state::week_days(date(15), 1);
+    assert_eq!(week.first().copied(), Some(date(12)));
+    assert_eq!(week.last().copied(), Some(date(18)));
+    assert_eq!(
+        calendar_state::visible_days(CalendarView::Month, date(15), 0).len(),
+        42
+    );
+    assert_eq!(
+        calendar_state::step(CalendarView::Week, date(15), -1),
+        date(8)
+    );
+
+    assert_eq!(
+        calendar_state::click_range(None, date(20)),
+        calendar_state::RangeClick::Anchored(date(20))
+    );
+    let range = DateRange::new(date(20), date(14));
+    assert_eq!(
+        calendar_state::click_range(Some(date(20)), date(14)),
+        calendar_state::RangeClick::Completed(range)
+    );
+    assert_eq!(
+        (range.start(), range.end(), range.days()),
+        (date(14), date(20), 7)
+    );
+    assert_eq!(range.to_string().parse::<DateRange>(), Ok(range));
+
+    let events = vec![
+        CalendarEvent::new("a", "Review", date(15)),
+        CalendarEvent::new("b", "Trip", date(14)).until(date(16)),
+        CalendarEvent::new("c", "Launch", date(15)),
+    ];
+    let (chips, hidden) = calendar_state::chips_for_day(&events, date(15), 2);
+    assert_eq!(
+        chips
+            .iter()
+            .map(|event| event.id().as_ref())
+            .collect::<Vec<_>>(),
+        vec!["b", "a"]
+    );
+    assert_eq!(hidden, 1);
+
+    assert_eq!(calendar_state::first_weekday_for_locale("en-US"), 0);
+    assert_eq!(calendar_state::first_weekday_for_locale("de_DE.UTF-8"), 1);
+    assert_eq!(calendar_state::first_weekday_for_locale("ar-EG"), 6);
+    assert_eq!(calendar_state::first_weekday_for_locale("zh-Hant-TW"), 0);
+    assert_eq!(calendar_state::first_weekday_for_locale("fr"), 1);
+
+    calendar_state::set_range_anchor("calendar", Some(date(3)));
+    assert_eq!(calendar_state::range_anchor("calendar"), Some(date(3)));
+    assert!(calendar_state::set_hovered_date("calendar", Some(date(5))));
+    assert!(!calendar_state::set_hovered_date("calendar", Some(date(5))));
+}
+
+#[test]
+fn kanban_board_state_windows_slots_and_moves() {
+    let _guard = guard();
+
+    assert_eq!(
+        kanban_board_state::visible_window(100, 50.0, 200.0, 0.0, 2),
+        0..7
+    );
+    assert_eq!(
+        kanban_board_state::visible_window(100, 50.0, 200.0, 1000.0, 2),
+        18..27
+    );
+    assert_eq!(
+        kanban_board_state::visible_window(3, 50.0, 200.0, 0.0, 2),
+        0..3
+    );
+    assert_eq!(
+        kanban_board_state::visible_window(0, 50.0, 200.0, 0.0, 2),
+        0..0
+    );
+
+    assert_eq!(kanban_board_state::drop_slot(0.0, 50.0, 4, None), 0);
+    assert_eq!(kanban_board_state::drop_slot(80.0, 50.0, 4, None), 2);
+    assert_eq!(kanban_board_state::drop_slot(900.0, 50.0, 4, None), 4);
+    assert_eq!(kanban_board_state::drop_slot(120.0, 50.0, 4, Some(2)), 2);
+    assert_eq!(kanban_board_state::drop_slot(180.0, 50.0, 4, Some(2)), 3);
+    assert_eq!(kanban_board_state::drop_slot(20.0, 50.0, 4, Some(2)), 0);
+
+    assert_eq!(
+        kanban_board_state::resolve_move("a".into(), "todo".into(), 1, "todo".into(), 1),
+        None
+    );
+    assert_eq!(
+        kanban_board_state::resolve_move("a".into(), "todo".into(), 1, "todo".into(), 2),
+        None
+    );
+    let down = kanban_board_state::resolve_move("a".into(), "todo".into(), 1, "todo".into(), 4)
+        .expect("reorder down");
+    assert!(down.is_reorder());
+    assert_eq!(down.to_index, 3);
+    let up = kanban_board_state::resolve_move("a".into(), "todo".into(), 3, "todo".into(), 0)
+        .expect("reorder up");
+    assert_eq!(up.to_index, 0);
+    let across = kanban_board_state::resolve_move("a".into(), "todo".into(), 1, "done".into(), 2)
+        .expect("move across");
+    assert!(!across.is_reorder());
+    assert_eq!(across.to_column.as_ref(), "done");
+    assert_eq!(across.to_index, 2);
+
+    let id = "state-kanban";
+    assert!(kanban_board_state::set_drop_target(id, Some(("todo", 2))));
+    assert!(!kanban_board_state::set_drop_target(id, Some(("todo", 2))));
+    assert_eq!(
+        kanban_board_state::drop_target(id),
+        Some((String::from("todo"), 2))
+    );
+    assert!(kanban_board_state::set_drop_target(id, Some(("done", 2))));
+
+    assert!(!kanban_board_state::store_scroll_y(id, "todo", 10.0, 50.0));
+    assert!(kanban_board_state::store_scroll_y(id, "todo", 60.0, 50.0));
+    assert_eq!(kanban_board_state::scroll_y(id, "todo"), 60.0);
+    assert_eq!(kanban_board_state::scroll_y(id, "done"), 0.0);
+
+    kanban_board_state::set_dragging(id, Some("a".into()));
+    assert_eq!(kanban_board_state::dragging(id).as_deref(), Some("a"));
+    kanban_board_state::finish_drag(id);
+    assert_eq!(kanban_board_state::dragging(id), None);
+    assert_eq!(kanban_board_state::drop_target(id), None);
+}
+
+#[test]
+fn chart_state_scales_hits_and_tracks_data_changes() {
+    let _guard = guard();
+
+    assert_eq!(chart_state::value_bounds([3.0, 8.0], true), (0.0, 8.0));
+    assert_eq!(chart_state::value_bounds([-2.0, 5.0], false), (-2.0, 5.0));
+    assert_eq!(chart_state::value_bounds([4.0, 4.0], false), (3.0, 5.0));
+    assert_eq!(chart_state::value_bounds([], false), (0.0, 1.0));
+    assert_eq!(
+        chart_state::nice_ticks(0.0, 8.0, 4),
+        vec![0.0, 2.0, 4.0, 6.0, 8.0]
+    );
+    assert_eq!(
+        chart_state::nice_ticks(-3.0, 17.0, 4),
+        vec![-5.0, 0.0, 5.0, 10.0, 15.0, 20.0]
+    );
+    assert_eq!(chart_state::scale_y(5.0, (0.0, 10.0), 100.0), 50.0);
+    assert_eq!(chart_state::scale_y(10.0, (0.0, 10.0), 100.0), 0.0);
+
+    assert_eq!(chart_state::point_x(2, 5, 100.0), 50.0);
+    assert_eq!(chart_state::point_x(0, 1, 100.0), 50.0);
+    assert_eq!(chart_state::nearest_point(60.0, 5, 100.0), Some(2));
+    assert_eq!(chart_state::nearest_point(-10.0, 5, 100.0), Some(0));
+    assert_eq!(chart_state::nearest_point(10.0, 0, 100.0), None);
+    assert_eq!(chart_state::bar_band(1, 4, 100.0, 10.0), (30.0, 15.0));
+    assert_eq!(chart_state::bar_at(99.0, 4, 100.0), Some(3));
+    assert_eq!(chart_state::bar_at(100.0, 4, 100.0), None);
+
+    let segments = chart_state::donut_segments(&[1.0, 1.0, -3.0, 2.0]);
+    assert_eq!(segments[0].0, 0.0);
+    assert!((segments[1].1 - std::f32::consts::PI).abs() < 1e-5);
+    assert_eq!(segments[2].0, segments[2].1);
+    assert!((segments[3].1 - std::f32::consts::TAU).abs() < 1e-5);
+    assert_eq!(
+        chart_state::donut_segment_at((10.0, -45.0), 50.0, 30.0, &segments),
+        Some(0)
+    );
+    assert_eq!(
+        chart_state::donut_segment_at((-45.0, 5.0), 50.0, 30.0, &segments),
+        Some(3)
+    );
+    assert_eq!(
+        chart_state::donut_segment_at((0.0, 10.0), 50.0, 30.0, &segments),
+        None
+    );
+
+    assert_eq!(
+        chart_state::interpolate(&[0.0, 10.0], &[10.0, 20.0, 4.0], 0.5),
+        vec![5.0, 15.0, 2.0]
+    );
+    assert_eq!(chart_state::format_value(12.0), "12");
+    assert_eq!(chart_state::format_value(1.5), "1.5");
+    assert_eq!(chart_state::format_value(0.127), "0.13");
+
+    let id = "state-chart";
+    assert_eq!(
+        chart_state::data_generation(id, &[1.0, 2.0]),
+        (Vec::new(), 0)
+    );
+    assert_eq!(
+        chart_state::data_generation(id, &[1.0, 2.0]),
+        (Vec::new(), 0)
+    );
+    assert_eq!(
+        chart_state::data_generation(id, &[3.0]),
+        (vec![1.0, 2.0], 1)
+    );
+    assert_eq!(
+        chart_state::data_generation(id, &[3.0]),
+        (vec![1.0, 2.0], 1)
+    );
+
+    assert!(chart_state::set_hovered(id, Some(2)));
+    assert!(!chart_state::set_hovered(id, Some(2)));
+    assert_eq!(chart_state::hovered(id), Some(2));
+    chart_state::store_plot(id, (10.0, 20.0), (300.0, 200.0));
+    assert_eq!(chart_state::plot(id), ((10.0, 20.0), (300.0, 200.0)));
+}
+
+#[test]
+fn stat_card_formats_and_classifies_deltas() {
+    let _guard = guard();
+    use stat_card::DeltaTrend;
+
+    assert_eq!(DeltaTrend::of(3.2), DeltaTrend::Up);
+    assert_eq!(DeltaTrend::of(-0.4), DeltaTrend::Down);
+    assert_eq!(DeltaTrend::of(0.0), DeltaTrend::Flat);
+    assert_eq!(stat_card::format_delta(12.46), "+12.5%");
+    assert_eq!(stat_card::format_delta(-3.0), "-3%");
+    assert_eq!(stat_card::format_delta(0.02), "0%");
+}
+
+#[test]
+fn wizard_state_skips_steps_and_tracks_validation() {
+    let _guard = guard();
+    let skipped = [false, true, false, true];
+
+    assert_eq!(wizard_state::next_step(0, &skipped), Some(2));
+    assert_eq!(wizard_state::next_step(2, &skipped), None);
+    assert_eq!(wizard_state::previous_step(2, &skipped), Some(0));
+    assert_eq!(wizard_state::previous_step(0, &skipped), None);
+    assert_eq!(wizard_state::settle(1, &skipped), 2);
+    assert_eq!(wizard_state::settle(3, &skipped), 2);
+    assert_eq!(wizard_state::settle(9, &[]), 0);
+
+    let id = "wizard-validation";
+    assert!(!wizard_state::validating(id));
+    let stale = wizard_state::begin_validation(id);
+    let current = wizard_state::begin_validation(id);
+    assert!(wizard_state::validating(id));
+    assert!(!wizard_state::finish_validation(id, stale));
+    assert!(wizard_state::finish_validation(id, current));
+    assert!(!wizard_state::validating(id));
+
+    let cancelled = wizard_state::begin_validation(id);
+    wizard_state::cancel_validation(id);
+    assert!(!wizard_state::validating(id));
+    assert!(!wizard_state::finish_validation(id, cancelled));
+
+    wizard_state::set_error(id, Some("Email is required".to_string()));
+    assert_eq!(
+        wizard_state::error(id).as_deref(),
+        Some("Email is required")
+    );
+    wizard_state::set_error(id, None);
+    assert_eq!(wizard_state::error(id), None);
+}
+
+#[test]
+fn pin_input_state_handles_pasted_codes_and_verification() {
+    let _guard = guard();
+
+    assert_eq!(
+        pin_input_state::pasted_code("Code: 12-34-56", 6),
+        ("123456".to_string(), true)
+    );
+    assert_eq!(
+        pin_input_state::pasted_code("\u{FF17}8", 6),
+        ("78".to_string(), false)
+    );
+
+    let id = "pin-verify";
+    let stale = pin_input_state::begin_verification(id);
+    let current = pin_input_state::begin_verification(id);
+    assert!(pin_input_state::verifying(id));
+    assert!(!pin_input_state::finish_verification(id, stale));
+    assert!(pin_input_state::finish_verification(id, current));
+    assert!(!pin_input_state::verifying(id));
+
+    assert_eq!(pin_input_state::note_error(id, None), 0);
+    assert_eq!(pin_input_state::note_error(id, Some("Wrong code")), 1);
+    assert_eq!(pin_input_state::note_error(id, Some("Wrong code")), 1);
+    assert_eq!(pin_input_state::note_error(id, Some("Expired")), 2);
+    assert_eq!(pin_input_state::note_error(id, None), 2);
+    pin_input_state::trigger_shake(id);
+    assert_eq!(pin_input_state::shake_count(id), 3);
+
+    pin_input_state::set_verify_error(id, Some("Wrong code".to_string()));
+    assert_eq!(
+        pin_input_state::verify_error(id).as_deref(),
+        Some("Wrong code")
+    );
+}
+
+#[test]
+fn password_strength_scores_length_and_variety() {
+    use password_strength::PasswordStrength;
+
+    assert_eq!(PasswordStrength::estimate("abc"), PasswordStrength::Weak);
+    assert_eq!(
+        PasswordStrength::estimate("password"),
+        PasswordStrength::Weak
+    );
+    assert_eq!(
+        PasswordStrength::estimate("password12"),
+        PasswordStrength::Fair
+    );
+    assert_eq!(
+        PasswordStrength::estimate("Password12"),
+        PasswordStrength::Good
+    );
+    assert_eq!(
+        PasswordStrength::estimate("Correct-Horse-42"),
+        PasswordStrength::Strong
+    );
+    assert_eq!(PasswordStrength::Good.segments(), 3);
+    assert!(PasswordStrength::Weak < PasswordStrength::Strong);
+}
+
+#[test]
+fn input_mask_formats_patterns_and_numbers_while_editing() {
+    use input_mask::InputMask;
+
+    let phone = InputMask::pattern("(###) ###-####");
+    assert_eq!(phone.format("5551234567"), "(555) 123-4567");
+    assert_eq!(phone.format("5551"), "(555) 1");
+    assert_eq!(phone.unmask("(555) 123-4567"), "5551234567");
+    assert_eq!(
+        phone.apply_edit("(555", 4, "(5551", 5),
+        ("(555) 1".to_string(), 7)
+    );
+    assert_eq!(
+        phone.apply_edit("(555) 1", 6, "(555)1", 5),
+        ("(551".to_string(), 3)
+    );
+    assert_eq!(
+        phone.apply_edit("", 0, "555-123-4567", 12),
+        ("(555) 123-4567".to_string(), 14)
+    );
+    assert_eq!(
+        phone.apply_edit("(55", 3, "(55x", 4),
+        ("(55".to_string(), 3)
+    );
+
+    let amount = InputMask::currency("$");
+    assert_eq!(amount.format("1234.5"), "$1,234.5");
+    assert_eq!(amount.unmask("$1,234.50"), "1234.50");
+    assert_eq!(
+        amount.apply_edit("$1,234", 6, "$1,2345", 7),
+        ("$12,345".to_string(), 7)
+    );
+
+    let euro = InputMask::number()
+        .thousands_separator(Some('.'))
+        .decimal_separator(',')
+        .decimals(2)
+        .suffix(" €");
+    assert_eq!(euro.format("1234567.891"), "1.234.567,89 €");
+    assert_eq!(euro.unmask("1.234.567,89 €"), "1234567.89");
+}
+
+#[test]
+fn number_input_state_picks_separators_and_tracks_repeats() {
+    let _guard = guard();
+    assert_eq!(
+        number_input_state::separators_for_locale("en-US"),
+        (',', '.')
+    );
+    assert_eq!(
+        number_input_state::separators_for_locale("de_DE.UTF-8"),
+        ('.', ',')
+    );
+    assert_eq!(
+        number_input_state::separators_for_locale("fr-FR"),
+        ('\u{a0}', ',')
+    );
+    assert_eq!(
+        number_input_state::separators_for_locale("de-CH"),
+        ('\u{2019}', '.')
+    );
+    assert_eq!(
+        number_input_state::separators_for_locale("pt-BR"),
+        ('.', ',')
+    );
+
+    let id = "number-input-state";
+    let stale = number_input_state::begin_repeat(id);
+    let current = number_input_state::begin_repeat(id);
+    assert!(!number_input_state::repeating(id, stale));
+    assert!(number_input_state::repeating(id, current));
+    number_input_state::stop_repeat(id);
+    assert!(!number_input_state::repeating(id, current));
+
+    assert!(!number_input_state::note_focus(id, true));
+    assert!(number_input_state::note_focus(id, false));
+    assert!(!number_input_state::note_focus(id, false));
+
+    assert_eq!(number_input_state::nudge_count(id), 0);
+    number_input_state::nudge(id);
+    assert_eq!(number_input_state::nudge_count(id), 1);
+
+    let signed = input_mask::InputMask::number().signed(true).prefix("$");
+    assert_eq!(signed.format("-1234"), "-$1,234");
+    assert_eq!(signed.format("-"), "-$");
+    assert_eq!(signed.unmask("-$1,234"), "-1234");
+    assert_eq!(signed.unmask("$-12"), "-12");
+    assert_eq!(input_mask::InputMask::number().format("-12"), "12");
+}
+
+#[test]
+fn slider_axis_places_ticks_and_snaps_to_marks() {
+    assert_eq!(
+        slider_axis::tick_ratios(0.0, 100.0, 25.0),
+        vec![0.25, 0.5, 0.75]
+    );
+    assert_eq!(
+        slider_axis::tick_ratios(0.0, 100.0, 30.0),
+        vec![0.3, 0.6, 0.9]
+    );
+    assert!(slider_axis::tick_ratios(0.0, 100.0, 0.5).is_empty());
+
+    assert_eq!(
+        slider_axis::value_offset(slider_axis::SliderAxis::Horizontal, 120.0, 20.0, 0.5),
+        60.0
+    );
+    assert_eq!(
+        slider_axis::value_offset(slider_axis::SliderAxis::Vertical, 120.0, 20.0, 1.0),
+        10.0
+    );
+
+    let marks = [0.0, 20.0, 50.0, 100.0, 150.0];
+    assert_eq!(slider_axis::snap(0.0, 100.0, 1.0, &marks, 33.0), 20.0);
+    assert_eq!(slider_axis::snap(0.0, 100.0, 1.0, &marks, 140.0), 100.0);
+    assert_eq!(slider_axis::snap(0.0, 100.0, 5.0, &[], 33.0), 35.0);
+}
+
+#[test]
+fn slider_axis_maps_keys_to_steps() {
+    let key = |key, shift, current, marks: &[f32]| {
+        slider_axis::keyboard_value(key, shift, current, 0.0, 100.0, 5.0, marks)
+    };
+    assert_eq!(key("up", false, 40.0, &[]), Some(45.0));
+    assert_eq!(key("right", false, 40.0, &[]), Some(45.0));
+    assert_eq!(key("down", false, 40.0, &[]), Some(35.0));
+    assert_eq!(key("left", true, 40.0, &[]), Some(0.0));
+    assert_eq!(key("pageup", false, 40.0, &[]), Some(90.0));
+    assert_eq!(key("up", false, 100.0, &[]), Some(100.0));
+    assert_eq!(key("home", false, 40.0, &[]), Some(0.0));
+    assert_eq!(key("end", false, 40.0, &[]), Some(100.0));
+    assert_eq!(key("tab", false, 40.0, &[]), None);
+
+    let marks = [10.0, 50.0, 90.0];
+    assert_eq!(key("up", false, 50.0, &marks), Some(90.0));
+    assert_eq!(key("down", false, 50.0, &marks), Some(10.0));
+    assert_eq!(key("down", false, 10.0, &marks), Some(10.0));
+    assert_eq!(key("end", false, 50.0, &marks), Some(90.0));
+}
+
+#[test]
+fn rating_state_picks_fractions_and_previews_hover() {
+    let _guard = guard();
+    assert_eq!(rating_state::quantize(3.7, 2), 3.5);
+    assert_eq!(rating_state::quantize(3.7, 4), 3.75);
+    assert_eq!(rating_state::quantize(3.7, 1), 4.0);
+    assert_eq!(rating_state::fill_ratio(3.25, 4), 0.25);
+    assert_eq!(rating_state::fill_ratio(3.25, 2), 1.0);
+    assert_eq!(rating_state::fill_ratio(3.25, 5), 0.0);
+
+    assert_eq!(rating_state::target(3, 0, 4, 0.0, false), 2.25);
+    assert_eq!(rating_state::target(3, 3, 4, 0.0, false), 3.0);
+    assert_eq!(rating_state::target(2, 0, 2, 1.5, true), 0.0);
+    assert_eq!(rating_state::target(2, 0, 1, 1.0, true), 2.0);
+
+    assert_eq!(rating_state::format_value(3.75), "3.75");
+    assert_eq!(rating_state::format_value(3.5), "3.5");
+    assert_eq!(rating_state::format_value(4.0), "4");
+
+    let id = "rating-hover";
+    rating_state::set_hover(id, 2.5);
+    rating_state::set_hover(id, 3.0);
+    rating_state::clear_hover(id, 2.5);
+    assert_eq!(rating_state::hover(id), Some(3.0));
+    rating_state::clear_hover(id, 3.0);
+    assert_eq!(rating_state::hover(id), None);
+}
+
+#[test]
+fn editable_text_state_drops_stale_saves() {
+    let _guard = guard();
+    let id = "editable-text";
+    editable_text_state::set_error(id, Some("Taken".into()));
+    editable_text_state::start(id, "Draft");
+    assert!(editable_text_state::editing(id));
+    assert_eq!(editable_text_state::draft(id), "Draft");
+    assert_eq!(editable_text_state::error(id), None);
+    editable_text_state::set_draft(id, "Final");
+    editable_text_state::stop(id);
+    assert!(!editable_text_state::editing(id));
+
+    let first = editable_text_state::begin_save(id, "Final");
+    let second = editable_text_state::begin_save(id, "Final v2");
+    assert_eq!(
+        editable_text_state::pending(id).as_deref(),
+        Some("Final v2")
+    );
+    assert!(!editable_text_state::finish_save(id, first));
+    assert!(editable_text_state::pending(id).is_some());
+    assert!(editable_text_state::finish_save(id, second));
+    assert_eq!(editable_text_state::pending(id), None);
+}
+
+#[test]
+fn markdown_stream_settles_blocks_closed_by_blank_lines() {
+    let source = "# Title\n\nFirst paragraph.\n\n- one\n\n  still one\n";
+    let settled = markdown_stream::settled_boundary(source, 0);
+    assert_eq!(&source[settled..], "- one\n\n  still one\n");
+
+    let fenced = "Intro\n\n```\ncode\n\nmore code\n";
+    let settled = markdown_stream::settled_boundary(fenced, 0);
+    assert_eq!(&fenced[settled..], "```\ncode\n\nmore code\n");
+
+    let closed = format!("{fenced}```\n\nAfter");
+    let settled = markdown_stream::settled_boundary(&closed, settled);
+    assert_eq!(&closed[settled..], "After");
+    assert_eq!(markdown_stream::settled_boundary("Still typing", 0), 0);
+}
+
+#[test]
+fn chat_view_state_windows_rows_and_counts_unread() {
+    let _guard = guard();
+    let heights = [40.0, 60.0, 40.0, 80.0, 40.0];
+    assert_eq!(chat_view_state::row_top(&heights, 3), 140.0);
+    assert_eq!(
+        chat_view_state::visible_rows(&heights, 100.0, 50.0, 0),
+        1..4
+    );
+    assert_eq!(
+        chat_view_state::visible_rows(&heights, 100.0, 50.0, 1),
+        0..5
+    );
+    assert_eq!(chat_view_state::anchor_at(&heights, 110.0), Some((2, 10.0)));
+    assert_eq!(chat_view_state::anchor_at(&heights, 500.0), None);
+
+    let messages = [("a", false), ("b", true), ("c", false), ("d", false)];
+    assert_eq!(chat_view_state::unread_count(messages.into_iter(), None), 0);
+    assert_eq!(
+        chat_view_state::unread_count(messages.into_iter(), Some("a")),
+        2
+    );
+    assert_eq!(
+        chat_view_state::unread_count(messages.into_iter(), Some("d")),
+        0
+    );
+
+    let id = "chat";
+    assert_eq!(chat_view_state::row_height(id, "message:a", 64.0), 64.0);
+    assert!(chat_view_state::store_row_height(id, "message:a", 52.0));
+    assert!(!chat_view_state::store_row_height(id, "message:a", 52.2));
+    assert_eq!(chat_view_state::row_height(id, "message:a", 64.0), 52.0);
+
+    assert!(chat_view_state::set_anchor(
+        id,
+        Some(("message:c".into(), 12.0))
+    ));
+    assert!(!chat_view_state::set_anchor(
+        id,
+        Some(("message:c".into(), 30.0))
+    ));
+    assert_eq!(
+        chat_view_state::anchor(id),
+        Some(("message:c".into(), 30.0))
+    );
+    assert!(chat_view_state::set_anchor(id, None));
+    assert_eq!(chat_view_state::anchor(id), None);
+}
+
+#[test]
+fn log_buffer_parses_ansi_caps_lines_and_filters() {
+    let _guard = guard();
+    let (text, spans) = log_buffer::parse_ansi("\x1b[1;31mERROR\x1b[0m disk full\r");
+    assert_eq!(text, "ERROR disk full");
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].0, 0..5);
+    assert!(spans[0].1.bold);
+    assert_eq!(spans[0].1.fg, Some(log_buffer::AnsiColor::Indexed(1)));
+    let (text, spans) = log_buffer::parse_ansi("\x1b[38;2;1;2;3mx\x1b]0;title\x07y");
+    assert_eq!(text, "xy");
+    assert_eq!(
+        spans[0],
+        (
+            0..2,
+            log_buffer::AnsiStyle {
+                fg: Some(log_buffer::AnsiColor::Rgb(1, 2, 3)),
+                ..Default::default()
+            }
+        )
+    );
+
+    assert_eq!(
+        log_buffer::LogLevel::detect("12:00:01 WARN disk almost full"),
+        Some(log_buffer::LogLevel::Warn)
+    );
+    assert_eq!(
+        log_buffer::LogLevel::detect("[error] boom"),
+        Some(log_buffer::LogLevel::Error)
+    );
+    assert_eq!(log_buffer::LogLevel::detect("compiling calmui"), None);
+
+    let buffer = log_buffer::LogBuffer::with_max_lines(3);
+    buffer.push("INFO a\nWARN b\nERROR c\nINFO d\n");
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.dropped(), 1);
+    assert_eq!(buffer.text(), "WARN b\nERROR c\nINFO d");
+    let numbers = |(count, lines): (usize, Vec<(usize, log_buffer::LogLine)>)| {
+        (
+            count,
+            lines
+                .into_iter()
+                .map(|(number, _)| number)
+                .collect::<Vec<_>>(),
+        )
+    };
+    assert_eq!(numbers(buffer.window("", None, 0..2)), (3, vec![1, 2]));
+    assert_eq!(numbers(buffer.window("error", None, 0..10)), (1, vec![2]));
+    let warn = Some(log_buffer::LogLevel::Warn);
+    assert_eq!(numbers(buffer.window("", warn, 0..10)), (2, vec![1, 2]));
+    buffer.push("WARN e");
+    assert_eq!(numbers(buffer.window("", warn, 0..10)), (2, vec![2, 4]));
+    buffer.clear();
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.dropped(), 5);
+}
+
+#[test]
+fn log_view_state_tracks_follow_and_scroll() {
+    let _guard = guard();
+    assert_eq!(
+        log_view_state::follow_after_scroll(true, 100.0, 100.2, 100.0),
+        None
+    );
+    assert_eq!(
+        log_view_state::follow_after_scroll(true, 100.0, 40.0, 100.0),
+        Some(false)
+    );
+    assert_eq!(
+        log_view_state::follow_after_scroll(false, 40.0, 99.0, 100.0),
+        Some(true)
+    );
+    assert_eq!(
+        log_view_state::follow_after_scroll(false, 40.0, 60.0, 100.0),
+        None
+    );
+
+    let id = "log";
+    assert!(log_view_state::following(id, None));
+    log_view_state::set_following(id, false);
+    assert!(!log_view_state::following(id, None));
+    assert!(log_view_state::following(id, Some(true)));
+
+    assert!(log_view_state::store_scroll_y(id, 30.0, 18.0));
+    assert!(!log_view_state::store_scroll_y(id, 35.0, 18.0));
+    assert_eq!(log_view_state::scroll_y(id), 35.0);
+    assert_eq!(log_view_state::viewport(id, 360.0), 360.0);
+    assert!(log_view_state::store_viewport(id, 240.0));
+    assert!(!log_view_state::store_viewport(id, 240.3));
+    assert_eq!(log_view_state::viewport(id, 360.0), 240.0);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_tree_state_pages_rows_and_reveals_matches() {
+    use super::json_tree_state::{self, JsonKey, JsonRowKind};
+
+    let _guard = guard();
+    let doc = serde_json::json!({
+        "users": [{ "name": "Ada", "tags": ["a", "b", "c"] }, { "first name": "Bo" }],
+        "ok": true
+    });
+    let pointers = |rows: &[json_tree_state::JsonRow<'_>]| {
+        rows.iter()
+            .map(|row| row.pointer.clone())
+            .collect::<Vec<_>>()
+    };
+    let rows = json_tree_state::visible_rows(&doc, |_, depth| depth < 1, |_| 100);
+    assert_eq!(pointers(&rows), ["", "/ok", "/users"]);
+    assert!(rows[0].expanded && !rows[2].expanded);
+
+    let rows = json_tree_state::visible_rows(&doc, |_, depth| depth < 4, |_| 2);
+    assert_eq!(rows.len(), 11);
+    assert_eq!(rows[8].pointer, "/users/0/tags");
+    assert_eq!(rows[8].kind, JsonRowKind::More { remaining: 1 });
+    assert_eq!(rows[10].key, Some(JsonKey::Field("first name".into())));
+
+    assert_eq!(
+        json_tree_state::display_path(&doc, &rows[10].pointer),
+        "$.users[1][\"first name\"]"
+    );
+    assert_eq!(
+        json_tree_state::child_pointer("", &JsonKey::Field("a/b~".into())),
+        "/a~1b~0"
+    );
+    assert_eq!(json_tree_state::copy_text(&doc["users"][0]["name"]), "Ada");
+    assert_eq!(
+        json_tree_state::summary(&doc["users"]).as_deref(),
+        Some("2 items")
+    );
+
+    let found = json_tree_state::search(&doc, "bo");
+    assert_eq!(found.matches, ["/users/1/first name"]);
+    assert_eq!(found.reveal.get(""), Some(&2));
+    assert_eq!(found.reveal.get("/users"), Some(&2));
+    assert_eq!(found.reveal.get("/users/1"), Some(&1));
+    assert!(json_tree_state::search(&doc, "").matches.is_empty());
+
+    let id = "json";
+    json_tree_state::set_toggled(id, "/users", false);
+    assert!(json_tree_state::toggles(id).1.contains("/users"));
+    json_tree_state::set_toggled(id, "/users", true);
+    let (opened, closed) = json_tree_state::toggles(id);
+    assert!(opened.contains("/users") && closed.is_empty());
+    assert_eq!(json_tree_state::shown(id, "/users", 100), 100);
+    json_tree_state::show_more(id, "/users", 100);
+    assert_eq!(json_tree_state::shown(id, "/users", 100), 200);
+    assert!(json_tree_state::query_changed(id, "bo"));
+    assert!(!json_tree_state::query_changed(id, "bo"));
+}
@@ -4,6 +4,7 @@ use gpui::{IntoElement, ParentElement, RenderOnce, SharedString, Styled, div};
 use crate::id::ComponentId;
 use crate::style::Size;
 
+use super::text_selection::{FindHighlight, FindScope, SelectableText};
 use super::utils::resolve_hsla;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -26,6 +27,8 @@ pub struct Text {
     truncate: bool,
     line_clamp: Option<usize>,
     with_ellipsis: bool,
+    selectable: bool,
+    find_scope: Option<FindScope>,
     pub(crate) theme: crate::theme::LocalTheme,
     style: gpui::StyleRefinement,
 }
@@ -41,6 +44,8 @@ impl Text {
             truncate: false,
             line_clamp: None,
             with_ellipsis: true,
+            selectable: false,
+            find_scope: None,
             theme: crate::theme::LocalTheme::default(),
             style: gpui::StyleRefinement::default(),
         }
@@ -65,6 +70,17 @@ impl Text {
         self
     }
 
+    pub fn selectable(mut self, value: bool) -> Self {
+        self.selectable = value;
+        self
+    }
+
+    /// Makes the text searchable by the `FindBar` that owns `scope`.
+    pub fn find_scope(mut self, scope: FindScope) -> Self {
+        self.find_scope = Some(scope);
+        self
+    }
+
     fn resolved_text_color(&self) -> gpui::Hsla {
         let tokens = &self.theme.components.text;
         let token = match self.tone {
@@ -113,6 +129,16 @@ impl RenderOnce for Text {
         }
 
         gpui::Refineable::refine(gpui::Styled::style(&mut node), &self.style);
+        if self.selectable || self.find_scope.is_some() {
+            let selection_bg = resolve_hsla(&self.theme, self.theme.components.text.selection_bg);
+            let find = self
+                .find_scope
+                .map(|scope| FindHighlight::new(scope, &self.theme));
+            return node.child(
+                SelectableText::new(self.id.scoped("selection"), self.content, selection_bg)
+                    .find(find),
+            );
+        }
         node.child(self.content)
     }
 }
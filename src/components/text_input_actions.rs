@@ -4,6 +4,7 @@ use gpui::{App, KeyBinding, actions};
 
 pub const INPUT_KEY_CONTEXT: &str = "calmui_text_input";
 pub const TEXTAREA_KEY_CONTEXT: &str = "calmui_textarea";
+pub const CONSOLE_KEY_CONTEXT: &str = "calmui_console_input";
 
 actions!(
     calmui_text_input,
@@ -28,6 +29,9 @@ actions!(
         PasteClipboard,
         Submit,
         InsertNewline,
+        HistoryPrevious,
+        HistoryNext,
+        CompleteInput,
     ]
 );
 
@@ -39,6 +43,8 @@ pub fn ensure_text_keybindings(cx: &mut App) {
         cx.bind_keys(common_bindings(TEXTAREA_KEY_CONTEXT));
         cx.bind_keys(textarea_only_bindings());
         cx.bind_keys(input_only_bindings());
+        cx.bind_keys(common_bindings(CONSOLE_KEY_CONTEXT));
+        cx.bind_keys(console_only_bindings());
     });
 }
 
@@ -79,14 +85,31 @@ fn textarea_only_bindings() -> Vec<KeyBinding> {
     ]
 }
 
+fn console_only_bindings() -> Vec<KeyBinding> {
+    vec![
+        KeyBinding::new("enter", Submit, Some(CONSOLE_KEY_CONTEXT)),
+        KeyBinding::new("shift-enter", InsertNewline, Some(CONSOLE_KEY_CONTEXT)),
+        KeyBinding::new("up", HistoryPrevious, Some(CONSOLE_KEY_CONTEXT)),
+        KeyBinding::new("down", HistoryNext, Some(CONSOLE_KEY_CONTEXT)),
+        KeyBinding::new("shift-up", SelectUp, Some(CONSOLE_KEY_CONTEXT)),
+        KeyBinding::new("shift-down", SelectDown, Some(CONSOLE_KEY_CONTEXT)),
+        KeyBinding::new("tab", CompleteInput, Some(CONSOLE_KEY_CONTEXT)),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
+
+    use gpui::{Action, KeyBindingContextPredicate, Keystroke};
+
     use super::*;
 
     #[test]
     fn key_context_constants_are_stable() {
         assert_eq!(INPUT_KEY_CONTEXT, "calmui_text_input");
         assert_eq!(TEXTAREA_KEY_CONTEXT, "calmui_textarea");
+        assert_eq!(CONSOLE_KEY_CONTEXT, "calmui_console_input");
     }
 
     #[test]
@@ -102,4 +125,37 @@ mod tests {
         assert_eq!(input.len(), 1);
         assert!(textarea.len() >= 3);
     }
+
+    fn binding_for<'a>(bindings: &'a [KeyBinding], keys: &str) -> &'a KeyBinding {
+        let typed = [Keystroke::parse(keys).expect("valid keystroke")];
+        bindings
+            .iter()
+            .find(|binding| binding.match_keystrokes(&typed) == Some(false))
+            .unwrap_or_else(|| panic!("no binding for {keys}"))
+    }
+
+    #[test]
+    fn console_bindings_split_submit_and_newline() {
+        let console = console_only_bindings();
+        assert_eq!(console.len(), 7);
+
+        let context = KeyBindingContextPredicate::parse(CONSOLE_KEY_CONTEXT)
+            .ok()
+            .map(Rc::new);
+        let expected: [(&str, &dyn Action); 4] = [
+            ("enter", &Submit),
+            ("shift-enter", &InsertNewline),
+            ("up", &HistoryPrevious),
+            ("down", &HistoryNext),
+        ];
+        for (keys, action) in expected {
+            let binding = binding_for(&console, keys);
+            assert!(
+                binding.action().partial_eq(action),
+                "{keys} is bound to {}",
+                binding.action().name()
+            );
+            assert_eq!(binding.predicate(), context, "{keys}");
+        }
+    }
 }
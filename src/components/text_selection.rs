@@ -0,0 +1,605 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::{Arc, LazyLock, Mutex, Once, RwLock};
+
+use gpui::{
+    AnyElement, App, ClipboardItem, FocusHandle, HighlightStyle, Hsla, InteractiveElement,
+    IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseMoveEvent, ParentElement, Pixels,
+    Point, RenderOnce, SharedString, StatefulInteractiveElement, Styled, StyledText, TextRun,
+    Window, actions, div,
+};
+
+use crate::id::ComponentId;
+use crate::theme::Theme;
+
+use super::control;
+use super::utils::resolve_hsla;
+
+pub const SELECTABLE_TEXT_KEY_CONTEXT: &str = "calmui_selectable_text";
+
+actions!(calmui_selectable_text, [CopySelectedText, SelectAllText]);
+
+type TextWrapper = Box<dyn FnOnce(StyledText) -> AnyElement>;
+
+static BINDINGS_INIT: Once = Once::new();
+static SELECTABLE_FOCUS_HANDLES: LazyLock<Mutex<HashMap<String, FocusHandle>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn ensure_selection_keybindings(cx: &mut App) {
+    BINDINGS_INIT.call_once(|| {
+        cx.bind_keys([
+            KeyBinding::new("cmd-c", CopySelectedText, Some(SELECTABLE_TEXT_KEY_CONTEXT)),
+            KeyBinding::new(
+                "ctrl-c",
+                CopySelectedText,
+                Some(SELECTABLE_TEXT_KEY_CONTEXT),
+            ),
+            KeyBinding::new("cmd-a", SelectAllText, Some(SELECTABLE_TEXT_KEY_CONTEXT)),
+            KeyBinding::new("ctrl-a", SelectAllText, Some(SELECTABLE_TEXT_KEY_CONTEXT)),
+        ]);
+    });
+}
+
+fn focus_handle_for(id: &str, cx: &App) -> FocusHandle {
+    if let Ok(mut handles) = SELECTABLE_FOCUS_HANDLES.lock() {
+        return handles
+            .entry(id.to_string())
+            .or_insert_with(|| cx.focus_handle())
+            .clone();
+    }
+    cx.focus_handle()
+}
+
+fn char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+pub(super) fn selection_range(id: &str, len: usize) -> Option<Range<usize>> {
+    let anchor = control::optional_usize_state(id, "selection-anchor", None, None)?;
+    let head = control::optional_usize_state(id, "selection-head", None, None)?;
+    let start = anchor.min(head).min(len);
+    let end = anchor.max(head).min(len);
+    (start < end).then_some(start..end)
+}
+
+pub(super) fn set_selection(id: &str, anchor: usize, head: usize) {
+    control::set_optional_usize_state(id, "selection-anchor", Some(anchor));
+    control::set_optional_usize_state(id, "selection-head", Some(head));
+}
+
+pub(super) fn clear_selection(id: &str) {
+    control::set_optional_usize_state(id, "selection-anchor", None);
+    control::set_optional_usize_state(id, "selection-head", None);
+    control::set_bool_state(id, "selection-dragging", false);
+}
+
+/// Byte range of the word, whitespace run or punctuation mark at `index`,
+/// matching what a double-click selects in editable fields.
+pub(super) fn word_range(text: &str, index: usize) -> Range<usize> {
+    fn class(ch: char) -> u8 {
+        if ch.is_alphanumeric() || ch == '_' {
+            0
+        } else if ch.is_whitespace() {
+            1
+        } else {
+            2
+        }
+    }
+
+    let index = char_boundary(text, index);
+    let Some(ch) = text[index..]
+        .chars()
+        .next()
+        .or_else(|| text[..index].chars().next_back())
+    else {
+        return index..index;
+    };
+    let kind = class(ch);
+    let pivot = if index == text.len() {
+        index - ch.len_utf8()
+    } else {
+        index
+    };
+    if kind == 2 {
+        return pivot..pivot + ch.len_utf8();
+    }
+    let start = text[..pivot]
+        .char_indices()
+        .rev()
+        .take_while(|(_, ch)| class(*ch) == kind)
+        .last()
+        .map_or(pivot, |(offset, _)| offset);
+    let end = text[pivot..]
+        .char_indices()
+        .find(|(_, ch)| class(*ch) != kind)
+        .map_or(text.len(), |(offset, _)| pivot + offset);
+    start..end
+}
+
+/// Splits `runs` so the bytes in `range` carry `background`.
+pub(super) fn highlight_runs(
+    runs: Vec<TextRun>,
+    range: Range<usize>,
+    background: Hsla,
+) -> Vec<TextRun> {
+    let mut highlighted = Vec::with_capacity(runs.len() + 2);
+    let mut offset = 0;
+    for run in runs {
+        let start = offset;
+        let end = offset + run.len;
+        offset = end;
+        let selected_start = range.start.clamp(start, end);
+        let selected_end = range.end.clamp(start, end);
+        if selected_start >= selected_end {
+            highlighted.push(run);
+            continue;
+        }
+        if selected_start > start {
+            highlighted.push(TextRun {
+                len: selected_start - start,
+                ..run.clone()
+            });
+        }
+        highlighted.push(TextRun {
+            len: selected_end - selected_start,
+            background_color: Some(background),
+            ..run.clone()
+        });
+        if end > selected_end {
+            highlighted.push(TextRun {
+                len: end - selected_end,
+                ..run
+            });
+        }
+    }
+    highlighted
+}
+
+/// Byte ranges of every non-overlapping occurrence of `query` in `text`.
+/// Case-insensitive matching folds ASCII letters only so ranges always map
+/// back onto the original text.
+pub(super) fn find_ranges(text: &str, query: &str, case_sensitive: bool) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    if query.is_empty() {
+        return ranges;
+    }
+    let needle = query.as_bytes();
+    let mut index = 0;
+    while index + needle.len() <= text.len() {
+        let candidate = &text.as_bytes()[index..index + needle.len()];
+        let found = if case_sensitive {
+            candidate == needle
+        } else {
+            candidate.eq_ignore_ascii_case(needle)
+        };
+        if found {
+            ranges.push(index..index + needle.len());
+            index += needle.len();
+        } else {
+            index += text[index..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    ranges
+}
+
+/// Flattens stacked background layers into sorted, non-overlapping ranges;
+/// later layers paint over earlier ones.
+pub(super) fn layer_backgrounds(layers: &[(Range<usize>, Hsla)]) -> Vec<(Range<usize>, Hsla)> {
+    let mut edges: Vec<usize> = layers
+        .iter()
+        .flat_map(|(range, _)| [range.start, range.end])
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+
+    let mut flattened: Vec<(Range<usize>, Hsla)> = Vec::new();
+    for pair in edges.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let Some((_, color)) = layers
+            .iter()
+            .rev()
+            .find(|(range, _)| range.start <= start && end <= range.end)
+        else {
+            continue;
+        };
+        match flattened.last_mut() {
+            Some((last, last_color)) if last.end == start && *last_color == *color => {
+                last.end = end;
+            }
+            _ => flattened.push((start..end, *color)),
+        }
+    }
+    flattened
+}
+
+/// A match found by a [`FindScope`], addressed by the id of the selectable
+/// text that registered it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FindMatch {
+    pub source: SharedString,
+    pub range: Range<usize>,
+}
+
+#[derive(Default)]
+struct FindScopeState {
+    sources: Vec<(SharedString, SharedString)>,
+    query: String,
+    case_sensitive: bool,
+    active: usize,
+    open: bool,
+}
+
+impl FindScopeState {
+    fn matches(&self) -> Vec<FindMatch> {
+        self.sources
+            .iter()
+            .flat_map(|(source, text)| {
+                find_ranges(text, &self.query, self.case_sensitive)
+                    .into_iter()
+                    .map(|range| FindMatch {
+                        source: source.clone(),
+                        range,
+                    })
+            })
+            .collect()
+    }
+
+    fn active_index(&self, count: usize) -> Option<usize> {
+        (count > 0).then(|| self.active.min(count - 1))
+    }
+}
+
+/// Shared search state for a `FindBar` and the selectable text inside it.
+///
+/// Text, Markdown and Table cells given a scope register their text while
+/// rendering, in render order; sources that leave the tree stay registered
+/// until `unregister` or `clear_sources` is called.
+#[derive(Clone, Default)]
+pub struct FindScope {
+    state: Arc<RwLock<FindScopeState>>,
+}
+
+impl FindScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a source, or replaces its text when the id is already known.
+    pub fn register(&self, id: impl Into<SharedString>, text: impl Into<SharedString>) {
+        let id = id.into();
+        let text = text.into();
+        let mut state = self.state.write().expect("find scope poisoned");
+        match state.sources.iter_mut().find(|(source, _)| *source == id) {
+            Some((_, current)) => {
+                if *current != text {
+                    *current = text;
+                }
+            }
+            None => state.sources.push((id, text)),
+        }
+    }
+
+    pub fn unregister(&self, id: &str) -> bool {
+        let mut state = self.state.write().expect("find scope poisoned");
+        let before = state.sources.len();
+        state.sources.retain(|(source, _)| source.as_ref() != id);
+        state.sources.len() != before
+    }
+
+    pub fn clear_sources(&self) {
+        self.state
+            .write()
+            .expect("find scope poisoned")
+            .sources
+            .clear();
+    }
+
+    pub fn query(&self) -> String {
+        self.state
+            .read()
+            .expect("find scope poisoned")
+            .query
+            .clone()
+    }
+
+    /// Updates the query and moves the active match back to the first hit.
+    pub fn set_query(&self, value: impl Into<String>) {
+        let value = value.into();
+        let mut state = self.state.write().expect("find scope poisoned");
+        if state.query != value {
+            state.query = value;
+            state.active = 0;
+        }
+    }
+
+    pub fn case_sensitive(&self) -> bool {
+        self.state
+            .read()
+            .expect("find scope poisoned")
+            .case_sensitive
+    }
+
+    pub fn set_case_sensitive(&self, value: bool) {
+        let mut state = self.state.write().expect("find scope poisoned");
+        if state.case_sensitive != value {
+            state.case_sensitive = value;
+            state.active = 0;
+        }
+    }
+
+    pub fn matches(&self) -> Vec<FindMatch> {
+        self.state.read().expect("find scope poisoned").matches()
+    }
+
+    pub fn match_count(&self) -> usize {
+        self.matches().len()
+    }
+
+    pub fn active_index(&self) -> Option<usize> {
+        let state = self.state.read().expect("find scope poisoned");
+        state.active_index(state.matches().len())
+    }
+
+    pub fn active_match(&self) -> Option<FindMatch> {
+        let state = self.state.read().expect("find scope poisoned");
+        let mut matches = state.matches();
+        let index = state.active_index(matches.len())?;
+        Some(matches.swap_remove(index))
+    }
+
+    /// Moves to the next match, wrapping after the last one.
+    pub fn select_next(&self) -> Option<FindMatch> {
+        self.step(1)
+    }
+
+    /// Moves to the previous match, wrapping before the first one.
+    pub fn select_previous(&self) -> Option<FindMatch> {
+        self.step(-1)
+    }
+
+    fn step(&self, delta: isize) -> Option<FindMatch> {
+        let mut state = self.state.write().expect("find scope poisoned");
+        let mut matches = state.matches();
+        let current = state.active_index(matches.len())?;
+        let next = (current as isize + delta).rem_euclid(matches.len() as isize) as usize;
+        state.active = next;
+        Some(matches.swap_remove(next))
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.state.read().expect("find scope poisoned").open
+    }
+
+    pub fn open(&self) {
+        self.state.write().expect("find scope poisoned").open = true;
+    }
+
+    /// Hides the bar and its highlights; the query is kept for the next open.
+    pub fn close(&self) {
+        self.state.write().expect("find scope poisoned").open = false;
+    }
+
+    /// Match ranges inside `source`, flagged when they hold the active match.
+    pub(super) fn highlights_for(&self, source: &str) -> Vec<(Range<usize>, bool)> {
+        let state = self.state.read().expect("find scope poisoned");
+        if !state.open {
+            return Vec::new();
+        }
+        let matches = state.matches();
+        let active = state.active_index(matches.len());
+        matches
+            .into_iter()
+            .enumerate()
+            .filter(|(_, found)| found.source.as_ref() == source)
+            .map(|(index, found)| (found.range, Some(index) == active))
+            .collect()
+    }
+}
+
+/// Find-match colors for selectable text rendered inside a [`FindScope`].
+#[derive(Clone)]
+pub(super) struct FindHighlight {
+    scope: FindScope,
+    match_bg: Hsla,
+    active_bg: Hsla,
+}
+
+impl FindHighlight {
+    pub(super) fn new(scope: FindScope, theme: &Theme) -> Self {
+        let tokens = &theme.components.find_bar;
+        Self {
+            scope,
+            match_bg: resolve_hsla(theme, tokens.match_bg),
+            active_bg: resolve_hsla(theme, tokens.active_match_bg),
+        }
+    }
+}
+
+#[derive(IntoElement)]
+pub(super) struct SelectableText {
+    id: ComponentId,
+    text: SharedString,
+    runs: Option<Vec<TextRun>>,
+    selection_bg: Hsla,
+    find: Option<FindHighlight>,
+    wrap: Option<TextWrapper>,
+}
+
+impl SelectableText {
+    pub(super) fn new(
+        id: impl Into<ComponentId>,
+        text: impl Into<SharedString>,
+        selection_bg: Hsla,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            text: text.into(),
+            runs: None,
+            selection_bg,
+            find: None,
+            wrap: None,
+        }
+    }
+
+    pub(super) fn runs(mut self, runs: Vec<TextRun>) -> Self {
+        self.runs = Some(runs);
+        self
+    }
+
+    pub(super) fn find(mut self, value: Option<FindHighlight>) -> Self {
+        self.find = value;
+        self
+    }
+
+    /// Lets callers decorate the styled text, e.g. to keep link hit-testing.
+    pub(super) fn wrap(mut self, wrap: impl FnOnce(StyledText) -> AnyElement + 'static) -> Self {
+        self.wrap = Some(Box::new(wrap));
+        self
+    }
+}
+
+impl RenderOnce for SelectableText {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        ensure_selection_keybindings(cx);
+        let focus_handle = focus_handle_for(&self.id, cx);
+        let mut layers = Vec::new();
+        if let Some(find) = self.find.as_ref() {
+            let scope = &find.scope;
+            scope.register(self.id.to_string(), self.text.clone());
+            layers.extend(
+                scope
+                    .highlights_for(&self.id)
+                    .into_iter()
+                    .map(|(range, active)| {
+                        let color = if active {
+                            find.active_bg
+                        } else {
+                            find.match_bg
+                        };
+                        (range, color)
+                    }),
+            );
+        }
+        layers.extend(
+            selection_range(&self.id, self.text.len()).map(|range| (range, self.selection_bg)),
+        );
+        let backgrounds = layer_backgrounds(&layers);
+        let styled = StyledText::new(self.text.clone());
+        let styled = match self.runs {
+            Some(runs) => {
+                styled.with_runs(backgrounds.into_iter().fold(runs, |runs, (range, color)| {
+                    highlight_runs(runs, range, color)
+                }))
+            }
+            None if backgrounds.is_empty() => styled,
+            None => styled.with_highlights(backgrounds.into_iter().map(|(range, color)| {
+                (
+                    range,
+                    HighlightStyle {
+                        background_color: Some(color),
+                        ..HighlightStyle::default()
+                    },
+                )
+            })),
+        };
+        let layout = styled.layout().clone();
+        let content = match self.wrap {
+            Some(wrap) => wrap(styled),
+            None => styled.into_any_element(),
+        };
+
+        let index_at = {
+            let text = self.text.clone();
+            move |position: Point<Pixels>| {
+                let index = layout.index_for_position(position).unwrap_or_else(|i| i);
+                char_boundary(&text, index)
+            }
+        };
+        let index_for_move = index_at.clone();
+
+        div()
+            .id(self.id.slot("selectable"))
+            .key_context(SELECTABLE_TEXT_KEY_CONTEXT)
+            .track_focus(&focus_handle)
+            .cursor_text()
+            .on_mouse_down(MouseButton::Left, {
+                let id = self.id.clone();
+                let text = self.text.clone();
+                move |event: &MouseDownEvent, window, cx| {
+                    window.focus(&focus_handle, cx);
+                    let index = index_at(event.position);
+                    if event.click_count >= 2 {
+                        let word = word_range(&text, index);
+                        set_selection(&id, word.start, word.end);
+                    } else if event.modifiers.shift
+                        && let Some(anchor) =
+                            control::optional_usize_state(&id, "selection-anchor", None, None)
+                    {
+                        set_selection(&id, anchor, index);
+                        control::set_bool_state(&id, "selection-dragging", true);
+                    } else {
+                        set_selection(&id, index, index);
+                        control::set_bool_state(&id, "selection-dragging", true);
+                    }
+                    window.refresh();
+                }
+            })
+            .on_mouse_move({
+                let id = self.id.clone();
+                move |event: &MouseMoveEvent, window, _| {
+                    if event.pressed_button != Some(MouseButton::Left)
+                        || !control::bool_state(&id, "selection-dragging", None, false)
+                    {
+                        return;
+                    }
+                    let Some(anchor) =
+                        control::optional_usize_state(&id, "selection-anchor", None, None)
+                    else {
+                        return;
+                    };
+                    set_selection(&id, anchor, index_for_move(event.position));
+                    window.refresh();
+                }
+            })
+            .on_mouse_up(MouseButton::Left, {
+                let id = self.id.clone();
+                move |_, _, _| control::set_bool_state(&id, "selection-dragging", false)
+            })
+            .on_mouse_up_out(MouseButton::Left, {
+                let id = self.id.clone();
+                move |_, _, _| control::set_bool_state(&id, "selection-dragging", false)
+            })
+            .on_mouse_down_out({
+                let id = self.id.clone();
+                let len = self.text.len();
+                move |_, window, _| {
+                    if selection_range(&id, len).is_some() {
+                        clear_selection(&id);
+                        window.refresh();
+                    }
+                }
+            })
+            .on_action({
+                let id = self.id.clone();
+                let text = self.text.clone();
+                move |_: &CopySelectedText, _, cx| {
+                    if let Some(range) = selection_range(&id, text.len()) {
+                        cx.write_to_clipboard(ClipboardItem::new_string(text[range].to_string()));
+                    }
+                }
+            })
+            .on_action({
+                let id = self.id.clone();
+                let len = self.text.len();
+                move |_: &SelectAllText, window, _| {
+                    set_selection(&id, 0, len);
+                    window.refresh();
+                }
+            })
+            .child(content)
+    }
+}
@@ -20,9 +20,18 @@ use crate::id::ComponentId;
 use crate::motion::MotionConfig;
 use crate::style::{FieldLayout, Radius, Size, Variant};
 
+#[cfg(feature = "spellcheck")]
+use crate::spellcheck::{self, Misspelling, SharedSpellChecker, SpellChecker};
+
 use super::Stack;
+use super::composition::{self, CompositionUnderline, composition_underlines};
+#[cfg(feature = "spellcheck")]
+use super::context_menu::{ContextMenu, ContextMenuItem};
 use super::control;
 use super::field_variant::FieldVariantRuntime;
+use super::paste_attachment::{PasteAttachment, PasteAttachmentHandler, take_pasted_text};
+#[cfg(feature = "spellcheck")]
+use super::spell_underline::{SpellUnderline, spell_underlines};
 use super::text_input_actions::{
     CopySelection, CutSelection, DeleteBackward, DeleteForward, InsertNewline, MoveDown, MoveEnd,
     MoveHome, MoveLeft, MoveRight, MoveUp, PasteClipboard, SelectAll, SelectDown, SelectEnd,
@@ -37,6 +46,8 @@ type NormalizedEdit = (String, usize, SelectionRange, SelectionRange);
 
 const CARET_BLINK_TOGGLE_MS: u64 = 680;
 const CARET_BLINK_CYCLE_MS: u64 = CARET_BLINK_TOGGLE_MS * 2;
+#[cfg(feature = "spellcheck")]
+const SPELL_SUGGESTION_LIMIT: usize = 5;
 
 #[derive(Clone)]
 struct WrappedLine {
@@ -108,26 +119,11 @@ impl TextareaImeHandler {
     }
 
     fn marked_range_chars(&self, len: usize) -> Option<(usize, usize)> {
-        let start = control::optional_usize_state(&self.id, "marked-start", None, None)
-            .unwrap_or(usize::MAX);
-        let end =
-            control::optional_usize_state(&self.id, "marked-end", None, None).unwrap_or(usize::MAX);
-        if start == usize::MAX || end == usize::MAX {
-            return None;
-        }
-        let start = start.min(len);
-        let end = end.min(len);
-        (start < end).then_some((start, end))
+        composition::marked_range(&self.id, len)
     }
 
     fn set_marked_range_chars(&self, marked: Option<(usize, usize)>) {
-        if let Some((start, end)) = marked {
-            control::set_optional_usize_state(&self.id, "marked-start", Some(start));
-            control::set_optional_usize_state(&self.id, "marked-end", Some(end));
-        } else {
-            control::set_optional_usize_state(&self.id, "marked-start", None);
-            control::set_optional_usize_state(&self.id, "marked-end", None);
-        }
+        composition::set_marked_range(&self.id, marked);
     }
 
     fn resolve_replacement_range(
@@ -317,13 +313,19 @@ impl InputHandler for TextareaImeHandler {
         });
 
         let caret = selection.map(|(_, end)| end).unwrap_or(fallback_caret);
-        let (next, caret, marked, selection) =
-            self.apply_max_length(next, caret, marked, selection);
         self.apply_edit_result(&value, next, caret, selection, marked, window, cx);
     }
 
-    fn unmark_text(&mut self, _window: &mut Window, _cx: &mut gpui::App) {
-        self.set_marked_range_chars(None);
+    fn unmark_text(&mut self, window: &mut Window, cx: &mut gpui::App) {
+        let value = self.current_value();
+        let len = value.chars().count();
+        if self.marked_range_chars(len).is_none() {
+            return;
+        }
+        let caret = control::usize_state(&self.id, "caret-index", None, len).min(len);
+        let (next, caret, _marked, _selection) =
+            self.apply_max_length(value.clone(), caret, None, None);
+        self.apply_edit_result(&value, next, caret, None, None, window, cx);
     }
 
     fn bounds_for_range(
@@ -418,7 +420,11 @@ pub struct Textarea {
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
     focus_handle: Option<FocusHandle>,
+    key_context: &'static str,
     on_change: Option<ChangeHandler>,
+    on_paste_attachment: Option<PasteAttachmentHandler>,
+    #[cfg(feature = "spellcheck")]
+    spell_checker: Option<SharedSpellChecker>,
 }
 
 impl Textarea {
@@ -460,7 +466,11 @@ impl Textarea {
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::default(),
             focus_handle: None,
+            key_context: TEXTAREA_KEY_CONTEXT,
             on_change: None,
+            on_paste_attachment: None,
+            #[cfg(feature = "spellcheck")]
+            spell_checker: None,
         }
     }
 
@@ -546,6 +556,26 @@ impl Textarea {
         self
     }
 
+    pub fn on_paste_attachment(
+        mut self,
+        handler: impl Fn(Vec<PasteAttachment>, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_paste_attachment = Some(Rc::new(handler));
+        self
+    }
+
+    pub(crate) fn key_context(mut self, value: &'static str) -> Self {
+        self.key_context = value;
+        self
+    }
+
+    pub(crate) fn replace_uncontrolled_value(id: &str, value: &str) {
+        let len = value.chars().count();
+        control::set_text_state(id, "value", value.to_string());
+        control::set_optional_f32_state(id, "preferred-x", None);
+        Self::persist_editor_state(id, &InputState::new(value.to_string(), len, len, None));
+    }
+
     fn resolved_value(&self) -> SharedString {
         let controlled = self
             .value_controlled
@@ -1175,7 +1205,7 @@ impl Textarea {
             .id(self.id.slot("box"))
             .relative()
             .focusable()
-            .key_context(TEXTAREA_KEY_CONTEXT)
+            .key_context(self.key_context)
             .flex()
             .flex_col()
             .items_start()
@@ -1369,9 +1399,17 @@ impl Textarea {
         input = input.on_mouse_down_out(move |_, window, _cx| {
             control::set_focused_state(&id_for_blur, false);
             control::set_bool_state(&id_for_blur, "mouse-selecting", false);
+            composition::commit(&id_for_blur);
             window.refresh();
         });
 
+        #[cfg(feature = "spellcheck")]
+        let misspellings = self.misspellings(&current_value);
+        #[cfg(feature = "spellcheck")]
+        if self.spell_checker.is_some() {
+            input = self.bind_spell_target(input, current_value.clone(), misspellings.clone());
+        }
+
         let max_length = self.max_length;
         if !self.disabled {
             let input_id = self.id.clone();
@@ -1899,6 +1937,9 @@ impl Textarea {
                         let rendered_value = current_value.clone();
                         let on_change = self.on_change.clone();
                         move |_: &InsertNewline, window, cx| {
+                            if composition::is_composing(&input_id) {
+                                return;
+                            }
                             control::set_optional_f32_state(&input_id, "preferred-x", None);
                             let current_value = control::text_state(
                                 &input_id,
@@ -1957,12 +1998,14 @@ impl Textarea {
                         let input_id = self.id.clone();
                         let rendered_value = current_value.clone();
                         let on_change = self.on_change.clone();
+                        let on_paste_attachment = self.on_paste_attachment.clone();
                         move |_: &PasteClipboard, window, cx| {
                             control::set_optional_f32_state(&input_id, "preferred-x", None);
                             let Some(item) = cx.read_from_clipboard() else {
                                 return;
                             };
-                            let Some(pasted) = item.text() else {
+                            let handler = on_paste_attachment.as_ref();
+                            let Some(pasted) = take_pasted_text(&item, handler, window, cx) else {
                                 return;
                             };
                             let normalized = pasted.replace("\r\n", "\n").replace('\r', "\n");
@@ -2128,6 +2171,30 @@ impl Textarea {
                     .size_full()
                 })
                 .child(content);
+            #[cfg(feature = "spellcheck")]
+            if !misspellings.is_empty() {
+                let color = resolve_hsla(&self.theme, tokens.spell_error);
+                let underlines = Self::spell_underlines(
+                    &misspellings,
+                    &wrapped_lines,
+                    window,
+                    font_size,
+                    line_height,
+                );
+                content_host = content_host.child(spell_underlines(underlines, color));
+            }
+            if let Some(marked) = composition::marked_range(&self.id, current_value.chars().count())
+            {
+                let underlines = Self::composition_underlines(
+                    marked,
+                    &wrapped_lines,
+                    window,
+                    font_size,
+                    line_height,
+                );
+                let color = resolve_hsla(&self.theme, tokens.fg);
+                content_host = content_host.child(composition_underlines(underlines, color));
+            }
             if !self.disabled && !self.read_only && is_focused && selection.is_none() {
                 let caret_left = wrapped_lines
                     .get(caret_line)
@@ -2171,7 +2238,213 @@ impl Textarea {
     }
 }
 
-impl Textarea {}
+impl Textarea {
+    fn composition_underlines(
+        marked: (usize, usize),
+        wrapped_lines: &[WrappedLine],
+        window: &Window,
+        font_size: f32,
+        line_height: f32,
+    ) -> Vec<CompositionUnderline> {
+        wrapped_lines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let (start, end) =
+                    composition::line_segment(marked, line.start_char, line.end_char)?;
+                Some(CompositionUnderline {
+                    start_x: Self::x_for_char(window, font_size, &line.text, start),
+                    end_x: Self::x_for_char(window, font_size, &line.text, end),
+                    baseline_y: (index + 1) as f32 * line_height - 2.0,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "spellcheck")]
+impl Textarea {
+    pub fn spell_checker(mut self, checker: impl SpellChecker + 'static) -> Self {
+        self.spell_checker = Some(Arc::new(checker));
+        self
+    }
+
+    fn misspellings(&self, value: &str) -> Vec<Misspelling> {
+        let Some(checker) = self.spell_checker.as_ref() else {
+            return Vec::new();
+        };
+        let ignored = control::list_state(&self.id, "spell-ignored", None, Vec::new());
+        spellcheck::misspellings(value, checker.as_ref(), &ignored)
+    }
+
+    fn spell_underlines(
+        misspellings: &[Misspelling],
+        wrapped_lines: &[WrappedLine],
+        window: &Window,
+        font_size: f32,
+        line_height: f32,
+    ) -> Vec<SpellUnderline> {
+        let mut underlines = Vec::new();
+        for (index, line) in wrapped_lines.iter().enumerate() {
+            for misspelling in misspellings {
+                let start = misspelling
+                    .range
+                    .start
+                    .clamp(line.start_char, line.end_char);
+                let end = misspelling.range.end.clamp(line.start_char, line.end_char);
+                if start >= end {
+                    continue;
+                }
+                let start_x =
+                    Self::x_for_char(window, font_size, &line.text, start - line.start_char);
+                let end_x = Self::x_for_char(window, font_size, &line.text, end - line.start_char);
+                underlines.push(SpellUnderline {
+                    start_x,
+                    end_x,
+                    baseline_y: (index + 1) as f32 * line_height - 3.0,
+                });
+            }
+        }
+        underlines
+    }
+
+    fn set_spell_target(id: &str, target: Option<&Misspelling>) {
+        control::set_optional_usize_state(id, "spell-start", target.map(|item| item.range.start));
+        control::set_optional_usize_state(id, "spell-end", target.map(|item| item.range.end));
+        control::set_optional_text_state(id, "spell-word", target.map(|item| item.word.clone()));
+    }
+
+    fn spell_menu_items(&self, checker: &SharedSpellChecker) -> Vec<ContextMenuItem> {
+        let Some(word) = control::optional_text_state(&self.id, "spell-word", None, None) else {
+            return vec![ContextMenuItem {
+                disabled: true,
+                ..ContextMenuItem::labeled("none", "No spelling suggestions")
+            }];
+        };
+        let mut items: Vec<_> = checker
+            .suggest(&word, SPELL_SUGGESTION_LIMIT)
+            .into_iter()
+            .map(|suggestion| ContextMenuItem::labeled(format!("replace:{suggestion}"), suggestion))
+            .collect();
+        if items.is_empty() {
+            items.push(ContextMenuItem {
+                disabled: true,
+                ..ContextMenuItem::labeled("none", "No suggestions")
+            });
+        }
+        items.push(ContextMenuItem::separator());
+        items.push(ContextMenuItem::labeled("ignore", "Ignore"));
+        items.push(ContextMenuItem::labeled("learn", "Add to dictionary"));
+        items
+    }
+
+    fn bind_spell_target(
+        &self,
+        input: gpui::Stateful<gpui::Div>,
+        rendered_value: String,
+        misspellings: Vec<Misspelling>,
+    ) -> gpui::Stateful<gpui::Div> {
+        let id = self.id.to_string();
+        let value_controlled = self.value_controlled;
+        let font_size = self.font_size_px();
+        let line_height = self.line_height_px();
+        let vertical_padding = self.vertical_padding_px();
+        let horizontal_padding = self.horizontal_padding_px();
+        let content_width_fallback =
+            f32::from(self.theme.components.textarea.content_width_fallback);
+        input.on_mouse_down(MouseButton::Right, move |event, window, _| {
+            let current_value = control::text_state(
+                &id,
+                "value",
+                value_controlled.then_some(rendered_value.clone()),
+                rendered_value.clone(),
+            );
+            let caret = Self::caret_from_click(
+                &id,
+                event.position,
+                &current_value,
+                window,
+                font_size,
+                line_height,
+                vertical_padding,
+                horizontal_padding,
+                content_width_fallback,
+            );
+            Self::set_spell_target(&id, spellcheck::misspelling_at(&misspellings, caret));
+            window.refresh();
+        })
+    }
+
+    fn with_spell_menu(&self, input_box: AnyElement) -> AnyElement {
+        let Some(checker) = self.spell_checker.clone() else {
+            return input_box;
+        };
+        let id = self.id.to_string();
+        let value_controlled = self.value_controlled;
+        let rendered_value = self.resolved_value().to_string();
+        let max_length = self.max_length;
+        let on_change = self.on_change.clone();
+        let items = self.spell_menu_items(&checker);
+        self.id
+            .ctx()
+            .child("spell-menu", ContextMenu::new())
+            .target(input_box)
+            .items(items)
+            .on_item_click(move |value, window, cx| {
+                let word = control::optional_text_state(&id, "spell-word", None, None);
+                let start = control::optional_usize_state(&id, "spell-start", None, None);
+                let end = control::optional_usize_state(&id, "spell-end", None, None);
+                let (Some(word), Some(start), Some(end)) = (word, start, end) else {
+                    return;
+                };
+                Self::set_spell_target(&id, None);
+                match &*value {
+                    "ignore" => {
+                        let mut ignored =
+                            control::list_state(&id, "spell-ignored", None, Vec::new());
+                        ignored.push(word);
+                        control::set_list_state(&id, "spell-ignored", ignored);
+                        window.refresh();
+                    }
+                    "learn" => {
+                        checker.add_word(&word);
+                        window.refresh();
+                    }
+                    value => {
+                        let Some(replacement) = value.strip_prefix("replace:") else {
+                            return;
+                        };
+                        let current_value = control::text_state(
+                            &id,
+                            "value",
+                            value_controlled.then_some(rendered_value.clone()),
+                            rendered_value.clone(),
+                        );
+                        let mut state = Self::editor_state_for(&id, &current_value);
+                        state.replace_char_range(start, end, replacement);
+                        state.clamp_to_max_length(max_length);
+                        Self::apply_editor_state(
+                            &id,
+                            &current_value,
+                            &state,
+                            value_controlled,
+                            on_change.as_ref(),
+                            window,
+                            cx,
+                        );
+                    }
+                }
+            })
+            .into_any_element()
+    }
+}
+
+#[cfg(not(feature = "spellcheck"))]
+impl Textarea {
+    fn with_spell_menu(&self, input_box: AnyElement) -> AnyElement {
+        input_box
+    }
+}
 
 impl FieldLike for Textarea {
     fn label(mut self, value: impl Into<SharedString>) -> Self {
@@ -2218,7 +2491,8 @@ impl RenderOnce for Textarea {
                 if let Some(label_block) = self.render_label_block() {
                     container = container.child(label_block);
                 }
-                container.child(self.render_input_box(window, _cx))
+                let input_box = self.render_input_box(window, _cx);
+                container.child(self.with_spell_menu(input_box))
             }
             FieldLayout::Horizontal => {
                 let mut row = Stack::horizontal()
@@ -2232,7 +2506,8 @@ impl RenderOnce for Textarea {
                             .child(label_block),
                     );
                 }
-                row.child(self.render_input_box(window, _cx))
+                let input_box = self.render_input_box(window, _cx);
+                row.child(self.with_spell_menu(input_box))
             }
         }
     }
@@ -0,0 +1,1102 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, FocusHandle, IntoElement, ParentElement, RenderOnce, SharedString, Stateful,
+    Styled, Window, div, px,
+};
+
+use crate::contracts::{FieldLike, MotionAware};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::{FieldLayout, Radius, Size, Variant};
+use crate::theme::Theme;
+
+pub use super::time_picker_state::{CalendarDateTime, ClockTime, TimeColumn};
+
+use super::Stack;
+use super::control;
+use super::date_picker::{CalendarDate, CalendarGrid};
+use super::date_picker_state;
+use super::field_variant::FieldVariantRuntime;
+use super::icon::Icon;
+use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
+use super::popup::{PopupPlacement, anchored_host};
+use super::popup_state::{self, PopupStateInput, PopupStateValue};
+use super::selection_state;
+use super::time_picker_state::{self, TimeKeyOutcome};
+use super::utils::{
+    InteractionStyles, apply_disabled_state, apply_field_size, apply_interaction_styles,
+    apply_radius, interaction_style, resolve_hsla,
+};
+
+type TimeChangeHandler = Rc<dyn Fn(ClockTime, &mut Window, &mut gpui::App)>;
+type DateTimeChangeHandler = Rc<dyn Fn(CalendarDateTime, &mut Window, &mut gpui::App)>;
+type OpenChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
+
+const WHEEL_RADIUS: usize = 3;
+
+#[derive(Clone, Default)]
+struct FieldText {
+    label: Option<SharedString>,
+    description: Option<SharedString>,
+    error: Option<SharedString>,
+    required: bool,
+}
+
+impl FieldText {
+    fn render(&self, theme: &Theme, width: Option<gpui::Pixels>) -> Option<AnyElement> {
+        if self.label.is_none() && self.description.is_none() && self.error.is_none() {
+            return None;
+        }
+        let tokens = &theme.components.date_picker;
+        let mut block = Stack::vertical().gap(tokens.label_block_gap);
+        if let Some(label) = self.label.clone() {
+            let mut row = Stack::horizontal().gap(tokens.label_row_gap).child(
+                div()
+                    .text_size(tokens.label_size)
+                    .font_weight(tokens.label_weight)
+                    .text_color(resolve_hsla(theme, tokens.label))
+                    .child(label),
+            );
+            if self.required {
+                row = row.child(
+                    div()
+                        .text_color(resolve_hsla(theme, theme.semantic.status_error))
+                        .child("*"),
+                );
+            }
+            block = block.child(row);
+        }
+        if let Some(description) = self.description.clone() {
+            block = block.child(
+                div()
+                    .text_size(tokens.description_size)
+                    .text_color(resolve_hsla(theme, tokens.description))
+                    .child(description),
+            );
+        }
+        if let Some(error) = self.error.clone() {
+            block = block.child(
+                div()
+                    .text_size(tokens.error_size)
+                    .text_color(resolve_hsla(theme, tokens.error))
+                    .child(error),
+            );
+        }
+        if let Some(width) = width {
+            block = block.w(width);
+        }
+        Some(block.into_any_element())
+    }
+}
+
+#[derive(Clone)]
+struct OpenCommit {
+    id: ComponentId,
+    controlled: bool,
+    on_open_change: Option<OpenChangeHandler>,
+}
+
+impl OpenCommit {
+    fn set_opened(&self, next: bool, window: &mut Window, cx: &mut gpui::App) {
+        popup_state::apply_opened(&self.id, self.controlled, next);
+        time_picker_state::set_typed_buffer(&self.id, String::new());
+        if !next {
+            date_picker_state::set_focused_date(&self.id, None);
+        }
+        window.refresh();
+        if let Some(handler) = self.on_open_change.as_ref() {
+            (handler)(next, window, cx);
+        }
+    }
+}
+
+struct TriggerControl<'a> {
+    id: &'a ComponentId,
+    theme: &'a Theme,
+    text: Option<SharedString>,
+    placeholder: SharedString,
+    opened: bool,
+    disabled: bool,
+    invalid: bool,
+    size: Size,
+    radius: Radius,
+    variant: Variant,
+    focus_handle: Option<FocusHandle>,
+    open: OpenCommit,
+}
+
+impl TriggerControl<'_> {
+    fn render(self, window: &gpui::Window) -> Stateful<gpui::Div> {
+        let tokens = &self.theme.components.date_picker;
+        let control_bg =
+            FieldVariantRuntime::control_bg(resolve_hsla(self.theme, tokens.bg), self.variant);
+        let border = FieldVariantRuntime::control_border(
+            if self.invalid {
+                resolve_hsla(self.theme, tokens.border_error)
+            } else if self.opened {
+                resolve_hsla(self.theme, tokens.border_focus)
+            } else {
+                resolve_hsla(self.theme, tokens.border)
+            },
+            self.variant,
+            self.opened,
+            self.invalid,
+        );
+
+        let mut control = div()
+            .id(self.id.slot("control"))
+            .relative()
+            .w_full()
+            .flex()
+            .items_center()
+            .gap(tokens.slot_gap)
+            .bg(control_bg)
+            .border(super::utils::quantized_stroke_px(window, 1.0))
+            .border_color(border);
+        control = apply_field_size(control, tokens.sizes.for_size(self.size));
+        control = apply_radius(self.theme, control, self.radius);
+
+        let (text, color) = match self.text {
+            Some(text) => (text, resolve_hsla(self.theme, tokens.fg)),
+            None => (
+                self.placeholder,
+                resolve_hsla(self.theme, tokens.placeholder),
+            ),
+        };
+        control = control
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .truncate()
+                    .text_color(color)
+                    .child(text),
+            )
+            .child(
+                self.id
+                    .ctx()
+                    .child("clock-icon", Icon::named("clock"))
+                    .size(f32::from(tokens.icon_size))
+                    .color(resolve_hsla(self.theme, tokens.icon)),
+            );
+
+        if self.disabled {
+            return apply_disabled_state(control.cursor_default(), tokens.states, 0.55);
+        }
+
+        let hover_bg = tokens
+            .states
+            .hover_bg_or(crate::theme::color_utils::lighten(control_bg, 0.04));
+        let focus_border = tokens
+            .states
+            .focus_border_or(resolve_hsla(self.theme, tokens.border_focus));
+        control = apply_interaction_styles(
+            control.cursor_pointer(),
+            InteractionStyles::new()
+                .hover(interaction_style(move |style| style.bg(hover_bg)))
+                .focus(interaction_style(move |style| {
+                    style.border_color(focus_border)
+                })),
+        );
+
+        let opened = self.opened;
+        let open = self.open;
+        let toggle: ActivateHandler = Rc::new(move |window: &mut Window, cx: &mut gpui::App| {
+            open.set_opened(!opened, window, cx);
+        });
+        bind_press_adapter(
+            control,
+            PressAdapter::new(self.id.slot("control"))
+                .on_activate(Some(toggle))
+                .focus_handle(self.focus_handle),
+        )
+    }
+}
+
+struct TimeColumns<'a> {
+    id: &'a ComponentId,
+    theme: &'a Theme,
+    value: ClockTime,
+    columns: &'static [TimeColumn],
+    steps: [u8; 3],
+    on_change: TimeChangeHandler,
+}
+
+impl TimeColumns<'_> {
+    fn render_item(&self, column: TimeColumn, item: u8, active: bool) -> AnyElement {
+        let tokens = &self.theme.components.date_picker;
+        let item_id = self
+            .id
+            .slot_index("time-item", format!("{}-{item}", column.key()));
+        let selected = self.value.column(column) == item;
+        let hover_bg = resolve_hsla(self.theme, tokens.day_hover_bg);
+
+        let mut cell = div()
+            .id(item_id.clone())
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .w_full()
+            .h(tokens.time_item_height)
+            .rounded_sm()
+            .text_size(tokens.time_item_size)
+            .child(format!("{item:02}"));
+        cell = if selected {
+            cell.bg(resolve_hsla(
+                self.theme,
+                if active {
+                    tokens.day_selected_bg
+                } else {
+                    tokens.border_focus
+                },
+            ))
+            .text_color(resolve_hsla(self.theme, tokens.day_selected_fg))
+        } else {
+            cell.text_color(resolve_hsla(self.theme, tokens.day_fg))
+        };
+
+        let id = self.id.clone();
+        let columns = self.columns;
+        let value = self.value;
+        let on_change = self.on_change.clone();
+        bind_press_adapter(
+            apply_interaction_styles(
+                cell.cursor_pointer(),
+                InteractionStyles::new().hover(interaction_style(move |style| style.bg(hover_bg))),
+            ),
+            PressAdapter::new(item_id).on_activate(Some(Rc::new(
+                move |window: &mut Window, cx: &mut gpui::App| {
+                    time_picker_state::set_active_column(&id, columns, column);
+                    on_change(value.with_column(column, item), window, cx);
+                },
+            ))),
+        )
+        .into_any_element()
+    }
+
+    fn render_column(&self, column: TimeColumn, active: bool) -> AnyElement {
+        let tokens = &self.theme.components.date_picker;
+        let step = self.steps[column.index()];
+        let items = time_picker_state::wheel_values(
+            self.value.column(column),
+            column.count(),
+            step,
+            WHEEL_RADIUS,
+        );
+
+        let id = self.id.clone();
+        let columns = self.columns;
+        let value = self.value;
+        let item_height = f32::from(tokens.time_item_height);
+        let on_change = self.on_change.clone();
+        div()
+            .id(self.id.slot_index("time-column", column.key()))
+            .flex()
+            .flex_col()
+            .w(tokens.time_column_width)
+            .gap(tokens.grid_gap)
+            .children(
+                items
+                    .into_iter()
+                    .map(|item| self.render_item(column, item, active)),
+            )
+            .on_scroll_wheel(move |event, window, cx| {
+                let delta = f32::from(event.delta.pixel_delta(px(item_height)).y);
+                let steps = time_picker_state::consume_wheel(&id, column, delta, item_height);
+                if steps == 0 {
+                    return;
+                }
+                time_picker_state::set_active_column(&id, columns, column);
+                let next = time_picker_state::step_column(
+                    value.column(column),
+                    steps,
+                    column.count(),
+                    step,
+                );
+                on_change(value.with_column(column, next), window, cx);
+                cx.stop_propagation();
+            })
+            .into_any_element()
+    }
+
+    fn render(&self) -> AnyElement {
+        let active = time_picker_state::active_column(self.id, self.columns);
+        Stack::horizontal()
+            .gap(self.theme.components.date_picker.grid_gap)
+            .children(
+                self.columns
+                    .iter()
+                    .map(|column| self.render_column(*column, *column == active)),
+            )
+            .into_any_element()
+    }
+}
+
+fn render_dropdown(
+    id: &ComponentId,
+    theme: &Theme,
+    window: &gpui::Window,
+    content: AnyElement,
+    open: OpenCommit,
+    motion: MotionConfig,
+) -> AnyElement {
+    let tokens = &theme.components.date_picker;
+    div()
+        .id(id.slot("dropdown"))
+        .rounded_md()
+        .border(super::utils::quantized_stroke_px(window, 1.0))
+        .border_color(resolve_hsla(theme, tokens.dropdown_border))
+        .bg(resolve_hsla(theme, tokens.dropdown_bg))
+        .shadow_sm()
+        .p(tokens.dropdown_padding)
+        .child(content)
+        .on_mouse_down_out(move |_, window, cx| open.set_opened(false, window, cx))
+        .with_enter_transition(id.slot("dropdown-enter"), motion)
+        .into_any_element()
+}
+
+fn render_field(
+    id: &ComponentId,
+    theme: &Theme,
+    layout: FieldLayout,
+    text: &FieldText,
+    trigger: AnyElement,
+    motion: MotionConfig,
+) -> impl IntoElement {
+    let tokens = &theme.components.date_picker;
+    let field = Stack::vertical()
+        .gap(tokens.label_block_gap)
+        .w_full()
+        .min_w_0()
+        .child(trigger);
+    let root = match layout {
+        FieldLayout::Vertical => {
+            let mut container = Stack::vertical()
+                .id(id.clone())
+                .gap(tokens.layout_gap_vertical)
+                .w_full();
+            if let Some(label_block) = text.render(theme, None) {
+                container = container.child(label_block);
+            }
+            container.child(field)
+        }
+        FieldLayout::Horizontal => {
+            let mut row = Stack::horizontal()
+                .id(id.clone())
+                .items_start()
+                .gap(tokens.layout_gap_horizontal);
+            if let Some(label_block) = text.render(theme, Some(tokens.horizontal_label_width)) {
+                row = row.child(label_block);
+            }
+            row.child(field)
+        }
+    };
+    root.with_enter_transition(id.slot("enter"), motion)
+}
+
+fn resolve_opened(id: &ComponentId, opened: Option<bool>, default: bool, disabled: bool) -> bool {
+    PopupStateValue::resolve(PopupStateInput {
+        id,
+        opened,
+        default_opened: default,
+        disabled,
+    })
+    .opened
+}
+
+#[derive(IntoElement)]
+pub struct TimePicker {
+    pub(crate) id: ComponentId,
+    value: Option<ClockTime>,
+    value_controlled: bool,
+    default_value: Option<ClockTime>,
+    with_seconds: bool,
+    steps: [u8; 3],
+    placeholder: Option<SharedString>,
+    text: FieldText,
+    layout: FieldLayout,
+    opened: Option<bool>,
+    default_opened: bool,
+    disabled: bool,
+    size: Size,
+    radius: Radius,
+    variant: Variant,
+    focus_handle: Option<FocusHandle>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<TimeChangeHandler>,
+    on_open_change: Option<OpenChangeHandler>,
+}
+
+impl TimePicker {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: None,
+            value_controlled: false,
+            default_value: None,
+            with_seconds: false,
+            steps: [1, 1, 1],
+            placeholder: None,
+            text: FieldText::default(),
+            layout: FieldLayout::Vertical,
+            opened: None,
+            default_opened: false,
+            disabled: false,
+            size: Size::Md,
+            radius: Radius::Sm,
+            variant: Variant::Default,
+            focus_handle: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+            on_open_change: None,
+        }
+    }
+
+    pub fn value(mut self, value: ClockTime) -> Self {
+        self.value = Some(value);
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn clear_value(mut self) -> Self {
+        self.value = None;
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn default_value(mut self, value: ClockTime) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+
+    pub fn with_seconds(mut self, value: bool) -> Self {
+        self.with_seconds = value;
+        self
+    }
+
+    pub fn step(mut self, column: TimeColumn, value: u8) -> Self {
+        self.steps[column.index()] = value.clamp(1, column.count());
+        self
+    }
+
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(value.into());
+        self
+    }
+
+    pub fn opened(mut self, value: bool) -> Self {
+        self.opened = Some(value);
+        self
+    }
+
+    pub fn default_opened(mut self, value: bool) -> Self {
+        self.default_opened = value;
+        self
+    }
+
+    pub fn focus_handle(mut self, value: FocusHandle) -> Self {
+        self.focus_handle = Some(value);
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(ClockTime, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_open_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_open_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_value(&self) -> Option<ClockTime> {
+        selection_state::resolve_optional_text(
+            &self.id,
+            "value",
+            self.value_controlled,
+            self.value.map(|value| value.to_string()),
+            self.default_value.map(|value| value.to_string()),
+        )
+        .and_then(|value| value.parse().ok())
+    }
+
+    fn change_handler(&self) -> TimeChangeHandler {
+        let id = self.id.clone();
+        let controlled = self.value_controlled;
+        let on_change = self.on_change.clone();
+        Rc::new(
+            move |time: ClockTime, window: &mut Window, cx: &mut gpui::App| {
+                selection_state::apply_optional_text(
+                    &id,
+                    "value",
+                    controlled,
+                    Some(time.to_string()),
+                );
+                window.refresh();
+                if let Some(handler) = on_change.as_ref() {
+                    (handler)(time, window, cx);
+                }
+            },
+        )
+    }
+}
+
+impl FieldLike for TimePicker {
+    fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.text.label = Some(value.into());
+        self
+    }
+
+    fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.text.description = Some(value.into());
+        self
+    }
+
+    fn error(mut self, value: impl Into<SharedString>) -> Self {
+        self.text.error = Some(value.into());
+        self
+    }
+
+    fn required(mut self, value: bool) -> Self {
+        self.text.required = value;
+        self
+    }
+
+    fn layout(mut self, value: FieldLayout) -> Self {
+        self.layout = value;
+        self
+    }
+}
+
+crate::impl_variant_size_radius_via_methods!(TimePicker, variant, size, radius);
+crate::impl_disableable!(TimePicker, |this, value| this.disabled = value);
+
+impl MotionAware for TimePicker {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for TimePicker {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.date_picker.clone();
+        let opened = resolve_opened(&self.id, self.opened, self.default_opened, self.disabled);
+        let value = self.resolved_value();
+        let columns = time_picker_state::time_columns(self.with_seconds);
+        let open = OpenCommit {
+            id: self.id.clone(),
+            controlled: self.opened.is_some(),
+            on_open_change: self.on_open_change.clone(),
+        };
+        let on_change = self.change_handler();
+
+        let control = TriggerControl {
+            id: &self.id,
+            theme: &self.theme,
+            text: value.map(|time| time_picker_state::format_clock(time, self.with_seconds).into()),
+            placeholder: self
+                .placeholder
+                .clone()
+                .unwrap_or_else(|| SharedString::from("Pick a time")),
+            opened,
+            disabled: self.disabled,
+            invalid: self.text.error.is_some(),
+            size: self.size,
+            radius: self.radius,
+            variant: self.variant,
+            focus_handle: self.focus_handle.clone(),
+            open: open.clone(),
+        }
+        .render(window);
+        let control = if self.disabled {
+            control
+        } else {
+            let id = self.id.clone();
+            let open = open.clone();
+            let on_change = on_change.clone();
+            let steps = self.steps;
+            let current = value.unwrap_or_default();
+            control.on_key_down(move |event, window, cx| {
+                if control::is_escape_keystroke(event) && opened {
+                    open.set_opened(false, window, cx);
+                    cx.stop_propagation();
+                    return;
+                }
+                if !control::is_plain_keystroke(event) {
+                    return;
+                }
+                let key = event.keystroke.key.as_str();
+                if !opened {
+                    if key == "down" {
+                        open.set_opened(true, window, cx);
+                        cx.stop_propagation();
+                    }
+                    return;
+                }
+                if key == "enter" {
+                    open.set_opened(false, window, cx);
+                    cx.stop_propagation();
+                    window.prevent_default();
+                    return;
+                }
+                match time_picker_state::time_key(&id, columns, steps, current, key) {
+                    TimeKeyOutcome::Ignored => return,
+                    TimeKeyOutcome::Moved => window.refresh(),
+                    TimeKeyOutcome::Changed(time) => on_change(time, window, cx),
+                }
+                cx.stop_propagation();
+                window.prevent_default();
+            })
+        };
+
+        let mut trigger = div()
+            .id(self.id.slot("trigger"))
+            .relative()
+            .w_full()
+            .child(control);
+        if opened {
+            let content = TimeColumns {
+                id: &self.id,
+                theme: &self.theme,
+                value: value.unwrap_or_default(),
+                columns,
+                steps: self.steps,
+                on_change,
+            }
+            .render();
+            trigger = trigger.child(anchored_host(
+                &self.id,
+                "anchor-host",
+                PopupPlacement::Bottom.options(
+                    f32::from(tokens.dropdown_anchor_offset),
+                    self.theme.components.layout.popup_snap_margin,
+                ),
+                render_dropdown(&self.id, &self.theme, window, content, open, self.motion),
+                24,
+                false,
+            ));
+        }
+
+        render_field(
+            &self.id,
+            &self.theme,
+            self.layout,
+            &self.text,
+            trigger.into_any_element(),
+            self.motion,
+        )
+    }
+}
+
+#[derive(IntoElement)]
+pub struct DateTimePicker {
+    pub(crate) id: ComponentId,
+    value: Option<CalendarDateTime>,
+    value_controlled: bool,
+    default_value: Option<CalendarDateTime>,
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+    first_weekday: u8,
+    with_seconds: bool,
+    steps: [u8; 3],
+    placeholder: Option<SharedString>,
+    text: FieldText,
+    layout: FieldLayout,
+    opened: Option<bool>,
+    default_opened: bool,
+    disabled: bool,
+    size: Size,
+    radius: Radius,
+    variant: Variant,
+    focus_handle: Option<FocusHandle>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_change: Option<DateTimeChangeHandler>,
+    on_open_change: Option<OpenChangeHandler>,
+}
+
+impl DateTimePicker {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            value: None,
+            value_controlled: false,
+            default_value: None,
+            min: None,
+            max: None,
+            first_weekday: 0,
+            with_seconds: false,
+            steps: [1, 1, 1],
+            placeholder: None,
+            text: FieldText::default(),
+            layout: FieldLayout::Vertical,
+            opened: None,
+            default_opened: false,
+            disabled: false,
+            size: Size::Md,
+            radius: Radius::Sm,
+            variant: Variant::Default,
+            focus_handle: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_change: None,
+            on_open_change: None,
+        }
+    }
+
+    pub fn value(mut self, value: CalendarDateTime) -> Self {
+        self.value = Some(value);
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn clear_value(mut self) -> Self {
+        self.value = None;
+        self.value_controlled = true;
+        self
+    }
+
+    pub fn default_value(mut self, value: CalendarDateTime) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+
+    pub fn min_date(mut self, value: CalendarDate) -> Self {
+        self.min = Some(value);
+        self
+    }
+
+    pub fn max_date(mut self, value: CalendarDate) -> Self {
+        self.max = Some(value);
+        self
+    }
+
+    pub fn first_weekday(mut self, value: u8) -> Self {
+        self.first_weekday = value % 7;
+        self
+    }
+
+    pub fn with_seconds(mut self, value: bool) -> Self {
+        self.with_seconds = value;
+        self
+    }
+
+    pub fn step(mut self, column: TimeColumn, value: u8) -> Self {
+        self.steps[column.index()] = value.clamp(1, column.count());
+        self
+    }
+
+    pub fn placeholder(mut self, value: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(value.into());
+        self
+    }
+
+    pub fn opened(mut self, value: bool) -> Self {
+        self.opened = Some(value);
+        self
+    }
+
+    pub fn default_opened(mut self, value: bool) -> Self {
+        self.default_opened = value;
+        self
+    }
+
+    pub fn focus_handle(mut self, value: FocusHandle) -> Self {
+        self.focus_handle = Some(value);
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(CalendarDateTime, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_open_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_open_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn resolved_value(&self) -> Option<CalendarDateTime> {
+        selection_state::resolve_optional_text(
+            &self.id,
+            "value",
+            self.value_controlled,
+            self.value.map(|value| value.to_string()),
+            self.default_value.map(|value| value.to_string()),
+        )
+        .and_then(|value| value.parse().ok())
+    }
+
+    fn change_handler(&self) -> DateTimeChangeHandler {
+        let id = self.id.clone();
+        let controlled = self.value_controlled;
+        let on_change = self.on_change.clone();
+        Rc::new(
+            move |value: CalendarDateTime, window: &mut Window, cx: &mut gpui::App| {
+                selection_state::apply_optional_text(
+                    &id,
+                    "value",
+                    controlled,
+                    Some(value.to_string()),
+                );
+                window.refresh();
+                if let Some(handler) = on_change.as_ref() {
+                    (handler)(value, window, cx);
+                }
+            },
+        )
+    }
+
+    fn render_panel(
+        &self,
+        focused: CalendarDate,
+        current: CalendarDateTime,
+        value: Option<CalendarDateTime>,
+        on_change: DateTimeChangeHandler,
+    ) -> AnyElement {
+        let id = self.id.clone();
+        let grid = CalendarGrid {
+            id: &self.id,
+            theme: &self.theme,
+            focused,
+            value: value.map(|value| value.date),
+            min: self.min,
+            max: self.max,
+            first_weekday: self.first_weekday,
+            on_select: Rc::new({
+                let on_change = on_change.clone();
+                move |date: CalendarDate, window: &mut Window, cx: &mut gpui::App| {
+                    date_picker_state::set_focused_date(&id, Some(date));
+                    on_change(CalendarDateTime::new(date, current.time), window, cx);
+                }
+            }),
+            on_focus: Rc::new({
+                let id = self.id.clone();
+                move |date: CalendarDate, window: &mut Window| {
+                    date_picker_state::set_focused_date(&id, Some(date));
+                    window.refresh();
+                }
+            }),
+        };
+        let columns = TimeColumns {
+            id: &self.id,
+            theme: &self.theme,
+            value: current.time,
+            columns: time_picker_state::time_columns(self.with_seconds),
+            steps: self.steps,
+            on_change: Rc::new(
+                move |time: ClockTime, window: &mut Window, cx: &mut gpui::App| {
+                    on_change(CalendarDateTime::new(current.date, time), window, cx);
+                },
+            ),
+        };
+        Stack::horizontal()
+            .items_start()
+            .gap(self.theme.components.date_picker.dropdown_padding)
+            .child(grid.render())
+            .child(columns.render())
+            .into_any_element()
+    }
+}
+
+impl FieldLike for DateTimePicker {
+    fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.text.label = Some(value.into());
+        self
+    }
+
+    fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.text.description = Some(value.into());
+        self
+    }
+
+    fn error(mut self, value: impl Into<SharedString>) -> Self {
+        self.text.error = Some(value.into());
+        self
+    }
+
+    fn required(mut self, value: bool) -> Self {
+        self.text.required = value;
+        self
+    }
+
+    fn layout(mut self, value: FieldLayout) -> Self {
+        self.layout = value;
+        self
+    }
+}
+
+crate::impl_variant_size_radius_via_methods!(DateTimePicker, variant, size, radius);
+crate::impl_disableable!(DateTimePicker, |this, value| this.disabled = value);
+
+impl MotionAware for DateTimePicker {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for DateTimePicker {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.date_picker.clone();
+        let opened = resolve_opened(&self.id, self.opened, self.default_opened, self.disabled);
+        let value = self.resolved_value();
+        let focused = date_picker_state::focused_date(
+            &self.id,
+            date_picker_state::clamp_date(
+                value.map_or_else(CalendarDate::today, |value| value.date),
+                self.min,
+                self.max,
+            ),
+        );
+        let current = CalendarDateTime::new(
+            value.map_or(focused, |value| value.date),
+            value.map(|value| value.time).unwrap_or_default(),
+        );
+        let columns = time_picker_state::time_columns(self.with_seconds);
+        let open = OpenCommit {
+            id: self.id.clone(),
+            controlled: self.opened.is_some(),
+            on_open_change: self.on_open_change.clone(),
+        };
+        let on_change = self.change_handler();
+
+        let with_seconds = self.with_seconds;
+        let control = TriggerControl {
+            id: &self.id,
+            theme: &self.theme,
+            text: value.map(|value| {
+                format!(
+                    "{} {}",
+                    value.date,
+                    time_picker_state::format_clock(value.time, with_seconds)
+                )
+                .into()
+            }),
+            placeholder: self
+                .placeholder
+                .clone()
+                .unwrap_or_else(|| SharedString::from("Pick date and time")),
+            opened,
+            disabled: self.disabled,
+            invalid: self.text.error.is_some(),
+            size: self.size,
+            radius: self.radius,
+            variant: self.variant,
+            focus_handle: self.focus_handle.clone(),
+            open: open.clone(),
+        }
+        .render(window);
+        let control = if self.disabled {
+            control
+        } else {
+            let id = self.id.clone();
+            let open = open.clone();
+            let on_change = on_change.clone();
+            let steps = self.steps;
+            let (min, max) = (self.min, self.max);
+            control.on_key_down(move |event, window, cx| {
+                if control::is_escape_keystroke(event) && opened {
+                    open.set_opened(false, window, cx);
+                    cx.stop_propagation();
+                    return;
+                }
+                if !control::is_plain_keystroke(event) {
+                    return;
+                }
+                let key = event.keystroke.key.as_str();
+                if !opened {
+                    if key == "down" {
+                        open.set_opened(true, window, cx);
+                        cx.stop_propagation();
+                    }
+                    return;
+                }
+                if key == "enter" {
+                    if date_picker_state::is_within(focused, min, max) {
+                        on_change(CalendarDateTime::new(focused, current.time), window, cx);
+                    }
+                    open.set_opened(false, window, cx);
+                    cx.stop_propagation();
+                    window.prevent_default();
+                    return;
+                }
+                if matches!(key, "pageup" | "pagedown") {
+                    let next = date_picker_state::keyboard_date(
+                        focused,
+                        key,
+                        event.keystroke.modifiers.shift,
+                        0,
+                    )
+                    .map(|date| date_picker_state::clamp_date(date, min, max));
+                    date_picker_state::set_focused_date(&id, next);
+                    window.refresh();
+                    cx.stop_propagation();
+                    return;
+                }
+                match time_picker_state::time_key(&id, columns, steps, current.time, key) {
+                    TimeKeyOutcome::Ignored => return,
+                    TimeKeyOutcome::Moved => window.refresh(),
+                    TimeKeyOutcome::Changed(time) => {
+                        on_change(CalendarDateTime::new(current.date, time), window, cx)
+                    }
+                }
+                cx.stop_propagation();
+                window.prevent_default();
+            })
+        };
+
+        let mut trigger = div()
+            .id(self.id.slot("trigger"))
+            .relative()
+            .w_full()
+            .child(control);
+        if opened {
+            let content = self.render_panel(focused, current, value, on_change);
+            trigger = trigger.child(anchored_host(
+                &self.id,
+                "anchor-host",
+                PopupPlacement::Bottom.options(
+                    f32::from(tokens.dropdown_anchor_offset),
+                    self.theme.components.layout.popup_snap_margin,
+                ),
+                render_dropdown(&self.id, &self.theme, window, content, open, self.motion),
+                24,
+                false,
+            ));
+        }
+
+        render_field(
+            &self.id,
+            &self.theme,
+            self.layout,
+            &self.text,
+            trigger.into_any_element(),
+            self.motion,
+        )
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use super::control;
+use super::date_picker_state::CalendarDate;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ClockTime {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl ClockTime {
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+        Some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    pub const fn midnight() -> Self {
+        Self {
+            hour: 0,
+            minute: 0,
+            second: 0,
+        }
+    }
+
+    pub fn now() -> Self {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| (elapsed.as_secs() % 86_400) as u32)
+            .unwrap_or(0);
+        Self::from_seconds(seconds)
+    }
+
+    pub const fn hour(self) -> u8 {
+        self.hour
+    }
+
+    pub const fn minute(self) -> u8 {
+        self.minute
+    }
+
+    pub const fn second(self) -> u8 {
+        self.second
+    }
+
+    pub fn to_seconds(self) -> u32 {
+        u32::from(self.hour) * 3_600 + u32::from(self.minute) * 60 + u32::from(self.second)
+    }
+
+    pub fn from_seconds(seconds: u32) -> Self {
+        let seconds = seconds % 86_400;
+        Self {
+            hour: (seconds / 3_600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+        }
+    }
+
+    pub fn column(self, column: TimeColumn) -> u8 {
+        match column {
+            TimeColumn::Hour => self.hour,
+            TimeColumn::Minute => self.minute,
+            TimeColumn::Second => self.second,
+        }
+    }
+
+    pub fn with_column(self, column: TimeColumn, value: u8) -> Self {
+        let value = value % column.count();
+        match column {
+            TimeColumn::Hour => Self {
+                hour: value,
+                ..self
+            },
+            TimeColumn::Minute => Self {
+                minute: value,
+                ..self
+            },
+            TimeColumn::Second => Self {
+                second: value,
+                ..self
+            },
+        }
+    }
+}
+
+impl Display for ClockTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+impl FromStr for ClockTime {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.trim().splitn(3, ':');
+        let hour = parts.next().and_then(|part| part.parse().ok()).ok_or(())?;
+        let minute = parts.next().and_then(|part| part.parse().ok()).ok_or(())?;
+        let second = match parts.next() {
+            Some(part) => part.parse().map_err(|_| ())?,
+            None => 0,
+        };
+        Self::new(hour, minute, second).ok_or(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CalendarDateTime {
+    pub date: CalendarDate,
+    pub time: ClockTime,
+}
+
+impl CalendarDateTime {
+    pub fn new(date: CalendarDate, time: ClockTime) -> Self {
+        Self { date, time }
+    }
+}
+
+impl Display for CalendarDateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.date, self.time)
+    }
+}
+
+impl FromStr for CalendarDateTime {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (date, time) = value.trim().split_once([' ', 'T']).ok_or(())?;
+        Ok(Self {
+            date: date.parse()?,
+            time: time.parse()?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TimeColumn {
+    Hour,
+    Minute,
+    Second,
+}
+
+impl TimeColumn {
+    pub const fn count(self) -> u8 {
+        match self {
+            Self::Hour => 24,
+            Self::Minute | Self::Second => 60,
+        }
+    }
+
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Hour => 0,
+            Self::Minute => 1,
+            Self::Second => 2,
+        }
+    }
+
+    pub(crate) const fn key(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeKeyOutcome {
+    Ignored,
+    Moved,
+    Changed(ClockTime),
+}
+
+pub fn format_clock(time: ClockTime, with_seconds: bool) -> String {
+    if with_seconds {
+        time.to_string()
+    } else {
+        format!("{:02}:{:02}", time.hour(), time.minute())
+    }
+}
+
+pub fn time_columns(with_seconds: bool) -> &'static [TimeColumn] {
+    if with_seconds {
+        &[TimeColumn::Hour, TimeColumn::Minute, TimeColumn::Second]
+    } else {
+        &[TimeColumn::Hour, TimeColumn::Minute]
+    }
+}
+
+pub fn adjacent_column(columns: &[TimeColumn], current: TimeColumn, forward: bool) -> TimeColumn {
+    let index = columns
+        .iter()
+        .position(|column| *column == current)
+        .unwrap_or(0);
+    let next = if forward {
+        (index + 1).min(columns.len().saturating_sub(1))
+    } else {
+        index.saturating_sub(1)
+    };
+    columns.get(next).copied().unwrap_or(current)
+}
+
+pub fn step_column(value: u8, delta: i32, count: u8, step: u8) -> u8 {
+    let count = i32::from(count.max(1));
+    let step = i32::from(step.max(1));
+    let aligned = i32::from(value) - i32::from(value) % step;
+    (aligned + delta * step).rem_euclid(count) as u8
+}
+
+pub fn wheel_values(value: u8, count: u8, step: u8, radius: usize) -> Vec<u8> {
+    let radius = radius as i32;
+    (-radius..=radius)
+        .map(|delta| step_column(value, delta, count, step))
+        .collect()
+}
+
+pub fn type_digit(buffer: &str, digit: char, count: u8) -> Option<(u8, bool)> {
+    let digit = digit.to_digit(10)? as u8;
+    let candidate = buffer
+        .parse::<u8>()
+        .ok()
+        .filter(|_| buffer.len() == 1)
+        .map(|previous| previous * 10 + digit)
+        .filter(|candidate| *candidate < count);
+    match candidate {
+        Some(value) => Some((value, true)),
+        None => Some((digit, digit * 10 >= count)),
+    }
+}
+
+pub fn active_column(id: &str, columns: &[TimeColumn]) -> TimeColumn {
+    let index = control::usize_state(id, "active-column", None, 0);
+    columns.get(index).copied().unwrap_or(TimeColumn::Hour)
+}
+
+pub fn set_active_column(id: &str, columns: &[TimeColumn], column: TimeColumn) {
+    let index = columns
+        .iter()
+        .position(|candidate| *candidate == column)
+        .unwrap_or(0);
+    control::set_usize_state(id, "active-column", index);
+    control::set_text_state(id, "typed", String::new());
+}
+
+pub fn typed_buffer(id: &str) -> String {
+    control::text_state(id, "typed", None, String::new())
+}
+
+pub fn set_typed_buffer(id: &str, value: String) {
+    control::set_text_state(id, "typed", value);
+}
+
+pub fn time_key(
+    id: &str,
+    columns: &[TimeColumn],
+    steps: [u8; 3],
+    value: ClockTime,
+    key: &str,
+) -> TimeKeyOutcome {
+    let active = active_column(id, columns);
+    let step = |delta: i32| {
+        let next = step_column(
+            value.column(active),
+            delta,
+            active.count(),
+            steps[active.index()],
+        );
+        TimeKeyOutcome::Changed(value.with_column(active, next))
+    };
+    match key {
+        "left" | "right" => {
+            set_active_column(
+                id,
+                columns,
+                adjacent_column(columns, active, key == "right"),
+            );
+            TimeKeyOutcome::Moved
+        }
+        "up" => step(-1),
+        "down" => step(1),
+        _ => {
+            let mut chars = key.chars();
+            let (Some(digit), None) = (chars.next(), chars.next()) else {
+                return TimeKeyOutcome::Ignored;
+            };
+            let Some((typed, complete)) = type_digit(&typed_buffer(id), digit, active.count())
+            else {
+                return TimeKeyOutcome::Ignored;
+            };
+            if complete {
+                set_active_column(id, columns, adjacent_column(columns, active, true));
+            } else {
+                set_typed_buffer(id, digit.to_string());
+            }
+            TimeKeyOutcome::Changed(value.with_column(active, typed))
+        }
+    }
+}
+
+pub fn consume_wheel(id: &str, column: TimeColumn, delta: f32, item_height: f32) -> i32 {
+    let slot = format!("wheel-{}", column.key());
+    let item_height = item_height.max(1.0);
+    let total = control::f32_state(id, &slot, None, 0.0) + delta;
+    let steps = (total / item_height).trunc();
+    control::set_f32_state(id, &slot, total - steps * item_height);
+    -(steps as i32)
+}
@@ -4,6 +4,7 @@ use gpui::StatefulInteractiveElement;
 use gpui::{InteractiveElement, Window};
 
 use crate::id::ComponentId;
+use crate::interaction::{self, InteractionAction, InteractionEvent};
 
 use super::control;
 
@@ -12,6 +13,7 @@ pub type ToggleChangeHandler = Rc<dyn Fn(bool, &mut Window, &mut gpui::App)>;
 #[derive(Clone)]
 pub struct ToggleConfig {
     pub id: ComponentId,
+    pub component: &'static str,
     pub checked: bool,
     pub controlled: bool,
     pub allow_uncheck: bool,
@@ -30,6 +32,11 @@ impl ToggleConfig {
     fn should_emit(&self, next: bool) -> bool {
         next != self.checked
     }
+
+    fn report(&self, next: bool, cx: &gpui::App) {
+        let event = InteractionEvent::new(self.component, &self.id, InteractionAction::Toggle);
+        interaction::emit(event.value(next.to_string()), cx);
+    }
 }
 
 pub fn wire_toggle_handlers<T>(node: T, config: ToggleConfig) -> T
@@ -53,6 +60,10 @@ where
             window.refresh();
         }
 
+        if click_cfg.should_emit(click_next) {
+            click_cfg.report(click_next, cx);
+        }
+
         if let Some(handler) = click_cfg.on_change.as_ref()
             && click_cfg.should_emit(click_next)
         {
@@ -69,6 +80,10 @@ where
                 window.refresh();
             }
 
+            if key_cfg.should_emit(key_next) {
+                key_cfg.report(key_next, cx);
+            }
+
             if let Some(handler) = key_cfg.on_change.as_ref()
                 && key_cfg.should_emit(key_next)
             {
@@ -90,6 +105,7 @@ mod tests {
     fn cfg(checked: bool, allow_uncheck: bool) -> ToggleConfig {
         ToggleConfig {
             id: ComponentId::stable("toggle-test"),
+            component: "toggle",
             checked,
             controlled: false,
             allow_uncheck,
@@ -6,6 +6,7 @@ use gpui::{AnyElement, IntoElement, ParentElement, RenderOnce, SharedString, Sty
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
+use crate::overlay::positioning::Align;
 
 use super::popup::{PopupPlacement, anchored_host};
 use super::popup_state::{self, PopupStateInput, PopupStateValue};
@@ -24,12 +25,14 @@ pub enum TooltipPlacement {
 pub struct Tooltip {
     pub(crate) id: ComponentId,
     label: Option<SharedString>,
+    shortcut: Option<SharedString>,
     opened: Option<bool>,
     default_opened: bool,
     disabled: bool,
     trigger_on_click: bool,
     placement: TooltipPlacement,
     offset_px: f32,
+    align: Align,
     pub(crate) theme: crate::theme::LocalTheme,
     motion: MotionConfig,
     trigger: Option<SlotRenderer>,
@@ -42,12 +45,14 @@ impl Tooltip {
         Self {
             id: ComponentId::default(),
             label: None,
+            shortcut: None,
             opened: None,
             default_opened: false,
             disabled: false,
             trigger_on_click: false,
             placement: TooltipPlacement::Top,
             offset_px: 3.0,
+            align: Align::Start,
             theme: crate::theme::LocalTheme::default(),
             motion: MotionConfig::default(),
             trigger: None,
@@ -63,6 +68,12 @@ impl Tooltip {
         self.label = Some(value.into());
         self
     }
+
+    pub fn shortcut(mut self, action: impl Into<SharedString>) -> Self {
+        self.shortcut = Some(action.into());
+        self
+    }
+
     pub fn default_opened(mut self, value: bool) -> Self {
         self.default_opened = value;
         self
@@ -82,6 +93,11 @@ impl Tooltip {
         self
     }
 
+    pub fn align(mut self, value: Align) -> Self {
+        self.align = value;
+        self
+    }
+
     pub fn trigger(mut self, trigger: impl IntoElement + 'static) -> Self {
         self.trigger = Some(Box::new(|| trigger.into_any_element()));
         self
@@ -95,9 +111,14 @@ impl Tooltip {
         self
     }
 
-    fn render_bubble(&self, window: &gpui::Window, label: SharedString) -> AnyElement {
+    fn render_bubble(
+        &self,
+        window: &gpui::Window,
+        label: SharedString,
+        hint: Option<SharedString>,
+    ) -> AnyElement {
         let tokens = &self.theme.components.tooltip;
-        div()
+        let bubble = div()
             .id(self.id.slot("bubble"))
             .text_size(tokens.text_size)
             .px(tokens.padding_x)
@@ -107,8 +128,15 @@ impl Tooltip {
             .border(super::utils::quantized_stroke_px(window, 1.0))
             .border_color(resolve_hsla(&self.theme, tokens.border))
             .bg(resolve_hsla(&self.theme, tokens.bg))
-            .text_color(resolve_hsla(&self.theme, tokens.fg))
-            .child(label)
+            .text_color(resolve_hsla(&self.theme, tokens.fg));
+        let hint = hint.map(|hint| div().opacity(0.7).child(hint));
+        let bubble = if hint.is_some() {
+            bubble.flex().gap(tokens.padding_x)
+        } else {
+            bubble
+        };
+        let bubble = bubble.child(label).children(hint);
+        bubble
             .with_enter_transition(self.id.slot("bubble-enter"), self.motion)
             .into_any_element()
     }
@@ -186,22 +214,18 @@ impl RenderOnce for Tooltip {
         }
 
         if opened && let Some(label) = self.label.clone() {
-            let bubble = self.render_bubble(window, label);
+            let hint = self
+                .shortcut
+                .as_ref()
+                .and_then(|action| crate::provider::CalmProvider::shortcuts(_cx).hint(action));
+            let bubble = self.render_bubble(window, label, hint);
             let placement = match self.placement {
                 TooltipPlacement::Top => PopupPlacement::Top,
                 TooltipPlacement::Bottom => PopupPlacement::Bottom,
             };
-            let anchor_host = anchored_host(
-                &self.id,
-                "anchor-host",
-                placement,
-                self.offset_px,
-                self.theme.components.layout.popup_snap_margin,
-                bubble,
-                24,
-                matches!(self.placement, TooltipPlacement::Bottom),
-                false,
-            );
+            let margin = self.theme.components.layout.popup_snap_margin;
+            let options = placement.options(self.offset_px, margin).align(self.align);
+            let anchor_host = anchored_host(&self.id, "anchor-host", options, bubble, 24, false);
 
             trigger = trigger.child(anchor_host);
         }
@@ -116,16 +116,7 @@ pub trait TransitionExt: Sized + AnimationExt + Styled + 'static {
 impl<E> TransitionExt for E where E: Sized + AnimationExt + Styled + 'static {}
 
 fn easing_fn(easing: Easing) -> impl Fn(f32) -> f32 {
-    move |delta| match easing {
-        Easing::Linear => gpui::linear(delta),
-        Easing::Ease => gpui::ease_in_out(delta),
-        Easing::EaseIn => gpui::quadratic(delta),
-        Easing::EaseOut => gpui::ease_out_quint()(delta),
-        Easing::EaseInOut => gpui::ease_in_out(delta),
-        Easing::Quadratic => gpui::quadratic(delta),
-        Easing::QuintOut => gpui::ease_out_quint()(delta),
-        Easing::BounceInOut => gpui::bounce(gpui::ease_out_quint())(delta),
-    }
+    move |delta| easing.apply(delta)
 }
 
 fn apply_preset<E: Styled>(element: E, profile: MotionTransition, progress: f32) -> E {
@@ -1,24 +1,61 @@
-use std::{collections::BTreeSet, rc::Rc};
+use std::future::Future;
+use std::pin::Pin;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    rc::Rc,
+};
 
 use gpui::InteractiveElement;
 use gpui::{
-    AnyElement, IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement,
-    Styled, Window, div, px,
+    AnyElement, AppContext, EmptyView, IntoElement, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement, Styled, Window, div, px,
 };
 
 use crate::contracts::MotionAware;
 use crate::id::ComponentId;
 use crate::motion::MotionConfig;
 use crate::style::{Radius, Size, Variant};
+use crate::theme::SemanticRadiusToken;
 
 use super::Stack;
+use super::control;
 use super::icon::Icon;
 use super::interaction_adapter::{ActivateHandler, PressAdapter, bind_press_adapter};
-use super::tree_state::{self, TreeVisibleNode};
-use super::utils::{apply_radius, resolve_hsla};
+use super::loader::{Loader, LoaderVariant};
+use super::tree_state::{self, TreeCheckState, TreeVisibleNode};
+use super::utils::{
+    apply_focus_ring, apply_hit_target, apply_radius, resolve_hsla, resolve_radius,
+};
 
 type SelectHandler = Rc<dyn Fn(Option<SharedString>, &mut Window, &mut gpui::App)>;
 type ExpandedChangeHandler = Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut gpui::App)>;
+type CheckedChangeHandler = Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut gpui::App)>;
+type DropHandler = Rc<dyn Fn(&TreeDropEvent, &mut Window, &mut gpui::App)>;
+type CanDropHandler = Rc<dyn Fn(&TreeDropEvent) -> bool>;
+type ChildrenLoaderFuture = Pin<Box<dyn Future<Output = Vec<TreeNode>>>>;
+type ChildrenLoader = Rc<dyn Fn(SharedString) -> ChildrenLoaderFuture>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TreeDropPosition {
+    Before,
+    After,
+    Inside,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreeDropEvent {
+    pub source: SharedString,
+    pub target: SharedString,
+    pub position: TreeDropPosition,
+    pub parent: Option<SharedString>,
+    pub index: usize,
+}
+
+#[derive(Clone)]
+struct TreeNodeDrag {
+    tree_id: String,
+    value: String,
+}
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TreeNode {
@@ -27,6 +64,7 @@ pub struct TreeNode {
     pub children: Vec<TreeNode>,
     pub disabled: bool,
     pub default_expanded: bool,
+    pub has_children: bool,
 }
 
 impl TreeNode {
@@ -37,6 +75,7 @@ impl TreeNode {
             children: Vec::new(),
             disabled: false,
             default_expanded: false,
+            has_children: false,
         }
     }
 
@@ -62,6 +101,11 @@ impl TreeNode {
         self.default_expanded = value;
         self
     }
+
+    pub fn has_children(mut self, value: bool) -> Self {
+        self.has_children = value;
+        self
+    }
 }
 
 #[derive(IntoElement)]
@@ -84,6 +128,15 @@ pub struct Tree {
     motion: MotionConfig,
     on_select: Option<SelectHandler>,
     on_expanded_change: Option<ExpandedChangeHandler>,
+    draggable: bool,
+    can_drop: Option<CanDropHandler>,
+    on_drop: Option<DropHandler>,
+    loader: Option<ChildrenLoader>,
+    checkable: bool,
+    checked_values: Vec<SharedString>,
+    checked_controlled: bool,
+    default_checked_values: Vec<SharedString>,
+    on_checked_change: Option<CheckedChangeHandler>,
 }
 
 impl Tree {
@@ -108,6 +161,15 @@ impl Tree {
             motion: MotionConfig::default(),
             on_select: None,
             on_expanded_change: None,
+            draggable: false,
+            can_drop: None,
+            on_drop: None,
+            loader: None,
+            checkable: false,
+            checked_values: Vec::new(),
+            checked_controlled: false,
+            default_checked_values: Vec::new(),
+            on_checked_change: None,
         }
     }
 
@@ -178,6 +240,63 @@ impl Tree {
         self
     }
 
+    pub fn checkable(mut self, value: bool) -> Self {
+        self.checkable = value;
+        self
+    }
+
+    pub fn checked_values(mut self, values: impl IntoIterator<Item = SharedString>) -> Self {
+        self.checked_values = values.into_iter().collect();
+        self.checked_controlled = true;
+        self.checkable = true;
+        self
+    }
+
+    pub fn default_checked_values(
+        mut self,
+        values: impl IntoIterator<Item = SharedString>,
+    ) -> Self {
+        self.default_checked_values = values.into_iter().collect();
+        self.checkable = true;
+        self
+    }
+
+    pub fn on_checked_change(
+        mut self,
+        handler: impl Fn(Vec<SharedString>, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_checked_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn load_children<F, Fut>(mut self, loader: F) -> Self
+    where
+        F: Fn(SharedString) -> Fut + 'static,
+        Fut: Future<Output = Vec<TreeNode>> + 'static,
+    {
+        self.loader = Some(Rc::new(move |value| Box::pin(loader(value))));
+        self
+    }
+
+    pub fn draggable(mut self, value: bool) -> Self {
+        self.draggable = value;
+        self
+    }
+
+    pub fn can_drop(mut self, handler: impl Fn(&TreeDropEvent) -> bool + 'static) -> Self {
+        self.can_drop = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn on_drop(
+        mut self,
+        handler: impl Fn(&TreeDropEvent, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_drop = Some(Rc::new(handler));
+        self.draggable = true;
+        self
+    }
+
     fn collect_default_expanded(nodes: &[TreeNode], output: &mut Vec<SharedString>) {
         struct Frame<'a> {
             nodes: &'a [TreeNode],
@@ -206,6 +325,38 @@ impl Tree {
         }
     }
 
+    fn collect_children_map(nodes: &[TreeNode]) -> BTreeMap<String, Vec<String>> {
+        let mut output = BTreeMap::new();
+        let mut pending = nodes.iter().collect::<Vec<_>>();
+        while let Some(node) = pending.pop() {
+            let children = node.children.iter().map(|child| child.value.to_string());
+            output.insert(node.value.to_string(), children.collect());
+            pending.extend(node.children.iter());
+        }
+        output
+    }
+
+    fn attach_loaded_children(nodes: &mut [TreeNode], loaded: &HashMap<String, Vec<TreeNode>>) {
+        let mut pending = nodes.iter_mut().collect::<Vec<_>>();
+        while let Some(node) = pending.pop() {
+            if node.has_children
+                && node.children.is_empty()
+                && let Some(children) = loaded.get(node.value.as_ref())
+            {
+                node.children = children.clone();
+                node.has_children = false;
+            }
+            pending.extend(node.children.iter_mut());
+        }
+    }
+
+    fn spawn_children_load(&self, loader: ChildrenLoader, value: String, cx: &mut gpui::App) {
+        let children = loader(SharedString::from(value.clone()));
+        tree_state::children_cache(&self.id)
+            .get(&value)
+            .load(children, cx);
+    }
+
     fn selected_bg(&self) -> gpui::Hsla {
         let base = resolve_hsla(&self.theme, self.theme.components.tree.row_selected_bg);
         match self.variant {
@@ -257,7 +408,7 @@ impl Tree {
                     format!("{}-{index}", frame.path_prefix)
                 };
                 let value = node.value.to_string();
-                let has_children = !node.children.is_empty();
+                let has_children = !node.children.is_empty() || node.has_children;
                 let is_expanded = expanded.contains(value.as_str());
                 let first_child = node.children.first().map(|child| child.value.to_string());
 
@@ -269,6 +420,7 @@ impl Tree {
                     path: path.clone(),
                     disabled: node.disabled,
                     has_children,
+                    child_count: node.children.len(),
                     first_child,
                 });
 
@@ -322,13 +474,149 @@ struct TreeRenderCtx {
     show_lines: bool,
     toggle_position: TreeTogglePosition,
     size_preset: crate::theme::TreeSizePreset,
+    min_hit_target: Option<gpui::Pixels>,
     radius: Radius,
     selected_bg: gpui::Hsla,
     on_select: Option<SelectHandler>,
     on_expanded_change: Option<ExpandedChangeHandler>,
+    visible: Rc<Vec<TreeVisibleNode>>,
+    drop_hover: Option<(String, TreeDropPosition)>,
+    draggable: bool,
+    can_drop: Option<CanDropHandler>,
+    on_drop: Option<DropHandler>,
+    loading: BTreeSet<String>,
+    checkable: bool,
+    children: Rc<BTreeMap<String, Vec<String>>>,
+    checked: Vec<String>,
+    checked_controlled: bool,
+    on_checked_change: Option<CheckedChangeHandler>,
 }
 
 impl TreeRenderCtx {
+    fn drop_event(
+        &self,
+        source: &str,
+        target: &str,
+        position: TreeDropPosition,
+    ) -> Option<TreeDropEvent> {
+        let (parent, index) =
+            tree_state::drop_destination(&self.visible, source, target, position)?;
+        let event = TreeDropEvent {
+            source: SharedString::from(source.to_string()),
+            target: SharedString::from(target.to_string()),
+            position,
+            parent: parent.map(SharedString::from),
+            index,
+        };
+        let allowed = self
+            .can_drop
+            .as_ref()
+            .is_none_or(|can_drop| (can_drop)(&event));
+        allowed.then_some(event)
+    }
+
+    fn bind_drag_and_drop(
+        &self,
+        row: gpui::Stateful<gpui::Div>,
+        node: &TreeVisibleNode,
+    ) -> gpui::Stateful<gpui::Div> {
+        let drag = TreeNodeDrag {
+            tree_id: self.tree_id.to_string(),
+            value: node.value.clone(),
+        };
+        let move_ctx = self.clone();
+        let drop_ctx = self.clone();
+        let target = node.value.clone();
+        let drop_target = node.value.clone();
+        row.on_drag(drag, |_drag, _, _, cx| cx.new(|_| EmptyView))
+            .on_drag_move::<TreeNodeDrag>(move |event, window, cx| {
+                let drag = event.drag(cx);
+                let position = event.event.position;
+                let same_tree = drag.tree_id == move_ctx.tree_id.key();
+                if !same_tree || !event.bounds.contains(&position) {
+                    return;
+                }
+                let offset = f32::from(position.y - event.bounds.top());
+                let drop_position =
+                    tree_state::drop_position(offset, f32::from(event.bounds.size.height));
+                let hover = move_ctx
+                    .drop_event(&drag.value, &target, drop_position)
+                    .map(|_| (target.clone(), drop_position));
+                if tree_state::set_drop_hover(&move_ctx.tree_id, hover) {
+                    window.refresh();
+                }
+            })
+            .on_drop::<TreeNodeDrag>(move |drag, window, cx| {
+                if drag.tree_id != drop_ctx.tree_id.key() {
+                    return;
+                }
+                let hover = tree_state::drop_hover(&drop_ctx.tree_id);
+                tree_state::set_drop_hover(&drop_ctx.tree_id, None);
+                window.refresh();
+                let Some((_, position)) = hover.filter(|(value, _)| *value == drop_target) else {
+                    return;
+                };
+                let Some(event) = drop_ctx.drop_event(&drag.value, &drop_target, position) else {
+                    return;
+                };
+                if let Some(handler) = drop_ctx.on_drop.as_ref() {
+                    (handler)(&event, window, cx);
+                }
+            })
+    }
+
+    fn render_check(&self, node: &TreeVisibleNode) -> gpui::Stateful<gpui::Div> {
+        let checked = self.checked.iter().cloned().collect::<BTreeSet<_>>();
+        let glyph = match tree_state::check_state(&self.children, &checked, &node.value) {
+            TreeCheckState::Checked => "☑",
+            TreeCheckState::Unchecked => "☐",
+            TreeCheckState::Indeterminate => "▣",
+        };
+        let check_id = self.tree_id.slot_index("check", node.path.clone());
+        let check = div()
+            .id(check_id.clone())
+            .flex()
+            .items_center()
+            .justify_center()
+            .w(self.size_preset.toggle_size)
+            .child(glyph);
+        if node.disabled {
+            return check;
+        }
+
+        let tree_id = self.tree_id.clone();
+        let value = node.value.clone();
+        let children = self.children.clone();
+        let snapshot = self.checked.clone();
+        let controlled = self.checked_controlled;
+        let on_checked_change = self.on_checked_change.clone();
+        let activate_handler: ActivateHandler = Rc::new(move |window, cx| {
+            let current = tree_state::resolve_checked(
+                &tree_id,
+                controlled,
+                snapshot.clone(),
+                snapshot.clone(),
+            );
+            let next = tree_state::toggle_checked(&children, &current, &value);
+            let should_refresh = tree_state::apply_checked(&tree_id, controlled, next.clone());
+            if let Some(handler) = on_checked_change.as_ref() {
+                (handler)(
+                    next.into_iter().map(SharedString::from).collect(),
+                    window,
+                    cx,
+                );
+            }
+            cx.stop_propagation();
+            if should_refresh {
+                window.refresh();
+            }
+        });
+        bind_press_adapter(
+            check.cursor_pointer(),
+            PressAdapter::new(check_id).on_activate(Some(activate_handler)),
+        )
+    }
+
     fn render_visible_row(&self, window: &gpui::Window, node: &TreeVisibleNode) -> AnyElement {
         let value_key = node.value.clone();
         let has_children = node.has_children;
@@ -375,7 +663,14 @@ impl TreeRenderCtx {
             .items_center()
             .justify_center();
         if has_children {
-            toggle = toggle.child(
+            let glyph = if self.loading.contains(value_key.as_str()) {
+                self.tree_id
+                    .ctx()
+                    .child_index("loader", node.path.clone(), Loader::new())
+                    .variant(LoaderVariant::Oval)
+                    .with_size(Size::Xs)
+                    .into_any_element()
+            } else {
                 self.tree_id
                     .ctx()
                     .child_index(
@@ -387,8 +682,10 @@ impl TreeRenderCtx {
                             "chevron-right"
                         }),
                     )
-                    .size(f32::from(self.size_preset.toggle_icon_size)),
-            );
+                    .size(f32::from(self.size_preset.toggle_icon_size))
+                    .into_any_element()
+            };
+            toggle = toggle.child(glyph);
             if !node.disabled {
                 let tree_id = self.tree_id.clone();
                 let value = SharedString::from(value_key.clone());
@@ -416,7 +713,11 @@ impl TreeRenderCtx {
                         window.refresh();
                     }
                 });
-                toggle = toggle.cursor_pointer();
+                toggle = apply_hit_target(
+                    toggle.cursor_pointer(),
+                    self.size_preset.toggle_size,
+                    self.min_hit_target,
+                );
                 toggle = bind_press_adapter(
                     toggle,
                     PressAdapter::new(self.tree_id.slot_index("toggle", node.path.clone()))
@@ -427,7 +728,7 @@ impl TreeRenderCtx {
 
         let connector = if self.show_lines && node.depth > 0 {
             Some(
-                div()
+                Stack::horizontal()
                     .id(self.tree_id.slot_index("line-h", node.path.clone()))
                     .w(self.size_preset.connector_stub_width)
                     .h(super::utils::hairline_px(window))
@@ -450,12 +751,13 @@ impl TreeRenderCtx {
                     .unwrap_or_else(|| SharedString::from(value_key.clone())),
             );
 
+        let check = self.checkable.then(|| self.render_check(node));
         if let Some(connector) = connector {
             row = row.child(connector);
         }
         row = match self.toggle_position {
-            TreeTogglePosition::Left => row.child(toggle).child(label),
-            TreeTogglePosition::Right => row.child(label).child(toggle),
+            TreeTogglePosition::Left => row.child(toggle).children(check).child(label),
+            TreeTogglePosition::Right => row.children(check).child(label).child(toggle),
         };
 
         if !node.disabled {
@@ -476,14 +778,47 @@ impl TreeRenderCtx {
                 }
             });
             row = row.cursor_pointer();
+            let row_id = self.tree_id.slot_index("row", node.path.clone());
             row = bind_press_adapter(
                 row,
-                PressAdapter::new(self.tree_id.slot_index("row", node.path.clone()))
-                    .on_activate(Some(activate_handler)),
+                PressAdapter::new(row_id.clone()).on_activate(Some(activate_handler)),
             );
+            row = apply_focus_ring(
+                &self.theme,
+                row,
+                control::focused_state(&row_id.to_string(), None, false),
+                resolve_radius(&self.theme, SemanticRadiusToken::from(self.radius)),
+            );
+            if self.draggable {
+                row = self.bind_drag_and_drop(row, node);
+            }
         } else {
             row = row.opacity(0.55).cursor_default();
         }
+
+        let drop_position = self
+            .drop_hover
+            .as_ref()
+            .filter(|(value, _)| *value == node.value)
+            .map(|(_, position)| *position);
+        let indicator = resolve_hsla(&self.theme, self.tokens.drop_indicator);
+        let thickness = px(2.0);
+        match drop_position {
+            Some(TreeDropPosition::Inside) => row = row.border_color(indicator),
+            Some(position) => {
+                let line = div()
+                    .absolute()
+                    .left_0()
+                    .right_0()
+                    .h(thickness)
+                    .bg(indicator);
+                row = row.relative().child(match position {
+                    TreeDropPosition::Before => line.top_0(),
+                    _ => line.bottom_0(),
+                });
+            }
+            None => {}
+        }
         row.into_any_element()
     }
 }
@@ -524,7 +859,21 @@ impl RenderOnce for Tree {
         let expanded_set = expanded_values.iter().cloned().collect::<BTreeSet<_>>();
         let tokens = self.theme.components.tree.clone();
         let tree_size_preset = tokens.sizes.for_size(self.size);
+        let loaded = tree_state::loaded_children(&self.id);
+        if !loaded.is_empty() {
+            Self::attach_loaded_children(&mut self.nodes, &loaded);
+        }
         let visible_nodes = Self::collect_visible_nodes(&self.nodes, &expanded_set);
+        if let Some(loader) = self.loader.clone() {
+            let loading = tree_state::loading_values(&self.id);
+            for value in tree_state::pending_loads(&visible_nodes, &expanded_set, &loading, &loaded)
+            {
+                self.spawn_children_load(loader.clone(), value, _cx);
+            }
+        }
+        if !_cx.has_active_drag() {
+            tree_state::set_drop_hover(&self.id, None);
+        }
         let ctx = TreeRenderCtx {
             tree_id: self.id.clone(),
             theme: self.theme.clone(),
@@ -537,10 +886,33 @@ impl RenderOnce for Tree {
             show_lines: self.show_lines,
             toggle_position: self.toggle_position,
             size_preset: tree_size_preset,
+            min_hit_target: crate::provider::CalmProvider::min_hit_target(_cx),
             radius: self.radius,
             selected_bg: self.selected_bg(),
             on_select: self.on_select.clone(),
             on_expanded_change: self.on_expanded_change.clone(),
+            visible: Rc::new(visible_nodes.clone()),
+            drop_hover: tree_state::drop_hover(&self.id),
+            draggable: self.draggable,
+            can_drop: self.can_drop.clone(),
+            on_drop: self.on_drop.clone(),
+            loading: tree_state::loading_values(&self.id).into_iter().collect(),
+            checkable: self.checkable,
+            children: Rc::new(Self::collect_children_map(&self.nodes)),
+            checked: tree_state::resolve_checked(
+                &self.id,
+                self.checked_controlled,
+                self.checked_values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+                self.default_checked_values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+            checked_controlled: self.checked_controlled,
+            on_checked_change: self.on_checked_change.clone(),
         };
 
         let tree_id = self.id.clone();
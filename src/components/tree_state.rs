@@ -1,6 +1,15 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{LazyLock, Mutex};
+
+use crate::resource::ResourceCache;
 
 use super::control;
+use super::tree::{TreeDropPosition, TreeNode};
+
+type ChildrenCache = ResourceCache<String, Vec<TreeNode>>;
+
+static LOADED_CHILDREN: LazyLock<Mutex<HashMap<String, ChildrenCache>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 #[derive(Clone, Debug)]
 pub struct TreeVisibleNode {
@@ -11,9 +20,17 @@ pub struct TreeVisibleNode {
     pub path: String,
     pub disabled: bool,
     pub has_children: bool,
+    pub child_count: usize,
     pub first_child: Option<String>,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TreeCheckState {
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
 #[derive(Default)]
 pub struct TreeKeyResult {
     pub next_selected: Option<String>,
@@ -186,3 +203,207 @@ pub fn key_transition(
 
     result
 }
+
+pub fn drop_position(offset: f32, height: f32) -> TreeDropPosition {
+    if height <= 0.0 {
+        return TreeDropPosition::Inside;
+    }
+    let ratio = offset / height;
+    if ratio < 0.25 {
+        TreeDropPosition::Before
+    } else if ratio > 0.75 {
+        TreeDropPosition::After
+    } else {
+        TreeDropPosition::Inside
+    }
+}
+
+pub fn is_descendant(visible_nodes: &[TreeVisibleNode], ancestor: &str, value: &str) -> bool {
+    let mut current = visible_nodes
+        .iter()
+        .find(|node| node.value == value)
+        .and_then(|node| node.parent.as_deref());
+    while let Some(parent) = current {
+        if parent == ancestor {
+            return true;
+        }
+        current = visible_nodes
+            .iter()
+            .find(|node| node.value == parent)
+            .and_then(|node| node.parent.as_deref());
+    }
+    false
+}
+
+pub fn drop_destination(
+    visible_nodes: &[TreeVisibleNode],
+    source: &str,
+    target: &str,
+    position: TreeDropPosition,
+) -> Option<(Option<String>, usize)> {
+    if source == target || is_descendant(visible_nodes, source, target) {
+        return None;
+    }
+    let source_parent = visible_nodes
+        .iter()
+        .find(|node| node.value == source)
+        .map(|node| node.parent.clone());
+    let target_node = visible_nodes.iter().find(|node| node.value == target)?;
+    if position == TreeDropPosition::Inside {
+        let moved_within = source_parent.as_ref() == Some(&Some(target.to_string()));
+        let index = target_node.child_count - usize::from(moved_within);
+        return Some((Some(target.to_string()), index));
+    }
+
+    let index = visible_nodes
+        .iter()
+        .filter(|node| node.parent == target_node.parent && node.value != source)
+        .position(|node| node.value == target)?;
+    let index = match position {
+        TreeDropPosition::After => index + 1,
+        _ => index,
+    };
+    Some((target_node.parent.clone(), index))
+}
+
+pub fn drop_hover(id: &str) -> Option<(String, TreeDropPosition)> {
+    let hover = control::list_state(id, "drop-hover", None, Vec::new());
+    let [value, position] = hover.as_slice() else {
+        return None;
+    };
+    let position = match position.as_str() {
+        "before" => TreeDropPosition::Before,
+        "after" => TreeDropPosition::After,
+        _ => TreeDropPosition::Inside,
+    };
+    Some((value.clone(), position))
+}
+
+pub fn set_drop_hover(id: &str, hover: Option<(String, TreeDropPosition)>) -> bool {
+    if drop_hover(id) == hover {
+        return false;
+    }
+    let next = hover
+        .map(|(value, position)| {
+            let position = match position {
+                TreeDropPosition::Before => "before",
+                TreeDropPosition::After => "after",
+                TreeDropPosition::Inside => "inside",
+            };
+            vec![value, position.to_string()]
+        })
+        .unwrap_or_default();
+    control::set_list_state(id, "drop-hover", next);
+    true
+}
+
+pub fn children_cache(id: &str) -> ChildrenCache {
+    LOADED_CHILDREN
+        .lock()
+        .map(|mut loaded| loaded.entry(id.to_string()).or_default().clone())
+        .unwrap_or_default()
+}
+
+pub fn loaded_children(id: &str) -> HashMap<String, Vec<TreeNode>> {
+    children_cache(id).values()
+}
+
+pub fn store_loaded_children(id: &str, value: &str, children: Vec<TreeNode>) {
+    children_cache(id).get(&value.to_string()).set(children);
+}
+
+pub fn loading_values(id: &str) -> Vec<String> {
+    let mut values = children_cache(id).loading_keys();
+    values.sort();
+    values
+}
+
+pub fn set_node_loading(id: &str, value: &str, loading: bool) {
+    let resource = children_cache(id).get(&value.to_string());
+    if loading {
+        resource.begin();
+    } else {
+        resource.cancel();
+    }
+}
+
+pub fn pending_loads(
+    visible_nodes: &[TreeVisibleNode],
+    expanded: &BTreeSet<String>,
+    loading: &[String],
+    loaded: &HashMap<String, Vec<TreeNode>>,
+) -> Vec<String> {
+    visible_nodes
+        .iter()
+        .filter(|node| node.has_children && node.child_count == 0)
+        .filter(|node| expanded.contains(&node.value))
+        .filter(|node| !loading.contains(&node.value) && !loaded.contains_key(&node.value))
+        .map(|node| node.value.clone())
+        .collect()
+}
+
+pub fn resolve_checked(
+    id: &str,
+    checked_controlled: bool,
+    controlled_values: Vec<String>,
+    default_values: Vec<String>,
+) -> Vec<String> {
+    control::list_state(
+        id,
+        "checked",
+        checked_controlled.then_some(controlled_values),
+        default_values,
+    )
+}
+
+pub fn apply_checked(id: &str, checked_controlled: bool, checked: Vec<String>) -> bool {
+    if checked_controlled {
+        return false;
+    }
+    control::set_list_state(id, "checked", checked);
+    true
+}
+
+pub fn leaf_descendants(children: &BTreeMap<String, Vec<String>>, value: &str) -> Vec<String> {
+    let mut leaves = Vec::new();
+    let mut pending = vec![value.to_string()];
+    while let Some(current) = pending.pop() {
+        match children.get(&current) {
+            Some(nested) if !nested.is_empty() => pending.extend(nested.iter().rev().cloned()),
+            _ => leaves.push(current),
+        }
+    }
+    leaves
+}
+
+pub fn check_state(
+    children: &BTreeMap<String, Vec<String>>,
+    checked: &BTreeSet<String>,
+    value: &str,
+) -> TreeCheckState {
+    let leaves = leaf_descendants(children, value);
+    let count = leaves.iter().filter(|leaf| checked.contains(*leaf)).count();
+    if count == 0 {
+        TreeCheckState::Unchecked
+    } else if count == leaves.len() {
+        TreeCheckState::Checked
+    } else {
+        TreeCheckState::Indeterminate
+    }
+}
+
+pub fn toggle_checked(
+    children: &BTreeMap<String, Vec<String>>,
+    current: &[String],
+    value: &str,
+) -> Vec<String> {
+    let checked = current.iter().cloned().collect::<BTreeSet<_>>();
+    let leaves = leaf_descendants(children, value);
+    let mut next = current.to_vec();
+    if check_state(children, &checked, value) == TreeCheckState::Checked {
+        next.retain(|leaf| !leaves.contains(leaf));
+    } else {
+        next.extend(leaves.into_iter().filter(|leaf| !checked.contains(leaf)));
+    }
+    next
+}
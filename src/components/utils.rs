@@ -2,10 +2,13 @@ use std::rc::Rc;
 
 use gpui::InteractiveElement;
 use gpui::StatefulInteractiveElement;
-use gpui::{ClickEvent, FontWeight, Hsla, Pixels, Styled, Window, px};
+use gpui::{ClickEvent, FontWeight, Hsla, ParentElement, Pixels, Styled, Window, div, px};
 
 use crate::style::{Radius, Variant};
-use crate::theme::{FieldSizePreset, ResolveWithTheme, SemanticRadiusToken, Theme};
+use crate::theme::{
+    FieldSizePreset, FocusRingStyle, PseudoStateTokens, ResolveWithTheme, SemanticRadiusToken,
+    Theme,
+};
 
 pub type PressHandler = Rc<dyn Fn(&ClickEvent, &mut Window, &mut gpui::App)>;
 
@@ -62,18 +65,114 @@ where
     node
 }
 
-pub fn default_pressable_surface_styles(bg: Hsla, focus_border: Hsla) -> InteractionStyles {
-    let hover_bg = bg.blend(gpui::white().opacity(0.06));
-    let active_bg = bg.blend(gpui::black().opacity(0.12));
+pub fn pressable_surface_styles(
+    bg: Hsla,
+    focus_border: Hsla,
+    states: PseudoStateTokens,
+) -> InteractionStyles {
+    let hover_bg = states.hover_bg_or(crate::theme::color_utils::lighten(bg, 0.06));
+    let hover_fg = states.hover_fg;
+    let active_bg = states.active_bg_or(crate::theme::color_utils::darken(bg, 0.12));
+    let focus_border = states.focus_border_or(focus_border);
 
     InteractionStyles::new()
-        .hover(interaction_style(move |style| style.bg(hover_bg)))
+        .hover(interaction_style(move |style| {
+            let style = style.bg(hover_bg);
+            match hover_fg {
+                Some(fg) => style.text_color(fg),
+                None => style,
+            }
+        }))
         .active(interaction_style(move |style| style.bg(active_bg)))
         .focus(interaction_style(move |style| {
             style.border_color(focus_border)
         }))
 }
 
+pub fn apply_disabled_state<T: Styled>(
+    node: T,
+    states: PseudoStateTokens,
+    fallback_opacity: f32,
+) -> T {
+    if !states.is_disabled_styled() {
+        return node.opacity(fallback_opacity);
+    }
+    let node = match states.disabled_bg {
+        Some(bg) => node.bg(bg),
+        None => node,
+    };
+    match states.disabled_fg {
+        Some(fg) => node.text_color(fg),
+        None => node,
+    }
+}
+
+pub fn apply_focus_ring<T>(theme: &Theme, node: T, focused: bool, radius: Pixels) -> T
+where
+    T: Styled + ParentElement,
+{
+    let ring = &theme.components.focus_ring;
+    if !focused || ring.width <= px(0.0) {
+        return node;
+    }
+    let strokes = match ring.style {
+        FocusRingStyle::Double => 3.0,
+        FocusRingStyle::Solid | FocusRingStyle::Dashed => 1.0,
+    };
+    let spread = ring.offset + ring.width * strokes;
+    let stroke = |inset: Pixels, radius: Pixels| {
+        div()
+            .absolute()
+            .top(inset)
+            .left(inset)
+            .right(inset)
+            .bottom(inset)
+            .rounded(radius)
+            .border(ring.width)
+            .border_color(ring.color)
+    };
+    let mut outline = stroke(-spread, radius + spread);
+    match ring.style {
+        FocusRingStyle::Solid => {}
+        FocusRingStyle::Dashed => outline = outline.border_dashed(),
+        FocusRingStyle::Double => {
+            let inner_radius = radius + ring.offset + ring.width;
+            outline = outline.child(stroke(ring.width, inner_radius));
+        }
+    }
+    node.child(outline)
+}
+
+pub fn hit_target_inset(visual: Pixels, min_hit_target: Option<Pixels>) -> Pixels {
+    min_hit_target.map_or(px(0.0), |min| {
+        px(((f32::from(min) - f32::from(visual)) / 2.0).max(0.0))
+    })
+}
+
+pub fn hit_area(
+    id: impl Into<gpui::ElementId>,
+    inset: Pixels,
+) -> Option<gpui::Stateful<gpui::Div>> {
+    (inset > px(0.0)).then(|| {
+        div()
+            .id(id)
+            .absolute()
+            .top(-inset)
+            .left(-inset)
+            .right(-inset)
+            .bottom(-inset)
+            .cursor_pointer()
+    })
+}
+
+pub fn apply_hit_target<T: Styled>(node: T, visual: Pixels, min_hit_target: Option<Pixels>) -> T {
+    let inset = hit_target_inset(visual, min_hit_target);
+    if inset <= px(0.0) {
+        return node;
+    }
+    node.p(inset).m(-inset)
+}
+
 pub fn resolve_hsla<T>(theme: &Theme, token: T) -> Hsla
 where
     T: ResolveWithTheme<Hsla>,
@@ -129,7 +228,7 @@ pub fn variant_text_weight(variant: Variant) -> FontWeight {
 }
 
 pub fn deepened_surface_border(bg: Hsla) -> Hsla {
-    bg.blend(gpui::black().opacity(0.12))
+    crate::theme::color_utils::darken(bg, 0.12)
 }
 
 pub fn offset_with_progress(offset_px: i16, progress: f32) -> f32 {
@@ -167,9 +266,11 @@ pub fn quantized_stroke_px(window: &Window, logical_px: f32) -> Pixels {
 
 #[cfg(test)]
 mod tests {
-    use super::{deepened_surface_border, offset_with_progress, variant_text_weight};
+    use super::{
+        deepened_surface_border, hit_target_inset, offset_with_progress, variant_text_weight,
+    };
     use crate::style::Variant;
-    use gpui::FontWeight;
+    use gpui::{FontWeight, px};
 
     #[test]
     fn offset_with_progress_interpolates_towards_zero() {
@@ -192,4 +293,12 @@ mod tests {
         assert!(border.a >= 0.0);
         assert!(border.a <= 1.0);
     }
+
+    #[test]
+    fn hit_target_inset_only_grows_controls_below_minimum() {
+        assert_eq!(hit_target_inset(px(16.0), None), px(0.0));
+        assert_eq!(hit_target_inset(px(16.0), Some(px(24.0))), px(4.0));
+        assert_eq!(hit_target_inset(px(18.0), Some(px(32.0))), px(7.0));
+        assert_eq!(hit_target_inset(px(36.0), Some(px(24.0))), px(0.0));
+    }
 }
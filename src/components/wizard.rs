@@ -0,0 +1,383 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use gpui::InteractiveElement;
+use gpui::{AnyElement, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window, div};
+
+use crate::contracts::{Disableable, MotionAware, Varianted};
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+use crate::style::{GroupOrientation, Variant};
+
+use super::button::Button;
+use super::selection_state;
+use super::stepper::{Stepper, StepperStep};
+use super::utils::resolve_hsla;
+use super::wizard_state;
+
+type SlotRenderer = Box<dyn FnOnce() -> AnyElement>;
+type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), SharedString>>>>;
+type SkipRule = Rc<dyn Fn() -> bool>;
+type StepChangeHandler = Rc<dyn Fn(usize, SharedString, &mut Window, &mut gpui::App)>;
+type FinishHandler = Rc<dyn Fn(&mut Window, &mut gpui::App)>;
+
+#[derive(Clone)]
+enum StepValidator {
+    Sync(Rc<dyn Fn(&mut Window, &mut gpui::App) -> Result<(), SharedString>>),
+    Async(Rc<dyn Fn() -> ValidationFuture>),
+}
+
+/// One page of a [`Wizard`].
+pub struct WizardStep {
+    pub value: SharedString,
+    pub label: Option<SharedString>,
+    pub description: Option<SharedString>,
+    content: Option<SlotRenderer>,
+    validator: Option<StepValidator>,
+    skip_when: Option<SkipRule>,
+}
+
+impl WizardStep {
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: None,
+            description: None,
+            content: None,
+            validator: None,
+            skip_when: None,
+        }
+    }
+
+    pub fn label(mut self, value: impl Into<SharedString>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    pub fn description(mut self, value: impl Into<SharedString>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    pub fn content(mut self, value: impl IntoElement + 'static) -> Self {
+        self.content = Some(Box::new(|| value.into_any_element()));
+        self
+    }
+
+    /// Runs when Next or Finish is pressed on this step. An `Err` keeps the
+    /// wizard here and shows the message under the panel.
+    pub fn validate(
+        mut self,
+        check: impl Fn(&mut Window, &mut gpui::App) -> Result<(), SharedString> + 'static,
+    ) -> Self {
+        self.validator = Some(StepValidator::Sync(Rc::new(check)));
+        self
+    }
+
+    /// Like [`WizardStep::validate`], for checks that have to wait, such as a
+    /// server round trip. The Next button shows a loader until it resolves.
+    pub fn validate_async<F, Fut>(mut self, check: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = Result<(), SharedString>> + 'static,
+    {
+        self.validator = Some(StepValidator::Async(Rc::new(move || Box::pin(check()))));
+        self
+    }
+
+    /// Leaves this step out of Next/Back navigation while `rule` returns
+    /// `true`. Checked on every render.
+    pub fn skip_when(mut self, rule: impl Fn() -> bool + 'static) -> Self {
+        self.skip_when = Some(Rc::new(rule));
+        self
+    }
+}
+
+#[derive(Clone)]
+struct WizardCommit {
+    id: ComponentId,
+    controlled: bool,
+    active: usize,
+    values: Vec<SharedString>,
+    skipped: Vec<bool>,
+    on_step_change: Option<StepChangeHandler>,
+    on_finish: Option<FinishHandler>,
+}
+
+impl WizardCommit {
+    fn go_to(&self, index: usize, window: &mut Window, cx: &mut gpui::App) {
+        wizard_state::cancel_validation(&self.id);
+        wizard_state::set_error(&self.id, None);
+        selection_state::apply_usize(&self.id, "active", self.controlled, index);
+        if let Some(handler) = self.on_step_change.as_ref() {
+            (handler)(index, self.values[index].clone(), window, cx);
+        }
+        window.refresh();
+    }
+
+    fn advance(&self, window: &mut Window, cx: &mut gpui::App) {
+        match wizard_state::next_step(self.active, &self.skipped) {
+            Some(next) => self.go_to(next, window, cx),
+            None => {
+                if let Some(handler) = self.on_finish.as_ref() {
+                    (handler)(window, cx);
+                }
+                window.refresh();
+            }
+        }
+    }
+
+    fn reject(&self, message: SharedString, window: &mut Window) {
+        wizard_state::set_error(&self.id, Some(message.to_string()));
+        window.refresh();
+    }
+
+    fn submit(&self, validator: Option<StepValidator>, window: &mut Window, cx: &mut gpui::App) {
+        if wizard_state::validating(&self.id) {
+            return;
+        }
+        match validator {
+            None => self.advance(window, cx),
+            Some(StepValidator::Sync(check)) => match check(window, cx) {
+                Ok(()) => self.advance(window, cx),
+                Err(message) => self.reject(message, window),
+            },
+            Some(StepValidator::Async(check)) => {
+                let ticket = wizard_state::begin_validation(&self.id);
+                wizard_state::set_error(&self.id, None);
+                window.refresh();
+                let pending = check();
+                let window_handle = window.window_handle();
+                let commit = self.clone();
+                cx.spawn(async move |cx| {
+                    let result = pending.await;
+                    let _ = window_handle.update(cx, |_, window, cx| {
+                        if !wizard_state::finish_validation(&commit.id, ticket) {
+                            return;
+                        }
+                        match result {
+                            Ok(()) => commit.advance(window, cx),
+                            Err(message) => commit.reject(message, window),
+                        }
+                    });
+                })
+                .detach();
+            }
+        }
+    }
+}
+
+/// Multi-step flow: a [`Stepper`] showing the current step's content, with
+/// Back and Next/Finish buttons underneath. Next runs the step's validation
+/// first; earlier steps can also be revisited by clicking them.
+#[derive(IntoElement)]
+pub struct Wizard {
+    pub(crate) id: ComponentId,
+    steps: Vec<WizardStep>,
+    active: Option<usize>,
+    default_active: usize,
+    orientation: GroupOrientation,
+    back_label: SharedString,
+    next_label: SharedString,
+    finish_label: SharedString,
+    on_step_change: Option<StepChangeHandler>,
+    on_finish: Option<FinishHandler>,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+}
+
+impl Wizard {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            steps: Vec::new(),
+            active: None,
+            default_active: 0,
+            orientation: GroupOrientation::Horizontal,
+            back_label: "Back".into(),
+            next_label: "Next".into(),
+            finish_label: "Finish".into(),
+            on_step_change: None,
+            on_finish: None,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+        }
+    }
+
+    pub fn step(mut self, step: WizardStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn steps(mut self, steps: impl IntoIterator<Item = WizardStep>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    pub fn active(mut self, value: usize) -> Self {
+        self.active = Some(value);
+        self
+    }
+
+    pub fn default_active(mut self, value: usize) -> Self {
+        self.default_active = value;
+        self
+    }
+
+    pub fn orientation(mut self, value: GroupOrientation) -> Self {
+        self.orientation = value;
+        self
+    }
+
+    pub fn back_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.back_label = value.into();
+        self
+    }
+
+    pub fn next_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.next_label = value.into();
+        self
+    }
+
+    pub fn finish_label(mut self, value: impl Into<SharedString>) -> Self {
+        self.finish_label = value.into();
+        self
+    }
+
+    /// Called with the index and value of the step being moved to.
+    pub fn on_step_change(
+        mut self,
+        handler: impl Fn(usize, SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_step_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called when Finish passes the last step's validation.
+    pub fn on_finish(mut self, handler: impl Fn(&mut Window, &mut gpui::App) + 'static) -> Self {
+        self.on_finish = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl MotionAware for Wizard {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+impl RenderOnce for Wizard {
+    fn render(mut self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.wizard.clone();
+        let skipped = self
+            .steps
+            .iter()
+            .map(|step| step.skip_when.as_ref().is_some_and(|rule| rule()))
+            .collect::<Vec<_>>();
+        let controlled = self.active.is_some();
+        let resolved = selection_state::resolve_usize(
+            &self.id,
+            "active",
+            controlled,
+            self.active.unwrap_or(self.default_active),
+            self.default_active,
+        );
+        let active = wizard_state::settle(resolved, &skipped);
+        let is_last = wizard_state::next_step(active, &skipped).is_none();
+        let has_previous = wizard_state::previous_step(active, &skipped).is_some();
+        let validating = wizard_state::validating(&self.id);
+        let validator = self
+            .steps
+            .get(active)
+            .and_then(|step| step.validator.clone());
+        let commit = WizardCommit {
+            id: self.id.clone(),
+            controlled,
+            active,
+            values: self.steps.iter().map(|step| step.value.clone()).collect(),
+            skipped: skipped.clone(),
+            on_step_change: self.on_step_change.clone(),
+            on_finish: self.on_finish.clone(),
+        };
+
+        let steps = std::mem::take(&mut self.steps)
+            .into_iter()
+            .enumerate()
+            .map(|(index, step)| {
+                let mut item = StepperStep::new(step.value).disabled(skipped[index]);
+                item.label = step.label;
+                item.description = step.description;
+                match step.content {
+                    Some(content) if index == active => item.content(content()),
+                    _ => item,
+                }
+            });
+        let jump = commit.clone();
+        let stepper = self
+            .id
+            .ctx()
+            .child("stepper", Stepper::new())
+            .steps(steps)
+            .active(active)
+            .orientation(self.orientation)
+            .motion(self.motion)
+            .on_change(move |index, _, window, cx| {
+                if index < jump.active {
+                    jump.go_to(index, window, cx);
+                }
+            });
+
+        let error = wizard_state::error(&self.id).map(|message| {
+            div()
+                .text_size(tokens.error_size)
+                .text_color(resolve_hsla(&self.theme, tokens.error_fg))
+                .child(message)
+        });
+
+        let back = commit.clone();
+        let back_button = self
+            .id
+            .ctx()
+            .child("back", Button::new())
+            .label(self.back_label.clone())
+            .with_variant(Variant::Default)
+            .disabled(!has_previous)
+            .on_click(move |_, window, cx| {
+                if let Some(previous) = wizard_state::previous_step(back.active, &back.skipped) {
+                    back.go_to(previous, window, cx);
+                }
+            });
+        let next_button = self
+            .id
+            .ctx()
+            .child("next", Button::new())
+            .label(if is_last {
+                self.finish_label.clone()
+            } else {
+                self.next_label.clone()
+            })
+            .loading(validating)
+            .on_click(move |_, window, cx| commit.submit(validator.clone(), window, cx));
+
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .gap(tokens.root_gap)
+            .w_full()
+            .child(stepper)
+            .children(error)
+            .child(
+                div()
+                    .flex()
+                    .justify_between()
+                    .gap(tokens.footer_gap)
+                    .child(back_button)
+                    .child(next_button),
+            )
+    }
+}
@@ -0,0 +1,64 @@
+use super::control;
+
+/// Closest step after `active` that is not skipped.
+pub fn next_step(active: usize, skipped: &[bool]) -> Option<usize> {
+    (active + 1..skipped.len()).find(|index| !skipped[*index])
+}
+
+/// Closest step before `active` that is not skipped.
+pub fn previous_step(active: usize, skipped: &[bool]) -> Option<usize> {
+    (0..active.min(skipped.len()))
+        .rev()
+        .find(|index| !skipped[*index])
+}
+
+/// Moves `active` off a skipped step, preferring the next shown one.
+pub fn settle(active: usize, skipped: &[bool]) -> usize {
+    let Some(last) = skipped.len().checked_sub(1) else {
+        return 0;
+    };
+    let active = active.min(last);
+    if !skipped[active] {
+        return active;
+    }
+    next_step(active, skipped)
+        .or_else(|| previous_step(active, skipped))
+        .unwrap_or(active)
+}
+
+pub fn error(id: &str) -> Option<String> {
+    control::optional_text_state(id, "error", None, None)
+}
+
+pub fn set_error(id: &str, value: Option<String>) {
+    control::set_optional_text_state(id, "error", value);
+}
+
+pub fn validating(id: &str) -> bool {
+    control::bool_state(id, "validating", None, false)
+}
+
+/// Marks a validation as running and returns its ticket. Starting another
+/// one or calling [`cancel_validation`] makes older tickets stale.
+pub fn begin_validation(id: &str) -> usize {
+    let ticket = control::usize_state(id, "validation", None, 0) + 1;
+    control::set_usize_state(id, "validation", ticket);
+    control::set_bool_state(id, "validating", true);
+    ticket
+}
+
+/// Ends the validation for `ticket`. Returns `false` when it went stale, in
+/// which case its result should be dropped.
+pub fn finish_validation(id: &str, ticket: usize) -> bool {
+    if control::usize_state(id, "validation", None, 0) != ticket {
+        return false;
+    }
+    control::set_bool_state(id, "validating", false);
+    true
+}
+
+pub fn cancel_validation(id: &str) {
+    let ticket = control::usize_state(id, "validation", None, 0);
+    control::set_usize_state(id, "validation", ticket + 1);
+    control::set_bool_state(id, "validating", false);
+}
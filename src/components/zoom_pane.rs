@@ -0,0 +1,530 @@
+use super::transition::TransitionExt;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+use gpui::InteractiveElement;
+use gpui::StatefulInteractiveElement;
+use gpui::{
+    AnyElement, AppContext, Bounds, EmptyView, Hsla, IntoElement, MouseButton, ParentElement,
+    Pixels, RenderOnce, Styled, Window, canvas, div, fill, point, px, size,
+};
+
+use crate::contracts::MotionAware;
+use crate::id::ComponentId;
+use crate::motion::MotionConfig;
+
+pub use super::zoom_pane_state::ZoomView;
+
+use super::utils::resolve_hsla;
+use super::zoom_pane_state::{self, ZOOM_STEP};
+
+type ContentRenderer = Box<dyn FnOnce(ZoomView, &mut Window, &mut gpui::App) -> AnyElement>;
+type ZoomChangeHandler = Rc<dyn Fn(f32, &mut Window, &mut gpui::App)>;
+
+#[derive(Clone, Copy, Debug)]
+struct ZoomLimits {
+    min: f32,
+    max: f32,
+    fit_padding: f32,
+}
+
+impl Default for ZoomLimits {
+    fn default() -> Self {
+        Self {
+            min: 0.1,
+            max: 8.0,
+            fit_padding: 24.0,
+        }
+    }
+}
+
+#[derive(Default)]
+struct ZoomPaneHandleState {
+    view: ZoomView,
+    viewport: (f32, f32),
+    content: Option<(f32, f32)>,
+    limits: ZoomLimits,
+}
+
+#[derive(Clone, Default)]
+pub struct ZoomPaneHandle {
+    state: Arc<RwLock<ZoomPaneHandleState>>,
+}
+
+impl ZoomPaneHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn view(&self) -> ZoomView {
+        self.state.read().expect("zoom pane state poisoned").view
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.view().zoom
+    }
+
+    pub fn set_view(&self, view: ZoomView) {
+        let mut state = self.state.write().expect("zoom pane state poisoned");
+        let zoom = zoom_pane_state::clamp_zoom(view.zoom, state.limits.min, state.limits.max);
+        state.view = ZoomView { zoom, ..view };
+    }
+
+    pub fn set_zoom(&self, zoom: f32) {
+        let mut state = self.state.write().expect("zoom pane state poisoned");
+        let zoom = zoom_pane_state::clamp_zoom(zoom, state.limits.min, state.limits.max);
+        let (width, height) = state.viewport;
+        state.view = state.view.zoom_at(zoom, width * 0.5, height * 0.5);
+    }
+
+    pub fn zoom_in(&self) {
+        self.set_zoom(self.zoom() * ZOOM_STEP);
+    }
+
+    pub fn zoom_out(&self) {
+        self.set_zoom(self.zoom() / ZOOM_STEP);
+    }
+
+    pub fn fit(&self) {
+        let mut state = self.state.write().expect("zoom pane state poisoned");
+        let Some(content) = state.content else {
+            return;
+        };
+        let limits = state.limits;
+        state.view = ZoomView::fit(
+            content,
+            state.viewport,
+            limits.fit_padding,
+            limits.min,
+            limits.max,
+        );
+    }
+
+    pub fn center(&self) {
+        let mut state = self.state.write().expect("zoom pane state poisoned");
+        let Some(content) = state.content else {
+            return;
+        };
+        state.view = state.view.centered(content, state.viewport);
+    }
+
+    pub fn reset(&self) {
+        self.state.write().expect("zoom pane state poisoned").view = ZoomView::default();
+    }
+
+    fn sync_layout(&self, content: Option<(f32, f32)>, limits: ZoomLimits) {
+        let mut state = self.state.write().expect("zoom pane state poisoned");
+        state.content = content;
+        state.limits = limits;
+    }
+
+    fn sync_viewport(&self, viewport: (f32, f32)) {
+        self.state
+            .write()
+            .expect("zoom pane state poisoned")
+            .viewport = viewport;
+    }
+}
+
+#[derive(Clone)]
+enum ViewStore {
+    Handle(ZoomPaneHandle),
+    Local(String),
+}
+
+impl ViewStore {
+    fn load(&self) -> ZoomView {
+        match self {
+            Self::Handle(handle) => handle.view(),
+            Self::Local(id) => zoom_pane_state::load_view(id, ZoomView::default()),
+        }
+    }
+
+    fn store(&self, view: ZoomView) {
+        match self {
+            Self::Handle(handle) => handle.set_view(view),
+            Self::Local(id) => zoom_pane_state::store_view(id, view),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ZoomPaneDragState {
+    pane_id: String,
+}
+
+#[derive(IntoElement)]
+pub struct ZoomPane {
+    pub(crate) id: ComponentId,
+    handle: Option<ZoomPaneHandle>,
+    content: Option<ContentRenderer>,
+    content_size: Option<(f32, f32)>,
+    height_px: Option<f32>,
+    limits: ZoomLimits,
+    show_grid: bool,
+    show_rulers: bool,
+    pannable: bool,
+    pub(crate) theme: crate::theme::LocalTheme,
+    motion: MotionConfig,
+    on_zoom_change: Option<ZoomChangeHandler>,
+}
+
+impl ZoomPane {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ComponentId::default(),
+            handle: None,
+            content: None,
+            content_size: None,
+            height_px: None,
+            limits: ZoomLimits::default(),
+            show_grid: false,
+            show_rulers: false,
+            pannable: true,
+            theme: crate::theme::LocalTheme::default(),
+            motion: MotionConfig::default(),
+            on_zoom_change: None,
+        }
+    }
+
+    pub fn handle(mut self, handle: ZoomPaneHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    pub fn content<E>(
+        mut self,
+        render: impl FnOnce(ZoomView, &mut Window, &mut gpui::App) -> E + 'static,
+    ) -> Self
+    where
+        E: IntoElement,
+    {
+        self.content = Some(Box::new(move |view, window, cx| {
+            render(view, window, cx).into_any_element()
+        }));
+        self
+    }
+
+    pub fn content_size(mut self, width: f32, height: f32) -> Self {
+        self.content_size = Some((width.max(1.0), height.max(1.0)));
+        self
+    }
+
+    pub fn height(mut self, value: f32) -> Self {
+        self.height_px = Some(value.max(1.0));
+        self
+    }
+
+    pub fn zoom_range(mut self, min: f32, max: f32) -> Self {
+        self.limits.min = min.max(0.01);
+        self.limits.max = max.max(self.limits.min);
+        self
+    }
+
+    pub fn fit_padding(mut self, value: f32) -> Self {
+        self.limits.fit_padding = value.max(0.0);
+        self
+    }
+
+    pub fn grid(mut self, value: bool) -> Self {
+        self.show_grid = value;
+        self
+    }
+
+    pub fn rulers(mut self, value: bool) -> Self {
+        self.show_rulers = value;
+        self
+    }
+
+    pub fn pannable(mut self, value: bool) -> Self {
+        self.pannable = value;
+        self
+    }
+
+    pub fn on_zoom_change(
+        mut self,
+        handler: impl Fn(f32, &mut Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        self.on_zoom_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn apply_view(
+        store: &ViewStore,
+        previous: ZoomView,
+        next: ZoomView,
+        on_zoom_change: Option<&ZoomChangeHandler>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) {
+        store.store(next);
+        window.refresh();
+        if (previous.zoom - next.zoom).abs() > f32::EPSILON
+            && let Some(handler) = on_zoom_change
+        {
+            (handler)(next.zoom, window, cx);
+        }
+    }
+}
+
+impl MotionAware for ZoomPane {
+    fn motion(mut self, value: MotionConfig) -> Self {
+        self.motion = value;
+        self
+    }
+}
+
+pub(super) fn paint_line(
+    window: &mut Window,
+    origin: (Pixels, Pixels),
+    extent: (f32, f32),
+    color: Hsla,
+) {
+    window.paint_quad(fill(
+        Bounds::new(point(origin.0, origin.1), size(px(extent.0), px(extent.1))),
+        color,
+    ));
+}
+
+impl RenderOnce for ZoomPane {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        self.theme.sync_from_provider(cx);
+        let tokens = self.theme.components.zoom_pane.clone();
+        let grid_color = resolve_hsla(&self.theme, tokens.grid_line);
+        let grid_major_color = resolve_hsla(&self.theme, tokens.grid_line_major);
+        let ruler_bg = resolve_hsla(&self.theme, tokens.ruler_bg);
+        let ruler_tick = resolve_hsla(&self.theme, tokens.ruler_tick);
+        let ruler_size = f32::from(tokens.ruler_size);
+        let grid_spacing = f32::from(tokens.grid_spacing);
+
+        let store = match self.handle.clone() {
+            Some(handle) => {
+                handle.sync_layout(self.content_size, self.limits);
+                ViewStore::Handle(handle)
+            }
+            None => ViewStore::Local(self.id.to_string()),
+        };
+        let view = store.load();
+
+        let show_grid = self.show_grid;
+        let background = canvas(
+            {
+                let id = self.id.to_string();
+                let handle = self.handle.clone();
+                move |bounds, _, _| {
+                    let viewport = (f32::from(bounds.size.width), f32::from(bounds.size.height));
+                    zoom_pane_state::store_viewport(
+                        &id,
+                        (f32::from(bounds.origin.x), f32::from(bounds.origin.y)),
+                        viewport,
+                    );
+                    if let Some(handle) = handle.as_ref() {
+                        handle.sync_viewport(viewport);
+                    }
+                }
+            },
+            move |bounds, _, window, _| {
+                if !show_grid {
+                    return;
+                }
+                let width = f32::from(bounds.size.width);
+                let height = f32::from(bounds.size.height);
+                for (x, major) in
+                    zoom_pane_state::grid_lines(view.offset_x, view.zoom, grid_spacing, width)
+                {
+                    let color = if major { grid_major_color } else { grid_color };
+                    paint_line(
+                        window,
+                        (bounds.origin.x + px(x), bounds.origin.y),
+                        (1.0, height),
+                        color,
+                    );
+                }
+                for (y, major) in
+                    zoom_pane_state::grid_lines(view.offset_y, view.zoom, grid_spacing, height)
+                {
+                    let color = if major { grid_major_color } else { grid_color };
+                    paint_line(
+                        window,
+                        (bounds.origin.x, bounds.origin.y + px(y)),
+                        (width, 1.0),
+                        color,
+                    );
+                }
+            },
+        )
+        .absolute()
+        .size_full();
+
+        let mut stage = div()
+            .absolute()
+            .left(px(view.offset_x))
+            .top(px(view.offset_y));
+        if let Some((width, height)) = self.content_size {
+            stage = stage.w(px(width * view.zoom)).h(px(height * view.zoom));
+        }
+        if let Some(content) = self.content.take() {
+            stage = stage.child(content(view, window, cx));
+        }
+
+        let mut pane = div()
+            .id(self.id.clone())
+            .relative()
+            .w_full()
+            .overflow_hidden()
+            .bg(resolve_hsla(&self.theme, tokens.bg))
+            .child(background)
+            .child(stage);
+        pane = match self.height_px {
+            Some(height) => pane.h(px(height)),
+            None => pane.h_full(),
+        };
+
+        if self.show_rulers {
+            pane = pane.child(
+                canvas(
+                    |_, _, _| (),
+                    move |bounds, _, window, _| {
+                        let width = f32::from(bounds.size.width);
+                        let height = f32::from(bounds.size.height);
+                        paint_line(
+                            window,
+                            (bounds.origin.x, bounds.origin.y),
+                            (width, ruler_size),
+                            ruler_bg,
+                        );
+                        paint_line(
+                            window,
+                            (bounds.origin.x, bounds.origin.y),
+                            (ruler_size, height),
+                            ruler_bg,
+                        );
+                        for (x, major) in zoom_pane_state::grid_lines(
+                            view.offset_x,
+                            view.zoom,
+                            grid_spacing,
+                            width,
+                        ) {
+                            if x < ruler_size {
+                                continue;
+                            }
+                            let len = if major {
+                                ruler_size * 0.6
+                            } else {
+                                ruler_size * 0.3
+                            };
+                            paint_line(
+                                window,
+                                (
+                                    bounds.origin.x + px(x),
+                                    bounds.origin.y + px(ruler_size - len),
+                                ),
+                                (1.0, len),
+                                ruler_tick,
+                            );
+                        }
+                        for (y, major) in zoom_pane_state::grid_lines(
+                            view.offset_y,
+                            view.zoom,
+                            grid_spacing,
+                            height,
+                        ) {
+                            if y < ruler_size {
+                                continue;
+                            }
+                            let len = if major {
+                                ruler_size * 0.6
+                            } else {
+                                ruler_size * 0.3
+                            };
+                            paint_line(
+                                window,
+                                (
+                                    bounds.origin.x + px(ruler_size - len),
+                                    bounds.origin.y + px(y),
+                                ),
+                                (len, 1.0),
+                                ruler_tick,
+                            );
+                        }
+                    },
+                )
+                .absolute()
+                .size_full(),
+            );
+        }
+
+        let pane_id = self.id.to_string();
+        let limits = self.limits;
+        let on_zoom_change = self.on_zoom_change.clone();
+        pane = pane.on_scroll_wheel({
+            let pane_id = pane_id.clone();
+            let store = store.clone();
+            let on_zoom_change = on_zoom_change.clone();
+            move |event, window, cx| {
+                let delta = event.delta.pixel_delta(px(16.0));
+                let previous = store.load();
+                let next = if event.modifiers.platform || event.modifiers.control {
+                    let zoom = zoom_pane_state::clamp_zoom(
+                        previous.zoom * zoom_pane_state::wheel_zoom_factor(f32::from(delta.y)),
+                        limits.min,
+                        limits.max,
+                    );
+                    let (anchor_x, anchor_y) = zoom_pane_state::viewport_local(
+                        &pane_id,
+                        f32::from(event.position.x),
+                        f32::from(event.position.y),
+                    );
+                    previous.zoom_at(zoom, anchor_x, anchor_y)
+                } else {
+                    previous.pan(f32::from(delta.x), f32::from(delta.y))
+                };
+                Self::apply_view(&store, previous, next, on_zoom_change.as_ref(), window, cx);
+                cx.stop_propagation();
+            }
+        });
+
+        if self.pannable {
+            pane = pane
+                .cursor_grab()
+                .on_mouse_down(MouseButton::Left, {
+                    let pane_id = pane_id.clone();
+                    move |event, _, _| {
+                        zoom_pane_state::store_drag_point(
+                            &pane_id,
+                            f32::from(event.position.x),
+                            f32::from(event.position.y),
+                        );
+                    }
+                })
+                .on_drag(
+                    ZoomPaneDragState {
+                        pane_id: pane_id.clone(),
+                    },
+                    |_drag, _, _, cx| cx.new(|_| EmptyView),
+                )
+                .on_drag_move::<ZoomPaneDragState>(move |event, window, cx| {
+                    if event.drag(cx).pane_id != pane_id {
+                        return;
+                    }
+                    let (dx, dy) = zoom_pane_state::drag_delta(
+                        &pane_id,
+                        f32::from(event.event.position.x),
+                        f32::from(event.event.position.y),
+                    );
+                    let previous = store.load();
+                    Self::apply_view(
+                        &store,
+                        previous,
+                        previous.pan(dx, dy),
+                        on_zoom_change.as_ref(),
+                        window,
+                        cx,
+                    );
+                });
+        }
+
+        pane.with_enter_transition(self.id.slot("enter"), self.motion)
+    }
+}
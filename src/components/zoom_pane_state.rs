@@ -0,0 +1,156 @@
+use super::control;
+
+pub const ZOOM_STEP: f32 = 1.25;
+const WHEEL_ZOOM_SPEED: f32 = 0.0025;
+const MIN_GRID_STEP_PX: f32 = 6.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomView {
+    pub zoom: f32,
+    pub offset_x: f32,
+    pub offset_y: f32,
+}
+
+impl Default for ZoomView {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            offset_x: 0.0,
+            offset_y: 0.0,
+        }
+    }
+}
+
+impl ZoomView {
+    pub fn to_content(self, x: f32, y: f32) -> (f32, f32) {
+        (
+            (x - self.offset_x) / self.zoom,
+            (y - self.offset_y) / self.zoom,
+        )
+    }
+
+    pub fn to_viewport(self, x: f32, y: f32) -> (f32, f32) {
+        (x * self.zoom + self.offset_x, y * self.zoom + self.offset_y)
+    }
+
+    pub fn zoom_at(self, zoom: f32, anchor_x: f32, anchor_y: f32) -> Self {
+        let (content_x, content_y) = self.to_content(anchor_x, anchor_y);
+        Self {
+            zoom,
+            offset_x: anchor_x - content_x * zoom,
+            offset_y: anchor_y - content_y * zoom,
+        }
+    }
+
+    pub fn pan(self, dx: f32, dy: f32) -> Self {
+        Self {
+            offset_x: self.offset_x + dx,
+            offset_y: self.offset_y + dy,
+            ..self
+        }
+    }
+
+    pub fn centered(self, content: (f32, f32), viewport: (f32, f32)) -> Self {
+        Self {
+            offset_x: (viewport.0 - content.0 * self.zoom) * 0.5,
+            offset_y: (viewport.1 - content.1 * self.zoom) * 0.5,
+            ..self
+        }
+    }
+
+    pub fn fit(
+        content: (f32, f32),
+        viewport: (f32, f32),
+        padding: f32,
+        min_zoom: f32,
+        max_zoom: f32,
+    ) -> Self {
+        let available_w = (viewport.0 - padding * 2.0).max(1.0);
+        let available_h = (viewport.1 - padding * 2.0).max(1.0);
+        let zoom = clamp_zoom(
+            (available_w / content.0.max(1.0)).min(available_h / content.1.max(1.0)),
+            min_zoom,
+            max_zoom,
+        );
+        Self {
+            zoom,
+            ..Self::default()
+        }
+        .centered(content, viewport)
+    }
+}
+
+pub fn clamp_zoom(zoom: f32, min_zoom: f32, max_zoom: f32) -> f32 {
+    let min_zoom = min_zoom.max(0.01);
+    let max_zoom = max_zoom.max(min_zoom);
+    if zoom.is_finite() {
+        zoom.clamp(min_zoom, max_zoom)
+    } else {
+        1.0_f32.clamp(min_zoom, max_zoom)
+    }
+}
+
+pub fn wheel_zoom_factor(delta_y: f32) -> f32 {
+    (delta_y * WHEEL_ZOOM_SPEED).exp()
+}
+
+pub fn grid_lines(offset: f32, zoom: f32, spacing: f32, extent: f32) -> Vec<(f32, bool)> {
+    let mut step = spacing.max(1.0) * zoom.max(0.01);
+    while step < MIN_GRID_STEP_PX {
+        step *= 5.0;
+    }
+    let first = (-offset / step).ceil() as i64;
+    let last = ((extent - offset) / step).floor() as i64;
+    (first..=last)
+        .map(|index| (offset + index as f32 * step, index.rem_euclid(5) == 0))
+        .collect()
+}
+
+pub fn load_view(id: &str, default: ZoomView) -> ZoomView {
+    ZoomView {
+        zoom: control::f32_state(id, "zoom", None, default.zoom),
+        offset_x: control::f32_state(id, "offset-x", None, default.offset_x),
+        offset_y: control::f32_state(id, "offset-y", None, default.offset_y),
+    }
+}
+
+pub fn store_view(id: &str, view: ZoomView) {
+    control::set_f32_state(id, "zoom", view.zoom);
+    control::set_f32_state(id, "offset-x", view.offset_x);
+    control::set_f32_state(id, "offset-y", view.offset_y);
+}
+
+pub fn store_viewport(id: &str, origin: (f32, f32), size: (f32, f32)) {
+    control::set_f32_state(id, "viewport-x", origin.0);
+    control::set_f32_state(id, "viewport-y", origin.1);
+    control::set_f32_state(id, "viewport-w", size.0);
+    control::set_f32_state(id, "viewport-h", size.1);
+}
+
+pub fn viewport_size(id: &str) -> (f32, f32) {
+    (
+        control::f32_state(id, "viewport-w", None, 0.0),
+        control::f32_state(id, "viewport-h", None, 0.0),
+    )
+}
+
+pub fn viewport_local(id: &str, x: f32, y: f32) -> (f32, f32) {
+    (
+        x - control::f32_state(id, "viewport-x", None, 0.0),
+        y - control::f32_state(id, "viewport-y", None, 0.0),
+    )
+}
+
+pub fn store_drag_point(id: &str, x: f32, y: f32) {
+    control::set_f32_state(id, "drag-x", x);
+    control::set_f32_state(id, "drag-y", y);
+}
+
+pub fn drag_delta(id: &str, x: f32, y: f32) -> (f32, f32) {
+    let delta = (
+        x - control::f32_state(id, "drag-x", None, x),
+        y - control::f32_state(id, "drag-y", None, y),
+    );
+    store_drag_point(id, x, y);
+    delta
+}
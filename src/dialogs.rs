@@ -0,0 +1,182 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use gpui::SharedString;
+
+use crate::components::Modal;
+use crate::overlay::{ModalId, ModalManager, ModalResult, ModalResultFuture};
+
+#[cfg(feature = "forms")]
+pub use prompt_dialog::{PromptFuture, prompt, prompt_with};
+
+/// Resolves to `true` when the user confirms, `false` for every other way the
+/// dialog can close.
+#[must_use = "the dialog stays open; await the future to read the answer"]
+pub struct ConfirmFuture {
+    inner: ModalResultFuture,
+}
+
+impl ConfirmFuture {
+    pub fn id(&self) -> ModalId {
+        self.inner.id()
+    }
+}
+
+impl Future for ConfirmFuture {
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.inner)
+            .poll(cx)
+            .map(|result| result == ModalResult::Confirmed)
+    }
+}
+
+/// Opens a confirm dialog on the provider's modal manager; render a
+/// `ModalLayer` for `CalmProvider::modal(cx)` so it shows up.
+pub fn confirm(
+    title: impl Into<SharedString>,
+    body: impl Into<SharedString>,
+    cx: &gpui::App,
+) -> ConfirmFuture {
+    confirm_with(&crate::CalmProvider::modal(cx), title, body)
+}
+
+pub fn confirm_with(
+    manager: &ModalManager,
+    title: impl Into<SharedString>,
+    body: impl Into<SharedString>,
+) -> ConfirmFuture {
+    ConfirmFuture {
+        inner: manager.open_modal(Modal::confirm(title, body)),
+    }
+}
+
+#[cfg(feature = "forms")]
+mod prompt_dialog {
+    use std::cell::Cell;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll};
+
+    use gpui::SharedString;
+
+    use crate::components::{Modal, TextInput};
+    use crate::contracts::WithId;
+    use crate::id::ComponentId;
+    use crate::overlay::{ModalId, ModalKind, ModalManager, ModalResult, ModalResultFuture};
+
+    /// Resolves to the entered text when the user confirms, or `None` when the
+    /// prompt is canceled or dismissed.
+    #[must_use = "the dialog stays open; await the future to read the answer"]
+    pub struct PromptFuture {
+        inner: ModalResultFuture,
+        value: Arc<Mutex<String>>,
+    }
+
+    impl PromptFuture {
+        pub fn id(&self) -> ModalId {
+            self.inner.id()
+        }
+    }
+
+    impl Future for PromptFuture {
+        type Output = Option<String>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let value = self.value.clone();
+            Pin::new(&mut self.inner).poll(cx).map(|result| {
+                (result == ModalResult::Confirmed)
+                    .then(|| value.lock().expect("prompt value poisoned").clone())
+            })
+        }
+    }
+
+    /// Opens a single-field text prompt on the provider's modal manager.
+    pub fn prompt(
+        title: impl Into<SharedString>,
+        placeholder: impl Into<SharedString>,
+        cx: &gpui::App,
+    ) -> PromptFuture {
+        prompt_with(&crate::CalmProvider::modal(cx), title, placeholder)
+    }
+
+    pub fn prompt_with(
+        manager: &ModalManager,
+        title: impl Into<SharedString>,
+        placeholder: impl Into<SharedString>,
+    ) -> PromptFuture {
+        let value = Arc::new(Mutex::new(String::new()));
+        let opened = Rc::new(Cell::new(None::<ModalId>));
+        let input_id = ComponentId::unique("calmui-prompt");
+        let placeholder = placeholder.into();
+
+        let modal = Modal::titled(title).with_kind(ModalKind::Confirm).custom({
+            let value = value.clone();
+            let opened = opened.clone();
+            let manager = manager.clone();
+            move || {
+                let current = value.lock().expect("prompt value poisoned").clone();
+                let value = value.clone();
+                let opened = opened.clone();
+                let manager = manager.clone();
+                TextInput::new()
+                    .with_id(input_id.clone())
+                    .placeholder(placeholder.clone())
+                    .value(current)
+                    .on_change(move |next, window, _| {
+                        *value.lock().expect("prompt value poisoned") = next.to_string();
+                        window.refresh();
+                    })
+                    .on_submit(move |_, window, _| {
+                        if let Some(id) = opened.get() {
+                            manager.confirm(id);
+                            window.refresh();
+                        }
+                    })
+            }
+        });
+        let inner = manager.open_modal(modal);
+        opened.set(Some(inner.id()));
+        PromptFuture { inner, value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn confirm_resolves_true_only_for_the_confirm_action() {
+        let manager = ModalManager::new();
+        let accepted = confirm_with(&manager, "Delete", "Delete this file?");
+        assert!(manager.confirm(accepted.id()));
+        assert!(block_on(accepted));
+
+        let declined = confirm_with(&manager, "Delete", "Delete this file?");
+        assert!(manager.cancel(declined.id()));
+        assert!(!block_on(declined));
+
+        let dismissed = confirm_with(&manager, "Delete", "Delete this file?");
+        manager.dismiss_top();
+        assert!(!block_on(dismissed));
+    }
+
+    #[cfg(feature = "forms")]
+    #[test]
+    fn prompt_resolves_text_on_confirm_and_none_otherwise() {
+        let manager = ModalManager::new();
+        let named = prompt_with(&manager, "Rename", "New name");
+        assert!(manager.is_top(named.id()));
+        assert!(manager.confirm(named.id()));
+        assert_eq!(block_on(named), Some(String::new()));
+
+        let canceled = prompt_with(&manager, "Rename", "New name");
+        manager.close_all();
+        assert_eq!(block_on(canceled), None);
+    }
+}
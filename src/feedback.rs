@@ -1,6 +1,7 @@
 use std::collections::{BTreeMap, VecDeque};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 use crate::icon::IconSource;
 use crate::motion::MotionConfig;
@@ -28,6 +29,78 @@ pub enum ToastPosition {
     BottomRight,
 }
 
+impl ToastPosition {
+    pub const ALL: [Self; 6] = [
+        Self::TopLeft,
+        Self::TopCenter,
+        Self::TopRight,
+        Self::BottomLeft,
+        Self::BottomCenter,
+        Self::BottomRight,
+    ];
+
+    pub fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopCenter | Self::TopRight)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ToastOverflow {
+    #[default]
+    Queue,
+    DropOldest,
+}
+
+type ToastActionHandler = Arc<dyn Fn(ToastId, &mut gpui::Window, &mut gpui::App) + Send + Sync>;
+
+#[derive(Clone)]
+pub struct ToastAction {
+    pub label: SharedString,
+    pub dismiss: bool,
+    handler: ToastActionHandler,
+}
+
+impl ToastAction {
+    pub fn new(
+        label: impl Into<SharedString>,
+        handler: impl Fn(ToastId, &mut gpui::Window, &mut gpui::App) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            dismiss: true,
+            handler: Arc::new(handler),
+        }
+    }
+
+    pub fn dismiss(mut self, value: bool) -> Self {
+        self.dismiss = value;
+        self
+    }
+
+    pub fn invoke(&self, id: ToastId, window: &mut gpui::Window, cx: &mut gpui::App) {
+        (self.handler)(id, window, cx);
+    }
+}
+
+impl std::fmt::Debug for ToastAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastAction")
+            .field("label", &self.label)
+            .field("dismiss", &self.dismiss)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ToastAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+            && self.dismiss == other.dismiss
+            && Arc::ptr_eq(&self.handler, &other.handler)
+    }
+}
+
+impl Eq for ToastAction {}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ToastEntry {
     pub id: Option<ToastId>,
@@ -38,6 +111,10 @@ pub struct ToastEntry {
     pub position: ToastPosition,
     pub auto_close_ms: Option<u32>,
     pub closable: bool,
+    pub pause_on_hover: bool,
+    pub show_countdown: bool,
+    pub actions: Vec<ToastAction>,
+    pub archive: bool,
     pub motion: MotionConfig,
 }
 
@@ -52,6 +129,10 @@ impl ToastEntry {
             position: ToastPosition::TopRight,
             auto_close_ms: Some(3_000),
             closable: true,
+            pause_on_hover: true,
+            show_countdown: false,
+            actions: Vec::new(),
+            archive: true,
             motion: MotionConfig::default(),
         }
     }
@@ -96,6 +177,31 @@ impl ToastEntry {
         self
     }
 
+    pub fn pause_on_hover(mut self, value: bool) -> Self {
+        self.pause_on_hover = value;
+        self
+    }
+
+    pub fn show_countdown(mut self, value: bool) -> Self {
+        self.show_countdown = value;
+        self
+    }
+
+    pub fn action(mut self, action: ToastAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    pub fn actions(mut self, actions: impl IntoIterator<Item = ToastAction>) -> Self {
+        self.actions.extend(actions);
+        self
+    }
+
+    pub fn archive(mut self, value: bool) -> Self {
+        self.archive = value;
+        self
+    }
+
     pub fn motion(mut self, value: MotionConfig) -> Self {
         self.motion = value;
         self
@@ -106,6 +212,7 @@ impl ToastEntry {
 pub struct ToastViewport {
     pub position: ToastPosition,
     pub max_visible: usize,
+    pub overflow: ToastOverflow,
 }
 
 impl ToastViewport {
@@ -113,6 +220,7 @@ impl ToastViewport {
         Self {
             position,
             max_visible: 5,
+            overflow: ToastOverflow::Queue,
         }
     }
 
@@ -120,20 +228,89 @@ impl ToastViewport {
         self.max_visible = value.max(1);
         self
     }
+
+    pub fn overflow(mut self, value: ToastOverflow) -> Self {
+        self.overflow = value;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct ToastTimer {
+    elapsed_ms: u32,
+    running_since: Option<Instant>,
+    paused: bool,
+}
+
+impl ToastTimer {
+    fn elapsed_ms(&self) -> u32 {
+        let running = self
+            .running_since
+            .map(|since| since.elapsed().as_millis().min(u128::from(u32::MAX)) as u32)
+            .unwrap_or_default();
+        self.elapsed_ms.saturating_add(running)
+    }
 }
 
 #[derive(Default)]
 struct ToastState {
     queues: BTreeMap<ToastPosition, VecDeque<ToastEntry>>,
-    max_visible: BTreeMap<ToastPosition, usize>,
+    viewports: BTreeMap<ToastPosition, ToastViewport>,
     versions: BTreeMap<ToastId, u64>,
     scheduled_versions: BTreeMap<ToastId, u64>,
+    timers: BTreeMap<ToastId, ToastTimer>,
+    archived: BTreeMap<ToastId, NotificationId>,
+}
+
+impl ToastState {
+    fn viewport(&self, position: ToastPosition) -> ToastViewport {
+        self.viewports
+            .get(&position)
+            .cloned()
+            .unwrap_or_else(|| ToastViewport::new(position))
+    }
+
+    fn entry(&self, id: ToastId) -> Option<&ToastEntry> {
+        self.queues
+            .values()
+            .flat_map(|queue| queue.iter())
+            .find(|entry| entry.id == Some(id))
+    }
+
+    fn bump_version(&mut self, id: ToastId) {
+        let next_version = self.versions.get(&id).copied().unwrap_or_default() + 1;
+        self.versions.insert(id, next_version);
+        self.scheduled_versions.remove(&id);
+    }
+
+    fn forget(&mut self, id: ToastId) {
+        self.versions.remove(&id);
+        self.scheduled_versions.remove(&id);
+        self.timers.remove(&id);
+        self.archived.remove(&id);
+    }
+
+    fn remove(&mut self, id: ToastId) -> bool {
+        let mut removed = false;
+        for queue in self.queues.values_mut() {
+            if let Some(index) = queue.iter().position(|entry| entry.id == Some(id)) {
+                queue.remove(index);
+                removed = true;
+                break;
+            }
+        }
+        if removed {
+            self.forget(id);
+        }
+        removed
+    }
 }
 
 #[derive(Clone, Default)]
 pub struct ToastManager {
     next_id: Arc<AtomicU64>,
     state: Arc<RwLock<ToastState>>,
+    inbox: NotificationInbox,
 }
 
 impl ToastManager {
@@ -141,27 +318,46 @@ impl ToastManager {
         Self::default()
     }
 
+    pub fn with_inbox(inbox: NotificationInbox) -> Self {
+        Self {
+            inbox,
+            ..Self::default()
+        }
+    }
+
+    pub fn inbox(&self) -> NotificationInbox {
+        self.inbox.clone()
+    }
+
     pub fn configure_viewport(&self, viewport: ToastViewport) {
         self.state
             .write()
             .expect("toast state poisoned")
-            .max_visible
-            .insert(viewport.position, viewport.max_visible);
+            .viewports
+            .insert(viewport.position, viewport);
     }
 
     pub fn show(&self, mut entry: ToastEntry) -> ToastId {
         let id = ToastId(self.next_id.fetch_add(1, Ordering::SeqCst) + 1);
         entry.id = Some(id);
+        let archived = entry
+            .archive
+            .then(|| self.inbox.push(NotificationEntry::from_toast(&entry)));
 
         let mut state = self.state.write().expect("toast state poisoned");
         state.versions.insert(id, 1);
+        if let Some(notification_id) = archived {
+            state.archived.insert(id, notification_id);
+        }
         state.scheduled_versions.remove(&id);
-        let limit = *state.max_visible.get(&entry.position).unwrap_or(&5);
+        let viewport = state.viewport(entry.position);
         let mut removed_ids = Vec::new();
         {
             let queue = state.queues.entry(entry.position).or_default();
             queue.push_back(entry);
-            while queue.len() > limit {
+            while viewport.overflow == ToastOverflow::DropOldest
+                && queue.len() > viewport.max_visible
+            {
                 if let Some(removed) = queue.pop_front()
                     && let Some(removed_id) = removed.id
                 {
@@ -170,8 +366,7 @@ impl ToastManager {
             }
         }
         for removed_id in removed_ids {
-            state.versions.remove(&removed_id);
-            state.scheduled_versions.remove(&removed_id);
+            state.forget(removed_id);
         }
         id
     }
@@ -188,28 +383,56 @@ impl ToastManager {
             }
         }
         if updated {
-            let next_version = state.versions.get(&id).copied().unwrap_or_default() + 1;
-            state.versions.insert(id, next_version);
-            state.scheduled_versions.remove(&id);
+            state.bump_version(id);
+            state.timers.remove(&id);
+            if let Some(notification_id) = state.archived.get(&id).copied()
+                && let Some(entry) = state.entry(id)
+            {
+                self.inbox
+                    .update(notification_id, NotificationEntry::from_toast(entry));
+            }
         }
         updated
     }
 
+    pub fn contains(&self, id: ToastId) -> bool {
+        self.state
+            .read()
+            .expect("toast state poisoned")
+            .entry(id)
+            .is_some()
+    }
+
     pub fn dismiss(&self, id: ToastId) -> bool {
+        self.state.write().expect("toast state poisoned").remove(id)
+    }
+
+    pub fn dismiss_region(&self, position: ToastPosition) -> usize {
         let mut state = self.state.write().expect("toast state poisoned");
-        let mut dismissed = false;
-        for queue in state.queues.values_mut() {
-            if let Some(index) = queue.iter().position(|entry| entry.id == Some(id)) {
-                queue.remove(index);
-                dismissed = true;
-                break;
-            }
-        }
-        if dismissed {
-            state.versions.remove(&id);
-            state.scheduled_versions.remove(&id);
+        let removed: Vec<_> = state
+            .queues
+            .get_mut(&position)
+            .map(|queue| queue.drain(..).filter_map(|entry| entry.id).collect())
+            .unwrap_or_default();
+        for id in &removed {
+            state.forget(*id);
         }
-        dismissed
+        removed.len()
+    }
+
+    pub fn regions(&self) -> Vec<ToastPosition> {
+        self.state
+            .read()
+            .expect("toast state poisoned")
+            .queues
+            .iter()
+            .filter(|(_, queue)| !queue.is_empty())
+            .map(|(position, _)| *position)
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions().is_empty()
     }
 
     pub fn dismiss_all(&self) {
@@ -219,18 +442,91 @@ impl ToastManager {
         }
         state.versions.clear();
         state.scheduled_versions.clear();
+        state.timers.clear();
+        state.archived.clear();
     }
 
     pub fn list(&self, position: ToastPosition) -> Vec<ToastEntry> {
-        self.state
-            .read()
-            .expect("toast state poisoned")
+        let state = self.state.read().expect("toast state poisoned");
+        let limit = state.viewport(position).max_visible;
+        state
             .queues
             .get(&position)
-            .cloned()
+            .map(|queue| queue.iter().take(limit).cloned().collect())
             .unwrap_or_default()
-            .into_iter()
-            .collect()
+    }
+
+    pub fn queued(&self, position: ToastPosition) -> Vec<ToastEntry> {
+        let state = self.state.read().expect("toast state poisoned");
+        let limit = state.viewport(position).max_visible;
+        state
+            .queues
+            .get(&position)
+            .map(|queue| queue.iter().skip(limit).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn pause(&self, id: ToastId) -> bool {
+        let mut state = self.state.write().expect("toast state poisoned");
+        let Some(timer) = state.timers.get_mut(&id) else {
+            return false;
+        };
+        if timer.paused {
+            return false;
+        }
+        timer.elapsed_ms = timer.elapsed_ms();
+        timer.running_since = None;
+        timer.paused = true;
+        state.bump_version(id);
+        true
+    }
+
+    pub fn resume(&self, id: ToastId) -> bool {
+        let mut state = self.state.write().expect("toast state poisoned");
+        let Some(timer) = state.timers.get_mut(&id) else {
+            return false;
+        };
+        if !timer.paused {
+            return false;
+        }
+        timer.paused = false;
+        state.bump_version(id);
+        true
+    }
+
+    pub fn is_paused(&self, id: ToastId) -> bool {
+        self.state
+            .read()
+            .expect("toast state poisoned")
+            .timers
+            .get(&id)
+            .is_some_and(|timer| timer.paused)
+    }
+
+    pub fn remaining_ms(&self, id: ToastId) -> Option<u32> {
+        let state = self.state.read().expect("toast state poisoned");
+        let total = state.entry(id)?.auto_close_ms?;
+        let elapsed = state
+            .timers
+            .get(&id)
+            .map(ToastTimer::elapsed_ms)
+            .unwrap_or_default();
+        Some(total.saturating_sub(elapsed))
+    }
+
+    pub fn countdown(&self, id: ToastId) -> Option<f32> {
+        let total = self
+            .state
+            .read()
+            .expect("toast state poisoned")
+            .entry(id)?
+            .auto_close_ms?;
+        let remaining = self.remaining_ms(id)?;
+        Some(if total == 0 {
+            0.0
+        } else {
+            remaining as f32 / total as f32
+        })
     }
 
     pub fn version_of(&self, id: ToastId) -> Option<u64> {
@@ -252,6 +548,12 @@ impl ToastManager {
             return false;
         }
 
+        let timer = state.timers.entry(id).or_default();
+        if timer.paused {
+            return false;
+        }
+        timer.running_since.get_or_insert_with(Instant::now);
+
         match state.scheduled_versions.get(&id).copied() {
             Some(scheduled_version) if scheduled_version == version => false,
             _ => {
@@ -270,20 +572,512 @@ impl ToastManager {
         if current_version != version {
             return false;
         }
+        state.remove(id)
+    }
+}
 
-        let mut dismissed = false;
-        for queue in state.queues.values_mut() {
-            if let Some(index) = queue.iter().position(|entry| entry.id == Some(id)) {
-                queue.remove(index);
-                dismissed = true;
-                break;
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NotificationId(pub u64);
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NotificationFilter {
+    #[default]
+    All,
+    Unread,
+    Kind(ToastKind),
+}
+
+impl NotificationFilter {
+    pub fn matches(self, entry: &NotificationEntry) -> bool {
+        match self {
+            Self::All => true,
+            Self::Unread => !entry.read,
+            Self::Kind(kind) => entry.kind == kind,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationEntry {
+    pub id: Option<NotificationId>,
+    pub title: SharedString,
+    pub message: SharedString,
+    pub icon: Option<IconSource>,
+    pub kind: ToastKind,
+    pub read: bool,
+    pub received_at: Instant,
+}
+
+impl NotificationEntry {
+    pub fn new(title: impl Into<SharedString>, message: impl Into<SharedString>) -> Self {
+        Self {
+            id: None,
+            title: title.into(),
+            message: message.into(),
+            icon: None,
+            kind: ToastKind::Info,
+            read: false,
+            received_at: Instant::now(),
+        }
+    }
+
+    pub fn from_toast(entry: &ToastEntry) -> Self {
+        Self {
+            icon: entry.icon.clone(),
+            kind: entry.kind,
+            ..Self::new(entry.title.clone(), entry.message.clone())
+        }
+    }
+
+    pub fn kind(mut self, value: ToastKind) -> Self {
+        self.kind = value;
+        self
+    }
+
+    pub fn icon(mut self, value: impl Into<SharedString>) -> Self {
+        self.icon = Some(IconSource::named(value.into().to_string()));
+        self
+    }
+
+    pub fn icon_source(mut self, source: IconSource) -> Self {
+        self.icon = Some(source);
+        self
+    }
+
+    pub fn read(mut self, value: bool) -> Self {
+        self.read = value;
+        self
+    }
+}
+
+const DEFAULT_NOTIFICATION_CAPACITY: usize = 100;
+
+struct NotificationState {
+    entries: VecDeque<NotificationEntry>,
+    capacity: usize,
+}
+
+impl Default for NotificationState {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity: DEFAULT_NOTIFICATION_CAPACITY,
+        }
+    }
+}
+
+impl NotificationState {
+    fn trim(&mut self) {
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct NotificationInbox {
+    next_id: Arc<AtomicU64>,
+    state: Arc<RwLock<NotificationState>>,
+}
+
+impl NotificationInbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_capacity(&self, value: usize) {
+        let mut state = self.state.write().expect("notification state poisoned");
+        state.capacity = value.max(1);
+        state.trim();
+    }
+
+    pub fn push(&self, mut entry: NotificationEntry) -> NotificationId {
+        let id = NotificationId(self.next_id.fetch_add(1, Ordering::SeqCst) + 1);
+        entry.id = Some(id);
+        let mut state = self.state.write().expect("notification state poisoned");
+        state.entries.push_back(entry);
+        state.trim();
+        id
+    }
+
+    pub fn update(&self, id: NotificationId, mut entry: NotificationEntry) -> bool {
+        let mut state = self.state.write().expect("notification state poisoned");
+        let Some(current) = state
+            .entries
+            .iter_mut()
+            .find(|candidate| candidate.id == Some(id))
+        else {
+            return false;
+        };
+        entry.id = Some(id);
+        entry.received_at = current.received_at;
+        *current = entry;
+        true
+    }
+
+    pub fn get(&self, id: NotificationId) -> Option<NotificationEntry> {
+        self.state
+            .read()
+            .expect("notification state poisoned")
+            .entries
+            .iter()
+            .find(|entry| entry.id == Some(id))
+            .cloned()
+    }
+
+    pub fn list(&self, filter: NotificationFilter) -> Vec<NotificationEntry> {
+        self.state
+            .read()
+            .expect("notification state poisoned")
+            .entries
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .cloned()
+            .collect()
+    }
+
+    pub fn count(&self, filter: NotificationFilter) -> usize {
+        self.state
+            .read()
+            .expect("notification state poisoned")
+            .entries
+            .iter()
+            .filter(|entry| filter.matches(entry))
+            .count()
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.count(NotificationFilter::Unread)
+    }
+
+    pub fn set_read(&self, id: NotificationId, read: bool) -> bool {
+        let mut state = self.state.write().expect("notification state poisoned");
+        match state.entries.iter_mut().find(|entry| entry.id == Some(id)) {
+            Some(entry) if entry.read != read => {
+                entry.read = read;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn mark_read(&self, id: NotificationId) -> bool {
+        self.set_read(id, true)
+    }
+
+    pub fn mark_all_read(&self) -> usize {
+        let mut state = self.state.write().expect("notification state poisoned");
+        let mut marked = 0;
+        for entry in state.entries.iter_mut().filter(|entry| !entry.read) {
+            entry.read = true;
+            marked += 1;
+        }
+        marked
+    }
+
+    pub fn remove(&self, id: NotificationId) -> bool {
+        let mut state = self.state.write().expect("notification state poisoned");
+        let Some(index) = state.entries.iter().position(|entry| entry.id == Some(id)) else {
+            return false;
+        };
+        state.entries.remove(index);
+        true
+    }
+
+    pub fn clear(&self) {
+        self.state
+            .write()
+            .expect("notification state poisoned")
+            .entries
+            .clear();
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TaskId(pub u64);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskStatus {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl TaskStatus {
+    pub fn is_finished(self) -> bool {
+        self != Self::Running
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskEntry {
+    pub id: Option<TaskId>,
+    pub title: SharedString,
+    pub message: Option<SharedString>,
+    pub progress: Option<f32>,
+    pub status: TaskStatus,
+    pub cancellable: bool,
+}
+
+impl TaskEntry {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            id: None,
+            title: title.into(),
+            message: None,
+            progress: None,
+            status: TaskStatus::Running,
+            cancellable: true,
+        }
+    }
+
+    pub fn message(mut self, value: impl Into<SharedString>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    pub fn progress(mut self, value: f32) -> Self {
+        self.progress = clamp_progress(value);
+        self
+    }
+
+    pub fn cancellable(mut self, value: bool) -> Self {
+        self.cancellable = value;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TaskSummary {
+    pub running: usize,
+    pub finished: usize,
+    pub failed: usize,
+    pub progress: Option<f32>,
+}
+
+fn clamp_progress(value: f32) -> Option<f32> {
+    value.is_finite().then(|| value.clamp(0.0, 1.0))
+}
+
+type CancelHandler = Arc<dyn Fn() + Send + Sync>;
+
+#[derive(Default)]
+struct TaskState {
+    tasks: Vec<TaskEntry>,
+    cancel_flags: BTreeMap<TaskId, Arc<AtomicBool>>,
+    cancel_handlers: BTreeMap<TaskId, Vec<CancelHandler>>,
+    refresh_scheduled: bool,
+}
+
+#[derive(Clone)]
+pub struct TaskHandle {
+    id: TaskId,
+    cancelled: Arc<AtomicBool>,
+    manager: TaskManager,
+}
+
+impl TaskHandle {
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub fn set_progress(&self, value: f32) -> bool {
+        self.manager.set_progress(self.id, value)
+    }
+
+    pub fn set_message(&self, value: impl Into<SharedString>) -> bool {
+        self.manager.set_message(self.id, value)
+    }
+
+    pub fn complete(&self) -> bool {
+        self.manager.complete(self.id)
+    }
+
+    pub fn fail(&self, message: impl Into<SharedString>) -> bool {
+        self.manager.fail(self.id, message)
+    }
+
+    pub fn on_cancel(&self, handler: impl Fn() + Send + Sync + 'static) {
+        self.manager.on_cancel(self.id, handler);
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct TaskManager {
+    next_id: Arc<AtomicU64>,
+    state: Arc<RwLock<TaskState>>,
+}
+
+impl TaskManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(&self, mut entry: TaskEntry) -> TaskHandle {
+        let id = TaskId(self.next_id.fetch_add(1, Ordering::SeqCst) + 1);
+        entry.id = Some(id);
+        entry.status = TaskStatus::Running;
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let mut state = self.state.write().expect("task state poisoned");
+        state.tasks.push(entry);
+        state.cancel_flags.insert(id, cancelled.clone());
+        TaskHandle {
+            id,
+            cancelled,
+            manager: self.clone(),
+        }
+    }
+
+    fn update_running(&self, id: TaskId, apply: impl FnOnce(&mut TaskEntry)) -> bool {
+        let mut state = self.state.write().expect("task state poisoned");
+        let Some(entry) = state
+            .tasks
+            .iter_mut()
+            .find(|entry| entry.id == Some(id) && entry.status == TaskStatus::Running)
+        else {
+            return false;
+        };
+        apply(entry);
+        true
+    }
+
+    pub fn set_progress(&self, id: TaskId, value: f32) -> bool {
+        self.update_running(id, |entry| entry.progress = clamp_progress(value))
+    }
+
+    pub fn set_message(&self, id: TaskId, value: impl Into<SharedString>) -> bool {
+        let value = value.into();
+        self.update_running(id, |entry| entry.message = Some(value))
+    }
+
+    pub fn complete(&self, id: TaskId) -> bool {
+        self.update_running(id, |entry| {
+            entry.status = TaskStatus::Completed;
+            entry.progress = Some(1.0);
+        })
+    }
+
+    pub fn fail(&self, id: TaskId, message: impl Into<SharedString>) -> bool {
+        let message = message.into();
+        self.update_running(id, |entry| {
+            entry.status = TaskStatus::Failed;
+            entry.message = Some(message);
+        })
+    }
+
+    pub fn on_cancel(&self, id: TaskId, handler: impl Fn() + Send + Sync + 'static) {
+        self.state
+            .write()
+            .expect("task state poisoned")
+            .cancel_handlers
+            .entry(id)
+            .or_default()
+            .push(Arc::new(handler));
+    }
+
+    pub fn cancel(&self, id: TaskId) -> bool {
+        let handlers = {
+            let mut state = self.state.write().expect("task state poisoned");
+            let Some(entry) = state.tasks.iter_mut().find(|entry| {
+                entry.id == Some(id) && entry.status == TaskStatus::Running && entry.cancellable
+            }) else {
+                return false;
+            };
+            entry.status = TaskStatus::Cancelled;
+            if let Some(flag) = state.cancel_flags.get(&id) {
+                flag.store(true, Ordering::SeqCst);
+            }
+            state.cancel_handlers.remove(&id).unwrap_or_default()
+        };
+        for handler in handlers {
+            handler();
+        }
+        true
+    }
+
+    pub fn dismiss(&self, id: TaskId) -> bool {
+        let mut state = self.state.write().expect("task state poisoned");
+        let Some(index) = state.tasks.iter().position(|entry| entry.id == Some(id)) else {
+            return false;
+        };
+        state.tasks.remove(index);
+        state.cancel_flags.remove(&id);
+        state.cancel_handlers.remove(&id);
+        true
+    }
+
+    pub fn clear_finished(&self) {
+        let mut state = self.state.write().expect("task state poisoned");
+        let finished: Vec<TaskId> = state
+            .tasks
+            .iter()
+            .filter(|entry| entry.status.is_finished())
+            .filter_map(|entry| entry.id)
+            .collect();
+        state.tasks.retain(|entry| !entry.status.is_finished());
+        for id in finished {
+            state.cancel_flags.remove(&id);
+            state.cancel_handlers.remove(&id);
+        }
+    }
+
+    pub fn list(&self) -> Vec<TaskEntry> {
+        self.state
+            .read()
+            .expect("task state poisoned")
+            .tasks
+            .clone()
+    }
+
+    pub fn summary(&self) -> TaskSummary {
+        let state = self.state.read().expect("task state poisoned");
+        let mut summary = TaskSummary::default();
+        let mut measured = Vec::new();
+        for entry in &state.tasks {
+            match entry.status {
+                TaskStatus::Running => {
+                    summary.running += 1;
+                    measured.push(entry.progress);
+                }
+                TaskStatus::Failed => {
+                    summary.finished += 1;
+                    summary.failed += 1;
+                }
+                TaskStatus::Completed | TaskStatus::Cancelled => summary.finished += 1,
             }
         }
-        if dismissed {
-            state.versions.remove(&id);
-            state.scheduled_versions.remove(&id);
+        if !measured.is_empty() && measured.iter().all(Option::is_some) {
+            let total: f32 = measured.iter().flatten().sum();
+            summary.progress = Some(total / measured.len() as f32);
         }
-        dismissed
+        summary
+    }
+
+    pub fn mark_refresh_scheduled(&self) -> bool {
+        let mut state = self.state.write().expect("task state poisoned");
+        let running = state
+            .tasks
+            .iter()
+            .any(|entry| entry.status == TaskStatus::Running);
+        if !running || state.refresh_scheduled {
+            return false;
+        }
+        state.refresh_scheduled = true;
+        true
+    }
+
+    pub fn finish_refresh(&self) {
+        self.state
+            .write()
+            .expect("task state poisoned")
+            .refresh_scheduled = false;
     }
 }
 
@@ -306,7 +1100,11 @@ mod tests {
     #[test]
     fn toast_manager_enforces_position_limit() {
         let manager = ToastManager::new();
-        manager.configure_viewport(ToastViewport::new(ToastPosition::TopRight).max_visible(2));
+        manager.configure_viewport(
+            ToastViewport::new(ToastPosition::TopRight)
+                .max_visible(2)
+                .overflow(ToastOverflow::DropOldest),
+        );
         manager.show(ToastEntry::new("a", "1"));
         manager.show(ToastEntry::new("b", "2"));
         manager.show(ToastEntry::new("c", "3"));
@@ -317,6 +1115,88 @@ mod tests {
         assert_eq!(top_right[1].title.to_string(), "c");
     }
 
+    #[test]
+    fn toast_manager_queues_overflow_until_visible_toasts_dismiss() {
+        let manager = ToastManager::new();
+        manager.configure_viewport(ToastViewport::new(ToastPosition::TopRight).max_visible(2));
+        let first = manager.show(ToastEntry::new("a", "1"));
+        manager.show(ToastEntry::new("b", "2"));
+        let third = manager.show(ToastEntry::new("c", "3"));
+
+        assert_eq!(manager.list(ToastPosition::TopRight).len(), 2);
+        assert_eq!(manager.queued(ToastPosition::TopRight)[0].id, Some(third));
+        assert!(manager.contains(third));
+
+        assert!(manager.dismiss(first));
+        assert!(!manager.contains(first));
+        let visible = manager.list(ToastPosition::TopRight);
+        assert_eq!(visible[1].id, Some(third));
+        assert!(manager.queued(ToastPosition::TopRight).is_empty());
+    }
+
+    #[test]
+    fn toast_manager_keeps_independent_region_stacks() {
+        let manager = ToastManager::new();
+        assert!(manager.is_empty());
+        manager.configure_viewport(ToastViewport::new(ToastPosition::BottomCenter).max_visible(1));
+        let top = manager.show(ToastEntry::new("a", "1").position(ToastPosition::TopLeft));
+        manager.show(ToastEntry::new("b", "2").position(ToastPosition::BottomCenter));
+        manager.show(ToastEntry::new("c", "3").position(ToastPosition::BottomCenter));
+
+        assert_eq!(
+            manager.regions(),
+            vec![ToastPosition::TopLeft, ToastPosition::BottomCenter]
+        );
+        assert_eq!(manager.list(ToastPosition::TopLeft)[0].id, Some(top));
+        assert_eq!(manager.list(ToastPosition::BottomCenter).len(), 1);
+        assert_eq!(manager.queued(ToastPosition::BottomCenter).len(), 1);
+
+        assert_eq!(manager.dismiss_region(ToastPosition::BottomCenter), 2);
+        assert_eq!(manager.regions(), vec![ToastPosition::TopLeft]);
+        assert!(manager.contains(top));
+        assert!(ToastPosition::TopCenter.is_top() && !ToastPosition::BottomLeft.is_top());
+        assert_eq!(ToastPosition::ALL.len(), 6);
+    }
+
+    #[test]
+    fn toast_manager_pauses_and_resumes_countdown() {
+        let manager = ToastManager::new();
+        let id = manager.show(ToastEntry::new("a", "1").auto_close_after_ms(5_000));
+        assert_eq!(manager.remaining_ms(id), Some(5_000));
+        assert!(!manager.pause(id));
+
+        let version = manager.version_of(id).expect("version should exist");
+        assert!(manager.mark_auto_close_scheduled(id, version));
+        assert!(manager.pause(id));
+        assert!(manager.is_paused(id));
+        assert!(!manager.dismiss_if_version(id, version));
+        let paused_version = manager.version_of(id).expect("version should exist");
+        assert!(!manager.mark_auto_close_scheduled(id, paused_version));
+        let remaining = manager.remaining_ms(id).expect("remaining should exist");
+        assert!(remaining <= 5_000);
+        assert_eq!(manager.remaining_ms(id), Some(remaining));
+
+        assert!(manager.resume(id));
+        assert!(!manager.is_paused(id));
+        let resumed_version = manager.version_of(id).expect("version should exist");
+        assert!(manager.mark_auto_close_scheduled(id, resumed_version));
+        let countdown = manager.countdown(id).expect("countdown should exist");
+        assert!((0.0..=1.0).contains(&countdown));
+
+        let persistent = manager.show(ToastEntry::new("b", "2").persistent());
+        assert_eq!(manager.countdown(persistent), None);
+    }
+
+    #[test]
+    fn toast_actions_compare_by_handler_identity() {
+        let undo = ToastAction::new("Undo", |_, _, _| {});
+        let entry = ToastEntry::new("Deleted", "1 file").action(undo.clone());
+        assert_eq!(entry.actions, vec![undo.clone()]);
+        assert!(entry.actions[0].dismiss);
+        assert_ne!(undo, ToastAction::new("Undo", |_, _, _| {}));
+        assert!(!undo.dismiss(false).dismiss);
+    }
+
     #[test]
     fn toast_manager_tracks_versions_for_auto_close_scheduling() {
         let manager = ToastManager::new();
@@ -335,4 +1215,100 @@ mod tests {
         assert!(!manager.dismiss_if_version(id, initial_version));
         assert!(manager.dismiss_if_version(id, updated_version));
     }
+
+    #[test]
+    fn toasts_are_archived_into_the_inbox_and_follow_updates() {
+        let manager = ToastManager::new();
+        let inbox = manager.inbox();
+        let id = manager.show(ToastEntry::new("Upload", "Sending").kind(ToastKind::Loading));
+        manager.show(ToastEntry::new("Typing", "...").archive(false));
+        assert_eq!(inbox.unread_count(), 1);
+
+        assert!(manager.update(
+            id,
+            ToastEntry::new("Upload", "Done").kind(ToastKind::Success)
+        ));
+        assert!(manager.dismiss(id));
+        let archived = inbox.list(NotificationFilter::All);
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].message.to_string(), "Done");
+        assert_eq!(archived[0].kind, ToastKind::Success);
+
+        let shared = ToastManager::with_inbox(inbox.clone());
+        shared.show(ToastEntry::new("Other window", "Hi").kind(ToastKind::Error));
+        assert_eq!(inbox.unread_count(), 2);
+        assert_eq!(
+            inbox.list(NotificationFilter::Unread)[0].title.to_string(),
+            "Other window"
+        );
+    }
+
+    #[test]
+    fn notification_inbox_filters_marks_read_and_caps_history() {
+        let inbox = NotificationInbox::new();
+        let info = inbox.push(NotificationEntry::new("a", "1"));
+        let error = inbox.push(NotificationEntry::new("b", "2").kind(ToastKind::Error));
+        inbox.push(NotificationEntry::new("c", "3").read(true));
+
+        assert_eq!(inbox.unread_count(), 2);
+        assert_eq!(inbox.count(NotificationFilter::Kind(ToastKind::Error)), 1);
+        assert!(inbox.mark_read(error));
+        assert!(!inbox.mark_read(error));
+        assert_eq!(inbox.list(NotificationFilter::Unread)[0].id, Some(info));
+        assert_eq!(inbox.mark_all_read(), 1);
+        assert_eq!(inbox.unread_count(), 0);
+        assert!(inbox.set_read(info, false));
+
+        inbox.set_capacity(2);
+        assert!(inbox.get(info).is_none());
+        assert_eq!(inbox.count(NotificationFilter::All), 2);
+        assert!(inbox.remove(error));
+        inbox.clear();
+        assert!(inbox.list(NotificationFilter::All).is_empty());
+    }
+
+    #[test]
+    fn task_manager_tracks_progress_cancel_and_summary() {
+        let manager = TaskManager::new();
+        let download = manager.start(TaskEntry::new("Download").progress(0.5));
+        let index = manager.start(TaskEntry::new("Index").cancellable(false));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let cancelled_for_task = cancelled.clone();
+        download.on_cancel(move || cancelled_for_task.store(true, Ordering::SeqCst));
+
+        assert_eq!(manager.summary().running, 2);
+        assert_eq!(manager.summary().progress, None);
+        assert!(index.set_progress(0.25));
+        assert_eq!(manager.summary().progress, Some(0.375));
+        assert!(download.set_progress(2.0));
+        assert_eq!(manager.list()[0].progress, Some(1.0));
+
+        assert!(!manager.cancel(index.id()));
+        assert!(manager.cancel(download.id()));
+        assert!(download.is_cancelled());
+        assert!(cancelled.load(Ordering::SeqCst));
+        assert!(!download.set_progress(0.1));
+
+        assert!(index.fail("disk full"));
+        let summary = manager.summary();
+        assert_eq!(summary.running, 0);
+        assert_eq!(summary.finished, 2);
+        assert_eq!(summary.failed, 1);
+        assert!(!manager.mark_refresh_scheduled());
+
+        manager.clear_finished();
+        assert!(manager.list().is_empty());
+    }
+
+    #[test]
+    fn task_manager_schedules_a_single_refresh_while_running() {
+        let manager = TaskManager::new();
+        let task = manager.start(TaskEntry::new("Sync"));
+        assert!(manager.mark_refresh_scheduled());
+        assert!(!manager.mark_refresh_scheduled());
+        manager.finish_refresh();
+        assert!(task.complete());
+        assert_eq!(manager.list()[0].status, TaskStatus::Completed);
+        assert!(!manager.mark_refresh_scheduled());
+    }
 }
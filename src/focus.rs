@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use gpui::{FocusHandle, InteractiveElement, KeyDownEvent, SharedString, Window};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FocusOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+impl FocusOrientation {
+    fn step(self, key: &str) -> Option<bool> {
+        let horizontal = matches!(self, Self::Horizontal | Self::Both);
+        let vertical = matches!(self, Self::Vertical | Self::Both);
+        match key {
+            "left" if horizontal => Some(false),
+            "up" if vertical => Some(false),
+            "right" if horizontal => Some(true),
+            "down" if vertical => Some(true),
+            _ => None,
+        }
+    }
+}
+
+pub fn roving_index(
+    key: &str,
+    current: Option<usize>,
+    disabled: &[bool],
+    orientation: FocusOrientation,
+    wrap: bool,
+) -> Option<usize> {
+    let count = disabled.len();
+    let enabled = |index: &usize| !disabled[*index];
+    match key {
+        "home" => return (0..count).find(enabled),
+        "end" => return (0..count).rev().find(enabled),
+        _ => {}
+    }
+    let forward = orientation.step(key)?;
+    let Some(mut index) = current.filter(|current| *current < count) else {
+        return if forward {
+            (0..count).find(enabled)
+        } else {
+            (0..count).rev().find(enabled)
+        };
+    };
+    for _ in 1..count {
+        index = match (forward, index) {
+            (true, index) if index + 1 < count => index + 1,
+            (false, index) if index > 0 => index - 1,
+            _ if !wrap => return None,
+            (true, _) => 0,
+            (false, _) => count - 1,
+        };
+        if !disabled[index] {
+            return Some(index);
+        }
+    }
+    None
+}
+
+pub fn roving_target(
+    event: &KeyDownEvent,
+    current: Option<usize>,
+    disabled: &[bool],
+    orientation: FocusOrientation,
+) -> Option<usize> {
+    if event.keystroke.modifiers.modified() {
+        return None;
+    }
+    roving_index(
+        event.keystroke.key.as_str(),
+        current,
+        disabled,
+        orientation,
+        true,
+    )
+}
+
+pub fn tab_index(current: Option<usize>, count: usize, backwards: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    Some(match (current, backwards) {
+        (Some(index), false) => (index + 1) % count,
+        (Some(index), true) => (index + count - 1) % count,
+        (None, false) => 0,
+        (None, true) => count - 1,
+    })
+}
+
+struct FocusTrap {
+    id: SharedString,
+    root: FocusHandle,
+    order: Vec<FocusHandle>,
+    restore: Option<FocusHandle>,
+}
+
+#[derive(Default)]
+struct FocusState {
+    handles: HashMap<String, FocusHandle>,
+    traps: Vec<FocusTrap>,
+}
+
+#[derive(Clone, Default)]
+pub struct FocusCoordinator {
+    state: Arc<RwLock<FocusState>>,
+}
+
+impl FocusCoordinator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle(&self, scope: &str, slot: &str, cx: &gpui::App) -> FocusHandle {
+        let mut state = self.state.write().expect("focus state poisoned");
+        let handle = state
+            .handles
+            .entry(format!("{scope}::{slot}"))
+            .or_insert_with(|| cx.focus_handle())
+            .clone();
+        if let Some(trap) = state
+            .traps
+            .iter_mut()
+            .find(|trap| trap.id.as_ref() == scope)
+            && !trap.order.contains(&handle)
+        {
+            trap.order.push(handle.clone());
+        }
+        handle
+    }
+
+    pub fn trap(
+        &self,
+        id: impl Into<SharedString>,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) -> FocusHandle {
+        let id = id.into();
+        let mut state = self.state.write().expect("focus state poisoned");
+        if let Some(trap) = state.traps.iter_mut().find(|trap| trap.id == id) {
+            trap.order.clear();
+            return trap.root.clone();
+        }
+        let root = cx.focus_handle();
+        state.traps.push(FocusTrap {
+            id,
+            root: root.clone(),
+            order: Vec::new(),
+            restore: window.focused(cx),
+        });
+        drop(state);
+        let initial = root.clone();
+        window.defer(cx, move |window, cx| window.focus(&initial, cx));
+        root
+    }
+
+    pub fn release(&self, id: &str, window: &mut Window, cx: &mut gpui::App) -> bool {
+        let mut state = self.state.write().expect("focus state poisoned");
+        let Some(position) = state.traps.iter().position(|trap| trap.id.as_ref() == id) else {
+            return false;
+        };
+        let trap = state.traps.remove(position);
+        let prefix = format!("{id}::");
+        state.handles.retain(|key, _| !key.starts_with(&prefix));
+        drop(state);
+        if let Some(restore) = trap.restore {
+            window.defer(cx, move |window, cx| window.focus(&restore, cx));
+        }
+        window.refresh();
+        true
+    }
+
+    pub fn release_where(
+        &self,
+        predicate: impl Fn(&str) -> bool,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) -> usize {
+        let stale = self
+            .state
+            .read()
+            .expect("focus state poisoned")
+            .traps
+            .iter()
+            .filter(|trap| predicate(trap.id.as_ref()))
+            .map(|trap| trap.id.clone())
+            .collect::<Vec<_>>();
+        stale
+            .iter()
+            .filter(|id| self.release(id, window, cx))
+            .count()
+    }
+
+    pub fn active_trap(&self) -> Option<SharedString> {
+        self.state
+            .read()
+            .expect("focus state poisoned")
+            .traps
+            .last()
+            .map(|trap| trap.id.clone())
+    }
+
+    pub fn is_trapped(&self, id: &str) -> bool {
+        self.state
+            .read()
+            .expect("focus state poisoned")
+            .traps
+            .iter()
+            .any(|trap| trap.id.as_ref() == id)
+    }
+
+    pub fn focus_next(&self, backwards: bool, window: &mut Window, cx: &mut gpui::App) -> bool {
+        let state = self.state.read().expect("focus state poisoned");
+        let Some(trap) = state.traps.last() else {
+            return false;
+        };
+        let current = trap
+            .order
+            .iter()
+            .position(|handle| handle.is_focused(window));
+        let target = tab_index(current, trap.order.len(), backwards)
+            .map(|index| trap.order[index].clone())
+            .unwrap_or_else(|| trap.root.clone());
+        drop(state);
+        window.focus(&target, cx);
+        window.refresh();
+        true
+    }
+
+    pub fn handle_key_down(
+        &self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) -> bool {
+        let modifiers = &event.keystroke.modifiers;
+        if event.keystroke.key != "tab" || modifiers.control || modifiers.alt || modifiers.platform
+        {
+            return false;
+        }
+        self.focus_next(modifiers.shift, window, cx)
+    }
+
+    pub fn scope<E: InteractiveElement>(&self, root: &FocusHandle, element: E) -> E {
+        let coordinator = self.clone();
+        element
+            .track_focus(root)
+            .on_key_down(move |event, window, cx| {
+                if coordinator.handle_key_down(event, window, cx) {
+                    cx.stop_propagation();
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roving_skips_disabled_items_and_wraps() {
+        let disabled = [false, true, false, false];
+        let horizontal = FocusOrientation::Horizontal;
+        assert_eq!(
+            roving_index("right", Some(0), &disabled, horizontal, true),
+            Some(2)
+        );
+        assert_eq!(
+            roving_index("right", Some(3), &disabled, horizontal, true),
+            Some(0)
+        );
+        assert_eq!(
+            roving_index("right", Some(3), &disabled, horizontal, false),
+            None
+        );
+        assert_eq!(
+            roving_index("left", Some(2), &disabled, horizontal, true),
+            Some(0)
+        );
+        assert_eq!(
+            roving_index("down", Some(0), &disabled, horizontal, true),
+            None
+        );
+        assert_eq!(
+            roving_index("down", None, &disabled, FocusOrientation::Vertical, true),
+            Some(0)
+        );
+        assert_eq!(
+            roving_index("up", None, &disabled, FocusOrientation::Both, true),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn roving_home_end_land_on_enabled_edges() {
+        let disabled = [true, false, false, true];
+        let orientation = FocusOrientation::Vertical;
+        assert_eq!(
+            roving_index("home", Some(2), &disabled, orientation, false),
+            Some(1)
+        );
+        assert_eq!(
+            roving_index("end", Some(1), &disabled, orientation, false),
+            Some(2)
+        );
+        assert_eq!(
+            roving_index("down", Some(1), &[true, false, true], orientation, true),
+            None
+        );
+    }
+
+    #[test]
+    fn tab_order_cycles_in_both_directions() {
+        assert_eq!(tab_index(None, 0, false), None);
+        assert_eq!(tab_index(None, 3, false), Some(0));
+        assert_eq!(tab_index(None, 3, true), Some(2));
+        assert_eq!(tab_index(Some(2), 3, false), Some(0));
+        assert_eq!(tab_index(Some(0), 3, true), Some(2));
+        assert_eq!(tab_index(Some(1), 3, true), Some(0));
+    }
+}
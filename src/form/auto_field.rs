@@ -0,0 +1,218 @@
+use std::rc::Rc;
+
+use gpui::{AnyElement, IntoElement, SharedString};
+use rust_decimal::Decimal;
+
+use super::controller::{FieldKey, FormController, FormResult};
+use super::options::FieldOptions;
+use super::validation::{FieldLens, ValidationError};
+use crate::components::{Checkbox, NumberInput, RadioGroup, Select, SelectOption, TextInput};
+use crate::contracts::{FieldLike, WithId};
+use crate::id::ComponentId;
+use crate::style::FieldLayout;
+
+type FieldRenderer<T, E> =
+    Rc<dyn Fn(&FormController<T, E>, AutoFieldProps) -> FormResult<AnyElement>>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AutoFieldKind {
+    Text,
+    Number,
+    Checkbox,
+    Select,
+    Radio,
+}
+
+/// Presentation passed to an [`AutoField`] when it builds its input.
+#[derive(Clone, Debug)]
+pub struct AutoFieldProps {
+    pub id: ComponentId,
+    /// Empty when something else labels the input, as in `SettingsView`.
+    pub label: SharedString,
+    pub placeholder: Option<SharedString>,
+    pub description: Option<SharedString>,
+    pub layout: FieldLayout,
+}
+
+/// A model field bound to the input `AutoForm` renders for it.
+///
+/// `#[derive(FormModel)]` creates these for `SharedString`, `Decimal` and
+/// `bool` fields, and for `FieldOptions` fields marked `#[form(select)]` or
+/// `#[form(radio)]`; build them by hand for lenses over other paths.
+pub struct AutoField<T, E> {
+    key: FieldKey,
+    kind: AutoFieldKind,
+    render: FieldRenderer<T, E>,
+}
+
+impl<T, E> Clone for AutoField<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            kind: self.kind,
+            render: self.render.clone(),
+        }
+    }
+}
+
+impl<T, E> AutoField<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: ValidationError,
+{
+    pub fn text<L>(lens: L) -> Self
+    where
+        L: FieldLens<T, Value = SharedString>,
+    {
+        Self::new(lens.key(), AutoFieldKind::Text, move |controller, props| {
+            let mut input = presented(TextInput::new(), &props);
+            if let Some(placeholder) = props.placeholder {
+                input = input.placeholder(placeholder);
+            }
+            Ok(controller.bind_text_input(lens, input)?.into_any_element())
+        })
+    }
+
+    pub fn number<L>(lens: L) -> Self
+    where
+        L: FieldLens<T, Value = Decimal>,
+    {
+        Self::new(
+            lens.key(),
+            AutoFieldKind::Number,
+            move |controller, props| {
+                let mut input = presented(NumberInput::new(), &props);
+                if let Some(placeholder) = props.placeholder {
+                    input = input.placeholder(placeholder);
+                }
+                Ok(controller
+                    .bind_number_input(lens, input)?
+                    .into_any_element())
+            },
+        )
+    }
+
+    pub fn checkbox<L>(lens: L) -> Self
+    where
+        L: FieldLens<T, Value = bool>,
+    {
+        Self::new(
+            lens.key(),
+            AutoFieldKind::Checkbox,
+            move |controller, props| {
+                let checkbox = presented(Checkbox::new(), &props);
+                Ok(controller.bind_checkbox(lens, checkbox)?.into_any_element())
+            },
+        )
+    }
+
+    pub fn select<L>(lens: L, options: impl IntoIterator<Item = impl Into<SharedString>>) -> Self
+    where
+        L: FieldLens<T, Value = SharedString>,
+    {
+        let options = options
+            .into_iter()
+            .map(SelectOption::new)
+            .collect::<Vec<_>>();
+        Self::new(
+            lens.key(),
+            AutoFieldKind::Select,
+            move |controller, props| {
+                let mut select = presented(Select::new(), &props).options(options.clone());
+                if let Some(placeholder) = props.placeholder {
+                    select = select.placeholder(placeholder);
+                }
+                Ok(controller.bind_select(lens, select)?.into_any_element())
+            },
+        )
+    }
+
+    pub fn enum_select<L, V>(lens: L) -> Self
+    where
+        L: FieldLens<T, Value = V>,
+        V: FieldOptions,
+    {
+        Self::new(
+            lens.key(),
+            AutoFieldKind::Select,
+            move |controller, props| {
+                let mut select = presented(Select::new(), &props);
+                if let Some(placeholder) = props.placeholder {
+                    select = select.placeholder(placeholder);
+                }
+                Ok(controller
+                    .bind_enum_select(lens, select)?
+                    .into_any_element())
+            },
+        )
+    }
+
+    pub fn enum_radio<L, V>(lens: L) -> Self
+    where
+        L: FieldLens<T, Value = V>,
+        V: FieldOptions,
+    {
+        Self::new(
+            lens.key(),
+            AutoFieldKind::Radio,
+            move |controller, props| {
+                let radio = presented(RadioGroup::new(), &props);
+                Ok(controller.bind_enum_radio(lens, radio)?.into_any_element())
+            },
+        )
+    }
+
+    fn new(
+        key: FieldKey,
+        kind: AutoFieldKind,
+        render: impl Fn(&FormController<T, E>, AutoFieldProps) -> FormResult<AnyElement> + 'static,
+    ) -> Self {
+        Self {
+            key,
+            kind,
+            render: Rc::new(render),
+        }
+    }
+
+    pub fn key(&self) -> FieldKey {
+        self.key
+    }
+
+    pub fn kind(&self) -> AutoFieldKind {
+        self.kind
+    }
+
+    pub fn render(
+        &self,
+        controller: &FormController<T, E>,
+        props: AutoFieldProps,
+    ) -> FormResult<AnyElement> {
+        (self.render)(controller, props)
+    }
+}
+
+fn presented<C>(component: C, props: &AutoFieldProps) -> C
+where
+    C: FieldLike + WithId,
+{
+    let mut component = component.with_id(props.id.clone()).layout(props.layout);
+    if !props.label.is_empty() {
+        component = component.label(props.label.clone());
+    }
+    match props.description.clone() {
+        Some(description) => component.description(description),
+        None => component,
+    }
+}
+
+/// Label used when a field declares none: the last key segment with
+/// underscores turned into spaces and the first letter capitalized.
+pub(crate) fn humanized_label(key: FieldKey) -> SharedString {
+    let name = key.as_str().rsplit('.').next().unwrap_or_default();
+    let words = name.split('_').filter(|word| !word.is_empty());
+    let mut label = words.collect::<Vec<_>>().join(" ");
+    if let Some(first) = label.get(..1) {
+        label = first.to_uppercase() + &label[1..];
+    }
+    label.into()
+}
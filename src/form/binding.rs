@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -6,10 +7,13 @@ use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
 
 use super::controller::{FieldKey, FormController, FormResult, read_lock};
-use super::validation::{FieldLens, ValidationError};
+use super::options::{FieldOptions, radio_options, select_options};
+use super::temporal::TemporalValue;
+use super::validation::{FieldDescriptor, FieldLens, FormModel, ValidationError};
 use crate::components::{
-    Checkbox, MultiSelect, NumberInput, PasswordInput, RadioGroup, RangeSlider, Rating, Select,
-    Slider, Switch, TextInput, Textarea,
+    CalendarDate, CalendarDateTime, Checkbox, ClockTime, DatePicker, DateTimePicker, FileInput,
+    MultiSelect, NumberInput, PasswordInput, RadioGroup, RangeSlider, Rating, Select, Slider,
+    Switch, TagsInput, TextInput, Textarea, TimePicker,
 };
 use crate::contracts::FieldLike;
 
@@ -183,6 +187,49 @@ where
         self.apply_fieldlike_presentation(key, bound)
     }
 
+    /// Binds a `FieldOptions` value, filling the select with its variants.
+    pub fn bind_enum_select<L, V>(&self, lens: L, select: Select) -> FormResult<Select>
+    where
+        L: FieldLens<T, Value = V>,
+        V: FieldOptions,
+    {
+        let key = lens.key();
+        let snapshot = self.snapshot()?;
+        let value = lens.get(&snapshot.model).value();
+        let controller = self.clone();
+        let bound = select
+            .options(select_options::<V>())
+            .value(value)
+            .on_change(move |next, _, _| {
+                if let Some(value) = V::from_value(&next) {
+                    drop(controller.set(lens, value));
+                }
+            });
+        self.apply_fieldlike_presentation(key, bound)
+    }
+
+    /// Binds a `FieldOptions` value, with one radio per variant.
+    pub fn bind_enum_radio<L, V>(&self, lens: L, radio: RadioGroup) -> FormResult<RadioGroup>
+    where
+        L: FieldLens<T, Value = V>,
+        V: FieldOptions,
+    {
+        let key = lens.key();
+        let snapshot = self.snapshot()?;
+        let value = lens.get(&snapshot.model).value();
+        let controller = self.clone();
+        let bound =
+            radio
+                .options(radio_options::<V>())
+                .value(value)
+                .on_change(move |next, _, _| {
+                    if let Some(value) = V::from_value(&next) {
+                        drop(controller.set(lens, value));
+                    }
+                });
+        self.apply_fieldlike_presentation(key, bound)
+    }
+
     pub fn bind_multiselect<L>(&self, lens: L, multiselect: MultiSelect) -> FormResult<MultiSelect>
     where
         L: FieldLens<T, Value = Vec<SharedString>>,
@@ -197,6 +244,98 @@ where
         self.apply_fieldlike_presentation(key, bound)
     }
 
+    pub fn bind_tags_input<L>(&self, lens: L, input: TagsInput) -> FormResult<TagsInput>
+    where
+        L: FieldLens<T, Value = Vec<SharedString>>,
+    {
+        let key = lens.key();
+        let snapshot = self.snapshot()?;
+        let values = lens.get(&snapshot.model).clone();
+        let controller = self.clone();
+        let bound = input
+            .values(values)
+            .on_change(move |next, _, _| drop(controller.set(lens, next)));
+        self.apply_fieldlike_presentation(key, bound)
+    }
+
+    pub fn bind_file_input<L>(&self, lens: L, input: FileInput) -> FormResult<FileInput>
+    where
+        L: FieldLens<T, Value = Vec<PathBuf>>,
+    {
+        let key = lens.key();
+        let snapshot = self.snapshot()?;
+        let files = lens.get(&snapshot.model).clone();
+        let controller = self.clone();
+        let bound = input
+            .value(files)
+            .on_change(move |next, _, _| drop(controller.set(lens, next)));
+        self.apply_fieldlike_presentation(key, bound)
+    }
+
+    pub fn bind_date_picker<L, V>(&self, lens: L, picker: DatePicker) -> FormResult<DatePicker>
+    where
+        L: FieldLens<T, Value = Option<V>>,
+        V: TemporalValue<CalendarDate>,
+    {
+        let key = lens.key();
+        let snapshot = self.snapshot()?;
+        let picker = match lens.get(&snapshot.model).as_ref().and_then(V::to_picker) {
+            Some(value) => picker.value(value),
+            None => picker.clear_value(),
+        };
+        let controller = self.clone();
+        let bound = picker.on_change(move |next, _, _| {
+            if let Some(value) = V::from_picker(next) {
+                drop(controller.set(lens, Some(value)));
+            }
+        });
+        self.apply_fieldlike_presentation(key, bound)
+    }
+
+    pub fn bind_time_picker<L, V>(&self, lens: L, picker: TimePicker) -> FormResult<TimePicker>
+    where
+        L: FieldLens<T, Value = Option<V>>,
+        V: TemporalValue<ClockTime>,
+    {
+        let key = lens.key();
+        let snapshot = self.snapshot()?;
+        let picker = match lens.get(&snapshot.model).as_ref().and_then(V::to_picker) {
+            Some(value) => picker.value(value),
+            None => picker.clear_value(),
+        };
+        let controller = self.clone();
+        let bound = picker.on_change(move |next, _, _| {
+            if let Some(value) = V::from_picker(next) {
+                drop(controller.set(lens, Some(value)));
+            }
+        });
+        self.apply_fieldlike_presentation(key, bound)
+    }
+
+    pub fn bind_date_time_picker<L, V>(
+        &self,
+        lens: L,
+        picker: DateTimePicker,
+    ) -> FormResult<DateTimePicker>
+    where
+        L: FieldLens<T, Value = Option<V>>,
+        V: TemporalValue<CalendarDateTime>,
+    {
+        let key = lens.key();
+        let snapshot = self.snapshot()?;
+        let picker = match lens.get(&snapshot.model).as_ref().and_then(V::to_picker) {
+            Some(value) => picker.value(value),
+            None => picker.clear_value(),
+        };
+        let controller = self.clone();
+        let bound = picker.on_change(move |next, _, _| {
+            if let Some(value) = V::from_picker(next) {
+                drop(controller.set(lens, Some(value)));
+            }
+        });
+        self.apply_fieldlike_presentation(key, bound)
+    }
+
     pub fn bind_text_input_submit<L, F>(
         &self,
         lens: L,
@@ -229,6 +368,36 @@ where
         Ok(bound.on_submit(move |_value, window, cx| submit_handler(&controller, window, cx)))
     }
 
+    /// Static label, placeholder and description declared on the model field.
+    pub fn field_descriptor<L>(&self, lens: L) -> Option<FieldDescriptor>
+    where
+        L: FieldLens<T>,
+        T: FormModel,
+    {
+        T::field_meta(lens.key())
+    }
+
+    /// Applies the label and description declared on the model field.
+    /// Placeholders differ per component, so read them from
+    /// [`field_descriptor`](Self::field_descriptor).
+    pub fn apply_field_descriptor<L, C>(&self, lens: L, mut component: C) -> C
+    where
+        L: FieldLens<T>,
+        T: FormModel,
+        C: FieldLike,
+    {
+        let Some(meta) = self.field_descriptor(lens) else {
+            return component;
+        };
+        if let Some(label) = meta.label {
+            component = component.label(label);
+        }
+        if let Some(description) = meta.description {
+            component = component.description(description);
+        }
+        component
+    }
+
     fn apply_fieldlike_presentation<C>(&self, key: FieldKey, mut component: C) -> FormResult<C>
     where
         C: FieldLike,
@@ -1,13 +1,14 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::{Display, Formatter};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::{Arc, Mutex, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::time::Duration;
 
 use gpui::{SharedString, Window};
 
+use super::rules::FieldRulesMap;
 use super::validation::ValidationError;
 
 static FORM_ID_ALLOCATOR: AtomicU64 = AtomicU64::new(1);
@@ -32,6 +33,21 @@ impl FieldKey {
     pub const fn as_str(self) -> &'static str {
         self.0
     }
+
+    pub fn join(self, child: FieldKey) -> Self {
+        static PATHS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+        let path = format!("{}.{}", self.0, child.0);
+        let mut paths = PATHS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(existing) = paths.get(path.as_str()) {
+            return Self(existing);
+        }
+        let interned: &'static str = Box::leak(path.into_boxed_str());
+        paths.insert(interned);
+        Self(interned)
+    }
 }
 
 impl Display for FieldKey {
@@ -52,6 +68,16 @@ pub enum SubmitState {
     Failed,
 }
 
+impl SubmitState {
+    pub const fn is_pending(self) -> bool {
+        matches!(self, Self::Validating | Self::Submitting)
+    }
+
+    pub const fn is_settled(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ValidationMode {
     OnChange,
@@ -122,6 +148,7 @@ pub enum FormError {
     DraftLoadFailed(String),
     DraftSaveFailed(String),
     DraftClearFailed(String),
+    InvalidRulePattern(String),
 }
 
 impl Display for FormError {
@@ -137,6 +164,7 @@ impl Display for FormError {
             FormError::DraftLoadFailed(error) => write!(f, "failed to load draft: {error}"),
             FormError::DraftSaveFailed(error) => write!(f, "failed to save draft: {error}"),
             FormError::DraftClearFailed(error) => write!(f, "failed to clear draft: {error}"),
+            FormError::InvalidRulePattern(error) => write!(f, "invalid rule pattern: {error}"),
         }
     }
 }
@@ -150,6 +178,7 @@ pub(super) type SyncFormValidatorFn<T, E> = Arc<dyn Fn(&T) -> Vec<(FieldKey, E)>
 pub(super) type AsyncFieldValidatorFn<T, E> =
     Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = Result<(), E>> + Send + 'static>> + Send + Sync>;
 pub(super) type FocusHandler = Arc<dyn Fn(&mut Window, &mut gpui::App) + Send + Sync>;
+pub(super) type ChangeListenerFn<T> = Arc<dyn Fn(FieldKey, &T) + Send + Sync>;
 type SyncFieldValidatorMap<T, E> = BTreeMap<FieldKey, Vec<SyncFieldValidatorFn<T, E>>>;
 type AsyncFieldValidatorMap<T, E> = BTreeMap<FieldKey, Vec<AsyncFieldValidatorEntry<T, E>>>;
 
@@ -159,7 +188,7 @@ pub(super) struct AsyncFieldValidatorEntry<T, E> {
     pub(super) validator: AsyncFieldValidatorFn<T, E>,
 }
 
-pub(super) struct FormState<T, E> {
+pub(super) struct ControllerState<T, E> {
     pub(super) id: FormId,
     pub(super) initial_model: T,
     pub(super) model: T,
@@ -171,7 +200,7 @@ pub(super) struct FormState<T, E> {
     pub(super) first_error: Option<FieldKey>,
 }
 
-impl<T, E> FormState<T, E> {
+impl<T, E> ControllerState<T, E> {
     pub(super) fn ensure_meta(&mut self, key: FieldKey) -> &mut FieldMeta<E> {
         self.field_meta.entry(key).or_default()
     }
@@ -184,14 +213,16 @@ where
     E: ValidationError,
 {
     pub(super) options: FormOptions,
-    pub(super) state: Arc<RwLock<FormState<T, E>>>,
+    pub(super) state: Arc<RwLock<ControllerState<T, E>>>,
     pub(super) sync_field_validators: Arc<RwLock<SyncFieldValidatorMap<T, E>>>,
     pub(super) async_field_validators: Arc<RwLock<AsyncFieldValidatorMap<T, E>>>,
     pub(super) form_validators: Arc<RwLock<Vec<SyncFormValidatorFn<T, E>>>>,
     pub(super) dependencies: Arc<RwLock<BTreeMap<FieldKey, BTreeSet<FieldKey>>>>,
     pub(super) focus_handlers: Arc<RwLock<BTreeMap<FieldKey, FocusHandler>>>,
     pub(super) required_fields: Arc<RwLock<BTreeSet<FieldKey>>>,
+    pub(super) field_rules: Arc<RwLock<FieldRulesMap>>,
     pub(super) field_descriptions: Arc<RwLock<BTreeMap<FieldKey, SharedString>>>,
+    pub(super) change_listeners: Arc<RwLock<BTreeMap<SharedString, ChangeListenerFn<T>>>>,
 }
 
 impl<T, E> FormController<T, E>
@@ -202,7 +233,7 @@ where
     pub fn new(initial: T, options: FormOptions) -> Self {
         Self {
             options,
-            state: Arc::new(RwLock::new(FormState {
+            state: Arc::new(RwLock::new(ControllerState {
                 id: FormId::next(),
                 initial_model: initial.clone(),
                 model: initial,
@@ -219,7 +250,9 @@ where
             dependencies: Arc::new(RwLock::new(BTreeMap::new())),
             focus_handlers: Arc::new(RwLock::new(BTreeMap::new())),
             required_fields: Arc::new(RwLock::new(BTreeSet::new())),
+            field_rules: Arc::new(RwLock::new(BTreeMap::new())),
             field_descriptions: Arc::new(RwLock::new(BTreeMap::new())),
+            change_listeners: Arc::new(RwLock::new(BTreeMap::new())),
         }
     }
 
@@ -240,6 +273,40 @@ where
         Ok(())
     }
 
+    /// Calls `listener` with the key and the updated model whenever `set`
+    /// changes a field's value. Registering under a name already in use
+    /// replaces that listener, so a view can register on every render.
+    pub fn register_change_listener(
+        &self,
+        name: impl Into<SharedString>,
+        listener: impl Fn(FieldKey, &T) + Send + Sync + 'static,
+    ) -> FormResult<()> {
+        let mut listeners = write_lock(&self.change_listeners, "registering change listener")?;
+        listeners.insert(name.into(), Arc::new(listener));
+        Ok(())
+    }
+
+    pub fn unregister_change_listener(&self, name: &str) -> FormResult<()> {
+        let mut listeners = write_lock(&self.change_listeners, "unregistering change listener")?;
+        listeners.remove(name);
+        Ok(())
+    }
+
+    pub(super) fn notify_change(&self, key: FieldKey) -> FormResult<()> {
+        let listeners = read_lock(&self.change_listeners, "reading change listeners")?
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        if listeners.is_empty() {
+            return Ok(());
+        }
+        let model = read_lock(&self.state, "reading form model")?.model.clone();
+        for listener in listeners {
+            listener(key, &model);
+        }
+        Ok(())
+    }
+
     pub fn register_required_field<L>(&self, lens: L) -> FormResult<()>
     where
         L: super::validation::FieldLens<T>,
@@ -478,6 +545,25 @@ where
             .cloned())
     }
 
+    pub fn field_errors<L>(&self, lens: L) -> FormResult<Vec<E>>
+    where
+        L: super::validation::FieldLens<T>,
+    {
+        Ok(read_lock(&self.state, "reading field errors")?
+            .field_meta
+            .get(&lens.key())
+            .map(|meta| meta.errors.clone())
+            .unwrap_or_default())
+    }
+
+    pub fn errors(&self) -> FormResult<Vec<(FieldKey, E)>> {
+        Ok(read_lock(&self.state, "reading form errors")?
+            .field_meta
+            .iter()
+            .flat_map(|(key, meta)| meta.errors.iter().map(|error| (*key, error.clone())))
+            .collect())
+    }
+
     pub fn field_description<L>(&self, lens: L) -> FormResult<Option<SharedString>>
     where
         L: super::validation::FieldLens<T>,
@@ -498,7 +584,7 @@ where
 }
 
 pub(super) fn transition_submit_state<T, E>(
-    state: &mut FormState<T, E>,
+    state: &mut ControllerState<T, E>,
     next: SubmitState,
 ) -> FormResult<()> {
     let current = state.submit_state;
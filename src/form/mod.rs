@@ -1,20 +1,35 @@
+mod auto_field;
 mod binding;
 mod controller;
 mod draft;
+mod nested;
+mod options;
+mod rules;
+mod state;
+mod submit;
+mod temporal;
 mod validation;
 
 #[cfg(test)]
 mod tests;
 
-pub use calmui_form_derive::FormModel;
+pub(crate) use auto_field::humanized_label;
+pub use auto_field::{AutoField, AutoFieldKind, AutoFieldProps};
+pub use calmui_form_derive::{FieldOptions, FormModel};
 pub use controller::{
     FieldKey, FieldMeta, FormController, FormError, FormId, FormOptions, FormResult, FormSnapshot,
     RevalidateMode, SubmitState, ValidationMode, ValidationTicket,
 };
 pub use draft::{FormDraftStore, InMemoryDraftStore};
+pub use nested::{ComposedLens, FieldPath, NestedFormModel};
+pub use options::FieldOptions;
+pub use rules::{FieldRule, FieldRuleKind, FieldRuleViolation, RuleValue};
+pub use state::FormState;
+pub use submit::{BoxedSubmitFuture, FormSubmit};
+pub use temporal::TemporalValue;
 pub use validation::{
-    AsyncFieldValidator, BoxedValidationFuture, FieldLens, FieldValidator, FormModel,
-    FormValidator, ValidationError,
+    AsyncFieldValidator, BoxedValidationFuture, FieldDescriptor, FieldLens, FieldValidator,
+    FormModel, FormValidator, ValidationError,
 };
 
 #[doc(hidden)]
@@ -0,0 +1,94 @@
+use super::auto_field::AutoField;
+use super::controller::{FieldKey, FormController, FormResult};
+use super::rules::{FieldRule, FieldRuleViolation};
+use super::validation::{FieldLens, FormModel, ValidationError};
+
+pub trait FieldPath<T>: Copy + Send + Sync + 'static {
+    type Target;
+
+    fn path_key(self) -> FieldKey;
+    fn target(self, model: &T) -> &Self::Target;
+    fn target_mut(self, model: &mut T) -> &mut Self::Target;
+}
+
+pub trait NestedFormModel: FormModel {
+    type NestedFields<P: Copy>;
+
+    fn nested_fields<P: Copy>(path: P) -> Self::NestedFields<P>;
+
+    fn register_nested_rules<T, E, P>(controller: &FormController<T, E>, path: P) -> FormResult<()>
+    where
+        T: Clone + Send + Sync + 'static,
+        E: ValidationError + From<FieldRuleViolation>,
+        P: FieldPath<T, Target = Self>,
+    {
+        let _ = (controller, path);
+        Ok(())
+    }
+
+    fn nested_auto_fields<T, E, P>(path: P) -> Vec<AutoField<T, E>>
+    where
+        T: Clone + Send + Sync + 'static,
+        E: ValidationError,
+        P: FieldPath<T, Target = Self>,
+    {
+        let _ = path;
+        Vec::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ComposedLens<P, L> {
+    outer: P,
+    inner: L,
+}
+
+impl<P, L> ComposedLens<P, L> {
+    pub const fn new(outer: P, inner: L) -> Self {
+        Self { outer, inner }
+    }
+}
+
+impl<T, P, L> FieldLens<T> for ComposedLens<P, L>
+where
+    P: FieldPath<T>,
+    L: FieldLens<P::Target>,
+{
+    type Value = L::Value;
+
+    fn key(self) -> FieldKey {
+        self.outer.path_key().join(self.inner.key())
+    }
+
+    fn get(self, model: &T) -> &Self::Value {
+        self.inner.get(self.outer.target(model))
+    }
+
+    fn set(self, model: &mut T, value: Self::Value) {
+        self.inner.set(self.outer.target_mut(model), value);
+    }
+
+    fn rules(self) -> Vec<FieldRule> {
+        self.inner.rules()
+    }
+}
+
+impl<T, P, L> FieldPath<T> for ComposedLens<P, L>
+where
+    P: FieldPath<T>,
+    L: FieldPath<P::Target>,
+{
+    type Target = L::Target;
+
+    fn path_key(self) -> FieldKey {
+        self.outer.path_key().join(self.inner.path_key())
+    }
+
+    fn target(self, model: &T) -> &Self::Target {
+        self.inner.target(self.outer.target(model))
+    }
+
+    fn target_mut(self, model: &mut T) -> &mut Self::Target {
+        self.inner.target_mut(self.outer.target_mut(model))
+    }
+}
@@ -0,0 +1,37 @@
+use crate::components::{RadioOption, SelectOption};
+
+/// A fixed set of choices backing a select-like field, usually a fieldless
+/// enum with `#[derive(FieldOptions)]`.
+///
+/// `value` is the stable key handed to `Select`/`RadioGroup`; `label` is what
+/// the user sees. Override either per variant with
+/// `#[option(value = "...", label = "...")]`.
+///
+/// Named after the field it fills rather than `FormOptions`, which is the
+/// controller's configuration struct.
+pub trait FieldOptions: Clone + PartialEq + Send + Sync + 'static {
+    fn variants() -> &'static [Self];
+    fn value(&self) -> &'static str;
+    fn label(&self) -> &'static str;
+
+    fn from_value(value: &str) -> Option<Self> {
+        Self::variants()
+            .iter()
+            .find(|variant| variant.value() == value)
+            .cloned()
+    }
+}
+
+pub(crate) fn select_options<V: FieldOptions>() -> Vec<SelectOption> {
+    V::variants()
+        .iter()
+        .map(|variant| SelectOption::labeled(variant.value(), variant.label()))
+        .collect()
+}
+
+pub(crate) fn radio_options<V: FieldOptions>() -> Vec<RadioOption> {
+    V::variants()
+        .iter()
+        .map(|variant| RadioOption::labeled(variant.value(), variant.label()))
+        .collect()
+}
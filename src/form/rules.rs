@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use gpui::SharedString;
+use regex::Regex;
+
+use super::controller::{FieldKey, FormController, FormError, FormResult, read_lock, write_lock};
+use super::validation::{FieldLens, ValidationError};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FieldRuleKind {
+    Required,
+    MinLen(usize),
+    MaxLen(usize),
+    Pattern(SharedString),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldRule {
+    pub kind: FieldRuleKind,
+    pub message: Option<SharedString>,
+}
+
+impl FieldRule {
+    pub fn new(kind: FieldRuleKind) -> Self {
+        Self {
+            kind,
+            message: None,
+        }
+    }
+
+    pub fn required() -> Self {
+        Self::new(FieldRuleKind::Required)
+    }
+
+    pub fn min_len(value: usize) -> Self {
+        Self::new(FieldRuleKind::MinLen(value))
+    }
+
+    pub fn max_len(value: usize) -> Self {
+        Self::new(FieldRuleKind::MaxLen(value))
+    }
+
+    pub fn pattern(value: impl Into<SharedString>) -> Self {
+        Self::new(FieldRuleKind::Pattern(value.into()))
+    }
+
+    pub fn message(mut self, value: impl Into<SharedString>) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    fn default_message(&self) -> SharedString {
+        match &self.kind {
+            FieldRuleKind::Required => "This field is required".into(),
+            FieldRuleKind::MinLen(min) => format!("Must be at least {min} characters").into(),
+            FieldRuleKind::MaxLen(max) => format!("Must be at most {max} characters").into(),
+            FieldRuleKind::Pattern(_) => "Invalid format".into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldRuleViolation {
+    pub field: FieldKey,
+    pub rule: FieldRuleKind,
+    pub message: SharedString,
+}
+
+impl ValidationError for FieldRuleViolation {
+    fn message(&self) -> SharedString {
+        self.message.clone()
+    }
+}
+
+pub trait RuleValue {
+    fn is_blank(&self) -> bool;
+    fn rule_len(&self) -> usize;
+    fn rule_text(&self) -> Option<&str>;
+}
+
+impl RuleValue for String {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+
+    fn rule_len(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn rule_text(&self) -> Option<&str> {
+        Some(self.as_str())
+    }
+}
+
+impl RuleValue for SharedString {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+
+    fn rule_len(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn rule_text(&self) -> Option<&str> {
+        Some(self.as_ref())
+    }
+}
+
+impl<V: RuleValue> RuleValue for Option<V> {
+    fn is_blank(&self) -> bool {
+        self.as_ref().is_none_or(RuleValue::is_blank)
+    }
+
+    fn rule_len(&self) -> usize {
+        self.as_ref().map_or(0, RuleValue::rule_len)
+    }
+
+    fn rule_text(&self) -> Option<&str> {
+        self.as_ref().and_then(RuleValue::rule_text)
+    }
+}
+
+impl<V> RuleValue for Vec<V> {
+    fn is_blank(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn rule_len(&self) -> usize {
+        self.len()
+    }
+
+    fn rule_text(&self) -> Option<&str> {
+        None
+    }
+}
+
+enum CompiledRule {
+    Required,
+    MinLen(usize),
+    MaxLen(usize),
+    Pattern(Regex),
+}
+
+/// Compiled rules of each field, replaced whenever a field's rules are
+/// registered again.
+pub(super) type FieldRulesMap = BTreeMap<FieldKey, Arc<CompiledRules>>;
+
+pub(super) struct CompiledRules {
+    key: FieldKey,
+    rules: Vec<(CompiledRule, FieldRule)>,
+}
+
+impl CompiledRules {
+    fn compile(key: FieldKey, rules: Vec<FieldRule>) -> FormResult<Self> {
+        let rules = rules
+            .into_iter()
+            .map(|rule| {
+                let compiled = match &rule.kind {
+                    FieldRuleKind::Required => CompiledRule::Required,
+                    FieldRuleKind::MinLen(min) => CompiledRule::MinLen(*min),
+                    FieldRuleKind::MaxLen(max) => CompiledRule::MaxLen(*max),
+                    FieldRuleKind::Pattern(pattern) => {
+                        CompiledRule::Pattern(Regex::new(pattern).map_err(|error| {
+                            FormError::InvalidRulePattern(format!("{key}: {error}"))
+                        })?)
+                    }
+                };
+                Ok((compiled, rule))
+            })
+            .collect::<FormResult<Vec<_>>>()?;
+        Ok(Self { key, rules })
+    }
+
+    fn is_required(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|(compiled, _)| matches!(compiled, CompiledRule::Required))
+    }
+
+    fn check<V: RuleValue>(&self, value: &V) -> Result<(), FieldRuleViolation> {
+        let blank = value.is_blank();
+        for (compiled, rule) in &self.rules {
+            let passed = match compiled {
+                CompiledRule::Required => !blank,
+                _ if blank => true,
+                CompiledRule::MinLen(min) => value.rule_len() >= *min,
+                CompiledRule::MaxLen(max) => value.rule_len() <= *max,
+                CompiledRule::Pattern(regex) => value.rule_text().is_none_or(|t| regex.is_match(t)),
+            };
+            if !passed {
+                return Err(FieldRuleViolation {
+                    field: self.key,
+                    rule: rule.kind.clone(),
+                    message: rule
+                        .message
+                        .clone()
+                        .unwrap_or_else(|| rule.default_message()),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, E> FormController<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: ValidationError,
+{
+    pub fn register_field_rules<L>(&self, lens: L, rules: Vec<FieldRule>) -> FormResult<()>
+    where
+        L: FieldLens<T>,
+        L::Value: RuleValue,
+        E: From<FieldRuleViolation>,
+    {
+        let key = lens.key();
+        if rules.is_empty() && !self.has_field_rules(key)? {
+            return Ok(());
+        }
+        let compiled = Arc::new(CompiledRules::compile(key, rules)?);
+        let required = compiled.is_required();
+        let previous =
+            write_lock(&self.field_rules, "registering field rules")?.insert(key, compiled);
+        if required {
+            self.register_required_field(lens)?;
+        } else if previous.as_ref().is_some_and(|rules| rules.is_required()) {
+            self.unregister_required_field(lens)?;
+        }
+        if previous.is_some() {
+            // The validator registered the first time reads the current rules.
+            return Ok(());
+        }
+        let field_rules = self.field_rules.clone();
+        self.register_field_validator(lens, move |_model: &T, value: &L::Value| {
+            let compiled = field_rules
+                .read()
+                .unwrap_or_else(|err| err.into_inner())
+                .get(&key)
+                .cloned();
+            compiled.map_or(Ok(()), |rules| rules.check(value).map_err(E::from))
+        })
+    }
+
+    fn has_field_rules(&self, key: FieldKey) -> FormResult<bool> {
+        Ok(read_lock(&self.field_rules, "reading field rules")?.contains_key(&key))
+    }
+
+    pub fn register_model_rules(&self) -> FormResult<()>
+    where
+        T: super::validation::FormModel,
+        E: From<FieldRuleViolation>,
+    {
+        T::register_rules(self)
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::BTreeSet;
+
+use super::controller::FieldKey;
+use super::validation::{FieldLens, FormModel};
+
+#[derive(Clone, Debug)]
+pub struct FormState<M: FormModel> {
+    initial: M,
+    model: M,
+    dirty_fields: BTreeSet<FieldKey>,
+}
+
+impl<M: FormModel> FormState<M> {
+    pub fn new(initial: M) -> Self {
+        Self {
+            model: initial.clone(),
+            initial,
+            dirty_fields: BTreeSet::new(),
+        }
+    }
+
+    pub fn initial(&self) -> &M {
+        &self.initial
+    }
+
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    pub fn get<L>(&self, lens: L) -> &L::Value
+    where
+        L: FieldLens<M>,
+    {
+        lens.get(&self.model)
+    }
+
+    pub fn set<L>(&mut self, lens: L, value: L::Value) -> bool
+    where
+        L: FieldLens<M>,
+    {
+        let changed = *lens.get(&self.model) != value;
+        lens.set(&mut self.model, value);
+        if lens.get(&self.model) != lens.get(&self.initial) {
+            self.dirty_fields.insert(lens.key());
+        } else {
+            self.dirty_fields.remove(&lens.key());
+        }
+        changed
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty_fields.is_empty()
+    }
+
+    pub fn is_field_dirty<L>(&self, lens: L) -> bool
+    where
+        L: FieldLens<M>,
+    {
+        self.dirty_fields.contains(&lens.key())
+    }
+
+    pub fn dirty_fields(&self) -> impl Iterator<Item = FieldKey> + '_ {
+        self.dirty_fields.iter().copied()
+    }
+
+    pub fn reset(&mut self) {
+        self.model = self.initial.clone();
+        self.dirty_fields.clear();
+    }
+
+    pub fn reset_field<L>(&mut self, lens: L)
+    where
+        L: FieldLens<M>,
+    {
+        let initial_value = lens.get(&self.initial).clone();
+        lens.set(&mut self.model, initial_value);
+        self.dirty_fields.remove(&lens.key());
+    }
+
+    pub fn commit(&mut self) {
+        self.initial = self.model.clone();
+        self.dirty_fields.clear();
+    }
+}
@@ -0,0 +1,114 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+
+use gpui::Window;
+
+use super::controller::{
+    FormController, FormError, FormResult, SubmitState, read_lock, write_lock,
+};
+use super::validation::ValidationError;
+use crate::components::Button;
+
+pub type BoxedSubmitFuture = Pin<Box<dyn Future<Output = FormResult<()>> + Send + 'static>>;
+
+type SubmitHandlerFn<T> = Arc<dyn Fn(T) -> BoxedSubmitFuture + Send + Sync>;
+
+#[derive(Clone)]
+pub struct FormSubmit<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: ValidationError,
+{
+    controller: FormController<T, E>,
+    handler: SubmitHandlerFn<T>,
+    last_error: Arc<RwLock<Option<FormError>>>,
+}
+
+impl<T, E> FormSubmit<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: ValidationError,
+{
+    pub fn new<F, Fut>(controller: FormController<T, E>, handler: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = FormResult<()>> + Send + 'static,
+    {
+        Self {
+            controller,
+            handler: Arc::new(move |model| Box::pin(handler(model))),
+            last_error: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn controller(&self) -> &FormController<T, E> {
+        &self.controller
+    }
+
+    pub fn state(&self) -> FormResult<SubmitState> {
+        Ok(read_lock(&self.controller.state, "reading submit state")?.submit_state)
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.state().is_ok_and(SubmitState::is_pending)
+    }
+
+    pub fn last_error(&self) -> FormResult<Option<FormError>> {
+        Ok(read_lock(&self.last_error, "reading last submit error")?.clone())
+    }
+
+    pub async fn run(&self) -> FormResult<()> {
+        write_lock(&self.last_error, "clearing last submit error")?.take();
+        let handler = self.handler.clone();
+        let result = self
+            .controller
+            .submit_async(move |model| handler(model.clone()))
+            .await;
+        if let Err(error) = result.as_ref() {
+            *write_lock(&self.last_error, "recording last submit error")? = Some(error.clone());
+        }
+        result
+    }
+
+    pub fn trigger(&self, window: &mut Window, cx: &mut gpui::App) {
+        if self.is_pending() {
+            return;
+        }
+
+        let submit = self.clone();
+        let window_handle = window.window_handle();
+        window.refresh();
+        cx.spawn(async move |cx| {
+            let _ = submit.run().await;
+            let _ = window_handle.update(cx, |_, window, cx| {
+                if submit.controller.options.focus_first_error_on_submit {
+                    let _ = submit.controller.focus_first_error(window, cx);
+                }
+                window.refresh();
+            });
+        })
+        .detach();
+    }
+
+    pub fn bind_button(&self, button: Button) -> Button {
+        let submit = self.clone();
+        button
+            .loading(self.is_pending())
+            .on_click(move |_, window, cx| submit.trigger(window, cx))
+    }
+}
+
+impl<T, E> FormController<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: ValidationError,
+{
+    pub fn submitter<F, Fut>(&self, handler: F) -> FormSubmit<T, E>
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = FormResult<()>> + Send + 'static,
+    {
+        FormSubmit::new(self.clone(), handler)
+    }
+}
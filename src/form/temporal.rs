@@ -0,0 +1,121 @@
+use crate::components::{CalendarDate, CalendarDateTime, ClockTime};
+
+pub trait TemporalValue<P>: Sized {
+    fn to_picker(&self) -> Option<P>;
+    fn from_picker(value: P) -> Option<Self>;
+}
+
+macro_rules! impl_identity_temporal {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl TemporalValue<$type> for $type {
+                fn to_picker(&self) -> Option<$type> {
+                    Some(*self)
+                }
+
+                fn from_picker(value: $type) -> Option<Self> {
+                    Some(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_identity_temporal!(CalendarDate, ClockTime, CalendarDateTime);
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+    use super::TemporalValue;
+    use crate::components::{CalendarDate, CalendarDateTime, ClockTime};
+
+    impl TemporalValue<CalendarDate> for NaiveDate {
+        fn to_picker(&self) -> Option<CalendarDate> {
+            CalendarDate::new(self.year(), self.month() as u8, self.day() as u8)
+        }
+
+        fn from_picker(value: CalendarDate) -> Option<Self> {
+            NaiveDate::from_ymd_opt(
+                value.year(),
+                u32::from(value.month()),
+                u32::from(value.day()),
+            )
+        }
+    }
+
+    impl TemporalValue<ClockTime> for NaiveTime {
+        fn to_picker(&self) -> Option<ClockTime> {
+            ClockTime::new(self.hour() as u8, self.minute() as u8, self.second() as u8)
+        }
+
+        fn from_picker(value: ClockTime) -> Option<Self> {
+            NaiveTime::from_hms_opt(
+                u32::from(value.hour()),
+                u32::from(value.minute()),
+                u32::from(value.second()),
+            )
+        }
+    }
+
+    impl TemporalValue<CalendarDateTime> for NaiveDateTime {
+        fn to_picker(&self) -> Option<CalendarDateTime> {
+            Some(CalendarDateTime::new(
+                self.date().to_picker()?,
+                self.time().to_picker()?,
+            ))
+        }
+
+        fn from_picker(value: CalendarDateTime) -> Option<Self> {
+            Some(NaiveDateTime::new(
+                NaiveDate::from_picker(value.date)?,
+                NaiveTime::from_picker(value.time)?,
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use super::TemporalValue;
+    use crate::components::{CalendarDate, CalendarDateTime, ClockTime};
+
+    impl TemporalValue<CalendarDate> for Date {
+        fn to_picker(&self) -> Option<CalendarDate> {
+            CalendarDate::new(self.year(), u8::from(self.month()), self.day())
+        }
+
+        fn from_picker(value: CalendarDate) -> Option<Self> {
+            let month = Month::try_from(value.month()).ok()?;
+            Date::from_calendar_date(value.year(), month, value.day()).ok()
+        }
+    }
+
+    impl TemporalValue<ClockTime> for Time {
+        fn to_picker(&self) -> Option<ClockTime> {
+            ClockTime::new(self.hour(), self.minute(), self.second())
+        }
+
+        fn from_picker(value: ClockTime) -> Option<Self> {
+            Time::from_hms(value.hour(), value.minute(), value.second()).ok()
+        }
+    }
+
+    impl TemporalValue<CalendarDateTime> for PrimitiveDateTime {
+        fn to_picker(&self) -> Option<CalendarDateTime> {
+            Some(CalendarDateTime::new(
+                self.date().to_picker()?,
+                self.time().to_picker()?,
+            ))
+        }
+
+        fn from_picker(value: CalendarDateTime) -> Option<Self> {
+            Some(PrimitiveDateTime::new(
+                Date::from_picker(value.date)?,
+                Time::from_picker(value.time)?,
+            ))
+        }
+    }
+}
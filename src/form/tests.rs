@@ -3,12 +3,16 @@ use futures::executor::block_on;
 use gpui::SharedString;
 use rust_decimal::Decimal;
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
 use std::time::Duration;
 
-use crate::components::TextInput;
+use crate::components::{
+    CalendarDate, CalendarDateTime, ClockTime, DatePicker, DateTimePicker, FileInput, TextInput,
+    TimePicker,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct TestError(&'static str);
@@ -153,6 +157,49 @@ fn field_lens_updates_model_and_dirty_state() {
     assert!(email_meta.dirty);
 }
 
+#[test]
+fn change_listeners_fire_on_changed_values_and_replace_by_name() {
+    let controller =
+        FormController::<ProfileForm, TestError>::new(base_form(), FormOptions::default());
+    let fields = ProfileForm::fields();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let first = calls.clone();
+    controller
+        .register_change_listener("view", move |_, _| {
+            first.fetch_add(100, Ordering::SeqCst);
+        })
+        .expect("register listener");
+    let (count, log) = (calls.clone(), seen.clone());
+    controller
+        .register_change_listener("view", move |key, model: &ProfileForm| {
+            count.fetch_add(1, Ordering::SeqCst);
+            log.lock().expect("log lock").push((key, model.enabled));
+        })
+        .expect("replace listener");
+
+    controller
+        .set(fields.enabled(), true)
+        .expect("set must succeed");
+    controller
+        .set(fields.enabled(), true)
+        .expect("unchanged set must succeed");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        *seen.lock().expect("log lock"),
+        [(fields.enabled().key(), true)]
+    );
+
+    controller
+        .unregister_change_listener("view")
+        .expect("unregister listener");
+    controller
+        .set(fields.enabled(), false)
+        .expect("set must succeed");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
 #[test]
 fn validation_mode_controls_when_errors_appear() {
     let fields = ProfileForm::fields();
@@ -521,6 +568,76 @@ fn text_input_submit_binding_compiles() {
         .expect("bind submit helper");
 }
 
+#[allow(dead_code)]
+#[derive(Clone, calmui_form_derive::FormModel)]
+struct ScheduleForm {
+    day: Option<CalendarDate>,
+    starts_at: Option<ClockTime>,
+    reminder: Option<CalendarDateTime>,
+}
+
+#[test]
+fn temporal_picker_bindings_round_trip_values() {
+    let fields = ScheduleForm::fields();
+    let day = CalendarDate::new(2024, 2, 29).expect("valid date");
+    let starts_at = ClockTime::new(9, 30, 0).expect("valid time");
+    let controller = FormController::<ScheduleForm, TestError>::new(
+        ScheduleForm {
+            day: Some(day),
+            starts_at: None,
+            reminder: None,
+        },
+        FormOptions::default(),
+    );
+
+    let _ = controller
+        .bind_date_picker(fields.day(), DatePicker::new())
+        .expect("bind date picker");
+    let _ = controller
+        .bind_time_picker(fields.starts_at(), TimePicker::new())
+        .expect("bind time picker");
+    let _ = controller
+        .bind_date_time_picker(fields.reminder(), DateTimePicker::new())
+        .expect("bind date time picker");
+
+    assert_eq!(ClockTime::from_picker(starts_at), Some(starts_at));
+    controller
+        .set(
+            fields.reminder(),
+            Some(CalendarDateTime::new(day, starts_at)),
+        )
+        .expect("set reminder");
+    let snapshot = controller.snapshot().expect("snapshot");
+    assert_eq!(
+        snapshot.model.reminder.and_then(|value| value.to_picker()),
+        Some(CalendarDateTime::new(day, starts_at))
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Clone, calmui_form_derive::FormModel)]
+struct AttachmentForm {
+    files: Vec<PathBuf>,
+}
+
+#[test]
+fn file_input_binding_tracks_selected_paths() {
+    let fields = AttachmentForm::fields();
+    let controller = FormController::<AttachmentForm, TestError>::new(
+        AttachmentForm { files: Vec::new() },
+        FormOptions::default(),
+    );
+    let _ = controller
+        .bind_file_input(fields.files(), FileInput::new().multiple(true))
+        .expect("bind file input");
+
+    controller
+        .set(fields.files(), vec![PathBuf::from("report.pdf")])
+        .expect("set files");
+    let snapshot = controller.snapshot().expect("snapshot");
+    assert_eq!(snapshot.model.files, vec![PathBuf::from("report.pdf")]);
+}
+
 #[test]
 fn single_field_update_keeps_other_field_meta_stable() {
     let fields = ProfileForm::fields();
@@ -673,3 +790,523 @@ fn derive_macro_generates_field_lenses() {
     assert_eq!(fields.email().key().as_str(), "email");
     assert_eq!(fields.confirm_password().key().as_str(), "confirm_password");
 }
+
+#[allow(dead_code)]
+#[derive(Clone, calmui_form_derive::FormModel)]
+struct SignupForm {
+    #[form(required, message = "Email is required")]
+    #[form(pattern = "^[^@]+@[^@]+$")]
+    email: SharedString,
+    #[form(min_len = 3, max_len = 8)]
+    handle: Option<String>,
+    tags: Vec<SharedString>,
+}
+
+#[test]
+fn derive_rules_register_validators_and_required_fields() {
+    let fields = SignupForm::fields();
+    let controller = FormController::<SignupForm, FieldRuleViolation>::new(
+        SignupForm {
+            email: "".into(),
+            handle: None,
+            tags: Vec::new(),
+        },
+        FormOptions::default(),
+    );
+    controller
+        .register_model_rules()
+        .expect("register model rules");
+
+    assert!(controller.is_required(fields.email()).expect("is required"));
+    assert!(
+        !controller
+            .is_required(fields.handle())
+            .expect("is required")
+    );
+    assert!(fields.tags().rules().is_empty());
+
+    assert!(!controller.validate_form().expect("validate form"));
+    let errors = controller.errors().expect("aggregate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, fields.email().key());
+    assert_eq!(errors[0].1.message, SharedString::from("Email is required"));
+
+    controller
+        .set(fields.email(), "not-an-email".into())
+        .expect("set email");
+    controller
+        .set(fields.handle(), Some("ab".to_string()))
+        .expect("set handle");
+    assert!(!controller.validate_form().expect("validate form"));
+    assert_eq!(
+        controller
+            .field_errors(fields.email())
+            .expect("email errors")
+            .first()
+            .map(|error| error.rule.clone()),
+        Some(FieldRuleKind::Pattern("^[^@]+@[^@]+$".into()))
+    );
+    assert_eq!(
+        controller
+            .field_errors(fields.handle())
+            .expect("handle errors")
+            .first()
+            .map(ValidationError::message),
+        Some(SharedString::from("Must be at least 3 characters"))
+    );
+
+    controller
+        .set(fields.email(), "user@example.com".into())
+        .expect("set email");
+    controller
+        .set(fields.handle(), Some("calm".to_string()))
+        .expect("set handle");
+    assert!(controller.validate_form().expect("validate form"));
+    assert!(controller.errors().expect("aggregate errors").is_empty());
+}
+
+#[test]
+fn registering_rules_again_replaces_them() {
+    let fields = SignupForm::fields();
+    let controller = FormController::<SignupForm, FieldRuleViolation>::new(
+        SignupForm {
+            email: "".into(),
+            handle: None,
+            tags: Vec::new(),
+        },
+        FormOptions::default(),
+    );
+    for _ in 0..2 {
+        controller
+            .register_model_rules()
+            .expect("register model rules");
+    }
+    assert!(!controller.validate_form().expect("validate form"));
+    assert_eq!(
+        controller
+            .field_errors(fields.email())
+            .expect("email errors")
+            .len(),
+        1
+    );
+
+    controller
+        .register_field_rules(fields.email(), vec![FieldRule::min_len(3)])
+        .expect("replace email rules");
+    assert!(!controller.is_required(fields.email()).expect("is required"));
+    assert!(controller.validate_form().expect("validate form"));
+    controller
+        .set(fields.email(), "ab".into())
+        .expect("set email");
+    assert!(!controller.validate_form().expect("validate form"));
+    assert_eq!(
+        controller
+            .field_errors(fields.email())
+            .expect("email errors")
+            .iter()
+            .map(|error| error.rule.clone())
+            .collect::<Vec<_>>(),
+        vec![FieldRuleKind::MinLen(3)]
+    );
+}
+
+#[test]
+fn invalid_rule_pattern_is_reported() {
+    let fields = SignupForm::fields();
+    let controller = FormController::<SignupForm, FieldRuleViolation>::new(
+        SignupForm {
+            email: "".into(),
+            handle: None,
+            tags: Vec::new(),
+        },
+        FormOptions::default(),
+    );
+    let result = controller.register_field_rules(fields.email(), vec![FieldRule::pattern("(")]);
+    assert!(matches!(result, Err(FormError::InvalidRulePattern(_))));
+}
+
+#[derive(Clone, calmui_form_derive::FormModel)]
+struct SettingsForm<T: Clone>
+where
+    T: Default,
+{
+    #[form(required)]
+    name: String,
+    value: T,
+}
+
+#[test]
+fn derive_supports_generic_models() {
+    let fields = SettingsForm::<u32>::fields();
+    let controller = FormController::<SettingsForm<u32>, FieldRuleViolation>::new(
+        SettingsForm {
+            name: String::new(),
+            value: u32::default(),
+        },
+        FormOptions::default(),
+    );
+    controller
+        .register_model_rules()
+        .expect("register model rules");
+    assert!(controller.is_required(fields.name()).expect("is required"));
+
+    controller.set(fields.value(), 7).expect("set value");
+    let snapshot = controller.snapshot().expect("snapshot");
+    assert_eq!(snapshot.model.value, 7);
+    assert_eq!(fields.value().key().as_str(), "value");
+    assert!(!controller.validate_form().expect("validate form"));
+}
+
+#[derive(Clone, Debug, PartialEq, calmui_form_derive::FormModel)]
+struct GeoPoint {
+    #[form(required, description = "Shown on the map pin")]
+    label: String,
+}
+
+#[derive(Clone, Debug, PartialEq, calmui_form_derive::FormModel)]
+struct Address {
+    #[form(required, message = "Street is required")]
+    #[form(label = "Street", placeholder = "221B Baker St")]
+    street: String,
+    #[form(min_len = 4)]
+    zip: String,
+    #[form(nested, label = "Location")]
+    geo: GeoPoint,
+}
+
+#[derive(Clone, calmui_form_derive::FormModel)]
+struct ContactForm {
+    name: String,
+    #[form(nested)]
+    address: Address,
+}
+
+fn contact_form() -> ContactForm {
+    ContactForm {
+        name: "Ada".into(),
+        address: Address {
+            street: String::new(),
+            zip: "12".into(),
+            geo: GeoPoint {
+                label: String::new(),
+            },
+        },
+    }
+}
+
+#[test]
+fn nested_models_compose_lenses_with_dotted_keys() {
+    let fields = ContactForm::fields();
+    assert_eq!(fields.address().street().key().as_str(), "address.street");
+    assert_eq!(
+        fields.address().geo().label().key().as_str(),
+        "address.geo.label"
+    );
+    assert_eq!(
+        fields.address().street().key(),
+        fields.address().street().key()
+    );
+
+    let mut model = contact_form();
+    fields.address().street().set(&mut model, "Main St".into());
+    fields.address().geo().label().set(&mut model, "HQ".into());
+    assert_eq!(fields.address().street().get(&model), "Main St");
+    assert_eq!(model.address.geo.label, "HQ");
+    assert_eq!(fields.name().get(&model), "Ada");
+}
+
+#[test]
+fn nested_model_rules_register_under_composed_keys() {
+    let fields = ContactForm::fields();
+    let controller = FormController::<ContactForm, FieldRuleViolation>::new(
+        contact_form(),
+        FormOptions::default(),
+    );
+    controller
+        .register_model_rules()
+        .expect("register model rules");
+
+    assert!(
+        controller
+            .is_required(fields.address().street())
+            .expect("is required")
+    );
+    assert!(
+        controller
+            .is_required(fields.address().geo().label())
+            .expect("is required")
+    );
+
+    assert!(!controller.validate_form().expect("validate form"));
+    let keys = controller
+        .errors()
+        .expect("aggregate errors")
+        .into_iter()
+        .map(|(key, _)| key.as_str())
+        .collect::<Vec<_>>();
+    assert!(keys.contains(&"address.street"));
+    assert!(keys.contains(&"address.zip"));
+    assert!(keys.contains(&"address.geo.label"));
+
+    controller
+        .set(fields.address().street(), "Main St".into())
+        .expect("set street");
+    controller
+        .set(fields.address().zip(), "12345".into())
+        .expect("set zip");
+    controller
+        .set(fields.address().geo().label(), "HQ".into())
+        .expect("set label");
+    assert!(controller.validate_form().expect("validate form"));
+}
+
+#[test]
+fn derived_field_meta_resolves_nested_keys() {
+    let fields = ContactForm::fields();
+    let street = ContactForm::field_meta(fields.address().street().key()).expect("street meta");
+    assert_eq!(street.label, Some("Street"));
+    assert_eq!(street.placeholder, Some("221B Baker St"));
+    assert_eq!(street.description, None);
+
+    assert_eq!(
+        ContactForm::field_meta(FieldKey::new("address.geo")),
+        Some(FieldDescriptor::new().label("Location"))
+    );
+    assert_eq!(
+        Address::field_meta(fields.address().geo().label().key()),
+        None,
+        "keys are resolved relative to the model they are asked on"
+    );
+    assert_eq!(
+        ContactForm::field_meta(fields.address().geo().label().key())
+            .and_then(|meta| meta.description),
+        Some("Shown on the map pin")
+    );
+    assert_eq!(ContactForm::field_meta(fields.name().key()), None);
+    assert_eq!(ContactForm::field_meta(fields.address().zip().key()), None);
+
+    let controller = FormController::<ContactForm, FieldRuleViolation>::new(
+        contact_form(),
+        FormOptions::default(),
+    );
+    assert_eq!(
+        controller.field_descriptor(fields.address().street()),
+        Some(street)
+    );
+}
+
+#[derive(Clone, calmui_form_derive::FormModel)]
+struct NotifyPrefs {
+    email_alerts: bool,
+}
+
+#[derive(Clone, calmui_form_derive::FormModel)]
+struct PreferencesForm {
+    #[form(options("light", "dark"))]
+    theme: SharedString,
+    #[form(placeholder = "Shown to teammates")]
+    display_name: SharedString,
+    #[form(nested)]
+    notify: NotifyPrefs,
+    tags: Vec<SharedString>,
+}
+
+#[test]
+fn derived_auto_fields_pick_inputs_by_field_type() {
+    let fields = PreferencesForm::auto_fields::<FieldRuleViolation>();
+    let summary = fields
+        .iter()
+        .map(|field| (field.key().as_str(), field.kind()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            ("theme", AutoFieldKind::Select),
+            ("display_name", AutoFieldKind::Text),
+            ("notify.email_alerts", AutoFieldKind::Checkbox),
+        ]
+    );
+    assert!(
+        ProfileForm::auto_fields::<TestError>()
+            .iter()
+            .any(|field| field.kind() == AutoFieldKind::Number)
+    );
+
+    let controller = FormController::<PreferencesForm, FieldRuleViolation>::new(
+        PreferencesForm {
+            theme: "light".into(),
+            display_name: SharedString::default(),
+            notify: NotifyPrefs { email_alerts: true },
+            tags: Vec::new(),
+        },
+        FormOptions::default(),
+    );
+    for field in &fields {
+        let props = AutoFieldProps {
+            id: crate::id::ComponentId::new(field.key().as_str()),
+            label: humanized_label(field.key()),
+            placeholder: None,
+            description: None,
+            layout: crate::style::FieldLayout::Vertical,
+        };
+        assert!(field.render(&controller, props).is_ok());
+    }
+    assert_eq!(
+        humanized_label(FieldKey::new("notify.email_alerts")),
+        SharedString::from("Email alerts")
+    );
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, calmui_form_derive::FieldOptions)]
+enum Plan {
+    Free,
+    #[option(label = "Team plan")]
+    Team,
+    #[option(value = "ent")]
+    EnterpriseTier,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, calmui_form_derive::FieldOptions)]
+enum BillingCycle {
+    Monthly,
+    Yearly,
+}
+
+#[derive(Clone, calmui_form_derive::FormModel)]
+struct BillingForm {
+    #[form(select)]
+    plan: Plan,
+    #[form(radio, label = "Billed")]
+    cycle: BillingCycle,
+}
+
+#[test]
+fn derived_field_options_drive_enum_bindings() {
+    assert_eq!(
+        Plan::variants(),
+        &[Plan::Free, Plan::Team, Plan::EnterpriseTier]
+    );
+    assert_eq!(Plan::Free.value(), "free");
+    assert_eq!(Plan::Team.label(), "Team plan");
+    assert_eq!(Plan::EnterpriseTier.value(), "ent");
+    assert_eq!(Plan::EnterpriseTier.label(), "Enterprise tier");
+    assert_eq!(Plan::from_value("team"), Some(Plan::Team));
+    assert_eq!(Plan::from_value("enterprise_tier"), None);
+    assert_eq!(
+        options::select_options::<BillingCycle>()
+            .into_iter()
+            .map(|option| (option.value, option.label))
+            .collect::<Vec<_>>(),
+        vec![
+            ("monthly".into(), Some("Monthly".into())),
+            ("yearly".into(), Some("Yearly".into())),
+        ]
+    );
+
+    let fields = BillingForm::auto_fields::<FieldRuleViolation>();
+    assert_eq!(
+        fields
+            .iter()
+            .map(|field| (field.key().as_str(), field.kind()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("plan", AutoFieldKind::Select),
+            ("cycle", AutoFieldKind::Radio)
+        ]
+    );
+
+    let controller = FormController::<BillingForm, FieldRuleViolation>::new(
+        BillingForm {
+            plan: Plan::Free,
+            cycle: BillingCycle::Monthly,
+        },
+        FormOptions::default(),
+    );
+    let lenses = BillingForm::fields();
+    assert!(
+        controller
+            .bind_enum_select(lenses.plan(), crate::components::Select::new())
+            .is_ok()
+    );
+    assert!(
+        controller
+            .bind_enum_radio(lenses.cycle(), crate::components::RadioGroup::new())
+            .is_ok()
+    );
+}
+
+#[test]
+fn form_state_tracks_dirty_fields_and_resets() {
+    let fields = ProfileForm::fields();
+    let mut state = FormState::new(base_form());
+    assert!(!state.is_dirty());
+
+    assert!(state.set(fields.email(), "changed@example.com".into()));
+    assert!(!state.set(fields.email(), "changed@example.com".into()));
+    state.set(fields.password(), "next-secret".into());
+    assert!(state.is_dirty());
+    assert!(state.is_field_dirty(fields.email()));
+    assert_eq!(state.dirty_fields().count(), 2);
+
+    state.reset_field(fields.email());
+    assert!(!state.is_field_dirty(fields.email()));
+    assert_eq!(state.get(fields.email()), &state.initial().email);
+    assert!(state.is_dirty());
+
+    let password = state.initial().password.clone();
+    state.set(fields.password(), password);
+    assert!(!state.is_dirty());
+
+    state.set(fields.email(), "saved@example.com".into());
+    state.commit();
+    assert!(!state.is_dirty());
+    assert_eq!(state.initial().email, "saved@example.com");
+
+    state.set(fields.email(), "draft@example.com".into());
+    state.reset();
+    assert!(!state.is_dirty());
+    assert_eq!(state.model().email, "saved@example.com");
+}
+
+#[test]
+fn form_submit_runs_async_handler_and_records_errors() {
+    let fields = ProfileForm::fields();
+    let controller =
+        FormController::<ProfileForm, TestError>::new(base_form(), FormOptions::default());
+    let submitted = Arc::new(AtomicUsize::new(0));
+    let submit = {
+        let submitted = submitted.clone();
+        controller.submitter(move |model: ProfileForm| {
+            let submitted = submitted.clone();
+            async move {
+                if model.email.is_empty() {
+                    return Err(FormError::DraftSaveFailed("missing email".into()));
+                }
+                submitted.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+    };
+
+    assert_eq!(submit.state().expect("state"), SubmitState::Idle);
+    assert!(!submit.is_pending());
+
+    controller
+        .set(fields.email(), "".into())
+        .expect("clear email");
+    let error = block_on(submit.run()).expect_err("submit rejects empty email");
+    assert_eq!(submit.last_error().expect("last error"), Some(error));
+    assert_eq!(submit.state().expect("state"), SubmitState::Failed);
+
+    controller
+        .set(fields.email(), "ok@calm.ui".into())
+        .expect("set email");
+    block_on(submit.run()).expect("submit succeeds");
+    assert_eq!(submitted.load(Ordering::SeqCst), 1);
+    assert_eq!(submit.last_error().expect("last error"), None);
+    assert_eq!(submit.state().expect("state"), SubmitState::Succeeded);
+    assert!(SubmitState::Succeeded.is_settled());
+    assert!(SubmitState::Submitting.is_pending());
+
+    let _ = submit.bind_button(crate::components::Button::new());
+}
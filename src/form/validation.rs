@@ -6,11 +6,13 @@ use std::time::Duration;
 use futures_timer::Delay;
 use gpui::SharedString;
 
+use super::auto_field::AutoField;
 use super::controller::{
     AsyncFieldValidatorEntry, AsyncFieldValidatorFn, FieldKey, FormController, FormResult,
     RevalidateMode, SyncFieldValidatorFn, SyncFormValidatorFn, ValidationMode, ValidationTicket,
     first_error_key, read_lock, write_lock,
 };
+use super::rules::{FieldRule, FieldRuleViolation};
 
 pub trait ValidationError: Clone + Send + Sync + 'static {
     fn message(&self) -> SharedString;
@@ -22,12 +24,74 @@ pub trait FieldLens<T>: Copy + Send + Sync + 'static {
     fn key(self) -> FieldKey;
     fn get(self, model: &T) -> &Self::Value;
     fn set(self, model: &mut T, value: Self::Value);
+
+    fn rules(self) -> Vec<FieldRule> {
+        Vec::new()
+    }
+}
+
+/// Static presentation strings declared on a model field with
+/// `#[form(label = "...", placeholder = "...", description = "...")]`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FieldDescriptor {
+    pub label: Option<&'static str>,
+    pub placeholder: Option<&'static str>,
+    pub description: Option<&'static str>,
+}
+
+impl FieldDescriptor {
+    pub const fn new() -> Self {
+        Self {
+            label: None,
+            placeholder: None,
+            description: None,
+        }
+    }
+
+    pub const fn label(mut self, value: &'static str) -> Self {
+        self.label = Some(value);
+        self
+    }
+
+    pub const fn placeholder(mut self, value: &'static str) -> Self {
+        self.placeholder = Some(value);
+        self
+    }
+
+    pub const fn description(mut self, value: &'static str) -> Self {
+        self.description = Some(value);
+        self
+    }
 }
 
 pub trait FormModel: Clone + Send + Sync + 'static {
     type Fields;
 
     fn fields() -> Self::Fields;
+
+    /// Presentation strings for `key`, including dotted keys of nested
+    /// models. `None` when the field declares none.
+    fn field_meta(key: FieldKey) -> Option<FieldDescriptor> {
+        let _ = key;
+        None
+    }
+
+    /// Inputs [`AutoForm`](crate::components::AutoForm) renders, in field
+    /// declaration order with nested models flattened in place.
+    fn auto_fields<E>() -> Vec<AutoField<Self, E>>
+    where
+        E: ValidationError,
+    {
+        Vec::new()
+    }
+
+    fn register_rules<E>(controller: &FormController<Self, E>) -> FormResult<()>
+    where
+        E: ValidationError + From<FieldRuleViolation>,
+    {
+        let _ = controller;
+        Ok(())
+    }
 }
 
 pub trait FieldValidator<T, L, E>: Send + Sync
@@ -187,8 +251,9 @@ where
         L: FieldLens<T>,
     {
         let key = lens.key();
-        {
+        let changed = {
             let mut state = write_lock(&self.state, "writing form model")?;
+            let changed = *lens.get(&state.model) != value;
             lens.set(&mut state.model, value);
             let is_dirty = lens.get(&state.model) != lens.get(&state.initial_model);
             if is_dirty {
@@ -197,7 +262,8 @@ where
                 state.dirty_fields.remove(&key);
             }
             state.ensure_meta(key).dirty = is_dirty;
-        }
+            changed
+        };
 
         if self.options.validate_mode == ValidationMode::OnChange {
             let _ = self.validate_field_by_key(key)?;
@@ -205,6 +271,9 @@ where
         if self.options.revalidate_mode == RevalidateMode::OnChange {
             self.revalidate_dependents(key)?;
         }
+        if changed {
+            self.notify_change(key)?;
+        }
         Ok(())
     }
 
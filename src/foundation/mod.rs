@@ -1,3 +1,7 @@
+pub mod clipboard {
+    pub use crate::clipboard::*;
+}
+
 pub mod contracts {
     pub use crate::contracts::*;
 }
@@ -6,10 +10,19 @@ pub mod feedback {
     pub use crate::feedback::*;
 }
 
+pub mod focus {
+    pub use crate::focus::*;
+}
+
+#[cfg(feature = "forms")]
 pub mod form {
     pub use crate::form::*;
 }
 
+pub mod hooks {
+    pub use crate::hooks::*;
+}
+
 pub mod icon {
     pub use crate::icon::*;
 }
@@ -18,6 +31,10 @@ pub mod id {
     pub use crate::id::*;
 }
 
+pub mod interaction {
+    pub use crate::interaction::*;
+}
+
 #[cfg(feature = "i18n")]
 pub mod i18n {
     pub use crate::i18n::*;
@@ -31,6 +48,22 @@ pub mod overlay {
     pub use crate::overlay::*;
 }
 
+pub mod resource {
+    pub use crate::resource::*;
+}
+
+pub mod shape {
+    pub use crate::shape::*;
+}
+
+pub mod shortcuts {
+    pub use crate::shortcuts::*;
+}
+
+pub mod spatial {
+    pub use crate::spatial::*;
+}
+
 pub mod style {
     pub use crate::style::*;
 }
@@ -0,0 +1,120 @@
+//! Shorthand accessors for [`CalmProvider`] services.
+//!
+//! The service accessors take `&gpui::App`, so a `Context<V>` from a render
+//! method or event handler can be passed directly. They panic like the
+//! provider accessors when [`CalmProvider::init`] has not run.
+
+use std::sync::Arc;
+
+use gpui::SharedString;
+
+use crate::CalmProvider;
+use crate::clipboard::Clipboard;
+use crate::feedback::{NotificationInbox, TaskManager, ToastManager};
+use crate::focus::FocusCoordinator;
+#[cfg(feature = "i18n")]
+use crate::i18n::I18nManager;
+use crate::motion::MotionLevel;
+use crate::overlay::ModalManager;
+use crate::shortcuts::ShortcutRegistry;
+use crate::theme::Theme;
+
+pub fn use_theme(cx: &gpui::App) -> Arc<Theme> {
+    CalmProvider::theme(cx)
+}
+
+pub fn use_motion_level(cx: &gpui::App) -> MotionLevel {
+    CalmProvider::motion_level(cx)
+}
+
+pub fn use_toasts(cx: &gpui::App) -> ToastManager {
+    CalmProvider::toast(cx)
+}
+
+/// Toasts scoped to `window`, sharing the app-wide notification inbox.
+pub fn use_window_toasts(window: &gpui::Window, cx: &gpui::App) -> ToastManager {
+    CalmProvider::window_toast(window, cx)
+}
+
+pub fn use_notifications(cx: &gpui::App) -> NotificationInbox {
+    CalmProvider::notifications(cx)
+}
+
+pub fn use_tasks(cx: &gpui::App) -> TaskManager {
+    CalmProvider::tasks(cx)
+}
+
+pub fn use_modals(cx: &gpui::App) -> ModalManager {
+    CalmProvider::modal(cx)
+}
+
+pub fn use_focus(cx: &gpui::App) -> FocusCoordinator {
+    CalmProvider::focus(cx)
+}
+
+pub fn use_shortcuts(cx: &gpui::App) -> ShortcutRegistry {
+    CalmProvider::shortcuts(cx)
+}
+
+#[cfg(feature = "i18n")]
+pub fn use_i18n(cx: &gpui::App) -> I18nManager {
+    CalmProvider::i18n(cx)
+}
+
+/// Fetches a handle registered with
+/// [`CalmProviderBuilder::service`](crate::CalmProviderBuilder::service).
+pub fn use_service<T: Clone + 'static>(cx: &gpui::App) -> Option<T> {
+    CalmProvider::service(cx)
+}
+
+/// Clipboard writer whose "copied" feedback is shared by every handle with
+/// the same `key`, e.g. a button id or a row key.
+pub fn use_clipboard(key: impl Into<SharedString>) -> Clipboard {
+    Clipboard::new(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Modal;
+    use crate::feedback::{NotificationEntry, NotificationFilter, ToastEntry};
+    use crate::tokens::PaletteKey;
+
+    #[gpui::test]
+    fn hooks_return_the_installed_provider_services(cx: &mut gpui::TestAppContext) {
+        #[derive(Clone, Debug, PartialEq)]
+        struct LayoutStore(&'static str);
+
+        let modals = ModalManager::new();
+        let opened = modals.open_modal(Modal::confirm("Quit", "Quit now?"));
+        cx.update(|cx| {
+            CalmProvider::builder()
+                .theme(|theme| theme.as_ref().clone().with_primary_color(PaletteKey::Teal))
+                .motion_level(MotionLevel::Reduced)
+                .modals(modals.clone())
+                .service(LayoutStore("workspace.json"))
+                .init(cx);
+        });
+
+        cx.update(|cx| {
+            assert_eq!(use_theme(cx).primary_color, PaletteKey::Teal);
+            assert_eq!(use_motion_level(cx), MotionLevel::Reduced);
+            assert!(use_modals(cx).is_top(opened.id()));
+            assert_eq!(
+                use_service::<LayoutStore>(cx),
+                Some(LayoutStore("workspace.json"))
+            );
+            assert_eq!(use_service::<String>(cx), None);
+
+            let toast = use_toasts(cx).show(ToastEntry::new("Saved", "Draft saved"));
+            assert!(use_toasts(cx).contains(toast));
+
+            let before = use_notifications(cx).count(NotificationFilter::All);
+            use_notifications(cx).push(NotificationEntry::new("Synced", "Up to date"));
+            assert_eq!(
+                use_toasts(cx).inbox().count(NotificationFilter::All),
+                before + 1
+            );
+        });
+    }
+}
@@ -69,6 +69,12 @@ impl I18nManager {
             .resolve_locale(self.requested_locale().as_deref())
     }
 
+    /// The requested locale tag before it is matched against the catalog,
+    /// e.g. `en-GB` even when only `en` has translations.
+    pub fn locale_tag(&self) -> Option<String> {
+        self.requested_locale()
+    }
+
     pub fn has_key(&self, key: &str) -> bool {
         self.lookup(key).is_some()
     }
@@ -0,0 +1,91 @@
+use gpui::SharedString;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InteractionAction {
+    Press,
+    Toggle,
+    Select,
+    Open,
+    Close,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InteractionEvent {
+    pub component: &'static str,
+    pub id: SharedString,
+    pub action: InteractionAction,
+    pub value: Option<SharedString>,
+}
+
+impl InteractionEvent {
+    pub fn new(
+        component: &'static str,
+        id: impl Into<SharedString>,
+        action: InteractionAction,
+    ) -> Self {
+        Self {
+            component,
+            id: id.into(),
+            action,
+            value: None,
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
+
+pub trait InteractionObserver: Send + Sync {
+    fn on_interaction(&self, event: &InteractionEvent);
+}
+
+impl<F> InteractionObserver for F
+where
+    F: Fn(&InteractionEvent) + Send + Sync,
+{
+    fn on_interaction(&self, event: &InteractionEvent) {
+        (self)(event);
+    }
+}
+
+pub(crate) fn emit(event: InteractionEvent, cx: &gpui::App) {
+    if let Some(observer) = crate::provider::CalmProvider::interaction_observer(cx) {
+        observer.on_interaction(&event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn closures_observe_structured_events() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let observer = move |event: &InteractionEvent| {
+            sink.lock().expect("sink poisoned").push(event.clone());
+        };
+
+        observer.on_interaction(&InteractionEvent::new(
+            "button",
+            "save",
+            InteractionAction::Press,
+        ));
+        observer.on_interaction(
+            &InteractionEvent::new("tabs", "settings", InteractionAction::Select).value("billing"),
+        );
+
+        let seen = seen.lock().expect("sink poisoned");
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0].component, "button");
+        assert_eq!(seen[0].value, None);
+        assert_eq!(seen[1].action, InteractionAction::Select);
+        assert_eq!(
+            seen[1].value.as_ref().map(|value| value.as_ref()),
+            Some("billing")
+        );
+    }
+}
@@ -1,16 +1,28 @@
+pub mod clipboard;
 pub mod components;
 pub mod contracts;
+pub mod dialogs;
 pub mod feedback;
+pub mod focus;
+#[cfg(feature = "forms")]
 pub mod form;
 pub mod foundation;
+pub mod hooks;
 #[cfg(feature = "i18n")]
 pub mod i18n;
 pub mod icon;
 pub mod id;
+pub mod interaction;
 pub mod motion;
 pub mod overlay;
 pub mod prelude;
 mod provider;
+pub mod resource;
+pub mod shape;
+pub mod shortcuts;
+pub mod spatial;
+#[cfg(feature = "spellcheck")]
+pub mod spellcheck;
 pub mod style;
 pub mod theme;
 pub mod tokens;
@@ -18,4 +30,4 @@ pub mod widgets;
 
 #[cfg(feature = "i18n")]
 pub use crate::i18n::{I18nManager, Locale};
-pub use provider::CalmProvider;
+pub use provider::{CalmProvider, CalmProviderBuilder};
@@ -1,5 +1,6 @@
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum MotionLevel {
+    #[default]
     Full,
     Reduced,
     None,
@@ -32,6 +33,21 @@ pub enum Easing {
     BounceInOut,
 }
 
+impl Easing {
+    pub fn apply(self, delta: f32) -> f32 {
+        match self {
+            Easing::Linear => gpui::linear(delta),
+            Easing::Ease => gpui::ease_in_out(delta),
+            Easing::EaseIn => gpui::quadratic(delta),
+            Easing::EaseOut => gpui::ease_out_quint()(delta),
+            Easing::EaseInOut => gpui::ease_in_out(delta),
+            Easing::Quadratic => gpui::quadratic(delta),
+            Easing::QuintOut => gpui::ease_out_quint()(delta),
+            Easing::BounceInOut => gpui::bounce(gpui::ease_out_quint())(delta),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct SpringConfig {
     pub stiffness: u16,
@@ -1,5 +1,10 @@
+pub mod positioning;
+
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
+use std::task::{Context, Poll, Waker};
 
 use crate::components::Modal;
 
@@ -36,10 +41,80 @@ pub enum ModalStateChange {
     Closed(ModalCloseReason),
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModalResult {
+    Confirmed,
+    Canceled,
+    Completed,
+    Dismissed(ModalCloseReason),
+}
+
+#[derive(Default)]
+struct OutcomeSlot {
+    result: Option<ModalResult>,
+    waker: Option<Waker>,
+}
+
+#[derive(Clone, Default)]
+struct ModalOutcome(Arc<Mutex<OutcomeSlot>>);
+
+impl ModalOutcome {
+    fn resolve(&self, result: ModalResult) {
+        let waker = {
+            let mut slot = self.0.lock().expect("modal outcome poisoned");
+            if slot.result.is_some() {
+                return;
+            }
+            slot.result = Some(result);
+            slot.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Resolves once the modal leaves the stack, whichever way it was closed.
+#[must_use = "the modal stays open; await the future to observe its result"]
+pub struct ModalResultFuture {
+    id: ModalId,
+    outcome: ModalOutcome,
+}
+
+impl ModalResultFuture {
+    pub fn id(&self) -> ModalId {
+        self.id
+    }
+
+    pub fn try_result(&self) -> Option<ModalResult> {
+        self.outcome
+            .0
+            .lock()
+            .expect("modal outcome poisoned")
+            .result
+    }
+}
+
+impl Future for ModalResultFuture {
+    type Output = ModalResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.outcome.0.lock().expect("modal outcome poisoned");
+        match slot.result {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ManagedModal {
     id: ModalId,
     modal: Arc<Modal>,
+    outcome: ModalOutcome,
 }
 
 impl ManagedModal {
@@ -72,10 +147,17 @@ impl ModalManager {
         Self::default()
     }
 
-    #[allow(clippy::arc_with_non_send_sync)]
     pub fn open(&self, modal: Modal) -> ModalId {
+        self.open_modal(modal).id()
+    }
+
+    /// Pushes `modal` on top of the stack and returns a future for its result,
+    /// so a confirm can be awaited on top of the form that raised it.
+    #[allow(clippy::arc_with_non_send_sync)]
+    pub fn open_modal(&self, modal: Modal) -> ModalResultFuture {
         let id = ModalId(self.next_id.fetch_add(1, Ordering::SeqCst) + 1);
         let modal = Arc::new(modal);
+        let outcome = ModalOutcome::default();
 
         self.state
             .write()
@@ -84,10 +166,11 @@ impl ModalManager {
             .push(ManagedModal {
                 id,
                 modal: modal.clone(),
+                outcome: outcome.clone(),
             });
 
         modal.emit_opened();
-        id
+        ModalResultFuture { id, outcome }
     }
 
     pub fn open_confirm(
@@ -149,6 +232,7 @@ impl ModalManager {
         let closed = self.remove(id);
         if let Some(entry) = closed {
             entry.modal.emit_closed(reason);
+            entry.outcome.resolve(ModalResult::Dismissed(reason));
             true
         } else {
             false
@@ -160,6 +244,7 @@ impl ModalManager {
         if let Some(entry) = closed {
             entry.modal.emit_confirmed();
             entry.modal.emit_closed(ModalCloseReason::ConfirmAction);
+            entry.outcome.resolve(ModalResult::Confirmed);
             true
         } else {
             false
@@ -171,6 +256,7 @@ impl ModalManager {
         if let Some(entry) = closed {
             entry.modal.emit_canceled();
             entry.modal.emit_closed(ModalCloseReason::CancelAction);
+            entry.outcome.resolve(ModalResult::Canceled);
             true
         } else {
             false
@@ -182,6 +268,7 @@ impl ModalManager {
         if let Some(entry) = closed {
             entry.modal.emit_completed();
             entry.modal.emit_closed(ModalCloseReason::CompleteAction);
+            entry.outcome.resolve(ModalResult::Completed);
             true
         } else {
             false
@@ -198,6 +285,9 @@ impl ModalManager {
 
         closed.map(|entry| {
             entry.modal.emit_closed(ModalCloseReason::Programmatic);
+            entry
+                .outcome
+                .resolve(ModalResult::Dismissed(ModalCloseReason::Programmatic));
             entry.id
         })
     }
@@ -208,8 +298,11 @@ impl ModalManager {
             state.stack.drain(..).collect::<Vec<_>>()
         };
 
-        for entry in closed {
+        for entry in closed.into_iter().rev() {
             entry.modal.emit_closed(ModalCloseReason::Programmatic);
+            entry
+                .outcome
+                .resolve(ModalResult::Dismissed(ModalCloseReason::Programmatic));
         }
     }
 
@@ -230,6 +323,39 @@ impl ModalManager {
             .cloned()
     }
 
+    pub fn len(&self) -> usize {
+        self.state.read().expect("modal state poisoned").stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Stack position of `id`, where `0` is the bottom-most modal.
+    pub fn depth(&self, id: ModalId) -> Option<usize> {
+        self.state
+            .read()
+            .expect("modal state poisoned")
+            .stack
+            .iter()
+            .position(|entry| entry.id == id)
+    }
+
+    pub fn is_top(&self, id: ModalId) -> bool {
+        self.top().is_some_and(|entry| entry.id == id)
+    }
+
+    /// Closes the topmost modal the way the Escape key does, leaving the
+    /// modals underneath open. Modals that opt out of Escape are left alone.
+    pub fn dismiss_top(&self) -> Option<ModalId> {
+        let top = self.top()?;
+        if !top.modal.close_on_escape_enabled() {
+            return None;
+        }
+        self.close_with_reason(top.id, ModalCloseReason::EscapeKey)
+            .then_some(top.id)
+    }
+
     fn remove(&self, id: ModalId) -> Option<ManagedModal> {
         let mut state = self.state.write().expect("modal state poisoned");
         let index = state.stack.iter().position(|entry| entry.id == id)?;
@@ -240,6 +366,7 @@ impl ModalManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures::executor::block_on;
     use std::sync::atomic::AtomicUsize;
 
     #[test]
@@ -258,6 +385,53 @@ mod tests {
         assert_eq!(closed, second_id);
     }
 
+    #[test]
+    fn modal_manager_stacks_nested_modals_and_escape_dismisses_only_the_top() {
+        let manager = ModalManager::new();
+        let form = manager.open(Modal::titled("Form"));
+        let confirm = manager.open(Modal::confirm("Discard", "Discard changes?"));
+
+        assert_eq!(manager.len(), 2);
+        assert_eq!(manager.depth(form), Some(0));
+        assert_eq!(manager.depth(confirm), Some(1));
+        assert!(manager.is_top(confirm));
+
+        assert_eq!(manager.dismiss_top(), Some(confirm));
+        assert!(manager.is_top(form));
+        assert_eq!(manager.depth(confirm), None);
+
+        let locked = manager.open(Modal::titled("Locked").close_on_escape(false));
+        assert_eq!(manager.dismiss_top(), None);
+        assert!(manager.is_top(locked));
+        manager.close_all();
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn modal_manager_resolves_awaited_results() {
+        let manager = ModalManager::new();
+        let form = manager.open_modal(Modal::titled("Form"));
+        let confirm = manager.open_modal(Modal::confirm("Save", "Save changes?"));
+        assert_eq!(confirm.try_result(), None);
+
+        assert!(manager.confirm(confirm.id()));
+        assert_eq!(block_on(confirm), ModalResult::Confirmed);
+        assert!(manager.is_top(form.id()));
+
+        let escaped = manager.open_modal(Modal::titled("Help"));
+        manager.dismiss_top();
+        assert_eq!(
+            block_on(escaped),
+            ModalResult::Dismissed(ModalCloseReason::EscapeKey)
+        );
+
+        manager.close_all();
+        assert_eq!(
+            block_on(form),
+            ModalResult::Dismissed(ModalCloseReason::Programmatic)
+        );
+    }
+
     #[test]
     fn modal_manager_fires_confirm_and_close_callbacks() {
         let manager = ModalManager::new();
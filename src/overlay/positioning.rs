@@ -0,0 +1,323 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width: width.max(0.0),
+            height: height.max(0.0),
+        }
+    }
+
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.right() && y >= self.y && y <= self.bottom()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PositionOptions {
+    pub side: Side,
+    pub align: Align,
+    pub offset: f32,
+    pub cross_offset: f32,
+    pub margin: f32,
+    pub flip: bool,
+    pub shift: bool,
+    pub arrow_padding: f32,
+}
+
+impl Default for PositionOptions {
+    fn default() -> Self {
+        Self {
+            side: Side::Bottom,
+            align: Align::Start,
+            offset: 0.0,
+            cross_offset: 0.0,
+            margin: 8.0,
+            flip: true,
+            shift: true,
+            arrow_padding: 8.0,
+        }
+    }
+}
+
+impl PositionOptions {
+    pub fn new(side: Side) -> Self {
+        Self {
+            side,
+            ..Self::default()
+        }
+    }
+
+    pub fn align(mut self, value: Align) -> Self {
+        self.align = value;
+        self
+    }
+
+    pub fn offset(mut self, value: f32) -> Self {
+        self.offset = value;
+        self
+    }
+
+    pub fn cross_offset(mut self, value: f32) -> Self {
+        self.cross_offset = value;
+        self
+    }
+
+    pub fn margin(mut self, value: f32) -> Self {
+        self.margin = value.max(0.0);
+        self
+    }
+
+    pub fn flip(mut self, value: bool) -> Self {
+        self.flip = value;
+        self
+    }
+
+    pub fn shift(mut self, value: bool) -> Self {
+        self.shift = value;
+        self
+    }
+
+    pub fn arrow_padding(mut self, value: f32) -> Self {
+        self.arrow_padding = value.max(0.0);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    pub side: Side,
+    pub x: f32,
+    pub y: f32,
+    pub arrow: f32,
+    pub flipped: bool,
+    pub shifted: bool,
+}
+
+fn main_axis_origin(anchor: Rect, width: f32, height: f32, side: Side, offset: f32) -> f32 {
+    match side {
+        Side::Top => anchor.y - height - offset,
+        Side::Bottom => anchor.bottom() + offset,
+        Side::Left => anchor.x - width - offset,
+        Side::Right => anchor.right() + offset,
+    }
+}
+
+fn cross_axis_origin(anchor_start: f32, anchor_len: f32, len: f32, align: Align) -> f32 {
+    match align {
+        Align::Start => anchor_start,
+        Align::Center => anchor_start + (anchor_len - len) / 2.0,
+        Align::End => anchor_start + anchor_len - len,
+    }
+}
+
+fn room(anchor: Rect, viewport: Rect, side: Side, margin: f32) -> f32 {
+    match side {
+        Side::Top => anchor.y - viewport.y - margin,
+        Side::Bottom => viewport.bottom() - margin - anchor.bottom(),
+        Side::Left => anchor.x - viewport.x - margin,
+        Side::Right => viewport.right() - margin - anchor.right(),
+    }
+}
+
+pub fn resolve_side(
+    anchor: Rect,
+    width: f32,
+    height: f32,
+    viewport: Rect,
+    options: PositionOptions,
+) -> Side {
+    let side = options.side;
+    if !options.flip {
+        return side;
+    }
+    let needed = if side.is_vertical() { height } else { width } + options.offset;
+    let preferred = room(anchor, viewport, side, options.margin);
+    let opposite = room(anchor, viewport, side.opposite(), options.margin);
+    if preferred < needed && opposite > preferred {
+        side.opposite()
+    } else {
+        side
+    }
+}
+
+pub fn compute(
+    anchor: Rect,
+    width: f32,
+    height: f32,
+    viewport: Rect,
+    options: PositionOptions,
+) -> Placement {
+    let side = resolve_side(anchor, width, height, viewport, options);
+    let main = main_axis_origin(anchor, width, height, side, options.offset);
+    let (anchor_start, anchor_len, len, view_start, view_len) = if side.is_vertical() {
+        (anchor.x, anchor.width, width, viewport.x, viewport.width)
+    } else {
+        (anchor.y, anchor.height, height, viewport.y, viewport.height)
+    };
+    let aligned =
+        cross_axis_origin(anchor_start, anchor_len, len, options.align) + options.cross_offset;
+    let mut cross = aligned;
+    if options.shift {
+        let min = view_start + options.margin;
+        let max = (view_start + view_len - options.margin - len).max(min);
+        cross = cross.clamp(min, max);
+    }
+
+    let anchor_center = anchor_start + anchor_len / 2.0;
+    let padding = options.arrow_padding.min(len / 2.0);
+    let arrow = (anchor_center - cross).clamp(padding, (len - padding).max(padding));
+    let (x, y) = if side.is_vertical() {
+        (cross, main)
+    } else {
+        (main, cross)
+    };
+    Placement {
+        side,
+        x,
+        y,
+        arrow,
+        flipped: side != options.side,
+        shifted: (cross - aligned).abs() > f32::EPSILON,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    };
+
+    #[test]
+    fn positioning_places_on_preferred_side_with_alignment_and_offset() {
+        let anchor = Rect::new(100.0, 100.0, 80.0, 30.0);
+        let options = PositionOptions::new(Side::Bottom).offset(4.0);
+        let placement = compute(anchor, 120.0, 60.0, VIEWPORT, options);
+        assert_eq!(placement.side, Side::Bottom);
+        assert_eq!((placement.x, placement.y), (100.0, 134.0));
+        assert!(!placement.flipped && !placement.shifted);
+
+        let centered = compute(anchor, 120.0, 60.0, VIEWPORT, options.align(Align::Center));
+        assert_eq!(centered.x, 80.0);
+        assert_eq!(centered.arrow, 60.0);
+
+        let end = compute(
+            anchor,
+            40.0,
+            20.0,
+            VIEWPORT,
+            PositionOptions::new(Side::Right).align(Align::End),
+        );
+        assert_eq!((end.x, end.y), (180.0, 110.0));
+    }
+
+    #[test]
+    fn positioning_flips_near_window_edges() {
+        let anchor = Rect::new(100.0, 560.0, 80.0, 30.0);
+        let placement = compute(
+            anchor,
+            120.0,
+            200.0,
+            VIEWPORT,
+            PositionOptions::new(Side::Bottom).offset(4.0),
+        );
+        assert_eq!(placement.side, Side::Top);
+        assert!(placement.flipped);
+        assert_eq!(placement.y, 356.0);
+
+        let pinned = compute(
+            anchor,
+            120.0,
+            200.0,
+            VIEWPORT,
+            PositionOptions::new(Side::Bottom).flip(false),
+        );
+        assert_eq!(pinned.side, Side::Bottom);
+
+        let edge = Rect::new(760.0, 100.0, 30.0, 20.0);
+        let side = resolve_side(
+            edge,
+            160.0,
+            40.0,
+            VIEWPORT,
+            PositionOptions::new(Side::Right),
+        );
+        assert_eq!(side, Side::Left);
+    }
+
+    #[test]
+    fn positioning_shifts_into_view_and_keeps_arrow_on_anchor() {
+        let anchor = Rect::new(760.0, 100.0, 30.0, 20.0);
+        let placement = compute(
+            anchor,
+            200.0,
+            40.0,
+            VIEWPORT,
+            PositionOptions::new(Side::Bottom).margin(8.0),
+        );
+        assert!(placement.shifted);
+        assert_eq!(placement.x, 592.0);
+        assert_eq!(placement.arrow, 183.0);
+
+        let far = Rect::new(790.0, 100.0, 10.0, 20.0);
+        let clamped = compute(
+            far,
+            100.0,
+            40.0,
+            VIEWPORT,
+            PositionOptions::new(Side::Bottom).arrow_padding(12.0),
+        );
+        assert_eq!(clamped.arrow, 88.0);
+    }
+}
@@ -1,33 +1,85 @@
-pub use crate::CalmProvider;
+pub mod forms {
+    pub use crate::contracts::{Disableable, FieldLike, Radiused, Sized, Varianted};
+    #[cfg(feature = "forms")]
+    pub use crate::form::{
+        AsyncFieldValidator, BoxedSubmitFuture, BoxedValidationFuture, ComposedLens, FieldKey,
+        FieldLens, FieldMeta, FieldOptions, FieldPath, FieldRule, FieldRuleKind,
+        FieldRuleViolation, FieldValidator, FormController, FormDraftStore, FormError, FormId,
+        FormModel, FormOptions, FormResult, FormSnapshot, FormState, FormSubmit, FormValidator,
+        InMemoryDraftStore, NestedFormModel, RevalidateMode, RuleValue, SubmitState, TemporalValue,
+        ValidationError, ValidationMode, ValidationTicket,
+    };
+    pub use crate::style::FieldLayout;
+    pub use crate::theme::{
+        ActionIconOverrides, ActionIconTokens, ButtonOverrides, ButtonTokens, CheckboxOverrides,
+        CheckboxTokens, ChipOverrides, ChipTokens, ColorPickerOverrides, ColorPickerTokens,
+        DatePickerOverrides, DatePickerTokens, FileInputOverrides, FileInputTokens, InputOverrides,
+        InputTokens, NumberInputOverrides, NumberInputTokens, PaginationOverrides,
+        PaginationTokens, RadioOverrides, RadioTokens, RangeSliderOverrides, RangeSliderTokens,
+        RatingOverrides, RatingTokens, SegmentedControlOverrides, SegmentedControlTokens,
+        SelectOverrides, SelectTokens, SliderOverrides, SliderTokens, SwitchOverrides,
+        SwitchTokens, TextareaOverrides, TextareaTokens,
+    };
+    pub use crate::widgets::form::*;
+}
+
+pub mod overlays {
+    pub use crate::contracts::{Openable, Visible};
+    #[cfg(feature = "forms")]
+    pub use crate::dialogs::PromptFuture;
+    pub use crate::dialogs::{self, ConfirmFuture};
+    pub use crate::feedback::{
+        NotificationEntry, NotificationFilter, NotificationId, NotificationInbox, TaskEntry,
+        TaskHandle, TaskId, TaskManager, TaskStatus, ToastAction, ToastEntry, ToastId, ToastKind,
+        ToastManager, ToastPosition,
+    };
+    pub use crate::overlay::positioning::{Align, Placement, PositionOptions, Side};
+    pub use crate::overlay::{
+        ManagedModal, ModalCloseReason, ModalId, ModalKind, ModalManager, ModalResult,
+        ModalResultFuture, ModalStateChange,
+    };
+    pub use crate::theme::{
+        AsyncContentOverrides, AsyncContentTokens, ChecklistOverrides, ChecklistTokens,
+        DrawerOverrides, DrawerTokens, FindBarOverrides, FindBarTokens, HoverCardOverrides,
+        HoverCardTokens, LoadingOverlayOverrides, LoadingOverlayTokens, MenuOverrides, MenuTokens,
+        ModalOverrides, ModalTokens, NotificationCenterOverrides, NotificationCenterTokens,
+        OverlayOverrides, OverlayTokens, PopoverOverrides, PopoverTokens, TaskStatusOverrides,
+        TaskStatusTokens, ToastOverrides, ToastTokens, TooltipOverrides, TooltipTokens,
+    };
+    pub use crate::widgets::feedback::*;
+    pub use crate::widgets::overlay::*;
+}
+
+pub mod data {
+    pub use crate::theme::{
+        ProgressOverrides, ProgressTokens, TableOverrides, TableTokens, TreeOverrides, TreeTokens,
+    };
+    pub use crate::widgets::data::*;
+    pub use crate::widgets::navigation::{
+        Tree, TreeDropEvent, TreeDropPosition, TreeNode, TreeTogglePosition,
+    };
+}
+
 pub use crate::contracts::{
     ComponentThemeOverridable, Disableable, FieldLike, MotionAware, Openable, Radiused, Sized,
     Varianted, Visible, WithId,
 };
-pub use crate::form::{
-    AsyncFieldValidator, FieldKey, FieldLens, FieldMeta, FieldValidator, FormController,
-    FormDraftStore, FormError, FormId, FormModel, FormOptions, FormResult, FormSnapshot,
-    FormValidator, InMemoryDraftStore, RevalidateMode, SubmitState, ValidationError,
-    ValidationMode, ValidationTicket,
-};
+pub use crate::focus::{FocusCoordinator, FocusOrientation};
+pub use crate::hooks::*;
 pub use crate::id::{ComponentId, IdCtx};
+pub use crate::interaction::{InteractionAction, InteractionEvent, InteractionObserver};
+pub use crate::resource::{AsyncState, Resource, ResourceCache};
+pub use crate::shortcuts::{KeyChord, ShortcutRegistry, ShortcutScope};
 pub use crate::style::{FieldLayout, Radius, Size, Variant};
-pub use crate::widgets::{
-    Accordion, AccordionItem, AccordionItemMeta, ActionIcon, Alert, AlertKind, AppShell, Badge,
-    BreadcrumbItem, Breadcrumbs, Button, ButtonGroup, ButtonGroupItem, Checkbox, CheckboxGroup,
-    CheckboxOption, Chip, ChipGroup, ChipOption, ChipSelectionMode, Divider, DividerLabelPosition,
-    Drawer, DrawerPlacement, Grid, HoverCard, HoverCardPlacement, Icon, Indicator,
-    IndicatorPosition, Loader, LoaderElement, LoaderVariant, LoadingOverlay, Markdown, Menu,
-    MenuItem, Modal, ModalLayer, MultiSelect, NumberInput, Overlay, OverlayCoverage,
-    OverlayMaterialMode, Pagination, PaneChrome, PanelMode, Paper, PasswordInput, PinInput,
-    Popover, PopoverPlacement, Progress, ProgressSection, Radio, RadioGroup, RadioOption,
-    RangeSlider, Rating, ScrollArea, SegmentedControl, SegmentedControlItem, Select, SelectOption,
-    Sidebar, SidebarMode, SimpleGrid, Slider, Space, Stack, Stepper, StepperContentPosition,
-    StepperStep, Switch, SwitchLabelPosition, TabItem, Table, TableAlign, TableCell,
-    TablePaginationPosition, TableRow, TableSort, TableSortDirection, Tabs, Text, TextInput,
-    TextTone, Textarea, Timeline, TimelineItem, Title, TitleBar, ToastEntry, ToastKind, ToastLayer,
-    ToastManager, ToastPosition, ToastViewport, Tooltip, TooltipPlacement, Tree, TreeNode,
-    TreeTogglePosition,
-};
+pub use crate::widgets::display::*;
+pub use crate::widgets::layout::*;
+pub use crate::widgets::navigation::*;
+pub use crate::{CalmProvider, CalmProviderBuilder};
+pub use data::*;
+pub use forms::*;
+pub use overlays::*;
 
+#[cfg(feature = "spellcheck")]
+pub use crate::spellcheck::{SpellChecker, WordListDictionary};
 #[cfg(feature = "i18n")]
 pub use crate::{I18nManager, Locale};
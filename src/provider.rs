@@ -1,17 +1,125 @@
-use crate::feedback::ToastManager;
+use crate::feedback::{NotificationInbox, TaskManager, ToastManager, ToastViewport};
+use crate::focus::FocusCoordinator;
+use crate::interaction::InteractionObserver;
+use crate::motion::MotionLevel;
 use crate::overlay::ModalManager;
-use crate::theme::Theme;
+use crate::shortcuts::ShortcutRegistry;
+use crate::theme::{
+    BlendTokens, ColorScheme, ColorSchemePreference, ContrastLevel, Theme, ThemeTransition,
+};
 #[cfg(feature = "i18n")]
 use crate::{I18nManager, Locale};
-use std::sync::Arc;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+type SchemeThemeBuilder = Arc<dyn Fn(ColorScheme) -> Theme + Send + Sync>;
+
+const THEME_TRANSITION_FRAME_MS: u64 = 16;
+
+struct ThemeCrossfade {
+    from: Arc<Theme>,
+    started: Instant,
+    transition: ThemeTransition,
+    frame: Option<(u32, Arc<Theme>)>,
+}
+
+impl ThemeCrossfade {
+    fn progress(&self, now: Instant) -> f32 {
+        let elapsed_ms = now.saturating_duration_since(self.started).as_secs_f32() * 1000.0;
+        (elapsed_ms / f32::from(self.transition.duration_ms.max(1))).clamp(0.0, 1.0)
+    }
+
+    fn theme_at(&mut self, to: &Theme, now: Instant) -> Option<Arc<Theme>> {
+        let progress = self.progress(now);
+        if progress >= 1.0 {
+            return None;
+        }
+        let step = (progress * 1000.0) as u32;
+        if let Some((cached_step, theme)) = self.frame.as_ref()
+            && *cached_step == step
+        {
+            return Some(theme.clone());
+        }
+        let theme = Arc::new(self.from.blend(to, self.transition.easing.apply(progress)));
+        self.frame = Some((step, theme.clone()));
+        Some(theme)
+    }
+}
+
+/// Typed subsystem handles keyed by their type; each handle is a cheap clone
+/// of shared state, so lookups hand out copies.
 #[derive(Default)]
+struct ServiceRegistry {
+    services: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl ServiceRegistry {
+    fn with_builtins() -> Self {
+        let mut registry = Self::default();
+        registry.insert(ToastManager::default());
+        registry.insert(TaskManager::default());
+        registry.insert(ModalManager::default());
+        registry.insert(FocusCoordinator::default());
+        registry.insert(ShortcutRegistry::default());
+        #[cfg(feature = "i18n")]
+        registry.insert(I18nManager::default());
+        registry
+    }
+
+    fn insert<T: Clone + 'static>(&mut self, service: T) {
+        self.services.insert(TypeId::of::<T>(), Box::new(service));
+    }
+
+    fn get<T: Clone + 'static>(&self) -> Option<T> {
+        self.services
+            .get(&TypeId::of::<T>())
+            .and_then(|service| service.downcast_ref::<T>())
+            .cloned()
+    }
+
+    fn builtin<T: Clone + 'static>(&self) -> T {
+        self.get::<T>()
+            .expect("built-in provider services are registered on construction")
+    }
+}
+
 pub struct CalmProvider {
     theme: Arc<Theme>,
-    toast_manager: ToastManager,
-    modal_manager: ModalManager,
-    #[cfg(feature = "i18n")]
-    i18n: I18nManager,
+    color_scheme_preference: Option<ColorSchemePreference>,
+    scheme_theme: Option<SchemeThemeBuilder>,
+    contrast_level: Option<ContrastLevel>,
+    theme_transition: ThemeTransition,
+    motion_level: MotionLevel,
+    min_hit_target: Option<gpui::Pixels>,
+    press_feedback_disabled: bool,
+    interaction_observer: Option<Arc<dyn InteractionObserver>>,
+    crossfade: Mutex<Option<ThemeCrossfade>>,
+    crossfade_frames: Arc<AtomicBool>,
+    window_toast_managers: Mutex<HashMap<gpui::WindowId, ToastManager>>,
+    services: ServiceRegistry,
+}
+
+impl Default for CalmProvider {
+    fn default() -> Self {
+        Self {
+            theme: Arc::default(),
+            color_scheme_preference: None,
+            scheme_theme: None,
+            contrast_level: None,
+            theme_transition: ThemeTransition::default(),
+            motion_level: MotionLevel::default(),
+            min_hit_target: None,
+            press_feedback_disabled: false,
+            interaction_observer: None,
+            crossfade: Mutex::default(),
+            crossfade_frames: Arc::default(),
+            window_toast_managers: Mutex::default(),
+            services: ServiceRegistry::with_builtins(),
+        }
+    }
 }
 
 impl gpui::Global for CalmProvider {}
@@ -21,35 +129,703 @@ impl CalmProvider {
         Self::default()
     }
 
+    /// Starts a provider whose subsystems are registered explicitly; anything
+    /// left unregistered falls back to a default instance.
+    pub fn builder() -> CalmProviderBuilder {
+        CalmProviderBuilder::default()
+    }
+
     pub fn set_theme(mut self, configure: impl FnOnce(Arc<Theme>) -> Theme) -> Self {
         self.theme = configure(self.theme).into();
         self
     }
 
+    pub fn set_theme_for_scheme(
+        mut self,
+        build: impl Fn(ColorScheme) -> Theme + Send + Sync + 'static,
+    ) -> Self {
+        self.scheme_theme = Some(Arc::new(build));
+        self
+    }
+
+    pub fn set_color_scheme_preference(mut self, preference: ColorSchemePreference) -> Self {
+        self.color_scheme_preference = Some(preference);
+        self
+    }
+
+    /// Derives the theme at `level`, including themes rebuilt when the
+    /// color scheme changes.
+    pub fn set_contrast_level(mut self, level: ContrastLevel) -> Self {
+        self.contrast_level = Some(level);
+        self
+    }
+
+    pub fn set_theme_transition(mut self, transition: ThemeTransition) -> Self {
+        self.theme_transition = transition;
+        self
+    }
+
+    pub fn set_motion_level(mut self, level: MotionLevel) -> Self {
+        self.motion_level = level;
+        self
+    }
+
+    pub fn set_min_hit_target(mut self, value: impl Into<gpui::Pixels>) -> Self {
+        self.min_hit_target = Some(value.into());
+        self
+    }
+
+    pub fn set_press_feedback(mut self, enabled: bool) -> Self {
+        self.press_feedback_disabled = !enabled;
+        self
+    }
+
+    pub fn set_interaction_observer(
+        mut self,
+        observer: impl InteractionObserver + 'static,
+    ) -> Self {
+        self.interaction_observer = Some(Arc::new(observer));
+        self
+    }
+
     #[cfg(feature = "i18n")]
     pub fn set_i18n_locale(self, locale: impl Into<Locale>) -> Self {
-        self.i18n.set_locale(locale);
+        self.services.builtin::<I18nManager>().set_locale(locale);
         self
     }
 
-    pub fn init(self, cx: &mut gpui::App) {
+    /// Registers a typed handle, replacing any previous one of the same type,
+    /// including the built-in managers.
+    pub fn register_service<T: Clone + 'static>(mut self, service: T) -> Self {
+        self.services.insert(service);
+        self
+    }
+
+    /// Looks up a handle on a provider that is not installed yet.
+    pub fn get<T: Clone + 'static>(&self) -> Option<T> {
+        self.services.get::<T>()
+    }
+
+    pub fn init(mut self, cx: &mut gpui::App) {
+        let force = self.scheme_theme.is_some();
+        if !self.sync_color_scheme(cx.window_appearance().into(), force) {
+            self.theme = self.with_contrast_level(self.theme.as_ref().clone()).into();
+        }
         cx.set_global(self);
     }
 
     pub fn theme(cx: &gpui::App) -> Arc<Theme> {
-        cx.global::<CalmProvider>().theme.clone()
+        cx.global::<CalmProvider>().visible_theme(Instant::now())
+    }
+
+    pub fn update_theme(configure: impl FnOnce(Arc<Theme>) -> Theme, cx: &mut gpui::App) {
+        let changed = cx.update_global::<CalmProvider, _>(|provider, _| {
+            let next = configure(provider.theme.clone());
+            provider.replace_theme(next, Instant::now())
+        });
+        if changed {
+            Self::animate_theme_transition(cx);
+            cx.refresh_windows();
+        }
+    }
+
+    pub fn theme_transition(cx: &gpui::App) -> ThemeTransition {
+        cx.global::<CalmProvider>().theme_transition
+    }
+
+    pub fn update_theme_transition(transition: ThemeTransition, cx: &mut gpui::App) {
+        cx.update_global::<CalmProvider, _>(|provider, _| {
+            provider.theme_transition = transition;
+            if !transition.animates(provider.motion_level) {
+                provider.finish_crossfade();
+            }
+        });
+    }
+
+    pub fn motion_level(cx: &gpui::App) -> MotionLevel {
+        cx.global::<CalmProvider>().motion_level
+    }
+
+    pub fn update_motion_level(level: MotionLevel, cx: &mut gpui::App) {
+        cx.update_global::<CalmProvider, _>(|provider, _| {
+            provider.motion_level = level;
+            if !provider.theme_transition.animates(level) {
+                provider.finish_crossfade();
+            }
+        });
+        cx.refresh_windows();
+    }
+
+    pub fn min_hit_target(cx: &gpui::App) -> Option<gpui::Pixels> {
+        cx.global::<CalmProvider>().min_hit_target
+    }
+
+    pub fn update_min_hit_target(value: Option<gpui::Pixels>, cx: &mut gpui::App) {
+        cx.update_global::<CalmProvider, _>(|provider, _| {
+            provider.min_hit_target = value;
+        });
+        cx.refresh_windows();
+    }
+
+    pub fn press_feedback(cx: &gpui::App) -> bool {
+        cx.global::<CalmProvider>().press_feedback_active()
+    }
+
+    pub fn update_press_feedback(enabled: bool, cx: &mut gpui::App) {
+        cx.update_global::<CalmProvider, _>(|provider, _| {
+            provider.press_feedback_disabled = !enabled;
+        });
+        cx.refresh_windows();
+    }
+
+    pub fn contrast_level(cx: &gpui::App) -> ContrastLevel {
+        cx.global::<CalmProvider>().theme.seed.contrast
+    }
+
+    pub fn update_contrast_level(level: ContrastLevel, cx: &mut gpui::App) {
+        let changed = cx.update_global::<CalmProvider, _>(|provider, _| {
+            provider.contrast_level = Some(level);
+            let next = provider.with_contrast_level(provider.theme.as_ref().clone());
+            provider.replace_theme(next, Instant::now())
+        });
+        if changed {
+            Self::animate_theme_transition(cx);
+            cx.refresh_windows();
+        }
+    }
+
+    pub fn is_theme_transitioning(cx: &gpui::App) -> bool {
+        cx.global::<CalmProvider>()
+            .crossfade_running(Instant::now())
+    }
+
+    pub fn color_scheme_preference(cx: &gpui::App) -> Option<ColorSchemePreference> {
+        cx.global::<CalmProvider>().color_scheme_preference
+    }
+
+    pub fn update_color_scheme_preference(preference: ColorSchemePreference, cx: &mut gpui::App) {
+        let system = cx.window_appearance().into();
+        let changed = cx.update_global::<CalmProvider, _>(|provider, _| {
+            provider.color_scheme_preference = Some(preference);
+            provider.sync_color_scheme(system, false)
+        });
+        if changed {
+            Self::animate_theme_transition(cx);
+            cx.refresh_windows();
+        }
+    }
+
+    pub fn observe_system_color_scheme(window: &gpui::Window) -> gpui::Subscription {
+        window.observe_window_appearance(|window, cx| {
+            let system = window.appearance().into();
+            let changed = cx.update_global::<CalmProvider, _>(|provider, _| {
+                provider.sync_color_scheme(system, false)
+            });
+            if changed {
+                Self::animate_theme_transition(cx);
+                cx.refresh_windows();
+            }
+        })
+    }
+
+    /// Looks up a handle registered with [`CalmProviderBuilder::service`] or
+    /// one of the built-in managers.
+    pub fn service<T: Clone + 'static>(cx: &gpui::App) -> Option<T> {
+        cx.global::<CalmProvider>().get::<T>()
+    }
+
+    pub fn update_service<T: Clone + 'static>(service: T, cx: &mut gpui::App) {
+        cx.update_global::<CalmProvider, _>(|provider, _| {
+            provider.services.insert(service);
+        });
     }
 
     pub fn toast(cx: &gpui::App) -> ToastManager {
-        cx.global::<CalmProvider>().toast_manager.clone()
+        cx.global::<CalmProvider>().services.builtin()
+    }
+
+    pub fn window_toast(window: &gpui::Window, cx: &gpui::App) -> ToastManager {
+        let provider = cx.global::<CalmProvider>();
+        provider
+            .window_toast_managers
+            .lock()
+            .expect("window toast managers poisoned")
+            .entry(window.window_handle().window_id())
+            .or_insert_with(|| {
+                let shared = provider.services.builtin::<ToastManager>();
+                ToastManager::with_inbox(shared.inbox())
+            })
+            .clone()
+    }
+
+    pub fn release_window_toast(window_id: gpui::WindowId, cx: &gpui::App) -> bool {
+        cx.global::<CalmProvider>()
+            .window_toast_managers
+            .lock()
+            .expect("window toast managers poisoned")
+            .remove(&window_id)
+            .is_some()
+    }
+
+    pub fn notifications(cx: &gpui::App) -> NotificationInbox {
+        Self::toast(cx).inbox()
+    }
+
+    pub fn tasks(cx: &gpui::App) -> TaskManager {
+        cx.global::<CalmProvider>().services.builtin()
     }
 
     pub fn modal(cx: &gpui::App) -> ModalManager {
-        cx.global::<CalmProvider>().modal_manager.clone()
+        cx.global::<CalmProvider>().services.builtin()
+    }
+
+    pub fn interaction_observer(cx: &gpui::App) -> Option<Arc<dyn InteractionObserver>> {
+        cx.global::<CalmProvider>().interaction_observer.clone()
+    }
+
+    pub fn update_interaction_observer(
+        observer: Option<Arc<dyn InteractionObserver>>,
+        cx: &mut gpui::App,
+    ) {
+        cx.update_global::<CalmProvider, _>(|provider, _| {
+            provider.interaction_observer = observer;
+        });
+    }
+
+    pub fn focus(cx: &gpui::App) -> FocusCoordinator {
+        cx.global::<CalmProvider>().services.builtin()
+    }
+
+    pub fn shortcuts(cx: &gpui::App) -> ShortcutRegistry {
+        cx.global::<CalmProvider>().services.builtin()
     }
 
     #[cfg(feature = "i18n")]
     pub fn i18n(cx: &gpui::App) -> I18nManager {
-        cx.global::<CalmProvider>().i18n.clone()
+        cx.global::<CalmProvider>().services.builtin()
+    }
+
+    fn sync_color_scheme(&mut self, system: ColorScheme, force: bool) -> bool {
+        let Some(preference) = self.color_scheme_preference else {
+            return false;
+        };
+        let scheme = preference.resolve(system);
+        if self.theme.color_scheme == scheme && !force {
+            return false;
+        }
+        let next = match self.scheme_theme.as_ref() {
+            Some(build) => build(scheme),
+            None => {
+                // Switching scheme derives every token again, so carry the
+                // app's changes to the derived tokens over to the new scheme.
+                let current = self.theme.as_ref().clone();
+                let overrides = current
+                    .clone()
+                    .with_color_scheme(current.color_scheme)
+                    .diff(&current);
+                current.with_color_scheme(scheme).merged(&overrides)
+            }
+        };
+        let next = self.with_contrast_level(next);
+        self.replace_theme(next, Instant::now());
+        true
+    }
+
+    fn with_contrast_level(&self, theme: Theme) -> Theme {
+        match self.contrast_level {
+            Some(level) if theme.seed.contrast != level => theme.with_contrast(level),
+            _ => theme,
+        }
+    }
+
+    fn replace_theme(&mut self, next: Theme, now: Instant) -> bool {
+        if *self.theme == next {
+            return false;
+        }
+        let crossfade = self
+            .theme_transition
+            .animates(self.motion_level)
+            .then(|| ThemeCrossfade {
+                from: self.visible_theme(now),
+                started: now,
+                transition: self.theme_transition,
+                frame: None,
+            });
+        *self.crossfade.get_mut().expect("theme crossfade poisoned") = crossfade;
+        self.theme = next.into();
+        true
+    }
+
+    fn visible_theme(&self, now: Instant) -> Arc<Theme> {
+        let mut crossfade = self.crossfade.lock().expect("theme crossfade poisoned");
+        match crossfade
+            .as_mut()
+            .and_then(|crossfade| crossfade.theme_at(&self.theme, now))
+        {
+            Some(theme) => theme,
+            None => {
+                *crossfade = None;
+                self.theme.clone()
+            }
+        }
+    }
+
+    fn press_feedback_active(&self) -> bool {
+        !self.press_feedback_disabled && self.motion_level == MotionLevel::Full
+    }
+
+    fn crossfade_running(&self, now: Instant) -> bool {
+        self.crossfade
+            .lock()
+            .expect("theme crossfade poisoned")
+            .as_ref()
+            .is_some_and(|crossfade| crossfade.progress(now) < 1.0)
+    }
+
+    fn finish_crossfade(&mut self) {
+        *self.crossfade.get_mut().expect("theme crossfade poisoned") = None;
+    }
+
+    fn animate_theme_transition(cx: &mut gpui::App) {
+        let frames = cx.global::<CalmProvider>().crossfade_frames.clone();
+        if !Self::is_theme_transitioning(cx) || frames.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        cx.spawn(async move |cx| {
+            loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(THEME_TRANSITION_FRAME_MS))
+                    .await;
+                let running = cx.update(|cx| {
+                    cx.refresh_windows();
+                    Self::is_theme_transitioning(cx)
+                });
+                if !matches!(running, Ok(true)) {
+                    break;
+                }
+            }
+            frames.store(false, Ordering::Release);
+        })
+        .detach();
+    }
+}
+
+/// Collects subsystem registrations before installing the provider as a gpui
+/// global.
+#[derive(Default)]
+pub struct CalmProviderBuilder {
+    provider: CalmProvider,
+}
+
+impl CalmProviderBuilder {
+    pub fn theme(mut self, configure: impl FnOnce(Arc<Theme>) -> Theme) -> Self {
+        self.provider = self.provider.set_theme(configure);
+        self
+    }
+
+    pub fn theme_for_scheme(
+        mut self,
+        build: impl Fn(ColorScheme) -> Theme + Send + Sync + 'static,
+    ) -> Self {
+        self.provider = self.provider.set_theme_for_scheme(build);
+        self
+    }
+
+    pub fn color_scheme_preference(mut self, preference: ColorSchemePreference) -> Self {
+        self.provider = self.provider.set_color_scheme_preference(preference);
+        self
+    }
+
+    pub fn contrast_level(mut self, level: ContrastLevel) -> Self {
+        self.provider = self.provider.set_contrast_level(level);
+        self
+    }
+
+    pub fn theme_transition(mut self, transition: ThemeTransition) -> Self {
+        self.provider = self.provider.set_theme_transition(transition);
+        self
+    }
+
+    pub fn motion_level(mut self, level: MotionLevel) -> Self {
+        self.provider = self.provider.set_motion_level(level);
+        self
+    }
+
+    pub fn min_hit_target(mut self, value: impl Into<gpui::Pixels>) -> Self {
+        self.provider = self.provider.set_min_hit_target(value);
+        self
+    }
+
+    pub fn press_feedback(mut self, enabled: bool) -> Self {
+        self.provider = self.provider.set_press_feedback(enabled);
+        self
+    }
+
+    /// Registers the toast manager along with the viewports its stacks use.
+    pub fn toasts(
+        self,
+        manager: ToastManager,
+        viewports: impl IntoIterator<Item = ToastViewport>,
+    ) -> Self {
+        for viewport in viewports {
+            manager.configure_viewport(viewport);
+        }
+        self.service(manager)
+    }
+
+    pub fn tasks(self, manager: TaskManager) -> Self {
+        self.service(manager)
+    }
+
+    pub fn modals(self, manager: ModalManager) -> Self {
+        self.service(manager)
+    }
+
+    pub fn focus(self, coordinator: FocusCoordinator) -> Self {
+        self.service(coordinator)
+    }
+
+    pub fn shortcuts(self, registry: ShortcutRegistry) -> Self {
+        self.service(registry)
+    }
+
+    #[cfg(feature = "i18n")]
+    pub fn i18n(self, manager: I18nManager, locale: impl Into<Locale>) -> Self {
+        manager.set_locale(locale);
+        self.service(manager)
+    }
+
+    /// Receives every [`crate::interaction::InteractionEvent`] components emit.
+    pub fn telemetry(mut self, observer: impl InteractionObserver + 'static) -> Self {
+        self.provider = self.provider.set_interaction_observer(observer);
+        self
+    }
+
+    /// Registers an application-defined subsystem, such as a persistence
+    /// store, for lookup through [`CalmProvider::service`].
+    pub fn service<T: Clone + 'static>(mut self, service: T) -> Self {
+        self.provider = self.provider.register_service(service);
+        self
+    }
+
+    pub fn build(self) -> CalmProvider {
+        self.provider
+    }
+
+    pub fn init(self, cx: &mut gpui::App) {
+        self.build().init(cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CalmProvider;
+    use crate::feedback::ToastManager;
+    use crate::motion::{Easing, MotionLevel};
+    use crate::overlay::ModalManager;
+    use crate::theme::{
+        ColorScheme, ColorSchemePreference, ContrastLevel, Theme, ThemeOverrides, ThemeTransition,
+    };
+    use crate::tokens::PaletteKey;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn system_preference_rebuilds_theme_when_appearance_flips() {
+        let mut provider =
+            CalmProvider::new().set_color_scheme_preference(ColorSchemePreference::System);
+
+        assert!(!provider.sync_color_scheme(ColorScheme::Light, false));
+        assert!(provider.sync_color_scheme(ColorScheme::Dark, false));
+        assert_eq!(provider.theme.color_scheme, ColorScheme::Dark);
+        assert!(!provider.sync_color_scheme(ColorScheme::Dark, false));
+    }
+
+    #[test]
+    fn explicit_preference_ignores_system_appearance() {
+        let mut provider =
+            CalmProvider::new().set_color_scheme_preference(ColorSchemePreference::Light);
+        assert!(!provider.sync_color_scheme(ColorScheme::Dark, false));
+
+        let mut untouched = CalmProvider::new();
+        assert!(!untouched.sync_color_scheme(ColorScheme::Dark, true));
+        assert_eq!(untouched.theme.color_scheme, ColorScheme::Light);
+    }
+
+    #[test]
+    fn scheme_theme_builder_is_used_for_rebuilds() {
+        let mut provider = CalmProvider::new()
+            .set_color_scheme_preference(ColorSchemePreference::System)
+            .set_theme_for_scheme(|scheme| {
+                Theme::default()
+                    .with_primary_color(PaletteKey::Teal)
+                    .with_color_scheme(scheme)
+            });
+
+        assert!(provider.sync_color_scheme(ColorScheme::Dark, false));
+        assert_eq!(provider.theme.color_scheme, ColorScheme::Dark);
+        assert_eq!(provider.theme.primary_color, PaletteKey::Teal);
+    }
+
+    #[test]
+    fn contrast_level_survives_scheme_rebuilds() {
+        let mut provider = CalmProvider::new()
+            .set_color_scheme_preference(ColorSchemePreference::System)
+            .set_theme_for_scheme(|scheme| Theme::default().with_color_scheme(scheme))
+            .set_contrast_level(ContrastLevel::High);
+
+        assert!(provider.sync_color_scheme(ColorScheme::Dark, false));
+        assert_eq!(provider.theme.seed.contrast, ContrastLevel::High);
+        assert_eq!(
+            provider.theme.semantic,
+            Theme::default()
+                .with_color_scheme(ColorScheme::Dark)
+                .with_contrast(ContrastLevel::High)
+                .semantic
+        );
+
+        provider.contrast_level = Some(ContrastLevel::Standard);
+        let standard = provider.with_contrast_level(provider.theme.as_ref().clone());
+        assert!(provider.replace_theme(standard, Instant::now()));
+        assert_eq!(
+            provider.theme.semantic,
+            Theme::default()
+                .with_color_scheme(ColorScheme::Dark)
+                .semantic
+        );
+    }
+
+    #[test]
+    fn theme_overrides_survive_scheme_round_trip() {
+        let accent = gpui::red();
+        let mut provider = CalmProvider::new()
+            .set_color_scheme_preference(ColorSchemePreference::System)
+            .set_theme(|theme| {
+                theme.merged(
+                    &ThemeOverrides::default()
+                        .primary_color(PaletteKey::Teal)
+                        .button(|button| button.filled_bg(accent)),
+                )
+            });
+
+        assert!(provider.sync_color_scheme(ColorScheme::Dark, false));
+        assert_eq!(provider.theme.color_scheme, ColorScheme::Dark);
+        assert_eq!(provider.theme.primary_color, PaletteKey::Teal);
+        assert_eq!(provider.theme.components.button.filled_bg, accent);
+        assert_eq!(
+            provider.theme.semantic,
+            Theme::default()
+                .with_primary_color(PaletteKey::Teal)
+                .with_color_scheme(ColorScheme::Dark)
+                .semantic
+        );
+
+        assert!(provider.sync_color_scheme(ColorScheme::Light, false));
+        assert_eq!(provider.theme.components.button.filled_bg, accent);
+        assert_eq!(
+            provider.theme.components.button.light_fg,
+            Theme::default()
+                .with_primary_color(PaletteKey::Teal)
+                .components
+                .button
+                .light_fg
+        );
+    }
+
+    #[test]
+    fn press_feedback_respects_toggle_and_reduced_motion() {
+        assert!(CalmProvider::new().press_feedback_active());
+        assert!(
+            !CalmProvider::new()
+                .set_press_feedback(false)
+                .press_feedback_active()
+        );
+        assert!(
+            !CalmProvider::new()
+                .set_motion_level(MotionLevel::Reduced)
+                .press_feedback_active()
+        );
+    }
+
+    #[test]
+    fn interaction_observer_is_opt_in() {
+        assert!(CalmProvider::new().interaction_observer.is_none());
+        let provider = CalmProvider::new()
+            .set_interaction_observer(|_: &crate::interaction::InteractionEvent| {});
+        assert!(provider.interaction_observer.is_some());
+    }
+
+    #[test]
+    fn builder_registers_subsystems_and_typed_services() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct LayoutStore(&'static str);
+
+        let modals = ModalManager::new();
+        let opened = modals.open_modal(crate::components::Modal::confirm("Quit", "Quit now?"));
+        let provider = CalmProvider::builder()
+            .modals(modals.clone())
+            .service(LayoutStore("workspace.json"))
+            .motion_level(MotionLevel::Reduced)
+            .build();
+
+        let registered = provider.get::<ModalManager>().expect("modal manager");
+        assert!(registered.is_top(opened.id()));
+        assert_eq!(
+            provider.get::<LayoutStore>(),
+            Some(LayoutStore("workspace.json"))
+        );
+        assert!(provider.get::<ToastManager>().is_some());
+        assert!(provider.get::<String>().is_none());
+        assert_eq!(provider.motion_level, MotionLevel::Reduced);
+
+        let replaced = CalmProvider::new().register_service(LayoutStore("a"));
+        let replaced = replaced.register_service(LayoutStore("b"));
+        assert_eq!(replaced.get::<LayoutStore>(), Some(LayoutStore("b")));
+    }
+
+    #[test]
+    fn min_hit_target_is_opt_in() {
+        assert_eq!(CalmProvider::new().min_hit_target, None);
+        let provider = CalmProvider::new().set_min_hit_target(gpui::px(32.0));
+        assert_eq!(provider.min_hit_target, Some(gpui::px(32.0)));
+    }
+
+    #[test]
+    fn theme_transition_crossfades_between_resolved_colors() {
+        let mut provider = CalmProvider::new()
+            .set_theme_transition(ThemeTransition::crossfade(200).easing(Easing::Linear));
+        let light = provider.theme.clone();
+        let dark = Theme::default().with_color_scheme(ColorScheme::Dark);
+        let start = Instant::now();
+
+        assert!(provider.replace_theme(dark.clone(), start));
+        assert!(!provider.replace_theme(dark.clone(), start));
+        assert_eq!(provider.visible_theme(start).semantic, light.semantic);
+
+        let midway = provider.visible_theme(start + Duration::from_millis(100));
+        assert_eq!(midway.color_scheme, ColorScheme::Dark);
+        assert_ne!(midway.semantic.bg_canvas, light.semantic.bg_canvas);
+        assert_ne!(midway.semantic.bg_canvas, dark.semantic.bg_canvas);
+        assert!(provider.crossfade_running(start + Duration::from_millis(100)));
+
+        let settled = provider.visible_theme(start + Duration::from_millis(250));
+        assert_eq!(*settled, dark);
+        assert!(!provider.crossfade_running(start + Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn theme_transition_snaps_when_disabled_or_motion_reduced() {
+        let start = Instant::now();
+        let dark = Theme::default().with_color_scheme(ColorScheme::Dark);
+
+        let mut disabled = CalmProvider::new();
+        assert!(disabled.replace_theme(dark.clone(), start));
+        assert_eq!(*disabled.visible_theme(start), dark);
+
+        let mut reduced = CalmProvider::new()
+            .set_theme_transition(ThemeTransition::crossfade(200))
+            .set_motion_level(MotionLevel::Reduced);
+        assert!(reduced.replace_theme(dark.clone(), start));
+        assert!(!reduced.crossfade_running(start));
+        assert_eq!(*reduced.visible_theme(start), dark);
     }
 }
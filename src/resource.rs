@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use gpui::SharedString;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AsyncState<T> {
+    Loading,
+    Error(SharedString),
+    Empty,
+    Ready(T),
+}
+
+impl<T> Default for AsyncState<T> {
+    fn default() -> Self {
+        Self::Loading
+    }
+}
+
+impl<T> AsyncState<T> {
+    pub fn from_result<E: Display>(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => Self::Ready(value),
+            Err(error) => Self::Error(error.to_string().into()),
+        }
+    }
+
+    pub fn from_option(value: Option<T>) -> Self {
+        value.map_or(Self::Empty, Self::Ready)
+    }
+
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready(_))
+    }
+
+    pub fn as_ref(&self) -> AsyncState<&T> {
+        match self {
+            Self::Loading => AsyncState::Loading,
+            Self::Error(error) => AsyncState::Error(error.clone()),
+            Self::Empty => AsyncState::Empty,
+            Self::Ready(value) => AsyncState::Ready(value),
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> AsyncState<U> {
+        match self {
+            Self::Loading => AsyncState::Loading,
+            Self::Error(error) => AsyncState::Error(error),
+            Self::Empty => AsyncState::Empty,
+            Self::Ready(value) => AsyncState::Ready(f(value)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ResourceTicket(u64);
+
+struct ResourceInner<T> {
+    value: Option<T>,
+    error: Option<SharedString>,
+    loading: bool,
+    generation: u64,
+    loaded_at: Option<Instant>,
+    stale_after: Option<Duration>,
+}
+
+impl<T> Default for ResourceInner<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            error: None,
+            loading: false,
+            generation: 0,
+            loaded_at: None,
+            stale_after: None,
+        }
+    }
+}
+
+pub struct Resource<T> {
+    inner: Arc<RwLock<ResourceInner<T>>>,
+}
+
+impl<T> Clone for Resource<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Default for Resource<T> {
+    fn default() -> Self {
+        Self {
+            inner: Arc::default(),
+        }
+    }
+}
+
+impl<T> Resource<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ready(value: T) -> Self {
+        let resource = Self::new();
+        resource.set(value);
+        resource
+    }
+
+    pub fn stale_after(self, value: Duration) -> Self {
+        self.inner
+            .write()
+            .expect("resource state poisoned")
+            .stale_after = Some(value);
+        self
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.inner.read().expect("resource state poisoned").loading
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.inner
+            .read()
+            .expect("resource state poisoned")
+            .loaded_at
+            .is_some()
+    }
+
+    pub fn is_stale(&self) -> bool {
+        let inner = self.inner.read().expect("resource state poisoned");
+        match (inner.loaded_at, inner.stale_after) {
+            (None, _) => true,
+            (Some(loaded_at), Some(stale_after)) => loaded_at.elapsed() >= stale_after,
+            (Some(_), None) => false,
+        }
+    }
+
+    pub fn error(&self) -> Option<SharedString> {
+        self.inner
+            .read()
+            .expect("resource state poisoned")
+            .error
+            .clone()
+    }
+
+    pub fn begin(&self) -> ResourceTicket {
+        let mut inner = self.inner.write().expect("resource state poisoned");
+        inner.generation += 1;
+        inner.loading = true;
+        ResourceTicket(inner.generation)
+    }
+
+    pub fn finish(&self, ticket: ResourceTicket, result: Result<T, SharedString>) -> bool {
+        let mut inner = self.inner.write().expect("resource state poisoned");
+        if inner.generation != ticket.0 {
+            return false;
+        }
+        match result {
+            Ok(value) => {
+                inner.value = Some(value);
+                inner.error = None;
+            }
+            Err(error) => inner.error = Some(error),
+        }
+        inner.loading = false;
+        inner.loaded_at = Some(Instant::now());
+        true
+    }
+
+    pub fn set(&self, value: T) {
+        let ticket = self.begin();
+        self.finish(ticket, Ok(value));
+    }
+
+    pub fn cancel(&self) -> bool {
+        let mut inner = self.inner.write().expect("resource state poisoned");
+        let was_loading = inner.loading;
+        inner.generation += 1;
+        inner.loading = false;
+        was_loading
+    }
+
+    pub fn invalidate(&self) {
+        self.inner
+            .write()
+            .expect("resource state poisoned")
+            .loaded_at = None;
+    }
+
+    pub fn clear(&self) {
+        let mut inner = self.inner.write().expect("resource state poisoned");
+        let generation = inner.generation + 1;
+        let stale_after = inner.stale_after;
+        *inner = ResourceInner {
+            generation,
+            stale_after,
+            ..ResourceInner::default()
+        };
+    }
+}
+
+impl<T: 'static> Resource<T> {
+    pub fn load(&self, future: impl Future<Output = T> + 'static, cx: &mut gpui::App) {
+        self.try_load(async move { Ok::<_, SharedString>(future.await) }, cx);
+    }
+
+    pub fn try_load<E: Display>(
+        &self,
+        future: impl Future<Output = Result<T, E>> + 'static,
+        cx: &mut gpui::App,
+    ) {
+        let ticket = self.begin();
+        let resource = self.clone();
+        cx.spawn(async move |cx| {
+            let result = future.await.map_err(|error| error.to_string().into());
+            if resource.finish(ticket, result) {
+                let _ = cx.update(|cx| cx.refresh_windows());
+            }
+        })
+        .detach();
+    }
+
+    pub fn revalidate<F, Fut>(&self, fetch: F, cx: &mut gpui::App) -> bool
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T> + 'static,
+    {
+        if self.is_loading() || !self.is_stale() {
+            return false;
+        }
+        self.load(fetch(), cx);
+        true
+    }
+}
+
+impl<T: Clone> Resource<T> {
+    pub fn value(&self) -> Option<T> {
+        self.inner
+            .read()
+            .expect("resource state poisoned")
+            .value
+            .clone()
+    }
+
+    pub fn state(&self) -> AsyncState<T> {
+        let inner = self.inner.read().expect("resource state poisoned");
+        match (&inner.value, &inner.error) {
+            (Some(value), _) => AsyncState::Ready(value.clone()),
+            (None, Some(error)) if !inner.loading => AsyncState::Error(error.clone()),
+            (None, _) => AsyncState::Loading,
+        }
+    }
+}
+
+pub struct ResourceCache<K, T> {
+    entries: Arc<RwLock<HashMap<K, Resource<T>>>>,
+    stale_after: Option<Duration>,
+}
+
+impl<K, T> Clone for ResourceCache<K, T> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            stale_after: self.stale_after,
+        }
+    }
+}
+
+impl<K, T> Default for ResourceCache<K, T> {
+    fn default() -> Self {
+        Self {
+            entries: Arc::default(),
+            stale_after: None,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, T> ResourceCache<K, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stale_after(mut self, value: Duration) -> Self {
+        self.stale_after = Some(value);
+        self
+    }
+
+    pub fn get(&self, key: &K) -> Resource<T> {
+        if let Some(resource) = self.peek(key) {
+            return resource;
+        }
+        let mut entries = self.entries.write().expect("resource cache poisoned");
+        entries
+            .entry(key.clone())
+            .or_insert_with(|| match self.stale_after {
+                Some(stale_after) => Resource::new().stale_after(stale_after),
+                None => Resource::new(),
+            })
+            .clone()
+    }
+
+    pub fn peek(&self, key: &K) -> Option<Resource<T>> {
+        self.entries
+            .read()
+            .expect("resource cache poisoned")
+            .get(key)
+            .cloned()
+    }
+
+    pub fn loading_keys(&self) -> Vec<K> {
+        self.entries
+            .read()
+            .expect("resource cache poisoned")
+            .iter()
+            .filter(|(_, resource)| resource.is_loading())
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    pub fn remove(&self, key: &K) -> bool {
+        let removed = self
+            .entries
+            .write()
+            .expect("resource cache poisoned")
+            .remove(key);
+        removed.map(|resource| resource.cancel()).is_some()
+    }
+
+    pub fn invalidate_all(&self) {
+        for resource in self
+            .entries
+            .read()
+            .expect("resource cache poisoned")
+            .values()
+        {
+            resource.invalidate();
+        }
+    }
+
+    pub fn clear(&self) {
+        let entries = std::mem::take(&mut *self.entries.write().expect("resource cache poisoned"));
+        for resource in entries.values() {
+            resource.cancel();
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, T: Clone> ResourceCache<K, T> {
+    pub fn values(&self) -> HashMap<K, T> {
+        self.entries
+            .read()
+            .expect("resource cache poisoned")
+            .iter()
+            .filter_map(|(key, resource)| Some((key.clone(), resource.value()?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn async_state_adapts_results_options_and_values() {
+        let ready: AsyncState<Vec<u8>> = AsyncState::from_result::<String>(Ok(vec![1, 2]));
+        assert!(ready.is_ready());
+        assert_eq!(ready.as_ref().map(Vec::len), AsyncState::Ready(2));
+
+        let failed: AsyncState<u8> = AsyncState::from_result(Err("timed out"));
+        assert_eq!(failed, AsyncState::Error("timed out".into()));
+        assert_eq!(
+            failed.map(|value| value + 1),
+            AsyncState::Error("timed out".into())
+        );
+
+        assert_eq!(AsyncState::<u8>::from_option(None), AsyncState::Empty);
+        assert_eq!(AsyncState::from_option(Some(3)), AsyncState::Ready(3));
+        assert!(AsyncState::<u8>::default().is_loading());
+    }
+
+    #[test]
+    fn superseded_and_cancelled_loads_are_discarded() {
+        let resource = Resource::<u32>::new();
+        assert!(resource.is_stale());
+        assert_eq!(resource.state(), AsyncState::Loading);
+
+        let first = resource.begin();
+        let second = resource.begin();
+        assert!(!resource.finish(first, Ok(1)));
+        assert!(resource.is_loading());
+        assert!(resource.finish(second, Ok(2)));
+        assert_eq!(resource.state(), AsyncState::Ready(2));
+
+        let cancelled = resource.begin();
+        assert!(resource.cancel());
+        assert!(!resource.finish(cancelled, Ok(3)));
+        assert_eq!(resource.value(), Some(2));
+        assert!(!resource.is_loading());
+    }
+
+    #[test]
+    fn stale_values_remain_visible_while_revalidating() {
+        let resource = Resource::ready(vec!["a"]).stale_after(Duration::ZERO);
+        assert!(resource.is_loaded());
+        assert!(resource.is_stale());
+
+        let ticket = resource.begin();
+        assert_eq!(resource.state(), AsyncState::Ready(vec!["a"]));
+        assert!(resource.finish(ticket, Err("offline".into())));
+        assert_eq!(resource.state(), AsyncState::Ready(vec!["a"]));
+        assert_eq!(resource.error(), Some("offline".into()));
+
+        resource.clear();
+        let ticket = resource.begin();
+        resource.finish(ticket, Err("offline".into()));
+        assert_eq!(resource.state(), AsyncState::Error("offline".into()));
+    }
+
+    #[test]
+    fn caches_share_resources_per_key() {
+        let cache = ResourceCache::<String, u8>::new();
+        cache.get(&"a".to_string()).set(1);
+        let _ = cache.get(&"b".to_string()).begin();
+        assert_eq!(cache.values().get("a"), Some(&1));
+        assert_eq!(cache.loading_keys(), vec!["b".to_string()]);
+        assert!(cache.remove(&"b".to_string()));
+        assert!(cache.loading_keys().is_empty());
+        cache.clear();
+        assert!(cache.values().is_empty());
+    }
+}
@@ -0,0 +1,412 @@
+use gpui::{Hsla, IntoElement, PathBuilder, Pixels, Point, Styled, Window, canvas, point, px};
+
+use crate::theme::{ColorToken, Theme};
+
+// Angles are in degrees, measured clockwise from 12 o'clock, which is how
+// gauges and circular progress indicators read.
+const ARC_STEP_DEGREES: f32 = 4.0;
+
+fn polar(center: (f32, f32), radius: f32, degrees: f32) -> (f32, f32) {
+    let radians = degrees.to_radians();
+    (
+        center.0 + radius * radians.sin(),
+        center.1 - radius * radians.cos(),
+    )
+}
+
+/// Points along an arc, including both end points. Sweeps beyond a full turn
+/// are clamped to one turn.
+pub fn arc_points(
+    center: (f32, f32),
+    radius: f32,
+    start_degrees: f32,
+    sweep_degrees: f32,
+) -> Vec<(f32, f32)> {
+    let sweep = sweep_degrees.clamp(-360.0, 360.0);
+    let steps = (sweep.abs() / ARC_STEP_DEGREES).ceil().max(1.0) as usize;
+    (0..=steps)
+        .map(|step| {
+            let degrees = start_degrees + sweep * step as f32 / steps as f32;
+            polar(center, radius.max(0.0), degrees)
+        })
+        .collect()
+}
+
+/// Closed outline of a ring segment: the outer arc forwards, then the inner
+/// arc backwards.
+pub fn ring_segment_points(
+    center: (f32, f32),
+    inner_radius: f32,
+    outer_radius: f32,
+    start_degrees: f32,
+    sweep_degrees: f32,
+) -> Vec<(f32, f32)> {
+    let mut points = arc_points(center, outer_radius, start_degrees, sweep_degrees);
+    let mut inner = arc_points(center, inner_radius, start_degrees, sweep_degrees);
+    inner.reverse();
+    points.extend(inner);
+    points
+}
+
+/// Vertices of a regular polygon with its first vertex at `rotation_degrees`.
+pub fn regular_polygon_points(
+    center: (f32, f32),
+    radius: f32,
+    sides: usize,
+    rotation_degrees: f32,
+) -> Vec<(f32, f32)> {
+    let sides = sides.max(3);
+    (0..sides)
+        .map(|side| {
+            let degrees = rotation_degrees + 360.0 * side as f32 / sides as f32;
+            polar(center, radius, degrees)
+        })
+        .collect()
+}
+
+/// Splits a line into dash segments; the last dash is cut at the end point.
+pub fn dash_segments(
+    from: (f32, f32),
+    to: (f32, f32),
+    dash: f32,
+    gap: f32,
+) -> Vec<((f32, f32), (f32, f32))> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length = dx.hypot(dy);
+    if length <= f32::EPSILON || dash <= 0.0 {
+        return Vec::new();
+    }
+    let at = |distance: f32| {
+        let t = distance / length;
+        (from.0 + dx * t, from.1 + dy * t)
+    };
+    let mut segments = Vec::new();
+    let mut distance = 0.0;
+    while distance < length {
+        let end = (distance + dash).min(length);
+        segments.push((at(distance), at(end)));
+        distance = end + gap.max(0.0);
+    }
+    segments
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ShapeKind {
+    Arc {
+        center: (f32, f32),
+        radius: f32,
+        start: f32,
+        sweep: f32,
+        width: f32,
+    },
+    Ring {
+        center: (f32, f32),
+        inner_radius: f32,
+        outer_radius: f32,
+        start: f32,
+        sweep: f32,
+    },
+    Polygon {
+        points: Vec<(f32, f32)>,
+        stroke_width: Option<f32>,
+    },
+    DashedLine {
+        from: (f32, f32),
+        to: (f32, f32),
+        width: f32,
+        dash: f32,
+        gap: f32,
+    },
+}
+
+/// A themed drawing primitive positioned in the local coordinates of the
+/// canvas that paints it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shape {
+    kind: ShapeKind,
+    color: ColorToken,
+}
+
+impl Shape {
+    /// A stroked arc; use a sweep of 360 for a full circle outline.
+    pub fn arc(
+        center: (f32, f32),
+        radius: f32,
+        start_degrees: f32,
+        sweep_degrees: f32,
+        width: f32,
+    ) -> Self {
+        Self::with_kind(ShapeKind::Arc {
+            center,
+            radius,
+            start: start_degrees,
+            sweep: sweep_degrees,
+            width,
+        })
+    }
+
+    /// A filled ring segment, e.g. one band of a gauge or a circular progress
+    /// track.
+    pub fn ring(
+        center: (f32, f32),
+        inner_radius: f32,
+        outer_radius: f32,
+        start_degrees: f32,
+        sweep_degrees: f32,
+    ) -> Self {
+        Self::with_kind(ShapeKind::Ring {
+            center,
+            inner_radius: inner_radius.min(outer_radius),
+            outer_radius: outer_radius.max(inner_radius),
+            start: start_degrees,
+            sweep: sweep_degrees,
+        })
+    }
+
+    /// A filled polygon through `points`.
+    pub fn polygon(points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        Self::with_kind(ShapeKind::Polygon {
+            points: points.into_iter().collect(),
+            stroke_width: None,
+        })
+    }
+
+    pub fn regular_polygon(
+        center: (f32, f32),
+        radius: f32,
+        sides: usize,
+        rotation_degrees: f32,
+    ) -> Self {
+        Self::polygon(regular_polygon_points(
+            center,
+            radius,
+            sides,
+            rotation_degrees,
+        ))
+    }
+
+    pub fn dashed_line(from: (f32, f32), to: (f32, f32), width: f32, dash: f32, gap: f32) -> Self {
+        Self::with_kind(ShapeKind::DashedLine {
+            from,
+            to,
+            width,
+            dash,
+            gap,
+        })
+    }
+
+    fn with_kind(kind: ShapeKind) -> Self {
+        Self {
+            kind,
+            color: ColorToken::Semantic(crate::theme::SemanticColorToken::BorderStrong),
+        }
+    }
+
+    pub fn color(mut self, value: impl Into<ColorToken>) -> Self {
+        self.color = value.into();
+        self
+    }
+
+    /// Outlines a polygon instead of filling it; other shapes ignore this.
+    pub fn outlined(mut self, width: f32) -> Self {
+        if let ShapeKind::Polygon { stroke_width, .. } = &mut self.kind {
+            *stroke_width = Some(width);
+        }
+        self
+    }
+
+    /// Paints the shape with its local coordinates offset by `origin`.
+    pub fn paint(&self, origin: Point<Pixels>, theme: &Theme, window: &mut Window) {
+        self.paint_with(origin, self.color.resolve(theme), window);
+    }
+
+    fn paint_with(&self, origin: Point<Pixels>, color: Hsla, window: &mut Window) {
+        let at = |(x, y): (f32, f32)| point(origin.x + px(x), origin.y + px(y));
+        match &self.kind {
+            ShapeKind::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+                width,
+            } => {
+                let points = arc_points(*center, *radius, *start, *sweep);
+                paint_polyline(window, points.into_iter().map(at), *width, false, color);
+            }
+            ShapeKind::Ring {
+                center,
+                inner_radius,
+                outer_radius,
+                start,
+                sweep,
+            } => {
+                let points =
+                    ring_segment_points(*center, *inner_radius, *outer_radius, *start, *sweep);
+                paint_filled(window, points.into_iter().map(at), color);
+            }
+            ShapeKind::Polygon {
+                points,
+                stroke_width: Some(width),
+            } => {
+                paint_polyline(window, points.iter().copied().map(at), *width, true, color);
+            }
+            ShapeKind::Polygon {
+                points,
+                stroke_width: None,
+            } => paint_filled(window, points.iter().copied().map(at), color),
+            ShapeKind::DashedLine {
+                from,
+                to,
+                width,
+                dash,
+                gap,
+            } => {
+                for (start, end) in dash_segments(*from, *to, *dash, *gap) {
+                    paint_polyline(window, [at(start), at(end)], *width, false, color);
+                }
+            }
+        }
+    }
+}
+
+fn paint_polyline(
+    window: &mut Window,
+    points: impl IntoIterator<Item = Point<Pixels>>,
+    width: f32,
+    closed: bool,
+    color: Hsla,
+) {
+    let mut builder = PathBuilder::stroke(px(width.max(0.5)));
+    let mut points = points.into_iter();
+    let Some(first) = points.next() else {
+        return;
+    };
+    builder.move_to(first);
+    for next in points {
+        builder.line_to(next);
+    }
+    if closed {
+        builder.close();
+    }
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+fn paint_filled(window: &mut Window, points: impl IntoIterator<Item = Point<Pixels>>, color: Hsla) {
+    let mut builder = PathBuilder::fill();
+    let mut points = points.into_iter();
+    let Some(first) = points.next() else {
+        return;
+    };
+    builder.move_to(first);
+    for next in points {
+        builder.line_to(next);
+    }
+    builder.close();
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// A full-size canvas that paints `shapes` relative to its top-left corner,
+/// resolving their colors against `theme` up front.
+pub fn shape_canvas(shapes: Vec<Shape>, theme: &Theme) -> impl IntoElement + Styled {
+    let resolved: Vec<(Shape, Hsla)> = shapes
+        .into_iter()
+        .map(|shape| {
+            let color = shape.color.resolve(theme);
+            (shape, color)
+        })
+        .collect();
+    canvas(
+        |_, _, _| (),
+        move |bounds, _, window, _| {
+            for (shape, color) in &resolved {
+                shape.paint_with(bounds.origin, *color, window);
+            }
+        },
+    )
+    .size_full()
+}
+
+/// Sweep covering `ratio` of `max_sweep_degrees`, for mapping a progress
+/// value onto an arc.
+pub fn sweep_for_ratio(ratio: f32, max_sweep_degrees: f32) -> f32 {
+    ratio.clamp(0.0, 1.0) * max_sweep_degrees.clamp(0.0, 360.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: (f32, f32), b: (f32, f32)) -> bool {
+        (a.0 - b.0).abs() < 0.01 && (a.1 - b.1).abs() < 0.01
+    }
+
+    #[test]
+    fn arcs_start_at_twelve_and_run_clockwise() {
+        let points = arc_points((50.0, 50.0), 10.0, 0.0, 90.0);
+        assert!(close(points[0], (50.0, 40.0)));
+        assert!(close(*points.last().unwrap(), (60.0, 50.0)));
+        assert_eq!(points.len(), 24);
+
+        let full = arc_points((0.0, 0.0), 5.0, 0.0, 720.0);
+        assert!(close(full[0], *full.last().unwrap()));
+        assert_eq!(arc_points((0.0, 0.0), 5.0, 0.0, 0.0).len(), 2);
+    }
+
+    #[test]
+    fn ring_segments_close_between_outer_and_inner_arcs() {
+        let points = ring_segment_points((0.0, 0.0), 8.0, 10.0, 0.0, 180.0);
+        assert!(close(points[0], (0.0, -10.0)));
+        assert!(close(*points.last().unwrap(), (0.0, -8.0)));
+        assert_eq!(points.len() % 2, 0);
+    }
+
+    #[test]
+    fn polygons_and_dashes_cover_the_requested_geometry() {
+        let triangle = regular_polygon_points((0.0, 0.0), 10.0, 3, 0.0);
+        assert_eq!(triangle.len(), 3);
+        assert!(close(triangle[0], (0.0, -10.0)));
+        assert_eq!(regular_polygon_points((0.0, 0.0), 10.0, 1, 0.0).len(), 3);
+
+        let dashes = dash_segments((0.0, 0.0), (10.0, 0.0), 3.0, 2.0);
+        assert_eq!(
+            dashes,
+            vec![((0.0, 0.0), (3.0, 0.0)), ((5.0, 0.0), (8.0, 0.0))]
+        );
+        assert!(dash_segments((1.0, 1.0), (1.0, 1.0), 3.0, 2.0).is_empty());
+        assert!(dash_segments((0.0, 0.0), (4.0, 0.0), 0.0, 2.0).is_empty());
+    }
+
+    #[test]
+    fn shapes_resolve_theme_colors_and_only_outline_polygons() {
+        let theme = Theme::default();
+        let shape = Shape::ring((0.0, 0.0), 10.0, 8.0, 0.0, 90.0).outlined(2.0);
+        assert_eq!(
+            shape.kind,
+            ShapeKind::Ring {
+                center: (0.0, 0.0),
+                inner_radius: 8.0,
+                outer_radius: 10.0,
+                start: 0.0,
+                sweep: 90.0,
+            }
+        );
+        assert_eq!(shape.color.resolve(&theme), theme.semantic.border_strong);
+        let polygon = Shape::regular_polygon((0.0, 0.0), 4.0, 4, 45.0)
+            .outlined(1.5)
+            .color(gpui::white());
+        assert!(matches!(
+            polygon.kind,
+            ShapeKind::Polygon {
+                stroke_width: Some(_),
+                ..
+            }
+        ));
+        assert_eq!(polygon.color.resolve(&theme), gpui::white());
+        assert_eq!(sweep_for_ratio(0.5, 270.0), 135.0);
+        assert_eq!(sweep_for_ratio(2.0, 720.0), 360.0);
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, RwLock};
+
+use gpui::{InteractiveElement, Keystroke, SharedString, Window};
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KeyChord {
+    pub control: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub platform: bool,
+    pub key: SharedString,
+}
+
+impl KeyChord {
+    pub fn parse(source: &str) -> Result<Self, ShortcutError> {
+        let invalid = || ShortcutError::InvalidChord(source.to_string());
+        let mut chord = Self::default();
+        let mut parts = source.split('+').map(str::trim).peekable();
+        while let Some(part) = parts.next() {
+            if part.is_empty() {
+                return Err(invalid());
+            }
+            let lower = part.to_ascii_lowercase();
+            if parts.peek().is_none() {
+                chord.key = normalize_key(&lower).into();
+                break;
+            }
+            let flag = match lower.as_str() {
+                "ctrl" | "control" => &mut chord.control,
+                "alt" | "option" | "opt" => &mut chord.alt,
+                "shift" => &mut chord.shift,
+                "cmd" | "command" | "super" | "meta" | "win" => &mut chord.platform,
+                "mod" if cfg!(target_os = "macos") => &mut chord.platform,
+                "mod" => &mut chord.control,
+                _ => return Err(invalid()),
+            };
+            if *flag {
+                return Err(invalid());
+            }
+            *flag = true;
+        }
+        if chord.key.is_empty() {
+            return Err(invalid());
+        }
+        Ok(chord)
+    }
+
+    pub fn matches(&self, keystroke: &Keystroke) -> bool {
+        let modifiers = &keystroke.modifiers;
+        modifiers.control == self.control
+            && modifiers.alt == self.alt
+            && modifiers.shift == self.shift
+            && modifiers.platform == self.platform
+            && keystroke.key.eq_ignore_ascii_case(&self.key)
+    }
+
+    pub fn label(&self) -> SharedString {
+        let key = match self.key.as_ref() {
+            key if key.chars().count() == 1 => key.to_uppercase(),
+            key => {
+                let mut chars = key.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+        };
+        if cfg!(target_os = "macos") {
+            let glyphs = [
+                (self.control, "⌃"),
+                (self.alt, "⌥"),
+                (self.shift, "⇧"),
+                (self.platform, "⌘"),
+            ];
+            let prefix: String = glyphs
+                .iter()
+                .filter(|(on, _)| *on)
+                .map(|(_, glyph)| *glyph)
+                .collect();
+            return format!("{prefix}{key}").into();
+        }
+        let names = [
+            (self.control, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+            (self.platform, "Super"),
+        ];
+        names
+            .iter()
+            .filter(|(on, _)| *on)
+            .map(|(_, name)| *name)
+            .chain(std::iter::once(key.as_str()))
+            .collect::<Vec<_>>()
+            .join("+")
+            .into()
+    }
+}
+
+impl Display for KeyChord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label())
+    }
+}
+
+fn normalize_key(key: &str) -> &str {
+    match key {
+        "esc" => "escape",
+        "return" => "enter",
+        "del" => "delete",
+        "arrowup" => "up",
+        "arrowdown" => "down",
+        "arrowleft" => "left",
+        "arrowright" => "right",
+        key => key,
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ShortcutScope {
+    #[default]
+    Global,
+    Region(SharedString),
+}
+
+impl ShortcutScope {
+    pub fn region(name: impl Into<SharedString>) -> Self {
+        Self::Region(name.into())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Shortcut {
+    pub action: SharedString,
+    pub chord: KeyChord,
+    pub scope: ShortcutScope,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ShortcutError {
+    InvalidChord(String),
+    UnknownAction(SharedString),
+    Conflict {
+        chord: KeyChord,
+        scope: ShortcutScope,
+        existing: SharedString,
+    },
+}
+
+impl Display for ShortcutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShortcutError::InvalidChord(source) => write!(f, "invalid key chord: {source:?}"),
+            ShortcutError::UnknownAction(action) => write!(f, "unknown shortcut action: {action}"),
+            ShortcutError::Conflict {
+                chord,
+                scope,
+                existing,
+            } => write!(f, "{chord} is already bound to {existing} in {scope:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ShortcutError {}
+
+pub type ShortcutResult<T> = Result<T, ShortcutError>;
+
+#[derive(Clone, Default)]
+pub struct ShortcutRegistry {
+    actions: Arc<RwLock<BTreeMap<SharedString, Shortcut>>>,
+}
+
+impl ShortcutRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(
+        &self,
+        action: impl Into<SharedString>,
+        chord: &str,
+        scope: ShortcutScope,
+    ) -> ShortcutResult<()> {
+        let action = action.into();
+        let chord = KeyChord::parse(chord)?;
+        let mut actions = self.actions.write().expect("shortcut registry poisoned");
+        if let Some(existing) = Self::conflict(&actions, &action, &chord, &scope) {
+            return Err(ShortcutError::Conflict {
+                chord,
+                scope,
+                existing,
+            });
+        }
+        actions.insert(
+            action.clone(),
+            Shortcut {
+                action,
+                chord,
+                scope,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn rebind(&self, action: &str, chord: &str) -> ShortcutResult<KeyChord> {
+        let chord = KeyChord::parse(chord)?;
+        let mut actions = self.actions.write().expect("shortcut registry poisoned");
+        let Some(scope) = actions.get(action).map(|shortcut| shortcut.scope.clone()) else {
+            return Err(ShortcutError::UnknownAction(action.to_string().into()));
+        };
+        if let Some(existing) = Self::conflict(&actions, action, &chord, &scope) {
+            return Err(ShortcutError::Conflict {
+                chord,
+                scope,
+                existing,
+            });
+        }
+        let shortcut = actions.get_mut(action).expect("shortcut action present");
+        Ok(std::mem::replace(&mut shortcut.chord, chord))
+    }
+
+    pub fn unregister(&self, action: &str) -> bool {
+        self.actions
+            .write()
+            .expect("shortcut registry poisoned")
+            .remove(action)
+            .is_some()
+    }
+
+    pub fn shortcut(&self, action: &str) -> Option<Shortcut> {
+        self.actions
+            .read()
+            .expect("shortcut registry poisoned")
+            .get(action)
+            .cloned()
+    }
+
+    pub fn shortcuts(&self) -> Vec<Shortcut> {
+        self.actions
+            .read()
+            .expect("shortcut registry poisoned")
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    pub fn hint(&self, action: &str) -> Option<SharedString> {
+        self.shortcut(action).map(|shortcut| shortcut.chord.label())
+    }
+
+    pub fn action_for(&self, chord: &KeyChord, scope: &ShortcutScope) -> Option<SharedString> {
+        self.actions
+            .read()
+            .expect("shortcut registry poisoned")
+            .values()
+            .find(|shortcut| &shortcut.chord == chord && &shortcut.scope == scope)
+            .map(|shortcut| shortcut.action.clone())
+    }
+
+    pub fn resolve(&self, keystroke: &Keystroke, scope: &ShortcutScope) -> Option<SharedString> {
+        self.actions
+            .read()
+            .expect("shortcut registry poisoned")
+            .values()
+            .find(|shortcut| &shortcut.scope == scope && shortcut.chord.matches(keystroke))
+            .map(|shortcut| shortcut.action.clone())
+    }
+
+    pub fn scope<E: InteractiveElement>(
+        &self,
+        scope: ShortcutScope,
+        element: E,
+        handler: impl Fn(&SharedString, &mut Window, &mut gpui::App) + 'static,
+    ) -> E {
+        let registry = self.clone();
+        element.on_key_down(move |event, window, cx| {
+            if let Some(action) = registry.resolve(&event.keystroke, &scope) {
+                handler(&action, window, cx);
+                cx.stop_propagation();
+            }
+        })
+    }
+
+    fn conflict(
+        actions: &BTreeMap<SharedString, Shortcut>,
+        action: &str,
+        chord: &KeyChord,
+        scope: &ShortcutScope,
+    ) -> Option<SharedString> {
+        actions
+            .values()
+            .find(|shortcut| {
+                shortcut.action.as_ref() != action
+                    && &shortcut.chord == chord
+                    && &shortcut.scope == scope
+            })
+            .map(|shortcut| shortcut.action.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chords_parse_aliases_and_reject_malformed_input() {
+        let chord = KeyChord::parse("Cmd+Shift+K").expect("valid chord");
+        assert!(chord.platform && chord.shift && !chord.control && !chord.alt);
+        assert_eq!(chord.key.as_ref(), "k");
+        assert_eq!(
+            KeyChord::parse("ctrl + esc")
+                .expect("valid chord")
+                .key
+                .as_ref(),
+            "escape"
+        );
+        assert!(KeyChord::parse("").is_err());
+        assert!(KeyChord::parse("Cmd+").is_err());
+        assert!(KeyChord::parse("Hyper+K").is_err());
+        assert!(KeyChord::parse("Shift+Shift+K").is_err());
+    }
+
+    #[test]
+    fn chords_match_keystrokes_exactly() {
+        let chord = KeyChord::parse("Ctrl+K").expect("valid chord");
+        assert!(chord.matches(&Keystroke::parse("ctrl-k").expect("keystroke")));
+        assert!(!chord.matches(&Keystroke::parse("ctrl-shift-k").expect("keystroke")));
+        assert!(!chord.matches(&Keystroke::parse("k").expect("keystroke")));
+    }
+
+    #[test]
+    fn registry_detects_conflicts_per_scope_and_rebinds() {
+        let registry = ShortcutRegistry::new();
+        let editor = ShortcutScope::region("editor");
+        registry
+            .register("palette.open", "Ctrl+K", ShortcutScope::Global)
+            .expect("register palette");
+        registry
+            .register("editor.delete_line", "Ctrl+K", editor.clone())
+            .expect("regions may shadow global chords");
+
+        let conflict = registry
+            .register("search.open", "ctrl+k", ShortcutScope::Global)
+            .expect_err("duplicate global chord");
+        assert!(matches!(
+            conflict,
+            ShortcutError::Conflict { ref existing, .. } if existing.as_ref() == "palette.open"
+        ));
+
+        let keystroke = Keystroke::parse("ctrl-k").expect("keystroke");
+        assert_eq!(
+            registry
+                .resolve(&keystroke, &editor)
+                .as_ref()
+                .map(|action| action.as_ref()),
+            Some("editor.delete_line")
+        );
+
+        let previous = registry.rebind("palette.open", "Ctrl+P").expect("rebind");
+        assert_eq!(previous.key.as_ref(), "k");
+        assert_eq!(
+            registry
+                .resolve(&keystroke, &ShortcutScope::Global)
+                .map(|action| action.to_string()),
+            None
+        );
+        assert_eq!(
+            registry.rebind("missing", "Ctrl+J"),
+            Err(ShortcutError::UnknownAction("missing".into()))
+        );
+        assert!(registry.hint("palette.open").is_some());
+        assert!(registry.unregister("palette.open"));
+        assert_eq!(registry.hint("palette.open"), None);
+    }
+}
@@ -0,0 +1,317 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use gpui::{
+    AnyElement, Bounds, FocusHandle, InteractiveElement, IntoElement, KeyDownEvent, ParentElement,
+    Pixels, SharedString, Styled, Window, canvas, div,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpatialDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl SpatialDirection {
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "up" => Some(Self::Up),
+            "down" => Some(Self::Down),
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+pub fn nearest_in_direction<K>(
+    from: Bounds<Pixels>,
+    direction: SpatialDirection,
+    candidates: impl IntoIterator<Item = (K, Bounds<Pixels>)>,
+) -> Option<K> {
+    let origin = from.center();
+    candidates
+        .into_iter()
+        .filter_map(|(key, bounds)| {
+            let center = bounds.center();
+            let (ahead, gap, across) = match direction {
+                SpatialDirection::Up => (
+                    center.y < origin.y,
+                    from.top() - bounds.bottom(),
+                    span_gap(from.left(), from.right(), bounds.left(), bounds.right()),
+                ),
+                SpatialDirection::Down => (
+                    center.y > origin.y,
+                    bounds.top() - from.bottom(),
+                    span_gap(from.left(), from.right(), bounds.left(), bounds.right()),
+                ),
+                SpatialDirection::Left => (
+                    center.x < origin.x,
+                    from.left() - bounds.right(),
+                    span_gap(from.top(), from.bottom(), bounds.top(), bounds.bottom()),
+                ),
+                SpatialDirection::Right => (
+                    center.x > origin.x,
+                    bounds.left() - from.right(),
+                    span_gap(from.top(), from.bottom(), bounds.top(), bounds.bottom()),
+                ),
+            };
+            let distance = f32::from(center.x - origin.x).hypot(f32::from(center.y - origin.y));
+            ahead.then(|| {
+                let score = f32::from(gap).max(0.0) + f32::from(across) * 2.0;
+                (key, score, distance)
+            })
+        })
+        .min_by(|left, right| left.1.total_cmp(&right.1).then(left.2.total_cmp(&right.2)))
+        .map(|(key, _, _)| key)
+}
+
+fn span_gap(start: Pixels, end: Pixels, other_start: Pixels, other_end: Pixels) -> Pixels {
+    if other_end < start {
+        start - other_end
+    } else if other_start > end {
+        other_start - end
+    } else {
+        Pixels::ZERO
+    }
+}
+
+struct SpatialTarget {
+    bounds: Bounds<Pixels>,
+    focus_handle: FocusHandle,
+}
+
+#[derive(Default)]
+struct SpatialState {
+    targets: BTreeMap<SharedString, SpatialTarget>,
+}
+
+#[derive(Clone, Default)]
+pub struct SpatialNavigator {
+    state: Arc<RwLock<SpatialState>>,
+}
+
+impl SpatialNavigator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(
+        &self,
+        key: impl Into<SharedString>,
+        bounds: Bounds<Pixels>,
+        focus_handle: FocusHandle,
+    ) {
+        self.state
+            .write()
+            .expect("spatial state poisoned")
+            .targets
+            .insert(
+                key.into(),
+                SpatialTarget {
+                    bounds,
+                    focus_handle,
+                },
+            );
+    }
+
+    pub fn unregister(&self, key: &str) -> bool {
+        self.state
+            .write()
+            .expect("spatial state poisoned")
+            .targets
+            .remove(key)
+            .is_some()
+    }
+
+    pub fn clear(&self) {
+        self.state
+            .write()
+            .expect("spatial state poisoned")
+            .targets
+            .clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.state
+            .read()
+            .expect("spatial state poisoned")
+            .targets
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn target(
+        &self,
+        key: impl Into<SharedString>,
+        focus_handle: FocusHandle,
+        content: impl IntoElement,
+    ) -> AnyElement {
+        let navigator = self.clone();
+        let key = key.into();
+        let measure = canvas(
+            move |bounds, _window, _cx| navigator.register(key, bounds, focus_handle),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full();
+        div()
+            .relative()
+            .child(content)
+            .child(measure)
+            .into_any_element()
+    }
+
+    pub fn scope<E: InteractiveElement>(&self, element: E) -> E {
+        self.clear();
+        let navigator = self.clone();
+        element.on_key_down(move |event, window, cx| {
+            if navigator.handle_key_down(event, window, cx) {
+                cx.stop_propagation();
+            }
+        })
+    }
+
+    pub fn focused_key(&self, window: &Window) -> Option<SharedString> {
+        self.state
+            .read()
+            .expect("spatial state poisoned")
+            .targets
+            .iter()
+            .find(|(_, target)| target.focus_handle.is_focused(window))
+            .map(|(key, _)| key.clone())
+    }
+
+    pub fn next_key(&self, direction: SpatialDirection, window: &Window) -> Option<SharedString> {
+        let state = self.state.read().expect("spatial state poisoned");
+        let focused = state
+            .targets
+            .values()
+            .find(|target| target.focus_handle.is_focused(window));
+        let Some(focused) = focused else {
+            return state
+                .targets
+                .iter()
+                .min_by(|(_, left), (_, right)| {
+                    let left = (left.bounds.top(), left.bounds.left());
+                    let right = (right.bounds.top(), right.bounds.left());
+                    left.partial_cmp(&right)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(key, _)| key.clone());
+        };
+        nearest_in_direction(
+            focused.bounds,
+            direction,
+            state
+                .targets
+                .iter()
+                .filter(|(_, target)| target.focus_handle != focused.focus_handle)
+                .map(|(key, target)| (key.clone(), target.bounds)),
+        )
+    }
+
+    pub fn navigate(
+        &self,
+        direction: SpatialDirection,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) -> bool {
+        let Some(key) = self.next_key(direction, window) else {
+            return false;
+        };
+        let focus_handle = self
+            .state
+            .read()
+            .expect("spatial state poisoned")
+            .targets
+            .get(&key)
+            .map(|target| target.focus_handle.clone());
+        let Some(focus_handle) = focus_handle else {
+            return false;
+        };
+        window.focus(&focus_handle, cx);
+        window.refresh();
+        true
+    }
+
+    pub fn handle_key_down(
+        &self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut gpui::App,
+    ) -> bool {
+        if event.keystroke.modifiers.modified() {
+            return false;
+        }
+        SpatialDirection::from_key(event.keystroke.key.as_str())
+            .is_some_and(|direction| self.navigate(direction, window, cx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::{point, px, size};
+
+    fn cell(x: f32, y: f32) -> Bounds<Pixels> {
+        Bounds::new(point(px(x), px(y)), size(px(40.0), px(20.0)))
+    }
+
+    #[test]
+    fn direction_keys_map_to_arrows_only() {
+        assert_eq!(SpatialDirection::from_key("up"), Some(SpatialDirection::Up));
+        assert_eq!(
+            SpatialDirection::from_key("right"),
+            Some(SpatialDirection::Right)
+        );
+        assert_eq!(SpatialDirection::from_key("tab"), None);
+    }
+
+    #[test]
+    fn nearest_prefers_aligned_targets_in_direction() {
+        let grid = [
+            ("a", cell(0.0, 0.0)),
+            ("b", cell(60.0, 0.0)),
+            ("c", cell(120.0, 0.0)),
+            ("d", cell(0.0, 40.0)),
+            ("e", cell(60.0, 40.0)),
+            ("far", cell(0.0, 400.0)),
+        ];
+        let from = cell(0.0, 0.0);
+        assert_eq!(
+            nearest_in_direction(from, SpatialDirection::Right, grid),
+            Some("b")
+        );
+        assert_eq!(
+            nearest_in_direction(from, SpatialDirection::Down, grid),
+            Some("d")
+        );
+        assert_eq!(nearest_in_direction(from, SpatialDirection::Up, grid), None);
+        assert_eq!(
+            nearest_in_direction(cell(60.0, 40.0), SpatialDirection::Left, grid),
+            Some("d")
+        );
+        assert_eq!(
+            nearest_in_direction(cell(60.0, 40.0), SpatialDirection::Up, grid),
+            Some("b")
+        );
+    }
+
+    #[test]
+    fn offset_targets_are_penalized_across_the_axis() {
+        let candidates = [
+            ("diagonal", cell(60.0, 60.0)),
+            ("straight", cell(120.0, 0.0)),
+        ];
+        assert_eq!(
+            nearest_in_direction(cell(0.0, 0.0), SpatialDirection::Right, candidates),
+            Some("straight")
+        );
+    }
+}
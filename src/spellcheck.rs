@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
+
+pub trait SpellChecker: Send + Sync {
+    fn check(&self, word: &str) -> bool;
+
+    fn suggest(&self, word: &str, limit: usize) -> Vec<String>;
+
+    fn add_word(&self, _word: &str) {}
+}
+
+pub type SharedSpellChecker = Arc<dyn SpellChecker>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Misspelling {
+    pub range: Range<usize>,
+    pub word: String,
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphabetic() || ch == '\'' || ch == '’'
+}
+
+fn checkable(word: &str) -> bool {
+    let letters = word.chars().filter(|ch| ch.is_alphabetic()).count();
+    letters > 1 && !word.chars().all(|ch| !ch.is_lowercase())
+}
+
+pub fn words(text: &str) -> Vec<(Range<usize>, String)> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    for (index, ch) in text.chars().chain(std::iter::once(' ')).enumerate() {
+        if is_word_char(ch) {
+            if current.is_empty() {
+                start = index;
+            }
+            current.push(ch);
+            continue;
+        }
+        if current.is_empty() {
+            continue;
+        }
+        let leading = current.chars().take_while(|ch| !ch.is_alphabetic()).count();
+        let word: String = current.chars().skip(leading).collect();
+        let trailing = word
+            .chars()
+            .rev()
+            .take_while(|ch| !ch.is_alphabetic())
+            .count();
+        let len = word.chars().count() - trailing;
+        if len > 0 {
+            let word: String = word.chars().take(len).collect();
+            words.push((start + leading..start + leading + len, word));
+        }
+        current.clear();
+    }
+    words
+}
+
+pub fn misspellings(
+    text: &str,
+    checker: &dyn SpellChecker,
+    ignored: &[String],
+) -> Vec<Misspelling> {
+    words(text)
+        .into_iter()
+        .filter(|(_, word)| checkable(word))
+        .filter(|(_, word)| {
+            !ignored
+                .iter()
+                .any(|ignored| ignored.eq_ignore_ascii_case(word))
+        })
+        .filter(|(_, word)| !checker.check(word))
+        .map(|(range, word)| Misspelling { range, word })
+        .collect()
+}
+
+pub fn misspelling_at(misspellings: &[Misspelling], char_index: usize) -> Option<&Misspelling> {
+    misspellings.iter().find(|misspelling| {
+        misspelling.range.start <= char_index && char_index <= misspelling.range.end
+    })
+}
+
+fn edit_distance(left: &str, right: &str) -> usize {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut before_previous = previous.clone();
+    for (i, left_ch) in left.iter().enumerate() {
+        let mut current = vec![i + 1; right.len() + 1];
+        for (j, right_ch) in right.iter().enumerate() {
+            let cost = usize::from(left_ch != right_ch);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+            if i > 0 && j > 0 && *left_ch == right[j - 1] && left[i - 1] == *right_ch {
+                current[j + 1] = current[j + 1].min(before_previous[j - 1] + 1);
+            }
+        }
+        before_previous = std::mem::replace(&mut previous, current);
+    }
+    previous[right.len()]
+}
+
+fn match_case(word: &str, suggestion: &str) -> String {
+    let mut chars = word.chars();
+    let capitalized = chars.next().is_some_and(char::is_uppercase);
+    if capitalized && chars.all(|ch| !ch.is_lowercase()) && word.chars().count() > 1 {
+        return suggestion.to_uppercase();
+    }
+    if !capitalized {
+        return suggestion.to_string();
+    }
+    let mut suggestion_chars = suggestion.chars();
+    suggestion_chars
+        .next()
+        .map(|first| first.to_uppercase().chain(suggestion_chars).collect())
+        .unwrap_or_default()
+}
+
+#[derive(Clone, Default)]
+pub struct WordListDictionary {
+    words: Arc<RwLock<HashSet<String>>>,
+}
+
+impl WordListDictionary {
+    pub fn new(words: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        let dictionary = Self::default();
+        for word in words {
+            dictionary.add_word(word.as_ref());
+        }
+        dictionary
+    }
+
+    pub fn from_text(text: &str) -> Self {
+        Self::new(text.split_whitespace())
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+            .read()
+            .expect("spellcheck dictionary poisoned")
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl SpellChecker for WordListDictionary {
+    fn check(&self, word: &str) -> bool {
+        let normalized = word.replace('’', "'").to_lowercase();
+        let words = self.words.read().expect("spellcheck dictionary poisoned");
+        words.contains(&normalized)
+            || normalized
+                .strip_suffix("'s")
+                .is_some_and(|stem| words.contains(stem))
+    }
+
+    fn suggest(&self, word: &str, limit: usize) -> Vec<String> {
+        let normalized = word.replace('’', "'").to_lowercase();
+        let max_distance = if normalized.chars().count() <= 4 {
+            1
+        } else {
+            2
+        };
+        let words = self.words.read().expect("spellcheck dictionary poisoned");
+        let mut candidates: Vec<(usize, &String)> = words
+            .iter()
+            .filter(|candidate| {
+                candidate
+                    .chars()
+                    .count()
+                    .abs_diff(normalized.chars().count())
+                    <= max_distance
+            })
+            .map(|candidate| (edit_distance(&normalized, candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect();
+        candidates.sort();
+        candidates
+            .into_iter()
+            .take(limit)
+            .map(|(_, candidate)| match_case(word, candidate))
+            .collect()
+    }
+
+    fn add_word(&self, word: &str) {
+        let normalized = word.trim().replace('’', "'").to_lowercase();
+        if normalized.is_empty() {
+            return;
+        }
+        self.words
+            .write()
+            .expect("spellcheck dictionary poisoned")
+            .insert(normalized);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spellcheck_splits_words_and_flags_unknown_ones() {
+        let dictionary = WordListDictionary::from_text("the quick brown fox jumps");
+        let text = "The quikc brown fox's 'jumsp' 42 NASA x";
+        assert_eq!(words("it's 'ok'")[1], (6..8, "ok".to_string()));
+
+        let found = misspellings(text, &dictionary, &[]);
+        let flagged: Vec<_> = found.iter().map(|item| item.word.as_str()).collect();
+        assert_eq!(flagged, vec!["quikc", "jumsp"]);
+        assert_eq!(found[0].range, 4..9);
+        assert_eq!(
+            misspelling_at(&found, 9).map(|item| item.range.clone()),
+            Some(4..9)
+        );
+        assert_eq!(misspelling_at(&found, 12), None);
+
+        let ignored = misspellings(text, &dictionary, &["Quikc".to_string()]);
+        assert_eq!(ignored.len(), 1);
+    }
+
+    #[test]
+    fn spellcheck_dictionary_suggests_close_words_in_matching_case() {
+        let dictionary = WordListDictionary::new(["quick", "quack", "brown", "receive"]);
+        assert_eq!(dictionary.suggest("quikc", 3), vec!["quick", "quack"]);
+        assert_eq!(dictionary.suggest("quikc", 1), vec!["quick"]);
+        assert_eq!(dictionary.suggest("Recieve", 3), vec!["Receive"]);
+        assert_eq!(dictionary.suggest("BRWON", 3), vec!["BROWN"]);
+        assert!(dictionary.suggest("zzzzzz", 3).is_empty());
+
+        assert!(!dictionary.check("calmui"));
+        dictionary.add_word("calmui");
+        assert!(dictionary.check("Calmui"));
+        assert_eq!(dictionary.len(), 5);
+    }
+}
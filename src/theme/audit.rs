@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use super::*;
+
+/// WCAG 2.1 AA level a pair is held to. Disabled tokens are left out of the
+/// default pairs, as WCAG exempts inactive controls.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ContrastTarget {
+    /// Body text, 4.5:1.
+    Text,
+    /// Text of at least 18pt, or 14pt bold, 3:1.
+    LargeText,
+    /// Borders, focus rings and indicators that identify a control, 3:1.
+    NonText,
+}
+
+impl ContrastTarget {
+    pub fn min_ratio(self) -> f32 {
+        match self {
+            Self::Text => 4.5,
+            Self::LargeText | Self::NonText => 3.0,
+        }
+    }
+}
+
+/// Foreground and background token paths, as in [`Theme::design_tokens`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ContrastPair {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    pub target: ContrastTarget,
+}
+
+impl ContrastPair {
+    pub const fn text(foreground: &'static str, background: &'static str) -> Self {
+        Self::new(foreground, background, ContrastTarget::Text)
+    }
+
+    pub const fn large_text(foreground: &'static str, background: &'static str) -> Self {
+        Self::new(foreground, background, ContrastTarget::LargeText)
+    }
+
+    pub const fn non_text(foreground: &'static str, background: &'static str) -> Self {
+        Self::new(foreground, background, ContrastTarget::NonText)
+    }
+
+    const fn new(
+        foreground: &'static str,
+        background: &'static str,
+        target: ContrastTarget,
+    ) -> Self {
+        Self {
+            foreground,
+            background,
+            target,
+        }
+    }
+}
+
+/// A pair below its target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContrastFinding {
+    pub pair: ContrastPair,
+    pub foreground: Hsla,
+    pub background: Hsla,
+    pub ratio: f32,
+}
+
+impl ContrastFinding {
+    pub fn required(&self) -> f32 {
+        self.pair.target.min_ratio()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuditReport {
+    /// Number of pairs whose tokens both resolved.
+    pub checked: usize,
+    pub findings: Vec<ContrastFinding>,
+    /// Token paths named by a pair that the theme does not have.
+    pub unknown_paths: Vec<&'static str>,
+}
+
+impl AuditReport {
+    pub fn passes(&self) -> bool {
+        self.findings.is_empty() && self.unknown_paths.is_empty()
+    }
+
+    /// Findings whose foreground is `path`.
+    pub fn findings_for<'a>(
+        &'a self,
+        path: &'a str,
+    ) -> impl Iterator<Item = &'a ContrastFinding> + 'a {
+        self.findings
+            .iter()
+            .filter(move |finding| finding.pair.foreground == path)
+    }
+}
+
+/// Text and control pairs the built-in components draw.
+pub const DEFAULT_PAIRS: &[ContrastPair] = &[
+    ContrastPair::text("semantic.text_primary", "semantic.bg_canvas"),
+    ContrastPair::text("semantic.text_primary", "semantic.bg_surface"),
+    ContrastPair::text("semantic.text_primary", "semantic.bg_soft"),
+    ContrastPair::text("semantic.text_secondary", "semantic.bg_canvas"),
+    ContrastPair::text("semantic.text_muted", "semantic.bg_canvas"),
+    ContrastPair::non_text("semantic.border_strong", "semantic.bg_canvas"),
+    ContrastPair::non_text("semantic.focus_ring", "semantic.bg_canvas"),
+    ContrastPair::text("components.button.filled_fg", "components.button.filled_bg"),
+    ContrastPair::text("components.button.light_fg", "components.button.light_bg"),
+    ContrastPair::text("components.button.subtle_fg", "components.button.subtle_bg"),
+    ContrastPair::text("components.button.outline_fg", "semantic.bg_canvas"),
+    ContrastPair::text("components.button.ghost_fg", "semantic.bg_canvas"),
+    ContrastPair::text("components.input.fg", "components.input.bg"),
+    ContrastPair::text("components.input.placeholder", "components.input.bg"),
+    ContrastPair::text("components.input.label", "semantic.bg_canvas"),
+    ContrastPair::text("components.input.error", "semantic.bg_canvas"),
+    ContrastPair::non_text("components.input.border_focus", "components.input.bg"),
+    ContrastPair::text("components.textarea.fg", "components.textarea.bg"),
+    ContrastPair::text("components.textarea.placeholder", "components.textarea.bg"),
+    ContrastPair::text("components.select.fg", "components.select.bg"),
+    ContrastPair::text("components.select.placeholder", "components.select.bg"),
+    ContrastPair::text(
+        "components.select.option_fg",
+        "components.select.dropdown_bg",
+    ),
+    ContrastPair::non_text(
+        "components.checkbox.indicator",
+        "components.checkbox.control_bg_checked",
+    ),
+    ContrastPair::text("components.badge.filled_fg", "components.badge.filled_bg"),
+    ContrastPair::text("components.badge.light_fg", "components.badge.light_bg"),
+    ContrastPair::text("components.toast.info_fg", "components.toast.info_bg"),
+    ContrastPair::text("components.toast.success_fg", "components.toast.success_bg"),
+    ContrastPair::text("components.toast.warning_fg", "components.toast.warning_bg"),
+    ContrastPair::text("components.toast.error_fg", "components.toast.error_bg"),
+    ContrastPair::text("components.tooltip.fg", "components.tooltip.bg"),
+    ContrastPair::text("components.menu.item_fg", "components.menu.dropdown_bg"),
+    ContrastPair::text("components.popover.body", "components.popover.bg"),
+    ContrastPair::text("components.modal.title", "components.modal.panel_bg"),
+    ContrastPair::text("components.modal.body", "components.modal.panel_bg"),
+    ContrastPair::text("components.tabs.tab_fg", "components.tabs.list_bg"),
+    ContrastPair::text(
+        "components.tabs.tab_active_fg",
+        "components.tabs.tab_active_bg",
+    ),
+    ContrastPair::text("components.tabs.panel_fg", "components.tabs.panel_bg"),
+    ContrastPair::text("components.table.header_fg", "components.table.header_bg"),
+    ContrastPair::text("components.table.cell_fg", "components.table.row_bg"),
+];
+
+/// Checks `theme` against [`DEFAULT_PAIRS`].
+pub fn audit(theme: &Theme) -> AuditReport {
+    audit_pairs(theme, DEFAULT_PAIRS)
+}
+
+/// Checks `theme` against `pairs`. Translucent colors are measured as drawn
+/// over `semantic.bg_canvas`.
+pub fn audit_pairs(theme: &Theme, pairs: &[ContrastPair]) -> AuditReport {
+    let colors = theme
+        .design_tokens()
+        .into_iter()
+        .filter_map(|token| match token.value {
+            DesignTokenValue::Color(color) => Some((token.path, color)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let canvas = theme.semantic.bg_canvas;
+    let mut report = AuditReport::default();
+    for pair in pairs {
+        let (Some(foreground), Some(background)) =
+            (colors.get(pair.foreground), colors.get(pair.background))
+        else {
+            for path in [pair.foreground, pair.background] {
+                if !colors.contains_key(path) && !report.unknown_paths.contains(&path) {
+                    report.unknown_paths.push(path);
+                }
+            }
+            continue;
+        };
+        let background = canvas.blend(*background);
+        let foreground = background.blend(*foreground);
+        let ratio = color_utils::contrast_ratio(foreground, background);
+        report.checked += 1;
+        if ratio < pair.target.min_ratio() {
+            report.findings.push(ContrastFinding {
+                pair: *pair,
+                foreground,
+                background,
+                ratio,
+            });
+        }
+    }
+    report
+}
+
+impl Theme {
+    /// Contrast report over [`DEFAULT_PAIRS`]; a test can
+    /// `assert!(theme.audit().passes())`.
+    pub fn audit(&self) -> AuditReport {
+        audit(self)
+    }
+
+    /// Raises every pair in `pairs` below its target. The foreground moves
+    /// toward black or white; when that would flip it to the other side of
+    /// the background, as with white text on a filled control, the
+    /// background darkens or lightens instead. Raised colors are opaque.
+    pub(super) fn meet_pair_contrast(&mut self, pairs: &[ContrastPair]) {
+        const MAX_PASSES: usize = 4;
+        for _ in 0..MAX_PASSES {
+            let report = audit_pairs(self, pairs);
+            if report.findings.is_empty() {
+                return;
+            }
+            for finding in report.findings {
+                let ContrastFinding {
+                    pair,
+                    foreground,
+                    background,
+                    ..
+                } = finding;
+                let target = finding.required();
+                let fg_is_lighter = color_utils::relative_luminance(foreground)
+                    > color_utils::relative_luminance(background);
+                let lightens = color_utils::readable_on(background) == white();
+                if fg_is_lighter == lightens {
+                    let raised = color_utils::ensure_contrast(foreground, background, target);
+                    self.replace_color_token(pair.foreground, raised);
+                } else {
+                    let raised = color_utils::ensure_contrast(background, foreground, target);
+                    self.replace_color_token(pair.background, raised);
+                }
+            }
+        }
+    }
+}
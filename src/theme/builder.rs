@@ -0,0 +1,144 @@
+use super::*;
+
+/// Fluent way to put a [`Theme`] together without writing out override
+/// structs, as in `ThemeBuilder::dark().primary(PaletteKey::Violet)
+/// .button(|button| button.filled_bg(color)).radius_scale(1.25).build()`.
+/// Every component has a method taking its overrides.
+///
+/// Scales multiply the radius and spacing tokens of the base theme, across
+/// every size step. Token overrides are applied after scaling and keep the
+/// exact value given. Palette overrides are applied before tokens are
+/// derived, so they retint the primary tokens wherever they appear in the
+/// chain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeBuilder {
+    base: Theme,
+    pub(super) overrides: ThemeOverrides,
+    palettes: BTreeMap<PaletteKey, ColorScale>,
+    factors: DimensionScale,
+}
+
+impl ThemeBuilder {
+    pub fn light() -> Self {
+        Self::from_theme(Theme::default())
+    }
+
+    pub fn dark() -> Self {
+        Self::from_theme(Theme::default().with_color_scheme(ColorScheme::Dark))
+    }
+
+    /// Starts from an existing theme, keeping all of its tokens.
+    pub fn from_theme(theme: Theme) -> Self {
+        Self {
+            base: theme,
+            overrides: ThemeOverrides::default(),
+            palettes: BTreeMap::new(),
+            factors: DimensionScale {
+                radius: 1.0,
+                spacing: 1.0,
+            },
+        }
+    }
+
+    /// Switches scheme, recomputing the scheme's default tokens.
+    pub fn color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.base = self.base.with_color_scheme(scheme);
+        self
+    }
+
+    /// Derives every semantic and component token from `seed`.
+    pub fn seed(mut self, seed: ThemeSeed) -> Self {
+        self.base = self.base.with_seed(seed);
+        self
+    }
+
+    pub fn contrast(mut self, level: ContrastLevel) -> Self {
+        self.base = self.base.with_contrast(level);
+        self
+    }
+
+    pub fn primary(mut self, key: PaletteKey) -> Self {
+        self.base = self.base.with_primary_color(key);
+        self
+    }
+
+    /// Uses a scale generated from `color` as the primary palette.
+    pub fn brand_color(mut self, color: Hsla) -> Self {
+        self.palettes
+            .remove(&color_utils::nearest_palette_key(color));
+        self.base = self.base.with_brand_color(color);
+        self
+    }
+
+    pub fn primary_shade(mut self, shade: u8) -> Self {
+        self.base = self.base.with_primary_shade(shade);
+        self
+    }
+
+    pub fn primary_shades(mut self, light: u8, dark: u8) -> Self {
+        self.base = self.base.with_primary_shades(light, dark);
+        self
+    }
+
+    pub fn palette_override(mut self, key: PaletteKey, scale: ColorScale) -> Self {
+        self.palettes.insert(key, scale);
+        self
+    }
+
+    /// Multiplies every radius: the theme radii and each component's
+    /// radius tokens. `radii.pill` is left alone.
+    pub fn radius_scale(mut self, factor: f32) -> Self {
+        self.factors.radius = factor.max(0.0);
+        self
+    }
+
+    /// Multiplies every padding, gap, margin and inset token, at each size.
+    pub fn spacing_scale(mut self, factor: f32) -> Self {
+        self.factors.spacing = factor.max(0.0);
+        self
+    }
+
+    pub fn radii(mut self, configure: impl FnOnce(RadiiOverrides) -> RadiiOverrides) -> Self {
+        self.overrides = self.overrides.radii(configure);
+        self
+    }
+
+    pub fn semantic(
+        mut self,
+        configure: impl FnOnce(SemanticOverrides) -> SemanticOverrides,
+    ) -> Self {
+        self.overrides = self.overrides.semantic(configure);
+        self
+    }
+
+    pub fn components(
+        mut self,
+        configure: impl FnOnce(ComponentOverrides) -> ComponentOverrides,
+    ) -> Self {
+        self.overrides = self.overrides.components(configure);
+        self
+    }
+
+    pub fn build(self) -> Theme {
+        let mut theme = self.base;
+        let retints_primary = self.palettes.contains_key(&theme.primary_color);
+        theme.palette.extend(self.palettes);
+        if retints_primary {
+            theme.derive_tokens();
+        }
+        theme.scale_dimensions(self.factors);
+        theme.merged(&self.overrides)
+    }
+}
+
+impl Default for ThemeBuilder {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl From<ThemeBuilder> for Theme {
+    fn from(builder: ThemeBuilder) -> Self {
+        builder.build()
+    }
+}
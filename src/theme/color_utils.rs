@@ -0,0 +1,136 @@
+use std::collections::BTreeSet;
+use std::sync::{LazyLock, Mutex};
+
+use crate::tokens::{COLOR_STOPS, ColorScale, PALETTE_KEYS, PaletteCatalog, PaletteKey};
+use gpui::{Hsla, Rgba, black, white};
+
+pub fn mix(from: Hsla, to: Hsla, weight: f32) -> Hsla {
+    let weight = weight.clamp(0.0, 1.0);
+    let from = from.to_rgb();
+    let to = to.to_rgb();
+    let channel = |a: f32, b: f32| a + (b - a) * weight;
+    Rgba {
+        r: channel(from.r, to.r),
+        g: channel(from.g, to.g),
+        b: channel(from.b, to.b),
+        a: channel(from.a, to.a),
+    }
+    .into()
+}
+
+pub fn lighten(color: Hsla, amount: f32) -> Hsla {
+    color.blend(white().opacity(amount.clamp(0.0, 1.0)))
+}
+
+pub fn darken(color: Hsla, amount: f32) -> Hsla {
+    color.blend(black().opacity(amount.clamp(0.0, 1.0)))
+}
+
+pub fn with_alpha(color: Hsla, alpha: f32) -> Hsla {
+    Hsla {
+        a: alpha.clamp(0.0, 1.0),
+        ..color
+    }
+}
+
+pub fn relative_luminance(color: Hsla) -> f32 {
+    let rgb = color.to_rgb();
+    let linear = |value: f32| {
+        let value = value.clamp(0.0, 1.0);
+        if value <= 0.03928 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(rgb.r) + 0.7152 * linear(rgb.g) + 0.0722 * linear(rgb.b)
+}
+
+pub fn contrast_ratio(a: Hsla, b: Hsla) -> f32 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    let (light, dark) = if la >= lb { (la, lb) } else { (lb, la) };
+    (light + 0.05) / (dark + 0.05)
+}
+
+/// Moves `fg` toward black or white, whichever `bg` contrasts with more,
+/// until the pair reaches `target`. Colors already meeting it are returned
+/// unchanged.
+pub fn ensure_contrast(fg: Hsla, bg: Hsla, target: f32) -> Hsla {
+    let end = with_alpha(readable_on(bg), fg.a);
+    let mut adjusted = fg;
+    let mut step = 0;
+    while contrast_ratio(adjusted, bg) < target && step < CONTRAST_STEPS {
+        step += 1;
+        adjusted = mix(fg, end, step as f32 / CONTRAST_STEPS as f32);
+    }
+    adjusted
+}
+
+pub fn readable_on(bg: Hsla) -> Hsla {
+    let light = white();
+    let dark = black();
+    if contrast_ratio(light, bg) >= contrast_ratio(dark, bg) {
+        light
+    } else {
+        dark
+    }
+}
+
+pub fn to_hex(color: Hsla) -> String {
+    let rgb = color.to_rgb();
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(rgb.r),
+        channel(rgb.g),
+        channel(rgb.b)
+    )
+}
+
+pub fn scale_from_color(brand: Hsla) -> ColorScale {
+    let anchor = brand.l.clamp(0.3, 0.6);
+    std::array::from_fn(|stop| {
+        let lightness = if stop <= BRAND_STOP {
+            0.97 + (anchor - 0.97) * stop as f32 / BRAND_STOP as f32
+        } else {
+            let progress = (stop - BRAND_STOP) as f32 / (COLOR_STOPS - 1 - BRAND_STOP) as f32;
+            anchor - anchor * 0.45 * progress
+        };
+        intern_hex(to_hex(Hsla {
+            l: lightness,
+            a: 1.0,
+            ..brand
+        }))
+    })
+}
+
+pub fn nearest_palette_key(color: Hsla) -> PaletteKey {
+    let target = color.to_rgb();
+    let distance = |key: PaletteKey| {
+        let candidate = Rgba::try_from(PaletteCatalog::scale(key)[BRAND_STOP])
+            .unwrap_or_else(|_| black().to_rgb());
+        (candidate.r - target.r).powi(2)
+            + (candidate.g - target.g).powi(2)
+            + (candidate.b - target.b).powi(2)
+    };
+    PALETTE_KEYS
+        .into_iter()
+        .filter(|key| color.s < 0.12 || !matches!(key, PaletteKey::Dark | PaletteKey::Gray))
+        .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+        .unwrap_or(PaletteKey::Blue)
+}
+
+const BRAND_STOP: usize = 6;
+const CONTRAST_STEPS: u32 = 20;
+
+pub(crate) fn intern_hex(hex: String) -> &'static str {
+    static INTERNED: LazyLock<Mutex<BTreeSet<&'static str>>> =
+        LazyLock::new(|| Mutex::new(BTreeSet::new()));
+    let mut interned = INTERNED.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some(existing) = interned.get(hex.as_str()) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(hex.into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
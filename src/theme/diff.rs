@@ -0,0 +1,132 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ThemeOverrideIssueKind {
+    Redundant,
+    OutOfRange,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThemeOverrideIssue {
+    pub path: String,
+    pub kind: ThemeOverrideIssueKind,
+}
+
+impl ThemeOverrideIssue {
+    pub(crate) fn new(path: impl Into<String>, kind: ThemeOverrideIssueKind) -> Self {
+        Self {
+            path: path.into(),
+            kind,
+        }
+    }
+}
+
+pub(crate) trait OverridePatch<T> {
+    fn diff(base: &T, target: &T) -> Self;
+    fn collect_issues(&self, current: &T, prefix: &str, issues: &mut Vec<ThemeOverrideIssue>);
+}
+
+pub(super) fn check_value<T: PartialEq>(
+    issues: &mut Vec<ThemeOverrideIssue>,
+    path: &str,
+    value: Option<&T>,
+    current: &T,
+) {
+    if value == Some(current) {
+        issues.push(ThemeOverrideIssue::new(
+            path,
+            ThemeOverrideIssueKind::Redundant,
+        ));
+    }
+}
+
+fn check_shade(issues: &mut Vec<ThemeOverrideIssue>, path: &str, value: Option<u8>, current: u8) {
+    match value {
+        Some(shade) if shade > 9 => issues.push(ThemeOverrideIssue::new(
+            path,
+            ThemeOverrideIssueKind::OutOfRange,
+        )),
+        _ => check_value(issues, path, value.as_ref(), &current),
+    }
+}
+
+fn changed<T: Clone + PartialEq>(base: &T, target: &T) -> Option<T> {
+    (base != target).then(|| target.clone())
+}
+
+impl Theme {
+    pub fn diff(&self, other: &Theme) -> ThemeOverrides {
+        let mut base = self.clone();
+        let primary_color = changed(&self.primary_color, &other.primary_color);
+        if let Some(primary) = primary_color {
+            base = base.with_primary_color(primary);
+        }
+        ThemeOverrides {
+            primary_color,
+            primary_shade_light: changed(&self.primary_shade_light, &other.primary_shade_light),
+            primary_shade_dark: changed(&self.primary_shade_dark, &other.primary_shade_dark),
+            color_scheme: changed(&self.color_scheme, &other.color_scheme),
+            palette_overrides: other
+                .palette
+                .iter()
+                .filter(|(key, scale)| self.palette.get(*key) != Some(*scale))
+                .map(|(key, scale)| (*key, *scale))
+                .collect(),
+            radii: RadiiOverrides::diff(&base.radii, &other.radii),
+            semantic: SemanticOverrides::diff(&base.semantic, &other.semantic),
+            components: ComponentOverrides::diff(&base.components, &other.components),
+        }
+    }
+}
+
+impl ThemeOverrides {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub fn validate(&self, theme: &Theme) -> Vec<ThemeOverrideIssue> {
+        let mut issues = Vec::new();
+        let mut base = theme.clone();
+        check_value(
+            &mut issues,
+            "primary_color",
+            self.primary_color.as_ref(),
+            &theme.primary_color,
+        );
+        if let Some(primary) = self.primary_color {
+            base = base.with_primary_color(primary);
+        }
+        check_shade(
+            &mut issues,
+            "primary_shade_light",
+            self.primary_shade_light,
+            theme.primary_shade_light,
+        );
+        check_shade(
+            &mut issues,
+            "primary_shade_dark",
+            self.primary_shade_dark,
+            theme.primary_shade_dark,
+        );
+        check_value(
+            &mut issues,
+            "color_scheme",
+            self.color_scheme.as_ref(),
+            &theme.color_scheme,
+        );
+        for (key, scale) in &self.palette_overrides {
+            if theme.palette.get(key) == Some(scale) {
+                issues.push(ThemeOverrideIssue::new(
+                    format!("palette.{}", key.as_str()),
+                    ThemeOverrideIssueKind::Redundant,
+                ));
+            }
+        }
+        self.radii.collect_issues(&base.radii, "radii", &mut issues);
+        self.semantic
+            .collect_issues(&base.semantic, "semantic", &mut issues);
+        self.components
+            .collect_issues(&base.components, "components", &mut issues);
+        issues
+    }
+}
@@ -0,0 +1,454 @@
+use std::fmt::Write as _;
+
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DesignTokenValue {
+    Color(Hsla),
+    Dimension(Pixels),
+    FontWeight(FontWeight),
+    Size(Size),
+    Keyword(&'static str),
+}
+
+impl DesignTokenValue {
+    pub fn figma_type(&self) -> &'static str {
+        match self {
+            Self::Color(_) => "color",
+            Self::Dimension(_) => "dimension",
+            Self::FontWeight(_) => "fontWeights",
+            Self::Size(_) | Self::Keyword(_) => "other",
+        }
+    }
+
+    pub fn css_value(&self) -> String {
+        match self {
+            Self::Color(color) => hex_color(*color),
+            Self::Dimension(pixels) => format!("{}px", trim_number(f32::from(*pixels))),
+            Self::FontWeight(weight) => trim_number(weight.0),
+            Self::Size(size) => match size {
+                Size::Xs => "xs",
+                Size::Sm => "sm",
+                Size::Md => "md",
+                Size::Lg => "lg",
+                Size::Xl => "xl",
+            }
+            .to_string(),
+            Self::Keyword(keyword) => keyword.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DesignToken {
+    pub path: String,
+    pub value: DesignTokenValue,
+}
+
+impl DesignToken {
+    pub fn css_variable(&self, prefix: &str) -> String {
+        let name = self.path.replace(['.', '_'], "-");
+        if prefix.is_empty() {
+            format!("--{name}")
+        } else {
+            format!("--{prefix}-{name}")
+        }
+    }
+}
+
+pub(crate) trait ExportTokens {
+    fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>);
+}
+
+impl ExportTokens for Hsla {
+    fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+        push_token(tokens, path, DesignTokenValue::Color(*self));
+    }
+}
+
+impl ExportTokens for Pixels {
+    fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+        push_token(tokens, path, DesignTokenValue::Dimension(*self));
+    }
+}
+
+impl ExportTokens for FontWeight {
+    fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+        push_token(tokens, path, DesignTokenValue::FontWeight(*self));
+    }
+}
+
+impl ExportTokens for Size {
+    fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+        push_token(tokens, path, DesignTokenValue::Size(*self));
+    }
+}
+
+impl ExportTokens for FocusRingStyle {
+    fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+        let keyword = match self {
+            FocusRingStyle::Solid => "solid",
+            FocusRingStyle::Dashed => "dashed",
+            FocusRingStyle::Double => "double",
+        };
+        push_token(tokens, path, DesignTokenValue::Keyword(keyword));
+    }
+}
+
+impl ExportTokens for PressFeedbackStyle {
+    fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+        let keyword = match self {
+            PressFeedbackStyle::None => "none",
+            PressFeedbackStyle::Scale => "scale",
+            PressFeedbackStyle::Ripple => "ripple",
+        };
+        push_token(tokens, path, DesignTokenValue::Keyword(keyword));
+    }
+}
+
+fn push_token(tokens: &mut Vec<DesignToken>, path: &str, value: DesignTokenValue) {
+    tokens.push(DesignToken {
+        path: path.to_string(),
+        value,
+    });
+}
+
+macro_rules! impl_struct_export {
+    ($($type:ty { $($field:ident),* $(,)? }),* $(,)?) => {
+        $(
+            impl ExportTokens for $type {
+                fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+                    $(
+                        self.$field
+                            .export_tokens(&format!("{path}.{}", stringify!($field)), tokens);
+                    )*
+                }
+            }
+
+            impl ScaleTokens for $type {
+                fn scale(&mut self, _field: &str, factors: DimensionScale) {
+                    $(
+                        self.$field.scale(stringify!($field), factors);
+                    )*
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_size_scale_export {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl ExportTokens for $type {
+                fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+                    for (size, value) in [
+                        ("xs", &self.xs),
+                        ("sm", &self.sm),
+                        ("md", &self.md),
+                        ("lg", &self.lg),
+                        ("xl", &self.xl),
+                    ] {
+                        value.export_tokens(&format!("{path}.{size}"), tokens);
+                    }
+                }
+            }
+
+            // Steps are named by size, so they take the name of the field
+            // holding the scale: every step of a `gap` scale is spacing.
+            impl ScaleTokens for $type {
+                fn scale(&mut self, field: &str, factors: DimensionScale) {
+                    for value in [
+                        &mut self.xs,
+                        &mut self.sm,
+                        &mut self.md,
+                        &mut self.lg,
+                        &mut self.xl,
+                    ] {
+                        value.scale(field, factors);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_size_scale_export!(
+    ButtonSizeScale,
+    FieldSizeScale,
+    InsetSizeScale,
+    GapSizeScale,
+    ChoiceControlSizeScale,
+    SwitchSizeScale,
+    BadgeSizeScale,
+    AccordionSizeScale,
+    ProgressSizeScale,
+    SliderSizeScale,
+    TableSizeScale,
+    TreeSizeScale,
+    LoaderSizeScale,
+    TextSizeScale,
+    ActionIconSizeScale,
+    SegmentedControlSizeScale,
+    RatingSizeScale,
+    TabsSizeScale,
+    PaginationSizeScale,
+    BreadcrumbsSizeScale,
+    StepperSizeScale,
+    TimelineSizeScale,
+    EmptyStateSizeScale,
+);
+
+impl_struct_export!(
+    ButtonSizePreset {
+        font_size,
+        line_height,
+        padding_x,
+        padding_y,
+        content_gap,
+    },
+    FieldSizePreset {
+        font_size,
+        line_height,
+        padding_x,
+        padding_y,
+        caret_height,
+    },
+    ChoiceControlSizePreset {
+        control_size,
+        indicator_size,
+        label_size,
+        description_size,
+        content_gap,
+        description_indent_gap,
+    },
+    SwitchSizePreset {
+        track_width,
+        track_height,
+        thumb_size,
+        label_size,
+        description_size,
+        label_gap,
+        description_indent_gap,
+    },
+    BadgeSizePreset {
+        font_size,
+        padding_x,
+        padding_y,
+        gap,
+    },
+    AccordionSizePreset {
+        label_size,
+        description_size,
+        content_size,
+        chevron_size,
+        header_padding_x,
+        header_padding_y,
+        panel_padding_x,
+        panel_padding_bottom,
+        panel_padding_top,
+    },
+    ProgressSizePreset {
+        bar_height,
+        label_size,
+    },
+    SliderSizePreset {
+        track_thickness,
+        thumb_size,
+    },
+    TableSizePreset {
+        font_size,
+        padding_x,
+        padding_y,
+        row_height,
+    },
+    TreeSizePreset {
+        label_size,
+        indent,
+        row_padding_y,
+        row_padding_right,
+        row_inner_gap,
+        toggle_size,
+        toggle_icon_size,
+        connector_stub_width,
+        child_line_margin,
+        child_line_padding,
+    },
+    LoaderSizePreset {
+        dot_size,
+        ring_size,
+        bar_width,
+        bar_height_max,
+        cluster_gap,
+        label_size,
+        label_gap,
+    },
+    TextSizePreset {
+        font_size,
+        line_height,
+    },
+    ActionIconSizePreset {
+        box_size,
+        icon_size,
+    },
+    SegmentedControlSizePreset {
+        font_size,
+        line_height,
+        padding_x,
+        padding_y,
+        indicator_inset,
+        divider_height,
+    },
+    RatingSizePreset { icon_size, gap },
+    TabsSizePreset {
+        font_size,
+        line_height,
+        padding_x,
+        padding_y,
+    },
+    PaginationSizePreset {
+        font_size,
+        padding_x,
+        padding_y,
+        min_width,
+    },
+    BreadcrumbsSizePreset {
+        font_size,
+        item_padding_x,
+        item_padding_y,
+        item_radius,
+    },
+    StepperSizePreset {
+        indicator_size,
+        connector_thickness,
+        connector_span,
+        label_size,
+        description_size,
+        item_padding,
+        item_gap_vertical,
+        item_gap_horizontal,
+        panel_padding,
+    },
+    TimelineSizePreset {
+        bullet_size,
+        line_width,
+        title_size,
+        body_size,
+        card_padding,
+    },
+    EmptyStateSizePreset {
+        icon_size,
+        icon_box_size,
+        title_size,
+        description_size,
+        gap,
+        max_width,
+        padding,
+    },
+);
+
+fn trim_number(value: f32) -> String {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{rounded}")
+    }
+}
+
+fn hex_color(color: Hsla) -> String {
+    let rgba = color.to_rgb();
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let mut hex = format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(rgba.r),
+        channel(rgba.g),
+        channel(rgba.b)
+    );
+    if channel(rgba.a) < u8::MAX {
+        let _ = write!(hex, "{:02x}", channel(rgba.a));
+    }
+    hex
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            ch if ch.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", ch as u32);
+            }
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn write_json_group(out: &mut String, tokens: &[(Vec<&str>, &DesignToken)], depth: usize) {
+    let indent = "  ".repeat(depth + 1);
+    out.push_str("{\n");
+    let mut index = 0;
+    while index < tokens.len() {
+        let key = tokens[index].0[depth];
+        let end = tokens[index..]
+            .iter()
+            .position(|(segments, _)| segments[depth] != key)
+            .map_or(tokens.len(), |offset| index + offset);
+        let _ = write!(out, "{indent}{}: ", json_string(key));
+        let (segments, token) = &tokens[index];
+        if segments.len() == depth + 1 {
+            let _ = write!(
+                out,
+                "{{ \"value\": {}, \"type\": {} }}",
+                json_string(&token.value.css_value()),
+                json_string(token.value.figma_type())
+            );
+        } else {
+            write_json_group(out, &tokens[index..end], depth + 1);
+        }
+        out.push_str(if end < tokens.len() { ",\n" } else { "\n" });
+        index = end;
+    }
+    out.push_str(&"  ".repeat(depth));
+    out.push('}');
+}
+
+impl Theme {
+    pub fn design_tokens(&self) -> Vec<DesignToken> {
+        let mut tokens = Vec::new();
+        self.radii.export_tokens("radii", &mut tokens);
+        self.semantic.export_tokens("semantic", &mut tokens);
+        self.components.export_tokens("components", &mut tokens);
+        tokens
+    }
+
+    pub fn to_css_variables(&self, selector: &str, prefix: &str) -> String {
+        let mut css = format!("{selector} {{\n");
+        for token in self.design_tokens() {
+            let _ = writeln!(
+                css,
+                "  {}: {};",
+                token.css_variable(prefix),
+                token.value.css_value()
+            );
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    pub fn to_figma_tokens_json(&self) -> String {
+        let tokens = self.design_tokens();
+        let entries = tokens
+            .iter()
+            .map(|token| (token.path.split('.').collect::<Vec<_>>(), token))
+            .collect::<Vec<_>>();
+        let mut json = String::new();
+        write_json_group(&mut json, &entries, 0);
+        json.push('\n');
+        json
+    }
+}
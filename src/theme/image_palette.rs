@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use super::color_utils;
+use crate::tokens::ColorScale;
+use gpui::{Hsla, Rgba};
+
+const MAX_SAMPLES: usize = 16_384;
+const MERGE_DISTANCE: f32 = 0.08;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PaletteSwatch {
+    pub color: Hsla,
+    pub population: f32,
+}
+
+pub fn dominant_colors(rgba: &[u8], max_colors: usize) -> Vec<PaletteSwatch> {
+    let pixels = rgba.len() / 4;
+    if pixels == 0 || max_colors == 0 {
+        return Vec::new();
+    }
+    let stride = pixels.div_ceil(MAX_SAMPLES).max(1);
+    let mut buckets: HashMap<u16, (u32, [f32; 3])> = HashMap::new();
+    let mut sampled = 0_u32;
+    for pixel in rgba.chunks_exact(4).step_by(stride) {
+        if pixel[3] < 128 {
+            continue;
+        }
+        let key = (u16::from(pixel[0] >> 3) << 10)
+            | (u16::from(pixel[1] >> 3) << 5)
+            | u16::from(pixel[2] >> 3);
+        let entry = buckets.entry(key).or_insert((0, [0.0; 3]));
+        entry.0 += 1;
+        for (sum, channel) in entry.1.iter_mut().zip(&pixel[..3]) {
+            *sum += f32::from(*channel) / 255.0;
+        }
+        sampled += 1;
+    }
+    if sampled == 0 {
+        return Vec::new();
+    }
+
+    let mut ranked = buckets.into_values().collect::<Vec<_>>();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0));
+    let mut clusters: Vec<(u32, [f32; 3])> = Vec::new();
+    for (count, sum) in ranked {
+        let mean = sum.map(|value| value / count as f32);
+        let nearest = clusters.iter_mut().find(|(cluster_count, cluster_sum)| {
+            let center = cluster_sum.map(|value| value / *cluster_count as f32);
+            distance(center, mean) < MERGE_DISTANCE
+        });
+        match nearest {
+            Some(cluster) => {
+                cluster.0 += count;
+                for (total, value) in cluster.1.iter_mut().zip(sum) {
+                    *total += value;
+                }
+            }
+            None => clusters.push((count, sum)),
+        }
+    }
+    clusters.sort_by(|a, b| b.0.cmp(&a.0));
+    clusters
+        .into_iter()
+        .take(max_colors)
+        .map(|(count, sum)| {
+            let [r, g, b] = sum.map(|value| value / count as f32);
+            PaletteSwatch {
+                color: Rgba { r, g, b, a: 1.0 }.into(),
+                population: count as f32 / sampled as f32,
+            }
+        })
+        .collect()
+}
+
+pub fn brand_color_from_image(rgba: &[u8]) -> Option<Hsla> {
+    dominant_colors(rgba, 8)
+        .into_iter()
+        .map(|swatch| (swatch, vibrancy(swatch.color) * swatch.population))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(swatch, _)| swatch.color)
+}
+
+pub fn scale_from_image(rgba: &[u8]) -> Option<ColorScale> {
+    brand_color_from_image(rgba).map(color_utils::scale_from_color)
+}
+
+fn vibrancy(color: Hsla) -> f32 {
+    let lightness = 1.0 - (color.l - 0.5).abs() * 2.0;
+    0.1 + color.s * lightness.max(0.1)
+}
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
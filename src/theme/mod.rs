@@ -4,11 +4,37 @@ use std::sync::{Arc, OnceLock};
 use crate::style::{Radius, Size};
 use crate::tokens::{ColorScale, PaletteCatalog, PaletteKey};
 use gpui::{
-    Background, Corners, Fill, FontWeight, Hsla, Pixels, Rgba, black, px, transparent_black, white,
+    Background, Corners, Fill, Font, FontFallbacks, FontWeight, Hsla, Pixels, Rgba, SharedString,
+    black, font, px, transparent_black, white,
 };
 
+pub mod audit;
+mod builder;
+pub mod color_utils;
+mod diff;
+mod export;
+#[cfg(feature = "image-palette")]
+pub mod image_palette;
 mod overrides_api;
+mod pseudo_state;
+mod retint;
+mod scale;
+mod seed;
 mod themable_impls;
+mod transition;
+
+pub use builder::ThemeBuilder;
+pub use diff::{ThemeOverrideIssue, ThemeOverrideIssueKind};
+pub use export::{DesignToken, DesignTokenValue};
+pub use pseudo_state::PseudoStateTokens;
+pub use seed::{ContrastLevel, ThemeSeed};
+pub use transition::{BlendTokens, ThemeTransition};
+
+use diff::{OverridePatch, check_value};
+use export::ExportTokens;
+use retint::RetintTokens;
+use scale::{DimensionScale, ScaleTokens};
+use seed::{SeedPalette, seed_scale};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ColorScheme {
@@ -16,6 +42,32 @@ pub enum ColorScheme {
     Dark,
 }
 
+impl From<gpui::WindowAppearance> for ColorScheme {
+    fn from(value: gpui::WindowAppearance) -> Self {
+        match value {
+            gpui::WindowAppearance::Light | gpui::WindowAppearance::VibrantLight => Self::Light,
+            gpui::WindowAppearance::Dark | gpui::WindowAppearance::VibrantDark => Self::Dark,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ColorSchemePreference {
+    Light,
+    Dark,
+    System,
+}
+
+impl ColorSchemePreference {
+    pub fn resolve(self, system: ColorScheme) -> ColorScheme {
+        match self {
+            Self::Light => ColorScheme::Light,
+            Self::Dark => ColorScheme::Dark,
+            Self::System => system,
+        }
+    }
+}
+
 pub const PRIMARY_SHADE_LIGHT_DEFAULT: u8 = 6;
 pub const PRIMARY_SHADE_DARK_DEFAULT: u8 = 8;
 pub const BUILTIN_TRANSPARENT_HEX: &str = "#00000000";
@@ -26,7 +78,11 @@ pub const COLOR_TOKEN_BLACK: ColorToken = ColorToken::Hex(BUILTIN_BLACK_HEX);
 pub const COLOR_TOKEN_WHITE: ColorToken = ColorToken::Hex(BUILTIN_WHITE_HEX);
 
 fn resolve_palette_hsla(key: PaletteKey, shade: u8) -> Hsla {
-    Rgba::try_from(PaletteCatalog::scale(key)[shade.min(9) as usize])
+    scale_hsla(PaletteCatalog::resolve(key), shade)
+}
+
+fn scale_hsla(scale: ColorScale, shade: u8) -> Hsla {
+    Rgba::try_from(scale[shade.min(9) as usize])
         .map(Into::into)
         .unwrap_or_else(|_| black())
 }
@@ -133,7 +189,10 @@ impl ColorToken {
         match self {
             ColorToken::Raw(value) => value,
             ColorToken::Hex(hex) => resolve_hex_hsla(hex),
-            ColorToken::Palette { key, shade } => resolve_palette_hsla(key, shade),
+            ColorToken::Palette { key, shade } => match theme.palette.get(&key) {
+                Some(scale) => scale_hsla(*scale, shade),
+                None => resolve_palette_hsla(key, shade),
+            },
             ColorToken::Semantic(value) => value.resolve(theme),
         }
     }
@@ -319,6 +378,53 @@ impl ResolveWithTheme<Corners<Pixels>> for &RadiusToken {
     }
 }
 
+/// Font families components pick explicitly. Each is a stack: the first
+/// family installed on the system is drawn, the rest are fallbacks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThemeFonts {
+    pub monospace: Vec<SharedString>,
+}
+
+impl ThemeFonts {
+    /// Font for code, logs and consoles.
+    pub fn monospace(&self) -> Font {
+        font_stack(&self.monospace)
+    }
+}
+
+impl Default for ThemeFonts {
+    fn default() -> Self {
+        let monospace: &[&str] = if cfg!(target_os = "macos") {
+            &["SF Mono", "SFMono-Regular", "Menlo", "Monaco"]
+        } else if cfg!(target_os = "windows") {
+            &["Cascadia Mono", "Consolas", "Courier New"]
+        } else {
+            &[
+                "DejaVu Sans Mono",
+                "Liberation Mono",
+                "Noto Sans Mono",
+                "Ubuntu Mono",
+            ]
+        };
+        Self {
+            monospace: monospace.iter().copied().map(SharedString::from).collect(),
+        }
+    }
+}
+
+fn font_stack(families: &[SharedString]) -> Font {
+    let Some((first, rest)) = families.split_first() else {
+        return font("monospace");
+    };
+    let mut resolved = font(first.clone());
+    if !rest.is_empty() {
+        resolved.fallbacks = Some(FontFallbacks::from_fonts(
+            rest.iter().map(ToString::to_string).collect(),
+        ));
+    }
+    resolved
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct ThemeRadii {
     pub default: Pixels,
@@ -368,98 +474,53 @@ impl SemanticColors {
     }
 
     pub fn defaults_for(primary: PaletteKey, scheme: ColorScheme) -> Self {
-        match scheme {
+        Self::from_seed(ThemeSeed::new(primary), scheme)
+    }
+
+    pub fn from_seed(seed: ThemeSeed, scheme: ColorScheme) -> Self {
+        Self::from_seed_palette(SeedPalette::new(seed, scheme))
+    }
+
+    pub(super) fn from_seed_palette(palette: SeedPalette) -> Self {
+        let (seed, scheme) = (palette.seed, palette.scheme);
+        let mut colors = match scheme {
             ColorScheme::Light => Self {
-                text_primary: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                text_secondary: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                text_muted: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                bg_canvas: white(),
-                bg_surface: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                bg_soft: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                border_subtle: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                border_strong: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                focus_ring: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                status_info: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Blue)[6_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                status_success: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Green)[6_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                status_warning: (Rgba::try_from(
-                    PaletteCatalog::scale(PaletteKey::Yellow)[7_usize],
-                )
-                .map(Into::into)
-                .unwrap_or_else(|_| black())),
-                status_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                overlay_mask: (Rgba::try_from("#00000073")
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                text_primary: palette.dark(9),
+                text_secondary: palette.gray(7),
+                text_muted: palette.gray(6),
+                bg_canvas: palette.surface(),
+                bg_surface: palette.gray(0),
+                bg_soft: palette.gray(1),
+                border_subtle: palette.gray(3),
+                border_strong: palette.gray(5),
+                focus_ring: palette.primary(6),
+                status_info: palette.color(PaletteKey::Blue, 6),
+                status_success: palette.color(PaletteKey::Green, 6),
+                status_warning: palette.color(PaletteKey::Yellow, 7),
+                status_error: palette.color(PaletteKey::Red, 6),
+                overlay_mask: resolve_hex_hsla("#00000073"),
             },
             ColorScheme::Dark => Self {
-                text_primary: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                text_secondary: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                text_muted: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                bg_canvas: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                bg_surface: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                bg_soft: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                border_subtle: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[5_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                border_strong: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                focus_ring: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                status_info: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Blue)[4_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                status_success: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Green)[4_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                status_warning: (Rgba::try_from(
-                    PaletteCatalog::scale(PaletteKey::Yellow)[4_usize],
-                )
-                .map(Into::into)
-                .unwrap_or_else(|_| black())),
-                status_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[4_usize])
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                overlay_mask: (Rgba::try_from("#000000CC")
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                text_primary: palette.gray(0),
+                text_secondary: palette.gray(3),
+                text_muted: palette.gray(5),
+                bg_canvas: palette.dark(9),
+                bg_surface: palette.dark(8),
+                bg_soft: palette.dark(7),
+                border_subtle: palette.dark(5),
+                border_strong: palette.dark(4),
+                focus_ring: palette.primary(5),
+                status_info: palette.color(PaletteKey::Blue, 4),
+                status_success: palette.color(PaletteKey::Green, 4),
+                status_warning: palette.color(PaletteKey::Yellow, 4),
+                status_error: palette.color(PaletteKey::Red, 4),
+                overlay_mask: resolve_hex_hsla("#000000CC"),
             },
+        };
+        if seed.contrast == ContrastLevel::High {
+            colors.meet_contrast_targets();
         }
+        colors
     }
 }
 
@@ -477,6 +538,7 @@ pub struct ButtonTokens {
     pub disabled_bg: Hsla,
     pub disabled_fg: Hsla,
     pub sizes: ButtonSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -718,6 +780,18 @@ pub struct InputTokens {
     pub horizontal_label_width: Pixels,
     pub pin_cells_gap: Pixels,
     pub pin_error_gap: Pixels,
+    pub password_toggle_size: Pixels,
+    pub password_caps_lock_fg: Hsla,
+    pub password_caps_lock_size: Pixels,
+    pub password_meter_gap: Pixels,
+    pub password_meter_height: Pixels,
+    pub password_meter_segment_gap: Pixels,
+    pub password_meter_track: Hsla,
+    pub password_strength_weak: Hsla,
+    pub password_strength_fair: Hsla,
+    pub password_strength_good: Hsla,
+    pub password_strength_strong: Hsla,
+    pub password_strength_label_size: Pixels,
     pub sizes: FieldSizeScale,
 }
 
@@ -1044,6 +1118,7 @@ pub struct AccordionTokens {
     pub label_stack_gap: Pixels,
     pub panel_gap: Pixels,
     pub sizes: AccordionSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -1159,6 +1234,7 @@ pub struct MenuTokens {
     pub dropdown_radius: Pixels,
     pub dropdown_width_fallback: Pixels,
     pub dropdown_min_width: Pixels,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1238,6 +1314,12 @@ pub struct SliderTokens {
     pub header_gap_horizontal: Pixels,
     pub default_width: Pixels,
     pub min_width: Pixels,
+    pub mark_bg: Hsla,
+    pub mark_size: Pixels,
+    pub mark_label: Hsla,
+    pub mark_label_size: Pixels,
+    pub mark_label_gap: Pixels,
+    pub tooltip_offset: Pixels,
     pub sizes: SliderSizeScale,
 }
 
@@ -1648,6 +1730,7 @@ pub struct SelectTokens {
     pub tag_max_width: Pixels,
     pub dropdown_anchor_offset: Pixels,
     pub sizes: FieldSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1697,6 +1780,8 @@ pub struct ToastTokens {
     pub stack_gap: Pixels,
     pub edge_offset: Pixels,
     pub top_offset_extra: Pixels,
+    pub action_gap: Pixels,
+    pub progress_height: Pixels,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1714,6 +1799,15 @@ pub struct ScrollAreaTokens {
     pub bg: Hsla,
     pub border: Hsla,
     pub padding: InsetSizeScale,
+    pub minimap_width: Pixels,
+    pub minimap_bg: Hsla,
+    pub minimap_row: Hsla,
+    pub minimap_viewport_bg: Hsla,
+    pub minimap_viewport_border: Hsla,
+    pub minimap_marker_info: Hsla,
+    pub minimap_marker_search: Hsla,
+    pub minimap_marker_warning: Hsla,
+    pub minimap_marker_error: Hsla,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1751,6 +1845,8 @@ pub struct AppShellTokens {
     pub inspector_min_width: Pixels,
     pub bottom_panel_height: Pixels,
     pub bottom_panel_min_height: Pixels,
+    pub resize_handle_bg: Hsla,
+    pub resize_handle_size: Pixels,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1846,6 +1942,9 @@ pub struct MarkdownTokens {
     pub image_border: Hsla,
     pub image_bg: Hsla,
     pub image_caption_fg: Hsla,
+    pub image_placeholder_bg: Hsla,
+    pub footnote_fg: Hsla,
+    pub selection_bg: Hsla,
     pub gap_regular: Pixels,
     pub gap_compact: Pixels,
     pub paragraph_size: Pixels,
@@ -1859,6 +1958,7 @@ pub struct MarkdownTokens {
     pub list_line_height: Pixels,
     pub table_size: Pixels,
     pub image_caption_size: Pixels,
+    pub footnote_size: Pixels,
     pub quote_padding_x: Pixels,
     pub quote_padding_y: Pixels,
     pub quote_radius: Pixels,
@@ -1880,6 +1980,7 @@ pub struct MarkdownTokens {
     pub image_radius: Pixels,
     pub image_padding: Pixels,
     pub image_gap: Pixels,
+    pub image_placeholder_height: Pixels,
     pub heading2_padding_top: Pixels,
 }
 
@@ -1892,6 +1993,7 @@ pub struct TextTokens {
     pub success: Hsla,
     pub warning: Hsla,
     pub error: Hsla,
+    pub selection_bg: Hsla,
     pub sizes: TextSizeScale,
 }
 
@@ -2008,6 +2110,7 @@ pub struct ActionIconTokens {
     pub disabled_fg: Hsla,
     pub disabled_border: Hsla,
     pub sizes: ActionIconSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -2074,6 +2177,7 @@ pub struct SegmentedControlTokens {
     pub track_padding: Pixels,
     pub item_gap: Pixels,
     pub sizes: SegmentedControlSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -2162,6 +2266,7 @@ pub struct TextareaTokens {
     pub border: Hsla,
     pub border_focus: Hsla,
     pub border_error: Hsla,
+    pub spell_error: Hsla,
     pub label: Hsla,
     pub label_size: Pixels,
     pub label_weight: FontWeight,
@@ -2217,6 +2322,12 @@ pub struct RangeSliderTokens {
     pub header_gap_horizontal: Pixels,
     pub default_width: Pixels,
     pub min_width: Pixels,
+    pub mark_bg: Hsla,
+    pub mark_size: Pixels,
+    pub mark_label: Hsla,
+    pub mark_label_size: Pixels,
+    pub mark_label_gap: Pixels,
+    pub tooltip_offset: Pixels,
     pub sizes: SliderSizeScale,
 }
 
@@ -2224,6 +2335,9 @@ pub struct RangeSliderTokens {
 pub struct RatingTokens {
     pub active: Hsla,
     pub inactive: Hsla,
+    pub value: Hsla,
+    pub value_size: Pixels,
+    pub value_gap: Pixels,
     pub sizes: RatingSizeScale,
 }
 
@@ -2296,6 +2410,7 @@ pub struct TabsTokens {
     pub list_padding: Pixels,
     pub panel_padding: Pixels,
     pub sizes: TabsSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -2438,6 +2553,7 @@ pub struct PaginationTokens {
     pub dots_fg: Hsla,
     pub root_gap: Pixels,
     pub sizes: PaginationSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -2448,6 +2564,7 @@ pub struct BreadcrumbsTokens {
     pub item_hover_bg: Hsla,
     pub root_gap: Pixels,
     pub sizes: BreadcrumbsSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -2521,8 +2638,10 @@ pub struct TableTokens {
     pub row_bg: Hsla,
     pub row_alt_bg: Hsla,
     pub row_hover_bg: Hsla,
+    pub row_selected_bg: Hsla,
     pub row_border: Hsla,
     pub cell_fg: Hsla,
+    pub selection_bg: Hsla,
     pub caption: Hsla,
     pub caption_size: Pixels,
     pub row_gap: Pixels,
@@ -2539,6 +2658,7 @@ pub struct TableTokens {
     pub virtualization_padding: Pixels,
     pub min_viewport_height: Pixels,
     pub sizes: TableSizeScale,
+    pub states: PseudoStateTokens,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -2762,11 +2882,167 @@ pub struct TreeTokens {
     pub row_hover_bg: Hsla,
     pub row_disabled_fg: Hsla,
     pub line: Hsla,
+    pub drop_indicator: Hsla,
     pub root_gap: Pixels,
     pub children_gap: Pixels,
     pub sizes: TreeSizeScale,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ZoomPaneTokens {
+    pub bg: Hsla,
+    pub grid_line: Hsla,
+    pub grid_line_major: Hsla,
+    pub ruler_bg: Hsla,
+    pub ruler_tick: Hsla,
+    pub ruler_size: Pixels,
+    pub grid_spacing: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GraphCanvasTokens {
+    pub node_bg: Hsla,
+    pub node_border: Hsla,
+    pub node_selected_border: Hsla,
+    pub node_header_bg: Hsla,
+    pub node_fg: Hsla,
+    pub port_bg: Hsla,
+    pub port_border: Hsla,
+    pub edge: Hsla,
+    pub edge_selected: Hsla,
+    pub minimap_bg: Hsla,
+    pub minimap_node: Hsla,
+    pub minimap_viewport: Hsla,
+    pub node_radius: Pixels,
+    pub edge_width: Pixels,
+    pub port_size: Pixels,
+    pub label_size: Pixels,
+    pub minimap_width: Pixels,
+    pub minimap_height: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DatePickerTokens {
+    pub bg: Hsla,
+    pub fg: Hsla,
+    pub placeholder: Hsla,
+    pub border: Hsla,
+    pub border_focus: Hsla,
+    pub border_error: Hsla,
+    pub icon: Hsla,
+    pub label: Hsla,
+    pub label_size: Pixels,
+    pub label_weight: FontWeight,
+    pub description: Hsla,
+    pub description_size: Pixels,
+    pub error: Hsla,
+    pub error_size: Pixels,
+    pub label_block_gap: Pixels,
+    pub label_row_gap: Pixels,
+    pub layout_gap_vertical: Pixels,
+    pub layout_gap_horizontal: Pixels,
+    pub horizontal_label_width: Pixels,
+    pub slot_gap: Pixels,
+    pub icon_size: Pixels,
+    pub dropdown_bg: Hsla,
+    pub dropdown_border: Hsla,
+    pub dropdown_padding: Pixels,
+    pub dropdown_anchor_offset: Pixels,
+    pub dropdown_open_preferred_height: Pixels,
+    pub header_fg: Hsla,
+    pub header_size: Pixels,
+    pub nav_hover_bg: Hsla,
+    pub weekday_fg: Hsla,
+    pub weekday_size: Pixels,
+    pub day_fg: Hsla,
+    pub day_outside_fg: Hsla,
+    pub day_hover_bg: Hsla,
+    pub day_selected_bg: Hsla,
+    pub day_selected_fg: Hsla,
+    pub day_today_border: Hsla,
+    pub day_size: Pixels,
+    pub day_cell_size: Pixels,
+    pub grid_gap: Pixels,
+    pub time_column_width: Pixels,
+    pub time_item_height: Pixels,
+    pub time_item_size: Pixels,
+    pub sizes: FieldSizeScale,
+    pub states: PseudoStateTokens,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RulersTokens {
+    pub bg: Hsla,
+    pub border: Hsla,
+    pub tick: Hsla,
+    pub tick_major: Hsla,
+    pub label: Hsla,
+    pub label_size: Pixels,
+    pub size: Pixels,
+    pub spacing: Pixels,
+    pub guide: Hsla,
+    pub guide_snapped: Hsla,
+    pub guide_hit_width: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ColorPickerTokens {
+    pub bg: Hsla,
+    pub border: Hsla,
+    pub padding: Pixels,
+    pub gap: Pixels,
+    pub area_height: Pixels,
+    pub slider_height: Pixels,
+    pub thumb_size: Pixels,
+    pub thumb_border: Hsla,
+    pub checker: Hsla,
+    pub preview_size: Pixels,
+    pub swatch_size: Pixels,
+    pub swatch_gap: Pixels,
+    pub swatch_border: Hsla,
+    pub swatch_selected_border: Hsla,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FileInputTokens {
+    pub bg: Hsla,
+    pub bg_active: Hsla,
+    pub border: Hsla,
+    pub border_active: Hsla,
+    pub border_error: Hsla,
+    pub fg: Hsla,
+    pub hint: Hsla,
+    pub icon: Hsla,
+    pub radius: Pixels,
+    pub padding: Pixels,
+    pub gap: Pixels,
+    pub icon_size: Pixels,
+    pub text_size: Pixels,
+    pub hint_size: Pixels,
+    pub chip_bg: Hsla,
+    pub chip_border: Hsla,
+    pub chip_fg: Hsla,
+    pub chip_meta: Hsla,
+    pub chip_remove_hover_bg: Hsla,
+    pub chip_size: Pixels,
+    pub chip_padding_x: Pixels,
+    pub chip_padding_y: Pixels,
+    pub chip_gap: Pixels,
+    pub chip_max_width: Pixels,
+    pub label: Hsla,
+    pub label_size: Pixels,
+    pub label_weight: FontWeight,
+    pub description: Hsla,
+    pub description_size: Pixels,
+    pub error: Hsla,
+    pub error_size: Pixels,
+    pub label_block_gap: Pixels,
+    pub label_row_gap: Pixels,
+    pub layout_gap_vertical: Pixels,
+    pub layout_gap_horizontal: Pixels,
+    pub horizontal_label_width: Pixels,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LayoutTokens {
     pub gap: GapSizeScale,
@@ -2774,292 +3050,749 @@ pub struct LayoutTokens {
     pub popup_snap_margin: Pixels,
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FocusRingStyle {
+    #[default]
+    Solid,
+    Dashed,
+    Double,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ComponentTokens {
-    pub button: ButtonTokens,
-    pub input: InputTokens,
-    pub radio: RadioTokens,
-    pub checkbox: CheckboxTokens,
-    pub switch: SwitchTokens,
-    pub chip: ChipTokens,
-    pub badge: BadgeTokens,
-    pub accordion: AccordionTokens,
-    pub menu: MenuTokens,
-    pub progress: ProgressTokens,
-    pub slider: SliderTokens,
-    pub overlay: OverlayTokens,
-    pub loader: LoaderTokens,
-    pub loading_overlay: LoadingOverlayTokens,
-    pub popover: PopoverTokens,
-    pub tooltip: TooltipTokens,
-    pub hover_card: HoverCardTokens,
-    pub select: SelectTokens,
-    pub modal: ModalTokens,
-    pub toast: ToastTokens,
-    pub divider: DividerTokens,
-    pub scroll_area: ScrollAreaTokens,
-    pub drawer: DrawerTokens,
-    pub app_shell: AppShellTokens,
-    pub title_bar: TitleBarTokens,
-    pub sidebar: SidebarTokens,
-    pub markdown: MarkdownTokens,
-    pub text: TextTokens,
-    pub title: TitleTokens,
-    pub paper: PaperTokens,
-    pub action_icon: ActionIconTokens,
-    pub segmented_control: SegmentedControlTokens,
-    pub textarea: TextareaTokens,
-    pub number_input: NumberInputTokens,
-    pub range_slider: RangeSliderTokens,
-    pub rating: RatingTokens,
-    pub tabs: TabsTokens,
-    pub pagination: PaginationTokens,
-    pub breadcrumbs: BreadcrumbsTokens,
-    pub table: TableTokens,
-    pub stepper: StepperTokens,
-    pub timeline: TimelineTokens,
-    pub tree: TreeTokens,
-    pub layout: LayoutTokens,
+pub struct FocusRingTokens {
+    pub color: Hsla,
+    pub width: Pixels,
+    pub offset: Pixels,
+    pub style: FocusRingStyle,
 }
 
-impl ComponentTokens {
-    pub fn defaults(primary: PaletteKey) -> Self {
-        Self::defaults_for(primary, ColorScheme::Light)
-    }
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PressFeedbackStyle {
+    None,
+    #[default]
+    Scale,
+    Ripple,
+}
 
-    pub fn defaults_for(primary: PaletteKey, scheme: ColorScheme) -> Self {
-        match scheme {
-            ColorScheme::Light => Self {
-                button: ButtonTokens {
-                    filled_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    filled_fg: white(),
-                    light_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    light_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_border: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    ghost_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    disabled_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    disabled_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    sizes: default_button_size_scale(),
-                },
-                input: InputTokens {
-                    bg: white(),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    caret: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    selection_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    placeholder: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label_size: px(14.0),
-                    label_weight: FontWeight::MEDIUM,
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description_size: px(13.0),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    error_size: px(13.0),
-                    label_block_gap: px(4.0),
-                    label_row_gap: px(4.0),
-                    slot_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    slot_gap: px(8.0),
-                    slot_min_width: px(16.0),
-                    layout_gap_vertical: px(8.0),
-                    layout_gap_horizontal: px(12.0),
-                    horizontal_label_width: px(168.0),
-                    pin_cells_gap: px(8.0),
-                    pin_error_gap: px(4.0),
-                    sizes: default_field_size_scale(),
-                },
-                radio: RadioTokens {
-                    control_bg: white(),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_hover: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_checked: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    indicator: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label_description_gap: px(2.0),
-                    group_gap_horizontal: px(12.0),
-                    group_gap_vertical: px(8.0),
-                    sizes: default_choice_control_size_scale(),
-                },
-                checkbox: CheckboxTokens {
-                    control_bg: white(),
-                    control_bg_checked: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_hover: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_checked: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    indicator: white(),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label_description_gap: px(2.0),
-                    group_gap_horizontal: px(12.0),
-                    group_gap_vertical: px(8.0),
-                    sizes: default_choice_control_size_scale(),
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PressFeedbackTokens {
+    pub style: PressFeedbackStyle,
+    pub scale_inset: Pixels,
+    pub ripple_color: Hsla,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AsyncContentTokens {
+    pub skeleton_bg: Hsla,
+    pub skeleton_radius: Pixels,
+    pub skeleton_line_height: Pixels,
+    pub skeleton_gap: Pixels,
+    pub empty_icon: Hsla,
+    pub empty_title: Hsla,
+    pub empty_description: Hsla,
+    pub empty_icon_size: Pixels,
+    pub empty_title_size: Pixels,
+    pub empty_description_size: Pixels,
+    pub empty_gap: Pixels,
+    pub empty_padding: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChecklistTokens {
+    pub done: Hsla,
+    pub active: Hsla,
+    pub pending: Hsla,
+    pub error: Hsla,
+    pub label: Hsla,
+    pub description: Hsla,
+    pub summary: Hsla,
+    pub icon_size: Pixels,
+    pub label_size: Pixels,
+    pub description_size: Pixels,
+    pub summary_size: Pixels,
+    pub gap: Pixels,
+    pub item_gap: Pixels,
+    pub detail_indent: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskStatusTokens {
+    pub trigger_fg: Hsla,
+    pub trigger_hover_bg: Hsla,
+    pub title: Hsla,
+    pub message: Hsla,
+    pub running: Hsla,
+    pub done: Hsla,
+    pub error: Hsla,
+    pub cancelled: Hsla,
+    pub trigger_size: Pixels,
+    pub title_size: Pixels,
+    pub message_size: Pixels,
+    pub icon_size: Pixels,
+    pub trigger_bar_width: Pixels,
+    pub panel_width: Pixels,
+    pub item_gap: Pixels,
+    pub row_gap: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationCenterTokens {
+    pub header: Hsla,
+    pub title: Hsla,
+    pub message: Hsla,
+    pub empty: Hsla,
+    pub unread_dot: Hsla,
+    pub unread_bg: Hsla,
+    pub row_hover_bg: Hsla,
+    pub info: Hsla,
+    pub success: Hsla,
+    pub warning: Hsla,
+    pub error: Hsla,
+    pub header_size: Pixels,
+    pub title_size: Pixels,
+    pub message_size: Pixels,
+    pub icon_size: Pixels,
+    pub dot_size: Pixels,
+    pub panel_width: Pixels,
+    pub row_padding: Pixels,
+    pub item_gap: Pixels,
+    pub row_gap: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FindBarTokens {
+    pub bg: Hsla,
+    pub border: Hsla,
+    pub count: Hsla,
+    pub match_bg: Hsla,
+    pub active_match_bg: Hsla,
+    pub width: Pixels,
+    pub padding: Pixels,
+    pub gap: Pixels,
+    pub radius: Pixels,
+    pub offset: Pixels,
+    pub count_size: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitPaneTokens {
+    pub divider: Hsla,
+    pub divider_active: Hsla,
+    pub divider_size: Pixels,
+    pub handle_size: Pixels,
+    pub min_pane_size: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DockTabsTokens {
+    pub strip_bg: Hsla,
+    pub strip_border: Hsla,
+    pub tab_fg: Hsla,
+    pub tab_hover_bg: Hsla,
+    pub tab_active_bg: Hsla,
+    pub tab_active_fg: Hsla,
+    pub tab_active_indicator: Hsla,
+    pub tab_disabled_fg: Hsla,
+    pub dirty_dot: Hsla,
+    pub drop_indicator: Hsla,
+    pub tab_height: Pixels,
+    pub tab_padding_x: Pixels,
+    pub tab_gap: Pixels,
+    pub tab_max_width: Pixels,
+    pub close_size: Pixels,
+    pub dirty_dot_size: Pixels,
+    pub overflow_button_width: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageTokens {
+    pub placeholder_bg: Hsla,
+    pub fallback_bg: Hsla,
+    pub fallback_fg: Hsla,
+    pub fallback_icon_size: Pixels,
+    pub fallback_gap: Pixels,
+    pub fallback_font_size: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CalendarTokens {
+    pub bg: Hsla,
+    pub border: Hsla,
+    pub header_fg: Hsla,
+    pub header_size: Pixels,
+    pub nav_hover_bg: Hsla,
+    pub icon: Hsla,
+    pub icon_size: Pixels,
+    pub weekday_fg: Hsla,
+    pub weekday_size: Pixels,
+    pub day_fg: Hsla,
+    pub day_outside_fg: Hsla,
+    pub day_hover_bg: Hsla,
+    pub day_size: Pixels,
+    pub today_bg: Hsla,
+    pub today_fg: Hsla,
+    pub range_bg: Hsla,
+    pub range_edge_bg: Hsla,
+    pub cell_border: Hsla,
+    pub cell_padding: Pixels,
+    pub month_cell_height: Pixels,
+    pub week_cell_height: Pixels,
+    pub chip_bg: Hsla,
+    pub chip_fg: Hsla,
+    pub chip_size: Pixels,
+    pub chip_padding_x: Pixels,
+    pub chip_gap: Pixels,
+    pub more_fg: Hsla,
+    pub header_gap: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KanbanBoardTokens {
+    pub column_bg: Hsla,
+    pub column_border: Hsla,
+    pub column_width: Pixels,
+    pub column_gap: Pixels,
+    pub column_padding: Pixels,
+    pub column_radius: Pixels,
+    pub list_height: Pixels,
+    pub header_fg: Hsla,
+    pub header_size: Pixels,
+    pub header_gap: Pixels,
+    pub count_bg: Hsla,
+    pub count_fg: Hsla,
+    pub count_over_limit_bg: Hsla,
+    pub count_over_limit_fg: Hsla,
+    pub count_size: Pixels,
+    pub card_bg: Hsla,
+    pub card_border: Hsla,
+    pub card_height: Pixels,
+    pub card_gap: Pixels,
+    pub card_padding: Pixels,
+    pub placeholder_bg: Hsla,
+    pub placeholder_border: Hsla,
+    pub empty_fg: Hsla,
+    pub empty_size: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChartTokens {
+    pub grid: Hsla,
+    pub axis_fg: Hsla,
+    pub axis_size: Pixels,
+    pub axis_width: Pixels,
+    pub axis_gap: Pixels,
+    pub height: Pixels,
+    pub line_width: Pixels,
+    pub point_size: Pixels,
+    pub point_bg: Hsla,
+    pub bar_gap: Pixels,
+    pub bar_radius: Pixels,
+    pub donut_size: Pixels,
+    pub donut_thickness: Pixels,
+    pub donut_track: Hsla,
+    pub center_fg: Hsla,
+    pub center_size: Pixels,
+    pub legend_fg: Hsla,
+    pub legend_size: Pixels,
+    pub legend_gap: Pixels,
+    pub swatch_size: Pixels,
+    pub sparkline_width: Pixels,
+    pub sparkline_height: Pixels,
+    pub sparkline_line_width: Pixels,
+    pub empty_fg: Hsla,
+    pub empty_size: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RingProgressTokens {
+    pub size: Pixels,
+    pub thickness: Pixels,
+    pub label_size: Pixels,
+    pub section_gap: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatCardTokens {
+    pub bg: Hsla,
+    pub border: Hsla,
+    pub radius: Pixels,
+    pub padding: Pixels,
+    pub gap: Pixels,
+    pub label_fg: Hsla,
+    pub label_size: Pixels,
+    pub value_fg: Hsla,
+    pub value_size: Pixels,
+    pub icon_fg: Hsla,
+    pub icon_size: Pixels,
+    pub delta_size: Pixels,
+    pub delta_icon_size: Pixels,
+    pub delta_up: Hsla,
+    pub delta_down: Hsla,
+    pub delta_flat: Hsla,
+    pub description_fg: Hsla,
+    pub description_size: Pixels,
+    pub skeleton_bg: Hsla,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EmptyStateSizePreset {
+    pub icon_size: Pixels,
+    pub icon_box_size: Pixels,
+    pub title_size: Pixels,
+    pub description_size: Pixels,
+    pub gap: Pixels,
+    pub max_width: Pixels,
+    pub padding: Pixels,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EmptyStateSizeScale {
+    pub xs: EmptyStateSizePreset,
+    pub sm: EmptyStateSizePreset,
+    pub md: EmptyStateSizePreset,
+    pub lg: EmptyStateSizePreset,
+    pub xl: EmptyStateSizePreset,
+}
+
+impl EmptyStateSizeScale {
+    pub fn for_size(&self, size: Size) -> EmptyStateSizePreset {
+        match size {
+            Size::Xs => self.xs,
+            Size::Sm => self.sm,
+            Size::Md => self.md,
+            Size::Lg => self.lg,
+            Size::Xl => self.xl,
+        }
+    }
+}
+
+fn default_empty_state_size_scale() -> EmptyStateSizeScale {
+    EmptyStateSizeScale {
+        xs: EmptyStateSizePreset {
+            icon_size: px(16.0),
+            icon_box_size: px(28.0),
+            title_size: px(13.0),
+            description_size: px(12.0),
+            gap: px(4.0),
+            max_width: px(240.0),
+            padding: px(8.0),
+        },
+        sm: EmptyStateSizePreset {
+            icon_size: px(20.0),
+            icon_box_size: px(36.0),
+            title_size: px(14.0),
+            description_size: px(13.0),
+            gap: px(6.0),
+            max_width: px(280.0),
+            padding: px(12.0),
+        },
+        md: EmptyStateSizePreset {
+            icon_size: px(24.0),
+            icon_box_size: px(48.0),
+            title_size: px(16.0),
+            description_size: px(14.0),
+            gap: px(8.0),
+            max_width: px(340.0),
+            padding: px(20.0),
+        },
+        lg: EmptyStateSizePreset {
+            icon_size: px(32.0),
+            icon_box_size: px(64.0),
+            title_size: px(20.0),
+            description_size: px(15.0),
+            gap: px(10.0),
+            max_width: px(400.0),
+            padding: px(28.0),
+        },
+        xl: EmptyStateSizePreset {
+            icon_size: px(40.0),
+            icon_box_size: px(80.0),
+            title_size: px(24.0),
+            description_size: px(16.0),
+            gap: px(12.0),
+            max_width: px(460.0),
+            padding: px(36.0),
+        },
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmptyStateTokens {
+    pub icon_fg: Hsla,
+    pub icon_bg: Hsla,
+    pub title_fg: Hsla,
+    pub description_fg: Hsla,
+    pub actions_gap: Pixels,
+    pub sizes: EmptyStateSizeScale,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnnouncementBarTokens {
+    pub info_bg: Hsla,
+    pub info_fg: Hsla,
+    pub success_bg: Hsla,
+    pub success_fg: Hsla,
+    pub warning_bg: Hsla,
+    pub warning_fg: Hsla,
+    pub error_bg: Hsla,
+    pub error_fg: Hsla,
+    pub min_height: Pixels,
+    pub padding_x: Pixels,
+    pub padding_y: Pixels,
+    pub gap: Pixels,
+    pub font_size: Pixels,
+    pub icon_size: Pixels,
+    pub close_button_size: Pixels,
+    pub close_icon_size: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WizardTokens {
+    pub error_fg: Hsla,
+    pub error_size: Pixels,
+    pub footer_gap: Pixels,
+    pub root_gap: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EditableTextTokens {
+    pub fg: Hsla,
+    pub placeholder: Hsla,
+    pub hover_bg: Hsla,
+    pub error_fg: Hsla,
+    pub text_size: Pixels,
+    pub error_size: Pixels,
+    pub padding_x: Pixels,
+    pub padding_y: Pixels,
+    pub radius: Pixels,
+    pub gap: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CopyButtonTokens {
+    pub copied_fg: Hsla,
+    pub icon_size: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChatViewTokens {
+    pub own_bg: Hsla,
+    pub other_bg: Hsla,
+    pub author_fg: Hsla,
+    pub time_fg: Hsla,
+    pub separator_fg: Hsla,
+    pub separator_line: Hsla,
+    pub avatar_bg: Hsla,
+    pub avatar_fg: Hsla,
+    pub pill_bg: Hsla,
+    pub pill_fg: Hsla,
+    pub typing_fg: Hsla,
+    pub padding: Pixels,
+    pub message_gap: Pixels,
+    pub avatar_size: Pixels,
+    pub avatar_gap: Pixels,
+    pub bubble_radius: Pixels,
+    pub bubble_padding_x: Pixels,
+    pub bubble_padding_y: Pixels,
+    pub bubble_max_width: Pixels,
+    pub meta_size: Pixels,
+    pub separator_size: Pixels,
+    pub estimated_row_height: Pixels,
+    pub pill_offset: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogViewTokens {
+    pub bg: Hsla,
+    pub border: Hsla,
+    pub fg: Hsla,
+    pub muted_fg: Hsla,
+    pub gutter_fg: Hsla,
+    pub warn_fg: Hsla,
+    pub error_fg: Hsla,
+    pub selection_bg: Hsla,
+    pub ansi_black: Hsla,
+    pub ansi_red: Hsla,
+    pub ansi_green: Hsla,
+    pub ansi_yellow: Hsla,
+    pub ansi_blue: Hsla,
+    pub ansi_magenta: Hsla,
+    pub ansi_cyan: Hsla,
+    pub ansi_white: Hsla,
+    pub text_size: Pixels,
+    pub line_height: Pixels,
+    pub padding: Pixels,
+    pub gutter_gap: Pixels,
+    pub radius: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JsonTreeTokens {
+    pub bg: Hsla,
+    pub border: Hsla,
+    pub punctuation_fg: Hsla,
+    pub key_fg: Hsla,
+    pub index_fg: Hsla,
+    pub string_fg: Hsla,
+    pub number_fg: Hsla,
+    pub boolean_fg: Hsla,
+    pub null_fg: Hsla,
+    pub summary_fg: Hsla,
+    pub toggle_fg: Hsla,
+    pub more_fg: Hsla,
+    pub row_hover_bg: Hsla,
+    pub match_bg: Hsla,
+    pub text_size: Pixels,
+    pub row_height: Pixels,
+    pub indent: Pixels,
+    pub padding: Pixels,
+    pub toggle_size: Pixels,
+    pub radius: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettingsViewTokens {
+    pub bg: Hsla,
+    pub border: Hsla,
+    pub nav_bg: Hsla,
+    pub nav_fg: Hsla,
+    pub nav_hover_bg: Hsla,
+    pub nav_active_bg: Hsla,
+    pub nav_active_fg: Hsla,
+    pub count_fg: Hsla,
+    pub title_fg: Hsla,
+    pub description_fg: Hsla,
+    pub label_fg: Hsla,
+    pub separator: Hsla,
+    pub match_bg: Hsla,
+    pub empty_fg: Hsla,
+    pub nav_width: Pixels,
+    pub nav_padding: Pixels,
+    pub nav_gap: Pixels,
+    pub nav_item_padding_x: Pixels,
+    pub nav_item_padding_y: Pixels,
+    pub nav_item_radius: Pixels,
+    pub nav_item_size: Pixels,
+    pub content_padding: Pixels,
+    pub content_max_width: Pixels,
+    pub section_gap: Pixels,
+    pub title_size: Pixels,
+    pub heading_size: Pixels,
+    pub description_size: Pixels,
+    pub label_size: Pixels,
+    pub item_gap: Pixels,
+    pub item_padding_y: Pixels,
+    pub control_width: Pixels,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComponentTokens {
+    pub button: ButtonTokens,
+    pub input: InputTokens,
+    pub radio: RadioTokens,
+    pub checkbox: CheckboxTokens,
+    pub switch: SwitchTokens,
+    pub chip: ChipTokens,
+    pub badge: BadgeTokens,
+    pub accordion: AccordionTokens,
+    pub menu: MenuTokens,
+    pub progress: ProgressTokens,
+    pub slider: SliderTokens,
+    pub overlay: OverlayTokens,
+    pub loader: LoaderTokens,
+    pub loading_overlay: LoadingOverlayTokens,
+    pub popover: PopoverTokens,
+    pub tooltip: TooltipTokens,
+    pub hover_card: HoverCardTokens,
+    pub select: SelectTokens,
+    pub modal: ModalTokens,
+    pub toast: ToastTokens,
+    pub divider: DividerTokens,
+    pub scroll_area: ScrollAreaTokens,
+    pub drawer: DrawerTokens,
+    pub app_shell: AppShellTokens,
+    pub title_bar: TitleBarTokens,
+    pub sidebar: SidebarTokens,
+    pub markdown: MarkdownTokens,
+    pub text: TextTokens,
+    pub title: TitleTokens,
+    pub paper: PaperTokens,
+    pub action_icon: ActionIconTokens,
+    pub segmented_control: SegmentedControlTokens,
+    pub textarea: TextareaTokens,
+    pub number_input: NumberInputTokens,
+    pub range_slider: RangeSliderTokens,
+    pub rating: RatingTokens,
+    pub tabs: TabsTokens,
+    pub pagination: PaginationTokens,
+    pub breadcrumbs: BreadcrumbsTokens,
+    pub table: TableTokens,
+    pub stepper: StepperTokens,
+    pub timeline: TimelineTokens,
+    pub tree: TreeTokens,
+    pub zoom_pane: ZoomPaneTokens,
+    pub graph_canvas: GraphCanvasTokens,
+    pub date_picker: DatePickerTokens,
+    pub rulers: RulersTokens,
+    pub color_picker: ColorPickerTokens,
+    pub file_input: FileInputTokens,
+    pub layout: LayoutTokens,
+    pub focus_ring: FocusRingTokens,
+    pub press_feedback: PressFeedbackTokens,
+    pub async_content: AsyncContentTokens,
+    pub checklist: ChecklistTokens,
+    pub task_status: TaskStatusTokens,
+    pub notification_center: NotificationCenterTokens,
+    pub find_bar: FindBarTokens,
+    pub split_pane: SplitPaneTokens,
+    pub dock_tabs: DockTabsTokens,
+    pub image: ImageTokens,
+    pub calendar: CalendarTokens,
+    pub kanban_board: KanbanBoardTokens,
+    pub chart: ChartTokens,
+    pub ring_progress: RingProgressTokens,
+    pub stat_card: StatCardTokens,
+    pub empty_state: EmptyStateTokens,
+    pub announcement_bar: AnnouncementBarTokens,
+    pub wizard: WizardTokens,
+    pub editable_text: EditableTextTokens,
+    pub copy_button: CopyButtonTokens,
+    pub chat_view: ChatViewTokens,
+    pub log_view: LogViewTokens,
+    pub json_tree: JsonTreeTokens,
+    pub settings_view: SettingsViewTokens,
+}
+
+impl ComponentTokens {
+    pub fn defaults(primary: PaletteKey) -> Self {
+        Self::defaults_for(primary, ColorScheme::Light)
+    }
+
+    pub fn defaults_for(primary: PaletteKey, scheme: ColorScheme) -> Self {
+        Self::from_seed(ThemeSeed::new(primary), scheme)
+    }
+
+    pub fn from_seed(seed: ThemeSeed, scheme: ColorScheme) -> Self {
+        Self::from_seed_palette(SeedPalette::new(seed, scheme))
+    }
+
+    pub(super) fn from_seed_palette(palette: SeedPalette) -> Self {
+        let (seed, scheme) = (palette.seed, palette.scheme);
+        match scheme {
+            ColorScheme::Light => Self {
+                button: ButtonTokens {
+                    filled_bg: palette.primary(6),
+                    filled_fg: white(),
+                    light_bg: palette.primary(0),
+                    light_fg: palette.primary(6),
+                    subtle_bg: palette.gray(0),
+                    subtle_fg: palette.gray(8),
+                    outline_border: palette.primary(4),
+                    outline_fg: palette.primary(7),
+                    ghost_fg: palette.primary(6),
+                    disabled_bg: palette.gray(2),
+                    disabled_fg: palette.gray(5),
+                    sizes: default_button_size_scale(),
+                    states: PseudoStateTokens::default(),
+                },
+                input: InputTokens {
+                    bg: palette.surface(),
+                    fg: palette.dark(9),
+                    caret: palette.dark(9),
+                    selection_bg: palette.primary(1),
+                    placeholder: palette.gray(5),
+                    border: palette.gray(4),
+                    border_focus: palette.primary(6),
+                    border_error: palette.color(PaletteKey::Red, 6),
+                    label: palette.dark(8),
+                    label_size: px(14.0),
+                    label_weight: FontWeight::MEDIUM,
+                    description: palette.gray(7),
+                    description_size: px(13.0),
+                    error: palette.color(PaletteKey::Red, 6),
+                    error_size: px(13.0),
+                    label_block_gap: px(4.0),
+                    label_row_gap: px(4.0),
+                    slot_fg: palette.gray(7),
+                    slot_gap: px(8.0),
+                    slot_min_width: px(16.0),
+                    layout_gap_vertical: px(8.0),
+                    layout_gap_horizontal: px(12.0),
+                    horizontal_label_width: px(168.0),
+                    pin_cells_gap: px(8.0),
+                    pin_error_gap: px(4.0),
+                    password_toggle_size: px(16.0),
+                    password_caps_lock_fg: palette.color(PaletteKey::Orange, 7),
+                    password_caps_lock_size: px(12.0),
+                    password_meter_gap: px(6.0),
+                    password_meter_height: px(4.0),
+                    password_meter_segment_gap: px(4.0),
+                    password_meter_track: palette.gray(2),
+                    password_strength_weak: palette.color(PaletteKey::Red, 6),
+                    password_strength_fair: palette.color(PaletteKey::Orange, 6),
+                    password_strength_good: palette.color(PaletteKey::Yellow, 6),
+                    password_strength_strong: palette.color(PaletteKey::Green, 6),
+                    password_strength_label_size: px(12.0),
+                    sizes: default_field_size_scale(),
+                },
+                radio: RadioTokens {
+                    control_bg: palette.surface(),
+                    border: palette.gray(4),
+                    border_hover: palette.gray(5),
+                    border_focus: palette.primary(6),
+                    border_checked: palette.primary(6),
+                    indicator: palette.primary(6),
+                    label: palette.dark(9),
+                    description: palette.gray(7),
+                    label_description_gap: px(2.0),
+                    group_gap_horizontal: px(12.0),
+                    group_gap_vertical: px(8.0),
+                    sizes: default_choice_control_size_scale(),
+                },
+                checkbox: CheckboxTokens {
+                    control_bg: palette.surface(),
+                    control_bg_checked: palette.primary(6),
+                    border: palette.gray(4),
+                    border_hover: palette.gray(5),
+                    border_focus: palette.primary(6),
+                    border_checked: palette.primary(6),
+                    indicator: white(),
+                    label: palette.dark(9),
+                    description: palette.gray(7),
+                    label_description_gap: px(2.0),
+                    group_gap_horizontal: px(12.0),
+                    group_gap_vertical: px(8.0),
+                    sizes: default_choice_control_size_scale(),
                 },
                 switch: SwitchTokens {
-                    track_off_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    track_on_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    track_hover_border: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    track_focus_border: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    thumb_bg: white(),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    track_off_bg: palette.gray(4),
+                    track_on_bg: palette.primary(6),
+                    track_hover_border: palette.primary(5),
+                    track_focus_border: palette.primary(6),
+                    thumb_bg: palette.surface(),
+                    label: palette.dark(9),
+                    description: palette.gray(7),
                     label_description_gap: px(2.0),
                     sizes: default_switch_size_scale(),
                 },
                 chip: ChipTokens {
-                    unchecked_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    unchecked_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    unchecked_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    filled_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    unchecked_bg: palette.gray(0),
+                    unchecked_fg: palette.gray(8),
+                    unchecked_border: palette.gray(3),
+                    filled_bg: palette.primary(6),
                     filled_fg: white(),
-                    light_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    light_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_border: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    ghost_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    border_hover: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    light_bg: palette.primary(0),
+                    light_fg: palette.primary(7),
+                    subtle_bg: palette.gray(1),
+                    subtle_fg: palette.gray(8),
+                    outline_border: palette.primary(4),
+                    outline_fg: palette.primary(7),
+                    ghost_fg: palette.primary(6),
+                    default_bg: palette.gray(0),
+                    default_fg: palette.dark(8),
+                    default_border: palette.gray(3),
+                    border_hover: palette.gray(5),
+                    border_focus: palette.primary(6),
                     content_gap: px(4.0),
                     indicator_size: px(12.0),
                     group_gap_horizontal: px(8.0),
@@ -3067,87 +3800,40 @@ impl ComponentTokens {
                     sizes: default_button_size_scale(),
                 },
                 badge: BadgeTokens {
-                    filled_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    filled_bg: palette.primary(6),
                     filled_fg: white(),
-                    light_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    light_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_border: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    light_bg: palette.primary(0),
+                    light_fg: palette.primary(7),
+                    subtle_bg: palette.gray(1),
+                    subtle_fg: palette.gray(8),
+                    outline_border: palette.primary(4),
+                    outline_fg: palette.primary(7),
+                    default_bg: palette.gray(0),
+                    default_fg: palette.dark(8),
+                    default_border: palette.gray(3),
                     sizes: default_badge_size_scale(),
                 },
                 accordion: AccordionTokens {
-                    item_bg: white(),
-                    item_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    content: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    chevron: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    item_bg: palette.surface(),
+                    item_border: palette.gray(3),
+                    label: palette.dark(9),
+                    description: palette.gray(7),
+                    content: palette.dark(8),
+                    chevron: palette.gray(7),
                     stack_gap: px(8.0),
                     header_gap: px(8.0),
                     label_stack_gap: px(2.0),
                     panel_gap: px(4.0),
                     sizes: default_accordion_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 menu: MenuTokens {
-                    dropdown_bg: white(),
-                    dropdown_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    icon: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    dropdown_bg: palette.surface(),
+                    dropdown_border: palette.gray(3),
+                    item_fg: palette.dark(9),
+                    item_hover_bg: palette.gray(1),
+                    item_disabled_fg: palette.gray(5),
+                    icon: palette.gray(7),
                     item_gap: px(8.0),
                     item_padding_x: px(10.0),
                     item_padding_y: px(8.0),
@@ -3159,95 +3845,66 @@ impl ComponentTokens {
                     dropdown_radius: px(8.0),
                     dropdown_width_fallback: px(220.0),
                     dropdown_min_width: px(180.0),
+                    states: PseudoStateTokens::default(),
                 },
                 progress: ProgressTokens {
-                    track_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fill_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    track_bg: palette.gray(2),
+                    fill_bg: palette.primary(6),
+                    label: palette.dark(8),
                     default_width: px(260.0),
                     min_width: px(80.0),
                     root_gap: px(6.0),
                     sizes: default_progress_size_scale(),
                 },
                 slider: SliderTokens {
-                    track_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fill_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    thumb_bg: white(),
-                    thumb_border: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    value: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    track_bg: palette.gray(2),
+                    fill_bg: palette.primary(6),
+                    thumb_bg: palette.surface(),
+                    thumb_border: palette.primary(6),
+                    label: palette.dark(8),
+                    value: palette.gray(7),
                     label_size: px(14.0),
                     value_size: px(14.0),
                     header_gap_vertical: px(6.0),
                     header_gap_horizontal: px(8.0),
                     default_width: px(260.0),
                     min_width: px(120.0),
+                    mark_bg: palette.gray(4),
+                    mark_size: px(6.0),
+                    mark_label: palette.gray(6),
+                    mark_label_size: px(12.0),
+                    mark_label_gap: px(6.0),
+                    tooltip_offset: px(6.0),
                     sizes: default_slider_size_scale(),
                 },
                 overlay: OverlayTokens {
-                    bg: (Rgba::try_from("#000000E6")
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: resolve_hex_hsla("#000000E6"),
                 },
                 loader: LoaderTokens {
-                    color: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    color: palette.primary(6),
+                    label: palette.gray(7),
                     sizes: default_loader_size_scale(),
                 },
                 loading_overlay: LoadingOverlayTokens {
-                    bg: (Rgba::try_from("#000000E6")
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    loader_color: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: resolve_hex_hsla("#000000E6"),
+                    loader_color: palette.primary(6),
                     label: white(),
                     content_gap: px(8.0),
                     label_size: px(13.0),
                 },
                 popover: PopoverTokens {
-                    bg: white(),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    title: palette.dark(9),
+                    body: palette.gray(8),
                     padding: px(12.0),
                     gap: px(8.0),
                     radius: px(8.0),
                 },
                 tooltip: TooltipTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.dark(8),
                     fg: white(),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    border: palette.dark(6),
                     text_size: px(12.0),
                     padding_x: px(8.0),
                     padding_y: px(5.0),
@@ -3255,16 +3912,10 @@ impl ComponentTokens {
                     max_width: px(240.0),
                 },
                 hover_card: HoverCardTokens {
-                    bg: white(),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    title: palette.dark(9),
+                    body: palette.gray(8),
                     title_size: px(15.0),
                     title_weight: FontWeight::SEMIBOLD,
                     body_size: px(14.0),
@@ -3275,63 +3926,27 @@ impl ComponentTokens {
                     radius: px(8.0),
                 },
                 select: SelectTokens {
-                    bg: white(),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    placeholder: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    dropdown_bg: white(),
-                    dropdown_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    option_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    option_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    option_selected_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tag_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tag_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tag_border: (Rgba::try_from(PaletteCatalog::scale(primary)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    icon: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    fg: palette.dark(9),
+                    placeholder: palette.gray(5),
+                    border: palette.gray(4),
+                    border_focus: palette.primary(6),
+                    border_error: palette.color(PaletteKey::Red, 6),
+                    dropdown_bg: palette.surface(),
+                    dropdown_border: palette.gray(3),
+                    option_fg: palette.dark(9),
+                    option_hover_bg: palette.gray(1),
+                    option_selected_bg: palette.primary(0),
+                    tag_bg: palette.primary(0),
+                    tag_fg: palette.primary(7),
+                    tag_border: palette.primary(3),
+                    icon: palette.gray(7),
+                    label: palette.dark(8),
                     label_size: px(14.0),
                     label_weight: FontWeight::MEDIUM,
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    description: palette.gray(7),
                     description_size: px(13.0),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    error: palette.color(PaletteKey::Red, 6),
                     error_size: px(13.0),
                     label_block_gap: px(4.0),
                     label_row_gap: px(4.0),
@@ -3358,23 +3973,14 @@ impl ComponentTokens {
                     tag_max_width: px(120.0),
                     dropdown_anchor_offset: px(2.0),
                     sizes: default_field_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 modal: ModalTokens {
-                    panel_bg: white(),
-                    panel_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    overlay_bg: (Rgba::try_from("#000000E6")
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    panel_bg: palette.surface(),
+                    panel_border: palette.gray(3),
+                    overlay_bg: resolve_hex_hsla("#000000E6"),
+                    title: palette.dark(9),
+                    body: palette.gray(8),
                     title_size: px(18.0),
                     title_weight: FontWeight::SEMIBOLD,
                     body_size: px(14.0),
@@ -3392,34 +3998,14 @@ impl ComponentTokens {
                     min_width: px(240.0),
                 },
                 toast: ToastTokens {
-                    info_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Blue)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    info_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Blue)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    success_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Green)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    success_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Green)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    warning_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Yellow)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    warning_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Yellow)[9_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    error_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    error_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    info_bg: palette.color(PaletteKey::Blue, 0),
+                    info_fg: palette.color(PaletteKey::Blue, 8),
+                    success_bg: palette.color(PaletteKey::Green, 0),
+                    success_fg: palette.color(PaletteKey::Green, 8),
+                    warning_bg: palette.color(PaletteKey::Yellow, 0),
+                    warning_fg: palette.color(PaletteKey::Yellow, 9),
+                    error_bg: palette.color(PaletteKey::Red, 0),
+                    error_fg: palette.color(PaletteKey::Red, 8),
                     card_width: px(360.0),
                     card_padding: px(12.0),
                     row_gap: px(8.0),
@@ -3433,44 +4019,37 @@ impl ComponentTokens {
                     stack_gap: px(8.0),
                     edge_offset: px(16.0),
                     top_offset_extra: px(8.0),
+                    action_gap: px(8.0),
+                    progress_height: px(2.0),
                 },
                 divider: DividerTokens {
-                    line: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    line: palette.gray(3),
                     line_width: px(1.0),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    label: palette.gray(6),
                     label_size: px(12.0),
                     label_gap: px(8.0),
                     edge_span: px(16.0),
                 },
                 scroll_area: ScrollAreaTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.gray(0),
+                    border: palette.gray(3),
                     padding: default_inset_size_scale(),
+                    minimap_width: px(64.0),
+                    minimap_bg: palette.gray(1),
+                    minimap_row: palette.gray(4),
+                    minimap_viewport_bg: palette.gray(6).opacity(0.15),
+                    minimap_viewport_border: palette.gray(5),
+                    minimap_marker_info: palette.color(PaletteKey::Blue, 6),
+                    minimap_marker_search: palette.color(PaletteKey::Yellow, 5),
+                    minimap_marker_warning: palette.color(PaletteKey::Orange, 6),
+                    minimap_marker_error: palette.color(PaletteKey::Red, 6),
                 },
                 drawer: DrawerTokens {
-                    panel_bg: white(),
-                    panel_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    overlay_bg: (Rgba::try_from("#000000E6")
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    panel_bg: palette.surface(),
+                    panel_border: palette.gray(3),
+                    overlay_bg: resolve_hex_hsla("#000000E6"),
+                    title: palette.dark(9),
+                    body: palette.gray(8),
                     title_size: px(16.0),
                     title_weight: FontWeight::SEMIBOLD,
                     body_size: px(14.0),
@@ -3481,29 +4060,15 @@ impl ComponentTokens {
                     close_icon_size: px(14.0),
                 },
                 app_shell: AppShellTokens {
-                    bg: white(),
-                    title_bar_bg: white(),
-                    sidebar_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    sidebar_overlay_bg: white(),
-                    content_bg: white(),
-                    bottom_panel_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    inspector_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    inspector_overlay_bg: white(),
-                    region_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    title_bar_bg: palette.surface(),
+                    sidebar_bg: palette.gray(0),
+                    sidebar_overlay_bg: palette.surface(),
+                    content_bg: palette.surface(),
+                    bottom_panel_bg: palette.gray(0),
+                    inspector_bg: palette.gray(0),
+                    inspector_overlay_bg: palette.surface(),
+                    region_border: palette.gray(3),
                     title_bar_height: px(44.0),
                     sidebar_width: px(260.0),
                     sidebar_min_width: px(120.0),
@@ -3511,18 +4076,14 @@ impl ComponentTokens {
                     inspector_min_width: px(120.0),
                     bottom_panel_height: px(180.0),
                     bottom_panel_min_height: px(80.0),
+                    resize_handle_bg: palette.primary(5),
+                    resize_handle_size: px(6.0),
                 },
                 title_bar: TitleBarTokens {
                     bg: transparent_black(),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    controls_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    border: palette.gray(3),
+                    fg: palette.dark(8),
+                    controls_bg: palette.gray(1),
                     height: default_title_bar_height_px(),
                     title_size: px(14.0),
                     title_weight: FontWeight::MEDIUM,
@@ -3541,21 +4102,11 @@ impl ComponentTokens {
                     control_button_radius: px(6.0),
                 },
                 sidebar: SidebarTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    header_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    content_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    footer_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.gray(0),
+                    border: palette.gray(3),
+                    header_fg: palette.dark(8),
+                    content_fg: palette.dark(8),
+                    footer_fg: palette.gray(7),
                     inline_radius: px(10.0),
                     overlay_radius: px(18.0),
                     min_width: px(120.0),
@@ -3564,164 +4115,51 @@ impl ComponentTokens {
                     scroll_padding: Size::Md,
                 },
                 markdown: MarkdownTokens {
-                    paragraph: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    paragraph_muted: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    heading: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    heading2_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    paragraph: palette.dark(8),
+                    paragraph_muted: palette.gray(7),
+                    heading: palette.dark(9),
+                    heading2_border: palette.gray(3),
                     quote_bg: transparent_black(),
-                    quote_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    quote_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    code_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    code_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    code_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    code_lang_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[6_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    link: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    link_hover: (Rgba::try_from(PaletteCatalog::scale(primary)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    strong: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    em: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    del: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    inline_code_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    inline_code_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    inline_code_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    kbd_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    kbd_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    kbd_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    mark_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Yellow)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    mark_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    list_marker: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    rule: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    table_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    table_header_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    table_header_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    table_row_alt_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    table_cell_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    task_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    task_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    task_checked_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    task_checked_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    details_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    details_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    details_summary_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    details_body_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    image_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    quote_border: palette.gray(3),
+                    quote_fg: palette.gray(7),
+                    code_bg: palette.gray(1),
+                    code_border: palette.gray(3),
+                    code_fg: palette.dark(8),
+                    code_lang_fg: palette.gray(6),
+                    link: palette.primary(7),
+                    link_hover: palette.primary(8),
+                    strong: palette.dark(9),
+                    em: palette.dark(8),
+                    del: palette.gray(6),
+                    inline_code_bg: palette.gray(1),
+                    inline_code_border: palette.gray(3),
+                    inline_code_fg: palette.dark(8),
+                    kbd_bg: palette.gray(1),
+                    kbd_border: palette.gray(4),
+                    kbd_fg: palette.dark(8),
+                    mark_bg: palette.color(PaletteKey::Yellow, 1),
+                    mark_fg: palette.dark(8),
+                    list_marker: palette.gray(6),
+                    rule: palette.gray(3),
+                    table_border: palette.gray(3),
+                    table_header_bg: palette.gray(1),
+                    table_header_fg: palette.dark(8),
+                    table_row_alt_bg: palette.gray(0),
+                    table_cell_fg: palette.dark(8),
+                    task_border: palette.gray(4),
+                    task_bg: palette.gray(0),
+                    task_checked_bg: palette.primary(6),
+                    task_checked_fg: palette.gray(0),
+                    details_bg: palette.gray(0),
+                    details_border: palette.gray(3),
+                    details_summary_fg: palette.dark(8),
+                    details_body_fg: palette.gray(7),
+                    image_border: palette.gray(3),
                     image_bg: transparent_black(),
-                    image_caption_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    image_caption_fg: palette.gray(7),
+                    image_placeholder_bg: palette.gray(1),
+                    footnote_fg: palette.gray(7),
+                    selection_bg: palette.primary(1),
                     gap_regular: px(12.0),
                     gap_compact: px(8.0),
                     paragraph_size: px(16.0),
@@ -3735,6 +4173,7 @@ impl ComponentTokens {
                     list_line_height: px(24.0),
                     table_size: px(14.0),
                     image_caption_size: px(13.0),
+                    footnote_size: px(12.0),
                     quote_padding_x: px(8.0),
                     quote_padding_y: px(6.0),
                     quote_radius: px(8.0),
@@ -3756,39 +4195,23 @@ impl ComponentTokens {
                     image_radius: px(8.0),
                     image_padding: px(8.0),
                     image_gap: px(8.0),
+                    image_placeholder_height: px(160.0),
                     heading2_padding_top: px(8.0),
                 },
                 text: TextTokens {
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    secondary: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    muted: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    accent: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    success: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Green)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    warning: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Yellow)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    fg: palette.dark(9),
+                    secondary: palette.gray(7),
+                    muted: palette.gray(6),
+                    accent: palette.primary(6),
+                    success: palette.color(PaletteKey::Green, 7),
+                    warning: palette.color(PaletteKey::Yellow, 8),
+                    error: palette.color(PaletteKey::Red, 6),
+                    selection_bg: palette.primary(1),
                     sizes: default_text_size_scale(),
                 },
                 title: TitleTokens {
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtitle: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    fg: palette.dark(9),
+                    subtitle: palette.gray(6),
                     gap: px(4.0),
                     subtitle_size: px(15.0),
                     subtitle_line_height: px(22.0),
@@ -3825,176 +4248,83 @@ impl ComponentTokens {
                     },
                 },
                 paper: PaperTokens {
-                    bg: white(),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    border: palette.gray(3),
                     padding: default_inset_size_scale(),
                 },
                 action_icon: ActionIconTokens {
-                    filled_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    filled_bg: palette.primary(6),
                     filled_fg: white(),
-                    light_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    light_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_border: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    ghost_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    disabled_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    disabled_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    disabled_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    light_bg: palette.primary(0),
+                    light_fg: palette.primary(6),
+                    subtle_bg: palette.gray(0),
+                    subtle_fg: palette.gray(8),
+                    outline_border: palette.primary(4),
+                    outline_fg: palette.primary(7),
+                    ghost_fg: palette.primary(6),
+                    default_bg: palette.gray(0),
+                    default_fg: palette.dark(8),
+                    default_border: palette.gray(3),
+                    disabled_bg: palette.gray(1),
+                    disabled_fg: palette.gray(5),
+                    disabled_border: palette.gray(3),
                     sizes: default_action_icon_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 segmented_control: SegmentedControlTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_active_bg: white(),
-                    item_active_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[9_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    bg: palette.gray(2),
+                    border: palette.gray(3),
+                    item_fg: palette.gray(8),
+                    item_active_bg: palette.surface(),
+                    item_active_fg: palette.dark(9),
+                    item_hover_bg: palette.gray(1),
+                    item_disabled_fg: palette.gray(5),
                     track_padding: px(2.0),
                     item_gap: px(0.0),
                     sizes: default_segmented_control_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 textarea: TextareaTokens {
-                    bg: white(),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    caret: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    selection_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    placeholder: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    fg: palette.dark(9),
+                    caret: palette.dark(9),
+                    selection_bg: palette.primary(1),
+                    placeholder: palette.gray(5),
+                    border: palette.gray(4),
+                    border_focus: palette.primary(6),
+                    border_error: palette.color(PaletteKey::Red, 6),
+                    spell_error: palette.color(PaletteKey::Red, 6),
+                    label: palette.dark(8),
                     label_size: px(14.0),
                     label_weight: FontWeight::MEDIUM,
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    description: palette.gray(7),
                     description_size: px(13.0),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    error: palette.color(PaletteKey::Red, 6),
                     error_size: px(13.0),
                     label_block_gap: px(4.0),
                     label_row_gap: px(4.0),
                     layout_gap_vertical: px(8.0),
                     layout_gap_horizontal: px(12.0),
-                    horizontal_label_width: px(168.0),
-                    content_width_fallback: px(240.0),
-                    sizes: default_field_size_scale(),
-                },
-                number_input: NumberInputTokens {
-                    bg: white(),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    placeholder: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    controls_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    controls_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    controls_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    horizontal_label_width: px(168.0),
+                    content_width_fallback: px(240.0),
+                    sizes: default_field_size_scale(),
+                },
+                number_input: NumberInputTokens {
+                    bg: palette.surface(),
+                    fg: palette.dark(9),
+                    placeholder: palette.gray(5),
+                    border: palette.gray(4),
+                    border_focus: palette.primary(6),
+                    border_error: palette.color(PaletteKey::Red, 6),
+                    controls_bg: palette.gray(0),
+                    controls_fg: palette.gray(7),
+                    controls_border: palette.gray(3),
+                    label: palette.dark(8),
                     label_size: px(14.0),
                     label_weight: FontWeight::MEDIUM,
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    description: palette.gray(7),
                     description_size: px(13.0),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    error: palette.color(PaletteKey::Red, 6),
                     error_size: px(13.0),
                     controls_width: px(18.0),
                     controls_height: px(12.0),
@@ -4003,153 +4333,85 @@ impl ComponentTokens {
                     sizes: default_field_size_scale(),
                 },
                 range_slider: RangeSliderTokens {
-                    track_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    range_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    thumb_bg: white(),
-                    thumb_border: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    value: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    track_bg: palette.gray(2),
+                    range_bg: palette.primary(6),
+                    thumb_bg: palette.surface(),
+                    thumb_border: palette.primary(6),
+                    label: palette.dark(8),
+                    value: palette.gray(7),
                     label_size: px(14.0),
                     value_size: px(14.0),
                     header_gap_vertical: px(6.0),
                     header_gap_horizontal: px(8.0),
                     default_width: px(260.0),
                     min_width: px(140.0),
+                    mark_bg: palette.gray(4),
+                    mark_size: px(6.0),
+                    mark_label: palette.gray(6),
+                    mark_label_size: px(12.0),
+                    mark_label_gap: px(6.0),
+                    tooltip_offset: px(6.0),
                     sizes: default_slider_size_scale(),
                 },
                 rating: RatingTokens {
-                    active: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Yellow)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    inactive: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    active: palette.color(PaletteKey::Yellow, 6),
+                    inactive: palette.gray(4),
+                    value: palette.gray(7),
+                    value_size: px(14.0),
+                    value_gap: px(8.0),
                     sizes: default_rating_size_scale(),
                 },
                 tabs: TabsTokens {
-                    list_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    list_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tab_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tab_active_bg: white(),
-                    tab_active_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[9_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    tab_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    tab_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    panel_bg: white(),
-                    panel_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    panel_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    list_bg: palette.gray(0),
+                    list_border: palette.gray(3),
+                    tab_fg: palette.gray(7),
+                    tab_active_bg: palette.surface(),
+                    tab_active_fg: palette.dark(9),
+                    tab_hover_bg: palette.gray(1),
+                    tab_disabled_fg: palette.gray(5),
+                    panel_bg: palette.surface(),
+                    panel_border: palette.gray(3),
+                    panel_fg: palette.dark(8),
                     root_gap: px(8.0),
                     list_gap: px(2.0),
                     list_padding: px(2.0),
                     panel_padding: px(16.0),
                     sizes: default_tabs_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 pagination: PaginationTokens {
-                    item_bg: white(),
-                    item_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_active_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    item_bg: palette.surface(),
+                    item_border: palette.gray(3),
+                    item_fg: palette.dark(8),
+                    item_active_bg: palette.primary(6),
                     item_active_fg: white(),
-                    item_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    dots_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    item_hover_bg: palette.gray(1),
+                    item_disabled_fg: palette.gray(5),
+                    dots_fg: palette.gray(6),
                     root_gap: px(4.0),
                     sizes: default_pagination_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 breadcrumbs: BreadcrumbsTokens {
-                    item_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_current_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[9_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    separator: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    item_fg: palette.gray(7),
+                    item_current_fg: palette.dark(9),
+                    separator: palette.gray(5),
+                    item_hover_bg: palette.gray(1),
                     root_gap: px(4.0),
                     sizes: default_breadcrumbs_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 table: TableTokens {
-                    header_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    header_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_bg: white(),
-                    row_alt_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    row_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    cell_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    caption: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    header_bg: palette.gray(0),
+                    header_fg: palette.dark(8),
+                    row_bg: palette.surface(),
+                    row_alt_bg: palette.gray(0),
+                    row_hover_bg: palette.gray(1),
+                    row_selected_bg: palette.primary(0),
+                    row_border: palette.gray(3),
+                    cell_fg: palette.dark(8),
+                    selection_bg: palette.primary(1),
+                    caption: palette.gray(6),
                     caption_size: px(13.0),
                     row_gap: px(0.0),
                     pagination_summary_size: px(13.0),
@@ -4165,49 +4427,24 @@ impl ComponentTokens {
                     virtualization_padding: px(4.0),
                     min_viewport_height: px(80.0),
                     sizes: default_table_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 stepper: StepperTokens {
-                    step_bg: white(),
-                    step_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_active_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_active_border: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    step_bg: palette.surface(),
+                    step_border: palette.gray(4),
+                    step_fg: palette.gray(7),
+                    step_active_bg: palette.primary(6),
+                    step_active_border: palette.primary(6),
                     step_active_fg: white(),
-                    step_completed_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_completed_border: (Rgba::try_from(
-                        PaletteCatalog::scale(primary)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    step_completed_bg: palette.primary(5),
+                    step_completed_border: palette.primary(5),
                     step_completed_fg: white(),
-                    connector: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    panel_bg: white(),
-                    panel_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    panel_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    connector: palette.gray(3),
+                    label: palette.dark(8),
+                    description: palette.gray(6),
+                    panel_bg: palette.surface(),
+                    panel_border: palette.gray(3),
+                    panel_fg: palette.dark(8),
                     root_gap: px(6.0),
                     steps_gap_vertical: px(6.0),
                     text_gap: px(2.0),
@@ -4215,45 +4452,19 @@ impl ComponentTokens {
                     sizes: default_stepper_size_scale(),
                 },
                 timeline: TimelineTokens {
-                    bullet_bg: white(),
-                    bullet_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    bullet_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    bullet_active_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    bullet_active_border: (Rgba::try_from(PaletteCatalog::scale(primary)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bullet_bg: palette.surface(),
+                    bullet_border: palette.gray(4),
+                    bullet_fg: palette.gray(7),
+                    bullet_active_bg: palette.primary(6),
+                    bullet_active_border: palette.primary(6),
                     bullet_active_fg: white(),
-                    line: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    line_active: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title_active: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[9_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    card_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    card_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    line: palette.gray(3),
+                    line_active: palette.primary(5),
+                    title: palette.dark(8),
+                    title_active: palette.dark(9),
+                    body: palette.gray(6),
+                    card_bg: palette.gray(0),
+                    card_border: palette.gray(3),
                     root_gap: px(0.0),
                     row_gap: px(8.0),
                     content_gap: px(4.0),
@@ -4264,118 +4475,590 @@ impl ComponentTokens {
                     sizes: default_timeline_size_scale(),
                 },
                 tree: TreeTokens {
-                    row_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_selected_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_selected_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    row_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    line: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    row_fg: palette.dark(8),
+                    row_selected_fg: palette.primary(7),
+                    row_selected_bg: palette.primary(0),
+                    row_hover_bg: palette.gray(0),
+                    row_disabled_fg: palette.gray(5),
+                    line: palette.gray(3),
+                    drop_indicator: palette.primary(6),
                     root_gap: px(2.0),
                     children_gap: px(0.0),
                     sizes: default_tree_size_scale(),
                 },
+                zoom_pane: ZoomPaneTokens {
+                    bg: palette.gray(0),
+                    grid_line: palette.gray(2),
+                    grid_line_major: palette.gray(3),
+                    ruler_bg: palette.surface(),
+                    ruler_tick: palette.gray(5),
+                    ruler_size: px(16.0),
+                    grid_spacing: px(16.0),
+                },
+                graph_canvas: GraphCanvasTokens {
+                    node_bg: palette.surface(),
+                    node_border: palette.gray(3),
+                    node_selected_border: palette.primary(6),
+                    node_header_bg: palette.gray(1),
+                    node_fg: palette.dark(8),
+                    port_bg: palette.surface(),
+                    port_border: palette.gray(5),
+                    edge: palette.gray(5),
+                    edge_selected: palette.primary(6),
+                    minimap_bg: palette.surface(),
+                    minimap_node: palette.gray(3),
+                    minimap_viewport: palette.primary(6).opacity(0.2),
+                    node_radius: px(8.0),
+                    edge_width: px(2.0),
+                    port_size: px(10.0),
+                    label_size: px(12.0),
+                    minimap_width: px(160.0),
+                    minimap_height: px(110.0),
+                },
+                date_picker: DatePickerTokens {
+                    bg: palette.surface(),
+                    fg: palette.dark(9),
+                    placeholder: palette.gray(5),
+                    border: palette.gray(4),
+                    border_focus: palette.primary(6),
+                    border_error: palette.color(PaletteKey::Red, 6),
+                    icon: palette.gray(7),
+                    label: palette.dark(8),
+                    label_size: px(14.0),
+                    label_weight: FontWeight::MEDIUM,
+                    description: palette.gray(7),
+                    description_size: px(13.0),
+                    error: palette.color(PaletteKey::Red, 6),
+                    error_size: px(13.0),
+                    label_block_gap: px(4.0),
+                    label_row_gap: px(4.0),
+                    layout_gap_vertical: px(8.0),
+                    layout_gap_horizontal: px(12.0),
+                    horizontal_label_width: px(168.0),
+                    slot_gap: px(8.0),
+                    icon_size: px(14.0),
+                    dropdown_bg: palette.surface(),
+                    dropdown_border: palette.gray(3),
+                    dropdown_padding: px(10.0),
+                    dropdown_anchor_offset: px(2.0),
+                    dropdown_open_preferred_height: px(300.0),
+                    header_fg: palette.dark(9),
+                    header_size: px(14.0),
+                    nav_hover_bg: palette.gray(1),
+                    weekday_fg: palette.gray(6),
+                    weekday_size: px(12.0),
+                    day_fg: palette.dark(9),
+                    day_outside_fg: palette.gray(5),
+                    day_hover_bg: palette.gray(1),
+                    day_selected_bg: palette.primary(6),
+                    day_selected_fg: white(),
+                    day_today_border: palette.primary(4),
+                    day_size: px(13.0),
+                    day_cell_size: px(32.0),
+                    grid_gap: px(2.0),
+                    time_column_width: px(52.0),
+                    time_item_height: px(28.0),
+                    time_item_size: px(13.0),
+                    sizes: default_field_size_scale(),
+                    states: PseudoStateTokens::default(),
+                },
+                rulers: RulersTokens {
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    tick: palette.gray(4),
+                    tick_major: palette.gray(6),
+                    label: palette.gray(7),
+                    label_size: px(9.0),
+                    size: px(20.0),
+                    spacing: px(10.0),
+                    guide: palette.color(PaletteKey::Cyan, 6),
+                    guide_snapped: palette.color(PaletteKey::Pink, 6),
+                    guide_hit_width: px(7.0),
+                },
+                color_picker: ColorPickerTokens {
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    padding: px(10.0),
+                    gap: px(10.0),
+                    area_height: px(150.0),
+                    slider_height: px(12.0),
+                    thumb_size: px(14.0),
+                    thumb_border: white(),
+                    checker: palette.gray(2),
+                    preview_size: px(28.0),
+                    swatch_size: px(20.0),
+                    swatch_gap: px(6.0),
+                    swatch_border: palette.gray(3),
+                    swatch_selected_border: palette.dark(9),
+                },
+                file_input: FileInputTokens {
+                    bg: palette.gray(0),
+                    bg_active: palette.primary(0),
+                    border: palette.gray(4),
+                    border_active: palette.primary(6),
+                    border_error: palette.color(PaletteKey::Red, 6),
+                    fg: palette.dark(9),
+                    hint: palette.gray(6),
+                    icon: palette.gray(6),
+                    radius: px(8.0),
+                    padding: px(16.0),
+                    gap: px(6.0),
+                    icon_size: px(24.0),
+                    text_size: px(14.0),
+                    hint_size: px(12.0),
+                    chip_bg: palette.surface(),
+                    chip_border: palette.gray(3),
+                    chip_fg: palette.dark(8),
+                    chip_meta: palette.gray(6),
+                    chip_remove_hover_bg: palette.gray(1),
+                    chip_size: px(13.0),
+                    chip_padding_x: px(8.0),
+                    chip_padding_y: px(4.0),
+                    chip_gap: px(6.0),
+                    chip_max_width: px(240.0),
+                    label: palette.dark(8),
+                    label_size: px(14.0),
+                    label_weight: FontWeight::MEDIUM,
+                    description: palette.gray(7),
+                    description_size: px(13.0),
+                    error: palette.color(PaletteKey::Red, 6),
+                    error_size: px(13.0),
+                    label_block_gap: px(4.0),
+                    label_row_gap: px(4.0),
+                    layout_gap_vertical: px(8.0),
+                    layout_gap_horizontal: px(12.0),
+                    horizontal_label_width: px(168.0),
+                },
                 layout: LayoutTokens {
                     gap: default_layout_gap_scale(),
                     space: default_layout_space_scale(),
                     popup_snap_margin: px(8.0),
                 },
+                focus_ring: FocusRingTokens {
+                    color: palette.primary(6),
+                    width: px(2.0),
+                    offset: px(2.0),
+                    style: FocusRingStyle::Solid,
+                },
+                press_feedback: PressFeedbackTokens {
+                    style: PressFeedbackStyle::Scale,
+                    scale_inset: px(1.0),
+                    ripple_color: black().opacity(0.12),
+                },
+                async_content: AsyncContentTokens {
+                    skeleton_bg: palette.gray(2),
+                    skeleton_radius: px(4.0),
+                    skeleton_line_height: px(12.0),
+                    skeleton_gap: px(10.0),
+                    empty_icon: palette.gray(5),
+                    empty_title: palette.gray(8),
+                    empty_description: palette.gray(6),
+                    empty_icon_size: px(32.0),
+                    empty_title_size: px(14.0),
+                    empty_description_size: px(13.0),
+                    empty_gap: px(6.0),
+                    empty_padding: px(24.0),
+                },
+                checklist: ChecklistTokens {
+                    done: palette.color(PaletteKey::Green, 6),
+                    active: palette.color(PaletteKey::Blue, 6),
+                    pending: palette.gray(4),
+                    error: palette.color(PaletteKey::Red, 6),
+                    label: palette.gray(8),
+                    description: palette.gray(6),
+                    summary: palette.gray(7),
+                    icon_size: px(18.0),
+                    label_size: px(14.0),
+                    description_size: px(13.0),
+                    summary_size: px(13.0),
+                    gap: px(10.0),
+                    item_gap: px(10.0),
+                    detail_indent: px(28.0),
+                },
+                task_status: TaskStatusTokens {
+                    trigger_fg: palette.gray(7),
+                    trigger_hover_bg: palette.gray(1),
+                    title: palette.gray(9),
+                    message: palette.gray(6),
+                    running: palette.color(PaletteKey::Blue, 6),
+                    done: palette.color(PaletteKey::Green, 6),
+                    error: palette.color(PaletteKey::Red, 6),
+                    cancelled: palette.gray(5),
+                    trigger_size: px(12.0),
+                    title_size: px(13.0),
+                    message_size: px(12.0),
+                    icon_size: px(14.0),
+                    trigger_bar_width: px(64.0),
+                    panel_width: px(280.0),
+                    item_gap: px(10.0),
+                    row_gap: px(4.0),
+                },
+                notification_center: NotificationCenterTokens {
+                    header: palette.gray(9),
+                    title: palette.gray(9),
+                    message: palette.gray(6),
+                    empty: palette.gray(5),
+                    unread_dot: palette.color(PaletteKey::Blue, 6),
+                    unread_bg: palette.color(PaletteKey::Blue, 0),
+                    row_hover_bg: palette.gray(1),
+                    info: palette.color(PaletteKey::Blue, 6),
+                    success: palette.color(PaletteKey::Green, 6),
+                    warning: palette.color(PaletteKey::Yellow, 6),
+                    error: palette.color(PaletteKey::Red, 6),
+                    header_size: px(14.0),
+                    title_size: px(13.0),
+                    message_size: px(12.0),
+                    icon_size: px(16.0),
+                    dot_size: px(8.0),
+                    panel_width: px(320.0),
+                    row_padding: px(8.0),
+                    item_gap: px(4.0),
+                    row_gap: px(10.0),
+                },
+                find_bar: FindBarTokens {
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    count: palette.gray(6),
+                    match_bg: palette.color(PaletteKey::Yellow, 2),
+                    active_match_bg: palette.color(PaletteKey::Orange, 4),
+                    width: px(320.0),
+                    padding: px(6.0),
+                    gap: px(6.0),
+                    radius: px(8.0),
+                    offset: px(8.0),
+                    count_size: px(12.0),
+                },
+                split_pane: SplitPaneTokens {
+                    divider: palette.gray(3),
+                    divider_active: palette.primary(6),
+                    divider_size: px(1.0),
+                    handle_size: px(8.0),
+                    min_pane_size: px(48.0),
+                },
+                dock_tabs: DockTabsTokens {
+                    strip_bg: palette.gray(1),
+                    strip_border: palette.gray(3),
+                    tab_fg: palette.gray(7),
+                    tab_hover_bg: palette.gray(2),
+                    tab_active_bg: palette.surface(),
+                    tab_active_fg: black(),
+                    tab_active_indicator: palette.primary(6),
+                    tab_disabled_fg: palette.gray(5),
+                    dirty_dot: palette.gray(6),
+                    drop_indicator: palette.primary(6),
+                    tab_height: px(32.0),
+                    tab_padding_x: px(10.0),
+                    tab_gap: px(6.0),
+                    tab_max_width: px(220.0),
+                    close_size: px(16.0),
+                    dirty_dot_size: px(8.0),
+                    overflow_button_width: px(28.0),
+                },
+                image: ImageTokens {
+                    placeholder_bg: palette.gray(1),
+                    fallback_bg: palette.gray(0),
+                    fallback_fg: palette.gray(6),
+                    fallback_icon_size: px(24.0),
+                    fallback_gap: px(6.0),
+                    fallback_font_size: px(12.0),
+                },
+                calendar: CalendarTokens {
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    header_fg: palette.dark(9),
+                    header_size: px(16.0),
+                    nav_hover_bg: palette.gray(1),
+                    icon: palette.gray(6),
+                    icon_size: px(16.0),
+                    weekday_fg: palette.gray(6),
+                    weekday_size: px(12.0),
+                    day_fg: palette.dark(9),
+                    day_outside_fg: palette.gray(5),
+                    day_hover_bg: palette.gray(0),
+                    day_size: px(13.0),
+                    today_bg: palette.primary(6),
+                    today_fg: white(),
+                    range_bg: palette.primary(0),
+                    range_edge_bg: palette.primary(1),
+                    cell_border: palette.gray(2),
+                    cell_padding: px(4.0),
+                    month_cell_height: px(96.0),
+                    week_cell_height: px(240.0),
+                    chip_bg: palette.primary(1),
+                    chip_fg: palette.primary(9),
+                    chip_size: px(11.0),
+                    chip_padding_x: px(4.0),
+                    chip_gap: px(2.0),
+                    more_fg: palette.gray(6),
+                    header_gap: px(8.0),
+                },
+                kanban_board: KanbanBoardTokens {
+                    column_bg: palette.gray(0),
+                    column_border: palette.gray(2),
+                    column_width: px(280.0),
+                    column_gap: px(12.0),
+                    column_padding: px(8.0),
+                    column_radius: px(8.0),
+                    list_height: px(480.0),
+                    header_fg: palette.dark(9),
+                    header_size: px(14.0),
+                    header_gap: px(8.0),
+                    count_bg: palette.gray(2),
+                    count_fg: palette.gray(7),
+                    count_over_limit_bg: palette.color(PaletteKey::Red, 1),
+                    count_over_limit_fg: palette.color(PaletteKey::Red, 8),
+                    count_size: px(12.0),
+                    card_bg: palette.surface(),
+                    card_border: palette.gray(3),
+                    card_height: px(72.0),
+                    card_gap: px(8.0),
+                    card_padding: px(10.0),
+                    placeholder_bg: palette.primary(0),
+                    placeholder_border: palette.primary(4),
+                    empty_fg: palette.gray(5),
+                    empty_size: px(12.0),
+                },
+                chart: ChartTokens {
+                    grid: palette.gray(2),
+                    axis_fg: palette.gray(6),
+                    axis_size: px(11.0),
+                    axis_width: px(40.0),
+                    axis_gap: px(6.0),
+                    height: px(200.0),
+                    line_width: px(2.0),
+                    point_size: px(6.0),
+                    point_bg: palette.surface(),
+                    bar_gap: px(8.0),
+                    bar_radius: px(3.0),
+                    donut_size: px(160.0),
+                    donut_thickness: px(20.0),
+                    donut_track: palette.gray(1),
+                    center_fg: palette.gray(9),
+                    center_size: px(20.0),
+                    legend_fg: palette.gray(7),
+                    legend_size: px(12.0),
+                    legend_gap: px(12.0),
+                    swatch_size: px(8.0),
+                    sparkline_width: px(120.0),
+                    sparkline_height: px(32.0),
+                    sparkline_line_width: px(1.5),
+                    empty_fg: palette.gray(5),
+                    empty_size: px(12.0),
+                },
+                ring_progress: RingProgressTokens {
+                    size: px(96.0),
+                    thickness: px(8.0),
+                    label_size: px(14.0),
+                    section_gap: px(2.0),
+                },
+                stat_card: StatCardTokens {
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    radius: px(8.0),
+                    padding: px(16.0),
+                    gap: px(6.0),
+                    label_fg: palette.gray(6),
+                    label_size: px(13.0),
+                    value_fg: palette.gray(9),
+                    value_size: px(26.0),
+                    icon_fg: palette.gray(5),
+                    icon_size: px(20.0),
+                    delta_size: px(13.0),
+                    delta_icon_size: px(14.0),
+                    delta_up: palette.color(PaletteKey::Green, 7),
+                    delta_down: palette.color(PaletteKey::Red, 7),
+                    delta_flat: palette.gray(6),
+                    description_fg: palette.gray(6),
+                    description_size: px(12.0),
+                    skeleton_bg: palette.gray(2),
+                },
+                empty_state: EmptyStateTokens {
+                    icon_fg: palette.gray(6),
+                    icon_bg: palette.gray(1),
+                    title_fg: palette.gray(9),
+                    description_fg: palette.gray(6),
+                    actions_gap: px(8.0),
+                    sizes: default_empty_state_size_scale(),
+                },
+                announcement_bar: AnnouncementBarTokens {
+                    info_bg: palette.color(PaletteKey::Blue, 6),
+                    info_fg: white(),
+                    success_bg: palette.color(PaletteKey::Green, 7),
+                    success_fg: white(),
+                    warning_bg: palette.color(PaletteKey::Yellow, 4),
+                    warning_fg: palette.gray(9),
+                    error_bg: palette.color(PaletteKey::Red, 7),
+                    error_fg: white(),
+                    min_height: px(36.0),
+                    padding_x: px(16.0),
+                    padding_y: px(6.0),
+                    gap: px(10.0),
+                    font_size: px(13.0),
+                    icon_size: px(16.0),
+                    close_button_size: px(22.0),
+                    close_icon_size: px(14.0),
+                },
+                wizard: WizardTokens {
+                    error_fg: palette.color(PaletteKey::Red, 7),
+                    error_size: px(13.0),
+                    footer_gap: px(8.0),
+                    root_gap: px(12.0),
+                },
+                editable_text: EditableTextTokens {
+                    fg: palette.gray(9),
+                    placeholder: palette.gray(5),
+                    hover_bg: palette.gray(1),
+                    error_fg: palette.color(PaletteKey::Red, 7),
+                    text_size: px(14.0),
+                    error_size: px(12.0),
+                    padding_x: px(6.0),
+                    padding_y: px(2.0),
+                    radius: px(4.0),
+                    gap: px(4.0),
+                },
+                copy_button: CopyButtonTokens {
+                    copied_fg: palette.color(PaletteKey::Green, 6),
+                    icon_size: px(14.0),
+                },
+                chat_view: ChatViewTokens {
+                    own_bg: palette.primary(1),
+                    other_bg: palette.gray(1),
+                    author_fg: palette.gray(7),
+                    time_fg: palette.gray(6),
+                    separator_fg: palette.gray(6),
+                    separator_line: palette.gray(3),
+                    avatar_bg: palette.gray(2),
+                    avatar_fg: palette.gray(7),
+                    pill_bg: palette.primary(6),
+                    pill_fg: white(),
+                    typing_fg: palette.gray(6),
+                    padding: px(12.0),
+                    message_gap: px(4.0),
+                    avatar_size: px(28.0),
+                    avatar_gap: px(8.0),
+                    bubble_radius: px(14.0),
+                    bubble_padding_x: px(12.0),
+                    bubble_padding_y: px(8.0),
+                    bubble_max_width: px(520.0),
+                    meta_size: px(12.0),
+                    separator_size: px(12.0),
+                    estimated_row_height: px(64.0),
+                    pill_offset: px(12.0),
+                },
+                log_view: LogViewTokens {
+                    bg: palette.gray(0),
+                    border: palette.gray(3),
+                    fg: palette.gray(9),
+                    muted_fg: palette.gray(6),
+                    gutter_fg: palette.gray(5),
+                    warn_fg: palette.color(PaletteKey::Yellow, 8),
+                    error_fg: palette.color(PaletteKey::Red, 7),
+                    selection_bg: palette.primary(1),
+                    ansi_black: palette.dark(9),
+                    ansi_red: palette.color(PaletteKey::Red, 7),
+                    ansi_green: palette.color(PaletteKey::Green, 7),
+                    ansi_yellow: palette.color(PaletteKey::Yellow, 8),
+                    ansi_blue: palette.color(PaletteKey::Blue, 7),
+                    ansi_magenta: palette.color(PaletteKey::Grape, 7),
+                    ansi_cyan: palette.color(PaletteKey::Cyan, 7),
+                    ansi_white: palette.gray(5),
+                    text_size: px(12.0),
+                    line_height: px(18.0),
+                    padding: px(8.0),
+                    gutter_gap: px(12.0),
+                    radius: px(6.0),
+                },
+                json_tree: JsonTreeTokens {
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    punctuation_fg: palette.gray(6),
+                    key_fg: palette.color(PaletteKey::Grape, 8),
+                    index_fg: palette.gray(5),
+                    string_fg: palette.color(PaletteKey::Green, 8),
+                    number_fg: palette.color(PaletteKey::Blue, 7),
+                    boolean_fg: palette.color(PaletteKey::Orange, 8),
+                    null_fg: palette.gray(5),
+                    summary_fg: palette.gray(5),
+                    toggle_fg: palette.gray(6),
+                    more_fg: palette.primary(6),
+                    row_hover_bg: palette.gray(0),
+                    match_bg: palette.color(PaletteKey::Yellow, 2),
+                    text_size: px(12.0),
+                    row_height: px(22.0),
+                    indent: px(16.0),
+                    padding: px(6.0),
+                    toggle_size: px(12.0),
+                    radius: px(6.0),
+                },
+                settings_view: SettingsViewTokens {
+                    bg: palette.surface(),
+                    border: palette.gray(3),
+                    nav_bg: palette.gray(0),
+                    nav_fg: palette.gray(7),
+                    nav_hover_bg: palette.gray(1),
+                    nav_active_bg: palette.primary(0),
+                    nav_active_fg: palette.primary(7),
+                    count_fg: palette.gray(6),
+                    title_fg: palette.gray(9),
+                    description_fg: palette.gray(6),
+                    label_fg: palette.gray(9),
+                    separator: palette.gray(2),
+                    match_bg: palette.color(PaletteKey::Yellow, 2),
+                    empty_fg: palette.gray(6),
+                    nav_width: px(220.0),
+                    nav_padding: px(12.0),
+                    nav_gap: px(2.0),
+                    nav_item_padding_x: px(10.0),
+                    nav_item_padding_y: px(6.0),
+                    nav_item_radius: px(6.0),
+                    nav_item_size: px(14.0),
+                    content_padding: px(24.0),
+                    content_max_width: px(720.0),
+                    section_gap: px(32.0),
+                    title_size: px(20.0),
+                    heading_size: px(15.0),
+                    description_size: px(13.0),
+                    label_size: px(14.0),
+                    item_gap: px(24.0),
+                    item_padding_y: px(14.0),
+                    control_width: px(240.0),
+                },
             },
             ColorScheme::Dark => Self {
                 button: ButtonTokens {
-                    filled_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    filled_bg: palette.primary(5),
                     filled_fg: white(),
-                    light_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    light_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    outline_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    ghost_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    disabled_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    disabled_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    light_bg: palette.dark(6),
+                    light_fg: palette.primary(2),
+                    subtle_bg: palette.dark(7),
+                    subtle_fg: palette.gray(1),
+                    outline_border: palette.dark(4),
+                    outline_fg: palette.gray(1),
+                    ghost_fg: palette.gray(2),
+                    disabled_bg: palette.dark(6),
+                    disabled_fg: palette.dark(3),
                     sizes: default_button_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 input: InputTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    caret: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    selection_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    placeholder: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    fg: palette.gray(0),
+                    caret: palette.gray(0),
+                    selection_bg: palette.primary(8),
+                    placeholder: palette.dark(2),
+                    border: palette.dark(4),
+                    border_focus: palette.primary(5),
+                    border_error: palette.color(PaletteKey::Red, 5),
+                    label: palette.gray(1),
                     label_size: px(14.0),
                     label_weight: FontWeight::MEDIUM,
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    description: palette.gray(4),
                     description_size: px(13.0),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    error: palette.color(PaletteKey::Red, 4),
                     error_size: px(13.0),
                     label_block_gap: px(4.0),
                     label_row_gap: px(4.0),
-                    slot_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    slot_fg: palette.gray(4),
                     slot_gap: px(8.0),
                     slot_min_width: px(16.0),
                     layout_gap_vertical: px(8.0),
@@ -4383,162 +5066,78 @@ impl ComponentTokens {
                     horizontal_label_width: px(168.0),
                     pin_cells_gap: px(8.0),
                     pin_error_gap: px(4.0),
+                    password_toggle_size: px(16.0),
+                    password_caps_lock_fg: palette.color(PaletteKey::Orange, 4),
+                    password_caps_lock_size: px(12.0),
+                    password_meter_gap: px(6.0),
+                    password_meter_height: px(4.0),
+                    password_meter_segment_gap: px(4.0),
+                    password_meter_track: palette.dark(4),
+                    password_strength_weak: palette.color(PaletteKey::Red, 5),
+                    password_strength_fair: palette.color(PaletteKey::Orange, 5),
+                    password_strength_good: palette.color(PaletteKey::Yellow, 5),
+                    password_strength_strong: palette.color(PaletteKey::Green, 5),
+                    password_strength_label_size: px(12.0),
                     sizes: default_field_size_scale(),
                 },
                 radio: RadioTokens {
-                    control_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_hover: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_checked: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    indicator: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    control_bg: palette.surface(),
+                    border: palette.dark(4),
+                    border_hover: palette.gray(3),
+                    border_focus: palette.primary(5),
+                    border_checked: palette.primary(5),
+                    indicator: palette.primary(4),
+                    label: palette.gray(0),
+                    description: palette.gray(4),
                     label_description_gap: px(2.0),
                     group_gap_horizontal: px(12.0),
                     group_gap_vertical: px(8.0),
                     sizes: default_choice_control_size_scale(),
                 },
                 checkbox: CheckboxTokens {
-                    control_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    control_bg_checked: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_hover: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_checked: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    control_bg: palette.surface(),
+                    control_bg_checked: palette.primary(5),
+                    border: palette.dark(4),
+                    border_hover: palette.gray(3),
+                    border_focus: palette.primary(5),
+                    border_checked: palette.primary(5),
                     indicator: white(),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    label: palette.gray(0),
+                    description: palette.gray(4),
                     label_description_gap: px(2.0),
                     group_gap_horizontal: px(12.0),
                     group_gap_vertical: px(8.0),
                     sizes: default_choice_control_size_scale(),
                 },
                 switch: SwitchTokens {
-                    track_off_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    track_on_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    track_hover_border: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    track_focus_border: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    thumb_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    track_off_bg: palette.dark(3),
+                    track_on_bg: palette.primary(5),
+                    track_hover_border: palette.primary(4),
+                    track_focus_border: palette.primary(5),
+                    thumb_bg: palette.gray(0),
+                    label: palette.gray(0),
+                    description: palette.gray(4),
                     label_description_gap: px(2.0),
                     sizes: default_switch_size_scale(),
                 },
                 chip: ChipTokens {
-                    unchecked_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    unchecked_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    unchecked_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    filled_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    unchecked_bg: palette.dark(7),
+                    unchecked_fg: palette.gray(1),
+                    unchecked_border: palette.dark(4),
+                    filled_bg: palette.primary(5),
                     filled_fg: white(),
-                    light_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    light_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    outline_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    ghost_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    border_hover: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    light_bg: palette.dark(6),
+                    light_fg: palette.primary(2),
+                    subtle_bg: palette.dark(7),
+                    subtle_fg: palette.gray(1),
+                    outline_border: palette.dark(4),
+                    outline_fg: palette.gray(1),
+                    ghost_fg: palette.gray(1),
+                    default_bg: palette.dark(7),
+                    default_fg: palette.gray(1),
+                    default_border: palette.dark(4),
+                    border_hover: palette.gray(3),
+                    border_focus: palette.primary(5),
                     content_gap: px(4.0),
                     indicator_size: px(12.0),
                     group_gap_horizontal: px(8.0),
@@ -4546,93 +5145,40 @@ impl ComponentTokens {
                     sizes: default_button_size_scale(),
                 },
                 badge: BadgeTokens {
-                    filled_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    filled_bg: palette.primary(5),
                     filled_fg: white(),
-                    light_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    light_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    outline_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    light_bg: palette.dark(6),
+                    light_fg: palette.primary(2),
+                    subtle_bg: palette.dark(7),
+                    subtle_fg: palette.gray(1),
+                    outline_border: palette.dark(4),
+                    outline_fg: palette.gray(1),
+                    default_bg: palette.dark(7),
+                    default_fg: palette.gray(1),
+                    default_border: palette.dark(4),
                     sizes: default_badge_size_scale(),
                 },
                 accordion: AccordionTokens {
-                    item_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    content: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    chevron: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    item_bg: palette.surface(),
+                    item_border: palette.dark(4),
+                    label: palette.gray(0),
+                    description: palette.gray(4),
+                    content: palette.gray(2),
+                    chevron: palette.gray(4),
                     stack_gap: px(8.0),
                     header_gap: px(8.0),
                     label_stack_gap: px(2.0),
                     panel_gap: px(4.0),
                     sizes: default_accordion_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 menu: MenuTokens {
-                    dropdown_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    dropdown_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[2_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    icon: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    dropdown_bg: palette.surface(),
+                    dropdown_border: palette.dark(4),
+                    item_fg: palette.gray(0),
+                    item_hover_bg: palette.dark(7),
+                    item_disabled_fg: palette.dark(2),
+                    icon: palette.gray(4),
                     item_gap: px(8.0),
                     item_padding_x: px(10.0),
                     item_padding_y: px(8.0),
@@ -4644,103 +5190,66 @@ impl ComponentTokens {
                     dropdown_radius: px(8.0),
                     dropdown_width_fallback: px(220.0),
                     dropdown_min_width: px(180.0),
+                    states: PseudoStateTokens::default(),
                 },
                 progress: ProgressTokens {
-                    track_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fill_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    track_bg: palette.dark(5),
+                    fill_bg: palette.primary(5),
+                    label: palette.gray(2),
                     default_width: px(260.0),
                     min_width: px(80.0),
                     root_gap: px(6.0),
                     sizes: default_progress_size_scale(),
                 },
                 slider: SliderTokens {
-                    track_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fill_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    thumb_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    thumb_border: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    value: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    track_bg: palette.dark(5),
+                    fill_bg: palette.primary(5),
+                    thumb_bg: palette.gray(0),
+                    thumb_border: palette.primary(5),
+                    label: palette.gray(2),
+                    value: palette.gray(4),
                     label_size: px(14.0),
                     value_size: px(14.0),
                     header_gap_vertical: px(6.0),
                     header_gap_horizontal: px(8.0),
                     default_width: px(260.0),
                     min_width: px(120.0),
+                    mark_bg: palette.dark(3),
+                    mark_size: px(6.0),
+                    mark_label: palette.dark(2),
+                    mark_label_size: px(12.0),
+                    mark_label_gap: px(6.0),
+                    tooltip_offset: px(6.0),
                     sizes: default_slider_size_scale(),
                 },
                 overlay: OverlayTokens {
-                    bg: (Rgba::try_from("#000000E6")
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: resolve_hex_hsla("#000000E6"),
                 },
                 loader: LoaderTokens {
-                    color: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    color: palette.primary(4),
+                    label: palette.gray(3),
                     sizes: default_loader_size_scale(),
                 },
                 loading_overlay: LoadingOverlayTokens {
-                    bg: (Rgba::try_from("#000000E6")
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    loader_color: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: resolve_hex_hsla("#000000E6"),
+                    loader_color: palette.primary(4),
+                    label: palette.gray(0),
                     content_gap: px(8.0),
                     label_size: px(13.0),
                 },
                 popover: PopoverTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    border: palette.dark(4),
+                    title: palette.gray(0),
+                    body: palette.gray(3),
                     padding: px(12.0),
                     gap: px(8.0),
                     radius: px(8.0),
                 },
                 tooltip: TooltipTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.dark(9),
+                    fg: palette.gray(0),
+                    border: palette.dark(4),
                     text_size: px(12.0),
                     padding_x: px(8.0),
                     padding_y: px(5.0),
@@ -4748,18 +5257,10 @@ impl ComponentTokens {
                     max_width: px(240.0),
                 },
                 hover_card: HoverCardTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    border: palette.dark(4),
+                    title: palette.gray(0),
+                    body: palette.gray(3),
                     title_size: px(15.0),
                     title_weight: FontWeight::SEMIBOLD,
                     body_size: px(14.0),
@@ -4770,67 +5271,27 @@ impl ComponentTokens {
                     radius: px(8.0),
                 },
                 select: SelectTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    placeholder: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    dropdown_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    dropdown_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    option_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    option_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    option_selected_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tag_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tag_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tag_border: (Rgba::try_from(PaletteCatalog::scale(primary)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    icon: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    fg: palette.gray(0),
+                    placeholder: palette.dark(2),
+                    border: palette.dark(4),
+                    border_focus: palette.primary(5),
+                    border_error: palette.color(PaletteKey::Red, 5),
+                    dropdown_bg: palette.surface(),
+                    dropdown_border: palette.dark(4),
+                    option_fg: palette.gray(0),
+                    option_hover_bg: palette.dark(7),
+                    option_selected_bg: palette.primary(9),
+                    tag_bg: palette.primary(9),
+                    tag_fg: palette.primary(2),
+                    tag_border: palette.primary(7),
+                    icon: palette.gray(4),
+                    label: palette.gray(1),
                     label_size: px(14.0),
                     label_weight: FontWeight::MEDIUM,
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    description: palette.gray(4),
                     description_size: px(13.0),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    error: palette.color(PaletteKey::Red, 4),
                     error_size: px(13.0),
                     label_block_gap: px(4.0),
                     label_row_gap: px(4.0),
@@ -4857,25 +5318,14 @@ impl ComponentTokens {
                     tag_max_width: px(120.0),
                     dropdown_anchor_offset: px(2.0),
                     sizes: default_field_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 modal: ModalTokens {
-                    panel_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    panel_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    overlay_bg: (Rgba::try_from("#000000E6")
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    panel_bg: palette.surface(),
+                    panel_border: palette.dark(4),
+                    overlay_bg: resolve_hex_hsla("#000000E6"),
+                    title: palette.gray(0),
+                    body: palette.gray(3),
                     title_size: px(18.0),
                     title_weight: FontWeight::SEMIBOLD,
                     body_size: px(14.0),
@@ -4893,14 +5343,14 @@ impl ComponentTokens {
                     min_width: px(240.0),
                 },
                 toast: ToastTokens {
-                    info_bg: resolve_palette_hsla(PaletteKey::Blue, 4).opacity(0.15),
-                    info_fg: resolve_palette_hsla(PaletteKey::Blue, 4),
-                    success_bg: resolve_palette_hsla(PaletteKey::Green, 4).opacity(0.15),
-                    success_fg: resolve_palette_hsla(PaletteKey::Green, 4),
-                    warning_bg: resolve_palette_hsla(PaletteKey::Yellow, 4).opacity(0.15),
-                    warning_fg: resolve_palette_hsla(PaletteKey::Yellow, 4),
-                    error_bg: resolve_palette_hsla(PaletteKey::Red, 4).opacity(0.15),
-                    error_fg: resolve_palette_hsla(PaletteKey::Red, 4),
+                    info_bg: palette.color(PaletteKey::Blue, 4).opacity(0.15),
+                    info_fg: palette.color(PaletteKey::Blue, 4),
+                    success_bg: palette.color(PaletteKey::Green, 4).opacity(0.15),
+                    success_fg: palette.color(PaletteKey::Green, 4),
+                    warning_bg: palette.color(PaletteKey::Yellow, 4).opacity(0.15),
+                    warning_fg: palette.color(PaletteKey::Yellow, 4),
+                    error_bg: palette.color(PaletteKey::Red, 4).opacity(0.15),
+                    error_fg: palette.color(PaletteKey::Red, 4),
                     card_width: px(360.0),
                     card_padding: px(12.0),
                     row_gap: px(8.0),
@@ -4914,46 +5364,37 @@ impl ComponentTokens {
                     stack_gap: px(8.0),
                     edge_offset: px(16.0),
                     top_offset_extra: px(8.0),
+                    action_gap: px(8.0),
+                    progress_height: px(2.0),
                 },
                 divider: DividerTokens {
-                    line: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    line: palette.dark(4),
                     line_width: px(1.0),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    label: palette.gray(5),
                     label_size: px(12.0),
                     label_gap: px(8.0),
                     edge_span: px(16.0),
                 },
                 scroll_area: ScrollAreaTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.dark(8),
+                    border: palette.dark(5),
                     padding: default_inset_size_scale(),
+                    minimap_width: px(64.0),
+                    minimap_bg: palette.dark(7),
+                    minimap_row: palette.dark(4),
+                    minimap_viewport_bg: palette.dark(0).opacity(0.12),
+                    minimap_viewport_border: palette.dark(3),
+                    minimap_marker_info: palette.color(PaletteKey::Blue, 4),
+                    minimap_marker_search: palette.color(PaletteKey::Yellow, 4),
+                    minimap_marker_warning: palette.color(PaletteKey::Orange, 4),
+                    minimap_marker_error: palette.color(PaletteKey::Red, 5),
                 },
                 drawer: DrawerTokens {
-                    panel_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    panel_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    overlay_bg: (Rgba::try_from("#000000E6")
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    panel_bg: palette.surface(),
+                    panel_border: palette.dark(4),
+                    overlay_bg: resolve_hex_hsla("#000000E6"),
+                    title: palette.gray(0),
+                    body: palette.gray(3),
                     title_size: px(16.0),
                     title_weight: FontWeight::SEMIBOLD,
                     body_size: px(14.0),
@@ -4962,47 +5403,17 @@ impl ComponentTokens {
                     header_margin_bottom: px(8.0),
                     close_size: px(28.0),
                     close_icon_size: px(14.0),
-                },
-                app_shell: AppShellTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title_bar_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[9_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    sidebar_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    sidebar_overlay_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    content_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    bottom_panel_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    inspector_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    inspector_overlay_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    region_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                },
+                app_shell: AppShellTokens {
+                    bg: palette.dark(9),
+                    title_bar_bg: palette.dark(9),
+                    sidebar_bg: palette.dark(8),
+                    sidebar_overlay_bg: palette.surface(),
+                    content_bg: palette.dark(9),
+                    bottom_panel_bg: palette.dark(8),
+                    inspector_bg: palette.dark(8),
+                    inspector_overlay_bg: palette.surface(),
+                    region_border: palette.dark(4),
                     title_bar_height: px(44.0),
                     sidebar_width: px(260.0),
                     sidebar_min_width: px(120.0),
@@ -5010,18 +5421,14 @@ impl ComponentTokens {
                     inspector_min_width: px(120.0),
                     bottom_panel_height: px(180.0),
                     bottom_panel_min_height: px(80.0),
+                    resize_handle_bg: palette.primary(4),
+                    resize_handle_size: px(6.0),
                 },
                 title_bar: TitleBarTokens {
                     bg: transparent_black(),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    controls_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    border: palette.dark(4),
+                    fg: palette.gray(1),
+                    controls_bg: palette.dark(6),
                     height: default_title_bar_height_px(),
                     title_size: px(14.0),
                     title_weight: FontWeight::MEDIUM,
@@ -5040,21 +5447,11 @@ impl ComponentTokens {
                     control_button_radius: px(6.0),
                 },
                 sidebar: SidebarTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    header_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    content_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    footer_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.dark(8),
+                    border: palette.dark(4),
+                    header_fg: palette.gray(1),
+                    content_fg: palette.gray(2),
+                    footer_fg: palette.gray(4),
                     inline_radius: px(10.0),
                     overlay_radius: px(18.0),
                     min_width: px(120.0),
@@ -5063,164 +5460,51 @@ impl ComponentTokens {
                     scroll_padding: Size::Md,
                 },
                 markdown: MarkdownTokens {
-                    paragraph: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    paragraph_muted: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    heading: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    heading2_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    paragraph: palette.gray(2),
+                    paragraph_muted: palette.gray(5),
+                    heading: palette.gray(0),
+                    heading2_border: palette.dark(5),
                     quote_bg: transparent_black(),
-                    quote_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    quote_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    code_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    code_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    code_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    code_lang_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    link: (Rgba::try_from(PaletteCatalog::scale(primary)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    link_hover: (Rgba::try_from(PaletteCatalog::scale(primary)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    strong: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    em: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    del: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    inline_code_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    inline_code_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    inline_code_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[2_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    kbd_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    kbd_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    kbd_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    mark_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Yellow)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    mark_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    list_marker: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    rule: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    table_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    table_header_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    table_header_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    table_row_alt_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[8_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    table_cell_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[2_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    task_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    task_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    task_checked_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    task_checked_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[9_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    details_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    details_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    details_summary_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[1_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    details_body_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    image_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    quote_border: palette.dark(5),
+                    quote_fg: palette.gray(4),
+                    code_bg: palette.dark(8),
+                    code_border: palette.dark(5),
+                    code_fg: palette.gray(2),
+                    code_lang_fg: palette.gray(5),
+                    link: palette.primary(3),
+                    link_hover: palette.primary(2),
+                    strong: palette.gray(0),
+                    em: palette.gray(1),
+                    del: palette.gray(5),
+                    inline_code_bg: palette.dark(7),
+                    inline_code_border: palette.dark(5),
+                    inline_code_fg: palette.gray(2),
+                    kbd_bg: palette.dark(7),
+                    kbd_border: palette.dark(4),
+                    kbd_fg: palette.gray(2),
+                    mark_bg: palette.color(PaletteKey::Yellow, 8),
+                    mark_fg: palette.gray(0),
+                    list_marker: palette.gray(5),
+                    rule: palette.dark(5),
+                    table_border: palette.dark(5),
+                    table_header_bg: palette.dark(7),
+                    table_header_fg: palette.gray(1),
+                    table_row_alt_bg: palette.dark(8),
+                    table_cell_fg: palette.gray(2),
+                    task_border: palette.dark(4),
+                    task_bg: palette.dark(7),
+                    task_checked_bg: palette.primary(4),
+                    task_checked_fg: palette.dark(9),
+                    details_bg: palette.dark(8),
+                    details_border: palette.dark(5),
+                    details_summary_fg: palette.gray(1),
+                    details_body_fg: palette.gray(3),
+                    image_border: palette.dark(5),
                     image_bg: transparent_black(),
-                    image_caption_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    image_caption_fg: palette.gray(4),
+                    image_placeholder_bg: palette.dark(6),
+                    footnote_fg: palette.gray(5),
+                    selection_bg: palette.primary(8),
                     gap_regular: px(12.0),
                     gap_compact: px(8.0),
                     paragraph_size: px(16.0),
@@ -5234,6 +5518,7 @@ impl ComponentTokens {
                     list_line_height: px(24.0),
                     table_size: px(14.0),
                     image_caption_size: px(13.0),
+                    footnote_size: px(12.0),
                     quote_padding_x: px(8.0),
                     quote_padding_y: px(6.0),
                     quote_radius: px(8.0),
@@ -5255,39 +5540,23 @@ impl ComponentTokens {
                     image_radius: px(8.0),
                     image_padding: px(8.0),
                     image_gap: px(8.0),
+                    image_placeholder_height: px(160.0),
                     heading2_padding_top: px(8.0),
                 },
                 text: TextTokens {
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    secondary: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    muted: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    accent: (Rgba::try_from(PaletteCatalog::scale(primary)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    success: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Green)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    warning: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Yellow)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    fg: palette.gray(0),
+                    secondary: palette.gray(3),
+                    muted: palette.gray(5),
+                    accent: palette.primary(3),
+                    success: palette.color(PaletteKey::Green, 4),
+                    warning: palette.color(PaletteKey::Yellow, 4),
+                    error: palette.color(PaletteKey::Red, 4),
+                    selection_bg: palette.primary(8),
                     sizes: default_text_size_scale(),
                 },
                 title: TitleTokens {
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtitle: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    fg: palette.gray(0),
+                    subtitle: palette.gray(4),
                     gap: px(4.0),
                     subtitle_size: px(15.0),
                     subtitle_line_height: px(22.0),
@@ -5324,137 +5593,58 @@ impl ComponentTokens {
                     },
                 },
                 paper: PaperTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    border: palette.dark(4),
                     padding: default_inset_size_scale(),
                 },
                 action_icon: ActionIconTokens {
-                    filled_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    filled_bg: palette.primary(5),
                     filled_fg: white(),
-                    light_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[6_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    light_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    subtle_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    outline_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    outline_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    ghost_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    default_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    disabled_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    disabled_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    disabled_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    light_bg: palette.dark(6),
+                    light_fg: palette.primary(2),
+                    subtle_bg: palette.dark(7),
+                    subtle_fg: palette.gray(1),
+                    outline_border: palette.dark(4),
+                    outline_fg: palette.gray(1),
+                    ghost_fg: palette.gray(1),
+                    default_bg: palette.dark(7),
+                    default_fg: palette.gray(1),
+                    default_border: palette.dark(4),
+                    disabled_bg: palette.dark(7),
+                    disabled_fg: palette.dark(3),
+                    disabled_border: palette.dark(5),
                     sizes: default_action_icon_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 segmented_control: SegmentedControlTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_active_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_active_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[6_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    bg: palette.dark(7),
+                    border: palette.dark(4),
+                    item_fg: palette.gray(2),
+                    item_active_bg: palette.dark(5),
+                    item_active_fg: palette.gray(0),
+                    item_hover_bg: palette.dark(6),
+                    item_disabled_fg: palette.dark(3),
                     track_padding: px(2.0),
                     item_gap: px(0.0),
                     sizes: default_segmented_control_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 textarea: TextareaTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    caret: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    selection_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    placeholder: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    fg: palette.gray(0),
+                    caret: palette.gray(0),
+                    selection_bg: palette.primary(8),
+                    placeholder: palette.dark(2),
+                    border: palette.dark(4),
+                    border_focus: palette.primary(5),
+                    border_error: palette.color(PaletteKey::Red, 5),
+                    spell_error: palette.color(PaletteKey::Red, 5),
+                    label: palette.gray(1),
                     label_size: px(14.0),
                     label_weight: FontWeight::MEDIUM,
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    description: palette.gray(4),
                     description_size: px(13.0),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    error: palette.color(PaletteKey::Red, 4),
                     error_size: px(13.0),
                     label_block_gap: px(4.0),
                     label_row_gap: px(4.0),
@@ -5465,47 +5655,21 @@ impl ComponentTokens {
                     sizes: default_field_size_scale(),
                 },
                 number_input: NumberInputTokens {
-                    bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    placeholder: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_focus: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    border_error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    controls_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    controls_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    controls_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bg: palette.surface(),
+                    fg: palette.gray(0),
+                    placeholder: palette.dark(2),
+                    border: palette.dark(4),
+                    border_focus: palette.primary(5),
+                    border_error: palette.color(PaletteKey::Red, 5),
+                    controls_bg: palette.dark(7),
+                    controls_fg: palette.gray(3),
+                    controls_border: palette.dark(4),
+                    label: palette.gray(1),
                     label_size: px(14.0),
                     label_weight: FontWeight::MEDIUM,
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    description: palette.gray(4),
                     description_size: px(13.0),
-                    error: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Red)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    error: palette.color(PaletteKey::Red, 4),
                     error_size: px(13.0),
                     controls_width: px(18.0),
                     controls_height: px(12.0),
@@ -5514,165 +5678,85 @@ impl ComponentTokens {
                     sizes: default_field_size_scale(),
                 },
                 range_slider: RangeSliderTokens {
-                    track_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    range_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    thumb_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[0_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    thumb_border: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    value: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    track_bg: palette.dark(5),
+                    range_bg: palette.primary(5),
+                    thumb_bg: palette.gray(0),
+                    thumb_border: palette.primary(5),
+                    label: palette.gray(2),
+                    value: palette.gray(4),
                     label_size: px(14.0),
                     value_size: px(14.0),
                     header_gap_vertical: px(6.0),
                     header_gap_horizontal: px(8.0),
                     default_width: px(260.0),
                     min_width: px(140.0),
+                    mark_bg: palette.dark(3),
+                    mark_size: px(6.0),
+                    mark_label: palette.dark(2),
+                    mark_label_size: px(12.0),
+                    mark_label_gap: px(6.0),
+                    tooltip_offset: px(6.0),
                     sizes: default_slider_size_scale(),
                 },
                 rating: RatingTokens {
-                    active: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Yellow)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    inactive: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    active: palette.color(PaletteKey::Yellow, 4),
+                    inactive: palette.dark(3),
+                    value: palette.dark(0),
+                    value_size: px(14.0),
+                    value_gap: px(8.0),
                     sizes: default_rating_size_scale(),
                 },
                 tabs: TabsTokens {
-                    list_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    list_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tab_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    tab_active_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[5_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    tab_active_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    tab_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[6_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    tab_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    panel_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    panel_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    panel_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    list_bg: palette.dark(7),
+                    list_border: palette.dark(4),
+                    tab_fg: palette.gray(2),
+                    tab_active_bg: palette.dark(5),
+                    tab_active_fg: palette.gray(0),
+                    tab_hover_bg: palette.dark(6),
+                    tab_disabled_fg: palette.dark(3),
+                    panel_bg: palette.surface(),
+                    panel_border: palette.dark(4),
+                    panel_fg: palette.gray(2),
                     root_gap: px(8.0),
                     list_gap: px(2.0),
                     list_padding: px(2.0),
                     panel_padding: px(16.0),
                     sizes: default_tabs_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 pagination: PaginationTokens {
-                    item_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_active_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    item_bg: palette.dark(7),
+                    item_border: palette.dark(4),
+                    item_fg: palette.gray(1),
+                    item_active_bg: palette.primary(5),
                     item_active_fg: white(),
-                    item_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[6_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    item_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    dots_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    item_hover_bg: palette.dark(6),
+                    item_disabled_fg: palette.dark(3),
+                    dots_fg: palette.gray(5),
                     root_gap: px(4.0),
                     sizes: default_pagination_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 breadcrumbs: BreadcrumbsTokens {
-                    item_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_current_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    separator: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    item_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[6_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    item_fg: palette.gray(3),
+                    item_current_fg: palette.gray(0),
+                    separator: palette.dark(2),
+                    item_hover_bg: palette.dark(6),
                     root_gap: px(4.0),
                     sizes: default_breadcrumbs_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 table: TableTokens {
-                    header_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    header_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_alt_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[6_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    row_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    cell_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    caption: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    header_bg: palette.dark(7),
+                    header_fg: palette.gray(1),
+                    row_bg: palette.surface(),
+                    row_alt_bg: palette.dark(7),
+                    row_hover_bg: palette.dark(6),
+                    row_selected_bg: palette.primary(9),
+                    row_border: palette.dark(4),
+                    cell_fg: palette.gray(2),
+                    selection_bg: palette.primary(8),
+                    caption: palette.gray(5),
                     caption_size: px(13.0),
                     row_gap: px(0.0),
                     pagination_summary_size: px(13.0),
@@ -5688,53 +5772,24 @@ impl ComponentTokens {
                     virtualization_padding: px(4.0),
                     min_viewport_height: px(80.0),
                     sizes: default_table_size_scale(),
+                    states: PseudoStateTokens::default(),
                 },
                 stepper: StepperTokens {
-                    step_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[3_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_active_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_active_border: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    step_bg: palette.dark(7),
+                    step_border: palette.dark(4),
+                    step_fg: palette.gray(3),
+                    step_active_bg: palette.primary(5),
+                    step_active_border: palette.primary(5),
                     step_active_fg: white(),
-                    step_completed_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    step_completed_border: (Rgba::try_from(
-                        PaletteCatalog::scale(primary)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
+                    step_completed_bg: palette.primary(4),
+                    step_completed_border: palette.primary(4),
                     step_completed_fg: white(),
-                    connector: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    label: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    description: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    panel_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[8_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    panel_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    panel_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    connector: palette.dark(4),
+                    label: palette.gray(1),
+                    description: palette.gray(5),
+                    panel_bg: palette.surface(),
+                    panel_border: palette.dark(4),
+                    panel_fg: palette.gray(2),
                     root_gap: px(6.0),
                     steps_gap_vertical: px(6.0),
                     text_gap: px(2.0),
@@ -5742,47 +5797,19 @@ impl ComponentTokens {
                     sizes: default_stepper_size_scale(),
                 },
                 timeline: TimelineTokens {
-                    bullet_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    bullet_border: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[4_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    bullet_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    bullet_active_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    bullet_active_border: (Rgba::try_from(PaletteCatalog::scale(primary)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    bullet_bg: palette.dark(7),
+                    bullet_border: palette.dark(4),
+                    bullet_fg: palette.gray(4),
+                    bullet_active_bg: palette.primary(5),
+                    bullet_active_border: palette.primary(5),
                     bullet_active_fg: white(),
-                    line: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    line_active: (Rgba::try_from(PaletteCatalog::scale(primary)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    title_active: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Gray)[0_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    body: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[5_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    card_bg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[7_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    card_border: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    line: palette.dark(4),
+                    line_active: palette.primary(4),
+                    title: palette.gray(2),
+                    title_active: palette.gray(0),
+                    body: palette.gray(5),
+                    card_bg: palette.dark(7),
+                    card_border: palette.dark(4),
                     root_gap: px(0.0),
                     row_gap: px(8.0),
                     content_gap: px(4.0),
@@ -5793,699 +5820,3087 @@ impl ComponentTokens {
                     sizes: default_timeline_size_scale(),
                 },
                 tree: TreeTokens {
-                    row_fg: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Gray)[2_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_selected_fg: (Rgba::try_from(PaletteCatalog::scale(primary)[1_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_selected_bg: (Rgba::try_from(PaletteCatalog::scale(primary)[9_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
-                    row_hover_bg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[7_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    row_disabled_fg: (Rgba::try_from(
-                        PaletteCatalog::scale(PaletteKey::Dark)[3_usize],
-                    )
-                    .map(Into::into)
-                    .unwrap_or_else(|_| black())),
-                    line: (Rgba::try_from(PaletteCatalog::scale(PaletteKey::Dark)[4_usize])
-                        .map(Into::into)
-                        .unwrap_or_else(|_| black())),
+                    row_fg: palette.gray(2),
+                    row_selected_fg: palette.primary(1),
+                    row_selected_bg: palette.primary(9),
+                    row_hover_bg: palette.dark(7),
+                    row_disabled_fg: palette.dark(3),
+                    line: palette.dark(4),
+                    drop_indicator: palette.primary(4),
                     root_gap: px(2.0),
                     children_gap: px(0.0),
                     sizes: default_tree_size_scale(),
                 },
+                zoom_pane: ZoomPaneTokens {
+                    bg: palette.dark(8),
+                    grid_line: palette.dark(6),
+                    grid_line_major: palette.dark(5),
+                    ruler_bg: palette.dark(7),
+                    ruler_tick: palette.dark(3),
+                    ruler_size: px(16.0),
+                    grid_spacing: px(16.0),
+                },
+                graph_canvas: GraphCanvasTokens {
+                    node_bg: palette.dark(6),
+                    node_border: palette.dark(4),
+                    node_selected_border: palette.primary(5),
+                    node_header_bg: palette.dark(5),
+                    node_fg: palette.dark(0),
+                    port_bg: palette.dark(7),
+                    port_border: palette.dark(2),
+                    edge: palette.dark(3),
+                    edge_selected: palette.primary(5),
+                    minimap_bg: palette.dark(7),
+                    minimap_node: palette.dark(4),
+                    minimap_viewport: palette.primary(5).opacity(0.25),
+                    node_radius: px(8.0),
+                    edge_width: px(2.0),
+                    port_size: px(10.0),
+                    label_size: px(12.0),
+                    minimap_width: px(160.0),
+                    minimap_height: px(110.0),
+                },
+                date_picker: DatePickerTokens {
+                    bg: palette.surface(),
+                    fg: palette.gray(0),
+                    placeholder: palette.dark(2),
+                    border: palette.dark(4),
+                    border_focus: palette.primary(5),
+                    border_error: palette.color(PaletteKey::Red, 5),
+                    icon: palette.gray(4),
+                    label: palette.gray(1),
+                    label_size: px(14.0),
+                    label_weight: FontWeight::MEDIUM,
+                    description: palette.gray(4),
+                    description_size: px(13.0),
+                    error: palette.color(PaletteKey::Red, 4),
+                    error_size: px(13.0),
+                    label_block_gap: px(4.0),
+                    label_row_gap: px(4.0),
+                    layout_gap_vertical: px(8.0),
+                    layout_gap_horizontal: px(12.0),
+                    horizontal_label_width: px(168.0),
+                    slot_gap: px(8.0),
+                    icon_size: px(14.0),
+                    dropdown_bg: palette.surface(),
+                    dropdown_border: palette.dark(4),
+                    dropdown_padding: px(10.0),
+                    dropdown_anchor_offset: px(2.0),
+                    dropdown_open_preferred_height: px(300.0),
+                    header_fg: palette.gray(0),
+                    header_size: px(14.0),
+                    nav_hover_bg: palette.dark(7),
+                    weekday_fg: palette.dark(2),
+                    weekday_size: px(12.0),
+                    day_fg: palette.gray(0),
+                    day_outside_fg: palette.dark(3),
+                    day_hover_bg: palette.dark(7),
+                    day_selected_bg: palette.primary(8),
+                    day_selected_fg: white(),
+                    day_today_border: palette.primary(6),
+                    day_size: px(13.0),
+                    day_cell_size: px(32.0),
+                    grid_gap: px(2.0),
+                    time_column_width: px(52.0),
+                    time_item_height: px(28.0),
+                    time_item_size: px(13.0),
+                    sizes: default_field_size_scale(),
+                    states: PseudoStateTokens::default(),
+                },
+                rulers: RulersTokens {
+                    bg: palette.dark(7),
+                    border: palette.dark(4),
+                    tick: palette.dark(4),
+                    tick_major: palette.dark(2),
+                    label: palette.dark(1),
+                    label_size: px(9.0),
+                    size: px(20.0),
+                    spacing: px(10.0),
+                    guide: palette.color(PaletteKey::Cyan, 4),
+                    guide_snapped: palette.color(PaletteKey::Pink, 4),
+                    guide_hit_width: px(7.0),
+                },
+                color_picker: ColorPickerTokens {
+                    bg: palette.dark(7),
+                    border: palette.dark(4),
+                    padding: px(10.0),
+                    gap: px(10.0),
+                    area_height: px(150.0),
+                    slider_height: px(12.0),
+                    thumb_size: px(14.0),
+                    thumb_border: white(),
+                    checker: palette.dark(5),
+                    preview_size: px(28.0),
+                    swatch_size: px(20.0),
+                    swatch_gap: px(6.0),
+                    swatch_border: palette.dark(4),
+                    swatch_selected_border: white(),
+                },
+                file_input: FileInputTokens {
+                    bg: palette.dark(7),
+                    bg_active: palette.dark(6),
+                    border: palette.dark(4),
+                    border_active: palette.primary(5),
+                    border_error: palette.color(PaletteKey::Red, 5),
+                    fg: palette.gray(0),
+                    hint: palette.dark(2),
+                    icon: palette.gray(4),
+                    radius: px(8.0),
+                    padding: px(16.0),
+                    gap: px(6.0),
+                    icon_size: px(24.0),
+                    text_size: px(14.0),
+                    hint_size: px(12.0),
+                    chip_bg: palette.dark(6),
+                    chip_border: palette.dark(4),
+                    chip_fg: palette.gray(1),
+                    chip_meta: palette.dark(2),
+                    chip_remove_hover_bg: palette.dark(5),
+                    chip_size: px(13.0),
+                    chip_padding_x: px(8.0),
+                    chip_padding_y: px(4.0),
+                    chip_gap: px(6.0),
+                    chip_max_width: px(240.0),
+                    label: palette.gray(1),
+                    label_size: px(14.0),
+                    label_weight: FontWeight::MEDIUM,
+                    description: palette.gray(4),
+                    description_size: px(13.0),
+                    error: palette.color(PaletteKey::Red, 4),
+                    error_size: px(13.0),
+                    label_block_gap: px(4.0),
+                    label_row_gap: px(4.0),
+                    layout_gap_vertical: px(8.0),
+                    layout_gap_horizontal: px(12.0),
+                    horizontal_label_width: px(168.0),
+                },
                 layout: LayoutTokens {
                     gap: default_layout_gap_scale(),
                     space: default_layout_space_scale(),
                     popup_snap_margin: px(8.0),
                 },
+                focus_ring: FocusRingTokens {
+                    color: palette.primary(5),
+                    width: px(2.0),
+                    offset: px(2.0),
+                    style: FocusRingStyle::Solid,
+                },
+                press_feedback: PressFeedbackTokens {
+                    style: PressFeedbackStyle::Scale,
+                    scale_inset: px(1.0),
+                    ripple_color: white().opacity(0.16),
+                },
+                async_content: AsyncContentTokens {
+                    skeleton_bg: palette.dark(5),
+                    skeleton_radius: px(4.0),
+                    skeleton_line_height: px(12.0),
+                    skeleton_gap: px(10.0),
+                    empty_icon: palette.dark(2),
+                    empty_title: palette.dark(0),
+                    empty_description: palette.dark(2),
+                    empty_icon_size: px(32.0),
+                    empty_title_size: px(14.0),
+                    empty_description_size: px(13.0),
+                    empty_gap: px(6.0),
+                    empty_padding: px(24.0),
+                },
+                checklist: ChecklistTokens {
+                    done: palette.color(PaletteKey::Green, 4),
+                    active: palette.color(PaletteKey::Blue, 4),
+                    pending: palette.dark(3),
+                    error: palette.color(PaletteKey::Red, 4),
+                    label: palette.dark(0),
+                    description: palette.dark(2),
+                    summary: palette.dark(1),
+                    icon_size: px(18.0),
+                    label_size: px(14.0),
+                    description_size: px(13.0),
+                    summary_size: px(13.0),
+                    gap: px(10.0),
+                    item_gap: px(10.0),
+                    detail_indent: px(28.0),
+                },
+                task_status: TaskStatusTokens {
+                    trigger_fg: palette.dark(1),
+                    trigger_hover_bg: palette.dark(5),
+                    title: palette.dark(0),
+                    message: palette.dark(2),
+                    running: palette.color(PaletteKey::Blue, 4),
+                    done: palette.color(PaletteKey::Green, 4),
+                    error: palette.color(PaletteKey::Red, 4),
+                    cancelled: palette.dark(3),
+                    trigger_size: px(12.0),
+                    title_size: px(13.0),
+                    message_size: px(12.0),
+                    icon_size: px(14.0),
+                    trigger_bar_width: px(64.0),
+                    panel_width: px(280.0),
+                    item_gap: px(10.0),
+                    row_gap: px(4.0),
+                },
+                notification_center: NotificationCenterTokens {
+                    header: palette.dark(0),
+                    title: palette.dark(0),
+                    message: palette.dark(2),
+                    empty: palette.dark(3),
+                    unread_dot: palette.color(PaletteKey::Blue, 4),
+                    unread_bg: palette.dark(6),
+                    row_hover_bg: palette.dark(5),
+                    info: palette.color(PaletteKey::Blue, 4),
+                    success: palette.color(PaletteKey::Green, 4),
+                    warning: palette.color(PaletteKey::Yellow, 4),
+                    error: palette.color(PaletteKey::Red, 4),
+                    header_size: px(14.0),
+                    title_size: px(13.0),
+                    message_size: px(12.0),
+                    icon_size: px(16.0),
+                    dot_size: px(8.0),
+                    panel_width: px(320.0),
+                    row_padding: px(8.0),
+                    item_gap: px(4.0),
+                    row_gap: px(10.0),
+                },
+                find_bar: FindBarTokens {
+                    bg: palette.surface(),
+                    border: palette.dark(4),
+                    count: palette.dark(2),
+                    match_bg: palette.color(PaletteKey::Yellow, 9),
+                    active_match_bg: palette.color(PaletteKey::Orange, 7),
+                    width: px(320.0),
+                    padding: px(6.0),
+                    gap: px(6.0),
+                    radius: px(8.0),
+                    offset: px(8.0),
+                    count_size: px(12.0),
+                },
+                split_pane: SplitPaneTokens {
+                    divider: palette.dark(4),
+                    divider_active: palette.primary(5),
+                    divider_size: px(1.0),
+                    handle_size: px(8.0),
+                    min_pane_size: px(48.0),
+                },
+                dock_tabs: DockTabsTokens {
+                    strip_bg: palette.dark(7),
+                    strip_border: palette.dark(4),
+                    tab_fg: palette.dark(1),
+                    tab_hover_bg: palette.dark(6),
+                    tab_active_bg: palette.surface(),
+                    tab_active_fg: white(),
+                    tab_active_indicator: palette.primary(5),
+                    tab_disabled_fg: palette.dark(3),
+                    dirty_dot: palette.dark(2),
+                    drop_indicator: palette.primary(5),
+                    tab_height: px(32.0),
+                    tab_padding_x: px(10.0),
+                    tab_gap: px(6.0),
+                    tab_max_width: px(220.0),
+                    close_size: px(16.0),
+                    dirty_dot_size: px(8.0),
+                    overflow_button_width: px(28.0),
+                },
+                image: ImageTokens {
+                    placeholder_bg: palette.dark(5),
+                    fallback_bg: palette.dark(6),
+                    fallback_fg: palette.dark(2),
+                    fallback_icon_size: px(24.0),
+                    fallback_gap: px(6.0),
+                    fallback_font_size: px(12.0),
+                },
+                calendar: CalendarTokens {
+                    bg: palette.dark(7),
+                    border: palette.dark(4),
+                    header_fg: palette.gray(0),
+                    header_size: px(16.0),
+                    nav_hover_bg: palette.dark(5),
+                    icon: palette.dark(1),
+                    icon_size: px(16.0),
+                    weekday_fg: palette.dark(2),
+                    weekday_size: px(12.0),
+                    day_fg: palette.gray(0),
+                    day_outside_fg: palette.dark(3),
+                    day_hover_bg: palette.dark(6),
+                    day_size: px(13.0),
+                    today_bg: palette.primary(8),
+                    today_fg: white(),
+                    range_bg: palette.primary(9),
+                    range_edge_bg: palette.primary(8),
+                    cell_border: palette.dark(5),
+                    cell_padding: px(4.0),
+                    month_cell_height: px(96.0),
+                    week_cell_height: px(240.0),
+                    chip_bg: palette.primary(9),
+                    chip_fg: palette.primary(1),
+                    chip_size: px(11.0),
+                    chip_padding_x: px(4.0),
+                    chip_gap: px(2.0),
+                    more_fg: palette.dark(2),
+                    header_gap: px(8.0),
+                },
+                kanban_board: KanbanBoardTokens {
+                    column_bg: palette.dark(8),
+                    column_border: palette.dark(5),
+                    column_width: px(280.0),
+                    column_gap: px(12.0),
+                    column_padding: px(8.0),
+                    column_radius: px(8.0),
+                    list_height: px(480.0),
+                    header_fg: palette.gray(0),
+                    header_size: px(14.0),
+                    header_gap: px(8.0),
+                    count_bg: palette.dark(5),
+                    count_fg: palette.dark(1),
+                    count_over_limit_bg: palette.color(PaletteKey::Red, 9),
+                    count_over_limit_fg: palette.color(PaletteKey::Red, 2),
+                    count_size: px(12.0),
+                    card_bg: palette.dark(6),
+                    card_border: palette.dark(4),
+                    card_height: px(72.0),
+                    card_gap: px(8.0),
+                    card_padding: px(10.0),
+                    placeholder_bg: palette.primary(9),
+                    placeholder_border: palette.primary(6),
+                    empty_fg: palette.dark(3),
+                    empty_size: px(12.0),
+                },
+                chart: ChartTokens {
+                    grid: palette.dark(5),
+                    axis_fg: palette.dark(2),
+                    axis_size: px(11.0),
+                    axis_width: px(40.0),
+                    axis_gap: px(6.0),
+                    height: px(200.0),
+                    line_width: px(2.0),
+                    point_size: px(6.0),
+                    point_bg: palette.dark(7),
+                    bar_gap: px(8.0),
+                    bar_radius: px(3.0),
+                    donut_size: px(160.0),
+                    donut_thickness: px(20.0),
+                    donut_track: palette.dark(6),
+                    center_fg: palette.dark(0),
+                    center_size: px(20.0),
+                    legend_fg: palette.dark(1),
+                    legend_size: px(12.0),
+                    legend_gap: px(12.0),
+                    swatch_size: px(8.0),
+                    sparkline_width: px(120.0),
+                    sparkline_height: px(32.0),
+                    sparkline_line_width: px(1.5),
+                    empty_fg: palette.dark(3),
+                    empty_size: px(12.0),
+                },
+                ring_progress: RingProgressTokens {
+                    size: px(96.0),
+                    thickness: px(8.0),
+                    label_size: px(14.0),
+                    section_gap: px(2.0),
+                },
+                stat_card: StatCardTokens {
+                    bg: palette.dark(7),
+                    border: palette.dark(4),
+                    radius: px(8.0),
+                    padding: px(16.0),
+                    gap: px(6.0),
+                    label_fg: palette.dark(2),
+                    label_size: px(13.0),
+                    value_fg: palette.dark(0),
+                    value_size: px(26.0),
+                    icon_fg: palette.dark(3),
+                    icon_size: px(20.0),
+                    delta_size: px(13.0),
+                    delta_icon_size: px(14.0),
+                    delta_up: palette.color(PaletteKey::Green, 5),
+                    delta_down: palette.color(PaletteKey::Red, 5),
+                    delta_flat: palette.dark(2),
+                    description_fg: palette.dark(2),
+                    description_size: px(12.0),
+                    skeleton_bg: palette.dark(5),
+                },
+                empty_state: EmptyStateTokens {
+                    icon_fg: palette.dark(2),
+                    icon_bg: palette.dark(6),
+                    title_fg: palette.dark(0),
+                    description_fg: palette.dark(2),
+                    actions_gap: px(8.0),
+                    sizes: default_empty_state_size_scale(),
+                },
+                announcement_bar: AnnouncementBarTokens {
+                    info_bg: palette.color(PaletteKey::Blue, 8),
+                    info_fg: white(),
+                    success_bg: palette.color(PaletteKey::Green, 8),
+                    success_fg: white(),
+                    warning_bg: palette.color(PaletteKey::Yellow, 6),
+                    warning_fg: palette.dark(9),
+                    error_bg: palette.color(PaletteKey::Red, 8),
+                    error_fg: white(),
+                    min_height: px(36.0),
+                    padding_x: px(16.0),
+                    padding_y: px(6.0),
+                    gap: px(10.0),
+                    font_size: px(13.0),
+                    icon_size: px(16.0),
+                    close_button_size: px(22.0),
+                    close_icon_size: px(14.0),
+                },
+                wizard: WizardTokens {
+                    error_fg: palette.color(PaletteKey::Red, 5),
+                    error_size: px(13.0),
+                    footer_gap: px(8.0),
+                    root_gap: px(12.0),
+                },
+                editable_text: EditableTextTokens {
+                    fg: palette.dark(0),
+                    placeholder: palette.dark(3),
+                    hover_bg: palette.dark(6),
+                    error_fg: palette.color(PaletteKey::Red, 5),
+                    text_size: px(14.0),
+                    error_size: px(12.0),
+                    padding_x: px(6.0),
+                    padding_y: px(2.0),
+                    radius: px(4.0),
+                    gap: px(4.0),
+                },
+                copy_button: CopyButtonTokens {
+                    copied_fg: palette.color(PaletteKey::Green, 4),
+                    icon_size: px(14.0),
+                },
+                chat_view: ChatViewTokens {
+                    own_bg: palette.primary(8),
+                    other_bg: palette.dark(5),
+                    author_fg: palette.dark(1),
+                    time_fg: palette.dark(2),
+                    separator_fg: palette.dark(2),
+                    separator_line: palette.dark(4),
+                    avatar_bg: palette.dark(4),
+                    avatar_fg: palette.dark(0),
+                    pill_bg: palette.primary(7),
+                    pill_fg: white(),
+                    typing_fg: palette.dark(2),
+                    padding: px(12.0),
+                    message_gap: px(4.0),
+                    avatar_size: px(28.0),
+                    avatar_gap: px(8.0),
+                    bubble_radius: px(14.0),
+                    bubble_padding_x: px(12.0),
+                    bubble_padding_y: px(8.0),
+                    bubble_max_width: px(520.0),
+                    meta_size: px(12.0),
+                    separator_size: px(12.0),
+                    estimated_row_height: px(64.0),
+                    pill_offset: px(12.0),
+                },
+                log_view: LogViewTokens {
+                    bg: palette.dark(8),
+                    border: palette.dark(4),
+                    fg: palette.dark(0),
+                    muted_fg: palette.dark(2),
+                    gutter_fg: palette.dark(3),
+                    warn_fg: palette.color(PaletteKey::Yellow, 5),
+                    error_fg: palette.color(PaletteKey::Red, 5),
+                    selection_bg: palette.primary(8),
+                    ansi_black: palette.dark(3),
+                    ansi_red: palette.color(PaletteKey::Red, 5),
+                    ansi_green: palette.color(PaletteKey::Green, 5),
+                    ansi_yellow: palette.color(PaletteKey::Yellow, 4),
+                    ansi_blue: palette.color(PaletteKey::Blue, 4),
+                    ansi_magenta: palette.color(PaletteKey::Grape, 4),
+                    ansi_cyan: palette.color(PaletteKey::Cyan, 4),
+                    ansi_white: palette.gray(1),
+                    text_size: px(12.0),
+                    line_height: px(18.0),
+                    padding: px(8.0),
+                    gutter_gap: px(12.0),
+                    radius: px(6.0),
+                },
+                json_tree: JsonTreeTokens {
+                    bg: palette.dark(7),
+                    border: palette.dark(4),
+                    punctuation_fg: palette.dark(2),
+                    key_fg: palette.color(PaletteKey::Grape, 3),
+                    index_fg: palette.dark(3),
+                    string_fg: palette.color(PaletteKey::Green, 4),
+                    number_fg: palette.color(PaletteKey::Blue, 4),
+                    boolean_fg: palette.color(PaletteKey::Orange, 4),
+                    null_fg: palette.dark(3),
+                    summary_fg: palette.dark(3),
+                    toggle_fg: palette.dark(2),
+                    more_fg: palette.primary(4),
+                    row_hover_bg: palette.dark(6),
+                    match_bg: palette.color(PaletteKey::Yellow, 9),
+                    text_size: px(12.0),
+                    row_height: px(22.0),
+                    indent: px(16.0),
+                    padding: px(6.0),
+                    toggle_size: px(12.0),
+                    radius: px(6.0),
+                },
+                settings_view: SettingsViewTokens {
+                    bg: palette.dark(7),
+                    border: palette.dark(4),
+                    nav_bg: palette.dark(8),
+                    nav_fg: palette.dark(1),
+                    nav_hover_bg: palette.dark(6),
+                    nav_active_bg: palette.primary(9),
+                    nav_active_fg: palette.primary(2),
+                    count_fg: palette.dark(2),
+                    title_fg: palette.dark(0),
+                    description_fg: palette.dark(2),
+                    label_fg: palette.dark(0),
+                    separator: palette.dark(5),
+                    match_bg: palette.color(PaletteKey::Yellow, 9),
+                    empty_fg: palette.dark(2),
+                    nav_width: px(220.0),
+                    nav_padding: px(12.0),
+                    nav_gap: px(2.0),
+                    nav_item_padding_x: px(10.0),
+                    nav_item_padding_y: px(6.0),
+                    nav_item_radius: px(6.0),
+                    nav_item_size: px(14.0),
+                    content_padding: px(24.0),
+                    content_max_width: px(720.0),
+                    section_gap: px(32.0),
+                    title_size: px(20.0),
+                    heading_size: px(15.0),
+                    description_size: px(13.0),
+                    label_size: px(14.0),
+                    item_gap: px(24.0),
+                    item_padding_y: px(14.0),
+                    control_width: px(240.0),
+                },
             },
         }
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Theme {
-    pub radii: ThemeRadii,
-    pub primary_color: PaletteKey,
-    pub primary_shade_light: u8,
-    pub primary_shade_dark: u8,
-    pub color_scheme: ColorScheme,
-    pub palette: BTreeMap<PaletteKey, ColorScale>,
-    pub semantic: SemanticColors,
-    pub components: ComponentTokens,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Theme {
+    pub radii: ThemeRadii,
+    pub fonts: ThemeFonts,
+    pub primary_color: PaletteKey,
+    /// What the semantic and component tokens were derived from. Its
+    /// `primary` follows `primary_color`.
+    pub seed: ThemeSeed,
+    pub primary_shade_light: u8,
+    pub primary_shade_dark: u8,
+    pub color_scheme: ColorScheme,
+    pub palette: BTreeMap<PaletteKey, ColorScale>,
+    pub semantic: SemanticColors,
+    pub components: ComponentTokens,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::from_seed(ThemeSeed::default(), ColorScheme::Light)
+    }
+}
+
+impl Theme {
+    /// Theme whose semantic and component tokens all come from `seed`.
+    pub fn from_seed(seed: ThemeSeed, scheme: ColorScheme) -> Self {
+        let palette = PaletteCatalog::store();
+        let tokens = SeedPalette::from_theme_palette(seed, scheme, &palette);
+        let mut theme = Self {
+            radii: ThemeRadii::default(),
+            fonts: ThemeFonts::default(),
+            primary_color: seed.primary,
+            seed,
+            primary_shade_light: PRIMARY_SHADE_LIGHT_DEFAULT,
+            primary_shade_dark: PRIMARY_SHADE_DARK_DEFAULT,
+            color_scheme: scheme,
+            palette,
+            semantic: SemanticColors::from_seed_palette(tokens),
+            components: ComponentTokens::from_seed_palette(tokens),
+        };
+        if seed.contrast == ContrastLevel::High {
+            theme.meet_pair_contrast(audit::DEFAULT_PAIRS);
+        }
+        theme
+    }
+
+    /// Derives the semantic and component tokens again from `seed`,
+    /// replacing any changes made to them.
+    pub fn with_seed(mut self, seed: ThemeSeed) -> Self {
+        self.seed = seed;
+        self.primary_color = seed.primary;
+        self.derive_tokens();
+        self
+    }
+
+    /// Switches to `level`, deriving the tokens again from the seed.
+    pub fn with_contrast(self, level: ContrastLevel) -> Self {
+        let seed = self.seed.contrast(level);
+        self.with_seed(seed)
+    }
+
+    pub fn with_primary_color(mut self, primary: PaletteKey) -> Self {
+        self.primary_color = primary;
+        self.derive_tokens();
+        self
+    }
+
+    pub fn with_primary_shades(mut self, light: u8, dark: u8) -> Self {
+        self.primary_shade_light = light.min(9);
+        self.primary_shade_dark = dark.min(9);
+        self
+    }
+
+    pub fn with_primary_shade(mut self, shade: u8) -> Self {
+        let clamped = shade.min(9);
+        self.primary_shade_light = clamped;
+        self.primary_shade_dark = clamped;
+        self
+    }
+
+    pub fn with_color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.color_scheme = scheme;
+        self.derive_tokens();
+        self
+    }
+
+    fn derive_tokens(&mut self) {
+        self.seed.primary = self.primary_color;
+        let tokens = SeedPalette::from_theme_palette(self.seed, self.color_scheme, &self.palette);
+        self.semantic = SemanticColors::from_seed_palette(tokens);
+        self.components = ComponentTokens::from_seed_palette(tokens);
+        self.retint_primary();
+        if self.seed.contrast == ContrastLevel::High {
+            self.meet_pair_contrast(audit::DEFAULT_PAIRS);
+        }
+    }
+
+    pub fn with_palette_override(mut self, key: PaletteKey, scale: ColorScale) -> Self {
+        self.palette.insert(key, scale);
+        self
+    }
+
+    pub fn with_accent_color(self, accent: PaletteKey) -> Self {
+        self.with_primary_color(accent)
+    }
+
+    pub fn with_radii(mut self, radii: ThemeRadii) -> Self {
+        self.radii = radii;
+        self
+    }
+
+    pub fn with_fonts(mut self, fonts: ThemeFonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    pub fn resolve_color<T>(&self, token: T) -> String
+    where
+        T: ResolveWithTheme<Hsla>,
+    {
+        format!("{:?}", Rgba::from(token.resolve(self)))
+    }
+
+    pub fn resolve_hsla<T>(&self, token: T) -> Hsla
+    where
+        T: ResolveWithTheme<Hsla>,
+    {
+        token.resolve(self)
+    }
+
+    pub fn resolve_radius<T>(&self, token: T) -> Pixels
+    where
+        T: ResolveWithTheme<Pixels>,
+    {
+        token.resolve(self)
+    }
+
+    pub fn merged(&self, patch: &ThemeOverrides) -> Self {
+        let mut next = self.clone();
+        if let Some(primary) = patch.primary_color {
+            next = next.with_primary_color(primary);
+        }
+        if let Some(primary_shade_light) = patch.primary_shade_light {
+            next.primary_shade_light = primary_shade_light.min(9);
+        }
+        if let Some(primary_shade_dark) = patch.primary_shade_dark {
+            next.primary_shade_dark = primary_shade_dark.min(9);
+        }
+        if let Some(color_scheme) = patch.color_scheme {
+            next.color_scheme = color_scheme;
+        }
+        for (key, value) in &patch.palette_overrides {
+            next.palette.insert(*key, *value);
+        }
+        next.radii = patch.radii.apply(next.radii);
+        next.semantic = patch.semantic.apply(next.semantic);
+        next.components = patch.components.apply(next.components);
+        next
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SemanticOverrides {
+    pub text_primary: Option<Hsla>,
+    pub text_secondary: Option<Hsla>,
+    pub text_muted: Option<Hsla>,
+    pub bg_canvas: Option<Hsla>,
+    pub bg_surface: Option<Hsla>,
+    pub bg_soft: Option<Hsla>,
+    pub border_subtle: Option<Hsla>,
+    pub border_strong: Option<Hsla>,
+    pub focus_ring: Option<Hsla>,
+    pub status_info: Option<Hsla>,
+    pub status_success: Option<Hsla>,
+    pub status_warning: Option<Hsla>,
+    pub status_error: Option<Hsla>,
+    pub overlay_mask: Option<Hsla>,
+}
+
+impl SemanticOverrides {
+    fn apply(&self, mut current: SemanticColors) -> SemanticColors {
+        if let Some(value) = &self.text_primary {
+            current.text_primary = *value;
+        }
+        if let Some(value) = &self.text_secondary {
+            current.text_secondary = *value;
+        }
+        if let Some(value) = &self.text_muted {
+            current.text_muted = *value;
+        }
+        if let Some(value) = &self.bg_canvas {
+            current.bg_canvas = *value;
+        }
+        if let Some(value) = &self.bg_surface {
+            current.bg_surface = *value;
+        }
+        if let Some(value) = &self.bg_soft {
+            current.bg_soft = *value;
+        }
+        if let Some(value) = &self.border_subtle {
+            current.border_subtle = *value;
+        }
+        if let Some(value) = &self.border_strong {
+            current.border_strong = *value;
+        }
+        if let Some(value) = &self.focus_ring {
+            current.focus_ring = *value;
+        }
+        if let Some(value) = &self.status_info {
+            current.status_info = *value;
+        }
+        if let Some(value) = &self.status_success {
+            current.status_success = *value;
+        }
+        if let Some(value) = &self.status_warning {
+            current.status_warning = *value;
+        }
+        if let Some(value) = &self.status_error {
+            current.status_error = *value;
+        }
+        if let Some(value) = &self.overlay_mask {
+            current.overlay_mask = *value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RadiiOverrides {
+    pub default: Option<Pixels>,
+    pub xs: Option<Pixels>,
+    pub sm: Option<Pixels>,
+    pub md: Option<Pixels>,
+    pub lg: Option<Pixels>,
+    pub xl: Option<Pixels>,
+    pub pill: Option<Pixels>,
+}
+
+impl RadiiOverrides {
+    fn apply(&self, mut current: ThemeRadii) -> ThemeRadii {
+        if let Some(value) = self.default {
+            current.default = value;
+        }
+        if let Some(value) = self.xs {
+            current.xs = value;
+        }
+        if let Some(value) = self.sm {
+            current.sm = value;
+        }
+        if let Some(value) = self.md {
+            current.md = value;
+        }
+        if let Some(value) = self.lg {
+            current.lg = value;
+        }
+        if let Some(value) = self.xl {
+            current.xl = value;
+        }
+        if let Some(value) = self.pill {
+            current.pill = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ButtonOverrides {
+    pub filled_bg: Option<Hsla>,
+    pub filled_fg: Option<Hsla>,
+    pub light_bg: Option<Hsla>,
+    pub light_fg: Option<Hsla>,
+    pub subtle_bg: Option<Hsla>,
+    pub subtle_fg: Option<Hsla>,
+    pub outline_border: Option<Hsla>,
+    pub outline_fg: Option<Hsla>,
+    pub ghost_fg: Option<Hsla>,
+    pub disabled_bg: Option<Hsla>,
+    pub disabled_fg: Option<Hsla>,
+    pub sizes: Option<ButtonSizeScale>,
+    pub states: Option<PseudoStateTokens>,
+}
+
+impl ButtonOverrides {
+    fn apply(&self, mut current: ButtonTokens) -> ButtonTokens {
+        if let Some(value) = &self.filled_bg {
+            current.filled_bg = *value;
+        }
+        if let Some(value) = &self.filled_fg {
+            current.filled_fg = *value;
+        }
+        if let Some(value) = &self.light_bg {
+            current.light_bg = *value;
+        }
+        if let Some(value) = &self.light_fg {
+            current.light_fg = *value;
+        }
+        if let Some(value) = &self.subtle_bg {
+            current.subtle_bg = *value;
+        }
+        if let Some(value) = &self.subtle_fg {
+            current.subtle_fg = *value;
+        }
+        if let Some(value) = &self.outline_border {
+            current.outline_border = *value;
+        }
+        if let Some(value) = &self.outline_fg {
+            current.outline_fg = *value;
+        }
+        if let Some(value) = &self.ghost_fg {
+            current.ghost_fg = *value;
+        }
+        if let Some(value) = &self.disabled_bg {
+            current.disabled_bg = *value;
+        }
+        if let Some(value) = &self.disabled_fg {
+            current.disabled_fg = *value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InputOverrides {
+    pub bg: Option<Hsla>,
+    pub fg: Option<Hsla>,
+    pub caret: Option<Hsla>,
+    pub selection_bg: Option<Hsla>,
+    pub placeholder: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub border_focus: Option<Hsla>,
+    pub border_error: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub label_size: Option<Pixels>,
+    pub label_weight: Option<FontWeight>,
+    pub description: Option<Hsla>,
+    pub description_size: Option<Pixels>,
+    pub error: Option<Hsla>,
+    pub error_size: Option<Pixels>,
+    pub label_block_gap: Option<Pixels>,
+    pub label_row_gap: Option<Pixels>,
+    pub slot_fg: Option<Hsla>,
+    pub slot_gap: Option<Pixels>,
+    pub slot_min_width: Option<Pixels>,
+    pub layout_gap_vertical: Option<Pixels>,
+    pub layout_gap_horizontal: Option<Pixels>,
+    pub horizontal_label_width: Option<Pixels>,
+    pub pin_cells_gap: Option<Pixels>,
+    pub pin_error_gap: Option<Pixels>,
+    pub password_toggle_size: Option<Pixels>,
+    pub password_caps_lock_fg: Option<Hsla>,
+    pub password_caps_lock_size: Option<Pixels>,
+    pub password_meter_gap: Option<Pixels>,
+    pub password_meter_height: Option<Pixels>,
+    pub password_meter_segment_gap: Option<Pixels>,
+    pub password_meter_track: Option<Hsla>,
+    pub password_strength_weak: Option<Hsla>,
+    pub password_strength_fair: Option<Hsla>,
+    pub password_strength_good: Option<Hsla>,
+    pub password_strength_strong: Option<Hsla>,
+    pub password_strength_label_size: Option<Pixels>,
+    pub sizes: Option<FieldSizeScale>,
+}
+
+impl InputOverrides {
+    fn apply(&self, mut current: InputTokens) -> InputTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        if let Some(value) = &self.fg {
+            current.fg = *value;
+        }
+        if let Some(value) = &self.caret {
+            current.caret = *value;
+        }
+        if let Some(value) = &self.selection_bg {
+            current.selection_bg = *value;
+        }
+        if let Some(value) = &self.placeholder {
+            current.placeholder = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = &self.border_focus {
+            current.border_focus = *value;
+        }
+        if let Some(value) = &self.border_error {
+            current.border_error = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        if let Some(value) = self.label_weight {
+            current.label_weight = value;
+        }
+        if let Some(value) = &self.description {
+            current.description = *value;
+        }
+        if let Some(value) = self.description_size {
+            current.description_size = value;
+        }
+        if let Some(value) = &self.error {
+            current.error = *value;
+        }
+        if let Some(value) = self.error_size {
+            current.error_size = value;
+        }
+        if let Some(value) = self.label_block_gap {
+            current.label_block_gap = value;
+        }
+        if let Some(value) = self.label_row_gap {
+            current.label_row_gap = value;
+        }
+        if let Some(value) = &self.slot_fg {
+            current.slot_fg = *value;
+        }
+        if let Some(value) = self.slot_gap {
+            current.slot_gap = value;
+        }
+        if let Some(value) = self.slot_min_width {
+            current.slot_min_width = value;
+        }
+        if let Some(value) = self.layout_gap_vertical {
+            current.layout_gap_vertical = value;
+        }
+        if let Some(value) = self.layout_gap_horizontal {
+            current.layout_gap_horizontal = value;
+        }
+        if let Some(value) = self.horizontal_label_width {
+            current.horizontal_label_width = value;
+        }
+        if let Some(value) = self.pin_cells_gap {
+            current.pin_cells_gap = value;
+        }
+        if let Some(value) = self.pin_error_gap {
+            current.pin_error_gap = value;
+        }
+        if let Some(value) = self.password_toggle_size {
+            current.password_toggle_size = value;
+        }
+        if let Some(value) = &self.password_caps_lock_fg {
+            current.password_caps_lock_fg = *value;
+        }
+        if let Some(value) = self.password_caps_lock_size {
+            current.password_caps_lock_size = value;
+        }
+        if let Some(value) = self.password_meter_gap {
+            current.password_meter_gap = value;
+        }
+        if let Some(value) = self.password_meter_height {
+            current.password_meter_height = value;
+        }
+        if let Some(value) = self.password_meter_segment_gap {
+            current.password_meter_segment_gap = value;
+        }
+        if let Some(value) = &self.password_meter_track {
+            current.password_meter_track = *value;
+        }
+        if let Some(value) = &self.password_strength_weak {
+            current.password_strength_weak = *value;
+        }
+        if let Some(value) = &self.password_strength_fair {
+            current.password_strength_fair = *value;
+        }
+        if let Some(value) = &self.password_strength_good {
+            current.password_strength_good = *value;
+        }
+        if let Some(value) = &self.password_strength_strong {
+            current.password_strength_strong = *value;
+        }
+        if let Some(value) = self.password_strength_label_size {
+            current.password_strength_label_size = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RadioOverrides {
+    pub control_bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub border_hover: Option<Hsla>,
+    pub border_focus: Option<Hsla>,
+    pub border_checked: Option<Hsla>,
+    pub indicator: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub description: Option<Hsla>,
+    pub label_description_gap: Option<Pixels>,
+    pub group_gap_horizontal: Option<Pixels>,
+    pub group_gap_vertical: Option<Pixels>,
+    pub sizes: Option<ChoiceControlSizeScale>,
+}
+
+impl RadioOverrides {
+    fn apply(&self, mut current: RadioTokens) -> RadioTokens {
+        if let Some(value) = &self.control_bg {
+            current.control_bg = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = &self.border_hover {
+            current.border_hover = *value;
+        }
+        if let Some(value) = &self.border_focus {
+            current.border_focus = *value;
+        }
+        if let Some(value) = &self.border_checked {
+            current.border_checked = *value;
+        }
+        if let Some(value) = &self.indicator {
+            current.indicator = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = &self.description {
+            current.description = *value;
+        }
+        if let Some(value) = self.label_description_gap {
+            current.label_description_gap = value;
+        }
+        if let Some(value) = self.group_gap_horizontal {
+            current.group_gap_horizontal = value;
+        }
+        if let Some(value) = self.group_gap_vertical {
+            current.group_gap_vertical = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CheckboxOverrides {
+    pub control_bg: Option<Hsla>,
+    pub control_bg_checked: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub border_hover: Option<Hsla>,
+    pub border_focus: Option<Hsla>,
+    pub border_checked: Option<Hsla>,
+    pub indicator: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub description: Option<Hsla>,
+    pub label_description_gap: Option<Pixels>,
+    pub group_gap_horizontal: Option<Pixels>,
+    pub group_gap_vertical: Option<Pixels>,
+    pub sizes: Option<ChoiceControlSizeScale>,
+}
+
+impl CheckboxOverrides {
+    fn apply(&self, mut current: CheckboxTokens) -> CheckboxTokens {
+        if let Some(value) = &self.control_bg {
+            current.control_bg = *value;
+        }
+        if let Some(value) = &self.control_bg_checked {
+            current.control_bg_checked = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = &self.border_hover {
+            current.border_hover = *value;
+        }
+        if let Some(value) = &self.border_focus {
+            current.border_focus = *value;
+        }
+        if let Some(value) = &self.border_checked {
+            current.border_checked = *value;
+        }
+        if let Some(value) = &self.indicator {
+            current.indicator = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = &self.description {
+            current.description = *value;
+        }
+        if let Some(value) = self.label_description_gap {
+            current.label_description_gap = value;
+        }
+        if let Some(value) = self.group_gap_horizontal {
+            current.group_gap_horizontal = value;
+        }
+        if let Some(value) = self.group_gap_vertical {
+            current.group_gap_vertical = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SwitchOverrides {
+    pub track_off_bg: Option<Hsla>,
+    pub track_on_bg: Option<Hsla>,
+    pub track_hover_border: Option<Hsla>,
+    pub track_focus_border: Option<Hsla>,
+    pub thumb_bg: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub description: Option<Hsla>,
+    pub label_description_gap: Option<Pixels>,
+    pub sizes: Option<SwitchSizeScale>,
+}
+
+impl SwitchOverrides {
+    fn apply(&self, mut current: SwitchTokens) -> SwitchTokens {
+        if let Some(value) = &self.track_off_bg {
+            current.track_off_bg = *value;
+        }
+        if let Some(value) = &self.track_on_bg {
+            current.track_on_bg = *value;
+        }
+        if let Some(value) = &self.track_hover_border {
+            current.track_hover_border = *value;
+        }
+        if let Some(value) = &self.track_focus_border {
+            current.track_focus_border = *value;
+        }
+        if let Some(value) = &self.thumb_bg {
+            current.thumb_bg = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = &self.description {
+            current.description = *value;
+        }
+        if let Some(value) = self.label_description_gap {
+            current.label_description_gap = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChipOverrides {
+    pub unchecked_bg: Option<Hsla>,
+    pub unchecked_fg: Option<Hsla>,
+    pub unchecked_border: Option<Hsla>,
+    pub filled_bg: Option<Hsla>,
+    pub filled_fg: Option<Hsla>,
+    pub light_bg: Option<Hsla>,
+    pub light_fg: Option<Hsla>,
+    pub subtle_bg: Option<Hsla>,
+    pub subtle_fg: Option<Hsla>,
+    pub outline_border: Option<Hsla>,
+    pub outline_fg: Option<Hsla>,
+    pub ghost_fg: Option<Hsla>,
+    pub default_bg: Option<Hsla>,
+    pub default_fg: Option<Hsla>,
+    pub default_border: Option<Hsla>,
+    pub border_hover: Option<Hsla>,
+    pub border_focus: Option<Hsla>,
+    pub content_gap: Option<Pixels>,
+    pub indicator_size: Option<Pixels>,
+    pub group_gap_horizontal: Option<Pixels>,
+    pub group_gap_vertical: Option<Pixels>,
+    pub sizes: Option<ButtonSizeScale>,
+}
+
+impl ChipOverrides {
+    fn apply(&self, mut current: ChipTokens) -> ChipTokens {
+        if let Some(value) = &self.unchecked_bg {
+            current.unchecked_bg = *value;
+        }
+        if let Some(value) = &self.unchecked_fg {
+            current.unchecked_fg = *value;
+        }
+        if let Some(value) = &self.unchecked_border {
+            current.unchecked_border = *value;
+        }
+        if let Some(value) = &self.filled_bg {
+            current.filled_bg = *value;
+        }
+        if let Some(value) = &self.filled_fg {
+            current.filled_fg = *value;
+        }
+        if let Some(value) = &self.light_bg {
+            current.light_bg = *value;
+        }
+        if let Some(value) = &self.light_fg {
+            current.light_fg = *value;
+        }
+        if let Some(value) = &self.subtle_bg {
+            current.subtle_bg = *value;
+        }
+        if let Some(value) = &self.subtle_fg {
+            current.subtle_fg = *value;
+        }
+        if let Some(value) = &self.outline_border {
+            current.outline_border = *value;
+        }
+        if let Some(value) = &self.outline_fg {
+            current.outline_fg = *value;
+        }
+        if let Some(value) = &self.ghost_fg {
+            current.ghost_fg = *value;
+        }
+        if let Some(value) = &self.default_bg {
+            current.default_bg = *value;
+        }
+        if let Some(value) = &self.default_fg {
+            current.default_fg = *value;
+        }
+        if let Some(value) = &self.default_border {
+            current.default_border = *value;
+        }
+        if let Some(value) = &self.border_hover {
+            current.border_hover = *value;
+        }
+        if let Some(value) = &self.border_focus {
+            current.border_focus = *value;
+        }
+        if let Some(value) = self.content_gap {
+            current.content_gap = value;
+        }
+        if let Some(value) = self.indicator_size {
+            current.indicator_size = value;
+        }
+        if let Some(value) = self.group_gap_horizontal {
+            current.group_gap_horizontal = value;
+        }
+        if let Some(value) = self.group_gap_vertical {
+            current.group_gap_vertical = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BadgeOverrides {
+    pub filled_bg: Option<Hsla>,
+    pub filled_fg: Option<Hsla>,
+    pub light_bg: Option<Hsla>,
+    pub light_fg: Option<Hsla>,
+    pub subtle_bg: Option<Hsla>,
+    pub subtle_fg: Option<Hsla>,
+    pub outline_border: Option<Hsla>,
+    pub outline_fg: Option<Hsla>,
+    pub default_bg: Option<Hsla>,
+    pub default_fg: Option<Hsla>,
+    pub default_border: Option<Hsla>,
+    pub sizes: Option<BadgeSizeScale>,
+}
+
+impl BadgeOverrides {
+    fn apply(&self, mut current: BadgeTokens) -> BadgeTokens {
+        if let Some(value) = &self.filled_bg {
+            current.filled_bg = *value;
+        }
+        if let Some(value) = &self.filled_fg {
+            current.filled_fg = *value;
+        }
+        if let Some(value) = &self.light_bg {
+            current.light_bg = *value;
+        }
+        if let Some(value) = &self.light_fg {
+            current.light_fg = *value;
+        }
+        if let Some(value) = &self.subtle_bg {
+            current.subtle_bg = *value;
+        }
+        if let Some(value) = &self.subtle_fg {
+            current.subtle_fg = *value;
+        }
+        if let Some(value) = &self.outline_border {
+            current.outline_border = *value;
+        }
+        if let Some(value) = &self.outline_fg {
+            current.outline_fg = *value;
+        }
+        if let Some(value) = &self.default_bg {
+            current.default_bg = *value;
+        }
+        if let Some(value) = &self.default_fg {
+            current.default_fg = *value;
+        }
+        if let Some(value) = &self.default_border {
+            current.default_border = *value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccordionOverrides {
+    pub item_bg: Option<Hsla>,
+    pub item_border: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub description: Option<Hsla>,
+    pub content: Option<Hsla>,
+    pub chevron: Option<Hsla>,
+    pub stack_gap: Option<Pixels>,
+    pub header_gap: Option<Pixels>,
+    pub label_stack_gap: Option<Pixels>,
+    pub panel_gap: Option<Pixels>,
+    pub sizes: Option<AccordionSizeScale>,
+    pub states: Option<PseudoStateTokens>,
+}
+
+impl AccordionOverrides {
+    fn apply(&self, mut current: AccordionTokens) -> AccordionTokens {
+        if let Some(value) = &self.item_bg {
+            current.item_bg = *value;
+        }
+        if let Some(value) = &self.item_border {
+            current.item_border = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = &self.description {
+            current.description = *value;
+        }
+        if let Some(value) = &self.content {
+            current.content = *value;
+        }
+        if let Some(value) = &self.chevron {
+            current.chevron = *value;
+        }
+        if let Some(value) = self.stack_gap {
+            current.stack_gap = value;
+        }
+        if let Some(value) = self.header_gap {
+            current.header_gap = value;
+        }
+        if let Some(value) = self.label_stack_gap {
+            current.label_stack_gap = value;
+        }
+        if let Some(value) = self.panel_gap {
+            current.panel_gap = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MenuOverrides {
+    pub dropdown_bg: Option<Hsla>,
+    pub dropdown_border: Option<Hsla>,
+    pub item_fg: Option<Hsla>,
+    pub item_hover_bg: Option<Hsla>,
+    pub item_disabled_fg: Option<Hsla>,
+    pub icon: Option<Hsla>,
+    pub item_gap: Option<Pixels>,
+    pub item_padding_x: Option<Pixels>,
+    pub item_padding_y: Option<Pixels>,
+    pub item_size: Option<Pixels>,
+    pub item_icon_size: Option<Pixels>,
+    pub item_radius: Option<Pixels>,
+    pub dropdown_padding: Option<Pixels>,
+    pub dropdown_gap: Option<Pixels>,
+    pub dropdown_radius: Option<Pixels>,
+    pub dropdown_width_fallback: Option<Pixels>,
+    pub dropdown_min_width: Option<Pixels>,
+    pub states: Option<PseudoStateTokens>,
+}
+
+impl MenuOverrides {
+    fn apply(&self, mut current: MenuTokens) -> MenuTokens {
+        if let Some(value) = &self.dropdown_bg {
+            current.dropdown_bg = *value;
+        }
+        if let Some(value) = &self.dropdown_border {
+            current.dropdown_border = *value;
+        }
+        if let Some(value) = &self.item_fg {
+            current.item_fg = *value;
+        }
+        if let Some(value) = &self.item_hover_bg {
+            current.item_hover_bg = *value;
+        }
+        if let Some(value) = &self.item_disabled_fg {
+            current.item_disabled_fg = *value;
+        }
+        if let Some(value) = &self.icon {
+            current.icon = *value;
+        }
+        if let Some(value) = self.item_gap {
+            current.item_gap = value;
+        }
+        if let Some(value) = self.item_padding_x {
+            current.item_padding_x = value;
+        }
+        if let Some(value) = self.item_padding_y {
+            current.item_padding_y = value;
+        }
+        if let Some(value) = self.item_size {
+            current.item_size = value;
+        }
+        if let Some(value) = self.item_icon_size {
+            current.item_icon_size = value;
+        }
+        if let Some(value) = self.item_radius {
+            current.item_radius = value;
+        }
+        if let Some(value) = self.dropdown_padding {
+            current.dropdown_padding = value;
+        }
+        if let Some(value) = self.dropdown_gap {
+            current.dropdown_gap = value;
+        }
+        if let Some(value) = self.dropdown_radius {
+            current.dropdown_radius = value;
+        }
+        if let Some(value) = self.dropdown_width_fallback {
+            current.dropdown_width_fallback = value;
+        }
+        if let Some(value) = self.dropdown_min_width {
+            current.dropdown_min_width = value;
+        }
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProgressOverrides {
+    pub track_bg: Option<Hsla>,
+    pub fill_bg: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub default_width: Option<Pixels>,
+    pub min_width: Option<Pixels>,
+    pub root_gap: Option<Pixels>,
+    pub sizes: Option<ProgressSizeScale>,
+}
+
+impl ProgressOverrides {
+    fn apply(&self, mut current: ProgressTokens) -> ProgressTokens {
+        if let Some(value) = &self.track_bg {
+            current.track_bg = *value;
+        }
+        if let Some(value) = &self.fill_bg {
+            current.fill_bg = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = self.default_width {
+            current.default_width = value;
+        }
+        if let Some(value) = self.min_width {
+            current.min_width = value;
+        }
+        if let Some(value) = self.root_gap {
+            current.root_gap = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SliderOverrides {
+    pub track_bg: Option<Hsla>,
+    pub fill_bg: Option<Hsla>,
+    pub thumb_bg: Option<Hsla>,
+    pub thumb_border: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub value: Option<Hsla>,
+    pub label_size: Option<Pixels>,
+    pub value_size: Option<Pixels>,
+    pub header_gap_vertical: Option<Pixels>,
+    pub header_gap_horizontal: Option<Pixels>,
+    pub default_width: Option<Pixels>,
+    pub min_width: Option<Pixels>,
+    pub mark_bg: Option<Hsla>,
+    pub mark_size: Option<Pixels>,
+    pub mark_label: Option<Hsla>,
+    pub mark_label_size: Option<Pixels>,
+    pub mark_label_gap: Option<Pixels>,
+    pub tooltip_offset: Option<Pixels>,
+    pub sizes: Option<SliderSizeScale>,
+}
+
+impl SliderOverrides {
+    fn apply(&self, mut current: SliderTokens) -> SliderTokens {
+        if let Some(value) = &self.track_bg {
+            current.track_bg = *value;
+        }
+        if let Some(value) = &self.fill_bg {
+            current.fill_bg = *value;
+        }
+        if let Some(value) = &self.thumb_bg {
+            current.thumb_bg = *value;
+        }
+        if let Some(value) = &self.thumb_border {
+            current.thumb_border = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = &self.value {
+            current.value = *value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        if let Some(value) = self.value_size {
+            current.value_size = value;
+        }
+        if let Some(value) = self.header_gap_vertical {
+            current.header_gap_vertical = value;
+        }
+        if let Some(value) = self.header_gap_horizontal {
+            current.header_gap_horizontal = value;
+        }
+        if let Some(value) = self.default_width {
+            current.default_width = value;
+        }
+        if let Some(value) = self.min_width {
+            current.min_width = value;
+        }
+        if let Some(value) = &self.mark_bg {
+            current.mark_bg = *value;
+        }
+        if let Some(value) = self.mark_size {
+            current.mark_size = value;
+        }
+        if let Some(value) = &self.mark_label {
+            current.mark_label = *value;
+        }
+        if let Some(value) = self.mark_label_size {
+            current.mark_label_size = value;
+        }
+        if let Some(value) = self.mark_label_gap {
+            current.mark_label_gap = value;
+        }
+        if let Some(value) = self.tooltip_offset {
+            current.tooltip_offset = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OverlayOverrides {
+    pub bg: Option<Hsla>,
+}
+
+impl OverlayOverrides {
+    fn apply(&self, mut current: OverlayTokens) -> OverlayTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LoaderOverrides {
+    pub color: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub sizes: Option<LoaderSizeScale>,
+}
+
+impl LoaderOverrides {
+    fn apply(&self, mut current: LoaderTokens) -> LoaderTokens {
+        if let Some(value) = &self.color {
+            current.color = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LoadingOverlayOverrides {
+    pub bg: Option<Hsla>,
+    pub loader_color: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub content_gap: Option<Pixels>,
+    pub label_size: Option<Pixels>,
+}
+
+impl LoadingOverlayOverrides {
+    fn apply(&self, mut current: LoadingOverlayTokens) -> LoadingOverlayTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        if let Some(value) = &self.loader_color {
+            current.loader_color = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = self.content_gap {
+            current.content_gap = value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PopoverOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub title: Option<Hsla>,
+    pub body: Option<Hsla>,
+    pub padding: Option<Pixels>,
+    pub gap: Option<Pixels>,
+    pub radius: Option<Pixels>,
+}
+
+impl PopoverOverrides {
+    fn apply(&self, mut current: PopoverTokens) -> PopoverTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = &self.title {
+            current.title = *value;
+        }
+        if let Some(value) = &self.body {
+            current.body = *value;
+        }
+        if let Some(value) = self.padding {
+            current.padding = value;
+        }
+        if let Some(value) = self.gap {
+            current.gap = value;
+        }
+        if let Some(value) = self.radius {
+            current.radius = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TooltipOverrides {
+    pub bg: Option<Hsla>,
+    pub fg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub text_size: Option<Pixels>,
+    pub padding_x: Option<Pixels>,
+    pub padding_y: Option<Pixels>,
+    pub radius: Option<Pixels>,
+    pub max_width: Option<Pixels>,
+}
+
+impl TooltipOverrides {
+    fn apply(&self, mut current: TooltipTokens) -> TooltipTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        if let Some(value) = &self.fg {
+            current.fg = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = self.text_size {
+            current.text_size = value;
+        }
+        if let Some(value) = self.padding_x {
+            current.padding_x = value;
+        }
+        if let Some(value) = self.padding_y {
+            current.padding_y = value;
+        }
+        if let Some(value) = self.radius {
+            current.radius = value;
+        }
+        if let Some(value) = self.max_width {
+            current.max_width = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HoverCardOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub title: Option<Hsla>,
+    pub body: Option<Hsla>,
+    pub title_size: Option<Pixels>,
+    pub title_weight: Option<FontWeight>,
+    pub body_size: Option<Pixels>,
+    pub min_width: Option<Pixels>,
+    pub max_width: Option<Pixels>,
+    pub padding: Option<Pixels>,
+    pub gap: Option<Pixels>,
+    pub radius: Option<Pixels>,
+}
+
+impl HoverCardOverrides {
+    fn apply(&self, mut current: HoverCardTokens) -> HoverCardTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = &self.title {
+            current.title = *value;
+        }
+        if let Some(value) = &self.body {
+            current.body = *value;
+        }
+        if let Some(value) = self.title_size {
+            current.title_size = value;
+        }
+        if let Some(value) = self.title_weight {
+            current.title_weight = value;
+        }
+        if let Some(value) = self.body_size {
+            current.body_size = value;
+        }
+        if let Some(value) = self.min_width {
+            current.min_width = value;
+        }
+        if let Some(value) = self.max_width {
+            current.max_width = value;
+        }
+        if let Some(value) = self.padding {
+            current.padding = value;
+        }
+        if let Some(value) = self.gap {
+            current.gap = value;
+        }
+        if let Some(value) = self.radius {
+            current.radius = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SelectOverrides {
+    pub bg: Option<Hsla>,
+    pub fg: Option<Hsla>,
+    pub placeholder: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub border_focus: Option<Hsla>,
+    pub border_error: Option<Hsla>,
+    pub dropdown_bg: Option<Hsla>,
+    pub dropdown_border: Option<Hsla>,
+    pub option_fg: Option<Hsla>,
+    pub option_hover_bg: Option<Hsla>,
+    pub option_selected_bg: Option<Hsla>,
+    pub tag_bg: Option<Hsla>,
+    pub tag_fg: Option<Hsla>,
+    pub tag_border: Option<Hsla>,
+    pub icon: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub label_size: Option<Pixels>,
+    pub label_weight: Option<FontWeight>,
+    pub description: Option<Hsla>,
+    pub description_size: Option<Pixels>,
+    pub error: Option<Hsla>,
+    pub error_size: Option<Pixels>,
+    pub label_block_gap: Option<Pixels>,
+    pub label_row_gap: Option<Pixels>,
+    pub slot_gap: Option<Pixels>,
+    pub slot_min_width: Option<Pixels>,
+    pub layout_gap_vertical: Option<Pixels>,
+    pub layout_gap_horizontal: Option<Pixels>,
+    pub horizontal_label_width: Option<Pixels>,
+    pub icon_size: Option<Pixels>,
+    pub option_size: Option<Pixels>,
+    pub option_padding_x: Option<Pixels>,
+    pub option_padding_y: Option<Pixels>,
+    pub option_content_gap: Option<Pixels>,
+    pub option_check_size: Option<Pixels>,
+    pub dropdown_padding: Option<Pixels>,
+    pub dropdown_gap: Option<Pixels>,
+    pub dropdown_max_height: Option<Pixels>,
+    pub dropdown_width_fallback: Option<Pixels>,
+    pub dropdown_open_preferred_height: Option<Pixels>,
+    pub tag_size: Option<Pixels>,
+    pub tag_padding_x: Option<Pixels>,
+    pub tag_padding_y: Option<Pixels>,
+    pub tag_gap: Option<Pixels>,
+    pub tag_max_width: Option<Pixels>,
+    pub dropdown_anchor_offset: Option<Pixels>,
+    pub sizes: Option<FieldSizeScale>,
+    pub states: Option<PseudoStateTokens>,
+}
+
+impl SelectOverrides {
+    fn apply(&self, mut current: SelectTokens) -> SelectTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        if let Some(value) = &self.fg {
+            current.fg = *value;
+        }
+        if let Some(value) = &self.placeholder {
+            current.placeholder = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = &self.border_focus {
+            current.border_focus = *value;
+        }
+        if let Some(value) = &self.border_error {
+            current.border_error = *value;
+        }
+        if let Some(value) = &self.dropdown_bg {
+            current.dropdown_bg = *value;
+        }
+        if let Some(value) = &self.dropdown_border {
+            current.dropdown_border = *value;
+        }
+        if let Some(value) = &self.option_fg {
+            current.option_fg = *value;
+        }
+        if let Some(value) = &self.option_hover_bg {
+            current.option_hover_bg = *value;
+        }
+        if let Some(value) = &self.option_selected_bg {
+            current.option_selected_bg = *value;
+        }
+        if let Some(value) = &self.tag_bg {
+            current.tag_bg = *value;
+        }
+        if let Some(value) = &self.tag_fg {
+            current.tag_fg = *value;
+        }
+        if let Some(value) = &self.tag_border {
+            current.tag_border = *value;
+        }
+        if let Some(value) = &self.icon {
+            current.icon = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        if let Some(value) = self.label_weight {
+            current.label_weight = value;
+        }
+        if let Some(value) = &self.description {
+            current.description = *value;
+        }
+        if let Some(value) = self.description_size {
+            current.description_size = value;
+        }
+        if let Some(value) = &self.error {
+            current.error = *value;
+        }
+        if let Some(value) = self.error_size {
+            current.error_size = value;
+        }
+        if let Some(value) = self.label_block_gap {
+            current.label_block_gap = value;
+        }
+        if let Some(value) = self.label_row_gap {
+            current.label_row_gap = value;
+        }
+        if let Some(value) = self.slot_gap {
+            current.slot_gap = value;
+        }
+        if let Some(value) = self.slot_min_width {
+            current.slot_min_width = value;
+        }
+        if let Some(value) = self.layout_gap_vertical {
+            current.layout_gap_vertical = value;
+        }
+        if let Some(value) = self.layout_gap_horizontal {
+            current.layout_gap_horizontal = value;
+        }
+        if let Some(value) = self.horizontal_label_width {
+            current.horizontal_label_width = value;
+        }
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
+        }
+        if let Some(value) = self.option_size {
+            current.option_size = value;
+        }
+        if let Some(value) = self.option_padding_x {
+            current.option_padding_x = value;
+        }
+        if let Some(value) = self.option_padding_y {
+            current.option_padding_y = value;
+        }
+        if let Some(value) = self.option_content_gap {
+            current.option_content_gap = value;
+        }
+        if let Some(value) = self.option_check_size {
+            current.option_check_size = value;
+        }
+        if let Some(value) = self.dropdown_padding {
+            current.dropdown_padding = value;
+        }
+        if let Some(value) = self.dropdown_gap {
+            current.dropdown_gap = value;
+        }
+        if let Some(value) = self.dropdown_max_height {
+            current.dropdown_max_height = value;
+        }
+        if let Some(value) = self.dropdown_width_fallback {
+            current.dropdown_width_fallback = value;
+        }
+        if let Some(value) = self.dropdown_open_preferred_height {
+            current.dropdown_open_preferred_height = value;
+        }
+        if let Some(value) = self.tag_size {
+            current.tag_size = value;
+        }
+        if let Some(value) = self.tag_padding_x {
+            current.tag_padding_x = value;
+        }
+        if let Some(value) = self.tag_padding_y {
+            current.tag_padding_y = value;
+        }
+        if let Some(value) = self.tag_gap {
+            current.tag_gap = value;
+        }
+        if let Some(value) = self.tag_max_width {
+            current.tag_max_width = value;
+        }
+        if let Some(value) = self.dropdown_anchor_offset {
+            current.dropdown_anchor_offset = value;
+        }
+        if let Some(value) = self.sizes {
+            current.sizes = value;
+        }
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ModalOverrides {
+    pub panel_bg: Option<Hsla>,
+    pub panel_border: Option<Hsla>,
+    pub overlay_bg: Option<Hsla>,
+    pub title: Option<Hsla>,
+    pub body: Option<Hsla>,
+    pub title_size: Option<Pixels>,
+    pub title_weight: Option<FontWeight>,
+    pub body_size: Option<Pixels>,
+    pub kind_icon_size: Option<Pixels>,
+    pub kind_icon_gap: Option<Pixels>,
+    pub panel_radius: Option<Pixels>,
+    pub panel_padding: Option<Pixels>,
+    pub header_margin_bottom: Option<Pixels>,
+    pub body_margin_bottom: Option<Pixels>,
+    pub actions_margin_top: Option<Pixels>,
+    pub actions_gap: Option<Pixels>,
+    pub close_size: Option<Pixels>,
+    pub close_icon_size: Option<Pixels>,
+    pub default_width: Option<Pixels>,
+    pub min_width: Option<Pixels>,
+}
+
+impl ModalOverrides {
+    fn apply(&self, mut current: ModalTokens) -> ModalTokens {
+        if let Some(value) = &self.panel_bg {
+            current.panel_bg = *value;
+        }
+        if let Some(value) = &self.panel_border {
+            current.panel_border = *value;
+        }
+        if let Some(value) = &self.overlay_bg {
+            current.overlay_bg = *value;
+        }
+        if let Some(value) = &self.title {
+            current.title = *value;
+        }
+        if let Some(value) = &self.body {
+            current.body = *value;
+        }
+        if let Some(value) = self.title_size {
+            current.title_size = value;
+        }
+        if let Some(value) = self.title_weight {
+            current.title_weight = value;
+        }
+        if let Some(value) = self.body_size {
+            current.body_size = value;
+        }
+        if let Some(value) = self.kind_icon_size {
+            current.kind_icon_size = value;
+        }
+        if let Some(value) = self.kind_icon_gap {
+            current.kind_icon_gap = value;
+        }
+        if let Some(value) = self.panel_radius {
+            current.panel_radius = value;
+        }
+        if let Some(value) = self.panel_padding {
+            current.panel_padding = value;
+        }
+        if let Some(value) = self.header_margin_bottom {
+            current.header_margin_bottom = value;
+        }
+        if let Some(value) = self.body_margin_bottom {
+            current.body_margin_bottom = value;
+        }
+        if let Some(value) = self.actions_margin_top {
+            current.actions_margin_top = value;
+        }
+        if let Some(value) = self.actions_gap {
+            current.actions_gap = value;
+        }
+        if let Some(value) = self.close_size {
+            current.close_size = value;
+        }
+        if let Some(value) = self.close_icon_size {
+            current.close_icon_size = value;
+        }
+        if let Some(value) = self.default_width {
+            current.default_width = value;
+        }
+        if let Some(value) = self.min_width {
+            current.min_width = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ToastOverrides {
+    pub info_bg: Option<Hsla>,
+    pub info_fg: Option<Hsla>,
+    pub success_bg: Option<Hsla>,
+    pub success_fg: Option<Hsla>,
+    pub warning_bg: Option<Hsla>,
+    pub warning_fg: Option<Hsla>,
+    pub error_bg: Option<Hsla>,
+    pub error_fg: Option<Hsla>,
+    pub card_width: Option<Pixels>,
+    pub card_padding: Option<Pixels>,
+    pub row_gap: Option<Pixels>,
+    pub content_gap: Option<Pixels>,
+    pub icon_box_size: Option<Pixels>,
+    pub icon_size: Option<Pixels>,
+    pub close_button_size: Option<Pixels>,
+    pub close_icon_size: Option<Pixels>,
+    pub title_size: Option<Pixels>,
+    pub body_size: Option<Pixels>,
+    pub stack_gap: Option<Pixels>,
+    pub edge_offset: Option<Pixels>,
+    pub top_offset_extra: Option<Pixels>,
+    pub action_gap: Option<Pixels>,
+    pub progress_height: Option<Pixels>,
+}
+
+impl ToastOverrides {
+    fn apply(&self, mut current: ToastTokens) -> ToastTokens {
+        if let Some(value) = &self.info_bg {
+            current.info_bg = *value;
+        }
+        if let Some(value) = &self.info_fg {
+            current.info_fg = *value;
+        }
+        if let Some(value) = &self.success_bg {
+            current.success_bg = *value;
+        }
+        if let Some(value) = &self.success_fg {
+            current.success_fg = *value;
+        }
+        if let Some(value) = &self.warning_bg {
+            current.warning_bg = *value;
+        }
+        if let Some(value) = &self.warning_fg {
+            current.warning_fg = *value;
+        }
+        if let Some(value) = &self.error_bg {
+            current.error_bg = *value;
+        }
+        if let Some(value) = &self.error_fg {
+            current.error_fg = *value;
+        }
+        if let Some(value) = self.card_width {
+            current.card_width = value;
+        }
+        if let Some(value) = self.card_padding {
+            current.card_padding = value;
+        }
+        if let Some(value) = self.row_gap {
+            current.row_gap = value;
+        }
+        if let Some(value) = self.content_gap {
+            current.content_gap = value;
+        }
+        if let Some(value) = self.icon_box_size {
+            current.icon_box_size = value;
+        }
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
+        }
+        if let Some(value) = self.close_button_size {
+            current.close_button_size = value;
+        }
+        if let Some(value) = self.close_icon_size {
+            current.close_icon_size = value;
+        }
+        if let Some(value) = self.title_size {
+            current.title_size = value;
+        }
+        if let Some(value) = self.body_size {
+            current.body_size = value;
+        }
+        if let Some(value) = self.stack_gap {
+            current.stack_gap = value;
+        }
+        if let Some(value) = self.edge_offset {
+            current.edge_offset = value;
+        }
+        if let Some(value) = self.top_offset_extra {
+            current.top_offset_extra = value;
+        }
+        if let Some(value) = self.action_gap {
+            current.action_gap = value;
+        }
+        if let Some(value) = self.progress_height {
+            current.progress_height = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DividerOverrides {
+    pub line: Option<Hsla>,
+    pub line_width: Option<Pixels>,
+    pub label: Option<Hsla>,
+    pub label_size: Option<Pixels>,
+    pub label_gap: Option<Pixels>,
+    pub edge_span: Option<Pixels>,
+}
+
+impl DividerOverrides {
+    fn apply(&self, mut current: DividerTokens) -> DividerTokens {
+        if let Some(value) = &self.line {
+            current.line = *value;
+        }
+        if let Some(value) = self.line_width {
+            current.line_width = value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        if let Some(value) = self.label_gap {
+            current.label_gap = value;
+        }
+        if let Some(value) = self.edge_span {
+            current.edge_span = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScrollAreaOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub padding: Option<InsetSizeScale>,
+    pub minimap_width: Option<Pixels>,
+    pub minimap_bg: Option<Hsla>,
+    pub minimap_row: Option<Hsla>,
+    pub minimap_viewport_bg: Option<Hsla>,
+    pub minimap_viewport_border: Option<Hsla>,
+    pub minimap_marker_info: Option<Hsla>,
+    pub minimap_marker_search: Option<Hsla>,
+    pub minimap_marker_warning: Option<Hsla>,
+    pub minimap_marker_error: Option<Hsla>,
 }
 
-impl Default for Theme {
-    fn default() -> Self {
-        let primary = PaletteKey::Blue;
-        Self {
-            radii: ThemeRadii::default(),
-            primary_color: primary,
-            primary_shade_light: PRIMARY_SHADE_LIGHT_DEFAULT,
-            primary_shade_dark: PRIMARY_SHADE_DARK_DEFAULT,
-            color_scheme: ColorScheme::Light,
-            palette: PaletteCatalog::store(),
-            semantic: SemanticColors::defaults_for(primary, ColorScheme::Light),
-            components: ComponentTokens::defaults_for(primary, ColorScheme::Light),
+impl ScrollAreaOverrides {
+    fn apply(&self, mut current: ScrollAreaTokens) -> ScrollAreaTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = self.padding {
+            current.padding = value;
+        }
+        if let Some(value) = self.minimap_width {
+            current.minimap_width = value;
+        }
+        if let Some(value) = self.minimap_bg {
+            current.minimap_bg = value;
+        }
+        if let Some(value) = self.minimap_row {
+            current.minimap_row = value;
+        }
+        if let Some(value) = self.minimap_viewport_bg {
+            current.minimap_viewport_bg = value;
+        }
+        if let Some(value) = self.minimap_viewport_border {
+            current.minimap_viewport_border = value;
+        }
+        if let Some(value) = self.minimap_marker_info {
+            current.minimap_marker_info = value;
         }
+        if let Some(value) = self.minimap_marker_search {
+            current.minimap_marker_search = value;
+        }
+        if let Some(value) = self.minimap_marker_warning {
+            current.minimap_marker_warning = value;
+        }
+        if let Some(value) = self.minimap_marker_error {
+            current.minimap_marker_error = value;
+        }
+        current
     }
 }
 
-impl Theme {
-    pub fn with_primary_color(mut self, primary: PaletteKey) -> Self {
-        self.primary_color = primary;
-        self.semantic = SemanticColors::defaults_for(primary, self.color_scheme);
-        self.components = ComponentTokens::defaults_for(primary, self.color_scheme);
-        self
-    }
-
-    pub fn with_primary_shades(mut self, light: u8, dark: u8) -> Self {
-        self.primary_shade_light = light.min(9);
-        self.primary_shade_dark = dark.min(9);
-        self
-    }
-
-    pub fn with_primary_shade(mut self, shade: u8) -> Self {
-        let clamped = shade.min(9);
-        self.primary_shade_light = clamped;
-        self.primary_shade_dark = clamped;
-        self
-    }
-
-    pub fn with_color_scheme(mut self, scheme: ColorScheme) -> Self {
-        self.color_scheme = scheme;
-        self.semantic = SemanticColors::defaults_for(self.primary_color, scheme);
-        self.components = ComponentTokens::defaults_for(self.primary_color, scheme);
-        self
-    }
-
-    pub fn with_palette_override(mut self, key: PaletteKey, scale: ColorScale) -> Self {
-        self.palette.insert(key, scale);
-        self
-    }
-
-    pub fn with_accent_color(self, accent: PaletteKey) -> Self {
-        self.with_primary_color(accent)
-    }
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DrawerOverrides {
+    pub panel_bg: Option<Hsla>,
+    pub panel_border: Option<Hsla>,
+    pub overlay_bg: Option<Hsla>,
+    pub title: Option<Hsla>,
+    pub body: Option<Hsla>,
+    pub title_size: Option<Pixels>,
+    pub title_weight: Option<FontWeight>,
+    pub body_size: Option<Pixels>,
+    pub panel_padding: Option<Pixels>,
+    pub panel_radius: Option<Pixels>,
+    pub header_margin_bottom: Option<Pixels>,
+    pub close_size: Option<Pixels>,
+    pub close_icon_size: Option<Pixels>,
+}
 
-    pub fn with_radii(mut self, radii: ThemeRadii) -> Self {
-        self.radii = radii;
-        self
+impl DrawerOverrides {
+    fn apply(&self, mut current: DrawerTokens) -> DrawerTokens {
+        if let Some(value) = &self.panel_bg {
+            current.panel_bg = *value;
+        }
+        if let Some(value) = &self.panel_border {
+            current.panel_border = *value;
+        }
+        if let Some(value) = &self.overlay_bg {
+            current.overlay_bg = *value;
+        }
+        if let Some(value) = &self.title {
+            current.title = *value;
+        }
+        if let Some(value) = &self.body {
+            current.body = *value;
+        }
+        if let Some(value) = self.title_size {
+            current.title_size = value;
+        }
+        if let Some(value) = self.title_weight {
+            current.title_weight = value;
+        }
+        if let Some(value) = self.body_size {
+            current.body_size = value;
+        }
+        if let Some(value) = self.panel_padding {
+            current.panel_padding = value;
+        }
+        if let Some(value) = self.panel_radius {
+            current.panel_radius = value;
+        }
+        if let Some(value) = self.header_margin_bottom {
+            current.header_margin_bottom = value;
+        }
+        if let Some(value) = self.close_size {
+            current.close_size = value;
+        }
+        if let Some(value) = self.close_icon_size {
+            current.close_icon_size = value;
+        }
+        current
     }
+}
 
-    pub fn resolve_color<T>(&self, token: T) -> String
-    where
-        T: ResolveWithTheme<Hsla>,
-    {
-        format!("{:?}", Rgba::from(token.resolve(self)))
-    }
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AppShellOverrides {
+    pub bg: Option<Hsla>,
+    pub title_bar_bg: Option<Hsla>,
+    pub sidebar_bg: Option<Hsla>,
+    pub sidebar_overlay_bg: Option<Hsla>,
+    pub content_bg: Option<Hsla>,
+    pub bottom_panel_bg: Option<Hsla>,
+    pub inspector_bg: Option<Hsla>,
+    pub inspector_overlay_bg: Option<Hsla>,
+    pub region_border: Option<Hsla>,
+    pub title_bar_height: Option<Pixels>,
+    pub sidebar_width: Option<Pixels>,
+    pub sidebar_min_width: Option<Pixels>,
+    pub inspector_width: Option<Pixels>,
+    pub inspector_min_width: Option<Pixels>,
+    pub bottom_panel_height: Option<Pixels>,
+    pub bottom_panel_min_height: Option<Pixels>,
+    pub resize_handle_bg: Option<Hsla>,
+    pub resize_handle_size: Option<Pixels>,
+}
 
-    pub fn resolve_hsla<T>(&self, token: T) -> Hsla
-    where
-        T: ResolveWithTheme<Hsla>,
-    {
-        token.resolve(self)
+impl AppShellOverrides {
+    fn apply(&self, mut current: AppShellTokens) -> AppShellTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
+        }
+        if let Some(value) = &self.title_bar_bg {
+            current.title_bar_bg = *value;
+        }
+        if let Some(value) = &self.sidebar_bg {
+            current.sidebar_bg = *value;
+        }
+        if let Some(value) = &self.sidebar_overlay_bg {
+            current.sidebar_overlay_bg = *value;
+        }
+        if let Some(value) = &self.content_bg {
+            current.content_bg = *value;
+        }
+        if let Some(value) = &self.bottom_panel_bg {
+            current.bottom_panel_bg = *value;
+        }
+        if let Some(value) = &self.inspector_bg {
+            current.inspector_bg = *value;
+        }
+        if let Some(value) = &self.inspector_overlay_bg {
+            current.inspector_overlay_bg = *value;
+        }
+        if let Some(value) = &self.region_border {
+            current.region_border = *value;
+        }
+        if let Some(value) = self.title_bar_height {
+            current.title_bar_height = value;
+        }
+        if let Some(value) = self.sidebar_width {
+            current.sidebar_width = value;
+        }
+        if let Some(value) = self.sidebar_min_width {
+            current.sidebar_min_width = value;
+        }
+        if let Some(value) = self.inspector_width {
+            current.inspector_width = value;
+        }
+        if let Some(value) = self.inspector_min_width {
+            current.inspector_min_width = value;
+        }
+        if let Some(value) = self.bottom_panel_height {
+            current.bottom_panel_height = value;
+        }
+        if let Some(value) = self.bottom_panel_min_height {
+            current.bottom_panel_min_height = value;
+        }
+        if let Some(value) = &self.resize_handle_bg {
+            current.resize_handle_bg = *value;
+        }
+        if let Some(value) = self.resize_handle_size {
+            current.resize_handle_size = value;
+        }
+        current
     }
+}
 
-    pub fn resolve_radius<T>(&self, token: T) -> Pixels
-    where
-        T: ResolveWithTheme<Pixels>,
-    {
-        token.resolve(self)
-    }
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TitleBarOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub fg: Option<Hsla>,
+    pub controls_bg: Option<Hsla>,
+    pub height: Option<Pixels>,
+    pub title_size: Option<Pixels>,
+    pub title_weight: Option<FontWeight>,
+    pub windows_button_width: Option<Pixels>,
+    pub windows_icon_size: Option<Pixels>,
+    pub linux_button_width: Option<Pixels>,
+    pub linux_button_height: Option<Pixels>,
+    pub linux_buttons_gap: Option<Pixels>,
+    pub macos_controls_reserve: Option<Pixels>,
+    pub title_padding_right: Option<Pixels>,
+    pub title_max_width: Option<Pixels>,
+    pub title_min_width: Option<Pixels>,
+    pub platform_padding_left: Option<Pixels>,
+    pub platform_padding_right: Option<Pixels>,
+    pub controls_slot_gap: Option<Pixels>,
+    pub control_button_radius: Option<Pixels>,
+}
 
-    pub fn merged(&self, patch: &ThemeOverrides) -> Self {
-        let mut next = self.clone();
-        if let Some(primary) = patch.primary_color {
-            next = next.with_primary_color(primary);
+impl TitleBarOverrides {
+    fn apply(&self, mut current: TitleBarTokens) -> TitleBarTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(primary_shade_light) = patch.primary_shade_light {
-            next.primary_shade_light = primary_shade_light.min(9);
+        if let Some(value) = &self.border {
+            current.border = *value;
         }
-        if let Some(primary_shade_dark) = patch.primary_shade_dark {
-            next.primary_shade_dark = primary_shade_dark.min(9);
+        if let Some(value) = &self.fg {
+            current.fg = *value;
         }
-        if let Some(color_scheme) = patch.color_scheme {
-            next.color_scheme = color_scheme;
+        if let Some(value) = &self.controls_bg {
+            current.controls_bg = *value;
         }
-        for (key, value) in &patch.palette_overrides {
-            next.palette.insert(*key, *value);
+        if let Some(value) = self.height {
+            current.height = value;
         }
-        next.radii = patch.radii.apply(next.radii);
-        next.semantic = patch.semantic.apply(next.semantic);
-        next.components = patch.components.apply(next.components);
-        next
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct SemanticOverrides {
-    pub text_primary: Option<Hsla>,
-    pub text_secondary: Option<Hsla>,
-    pub text_muted: Option<Hsla>,
-    pub bg_canvas: Option<Hsla>,
-    pub bg_surface: Option<Hsla>,
-    pub bg_soft: Option<Hsla>,
-    pub border_subtle: Option<Hsla>,
-    pub border_strong: Option<Hsla>,
-    pub focus_ring: Option<Hsla>,
-    pub status_info: Option<Hsla>,
-    pub status_success: Option<Hsla>,
-    pub status_warning: Option<Hsla>,
-    pub status_error: Option<Hsla>,
-    pub overlay_mask: Option<Hsla>,
-}
-
-impl SemanticOverrides {
-    fn apply(&self, mut current: SemanticColors) -> SemanticColors {
-        if let Some(value) = &self.text_primary {
-            current.text_primary = *value;
+        if let Some(value) = self.title_size {
+            current.title_size = value;
         }
-        if let Some(value) = &self.text_secondary {
-            current.text_secondary = *value;
+        if let Some(value) = self.title_weight {
+            current.title_weight = value;
         }
-        if let Some(value) = &self.text_muted {
-            current.text_muted = *value;
+        if let Some(value) = self.windows_button_width {
+            current.windows_button_width = value;
         }
-        if let Some(value) = &self.bg_canvas {
-            current.bg_canvas = *value;
+        if let Some(value) = self.windows_icon_size {
+            current.windows_icon_size = value;
         }
-        if let Some(value) = &self.bg_surface {
-            current.bg_surface = *value;
+        if let Some(value) = self.linux_button_width {
+            current.linux_button_width = value;
         }
-        if let Some(value) = &self.bg_soft {
-            current.bg_soft = *value;
+        if let Some(value) = self.linux_button_height {
+            current.linux_button_height = value;
         }
-        if let Some(value) = &self.border_subtle {
-            current.border_subtle = *value;
+        if let Some(value) = self.linux_buttons_gap {
+            current.linux_buttons_gap = value;
         }
-        if let Some(value) = &self.border_strong {
-            current.border_strong = *value;
+        if let Some(value) = self.macos_controls_reserve {
+            current.macos_controls_reserve = value;
         }
-        if let Some(value) = &self.focus_ring {
-            current.focus_ring = *value;
+        if let Some(value) = self.title_padding_right {
+            current.title_padding_right = value;
         }
-        if let Some(value) = &self.status_info {
-            current.status_info = *value;
+        if let Some(value) = self.title_max_width {
+            current.title_max_width = value;
         }
-        if let Some(value) = &self.status_success {
-            current.status_success = *value;
+        if let Some(value) = self.title_min_width {
+            current.title_min_width = value;
         }
-        if let Some(value) = &self.status_warning {
-            current.status_warning = *value;
+        if let Some(value) = self.platform_padding_left {
+            current.platform_padding_left = value;
         }
-        if let Some(value) = &self.status_error {
-            current.status_error = *value;
+        if let Some(value) = self.platform_padding_right {
+            current.platform_padding_right = value;
         }
-        if let Some(value) = &self.overlay_mask {
-            current.overlay_mask = *value;
+        if let Some(value) = self.controls_slot_gap {
+            current.controls_slot_gap = value;
+        }
+        if let Some(value) = self.control_button_radius {
+            current.control_button_radius = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct RadiiOverrides {
-    pub default: Option<Pixels>,
-    pub xs: Option<Pixels>,
-    pub sm: Option<Pixels>,
-    pub md: Option<Pixels>,
-    pub lg: Option<Pixels>,
-    pub xl: Option<Pixels>,
-    pub pill: Option<Pixels>,
+pub struct SidebarOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub header_fg: Option<Hsla>,
+    pub content_fg: Option<Hsla>,
+    pub footer_fg: Option<Hsla>,
+    pub inline_radius: Option<Pixels>,
+    pub overlay_radius: Option<Pixels>,
+    pub min_width: Option<Pixels>,
+    pub section_padding: Option<Pixels>,
+    pub footer_size: Option<Pixels>,
+    pub scroll_padding: Option<Size>,
 }
 
-impl RadiiOverrides {
-    fn apply(&self, mut current: ThemeRadii) -> ThemeRadii {
-        if let Some(value) = self.default {
-            current.default = value;
+impl SidebarOverrides {
+    fn apply(&self, mut current: SidebarTokens) -> SidebarTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = self.xs {
-            current.xs = value;
+        if let Some(value) = &self.border {
+            current.border = *value;
         }
-        if let Some(value) = self.sm {
-            current.sm = value;
+        if let Some(value) = &self.header_fg {
+            current.header_fg = *value;
+        }
+        if let Some(value) = &self.content_fg {
+            current.content_fg = *value;
+        }
+        if let Some(value) = &self.footer_fg {
+            current.footer_fg = *value;
+        }
+        if let Some(value) = self.inline_radius {
+            current.inline_radius = value;
+        }
+        if let Some(value) = self.overlay_radius {
+            current.overlay_radius = value;
+        }
+        if let Some(value) = self.min_width {
+            current.min_width = value;
+        }
+        if let Some(value) = self.section_padding {
+            current.section_padding = value;
+        }
+        if let Some(value) = self.footer_size {
+            current.footer_size = value;
+        }
+        if let Some(value) = self.scroll_padding {
+            current.scroll_padding = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MarkdownOverrides {
+    pub paragraph: Option<Hsla>,
+    pub paragraph_muted: Option<Hsla>,
+    pub heading: Option<Hsla>,
+    pub heading2_border: Option<Hsla>,
+    pub quote_bg: Option<Hsla>,
+    pub quote_border: Option<Hsla>,
+    pub quote_fg: Option<Hsla>,
+    pub code_bg: Option<Hsla>,
+    pub code_border: Option<Hsla>,
+    pub code_fg: Option<Hsla>,
+    pub code_lang_fg: Option<Hsla>,
+    pub link: Option<Hsla>,
+    pub link_hover: Option<Hsla>,
+    pub strong: Option<Hsla>,
+    pub em: Option<Hsla>,
+    pub del: Option<Hsla>,
+    pub inline_code_bg: Option<Hsla>,
+    pub inline_code_border: Option<Hsla>,
+    pub inline_code_fg: Option<Hsla>,
+    pub kbd_bg: Option<Hsla>,
+    pub kbd_border: Option<Hsla>,
+    pub kbd_fg: Option<Hsla>,
+    pub mark_bg: Option<Hsla>,
+    pub mark_fg: Option<Hsla>,
+    pub list_marker: Option<Hsla>,
+    pub rule: Option<Hsla>,
+    pub table_border: Option<Hsla>,
+    pub table_header_bg: Option<Hsla>,
+    pub table_header_fg: Option<Hsla>,
+    pub table_row_alt_bg: Option<Hsla>,
+    pub table_cell_fg: Option<Hsla>,
+    pub task_border: Option<Hsla>,
+    pub task_bg: Option<Hsla>,
+    pub task_checked_bg: Option<Hsla>,
+    pub task_checked_fg: Option<Hsla>,
+    pub details_bg: Option<Hsla>,
+    pub details_border: Option<Hsla>,
+    pub details_summary_fg: Option<Hsla>,
+    pub details_body_fg: Option<Hsla>,
+    pub image_border: Option<Hsla>,
+    pub image_bg: Option<Hsla>,
+    pub image_caption_fg: Option<Hsla>,
+    pub image_placeholder_bg: Option<Hsla>,
+    pub footnote_fg: Option<Hsla>,
+    pub selection_bg: Option<Hsla>,
+    pub gap_regular: Option<Pixels>,
+    pub gap_compact: Option<Pixels>,
+    pub paragraph_size: Option<Pixels>,
+    pub paragraph_line_height: Option<Pixels>,
+    pub quote_size: Option<Pixels>,
+    pub quote_line_height: Option<Pixels>,
+    pub code_size: Option<Pixels>,
+    pub code_line_height: Option<Pixels>,
+    pub code_lang_size: Option<Pixels>,
+    pub list_size: Option<Pixels>,
+    pub list_line_height: Option<Pixels>,
+    pub table_size: Option<Pixels>,
+    pub image_caption_size: Option<Pixels>,
+    pub footnote_size: Option<Pixels>,
+    pub quote_padding_x: Option<Pixels>,
+    pub quote_padding_y: Option<Pixels>,
+    pub quote_radius: Option<Pixels>,
+    pub quote_gap: Option<Pixels>,
+    pub code_padding: Option<Pixels>,
+    pub code_radius: Option<Pixels>,
+    pub code_gap: Option<Pixels>,
+    pub inline_code_radius: Option<Pixels>,
+    pub kbd_radius: Option<Pixels>,
+    pub list_gap: Option<Pixels>,
+    pub list_item_gap: Option<Pixels>,
+    pub list_indent: Option<Pixels>,
+    pub table_radius: Option<Pixels>,
+    pub table_cell_padding_x: Option<Pixels>,
+    pub table_cell_padding_y: Option<Pixels>,
+    pub details_radius: Option<Pixels>,
+    pub details_padding_x: Option<Pixels>,
+    pub details_padding_y: Option<Pixels>,
+    pub image_radius: Option<Pixels>,
+    pub image_padding: Option<Pixels>,
+    pub image_gap: Option<Pixels>,
+    pub image_placeholder_height: Option<Pixels>,
+    pub heading2_padding_top: Option<Pixels>,
+}
+
+impl MarkdownOverrides {
+    fn apply(&self, mut current: MarkdownTokens) -> MarkdownTokens {
+        if let Some(value) = &self.paragraph {
+            current.paragraph = *value;
         }
-        if let Some(value) = self.md {
-            current.md = value;
+        if let Some(value) = &self.paragraph_muted {
+            current.paragraph_muted = *value;
         }
-        if let Some(value) = self.lg {
-            current.lg = value;
+        if let Some(value) = &self.heading {
+            current.heading = *value;
         }
-        if let Some(value) = self.xl {
-            current.xl = value;
+        if let Some(value) = &self.heading2_border {
+            current.heading2_border = *value;
         }
-        if let Some(value) = self.pill {
-            current.pill = value;
+        if let Some(value) = &self.quote_bg {
+            current.quote_bg = *value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct ButtonOverrides {
-    pub filled_bg: Option<Hsla>,
-    pub filled_fg: Option<Hsla>,
-    pub light_bg: Option<Hsla>,
-    pub light_fg: Option<Hsla>,
-    pub subtle_bg: Option<Hsla>,
-    pub subtle_fg: Option<Hsla>,
-    pub outline_border: Option<Hsla>,
-    pub outline_fg: Option<Hsla>,
-    pub ghost_fg: Option<Hsla>,
-    pub disabled_bg: Option<Hsla>,
-    pub disabled_fg: Option<Hsla>,
-    pub sizes: Option<ButtonSizeScale>,
-}
-
-impl ButtonOverrides {
-    fn apply(&self, mut current: ButtonTokens) -> ButtonTokens {
-        if let Some(value) = &self.filled_bg {
-            current.filled_bg = *value;
+        if let Some(value) = &self.quote_border {
+            current.quote_border = *value;
         }
-        if let Some(value) = &self.filled_fg {
-            current.filled_fg = *value;
+        if let Some(value) = &self.quote_fg {
+            current.quote_fg = *value;
         }
-        if let Some(value) = &self.light_bg {
-            current.light_bg = *value;
+        if let Some(value) = &self.code_bg {
+            current.code_bg = *value;
         }
-        if let Some(value) = &self.light_fg {
-            current.light_fg = *value;
+        if let Some(value) = &self.code_border {
+            current.code_border = *value;
         }
-        if let Some(value) = &self.subtle_bg {
-            current.subtle_bg = *value;
+        if let Some(value) = &self.code_fg {
+            current.code_fg = *value;
         }
-        if let Some(value) = &self.subtle_fg {
-            current.subtle_fg = *value;
+        if let Some(value) = &self.code_lang_fg {
+            current.code_lang_fg = *value;
         }
-        if let Some(value) = &self.outline_border {
-            current.outline_border = *value;
+        if let Some(value) = &self.link {
+            current.link = *value;
         }
-        if let Some(value) = &self.outline_fg {
-            current.outline_fg = *value;
+        if let Some(value) = &self.link_hover {
+            current.link_hover = *value;
         }
-        if let Some(value) = &self.ghost_fg {
-            current.ghost_fg = *value;
+        if let Some(value) = &self.strong {
+            current.strong = *value;
         }
-        if let Some(value) = &self.disabled_bg {
-            current.disabled_bg = *value;
+        if let Some(value) = &self.em {
+            current.em = *value;
         }
-        if let Some(value) = &self.disabled_fg {
-            current.disabled_fg = *value;
+        if let Some(value) = &self.del {
+            current.del = *value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = &self.inline_code_bg {
+            current.inline_code_bg = *value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct InputOverrides {
-    pub bg: Option<Hsla>,
-    pub fg: Option<Hsla>,
-    pub caret: Option<Hsla>,
-    pub selection_bg: Option<Hsla>,
-    pub placeholder: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub border_focus: Option<Hsla>,
-    pub border_error: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub label_size: Option<Pixels>,
-    pub label_weight: Option<FontWeight>,
-    pub description: Option<Hsla>,
-    pub description_size: Option<Pixels>,
-    pub error: Option<Hsla>,
-    pub error_size: Option<Pixels>,
-    pub label_block_gap: Option<Pixels>,
-    pub label_row_gap: Option<Pixels>,
-    pub slot_fg: Option<Hsla>,
-    pub slot_gap: Option<Pixels>,
-    pub slot_min_width: Option<Pixels>,
-    pub layout_gap_vertical: Option<Pixels>,
-    pub layout_gap_horizontal: Option<Pixels>,
-    pub horizontal_label_width: Option<Pixels>,
-    pub pin_cells_gap: Option<Pixels>,
-    pub pin_error_gap: Option<Pixels>,
-    pub sizes: Option<FieldSizeScale>,
-}
-
-impl InputOverrides {
-    fn apply(&self, mut current: InputTokens) -> InputTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+        if let Some(value) = &self.inline_code_border {
+            current.inline_code_border = *value;
         }
-        if let Some(value) = &self.fg {
-            current.fg = *value;
+        if let Some(value) = &self.inline_code_fg {
+            current.inline_code_fg = *value;
         }
-        if let Some(value) = &self.caret {
-            current.caret = *value;
+        if let Some(value) = &self.kbd_bg {
+            current.kbd_bg = *value;
         }
-        if let Some(value) = &self.selection_bg {
-            current.selection_bg = *value;
+        if let Some(value) = &self.kbd_border {
+            current.kbd_border = *value;
         }
-        if let Some(value) = &self.placeholder {
-            current.placeholder = *value;
+        if let Some(value) = &self.kbd_fg {
+            current.kbd_fg = *value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = &self.mark_bg {
+            current.mark_bg = *value;
         }
-        if let Some(value) = &self.border_focus {
-            current.border_focus = *value;
+        if let Some(value) = &self.mark_fg {
+            current.mark_fg = *value;
         }
-        if let Some(value) = &self.border_error {
-            current.border_error = *value;
+        if let Some(value) = &self.list_marker {
+            current.list_marker = *value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = &self.rule {
+            current.rule = *value;
         }
-        if let Some(value) = self.label_size {
-            current.label_size = value;
+        if let Some(value) = &self.table_border {
+            current.table_border = *value;
         }
-        if let Some(value) = self.label_weight {
-            current.label_weight = value;
+        if let Some(value) = &self.table_header_bg {
+            current.table_header_bg = *value;
         }
-        if let Some(value) = &self.description {
-            current.description = *value;
+        if let Some(value) = &self.table_header_fg {
+            current.table_header_fg = *value;
         }
-        if let Some(value) = self.description_size {
-            current.description_size = value;
+        if let Some(value) = &self.table_row_alt_bg {
+            current.table_row_alt_bg = *value;
         }
-        if let Some(value) = &self.error {
-            current.error = *value;
+        if let Some(value) = &self.table_cell_fg {
+            current.table_cell_fg = *value;
         }
-        if let Some(value) = self.error_size {
-            current.error_size = value;
+        if let Some(value) = &self.task_border {
+            current.task_border = *value;
         }
-        if let Some(value) = self.label_block_gap {
-            current.label_block_gap = value;
+        if let Some(value) = &self.task_bg {
+            current.task_bg = *value;
         }
-        if let Some(value) = self.label_row_gap {
-            current.label_row_gap = value;
+        if let Some(value) = &self.task_checked_bg {
+            current.task_checked_bg = *value;
         }
-        if let Some(value) = &self.slot_fg {
-            current.slot_fg = *value;
+        if let Some(value) = &self.task_checked_fg {
+            current.task_checked_fg = *value;
         }
-        if let Some(value) = self.slot_gap {
-            current.slot_gap = value;
+        if let Some(value) = &self.details_bg {
+            current.details_bg = *value;
         }
-        if let Some(value) = self.slot_min_width {
-            current.slot_min_width = value;
+        if let Some(value) = &self.details_border {
+            current.details_border = *value;
         }
-        if let Some(value) = self.layout_gap_vertical {
-            current.layout_gap_vertical = value;
+        if let Some(value) = &self.details_summary_fg {
+            current.details_summary_fg = *value;
         }
-        if let Some(value) = self.layout_gap_horizontal {
-            current.layout_gap_horizontal = value;
+        if let Some(value) = &self.details_body_fg {
+            current.details_body_fg = *value;
         }
-        if let Some(value) = self.horizontal_label_width {
-            current.horizontal_label_width = value;
+        if let Some(value) = &self.image_border {
+            current.image_border = *value;
         }
-        if let Some(value) = self.pin_cells_gap {
-            current.pin_cells_gap = value;
+        if let Some(value) = &self.image_bg {
+            current.image_bg = *value;
         }
-        if let Some(value) = self.pin_error_gap {
-            current.pin_error_gap = value;
+        if let Some(value) = &self.image_caption_fg {
+            current.image_caption_fg = *value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = &self.image_placeholder_bg {
+            current.image_placeholder_bg = *value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct RadioOverrides {
-    pub control_bg: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub border_hover: Option<Hsla>,
-    pub border_focus: Option<Hsla>,
-    pub border_checked: Option<Hsla>,
-    pub indicator: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub description: Option<Hsla>,
-    pub label_description_gap: Option<Pixels>,
-    pub group_gap_horizontal: Option<Pixels>,
-    pub group_gap_vertical: Option<Pixels>,
-    pub sizes: Option<ChoiceControlSizeScale>,
-}
-
-impl RadioOverrides {
-    fn apply(&self, mut current: RadioTokens) -> RadioTokens {
-        if let Some(value) = &self.control_bg {
-            current.control_bg = *value;
+        if let Some(value) = &self.footnote_fg {
+            current.footnote_fg = *value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = &self.selection_bg {
+            current.selection_bg = *value;
         }
-        if let Some(value) = &self.border_hover {
-            current.border_hover = *value;
+        if let Some(value) = self.gap_regular {
+            current.gap_regular = value;
         }
-        if let Some(value) = &self.border_focus {
-            current.border_focus = *value;
+        if let Some(value) = self.gap_compact {
+            current.gap_compact = value;
         }
-        if let Some(value) = &self.border_checked {
-            current.border_checked = *value;
+        if let Some(value) = self.paragraph_size {
+            current.paragraph_size = value;
         }
-        if let Some(value) = &self.indicator {
-            current.indicator = *value;
+        if let Some(value) = self.paragraph_line_height {
+            current.paragraph_line_height = value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = self.quote_size {
+            current.quote_size = value;
         }
-        if let Some(value) = &self.description {
-            current.description = *value;
+        if let Some(value) = self.quote_line_height {
+            current.quote_line_height = value;
         }
-        if let Some(value) = self.label_description_gap {
-            current.label_description_gap = value;
+        if let Some(value) = self.code_size {
+            current.code_size = value;
         }
-        if let Some(value) = self.group_gap_horizontal {
-            current.group_gap_horizontal = value;
+        if let Some(value) = self.code_line_height {
+            current.code_line_height = value;
         }
-        if let Some(value) = self.group_gap_vertical {
-            current.group_gap_vertical = value;
+        if let Some(value) = self.code_lang_size {
+            current.code_lang_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.list_size {
+            current.list_size = value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct CheckboxOverrides {
-    pub control_bg: Option<Hsla>,
-    pub control_bg_checked: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub border_hover: Option<Hsla>,
-    pub border_focus: Option<Hsla>,
-    pub border_checked: Option<Hsla>,
-    pub indicator: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub description: Option<Hsla>,
-    pub label_description_gap: Option<Pixels>,
-    pub group_gap_horizontal: Option<Pixels>,
-    pub group_gap_vertical: Option<Pixels>,
-    pub sizes: Option<ChoiceControlSizeScale>,
-}
-
-impl CheckboxOverrides {
-    fn apply(&self, mut current: CheckboxTokens) -> CheckboxTokens {
-        if let Some(value) = &self.control_bg {
-            current.control_bg = *value;
+        if let Some(value) = self.list_line_height {
+            current.list_line_height = value;
         }
-        if let Some(value) = &self.control_bg_checked {
-            current.control_bg_checked = *value;
+        if let Some(value) = self.table_size {
+            current.table_size = value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = self.image_caption_size {
+            current.image_caption_size = value;
         }
-        if let Some(value) = &self.border_hover {
-            current.border_hover = *value;
+        if let Some(value) = self.footnote_size {
+            current.footnote_size = value;
         }
-        if let Some(value) = &self.border_focus {
-            current.border_focus = *value;
+        if let Some(value) = self.quote_padding_x {
+            current.quote_padding_x = value;
         }
-        if let Some(value) = &self.border_checked {
-            current.border_checked = *value;
+        if let Some(value) = self.quote_padding_y {
+            current.quote_padding_y = value;
         }
-        if let Some(value) = &self.indicator {
-            current.indicator = *value;
+        if let Some(value) = self.quote_radius {
+            current.quote_radius = value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = self.quote_gap {
+            current.quote_gap = value;
         }
-        if let Some(value) = &self.description {
-            current.description = *value;
+        if let Some(value) = self.code_padding {
+            current.code_padding = value;
         }
-        if let Some(value) = self.label_description_gap {
-            current.label_description_gap = value;
+        if let Some(value) = self.code_radius {
+            current.code_radius = value;
         }
-        if let Some(value) = self.group_gap_horizontal {
-            current.group_gap_horizontal = value;
+        if let Some(value) = self.code_gap {
+            current.code_gap = value;
         }
-        if let Some(value) = self.group_gap_vertical {
-            current.group_gap_vertical = value;
+        if let Some(value) = self.inline_code_radius {
+            current.inline_code_radius = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.kbd_radius {
+            current.kbd_radius = value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct SwitchOverrides {
-    pub track_off_bg: Option<Hsla>,
-    pub track_on_bg: Option<Hsla>,
-    pub track_hover_border: Option<Hsla>,
-    pub track_focus_border: Option<Hsla>,
-    pub thumb_bg: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub description: Option<Hsla>,
-    pub label_description_gap: Option<Pixels>,
-    pub sizes: Option<SwitchSizeScale>,
-}
-
-impl SwitchOverrides {
-    fn apply(&self, mut current: SwitchTokens) -> SwitchTokens {
-        if let Some(value) = &self.track_off_bg {
-            current.track_off_bg = *value;
+        if let Some(value) = self.list_gap {
+            current.list_gap = value;
         }
-        if let Some(value) = &self.track_on_bg {
-            current.track_on_bg = *value;
+        if let Some(value) = self.list_item_gap {
+            current.list_item_gap = value;
         }
-        if let Some(value) = &self.track_hover_border {
-            current.track_hover_border = *value;
+        if let Some(value) = self.list_indent {
+            current.list_indent = value;
         }
-        if let Some(value) = &self.track_focus_border {
-            current.track_focus_border = *value;
+        if let Some(value) = self.table_radius {
+            current.table_radius = value;
         }
-        if let Some(value) = &self.thumb_bg {
-            current.thumb_bg = *value;
+        if let Some(value) = self.table_cell_padding_x {
+            current.table_cell_padding_x = value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = self.table_cell_padding_y {
+            current.table_cell_padding_y = value;
         }
-        if let Some(value) = &self.description {
-            current.description = *value;
+        if let Some(value) = self.details_radius {
+            current.details_radius = value;
         }
-        if let Some(value) = self.label_description_gap {
-            current.label_description_gap = value;
+        if let Some(value) = self.details_padding_x {
+            current.details_padding_x = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.details_padding_y {
+            current.details_padding_y = value;
+        }
+        if let Some(value) = self.image_radius {
+            current.image_radius = value;
+        }
+        if let Some(value) = self.image_padding {
+            current.image_padding = value;
+        }
+        if let Some(value) = self.image_gap {
+            current.image_gap = value;
+        }
+        if let Some(value) = self.image_placeholder_height {
+            current.image_placeholder_height = value;
+        }
+        if let Some(value) = self.heading2_padding_top {
+            current.heading2_padding_top = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct ChipOverrides {
-    pub unchecked_bg: Option<Hsla>,
-    pub unchecked_fg: Option<Hsla>,
-    pub unchecked_border: Option<Hsla>,
-    pub filled_bg: Option<Hsla>,
-    pub filled_fg: Option<Hsla>,
-    pub light_bg: Option<Hsla>,
-    pub light_fg: Option<Hsla>,
-    pub subtle_bg: Option<Hsla>,
-    pub subtle_fg: Option<Hsla>,
-    pub outline_border: Option<Hsla>,
-    pub outline_fg: Option<Hsla>,
-    pub ghost_fg: Option<Hsla>,
-    pub default_bg: Option<Hsla>,
-    pub default_fg: Option<Hsla>,
-    pub default_border: Option<Hsla>,
-    pub border_hover: Option<Hsla>,
-    pub border_focus: Option<Hsla>,
-    pub content_gap: Option<Pixels>,
-    pub indicator_size: Option<Pixels>,
-    pub group_gap_horizontal: Option<Pixels>,
-    pub group_gap_vertical: Option<Pixels>,
-    pub sizes: Option<ButtonSizeScale>,
+pub struct TextOverrides {
+    pub fg: Option<Hsla>,
+    pub secondary: Option<Hsla>,
+    pub muted: Option<Hsla>,
+    pub accent: Option<Hsla>,
+    pub success: Option<Hsla>,
+    pub warning: Option<Hsla>,
+    pub error: Option<Hsla>,
+    pub selection_bg: Option<Hsla>,
+    pub sizes: Option<TextSizeScale>,
 }
 
-impl ChipOverrides {
-    fn apply(&self, mut current: ChipTokens) -> ChipTokens {
-        if let Some(value) = &self.unchecked_bg {
-            current.unchecked_bg = *value;
-        }
-        if let Some(value) = &self.unchecked_fg {
-            current.unchecked_fg = *value;
-        }
-        if let Some(value) = &self.unchecked_border {
-            current.unchecked_border = *value;
+impl TextOverrides {
+    fn apply(&self, mut current: TextTokens) -> TextTokens {
+        if let Some(value) = &self.fg {
+            current.fg = *value;
         }
-        if let Some(value) = &self.filled_bg {
-            current.filled_bg = *value;
+        if let Some(value) = &self.secondary {
+            current.secondary = *value;
         }
-        if let Some(value) = &self.filled_fg {
-            current.filled_fg = *value;
+        if let Some(value) = &self.muted {
+            current.muted = *value;
         }
-        if let Some(value) = &self.light_bg {
-            current.light_bg = *value;
+        if let Some(value) = &self.accent {
+            current.accent = *value;
         }
-        if let Some(value) = &self.light_fg {
-            current.light_fg = *value;
+        if let Some(value) = &self.success {
+            current.success = *value;
         }
-        if let Some(value) = &self.subtle_bg {
-            current.subtle_bg = *value;
+        if let Some(value) = &self.warning {
+            current.warning = *value;
         }
-        if let Some(value) = &self.subtle_fg {
-            current.subtle_fg = *value;
+        if let Some(value) = &self.error {
+            current.error = *value;
         }
-        if let Some(value) = &self.outline_border {
-            current.outline_border = *value;
+        if let Some(value) = &self.selection_bg {
+            current.selection_bg = *value;
         }
-        if let Some(value) = &self.outline_fg {
-            current.outline_fg = *value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
-        if let Some(value) = &self.ghost_fg {
-            current.ghost_fg = *value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TitleOverrides {
+    pub fg: Option<Hsla>,
+    pub subtitle: Option<Hsla>,
+    pub gap: Option<Pixels>,
+    pub subtitle_size: Option<Pixels>,
+    pub subtitle_line_height: Option<Pixels>,
+    pub subtitle_weight: Option<FontWeight>,
+    pub h1: TitleLevelOverrides,
+    pub h2: TitleLevelOverrides,
+    pub h3: TitleLevelOverrides,
+    pub h4: TitleLevelOverrides,
+    pub h5: TitleLevelOverrides,
+    pub h6: TitleLevelOverrides,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TitleLevelOverrides {
+    pub font_size: Option<Pixels>,
+    pub line_height: Option<Pixels>,
+    pub weight: Option<FontWeight>,
+}
+
+impl TitleLevelOverrides {
+    fn apply(&self, mut current: TitleLevelTokens) -> TitleLevelTokens {
+        if let Some(value) = &self.font_size {
+            current.font_size = *value;
         }
-        if let Some(value) = &self.default_bg {
-            current.default_bg = *value;
+        if let Some(value) = &self.line_height {
+            current.line_height = *value;
         }
-        if let Some(value) = &self.default_fg {
-            current.default_fg = *value;
+        if let Some(value) = &self.weight {
+            current.weight = *value;
         }
-        if let Some(value) = &self.default_border {
-            current.default_border = *value;
+        current
+    }
+}
+
+impl TitleOverrides {
+    fn apply(&self, mut current: TitleTokens) -> TitleTokens {
+        if let Some(value) = &self.fg {
+            current.fg = *value;
         }
-        if let Some(value) = &self.border_hover {
-            current.border_hover = *value;
+        if let Some(value) = &self.subtitle {
+            current.subtitle = *value;
         }
-        if let Some(value) = &self.border_focus {
-            current.border_focus = *value;
+        if let Some(value) = &self.gap {
+            current.gap = *value;
         }
-        if let Some(value) = self.content_gap {
-            current.content_gap = value;
+        if let Some(value) = &self.subtitle_size {
+            current.subtitle_size = *value;
         }
-        if let Some(value) = self.indicator_size {
-            current.indicator_size = value;
+        if let Some(value) = &self.subtitle_line_height {
+            current.subtitle_line_height = *value;
         }
-        if let Some(value) = self.group_gap_horizontal {
-            current.group_gap_horizontal = value;
+        if let Some(value) = &self.subtitle_weight {
+            current.subtitle_weight = *value;
         }
-        if let Some(value) = self.group_gap_vertical {
-            current.group_gap_vertical = value;
+        current.h1 = self.h1.apply(current.h1);
+        current.h2 = self.h2.apply(current.h2);
+        current.h3 = self.h3.apply(current.h3);
+        current.h4 = self.h4.apply(current.h4);
+        current.h5 = self.h5.apply(current.h5);
+        current.h6 = self.h6.apply(current.h6);
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PaperOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub padding: Option<InsetSizeScale>,
+}
+
+impl PaperOverrides {
+    fn apply(&self, mut current: PaperTokens) -> PaperTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = self.padding {
+            current.padding = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct BadgeOverrides {
+pub struct ActionIconOverrides {
     pub filled_bg: Option<Hsla>,
     pub filled_fg: Option<Hsla>,
     pub light_bg: Option<Hsla>,
@@ -6494,14 +8909,19 @@ pub struct BadgeOverrides {
     pub subtle_fg: Option<Hsla>,
     pub outline_border: Option<Hsla>,
     pub outline_fg: Option<Hsla>,
+    pub ghost_fg: Option<Hsla>,
     pub default_bg: Option<Hsla>,
     pub default_fg: Option<Hsla>,
     pub default_border: Option<Hsla>,
-    pub sizes: Option<BadgeSizeScale>,
+    pub disabled_bg: Option<Hsla>,
+    pub disabled_fg: Option<Hsla>,
+    pub disabled_border: Option<Hsla>,
+    pub sizes: Option<ActionIconSizeScale>,
+    pub states: Option<PseudoStateTokens>,
 }
 
-impl BadgeOverrides {
-    fn apply(&self, mut current: BadgeTokens) -> BadgeTokens {
+impl ActionIconOverrides {
+    fn apply(&self, mut current: ActionIconTokens) -> ActionIconTokens {
         if let Some(value) = &self.filled_bg {
             current.filled_bg = *value;
         }
@@ -6526,6 +8946,9 @@ impl BadgeOverrides {
         if let Some(value) = &self.outline_fg {
             current.outline_fg = *value;
         }
+        if let Some(value) = &self.ghost_fg {
+            current.ghost_fg = *value;
+        }
         if let Some(value) = &self.default_bg {
             current.default_bg = *value;
         }
@@ -6535,237 +8958,173 @@ impl BadgeOverrides {
         if let Some(value) = &self.default_border {
             current.default_border = *value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
-        }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct AccordionOverrides {
-    pub item_bg: Option<Hsla>,
-    pub item_border: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub description: Option<Hsla>,
-    pub content: Option<Hsla>,
-    pub chevron: Option<Hsla>,
-    pub stack_gap: Option<Pixels>,
-    pub header_gap: Option<Pixels>,
-    pub label_stack_gap: Option<Pixels>,
-    pub panel_gap: Option<Pixels>,
-    pub sizes: Option<AccordionSizeScale>,
-}
-
-impl AccordionOverrides {
-    fn apply(&self, mut current: AccordionTokens) -> AccordionTokens {
-        if let Some(value) = &self.item_bg {
-            current.item_bg = *value;
-        }
-        if let Some(value) = &self.item_border {
-            current.item_border = *value;
-        }
-        if let Some(value) = &self.label {
-            current.label = *value;
-        }
-        if let Some(value) = &self.description {
-            current.description = *value;
-        }
-        if let Some(value) = &self.content {
-            current.content = *value;
-        }
-        if let Some(value) = &self.chevron {
-            current.chevron = *value;
-        }
-        if let Some(value) = self.stack_gap {
-            current.stack_gap = value;
-        }
-        if let Some(value) = self.header_gap {
-            current.header_gap = value;
+        if let Some(value) = &self.disabled_bg {
+            current.disabled_bg = *value;
         }
-        if let Some(value) = self.label_stack_gap {
-            current.label_stack_gap = value;
+        if let Some(value) = &self.disabled_fg {
+            current.disabled_fg = *value;
         }
-        if let Some(value) = self.panel_gap {
-            current.panel_gap = value;
+        if let Some(value) = &self.disabled_border {
+            current.disabled_border = *value;
         }
         if let Some(value) = self.sizes {
             current.sizes = value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct MenuOverrides {
-    pub dropdown_bg: Option<Hsla>,
-    pub dropdown_border: Option<Hsla>,
-    pub item_fg: Option<Hsla>,
-    pub item_hover_bg: Option<Hsla>,
-    pub item_disabled_fg: Option<Hsla>,
-    pub icon: Option<Hsla>,
-    pub item_gap: Option<Pixels>,
-    pub item_padding_x: Option<Pixels>,
-    pub item_padding_y: Option<Pixels>,
-    pub item_size: Option<Pixels>,
-    pub item_icon_size: Option<Pixels>,
-    pub item_radius: Option<Pixels>,
-    pub dropdown_padding: Option<Pixels>,
-    pub dropdown_gap: Option<Pixels>,
-    pub dropdown_radius: Option<Pixels>,
-    pub dropdown_width_fallback: Option<Pixels>,
-    pub dropdown_min_width: Option<Pixels>,
-}
-
-impl MenuOverrides {
-    fn apply(&self, mut current: MenuTokens) -> MenuTokens {
-        if let Some(value) = &self.dropdown_bg {
-            current.dropdown_bg = *value;
-        }
-        if let Some(value) = &self.dropdown_border {
-            current.dropdown_border = *value;
-        }
-        if let Some(value) = &self.item_fg {
-            current.item_fg = *value;
-        }
-        if let Some(value) = &self.item_hover_bg {
-            current.item_hover_bg = *value;
-        }
-        if let Some(value) = &self.item_disabled_fg {
-            current.item_disabled_fg = *value;
-        }
-        if let Some(value) = &self.icon {
-            current.icon = *value;
-        }
-        if let Some(value) = self.item_gap {
-            current.item_gap = value;
-        }
-        if let Some(value) = self.item_padding_x {
-            current.item_padding_x = value;
-        }
-        if let Some(value) = self.item_padding_y {
-            current.item_padding_y = value;
-        }
-        if let Some(value) = self.item_size {
-            current.item_size = value;
-        }
-        if let Some(value) = self.item_icon_size {
-            current.item_icon_size = value;
-        }
-        if let Some(value) = self.item_radius {
-            current.item_radius = value;
-        }
-        if let Some(value) = self.dropdown_padding {
-            current.dropdown_padding = value;
-        }
-        if let Some(value) = self.dropdown_gap {
-            current.dropdown_gap = value;
-        }
-        if let Some(value) = self.dropdown_radius {
-            current.dropdown_radius = value;
-        }
-        if let Some(value) = self.dropdown_width_fallback {
-            current.dropdown_width_fallback = value;
-        }
-        if let Some(value) = self.dropdown_min_width {
-            current.dropdown_min_width = value;
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct ProgressOverrides {
-    pub track_bg: Option<Hsla>,
-    pub fill_bg: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub default_width: Option<Pixels>,
-    pub min_width: Option<Pixels>,
-    pub root_gap: Option<Pixels>,
-    pub sizes: Option<ProgressSizeScale>,
+pub struct SegmentedControlOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub item_fg: Option<Hsla>,
+    pub item_active_bg: Option<Hsla>,
+    pub item_active_fg: Option<Hsla>,
+    pub item_hover_bg: Option<Hsla>,
+    pub item_disabled_fg: Option<Hsla>,
+    pub track_padding: Option<Pixels>,
+    pub item_gap: Option<Pixels>,
+    pub sizes: Option<SegmentedControlSizeScale>,
+    pub states: Option<PseudoStateTokens>,
 }
 
-impl ProgressOverrides {
-    fn apply(&self, mut current: ProgressTokens) -> ProgressTokens {
-        if let Some(value) = &self.track_bg {
-            current.track_bg = *value;
+impl SegmentedControlOverrides {
+    fn apply(&self, mut current: SegmentedControlTokens) -> SegmentedControlTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = &self.fill_bg {
-            current.fill_bg = *value;
+        if let Some(value) = &self.border {
+            current.border = *value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = &self.item_fg {
+            current.item_fg = *value;
         }
-        if let Some(value) = self.default_width {
-            current.default_width = value;
+        if let Some(value) = &self.item_active_bg {
+            current.item_active_bg = *value;
         }
-        if let Some(value) = self.min_width {
-            current.min_width = value;
+        if let Some(value) = &self.item_active_fg {
+            current.item_active_fg = *value;
         }
-        if let Some(value) = self.root_gap {
-            current.root_gap = value;
+        if let Some(value) = &self.item_hover_bg {
+            current.item_hover_bg = *value;
+        }
+        if let Some(value) = &self.item_disabled_fg {
+            current.item_disabled_fg = *value;
+        }
+        if let Some(value) = self.track_padding {
+            current.track_padding = value;
+        }
+        if let Some(value) = self.item_gap {
+            current.item_gap = value;
         }
         if let Some(value) = self.sizes {
             current.sizes = value;
         }
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
+        }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct SliderOverrides {
-    pub track_bg: Option<Hsla>,
-    pub fill_bg: Option<Hsla>,
-    pub thumb_bg: Option<Hsla>,
-    pub thumb_border: Option<Hsla>,
+pub struct TextareaOverrides {
+    pub bg: Option<Hsla>,
+    pub fg: Option<Hsla>,
+    pub caret: Option<Hsla>,
+    pub selection_bg: Option<Hsla>,
+    pub placeholder: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub border_focus: Option<Hsla>,
+    pub border_error: Option<Hsla>,
+    pub spell_error: Option<Hsla>,
     pub label: Option<Hsla>,
-    pub value: Option<Hsla>,
     pub label_size: Option<Pixels>,
-    pub value_size: Option<Pixels>,
-    pub header_gap_vertical: Option<Pixels>,
-    pub header_gap_horizontal: Option<Pixels>,
-    pub default_width: Option<Pixels>,
-    pub min_width: Option<Pixels>,
-    pub sizes: Option<SliderSizeScale>,
+    pub label_weight: Option<FontWeight>,
+    pub description: Option<Hsla>,
+    pub description_size: Option<Pixels>,
+    pub error: Option<Hsla>,
+    pub error_size: Option<Pixels>,
+    pub label_block_gap: Option<Pixels>,
+    pub label_row_gap: Option<Pixels>,
+    pub layout_gap_vertical: Option<Pixels>,
+    pub layout_gap_horizontal: Option<Pixels>,
+    pub horizontal_label_width: Option<Pixels>,
+    pub content_width_fallback: Option<Pixels>,
+    pub sizes: Option<FieldSizeScale>,
 }
 
-impl SliderOverrides {
-    fn apply(&self, mut current: SliderTokens) -> SliderTokens {
-        if let Some(value) = &self.track_bg {
-            current.track_bg = *value;
+impl TextareaOverrides {
+    fn apply(&self, mut current: TextareaTokens) -> TextareaTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = &self.fill_bg {
-            current.fill_bg = *value;
+        if let Some(value) = &self.fg {
+            current.fg = *value;
         }
-        if let Some(value) = &self.thumb_bg {
-            current.thumb_bg = *value;
+        if let Some(value) = &self.caret {
+            current.caret = *value;
         }
-        if let Some(value) = &self.thumb_border {
-            current.thumb_border = *value;
+        if let Some(value) = &self.selection_bg {
+            current.selection_bg = *value;
+        }
+        if let Some(value) = &self.placeholder {
+            current.placeholder = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = &self.border_focus {
+            current.border_focus = *value;
+        }
+        if let Some(value) = &self.border_error {
+            current.border_error = *value;
+        }
+        if let Some(value) = &self.spell_error {
+            current.spell_error = *value;
         }
         if let Some(value) = &self.label {
             current.label = *value;
         }
-        if let Some(value) = &self.value {
-            current.value = *value;
-        }
         if let Some(value) = self.label_size {
             current.label_size = value;
         }
-        if let Some(value) = self.value_size {
-            current.value_size = value;
+        if let Some(value) = self.label_weight {
+            current.label_weight = value;
         }
-        if let Some(value) = self.header_gap_vertical {
-            current.header_gap_vertical = value;
+        if let Some(value) = &self.description {
+            current.description = *value;
         }
-        if let Some(value) = self.header_gap_horizontal {
-            current.header_gap_horizontal = value;
+        if let Some(value) = self.description_size {
+            current.description_size = value;
         }
-        if let Some(value) = self.default_width {
-            current.default_width = value;
+        if let Some(value) = &self.error {
+            current.error = *value;
         }
-        if let Some(value) = self.min_width {
-            current.min_width = value;
+        if let Some(value) = self.error_size {
+            current.error_size = value;
+        }
+        if let Some(value) = self.label_block_gap {
+            current.label_block_gap = value;
+        }
+        if let Some(value) = self.label_row_gap {
+            current.label_row_gap = value;
+        }
+        if let Some(value) = self.layout_gap_vertical {
+            current.layout_gap_vertical = value;
+        }
+        if let Some(value) = self.layout_gap_horizontal {
+            current.layout_gap_horizontal = value;
+        }
+        if let Some(value) = self.horizontal_label_width {
+            current.horizontal_label_width = value;
+        }
+        if let Some(value) = self.content_width_fallback {
+            current.content_width_fallback = value;
         }
         if let Some(value) = self.sizes {
             current.sizes = value;
@@ -6775,33 +9134,91 @@ impl SliderOverrides {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct OverlayOverrides {
+pub struct NumberInputOverrides {
     pub bg: Option<Hsla>,
+    pub fg: Option<Hsla>,
+    pub placeholder: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub border_focus: Option<Hsla>,
+    pub border_error: Option<Hsla>,
+    pub controls_bg: Option<Hsla>,
+    pub controls_fg: Option<Hsla>,
+    pub controls_border: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub label_size: Option<Pixels>,
+    pub label_weight: Option<FontWeight>,
+    pub description: Option<Hsla>,
+    pub description_size: Option<Pixels>,
+    pub error: Option<Hsla>,
+    pub error_size: Option<Pixels>,
+    pub controls_width: Option<Pixels>,
+    pub controls_height: Option<Pixels>,
+    pub controls_icon_size: Option<Pixels>,
+    pub controls_gap: Option<Pixels>,
+    pub sizes: Option<FieldSizeScale>,
 }
 
-impl OverlayOverrides {
-    fn apply(&self, mut current: OverlayTokens) -> OverlayTokens {
+impl NumberInputOverrides {
+    fn apply(&self, mut current: NumberInputTokens) -> NumberInputTokens {
         if let Some(value) = &self.bg {
             current.bg = *value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct LoaderOverrides {
-    pub color: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub sizes: Option<LoaderSizeScale>,
-}
-
-impl LoaderOverrides {
-    fn apply(&self, mut current: LoaderTokens) -> LoaderTokens {
-        if let Some(value) = &self.color {
-            current.color = *value;
+        if let Some(value) = &self.fg {
+            current.fg = *value;
+        }
+        if let Some(value) = &self.placeholder {
+            current.placeholder = *value;
+        }
+        if let Some(value) = &self.border {
+            current.border = *value;
+        }
+        if let Some(value) = &self.border_focus {
+            current.border_focus = *value;
+        }
+        if let Some(value) = &self.border_error {
+            current.border_error = *value;
+        }
+        if let Some(value) = &self.controls_bg {
+            current.controls_bg = *value;
+        }
+        if let Some(value) = &self.controls_fg {
+            current.controls_fg = *value;
+        }
+        if let Some(value) = &self.controls_border {
+            current.controls_border = *value;
+        }
+        if let Some(value) = &self.label {
+            current.label = *value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        if let Some(value) = self.label_weight {
+            current.label_weight = value;
+        }
+        if let Some(value) = &self.description {
+            current.description = *value;
+        }
+        if let Some(value) = self.description_size {
+            current.description_size = value;
+        }
+        if let Some(value) = &self.error {
+            current.error = *value;
+        }
+        if let Some(value) = self.error_size {
+            current.error_size = value;
+        }
+        if let Some(value) = self.controls_width {
+            current.controls_width = value;
+        }
+        if let Some(value) = self.controls_height {
+            current.controls_height = value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = self.controls_icon_size {
+            current.controls_icon_size = value;
+        }
+        if let Some(value) = self.controls_gap {
+            current.controls_gap = value;
         }
         if let Some(value) = self.sizes {
             current.sizes = value;
@@ -6811,1234 +9228,1540 @@ impl LoaderOverrides {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct LoadingOverlayOverrides {
-    pub bg: Option<Hsla>,
-    pub loader_color: Option<Hsla>,
+pub struct RangeSliderOverrides {
+    pub track_bg: Option<Hsla>,
+    pub range_bg: Option<Hsla>,
+    pub thumb_bg: Option<Hsla>,
+    pub thumb_border: Option<Hsla>,
     pub label: Option<Hsla>,
-    pub content_gap: Option<Pixels>,
+    pub value: Option<Hsla>,
     pub label_size: Option<Pixels>,
+    pub value_size: Option<Pixels>,
+    pub header_gap_vertical: Option<Pixels>,
+    pub header_gap_horizontal: Option<Pixels>,
+    pub default_width: Option<Pixels>,
+    pub min_width: Option<Pixels>,
+    pub mark_bg: Option<Hsla>,
+    pub mark_size: Option<Pixels>,
+    pub mark_label: Option<Hsla>,
+    pub mark_label_size: Option<Pixels>,
+    pub mark_label_gap: Option<Pixels>,
+    pub tooltip_offset: Option<Pixels>,
+    pub sizes: Option<SliderSizeScale>,
 }
 
-impl LoadingOverlayOverrides {
-    fn apply(&self, mut current: LoadingOverlayTokens) -> LoadingOverlayTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+impl RangeSliderOverrides {
+    fn apply(&self, mut current: RangeSliderTokens) -> RangeSliderTokens {
+        if let Some(value) = &self.track_bg {
+            current.track_bg = *value;
         }
-        if let Some(value) = &self.loader_color {
-            current.loader_color = *value;
+        if let Some(value) = &self.range_bg {
+            current.range_bg = *value;
+        }
+        if let Some(value) = &self.thumb_bg {
+            current.thumb_bg = *value;
+        }
+        if let Some(value) = &self.thumb_border {
+            current.thumb_border = *value;
         }
         if let Some(value) = &self.label {
             current.label = *value;
         }
-        if let Some(value) = self.content_gap {
-            current.content_gap = value;
+        if let Some(value) = &self.value {
+            current.value = *value;
         }
         if let Some(value) = self.label_size {
             current.label_size = value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct PopoverOverrides {
-    pub bg: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub title: Option<Hsla>,
-    pub body: Option<Hsla>,
-    pub padding: Option<Pixels>,
-    pub gap: Option<Pixels>,
-    pub radius: Option<Pixels>,
-}
-
-impl PopoverOverrides {
-    fn apply(&self, mut current: PopoverTokens) -> PopoverTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
-        }
-        if let Some(value) = &self.border {
-            current.border = *value;
-        }
-        if let Some(value) = &self.title {
-            current.title = *value;
-        }
-        if let Some(value) = &self.body {
-            current.body = *value;
+        if let Some(value) = self.value_size {
+            current.value_size = value;
         }
-        if let Some(value) = self.padding {
-            current.padding = value;
+        if let Some(value) = self.header_gap_vertical {
+            current.header_gap_vertical = value;
         }
-        if let Some(value) = self.gap {
-            current.gap = value;
+        if let Some(value) = self.header_gap_horizontal {
+            current.header_gap_horizontal = value;
         }
-        if let Some(value) = self.radius {
-            current.radius = value;
+        if let Some(value) = self.default_width {
+            current.default_width = value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TooltipOverrides {
-    pub bg: Option<Hsla>,
-    pub fg: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub text_size: Option<Pixels>,
-    pub padding_x: Option<Pixels>,
-    pub padding_y: Option<Pixels>,
-    pub radius: Option<Pixels>,
-    pub max_width: Option<Pixels>,
-}
-
-impl TooltipOverrides {
-    fn apply(&self, mut current: TooltipTokens) -> TooltipTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+        if let Some(value) = self.min_width {
+            current.min_width = value;
         }
-        if let Some(value) = &self.fg {
-            current.fg = *value;
+        if let Some(value) = &self.mark_bg {
+            current.mark_bg = *value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = self.mark_size {
+            current.mark_size = value;
         }
-        if let Some(value) = self.text_size {
-            current.text_size = value;
+        if let Some(value) = &self.mark_label {
+            current.mark_label = *value;
         }
-        if let Some(value) = self.padding_x {
-            current.padding_x = value;
+        if let Some(value) = self.mark_label_size {
+            current.mark_label_size = value;
         }
-        if let Some(value) = self.padding_y {
-            current.padding_y = value;
+        if let Some(value) = self.mark_label_gap {
+            current.mark_label_gap = value;
         }
-        if let Some(value) = self.radius {
-            current.radius = value;
+        if let Some(value) = self.tooltip_offset {
+            current.tooltip_offset = value;
         }
-        if let Some(value) = self.max_width {
-            current.max_width = value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct HoverCardOverrides {
-    pub bg: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub title: Option<Hsla>,
-    pub body: Option<Hsla>,
-    pub title_size: Option<Pixels>,
-    pub title_weight: Option<FontWeight>,
-    pub body_size: Option<Pixels>,
-    pub min_width: Option<Pixels>,
-    pub max_width: Option<Pixels>,
-    pub padding: Option<Pixels>,
-    pub gap: Option<Pixels>,
-    pub radius: Option<Pixels>,
+pub struct RatingOverrides {
+    pub active: Option<Hsla>,
+    pub inactive: Option<Hsla>,
+    pub value: Option<Hsla>,
+    pub value_size: Option<Pixels>,
+    pub value_gap: Option<Pixels>,
+    pub sizes: Option<RatingSizeScale>,
 }
 
-impl HoverCardOverrides {
-    fn apply(&self, mut current: HoverCardTokens) -> HoverCardTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
-        }
-        if let Some(value) = &self.border {
-            current.border = *value;
-        }
-        if let Some(value) = &self.title {
-            current.title = *value;
-        }
-        if let Some(value) = &self.body {
-            current.body = *value;
-        }
-        if let Some(value) = self.title_size {
-            current.title_size = value;
-        }
-        if let Some(value) = self.title_weight {
-            current.title_weight = value;
-        }
-        if let Some(value) = self.body_size {
-            current.body_size = value;
+impl RatingOverrides {
+    fn apply(&self, mut current: RatingTokens) -> RatingTokens {
+        if let Some(value) = &self.active {
+            current.active = *value;
         }
-        if let Some(value) = self.min_width {
-            current.min_width = value;
+        if let Some(value) = &self.inactive {
+            current.inactive = *value;
         }
-        if let Some(value) = self.max_width {
-            current.max_width = value;
+        if let Some(value) = &self.value {
+            current.value = *value;
         }
-        if let Some(value) = self.padding {
-            current.padding = value;
+        if let Some(value) = self.value_size {
+            current.value_size = value;
         }
-        if let Some(value) = self.gap {
-            current.gap = value;
+        if let Some(value) = self.value_gap {
+            current.value_gap = value;
         }
-        if let Some(value) = self.radius {
-            current.radius = value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct SelectOverrides {
-    pub bg: Option<Hsla>,
-    pub fg: Option<Hsla>,
-    pub placeholder: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub border_focus: Option<Hsla>,
-    pub border_error: Option<Hsla>,
-    pub dropdown_bg: Option<Hsla>,
-    pub dropdown_border: Option<Hsla>,
-    pub option_fg: Option<Hsla>,
-    pub option_hover_bg: Option<Hsla>,
-    pub option_selected_bg: Option<Hsla>,
-    pub tag_bg: Option<Hsla>,
-    pub tag_fg: Option<Hsla>,
-    pub tag_border: Option<Hsla>,
-    pub icon: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub label_size: Option<Pixels>,
-    pub label_weight: Option<FontWeight>,
-    pub description: Option<Hsla>,
-    pub description_size: Option<Pixels>,
-    pub error: Option<Hsla>,
-    pub error_size: Option<Pixels>,
-    pub label_block_gap: Option<Pixels>,
-    pub label_row_gap: Option<Pixels>,
-    pub slot_gap: Option<Pixels>,
-    pub slot_min_width: Option<Pixels>,
-    pub layout_gap_vertical: Option<Pixels>,
-    pub layout_gap_horizontal: Option<Pixels>,
-    pub horizontal_label_width: Option<Pixels>,
-    pub icon_size: Option<Pixels>,
-    pub option_size: Option<Pixels>,
-    pub option_padding_x: Option<Pixels>,
-    pub option_padding_y: Option<Pixels>,
-    pub option_content_gap: Option<Pixels>,
-    pub option_check_size: Option<Pixels>,
-    pub dropdown_padding: Option<Pixels>,
-    pub dropdown_gap: Option<Pixels>,
-    pub dropdown_max_height: Option<Pixels>,
-    pub dropdown_width_fallback: Option<Pixels>,
-    pub dropdown_open_preferred_height: Option<Pixels>,
-    pub tag_size: Option<Pixels>,
-    pub tag_padding_x: Option<Pixels>,
-    pub tag_padding_y: Option<Pixels>,
-    pub tag_gap: Option<Pixels>,
-    pub tag_max_width: Option<Pixels>,
-    pub dropdown_anchor_offset: Option<Pixels>,
-    pub sizes: Option<FieldSizeScale>,
+pub struct TabsOverrides {
+    pub list_bg: Option<Hsla>,
+    pub list_border: Option<Hsla>,
+    pub tab_fg: Option<Hsla>,
+    pub tab_active_bg: Option<Hsla>,
+    pub tab_active_fg: Option<Hsla>,
+    pub tab_hover_bg: Option<Hsla>,
+    pub tab_disabled_fg: Option<Hsla>,
+    pub panel_bg: Option<Hsla>,
+    pub panel_border: Option<Hsla>,
+    pub panel_fg: Option<Hsla>,
+    pub root_gap: Option<Pixels>,
+    pub list_gap: Option<Pixels>,
+    pub list_padding: Option<Pixels>,
+    pub panel_padding: Option<Pixels>,
+    pub sizes: Option<TabsSizeScale>,
+    pub states: Option<PseudoStateTokens>,
 }
 
-impl SelectOverrides {
-    fn apply(&self, mut current: SelectTokens) -> SelectTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+impl TabsOverrides {
+    fn apply(&self, mut current: TabsTokens) -> TabsTokens {
+        if let Some(value) = &self.list_bg {
+            current.list_bg = *value;
         }
-        if let Some(value) = &self.fg {
-            current.fg = *value;
+        if let Some(value) = &self.list_border {
+            current.list_border = *value;
         }
-        if let Some(value) = &self.placeholder {
-            current.placeholder = *value;
+        if let Some(value) = &self.tab_fg {
+            current.tab_fg = *value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = &self.tab_active_bg {
+            current.tab_active_bg = *value;
         }
-        if let Some(value) = &self.border_focus {
-            current.border_focus = *value;
+        if let Some(value) = &self.tab_active_fg {
+            current.tab_active_fg = *value;
         }
-        if let Some(value) = &self.border_error {
-            current.border_error = *value;
+        if let Some(value) = &self.tab_hover_bg {
+            current.tab_hover_bg = *value;
         }
-        if let Some(value) = &self.dropdown_bg {
-            current.dropdown_bg = *value;
+        if let Some(value) = &self.tab_disabled_fg {
+            current.tab_disabled_fg = *value;
         }
-        if let Some(value) = &self.dropdown_border {
-            current.dropdown_border = *value;
+        if let Some(value) = &self.panel_bg {
+            current.panel_bg = *value;
         }
-        if let Some(value) = &self.option_fg {
-            current.option_fg = *value;
+        if let Some(value) = &self.panel_border {
+            current.panel_border = *value;
         }
-        if let Some(value) = &self.option_hover_bg {
-            current.option_hover_bg = *value;
+        if let Some(value) = &self.panel_fg {
+            current.panel_fg = *value;
         }
-        if let Some(value) = &self.option_selected_bg {
-            current.option_selected_bg = *value;
+        if let Some(value) = self.root_gap {
+            current.root_gap = value;
         }
-        if let Some(value) = &self.tag_bg {
-            current.tag_bg = *value;
+        if let Some(value) = self.list_gap {
+            current.list_gap = value;
         }
-        if let Some(value) = &self.tag_fg {
-            current.tag_fg = *value;
+        if let Some(value) = self.list_padding {
+            current.list_padding = value;
         }
-        if let Some(value) = &self.tag_border {
-            current.tag_border = *value;
+        if let Some(value) = self.panel_padding {
+            current.panel_padding = value;
         }
-        if let Some(value) = &self.icon {
-            current.icon = *value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
         }
-        if let Some(value) = self.label_size {
-            current.label_size = value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PaginationOverrides {
+    pub item_bg: Option<Hsla>,
+    pub item_border: Option<Hsla>,
+    pub item_fg: Option<Hsla>,
+    pub item_active_bg: Option<Hsla>,
+    pub item_active_fg: Option<Hsla>,
+    pub item_hover_bg: Option<Hsla>,
+    pub item_disabled_fg: Option<Hsla>,
+    pub dots_fg: Option<Hsla>,
+    pub root_gap: Option<Pixels>,
+    pub sizes: Option<PaginationSizeScale>,
+    pub states: Option<PseudoStateTokens>,
+}
+
+impl PaginationOverrides {
+    fn apply(&self, mut current: PaginationTokens) -> PaginationTokens {
+        if let Some(value) = &self.item_bg {
+            current.item_bg = *value;
         }
-        if let Some(value) = self.label_weight {
-            current.label_weight = value;
+        if let Some(value) = &self.item_border {
+            current.item_border = *value;
         }
-        if let Some(value) = &self.description {
-            current.description = *value;
+        if let Some(value) = &self.item_fg {
+            current.item_fg = *value;
         }
-        if let Some(value) = self.description_size {
-            current.description_size = value;
+        if let Some(value) = &self.item_active_bg {
+            current.item_active_bg = *value;
         }
-        if let Some(value) = &self.error {
-            current.error = *value;
+        if let Some(value) = &self.item_active_fg {
+            current.item_active_fg = *value;
         }
-        if let Some(value) = self.error_size {
-            current.error_size = value;
+        if let Some(value) = &self.item_hover_bg {
+            current.item_hover_bg = *value;
         }
-        if let Some(value) = self.label_block_gap {
-            current.label_block_gap = value;
+        if let Some(value) = &self.item_disabled_fg {
+            current.item_disabled_fg = *value;
         }
-        if let Some(value) = self.label_row_gap {
-            current.label_row_gap = value;
+        if let Some(value) = &self.dots_fg {
+            current.dots_fg = *value;
         }
-        if let Some(value) = self.slot_gap {
-            current.slot_gap = value;
+        if let Some(value) = self.root_gap {
+            current.root_gap = value;
         }
-        if let Some(value) = self.slot_min_width {
-            current.slot_min_width = value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
-        if let Some(value) = self.layout_gap_vertical {
-            current.layout_gap_vertical = value;
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
         }
-        if let Some(value) = self.layout_gap_horizontal {
-            current.layout_gap_horizontal = value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BreadcrumbsOverrides {
+    pub item_fg: Option<Hsla>,
+    pub item_current_fg: Option<Hsla>,
+    pub separator: Option<Hsla>,
+    pub item_hover_bg: Option<Hsla>,
+    pub root_gap: Option<Pixels>,
+    pub sizes: Option<BreadcrumbsSizeScale>,
+    pub states: Option<PseudoStateTokens>,
+}
+
+impl BreadcrumbsOverrides {
+    fn apply(&self, mut current: BreadcrumbsTokens) -> BreadcrumbsTokens {
+        if let Some(value) = &self.item_fg {
+            current.item_fg = *value;
         }
-        if let Some(value) = self.horizontal_label_width {
-            current.horizontal_label_width = value;
+        if let Some(value) = &self.item_current_fg {
+            current.item_current_fg = *value;
         }
-        if let Some(value) = self.icon_size {
-            current.icon_size = value;
+        if let Some(value) = &self.separator {
+            current.separator = *value;
         }
-        if let Some(value) = self.option_size {
-            current.option_size = value;
+        if let Some(value) = &self.item_hover_bg {
+            current.item_hover_bg = *value;
         }
-        if let Some(value) = self.option_padding_x {
-            current.option_padding_x = value;
+        if let Some(value) = self.root_gap {
+            current.root_gap = value;
         }
-        if let Some(value) = self.option_padding_y {
-            current.option_padding_y = value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
-        if let Some(value) = self.option_content_gap {
-            current.option_content_gap = value;
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
         }
-        if let Some(value) = self.option_check_size {
-            current.option_check_size = value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TableOverrides {
+    pub header_bg: Option<Hsla>,
+    pub header_fg: Option<Hsla>,
+    pub row_bg: Option<Hsla>,
+    pub row_alt_bg: Option<Hsla>,
+    pub row_hover_bg: Option<Hsla>,
+    pub row_selected_bg: Option<Hsla>,
+    pub row_border: Option<Hsla>,
+    pub cell_fg: Option<Hsla>,
+    pub selection_bg: Option<Hsla>,
+    pub caption: Option<Hsla>,
+    pub caption_size: Option<Pixels>,
+    pub row_gap: Option<Pixels>,
+    pub pagination_summary_size: Option<Pixels>,
+    pub page_chip_size: Option<Pixels>,
+    pub page_chip_padding_x: Option<Pixels>,
+    pub page_chip_padding_y: Option<Pixels>,
+    pub page_chip_radius: Option<Pixels>,
+    pub page_chip_gap: Option<Pixels>,
+    pub pagination_items_gap: Option<Pixels>,
+    pub pagination_padding_x: Option<Pixels>,
+    pub pagination_padding_y: Option<Pixels>,
+    pub pagination_gap: Option<Pixels>,
+    pub virtualization_padding: Option<Pixels>,
+    pub min_viewport_height: Option<Pixels>,
+    pub sizes: Option<TableSizeScale>,
+    pub states: Option<PseudoStateTokens>,
+}
+
+impl TableOverrides {
+    fn apply(&self, mut current: TableTokens) -> TableTokens {
+        if let Some(value) = &self.header_bg {
+            current.header_bg = *value;
         }
-        if let Some(value) = self.dropdown_padding {
-            current.dropdown_padding = value;
+        if let Some(value) = &self.header_fg {
+            current.header_fg = *value;
         }
-        if let Some(value) = self.dropdown_gap {
-            current.dropdown_gap = value;
+        if let Some(value) = &self.row_bg {
+            current.row_bg = *value;
         }
-        if let Some(value) = self.dropdown_max_height {
-            current.dropdown_max_height = value;
+        if let Some(value) = &self.row_alt_bg {
+            current.row_alt_bg = *value;
+        }
+        if let Some(value) = &self.row_hover_bg {
+            current.row_hover_bg = *value;
+        }
+        if let Some(value) = &self.row_selected_bg {
+            current.row_selected_bg = *value;
+        }
+        if let Some(value) = &self.row_border {
+            current.row_border = *value;
+        }
+        if let Some(value) = &self.cell_fg {
+            current.cell_fg = *value;
+        }
+        if let Some(value) = &self.selection_bg {
+            current.selection_bg = *value;
+        }
+        if let Some(value) = &self.caption {
+            current.caption = *value;
+        }
+        if let Some(value) = self.caption_size {
+            current.caption_size = value;
+        }
+        if let Some(value) = self.row_gap {
+            current.row_gap = value;
+        }
+        if let Some(value) = self.pagination_summary_size {
+            current.pagination_summary_size = value;
+        }
+        if let Some(value) = self.page_chip_size {
+            current.page_chip_size = value;
+        }
+        if let Some(value) = self.page_chip_padding_x {
+            current.page_chip_padding_x = value;
+        }
+        if let Some(value) = self.page_chip_padding_y {
+            current.page_chip_padding_y = value;
         }
-        if let Some(value) = self.dropdown_width_fallback {
-            current.dropdown_width_fallback = value;
+        if let Some(value) = self.page_chip_radius {
+            current.page_chip_radius = value;
         }
-        if let Some(value) = self.dropdown_open_preferred_height {
-            current.dropdown_open_preferred_height = value;
+        if let Some(value) = self.page_chip_gap {
+            current.page_chip_gap = value;
         }
-        if let Some(value) = self.tag_size {
-            current.tag_size = value;
+        if let Some(value) = self.pagination_items_gap {
+            current.pagination_items_gap = value;
         }
-        if let Some(value) = self.tag_padding_x {
-            current.tag_padding_x = value;
+        if let Some(value) = self.pagination_padding_x {
+            current.pagination_padding_x = value;
         }
-        if let Some(value) = self.tag_padding_y {
-            current.tag_padding_y = value;
+        if let Some(value) = self.pagination_padding_y {
+            current.pagination_padding_y = value;
         }
-        if let Some(value) = self.tag_gap {
-            current.tag_gap = value;
+        if let Some(value) = self.pagination_gap {
+            current.pagination_gap = value;
         }
-        if let Some(value) = self.tag_max_width {
-            current.tag_max_width = value;
+        if let Some(value) = self.virtualization_padding {
+            current.virtualization_padding = value;
         }
-        if let Some(value) = self.dropdown_anchor_offset {
-            current.dropdown_anchor_offset = value;
+        if let Some(value) = self.min_viewport_height {
+            current.min_viewport_height = value;
         }
         if let Some(value) = self.sizes {
             current.sizes = value;
         }
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
+        }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct ModalOverrides {
+pub struct StepperOverrides {
+    pub step_bg: Option<Hsla>,
+    pub step_border: Option<Hsla>,
+    pub step_fg: Option<Hsla>,
+    pub step_active_bg: Option<Hsla>,
+    pub step_active_border: Option<Hsla>,
+    pub step_active_fg: Option<Hsla>,
+    pub step_completed_bg: Option<Hsla>,
+    pub step_completed_border: Option<Hsla>,
+    pub step_completed_fg: Option<Hsla>,
+    pub connector: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub description: Option<Hsla>,
     pub panel_bg: Option<Hsla>,
     pub panel_border: Option<Hsla>,
-    pub overlay_bg: Option<Hsla>,
-    pub title: Option<Hsla>,
-    pub body: Option<Hsla>,
-    pub title_size: Option<Pixels>,
-    pub title_weight: Option<FontWeight>,
-    pub body_size: Option<Pixels>,
-    pub kind_icon_size: Option<Pixels>,
-    pub kind_icon_gap: Option<Pixels>,
-    pub panel_radius: Option<Pixels>,
-    pub panel_padding: Option<Pixels>,
-    pub header_margin_bottom: Option<Pixels>,
-    pub body_margin_bottom: Option<Pixels>,
-    pub actions_margin_top: Option<Pixels>,
-    pub actions_gap: Option<Pixels>,
-    pub close_size: Option<Pixels>,
-    pub close_icon_size: Option<Pixels>,
-    pub default_width: Option<Pixels>,
-    pub min_width: Option<Pixels>,
+    pub panel_fg: Option<Hsla>,
+    pub root_gap: Option<Pixels>,
+    pub steps_gap_vertical: Option<Pixels>,
+    pub text_gap: Option<Pixels>,
+    pub panel_margin_top: Option<Pixels>,
+    pub sizes: Option<StepperSizeScale>,
 }
 
-impl ModalOverrides {
-    fn apply(&self, mut current: ModalTokens) -> ModalTokens {
-        if let Some(value) = &self.panel_bg {
-            current.panel_bg = *value;
+impl StepperOverrides {
+    fn apply(&self, mut current: StepperTokens) -> StepperTokens {
+        if let Some(value) = &self.step_bg {
+            current.step_bg = *value;
         }
-        if let Some(value) = &self.panel_border {
-            current.panel_border = *value;
+        if let Some(value) = &self.step_border {
+            current.step_border = *value;
         }
-        if let Some(value) = &self.overlay_bg {
-            current.overlay_bg = *value;
+        if let Some(value) = &self.step_fg {
+            current.step_fg = *value;
         }
-        if let Some(value) = &self.title {
-            current.title = *value;
+        if let Some(value) = &self.step_active_bg {
+            current.step_active_bg = *value;
         }
-        if let Some(value) = &self.body {
-            current.body = *value;
+        if let Some(value) = &self.step_active_border {
+            current.step_active_border = *value;
         }
-        if let Some(value) = self.title_size {
-            current.title_size = value;
+        if let Some(value) = &self.step_active_fg {
+            current.step_active_fg = *value;
         }
-        if let Some(value) = self.title_weight {
-            current.title_weight = value;
+        if let Some(value) = &self.step_completed_bg {
+            current.step_completed_bg = *value;
         }
-        if let Some(value) = self.body_size {
-            current.body_size = value;
+        if let Some(value) = &self.step_completed_border {
+            current.step_completed_border = *value;
         }
-        if let Some(value) = self.kind_icon_size {
-            current.kind_icon_size = value;
+        if let Some(value) = &self.step_completed_fg {
+            current.step_completed_fg = *value;
         }
-        if let Some(value) = self.kind_icon_gap {
-            current.kind_icon_gap = value;
+        if let Some(value) = &self.connector {
+            current.connector = *value;
         }
-        if let Some(value) = self.panel_radius {
-            current.panel_radius = value;
+        if let Some(value) = &self.label {
+            current.label = *value;
         }
-        if let Some(value) = self.panel_padding {
-            current.panel_padding = value;
+        if let Some(value) = &self.description {
+            current.description = *value;
         }
-        if let Some(value) = self.header_margin_bottom {
-            current.header_margin_bottom = value;
+        if let Some(value) = &self.panel_bg {
+            current.panel_bg = *value;
         }
-        if let Some(value) = self.body_margin_bottom {
-            current.body_margin_bottom = value;
+        if let Some(value) = &self.panel_border {
+            current.panel_border = *value;
         }
-        if let Some(value) = self.actions_margin_top {
-            current.actions_margin_top = value;
+        if let Some(value) = &self.panel_fg {
+            current.panel_fg = *value;
         }
-        if let Some(value) = self.actions_gap {
-            current.actions_gap = value;
+        if let Some(value) = self.root_gap {
+            current.root_gap = value;
         }
-        if let Some(value) = self.close_size {
-            current.close_size = value;
+        if let Some(value) = self.steps_gap_vertical {
+            current.steps_gap_vertical = value;
         }
-        if let Some(value) = self.close_icon_size {
-            current.close_icon_size = value;
+        if let Some(value) = self.text_gap {
+            current.text_gap = value;
         }
-        if let Some(value) = self.default_width {
-            current.default_width = value;
+        if let Some(value) = self.panel_margin_top {
+            current.panel_margin_top = value;
         }
-        if let Some(value) = self.min_width {
-            current.min_width = value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct ToastOverrides {
-    pub info_bg: Option<Hsla>,
-    pub info_fg: Option<Hsla>,
-    pub success_bg: Option<Hsla>,
-    pub success_fg: Option<Hsla>,
-    pub warning_bg: Option<Hsla>,
-    pub warning_fg: Option<Hsla>,
-    pub error_bg: Option<Hsla>,
-    pub error_fg: Option<Hsla>,
-    pub card_width: Option<Pixels>,
-    pub card_padding: Option<Pixels>,
+pub struct TimelineOverrides {
+    pub bullet_bg: Option<Hsla>,
+    pub bullet_border: Option<Hsla>,
+    pub bullet_fg: Option<Hsla>,
+    pub bullet_active_bg: Option<Hsla>,
+    pub bullet_active_border: Option<Hsla>,
+    pub bullet_active_fg: Option<Hsla>,
+    pub line: Option<Hsla>,
+    pub line_active: Option<Hsla>,
+    pub title: Option<Hsla>,
+    pub title_active: Option<Hsla>,
+    pub body: Option<Hsla>,
+    pub card_bg: Option<Hsla>,
+    pub card_border: Option<Hsla>,
+    pub root_gap: Option<Pixels>,
     pub row_gap: Option<Pixels>,
     pub content_gap: Option<Pixels>,
-    pub icon_box_size: Option<Pixels>,
-    pub icon_size: Option<Pixels>,
-    pub close_button_size: Option<Pixels>,
-    pub close_icon_size: Option<Pixels>,
-    pub title_size: Option<Pixels>,
-    pub body_size: Option<Pixels>,
-    pub stack_gap: Option<Pixels>,
-    pub edge_offset: Option<Pixels>,
-    pub top_offset_extra: Option<Pixels>,
+    pub card_margin_top: Option<Pixels>,
+    pub row_padding_y: Option<Pixels>,
+    pub line_min_height: Option<Pixels>,
+    pub line_extra_height: Option<Pixels>,
+    pub sizes: Option<TimelineSizeScale>,
 }
 
-impl ToastOverrides {
-    fn apply(&self, mut current: ToastTokens) -> ToastTokens {
-        if let Some(value) = &self.info_bg {
-            current.info_bg = *value;
+impl TimelineOverrides {
+    fn apply(&self, mut current: TimelineTokens) -> TimelineTokens {
+        if let Some(value) = &self.bullet_bg {
+            current.bullet_bg = *value;
         }
-        if let Some(value) = &self.info_fg {
-            current.info_fg = *value;
+        if let Some(value) = &self.bullet_border {
+            current.bullet_border = *value;
         }
-        if let Some(value) = &self.success_bg {
-            current.success_bg = *value;
+        if let Some(value) = &self.bullet_fg {
+            current.bullet_fg = *value;
         }
-        if let Some(value) = &self.success_fg {
-            current.success_fg = *value;
+        if let Some(value) = &self.bullet_active_bg {
+            current.bullet_active_bg = *value;
         }
-        if let Some(value) = &self.warning_bg {
-            current.warning_bg = *value;
+        if let Some(value) = &self.bullet_active_border {
+            current.bullet_active_border = *value;
         }
-        if let Some(value) = &self.warning_fg {
-            current.warning_fg = *value;
+        if let Some(value) = &self.bullet_active_fg {
+            current.bullet_active_fg = *value;
         }
-        if let Some(value) = &self.error_bg {
-            current.error_bg = *value;
+        if let Some(value) = &self.line {
+            current.line = *value;
         }
-        if let Some(value) = &self.error_fg {
-            current.error_fg = *value;
+        if let Some(value) = &self.line_active {
+            current.line_active = *value;
         }
-        if let Some(value) = self.card_width {
-            current.card_width = value;
+        if let Some(value) = &self.title {
+            current.title = *value;
         }
-        if let Some(value) = self.card_padding {
-            current.card_padding = value;
+        if let Some(value) = &self.title_active {
+            current.title_active = *value;
         }
-        if let Some(value) = self.row_gap {
-            current.row_gap = value;
+        if let Some(value) = &self.body {
+            current.body = *value;
         }
-        if let Some(value) = self.content_gap {
-            current.content_gap = value;
+        if let Some(value) = &self.card_bg {
+            current.card_bg = *value;
         }
-        if let Some(value) = self.icon_box_size {
-            current.icon_box_size = value;
+        if let Some(value) = &self.card_border {
+            current.card_border = *value;
         }
-        if let Some(value) = self.icon_size {
-            current.icon_size = value;
+        if let Some(value) = self.root_gap {
+            current.root_gap = value;
         }
-        if let Some(value) = self.close_button_size {
-            current.close_button_size = value;
+        if let Some(value) = self.row_gap {
+            current.row_gap = value;
         }
-        if let Some(value) = self.close_icon_size {
-            current.close_icon_size = value;
+        if let Some(value) = self.content_gap {
+            current.content_gap = value;
         }
-        if let Some(value) = self.title_size {
-            current.title_size = value;
+        if let Some(value) = self.card_margin_top {
+            current.card_margin_top = value;
         }
-        if let Some(value) = self.body_size {
-            current.body_size = value;
+        if let Some(value) = self.row_padding_y {
+            current.row_padding_y = value;
         }
-        if let Some(value) = self.stack_gap {
-            current.stack_gap = value;
+        if let Some(value) = self.line_min_height {
+            current.line_min_height = value;
         }
-        if let Some(value) = self.edge_offset {
-            current.edge_offset = value;
+        if let Some(value) = self.line_extra_height {
+            current.line_extra_height = value;
         }
-        if let Some(value) = self.top_offset_extra {
-            current.top_offset_extra = value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct DividerOverrides {
+pub struct TreeOverrides {
+    pub row_fg: Option<Hsla>,
+    pub row_selected_fg: Option<Hsla>,
+    pub row_selected_bg: Option<Hsla>,
+    pub row_hover_bg: Option<Hsla>,
+    pub row_disabled_fg: Option<Hsla>,
     pub line: Option<Hsla>,
-    pub line_width: Option<Pixels>,
-    pub label: Option<Hsla>,
-    pub label_size: Option<Pixels>,
-    pub label_gap: Option<Pixels>,
-    pub edge_span: Option<Pixels>,
+    pub drop_indicator: Option<Hsla>,
+    pub root_gap: Option<Pixels>,
+    pub children_gap: Option<Pixels>,
+    pub sizes: Option<TreeSizeScale>,
 }
 
-impl DividerOverrides {
-    fn apply(&self, mut current: DividerTokens) -> DividerTokens {
+impl TreeOverrides {
+    fn apply(&self, mut current: TreeTokens) -> TreeTokens {
+        if let Some(value) = &self.row_fg {
+            current.row_fg = *value;
+        }
+        if let Some(value) = &self.row_selected_fg {
+            current.row_selected_fg = *value;
+        }
+        if let Some(value) = &self.row_selected_bg {
+            current.row_selected_bg = *value;
+        }
+        if let Some(value) = &self.row_hover_bg {
+            current.row_hover_bg = *value;
+        }
+        if let Some(value) = &self.row_disabled_fg {
+            current.row_disabled_fg = *value;
+        }
         if let Some(value) = &self.line {
             current.line = *value;
         }
-        if let Some(value) = self.line_width {
-            current.line_width = value;
-        }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = &self.drop_indicator {
+            current.drop_indicator = *value;
         }
-        if let Some(value) = self.label_size {
-            current.label_size = value;
+        if let Some(value) = self.root_gap {
+            current.root_gap = value;
         }
-        if let Some(value) = self.label_gap {
-            current.label_gap = value;
+        if let Some(value) = self.children_gap {
+            current.children_gap = value;
         }
-        if let Some(value) = self.edge_span {
-            current.edge_span = value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct ScrollAreaOverrides {
+pub struct ZoomPaneOverrides {
     pub bg: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub padding: Option<InsetSizeScale>,
+    pub grid_line: Option<Hsla>,
+    pub grid_line_major: Option<Hsla>,
+    pub ruler_bg: Option<Hsla>,
+    pub ruler_tick: Option<Hsla>,
+    pub ruler_size: Option<Pixels>,
+    pub grid_spacing: Option<Pixels>,
 }
 
-impl ScrollAreaOverrides {
-    fn apply(&self, mut current: ScrollAreaTokens) -> ScrollAreaTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+impl ZoomPaneOverrides {
+    fn apply(&self, mut current: ZoomPaneTokens) -> ZoomPaneTokens {
+        if let Some(value) = self.bg {
+            current.bg = value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = self.grid_line {
+            current.grid_line = value;
         }
-        if let Some(value) = self.padding {
-            current.padding = value;
+        if let Some(value) = self.grid_line_major {
+            current.grid_line_major = value;
+        }
+        if let Some(value) = self.ruler_bg {
+            current.ruler_bg = value;
+        }
+        if let Some(value) = self.ruler_tick {
+            current.ruler_tick = value;
+        }
+        if let Some(value) = self.ruler_size {
+            current.ruler_size = value;
+        }
+        if let Some(value) = self.grid_spacing {
+            current.grid_spacing = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct DrawerOverrides {
-    pub panel_bg: Option<Hsla>,
-    pub panel_border: Option<Hsla>,
-    pub overlay_bg: Option<Hsla>,
-    pub title: Option<Hsla>,
-    pub body: Option<Hsla>,
-    pub title_size: Option<Pixels>,
-    pub title_weight: Option<FontWeight>,
-    pub body_size: Option<Pixels>,
-    pub panel_padding: Option<Pixels>,
-    pub panel_radius: Option<Pixels>,
-    pub header_margin_bottom: Option<Pixels>,
-    pub close_size: Option<Pixels>,
-    pub close_icon_size: Option<Pixels>,
+pub struct GraphCanvasOverrides {
+    pub node_bg: Option<Hsla>,
+    pub node_border: Option<Hsla>,
+    pub node_selected_border: Option<Hsla>,
+    pub node_header_bg: Option<Hsla>,
+    pub node_fg: Option<Hsla>,
+    pub port_bg: Option<Hsla>,
+    pub port_border: Option<Hsla>,
+    pub edge: Option<Hsla>,
+    pub edge_selected: Option<Hsla>,
+    pub minimap_bg: Option<Hsla>,
+    pub minimap_node: Option<Hsla>,
+    pub minimap_viewport: Option<Hsla>,
+    pub node_radius: Option<Pixels>,
+    pub edge_width: Option<Pixels>,
+    pub port_size: Option<Pixels>,
+    pub label_size: Option<Pixels>,
+    pub minimap_width: Option<Pixels>,
+    pub minimap_height: Option<Pixels>,
 }
 
-impl DrawerOverrides {
-    fn apply(&self, mut current: DrawerTokens) -> DrawerTokens {
-        if let Some(value) = &self.panel_bg {
-            current.panel_bg = *value;
+impl GraphCanvasOverrides {
+    fn apply(&self, mut current: GraphCanvasTokens) -> GraphCanvasTokens {
+        if let Some(value) = self.node_bg {
+            current.node_bg = value;
         }
-        if let Some(value) = &self.panel_border {
-            current.panel_border = *value;
+        if let Some(value) = self.node_border {
+            current.node_border = value;
         }
-        if let Some(value) = &self.overlay_bg {
-            current.overlay_bg = *value;
+        if let Some(value) = self.node_selected_border {
+            current.node_selected_border = value;
         }
-        if let Some(value) = &self.title {
-            current.title = *value;
+        if let Some(value) = self.node_header_bg {
+            current.node_header_bg = value;
         }
-        if let Some(value) = &self.body {
-            current.body = *value;
+        if let Some(value) = self.node_fg {
+            current.node_fg = value;
         }
-        if let Some(value) = self.title_size {
-            current.title_size = value;
+        if let Some(value) = self.port_bg {
+            current.port_bg = value;
         }
-        if let Some(value) = self.title_weight {
-            current.title_weight = value;
+        if let Some(value) = self.port_border {
+            current.port_border = value;
         }
-        if let Some(value) = self.body_size {
-            current.body_size = value;
+        if let Some(value) = self.edge {
+            current.edge = value;
         }
-        if let Some(value) = self.panel_padding {
-            current.panel_padding = value;
+        if let Some(value) = self.edge_selected {
+            current.edge_selected = value;
         }
-        if let Some(value) = self.panel_radius {
-            current.panel_radius = value;
+        if let Some(value) = self.minimap_bg {
+            current.minimap_bg = value;
         }
-        if let Some(value) = self.header_margin_bottom {
-            current.header_margin_bottom = value;
+        if let Some(value) = self.minimap_node {
+            current.minimap_node = value;
         }
-        if let Some(value) = self.close_size {
-            current.close_size = value;
+        if let Some(value) = self.minimap_viewport {
+            current.minimap_viewport = value;
         }
-        if let Some(value) = self.close_icon_size {
-            current.close_icon_size = value;
+        if let Some(value) = self.node_radius {
+            current.node_radius = value;
+        }
+        if let Some(value) = self.edge_width {
+            current.edge_width = value;
+        }
+        if let Some(value) = self.port_size {
+            current.port_size = value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        if let Some(value) = self.minimap_width {
+            current.minimap_width = value;
+        }
+        if let Some(value) = self.minimap_height {
+            current.minimap_height = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct AppShellOverrides {
+pub struct DatePickerOverrides {
     pub bg: Option<Hsla>,
-    pub title_bar_bg: Option<Hsla>,
-    pub sidebar_bg: Option<Hsla>,
-    pub sidebar_overlay_bg: Option<Hsla>,
-    pub content_bg: Option<Hsla>,
-    pub bottom_panel_bg: Option<Hsla>,
-    pub inspector_bg: Option<Hsla>,
-    pub inspector_overlay_bg: Option<Hsla>,
-    pub region_border: Option<Hsla>,
-    pub title_bar_height: Option<Pixels>,
-    pub sidebar_width: Option<Pixels>,
-    pub sidebar_min_width: Option<Pixels>,
-    pub inspector_width: Option<Pixels>,
-    pub inspector_min_width: Option<Pixels>,
-    pub bottom_panel_height: Option<Pixels>,
-    pub bottom_panel_min_height: Option<Pixels>,
+    pub fg: Option<Hsla>,
+    pub placeholder: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub border_focus: Option<Hsla>,
+    pub border_error: Option<Hsla>,
+    pub icon: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub label_size: Option<Pixels>,
+    pub label_weight: Option<FontWeight>,
+    pub description: Option<Hsla>,
+    pub description_size: Option<Pixels>,
+    pub error: Option<Hsla>,
+    pub error_size: Option<Pixels>,
+    pub label_block_gap: Option<Pixels>,
+    pub label_row_gap: Option<Pixels>,
+    pub layout_gap_vertical: Option<Pixels>,
+    pub layout_gap_horizontal: Option<Pixels>,
+    pub horizontal_label_width: Option<Pixels>,
+    pub slot_gap: Option<Pixels>,
+    pub icon_size: Option<Pixels>,
+    pub dropdown_bg: Option<Hsla>,
+    pub dropdown_border: Option<Hsla>,
+    pub dropdown_padding: Option<Pixels>,
+    pub dropdown_anchor_offset: Option<Pixels>,
+    pub dropdown_open_preferred_height: Option<Pixels>,
+    pub header_fg: Option<Hsla>,
+    pub header_size: Option<Pixels>,
+    pub nav_hover_bg: Option<Hsla>,
+    pub weekday_fg: Option<Hsla>,
+    pub weekday_size: Option<Pixels>,
+    pub day_fg: Option<Hsla>,
+    pub day_outside_fg: Option<Hsla>,
+    pub day_hover_bg: Option<Hsla>,
+    pub day_selected_bg: Option<Hsla>,
+    pub day_selected_fg: Option<Hsla>,
+    pub day_today_border: Option<Hsla>,
+    pub day_size: Option<Pixels>,
+    pub day_cell_size: Option<Pixels>,
+    pub grid_gap: Option<Pixels>,
+    pub time_column_width: Option<Pixels>,
+    pub time_item_height: Option<Pixels>,
+    pub time_item_size: Option<Pixels>,
+    pub sizes: Option<FieldSizeScale>,
+    pub states: Option<PseudoStateTokens>,
 }
 
-impl AppShellOverrides {
-    fn apply(&self, mut current: AppShellTokens) -> AppShellTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+impl DatePickerOverrides {
+    fn apply(&self, mut current: DatePickerTokens) -> DatePickerTokens {
+        if let Some(value) = self.bg {
+            current.bg = value;
         }
-        if let Some(value) = &self.title_bar_bg {
-            current.title_bar_bg = *value;
+        if let Some(value) = self.fg {
+            current.fg = value;
         }
-        if let Some(value) = &self.sidebar_bg {
-            current.sidebar_bg = *value;
+        if let Some(value) = self.placeholder {
+            current.placeholder = value;
         }
-        if let Some(value) = &self.sidebar_overlay_bg {
-            current.sidebar_overlay_bg = *value;
+        if let Some(value) = self.border {
+            current.border = value;
         }
-        if let Some(value) = &self.content_bg {
-            current.content_bg = *value;
+        if let Some(value) = self.border_focus {
+            current.border_focus = value;
+        }
+        if let Some(value) = self.border_error {
+            current.border_error = value;
+        }
+        if let Some(value) = self.icon {
+            current.icon = value;
+        }
+        if let Some(value) = self.label {
+            current.label = value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        if let Some(value) = self.label_weight {
+            current.label_weight = value;
+        }
+        if let Some(value) = self.description {
+            current.description = value;
+        }
+        if let Some(value) = self.description_size {
+            current.description_size = value;
+        }
+        if let Some(value) = self.error {
+            current.error = value;
+        }
+        if let Some(value) = self.error_size {
+            current.error_size = value;
+        }
+        if let Some(value) = self.label_block_gap {
+            current.label_block_gap = value;
+        }
+        if let Some(value) = self.label_row_gap {
+            current.label_row_gap = value;
+        }
+        if let Some(value) = self.layout_gap_vertical {
+            current.layout_gap_vertical = value;
+        }
+        if let Some(value) = self.layout_gap_horizontal {
+            current.layout_gap_horizontal = value;
+        }
+        if let Some(value) = self.horizontal_label_width {
+            current.horizontal_label_width = value;
+        }
+        if let Some(value) = self.slot_gap {
+            current.slot_gap = value;
+        }
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
+        }
+        if let Some(value) = self.dropdown_bg {
+            current.dropdown_bg = value;
+        }
+        if let Some(value) = self.dropdown_border {
+            current.dropdown_border = value;
+        }
+        if let Some(value) = self.dropdown_padding {
+            current.dropdown_padding = value;
+        }
+        if let Some(value) = self.dropdown_anchor_offset {
+            current.dropdown_anchor_offset = value;
+        }
+        if let Some(value) = self.dropdown_open_preferred_height {
+            current.dropdown_open_preferred_height = value;
+        }
+        if let Some(value) = self.header_fg {
+            current.header_fg = value;
+        }
+        if let Some(value) = self.header_size {
+            current.header_size = value;
+        }
+        if let Some(value) = self.nav_hover_bg {
+            current.nav_hover_bg = value;
+        }
+        if let Some(value) = self.weekday_fg {
+            current.weekday_fg = value;
+        }
+        if let Some(value) = self.weekday_size {
+            current.weekday_size = value;
+        }
+        if let Some(value) = self.day_fg {
+            current.day_fg = value;
+        }
+        if let Some(value) = self.day_outside_fg {
+            current.day_outside_fg = value;
+        }
+        if let Some(value) = self.day_hover_bg {
+            current.day_hover_bg = value;
         }
-        if let Some(value) = &self.bottom_panel_bg {
-            current.bottom_panel_bg = *value;
+        if let Some(value) = self.day_selected_bg {
+            current.day_selected_bg = value;
         }
-        if let Some(value) = &self.inspector_bg {
-            current.inspector_bg = *value;
+        if let Some(value) = self.day_selected_fg {
+            current.day_selected_fg = value;
         }
-        if let Some(value) = &self.inspector_overlay_bg {
-            current.inspector_overlay_bg = *value;
+        if let Some(value) = self.day_today_border {
+            current.day_today_border = value;
         }
-        if let Some(value) = &self.region_border {
-            current.region_border = *value;
+        if let Some(value) = self.day_size {
+            current.day_size = value;
         }
-        if let Some(value) = self.title_bar_height {
-            current.title_bar_height = value;
+        if let Some(value) = self.day_cell_size {
+            current.day_cell_size = value;
         }
-        if let Some(value) = self.sidebar_width {
-            current.sidebar_width = value;
+        if let Some(value) = self.grid_gap {
+            current.grid_gap = value;
         }
-        if let Some(value) = self.sidebar_min_width {
-            current.sidebar_min_width = value;
+        if let Some(value) = self.time_column_width {
+            current.time_column_width = value;
         }
-        if let Some(value) = self.inspector_width {
-            current.inspector_width = value;
+        if let Some(value) = self.time_item_height {
+            current.time_item_height = value;
         }
-        if let Some(value) = self.inspector_min_width {
-            current.inspector_min_width = value;
+        if let Some(value) = self.time_item_size {
+            current.time_item_size = value;
         }
-        if let Some(value) = self.bottom_panel_height {
-            current.bottom_panel_height = value;
+        if let Some(value) = self.sizes {
+            current.sizes = value;
         }
-        if let Some(value) = self.bottom_panel_min_height {
-            current.bottom_panel_min_height = value;
+        if let Some(value) = self.states {
+            current.states = current.states.merged(value);
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TitleBarOverrides {
+pub struct RulersOverrides {
     pub bg: Option<Hsla>,
     pub border: Option<Hsla>,
-    pub fg: Option<Hsla>,
-    pub controls_bg: Option<Hsla>,
-    pub height: Option<Pixels>,
-    pub title_size: Option<Pixels>,
-    pub title_weight: Option<FontWeight>,
-    pub windows_button_width: Option<Pixels>,
-    pub windows_icon_size: Option<Pixels>,
-    pub linux_button_width: Option<Pixels>,
-    pub linux_button_height: Option<Pixels>,
-    pub linux_buttons_gap: Option<Pixels>,
-    pub macos_controls_reserve: Option<Pixels>,
-    pub title_padding_right: Option<Pixels>,
-    pub title_max_width: Option<Pixels>,
-    pub title_min_width: Option<Pixels>,
-    pub platform_padding_left: Option<Pixels>,
-    pub platform_padding_right: Option<Pixels>,
-    pub controls_slot_gap: Option<Pixels>,
-    pub control_button_radius: Option<Pixels>,
+    pub tick: Option<Hsla>,
+    pub tick_major: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub label_size: Option<Pixels>,
+    pub size: Option<Pixels>,
+    pub spacing: Option<Pixels>,
+    pub guide: Option<Hsla>,
+    pub guide_snapped: Option<Hsla>,
+    pub guide_hit_width: Option<Pixels>,
 }
 
-impl TitleBarOverrides {
-    fn apply(&self, mut current: TitleBarTokens) -> TitleBarTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
-        }
-        if let Some(value) = &self.border {
-            current.border = *value;
-        }
-        if let Some(value) = &self.fg {
-            current.fg = *value;
-        }
-        if let Some(value) = &self.controls_bg {
-            current.controls_bg = *value;
-        }
-        if let Some(value) = self.height {
-            current.height = value;
-        }
-        if let Some(value) = self.title_size {
-            current.title_size = value;
+impl RulersOverrides {
+    fn apply(&self, mut current: RulersTokens) -> RulersTokens {
+        if let Some(value) = self.bg {
+            current.bg = value;
         }
-        if let Some(value) = self.title_weight {
-            current.title_weight = value;
-        }
-        if let Some(value) = self.windows_button_width {
-            current.windows_button_width = value;
-        }
-        if let Some(value) = self.windows_icon_size {
-            current.windows_icon_size = value;
+        if let Some(value) = self.border {
+            current.border = value;
         }
-        if let Some(value) = self.linux_button_width {
-            current.linux_button_width = value;
-        }
-        if let Some(value) = self.linux_button_height {
-            current.linux_button_height = value;
-        }
-        if let Some(value) = self.linux_buttons_gap {
-            current.linux_buttons_gap = value;
+        if let Some(value) = self.tick {
+            current.tick = value;
         }
-        if let Some(value) = self.macos_controls_reserve {
-            current.macos_controls_reserve = value;
+        if let Some(value) = self.tick_major {
+            current.tick_major = value;
         }
-        if let Some(value) = self.title_padding_right {
-            current.title_padding_right = value;
+        if let Some(value) = self.label {
+            current.label = value;
         }
-        if let Some(value) = self.title_max_width {
-            current.title_max_width = value;
+        if let Some(value) = self.label_size {
+            current.label_size = value;
         }
-        if let Some(value) = self.title_min_width {
-            current.title_min_width = value;
+        if let Some(value) = self.size {
+            current.size = value;
         }
-        if let Some(value) = self.platform_padding_left {
-            current.platform_padding_left = value;
+        if let Some(value) = self.spacing {
+            current.spacing = value;
         }
-        if let Some(value) = self.platform_padding_right {
-            current.platform_padding_right = value;
+        if let Some(value) = self.guide {
+            current.guide = value;
         }
-        if let Some(value) = self.controls_slot_gap {
-            current.controls_slot_gap = value;
+        if let Some(value) = self.guide_snapped {
+            current.guide_snapped = value;
         }
-        if let Some(value) = self.control_button_radius {
-            current.control_button_radius = value;
+        if let Some(value) = self.guide_hit_width {
+            current.guide_hit_width = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct SidebarOverrides {
+pub struct ColorPickerOverrides {
     pub bg: Option<Hsla>,
     pub border: Option<Hsla>,
-    pub header_fg: Option<Hsla>,
-    pub content_fg: Option<Hsla>,
-    pub footer_fg: Option<Hsla>,
-    pub inline_radius: Option<Pixels>,
-    pub overlay_radius: Option<Pixels>,
-    pub min_width: Option<Pixels>,
-    pub section_padding: Option<Pixels>,
-    pub footer_size: Option<Pixels>,
-    pub scroll_padding: Option<Size>,
+    pub padding: Option<Pixels>,
+    pub gap: Option<Pixels>,
+    pub area_height: Option<Pixels>,
+    pub slider_height: Option<Pixels>,
+    pub thumb_size: Option<Pixels>,
+    pub thumb_border: Option<Hsla>,
+    pub checker: Option<Hsla>,
+    pub preview_size: Option<Pixels>,
+    pub swatch_size: Option<Pixels>,
+    pub swatch_gap: Option<Pixels>,
+    pub swatch_border: Option<Hsla>,
+    pub swatch_selected_border: Option<Hsla>,
 }
 
-impl SidebarOverrides {
-    fn apply(&self, mut current: SidebarTokens) -> SidebarTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+impl ColorPickerOverrides {
+    fn apply(&self, mut current: ColorPickerTokens) -> ColorPickerTokens {
+        if let Some(value) = self.bg {
+            current.bg = value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = self.border {
+            current.border = value;
         }
-        if let Some(value) = &self.header_fg {
-            current.header_fg = *value;
+        if let Some(value) = self.padding {
+            current.padding = value;
         }
-        if let Some(value) = &self.content_fg {
-            current.content_fg = *value;
+        if let Some(value) = self.gap {
+            current.gap = value;
         }
-        if let Some(value) = &self.footer_fg {
-            current.footer_fg = *value;
+        if let Some(value) = self.area_height {
+            current.area_height = value;
         }
-        if let Some(value) = self.inline_radius {
-            current.inline_radius = value;
+        if let Some(value) = self.slider_height {
+            current.slider_height = value;
         }
-        if let Some(value) = self.overlay_radius {
-            current.overlay_radius = value;
+        if let Some(value) = self.thumb_size {
+            current.thumb_size = value;
         }
-        if let Some(value) = self.min_width {
-            current.min_width = value;
+        if let Some(value) = self.thumb_border {
+            current.thumb_border = value;
         }
-        if let Some(value) = self.section_padding {
-            current.section_padding = value;
+        if let Some(value) = self.checker {
+            current.checker = value;
         }
-        if let Some(value) = self.footer_size {
-            current.footer_size = value;
+        if let Some(value) = self.preview_size {
+            current.preview_size = value;
         }
-        if let Some(value) = self.scroll_padding {
-            current.scroll_padding = value;
+        if let Some(value) = self.swatch_size {
+            current.swatch_size = value;
+        }
+        if let Some(value) = self.swatch_gap {
+            current.swatch_gap = value;
+        }
+        if let Some(value) = self.swatch_border {
+            current.swatch_border = value;
+        }
+        if let Some(value) = self.swatch_selected_border {
+            current.swatch_selected_border = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct MarkdownOverrides {
-    pub paragraph: Option<Hsla>,
-    pub paragraph_muted: Option<Hsla>,
-    pub heading: Option<Hsla>,
-    pub heading2_border: Option<Hsla>,
-    pub quote_bg: Option<Hsla>,
-    pub quote_border: Option<Hsla>,
-    pub quote_fg: Option<Hsla>,
-    pub code_bg: Option<Hsla>,
-    pub code_border: Option<Hsla>,
-    pub code_fg: Option<Hsla>,
-    pub code_lang_fg: Option<Hsla>,
-    pub link: Option<Hsla>,
-    pub link_hover: Option<Hsla>,
-    pub strong: Option<Hsla>,
-    pub em: Option<Hsla>,
-    pub del: Option<Hsla>,
-    pub inline_code_bg: Option<Hsla>,
-    pub inline_code_border: Option<Hsla>,
-    pub inline_code_fg: Option<Hsla>,
-    pub kbd_bg: Option<Hsla>,
-    pub kbd_border: Option<Hsla>,
-    pub kbd_fg: Option<Hsla>,
-    pub mark_bg: Option<Hsla>,
-    pub mark_fg: Option<Hsla>,
-    pub list_marker: Option<Hsla>,
-    pub rule: Option<Hsla>,
-    pub table_border: Option<Hsla>,
-    pub table_header_bg: Option<Hsla>,
-    pub table_header_fg: Option<Hsla>,
-    pub table_row_alt_bg: Option<Hsla>,
-    pub table_cell_fg: Option<Hsla>,
-    pub task_border: Option<Hsla>,
-    pub task_bg: Option<Hsla>,
-    pub task_checked_bg: Option<Hsla>,
-    pub task_checked_fg: Option<Hsla>,
-    pub details_bg: Option<Hsla>,
-    pub details_border: Option<Hsla>,
-    pub details_summary_fg: Option<Hsla>,
-    pub details_body_fg: Option<Hsla>,
-    pub image_border: Option<Hsla>,
-    pub image_bg: Option<Hsla>,
-    pub image_caption_fg: Option<Hsla>,
-    pub gap_regular: Option<Pixels>,
-    pub gap_compact: Option<Pixels>,
-    pub paragraph_size: Option<Pixels>,
-    pub paragraph_line_height: Option<Pixels>,
-    pub quote_size: Option<Pixels>,
-    pub quote_line_height: Option<Pixels>,
-    pub code_size: Option<Pixels>,
-    pub code_line_height: Option<Pixels>,
-    pub code_lang_size: Option<Pixels>,
-    pub list_size: Option<Pixels>,
-    pub list_line_height: Option<Pixels>,
-    pub table_size: Option<Pixels>,
-    pub image_caption_size: Option<Pixels>,
-    pub quote_padding_x: Option<Pixels>,
-    pub quote_padding_y: Option<Pixels>,
-    pub quote_radius: Option<Pixels>,
-    pub quote_gap: Option<Pixels>,
-    pub code_padding: Option<Pixels>,
-    pub code_radius: Option<Pixels>,
-    pub code_gap: Option<Pixels>,
-    pub inline_code_radius: Option<Pixels>,
-    pub kbd_radius: Option<Pixels>,
-    pub list_gap: Option<Pixels>,
-    pub list_item_gap: Option<Pixels>,
-    pub list_indent: Option<Pixels>,
-    pub table_radius: Option<Pixels>,
-    pub table_cell_padding_x: Option<Pixels>,
-    pub table_cell_padding_y: Option<Pixels>,
-    pub details_radius: Option<Pixels>,
-    pub details_padding_x: Option<Pixels>,
-    pub details_padding_y: Option<Pixels>,
-    pub image_radius: Option<Pixels>,
-    pub image_padding: Option<Pixels>,
-    pub image_gap: Option<Pixels>,
-    pub heading2_padding_top: Option<Pixels>,
+pub struct FileInputOverrides {
+    pub bg: Option<Hsla>,
+    pub bg_active: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub border_active: Option<Hsla>,
+    pub border_error: Option<Hsla>,
+    pub fg: Option<Hsla>,
+    pub hint: Option<Hsla>,
+    pub icon: Option<Hsla>,
+    pub radius: Option<Pixels>,
+    pub padding: Option<Pixels>,
+    pub gap: Option<Pixels>,
+    pub icon_size: Option<Pixels>,
+    pub text_size: Option<Pixels>,
+    pub hint_size: Option<Pixels>,
+    pub chip_bg: Option<Hsla>,
+    pub chip_border: Option<Hsla>,
+    pub chip_fg: Option<Hsla>,
+    pub chip_meta: Option<Hsla>,
+    pub chip_remove_hover_bg: Option<Hsla>,
+    pub chip_size: Option<Pixels>,
+    pub chip_padding_x: Option<Pixels>,
+    pub chip_padding_y: Option<Pixels>,
+    pub chip_gap: Option<Pixels>,
+    pub chip_max_width: Option<Pixels>,
+    pub label: Option<Hsla>,
+    pub label_size: Option<Pixels>,
+    pub label_weight: Option<FontWeight>,
+    pub description: Option<Hsla>,
+    pub description_size: Option<Pixels>,
+    pub error: Option<Hsla>,
+    pub error_size: Option<Pixels>,
+    pub label_block_gap: Option<Pixels>,
+    pub label_row_gap: Option<Pixels>,
+    pub layout_gap_vertical: Option<Pixels>,
+    pub layout_gap_horizontal: Option<Pixels>,
+    pub horizontal_label_width: Option<Pixels>,
 }
 
-impl MarkdownOverrides {
-    fn apply(&self, mut current: MarkdownTokens) -> MarkdownTokens {
-        if let Some(value) = &self.paragraph {
-            current.paragraph = *value;
+impl FileInputOverrides {
+    fn apply(&self, mut current: FileInputTokens) -> FileInputTokens {
+        if let Some(value) = self.bg {
+            current.bg = value;
+        }
+        if let Some(value) = self.bg_active {
+            current.bg_active = value;
+        }
+        if let Some(value) = self.border {
+            current.border = value;
+        }
+        if let Some(value) = self.border_active {
+            current.border_active = value;
+        }
+        if let Some(value) = self.border_error {
+            current.border_error = value;
+        }
+        if let Some(value) = self.fg {
+            current.fg = value;
+        }
+        if let Some(value) = self.hint {
+            current.hint = value;
+        }
+        if let Some(value) = self.icon {
+            current.icon = value;
+        }
+        if let Some(value) = self.radius {
+            current.radius = value;
         }
-        if let Some(value) = &self.paragraph_muted {
-            current.paragraph_muted = *value;
+        if let Some(value) = self.padding {
+            current.padding = value;
         }
-        if let Some(value) = &self.heading {
-            current.heading = *value;
+        if let Some(value) = self.gap {
+            current.gap = value;
         }
-        if let Some(value) = &self.heading2_border {
-            current.heading2_border = *value;
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
         }
-        if let Some(value) = &self.quote_bg {
-            current.quote_bg = *value;
+        if let Some(value) = self.text_size {
+            current.text_size = value;
         }
-        if let Some(value) = &self.quote_border {
-            current.quote_border = *value;
+        if let Some(value) = self.hint_size {
+            current.hint_size = value;
         }
-        if let Some(value) = &self.quote_fg {
-            current.quote_fg = *value;
+        if let Some(value) = self.chip_bg {
+            current.chip_bg = value;
         }
-        if let Some(value) = &self.code_bg {
-            current.code_bg = *value;
+        if let Some(value) = self.chip_border {
+            current.chip_border = value;
         }
-        if let Some(value) = &self.code_border {
-            current.code_border = *value;
+        if let Some(value) = self.chip_fg {
+            current.chip_fg = value;
         }
-        if let Some(value) = &self.code_fg {
-            current.code_fg = *value;
+        if let Some(value) = self.chip_meta {
+            current.chip_meta = value;
         }
-        if let Some(value) = &self.code_lang_fg {
-            current.code_lang_fg = *value;
+        if let Some(value) = self.chip_remove_hover_bg {
+            current.chip_remove_hover_bg = value;
         }
-        if let Some(value) = &self.link {
-            current.link = *value;
+        if let Some(value) = self.chip_size {
+            current.chip_size = value;
         }
-        if let Some(value) = &self.link_hover {
-            current.link_hover = *value;
+        if let Some(value) = self.chip_padding_x {
+            current.chip_padding_x = value;
         }
-        if let Some(value) = &self.strong {
-            current.strong = *value;
+        if let Some(value) = self.chip_padding_y {
+            current.chip_padding_y = value;
         }
-        if let Some(value) = &self.em {
-            current.em = *value;
+        if let Some(value) = self.chip_gap {
+            current.chip_gap = value;
         }
-        if let Some(value) = &self.del {
-            current.del = *value;
+        if let Some(value) = self.chip_max_width {
+            current.chip_max_width = value;
         }
-        if let Some(value) = &self.inline_code_bg {
-            current.inline_code_bg = *value;
+        if let Some(value) = self.label {
+            current.label = value;
         }
-        if let Some(value) = &self.inline_code_border {
-            current.inline_code_border = *value;
+        if let Some(value) = self.label_size {
+            current.label_size = value;
         }
-        if let Some(value) = &self.inline_code_fg {
-            current.inline_code_fg = *value;
+        if let Some(value) = self.label_weight {
+            current.label_weight = value;
         }
-        if let Some(value) = &self.kbd_bg {
-            current.kbd_bg = *value;
+        if let Some(value) = self.description {
+            current.description = value;
         }
-        if let Some(value) = &self.kbd_border {
-            current.kbd_border = *value;
+        if let Some(value) = self.description_size {
+            current.description_size = value;
         }
-        if let Some(value) = &self.kbd_fg {
-            current.kbd_fg = *value;
+        if let Some(value) = self.error {
+            current.error = value;
         }
-        if let Some(value) = &self.mark_bg {
-            current.mark_bg = *value;
+        if let Some(value) = self.error_size {
+            current.error_size = value;
         }
-        if let Some(value) = &self.mark_fg {
-            current.mark_fg = *value;
+        if let Some(value) = self.label_block_gap {
+            current.label_block_gap = value;
         }
-        if let Some(value) = &self.list_marker {
-            current.list_marker = *value;
+        if let Some(value) = self.label_row_gap {
+            current.label_row_gap = value;
         }
-        if let Some(value) = &self.rule {
-            current.rule = *value;
+        if let Some(value) = self.layout_gap_vertical {
+            current.layout_gap_vertical = value;
         }
-        if let Some(value) = &self.table_border {
-            current.table_border = *value;
+        if let Some(value) = self.layout_gap_horizontal {
+            current.layout_gap_horizontal = value;
         }
-        if let Some(value) = &self.table_header_bg {
-            current.table_header_bg = *value;
+        if let Some(value) = self.horizontal_label_width {
+            current.horizontal_label_width = value;
         }
-        if let Some(value) = &self.table_header_fg {
-            current.table_header_fg = *value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LayoutOverrides {
+    pub gap: Option<GapSizeScale>,
+    pub space: Option<GapSizeScale>,
+    pub popup_snap_margin: Option<Pixels>,
+}
+
+impl LayoutOverrides {
+    fn apply(&self, mut current: LayoutTokens) -> LayoutTokens {
+        if let Some(value) = self.gap {
+            current.gap = value;
         }
-        if let Some(value) = &self.table_row_alt_bg {
-            current.table_row_alt_bg = *value;
+        if let Some(value) = self.space {
+            current.space = value;
         }
-        if let Some(value) = &self.table_cell_fg {
-            current.table_cell_fg = *value;
+        if let Some(value) = self.popup_snap_margin {
+            current.popup_snap_margin = value;
         }
-        if let Some(value) = &self.task_border {
-            current.task_border = *value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FocusRingOverrides {
+    pub color: Option<Hsla>,
+    pub width: Option<Pixels>,
+    pub offset: Option<Pixels>,
+    pub style: Option<FocusRingStyle>,
+}
+
+impl FocusRingOverrides {
+    fn apply(&self, mut current: FocusRingTokens) -> FocusRingTokens {
+        if let Some(value) = &self.color {
+            current.color = *value;
         }
-        if let Some(value) = &self.task_bg {
-            current.task_bg = *value;
+        if let Some(value) = self.width {
+            current.width = value;
         }
-        if let Some(value) = &self.task_checked_bg {
-            current.task_checked_bg = *value;
+        if let Some(value) = self.offset {
+            current.offset = value;
         }
-        if let Some(value) = &self.task_checked_fg {
-            current.task_checked_fg = *value;
+        if let Some(value) = self.style {
+            current.style = value;
         }
-        if let Some(value) = &self.details_bg {
-            current.details_bg = *value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PressFeedbackOverrides {
+    pub style: Option<PressFeedbackStyle>,
+    pub scale_inset: Option<Pixels>,
+    pub ripple_color: Option<Hsla>,
+}
+
+impl PressFeedbackOverrides {
+    fn apply(&self, mut current: PressFeedbackTokens) -> PressFeedbackTokens {
+        if let Some(value) = self.style {
+            current.style = value;
         }
-        if let Some(value) = &self.details_border {
-            current.details_border = *value;
+        if let Some(value) = self.scale_inset {
+            current.scale_inset = value;
         }
-        if let Some(value) = &self.details_summary_fg {
-            current.details_summary_fg = *value;
+        if let Some(value) = &self.ripple_color {
+            current.ripple_color = *value;
         }
-        if let Some(value) = &self.details_body_fg {
-            current.details_body_fg = *value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AsyncContentOverrides {
+    pub skeleton_bg: Option<Hsla>,
+    pub skeleton_radius: Option<Pixels>,
+    pub skeleton_line_height: Option<Pixels>,
+    pub skeleton_gap: Option<Pixels>,
+    pub empty_icon: Option<Hsla>,
+    pub empty_title: Option<Hsla>,
+    pub empty_description: Option<Hsla>,
+    pub empty_icon_size: Option<Pixels>,
+    pub empty_title_size: Option<Pixels>,
+    pub empty_description_size: Option<Pixels>,
+    pub empty_gap: Option<Pixels>,
+    pub empty_padding: Option<Pixels>,
+}
+
+impl AsyncContentOverrides {
+    fn apply(&self, mut current: AsyncContentTokens) -> AsyncContentTokens {
+        if let Some(value) = &self.skeleton_bg {
+            current.skeleton_bg = *value;
         }
-        if let Some(value) = &self.image_border {
-            current.image_border = *value;
+        if let Some(value) = self.skeleton_radius {
+            current.skeleton_radius = value;
         }
-        if let Some(value) = &self.image_bg {
-            current.image_bg = *value;
+        if let Some(value) = self.skeleton_line_height {
+            current.skeleton_line_height = value;
         }
-        if let Some(value) = &self.image_caption_fg {
-            current.image_caption_fg = *value;
+        if let Some(value) = self.skeleton_gap {
+            current.skeleton_gap = value;
         }
-        if let Some(value) = self.gap_regular {
-            current.gap_regular = value;
+        if let Some(value) = &self.empty_icon {
+            current.empty_icon = *value;
         }
-        if let Some(value) = self.gap_compact {
-            current.gap_compact = value;
+        if let Some(value) = &self.empty_title {
+            current.empty_title = *value;
         }
-        if let Some(value) = self.paragraph_size {
-            current.paragraph_size = value;
+        if let Some(value) = &self.empty_description {
+            current.empty_description = *value;
         }
-        if let Some(value) = self.paragraph_line_height {
-            current.paragraph_line_height = value;
+        if let Some(value) = self.empty_icon_size {
+            current.empty_icon_size = value;
         }
-        if let Some(value) = self.quote_size {
-            current.quote_size = value;
+        if let Some(value) = self.empty_title_size {
+            current.empty_title_size = value;
         }
-        if let Some(value) = self.quote_line_height {
-            current.quote_line_height = value;
+        if let Some(value) = self.empty_description_size {
+            current.empty_description_size = value;
         }
-        if let Some(value) = self.code_size {
-            current.code_size = value;
+        if let Some(value) = self.empty_gap {
+            current.empty_gap = value;
         }
-        if let Some(value) = self.code_line_height {
-            current.code_line_height = value;
+        if let Some(value) = self.empty_padding {
+            current.empty_padding = value;
         }
-        if let Some(value) = self.code_lang_size {
-            current.code_lang_size = value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChecklistOverrides {
+    pub done: Option<Hsla>,
+    pub active: Option<Hsla>,
+    pub pending: Option<Hsla>,
+    pub error: Option<Hsla>,
+    pub label: Option<Hsla>,
+    pub description: Option<Hsla>,
+    pub summary: Option<Hsla>,
+    pub icon_size: Option<Pixels>,
+    pub label_size: Option<Pixels>,
+    pub description_size: Option<Pixels>,
+    pub summary_size: Option<Pixels>,
+    pub gap: Option<Pixels>,
+    pub item_gap: Option<Pixels>,
+    pub detail_indent: Option<Pixels>,
+}
+
+impl ChecklistOverrides {
+    fn apply(&self, mut current: ChecklistTokens) -> ChecklistTokens {
+        if let Some(value) = &self.done {
+            current.done = *value;
         }
-        if let Some(value) = self.list_size {
-            current.list_size = value;
+        if let Some(value) = &self.active {
+            current.active = *value;
         }
-        if let Some(value) = self.list_line_height {
-            current.list_line_height = value;
+        if let Some(value) = &self.pending {
+            current.pending = *value;
         }
-        if let Some(value) = self.table_size {
-            current.table_size = value;
+        if let Some(value) = &self.error {
+            current.error = *value;
         }
-        if let Some(value) = self.image_caption_size {
-            current.image_caption_size = value;
+        if let Some(value) = &self.label {
+            current.label = *value;
         }
-        if let Some(value) = self.quote_padding_x {
-            current.quote_padding_x = value;
+        if let Some(value) = &self.description {
+            current.description = *value;
         }
-        if let Some(value) = self.quote_padding_y {
-            current.quote_padding_y = value;
+        if let Some(value) = &self.summary {
+            current.summary = *value;
         }
-        if let Some(value) = self.quote_radius {
-            current.quote_radius = value;
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
         }
-        if let Some(value) = self.quote_gap {
-            current.quote_gap = value;
+        if let Some(value) = self.label_size {
+            current.label_size = value;
         }
-        if let Some(value) = self.code_padding {
-            current.code_padding = value;
+        if let Some(value) = self.description_size {
+            current.description_size = value;
         }
-        if let Some(value) = self.code_radius {
-            current.code_radius = value;
+        if let Some(value) = self.summary_size {
+            current.summary_size = value;
         }
-        if let Some(value) = self.code_gap {
-            current.code_gap = value;
+        if let Some(value) = self.gap {
+            current.gap = value;
         }
-        if let Some(value) = self.inline_code_radius {
-            current.inline_code_radius = value;
+        if let Some(value) = self.item_gap {
+            current.item_gap = value;
         }
-        if let Some(value) = self.kbd_radius {
-            current.kbd_radius = value;
+        if let Some(value) = self.detail_indent {
+            current.detail_indent = value;
         }
-        if let Some(value) = self.list_gap {
-            current.list_gap = value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TaskStatusOverrides {
+    pub trigger_fg: Option<Hsla>,
+    pub trigger_hover_bg: Option<Hsla>,
+    pub title: Option<Hsla>,
+    pub message: Option<Hsla>,
+    pub running: Option<Hsla>,
+    pub done: Option<Hsla>,
+    pub error: Option<Hsla>,
+    pub cancelled: Option<Hsla>,
+    pub trigger_size: Option<Pixels>,
+    pub title_size: Option<Pixels>,
+    pub message_size: Option<Pixels>,
+    pub icon_size: Option<Pixels>,
+    pub trigger_bar_width: Option<Pixels>,
+    pub panel_width: Option<Pixels>,
+    pub item_gap: Option<Pixels>,
+    pub row_gap: Option<Pixels>,
+}
+
+impl TaskStatusOverrides {
+    fn apply(&self, mut current: TaskStatusTokens) -> TaskStatusTokens {
+        if let Some(value) = &self.trigger_fg {
+            current.trigger_fg = *value;
         }
-        if let Some(value) = self.list_item_gap {
-            current.list_item_gap = value;
+        if let Some(value) = &self.trigger_hover_bg {
+            current.trigger_hover_bg = *value;
         }
-        if let Some(value) = self.list_indent {
-            current.list_indent = value;
+        if let Some(value) = &self.title {
+            current.title = *value;
         }
-        if let Some(value) = self.table_radius {
-            current.table_radius = value;
+        if let Some(value) = &self.message {
+            current.message = *value;
+        }
+        if let Some(value) = &self.running {
+            current.running = *value;
+        }
+        if let Some(value) = &self.done {
+            current.done = *value;
+        }
+        if let Some(value) = &self.error {
+            current.error = *value;
         }
-        if let Some(value) = self.table_cell_padding_x {
-            current.table_cell_padding_x = value;
+        if let Some(value) = &self.cancelled {
+            current.cancelled = *value;
         }
-        if let Some(value) = self.table_cell_padding_y {
-            current.table_cell_padding_y = value;
+        if let Some(value) = self.trigger_size {
+            current.trigger_size = value;
         }
-        if let Some(value) = self.details_radius {
-            current.details_radius = value;
+        if let Some(value) = self.title_size {
+            current.title_size = value;
         }
-        if let Some(value) = self.details_padding_x {
-            current.details_padding_x = value;
+        if let Some(value) = self.message_size {
+            current.message_size = value;
         }
-        if let Some(value) = self.details_padding_y {
-            current.details_padding_y = value;
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
         }
-        if let Some(value) = self.image_radius {
-            current.image_radius = value;
+        if let Some(value) = self.trigger_bar_width {
+            current.trigger_bar_width = value;
         }
-        if let Some(value) = self.image_padding {
-            current.image_padding = value;
+        if let Some(value) = self.panel_width {
+            current.panel_width = value;
         }
-        if let Some(value) = self.image_gap {
-            current.image_gap = value;
+        if let Some(value) = self.item_gap {
+            current.item_gap = value;
         }
-        if let Some(value) = self.heading2_padding_top {
-            current.heading2_padding_top = value;
+        if let Some(value) = self.row_gap {
+            current.row_gap = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TextOverrides {
-    pub fg: Option<Hsla>,
-    pub secondary: Option<Hsla>,
-    pub muted: Option<Hsla>,
-    pub accent: Option<Hsla>,
+pub struct NotificationCenterOverrides {
+    pub header: Option<Hsla>,
+    pub title: Option<Hsla>,
+    pub message: Option<Hsla>,
+    pub empty: Option<Hsla>,
+    pub unread_dot: Option<Hsla>,
+    pub unread_bg: Option<Hsla>,
+    pub row_hover_bg: Option<Hsla>,
+    pub info: Option<Hsla>,
     pub success: Option<Hsla>,
     pub warning: Option<Hsla>,
     pub error: Option<Hsla>,
-    pub sizes: Option<TextSizeScale>,
+    pub header_size: Option<Pixels>,
+    pub title_size: Option<Pixels>,
+    pub message_size: Option<Pixels>,
+    pub icon_size: Option<Pixels>,
+    pub dot_size: Option<Pixels>,
+    pub panel_width: Option<Pixels>,
+    pub row_padding: Option<Pixels>,
+    pub item_gap: Option<Pixels>,
+    pub row_gap: Option<Pixels>,
 }
 
-impl TextOverrides {
-    fn apply(&self, mut current: TextTokens) -> TextTokens {
-        if let Some(value) = &self.fg {
-            current.fg = *value;
+impl NotificationCenterOverrides {
+    fn apply(&self, mut current: NotificationCenterTokens) -> NotificationCenterTokens {
+        if let Some(value) = &self.header {
+            current.header = *value;
         }
-        if let Some(value) = &self.secondary {
-            current.secondary = *value;
+        if let Some(value) = &self.title {
+            current.title = *value;
         }
-        if let Some(value) = &self.muted {
-            current.muted = *value;
+        if let Some(value) = &self.message {
+            current.message = *value;
         }
-        if let Some(value) = &self.accent {
-            current.accent = *value;
+        if let Some(value) = &self.empty {
+            current.empty = *value;
+        }
+        if let Some(value) = &self.unread_dot {
+            current.unread_dot = *value;
+        }
+        if let Some(value) = &self.unread_bg {
+            current.unread_bg = *value;
+        }
+        if let Some(value) = &self.row_hover_bg {
+            current.row_hover_bg = *value;
+        }
+        if let Some(value) = &self.info {
+            current.info = *value;
         }
         if let Some(value) = &self.success {
             current.success = *value;
@@ -8049,495 +10772,709 @@ impl TextOverrides {
         if let Some(value) = &self.error {
             current.error = *value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.header_size {
+            current.header_size = value;
+        }
+        if let Some(value) = self.title_size {
+            current.title_size = value;
+        }
+        if let Some(value) = self.message_size {
+            current.message_size = value;
+        }
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
+        }
+        if let Some(value) = self.dot_size {
+            current.dot_size = value;
+        }
+        if let Some(value) = self.panel_width {
+            current.panel_width = value;
+        }
+        if let Some(value) = self.row_padding {
+            current.row_padding = value;
+        }
+        if let Some(value) = self.item_gap {
+            current.item_gap = value;
+        }
+        if let Some(value) = self.row_gap {
+            current.row_gap = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TitleOverrides {
-    pub fg: Option<Hsla>,
-    pub subtitle: Option<Hsla>,
+pub struct FindBarOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub count: Option<Hsla>,
+    pub match_bg: Option<Hsla>,
+    pub active_match_bg: Option<Hsla>,
+    pub width: Option<Pixels>,
+    pub padding: Option<Pixels>,
     pub gap: Option<Pixels>,
-    pub subtitle_size: Option<Pixels>,
-    pub subtitle_line_height: Option<Pixels>,
-    pub subtitle_weight: Option<FontWeight>,
-    pub h1: TitleLevelOverrides,
-    pub h2: TitleLevelOverrides,
-    pub h3: TitleLevelOverrides,
-    pub h4: TitleLevelOverrides,
-    pub h5: TitleLevelOverrides,
-    pub h6: TitleLevelOverrides,
-}
-
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-pub struct TitleLevelOverrides {
-    pub font_size: Option<Pixels>,
-    pub line_height: Option<Pixels>,
-    pub weight: Option<FontWeight>,
+    pub radius: Option<Pixels>,
+    pub offset: Option<Pixels>,
+    pub count_size: Option<Pixels>,
 }
 
-impl TitleLevelOverrides {
-    fn apply(&self, mut current: TitleLevelTokens) -> TitleLevelTokens {
-        if let Some(value) = &self.font_size {
-            current.font_size = *value;
+impl FindBarOverrides {
+    fn apply(&self, mut current: FindBarTokens) -> FindBarTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = &self.line_height {
-            current.line_height = *value;
+        if let Some(value) = &self.border {
+            current.border = *value;
         }
-        if let Some(value) = &self.weight {
-            current.weight = *value;
+        if let Some(value) = &self.count {
+            current.count = *value;
         }
-        current
-    }
-}
-
-impl TitleOverrides {
-    fn apply(&self, mut current: TitleTokens) -> TitleTokens {
-        if let Some(value) = &self.fg {
-            current.fg = *value;
+        if let Some(value) = &self.match_bg {
+            current.match_bg = *value;
         }
-        if let Some(value) = &self.subtitle {
-            current.subtitle = *value;
+        if let Some(value) = &self.active_match_bg {
+            current.active_match_bg = *value;
         }
-        if let Some(value) = &self.gap {
-            current.gap = *value;
+        if let Some(value) = self.width {
+            current.width = value;
         }
-        if let Some(value) = &self.subtitle_size {
-            current.subtitle_size = *value;
+        if let Some(value) = self.padding {
+            current.padding = value;
         }
-        if let Some(value) = &self.subtitle_line_height {
-            current.subtitle_line_height = *value;
+        if let Some(value) = self.gap {
+            current.gap = value;
         }
-        if let Some(value) = &self.subtitle_weight {
-            current.subtitle_weight = *value;
+        if let Some(value) = self.radius {
+            current.radius = value;
+        }
+        if let Some(value) = self.offset {
+            current.offset = value;
+        }
+        if let Some(value) = self.count_size {
+            current.count_size = value;
         }
-        current.h1 = self.h1.apply(current.h1);
-        current.h2 = self.h2.apply(current.h2);
-        current.h3 = self.h3.apply(current.h3);
-        current.h4 = self.h4.apply(current.h4);
-        current.h5 = self.h5.apply(current.h5);
-        current.h6 = self.h6.apply(current.h6);
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct PaperOverrides {
-    pub bg: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub padding: Option<InsetSizeScale>,
+pub struct SplitPaneOverrides {
+    pub divider: Option<Hsla>,
+    pub divider_active: Option<Hsla>,
+    pub divider_size: Option<Pixels>,
+    pub handle_size: Option<Pixels>,
+    pub min_pane_size: Option<Pixels>,
 }
 
-impl PaperOverrides {
-    fn apply(&self, mut current: PaperTokens) -> PaperTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+impl SplitPaneOverrides {
+    fn apply(&self, mut current: SplitPaneTokens) -> SplitPaneTokens {
+        if let Some(value) = &self.divider {
+            current.divider = *value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = &self.divider_active {
+            current.divider_active = *value;
         }
-        if let Some(value) = self.padding {
-            current.padding = value;
+        if let Some(value) = self.divider_size {
+            current.divider_size = value;
+        }
+        if let Some(value) = self.handle_size {
+            current.handle_size = value;
+        }
+        if let Some(value) = self.min_pane_size {
+            current.min_pane_size = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct ActionIconOverrides {
-    pub filled_bg: Option<Hsla>,
-    pub filled_fg: Option<Hsla>,
-    pub light_bg: Option<Hsla>,
-    pub light_fg: Option<Hsla>,
-    pub subtle_bg: Option<Hsla>,
-    pub subtle_fg: Option<Hsla>,
-    pub outline_border: Option<Hsla>,
-    pub outline_fg: Option<Hsla>,
-    pub ghost_fg: Option<Hsla>,
-    pub default_bg: Option<Hsla>,
-    pub default_fg: Option<Hsla>,
-    pub default_border: Option<Hsla>,
-    pub disabled_bg: Option<Hsla>,
-    pub disabled_fg: Option<Hsla>,
-    pub disabled_border: Option<Hsla>,
-    pub sizes: Option<ActionIconSizeScale>,
+pub struct DockTabsOverrides {
+    pub strip_bg: Option<Hsla>,
+    pub strip_border: Option<Hsla>,
+    pub tab_fg: Option<Hsla>,
+    pub tab_hover_bg: Option<Hsla>,
+    pub tab_active_bg: Option<Hsla>,
+    pub tab_active_fg: Option<Hsla>,
+    pub tab_active_indicator: Option<Hsla>,
+    pub tab_disabled_fg: Option<Hsla>,
+    pub dirty_dot: Option<Hsla>,
+    pub drop_indicator: Option<Hsla>,
+    pub tab_height: Option<Pixels>,
+    pub tab_padding_x: Option<Pixels>,
+    pub tab_gap: Option<Pixels>,
+    pub tab_max_width: Option<Pixels>,
+    pub close_size: Option<Pixels>,
+    pub dirty_dot_size: Option<Pixels>,
+    pub overflow_button_width: Option<Pixels>,
 }
 
-impl ActionIconOverrides {
-    fn apply(&self, mut current: ActionIconTokens) -> ActionIconTokens {
-        if let Some(value) = &self.filled_bg {
-            current.filled_bg = *value;
+impl DockTabsOverrides {
+    fn apply(&self, mut current: DockTabsTokens) -> DockTabsTokens {
+        if let Some(value) = &self.strip_bg {
+            current.strip_bg = *value;
         }
-        if let Some(value) = &self.filled_fg {
-            current.filled_fg = *value;
+        if let Some(value) = &self.strip_border {
+            current.strip_border = *value;
         }
-        if let Some(value) = &self.light_bg {
-            current.light_bg = *value;
+        if let Some(value) = &self.tab_fg {
+            current.tab_fg = *value;
         }
-        if let Some(value) = &self.light_fg {
-            current.light_fg = *value;
+        if let Some(value) = &self.tab_hover_bg {
+            current.tab_hover_bg = *value;
         }
-        if let Some(value) = &self.subtle_bg {
-            current.subtle_bg = *value;
+        if let Some(value) = &self.tab_active_bg {
+            current.tab_active_bg = *value;
         }
-        if let Some(value) = &self.subtle_fg {
-            current.subtle_fg = *value;
+        if let Some(value) = &self.tab_active_fg {
+            current.tab_active_fg = *value;
         }
-        if let Some(value) = &self.outline_border {
-            current.outline_border = *value;
+        if let Some(value) = &self.tab_active_indicator {
+            current.tab_active_indicator = *value;
         }
-        if let Some(value) = &self.outline_fg {
-            current.outline_fg = *value;
+        if let Some(value) = &self.tab_disabled_fg {
+            current.tab_disabled_fg = *value;
         }
-        if let Some(value) = &self.ghost_fg {
-            current.ghost_fg = *value;
+        if let Some(value) = &self.dirty_dot {
+            current.dirty_dot = *value;
         }
-        if let Some(value) = &self.default_bg {
-            current.default_bg = *value;
+        if let Some(value) = &self.drop_indicator {
+            current.drop_indicator = *value;
         }
-        if let Some(value) = &self.default_fg {
-            current.default_fg = *value;
+        if let Some(value) = self.tab_height {
+            current.tab_height = value;
         }
-        if let Some(value) = &self.default_border {
-            current.default_border = *value;
+        if let Some(value) = self.tab_padding_x {
+            current.tab_padding_x = value;
         }
-        if let Some(value) = &self.disabled_bg {
-            current.disabled_bg = *value;
+        if let Some(value) = self.tab_gap {
+            current.tab_gap = value;
         }
-        if let Some(value) = &self.disabled_fg {
-            current.disabled_fg = *value;
+        if let Some(value) = self.tab_max_width {
+            current.tab_max_width = value;
         }
-        if let Some(value) = &self.disabled_border {
-            current.disabled_border = *value;
+        if let Some(value) = self.close_size {
+            current.close_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.dirty_dot_size {
+            current.dirty_dot_size = value;
+        }
+        if let Some(value) = self.overflow_button_width {
+            current.overflow_button_width = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct SegmentedControlOverrides {
+pub struct ImageOverrides {
+    pub placeholder_bg: Option<Hsla>,
+    pub fallback_bg: Option<Hsla>,
+    pub fallback_fg: Option<Hsla>,
+    pub fallback_icon_size: Option<Pixels>,
+    pub fallback_gap: Option<Pixels>,
+    pub fallback_font_size: Option<Pixels>,
+}
+
+impl ImageOverrides {
+    fn apply(&self, mut current: ImageTokens) -> ImageTokens {
+        if let Some(value) = &self.placeholder_bg {
+            current.placeholder_bg = *value;
+        }
+        if let Some(value) = &self.fallback_bg {
+            current.fallback_bg = *value;
+        }
+        if let Some(value) = &self.fallback_fg {
+            current.fallback_fg = *value;
+        }
+        if let Some(value) = self.fallback_icon_size {
+            current.fallback_icon_size = value;
+        }
+        if let Some(value) = self.fallback_gap {
+            current.fallback_gap = value;
+        }
+        if let Some(value) = self.fallback_font_size {
+            current.fallback_font_size = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CalendarOverrides {
     pub bg: Option<Hsla>,
     pub border: Option<Hsla>,
-    pub item_fg: Option<Hsla>,
-    pub item_active_bg: Option<Hsla>,
-    pub item_active_fg: Option<Hsla>,
-    pub item_hover_bg: Option<Hsla>,
-    pub item_disabled_fg: Option<Hsla>,
-    pub track_padding: Option<Pixels>,
-    pub item_gap: Option<Pixels>,
-    pub sizes: Option<SegmentedControlSizeScale>,
+    pub header_fg: Option<Hsla>,
+    pub header_size: Option<Pixels>,
+    pub nav_hover_bg: Option<Hsla>,
+    pub icon: Option<Hsla>,
+    pub icon_size: Option<Pixels>,
+    pub weekday_fg: Option<Hsla>,
+    pub weekday_size: Option<Pixels>,
+    pub day_fg: Option<Hsla>,
+    pub day_outside_fg: Option<Hsla>,
+    pub day_hover_bg: Option<Hsla>,
+    pub day_size: Option<Pixels>,
+    pub today_bg: Option<Hsla>,
+    pub today_fg: Option<Hsla>,
+    pub range_bg: Option<Hsla>,
+    pub range_edge_bg: Option<Hsla>,
+    pub cell_border: Option<Hsla>,
+    pub cell_padding: Option<Pixels>,
+    pub month_cell_height: Option<Pixels>,
+    pub week_cell_height: Option<Pixels>,
+    pub chip_bg: Option<Hsla>,
+    pub chip_fg: Option<Hsla>,
+    pub chip_size: Option<Pixels>,
+    pub chip_padding_x: Option<Pixels>,
+    pub chip_gap: Option<Pixels>,
+    pub more_fg: Option<Hsla>,
+    pub header_gap: Option<Pixels>,
 }
-
-impl SegmentedControlOverrides {
-    fn apply(&self, mut current: SegmentedControlTokens) -> SegmentedControlTokens {
+
+impl CalendarOverrides {
+    fn apply(&self, mut current: CalendarTokens) -> CalendarTokens {
         if let Some(value) = &self.bg {
             current.bg = *value;
         }
         if let Some(value) = &self.border {
             current.border = *value;
         }
-        if let Some(value) = &self.item_fg {
-            current.item_fg = *value;
+        if let Some(value) = &self.header_fg {
+            current.header_fg = *value;
         }
-        if let Some(value) = &self.item_active_bg {
-            current.item_active_bg = *value;
+        if let Some(value) = self.header_size {
+            current.header_size = value;
         }
-        if let Some(value) = &self.item_active_fg {
-            current.item_active_fg = *value;
+        if let Some(value) = &self.nav_hover_bg {
+            current.nav_hover_bg = *value;
         }
-        if let Some(value) = &self.item_hover_bg {
-            current.item_hover_bg = *value;
+        if let Some(value) = &self.icon {
+            current.icon = *value;
         }
-        if let Some(value) = &self.item_disabled_fg {
-            current.item_disabled_fg = *value;
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
         }
-        if let Some(value) = self.track_padding {
-            current.track_padding = value;
+        if let Some(value) = &self.weekday_fg {
+            current.weekday_fg = *value;
         }
-        if let Some(value) = self.item_gap {
-            current.item_gap = value;
+        if let Some(value) = self.weekday_size {
+            current.weekday_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = &self.day_fg {
+            current.day_fg = *value;
+        }
+        if let Some(value) = &self.day_outside_fg {
+            current.day_outside_fg = *value;
+        }
+        if let Some(value) = &self.day_hover_bg {
+            current.day_hover_bg = *value;
+        }
+        if let Some(value) = self.day_size {
+            current.day_size = value;
+        }
+        if let Some(value) = &self.today_bg {
+            current.today_bg = *value;
+        }
+        if let Some(value) = &self.today_fg {
+            current.today_fg = *value;
+        }
+        if let Some(value) = &self.range_bg {
+            current.range_bg = *value;
+        }
+        if let Some(value) = &self.range_edge_bg {
+            current.range_edge_bg = *value;
+        }
+        if let Some(value) = &self.cell_border {
+            current.cell_border = *value;
+        }
+        if let Some(value) = self.cell_padding {
+            current.cell_padding = value;
+        }
+        if let Some(value) = self.month_cell_height {
+            current.month_cell_height = value;
+        }
+        if let Some(value) = self.week_cell_height {
+            current.week_cell_height = value;
+        }
+        if let Some(value) = &self.chip_bg {
+            current.chip_bg = *value;
+        }
+        if let Some(value) = &self.chip_fg {
+            current.chip_fg = *value;
+        }
+        if let Some(value) = self.chip_size {
+            current.chip_size = value;
+        }
+        if let Some(value) = self.chip_padding_x {
+            current.chip_padding_x = value;
+        }
+        if let Some(value) = self.chip_gap {
+            current.chip_gap = value;
+        }
+        if let Some(value) = &self.more_fg {
+            current.more_fg = *value;
+        }
+        if let Some(value) = self.header_gap {
+            current.header_gap = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TextareaOverrides {
-    pub bg: Option<Hsla>,
-    pub fg: Option<Hsla>,
-    pub caret: Option<Hsla>,
-    pub selection_bg: Option<Hsla>,
-    pub placeholder: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub border_focus: Option<Hsla>,
-    pub border_error: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub label_size: Option<Pixels>,
-    pub label_weight: Option<FontWeight>,
-    pub description: Option<Hsla>,
-    pub description_size: Option<Pixels>,
-    pub error: Option<Hsla>,
-    pub error_size: Option<Pixels>,
-    pub label_block_gap: Option<Pixels>,
-    pub label_row_gap: Option<Pixels>,
-    pub layout_gap_vertical: Option<Pixels>,
-    pub layout_gap_horizontal: Option<Pixels>,
-    pub horizontal_label_width: Option<Pixels>,
-    pub content_width_fallback: Option<Pixels>,
-    pub sizes: Option<FieldSizeScale>,
+pub struct KanbanBoardOverrides {
+    pub column_bg: Option<Hsla>,
+    pub column_border: Option<Hsla>,
+    pub column_width: Option<Pixels>,
+    pub column_gap: Option<Pixels>,
+    pub column_padding: Option<Pixels>,
+    pub column_radius: Option<Pixels>,
+    pub list_height: Option<Pixels>,
+    pub header_fg: Option<Hsla>,
+    pub header_size: Option<Pixels>,
+    pub header_gap: Option<Pixels>,
+    pub count_bg: Option<Hsla>,
+    pub count_fg: Option<Hsla>,
+    pub count_over_limit_bg: Option<Hsla>,
+    pub count_over_limit_fg: Option<Hsla>,
+    pub count_size: Option<Pixels>,
+    pub card_bg: Option<Hsla>,
+    pub card_border: Option<Hsla>,
+    pub card_height: Option<Pixels>,
+    pub card_gap: Option<Pixels>,
+    pub card_padding: Option<Pixels>,
+    pub placeholder_bg: Option<Hsla>,
+    pub placeholder_border: Option<Hsla>,
+    pub empty_fg: Option<Hsla>,
+    pub empty_size: Option<Pixels>,
 }
 
-impl TextareaOverrides {
-    fn apply(&self, mut current: TextareaTokens) -> TextareaTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+impl KanbanBoardOverrides {
+    fn apply(&self, mut current: KanbanBoardTokens) -> KanbanBoardTokens {
+        if let Some(value) = &self.column_bg {
+            current.column_bg = *value;
         }
-        if let Some(value) = &self.fg {
-            current.fg = *value;
+        if let Some(value) = &self.column_border {
+            current.column_border = *value;
         }
-        if let Some(value) = &self.caret {
-            current.caret = *value;
+        if let Some(value) = self.column_width {
+            current.column_width = value;
         }
-        if let Some(value) = &self.selection_bg {
-            current.selection_bg = *value;
+        if let Some(value) = self.column_gap {
+            current.column_gap = value;
         }
-        if let Some(value) = &self.placeholder {
-            current.placeholder = *value;
+        if let Some(value) = self.column_padding {
+            current.column_padding = value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = self.column_radius {
+            current.column_radius = value;
         }
-        if let Some(value) = &self.border_focus {
-            current.border_focus = *value;
+        if let Some(value) = self.list_height {
+            current.list_height = value;
         }
-        if let Some(value) = &self.border_error {
-            current.border_error = *value;
+        if let Some(value) = &self.header_fg {
+            current.header_fg = *value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = self.header_size {
+            current.header_size = value;
         }
-        if let Some(value) = self.label_size {
-            current.label_size = value;
+        if let Some(value) = self.header_gap {
+            current.header_gap = value;
         }
-        if let Some(value) = self.label_weight {
-            current.label_weight = value;
+        if let Some(value) = &self.count_bg {
+            current.count_bg = *value;
         }
-        if let Some(value) = &self.description {
-            current.description = *value;
+        if let Some(value) = &self.count_fg {
+            current.count_fg = *value;
         }
-        if let Some(value) = self.description_size {
-            current.description_size = value;
+        if let Some(value) = &self.count_over_limit_bg {
+            current.count_over_limit_bg = *value;
         }
-        if let Some(value) = &self.error {
-            current.error = *value;
+        if let Some(value) = &self.count_over_limit_fg {
+            current.count_over_limit_fg = *value;
         }
-        if let Some(value) = self.error_size {
-            current.error_size = value;
+        if let Some(value) = self.count_size {
+            current.count_size = value;
         }
-        if let Some(value) = self.label_block_gap {
-            current.label_block_gap = value;
+        if let Some(value) = &self.card_bg {
+            current.card_bg = *value;
         }
-        if let Some(value) = self.label_row_gap {
-            current.label_row_gap = value;
+        if let Some(value) = &self.card_border {
+            current.card_border = *value;
         }
-        if let Some(value) = self.layout_gap_vertical {
-            current.layout_gap_vertical = value;
+        if let Some(value) = self.card_height {
+            current.card_height = value;
         }
-        if let Some(value) = self.layout_gap_horizontal {
-            current.layout_gap_horizontal = value;
+        if let Some(value) = self.card_gap {
+            current.card_gap = value;
         }
-        if let Some(value) = self.horizontal_label_width {
-            current.horizontal_label_width = value;
+        if let Some(value) = self.card_padding {
+            current.card_padding = value;
         }
-        if let Some(value) = self.content_width_fallback {
-            current.content_width_fallback = value;
+        if let Some(value) = &self.placeholder_bg {
+            current.placeholder_bg = *value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = &self.placeholder_border {
+            current.placeholder_border = *value;
+        }
+        if let Some(value) = &self.empty_fg {
+            current.empty_fg = *value;
+        }
+        if let Some(value) = self.empty_size {
+            current.empty_size = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct NumberInputOverrides {
-    pub bg: Option<Hsla>,
-    pub fg: Option<Hsla>,
-    pub placeholder: Option<Hsla>,
-    pub border: Option<Hsla>,
-    pub border_focus: Option<Hsla>,
-    pub border_error: Option<Hsla>,
-    pub controls_bg: Option<Hsla>,
-    pub controls_fg: Option<Hsla>,
-    pub controls_border: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub label_size: Option<Pixels>,
-    pub label_weight: Option<FontWeight>,
-    pub description: Option<Hsla>,
-    pub description_size: Option<Pixels>,
-    pub error: Option<Hsla>,
-    pub error_size: Option<Pixels>,
-    pub controls_width: Option<Pixels>,
-    pub controls_height: Option<Pixels>,
-    pub controls_icon_size: Option<Pixels>,
-    pub controls_gap: Option<Pixels>,
-    pub sizes: Option<FieldSizeScale>,
-}
-
-impl NumberInputOverrides {
-    fn apply(&self, mut current: NumberInputTokens) -> NumberInputTokens {
-        if let Some(value) = &self.bg {
-            current.bg = *value;
+pub struct ChartOverrides {
+    pub grid: Option<Hsla>,
+    pub axis_fg: Option<Hsla>,
+    pub axis_size: Option<Pixels>,
+    pub axis_width: Option<Pixels>,
+    pub axis_gap: Option<Pixels>,
+    pub height: Option<Pixels>,
+    pub line_width: Option<Pixels>,
+    pub point_size: Option<Pixels>,
+    pub point_bg: Option<Hsla>,
+    pub bar_gap: Option<Pixels>,
+    pub bar_radius: Option<Pixels>,
+    pub donut_size: Option<Pixels>,
+    pub donut_thickness: Option<Pixels>,
+    pub donut_track: Option<Hsla>,
+    pub center_fg: Option<Hsla>,
+    pub center_size: Option<Pixels>,
+    pub legend_fg: Option<Hsla>,
+    pub legend_size: Option<Pixels>,
+    pub legend_gap: Option<Pixels>,
+    pub swatch_size: Option<Pixels>,
+    pub sparkline_width: Option<Pixels>,
+    pub sparkline_height: Option<Pixels>,
+    pub sparkline_line_width: Option<Pixels>,
+    pub empty_fg: Option<Hsla>,
+    pub empty_size: Option<Pixels>,
+}
+
+impl ChartOverrides {
+    fn apply(&self, mut current: ChartTokens) -> ChartTokens {
+        if let Some(value) = &self.grid {
+            current.grid = *value;
+        }
+        if let Some(value) = &self.axis_fg {
+            current.axis_fg = *value;
+        }
+        if let Some(value) = self.axis_size {
+            current.axis_size = value;
+        }
+        if let Some(value) = self.axis_width {
+            current.axis_width = value;
+        }
+        if let Some(value) = self.axis_gap {
+            current.axis_gap = value;
         }
-        if let Some(value) = &self.fg {
-            current.fg = *value;
+        if let Some(value) = self.height {
+            current.height = value;
         }
-        if let Some(value) = &self.placeholder {
-            current.placeholder = *value;
+        if let Some(value) = self.line_width {
+            current.line_width = value;
         }
-        if let Some(value) = &self.border {
-            current.border = *value;
+        if let Some(value) = self.point_size {
+            current.point_size = value;
         }
-        if let Some(value) = &self.border_focus {
-            current.border_focus = *value;
+        if let Some(value) = &self.point_bg {
+            current.point_bg = *value;
         }
-        if let Some(value) = &self.border_error {
-            current.border_error = *value;
+        if let Some(value) = self.bar_gap {
+            current.bar_gap = value;
         }
-        if let Some(value) = &self.controls_bg {
-            current.controls_bg = *value;
+        if let Some(value) = self.bar_radius {
+            current.bar_radius = value;
         }
-        if let Some(value) = &self.controls_fg {
-            current.controls_fg = *value;
+        if let Some(value) = self.donut_size {
+            current.donut_size = value;
         }
-        if let Some(value) = &self.controls_border {
-            current.controls_border = *value;
+        if let Some(value) = self.donut_thickness {
+            current.donut_thickness = value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = &self.donut_track {
+            current.donut_track = *value;
         }
-        if let Some(value) = self.label_size {
-            current.label_size = value;
+        if let Some(value) = &self.center_fg {
+            current.center_fg = *value;
         }
-        if let Some(value) = self.label_weight {
-            current.label_weight = value;
+        if let Some(value) = self.center_size {
+            current.center_size = value;
         }
-        if let Some(value) = &self.description {
-            current.description = *value;
+        if let Some(value) = &self.legend_fg {
+            current.legend_fg = *value;
         }
-        if let Some(value) = self.description_size {
-            current.description_size = value;
+        if let Some(value) = self.legend_size {
+            current.legend_size = value;
         }
-        if let Some(value) = &self.error {
-            current.error = *value;
+        if let Some(value) = self.legend_gap {
+            current.legend_gap = value;
         }
-        if let Some(value) = self.error_size {
-            current.error_size = value;
+        if let Some(value) = self.swatch_size {
+            current.swatch_size = value;
         }
-        if let Some(value) = self.controls_width {
-            current.controls_width = value;
+        if let Some(value) = self.sparkline_width {
+            current.sparkline_width = value;
         }
-        if let Some(value) = self.controls_height {
-            current.controls_height = value;
+        if let Some(value) = self.sparkline_height {
+            current.sparkline_height = value;
         }
-        if let Some(value) = self.controls_icon_size {
-            current.controls_icon_size = value;
+        if let Some(value) = self.sparkline_line_width {
+            current.sparkline_line_width = value;
         }
-        if let Some(value) = self.controls_gap {
-            current.controls_gap = value;
+        if let Some(value) = &self.empty_fg {
+            current.empty_fg = *value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.empty_size {
+            current.empty_size = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct RangeSliderOverrides {
-    pub track_bg: Option<Hsla>,
-    pub range_bg: Option<Hsla>,
-    pub thumb_bg: Option<Hsla>,
-    pub thumb_border: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub value: Option<Hsla>,
+pub struct RingProgressOverrides {
+    pub size: Option<Pixels>,
+    pub thickness: Option<Pixels>,
+    pub label_size: Option<Pixels>,
+    pub section_gap: Option<Pixels>,
+}
+
+impl RingProgressOverrides {
+    fn apply(&self, mut current: RingProgressTokens) -> RingProgressTokens {
+        if let Some(value) = self.size {
+            current.size = value;
+        }
+        if let Some(value) = self.thickness {
+            current.thickness = value;
+        }
+        if let Some(value) = self.label_size {
+            current.label_size = value;
+        }
+        if let Some(value) = self.section_gap {
+            current.section_gap = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StatCardOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub radius: Option<Pixels>,
+    pub padding: Option<Pixels>,
+    pub gap: Option<Pixels>,
+    pub label_fg: Option<Hsla>,
     pub label_size: Option<Pixels>,
+    pub value_fg: Option<Hsla>,
     pub value_size: Option<Pixels>,
-    pub header_gap_vertical: Option<Pixels>,
-    pub header_gap_horizontal: Option<Pixels>,
-    pub default_width: Option<Pixels>,
-    pub min_width: Option<Pixels>,
-    pub sizes: Option<SliderSizeScale>,
+    pub icon_fg: Option<Hsla>,
+    pub icon_size: Option<Pixels>,
+    pub delta_size: Option<Pixels>,
+    pub delta_icon_size: Option<Pixels>,
+    pub delta_up: Option<Hsla>,
+    pub delta_down: Option<Hsla>,
+    pub delta_flat: Option<Hsla>,
+    pub description_fg: Option<Hsla>,
+    pub description_size: Option<Pixels>,
+    pub skeleton_bg: Option<Hsla>,
 }
 
-impl RangeSliderOverrides {
-    fn apply(&self, mut current: RangeSliderTokens) -> RangeSliderTokens {
-        if let Some(value) = &self.track_bg {
-            current.track_bg = *value;
+impl StatCardOverrides {
+    fn apply(&self, mut current: StatCardTokens) -> StatCardTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = &self.range_bg {
-            current.range_bg = *value;
+        if let Some(value) = &self.border {
+            current.border = *value;
         }
-        if let Some(value) = &self.thumb_bg {
-            current.thumb_bg = *value;
+        if let Some(value) = self.radius {
+            current.radius = value;
         }
-        if let Some(value) = &self.thumb_border {
-            current.thumb_border = *value;
+        if let Some(value) = self.padding {
+            current.padding = value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = self.gap {
+            current.gap = value;
         }
-        if let Some(value) = &self.value {
-            current.value = *value;
+        if let Some(value) = &self.label_fg {
+            current.label_fg = *value;
         }
         if let Some(value) = self.label_size {
             current.label_size = value;
         }
+        if let Some(value) = &self.value_fg {
+            current.value_fg = *value;
+        }
         if let Some(value) = self.value_size {
             current.value_size = value;
         }
-        if let Some(value) = self.header_gap_vertical {
-            current.header_gap_vertical = value;
+        if let Some(value) = &self.icon_fg {
+            current.icon_fg = *value;
         }
-        if let Some(value) = self.header_gap_horizontal {
-            current.header_gap_horizontal = value;
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
         }
-        if let Some(value) = self.default_width {
-            current.default_width = value;
+        if let Some(value) = self.delta_size {
+            current.delta_size = value;
         }
-        if let Some(value) = self.min_width {
-            current.min_width = value;
+        if let Some(value) = self.delta_icon_size {
+            current.delta_icon_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = &self.delta_up {
+            current.delta_up = *value;
+        }
+        if let Some(value) = &self.delta_down {
+            current.delta_down = *value;
+        }
+        if let Some(value) = &self.delta_flat {
+            current.delta_flat = *value;
+        }
+        if let Some(value) = &self.description_fg {
+            current.description_fg = *value;
+        }
+        if let Some(value) = self.description_size {
+            current.description_size = value;
+        }
+        if let Some(value) = &self.skeleton_bg {
+            current.skeleton_bg = *value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct RatingOverrides {
-    pub active: Option<Hsla>,
-    pub inactive: Option<Hsla>,
-    pub sizes: Option<RatingSizeScale>,
+pub struct EmptyStateOverrides {
+    pub icon_fg: Option<Hsla>,
+    pub icon_bg: Option<Hsla>,
+    pub title_fg: Option<Hsla>,
+    pub description_fg: Option<Hsla>,
+    pub actions_gap: Option<Pixels>,
+    pub sizes: Option<EmptyStateSizeScale>,
 }
 
-impl RatingOverrides {
-    fn apply(&self, mut current: RatingTokens) -> RatingTokens {
-        if let Some(value) = &self.active {
-            current.active = *value;
+impl EmptyStateOverrides {
+    fn apply(&self, mut current: EmptyStateTokens) -> EmptyStateTokens {
+        if let Some(value) = &self.icon_fg {
+            current.icon_fg = *value;
         }
-        if let Some(value) = &self.inactive {
-            current.inactive = *value;
+        if let Some(value) = &self.icon_bg {
+            current.icon_bg = *value;
+        }
+        if let Some(value) = &self.title_fg {
+            current.title_fg = *value;
+        }
+        if let Some(value) = &self.description_fg {
+            current.description_fg = *value;
+        }
+        if let Some(value) = self.actions_gap {
+            current.actions_gap = value;
         }
         if let Some(value) = self.sizes {
             current.sizes = value;
@@ -8547,508 +11484,588 @@ impl RatingOverrides {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TabsOverrides {
-    pub list_bg: Option<Hsla>,
-    pub list_border: Option<Hsla>,
-    pub tab_fg: Option<Hsla>,
-    pub tab_active_bg: Option<Hsla>,
-    pub tab_active_fg: Option<Hsla>,
-    pub tab_hover_bg: Option<Hsla>,
-    pub tab_disabled_fg: Option<Hsla>,
-    pub panel_bg: Option<Hsla>,
-    pub panel_border: Option<Hsla>,
-    pub panel_fg: Option<Hsla>,
-    pub root_gap: Option<Pixels>,
-    pub list_gap: Option<Pixels>,
-    pub list_padding: Option<Pixels>,
-    pub panel_padding: Option<Pixels>,
-    pub sizes: Option<TabsSizeScale>,
+pub struct AnnouncementBarOverrides {
+    pub info_bg: Option<Hsla>,
+    pub info_fg: Option<Hsla>,
+    pub success_bg: Option<Hsla>,
+    pub success_fg: Option<Hsla>,
+    pub warning_bg: Option<Hsla>,
+    pub warning_fg: Option<Hsla>,
+    pub error_bg: Option<Hsla>,
+    pub error_fg: Option<Hsla>,
+    pub min_height: Option<Pixels>,
+    pub padding_x: Option<Pixels>,
+    pub padding_y: Option<Pixels>,
+    pub gap: Option<Pixels>,
+    pub font_size: Option<Pixels>,
+    pub icon_size: Option<Pixels>,
+    pub close_button_size: Option<Pixels>,
+    pub close_icon_size: Option<Pixels>,
 }
 
-impl TabsOverrides {
-    fn apply(&self, mut current: TabsTokens) -> TabsTokens {
-        if let Some(value) = &self.list_bg {
-            current.list_bg = *value;
+impl AnnouncementBarOverrides {
+    fn apply(&self, mut current: AnnouncementBarTokens) -> AnnouncementBarTokens {
+        if let Some(value) = &self.info_bg {
+            current.info_bg = *value;
         }
-        if let Some(value) = &self.list_border {
-            current.list_border = *value;
+        if let Some(value) = &self.info_fg {
+            current.info_fg = *value;
         }
-        if let Some(value) = &self.tab_fg {
-            current.tab_fg = *value;
+        if let Some(value) = &self.success_bg {
+            current.success_bg = *value;
         }
-        if let Some(value) = &self.tab_active_bg {
-            current.tab_active_bg = *value;
+        if let Some(value) = &self.success_fg {
+            current.success_fg = *value;
         }
-        if let Some(value) = &self.tab_active_fg {
-            current.tab_active_fg = *value;
+        if let Some(value) = &self.warning_bg {
+            current.warning_bg = *value;
         }
-        if let Some(value) = &self.tab_hover_bg {
-            current.tab_hover_bg = *value;
+        if let Some(value) = &self.warning_fg {
+            current.warning_fg = *value;
         }
-        if let Some(value) = &self.tab_disabled_fg {
-            current.tab_disabled_fg = *value;
+        if let Some(value) = &self.error_bg {
+            current.error_bg = *value;
         }
-        if let Some(value) = &self.panel_bg {
-            current.panel_bg = *value;
+        if let Some(value) = &self.error_fg {
+            current.error_fg = *value;
         }
-        if let Some(value) = &self.panel_border {
-            current.panel_border = *value;
+        if let Some(value) = self.min_height {
+            current.min_height = value;
         }
-        if let Some(value) = &self.panel_fg {
-            current.panel_fg = *value;
+        if let Some(value) = self.padding_x {
+            current.padding_x = value;
         }
-        if let Some(value) = self.root_gap {
-            current.root_gap = value;
+        if let Some(value) = self.padding_y {
+            current.padding_y = value;
         }
-        if let Some(value) = self.list_gap {
-            current.list_gap = value;
+        if let Some(value) = self.gap {
+            current.gap = value;
         }
-        if let Some(value) = self.list_padding {
-            current.list_padding = value;
+        if let Some(value) = self.font_size {
+            current.font_size = value;
         }
-        if let Some(value) = self.panel_padding {
-            current.panel_padding = value;
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.close_button_size {
+            current.close_button_size = value;
+        }
+        if let Some(value) = self.close_icon_size {
+            current.close_icon_size = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct PaginationOverrides {
-    pub item_bg: Option<Hsla>,
-    pub item_border: Option<Hsla>,
-    pub item_fg: Option<Hsla>,
-    pub item_active_bg: Option<Hsla>,
-    pub item_active_fg: Option<Hsla>,
-    pub item_hover_bg: Option<Hsla>,
-    pub item_disabled_fg: Option<Hsla>,
-    pub dots_fg: Option<Hsla>,
+pub struct WizardOverrides {
+    pub error_fg: Option<Hsla>,
+    pub error_size: Option<Pixels>,
+    pub footer_gap: Option<Pixels>,
     pub root_gap: Option<Pixels>,
-    pub sizes: Option<PaginationSizeScale>,
 }
 
-impl PaginationOverrides {
-    fn apply(&self, mut current: PaginationTokens) -> PaginationTokens {
-        if let Some(value) = &self.item_bg {
-            current.item_bg = *value;
+impl WizardOverrides {
+    fn apply(&self, mut current: WizardTokens) -> WizardTokens {
+        if let Some(value) = &self.error_fg {
+            current.error_fg = *value;
         }
-        if let Some(value) = &self.item_border {
-            current.item_border = *value;
+        if let Some(value) = self.error_size {
+            current.error_size = value;
         }
-        if let Some(value) = &self.item_fg {
-            current.item_fg = *value;
+        if let Some(value) = self.footer_gap {
+            current.footer_gap = value;
         }
-        if let Some(value) = &self.item_active_bg {
-            current.item_active_bg = *value;
+        if let Some(value) = self.root_gap {
+            current.root_gap = value;
         }
-        if let Some(value) = &self.item_active_fg {
-            current.item_active_fg = *value;
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EditableTextOverrides {
+    pub fg: Option<Hsla>,
+    pub placeholder: Option<Hsla>,
+    pub hover_bg: Option<Hsla>,
+    pub error_fg: Option<Hsla>,
+    pub text_size: Option<Pixels>,
+    pub error_size: Option<Pixels>,
+    pub padding_x: Option<Pixels>,
+    pub padding_y: Option<Pixels>,
+    pub radius: Option<Pixels>,
+    pub gap: Option<Pixels>,
+}
+
+impl EditableTextOverrides {
+    fn apply(&self, mut current: EditableTextTokens) -> EditableTextTokens {
+        if let Some(value) = &self.fg {
+            current.fg = *value;
         }
-        if let Some(value) = &self.item_hover_bg {
-            current.item_hover_bg = *value;
+        if let Some(value) = &self.placeholder {
+            current.placeholder = *value;
         }
-        if let Some(value) = &self.item_disabled_fg {
-            current.item_disabled_fg = *value;
+        if let Some(value) = &self.hover_bg {
+            current.hover_bg = *value;
         }
-        if let Some(value) = &self.dots_fg {
-            current.dots_fg = *value;
+        if let Some(value) = &self.error_fg {
+            current.error_fg = *value;
         }
-        if let Some(value) = self.root_gap {
-            current.root_gap = value;
+        if let Some(value) = self.text_size {
+            current.text_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.error_size {
+            current.error_size = value;
+        }
+        if let Some(value) = self.padding_x {
+            current.padding_x = value;
+        }
+        if let Some(value) = self.padding_y {
+            current.padding_y = value;
+        }
+        if let Some(value) = self.radius {
+            current.radius = value;
+        }
+        if let Some(value) = self.gap {
+            current.gap = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct BreadcrumbsOverrides {
-    pub item_fg: Option<Hsla>,
-    pub item_current_fg: Option<Hsla>,
-    pub separator: Option<Hsla>,
-    pub item_hover_bg: Option<Hsla>,
-    pub root_gap: Option<Pixels>,
-    pub sizes: Option<BreadcrumbsSizeScale>,
+pub struct CopyButtonOverrides {
+    pub copied_fg: Option<Hsla>,
+    pub icon_size: Option<Pixels>,
 }
 
-impl BreadcrumbsOverrides {
-    fn apply(&self, mut current: BreadcrumbsTokens) -> BreadcrumbsTokens {
-        if let Some(value) = &self.item_fg {
-            current.item_fg = *value;
+impl CopyButtonOverrides {
+    fn apply(&self, mut current: CopyButtonTokens) -> CopyButtonTokens {
+        if let Some(value) = &self.copied_fg {
+            current.copied_fg = *value;
         }
-        if let Some(value) = &self.item_current_fg {
-            current.item_current_fg = *value;
+        if let Some(value) = self.icon_size {
+            current.icon_size = value;
+        }
+        current
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChatViewOverrides {
+    pub own_bg: Option<Hsla>,
+    pub other_bg: Option<Hsla>,
+    pub author_fg: Option<Hsla>,
+    pub time_fg: Option<Hsla>,
+    pub separator_fg: Option<Hsla>,
+    pub separator_line: Option<Hsla>,
+    pub avatar_bg: Option<Hsla>,
+    pub avatar_fg: Option<Hsla>,
+    pub pill_bg: Option<Hsla>,
+    pub pill_fg: Option<Hsla>,
+    pub typing_fg: Option<Hsla>,
+    pub padding: Option<Pixels>,
+    pub message_gap: Option<Pixels>,
+    pub avatar_size: Option<Pixels>,
+    pub avatar_gap: Option<Pixels>,
+    pub bubble_radius: Option<Pixels>,
+    pub bubble_padding_x: Option<Pixels>,
+    pub bubble_padding_y: Option<Pixels>,
+    pub bubble_max_width: Option<Pixels>,
+    pub meta_size: Option<Pixels>,
+    pub separator_size: Option<Pixels>,
+    pub estimated_row_height: Option<Pixels>,
+    pub pill_offset: Option<Pixels>,
+}
+
+impl ChatViewOverrides {
+    fn apply(&self, mut current: ChatViewTokens) -> ChatViewTokens {
+        if let Some(value) = &self.own_bg {
+            current.own_bg = *value;
+        }
+        if let Some(value) = &self.other_bg {
+            current.other_bg = *value;
+        }
+        if let Some(value) = &self.author_fg {
+            current.author_fg = *value;
+        }
+        if let Some(value) = &self.time_fg {
+            current.time_fg = *value;
+        }
+        if let Some(value) = &self.separator_fg {
+            current.separator_fg = *value;
+        }
+        if let Some(value) = &self.separator_line {
+            current.separator_line = *value;
+        }
+        if let Some(value) = &self.avatar_bg {
+            current.avatar_bg = *value;
+        }
+        if let Some(value) = &self.avatar_fg {
+            current.avatar_fg = *value;
+        }
+        if let Some(value) = &self.pill_bg {
+            current.pill_bg = *value;
+        }
+        if let Some(value) = &self.pill_fg {
+            current.pill_fg = *value;
+        }
+        if let Some(value) = &self.typing_fg {
+            current.typing_fg = *value;
+        }
+        if let Some(value) = self.padding {
+            current.padding = value;
+        }
+        if let Some(value) = self.message_gap {
+            current.message_gap = value;
+        }
+        if let Some(value) = self.avatar_size {
+            current.avatar_size = value;
+        }
+        if let Some(value) = self.avatar_gap {
+            current.avatar_gap = value;
+        }
+        if let Some(value) = self.bubble_radius {
+            current.bubble_radius = value;
+        }
+        if let Some(value) = self.bubble_padding_x {
+            current.bubble_padding_x = value;
+        }
+        if let Some(value) = self.bubble_padding_y {
+            current.bubble_padding_y = value;
         }
-        if let Some(value) = &self.separator {
-            current.separator = *value;
+        if let Some(value) = self.bubble_max_width {
+            current.bubble_max_width = value;
         }
-        if let Some(value) = &self.item_hover_bg {
-            current.item_hover_bg = *value;
+        if let Some(value) = self.meta_size {
+            current.meta_size = value;
         }
-        if let Some(value) = self.root_gap {
-            current.root_gap = value;
+        if let Some(value) = self.separator_size {
+            current.separator_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.estimated_row_height {
+            current.estimated_row_height = value;
+        }
+        if let Some(value) = self.pill_offset {
+            current.pill_offset = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TableOverrides {
-    pub header_bg: Option<Hsla>,
-    pub header_fg: Option<Hsla>,
-    pub row_bg: Option<Hsla>,
-    pub row_alt_bg: Option<Hsla>,
-    pub row_hover_bg: Option<Hsla>,
-    pub row_border: Option<Hsla>,
-    pub cell_fg: Option<Hsla>,
-    pub caption: Option<Hsla>,
-    pub caption_size: Option<Pixels>,
-    pub row_gap: Option<Pixels>,
-    pub pagination_summary_size: Option<Pixels>,
-    pub page_chip_size: Option<Pixels>,
-    pub page_chip_padding_x: Option<Pixels>,
-    pub page_chip_padding_y: Option<Pixels>,
-    pub page_chip_radius: Option<Pixels>,
-    pub page_chip_gap: Option<Pixels>,
-    pub pagination_items_gap: Option<Pixels>,
-    pub pagination_padding_x: Option<Pixels>,
-    pub pagination_padding_y: Option<Pixels>,
-    pub pagination_gap: Option<Pixels>,
-    pub virtualization_padding: Option<Pixels>,
-    pub min_viewport_height: Option<Pixels>,
-    pub sizes: Option<TableSizeScale>,
+pub struct LogViewOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub fg: Option<Hsla>,
+    pub muted_fg: Option<Hsla>,
+    pub gutter_fg: Option<Hsla>,
+    pub warn_fg: Option<Hsla>,
+    pub error_fg: Option<Hsla>,
+    pub selection_bg: Option<Hsla>,
+    pub ansi_black: Option<Hsla>,
+    pub ansi_red: Option<Hsla>,
+    pub ansi_green: Option<Hsla>,
+    pub ansi_yellow: Option<Hsla>,
+    pub ansi_blue: Option<Hsla>,
+    pub ansi_magenta: Option<Hsla>,
+    pub ansi_cyan: Option<Hsla>,
+    pub ansi_white: Option<Hsla>,
+    pub text_size: Option<Pixels>,
+    pub line_height: Option<Pixels>,
+    pub padding: Option<Pixels>,
+    pub gutter_gap: Option<Pixels>,
+    pub radius: Option<Pixels>,
 }
 
-impl TableOverrides {
-    fn apply(&self, mut current: TableTokens) -> TableTokens {
-        if let Some(value) = &self.header_bg {
-            current.header_bg = *value;
-        }
-        if let Some(value) = &self.header_fg {
-            current.header_fg = *value;
-        }
-        if let Some(value) = &self.row_bg {
-            current.row_bg = *value;
+impl LogViewOverrides {
+    fn apply(&self, mut current: LogViewTokens) -> LogViewTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = &self.row_alt_bg {
-            current.row_alt_bg = *value;
+        if let Some(value) = &self.border {
+            current.border = *value;
         }
-        if let Some(value) = &self.row_hover_bg {
-            current.row_hover_bg = *value;
+        if let Some(value) = &self.fg {
+            current.fg = *value;
         }
-        if let Some(value) = &self.row_border {
-            current.row_border = *value;
+        if let Some(value) = &self.muted_fg {
+            current.muted_fg = *value;
         }
-        if let Some(value) = &self.cell_fg {
-            current.cell_fg = *value;
+        if let Some(value) = &self.gutter_fg {
+            current.gutter_fg = *value;
         }
-        if let Some(value) = &self.caption {
-            current.caption = *value;
+        if let Some(value) = &self.warn_fg {
+            current.warn_fg = *value;
         }
-        if let Some(value) = self.caption_size {
-            current.caption_size = value;
+        if let Some(value) = &self.error_fg {
+            current.error_fg = *value;
         }
-        if let Some(value) = self.row_gap {
-            current.row_gap = value;
+        if let Some(value) = &self.selection_bg {
+            current.selection_bg = *value;
         }
-        if let Some(value) = self.pagination_summary_size {
-            current.pagination_summary_size = value;
+        if let Some(value) = &self.ansi_black {
+            current.ansi_black = *value;
         }
-        if let Some(value) = self.page_chip_size {
-            current.page_chip_size = value;
+        if let Some(value) = &self.ansi_red {
+            current.ansi_red = *value;
         }
-        if let Some(value) = self.page_chip_padding_x {
-            current.page_chip_padding_x = value;
+        if let Some(value) = &self.ansi_green {
+            current.ansi_green = *value;
         }
-        if let Some(value) = self.page_chip_padding_y {
-            current.page_chip_padding_y = value;
+        if let Some(value) = &self.ansi_yellow {
+            current.ansi_yellow = *value;
         }
-        if let Some(value) = self.page_chip_radius {
-            current.page_chip_radius = value;
+        if let Some(value) = &self.ansi_blue {
+            current.ansi_blue = *value;
         }
-        if let Some(value) = self.page_chip_gap {
-            current.page_chip_gap = value;
+        if let Some(value) = &self.ansi_magenta {
+            current.ansi_magenta = *value;
         }
-        if let Some(value) = self.pagination_items_gap {
-            current.pagination_items_gap = value;
+        if let Some(value) = &self.ansi_cyan {
+            current.ansi_cyan = *value;
         }
-        if let Some(value) = self.pagination_padding_x {
-            current.pagination_padding_x = value;
+        if let Some(value) = &self.ansi_white {
+            current.ansi_white = *value;
         }
-        if let Some(value) = self.pagination_padding_y {
-            current.pagination_padding_y = value;
+        if let Some(value) = self.text_size {
+            current.text_size = value;
         }
-        if let Some(value) = self.pagination_gap {
-            current.pagination_gap = value;
+        if let Some(value) = self.line_height {
+            current.line_height = value;
         }
-        if let Some(value) = self.virtualization_padding {
-            current.virtualization_padding = value;
+        if let Some(value) = self.padding {
+            current.padding = value;
         }
-        if let Some(value) = self.min_viewport_height {
-            current.min_viewport_height = value;
+        if let Some(value) = self.gutter_gap {
+            current.gutter_gap = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.radius {
+            current.radius = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct StepperOverrides {
-    pub step_bg: Option<Hsla>,
-    pub step_border: Option<Hsla>,
-    pub step_fg: Option<Hsla>,
-    pub step_active_bg: Option<Hsla>,
-    pub step_active_border: Option<Hsla>,
-    pub step_active_fg: Option<Hsla>,
-    pub step_completed_bg: Option<Hsla>,
-    pub step_completed_border: Option<Hsla>,
-    pub step_completed_fg: Option<Hsla>,
-    pub connector: Option<Hsla>,
-    pub label: Option<Hsla>,
-    pub description: Option<Hsla>,
-    pub panel_bg: Option<Hsla>,
-    pub panel_border: Option<Hsla>,
-    pub panel_fg: Option<Hsla>,
-    pub root_gap: Option<Pixels>,
-    pub steps_gap_vertical: Option<Pixels>,
-    pub text_gap: Option<Pixels>,
-    pub panel_margin_top: Option<Pixels>,
-    pub sizes: Option<StepperSizeScale>,
+pub struct JsonTreeOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub punctuation_fg: Option<Hsla>,
+    pub key_fg: Option<Hsla>,
+    pub index_fg: Option<Hsla>,
+    pub string_fg: Option<Hsla>,
+    pub number_fg: Option<Hsla>,
+    pub boolean_fg: Option<Hsla>,
+    pub null_fg: Option<Hsla>,
+    pub summary_fg: Option<Hsla>,
+    pub toggle_fg: Option<Hsla>,
+    pub more_fg: Option<Hsla>,
+    pub row_hover_bg: Option<Hsla>,
+    pub match_bg: Option<Hsla>,
+    pub text_size: Option<Pixels>,
+    pub row_height: Option<Pixels>,
+    pub indent: Option<Pixels>,
+    pub padding: Option<Pixels>,
+    pub toggle_size: Option<Pixels>,
+    pub radius: Option<Pixels>,
 }
 
-impl StepperOverrides {
-    fn apply(&self, mut current: StepperTokens) -> StepperTokens {
-        if let Some(value) = &self.step_bg {
-            current.step_bg = *value;
+impl JsonTreeOverrides {
+    fn apply(&self, mut current: JsonTreeTokens) -> JsonTreeTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = &self.step_border {
-            current.step_border = *value;
+        if let Some(value) = &self.border {
+            current.border = *value;
         }
-        if let Some(value) = &self.step_fg {
-            current.step_fg = *value;
+        if let Some(value) = &self.punctuation_fg {
+            current.punctuation_fg = *value;
         }
-        if let Some(value) = &self.step_active_bg {
-            current.step_active_bg = *value;
+        if let Some(value) = &self.key_fg {
+            current.key_fg = *value;
         }
-        if let Some(value) = &self.step_active_border {
-            current.step_active_border = *value;
+        if let Some(value) = &self.index_fg {
+            current.index_fg = *value;
         }
-        if let Some(value) = &self.step_active_fg {
-            current.step_active_fg = *value;
+        if let Some(value) = &self.string_fg {
+            current.string_fg = *value;
         }
-        if let Some(value) = &self.step_completed_bg {
-            current.step_completed_bg = *value;
+        if let Some(value) = &self.number_fg {
+            current.number_fg = *value;
         }
-        if let Some(value) = &self.step_completed_border {
-            current.step_completed_border = *value;
+        if let Some(value) = &self.boolean_fg {
+            current.boolean_fg = *value;
         }
-        if let Some(value) = &self.step_completed_fg {
-            current.step_completed_fg = *value;
+        if let Some(value) = &self.null_fg {
+            current.null_fg = *value;
         }
-        if let Some(value) = &self.connector {
-            current.connector = *value;
+        if let Some(value) = &self.summary_fg {
+            current.summary_fg = *value;
         }
-        if let Some(value) = &self.label {
-            current.label = *value;
+        if let Some(value) = &self.toggle_fg {
+            current.toggle_fg = *value;
         }
-        if let Some(value) = &self.description {
-            current.description = *value;
+        if let Some(value) = &self.more_fg {
+            current.more_fg = *value;
         }
-        if let Some(value) = &self.panel_bg {
-            current.panel_bg = *value;
+        if let Some(value) = &self.row_hover_bg {
+            current.row_hover_bg = *value;
         }
-        if let Some(value) = &self.panel_border {
-            current.panel_border = *value;
+        if let Some(value) = &self.match_bg {
+            current.match_bg = *value;
         }
-        if let Some(value) = &self.panel_fg {
-            current.panel_fg = *value;
+        if let Some(value) = self.text_size {
+            current.text_size = value;
         }
-        if let Some(value) = self.root_gap {
-            current.root_gap = value;
+        if let Some(value) = self.row_height {
+            current.row_height = value;
         }
-        if let Some(value) = self.steps_gap_vertical {
-            current.steps_gap_vertical = value;
+        if let Some(value) = self.indent {
+            current.indent = value;
         }
-        if let Some(value) = self.text_gap {
-            current.text_gap = value;
+        if let Some(value) = self.padding {
+            current.padding = value;
         }
-        if let Some(value) = self.panel_margin_top {
-            current.panel_margin_top = value;
+        if let Some(value) = self.toggle_size {
+            current.toggle_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.radius {
+            current.radius = value;
         }
         current
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TimelineOverrides {
-    pub bullet_bg: Option<Hsla>,
-    pub bullet_border: Option<Hsla>,
-    pub bullet_fg: Option<Hsla>,
-    pub bullet_active_bg: Option<Hsla>,
-    pub bullet_active_border: Option<Hsla>,
-    pub bullet_active_fg: Option<Hsla>,
-    pub line: Option<Hsla>,
-    pub line_active: Option<Hsla>,
-    pub title: Option<Hsla>,
-    pub title_active: Option<Hsla>,
-    pub body: Option<Hsla>,
-    pub card_bg: Option<Hsla>,
-    pub card_border: Option<Hsla>,
-    pub root_gap: Option<Pixels>,
-    pub row_gap: Option<Pixels>,
-    pub content_gap: Option<Pixels>,
-    pub card_margin_top: Option<Pixels>,
-    pub row_padding_y: Option<Pixels>,
-    pub line_min_height: Option<Pixels>,
-    pub line_extra_height: Option<Pixels>,
-    pub sizes: Option<TimelineSizeScale>,
+pub struct SettingsViewOverrides {
+    pub bg: Option<Hsla>,
+    pub border: Option<Hsla>,
+    pub nav_bg: Option<Hsla>,
+    pub nav_fg: Option<Hsla>,
+    pub nav_hover_bg: Option<Hsla>,
+    pub nav_active_bg: Option<Hsla>,
+    pub nav_active_fg: Option<Hsla>,
+    pub count_fg: Option<Hsla>,
+    pub title_fg: Option<Hsla>,
+    pub description_fg: Option<Hsla>,
+    pub label_fg: Option<Hsla>,
+    pub separator: Option<Hsla>,
+    pub match_bg: Option<Hsla>,
+    pub empty_fg: Option<Hsla>,
+    pub nav_width: Option<Pixels>,
+    pub nav_padding: Option<Pixels>,
+    pub nav_gap: Option<Pixels>,
+    pub nav_item_padding_x: Option<Pixels>,
+    pub nav_item_padding_y: Option<Pixels>,
+    pub nav_item_radius: Option<Pixels>,
+    pub nav_item_size: Option<Pixels>,
+    pub content_padding: Option<Pixels>,
+    pub content_max_width: Option<Pixels>,
+    pub section_gap: Option<Pixels>,
+    pub title_size: Option<Pixels>,
+    pub heading_size: Option<Pixels>,
+    pub description_size: Option<Pixels>,
+    pub label_size: Option<Pixels>,
+    pub item_gap: Option<Pixels>,
+    pub item_padding_y: Option<Pixels>,
+    pub control_width: Option<Pixels>,
 }
 
-impl TimelineOverrides {
-    fn apply(&self, mut current: TimelineTokens) -> TimelineTokens {
-        if let Some(value) = &self.bullet_bg {
-            current.bullet_bg = *value;
-        }
-        if let Some(value) = &self.bullet_border {
-            current.bullet_border = *value;
-        }
-        if let Some(value) = &self.bullet_fg {
-            current.bullet_fg = *value;
+impl SettingsViewOverrides {
+    fn apply(&self, mut current: SettingsViewTokens) -> SettingsViewTokens {
+        if let Some(value) = &self.bg {
+            current.bg = *value;
         }
-        if let Some(value) = &self.bullet_active_bg {
-            current.bullet_active_bg = *value;
+        if let Some(value) = &self.border {
+            current.border = *value;
         }
-        if let Some(value) = &self.bullet_active_border {
-            current.bullet_active_border = *value;
+        if let Some(value) = &self.nav_bg {
+            current.nav_bg = *value;
         }
-        if let Some(value) = &self.bullet_active_fg {
-            current.bullet_active_fg = *value;
+        if let Some(value) = &self.nav_fg {
+            current.nav_fg = *value;
         }
-        if let Some(value) = &self.line {
-            current.line = *value;
+        if let Some(value) = &self.nav_hover_bg {
+            current.nav_hover_bg = *value;
         }
-        if let Some(value) = &self.line_active {
-            current.line_active = *value;
+        if let Some(value) = &self.nav_active_bg {
+            current.nav_active_bg = *value;
         }
-        if let Some(value) = &self.title {
-            current.title = *value;
+        if let Some(value) = &self.nav_active_fg {
+            current.nav_active_fg = *value;
         }
-        if let Some(value) = &self.title_active {
-            current.title_active = *value;
+        if let Some(value) = &self.count_fg {
+            current.count_fg = *value;
         }
-        if let Some(value) = &self.body {
-            current.body = *value;
+        if let Some(value) = &self.title_fg {
+            current.title_fg = *value;
         }
-        if let Some(value) = &self.card_bg {
-            current.card_bg = *value;
+        if let Some(value) = &self.description_fg {
+            current.description_fg = *value;
         }
-        if let Some(value) = &self.card_border {
-            current.card_border = *value;
+        if let Some(value) = &self.label_fg {
+            current.label_fg = *value;
         }
-        if let Some(value) = self.root_gap {
-            current.root_gap = value;
+        if let Some(value) = &self.separator {
+            current.separator = *value;
         }
-        if let Some(value) = self.row_gap {
-            current.row_gap = value;
+        if let Some(value) = &self.match_bg {
+            current.match_bg = *value;
         }
-        if let Some(value) = self.content_gap {
-            current.content_gap = value;
+        if let Some(value) = &self.empty_fg {
+            current.empty_fg = *value;
         }
-        if let Some(value) = self.card_margin_top {
-            current.card_margin_top = value;
+        if let Some(value) = self.nav_width {
+            current.nav_width = value;
         }
-        if let Some(value) = self.row_padding_y {
-            current.row_padding_y = value;
+        if let Some(value) = self.nav_padding {
+            current.nav_padding = value;
         }
-        if let Some(value) = self.line_min_height {
-            current.line_min_height = value;
+        if let Some(value) = self.nav_gap {
+            current.nav_gap = value;
         }
-        if let Some(value) = self.line_extra_height {
-            current.line_extra_height = value;
+        if let Some(value) = self.nav_item_padding_x {
+            current.nav_item_padding_x = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.nav_item_padding_y {
+            current.nav_item_padding_y = value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct TreeOverrides {
-    pub row_fg: Option<Hsla>,
-    pub row_selected_fg: Option<Hsla>,
-    pub row_selected_bg: Option<Hsla>,
-    pub row_hover_bg: Option<Hsla>,
-    pub row_disabled_fg: Option<Hsla>,
-    pub line: Option<Hsla>,
-    pub root_gap: Option<Pixels>,
-    pub children_gap: Option<Pixels>,
-    pub sizes: Option<TreeSizeScale>,
-}
-
-impl TreeOverrides {
-    fn apply(&self, mut current: TreeTokens) -> TreeTokens {
-        if let Some(value) = &self.row_fg {
-            current.row_fg = *value;
+        if let Some(value) = self.nav_item_radius {
+            current.nav_item_radius = value;
         }
-        if let Some(value) = &self.row_selected_fg {
-            current.row_selected_fg = *value;
+        if let Some(value) = self.nav_item_size {
+            current.nav_item_size = value;
         }
-        if let Some(value) = &self.row_selected_bg {
-            current.row_selected_bg = *value;
+        if let Some(value) = self.content_padding {
+            current.content_padding = value;
         }
-        if let Some(value) = &self.row_hover_bg {
-            current.row_hover_bg = *value;
+        if let Some(value) = self.content_max_width {
+            current.content_max_width = value;
         }
-        if let Some(value) = &self.row_disabled_fg {
-            current.row_disabled_fg = *value;
+        if let Some(value) = self.section_gap {
+            current.section_gap = value;
         }
-        if let Some(value) = &self.line {
-            current.line = *value;
+        if let Some(value) = self.title_size {
+            current.title_size = value;
         }
-        if let Some(value) = self.root_gap {
-            current.root_gap = value;
+        if let Some(value) = self.heading_size {
+            current.heading_size = value;
         }
-        if let Some(value) = self.children_gap {
-            current.children_gap = value;
+        if let Some(value) = self.description_size {
+            current.description_size = value;
         }
-        if let Some(value) = self.sizes {
-            current.sizes = value;
+        if let Some(value) = self.label_size {
+            current.label_size = value;
         }
-        current
-    }
-}
-
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct LayoutOverrides {
-    pub gap: Option<GapSizeScale>,
-    pub space: Option<GapSizeScale>,
-    pub popup_snap_margin: Option<Pixels>,
-}
-
-impl LayoutOverrides {
-    fn apply(&self, mut current: LayoutTokens) -> LayoutTokens {
-        if let Some(value) = self.gap {
-            current.gap = value;
+        if let Some(value) = self.item_gap {
+            current.item_gap = value;
         }
-        if let Some(value) = self.space {
-            current.space = value;
+        if let Some(value) = self.item_padding_y {
+            current.item_padding_y = value;
         }
-        if let Some(value) = self.popup_snap_margin {
-            current.popup_snap_margin = value;
+        if let Some(value) = self.control_width {
+            current.control_width = value;
         }
         current
     }
@@ -9099,7 +12116,37 @@ pub struct ComponentOverrides {
     pub stepper: StepperOverrides,
     pub timeline: TimelineOverrides,
     pub tree: TreeOverrides,
+    pub zoom_pane: ZoomPaneOverrides,
+    pub graph_canvas: GraphCanvasOverrides,
+    pub date_picker: DatePickerOverrides,
+    pub rulers: RulersOverrides,
+    pub color_picker: ColorPickerOverrides,
+    pub file_input: FileInputOverrides,
     pub layout: LayoutOverrides,
+    pub focus_ring: FocusRingOverrides,
+    pub press_feedback: PressFeedbackOverrides,
+    pub async_content: AsyncContentOverrides,
+    pub checklist: ChecklistOverrides,
+    pub task_status: TaskStatusOverrides,
+    pub notification_center: NotificationCenterOverrides,
+    pub find_bar: FindBarOverrides,
+    pub split_pane: SplitPaneOverrides,
+    pub dock_tabs: DockTabsOverrides,
+    pub image: ImageOverrides,
+    pub calendar: CalendarOverrides,
+    pub kanban_board: KanbanBoardOverrides,
+    pub chart: ChartOverrides,
+    pub ring_progress: RingProgressOverrides,
+    pub stat_card: StatCardOverrides,
+    pub empty_state: EmptyStateOverrides,
+    pub announcement_bar: AnnouncementBarOverrides,
+    pub wizard: WizardOverrides,
+    pub editable_text: EditableTextOverrides,
+    pub copy_button: CopyButtonOverrides,
+    pub chat_view: ChatViewOverrides,
+    pub log_view: LogViewOverrides,
+    pub json_tree: JsonTreeOverrides,
+    pub settings_view: SettingsViewOverrides,
 }
 
 impl ComponentOverrides {
@@ -9148,7 +12195,37 @@ impl ComponentOverrides {
             stepper: self.stepper.apply(current.stepper),
             timeline: self.timeline.apply(current.timeline),
             tree: self.tree.apply(current.tree),
+            zoom_pane: self.zoom_pane.apply(current.zoom_pane),
+            graph_canvas: self.graph_canvas.apply(current.graph_canvas),
+            date_picker: self.date_picker.apply(current.date_picker),
+            rulers: self.rulers.apply(current.rulers),
+            color_picker: self.color_picker.apply(current.color_picker),
+            file_input: self.file_input.apply(current.file_input),
             layout: self.layout.apply(current.layout),
+            focus_ring: self.focus_ring.apply(current.focus_ring),
+            press_feedback: self.press_feedback.apply(current.press_feedback),
+            async_content: self.async_content.apply(current.async_content),
+            checklist: self.checklist.apply(current.checklist),
+            task_status: self.task_status.apply(current.task_status),
+            notification_center: self.notification_center.apply(current.notification_center),
+            find_bar: self.find_bar.apply(current.find_bar),
+            split_pane: self.split_pane.apply(current.split_pane),
+            dock_tabs: self.dock_tabs.apply(current.dock_tabs),
+            image: self.image.apply(current.image),
+            calendar: self.calendar.apply(current.calendar),
+            kanban_board: self.kanban_board.apply(current.kanban_board),
+            chart: self.chart.apply(current.chart),
+            ring_progress: self.ring_progress.apply(current.ring_progress),
+            stat_card: self.stat_card.apply(current.stat_card),
+            empty_state: self.empty_state.apply(current.empty_state),
+            announcement_bar: self.announcement_bar.apply(current.announcement_bar),
+            wizard: self.wizard.apply(current.wizard),
+            editable_text: self.editable_text.apply(current.editable_text),
+            copy_button: self.copy_button.apply(current.copy_button),
+            chat_view: self.chat_view.apply(current.chat_view),
+            log_view: self.log_view.apply(current.log_view),
+            json_tree: self.json_tree.apply(current.json_tree),
+            settings_view: self.settings_view.apply(current.settings_view),
         }
     }
 }
@@ -9223,7 +12300,7 @@ impl std::ops::Deref for LocalTheme {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tokens::COLOR_STOPS;
+    use crate::tokens::{COLOR_STOPS, PALETTE_KEYS};
 
     #[test]
     fn default_theme_uses_blue_as_primary_color() {
@@ -9236,7 +12313,9 @@ mod tests {
     #[test]
     fn default_palette_is_complete() {
         let theme = Theme::default();
-        assert_eq!(theme.palette.len(), 14);
+        for key in PALETTE_KEYS {
+            assert_eq!(theme.palette[&key], PaletteCatalog::scale(key));
+        }
         assert_eq!(theme.palette[&PaletteKey::Blue].len(), COLOR_STOPS);
     }
 
@@ -9264,6 +12343,252 @@ mod tests {
         assert_eq!(next.semantic.text_secondary, base.semantic.text_secondary);
     }
 
+    #[test]
+    fn theme_seed_derives_neutrals_surface_and_contrast() {
+        let cream = resolve_hex_hsla("#fdf6e3");
+        let seed = ThemeSeed::new(PaletteKey::Teal)
+            .gray(PaletteKey::Dark)
+            .surface(ColorScheme::Light, cream);
+        let light = Theme::from_seed(seed, ColorScheme::Light);
+        assert_eq!(light.primary_color, PaletteKey::Teal);
+        assert_eq!(
+            light.components.button.filled_bg,
+            resolve_palette_hsla(PaletteKey::Teal, 6)
+        );
+        assert_eq!(
+            light.semantic.text_secondary,
+            resolve_palette_hsla(PaletteKey::Dark, 7)
+        );
+        assert_eq!(light.semantic.bg_canvas, cream);
+        assert_eq!(light.components.input.bg, cream);
+
+        let dark = light.with_color_scheme(ColorScheme::Dark);
+        assert_eq!(dark.seed, seed);
+        assert_eq!(
+            dark.components.input.bg,
+            resolve_palette_hsla(PaletteKey::Dark, 8)
+        );
+        assert_eq!(
+            dark.semantic.bg_canvas,
+            resolve_palette_hsla(PaletteKey::Dark, 9)
+        );
+        let night = resolve_hex_hsla("#1b1f24");
+        let dark = dark.with_seed(seed.surface(ColorScheme::Dark, night));
+        assert_eq!(dark.components.input.bg, night);
+        let light = Theme::from_seed(dark.seed, ColorScheme::Light);
+        assert_eq!(light.components.input.bg, cream);
+
+        let high = Theme::from_seed(
+            ThemeSeed::default().contrast(ContrastLevel::High),
+            ColorScheme::Light,
+        );
+        let standard = Theme::default();
+        assert_eq!(
+            high.semantic.border_subtle,
+            resolve_palette_hsla(PaletteKey::Gray, 4)
+        );
+        assert_eq!(high.semantic.bg_surface, standard.semantic.bg_surface);
+        assert_eq!(
+            standard.components,
+            ComponentTokens::defaults_for(PaletteKey::Blue, ColorScheme::Light)
+        );
+    }
+
+    #[test]
+    fn high_contrast_semantic_tokens_meet_wcag_targets() {
+        for scheme in [ColorScheme::Light, ColorScheme::Dark] {
+            let standard = Theme::default().with_color_scheme(scheme);
+            let high = standard.clone().with_contrast(ContrastLevel::High);
+            let semantic = &high.semantic;
+            assert_eq!(high.seed.contrast, ContrastLevel::High);
+            assert_ne!(semantic, &standard.semantic);
+
+            for bg in [semantic.bg_canvas, semantic.bg_surface, semantic.bg_soft] {
+                let ratio = |fg| color_utils::contrast_ratio(fg, bg);
+                assert!(ratio(semantic.text_primary) >= 7.0);
+                assert!(ratio(semantic.text_secondary) >= 7.0);
+                assert!(ratio(semantic.text_muted) >= 4.5);
+                assert!(ratio(semantic.status_warning) >= 4.5);
+                assert!(ratio(semantic.border_strong) >= 3.0);
+                assert!(ratio(semantic.focus_ring) >= 3.0);
+            }
+        }
+
+        let bg = white();
+        let fg = resolve_palette_hsla(PaletteKey::Gray, 5);
+        let raised = color_utils::ensure_contrast(fg, bg, 4.5);
+        assert!(color_utils::contrast_ratio(raised, bg) >= 4.5);
+        assert_eq!(color_utils::ensure_contrast(black(), bg, 4.5), black());
+    }
+
+    #[test]
+    fn high_contrast_raises_component_pairs_until_audit_passes() {
+        for scheme in [ColorScheme::Light, ColorScheme::Dark] {
+            let standard = Theme::default().with_color_scheme(scheme);
+            let high = standard.clone().with_contrast(ContrastLevel::High);
+            let report = high.audit();
+            assert!(report.passes(), "{scheme:?}: {:#?}", report.findings);
+            assert_eq!(
+                Theme::from_seed(ThemeSeed::default().contrast(ContrastLevel::High), scheme),
+                high
+            );
+            assert_eq!(
+                high.components.button.filled_fg,
+                standard.components.button.filled_fg
+            );
+        }
+
+        let light = Theme::default().with_contrast(ContrastLevel::High);
+        assert_ne!(
+            light.components.button.filled_bg,
+            Theme::default().components.button.filled_bg
+        );
+        assert_ne!(
+            light.components.input.placeholder,
+            Theme::default().components.input.placeholder
+        );
+    }
+
+    #[test]
+    fn audit_reports_low_contrast_pairs_and_unknown_paths() {
+        let report = Theme::default().audit();
+        assert_eq!(report.checked, audit::DEFAULT_PAIRS.len());
+        assert!(report.unknown_paths.is_empty());
+        assert_eq!(
+            report.findings_for("semantic.text_primary").count(),
+            0,
+            "{:#?}",
+            report.findings
+        );
+        let placeholder = report
+            .findings_for("components.input.placeholder")
+            .next()
+            .expect("light placeholder is below 4.5:1");
+        assert_eq!(placeholder.pair.background, "components.input.bg");
+        assert!(placeholder.ratio < placeholder.required());
+        assert_eq!(report.findings_for("semantic.text_muted").count(), 1);
+
+        for scheme in [ColorScheme::Light, ColorScheme::Dark] {
+            let high = Theme::default()
+                .with_color_scheme(scheme)
+                .with_contrast(ContrastLevel::High)
+                .audit();
+            assert_eq!(high.checked, audit::DEFAULT_PAIRS.len());
+            assert!(high.passes(), "{scheme:?}: {:#?}", high.findings);
+        }
+
+        let faded = Theme::default()
+            .with_contrast(ContrastLevel::High)
+            .with_overrides(|overrides| {
+                overrides
+                    .tooltip(|tooltip| tooltip.fg(gpui::white()).bg(resolve_hex_hsla("#f1f3f5")))
+            })
+            .audit();
+        assert_eq!(faded.findings.len(), 1, "{:#?}", faded.findings);
+        let finding = faded.findings[0];
+        assert_eq!(
+            finding.pair,
+            audit::ContrastPair::text("components.tooltip.fg", "components.tooltip.bg")
+        );
+        assert!(finding.ratio < finding.required());
+
+        let custom = audit::audit_pairs(
+            &Theme::default(),
+            &[
+                audit::ContrastPair::non_text("semantic.bg_canvas", "components.input.bg"),
+                audit::ContrastPair::text("semantic.missing", "semantic.bg_canvas"),
+            ],
+        );
+        assert_eq!(custom.checked, 1);
+        assert_eq!(custom.findings.len(), 1);
+        assert_eq!(custom.findings[0].ratio, 1.0);
+        assert_eq!(custom.unknown_paths, vec!["semantic.missing"]);
+        assert!(!custom.passes());
+    }
+
+    #[test]
+    fn monospace_font_is_a_stack_with_fallbacks() {
+        let theme = Theme::default();
+        let mono = theme.fonts.monospace();
+        assert_eq!(mono.family, theme.fonts.monospace[0]);
+        assert!(mono.fallbacks.is_some());
+
+        let single = theme.with_fonts(ThemeFonts {
+            monospace: vec!["Iosevka".into()],
+        });
+        let mono = single.fonts.monospace();
+        assert_eq!(mono.family, SharedString::from("Iosevka"));
+        assert!(mono.fallbacks.is_none());
+    }
+
+    #[test]
+    fn theme_builder_scales_radius_and_spacing_before_overrides() {
+        let base = Theme::default()
+            .with_color_scheme(ColorScheme::Dark)
+            .with_primary_color(PaletteKey::Violet);
+        let theme = ThemeBuilder::dark()
+            .primary(PaletteKey::Violet)
+            .radius_scale(2.0)
+            .spacing_scale(1.5)
+            .log_view(|log_view| log_view.padding(px(3.0)))
+            .build();
+
+        assert_eq!(theme.color_scheme, ColorScheme::Dark);
+        assert_eq!(theme.primary_color, PaletteKey::Violet);
+        assert_eq!(theme.semantic, base.semantic);
+        assert_eq!(theme.radii.md, base.radii.md * 2.0);
+        assert_eq!(theme.radii.pill, base.radii.pill);
+        let (tokens, base_tokens) = (&theme.components, &base.components);
+        assert_eq!(tokens.log_view.radius, base_tokens.log_view.radius * 2.0);
+        assert_eq!(tokens.layout.gap.md, base_tokens.layout.gap.md * 1.5);
+        assert_eq!(
+            tokens.button.sizes.md.padding_x,
+            base_tokens.button.sizes.md.padding_x * 1.5
+        );
+        assert_eq!(
+            tokens.button.sizes.md.font_size,
+            base_tokens.button.sizes.md.font_size
+        );
+        assert_eq!(
+            tokens.log_view.line_height,
+            base_tokens.log_view.line_height
+        );
+        assert_eq!(tokens.log_view.padding, px(3.0));
+        assert_eq!(ThemeBuilder::light().build(), Theme::default());
+    }
+
+    #[test]
+    fn theme_builder_palette_override_is_order_independent() {
+        let scale = color_utils::scale_from_color(resolve_hex_hsla("#2f9e44"));
+        let primary_first = ThemeBuilder::dark()
+            .primary(PaletteKey::Teal)
+            .palette_override(PaletteKey::Teal, scale)
+            .build();
+        let override_first = ThemeBuilder::dark()
+            .palette_override(PaletteKey::Teal, scale)
+            .primary(PaletteKey::Teal)
+            .build();
+
+        assert_eq!(primary_first, override_first);
+        assert_eq!(primary_first.palette[&PaletteKey::Teal], scale);
+        assert_eq!(
+            primary_first,
+            Theme::default()
+                .with_palette_override(PaletteKey::Teal, scale)
+                .with_primary_color(PaletteKey::Teal)
+                .with_color_scheme(ColorScheme::Dark)
+        );
+        assert_ne!(
+            primary_first.components.button.filled_bg,
+            ThemeBuilder::dark()
+                .primary(PaletteKey::Teal)
+                .build()
+                .components
+                .button
+                .filled_bg
+        );
+    }
+
     #[test]
     fn color_scheme_switch_recomputes_semantic_and_component_tokens() {
         let light = Theme::default().with_color_scheme(ColorScheme::Light);
@@ -9281,6 +12606,26 @@ mod tests {
         assert_ne!(light.components.switch.label, dark.components.switch.label);
     }
 
+    #[test]
+    fn color_scheme_preference_follows_system_only_in_system_mode() {
+        assert_eq!(
+            ColorSchemePreference::System.resolve(ColorScheme::Dark),
+            ColorScheme::Dark
+        );
+        assert_eq!(
+            ColorSchemePreference::Light.resolve(ColorScheme::Dark),
+            ColorScheme::Light
+        );
+        assert_eq!(
+            ColorSchemePreference::Dark.resolve(ColorScheme::Light),
+            ColorScheme::Dark
+        );
+        assert_eq!(
+            ColorScheme::from(gpui::WindowAppearance::VibrantDark),
+            ColorScheme::Dark
+        );
+    }
+
     #[test]
     fn dark_theme_uses_resolved_dark_text_for_selection_controls() {
         let dark = Theme::default().with_color_scheme(ColorScheme::Dark);
@@ -9353,4 +12698,434 @@ mod tests {
         assert_eq!(themed.components.input.sizes.md.font_size, px(17.0));
         assert_eq!(themed.components.input.sizes.md.padding_x, px(13.0));
     }
+
+    #[test]
+    fn theme_diff_round_trips_through_merged_overrides() {
+        let base = Theme::default();
+        let tweaked = base
+            .clone()
+            .with_primary_color(PaletteKey::Grape)
+            .with_primary_shade(4)
+            .with_overrides(|overrides| {
+                overrides
+                    .radii(|radii| radii.md(px(10.0)))
+                    .button(|button| button.filled_fg(white()))
+            });
+
+        let patch = base.diff(&tweaked);
+        assert_eq!(patch.primary_color, Some(PaletteKey::Grape));
+        assert_eq!(patch.radii.md, Some(px(10.0)));
+        assert_eq!(patch.radii.sm, None);
+        assert_eq!(patch.semantic, SemanticOverrides::default());
+        assert_eq!(base.merged(&patch), tweaked);
+        assert!(tweaked.diff(&tweaked).is_empty());
+    }
+
+    #[test]
+    fn theme_overrides_validate_flags_redundant_and_out_of_range_entries() {
+        let theme = Theme::default();
+        let overrides = ThemeOverrides::default()
+            .primary_shade_light(12_u8)
+            .radii(|radii| radii.md(theme.radii.md).lg(px(40.0)))
+            .button(|button| button.filled_bg(theme.components.button.filled_bg));
+
+        let issues = overrides.validate(&theme);
+        assert!(issues.contains(&ThemeOverrideIssue {
+            path: "primary_shade_light".into(),
+            kind: ThemeOverrideIssueKind::OutOfRange,
+        }));
+        assert!(issues.contains(&ThemeOverrideIssue {
+            path: "radii.md".into(),
+            kind: ThemeOverrideIssueKind::Redundant,
+        }));
+        assert!(issues.contains(&ThemeOverrideIssue {
+            path: "components.button.filled_bg".into(),
+            kind: ThemeOverrideIssueKind::Redundant,
+        }));
+        assert!(!issues.iter().any(|issue| issue.path == "radii.lg"));
+    }
+
+    #[test]
+    fn design_tokens_export_to_css_variables_and_figma_json() {
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides
+                .radii(|radii| radii.md(px(10.0)))
+                .button(|button| button.filled_fg(white()))
+        });
+
+        let tokens = theme.design_tokens();
+        assert!(
+            tokens
+                .iter()
+                .any(|token| token.path == "components.button.sizes.md.font_size")
+        );
+        let radius = tokens
+            .iter()
+            .find(|token| token.path == "radii.md")
+            .expect("radius token");
+        assert_eq!(radius.value, DesignTokenValue::Dimension(px(10.0)));
+        assert_eq!(radius.css_variable("calm"), "--calm-radii-md");
+
+        let css = theme.to_css_variables(":root", "calm");
+        assert!(css.starts_with(":root {\n"));
+        assert!(css.contains("  --calm-radii-md: 10px;\n"));
+        assert!(css.contains("  --calm-components-button-filled-fg: #ffffff;\n"));
+
+        let json = theme.to_figma_tokens_json();
+        assert!(json.contains("\"md\": { \"value\": \"10px\", \"type\": \"dimension\" }"));
+        assert!(json.contains("\"filled_fg\": { \"value\": \"#ffffff\", \"type\": \"color\" }"));
+        assert_eq!(json.matches('{').count(), json.matches('}').count());
+    }
+
+    #[test]
+    fn color_utils_mix_adjust_and_pick_readable_foreground() {
+        let mid = color_utils::mix(black(), white(), 0.5).to_rgb();
+        assert!((mid.r - 0.5).abs() < 0.01 && (mid.g - 0.5).abs() < 0.01);
+        assert_eq!(color_utils::mix(black(), white(), 2.0), white());
+
+        let base = Theme::default().semantic.bg_surface;
+        assert!(
+            color_utils::relative_luminance(color_utils::lighten(base, 0.2))
+                >= color_utils::relative_luminance(base)
+        );
+        assert!(
+            color_utils::relative_luminance(color_utils::darken(base, 0.2))
+                <= color_utils::relative_luminance(base)
+        );
+        assert_eq!(color_utils::with_alpha(base, 0.25).a, 0.25);
+
+        assert_eq!(color_utils::readable_on(white()), black());
+        assert_eq!(color_utils::readable_on(black()), white());
+        assert!((color_utils::contrast_ratio(black(), white()) - 21.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn brand_color_generates_scale_and_retints_primary_tokens() {
+        let brand: Hsla = Rgba::try_from("#7a3cff")
+            .map(Into::into)
+            .unwrap_or_else(|_| black());
+        let scale = color_utils::scale_from_color(brand);
+        assert_eq!(scale.len(), COLOR_STOPS);
+        assert!(
+            scale
+                .iter()
+                .all(|hex| hex.starts_with('#') && hex.len() == 7)
+        );
+        assert!(
+            color_utils::relative_luminance(resolve_hex_hsla(scale[0]))
+                > color_utils::relative_luminance(resolve_hex_hsla(scale[9]))
+        );
+
+        let theme = Theme::from_brand_color(brand);
+        assert_eq!(theme.primary_color, PaletteKey::Violet);
+        assert_eq!(theme.palette[&PaletteKey::Violet], scale);
+        assert_eq!(
+            theme.components.button.filled_bg,
+            resolve_hex_hsla(scale[6])
+        );
+
+        let dark = theme.clone().with_color_scheme(ColorScheme::Dark);
+        assert_ne!(
+            dark.components.button.filled_bg,
+            Theme::default()
+                .with_primary_color(PaletteKey::Violet)
+                .with_color_scheme(ColorScheme::Dark)
+                .components
+                .button
+                .filled_bg
+        );
+        assert_eq!(Theme::default().diff(&theme).palette_overrides.len(), 1);
+        assert_eq!(
+            Theme::default().merged(&Theme::default().diff(&theme)),
+            theme
+        );
+    }
+
+    #[test]
+    fn brand_color_retint_leaves_status_tokens_on_the_shared_palette() {
+        let brand: Hsla = Rgba::try_from("#f4433a")
+            .map(Into::into)
+            .unwrap_or_else(|_| black());
+        let scale = color_utils::scale_from_color(brand);
+
+        for scheme in [ColorScheme::Light, ColorScheme::Dark] {
+            let theme = Theme::from_brand_color(brand).with_color_scheme(scheme);
+            let catalog = Theme::default()
+                .with_primary_color(PaletteKey::Red)
+                .with_color_scheme(scheme);
+            assert_eq!(theme.primary_color, PaletteKey::Red);
+            assert_ne!(
+                theme.components.button.filled_bg,
+                catalog.components.button.filled_bg
+            );
+            assert_eq!(theme.semantic.status_error, catalog.semantic.status_error);
+            assert_eq!(theme.components.input.error, catalog.components.input.error);
+            assert_eq!(
+                theme.components.toast.error_bg,
+                catalog.components.toast.error_bg
+            );
+            assert_eq!(
+                theme.components.toast.error_fg,
+                catalog.components.toast.error_fg
+            );
+        }
+        assert_eq!(
+            Theme::from_brand_color(brand).components.button.filled_bg,
+            resolve_hex_hsla(scale[6])
+        );
+    }
+
+    #[cfg(feature = "image-palette")]
+    #[test]
+    fn image_palette_extracts_vibrant_brand_color() {
+        let mut pixels = Vec::new();
+        for index in 0..400 {
+            let rgba = if index % 4 == 0 {
+                [230, 80, 20, 255]
+            } else {
+                [240, 240, 240, 255]
+            };
+            pixels.extend_from_slice(&rgba);
+        }
+        pixels.extend_from_slice(&[0, 0, 255, 0]);
+
+        let swatches = image_palette::dominant_colors(&pixels, 4);
+        assert_eq!(swatches.len(), 2);
+        assert!(swatches[0].population > swatches[1].population);
+
+        let brand = image_palette::brand_color_from_image(&pixels).expect("brand color");
+        assert_eq!(color_utils::nearest_palette_key(brand), PaletteKey::Orange);
+        assert!(image_palette::scale_from_image(&[]).is_none());
+    }
+
+    #[test]
+    fn pseudo_state_overrides_merge_and_export_explicit_variants() {
+        let red = resolve_palette_hsla(PaletteKey::Red, 6);
+        let green = resolve_palette_hsla(PaletteKey::Green, 6);
+        let theme = Theme::default()
+            .with_overrides(|overrides| {
+                overrides.select(|select| select.states(PseudoStateTokens::new().hover_bg(red)))
+            })
+            .with_overrides(|overrides| {
+                overrides.select(|select| {
+                    select.states(PseudoStateTokens::new().active_bg(green).disabled_fg(red))
+                })
+            });
+
+        let states = theme.components.select.states;
+        assert_eq!(states.hover_bg, Some(red));
+        assert_eq!(states.active_bg, Some(green));
+        assert!(states.is_disabled_styled());
+        assert_eq!(states.focus_border_or(green), green);
+        assert_eq!(
+            Theme::default().components.button.states,
+            PseudoStateTokens::default()
+        );
+
+        let tokens = theme.design_tokens();
+        assert!(
+            tokens
+                .iter()
+                .any(|token| token.path == "components.select.states.hover_bg")
+        );
+        assert!(
+            !tokens
+                .iter()
+                .any(|token| token.path == "components.select.states.focus_border")
+        );
+        assert_eq!(
+            Theme::default().merged(&Theme::default().diff(&theme)),
+            theme
+        );
+    }
+
+    #[test]
+    fn focus_ring_tokens_override_and_export_style_keyword() {
+        let defaults = Theme::default().components.focus_ring;
+        assert_eq!(defaults.style, FocusRingStyle::Solid);
+        assert!(defaults.width > px(0.0));
+
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides.focus_ring(|ring| {
+                ring.width(px(3.0))
+                    .offset(px(1.0))
+                    .style(FocusRingStyle::Dashed)
+            })
+        });
+        let ring = &theme.components.focus_ring;
+        assert_eq!(ring.width, px(3.0));
+        assert_eq!(ring.offset, px(1.0));
+        assert_eq!(ring.color, defaults.color);
+
+        let tokens = theme.design_tokens();
+        let style = tokens
+            .iter()
+            .find(|token| token.path == "components.focus_ring.style")
+            .expect("focus ring style token");
+        assert_eq!(style.value, DesignTokenValue::Keyword("dashed"));
+        assert_eq!(style.value.css_value(), "dashed");
+
+        let violet = Theme::from_brand_color(resolve_palette_hsla(PaletteKey::Violet, 6));
+        assert_ne!(violet.components.focus_ring.color, defaults.color);
+    }
+
+    #[test]
+    fn press_feedback_tokens_override_and_export_style_keyword() {
+        let defaults = Theme::default().components.press_feedback;
+        assert_eq!(defaults.style, PressFeedbackStyle::Scale);
+
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides.press_feedback(|feedback| feedback.style(PressFeedbackStyle::Ripple))
+        });
+        assert_eq!(
+            theme.components.press_feedback.style,
+            PressFeedbackStyle::Ripple
+        );
+        assert_eq!(
+            theme.components.press_feedback.scale_inset,
+            defaults.scale_inset
+        );
+
+        let tokens = theme.design_tokens();
+        let style = tokens
+            .iter()
+            .find(|token| token.path == "components.press_feedback.style")
+            .expect("press feedback style token");
+        assert_eq!(style.value, DesignTokenValue::Keyword("ripple"));
+    }
+
+    #[test]
+    fn async_content_tokens_follow_scheme_and_accept_overrides() {
+        let light = Theme::default().components.async_content;
+        let dark = Theme::default()
+            .with_color_scheme(ColorScheme::Dark)
+            .components
+            .async_content;
+        assert_ne!(light.skeleton_bg, dark.skeleton_bg);
+
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides.async_content(|tokens| tokens.skeleton_gap(px(4.0)).empty_padding(px(12.0)))
+        });
+        let tokens = theme.components.async_content;
+        assert_eq!(tokens.skeleton_gap, px(4.0));
+        assert_eq!(tokens.empty_padding, px(12.0));
+        assert_eq!(tokens.skeleton_bg, light.skeleton_bg);
+    }
+
+    #[test]
+    fn checklist_tokens_follow_scheme_and_accept_overrides() {
+        let light = Theme::default().components.checklist;
+        let dark = Theme::default()
+            .with_color_scheme(ColorScheme::Dark)
+            .components
+            .checklist;
+        assert_ne!(light.label, dark.label);
+
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides.checklist(|tokens| tokens.detail_indent(px(20.0)))
+        });
+        let tokens = theme.components.checklist;
+        assert_eq!(tokens.detail_indent, px(20.0));
+        assert_eq!(tokens.done, light.done);
+    }
+
+    #[test]
+    fn task_status_tokens_follow_scheme_and_accept_overrides() {
+        let light = Theme::default().components.task_status;
+        let dark = Theme::default()
+            .with_color_scheme(ColorScheme::Dark)
+            .components
+            .task_status;
+        assert_ne!(light.title, dark.title);
+
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides.task_status(|tokens| tokens.panel_width(px(320.0)))
+        });
+        let tokens = theme.components.task_status;
+        assert_eq!(tokens.panel_width, px(320.0));
+        assert_eq!(tokens.running, light.running);
+    }
+
+    #[test]
+    fn notification_center_tokens_follow_scheme_and_accept_overrides() {
+        let light = Theme::default().components.notification_center;
+        let dark = Theme::default()
+            .with_color_scheme(ColorScheme::Dark)
+            .components
+            .notification_center;
+        assert_ne!(light.unread_bg, dark.unread_bg);
+
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides.notification_center(|tokens| tokens.dot_size(px(6.0)))
+        });
+        let tokens = theme.components.notification_center;
+        assert_eq!(tokens.dot_size, px(6.0));
+        assert_eq!(tokens.unread_dot, light.unread_dot);
+    }
+
+    #[test]
+    fn read_only_text_selection_matches_input_selection_color() {
+        for scheme in [ColorScheme::Light, ColorScheme::Dark] {
+            let components = Theme::default().with_color_scheme(scheme).components;
+            assert_eq!(components.text.selection_bg, components.input.selection_bg);
+            assert_eq!(
+                components.markdown.selection_bg,
+                components.input.selection_bg
+            );
+            assert_eq!(components.table.selection_bg, components.input.selection_bg);
+        }
+
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides.text(|tokens| tokens.selection_bg(gpui::white()))
+        });
+        assert_eq!(theme.components.text.selection_bg, gpui::white());
+    }
+
+    #[test]
+    fn find_bar_active_match_stands_out_from_other_matches() {
+        for scheme in [ColorScheme::Light, ColorScheme::Dark] {
+            let tokens = Theme::default()
+                .with_color_scheme(scheme)
+                .components
+                .find_bar;
+            assert_ne!(tokens.match_bg, tokens.active_match_bg);
+            assert_ne!(tokens.match_bg, tokens.bg);
+        }
+
+        let theme = Theme::default().with_overrides(|overrides| {
+            overrides.find_bar(|tokens| tokens.active_match_bg(gpui::white()).width(px(360.0)))
+        });
+        assert_eq!(theme.components.find_bar.active_match_bg, gpui::white());
+        assert_eq!(theme.components.find_bar.width, px(360.0));
+    }
+
+    #[test]
+    fn empty_state_size_presets_grow_with_size_and_accept_overrides() {
+        let sizes = Theme::default().components.empty_state.sizes;
+        let presets =
+            [Size::Xs, Size::Sm, Size::Md, Size::Lg, Size::Xl].map(|size| sizes.for_size(size));
+        assert_eq!(presets[2], sizes.md);
+        for pair in presets.windows(2) {
+            assert!(pair[0].icon_size < pair[1].icon_size);
+            assert!(pair[0].icon_box_size < pair[1].icon_box_size);
+            assert!(pair[0].title_size < pair[1].title_size);
+            assert!(pair[0].max_width < pair[1].max_width);
+            assert!(pair[0].padding < pair[1].padding);
+        }
+        for preset in presets {
+            assert!(preset.icon_size < preset.icon_box_size);
+            assert!(preset.description_size <= preset.title_size);
+        }
+
+        let compact = EmptyStateSizeScale {
+            lg: sizes.sm,
+            ..sizes
+        };
+        let theme = Theme::default()
+            .with_overrides(|overrides| overrides.empty_state(|tokens| tokens.sizes(compact)));
+        assert_eq!(
+            theme.components.empty_state.sizes.for_size(Size::Lg),
+            sizes.sm
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use super::*;
 macro_rules! impl_option_overrides_methods {
-    ($type:ty { $($field:ident : $value:ty),* $(,)? }) => {
+    ($type:ty => $tokens:ty { $($field:ident : $value:ty),* $(,)? }) => {
         impl $type {
             $(
                 pub fn $field(mut self, value: impl Into<$value>) -> Self {
@@ -9,6 +9,79 @@ macro_rules! impl_option_overrides_methods {
                 }
             )*
         }
+
+        impl BlendTokens for $tokens {
+            fn blend(&self, to: &Self, progress: f32) -> Self {
+                let mut next = to.clone();
+                $(
+                    next.$field = self.$field.blend(&to.$field, progress);
+                )*
+                next
+            }
+        }
+
+        impl RetintTokens for $tokens {
+            fn retint(&mut self, probe: &Self, map: &dyn Fn(Hsla) -> Hsla) {
+                $(
+                    self.$field.retint(&probe.$field, map);
+                )*
+            }
+
+            fn replace_color(&mut self, path: &str, color: Hsla) -> bool {
+                let (field, rest) = path.split_once('.').unwrap_or((path, ""));
+                match field {
+                    $(
+                        stringify!($field) => self.$field.replace_color(rest, color),
+                    )*
+                    _ => false,
+                }
+            }
+        }
+
+        impl ExportTokens for $tokens {
+            fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+                $(
+                    self.$field
+                        .export_tokens(&format!("{path}.{}", stringify!($field)), tokens);
+                )*
+            }
+        }
+
+        impl ScaleTokens for $tokens {
+            fn scale(&mut self, _field: &str, factors: DimensionScale) {
+                $(
+                    self.$field.scale(stringify!($field), factors);
+                )*
+            }
+        }
+
+        impl OverridePatch<$tokens> for $type {
+            fn diff(base: &$tokens, target: &$tokens) -> Self {
+                let mut patch = Self::default();
+                $(
+                    if base.$field != target.$field {
+                        patch.$field = Some(target.$field.clone());
+                    }
+                )*
+                patch
+            }
+
+            fn collect_issues(
+                &self,
+                current: &$tokens,
+                prefix: &str,
+                issues: &mut Vec<ThemeOverrideIssue>,
+            ) {
+                $(
+                    check_value(
+                        issues,
+                        &format!("{prefix}.{}", stringify!($field)),
+                        self.$field.as_ref(),
+                        &current.$field,
+                    );
+                )*
+            }
+        }
     };
 }
 
@@ -25,7 +98,7 @@ macro_rules! impl_nested_overrides_methods {
     };
 }
 
-impl_option_overrides_methods!(SemanticOverrides {
+impl_option_overrides_methods!(SemanticOverrides => SemanticColors {
     text_primary: Hsla,
     text_secondary: Hsla,
     text_muted: Hsla,
@@ -42,7 +115,7 @@ impl_option_overrides_methods!(SemanticOverrides {
     overlay_mask: Hsla,
 });
 
-impl_option_overrides_methods!(RadiiOverrides {
+impl_option_overrides_methods!(RadiiOverrides => ThemeRadii {
     default: Pixels,
     xs: Pixels,
     sm: Pixels,
@@ -52,7 +125,7 @@ impl_option_overrides_methods!(RadiiOverrides {
     pill: Pixels,
 });
 
-impl_option_overrides_methods!(ButtonOverrides {
+impl_option_overrides_methods!(ButtonOverrides => ButtonTokens {
     filled_bg: Hsla,
     filled_fg: Hsla,
     light_bg: Hsla,
@@ -65,9 +138,10 @@ impl_option_overrides_methods!(ButtonOverrides {
     disabled_bg: Hsla,
     disabled_fg: Hsla,
     sizes: ButtonSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(InputOverrides {
+impl_option_overrides_methods!(InputOverrides => InputTokens {
     bg: Hsla,
     fg: Hsla,
     caret: Hsla,
@@ -93,10 +167,22 @@ impl_option_overrides_methods!(InputOverrides {
     horizontal_label_width: Pixels,
     pin_cells_gap: Pixels,
     pin_error_gap: Pixels,
+    password_toggle_size: Pixels,
+    password_caps_lock_fg: Hsla,
+    password_caps_lock_size: Pixels,
+    password_meter_gap: Pixels,
+    password_meter_height: Pixels,
+    password_meter_segment_gap: Pixels,
+    password_meter_track: Hsla,
+    password_strength_weak: Hsla,
+    password_strength_fair: Hsla,
+    password_strength_good: Hsla,
+    password_strength_strong: Hsla,
+    password_strength_label_size: Pixels,
     sizes: FieldSizeScale,
 });
 
-impl_option_overrides_methods!(RadioOverrides {
+impl_option_overrides_methods!(RadioOverrides => RadioTokens {
     control_bg: Hsla,
     border: Hsla,
     border_hover: Hsla,
@@ -111,7 +197,7 @@ impl_option_overrides_methods!(RadioOverrides {
     sizes: ChoiceControlSizeScale,
 });
 
-impl_option_overrides_methods!(CheckboxOverrides {
+impl_option_overrides_methods!(CheckboxOverrides => CheckboxTokens {
     control_bg: Hsla,
     control_bg_checked: Hsla,
     border: Hsla,
@@ -127,7 +213,7 @@ impl_option_overrides_methods!(CheckboxOverrides {
     sizes: ChoiceControlSizeScale,
 });
 
-impl_option_overrides_methods!(SwitchOverrides {
+impl_option_overrides_methods!(SwitchOverrides => SwitchTokens {
     track_off_bg: Hsla,
     track_on_bg: Hsla,
     track_hover_border: Hsla,
@@ -139,7 +225,7 @@ impl_option_overrides_methods!(SwitchOverrides {
     sizes: SwitchSizeScale,
 });
 
-impl_option_overrides_methods!(ChipOverrides {
+impl_option_overrides_methods!(ChipOverrides => ChipTokens {
     unchecked_bg: Hsla,
     unchecked_fg: Hsla,
     unchecked_border: Hsla,
@@ -164,7 +250,7 @@ impl_option_overrides_methods!(ChipOverrides {
     sizes: ButtonSizeScale,
 });
 
-impl_option_overrides_methods!(BadgeOverrides {
+impl_option_overrides_methods!(BadgeOverrides => BadgeTokens {
     filled_bg: Hsla,
     filled_fg: Hsla,
     light_bg: Hsla,
@@ -179,7 +265,7 @@ impl_option_overrides_methods!(BadgeOverrides {
     sizes: BadgeSizeScale,
 });
 
-impl_option_overrides_methods!(AccordionOverrides {
+impl_option_overrides_methods!(AccordionOverrides => AccordionTokens {
     item_bg: Hsla,
     item_border: Hsla,
     label: Hsla,
@@ -191,9 +277,10 @@ impl_option_overrides_methods!(AccordionOverrides {
     label_stack_gap: Pixels,
     panel_gap: Pixels,
     sizes: AccordionSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(MenuOverrides {
+impl_option_overrides_methods!(MenuOverrides => MenuTokens {
     dropdown_bg: Hsla,
     dropdown_border: Hsla,
     item_fg: Hsla,
@@ -211,9 +298,10 @@ impl_option_overrides_methods!(MenuOverrides {
     dropdown_radius: Pixels,
     dropdown_width_fallback: Pixels,
     dropdown_min_width: Pixels,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(ProgressOverrides {
+impl_option_overrides_methods!(ProgressOverrides => ProgressTokens {
     track_bg: Hsla,
     fill_bg: Hsla,
     label: Hsla,
@@ -223,7 +311,7 @@ impl_option_overrides_methods!(ProgressOverrides {
     sizes: ProgressSizeScale,
 });
 
-impl_option_overrides_methods!(SliderOverrides {
+impl_option_overrides_methods!(SliderOverrides => SliderTokens {
     track_bg: Hsla,
     fill_bg: Hsla,
     thumb_bg: Hsla,
@@ -236,18 +324,24 @@ impl_option_overrides_methods!(SliderOverrides {
     header_gap_horizontal: Pixels,
     default_width: Pixels,
     min_width: Pixels,
+    mark_bg: Hsla,
+    mark_size: Pixels,
+    mark_label: Hsla,
+    mark_label_size: Pixels,
+    mark_label_gap: Pixels,
+    tooltip_offset: Pixels,
     sizes: SliderSizeScale,
 });
 
-impl_option_overrides_methods!(OverlayOverrides { bg: Hsla });
+impl_option_overrides_methods!(OverlayOverrides => OverlayTokens { bg: Hsla });
 
-impl_option_overrides_methods!(LoaderOverrides {
+impl_option_overrides_methods!(LoaderOverrides => LoaderTokens {
     color: Hsla,
     label: Hsla,
     sizes: LoaderSizeScale,
 });
 
-impl_option_overrides_methods!(LoadingOverlayOverrides {
+impl_option_overrides_methods!(LoadingOverlayOverrides => LoadingOverlayTokens {
     bg: Hsla,
     loader_color: Hsla,
     label: Hsla,
@@ -255,7 +349,7 @@ impl_option_overrides_methods!(LoadingOverlayOverrides {
     label_size: Pixels,
 });
 
-impl_option_overrides_methods!(PopoverOverrides {
+impl_option_overrides_methods!(PopoverOverrides => PopoverTokens {
     bg: Hsla,
     border: Hsla,
     title: Hsla,
@@ -265,7 +359,7 @@ impl_option_overrides_methods!(PopoverOverrides {
     radius: Pixels,
 });
 
-impl_option_overrides_methods!(TooltipOverrides {
+impl_option_overrides_methods!(TooltipOverrides => TooltipTokens {
     bg: Hsla,
     fg: Hsla,
     border: Hsla,
@@ -276,7 +370,7 @@ impl_option_overrides_methods!(TooltipOverrides {
     max_width: Pixels,
 });
 
-impl_option_overrides_methods!(HoverCardOverrides {
+impl_option_overrides_methods!(HoverCardOverrides => HoverCardTokens {
     bg: Hsla,
     border: Hsla,
     title: Hsla,
@@ -291,7 +385,7 @@ impl_option_overrides_methods!(HoverCardOverrides {
     radius: Pixels,
 });
 
-impl_option_overrides_methods!(SelectOverrides {
+impl_option_overrides_methods!(SelectOverrides => SelectTokens {
     bg: Hsla,
     fg: Hsla,
     placeholder: Hsla,
@@ -339,9 +433,10 @@ impl_option_overrides_methods!(SelectOverrides {
     tag_max_width: Pixels,
     dropdown_anchor_offset: Pixels,
     sizes: FieldSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(ModalOverrides {
+impl_option_overrides_methods!(ModalOverrides => ModalTokens {
     panel_bg: Hsla,
     panel_border: Hsla,
     overlay_bg: Hsla,
@@ -364,7 +459,7 @@ impl_option_overrides_methods!(ModalOverrides {
     min_width: Pixels,
 });
 
-impl_option_overrides_methods!(ToastOverrides {
+impl_option_overrides_methods!(ToastOverrides => ToastTokens {
     info_bg: Hsla,
     info_fg: Hsla,
     success_bg: Hsla,
@@ -386,9 +481,11 @@ impl_option_overrides_methods!(ToastOverrides {
     stack_gap: Pixels,
     edge_offset: Pixels,
     top_offset_extra: Pixels,
+    action_gap: Pixels,
+    progress_height: Pixels,
 });
 
-impl_option_overrides_methods!(DividerOverrides {
+impl_option_overrides_methods!(DividerOverrides => DividerTokens {
     line: Hsla,
     line_width: Pixels,
     label: Hsla,
@@ -397,13 +494,22 @@ impl_option_overrides_methods!(DividerOverrides {
     edge_span: Pixels,
 });
 
-impl_option_overrides_methods!(ScrollAreaOverrides {
+impl_option_overrides_methods!(ScrollAreaOverrides => ScrollAreaTokens {
     bg: Hsla,
     border: Hsla,
     padding: InsetSizeScale,
+    minimap_width: Pixels,
+    minimap_bg: Hsla,
+    minimap_row: Hsla,
+    minimap_viewport_bg: Hsla,
+    minimap_viewport_border: Hsla,
+    minimap_marker_info: Hsla,
+    minimap_marker_search: Hsla,
+    minimap_marker_warning: Hsla,
+    minimap_marker_error: Hsla,
 });
 
-impl_option_overrides_methods!(DrawerOverrides {
+impl_option_overrides_methods!(DrawerOverrides => DrawerTokens {
     panel_bg: Hsla,
     panel_border: Hsla,
     overlay_bg: Hsla,
@@ -419,7 +525,7 @@ impl_option_overrides_methods!(DrawerOverrides {
     close_icon_size: Pixels,
 });
 
-impl_option_overrides_methods!(AppShellOverrides {
+impl_option_overrides_methods!(AppShellOverrides => AppShellTokens {
     bg: Hsla,
     title_bar_bg: Hsla,
     sidebar_bg: Hsla,
@@ -436,9 +542,11 @@ impl_option_overrides_methods!(AppShellOverrides {
     inspector_min_width: Pixels,
     bottom_panel_height: Pixels,
     bottom_panel_min_height: Pixels,
+    resize_handle_bg: Hsla,
+    resize_handle_size: Pixels,
 });
 
-impl_option_overrides_methods!(TitleBarOverrides {
+impl_option_overrides_methods!(TitleBarOverrides => TitleBarTokens {
     bg: Hsla,
     border: Hsla,
     fg: Hsla,
@@ -461,7 +569,7 @@ impl_option_overrides_methods!(TitleBarOverrides {
     control_button_radius: Pixels,
 });
 
-impl_option_overrides_methods!(SidebarOverrides {
+impl_option_overrides_methods!(SidebarOverrides => SidebarTokens {
     bg: Hsla,
     border: Hsla,
     header_fg: Hsla,
@@ -475,7 +583,7 @@ impl_option_overrides_methods!(SidebarOverrides {
     scroll_padding: Size,
 });
 
-impl_option_overrides_methods!(MarkdownOverrides {
+impl_option_overrides_methods!(MarkdownOverrides => MarkdownTokens {
     paragraph: Hsla,
     paragraph_muted: Hsla,
     heading: Hsla,
@@ -518,6 +626,9 @@ impl_option_overrides_methods!(MarkdownOverrides {
     image_border: Hsla,
     image_bg: Hsla,
     image_caption_fg: Hsla,
+    image_placeholder_bg: Hsla,
+    footnote_fg: Hsla,
+    selection_bg: Hsla,
     gap_regular: Pixels,
     gap_compact: Pixels,
     paragraph_size: Pixels,
@@ -531,6 +642,7 @@ impl_option_overrides_methods!(MarkdownOverrides {
     list_line_height: Pixels,
     table_size: Pixels,
     image_caption_size: Pixels,
+    footnote_size: Pixels,
     quote_padding_x: Pixels,
     quote_padding_y: Pixels,
     quote_radius: Pixels,
@@ -552,10 +664,11 @@ impl_option_overrides_methods!(MarkdownOverrides {
     image_radius: Pixels,
     image_padding: Pixels,
     image_gap: Pixels,
+    image_placeholder_height: Pixels,
     heading2_padding_top: Pixels,
 });
 
-impl_option_overrides_methods!(TextOverrides {
+impl_option_overrides_methods!(TextOverrides => TextTokens {
     fg: Hsla,
     secondary: Hsla,
     muted: Hsla,
@@ -563,10 +676,11 @@ impl_option_overrides_methods!(TextOverrides {
     success: Hsla,
     warning: Hsla,
     error: Hsla,
+    selection_bg: Hsla,
     sizes: TextSizeScale,
 });
 
-impl_option_overrides_methods!(TitleOverrides {
+impl_option_overrides_methods!(TitleOverrides => TitleTokens {
     fg: Hsla,
     subtitle: Hsla,
     gap: Pixels,
@@ -575,19 +689,19 @@ impl_option_overrides_methods!(TitleOverrides {
     subtitle_weight: FontWeight,
 });
 
-impl_option_overrides_methods!(TitleLevelOverrides {
+impl_option_overrides_methods!(TitleLevelOverrides => TitleLevelTokens {
     font_size: Pixels,
     line_height: Pixels,
     weight: FontWeight,
 });
 
-impl_option_overrides_methods!(PaperOverrides {
+impl_option_overrides_methods!(PaperOverrides => PaperTokens {
     bg: Hsla,
     border: Hsla,
     padding: InsetSizeScale,
 });
 
-impl_option_overrides_methods!(ActionIconOverrides {
+impl_option_overrides_methods!(ActionIconOverrides => ActionIconTokens {
     filled_bg: Hsla,
     filled_fg: Hsla,
     light_bg: Hsla,
@@ -604,9 +718,10 @@ impl_option_overrides_methods!(ActionIconOverrides {
     disabled_fg: Hsla,
     disabled_border: Hsla,
     sizes: ActionIconSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(SegmentedControlOverrides {
+impl_option_overrides_methods!(SegmentedControlOverrides => SegmentedControlTokens {
     bg: Hsla,
     border: Hsla,
     item_fg: Hsla,
@@ -617,9 +732,10 @@ impl_option_overrides_methods!(SegmentedControlOverrides {
     track_padding: Pixels,
     item_gap: Pixels,
     sizes: SegmentedControlSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(TextareaOverrides {
+impl_option_overrides_methods!(TextareaOverrides => TextareaTokens {
     bg: Hsla,
     fg: Hsla,
     caret: Hsla,
@@ -628,6 +744,7 @@ impl_option_overrides_methods!(TextareaOverrides {
     border: Hsla,
     border_focus: Hsla,
     border_error: Hsla,
+    spell_error: Hsla,
     label: Hsla,
     label_size: Pixels,
     label_weight: FontWeight,
@@ -644,7 +761,7 @@ impl_option_overrides_methods!(TextareaOverrides {
     sizes: FieldSizeScale,
 });
 
-impl_option_overrides_methods!(NumberInputOverrides {
+impl_option_overrides_methods!(NumberInputOverrides => NumberInputTokens {
     bg: Hsla,
     fg: Hsla,
     placeholder: Hsla,
@@ -668,7 +785,7 @@ impl_option_overrides_methods!(NumberInputOverrides {
     sizes: FieldSizeScale,
 });
 
-impl_option_overrides_methods!(RangeSliderOverrides {
+impl_option_overrides_methods!(RangeSliderOverrides => RangeSliderTokens {
     track_bg: Hsla,
     range_bg: Hsla,
     thumb_bg: Hsla,
@@ -681,16 +798,25 @@ impl_option_overrides_methods!(RangeSliderOverrides {
     header_gap_horizontal: Pixels,
     default_width: Pixels,
     min_width: Pixels,
+    mark_bg: Hsla,
+    mark_size: Pixels,
+    mark_label: Hsla,
+    mark_label_size: Pixels,
+    mark_label_gap: Pixels,
+    tooltip_offset: Pixels,
     sizes: SliderSizeScale,
 });
 
-impl_option_overrides_methods!(RatingOverrides {
+impl_option_overrides_methods!(RatingOverrides => RatingTokens {
     active: Hsla,
     inactive: Hsla,
+    value: Hsla,
+    value_size: Pixels,
+    value_gap: Pixels,
     sizes: RatingSizeScale,
 });
 
-impl_option_overrides_methods!(TabsOverrides {
+impl_option_overrides_methods!(TabsOverrides => TabsTokens {
     list_bg: Hsla,
     list_border: Hsla,
     tab_fg: Hsla,
@@ -706,9 +832,10 @@ impl_option_overrides_methods!(TabsOverrides {
     list_padding: Pixels,
     panel_padding: Pixels,
     sizes: TabsSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(PaginationOverrides {
+impl_option_overrides_methods!(PaginationOverrides => PaginationTokens {
     item_bg: Hsla,
     item_border: Hsla,
     item_fg: Hsla,
@@ -719,25 +846,29 @@ impl_option_overrides_methods!(PaginationOverrides {
     dots_fg: Hsla,
     root_gap: Pixels,
     sizes: PaginationSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(BreadcrumbsOverrides {
+impl_option_overrides_methods!(BreadcrumbsOverrides => BreadcrumbsTokens {
     item_fg: Hsla,
     item_current_fg: Hsla,
     separator: Hsla,
     item_hover_bg: Hsla,
     root_gap: Pixels,
     sizes: BreadcrumbsSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(TableOverrides {
+impl_option_overrides_methods!(TableOverrides => TableTokens {
     header_bg: Hsla,
     header_fg: Hsla,
     row_bg: Hsla,
     row_alt_bg: Hsla,
     row_hover_bg: Hsla,
+    row_selected_bg: Hsla,
     row_border: Hsla,
     cell_fg: Hsla,
+    selection_bg: Hsla,
     caption: Hsla,
     caption_size: Pixels,
     row_gap: Pixels,
@@ -754,9 +885,10 @@ impl_option_overrides_methods!(TableOverrides {
     virtualization_padding: Pixels,
     min_viewport_height: Pixels,
     sizes: TableSizeScale,
+    states: PseudoStateTokens,
 });
 
-impl_option_overrides_methods!(StepperOverrides {
+impl_option_overrides_methods!(StepperOverrides => StepperTokens {
     step_bg: Hsla,
     step_border: Hsla,
     step_fg: Hsla,
@@ -779,7 +911,7 @@ impl_option_overrides_methods!(StepperOverrides {
     sizes: StepperSizeScale,
 });
 
-impl_option_overrides_methods!(TimelineOverrides {
+impl_option_overrides_methods!(TimelineOverrides => TimelineTokens {
     bullet_bg: Hsla,
     bullet_border: Hsla,
     bullet_fg: Hsla,
@@ -803,24 +935,587 @@ impl_option_overrides_methods!(TimelineOverrides {
     sizes: TimelineSizeScale,
 });
 
-impl_option_overrides_methods!(TreeOverrides {
+impl_option_overrides_methods!(TreeOverrides => TreeTokens {
     row_fg: Hsla,
     row_selected_fg: Hsla,
     row_selected_bg: Hsla,
     row_hover_bg: Hsla,
     row_disabled_fg: Hsla,
     line: Hsla,
+    drop_indicator: Hsla,
     root_gap: Pixels,
     children_gap: Pixels,
     sizes: TreeSizeScale,
 });
 
-impl_option_overrides_methods!(LayoutOverrides {
+impl_option_overrides_methods!(LayoutOverrides => LayoutTokens {
     gap: GapSizeScale,
     space: GapSizeScale,
     popup_snap_margin: Pixels,
 });
 
+impl_option_overrides_methods!(FocusRingOverrides => FocusRingTokens {
+    color: Hsla,
+    width: Pixels,
+    offset: Pixels,
+    style: FocusRingStyle,
+});
+
+impl_option_overrides_methods!(PressFeedbackOverrides => PressFeedbackTokens {
+    style: PressFeedbackStyle,
+    scale_inset: Pixels,
+    ripple_color: Hsla,
+});
+
+impl_option_overrides_methods!(AsyncContentOverrides => AsyncContentTokens {
+    skeleton_bg: Hsla,
+    skeleton_radius: Pixels,
+    skeleton_line_height: Pixels,
+    skeleton_gap: Pixels,
+    empty_icon: Hsla,
+    empty_title: Hsla,
+    empty_description: Hsla,
+    empty_icon_size: Pixels,
+    empty_title_size: Pixels,
+    empty_description_size: Pixels,
+    empty_gap: Pixels,
+    empty_padding: Pixels,
+});
+
+impl_option_overrides_methods!(ChecklistOverrides => ChecklistTokens {
+    done: Hsla,
+    active: Hsla,
+    pending: Hsla,
+    error: Hsla,
+    label: Hsla,
+    description: Hsla,
+    summary: Hsla,
+    icon_size: Pixels,
+    label_size: Pixels,
+    description_size: Pixels,
+    summary_size: Pixels,
+    gap: Pixels,
+    item_gap: Pixels,
+    detail_indent: Pixels,
+});
+
+impl_option_overrides_methods!(TaskStatusOverrides => TaskStatusTokens {
+    trigger_fg: Hsla,
+    trigger_hover_bg: Hsla,
+    title: Hsla,
+    message: Hsla,
+    running: Hsla,
+    done: Hsla,
+    error: Hsla,
+    cancelled: Hsla,
+    trigger_size: Pixels,
+    title_size: Pixels,
+    message_size: Pixels,
+    icon_size: Pixels,
+    trigger_bar_width: Pixels,
+    panel_width: Pixels,
+    item_gap: Pixels,
+    row_gap: Pixels,
+});
+
+impl_option_overrides_methods!(NotificationCenterOverrides => NotificationCenterTokens {
+    header: Hsla,
+    title: Hsla,
+    message: Hsla,
+    empty: Hsla,
+    unread_dot: Hsla,
+    unread_bg: Hsla,
+    row_hover_bg: Hsla,
+    info: Hsla,
+    success: Hsla,
+    warning: Hsla,
+    error: Hsla,
+    header_size: Pixels,
+    title_size: Pixels,
+    message_size: Pixels,
+    icon_size: Pixels,
+    dot_size: Pixels,
+    panel_width: Pixels,
+    row_padding: Pixels,
+    item_gap: Pixels,
+    row_gap: Pixels,
+});
+
+impl_option_overrides_methods!(FindBarOverrides => FindBarTokens {
+    bg: Hsla,
+    border: Hsla,
+    count: Hsla,
+    match_bg: Hsla,
+    active_match_bg: Hsla,
+    width: Pixels,
+    padding: Pixels,
+    gap: Pixels,
+    radius: Pixels,
+    offset: Pixels,
+    count_size: Pixels,
+});
+
+impl_option_overrides_methods!(SplitPaneOverrides => SplitPaneTokens {
+    divider: Hsla,
+    divider_active: Hsla,
+    divider_size: Pixels,
+    handle_size: Pixels,
+    min_pane_size: Pixels,
+});
+
+impl_option_overrides_methods!(DockTabsOverrides => DockTabsTokens {
+    strip_bg: Hsla,
+    strip_border: Hsla,
+    tab_fg: Hsla,
+    tab_hover_bg: Hsla,
+    tab_active_bg: Hsla,
+    tab_active_fg: Hsla,
+    tab_active_indicator: Hsla,
+    tab_disabled_fg: Hsla,
+    dirty_dot: Hsla,
+    drop_indicator: Hsla,
+    tab_height: Pixels,
+    tab_padding_x: Pixels,
+    tab_gap: Pixels,
+    tab_max_width: Pixels,
+    close_size: Pixels,
+    dirty_dot_size: Pixels,
+    overflow_button_width: Pixels,
+});
+
+impl_option_overrides_methods!(ImageOverrides => ImageTokens {
+    placeholder_bg: Hsla,
+    fallback_bg: Hsla,
+    fallback_fg: Hsla,
+    fallback_icon_size: Pixels,
+    fallback_gap: Pixels,
+    fallback_font_size: Pixels,
+});
+
+impl_option_overrides_methods!(CalendarOverrides => CalendarTokens {
+    bg: Hsla,
+    border: Hsla,
+    header_fg: Hsla,
+    header_size: Pixels,
+    nav_hover_bg: Hsla,
+    icon: Hsla,
+    icon_size: Pixels,
+    weekday_fg: Hsla,
+    weekday_size: Pixels,
+    day_fg: Hsla,
+    day_outside_fg: Hsla,
+    day_hover_bg: Hsla,
+    day_size: Pixels,
+    today_bg: Hsla,
+    today_fg: Hsla,
+    range_bg: Hsla,
+    range_edge_bg: Hsla,
+    cell_border: Hsla,
+    cell_padding: Pixels,
+    month_cell_height: Pixels,
+    week_cell_height: Pixels,
+    chip_bg: Hsla,
+    chip_fg: Hsla,
+    chip_size: Pixels,
+    chip_padding_x: Pixels,
+    chip_gap: Pixels,
+    more_fg: Hsla,
+    header_gap: Pixels,
+});
+
+impl_option_overrides_methods!(KanbanBoardOverrides => KanbanBoardTokens {
+    column_bg: Hsla,
+    column_border: Hsla,
+    column_width: Pixels,
+    column_gap: Pixels,
+    column_padding: Pixels,
+    column_radius: Pixels,
+    list_height: Pixels,
+    header_fg: Hsla,
+    header_size: Pixels,
+    header_gap: Pixels,
+    count_bg: Hsla,
+    count_fg: Hsla,
+    count_over_limit_bg: Hsla,
+    count_over_limit_fg: Hsla,
+    count_size: Pixels,
+    card_bg: Hsla,
+    card_border: Hsla,
+    card_height: Pixels,
+    card_gap: Pixels,
+    card_padding: Pixels,
+    placeholder_bg: Hsla,
+    placeholder_border: Hsla,
+    empty_fg: Hsla,
+    empty_size: Pixels,
+});
+
+impl_option_overrides_methods!(ChartOverrides => ChartTokens {
+    grid: Hsla,
+    axis_fg: Hsla,
+    axis_size: Pixels,
+    axis_width: Pixels,
+    axis_gap: Pixels,
+    height: Pixels,
+    line_width: Pixels,
+    point_size: Pixels,
+    point_bg: Hsla,
+    bar_gap: Pixels,
+    bar_radius: Pixels,
+    donut_size: Pixels,
+    donut_thickness: Pixels,
+    donut_track: Hsla,
+    center_fg: Hsla,
+    center_size: Pixels,
+    legend_fg: Hsla,
+    legend_size: Pixels,
+    legend_gap: Pixels,
+    swatch_size: Pixels,
+    sparkline_width: Pixels,
+    sparkline_height: Pixels,
+    sparkline_line_width: Pixels,
+    empty_fg: Hsla,
+    empty_size: Pixels,
+});
+
+impl_option_overrides_methods!(RingProgressOverrides => RingProgressTokens {
+    size: Pixels,
+    thickness: Pixels,
+    label_size: Pixels,
+    section_gap: Pixels,
+});
+
+impl_option_overrides_methods!(StatCardOverrides => StatCardTokens {
+    bg: Hsla,
+    border: Hsla,
+    radius: Pixels,
+    padding: Pixels,
+    gap: Pixels,
+    label_fg: Hsla,
+    label_size: Pixels,
+    value_fg: Hsla,
+    value_size: Pixels,
+    icon_fg: Hsla,
+    icon_size: Pixels,
+    delta_size: Pixels,
+    delta_icon_size: Pixels,
+    delta_up: Hsla,
+    delta_down: Hsla,
+    delta_flat: Hsla,
+    description_fg: Hsla,
+    description_size: Pixels,
+    skeleton_bg: Hsla,
+});
+
+impl_option_overrides_methods!(EmptyStateOverrides => EmptyStateTokens {
+    icon_fg: Hsla,
+    icon_bg: Hsla,
+    title_fg: Hsla,
+    description_fg: Hsla,
+    actions_gap: Pixels,
+    sizes: EmptyStateSizeScale,
+});
+
+impl_option_overrides_methods!(AnnouncementBarOverrides => AnnouncementBarTokens {
+    info_bg: Hsla,
+    info_fg: Hsla,
+    success_bg: Hsla,
+    success_fg: Hsla,
+    warning_bg: Hsla,
+    warning_fg: Hsla,
+    error_bg: Hsla,
+    error_fg: Hsla,
+    min_height: Pixels,
+    padding_x: Pixels,
+    padding_y: Pixels,
+    gap: Pixels,
+    font_size: Pixels,
+    icon_size: Pixels,
+    close_button_size: Pixels,
+    close_icon_size: Pixels,
+});
+
+impl_option_overrides_methods!(WizardOverrides => WizardTokens {
+    error_fg: Hsla,
+    error_size: Pixels,
+    footer_gap: Pixels,
+    root_gap: Pixels,
+});
+
+impl_option_overrides_methods!(EditableTextOverrides => EditableTextTokens {
+    fg: Hsla,
+    placeholder: Hsla,
+    hover_bg: Hsla,
+    error_fg: Hsla,
+    text_size: Pixels,
+    error_size: Pixels,
+    padding_x: Pixels,
+    padding_y: Pixels,
+    radius: Pixels,
+    gap: Pixels,
+});
+
+impl_option_overrides_methods!(CopyButtonOverrides => CopyButtonTokens {
+    copied_fg: Hsla,
+    icon_size: Pixels,
+});
+
+impl_option_overrides_methods!(ChatViewOverrides => ChatViewTokens {
+    own_bg: Hsla,
+    other_bg: Hsla,
+    author_fg: Hsla,
+    time_fg: Hsla,
+    separator_fg: Hsla,
+    separator_line: Hsla,
+    avatar_bg: Hsla,
+    avatar_fg: Hsla,
+    pill_bg: Hsla,
+    pill_fg: Hsla,
+    typing_fg: Hsla,
+    padding: Pixels,
+    message_gap: Pixels,
+    avatar_size: Pixels,
+    avatar_gap: Pixels,
+    bubble_radius: Pixels,
+    bubble_padding_x: Pixels,
+    bubble_padding_y: Pixels,
+    bubble_max_width: Pixels,
+    meta_size: Pixels,
+    separator_size: Pixels,
+    estimated_row_height: Pixels,
+    pill_offset: Pixels,
+});
+
+impl_option_overrides_methods!(LogViewOverrides => LogViewTokens {
+    bg: Hsla,
+    border: Hsla,
+    fg: Hsla,
+    muted_fg: Hsla,
+    gutter_fg: Hsla,
+    warn_fg: Hsla,
+    error_fg: Hsla,
+    selection_bg: Hsla,
+    ansi_black: Hsla,
+    ansi_red: Hsla,
+    ansi_green: Hsla,
+    ansi_yellow: Hsla,
+    ansi_blue: Hsla,
+    ansi_magenta: Hsla,
+    ansi_cyan: Hsla,
+    ansi_white: Hsla,
+    text_size: Pixels,
+    line_height: Pixels,
+    padding: Pixels,
+    gutter_gap: Pixels,
+    radius: Pixels,
+});
+
+impl_option_overrides_methods!(JsonTreeOverrides => JsonTreeTokens {
+    bg: Hsla,
+    border: Hsla,
+    punctuation_fg: Hsla,
+    key_fg: Hsla,
+    index_fg: Hsla,
+    string_fg: Hsla,
+    number_fg: Hsla,
+    boolean_fg: Hsla,
+    null_fg: Hsla,
+    summary_fg: Hsla,
+    toggle_fg: Hsla,
+    more_fg: Hsla,
+    row_hover_bg: Hsla,
+    match_bg: Hsla,
+    text_size: Pixels,
+    row_height: Pixels,
+    indent: Pixels,
+    padding: Pixels,
+    toggle_size: Pixels,
+    radius: Pixels,
+});
+
+impl_option_overrides_methods!(SettingsViewOverrides => SettingsViewTokens {
+    bg: Hsla,
+    border: Hsla,
+    nav_bg: Hsla,
+    nav_fg: Hsla,
+    nav_hover_bg: Hsla,
+    nav_active_bg: Hsla,
+    nav_active_fg: Hsla,
+    count_fg: Hsla,
+    title_fg: Hsla,
+    description_fg: Hsla,
+    label_fg: Hsla,
+    separator: Hsla,
+    match_bg: Hsla,
+    empty_fg: Hsla,
+    nav_width: Pixels,
+    nav_padding: Pixels,
+    nav_gap: Pixels,
+    nav_item_padding_x: Pixels,
+    nav_item_padding_y: Pixels,
+    nav_item_radius: Pixels,
+    nav_item_size: Pixels,
+    content_padding: Pixels,
+    content_max_width: Pixels,
+    section_gap: Pixels,
+    title_size: Pixels,
+    heading_size: Pixels,
+    description_size: Pixels,
+    label_size: Pixels,
+    item_gap: Pixels,
+    item_padding_y: Pixels,
+    control_width: Pixels,
+});
+
+impl_option_overrides_methods!(ZoomPaneOverrides => ZoomPaneTokens {
+    bg: Hsla,
+    grid_line: Hsla,
+    grid_line_major: Hsla,
+    ruler_bg: Hsla,
+    ruler_tick: Hsla,
+    ruler_size: Pixels,
+    grid_spacing: Pixels,
+});
+
+impl_option_overrides_methods!(GraphCanvasOverrides => GraphCanvasTokens {
+    node_bg: Hsla,
+    node_border: Hsla,
+    node_selected_border: Hsla,
+    node_header_bg: Hsla,
+    node_fg: Hsla,
+    port_bg: Hsla,
+    port_border: Hsla,
+    edge: Hsla,
+    edge_selected: Hsla,
+    minimap_bg: Hsla,
+    minimap_node: Hsla,
+    minimap_viewport: Hsla,
+    node_radius: Pixels,
+    edge_width: Pixels,
+    port_size: Pixels,
+    label_size: Pixels,
+    minimap_width: Pixels,
+    minimap_height: Pixels,
+});
+
+impl_option_overrides_methods!(DatePickerOverrides => DatePickerTokens {
+    bg: Hsla,
+    fg: Hsla,
+    placeholder: Hsla,
+    border: Hsla,
+    border_focus: Hsla,
+    border_error: Hsla,
+    icon: Hsla,
+    label: Hsla,
+    label_size: Pixels,
+    label_weight: FontWeight,
+    description: Hsla,
+    description_size: Pixels,
+    error: Hsla,
+    error_size: Pixels,
+    label_block_gap: Pixels,
+    label_row_gap: Pixels,
+    layout_gap_vertical: Pixels,
+    layout_gap_horizontal: Pixels,
+    horizontal_label_width: Pixels,
+    slot_gap: Pixels,
+    icon_size: Pixels,
+    dropdown_bg: Hsla,
+    dropdown_border: Hsla,
+    dropdown_padding: Pixels,
+    dropdown_anchor_offset: Pixels,
+    dropdown_open_preferred_height: Pixels,
+    header_fg: Hsla,
+    header_size: Pixels,
+    nav_hover_bg: Hsla,
+    weekday_fg: Hsla,
+    weekday_size: Pixels,
+    day_fg: Hsla,
+    day_outside_fg: Hsla,
+    day_hover_bg: Hsla,
+    day_selected_bg: Hsla,
+    day_selected_fg: Hsla,
+    day_today_border: Hsla,
+    day_size: Pixels,
+    day_cell_size: Pixels,
+    grid_gap: Pixels,
+    time_column_width: Pixels,
+    time_item_height: Pixels,
+    time_item_size: Pixels,
+    sizes: FieldSizeScale,
+    states: PseudoStateTokens,
+});
+
+impl_option_overrides_methods!(RulersOverrides => RulersTokens {
+    bg: Hsla,
+    border: Hsla,
+    tick: Hsla,
+    tick_major: Hsla,
+    label: Hsla,
+    label_size: Pixels,
+    size: Pixels,
+    spacing: Pixels,
+    guide: Hsla,
+    guide_snapped: Hsla,
+    guide_hit_width: Pixels,
+});
+
+impl_option_overrides_methods!(ColorPickerOverrides => ColorPickerTokens {
+    bg: Hsla,
+    border: Hsla,
+    padding: Pixels,
+    gap: Pixels,
+    area_height: Pixels,
+    slider_height: Pixels,
+    thumb_size: Pixels,
+    thumb_border: Hsla,
+    checker: Hsla,
+    preview_size: Pixels,
+    swatch_size: Pixels,
+    swatch_gap: Pixels,
+    swatch_border: Hsla,
+    swatch_selected_border: Hsla,
+});
+
+impl_option_overrides_methods!(FileInputOverrides => FileInputTokens {
+    bg: Hsla,
+    bg_active: Hsla,
+    border: Hsla,
+    border_active: Hsla,
+    border_error: Hsla,
+    fg: Hsla,
+    hint: Hsla,
+    icon: Hsla,
+    radius: Pixels,
+    padding: Pixels,
+    gap: Pixels,
+    icon_size: Pixels,
+    text_size: Pixels,
+    hint_size: Pixels,
+    chip_bg: Hsla,
+    chip_border: Hsla,
+    chip_fg: Hsla,
+    chip_meta: Hsla,
+    chip_remove_hover_bg: Hsla,
+    chip_size: Pixels,
+    chip_padding_x: Pixels,
+    chip_padding_y: Pixels,
+    chip_gap: Pixels,
+    chip_max_width: Pixels,
+    label: Hsla,
+    label_size: Pixels,
+    label_weight: FontWeight,
+    description: Hsla,
+    description_size: Pixels,
+    error: Hsla,
+    error_size: Pixels,
+    label_block_gap: Pixels,
+    label_row_gap: Pixels,
+    layout_gap_vertical: Pixels,
+    layout_gap_horizontal: Pixels,
+    horizontal_label_width: Pixels,
+});
+
 impl_nested_overrides_methods!(ComponentOverrides {
     button: ButtonOverrides,
     input: InputOverrides,
@@ -865,7 +1560,37 @@ impl_nested_overrides_methods!(ComponentOverrides {
     stepper: StepperOverrides,
     timeline: TimelineOverrides,
     tree: TreeOverrides,
+    zoom_pane: ZoomPaneOverrides,
+    graph_canvas: GraphCanvasOverrides,
+    date_picker: DatePickerOverrides,
+    rulers: RulersOverrides,
+    color_picker: ColorPickerOverrides,
+    file_input: FileInputOverrides,
     layout: LayoutOverrides,
+    focus_ring: FocusRingOverrides,
+    press_feedback: PressFeedbackOverrides,
+    async_content: AsyncContentOverrides,
+    checklist: ChecklistOverrides,
+    task_status: TaskStatusOverrides,
+    notification_center: NotificationCenterOverrides,
+    find_bar: FindBarOverrides,
+    split_pane: SplitPaneOverrides,
+    dock_tabs: DockTabsOverrides,
+    image: ImageOverrides,
+    calendar: CalendarOverrides,
+    kanban_board: KanbanBoardOverrides,
+    chart: ChartOverrides,
+    ring_progress: RingProgressOverrides,
+    stat_card: StatCardOverrides,
+    empty_state: EmptyStateOverrides,
+    announcement_bar: AnnouncementBarOverrides,
+    wizard: WizardOverrides,
+    editable_text: EditableTextOverrides,
+    copy_button: CopyButtonOverrides,
+    chat_view: ChatViewOverrides,
+    log_view: LogViewOverrides,
+    json_tree: JsonTreeOverrides,
+    settings_view: SettingsViewOverrides,
 });
 
 impl ThemeOverrides {
@@ -933,6 +1658,85 @@ macro_rules! impl_theme_component_passthrough_methods {
                 }
             )*
         }
+
+        impl ThemeBuilder {
+            $(
+                pub fn $field(mut self, configure: impl FnOnce($value) -> $value) -> Self {
+                    self.overrides = self.overrides.$field(configure);
+                    self
+                }
+            )*
+        }
+
+        impl BlendTokens for ComponentTokens {
+            fn blend(&self, to: &Self, progress: f32) -> Self {
+                let mut next = to.clone();
+                $(
+                    next.$field = self.$field.blend(&to.$field, progress);
+                )*
+                next
+            }
+        }
+
+        impl RetintTokens for ComponentTokens {
+            fn retint(&mut self, probe: &Self, map: &dyn Fn(Hsla) -> Hsla) {
+                $(
+                    self.$field.retint(&probe.$field, map);
+                )*
+            }
+
+            fn replace_color(&mut self, path: &str, color: Hsla) -> bool {
+                let (field, rest) = path.split_once('.').unwrap_or((path, ""));
+                match field {
+                    $(
+                        stringify!($field) => self.$field.replace_color(rest, color),
+                    )*
+                    _ => false,
+                }
+            }
+        }
+
+        impl ExportTokens for ComponentTokens {
+            fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+                $(
+                    self.$field
+                        .export_tokens(&format!("{path}.{}", stringify!($field)), tokens);
+                )*
+            }
+        }
+
+        impl ScaleTokens for ComponentTokens {
+            fn scale(&mut self, _field: &str, factors: DimensionScale) {
+                $(
+                    self.$field.scale(stringify!($field), factors);
+                )*
+            }
+        }
+
+        impl OverridePatch<ComponentTokens> for ComponentOverrides {
+            fn diff(base: &ComponentTokens, target: &ComponentTokens) -> Self {
+                Self {
+                    $(
+                        $field: <$value>::diff(&base.$field, &target.$field),
+                    )*
+                }
+            }
+
+            fn collect_issues(
+                &self,
+                current: &ComponentTokens,
+                prefix: &str,
+                issues: &mut Vec<ThemeOverrideIssue>,
+            ) {
+                $(
+                    self.$field.collect_issues(
+                        &current.$field,
+                        &format!("{prefix}.{}", stringify!($field)),
+                        issues,
+                    );
+                )*
+            }
+        }
     };
 }
 
@@ -980,7 +1784,37 @@ impl_theme_component_passthrough_methods!(
     stepper: StepperOverrides,
     timeline: TimelineOverrides,
     tree: TreeOverrides,
+    zoom_pane: ZoomPaneOverrides,
+    graph_canvas: GraphCanvasOverrides,
+    date_picker: DatePickerOverrides,
+    rulers: RulersOverrides,
+    color_picker: ColorPickerOverrides,
+    file_input: FileInputOverrides,
     layout: LayoutOverrides,
+    focus_ring: FocusRingOverrides,
+    press_feedback: PressFeedbackOverrides,
+    async_content: AsyncContentOverrides,
+    checklist: ChecklistOverrides,
+    task_status: TaskStatusOverrides,
+    notification_center: NotificationCenterOverrides,
+    find_bar: FindBarOverrides,
+    split_pane: SplitPaneOverrides,
+    dock_tabs: DockTabsOverrides,
+    image: ImageOverrides,
+    calendar: CalendarOverrides,
+    kanban_board: KanbanBoardOverrides,
+    chart: ChartOverrides,
+    ring_progress: RingProgressOverrides,
+    stat_card: StatCardOverrides,
+    empty_state: EmptyStateOverrides,
+    announcement_bar: AnnouncementBarOverrides,
+    wizard: WizardOverrides,
+    editable_text: EditableTextOverrides,
+    copy_button: CopyButtonOverrides,
+    chat_view: ChatViewOverrides,
+    log_view: LogViewOverrides,
+    json_tree: JsonTreeOverrides,
+    settings_view: SettingsViewOverrides,
 );
 
 impl Theme {
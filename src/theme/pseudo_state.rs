@@ -0,0 +1,139 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PseudoStateTokens {
+    pub hover_bg: Option<Hsla>,
+    pub hover_fg: Option<Hsla>,
+    pub active_bg: Option<Hsla>,
+    pub focus_border: Option<Hsla>,
+    pub disabled_bg: Option<Hsla>,
+    pub disabled_fg: Option<Hsla>,
+}
+
+impl PseudoStateTokens {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hover_bg(mut self, value: impl Into<Hsla>) -> Self {
+        self.hover_bg = Some(value.into());
+        self
+    }
+
+    pub fn hover_fg(mut self, value: impl Into<Hsla>) -> Self {
+        self.hover_fg = Some(value.into());
+        self
+    }
+
+    pub fn active_bg(mut self, value: impl Into<Hsla>) -> Self {
+        self.active_bg = Some(value.into());
+        self
+    }
+
+    pub fn focus_border(mut self, value: impl Into<Hsla>) -> Self {
+        self.focus_border = Some(value.into());
+        self
+    }
+
+    pub fn disabled_bg(mut self, value: impl Into<Hsla>) -> Self {
+        self.disabled_bg = Some(value.into());
+        self
+    }
+
+    pub fn disabled_fg(mut self, value: impl Into<Hsla>) -> Self {
+        self.disabled_fg = Some(value.into());
+        self
+    }
+
+    pub fn merged(self, patch: Self) -> Self {
+        Self {
+            hover_bg: patch.hover_bg.or(self.hover_bg),
+            hover_fg: patch.hover_fg.or(self.hover_fg),
+            active_bg: patch.active_bg.or(self.active_bg),
+            focus_border: patch.focus_border.or(self.focus_border),
+            disabled_bg: patch.disabled_bg.or(self.disabled_bg),
+            disabled_fg: patch.disabled_fg.or(self.disabled_fg),
+        }
+    }
+
+    pub fn hover_bg_or(&self, derived: Hsla) -> Hsla {
+        self.hover_bg.unwrap_or(derived)
+    }
+
+    pub fn active_bg_or(&self, derived: Hsla) -> Hsla {
+        self.active_bg.unwrap_or(derived)
+    }
+
+    pub fn focus_border_or(&self, derived: Hsla) -> Hsla {
+        self.focus_border.unwrap_or(derived)
+    }
+
+    pub fn is_disabled_styled(&self) -> bool {
+        self.disabled_bg.is_some() || self.disabled_fg.is_some()
+    }
+
+    fn fields(&self) -> [(&'static str, Option<Hsla>); 6] {
+        [
+            ("hover_bg", self.hover_bg),
+            ("hover_fg", self.hover_fg),
+            ("active_bg", self.active_bg),
+            ("focus_border", self.focus_border),
+            ("disabled_bg", self.disabled_bg),
+            ("disabled_fg", self.disabled_fg),
+        ]
+    }
+
+    fn map_fields(&mut self, mut map: impl FnMut(Option<Hsla>, usize) -> Option<Hsla>) {
+        let slots = [
+            &mut self.hover_bg,
+            &mut self.hover_fg,
+            &mut self.active_bg,
+            &mut self.focus_border,
+            &mut self.disabled_bg,
+            &mut self.disabled_fg,
+        ];
+        for (index, slot) in slots.into_iter().enumerate() {
+            *slot = map(*slot, index);
+        }
+    }
+}
+
+impl BlendTokens for PseudoStateTokens {
+    fn blend(&self, to: &Self, progress: f32) -> Self {
+        let from = self.fields();
+        let mut next = *to;
+        next.map_fields(|target, index| match (from[index].1, target) {
+            (Some(from), Some(target)) => Some(BlendTokens::blend(&from, &target, progress)),
+            (_, target) => target,
+        });
+        next
+    }
+}
+
+impl RetintTokens for PseudoStateTokens {
+    fn retint(&mut self, probe: &Self, map: &dyn Fn(Hsla) -> Hsla) {
+        let probe = probe.fields();
+        self.map_fields(|value, index| match (value, probe[index].1) {
+            (Some(value), Some(probe)) if value == probe => Some(value),
+            (value, _) => value.map(map),
+        });
+    }
+
+    fn replace_color(&mut self, path: &str, color: Hsla) -> bool {
+        let Some(index) = self.fields().iter().position(|(field, _)| *field == path) else {
+            return false;
+        };
+        self.map_fields(|value, slot| if slot == index { Some(color) } else { value });
+        true
+    }
+}
+
+impl ExportTokens for PseudoStateTokens {
+    fn export_tokens(&self, path: &str, tokens: &mut Vec<DesignToken>) {
+        for (field, value) in self.fields() {
+            if let Some(value) = value {
+                value.export_tokens(&format!("{path}.{field}"), tokens);
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use super::*;
+
+/// Retints colors that differ from `probe`, the same tokens derived with
+/// another primary color, so palette colors shared with status tokens keep
+/// their catalog values.
+pub(crate) trait RetintTokens {
+    fn retint(&mut self, probe: &Self, map: &dyn Fn(Hsla) -> Hsla);
+
+    /// Sets the color at `path`, dotted and relative to this group as in
+    /// [`Theme::design_tokens`]. Returns whether the path named a color.
+    fn replace_color(&mut self, path: &str, color: Hsla) -> bool;
+}
+
+impl RetintTokens for Hsla {
+    fn retint(&mut self, probe: &Self, map: &dyn Fn(Hsla) -> Hsla) {
+        if self != probe {
+            *self = map(*self);
+        }
+    }
+
+    fn replace_color(&mut self, path: &str, color: Hsla) -> bool {
+        if path.is_empty() {
+            *self = color;
+        }
+        path.is_empty()
+    }
+}
+
+macro_rules! impl_fixed_retint {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl RetintTokens for $type {
+                fn retint(&mut self, _probe: &Self, _map: &dyn Fn(Hsla) -> Hsla) {}
+
+                fn replace_color(&mut self, _path: &str, _color: Hsla) -> bool {
+                    false
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_retint!(
+    Pixels,
+    FontWeight,
+    Size,
+    FocusRingStyle,
+    PressFeedbackStyle,
+    ButtonSizeScale,
+    FieldSizeScale,
+    InsetSizeScale,
+    GapSizeScale,
+    ChoiceControlSizeScale,
+    SwitchSizeScale,
+    BadgeSizeScale,
+    AccordionSizeScale,
+    ProgressSizeScale,
+    SliderSizeScale,
+    TableSizeScale,
+    TreeSizeScale,
+    LoaderSizeScale,
+    TextSizeScale,
+    ActionIconSizeScale,
+    SegmentedControlSizeScale,
+    RatingSizeScale,
+    TabsSizeScale,
+    PaginationSizeScale,
+    BreadcrumbsSizeScale,
+    StepperSizeScale,
+    TimelineSizeScale,
+    EmptyStateSizeScale,
+);
+
+impl Theme {
+    pub fn from_brand_color(brand: Hsla) -> Self {
+        Self::default().with_brand_color(brand)
+    }
+
+    pub fn with_brand_color(self, brand: Hsla) -> Self {
+        let key = color_utils::nearest_palette_key(brand);
+        self.with_palette_override(key, color_utils::scale_from_color(brand))
+            .with_primary_color(key)
+    }
+
+    /// Sets a `semantic.*` or `components.*` color token.
+    pub(super) fn replace_color_token(&mut self, path: &str, color: Hsla) -> bool {
+        match path.split_once('.') {
+            Some(("semantic", rest)) => self.semantic.replace_color(rest, color),
+            Some(("components", rest)) => self.components.replace_color(rest, color),
+            _ => false,
+        }
+    }
+
+    pub(super) fn retint_primary(&mut self) {
+        let catalog = seed_scale(&self.palette, self.primary_color);
+        let Some(scale) = self.palette.get(&self.primary_color).copied() else {
+            return;
+        };
+        if scale == catalog {
+            return;
+        }
+        let stops = catalog
+            .iter()
+            .zip(scale.iter())
+            .map(|(from, to)| (resolve_hex_hsla(*from), resolve_hex_hsla(*to)))
+            .collect::<Vec<_>>();
+        let map = |color: Hsla| {
+            stops
+                .iter()
+                .find(|(from, _)| from.h == color.h && from.s == color.s && from.l == color.l)
+                .map_or(color, |(_, to)| Hsla { a: color.a, ..*to })
+        };
+        let mut seed = self.seed;
+        seed.primary = if self.primary_color == PaletteKey::Gray {
+            PaletteKey::Blue
+        } else {
+            PaletteKey::Gray
+        };
+        let probe = SeedPalette::from_theme_palette(seed, self.color_scheme, &self.palette);
+        self.semantic
+            .retint(&SemanticColors::from_seed_palette(probe), &map);
+        self.components
+            .retint(&ComponentTokens::from_seed_palette(probe), &map);
+    }
+}
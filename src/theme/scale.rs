@@ -0,0 +1,75 @@
+use super::*;
+
+/// Field names that mark a dimension as spacing rather than a size.
+const SPACING_WORDS: [&str; 6] = ["padding", "gap", "margin", "space", "inset", "indent"];
+
+/// Factors [`ThemeBuilder`] applies to every radius and spacing token.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct DimensionScale {
+    pub radius: f32,
+    pub spacing: f32,
+}
+
+impl DimensionScale {
+    /// Factor for a token named `field`. Font sizes, widths and other
+    /// dimensions keep their value.
+    fn factor(&self, field: &str) -> f32 {
+        if field.contains("radius") {
+            self.radius
+        } else if SPACING_WORDS.iter().any(|word| field.contains(word)) {
+            self.spacing
+        } else {
+            1.0
+        }
+    }
+}
+
+pub(crate) trait ScaleTokens {
+    /// Scales the dimensions in `self`. `field` is the name of the field
+    /// holding it, which is what decides the factor for a bare value.
+    fn scale(&mut self, field: &str, factors: DimensionScale);
+}
+
+impl ScaleTokens for Pixels {
+    fn scale(&mut self, field: &str, factors: DimensionScale) {
+        *self = *self * factors.factor(field);
+    }
+}
+
+macro_rules! impl_fixed_scale {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl ScaleTokens for $type {
+                fn scale(&mut self, _field: &str, _factors: DimensionScale) {}
+            }
+        )*
+    };
+}
+
+impl_fixed_scale!(
+    Hsla,
+    FontWeight,
+    Size,
+    FocusRingStyle,
+    PressFeedbackStyle,
+    PseudoStateTokens,
+);
+
+impl Theme {
+    pub(super) fn scale_dimensions(&mut self, factors: DimensionScale) {
+        // Radii are named by size, so they are scaled here rather than by
+        // field name. `pill` stays fully rounded either way.
+        let radii = &mut self.radii;
+        for radius in [
+            &mut radii.default,
+            &mut radii.xs,
+            &mut radii.sm,
+            &mut radii.md,
+            &mut radii.lg,
+            &mut radii.xl,
+        ] {
+            *radius = *radius * factors.radius;
+        }
+        self.components.scale("components", factors);
+    }
+}
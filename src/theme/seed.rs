@@ -0,0 +1,198 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ContrastLevel {
+    #[default]
+    Standard,
+    /// Moves text and border shades one step further from the background,
+    /// then raises semantic text to WCAG AAA contrast (7:1, 4.5:1 for muted
+    /// and status text) and borders and focus rings to 3:1. Component pairs
+    /// in [`audit::DEFAULT_PAIRS`] are then raised to their AA targets.
+    High,
+}
+
+const TEXT_CONTRAST: f32 = 7.0;
+const MUTED_TEXT_CONTRAST: f32 = 4.5;
+const NON_TEXT_CONTRAST: f32 = 3.0;
+
+/// The few choices every default token is derived from. A brand theme
+/// usually only needs a primary palette and perhaps a surface color for
+/// each scheme: `ThemeSeed::new(PaletteKey::Teal).surface(ColorScheme::Light, cream)`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ThemeSeed {
+    pub primary: PaletteKey,
+    /// Neutral palette for light-scheme surfaces, borders and text, and
+    /// for text in the dark scheme.
+    pub gray: PaletteKey,
+    /// Neutral palette dark-scheme surfaces and borders are taken from.
+    pub dark: PaletteKey,
+    /// Background of inputs, cards and popovers in the light scheme.
+    /// Defaults to white.
+    pub surface_light: Option<Hsla>,
+    /// Background of inputs, cards and popovers in the dark scheme.
+    /// Defaults to shade 8 of `dark`.
+    pub surface_dark: Option<Hsla>,
+    pub contrast: ContrastLevel,
+}
+
+impl ThemeSeed {
+    pub fn new(primary: PaletteKey) -> Self {
+        Self {
+            primary,
+            gray: PaletteKey::Gray,
+            dark: PaletteKey::Dark,
+            surface_light: None,
+            surface_dark: None,
+            contrast: ContrastLevel::Standard,
+        }
+    }
+
+    pub fn gray(mut self, key: PaletteKey) -> Self {
+        self.gray = key;
+        self
+    }
+
+    pub fn dark(mut self, key: PaletteKey) -> Self {
+        self.dark = key;
+        self
+    }
+
+    pub fn surface(mut self, scheme: ColorScheme, color: Hsla) -> Self {
+        match scheme {
+            ColorScheme::Light => self.surface_light = Some(color),
+            ColorScheme::Dark => self.surface_dark = Some(color),
+        }
+        self
+    }
+
+    pub fn contrast(mut self, level: ContrastLevel) -> Self {
+        self.contrast = level;
+        self
+    }
+}
+
+impl Default for ThemeSeed {
+    fn default() -> Self {
+        Self::new(PaletteKey::Blue)
+    }
+}
+
+/// A seed resolved for one scheme, which the default token tables pick
+/// their colors from.
+#[derive(Clone, Copy)]
+pub(super) struct SeedPalette {
+    pub(super) seed: ThemeSeed,
+    pub(super) scheme: ColorScheme,
+    primary: ColorScale,
+    gray: ColorScale,
+    dark: ColorScale,
+}
+
+impl SeedPalette {
+    pub(super) fn new(seed: ThemeSeed, scheme: ColorScheme) -> Self {
+        Self {
+            seed,
+            scheme,
+            primary: PaletteCatalog::resolve(seed.primary),
+            gray: PaletteCatalog::resolve(seed.gray),
+            dark: PaletteCatalog::resolve(seed.dark),
+        }
+    }
+
+    /// Takes custom seed palettes from a theme's own copy, so registering
+    /// one again later doesn't change the theme's tokens.
+    pub(super) fn from_theme_palette(
+        seed: ThemeSeed,
+        scheme: ColorScheme,
+        palette: &BTreeMap<PaletteKey, ColorScale>,
+    ) -> Self {
+        Self {
+            seed,
+            scheme,
+            primary: seed_scale(palette, seed.primary),
+            gray: seed_scale(palette, seed.gray),
+            dark: seed_scale(palette, seed.dark),
+        }
+    }
+
+    pub(super) fn primary(&self, shade: u8) -> Hsla {
+        scale_hsla(self.primary, shade)
+    }
+
+    pub(super) fn gray(&self, shade: u8) -> Hsla {
+        scale_hsla(self.gray, self.neutral_shade(shade))
+    }
+
+    pub(super) fn dark(&self, shade: u8) -> Hsla {
+        scale_hsla(self.dark, self.neutral_shade(shade))
+    }
+
+    /// Status and accent colors, which the seed leaves as they are.
+    pub(super) fn color(&self, key: PaletteKey, shade: u8) -> Hsla {
+        resolve_palette_hsla(key, shade)
+    }
+
+    pub(super) fn surface(&self) -> Hsla {
+        match self.scheme {
+            ColorScheme::Light => self.seed.surface_light.unwrap_or_else(white),
+            ColorScheme::Dark => self
+                .seed
+                .surface_dark
+                .unwrap_or_else(|| scale_hsla(self.dark, 8)),
+        }
+    }
+
+    /// Backgrounds sit at the ends of a neutral scale and text and borders
+    /// in between, so high contrast pushes the middle shades away from the
+    /// background the scheme uses.
+    fn neutral_shade(&self, shade: u8) -> u8 {
+        match (self.seed.contrast, self.scheme) {
+            (ContrastLevel::Standard, _) => shade,
+            (ContrastLevel::High, ColorScheme::Light) if (3..=8).contains(&shade) => shade + 1,
+            (ContrastLevel::High, ColorScheme::Dark) if (1..=6).contains(&shade) => shade - 1,
+            (ContrastLevel::High, _) => shade,
+        }
+    }
+}
+
+/// Scale the tokens of a theme are derived from for `key`. Built-in keys
+/// use the catalog scale, and overrides of them retint the primary tokens
+/// afterwards; custom keys only exist as the theme's copy.
+pub(super) fn seed_scale(
+    palette: &BTreeMap<PaletteKey, ColorScale>,
+    key: PaletteKey,
+) -> ColorScale {
+    match key {
+        PaletteKey::Custom(_) => palette
+            .get(&key)
+            .copied()
+            .unwrap_or_else(|| PaletteCatalog::resolve(key)),
+        _ => PaletteCatalog::scale(key),
+    }
+}
+
+impl SemanticColors {
+    /// Raises foreground tokens to the [`ContrastLevel::High`] targets on
+    /// every background token.
+    pub(super) fn meet_contrast_targets(&mut self) {
+        let backgrounds = [self.bg_canvas, self.bg_surface, self.bg_soft];
+        let raise = |color: &mut Hsla, target: f32| {
+            for bg in backgrounds {
+                *color = color_utils::ensure_contrast(*color, bg, target);
+            }
+        };
+        raise(&mut self.text_primary, TEXT_CONTRAST);
+        raise(&mut self.text_secondary, TEXT_CONTRAST);
+        for color in [
+            &mut self.text_muted,
+            &mut self.status_info,
+            &mut self.status_success,
+            &mut self.status_warning,
+            &mut self.status_error,
+        ] {
+            raise(color, MUTED_TEXT_CONTRAST);
+        }
+        raise(&mut self.border_strong, NON_TEXT_CONTRAST);
+        raise(&mut self.focus_ring, NON_TEXT_CONTRAST);
+    }
+}
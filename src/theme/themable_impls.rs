@@ -1,80 +1,193 @@
+#[cfg(feature = "json")]
+use crate::components::JsonTree;
+#[cfg(feature = "tables")]
+use crate::components::Table;
 use crate::components::{
-    Accordion, ActionIcon, Alert, AppShell, Badge, Breadcrumbs, Button, ButtonGroup, Checkbox,
-    CheckboxGroup, Chip, ChipGroup, Divider, Drawer, Grid, HoverCard, Loader, LoadingOverlay,
-    Markdown, Menu, Modal, ModalLayer, MultiSelect, NumberInput, Overlay, Pagination, Paper,
-    PasswordInput, PinInput, Popover, Progress, Radio, RadioGroup, RangeSlider, Rating, ScrollArea,
-    SegmentedControl, Select, Sidebar, SimpleGrid, Slider, Space, Stepper, Switch, Table, Tabs,
-    Text, TextInput, Textarea, Timeline, Title, TitleBar, ToastLayer, Tooltip, Tree,
+    Accordion, ActionIcon, Alert, AnnouncementBar, AppShell, Badge, Breadcrumbs, Button,
+    ButtonGroup, Calendar, Checklist, CompareSlider, CopyButton, Divider, EmptyState, Grid,
+    HistoryTimeline, Image, KanbanBoard, Loader, LogView, MiniMap, Modal, ModalLayer,
+    NotificationCenter, Overlay, Pagination, Paper, Progress, RingProgress, Rulers, ScrollArea,
+    Sidebar, SimpleGrid, Space, SplitPane, StatCard, Stepper, Tabs, Text, Timeline, Title,
+    TitleBar, ToastLayer, Tree, Wizard, ZoomPane,
+};
+#[cfg(feature = "charts")]
+use crate::components::{BarChart, DonutChart, GraphCanvas, LineChart, Sparkline};
+#[cfg(feature = "overlays")]
+use crate::components::{
+    BottomSheet, ContextMenu, DockTabs, Drawer, HoverCard, LoadingOverlay, Menu, Popover,
+    TaskStatusItem, Tooltip,
+};
+#[cfg(feature = "markdown")]
+use crate::components::{ChatView, Markdown};
+#[cfg(feature = "forms")]
+use crate::components::{
+    Checkbox, CheckboxGroup, Chip, ChipGroup, ColorPicker, Combobox, ConsoleInput, DatePicker,
+    DateTimePicker, EditableText, FileInput, FindBar, MultiSelect, NumberInput, PasswordInput,
+    PinInput, Radio, RadioGroup, RangeSlider, Rating, SegmentedControl, Select, Slider, Switch,
+    TagsInput, TextInput, Textarea, TimePicker,
 };
 
 use super::{
-    AccordionOverrides, ActionIconOverrides, AppShellOverrides, BadgeOverrides,
-    BreadcrumbsOverrides, ButtonOverrides, CheckboxOverrides, ChipOverrides, DividerOverrides,
-    DrawerOverrides, HoverCardOverrides, LayoutOverrides, LoaderOverrides, LoadingOverlayOverrides,
-    MarkdownOverrides, MenuOverrides, ModalOverrides, NumberInputOverrides, OverlayOverrides,
-    PaginationOverrides, PaperOverrides, PopoverOverrides, ProgressOverrides, RadioOverrides,
-    RangeSliderOverrides, RatingOverrides, ScrollAreaOverrides, SegmentedControlOverrides,
-    SelectOverrides, SidebarOverrides, SliderOverrides, StepperOverrides, SwitchOverrides,
-    TableOverrides, TabsOverrides, TextOverrides, TextareaOverrides, TimelineOverrides,
-    TitleBarOverrides, TreeOverrides,
+    AccordionOverrides, ActionIconOverrides, AnnouncementBarOverrides, AppShellOverrides,
+    BadgeOverrides, BreadcrumbsOverrides, ButtonOverrides, CalendarOverrides, ChecklistOverrides,
+    CopyButtonOverrides, DividerOverrides, EmptyStateOverrides, ImageOverrides,
+    KanbanBoardOverrides, LayoutOverrides, LoaderOverrides, LogViewOverrides, ModalOverrides,
+    OverlayOverrides, PaginationOverrides, PaperOverrides, ProgressOverrides,
+    RingProgressOverrides, RulersOverrides, ScrollAreaOverrides, SidebarOverrides, SliderOverrides,
+    SplitPaneOverrides, StatCardOverrides, StepperOverrides, TabsOverrides, TextOverrides,
+    TimelineOverrides, TitleBarOverrides, TreeOverrides, WizardOverrides, ZoomPaneOverrides,
 };
 
 crate::impl_themable!(Button, button, ButtonOverrides);
 crate::impl_themable!(ButtonGroup, button, ButtonOverrides);
+#[cfg(feature = "forms")]
 crate::impl_themable!(TextInput, input, super::InputOverrides);
+#[cfg(feature = "forms")]
 crate::impl_themable!(PasswordInput, input, super::InputOverrides);
+#[cfg(feature = "forms")]
 crate::impl_themable!(PinInput, input, super::InputOverrides);
-crate::impl_themable!(Radio, radio, RadioOverrides);
-crate::impl_themable!(RadioGroup, radio, RadioOverrides);
-crate::impl_themable!(Checkbox, checkbox, CheckboxOverrides);
-crate::impl_themable!(CheckboxGroup, checkbox, CheckboxOverrides);
-crate::impl_themable!(Switch, switch, SwitchOverrides);
-crate::impl_themable!(Chip, chip, ChipOverrides);
-crate::impl_themable!(ChipGroup, chip, ChipOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(FindBar, find_bar, super::FindBarOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(EditableText, editable_text, super::EditableTextOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Radio, radio, super::RadioOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(RadioGroup, radio, super::RadioOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Checkbox, checkbox, super::CheckboxOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(CheckboxGroup, checkbox, super::CheckboxOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Switch, switch, super::SwitchOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Chip, chip, super::ChipOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(ChipGroup, chip, super::ChipOverrides);
 crate::impl_themable!(Badge, badge, BadgeOverrides);
 crate::impl_themable!(Accordion, accordion, AccordionOverrides);
-crate::impl_themable!(Menu, menu, MenuOverrides);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(Menu, menu, super::MenuOverrides);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(ContextMenu, menu, super::MenuOverrides);
 crate::impl_themable!(Progress, progress, ProgressOverrides);
-crate::impl_themable!(Slider, slider, SliderOverrides);
+crate::impl_themable!(RingProgress, ring_progress, RingProgressOverrides);
+crate::impl_themable!(StatCard, stat_card, StatCardOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Slider, slider, super::SliderOverrides);
 crate::impl_themable!(Overlay, overlay, OverlayOverrides);
 crate::impl_themable!(Loader, loader, LoaderOverrides);
-crate::impl_themable!(LoadingOverlay, loading_overlay, LoadingOverlayOverrides);
-crate::impl_themable!(Popover, popover, PopoverOverrides);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(
+    LoadingOverlay,
+    loading_overlay,
+    super::LoadingOverlayOverrides
+);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(Popover, popover, super::PopoverOverrides);
+#[cfg(feature = "overlays")]
 crate::impl_themable!(Tooltip, tooltip, super::TooltipOverrides);
-crate::impl_themable!(HoverCard, hover_card, HoverCardOverrides);
-crate::impl_themable!(Select, select, SelectOverrides);
-crate::impl_themable!(MultiSelect, select, SelectOverrides);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(HoverCard, hover_card, super::HoverCardOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Select, select, super::SelectOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(MultiSelect, select, super::SelectOverrides);
 crate::impl_themable!(Modal, modal, ModalOverrides);
 crate::impl_themable!(ModalLayer, modal, ModalOverrides);
 crate::impl_themable!(ToastLayer, toast, super::ToastOverrides);
+crate::impl_themable!(
+    NotificationCenter,
+    notification_center,
+    super::NotificationCenterOverrides
+);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(TaskStatusItem, task_status, super::TaskStatusOverrides);
 crate::impl_themable!(Alert, toast, super::ToastOverrides);
+crate::impl_themable!(AnnouncementBar, announcement_bar, AnnouncementBarOverrides);
 crate::impl_themable!(Divider, divider, DividerOverrides);
+crate::impl_themable!(EmptyState, empty_state, EmptyStateOverrides);
 crate::impl_themable!(ScrollArea, scroll_area, ScrollAreaOverrides);
-crate::impl_themable!(Drawer, drawer, DrawerOverrides);
+crate::impl_themable!(MiniMap, scroll_area, ScrollAreaOverrides);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(Drawer, drawer, super::DrawerOverrides);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(DockTabs, dock_tabs, super::DockTabsOverrides);
+#[cfg(feature = "overlays")]
+crate::impl_themable!(BottomSheet, drawer, super::DrawerOverrides);
 crate::impl_themable!(AppShell, app_shell, AppShellOverrides);
 crate::impl_themable!(Sidebar, sidebar, SidebarOverrides);
 crate::impl_themable!(TitleBar, title_bar, TitleBarOverrides);
-crate::impl_themable!(Markdown, markdown, MarkdownOverrides);
+#[cfg(feature = "markdown")]
+crate::impl_themable!(Markdown, markdown, super::MarkdownOverrides);
+#[cfg(feature = "markdown")]
+crate::impl_themable!(ChatView, chat_view, super::ChatViewOverrides);
 crate::impl_themable!(Text, text, TextOverrides);
 crate::impl_themable!(Title, title, super::TitleOverrides);
 crate::impl_themable!(Paper, paper, PaperOverrides);
 crate::impl_themable!(ActionIcon, action_icon, ActionIconOverrides);
+#[cfg(feature = "forms")]
 crate::impl_themable!(
     SegmentedControl,
     segmented_control,
-    SegmentedControlOverrides
+    super::SegmentedControlOverrides
 );
-crate::impl_themable!(Textarea, textarea, TextareaOverrides);
-crate::impl_themable!(NumberInput, number_input, NumberInputOverrides);
-crate::impl_themable!(RangeSlider, range_slider, RangeSliderOverrides);
-crate::impl_themable!(Rating, rating, RatingOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Textarea, textarea, super::TextareaOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(ConsoleInput, textarea, super::TextareaOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(NumberInput, number_input, super::NumberInputOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(RangeSlider, range_slider, super::RangeSliderOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Rating, rating, super::RatingOverrides);
 crate::impl_themable!(Tabs, tabs, TabsOverrides);
 crate::impl_themable!(Pagination, pagination, PaginationOverrides);
 crate::impl_themable!(Breadcrumbs, breadcrumbs, BreadcrumbsOverrides);
-crate::impl_themable!(Table, table, TableOverrides);
+crate::impl_themable!(HistoryTimeline, breadcrumbs, BreadcrumbsOverrides);
+crate::impl_themable!(Image, image, ImageOverrides);
+crate::impl_themable!(Calendar, calendar, CalendarOverrides);
+#[cfg(feature = "json")]
+crate::impl_themable!(JsonTree, json_tree, super::JsonTreeOverrides);
+crate::impl_themable!(KanbanBoard, kanban_board, KanbanBoardOverrides);
+crate::impl_themable!(LogView, log_view, LogViewOverrides);
+#[cfg(feature = "tables")]
+crate::impl_themable!(Table, table, super::TableOverrides);
 crate::impl_themable!(Stepper, stepper, StepperOverrides);
+crate::impl_themable!(Checklist, checklist, ChecklistOverrides);
 crate::impl_themable!(Timeline, timeline, TimelineOverrides);
 crate::impl_themable!(Tree, tree, TreeOverrides);
+crate::impl_themable!(Wizard, wizard, WizardOverrides);
 crate::impl_themable!(Grid, layout, LayoutOverrides);
 crate::impl_themable!(SimpleGrid, layout, LayoutOverrides);
 crate::impl_themable!(Space, layout, LayoutOverrides);
+crate::impl_themable!(CompareSlider, slider, SliderOverrides);
+crate::impl_themable!(CopyButton, copy_button, CopyButtonOverrides);
+crate::impl_themable!(ZoomPane, zoom_pane, ZoomPaneOverrides);
+crate::impl_themable!(SplitPane, split_pane, SplitPaneOverrides);
+#[cfg(feature = "charts")]
+crate::impl_themable!(GraphCanvas, graph_canvas, super::GraphCanvasOverrides);
+#[cfg(feature = "charts")]
+crate::impl_themable!(Sparkline, chart, super::ChartOverrides);
+#[cfg(feature = "charts")]
+crate::impl_themable!(BarChart, chart, super::ChartOverrides);
+#[cfg(feature = "charts")]
+crate::impl_themable!(LineChart, chart, super::ChartOverrides);
+#[cfg(feature = "charts")]
+crate::impl_themable!(DonutChart, chart, super::ChartOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(DatePicker, date_picker, super::DatePickerOverrides);
+crate::impl_themable!(Rulers, rulers, RulersOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(TimePicker, date_picker, super::DatePickerOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(DateTimePicker, date_picker, super::DatePickerOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(ColorPicker, color_picker, super::ColorPickerOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(FileInput, file_input, super::FileInputOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(Combobox, select, super::SelectOverrides);
+#[cfg(feature = "forms")]
+crate::impl_themable!(TagsInput, select, super::SelectOverrides);
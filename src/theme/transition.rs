@@ -0,0 +1,130 @@
+use super::*;
+use crate::motion::{Easing, MotionLevel};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ThemeTransition {
+    pub enabled: bool,
+    pub duration_ms: u16,
+    pub easing: Easing,
+}
+
+impl Default for ThemeTransition {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration_ms: 240,
+            easing: Easing::EaseInOut,
+        }
+    }
+}
+
+impl ThemeTransition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn crossfade(duration_ms: u16) -> Self {
+        Self {
+            enabled: true,
+            duration_ms,
+            ..Self::default()
+        }
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn duration_ms(mut self, duration_ms: u16) -> Self {
+        self.duration_ms = duration_ms;
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn animates(self, level: MotionLevel) -> bool {
+        self.enabled && self.duration_ms > 0 && level == MotionLevel::Full
+    }
+}
+
+pub trait BlendTokens {
+    fn blend(&self, to: &Self, progress: f32) -> Self;
+}
+
+impl BlendTokens for Hsla {
+    fn blend(&self, to: &Self, progress: f32) -> Self {
+        if progress <= 0.0 {
+            return *self;
+        }
+        if progress >= 1.0 {
+            return *to;
+        }
+        let from = self.to_rgb();
+        let target = to.to_rgb();
+        let mix = |a: f32, b: f32| a + (b - a) * progress;
+        Rgba {
+            r: mix(from.r, target.r),
+            g: mix(from.g, target.g),
+            b: mix(from.b, target.b),
+            a: mix(from.a, target.a),
+        }
+        .into()
+    }
+}
+
+macro_rules! impl_snapping_blend {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl BlendTokens for $type {
+                fn blend(&self, to: &Self, _progress: f32) -> Self {
+                    to.clone()
+                }
+            }
+        )*
+    };
+}
+
+impl_snapping_blend!(
+    Pixels,
+    FontWeight,
+    Size,
+    FocusRingStyle,
+    PressFeedbackStyle,
+    ButtonSizeScale,
+    FieldSizeScale,
+    InsetSizeScale,
+    GapSizeScale,
+    ChoiceControlSizeScale,
+    SwitchSizeScale,
+    BadgeSizeScale,
+    AccordionSizeScale,
+    ProgressSizeScale,
+    SliderSizeScale,
+    TableSizeScale,
+    TreeSizeScale,
+    LoaderSizeScale,
+    TextSizeScale,
+    ActionIconSizeScale,
+    SegmentedControlSizeScale,
+    RatingSizeScale,
+    TabsSizeScale,
+    PaginationSizeScale,
+    BreadcrumbsSizeScale,
+    StepperSizeScale,
+    TimelineSizeScale,
+    EmptyStateSizeScale,
+);
+
+impl BlendTokens for Theme {
+    fn blend(&self, to: &Self, progress: f32) -> Self {
+        Self {
+            semantic: self.semantic.blend(&to.semantic, progress),
+            components: self.components.blend(&to.components, progress),
+            ..to.clone()
+        }
+    }
+}
@@ -1,4 +1,9 @@
 use std::collections::BTreeMap;
+use std::sync::{LazyLock, RwLock};
+
+use gpui::Hsla;
+
+use crate::theme::color_utils::{intern_hex, to_hex};
 
 pub const COLOR_STOPS: usize = 10;
 pub type ColorScale = [&'static str; COLOR_STOPS];
@@ -19,6 +24,8 @@ pub enum PaletteKey {
     Lime,
     Yellow,
     Orange,
+    /// A palette added with [`PaletteCatalog::register`].
+    Custom(&'static str),
 }
 
 impl PaletteKey {
@@ -38,6 +45,7 @@ impl PaletteKey {
             Self::Lime => "lime",
             Self::Yellow => "yellow",
             Self::Orange => "orange",
+            Self::Custom(name) => name,
         }
     }
 }
@@ -59,9 +67,97 @@ pub const PALETTE_KEYS: [PaletteKey; 14] = [
     PaletteKey::Orange,
 ];
 
+static CUSTOM_PALETTES: LazyLock<RwLock<BTreeMap<&'static str, ColorScale>>> =
+    LazyLock::new(RwLock::default);
+
+/// A stop of a palette passed to [`PaletteCatalog::register`]. Owned colors
+/// are converted to hex and interned, so registering the same scale again
+/// does not allocate.
+pub trait PaletteColor {
+    fn into_hex(self) -> &'static str;
+}
+
+impl PaletteColor for &'static str {
+    fn into_hex(self) -> &'static str {
+        self
+    }
+}
+
+impl PaletteColor for String {
+    fn into_hex(self) -> &'static str {
+        intern_hex(self)
+    }
+}
+
+impl PaletteColor for Hsla {
+    fn into_hex(self) -> &'static str {
+        intern_hex(to_hex(self))
+    }
+}
+
 pub struct PaletteCatalog;
 
 impl PaletteCatalog {
+    /// Adds a named palette, or replaces the scale of one registered
+    /// earlier, and returns its key. Names are interned, so registering one
+    /// read from configuration is fine. Themes built afterwards carry the
+    /// palette in [`Theme::palette`](crate::theme::Theme::palette).
+    pub fn register<C: PaletteColor>(
+        name: impl AsRef<str>,
+        colors: [C; COLOR_STOPS],
+    ) -> PaletteKey {
+        let scale = colors.map(PaletteColor::into_hex);
+        let mut palettes = CUSTOM_PALETTES
+            .write()
+            .unwrap_or_else(|err| err.into_inner());
+        let name: &'static str = match palettes.get_key_value(name.as_ref()) {
+            Some((interned, _)) => *interned,
+            None => Box::leak(name.as_ref().to_owned().into_boxed_str()),
+        };
+        palettes.insert(name, scale);
+        PaletteKey::Custom(name)
+    }
+
+    pub fn custom(name: &str) -> Option<PaletteKey> {
+        CUSTOM_PALETTES
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .get_key_value(name)
+            .map(|(interned, _)| PaletteKey::Custom(*interned))
+    }
+
+    /// Built-in keys followed by the registered ones.
+    pub fn keys() -> Vec<PaletteKey> {
+        let palettes = CUSTOM_PALETTES
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+        PALETTE_KEYS
+            .into_iter()
+            .chain(palettes.keys().copied().map(PaletteKey::Custom))
+            .collect()
+    }
+
+    /// Scale for any key, or `None` for a custom key that was never
+    /// registered.
+    pub fn get(key: PaletteKey) -> Option<ColorScale> {
+        match key {
+            PaletteKey::Custom(name) => CUSTOM_PALETTES
+                .read()
+                .unwrap_or_else(|err| err.into_inner())
+                .get(name)
+                .copied(),
+            _ => Some(Self::scale(key)),
+        }
+    }
+
+    /// [`Self::get`] for keys a theme was built with. A custom key that was
+    /// never registered falls back to the gray scale.
+    pub(crate) fn resolve(key: PaletteKey) -> ColorScale {
+        Self::get(key).unwrap_or(Self::scale(PaletteKey::Gray))
+    }
+
+    /// Scale for a built-in key. Custom keys are registered at runtime and
+    /// are looked up with [`Self::get`]; here they give the gray scale.
     pub const fn scale(key: PaletteKey) -> ColorScale {
         match key {
             PaletteKey::Dark => [
@@ -120,14 +216,22 @@ impl PaletteCatalog {
                 "#fff4e6", "#ffe8cc", "#ffd8a8", "#ffc078", "#ffa94d", "#ff922b", "#fd7e14",
                 "#f76707", "#e8590c", "#d9480f",
             ],
+            PaletteKey::Custom(_) => Self::scale(PaletteKey::Gray),
         }
     }
 
+    /// Every built-in and registered palette.
     pub fn store() -> BTreeMap<PaletteKey, ColorScale> {
         let mut palette_store = BTreeMap::new();
         for key in PALETTE_KEYS {
             palette_store.insert(key, Self::scale(key));
         }
+        let palettes = CUSTOM_PALETTES
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+        for (name, scale) in palettes.iter() {
+            palette_store.insert(PaletteKey::Custom(name), *scale);
+        }
         palette_store
     }
 }
@@ -1,56 +1,109 @@
+#[cfg(feature = "charts")]
+pub mod charts {
+    pub use crate::components::{
+        BarChart, ChartDatum, ChartSeries, DonutChart, LineChart, Sparkline,
+    };
+}
+
 pub mod data {
+    #[cfg(feature = "json")]
+    pub use crate::components::JsonTree;
     pub use crate::components::{
-        Progress, ProgressSection, Table, TableAlign, TableCell, TablePaginationPosition, TableRow,
-        TableSort, TableSortDirection,
+        KanbanBoard, KanbanColumn, KanbanMove, KanbanSource, LogBuffer, LogLevel, LogView,
+        Progress, ProgressSection, RingProgress, StatCard,
+    };
+    #[cfg(feature = "tables")]
+    pub use crate::components::{
+        Table, TableAlign, TableCell, TableLayoutState, TablePaginationPosition, TableRow,
+        TableSelectionChanged, TableSelectionMode, TableSort, TableSortDirection,
     };
 }
 
 pub mod display {
     pub use crate::components::{
-        Alert, AlertKind, Badge, Icon, Indicator, IndicatorPosition, Loader, LoaderElement,
-        LoaderVariant, Markdown, Text, TextTone, Title,
+        Alert, AlertKind, Badge, Calendar, CalendarDate, CalendarEvent, CalendarEventSource,
+        CalendarView, CompareSlider, DateRange, Icon, Image, ImageFit, ImageSrc, Indicator,
+        IndicatorPosition, Loader, LoaderElement, LoaderVariant, Text, TextTone, Title,
+    };
+    #[cfg(feature = "markdown")]
+    pub use crate::components::{ChatMessage, ChatView, Markdown};
+    #[cfg(feature = "charts")]
+    pub use crate::components::{
+        GraphCanvas, GraphConnectError, GraphEdge, GraphModel, GraphNode, GraphPort, GraphPortRef,
     };
 }
 
 pub mod feedback {
-    pub use crate::components::{LoadingOverlay, ModalLayer, ToastLayer};
-    pub use crate::feedback::{ToastEntry, ToastKind, ToastManager, ToastPosition, ToastViewport};
+    pub use crate::components::{
+        AnnouncementBar, AnnouncementBarPosition, AsyncContent, Checklist, ChecklistStatus,
+        ChecklistStep, EmptyState, ModalLayer, NotificationCenter, ToastLayer,
+    };
+    #[cfg(feature = "overlays")]
+    pub use crate::components::{LoadingOverlay, TaskStatusItem};
+    pub use crate::feedback::{
+        NotificationEntry, NotificationFilter, NotificationId, NotificationInbox, TaskEntry,
+        TaskHandle, TaskId, TaskManager, TaskStatus, TaskSummary, ToastAction, ToastEntry,
+        ToastKind, ToastManager, ToastOverflow, ToastPosition, ToastViewport,
+    };
 }
 
 pub mod form {
     pub use crate::components::{
-        ActionIcon, Button, ButtonGroup, ButtonGroupItem, Checkbox, CheckboxGroup, CheckboxOption,
-        Chip, ChipGroup, ChipOption, ChipSelectionMode, MultiSelect, NumberInput, Pagination,
-        PasswordInput, PinInput, Radio, RadioGroup, RadioOption, RangeSlider, Rating,
-        SegmentedControl, SegmentedControlItem, Select, SelectOption, Slider, Switch,
-        SwitchLabelPosition, TextInput, Textarea,
+        ActionIcon, Button, ButtonGroup, ButtonGroupItem, CopyButton, Pagination,
     };
+    #[cfg(feature = "forms")]
+    pub use crate::components::{
+        AutoForm, CalendarDate, CalendarDateTime, Checkbox, CheckboxGroup, CheckboxOption, Chip,
+        ChipGroup, ChipOption, ChipSelectionMode, ClockTime, ColorPicker, Combobox, ConsoleInput,
+        DatePicker, DateTimePicker, EditTrigger, EditableText, FileInput, InputMask, MultiSelect,
+        NumberInput, PasswordInput, PasswordStrength, PasteAttachment, PinInput, Radio, RadioGroup,
+        RadioOption, RangeSlider, Rating, RatingSymbol, SegmentedControl, SegmentedControlItem,
+        Select, SelectOption, SettingsItem, SettingsSchema, SettingsSection, SettingsView, Slider,
+        SliderMark, Switch, SwitchLabelPosition, TagsInput, TextInput, Textarea, TimeColumn,
+        TimePicker,
+    };
+    #[cfg(feature = "forms")]
     pub use crate::form::{
-        AsyncFieldValidator, FieldKey, FieldLens, FieldMeta, FieldValidator, FormController,
-        FormDraftStore, FormError, FormId, FormModel, FormOptions, FormResult, FormSnapshot,
-        FormValidator, InMemoryDraftStore, RevalidateMode, SubmitState, ValidationError,
-        ValidationMode, ValidationTicket,
+        AsyncFieldValidator, AutoField, AutoFieldKind, AutoFieldProps, ComposedLens,
+        FieldDescriptor, FieldKey, FieldLens, FieldMeta, FieldOptions, FieldPath, FieldRule,
+        FieldRuleKind, FieldRuleViolation, FieldValidator, FormController, FormDraftStore,
+        FormError, FormId, FormModel, FormOptions, FormResult, FormSnapshot, FormState, FormSubmit,
+        FormValidator, InMemoryDraftStore, NestedFormModel, RevalidateMode, SubmitState,
+        TemporalValue, ValidationError, ValidationMode, ValidationTicket,
     };
 }
 
 pub mod layout {
     pub use crate::components::{
-        Divider, DividerLabelPosition, Grid, Paper, ScrollArea, SimpleGrid, Space, Stack,
+        Divider, DividerLabelPosition, Grid, GuideAxis, GuideSnap, MiniMap, MiniMapMarker,
+        MiniMapMarkerKind, Paper, RulerGuide, Rulers, ScrollArea, SimpleGrid, Space, SplitPane,
+        Stack, ZoomPane, ZoomPaneHandle, ZoomView,
     };
 }
 
 pub mod navigation {
     pub use crate::components::{
-        Accordion, AccordionItem, AccordionItemMeta, AppShell, BreadcrumbItem, Breadcrumbs,
+        APP_SHELL_KEY_CONTEXT, Accordion, AccordionItem, AccordionItemMeta, AppShell,
+        AppShellLayout, AppShellPanel, BreadcrumbItem, Breadcrumbs, HistoryStep, HistoryTimeline,
         PaneChrome, PanelMode, Sidebar, SidebarMode, Stepper, StepperContentPosition, StepperStep,
-        TabItem, Tabs, Timeline, TimelineItem, TitleBar, Tree, TreeNode, TreeTogglePosition,
+        TabItem, Tabs, Timeline, TimelineItem, TitleBar, ToggleBottomPanel, ToggleInspector,
+        ToggleSidebar, Tree, TreeDropEvent, TreeDropPosition, TreeNode, TreeTogglePosition, Wizard,
+        WizardStep,
     };
+    #[cfg(feature = "overlays")]
+    pub use crate::components::{DockTab, DockTabs};
 }
 
 pub mod overlay {
+    #[cfg(feature = "forms")]
+    pub use crate::components::FindBar;
+    #[cfg(feature = "overlays")]
+    pub use crate::components::{
+        BottomSheet, ContextMenu, ContextMenuItem, Drawer, DrawerPlacement, HoverCard,
+        HoverCardPlacement, Menu, MenuItem, Popover, PopoverPlacement, Tooltip, TooltipPlacement,
+    };
     pub use crate::components::{
-        Drawer, DrawerPlacement, HoverCard, HoverCardPlacement, Menu, MenuItem, Modal, Overlay,
-        OverlayCoverage, OverlayMaterialMode, Popover, PopoverPlacement, Tooltip, TooltipPlacement,
+        FindMatch, FindScope, Modal, Overlay, OverlayCoverage, OverlayMaterialMode,
     };
 }
 
@@ -2,15 +2,47 @@ use calmui::components::*;
 use calmui::contracts::{
     Disableable, FieldLike, Openable, Radiused, Sized as SizedContract, Varianted, Visible,
 };
-use calmui::feedback::{ToastEntry, ToastKind, ToastManager, ToastPosition, ToastViewport};
+use calmui::feedback::{
+    NotificationEntry, NotificationFilter, NotificationInbox, TaskEntry, TaskManager, ToastAction,
+    ToastEntry, ToastKind, ToastManager, ToastPosition, ToastViewport,
+};
 use calmui::overlay::ModalManager;
-use calmui::style::{FieldLayout, Radius, Size, Variant};
+use calmui::resource::AsyncState;
+use calmui::style::{FieldLayout, GroupOrientation, Radius, Size, Variant};
 use gpui::{AnyElement, IntoElement, SharedString, div};
 
 fn into_any(element: impl IntoElement) -> AnyElement {
     element.into_any_element()
 }
 
+#[derive(Clone, calmui::form::FormModel)]
+struct ContractForm {
+    name: gpui::SharedString,
+}
+
+struct BoardSource;
+
+impl KanbanSource for BoardSource {
+    fn columns(&self) -> Vec<KanbanColumn> {
+        vec![
+            KanbanColumn::new("todo", "To do"),
+            KanbanColumn::new("doing", "In progress").limit(2),
+        ]
+    }
+
+    fn card_count(&self, column: &SharedString) -> usize {
+        if column == "todo" { 40 } else { 3 }
+    }
+
+    fn card_id(&self, column: &SharedString, index: usize) -> SharedString {
+        format!("{column}-{index}").into()
+    }
+
+    fn render_card(&self, column: &SharedString, index: usize) -> AnyElement {
+        div().child(format!("{column} #{index}")).into_any_element()
+    }
+}
+
 fn exercise_disableable<T, F>(mut make: F)
 where
     T: Disableable + IntoElement,
@@ -84,6 +116,15 @@ fn behavior_matrix_for_disableable_components() {
     let _ = CheckboxOption::new("check-a").label("A").disabled(true);
     exercise_disableable(|| Chip::new().label("chip"));
     let _ = ChipOption::new("chip-a").label("A").disabled(true);
+    exercise_disableable(ColorPicker::new);
+    exercise_disableable(Combobox::new);
+    exercise_disableable(|| CompareSlider::new().before(div()).after(div()));
+    exercise_disableable(|| CopyButton::new().value("npm install calmui"));
+    exercise_disableable(|| ConsoleInput::new().placeholder("command"));
+    exercise_disableable(|| DatePicker::new().placeholder("date"));
+    exercise_disableable(|| DateTimePicker::new().placeholder("date and time"));
+    exercise_disableable(|| EditableText::new().default_value("Untitled"));
+    exercise_disableable(|| FileInput::new().accept(["png"]));
     exercise_disableable(|| HoverCard::new().trigger(div()).content(div()));
     exercise_disableable(|| Indicator::new().child(div()));
     exercise_disableable(|| {
@@ -115,14 +156,17 @@ fn behavior_matrix_for_disableable_components() {
     let _ = StepperStep::new("step-a").labeled("A").disabled(true);
     exercise_disableable(|| Switch::new().label("switch"));
     let _ = TabItem::new("tab-a").label("A").disabled(true);
+    exercise_disableable(TagsInput::new);
     exercise_disableable(|| TextInput::new().placeholder("input"));
     exercise_disableable(|| Textarea::new().placeholder("textarea"));
+    exercise_disableable(|| TimePicker::new().with_seconds(true));
     exercise_disableable(|| Tooltip::new().label("tip").trigger(div()));
     let _ = TreeNode::new("node-a").label("Node A").disabled(true);
 }
 
 #[test]
 fn behavior_matrix_for_openable_and_visible_components() {
+    exercise_openable(|| BottomSheet::new().snap_points([0.4, 0.8]).content(div()));
     exercise_openable(|| Drawer::new().content(div()));
     exercise_openable(|| HoverCard::new().trigger(div()).content(div()));
     exercise_openable(|| {
@@ -158,6 +202,12 @@ fn behavior_matrix_for_field_components() {
     exercise_field_like(|| Slider::new().value(50.0));
     exercise_field_like(|| RangeSlider::new().values(20.0, 80.0));
     exercise_field_like(|| Rating::new().value(4.0));
+    exercise_field_like(DatePicker::new);
+    exercise_field_like(TimePicker::new);
+    exercise_field_like(DateTimePicker::new);
+    exercise_field_like(FileInput::new);
+    exercise_field_like(Combobox::new);
+    exercise_field_like(TagsInput::new);
 }
 
 #[test]
@@ -167,12 +217,17 @@ fn behavior_matrix_for_variant_size_radius_components() {
     exercise_variant_size_radius(|| Badge::new().label("badge"));
     exercise_variant_size_radius(|| Button::new().label("button"));
     exercise_variant_size_radius(|| ButtonGroup::new().item(ButtonGroupItem::new("a").label("A")));
+    exercise_variant_size_radius(|| CopyButton::new().value("token"));
     exercise_variant_size_radius(|| Checkbox::new().label("check"));
     exercise_variant_size_radius(|| {
         CheckboxGroup::new().option(CheckboxOption::new("a").label("A"))
     });
     exercise_variant_size_radius(|| Chip::new().label("chip"));
     exercise_variant_size_radius(|| ChipGroup::new().option(ChipOption::new("a").label("A")));
+    exercise_variant_size_radius(Combobox::new);
+    exercise_variant_size_radius(ConsoleInput::new);
+    exercise_variant_size_radius(DatePicker::new);
+    exercise_variant_size_radius(DateTimePicker::new);
     exercise_variant_size_radius(|| MultiSelect::new().option(SelectOption::new("a").label("A")));
     exercise_variant_size_radius(|| Pagination::new().total(20).value(1));
     exercise_variant_size_radius(|| Progress::new().value(60.0));
@@ -187,7 +242,9 @@ fn behavior_matrix_for_variant_size_radius_components() {
     exercise_variant_size_radius(|| Slider::new().value(50.0));
     exercise_variant_size_radius(|| Stepper::new().step(StepperStep::new("a").labeled("A")));
     exercise_variant_size_radius(|| Switch::new().label("switch"));
+    exercise_variant_size_radius(TagsInput::new);
     exercise_variant_size_radius(|| Tabs::new().item(TabItem::new("a").label("A")));
+    exercise_variant_size_radius(TimePicker::new);
     exercise_variant_size_radius(|| Timeline::new().item(TimelineItem::new().title("Event")));
     exercise_variant_size_radius(|| Tree::new().node(TreeNode::new("root").label("Root")));
 }
@@ -197,6 +254,10 @@ fn behavior_render_scenarios_group_a() {
     let _ = into_any(Accordion::new().item(AccordionItem::new("a").label("A").content(div())));
     let _ = into_any(ActionIcon::new().child(Icon::named("sparkles")));
     let _ = into_any(Alert::new().title("alert"));
+    let content: AsyncContent<u8> = AsyncContent::new()
+        .state(AsyncState::Error("offline".into()))
+        .on_retry(|_, _| {});
+    let _ = into_any(content);
     let _ = into_any(
         AppShell::new(div())
             .title_bar(TitleBar::new().title("Shell"))
@@ -209,26 +270,165 @@ fn behavior_render_scenarios_group_a() {
             .inspector_overlay_default_opened(true)
             .inline_dividers(true),
     );
+    let _ = into_any(
+        AppShell::new(div())
+            .title_bar(TitleBar::new().title("Shell"))
+            .title_bar_immersive(true)
+            .announcement_bar(
+                AnnouncementBar::new()
+                    .kind(AlertKind::Warning)
+                    .message("Your trial ends in 3 days")
+                    .action(div().child("Upgrade"))
+                    .on_dismiss(|_, _, _| {}),
+            )
+            .announcement_bar_position(AnnouncementBarPosition::AboveTitleBar),
+    );
+    let _ = into_any(
+        AppShell::new(div())
+            .title_bar(TitleBar::new().title("Shell"))
+            .announcement_bar(AnnouncementBar::new().message("Offline").dismissible(false)),
+    );
+    let _ = into_any(
+        AppShell::new(div())
+            .sidebar(div())
+            .inspector(div())
+            .bottom_panel(div())
+            .resizable(true)
+            .default_layout(
+                AppShellLayout::new()
+                    .with_size(AppShellPanel::Sidebar, 300.0)
+                    .with_collapsed(AppShellPanel::BottomPanel, true),
+            )
+            .on_layout_change(|_, _, _| {}),
+    );
     let _ = into_any(Badge::new().label("9+"));
     let _ = into_any(Breadcrumbs::new().item(BreadcrumbItem::new().label("Crumb")));
+    let _ = into_any(
+        Breadcrumbs::new()
+            .items(
+                (0..6)
+                    .map(|index| BreadcrumbItem::labeled(format!("Level {index}")).icon("folder")),
+            )
+            .max_items(3)
+            .on_item_click(|_, _, _, _| {})
+            .load_siblings(|_, label| async move {
+                vec![BreadcrumbItem::labeled(format!("{label} sibling"))]
+            })
+            .on_sibling_click(|_, _, _, _| {}),
+    );
     let _ = into_any(Button::new().label("button").loading(true));
+    let launch = CalendarDate::new(2026, 3, 9).expect("valid date");
+    let _ = into_any(
+        Calendar::new()
+            .default_view(CalendarView::Week)
+            .default_value(DateRange::new(launch, launch.add_days(4)))
+            .range_selection(true)
+            .locale("de-DE")
+            .today(launch)
+            .max_events_per_day(2)
+            .events(vec![
+                CalendarEvent::new("standup", "Standup", launch),
+                CalendarEvent::new("offsite", "Offsite", launch.add_days(1))
+                    .until(launch.add_days(3)),
+            ])
+            .on_change(|_, _, _| {})
+            .on_view_change(|_, _, _| {})
+            .on_event_click(|_, _, _| {}),
+    );
     let _ = into_any(
         ButtonGroup::new()
             .item(ButtonGroupItem::new("a").label("A"))
             .item(ButtonGroupItem::new("b").label("B"))
             .default_value("a"),
     );
+    let _ = into_any(CopyButton::new().value("sk-live-1234"));
+    let _ = into_any(
+        CopyButton::new()
+            .value("https://example.com/share/42")
+            .label("Copy link")
+            .copied_label("Link copied")
+            .tooltip_placement(TooltipPlacement::Bottom)
+            .feedback(std::time::Duration::from_secs(3))
+            .on_copy(|_, _, _| {}),
+    );
+    let _ = into_any(CopyButton::new().value("42").tooltip(false));
     let _ = into_any(Checkbox::new().label("check"));
     let _ = into_any(CheckboxGroup::new().option(CheckboxOption::new("a").label("A")));
+    let _ = into_any(
+        Checklist::new()
+            .title("Install")
+            .step(ChecklistStep::new("fetch").status(ChecklistStatus::Done))
+            .step(
+                ChecklistStep::new("build")
+                    .status(ChecklistStatus::Error)
+                    .details(div()),
+            )
+            .collapsible(false),
+    );
     let _ = into_any(Chip::new().label("chip"));
+    let _ = into_any(
+        ContextMenu::new()
+            .target(div())
+            .item(ContextMenuItem::new("copy").label("Copy"))
+            .item(ContextMenuItem::separator())
+            .item(ContextMenuItem::new("share").submenu([ContextMenuItem::new("email")])),
+    );
     let _ = into_any(ChipGroup::new().option(ChipOption::new("a").label("A")));
     let _ = into_any(Divider::horizontal());
+    let _ = into_any(
+        EmptyState::new()
+            .icon("search")
+            .title("No results")
+            .description("Try a different search term or clear the filters.")
+            .primary_action("New item", |_, _, _| {})
+            .secondary_action("Clear filters", |_, _, _| {})
+            .with_size(Size::Lg),
+    );
+    let _ = into_any(
+        EmptyState::new()
+            .illustration(div().size(gpui::px(120.0)))
+            .title("Welcome")
+            .with_size(Size::Xs),
+    );
+    let _ = into_any(
+        DockTabs::new()
+            .items([
+                DockTab::labeled("main", "main.rs").panel(div()),
+                DockTab::labeled("lib", "lib.rs").dirty(true),
+                DockTab::labeled("readme", "README.md")
+                    .closable(false)
+                    .disabled(true),
+            ])
+            .default_value("lib")
+            .order(["lib", "main"])
+            .on_change(|_, _, _| {})
+            .on_close(|_, _, _| {})
+            .on_reorder(|_, _, _| {})
+            .on_detach(|_, _, _, _| {}),
+    );
     let _ = into_any(
         Drawer::new()
             .content(div())
             .placement(DrawerPlacement::Left),
     );
+    let _ = into_any(
+        Drawer::new()
+            .content(div())
+            .placement(DrawerPlacement::Right)
+            .resizable(true)
+            .size(420.0)
+            .min_size(240.0)
+            .max_size(900.0)
+            .snap_points([0.3, 0.5, 1.0])
+            .on_resize(|_, _, _| {}),
+    );
     let _ = into_any(Grid::new().columns(3).child(div()).child(div()));
+    let _ = into_any(
+        HistoryTimeline::new()
+            .steps([HistoryStep::new("A"), HistoryStep::new("B").branches(2)])
+            .current(1)
+            .on_jump(|_, _, _| {}),
+    );
     let _ = into_any(
         HoverCard::new()
             .trigger(div())
@@ -237,6 +437,44 @@ fn behavior_render_scenarios_group_a() {
     );
     let _ = into_any(Icon::named("info"));
     let _ = into_any(Indicator::new().processing(true).child(div()));
+    let _ = into_any(
+        KanbanBoard::new()
+            .source(BoardSource)
+            .card_height(64.0)
+            .list_height(320.0)
+            .overscan(2)
+            .empty_label("Nothing here")
+            .on_card_click(|_, _, _, _| {})
+            .on_card_move(|_, _, _| {})
+            .on_card_reorder(|_, _, _| {}),
+    );
+    let _ = into_any(KanbanBoard::new().source(BoardSource).draggable(false));
+    let logs = LogBuffer::with_max_lines(500);
+    logs.push("\x1b[32mINFO\x1b[0m server listening on :8080\nWARN slow request\n");
+    logs.push_with_level(LogLevel::Error, "connection reset");
+    let _ = into_any(
+        LogView::new()
+            .buffer(logs.clone())
+            .filter("request")
+            .min_level(LogLevel::Warn)
+            .on_follow_change(|_following, _window, _cx| {}),
+    );
+    let _ = into_any(
+        LogView::new()
+            .buffer(logs)
+            .follow(false)
+            .line_numbers(false)
+            .selectable(false)
+            .overscan(0),
+    );
+    let _ = into_any(
+        Image::new()
+            .bytes(b"not an image".to_vec())
+            .fit(ImageFit::Contain)
+            .width(120.0)
+            .height(80.0)
+            .fallback(|| div().into_any_element()),
+    );
     let _ = into_any(Loader::new().variant(LoaderVariant::Bars).label("loading"));
     let _ = into_any(LoadingOverlay::new().content(div()).label("Busy"));
     let _ = into_any(
@@ -244,6 +482,31 @@ fn behavior_render_scenarios_group_a() {
             .open_links_with_system(false)
             .on_link_click(|_payload, _window, _cx| {}),
     );
+    let _ = into_any(
+        Markdown::new("Copy [this](https://example.com)\n\n```rust\nfn main() {}\n```")
+            .selectable(true),
+    );
+    let _ = into_any(
+        ChatView::new()
+            .messages([
+                ChatMessage::new("1", "Sam", "Morning! Did the **build** pass?")
+                    .day("Yesterday")
+                    .time("09:12"),
+                ChatMessage::new("2", "Sam", "The `nightly` one, I mean.").day("Yesterday"),
+                ChatMessage::new("3", "Me", "It did, see [the log](https://ci.example.com).")
+                    .own(true)
+                    .day("Today")
+                    .time("08:01"),
+                ChatMessage::new("4", "Assistant", "Summarising the")
+                    .day("Today")
+                    .streaming(true),
+            ])
+            .typing("Sam is typing")
+            .estimated_message_height(48.0)
+            .avatar(|_message| div().into_any_element())
+            .link_handler(|_link, _window, _cx| true),
+    );
+    let _ = into_any(ChatView::new().show_avatars(false).overscan(0));
     let _ = into_any(
         Menu::new()
             .item(MenuItem::new("a").label("A"))
@@ -257,6 +520,39 @@ fn behavior_render_scenarios_group_b() {
     let modal_manager = ModalManager::new();
     let _ = modal_manager.open(Modal::titled("Managed"));
     let _ = into_any(ModalLayer::new(modal_manager.clone()));
+    let nested = modal_manager.open_modal(Modal::confirm("Discard", "Discard changes?"));
+    let _ = into_any(ModalLayer::new(modal_manager.clone()).nested_overlay_opacity(0.4));
+    assert!(modal_manager.is_top(nested.id()));
+
+    let inbox = NotificationInbox::new();
+    let _ = inbox.push(
+        NotificationEntry::new("Build finished", "All targets passed").kind(ToastKind::Success),
+    );
+    let _ = into_any(
+        NotificationCenter::new()
+            .inbox(inbox.clone())
+            .filter(NotificationFilter::Unread)
+            .dismissible(false),
+    );
+
+    let scope = FindScope::new();
+    scope.set_query("report");
+    scope.open();
+    let _ = into_any(
+        FindBar::new(scope.clone())
+            .placeholder("Search page")
+            .on_navigate(|_, _, _| {})
+            .content(
+                Stack::vertical()
+                    .child(Text::new("Quarterly report").find_scope(scope.clone()))
+                    .child(Markdown::new("The **report** is ready.").find_scope(scope.clone())),
+            ),
+    );
+    let _ = into_any(
+        Table::new()
+            .row(TableRow::new().cell(TableCell::text("report.pdf")))
+            .find_scope(scope.clone()),
+    );
 
     let _ = into_any(
         MultiSelect::new()
@@ -271,6 +567,24 @@ fn behavior_render_scenarios_group_b() {
             .step(0.5)
             .precision(1),
     );
+    let _ = into_any(
+        NumberInput::new()
+            .default_value(1234.5)
+            .precision(2)
+            .fixed_decimals(true)
+            .group_thousands(true)
+            .locale("de-DE")
+            .suffix(" €"),
+    );
+    let _ = into_any(
+        NumberInput::new()
+            .value(-12.0)
+            .range(-100.0, 100.0)
+            .big_step(25.0)
+            .separators('\u{2019}', '.')
+            .prefix("$"),
+    );
+    let _ = into_any(NumberInput::new().value(16.0).suffix("px").max(16.0));
     let _ = into_any(
         Overlay::new()
             .content(div())
@@ -286,15 +600,107 @@ fn behavior_render_scenarios_group_b() {
             .section(ProgressSection::new(25.0))
             .section(ProgressSection::new(35.0)),
     );
+    let _ = into_any(
+        Progress::new()
+            .orientation(GroupOrientation::Vertical)
+            .sections([ProgressSection::new(40.0), ProgressSection::new(20.0)])
+            .striped(true)
+            .animated(true)
+            .label("Level")
+            .show_value(true),
+    );
+    let _ = into_any(
+        RingProgress::new()
+            .sections([
+                ProgressSection::new(30.0).color(gpui::rgb(0x40c057)),
+                ProgressSection::new(45.0),
+            ])
+            .size(120.0)
+            .thickness(12.0)
+            .start_angle(90.0)
+            .label(div().child("75%")),
+    );
+    let _ = into_any(RingProgress::gauge().value(64.0).show_value(true));
+    let _ = into_any(RingProgress::new().indeterminate(true).clear_sections());
+    let _ = into_any(
+        StatCard::new()
+            .label("Latency")
+            .value("182 ms")
+            .icon("clock")
+            .delta(-4.5)
+            .invert_delta(true)
+            .description("vs last week")
+            .sparkline(div().h(gpui::px(24.0))),
+    );
+    let _ = into_any(
+        StatCard::new()
+            .label("Users")
+            .delta(0.0)
+            .delta_label("steady"),
+    );
+    let _ = into_any(
+        StatCard::new()
+            .label("Orders")
+            .loading(true)
+            .bordered(false),
+    );
     let _ = into_any(Radio::new().label("radio"));
     let _ = into_any(RadioGroup::new().option(RadioOption::new("a").label("A")));
     let _ = into_any(RangeSlider::new().values(15.0, 85.0));
+    let _ = into_any(
+        RangeSlider::vertical()
+            .values(20.0, 60.0)
+            .tick_interval(20.0)
+            .mark(SliderMark::new(0.0).label("Low"))
+            .mark(SliderMark::new(100.0).label("High"))
+            .snap_to_marks(true)
+            .value_tooltip(true),
+    );
+    let _ = into_any(
+        RangeSlider::new()
+            .orientation(GroupOrientation::Vertical)
+            .values(30.0, 70.0)
+            .value_tooltip(true),
+    );
     let _ = into_any(Rating::new().value(3.5));
+    let _ = into_any(
+        Rating::new()
+            .value(3.75)
+            .fractions(4)
+            .symbol(RatingSymbol::heart())
+            .read_only(true)
+            .show_value(true),
+    );
+    let _ = into_any(
+        Rating::new()
+            .default_value(2.0)
+            .allow_half(false)
+            .clearable(true)
+            .symbol(RatingSymbol::single("brand:flame")),
+    );
     let _ = into_any(
         ScrollArea::new()
             .child(div())
             .direction(ScrollDirection::Both),
     );
+    let scroll_handle = gpui::ScrollHandle::new();
+    let _ = into_any(
+        ScrollArea::new()
+            .scroll_handle(scroll_handle.clone())
+            .child(div()),
+    );
+    let _ = into_any(
+        MiniMap::new()
+            .scroll_handle(scroll_handle)
+            .height(240.0)
+            .rows([0.5, 1.0, 0.0, 0.75])
+            .marker(MiniMapMarker::at_row(1, 4).kind(MiniMapMarkerKind::Error))
+            .marker(
+                MiniMapMarker::new(0.6)
+                    .span(0.1)
+                    .kind(MiniMapMarkerKind::Search),
+            ),
+    );
     let _ = into_any(
         SegmentedControl::new()
             .item(SegmentedControlItem::new("one").label("One"))
@@ -308,28 +714,152 @@ fn behavior_render_scenarios_group_b() {
     let _ = into_any(Sidebar::new().header(div()).content(div()).footer(div()));
     let _ = into_any(SimpleGrid::new().cols(2).child(div()).child(div()));
     let _ = into_any(Slider::new().value(30.0).step(5.0));
+    let _ = into_any(
+        Slider::new()
+            .value(50.0)
+            .tick_interval(25.0)
+            .marks(
+                [0.0, 50.0, 100.0].map(|value| SliderMark::new(value).label(format!("{value}%"))),
+            )
+            .snap_to_marks(true)
+            .value_tooltip(true),
+    );
+    let _ = into_any(
+        Slider::vertical()
+            .value(40.0)
+            .show_ticks(false)
+            .mark(SliderMark::new(20.0)),
+    );
+    let _ = into_any(
+        Slider::new()
+            .orientation(GroupOrientation::Vertical)
+            .value(70.0)
+            .width(160.0)
+            .with_size(Size::Lg),
+    );
     let _ = into_any(Space::new().with_size(Size::Lg));
+    let _ = into_any(
+        SplitPane::horizontal()
+            .default_ratio(0.3)
+            .min_first(120.0)
+            .min_second(200.0)
+            .step(24.0)
+            .first(div())
+            .second(
+                SplitPane::vertical()
+                    .ratio(0.6)
+                    .first(div())
+                    .second(div())
+                    .on_resize(|_, _, _| {}),
+            ),
+    );
     let _ = into_any(
         Stepper::new()
             .step(StepperStep::new("1").labeled("Step 1"))
             .step(StepperStep::new("2").labeled("Step 2")),
     );
+    let _ = into_any(
+        Wizard::new()
+            .step(
+                WizardStep::new("account")
+                    .label("Account")
+                    .description("Sign in details")
+                    .content(div())
+                    .validate(|_, _| Err("Email is required".into())),
+            )
+            .step(WizardStep::new("team").label("Team").skip_when(|| true))
+            .step(
+                WizardStep::new("confirm")
+                    .label("Confirm")
+                    .validate_async(|| async { Ok(()) }),
+            )
+            .default_active(1)
+            .back_label("Previous")
+            .next_label("Continue")
+            .finish_label("Create")
+            .on_step_change(|_, _, _, _| {})
+            .on_finish(|_, _| {}),
+    );
     let _ = into_any(Switch::new().label("switch"));
     let _ = into_any(
         Table::new()
             .header("Name")
             .row(TableRow::new().cell(TableCell::new("Alice"))),
     );
+    let _ = into_any(
+        Table::new()
+            .header("Email")
+            .selectable_cells(true)
+            .row(TableRow::new().cell(TableCell::text("alice@example.com"))),
+    );
     let _ = into_any(Tabs::new().item(TabItem::new("tab").label("Tab").panel("Panel")));
     let _ = into_any(Text::new("text"));
+    let _ = into_any(Text::new("selectable text").selectable(true));
 }
 
 #[test]
 fn behavior_render_scenarios_group_c() {
     let _ = into_any(TextInput::new().placeholder("input"));
+    let _ = into_any(
+        TextInput::new()
+            .mask(InputMask::pattern("(###) ###-####"))
+            .default_value("5551234567")
+            .on_change(|_, _, _| {})
+            .on_submit(|_, _, _| {}),
+    );
+    let _ = into_any(
+        TextInput::new()
+            .mask(InputMask::currency("$").suffix(" USD"))
+            .value("1234.5"),
+    );
     let _ = into_any(PasswordInput::new().placeholder("password"));
+    let _ = into_any(
+        PasswordInput::new()
+            .default_value("hunter2")
+            .reveal_toggle(true)
+            .on_reveal_change(|_, _, _| {})
+            .caps_lock_label("Caps Lock")
+            .strength_meter(true)
+            .right_slot(div()),
+    );
+    let _ = into_any(
+        PasswordInput::new()
+            .value("Correct-Horse-42")
+            .reveal(true)
+            .reveal_toggle(false)
+            .caps_lock_warning(false)
+            .strength_estimator(|value| {
+                if value.len() > 12 {
+                    PasswordStrength::Strong
+                } else {
+                    PasswordStrength::Weak
+                }
+            })
+            .strength_label(false),
+    );
     let _ = into_any(PinInput::new(6).value("123456"));
+    let _ = into_any(
+        PinInput::new(4)
+            .default_value("12")
+            .masked(true)
+            .error("Wrong code")
+            .on_complete(|_, _, _| {})
+            .verify(|code| async move {
+                if code == "1234" {
+                    Ok(())
+                } else {
+                    Err("Wrong code".into())
+                }
+            }),
+    );
+    let _ = into_any(PinInput::new(4).value("1234").verifying(true));
     let _ = into_any(Textarea::new().placeholder("textarea"));
+    let _ = into_any(
+        ConsoleInput::new()
+            .prompt("$")
+            .history(["help", "status"])
+            .on_complete(|value, _, _| Some(format!("{value} --all").into())),
+    );
     let _ = into_any(Timeline::new().item(TimelineItem::new().title("Event").body("Body")));
     let _ = into_any(Title::new("title"));
     let _ = into_any(
@@ -344,7 +874,19 @@ fn behavior_render_scenarios_group_d() {
     let toast_manager = ToastManager::new();
     toast_manager.configure_viewport(ToastViewport::new(ToastPosition::TopRight).max_visible(3));
     let _ = toast_manager.show(ToastEntry::new("Saved", "Done").kind(ToastKind::Success));
+    let _ = toast_manager.show(
+        ToastEntry::new("Deleted", "1 file")
+            .show_countdown(true)
+            .action(ToastAction::new("Undo", |_, _, _| {})),
+    );
     let _ = into_any(ToastLayer::new(toast_manager.clone()));
+    let _ = into_any(ToastLayer::new(toast_manager.clone()).regions([ToastPosition::TopRight]));
+
+    let task_manager = TaskManager::new();
+    let upload = task_manager.start(TaskEntry::new("Upload").progress(0.4));
+    let _ = task_manager.start(TaskEntry::new("Index").message("Scanning"));
+    let _ = upload.complete();
+    let _ = into_any(TaskStatusItem::new().manager(task_manager.clone()));
 
     let _ = into_any(
         Tooltip::new()
@@ -362,4 +904,270 @@ fn behavior_render_scenarios_group_d() {
             .value("root")
             .expanded_values(vec![SharedString::from("root")]),
     );
+
+    let zoom = ZoomPaneHandle::new();
+    zoom.zoom_in();
+    zoom.fit();
+    let _ = into_any(
+        ZoomPane::new()
+            .handle(zoom.clone())
+            .zoom_range(0.5, 4.0)
+            .pannable(false)
+            .on_zoom_change(|_, _, _| {})
+            .content(|_, _, _| div()),
+    );
+    zoom.reset();
+    assert_eq!(zoom.zoom(), 1.0);
+
+    let _ = into_any(
+        GraphCanvas::new()
+            .model(GraphModel::new().node(GraphNode::new("only").input(GraphPort::new("in"))))
+            .selected(["only"])
+            .zoom_handle(zoom)
+            .grid(false)
+            .minimap(false)
+            .on_node_move(|_, _, _, _, _| {})
+            .on_selection_change(|_, _, _| {})
+            .on_connect(|_, _, _, _| {}),
+    );
+
+    let _ = into_any(
+        Sparkline::new()
+            .values([1.0, 3.0, 2.0, 6.0, 4.0])
+            .color(gpui::rgb(0x12b886))
+            .width(96.0)
+            .height(24.0)
+            .area(true)
+            .value_format(|value| format!("{value:.1}").into()),
+    );
+    let _ = into_any(
+        BarChart::new()
+            .data([
+                ChartDatum::new("Q1", 12.0),
+                ChartDatum::new("Q2", -4.0).color(gpui::rgb(0xfa5252)),
+                ChartDatum::new("Q3", 20.0),
+            ])
+            .height(160.0)
+            .show_grid(false)
+            .show_tooltip(false),
+    );
+    let _ = into_any(BarChart::new().empty_label("Nothing yet"));
+    let _ = into_any(
+        LineChart::new()
+            .series([
+                ChartSeries::new("Visits", [120.0, 180.0, 150.0, 210.0]),
+                ChartSeries::new("Signups", [12.0, 30.0, 18.0]),
+            ])
+            .labels(["Mon", "Tue", "Wed", "Thu"])
+            .show_points(true)
+            .show_legend(true)
+            .area(true)
+            .show_axis(false),
+    );
+    let _ = into_any(
+        DonutChart::new()
+            .data([ChartDatum::new("Used", 42.0), ChartDatum::new("Free", 58.0)])
+            .size(120.0)
+            .thickness(14.0)
+            .label("42%")
+            .show_legend(false),
+    );
+
+    let start = CalendarDate::new(2024, 1, 31).expect("valid date");
+    let _ = into_any(
+        DatePicker::new()
+            .default_value(start)
+            .min_date(start.add_days(-10))
+            .max_date(start.add_months(2))
+            .first_weekday(1)
+            .opened(true)
+            .on_change(|_, _, _| {})
+            .on_open_change(|_, _, _| {}),
+    );
+
+    let _ = into_any(
+        ColorPicker::new()
+            .default_value(gpui::rgb(0x228be6))
+            .swatch_shade(4)
+            .on_change(|_, _, _| {}),
+    );
+    let _ = into_any(
+        ColorPicker::new()
+            .value(gpui::hsla(0.0, 0.0, 0.5, 0.4))
+            .with_alpha(false)
+            .show_hex(false)
+            .swatches([gpui::red(), gpui::blue()]),
+    );
+
+    let time = ClockTime::new(9, 30, 15).expect("valid time");
+    let _ = into_any(
+        TimePicker::new()
+            .default_value(time)
+            .with_seconds(true)
+            .step(TimeColumn::Minute, 5)
+            .opened(true)
+            .on_change(|_, _, _| {})
+            .on_open_change(|_, _, _| {}),
+    );
+    let _ = into_any(TimePicker::new().clear_value().placeholder("Start"));
+    let _ = into_any(
+        DateTimePicker::new()
+            .default_value(CalendarDateTime::new(start, time))
+            .min_date(start)
+            .first_weekday(1)
+            .step(TimeColumn::Second, 10)
+            .opened(true)
+            .on_change(|_, _, _| {}),
+    );
+
+    let _ = into_any(
+        EditableText::new()
+            .default_value("Quarterly report")
+            .placeholder("Untitled")
+            .trigger(EditTrigger::DoubleClick)
+            .validate(|value| {
+                if value.trim().is_empty() {
+                    Err("Name is required".into())
+                } else {
+                    Ok(())
+                }
+            })
+            .on_change(|_, _, _| {})
+            .on_cancel(|_, _| {})
+            .save(|_| async { Ok(()) }),
+    );
+    let _ = into_any(
+        EditableText::new()
+            .value("")
+            .placeholder("Add a title")
+            .with_size(Size::Xs)
+            .disabled(true),
+    );
+
+    let _ = into_any(
+        FileInput::new()
+            .multiple(true)
+            .max_files(3)
+            .accept([".pdf", "png"])
+            .default_value(["Cargo.toml", "missing/report.pdf"])
+            .placeholder("Attach files")
+            .on_change(|_, _, _| {})
+            .on_reject(|_, _, _| {}),
+    );
+    let _ = into_any(
+        FileInput::new()
+            .value(["README.md"])
+            .show_size(false)
+            .layout(FieldLayout::Horizontal)
+            .label("Attachment")
+            .error("Required")
+            .disabled(true),
+    );
+
+    let _ = into_any(
+        Combobox::new()
+            .options([
+                SelectOption::new("apple").label("Apple"),
+                SelectOption::new("apricot").label("Apricot"),
+                SelectOption {
+                    disabled: true,
+                    ..SelectOption::labeled("banana", "Banana")
+                },
+            ])
+            .default_value("ap")
+            .creatable(true)
+            .limit(5)
+            .debounce_ms(0)
+            .nothing_found("No fruit")
+            .render_option(|option, highlighted| {
+                let marker = if highlighted { "> " } else { "" };
+                div()
+                    .child(format!("{marker}{}", option.value))
+                    .into_any_element()
+            })
+            .on_change(|_, _, _| {})
+            .on_option_select(|_, _, _| {})
+            .on_create(|_, _, _| {})
+            .on_search(|_, _, _| {}),
+    );
+    let _ = into_any(
+        Combobox::new()
+            .value("query")
+            .filter(false)
+            .loading(true)
+            .placeholder("Search")
+            .layout(FieldLayout::Horizontal)
+            .label("City")
+            .error("Required")
+            .disabled(true),
+    );
+
+    let _ = into_any(
+        TagsInput::new()
+            .options([
+                SelectOption::labeled("rust", "Rust"),
+                SelectOption::labeled("gpui", "GPUI"),
+            ])
+            .default_values(["rust", "design", "ui"])
+            .separators([',', ';'])
+            .max_tags(5)
+            .max_visible_tags(2)
+            .limit(10)
+            .load_options(|query| async move { vec![SelectOption::labeled(query.clone(), query)] })
+            .on_change(|_, _, _| {})
+            .on_search(|_, _, _| {}),
+    );
+    let _ = into_any(
+        TagsInput::new()
+            .values(["only"])
+            .creatable(false)
+            .allow_duplicates(true)
+            .placeholder("Add tags")
+            .layout(FieldLayout::Horizontal)
+            .label("Tags")
+            .error("Required")
+            .disabled(true),
+    );
+
+    let guides = ZoomPaneHandle::new();
+    let _ = into_any(
+        Rulers::new()
+            .handle(guides.clone())
+            .default_guides([RulerGuide::vertical(40.0), RulerGuide::horizontal(12.5)])
+            .snap_step(10.0)
+            .snap_to([0.0, 100.0])
+            .snap_threshold(4.0)
+            .height(240.0)
+            .on_guides_change(|_, _, _| {})
+            .on_snap(|_, _, _| {})
+            .content(ZoomPane::new().handle(guides).rulers(false)),
+    );
+    let _ = into_any(
+        Rulers::new()
+            .guides([RulerGuide::horizontal(0.0)])
+            .show_guides(false),
+    );
+}
+
+#[test]
+fn behavior_render_scenarios_forms() {
+    use calmui::form::FormModel as _;
+
+    let controller = calmui::form::FormController::new(
+        ContractForm { name: "Ada".into() },
+        calmui::form::FormOptions::default(),
+    );
+    let form: AutoForm<ContractForm> = AutoForm::new(controller.clone()).columns(2);
+    let _ = into_any(form);
+    let schema = SettingsSchema::new().section(
+        SettingsSection::new("profile", "Profile").items(
+            ContractForm::auto_fields()
+                .into_iter()
+                .map(SettingsItem::new),
+        ),
+    );
+    let settings: SettingsView<ContractForm> = SettingsView::new(controller, schema)
+        .search("name")
+        .on_change(|_, _| {});
+    let _ = into_any(settings);
 }
@@ -1,5 +1,5 @@
 use calmui::components::*;
-use calmui::feedback::ToastManager;
+use calmui::feedback::{NotificationInbox, TaskManager, ToastManager};
 use calmui::overlay::ModalManager;
 use gpui::{AnyElement, IntoElement, div};
 
@@ -13,17 +13,30 @@ fn smoke_primitives_render_into_any_element() {
     let _ = into_any(Badge::new().label("badge"));
     let _ = into_any(Breadcrumbs::new().item(BreadcrumbItem::new().label("crumb")));
     let _ = into_any(Button::new().label("button"));
+    let _ = into_any(Calendar::new());
     let _ = into_any(
         ButtonGroup::new()
             .item(ButtonGroupItem::new("a").label("A"))
             .item(ButtonGroupItem::new("b").label("B")),
     );
     let _ = into_any(Checkbox::new().label("check"));
+    let _ = into_any(CopyButton::new().value("copy me"));
+    let _ = into_any(
+        CompareSlider::new()
+            .before(div())
+            .after(div())
+            .height(120.0),
+    );
     let _ = into_any(
         CheckboxGroup::new()
             .option(CheckboxOption::new("a").label("A"))
             .option(CheckboxOption::new("b").label("B")),
     );
+    let _ = into_any(
+        Checklist::new()
+            .title("Setup")
+            .step(ChecklistStep::new("download").status(ChecklistStatus::Active)),
+    );
     let _ = into_any(Chip::new().label("chip"));
     let _ = into_any(
         ChipGroup::new()
@@ -31,13 +44,23 @@ fn smoke_primitives_render_into_any_element() {
             .option(ChipOption::new("b").label("B")),
     );
     let _ = into_any(Divider::horizontal());
+    let _ = into_any(EmptyState::new().icon("inbox").title("No messages"));
+    let _ = into_any(
+        DockTabs::new()
+            .item(DockTab::labeled("main", "main.rs").dirty(true))
+            .item(DockTab::labeled("lib", "lib.rs").closable(false)),
+    );
     let _ = into_any(Icon::named("info"));
     let _ = into_any(Indicator::new().child(div().into_any_element()));
     let _ = into_any(Loader::new().label("loading"));
     let _ = into_any(LoadingOverlay::new().content(div()));
     let _ = into_any(Markdown::new("# hello"));
+    let _ = into_any(ChatView::new().messages([ChatMessage::new("1", "Sam", "Hi!")]));
+    let _ = into_any(Image::new().path("assets/missing.png").alt("Preview"));
     let _ = into_any(Paper::new().child(div().into_any_element()));
     let _ = into_any(Progress::new().value(40.0));
+    let _ = into_any(RingProgress::new().value(40.0).show_value(true));
+    let _ = into_any(StatCard::new().label("Revenue").value("$12,400").delta(8.2));
     let _ = into_any(
         Progress::new()
             .value(60.0)
@@ -52,11 +75,22 @@ fn smoke_primitives_render_into_any_element() {
 #[test]
 fn smoke_form_and_picker_components_render_into_any_element() {
     let _ = into_any(TextInput::new().placeholder("input"));
+    let _ = into_any(TextInput::new().on_paste_attachment(|_, _, _| {}));
     let _ = into_any(PasswordInput::new().placeholder("password"));
     let _ = into_any(PinInput::new(6).value("123456"));
     let _ = into_any(Textarea::new().placeholder("textarea"));
+    let _ = into_any(Textarea::new().on_paste_attachment(|_, _, _| {}));
+    let _ = into_any(ConsoleInput::new().placeholder("command"));
     let _ = into_any(NumberInput::new().value(42.0));
     let _ = into_any(Select::new().option(SelectOption::new("a").label("A")));
+    let _ = into_any(DatePicker::new().placeholder("Pick a date"));
+    let _ = into_any(TimePicker::new().placeholder("Pick a time"));
+    let _ = into_any(ColorPicker::new());
+    let _ = into_any(DateTimePicker::new().with_seconds(true));
+    let _ = into_any(FileInput::new().multiple(true));
+    let _ = into_any(EditableText::new().default_value("Untitled"));
+    let _ = into_any(Combobox::new().option(SelectOption::new("a").label("A")));
+    let _ = into_any(TagsInput::new().default_values(["a", "b"]));
     let _ = into_any(
         MultiSelect::new()
             .option(SelectOption::new("a").label("A"))
@@ -76,12 +110,30 @@ fn smoke_form_and_picker_components_render_into_any_element() {
             .step(StepperStep::new("1").labeled("Step 1"))
             .step(StepperStep::new("2").labeled("Step 2")),
     );
+    let _ = into_any(
+        Wizard::new()
+            .step(WizardStep::new("account").label("Account"))
+            .step(WizardStep::new("confirm").label("Confirm")),
+    );
 }
 
 #[test]
 fn smoke_popup_overlay_and_navigation_components_render_into_any_element() {
     let _ = into_any(Alert::new().title("alert"));
+    let _ = into_any(AnnouncementBar::new().message("Scheduled maintenance tonight"));
+    let _ = into_any(BottomSheet::titled("sheet").keyboard_inset(120.0));
+    let _ = into_any(
+        ContextMenu::new()
+            .target(div())
+            .item(ContextMenuItem::new("v").label("Item")),
+    );
     let _ = into_any(Drawer::new().content(div()));
+    let _ = into_any(
+        HistoryTimeline::new()
+            .step(HistoryStep::new("Open"))
+            .step(HistoryStep::new("Edit").branches(1))
+            .current(0),
+    );
     let _ = into_any(HoverCard::new().trigger(div()).content(div()));
     let _ = into_any(
         Menu::new()
@@ -93,6 +145,7 @@ fn smoke_popup_overlay_and_navigation_components_render_into_any_element() {
     let _ = into_any(Pagination::new().total(100).value(2));
     let _ = into_any(Popover::new().trigger(div()).content(div()));
     let _ = into_any(ScrollArea::new().child(div().into_any_element()));
+    let _ = into_any(MiniMap::new().rows([0.4, 0.8, 0.2]));
     let _ = into_any(Tooltip::new().label("tip").trigger(div()));
     let _ = into_any(TitleBar::new().title("titlebar"));
 }
@@ -102,10 +155,23 @@ fn smoke_layout_and_shell_components_render_into_any_element() {
     let _ = into_any(Grid::new().child(div().into_any_element()));
     let _ = into_any(SimpleGrid::new().child(div().into_any_element()));
     let _ = into_any(Space::new());
+    let _ = into_any(SplitPane::horizontal().first(div()).second(div()));
     let _ = into_any(Sidebar::new().content(div()));
     let _ = into_any(AppShell::new(div()));
     let _ = into_any(ToastLayer::new(ToastManager::new()));
+    let _ = into_any(TaskStatusItem::new().manager(TaskManager::new()));
     let _ = into_any(ModalLayer::new(ModalManager::new()));
+    let _ = into_any(NotificationCenter::new().inbox(NotificationInbox::new()));
+    let _ = into_any(FindBar::new(FindScope::new()).content(div()));
+    let _ = into_any(
+        ZoomPane::new()
+            .handle(ZoomPaneHandle::new())
+            .content_size(800.0, 600.0)
+            .grid(true)
+            .rulers(true)
+            .content(|_, _, _| div()),
+    );
+    let _ = into_any(Rulers::new().content(div()));
 }
 
 #[test]
@@ -114,13 +180,123 @@ fn smoke_data_heavy_components_render_into_any_element() {
         .header("Name")
         .row(TableRow::new().cell(TableCell::new("Alice")));
     let _ = into_any(table);
+    let _ = into_any(KanbanBoard::new());
+    let _ = into_any(LogView::new());
+
+    let sortable = Table::new()
+        .headers(["Name", "Age"])
+        .sortable(true)
+        .default_sorts([TableSort {
+            column: 1,
+            direction: TableSortDirection::Desc,
+        }])
+        .sort_comparator(0, |left, right| {
+            left.to_lowercase().cmp(&right.to_lowercase())
+        })
+        .on_sort_change(|_, _, _| {})
+        .resizable_columns(true)
+        .reorderable_columns(true)
+        .default_layout(TableLayoutState::new().order([1, 0]).width(0, 160.0))
+        .on_layout_change(|_, _, _| {})
+        .selection_mode(TableSelectionMode::Checkbox)
+        .default_selected_keys(["alice"])
+        .on_selection_change(|_, _, _| {})
+        .group_by(1)
+        .group_aggregate(1, |values| values.len().to_string().into())
+        .default_collapsed_groups(["9"])
+        .default_expanded_keys(["alice"])
+        .on_expanded_change(|_, _, _| {})
+        .rows([
+            TableRow::new()
+                .key("alice")
+                .detail("Alice details")
+                .cell(TableCell::new("Alice"))
+                .cell(TableCell::new("31").sort_value("31")),
+            TableRow::new()
+                .cell(TableCell::new("bob"))
+                .cell(TableCell::new("9").sort_value("9")),
+        ]);
+    let _ = into_any(sortable);
 
     let tree = Tree::new().node(TreeNode::new("root").label("Root"));
     let _ = into_any(tree);
 
+    let draggable_tree = Tree::new()
+        .node(TreeNode::new("root").child(TreeNode::new("leaf")))
+        .can_drop(|event| event.position != TreeDropPosition::Inside || event.parent.is_some())
+        .on_drop(|_event: &TreeDropEvent, _, _| {});
+    let _ = into_any(draggable_tree);
+
+    let lazy_tree = Tree::new()
+        .node(TreeNode::new("remote").has_children(true))
+        .load_children(|value| async move { vec![TreeNode::new(format!("{value}/child"))] });
+    let _ = into_any(lazy_tree);
+
+    let checkable_tree = Tree::new()
+        .node(
+            TreeNode::new("root")
+                .child(TreeNode::new("a"))
+                .child(TreeNode::new("b")),
+        )
+        .default_checked_values(["a".into()])
+        .on_checked_change(|_, _, _| {});
+    let _ = into_any(checkable_tree);
+
     let timeline = Timeline::new().item(TimelineItem::new().title("Event"));
     let _ = into_any(timeline);
 
     let accordion = Accordion::new().item(AccordionItem::new("a").label("A").content(div()));
     let _ = into_any(accordion);
+
+    let graph = GraphModel::new()
+        .node(
+            GraphNode::new("a")
+                .position(0.0, 0.0)
+                .output(GraphPort::new("out")),
+        )
+        .node(
+            GraphNode::new("b")
+                .position(240.0, 40.0)
+                .input(GraphPort::new("in")),
+        )
+        .edge(GraphEdge::new(
+            "a-b",
+            GraphPortRef::new("a", "out"),
+            GraphPortRef::new("b", "in"),
+        ));
+    let _ = into_any(GraphCanvas::new().model(graph).height(320.0));
+    let _ = into_any(Sparkline::new().values([3.0, 5.0, 4.0, 8.0]));
+    let _ = into_any(BarChart::new().data([ChartDatum::new("a", 2.0), ChartDatum::new("b", 5.0)]));
+    let _ = into_any(LineChart::new().series([ChartSeries::new("visits", [1.0, 4.0, 2.0])]));
+    let _ =
+        into_any(DonutChart::new().data([ChartDatum::new("a", 1.0), ChartDatum::new("b", 3.0)]));
+}
+
+#[cfg(feature = "spellcheck")]
+#[test]
+fn smoke_spell_checked_textarea_renders_into_any_element() {
+    let dictionary = calmui::spellcheck::WordListDictionary::from_text("hello world");
+    let _ = into_any(
+        Textarea::new()
+            .value("hello wrold")
+            .spell_checker(dictionary),
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn smoke_json_tree_renders_into_any_element() {
+    let doc = serde_json::json!({ "name": "calmui", "tags": ["ui", "gpui"], "stars": 42 });
+    let _ = into_any(JsonTree::new().value(doc.clone()));
+    let _ = into_any(
+        JsonTree::new()
+            .value(std::sync::Arc::new(doc))
+            .search("gpui")
+            .expand_depth(3)
+            .page_size(1)
+            .copy_actions(false)
+            .overscan(0)
+            .on_copy(|_text, _window, _cx| {})
+            .on_toggle(|_pointer, _open, _window, _cx| {}),
+    );
 }
@@ -14,65 +14,111 @@ fn assert_radiused<T: Radiused>() {}
 fn assert_theme_overridable<T: ComponentThemeOverridable>() {}
 fn assert_themable<T: Themable>() {}
 
+#[derive(Clone, calmui::form::FormModel)]
+struct ContractForm {
+    name: gpui::SharedString,
+}
+
 #[test]
 fn render_once_and_styled_matrix_compiles() {
     assert_render_once::<Accordion>();
     assert_render_once::<ActionIcon>();
     assert_render_once::<Alert>();
+    assert_render_once::<AnnouncementBar>();
     assert_render_once::<AppShell>();
+    assert_render_once::<AsyncContent<u8>>();
+    assert_render_once::<AutoForm<ContractForm>>();
     assert_render_once::<Badge>();
+    assert_render_once::<BottomSheet>();
     assert_render_once::<Breadcrumbs>();
     assert_render_once::<Button>();
+    assert_render_once::<Calendar>();
     assert_render_once::<ButtonGroup>();
+    assert_render_once::<Checklist>();
     assert_render_once::<Checkbox>();
     assert_render_once::<CheckboxGroup>();
     assert_render_once::<Chip>();
     assert_render_once::<ChipGroup>();
+    assert_render_once::<ColorPicker>();
+    assert_render_once::<Combobox>();
+    assert_render_once::<CompareSlider>();
+    assert_render_once::<CopyButton>();
+    assert_render_once::<ConsoleInput>();
+    assert_render_once::<ContextMenu>();
+    assert_render_once::<DatePicker>();
+    assert_render_once::<DateTimePicker>();
     assert_render_once::<Divider>();
+    assert_render_once::<DockTabs>();
     assert_render_once::<Drawer>();
+    assert_render_once::<EditableText>();
+    assert_render_once::<EmptyState>();
+    assert_render_once::<FileInput>();
+    assert_render_once::<FindBar>();
+    assert_render_once::<GraphCanvas>();
+    assert_render_once::<Sparkline>();
+    assert_render_once::<BarChart>();
+    assert_render_once::<LineChart>();
+    assert_render_once::<DonutChart>();
     assert_render_once::<Grid>();
+    assert_render_once::<HistoryTimeline>();
     assert_render_once::<HoverCard>();
     assert_render_once::<Icon>();
     assert_render_once::<Indicator>();
     assert_render_once::<Loader>();
     assert_render_once::<LoadingOverlay>();
     assert_render_once::<Markdown>();
+    assert_render_once::<ChatView>();
     assert_render_once::<Menu>();
+    assert_render_once::<MiniMap>();
     assert_render_once::<Modal>();
     assert_render_once::<ModalLayer>();
+    assert_render_once::<NotificationCenter>();
     assert_render_once::<MultiSelect>();
     assert_render_once::<NumberInput>();
     assert_render_once::<Overlay>();
     assert_render_once::<Pagination>();
+    assert_render_once::<Image>();
+    assert_render_once::<KanbanBoard>();
+    assert_render_once::<LogView>();
     assert_render_once::<Paper>();
     assert_render_once::<Popover>();
     assert_render_once::<Progress>();
+    assert_render_once::<RingProgress>();
     assert_render_once::<Radio>();
     assert_render_once::<RadioGroup>();
     assert_render_once::<RangeSlider>();
     assert_render_once::<Rating>();
+    assert_render_once::<Rulers>();
     assert_render_once::<ScrollArea>();
     assert_render_once::<SegmentedControl>();
     assert_render_once::<Select>();
+    assert_render_once::<SettingsView<ContractForm>>();
     assert_render_once::<Sidebar>();
     assert_render_once::<SimpleGrid>();
     assert_render_once::<Slider>();
     assert_render_once::<Space>();
+    assert_render_once::<SplitPane>();
+    assert_render_once::<StatCard>();
     assert_render_once::<Stepper>();
     assert_render_once::<Switch>();
     assert_render_once::<Table>();
     assert_render_once::<Tabs>();
+    assert_render_once::<TaskStatusItem>();
+    assert_render_once::<TagsInput>();
     assert_render_once::<Text>();
     assert_render_once::<TextInput>();
     assert_render_once::<PasswordInput>();
     assert_render_once::<PinInput>();
     assert_render_once::<Textarea>();
+    assert_render_once::<TimePicker>();
     assert_render_once::<Timeline>();
     assert_render_once::<Title>();
     assert_render_once::<TitleBar>();
     assert_render_once::<ToastLayer>();
     assert_render_once::<Tooltip>();
     assert_render_once::<Tree>();
+    assert_render_once::<Wizard>();
+    assert_render_once::<ZoomPane>();
 }
 
 #[test]
@@ -86,6 +132,14 @@ fn behavior_contract_matrix_compiles() {
     assert_disableable::<CheckboxOption>();
     assert_disableable::<Chip>();
     assert_disableable::<ChipOption>();
+    assert_disableable::<ColorPicker>();
+    assert_disableable::<Combobox>();
+    assert_disableable::<CompareSlider>();
+    assert_disableable::<CopyButton>();
+    assert_disableable::<DatePicker>();
+    assert_disableable::<DateTimePicker>();
+    assert_disableable::<EditableText>();
+    assert_disableable::<FileInput>();
     assert_disableable::<HoverCard>();
     assert_disableable::<Indicator>();
     assert_disableable::<Menu>();
@@ -107,11 +161,15 @@ fn behavior_contract_matrix_compiles() {
     assert_disableable::<StepperStep>();
     assert_disableable::<Switch>();
     assert_disableable::<TabItem>();
+    assert_disableable::<TagsInput>();
     assert_disableable::<TextInput>();
     assert_disableable::<Textarea>();
+    assert_disableable::<ConsoleInput>();
+    assert_disableable::<TimePicker>();
     assert_disableable::<Tooltip>();
     assert_disableable::<TreeNode>();
 
+    assert_openable::<BottomSheet>();
     assert_openable::<Drawer>();
     assert_openable::<HoverCard>();
     assert_openable::<Menu>();
@@ -124,6 +182,11 @@ fn behavior_contract_matrix_compiles() {
 
 #[test]
 fn field_and_style_contract_matrix_compiles() {
+    assert_field_like::<Combobox>();
+    assert_field_like::<DatePicker>();
+    assert_field_like::<DateTimePicker>();
+    assert_field_like::<FileInput>();
+    assert_field_like::<TagsInput>();
     assert_field_like::<TextInput>();
     assert_field_like::<PasswordInput>();
     assert_field_like::<Textarea>();
@@ -137,16 +200,22 @@ fn field_and_style_contract_matrix_compiles() {
     assert_field_like::<Slider>();
     assert_field_like::<RangeSlider>();
     assert_field_like::<Rating>();
+    assert_field_like::<TimePicker>();
 
     assert_varianted::<Accordion>();
     assert_varianted::<ActionIcon>();
     assert_varianted::<Badge>();
     assert_varianted::<Button>();
     assert_varianted::<ButtonGroup>();
+    assert_varianted::<CopyButton>();
     assert_varianted::<Checkbox>();
     assert_varianted::<CheckboxGroup>();
     assert_varianted::<Chip>();
     assert_varianted::<ChipGroup>();
+    assert_varianted::<Combobox>();
+    assert_varianted::<ConsoleInput>();
+    assert_varianted::<DatePicker>();
+    assert_varianted::<DateTimePicker>();
     assert_varianted::<MultiSelect>();
     assert_varianted::<Pagination>();
     assert_varianted::<Progress>();
@@ -160,6 +229,8 @@ fn field_and_style_contract_matrix_compiles() {
     assert_varianted::<Stepper>();
     assert_varianted::<Switch>();
     assert_varianted::<Tabs>();
+    assert_varianted::<TagsInput>();
+    assert_varianted::<TimePicker>();
     assert_varianted::<Timeline>();
     assert_varianted::<Tree>();
 
@@ -172,6 +243,14 @@ fn field_and_style_contract_matrix_compiles() {
     assert_sized::<CheckboxGroup>();
     assert_sized::<Chip>();
     assert_sized::<ChipGroup>();
+    assert_sized::<Combobox>();
+    assert_sized::<CompareSlider>();
+    assert_sized::<CopyButton>();
+    assert_sized::<ConsoleInput>();
+    assert_sized::<DatePicker>();
+    assert_sized::<DateTimePicker>();
+    assert_sized::<EditableText>();
+    assert_sized::<EmptyState>();
     assert_sized::<MultiSelect>();
     assert_sized::<Pagination>();
     assert_sized::<Progress>();
@@ -185,6 +264,8 @@ fn field_and_style_contract_matrix_compiles() {
     assert_sized::<Stepper>();
     assert_sized::<Switch>();
     assert_sized::<Tabs>();
+    assert_sized::<TagsInput>();
+    assert_sized::<TimePicker>();
     assert_sized::<Timeline>();
     assert_sized::<Tree>();
 
@@ -192,13 +273,21 @@ fn field_and_style_contract_matrix_compiles() {
     assert_radiused::<ActionIcon>();
     assert_radiused::<Badge>();
     assert_radiused::<Button>();
+    assert_radiused::<Calendar>();
     assert_radiused::<ButtonGroup>();
     assert_radiused::<Checkbox>();
     assert_radiused::<CheckboxGroup>();
     assert_radiused::<Chip>();
     assert_radiused::<ChipGroup>();
+    assert_radiused::<Combobox>();
+    assert_radiused::<CompareSlider>();
+    assert_radiused::<CopyButton>();
+    assert_radiused::<ConsoleInput>();
+    assert_radiused::<DatePicker>();
+    assert_radiused::<DateTimePicker>();
     assert_radiused::<MultiSelect>();
     assert_radiused::<Pagination>();
+    assert_radiused::<Image>();
     assert_radiused::<Paper>();
     assert_radiused::<Progress>();
     assert_radiused::<Radio>();
@@ -212,6 +301,8 @@ fn field_and_style_contract_matrix_compiles() {
     assert_radiused::<Switch>();
     assert_radiused::<Table>();
     assert_radiused::<Tabs>();
+    assert_radiused::<TagsInput>();
+    assert_radiused::<TimePicker>();
     assert_radiused::<Timeline>();
     assert_radiused::<Tree>();
 }
@@ -221,114 +312,196 @@ fn theme_contract_matrix_compiles() {
     assert_theme_overridable::<Accordion>();
     assert_theme_overridable::<ActionIcon>();
     assert_theme_overridable::<Alert>();
+    assert_theme_overridable::<AnnouncementBar>();
     assert_theme_overridable::<AppShell>();
+    assert_theme_overridable::<AsyncContent<u8>>();
+    assert_theme_overridable::<AutoForm<ContractForm>>();
     assert_theme_overridable::<Badge>();
+    assert_theme_overridable::<BottomSheet>();
     assert_theme_overridable::<Breadcrumbs>();
     assert_theme_overridable::<Button>();
+    assert_theme_overridable::<Calendar>();
     assert_theme_overridable::<ButtonGroup>();
+    assert_theme_overridable::<Checklist>();
     assert_theme_overridable::<Checkbox>();
     assert_theme_overridable::<CheckboxGroup>();
     assert_theme_overridable::<Chip>();
     assert_theme_overridable::<ChipGroup>();
+    assert_theme_overridable::<ColorPicker>();
+    assert_theme_overridable::<Combobox>();
+    assert_theme_overridable::<CompareSlider>();
+    assert_theme_overridable::<CopyButton>();
+    assert_theme_overridable::<ConsoleInput>();
+    assert_theme_overridable::<ContextMenu>();
+    assert_theme_overridable::<DatePicker>();
+    assert_theme_overridable::<DateTimePicker>();
     assert_theme_overridable::<Divider>();
+    assert_theme_overridable::<DockTabs>();
     assert_theme_overridable::<Drawer>();
+    assert_theme_overridable::<EditableText>();
+    assert_theme_overridable::<EmptyState>();
+    assert_theme_overridable::<FileInput>();
+    assert_theme_overridable::<FindBar>();
+    assert_theme_overridable::<GraphCanvas>();
+    assert_theme_overridable::<Sparkline>();
+    assert_theme_overridable::<BarChart>();
+    assert_theme_overridable::<LineChart>();
+    assert_theme_overridable::<DonutChart>();
     assert_theme_overridable::<Grid>();
+    assert_theme_overridable::<HistoryTimeline>();
     assert_theme_overridable::<HoverCard>();
     assert_theme_overridable::<Icon>();
     assert_theme_overridable::<Indicator>();
     assert_theme_overridable::<Loader>();
     assert_theme_overridable::<LoadingOverlay>();
     assert_theme_overridable::<Markdown>();
+    assert_theme_overridable::<ChatView>();
     assert_theme_overridable::<Menu>();
+    assert_theme_overridable::<MiniMap>();
     assert_theme_overridable::<Modal>();
     assert_theme_overridable::<ModalLayer>();
+    assert_theme_overridable::<NotificationCenter>();
     assert_theme_overridable::<MultiSelect>();
     assert_theme_overridable::<NumberInput>();
     assert_theme_overridable::<Overlay>();
     assert_theme_overridable::<Pagination>();
+    assert_theme_overridable::<Image>();
+    assert_theme_overridable::<KanbanBoard>();
+    assert_theme_overridable::<LogView>();
     assert_theme_overridable::<Paper>();
     assert_theme_overridable::<Popover>();
     assert_theme_overridable::<Progress>();
+    assert_theme_overridable::<RingProgress>();
     assert_theme_overridable::<Radio>();
     assert_theme_overridable::<RadioGroup>();
     assert_theme_overridable::<RangeSlider>();
     assert_theme_overridable::<Rating>();
+    assert_theme_overridable::<Rulers>();
     assert_theme_overridable::<ScrollArea>();
     assert_theme_overridable::<SegmentedControl>();
     assert_theme_overridable::<Select>();
+    assert_theme_overridable::<SettingsView<ContractForm>>();
     assert_theme_overridable::<Sidebar>();
     assert_theme_overridable::<SimpleGrid>();
     assert_theme_overridable::<Slider>();
     assert_theme_overridable::<Space>();
+    assert_theme_overridable::<SplitPane>();
+    assert_theme_overridable::<StatCard>();
     assert_theme_overridable::<Stepper>();
     assert_theme_overridable::<Switch>();
     assert_theme_overridable::<Table>();
     assert_theme_overridable::<Tabs>();
+    assert_theme_overridable::<TaskStatusItem>();
+    assert_theme_overridable::<TagsInput>();
     assert_theme_overridable::<Text>();
     assert_theme_overridable::<TextInput>();
     assert_theme_overridable::<PasswordInput>();
     assert_theme_overridable::<PinInput>();
     assert_theme_overridable::<Textarea>();
+    assert_theme_overridable::<TimePicker>();
     assert_theme_overridable::<Timeline>();
     assert_theme_overridable::<Title>();
     assert_theme_overridable::<TitleBar>();
     assert_theme_overridable::<ToastLayer>();
     assert_theme_overridable::<Tooltip>();
     assert_theme_overridable::<Tree>();
+    assert_theme_overridable::<Wizard>();
+    assert_theme_overridable::<ZoomPane>();
 
     assert_themable::<Accordion>();
     assert_themable::<ActionIcon>();
     assert_themable::<Alert>();
+    assert_themable::<AnnouncementBar>();
     assert_themable::<AppShell>();
+    assert_themable::<AsyncContent<u8>>();
+    assert_themable::<AutoForm<ContractForm>>();
     assert_themable::<Badge>();
+    assert_themable::<BottomSheet>();
     assert_themable::<Breadcrumbs>();
     assert_themable::<Button>();
+    assert_themable::<Calendar>();
     assert_themable::<ButtonGroup>();
+    assert_themable::<Checklist>();
     assert_themable::<Checkbox>();
     assert_themable::<CheckboxGroup>();
     assert_themable::<Chip>();
     assert_themable::<ChipGroup>();
+    assert_themable::<ColorPicker>();
+    assert_themable::<Combobox>();
+    assert_themable::<CompareSlider>();
+    assert_themable::<CopyButton>();
+    assert_themable::<ConsoleInput>();
+    assert_themable::<ContextMenu>();
+    assert_themable::<DatePicker>();
+    assert_themable::<DateTimePicker>();
     assert_themable::<Divider>();
+    assert_themable::<DockTabs>();
     assert_themable::<Drawer>();
+    assert_themable::<EditableText>();
+    assert_themable::<EmptyState>();
+    assert_themable::<FileInput>();
+    assert_themable::<FindBar>();
+    assert_themable::<GraphCanvas>();
+    assert_themable::<Sparkline>();
+    assert_themable::<BarChart>();
+    assert_themable::<LineChart>();
+    assert_themable::<DonutChart>();
     assert_themable::<Grid>();
+    assert_themable::<HistoryTimeline>();
     assert_themable::<HoverCard>();
     assert_themable::<LoadingOverlay>();
     assert_themable::<Loader>();
     assert_themable::<Markdown>();
+    assert_themable::<ChatView>();
     assert_themable::<Menu>();
+    assert_themable::<MiniMap>();
     assert_themable::<Modal>();
     assert_themable::<ModalLayer>();
+    assert_themable::<NotificationCenter>();
     assert_themable::<MultiSelect>();
     assert_themable::<NumberInput>();
     assert_themable::<Overlay>();
     assert_themable::<Pagination>();
+    assert_themable::<Image>();
+    assert_themable::<KanbanBoard>();
+    assert_themable::<LogView>();
     assert_themable::<Paper>();
     assert_themable::<Popover>();
     assert_themable::<Progress>();
+    assert_themable::<RingProgress>();
     assert_themable::<Radio>();
     assert_themable::<RadioGroup>();
     assert_themable::<RangeSlider>();
     assert_themable::<Rating>();
+    assert_themable::<Rulers>();
     assert_themable::<ScrollArea>();
     assert_themable::<SegmentedControl>();
     assert_themable::<Select>();
+    assert_themable::<SettingsView<ContractForm>>();
     assert_themable::<Sidebar>();
     assert_themable::<SimpleGrid>();
     assert_themable::<Slider>();
     assert_themable::<Space>();
+    assert_themable::<SplitPane>();
+    assert_themable::<StatCard>();
     assert_themable::<Stepper>();
     assert_themable::<Switch>();
     assert_themable::<Table>();
     assert_themable::<Tabs>();
+    assert_themable::<TaskStatusItem>();
+    assert_themable::<TagsInput>();
     assert_themable::<Text>();
     assert_themable::<TextInput>();
     assert_themable::<PasswordInput>();
     assert_themable::<PinInput>();
     assert_themable::<Textarea>();
+    assert_themable::<TimePicker>();
     assert_themable::<Timeline>();
     assert_themable::<Title>();
     assert_themable::<TitleBar>();
     assert_themable::<ToastLayer>();
     assert_themable::<Tooltip>();
     assert_themable::<Tree>();
+    assert_themable::<Wizard>();
+    assert_themable::<ZoomPane>();
 }
@@ -16,46 +16,226 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "alert.rs",
         src: include_str!("../../src/components/alert.rs"),
     },
+    FlattenInvariant {
+        file: "announcement_bar.rs",
+        src: include_str!("../../src/components/announcement_bar.rs"),
+    },
     FlattenInvariant {
         file: "app_shell.rs",
         src: include_str!("../../src/components/app_shell.rs"),
     },
+    FlattenInvariant {
+        file: "app_shell_layout.rs",
+        src: include_str!("../../src/components/app_shell_layout.rs"),
+    },
+    FlattenInvariant {
+        file: "async_content.rs",
+        src: include_str!("../../src/components/async_content.rs"),
+    },
+    FlattenInvariant {
+        file: "auto_form.rs",
+        src: include_str!("../../src/components/auto_form.rs"),
+    },
     FlattenInvariant {
         file: "badge.rs",
         src: include_str!("../../src/components/badge.rs"),
     },
+    FlattenInvariant {
+        file: "bar_chart.rs",
+        src: include_str!("../../src/components/bar_chart.rs"),
+    },
+    FlattenInvariant {
+        file: "bottom_sheet.rs",
+        src: include_str!("../../src/components/bottom_sheet.rs"),
+    },
+    FlattenInvariant {
+        file: "bottom_sheet_state.rs",
+        src: include_str!("../../src/components/bottom_sheet_state.rs"),
+    },
     FlattenInvariant {
         file: "breadcrumbs.rs",
         src: include_str!("../../src/components/breadcrumbs.rs"),
     },
+    FlattenInvariant {
+        file: "breadcrumbs_state.rs",
+        src: include_str!("../../src/components/breadcrumbs_state.rs"),
+    },
     FlattenInvariant {
         file: "button.rs",
         src: include_str!("../../src/components/button.rs"),
     },
+    FlattenInvariant {
+        file: "calendar.rs",
+        src: include_str!("../../src/components/calendar.rs"),
+    },
+    FlattenInvariant {
+        file: "calendar_state.rs",
+        src: include_str!("../../src/components/calendar_state.rs"),
+    },
+    FlattenInvariant {
+        file: "chart.rs",
+        src: include_str!("../../src/components/chart.rs"),
+    },
+    FlattenInvariant {
+        file: "chart_state.rs",
+        src: include_str!("../../src/components/chart_state.rs"),
+    },
+    FlattenInvariant {
+        file: "chat_view.rs",
+        src: include_str!("../../src/components/chat_view.rs"),
+    },
+    FlattenInvariant {
+        file: "chat_view_state.rs",
+        src: include_str!("../../src/components/chat_view_state.rs"),
+    },
     FlattenInvariant {
         file: "checkbox.rs",
         src: include_str!("../../src/components/checkbox.rs"),
     },
+    FlattenInvariant {
+        file: "checklist.rs",
+        src: include_str!("../../src/components/checklist.rs"),
+    },
+    FlattenInvariant {
+        file: "checklist_state.rs",
+        src: include_str!("../../src/components/checklist_state.rs"),
+    },
     FlattenInvariant {
         file: "chip.rs",
         src: include_str!("../../src/components/chip.rs"),
     },
+    FlattenInvariant {
+        file: "color_picker.rs",
+        src: include_str!("../../src/components/color_picker.rs"),
+    },
+    FlattenInvariant {
+        file: "color_picker_state.rs",
+        src: include_str!("../../src/components/color_picker_state.rs"),
+    },
+    FlattenInvariant {
+        file: "combobox.rs",
+        src: include_str!("../../src/components/combobox.rs"),
+    },
+    FlattenInvariant {
+        file: "combobox_state.rs",
+        src: include_str!("../../src/components/combobox_state.rs"),
+    },
+    FlattenInvariant {
+        file: "compare_slider.rs",
+        src: include_str!("../../src/components/compare_slider.rs"),
+    },
+    FlattenInvariant {
+        file: "compare_slider_state.rs",
+        src: include_str!("../../src/components/compare_slider_state.rs"),
+    },
+    FlattenInvariant {
+        file: "composition.rs",
+        src: include_str!("../../src/components/composition.rs"),
+    },
+    FlattenInvariant {
+        file: "console_input.rs",
+        src: include_str!("../../src/components/console_input.rs"),
+    },
+    FlattenInvariant {
+        file: "console_state.rs",
+        src: include_str!("../../src/components/console_state.rs"),
+    },
+    FlattenInvariant {
+        file: "context_menu.rs",
+        src: include_str!("../../src/components/context_menu.rs"),
+    },
+    FlattenInvariant {
+        file: "context_menu_state.rs",
+        src: include_str!("../../src/components/context_menu_state.rs"),
+    },
     FlattenInvariant {
         file: "control.rs",
         src: include_str!("../../src/components/control.rs"),
     },
+    FlattenInvariant {
+        file: "copy_button.rs",
+        src: include_str!("../../src/components/copy_button.rs"),
+    },
+    FlattenInvariant {
+        file: "date_picker.rs",
+        src: include_str!("../../src/components/date_picker.rs"),
+    },
+    FlattenInvariant {
+        file: "date_picker_state.rs",
+        src: include_str!("../../src/components/date_picker_state.rs"),
+    },
     FlattenInvariant {
         file: "divider.rs",
         src: include_str!("../../src/components/divider.rs"),
     },
+    FlattenInvariant {
+        file: "dock_tabs.rs",
+        src: include_str!("../../src/components/dock_tabs.rs"),
+    },
+    FlattenInvariant {
+        file: "dock_tabs_state.rs",
+        src: include_str!("../../src/components/dock_tabs_state.rs"),
+    },
+    FlattenInvariant {
+        file: "donut_chart.rs",
+        src: include_str!("../../src/components/donut_chart.rs"),
+    },
     FlattenInvariant {
         file: "drawer.rs",
         src: include_str!("../../src/components/drawer.rs"),
     },
+    FlattenInvariant {
+        file: "drawer_state.rs",
+        src: include_str!("../../src/components/drawer_state.rs"),
+    },
+    FlattenInvariant {
+        file: "editable_text.rs",
+        src: include_str!("../../src/components/editable_text.rs"),
+    },
+    FlattenInvariant {
+        file: "editable_text_state.rs",
+        src: include_str!("../../src/components/editable_text_state.rs"),
+    },
+    FlattenInvariant {
+        file: "empty_state.rs",
+        src: include_str!("../../src/components/empty_state.rs"),
+    },
     FlattenInvariant {
         file: "field_variant.rs",
         src: include_str!("../../src/components/field_variant.rs"),
     },
+    FlattenInvariant {
+        file: "file_input.rs",
+        src: include_str!("../../src/components/file_input.rs"),
+    },
+    FlattenInvariant {
+        file: "file_input_state.rs",
+        src: include_str!("../../src/components/file_input_state.rs"),
+    },
+    FlattenInvariant {
+        file: "find_bar.rs",
+        src: include_str!("../../src/components/find_bar.rs"),
+    },
+    FlattenInvariant {
+        file: "graph_canvas.rs",
+        src: include_str!("../../src/components/graph_canvas.rs"),
+    },
+    FlattenInvariant {
+        file: "graph_canvas_state.rs",
+        src: include_str!("../../src/components/graph_canvas_state.rs"),
+    },
+    FlattenInvariant {
+        file: "graph_model.rs",
+        src: include_str!("../../src/components/graph_model.rs"),
+    },
+    FlattenInvariant {
+        file: "history_timeline.rs",
+        src: include_str!("../../src/components/history_timeline.rs"),
+    },
+    FlattenInvariant {
+        file: "history_timeline_state.rs",
+        src: include_str!("../../src/components/history_timeline_state.rs"),
+    },
     FlattenInvariant {
         file: "hovercard.rs",
         src: include_str!("../../src/components/hovercard.rs"),
@@ -64,10 +244,38 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "icon.rs",
         src: include_str!("../../src/components/icon.rs"),
     },
+    FlattenInvariant {
+        file: "image.rs",
+        src: include_str!("../../src/components/image.rs"),
+    },
+    FlattenInvariant {
+        file: "image_state.rs",
+        src: include_str!("../../src/components/image_state.rs"),
+    },
     FlattenInvariant {
         file: "indicator.rs",
         src: include_str!("../../src/components/indicator.rs"),
     },
+    FlattenInvariant {
+        file: "input_mask.rs",
+        src: include_str!("../../src/components/input_mask.rs"),
+    },
+    FlattenInvariant {
+        file: "json_tree.rs",
+        src: include_str!("../../src/components/json_tree.rs"),
+    },
+    FlattenInvariant {
+        file: "json_tree_state.rs",
+        src: include_str!("../../src/components/json_tree_state.rs"),
+    },
+    FlattenInvariant {
+        file: "kanban_board.rs",
+        src: include_str!("../../src/components/kanban_board.rs"),
+    },
+    FlattenInvariant {
+        file: "kanban_board_state.rs",
+        src: include_str!("../../src/components/kanban_board_state.rs"),
+    },
     FlattenInvariant {
         file: "input.rs",
         src: include_str!("../../src/components/input.rs"),
@@ -84,6 +292,10 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "layout.rs",
         src: include_str!("../../src/components/layout.rs"),
     },
+    FlattenInvariant {
+        file: "line_chart.rs",
+        src: include_str!("../../src/components/line_chart.rs"),
+    },
     FlattenInvariant {
         file: "loader.rs",
         src: include_str!("../../src/components/loader.rs"),
@@ -92,26 +304,62 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "loading_overlay.rs",
         src: include_str!("../../src/components/loading_overlay.rs"),
     },
+    FlattenInvariant {
+        file: "log_buffer.rs",
+        src: include_str!("../../src/components/log_buffer.rs"),
+    },
+    FlattenInvariant {
+        file: "log_view.rs",
+        src: include_str!("../../src/components/log_view.rs"),
+    },
+    FlattenInvariant {
+        file: "log_view_state.rs",
+        src: include_str!("../../src/components/log_view_state.rs"),
+    },
     FlattenInvariant {
         file: "markdown.rs",
         src: include_str!("../../src/components/markdown.rs"),
     },
+    FlattenInvariant {
+        file: "markdown_stream.rs",
+        src: include_str!("../../src/components/markdown_stream.rs"),
+    },
     FlattenInvariant {
         file: "menu.rs",
         src: include_str!("../../src/components/menu.rs"),
     },
+    FlattenInvariant {
+        file: "menu_panel.rs",
+        src: include_str!("../../src/components/menu_panel.rs"),
+    },
     FlattenInvariant {
         file: "menu_state.rs",
         src: include_str!("../../src/components/menu_state.rs"),
     },
+    FlattenInvariant {
+        file: "minimap.rs",
+        src: include_str!("../../src/components/minimap.rs"),
+    },
+    FlattenInvariant {
+        file: "minimap_state.rs",
+        src: include_str!("../../src/components/minimap_state.rs"),
+    },
     FlattenInvariant {
         file: "modal.rs",
         src: include_str!("../../src/components/modal.rs"),
     },
+    FlattenInvariant {
+        file: "notification_center.rs",
+        src: include_str!("../../src/components/notification_center.rs"),
+    },
     FlattenInvariant {
         file: "number_input.rs",
         src: include_str!("../../src/components/number_input.rs"),
     },
+    FlattenInvariant {
+        file: "number_input_state.rs",
+        src: include_str!("../../src/components/number_input_state.rs"),
+    },
     FlattenInvariant {
         file: "overlay.rs",
         src: include_str!("../../src/components/overlay.rs"),
@@ -124,6 +372,18 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "paper.rs",
         src: include_str!("../../src/components/paper.rs"),
     },
+    FlattenInvariant {
+        file: "password_strength.rs",
+        src: include_str!("../../src/components/password_strength.rs"),
+    },
+    FlattenInvariant {
+        file: "paste_attachment.rs",
+        src: include_str!("../../src/components/paste_attachment.rs"),
+    },
+    FlattenInvariant {
+        file: "pin_input_state.rs",
+        src: include_str!("../../src/components/pin_input_state.rs"),
+    },
     FlattenInvariant {
         file: "popover.rs",
         src: include_str!("../../src/components/popover.rs"),
@@ -136,6 +396,10 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "popup_state.rs",
         src: include_str!("../../src/components/popup_state.rs"),
     },
+    FlattenInvariant {
+        file: "press_feedback.rs",
+        src: include_str!("../../src/components/press_feedback.rs"),
+    },
     FlattenInvariant {
         file: "progress.rs",
         src: include_str!("../../src/components/progress.rs"),
@@ -152,6 +416,26 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "rating.rs",
         src: include_str!("../../src/components/rating.rs"),
     },
+    FlattenInvariant {
+        file: "rating_state.rs",
+        src: include_str!("../../src/components/rating_state.rs"),
+    },
+    FlattenInvariant {
+        file: "ring_progress.rs",
+        src: include_str!("../../src/components/ring_progress.rs"),
+    },
+    FlattenInvariant {
+        file: "ring_progress_state.rs",
+        src: include_str!("../../src/components/ring_progress_state.rs"),
+    },
+    FlattenInvariant {
+        file: "rulers.rs",
+        src: include_str!("../../src/components/rulers.rs"),
+    },
+    FlattenInvariant {
+        file: "rulers_state.rs",
+        src: include_str!("../../src/components/rulers_state.rs"),
+    },
     FlattenInvariant {
         file: "scroll_area.rs",
         src: include_str!("../../src/components/scroll_area.rs"),
@@ -172,6 +456,14 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "selection_state.rs",
         src: include_str!("../../src/components/selection_state.rs"),
     },
+    FlattenInvariant {
+        file: "settings_view.rs",
+        src: include_str!("../../src/components/settings_view.rs"),
+    },
+    FlattenInvariant {
+        file: "settings_view_state.rs",
+        src: include_str!("../../src/components/settings_view_state.rs"),
+    },
     FlattenInvariant {
         file: "slider.rs",
         src: include_str!("../../src/components/slider.rs"),
@@ -180,6 +472,26 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "slider_axis.rs",
         src: include_str!("../../src/components/slider_axis.rs"),
     },
+    FlattenInvariant {
+        file: "sparkline.rs",
+        src: include_str!("../../src/components/sparkline.rs"),
+    },
+    FlattenInvariant {
+        file: "spell_underline.rs",
+        src: include_str!("../../src/components/spell_underline.rs"),
+    },
+    FlattenInvariant {
+        file: "split_pane.rs",
+        src: include_str!("../../src/components/split_pane.rs"),
+    },
+    FlattenInvariant {
+        file: "split_pane_state.rs",
+        src: include_str!("../../src/components/split_pane_state.rs"),
+    },
+    FlattenInvariant {
+        file: "stat_card.rs",
+        src: include_str!("../../src/components/stat_card.rs"),
+    },
     FlattenInvariant {
         file: "stepper.rs",
         src: include_str!("../../src/components/stepper.rs"),
@@ -200,6 +512,18 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "tabs.rs",
         src: include_str!("../../src/components/tabs.rs"),
     },
+    FlattenInvariant {
+        file: "tags_input.rs",
+        src: include_str!("../../src/components/tags_input.rs"),
+    },
+    FlattenInvariant {
+        file: "tags_input_state.rs",
+        src: include_str!("../../src/components/tags_input_state.rs"),
+    },
+    FlattenInvariant {
+        file: "task_status.rs",
+        src: include_str!("../../src/components/task_status.rs"),
+    },
     FlattenInvariant {
         file: "text.rs",
         src: include_str!("../../src/components/text.rs"),
@@ -212,10 +536,22 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "text_input_state.rs",
         src: include_str!("../../src/components/text_input_state.rs"),
     },
+    FlattenInvariant {
+        file: "text_selection.rs",
+        src: include_str!("../../src/components/text_selection.rs"),
+    },
     FlattenInvariant {
         file: "textarea.rs",
         src: include_str!("../../src/components/textarea.rs"),
     },
+    FlattenInvariant {
+        file: "time_picker.rs",
+        src: include_str!("../../src/components/time_picker.rs"),
+    },
+    FlattenInvariant {
+        file: "time_picker_state.rs",
+        src: include_str!("../../src/components/time_picker_state.rs"),
+    },
     FlattenInvariant {
         file: "timeline.rs",
         src: include_str!("../../src/components/timeline.rs"),
@@ -252,6 +588,22 @@ const INVARIANTS: &[FlattenInvariant] = &[
         file: "utils.rs",
         src: include_str!("../../src/components/utils.rs"),
     },
+    FlattenInvariant {
+        file: "wizard.rs",
+        src: include_str!("../../src/components/wizard.rs"),
+    },
+    FlattenInvariant {
+        file: "wizard_state.rs",
+        src: include_str!("../../src/components/wizard_state.rs"),
+    },
+    FlattenInvariant {
+        file: "zoom_pane.rs",
+        src: include_str!("../../src/components/zoom_pane.rs"),
+    },
+    FlattenInvariant {
+        file: "zoom_pane_state.rs",
+        src: include_str!("../../src/components/zoom_pane_state.rs"),
+    },
 ];
 
 #[test]
@@ -51,13 +51,41 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "announcement_bar.rs",
+        max_child: 8,
+        max_div: 4,
+        max_canvas: 0,
+        max_chain: 4,
+    },
     DepthBudget {
         file: "app_shell.rs",
-        max_child: 32,
-        max_div: 14,
+        max_child: 42,
+        max_div: 16,
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "app_shell_layout.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "async_content.rs",
+        max_child: 10,
+        max_div: 4,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "auto_form.rs",
+        max_child: 5,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "badge.rs",
         max_child: 5,
@@ -65,13 +93,41 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 6,
     },
+    DepthBudget {
+        file: "bar_chart.rs",
+        max_child: 7,
+        max_div: 6,
+        max_canvas: 0,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "bottom_sheet.rs",
+        max_child: 10,
+        max_div: 9,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "bottom_sheet_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
     DepthBudget {
         file: "breadcrumbs.rs",
-        max_child: 6,
+        max_child: 11,
         max_div: 5,
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "breadcrumbs_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "button.rs",
         max_child: 17,
@@ -79,6 +135,48 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 6,
     },
+    DepthBudget {
+        file: "calendar.rs",
+        max_child: 23,
+        max_div: 7,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "calendar_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "chart.rs",
+        max_child: 13,
+        max_div: 11,
+        max_canvas: 1,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "chart_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "chat_view.rs",
+        max_child: 27,
+        max_div: 18,
+        max_canvas: 2,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "chat_view_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 6,
+    },
     DepthBudget {
         file: "checkbox.rs",
         max_child: 15,
@@ -86,6 +184,20 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "checklist.rs",
+        max_child: 15,
+        max_div: 6,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "checklist_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 3,
+    },
     DepthBudget {
         file: "chip.rs",
         max_child: 15,
@@ -93,6 +205,83 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "color_picker.rs",
+        max_child: 13,
+        max_div: 13,
+        max_canvas: 1,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "color_picker_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "combobox.rs",
+        max_child: 11,
+        max_div: 8,
+        max_canvas: 1,
+        max_chain: 7,
+    },
+    DepthBudget {
+        file: "combobox_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 3,
+    },
+    DepthBudget {
+        file: "compare_slider.rs",
+        max_child: 10,
+        max_div: 8,
+        max_canvas: 1,
+        max_chain: 7,
+    },
+    DepthBudget {
+        file: "compare_slider_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "composition.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 1,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "console_input.rs",
+        max_child: 6,
+        max_div: 5,
+        max_canvas: 1,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "console_state.rs",
+        max_child: 2,
+        max_div: 2,
+        max_canvas: 1,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "context_menu.rs",
+        max_child: 12,
+        max_div: 7,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "context_menu_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 3,
+    },
     DepthBudget {
         file: "control.rs",
         max_child: 2,
@@ -100,6 +289,27 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "copy_button.rs",
+        max_child: 4,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "date_picker.rs",
+        max_child: 34,
+        max_div: 12,
+        max_canvas: 1,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "date_picker_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "divider.rs",
         max_child: 6,
@@ -107,13 +317,62 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "dock_tabs.rs",
+        max_child: 19,
+        max_div: 13,
+        max_canvas: 2,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "dock_tabs_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "donut_chart.rs",
+        max_child: 5,
+        max_div: 2,
+        max_canvas: 0,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "drawer.rs",
-        max_child: 16,
+        max_child: 17,
         max_div: 16,
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "drawer_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "editable_text.rs",
+        max_child: 5,
+        max_div: 3,
+        max_canvas: 0,
+        max_chain: 7,
+    },
+    DepthBudget {
+        file: "editable_text_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 1,
+    },
+    DepthBudget {
+        file: "empty_state.rs",
+        max_child: 10,
+        max_div: 5,
+        max_canvas: 0,
+        max_chain: 4,
+    },
     DepthBudget {
         file: "field_variant.rs",
         max_child: 2,
@@ -121,6 +380,62 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "file_input.rs",
+        max_child: 29,
+        max_div: 11,
+        max_canvas: 0,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "file_input_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "find_bar.rs",
+        max_child: 13,
+        max_div: 4,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "graph_canvas.rs",
+        max_child: 14,
+        max_div: 10,
+        max_canvas: 3,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "graph_canvas_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 10,
+    },
+    DepthBudget {
+        file: "graph_model.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 8,
+    },
+    DepthBudget {
+        file: "history_timeline.rs",
+        max_child: 6,
+        max_div: 4,
+        max_canvas: 1,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "history_timeline_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 4,
+    },
     DepthBudget {
         file: "hovercard.rs",
         max_child: 11,
@@ -135,6 +450,20 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "image.rs",
+        max_child: 4,
+        max_div: 3,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "image_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "indicator.rs",
         max_child: 6,
@@ -142,10 +471,45 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 7,
     },
+    DepthBudget {
+        file: "input_mask.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "json_tree.rs",
+        max_child: 20,
+        max_div: 14,
+        max_canvas: 1,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "json_tree_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "kanban_board.rs",
+        max_child: 14,
+        max_div: 12,
+        max_canvas: 1,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "kanban_board_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 3,
+    },
     DepthBudget {
         file: "input.rs",
-        max_child: 40,
-        max_div: 19,
+        max_child: 52,
+        max_div: 26,
         max_canvas: 2,
         max_chain: 6,
     },
@@ -170,6 +534,13 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 6,
     },
+    DepthBudget {
+        file: "line_chart.rs",
+        max_child: 8,
+        max_div: 6,
+        max_canvas: 0,
+        max_chain: 6,
+    },
     DepthBudget {
         file: "loader.rs",
         max_child: 17,
@@ -184,13 +555,41 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 6,
     },
+    DepthBudget {
+        file: "log_buffer.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 7,
+    },
+    DepthBudget {
+        file: "log_view.rs",
+        max_child: 14,
+        max_div: 10,
+        max_canvas: 1,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "log_view_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 3,
+    },
     DepthBudget {
         file: "markdown.rs",
-        max_child: 26,
+        max_child: 36,
         max_div: 18,
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "markdown_stream.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 3,
+    },
     DepthBudget {
         file: "menu.rs",
         max_child: 10,
@@ -198,6 +597,13 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "menu_panel.rs",
+        max_child: 10,
+        max_div: 5,
+        max_canvas: 1,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "menu_state.rs",
         max_child: 2,
@@ -205,6 +611,20 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "minimap.rs",
+        max_child: 4,
+        max_div: 4,
+        max_canvas: 1,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "minimap_state.rs",
+        max_child: 2,
+        max_div: 2,
+        max_canvas: 1,
+        max_chain: 6,
+    },
     DepthBudget {
         file: "mod.rs",
         max_child: 2,
@@ -219,6 +639,13 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "notification_center.rs",
+        max_child: 19,
+        max_div: 7,
+        max_canvas: 0,
+        max_chain: 4,
+    },
     DepthBudget {
         file: "number_input.rs",
         max_child: 8,
@@ -226,6 +653,13 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 6,
     },
+    DepthBudget {
+        file: "number_input_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
     DepthBudget {
         file: "overlay.rs",
         max_child: 5,
@@ -247,6 +681,27 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "password_strength.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 3,
+    },
+    DepthBudget {
+        file: "paste_attachment.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "pin_input_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 2,
+    },
     DepthBudget {
         file: "popover.rs",
         max_child: 6,
@@ -268,6 +723,13 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "press_feedback.rs",
+        max_child: 3,
+        max_div: 2,
+        max_canvas: 1,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "progress.rs",
         max_child: 13,
@@ -284,18 +746,53 @@ const BUDGETS: &[DepthBudget] = &[
     },
     DepthBudget {
         file: "range_slider.rs",
-        max_child: 34,
+        max_child: 42,
         max_div: 16,
         max_canvas: 4,
-        max_chain: 6,
+        max_chain: 7,
     },
     DepthBudget {
         file: "rating.rs",
-        max_child: 14,
-        max_div: 6,
+        max_child: 18,
+        max_div: 8,
         max_canvas: 1,
         max_chain: 6,
     },
+    DepthBudget {
+        file: "rating_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "ring_progress.rs",
+        max_child: 5,
+        max_div: 2,
+        max_canvas: 2,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "ring_progress_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "rulers.rs",
+        max_child: 13,
+        max_div: 10,
+        max_canvas: 2,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "rulers_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 4,
+    },
     DepthBudget {
         file: "scroll_area.rs",
         max_child: 4,
@@ -331,12 +828,26 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 2,
     },
+    DepthBudget {
+        file: "settings_view.rs",
+        max_child: 29,
+        max_div: 19,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "settings_view_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 6,
+    },
     DepthBudget {
         file: "slider.rs",
-        max_child: 30,
-        max_div: 13,
+        max_child: 39,
+        max_div: 15,
         max_canvas: 2,
-        max_chain: 6,
+        max_chain: 7,
     },
     DepthBudget {
         file: "slider_axis.rs",
@@ -345,6 +856,41 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "sparkline.rs",
+        max_child: 2,
+        max_div: 1,
+        max_canvas: 0,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "spell_underline.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 1,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "split_pane.rs",
+        max_child: 8,
+        max_div: 6,
+        max_canvas: 1,
+        max_chain: 5,
+    },
+    DepthBudget {
+        file: "split_pane_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "stat_card.rs",
+        max_child: 15,
+        max_div: 8,
+        max_canvas: 0,
+        max_chain: 4,
+    },
     DepthBudget {
         file: "stepper.rs",
         max_child: 25,
@@ -380,6 +926,27 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 6,
     },
+    DepthBudget {
+        file: "tags_input.rs",
+        max_child: 16,
+        max_div: 9,
+        max_canvas: 1,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "tags_input_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 3,
+    },
+    DepthBudget {
+        file: "task_status.rs",
+        max_child: 20,
+        max_div: 7,
+        max_canvas: 0,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "text.rs",
         max_child: 3,
@@ -401,6 +968,13 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 5,
     },
+    DepthBudget {
+        file: "text_selection.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 0,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "textarea.rs",
         max_child: 33,
@@ -408,6 +982,20 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 4,
         max_chain: 6,
     },
+    DepthBudget {
+        file: "time_picker.rs",
+        max_child: 28,
+        max_div: 11,
+        max_canvas: 1,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "time_picker_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 5,
+    },
     DepthBudget {
         file: "timeline.rs",
         max_child: 17,
@@ -471,6 +1059,34 @@ const BUDGETS: &[DepthBudget] = &[
         max_canvas: 1,
         max_chain: 4,
     },
+    DepthBudget {
+        file: "wizard.rs",
+        max_child: 9,
+        max_div: 3,
+        max_canvas: 0,
+        max_chain: 6,
+    },
+    DepthBudget {
+        file: "wizard_state.rs",
+        max_child: 0,
+        max_div: 0,
+        max_canvas: 0,
+        max_chain: 4,
+    },
+    DepthBudget {
+        file: "zoom_pane.rs",
+        max_child: 6,
+        max_div: 4,
+        max_canvas: 2,
+        max_chain: 7,
+    },
+    DepthBudget {
+        file: "zoom_pane_state.rs",
+        max_child: 1,
+        max_div: 1,
+        max_canvas: 1,
+        max_chain: 8,
+    },
 ];
 
 #[test]
@@ -480,57 +1096,147 @@ fn component_layout_depth_stays_within_budget() {
             "accordion.rs" => include_str!("../../src/components/accordion.rs"),
             "action_icon.rs" => include_str!("../../src/components/action_icon.rs"),
             "alert.rs" => include_str!("../../src/components/alert.rs"),
+            "announcement_bar.rs" => include_str!("../../src/components/announcement_bar.rs"),
             "app_shell.rs" => include_str!("../../src/components/app_shell.rs"),
+            "app_shell_layout.rs" => include_str!("../../src/components/app_shell_layout.rs"),
+            "async_content.rs" => include_str!("../../src/components/async_content.rs"),
+            "auto_form.rs" => include_str!("../../src/components/auto_form.rs"),
             "badge.rs" => include_str!("../../src/components/badge.rs"),
+            "bar_chart.rs" => include_str!("../../src/components/bar_chart.rs"),
+            "bottom_sheet.rs" => include_str!("../../src/components/bottom_sheet.rs"),
+            "bottom_sheet_state.rs" => include_str!("../../src/components/bottom_sheet_state.rs"),
             "breadcrumbs.rs" => include_str!("../../src/components/breadcrumbs.rs"),
+            "breadcrumbs_state.rs" => include_str!("../../src/components/breadcrumbs_state.rs"),
             "button.rs" => include_str!("../../src/components/button.rs"),
+            "calendar.rs" => include_str!("../../src/components/calendar.rs"),
+            "calendar_state.rs" => include_str!("../../src/components/calendar_state.rs"),
+            "chart.rs" => include_str!("../../src/components/chart.rs"),
+            "chart_state.rs" => include_str!("../../src/components/chart_state.rs"),
+            "chat_view.rs" => include_str!("../../src/components/chat_view.rs"),
+            "chat_view_state.rs" => include_str!("../../src/components/chat_view_state.rs"),
             "checkbox.rs" => include_str!("../../src/components/checkbox.rs"),
+            "checklist.rs" => include_str!("../../src/components/checklist.rs"),
+            "checklist_state.rs" => include_str!("../../src/components/checklist_state.rs"),
             "chip.rs" => include_str!("../../src/components/chip.rs"),
+            "color_picker.rs" => include_str!("../../src/components/color_picker.rs"),
+            "color_picker_state.rs" => include_str!("../../src/components/color_picker_state.rs"),
+            "combobox.rs" => include_str!("../../src/components/combobox.rs"),
+            "combobox_state.rs" => include_str!("../../src/components/combobox_state.rs"),
+            "compare_slider.rs" => include_str!("../../src/components/compare_slider.rs"),
+            "compare_slider_state.rs" => {
+                include_str!("../../src/components/compare_slider_state.rs")
+            }
+            "composition.rs" => include_str!("../../src/components/composition.rs"),
+            "console_input.rs" => include_str!("../../src/components/console_input.rs"),
+            "console_state.rs" => include_str!("../../src/components/console_state.rs"),
+            "context_menu.rs" => include_str!("../../src/components/context_menu.rs"),
+            "context_menu_state.rs" => include_str!("../../src/components/context_menu_state.rs"),
             "control.rs" => include_str!("../../src/components/control.rs"),
+            "copy_button.rs" => include_str!("../../src/components/copy_button.rs"),
+            "date_picker.rs" => include_str!("../../src/components/date_picker.rs"),
+            "date_picker_state.rs" => include_str!("../../src/components/date_picker_state.rs"),
             "divider.rs" => include_str!("../../src/components/divider.rs"),
+            "dock_tabs.rs" => include_str!("../../src/components/dock_tabs.rs"),
+            "dock_tabs_state.rs" => include_str!("../../src/components/dock_tabs_state.rs"),
+            "donut_chart.rs" => include_str!("../../src/components/donut_chart.rs"),
             "drawer.rs" => include_str!("../../src/components/drawer.rs"),
+            "drawer_state.rs" => include_str!("../../src/components/drawer_state.rs"),
+            "editable_text.rs" => include_str!("../../src/components/editable_text.rs"),
+            "editable_text_state.rs" => include_str!("../../src/components/editable_text_state.rs"),
+            "empty_state.rs" => include_str!("../../src/components/empty_state.rs"),
             "field_variant.rs" => include_str!("../../src/components/field_variant.rs"),
+            "file_input.rs" => include_str!("../../src/components/file_input.rs"),
+            "file_input_state.rs" => include_str!("../../src/components/file_input_state.rs"),
+            "find_bar.rs" => include_str!("../../src/components/find_bar.rs"),
+            "graph_canvas.rs" => include_str!("../../src/components/graph_canvas.rs"),
+            "graph_canvas_state.rs" => include_str!("../../src/components/graph_canvas_state.rs"),
+            "graph_model.rs" => include_str!("../../src/components/graph_model.rs"),
+            "history_timeline.rs" => include_str!("../../src/components/history_timeline.rs"),
+            "history_timeline_state.rs" => {
+                include_str!("../../src/components/history_timeline_state.rs")
+            }
             "hovercard.rs" => include_str!("../../src/components/hovercard.rs"),
             "icon.rs" => include_str!("../../src/components/icon.rs"),
+            "image.rs" => include_str!("../../src/components/image.rs"),
+            "image_state.rs" => include_str!("../../src/components/image_state.rs"),
             "indicator.rs" => include_str!("../../src/components/indicator.rs"),
+            "input_mask.rs" => include_str!("../../src/components/input_mask.rs"),
+            "json_tree.rs" => include_str!("../../src/components/json_tree.rs"),
+            "json_tree_state.rs" => include_str!("../../src/components/json_tree_state.rs"),
+            "kanban_board.rs" => include_str!("../../src/components/kanban_board.rs"),
+            "kanban_board_state.rs" => include_str!("../../src/components/kanban_board_state.rs"),
             "input.rs" => include_str!("../../src/components/input.rs"),
             "interaction_adapter.rs" => include_str!("../../src/components/interaction_adapter.rs"),
             "layers.rs" => include_str!("../../src/components/layers.rs"),
             "layout.rs" => include_str!("../../src/components/layout.rs"),
+            "line_chart.rs" => include_str!("../../src/components/line_chart.rs"),
             "loader.rs" => include_str!("../../src/components/loader.rs"),
             "loading_overlay.rs" => include_str!("../../src/components/loading_overlay.rs"),
+            "log_buffer.rs" => include_str!("../../src/components/log_buffer.rs"),
+            "log_view.rs" => include_str!("../../src/components/log_view.rs"),
+            "log_view_state.rs" => include_str!("../../src/components/log_view_state.rs"),
             "markdown.rs" => include_str!("../../src/components/markdown.rs"),
+            "markdown_stream.rs" => include_str!("../../src/components/markdown_stream.rs"),
             "menu.rs" => include_str!("../../src/components/menu.rs"),
+            "menu_panel.rs" => include_str!("../../src/components/menu_panel.rs"),
             "menu_state.rs" => include_str!("../../src/components/menu_state.rs"),
+            "minimap.rs" => include_str!("../../src/components/minimap.rs"),
+            "minimap_state.rs" => include_str!("../../src/components/minimap_state.rs"),
             "mod.rs" => include_str!("../../src/components/mod.rs"),
             "modal.rs" => include_str!("../../src/components/modal.rs"),
+            "notification_center.rs" => {
+                include_str!("../../src/components/notification_center.rs")
+            }
             "number_input.rs" => include_str!("../../src/components/number_input.rs"),
+            "number_input_state.rs" => include_str!("../../src/components/number_input_state.rs"),
             "overlay.rs" => include_str!("../../src/components/overlay.rs"),
             "pagination.rs" => include_str!("../../src/components/pagination.rs"),
             "paper.rs" => include_str!("../../src/components/paper.rs"),
+            "password_strength.rs" => include_str!("../../src/components/password_strength.rs"),
+            "paste_attachment.rs" => include_str!("../../src/components/paste_attachment.rs"),
+            "pin_input_state.rs" => include_str!("../../src/components/pin_input_state.rs"),
             "popover.rs" => include_str!("../../src/components/popover.rs"),
             "popup.rs" => include_str!("../../src/components/popup.rs"),
             "popup_state.rs" => include_str!("../../src/components/popup_state.rs"),
+            "press_feedback.rs" => include_str!("../../src/components/press_feedback.rs"),
             "progress.rs" => include_str!("../../src/components/progress.rs"),
             "radio.rs" => include_str!("../../src/components/radio.rs"),
             "range_slider.rs" => include_str!("../../src/components/range_slider.rs"),
             "rating.rs" => include_str!("../../src/components/rating.rs"),
+            "rating_state.rs" => include_str!("../../src/components/rating_state.rs"),
+            "ring_progress.rs" => include_str!("../../src/components/ring_progress.rs"),
+            "ring_progress_state.rs" => include_str!("../../src/components/ring_progress_state.rs"),
+            "rulers.rs" => include_str!("../../src/components/rulers.rs"),
+            "rulers_state.rs" => include_str!("../../src/components/rulers_state.rs"),
             "scroll_area.rs" => include_str!("../../src/components/scroll_area.rs"),
             "segmented_control.rs" => include_str!("../../src/components/segmented_control.rs"),
             "select.rs" => include_str!("../../src/components/select.rs"),
             "select_state.rs" => include_str!("../../src/components/select_state.rs"),
             "selection_state.rs" => include_str!("../../src/components/selection_state.rs"),
+            "settings_view.rs" => include_str!("../../src/components/settings_view.rs"),
+            "settings_view_state.rs" => include_str!("../../src/components/settings_view_state.rs"),
             "slider.rs" => include_str!("../../src/components/slider.rs"),
             "slider_axis.rs" => include_str!("../../src/components/slider_axis.rs"),
+            "sparkline.rs" => include_str!("../../src/components/sparkline.rs"),
+            "spell_underline.rs" => include_str!("../../src/components/spell_underline.rs"),
+            "split_pane.rs" => include_str!("../../src/components/split_pane.rs"),
+            "split_pane_state.rs" => include_str!("../../src/components/split_pane_state.rs"),
+            "stat_card.rs" => include_str!("../../src/components/stat_card.rs"),
             "stepper.rs" => include_str!("../../src/components/stepper.rs"),
             "switch.rs" => include_str!("../../src/components/switch.rs"),
             "table.rs" => include_str!("../../src/components/table.rs"),
             "table_state.rs" => include_str!("../../src/components/table_state.rs"),
             "tabs.rs" => include_str!("../../src/components/tabs.rs"),
+            "tags_input.rs" => include_str!("../../src/components/tags_input.rs"),
+            "tags_input_state.rs" => include_str!("../../src/components/tags_input_state.rs"),
+            "task_status.rs" => include_str!("../../src/components/task_status.rs"),
             "text.rs" => include_str!("../../src/components/text.rs"),
             "text_input_actions.rs" => include_str!("../../src/components/text_input_actions.rs"),
             "text_input_state.rs" => include_str!("../../src/components/text_input_state.rs"),
+            "text_selection.rs" => include_str!("../../src/components/text_selection.rs"),
             "textarea.rs" => include_str!("../../src/components/textarea.rs"),
+            "time_picker.rs" => include_str!("../../src/components/time_picker.rs"),
+            "time_picker_state.rs" => include_str!("../../src/components/time_picker_state.rs"),
             "timeline.rs" => include_str!("../../src/components/timeline.rs"),
             "title.rs" => include_str!("../../src/components/title.rs"),
             "title_bar.rs" => include_str!("../../src/components/title_bar.rs"),
@@ -540,6 +1246,10 @@ fn component_layout_depth_stays_within_budget() {
             "tree.rs" => include_str!("../../src/components/tree.rs"),
             "tree_state.rs" => include_str!("../../src/components/tree_state.rs"),
             "utils.rs" => include_str!("../../src/components/utils.rs"),
+            "wizard.rs" => include_str!("../../src/components/wizard.rs"),
+            "wizard_state.rs" => include_str!("../../src/components/wizard_state.rs"),
+            "zoom_pane.rs" => include_str!("../../src/components/zoom_pane.rs"),
+            "zoom_pane_state.rs" => include_str!("../../src/components/zoom_pane_state.rs"),
             _ => unreachable!("unknown component file: {}", budget.file),
         };
         let (child, div, canvas, chain) = metrics(src);
@@ -64,6 +64,75 @@ fn markdown_theme_override_compiles_with_extended_tokens() {
     }));
 }
 
+#[test]
+fn markdown_code_blocks_can_offer_a_copy_button() {
+    let _ = into_any(Markdown::new("```sh\ncargo add calmui\n```").copy_code(true));
+    let _ = into_any(
+        Markdown::new("> ```\nquoted\n```")
+            .copy_code(true)
+            .selectable(true),
+    );
+}
+
+#[test]
+fn markdown_renders_footnotes_and_interactive_tasks() {
+    let sample = r#"
+Rendering uses GPUI[^gpui] and a cached parse[^cache].
+
+- [x] ~~Parse tables~~
+- [ ] Toggle tasks
+
+| Feature | Status |
+| :------ | -----: |
+| Footnotes[^gpui] | done |
+
+[^cache]: Keyed by a hash of the source.
+[^gpui]: A GPU-accelerated UI framework.
+
+    With an indented second paragraph.
+"#;
+
+    let _ = into_any(
+        Markdown::new(sample)
+            .on_task_toggle(|toggle, _window, _cx| {
+                let _ = (toggle.index, toggle.checked, toggle.range.clone());
+            })
+            .selectable(true),
+    );
+}
+
+#[test]
+fn markdown_renders_inline_images_and_routes_links() {
+    let sample = r#"
+Build [![status](badges/ci.svg)](https://ci.example.com) and ![logo](logo.png) inline.
+
+![Diagram](https://example.com/diagram.png "Overview")
+
+See [the guide](app://docs/guide) or [the site](https://example.com).
+"#;
+
+    let _ = into_any(
+        Markdown::new(sample)
+            .resolve_image(|src| {
+                (!src.contains("://"))
+                    .then(|| gpui::ImageSource::from(std::path::PathBuf::from("docs").join(src)))
+            })
+            .link_handler(|link, _window, _cx| link.href.starts_with("app://")),
+    );
+}
+
+#[test]
+fn markdown_streaming_accepts_growing_text() {
+    let reply = "Here is the plan:\n\n1. Parse\n2. Render\n\n```rust\nfn main() {}\n";
+    for end in [8, 20, reply.len()] {
+        let _ = into_any(
+            Markdown::new(&reply[..end])
+                .streaming(true)
+                .with_id("markdown-stream"),
+        );
+    }
+}
+
 #[test]
 fn markdown_compact_and_id_are_still_available() {
     let _ = into_any(
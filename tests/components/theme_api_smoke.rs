@@ -1,8 +1,8 @@
 use calmui::components::*;
 use calmui::contracts::{ComponentThemeOverridable, Themable};
-use calmui::feedback::ToastManager;
+use calmui::feedback::{NotificationInbox, ToastManager};
 use calmui::overlay::ModalManager;
-use calmui::theme::ComponentOverrides;
+use calmui::theme::{ComponentOverrides, PseudoStateTokens};
 use gpui::div;
 
 fn apply_component_theme<T: ComponentThemeOverridable>(component: T) -> T {
@@ -29,30 +29,43 @@ fn theme_api_smoke_for_themable_components_body() {
     let _ = apply_themable(apply_component_theme(Switch::new()));
     let _ = apply_themable(apply_component_theme(Chip::new()));
     let _ = apply_themable(apply_component_theme(ChipGroup::new()));
+    let _ = apply_themable(apply_component_theme(ConsoleInput::new()));
     let _ = apply_themable(apply_component_theme(Badge::new()));
     let _ = apply_themable(apply_component_theme(Accordion::new()));
     let _ = apply_themable(apply_component_theme(Menu::new()));
+    let _ = apply_themable(apply_component_theme(MiniMap::new()));
     let _ = apply_themable(apply_component_theme(Progress::new()));
+    let _ = apply_themable(apply_component_theme(RingProgress::new()));
+    let _ = apply_themable(apply_component_theme(StatCard::new()));
     let _ = apply_themable(apply_component_theme(Slider::new()));
     let _ = apply_themable(apply_component_theme(Overlay::new()));
     let _ = apply_themable(apply_component_theme(Loader::new()));
     let _ = apply_themable(apply_component_theme(LoadingOverlay::new()));
     let _ = apply_themable(apply_component_theme(Popover::new()));
     let _ = apply_themable(apply_component_theme(Tooltip::new()));
+    let _ = apply_themable(apply_component_theme(HistoryTimeline::new()));
     let _ = apply_themable(apply_component_theme(HoverCard::new()));
     let _ = apply_themable(apply_component_theme(Select::new()));
     let _ = apply_themable(apply_component_theme(MultiSelect::new()));
     let _ = apply_themable(apply_component_theme(Modal::new()));
     let _ = apply_themable(apply_component_theme(ModalLayer::new(ModalManager::new())));
     let _ = apply_themable(apply_component_theme(ToastLayer::new(ToastManager::new())));
+    let _ = apply_themable(apply_component_theme(
+        NotificationCenter::new().inbox(NotificationInbox::new()),
+    ));
+    let _ = apply_themable(apply_component_theme(FindBar::new(FindScope::new())));
     let _ = apply_themable(apply_component_theme(Alert::new()));
+    let _ = apply_themable(apply_component_theme(AnnouncementBar::new()));
     let _ = apply_themable(apply_component_theme(Divider::horizontal()));
+    let _ = apply_themable(apply_component_theme(EmptyState::new()));
+    let _ = apply_themable(apply_component_theme(DockTabs::new()));
     let _ = apply_themable(apply_component_theme(ScrollArea::new()));
     let _ = apply_themable(apply_component_theme(Drawer::new()));
     let _ = apply_themable(apply_component_theme(AppShell::new(div())));
     let _ = apply_themable(apply_component_theme(Sidebar::new()));
     let _ = apply_themable(apply_component_theme(TitleBar::new()));
     let _ = apply_themable(apply_component_theme(Markdown::new("demo")));
+    let _ = apply_themable(apply_component_theme(ChatView::new()));
     let _ = apply_themable(apply_component_theme(Markdown::new("demo").theme(
         |theme| {
             theme.markdown(|markdown| {
@@ -65,8 +78,21 @@ fn theme_api_smoke_for_themable_components_body() {
     )));
     let _ = apply_themable(apply_component_theme(Text::new("demo")));
     let _ = apply_themable(apply_component_theme(Title::new("demo")));
+    let _ = apply_themable(apply_component_theme(Image::new()));
+    let _ = apply_themable(apply_component_theme(KanbanBoard::new()));
+    let _ = apply_themable(apply_component_theme(LogView::new()));
     let _ = apply_themable(apply_component_theme(Paper::new()));
     let _ = apply_themable(apply_component_theme(ActionIcon::new()));
+    let _ = apply_themable(apply_component_theme(Button::new().label("states").themed(
+        |button| {
+            button.states(
+                PseudoStateTokens::new()
+                    .hover_bg(gpui::white())
+                    .active_bg(gpui::black())
+                    .disabled_fg(gpui::black()),
+            )
+        },
+    )));
     let _ = apply_themable(apply_component_theme(SegmentedControl::new()));
     let _ = apply_themable(apply_component_theme(Textarea::new()));
     let _ = apply_themable(apply_component_theme(NumberInput::new()));
@@ -75,13 +101,33 @@ fn theme_api_smoke_for_themable_components_body() {
     let _ = apply_themable(apply_component_theme(Tabs::new()));
     let _ = apply_themable(apply_component_theme(Pagination::new()));
     let _ = apply_themable(apply_component_theme(Breadcrumbs::new()));
+    let _ = apply_themable(apply_component_theme(Calendar::new()));
     let _ = apply_themable(apply_component_theme(Table::new()));
     let _ = apply_themable(apply_component_theme(Stepper::new()));
     let _ = apply_themable(apply_component_theme(Timeline::new()));
     let _ = apply_themable(apply_component_theme(Tree::new()));
+    let _ = apply_themable(apply_component_theme(Wizard::new()));
     let _ = apply_themable(apply_component_theme(Grid::new()));
     let _ = apply_themable(apply_component_theme(SimpleGrid::new()));
     let _ = apply_themable(apply_component_theme(Space::new()));
+    let _ = apply_themable(apply_component_theme(SplitPane::horizontal()));
+    let _ = apply_themable(apply_component_theme(CompareSlider::new()));
+    let _ = apply_themable(apply_component_theme(CopyButton::new()));
+    let _ = apply_themable(apply_component_theme(ZoomPane::new()));
+    let _ = apply_themable(apply_component_theme(GraphCanvas::new()));
+    let _ = apply_themable(apply_component_theme(Sparkline::new()));
+    let _ = apply_themable(apply_component_theme(BarChart::new()));
+    let _ = apply_themable(apply_component_theme(LineChart::new()));
+    let _ = apply_themable(apply_component_theme(DonutChart::new()));
+    let _ = apply_themable(apply_component_theme(DatePicker::new()));
+    let _ = apply_themable(apply_component_theme(Rulers::new()));
+    let _ = apply_themable(apply_component_theme(TimePicker::new()));
+    let _ = apply_themable(apply_component_theme(DateTimePicker::new()));
+    let _ = apply_themable(apply_component_theme(ColorPicker::new()));
+    let _ = apply_themable(apply_component_theme(FileInput::new()));
+    let _ = apply_themable(apply_component_theme(EditableText::new()));
+    let _ = apply_themable(apply_component_theme(Combobox::new()));
+    let _ = apply_themable(apply_component_theme(TagsInput::new()));
 }
 
 #[test]
@@ -0,0 +1,74 @@
+//! Custom palettes live in a process-wide registry, so these checks run as
+//! one test in their own binary where nothing builds themes concurrently.
+
+use calmui::theme::{ColorScheme, ColorToken, Theme, ThemeOverrides};
+use calmui::tokens::{PALETTE_KEYS, PaletteCatalog, PaletteKey};
+use gpui::{Hsla, Rgba};
+
+fn hex(value: &str) -> Hsla {
+    Rgba::try_from(value).map(Into::into).expect("valid hex")
+}
+
+#[test]
+fn registered_custom_palettes_reach_themes_and_intern_colors() {
+    let scale = [
+        "#e6fcf5", "#c3fae8", "#96f2d7", "#63e6be", "#38d9a9", "#20c997", "#0b7285", "#095c6b",
+        "#074652", "#053038",
+    ];
+    let before = Theme::default();
+    let name = String::from("teal-corporate");
+    let key = PaletteCatalog::register(&name, scale);
+    assert_eq!(key, PaletteKey::Custom("teal-corporate"));
+    assert_eq!(key.as_str(), "teal-corporate");
+    assert_eq!(PaletteCatalog::custom("teal-corporate"), Some(key));
+    assert!(PaletteCatalog::keys().contains(&key));
+    assert_eq!(PaletteCatalog::get(key), Some(scale));
+    assert!(PaletteCatalog::store().contains_key(&key));
+
+    let theme = Theme::default().with_primary_color(key);
+    assert_eq!(theme.primary_color, key);
+    assert_eq!(theme.palette.get(&key), Some(&scale));
+    assert_eq!(theme.components.button.filled_bg, hex("#0b7285"));
+    assert_eq!(ColorToken::palette(key, 2).resolve(&theme), hex("#96f2d7"));
+
+    let overrides = ThemeOverrides::default().primary_color(key);
+    assert_eq!(Theme::default().merged(&overrides).primary_color, key);
+    assert!(Theme::default().diff(&theme).palette_overrides.is_empty());
+    assert!(overrides.validate(&Theme::default()).is_empty());
+    assert_eq!(
+        before.diff(&theme).palette_overrides.get(&key),
+        Some(&scale)
+    );
+
+    PaletteCatalog::register("teal-corporate", [scale[0]; 10]);
+    assert_eq!(theme.clone().with_color_scheme(ColorScheme::Light), theme);
+    assert_eq!(ColorToken::palette(key, 2).resolve(&theme), hex("#96f2d7"));
+
+    let unknown = PaletteKey::Custom("never-registered");
+    assert_eq!(PaletteCatalog::custom("never-registered"), None);
+    assert_eq!(PaletteCatalog::get(unknown), None);
+    assert_eq!(
+        Theme::default().with_primary_color(unknown).components,
+        Theme::default()
+            .with_primary_color(PaletteKey::Gray)
+            .components
+    );
+
+    let brand = hex("#5f3dc4");
+    let first = PaletteCatalog::register("brand-violet", [brand; 10]);
+    let scale = PaletteCatalog::get(first).expect("registered");
+    assert!(
+        scale
+            .iter()
+            .all(|stop| stop.starts_with('#') && stop.len() == 7)
+    );
+    assert!(PaletteCatalog::keys().starts_with(&PALETTE_KEYS));
+
+    let again = PaletteCatalog::register(
+        String::from("brand-violet"),
+        std::array::from_fn::<_, 10, _>(|_| scale[0].to_string()),
+    );
+    assert_eq!(again, first);
+    let reinterned = PaletteCatalog::get(again).expect("registered");
+    assert!(std::ptr::eq(scale[0], reinterned[0]));
+}
@@ -24,6 +24,7 @@ fn widgets_facade_exports_render_components() {
     assert_render_once::<calmui::widgets::data::Table>();
     assert_render_once::<calmui::widgets::display::Text>();
     assert_render_once::<calmui::widgets::feedback::ToastLayer>();
+    assert_render_once::<calmui::widgets::charts::LineChart>();
 }
 
 #[test]
@@ -44,6 +45,50 @@ fn prelude_smoke_builds_core_widgets() {
     let _ = into_any(Tree::new().node(TreeNode::new("root").label("Root")));
     let _ = into_any(Tabs::new().item(TabItem::new("tab").label("Tab")));
     let _ = into_any(Table::new().header("Name").row(TableRow::new()));
+    let provider: CalmProvider = CalmProvider::builder()
+        .shortcuts(ShortcutRegistry::new())
+        .service(String::from("settings.json"))
+        .build();
+    assert_eq!(provider.get::<String>().as_deref(), Some("settings.json"));
+    let _: fn(&gpui::App) -> ToastManager = use_toasts;
+    let _: fn(&gpui::App) -> Option<String> = use_service::<String>;
+}
+
+#[test]
+fn prelude_domain_barrels_export_builders_tokens_and_events() {
+    {
+        use calmui::prelude::forms::*;
+
+        let _ = into_any(
+            Combobox::new()
+                .option(SelectOption::new("a").label("A"))
+                .layout(FieldLayout::Horizontal),
+        );
+        let _: SelectTokens = calmui::theme::Theme::default().components.select;
+        let _ = SelectOverrides::default();
+        let _ = SubmitState::Idle;
+    }
+    {
+        use calmui::prelude::overlays::*;
+
+        let _ = into_any(Drawer::new().content(div()));
+        let _ = ModalCloseReason::Programmatic;
+        let _ = ToastKind::Info;
+        let _: ModalTokens = calmui::theme::Theme::default().components.modal;
+    }
+    {
+        use calmui::prelude::data::*;
+
+        let _ = into_any(Table::new().header("Name").row(TableRow::new()));
+        let _ = into_any(Tree::new().node(TreeNode::new("root").label("Root")));
+        let _ = TableSortDirection::Asc;
+        let _: TableTokens = calmui::theme::Theme::default().components.table;
+    }
+
+    use calmui::prelude::*;
+    let _ = ModalId(1);
+    let _ = into_any(Divider::horizontal());
+    let _ = ProgressOverrides::default();
 }
 
 #[test]
@@ -54,6 +99,21 @@ fn foundation_facade_exports_core_types() {
     let _ = calmui::foundation::style::FieldLayout::Vertical;
     let _ = calmui::foundation::motion::MotionConfig::default();
     let _ = calmui::foundation::theme::Theme::default();
+    let _ = calmui::foundation::shape::Shape::arc((12.0, 12.0), 10.0, -135.0, 270.0, 3.0);
+    let _ = calmui::foundation::shape::shape_canvas(
+        vec![calmui::foundation::shape::Shape::dashed_line(
+            (0.0, 0.0),
+            (24.0, 0.0),
+            1.0,
+            4.0,
+            2.0,
+        )],
+        &calmui::foundation::theme::Theme::default(),
+    );
+    let clipboard = calmui::foundation::clipboard::Clipboard::new("api-smoke")
+        .feedback(calmui::foundation::clipboard::COPIED_FEEDBACK);
+    let _ = clipboard.copied();
+    let _ = calmui::foundation::hooks::use_clipboard("api-smoke");
     let _ = calmui::foundation::form::FormOptions::default();
     let _ = calmui::foundation::form::ValidationMode::OnSubmit;
     let _ = calmui::foundation::form::RevalidateMode::OnChange;
@@ -126,6 +186,49 @@ fn form_public_api_smoke_compiles() {
         .bind_number_input(fields.amount(), calmui::widgets::NumberInput::new())
         .expect("bind number input");
 
+    let _ = into_any(
+        calmui::widgets::form::AutoForm::new(controller.clone())
+            .columns(2)
+            .hide(fields.enabled())
+            .override_field(fields.amount(), |_, _| div().into_any_element())
+            .footer(calmui::widgets::Button::new().label("Save")),
+    );
+
+    let schema = calmui::widgets::form::SettingsSchema::new()
+        .section(
+            calmui::widgets::form::SettingsSection::new("general", "General")
+                .description("Basics")
+                .icon("settings")
+                .items(
+                    ApiSmokeForm::auto_fields()
+                        .into_iter()
+                        .map(calmui::widgets::form::SettingsItem::new),
+                ),
+        )
+        .section(
+            calmui::widgets::form::SettingsSection::new("billing", "Billing").item(
+                calmui::widgets::form::SettingsItem::custom(fields.amount(), |_, _| {
+                    div().into_any_element()
+                })
+                .label("Amount")
+                .keywords(["price"]),
+            ),
+        );
+    let _ = into_any(
+        calmui::widgets::form::SettingsView::new(controller.clone(), schema)
+            .default_section("billing")
+            .search("amount")
+            .on_search_change(|_, _, _| {})
+            .on_section_change(|_, _, _| {})
+            .on_change(|_, _| {}),
+    );
+    controller
+        .register_change_listener("smoke", |_, _| {})
+        .expect("register change listener");
+    controller
+        .unregister_change_listener("smoke")
+        .expect("unregister change listener");
+
     let store = calmui::form::InMemoryDraftStore::new();
     controller.save_draft(&store).expect("save draft");
     controller.reset_to_initial().expect("reset");
@@ -1,6 +0,0 @@
-#[derive(Clone, calmui::form::FormModel)]
-struct GenericForm<T> {
-    value: T,
-}
-
-fn main() {}
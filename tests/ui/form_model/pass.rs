@@ -1,8 +1,48 @@
-use calmui::form::{FieldLens, FormModel};
+use calmui::form::{FieldLens, FieldOptions, FormModel};
 
 #[derive(Clone, calmui::form::FormModel)]
 struct DemoForm {
+    #[form(required, pattern = "^[^@]+@[^@]+$", message = "Enter a valid email")]
+    #[form(label = "Email", placeholder = "you@calm.ui")]
     email: String,
+    #[form(min_len = 3)]
+    #[form(max_len = 32)]
+    nickname: Option<String>,
+}
+
+#[derive(Clone, calmui::form::FormModel)]
+struct Settings<T: Clone>
+where
+    T: PartialEq + Send + Sync + 'static,
+{
+    #[form(required)]
+    label: String,
+    value: T,
+}
+
+#[derive(Clone, PartialEq, calmui::form::FormModel)]
+struct Address {
+    #[form(required)]
+    street: String,
+}
+
+#[derive(Clone, calmui::form::FormModel)]
+struct Profile {
+    #[form(nested)]
+    address: Address,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, calmui::form::FieldOptions)]
+enum Theme {
+    Light,
+    #[option(value = "night", label = "Night mode")]
+    DarkMode,
+}
+
+#[derive(Clone, calmui::form::FormModel)]
+struct Appearance {
+    #[form(select)]
+    theme: Theme,
 }
 
 fn main() {
@@ -10,8 +50,41 @@ fn main() {
     let lens = fields.email();
     let mut model = DemoForm {
         email: "a@calm.ui".to_string(),
+        nickname: None,
     };
     lens.set(&mut model, "b@calm.ui".to_string());
     assert_eq!(lens.key().as_str(), "email");
     assert_eq!(lens.get(&model), "b@calm.ui");
+    assert_eq!(lens.rules().len(), 2);
+    assert_eq!(fields.nickname().rules().len(), 2);
+    let meta = DemoForm::field_meta(lens.key()).expect("email meta");
+    assert_eq!(meta.label, Some("Email"));
+    assert_eq!(meta.placeholder, Some("you@calm.ui"));
+    assert!(DemoForm::field_meta(fields.nickname().key()).is_none());
+
+    let settings = Settings::<u8>::fields();
+    let mut model = Settings {
+        label: "theme".to_string(),
+        value: 1u8,
+    };
+    settings.value().set(&mut model, 2);
+    assert_eq!(*settings.value().get(&model), 2);
+    assert_eq!(settings.label().rules().len(), 1);
+
+    let street = Profile::fields().address().street();
+    let mut profile = Profile {
+        address: Address {
+            street: String::new(),
+        },
+    };
+    street.set(&mut profile, "Main St".to_string());
+    assert_eq!(street.key().as_str(), "address.street");
+    assert_eq!(street.get(&profile), "Main St");
+    assert_eq!(street.rules().len(), 1);
+
+    assert_eq!(Theme::Light.label(), "Light");
+    assert_eq!(Theme::DarkMode.value(), "night");
+    assert_eq!(Theme::from_value("light"), Some(Theme::Light));
+    let theme = Appearance::fields().theme();
+    assert_eq!(theme.key().as_str(), "theme");
 }